@@ -0,0 +1,75 @@
+//! Parsing many tiny documents with the default allocation functions
+//! and into an arena, either a new one per batch or one reset between
+//! batches. The arena bytes used per document are printed first.
+//!
+//! Run with `cargo bench --features rust-xmlmemory --bench arena_bench`.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use libxml2::{Arena, XmlDoc};
+
+const BATCH: usize = 1000;
+
+/// Small documents of the kind a message queue carries, each a little
+/// different.
+fn documents() -> Vec<Vec<u8>> {
+    (0..BATCH)
+        .map(|i| {
+            format!(
+                "<msg id='{i}' kind='event'><from>node-{}</from><body>payload {i}</body>\
+                 <tags><tag>a</tag><tag>b{}</tag></tags></msg>",
+                i % 7,
+                i % 3
+            )
+            .into_bytes()
+        })
+        .collect()
+}
+
+fn bench_tiny_documents(c: &mut Criterion) {
+    let docs = documents();
+    unsafe { Arena::install() };
+    let arena = Arena::new();
+    for doc in &docs {
+        drop(XmlDoc::parse_memory_in(&arena, doc, 0).unwrap());
+    }
+    println!(
+        "arena bytes per document: {}",
+        arena.allocated() / docs.len()
+    );
+    drop(arena);
+
+    let mut group = c.benchmark_group("tiny_documents");
+    group.throughput(Throughput::Elements(docs.len() as u64));
+
+    group.bench_function(BenchmarkId::new("parse_memory", "default"), |b| {
+        b.iter(|| {
+            for doc in &docs {
+                black_box(XmlDoc::parse_memory(doc, 0).unwrap());
+            }
+        })
+    });
+
+    group.bench_function(BenchmarkId::new("parse_memory_in", "arena"), |b| {
+        b.iter(|| {
+            let arena = Arena::new();
+            for doc in &docs {
+                black_box(XmlDoc::parse_memory_in(&arena, doc, 0).unwrap());
+            }
+        })
+    });
+
+    group.bench_function(BenchmarkId::new("parse_memory_in", "arena_reset"), |b| {
+        let mut arena = Arena::new();
+        b.iter(|| {
+            arena.reset();
+            for doc in &docs {
+                black_box(XmlDoc::parse_memory_in(&arena, doc, 0).unwrap());
+            }
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_tiny_documents);
+criterion_main!(benches);
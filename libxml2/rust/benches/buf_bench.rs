@@ -0,0 +1,126 @@
+//! Appending many small chunks to the Rust buffer, a naive exact-fit
+//! buffer, and `xmlBufferAdd` from the Rust module and the baseline
+//! library. The number of reallocations of each is printed first.
+//!
+//! Run with `cargo bench --features rust-buf --bench buf_bench`.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use libxml2::buf::Buf;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::ops::Range;
+use std::os::raw::c_int;
+
+/// Chunks of 1 to 16 bytes, like the markup and text pieces the
+/// serializer appends, as slices of one buffer.
+fn workload(n: usize) -> (Vec<u8>, Vec<Range<usize>>) {
+    let mut rng = StdRng::seed_from_u64(0x627566);
+    let mut text = Vec::new();
+    let mut spans = Vec::with_capacity(n);
+    for _ in 0..n {
+        let len = rng.gen_range(1..=16);
+        spans.push(text.len()..text.len() + len);
+        text.extend((0..len).map(|_| rng.gen_range(b'a'..=b'z')));
+    }
+    (text, spans)
+}
+
+/// Grows to exactly the size needed on every append that doesn't fit.
+struct ExactFit(Vec<u8>);
+
+impl ExactFit {
+    fn add(&mut self, chunk: &[u8]) {
+        self.0.reserve_exact(chunk.len());
+        self.0.extend_from_slice(chunk);
+    }
+}
+
+fn count_reallocations(chunks: &[&[u8]]) {
+    let mut buf = Buf::new(0).unwrap();
+    let mut naive = ExactFit(Vec::new());
+    let (mut rust, mut exact) = (0, 0);
+    for &chunk in chunks {
+        let cap = (buf.capacity(), naive.0.capacity());
+        buf.add(chunk).unwrap();
+        naive.add(chunk);
+        rust += (buf.capacity() != cap.0) as usize;
+        exact += (naive.0.capacity() != cap.1) as usize;
+    }
+
+    let c_lib = libxml2::libxml2_dynload::get_c_baseline();
+    let (mut ffi, mut c) = (0, 0);
+    unsafe {
+        let buffer = libxml2::xmlBufferCreateSize(0);
+        let c_buffer = c_lib.xmlBufferCreateSize(0);
+        for &chunk in chunks {
+            let size = ((*buffer).size, (*c_buffer).size);
+            libxml2::xmlBufferAdd(buffer, chunk.as_ptr(), chunk.len() as c_int);
+            c_lib.xmlBufferAdd(c_buffer, chunk.as_ptr(), chunk.len() as c_int);
+            ffi += ((*buffer).size != size.0) as usize;
+            c += ((*c_buffer).size != size.1) as usize;
+        }
+        libxml2::xmlBufferFree(buffer);
+        c_lib.xmlBufferFree(c_buffer);
+    }
+
+    println!(
+        "reallocations for {} chunks: rust_buf {rust}, exact_fit {exact}, \
+         xmlBufferAdd rust {ffi}, xmlBufferAdd c {c}",
+        chunks.len()
+    );
+}
+
+fn bench_append(c: &mut Criterion) {
+    let (text, spans) = workload(100_000);
+    let chunks: Vec<&[u8]> = spans.iter().map(|span| &text[span.clone()]).collect();
+    count_reallocations(&chunks);
+
+    let mut group = c.benchmark_group("buf_append");
+    group.throughput(Throughput::Bytes(text.len() as u64));
+
+    group.bench_function(BenchmarkId::new("rust_buf", "amortized"), |b| {
+        b.iter(|| {
+            let mut buf = Buf::new(0).unwrap();
+            for &chunk in &chunks {
+                buf.add(chunk).unwrap();
+            }
+            black_box(buf)
+        })
+    });
+
+    group.bench_function(BenchmarkId::new("naive", "exact_fit"), |b| {
+        b.iter(|| {
+            let mut buf = ExactFit(Vec::new());
+            for &chunk in &chunks {
+                buf.add(chunk);
+            }
+            black_box(buf.0)
+        })
+    });
+
+    group.bench_function(BenchmarkId::new("rust_buffer", "xmlBufferAdd"), |b| {
+        b.iter(|| unsafe {
+            let buf = libxml2::xmlBufferCreateSize(0);
+            for &chunk in &chunks {
+                libxml2::xmlBufferAdd(buf, chunk.as_ptr(), chunk.len() as c_int);
+            }
+            libxml2::xmlBufferFree(black_box(buf));
+        })
+    });
+
+    let c_lib = libxml2::libxml2_dynload::get_c_baseline();
+    group.bench_function(BenchmarkId::new("c_buffer", "xmlBufferAdd"), |b| {
+        b.iter(|| unsafe {
+            let buf = c_lib.xmlBufferCreateSize(0);
+            for &chunk in &chunks {
+                c_lib.xmlBufferAdd(buf, chunk.as_ptr(), chunk.len() as c_int);
+            }
+            c_lib.xmlBufferFree(black_box(buf));
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_append);
+criterion_main!(benches);
@@ -0,0 +1,156 @@
+//! Interning throughput of the Rust dict, a `HashMap` with the default
+//! SipHash hasher, and the C dict from the baseline library.
+//!
+//! Run with `cargo bench --features rust-dict --bench dict_bench`.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use libxml2::dict::{Dict, Key};
+use rand::distributions::{Distribution, WeightedIndex};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use std::collections::HashMap;
+use std::ops::Range;
+use std::os::raw::c_int;
+
+/// Names that dominate real documents: XHTML, SVG, XSLT, SOAP and
+/// attribute names, most frequent first.
+const COMMON: &[&str] = &[
+    "div",
+    "class",
+    "id",
+    "a",
+    "href",
+    "span",
+    "p",
+    "li",
+    "td",
+    "style",
+    "xmlns",
+    "tr",
+    "img",
+    "src",
+    "type",
+    "name",
+    "value",
+    "title",
+    "ul",
+    "table",
+    "input",
+    "item",
+    "link",
+    "rel",
+    "g",
+    "path",
+    "d",
+    "fill",
+    "transform",
+    "xsl:template",
+    "xsl:value-of",
+    "select",
+    "match",
+    "xsl:apply-templates",
+    "soap:Envelope",
+    "soap:Body",
+    "xmlns:xsl",
+    "version",
+    "encoding",
+    "lang",
+    "xml:lang",
+    "data-toggle",
+    "aria-label",
+    "onclick",
+    "width",
+    "height",
+    "viewBox",
+    "stroke-width",
+    "xlink:href",
+    "description",
+];
+
+/// A Zipf-like stream of `n` names over the common names and a long tail
+/// of generated ones, like element and attribute names in a corpus. The
+/// names are slices of one buffer, as the parser sees them in its input.
+fn workload(n: usize) -> (Vec<u8>, Vec<Range<usize>>) {
+    let mut vocab: Vec<Vec<u8>> = COMMON.iter().map(|s| s.as_bytes().to_vec()).collect();
+    vocab.extend(
+        (0..2000).map(|i| format!("field-{}-{}", ["name", "ref", "Value"][i % 3], i).into_bytes()),
+    );
+    let weights: Vec<f64> = (1..=vocab.len()).map(|rank| 1.0 / rank as f64).collect();
+    let dist = WeightedIndex::new(weights).unwrap();
+    let mut rng = StdRng::seed_from_u64(0x786d6c);
+    let mut text = Vec::new();
+    let mut spans = Vec::with_capacity(n);
+    for _ in 0..n {
+        let name = &vocab[dist.sample(&mut rng)];
+        spans.push(text.len()..text.len() + name.len());
+        text.extend_from_slice(name);
+        text.push(b' ');
+    }
+    (text, spans)
+}
+
+fn bench_intern(c: &mut Criterion) {
+    let (text, spans) = workload(100_000);
+    let names: Vec<&[u8]> = spans.iter().map(|span| &text[span.clone()]).collect();
+    let mut group = c.benchmark_group("dict_intern");
+    group.throughput(Throughput::Elements(names.len() as u64));
+
+    group.bench_function(BenchmarkId::new("rust_dict", "core"), |b| {
+        b.iter(|| {
+            let mut dict = Dict::new(0x1234_5678);
+            for &name in &names {
+                black_box(dict.lookup(Key::Name(name), None, true));
+            }
+            dict
+        })
+    });
+
+    group.bench_function(BenchmarkId::new("std_hashmap", "siphash"), |b| {
+        b.iter(|| {
+            let mut map: HashMap<Box<[u8]>, ()> = HashMap::new();
+            for &name in &names {
+                let key = match map.get_key_value(name) {
+                    Some((key, _)) => key.as_ptr(),
+                    None => {
+                        let key: Box<[u8]> = name.into();
+                        let ptr = key.as_ptr();
+                        map.insert(key, ());
+                        ptr
+                    }
+                };
+                black_box(key);
+            }
+            map
+        })
+    });
+
+    group.bench_function(BenchmarkId::new("rust_dict", "ffi"), |b| {
+        b.iter(|| unsafe {
+            let dict = libxml2::xmlDictCreate();
+            for &name in &names {
+                black_box(libxml2::xmlDictLookup(
+                    dict,
+                    name.as_ptr(),
+                    name.len() as c_int,
+                ));
+            }
+            libxml2::xmlDictFree(dict);
+        })
+    });
+
+    let c_lib = libxml2::libxml2_dynload::get_c_baseline();
+    group.bench_function(BenchmarkId::new("c_dict", "goodoaat"), |b| {
+        b.iter(|| unsafe {
+            let dict = c_lib.xmlDictCreate();
+            for &name in &names {
+                black_box(c_lib.xmlDictLookup(dict, name.as_ptr(), name.len() as c_int));
+            }
+            c_lib.xmlDictFree(dict);
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_intern);
+criterion_main!(benches);
@@ -1,16 +1,35 @@
 use std::env;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process::Command;
 use std::time::Instant;
 
+// The configure options, in their own file for tests/build_options_test.rs
+#[path = "build/options.rs"]
+mod options;
+
+// The C symbols Rust modules export, in their own file for
+// tests/build_exports_test.rs
+#[path = "build/exports.rs"]
+mod exports;
+
+// Relative to the libxml2 source tree, see `source_dir()`
 const INCLUDE_DIRS: &[&str] = &[
-    "..",                   // For config.h and libxml.h (first so they're found first)
-    "../include",           // Main libxml2 headers
-    "../include/libxml",    // Public headers  
+    ".",                    // For config.h and libxml.h (first so they're found first)
+    "include",              // Main libxml2 headers
+    "include/libxml",       // Public headers  
+];
+
+// Files that must exist for a directory to count as a libxml2 source tree
+const SOURCE_MARKERS: &[&str] = &[
+    "configure.ac",
+    "libxml.h",
+    "include/libxml/xmlversion.h.in",
+    "include/libxml/tree.h",
 ];
 
-// Mapping of module names to their C files
+// Mapping of module names to their C files. A C file stays in the hybrid
+// build while its module is only partly ported, see `build_hybrid_library`
 const MODULE_FILES: &[(&str, &[&str])] = &[
     ("xmlstring", &["xmlstring.c"]),
     ("chvalid", &["chvalid.c"]),
@@ -43,11 +62,12 @@ const MODULE_FILES: &[(&str, &[&str])] = &[
     ("xmlreader", &["xmlreader.c"]),
     ("xmlwriter", &["xmlwriter.c"]),
     ("c14n", &["c14n.c"]),
+    ("catalog", &["catalog.c"]),
 ];
 
 // Additional C files not covered by the main modules
 const ADDITIONAL_C_FILES: &[&str] = &[
-    "catalog.c", "debugXML.c", "globals.c", "nanohttp.c", 
+    "debugXML.c", "globals.c", "nanohttp.c", 
     "xinclude.c", "xlink.c", "xmlmodule.c", "xmlschemastypes.c", "xzlib.c"
 ];
 
@@ -65,16 +85,321 @@ const TEST_BINARIES: &[&str] = &[
     "testrecurse",
 ];
 
+/// C compiler driver used for object files, test binaries and the shared library
+struct Toolchain {
+    program: String,
+    args: Vec<String>,
+    is_clang: bool,
+    /// `-fsanitize=` value from LIBXML2_SANITIZE
+    sanitize: Option<String>,
+    /// Compiler cache object files are compiled through, see `compiler_launcher`
+    launcher: Option<String>,
+}
+
+impl Toolchain {
+    fn command(&self) -> Command {
+        let mut cmd = Command::new(&self.program);
+        cmd.args(&self.args);
+        cmd
+    }
+    
+    /// Command for compiling one C source to an object file, through the
+    /// compiler cache if there is one
+    fn compile_command(&self) -> Command {
+        match &self.launcher {
+            Some(launcher) => {
+                let mut cmd = Command::new(launcher);
+                cmd.arg(&self.program).args(&self.args);
+                cmd
+            }
+            None => self.command(),
+        }
+    }
+    
+    /// Extra flags for compiling C sources
+    fn compile_flags(&self) -> Vec<String> {
+        match &self.sanitize {
+            Some(kinds) => vec![format!("-fsanitize={}", kinds), "-fno-omit-frame-pointer".to_string()],
+            None => Vec::new(),
+        }
+    }
+    
+    /// Extra flags for linking the shared library and test binaries
+    fn link_flags(&self) -> Vec<String> {
+        match &self.sanitize {
+            Some(kinds) => vec![format!("-fsanitize={}", kinds)],
+            None => Vec::new(),
+        }
+    }
+    
+    /// Everything that changes the produced objects, to spot stale ones.
+    /// The launcher does not, so turning the cache on or off rebuilds nothing.
+    fn fingerprint(&self) -> String {
+        format!("{} {} sanitize={}", self.program, self.args.join(" "), 
+                self.sanitize.as_deref().unwrap_or(""))
+    }
+}
+
+static TOOLCHAIN: std::sync::OnceLock<Toolchain> = std::sync::OnceLock::new();
+static SOURCE_DIR: std::sync::OnceLock<PathBuf> = std::sync::OnceLock::new();
+
+fn toolchain() -> &'static Toolchain {
+    TOOLCHAIN.get().expect("toolchain is detected at the start of main")
+}
+
+fn source_dir() -> &'static Path {
+    SOURCE_DIR.get().expect("source tree is resolved at the start of main")
+}
+
+/// Path of `file` inside the libxml2 source tree
+fn src_path(file: &str) -> String {
+    source_dir().join(file).to_string_lossy().into_owned()
+}
+
+/// `-I` flags for every entry of `INCLUDE_DIRS`
+fn include_args() -> Vec<String> {
+    INCLUDE_DIRS.iter().map(|dir| format!("-I{}", src_path(dir))).collect()
+}
+
+/// The libxml2 source tree: `LIBXML2_SRC` if set, else the parent of this crate
+fn resolve_source_dir() -> Result<PathBuf, String> {
+    println!("cargo:rerun-if-env-changed=LIBXML2_SRC");
+    
+    let dir = match env::var_os("LIBXML2_SRC").filter(|dir| !dir.is_empty()) {
+        // Build scripts run in the crate directory, so pin relative paths down
+        Some(dir) => fs::canonicalize(&dir)
+            .map_err(|e| format!("LIBXML2_SRC={} is not accessible: {}", Path::new(&dir).display(), e))?,
+        None => PathBuf::from(".."),
+    };
+    
+    let missing: Vec<&str> = SOURCE_MARKERS.iter()
+        .copied()
+        .filter(|file| !dir.join(file).is_file())
+        .collect();
+    if !missing.is_empty() {
+        return Err(format!(
+            "{} does not look like a libxml2 source tree (missing {}); \
+             set LIBXML2_SRC to the directory containing configure.ac",
+            dir.display(), missing.join(", ")));
+    }
+    Ok(dir)
+}
+
+fn detect_toolchain() -> Result<Toolchain, Box<dyn std::error::Error>> {
+    println!("cargo:rerun-if-env-changed=CC");
+    println!("cargo:rerun-if-env-changed=LIBXML2_USE_CLANG");
+    println!("cargo:rerun-if-env-changed=LIBXML2_SANITIZE");
+    
+    // CC wins, then the clang toggle, then the system default
+    let use_clang = env::var("LIBXML2_USE_CLANG")
+        .map(|v| matches!(v.to_ascii_lowercase().as_str(), "1" | "true" | "yes" | "on"))
+        .unwrap_or(false);
+    let cc = env::var("CC").ok().filter(|cc| !cc.trim().is_empty())
+        .unwrap_or_else(|| if use_clang { "clang" } else { "cc" }.to_string());
+    
+    // CC may carry a wrapper or flags, e.g. "ccache clang"
+    let mut words = cc.split_whitespace().map(str::to_string);
+    let program = words.next().unwrap();
+    let args: Vec<String> = words.collect();
+    
+    let output = Command::new(&program).args(&args).arg("--version").output()
+        .map_err(|e| format!("C compiler `{}` could not be run: {}; set CC to a working compiler", cc, e))?;
+    if !output.status.success() {
+        return Err(format!("C compiler `{}` failed to report its version: {}", 
+                           cc, String::from_utf8_lossy(&output.stderr)).into());
+    }
+    let is_clang = String::from_utf8_lossy(&output.stdout).contains("clang");
+    if use_clang && !is_clang {
+        println!("cargo:warning=LIBXML2_USE_CLANG is set but `{}` is not clang", cc);
+    }
+    
+    let launcher = compiler_launcher(&program, &args);
+    Ok(Toolchain { program, args, is_clang, sanitize: sanitizer()?, launcher })
+}
+
+/// Find the compiler cache to compile object files through, per
+/// LIBXML2_CCACHE: unset or `auto` uses `ccache` if it is on the PATH,
+/// `1`/`on` does too, `0`/`off` never does, and any other value is the
+/// cache program to use, such as `sccache`.
+///
+/// A rebuild after `cargo clean`, in another target directory or after
+/// switching between RUST_MODULES sets recompiles the ~40 C files of the
+/// library, and each is then a cache hit, a copy instead of a compile,
+/// which leaves the C side of such a build bound by linking. Nothing
+/// changes if CC already names a cache, as in CC="ccache cc", or if no
+/// cache is installed.
+fn compiler_launcher(program: &str, args: &[String]) -> Option<String> {
+    println!("cargo:rerun-if-env-changed=LIBXML2_CCACHE");
+    
+    let setting = env::var("LIBXML2_CCACHE").unwrap_or_default();
+    let setting = setting.trim();
+    let launcher = match setting.to_ascii_lowercase().as_str() {
+        "0" | "off" | "false" | "no" => return None,
+        "" | "auto" | "1" | "on" | "true" | "yes" => "ccache".to_string(),
+        _ => setting.to_string(),
+    };
+    let explicit = !(setting.is_empty() || setting.eq_ignore_ascii_case("auto"));
+    
+    let is_cache = |word: &str| {
+        let name = std::path::Path::new(word).file_stem().and_then(|s| s.to_str()).unwrap_or("");
+        matches!(name, "ccache" | "sccache")
+    };
+    if is_cache(program) || args.iter().any(|arg| is_cache(arg)) {
+        return None;
+    }
+    
+    // A cache that cannot run is no cache, whether asked for or not
+    match Command::new(&launcher).arg("--version").output() {
+        Ok(output) if output.status.success() => {
+            println!("cargo:warning=Compiling C objects through {}", launcher);
+            Some(launcher)
+        }
+        _ => {
+            if explicit {
+                println!("cargo:warning=LIBXML2_CCACHE is set but `{}` could not be run", launcher);
+            }
+            None
+        }
+    }
+}
+
+/// Parse LIBXML2_SANITIZE, a comma-separated list of `address` and `undefined`.
+///
+/// The sanitizer is applied to every C object, the static libraries and the
+/// shared baseline library, so differential runs also catch UB on the C side.
+/// `cargo fuzz` builds the Rust code with `-Zsanitizer=address` by default,
+/// which links rustc's own ASan runtime; in that case no runtime is linked
+/// here, so use `LIBXML2_SANITIZE=address` together with the default
+/// `cargo fuzz` sanitizer, or `--sanitizer none` with an explicit runtime
+/// from this script. Mixing ASan and UBSan runtimes from different compilers
+/// is not supported.
+fn sanitizer() -> Result<Option<String>, Box<dyn std::error::Error>> {
+    let value = match env::var("LIBXML2_SANITIZE") {
+        Ok(value) if !value.trim().is_empty() => value,
+        _ => return Ok(None),
+    };
+    let kinds: Vec<&str> = value.split(',').map(str::trim).collect();
+    if let Some(bad) = kinds.iter().find(|kind| !matches!(**kind, "address" | "undefined")) {
+        return Err(format!("LIBXML2_SANITIZE: unsupported sanitizer `{}` (expected address or undefined)", bad).into());
+    }
+    Ok(Some(kinds.join(",")))
+}
+
+/// Link the sanitizer runtimes the C objects need, unless rustc already does
+fn link_sanitizer_runtime(toolchain: &Toolchain) {
+    let Some(kinds) = &toolchain.sanitize else { return };
+    
+    // -Zsanitizer=... makes rustc link its own runtime; a second copy breaks ASan
+    let rustflags = env::var("CARGO_ENCODED_RUSTFLAGS").unwrap_or_default();
+    if rustflags.contains("sanitizer=") {
+        println!("cargo:warning=rustc is sanitizing already, not linking a C sanitizer runtime");
+        return;
+    }
+    
+    let macos = target_os() == "macos";
+    let arch = env::var("CARGO_CFG_TARGET_ARCH").unwrap_or_default();
+    for kind in kinds.split(',') {
+        let lib = match (toolchain.is_clang, kind, macos) {
+            (false, "address", _) => "asan".to_string(),
+            (false, _, _) => "ubsan".to_string(),
+            (true, "address", true) => "clang_rt.asan_osx_dynamic".to_string(),
+            (true, _, true) => "clang_rt.ubsan_osx_dynamic".to_string(),
+            (true, "address", false) => format!("clang_rt.asan-{}", arch),
+            (true, _, false) => format!("clang_rt.ubsan_standalone-{}", arch),
+        };
+        
+        // Ask the compiler where its runtime lives
+        let dir_query = if toolchain.is_clang {
+            toolchain.command().arg("--print-runtime-dir").output()
+        } else {
+            toolchain.command().arg(format!("-print-file-name=lib{}.so", lib)).output()
+        };
+        if let Ok(output) = dir_query {
+            let path = PathBuf::from(String::from_utf8_lossy(&output.stdout).trim());
+            let dir = if toolchain.is_clang { Some(path.as_path()) } else { path.parent() };
+            if let Some(dir) = dir.filter(|dir| dir.is_absolute()) {
+                println!("cargo:rustc-link-search=native={}", dir.display());
+            }
+        }
+        println!("cargo:rustc-link-lib=dylib={}", lib);
+    }
+}
+
+// Build scripts run on the host, so cfg!(target_os) describes the host;
+// Cargo passes the real target in CARGO_CFG_* variables
+fn target_os() -> String {
+    env::var("CARGO_CFG_TARGET_OS").unwrap_or_default()
+}
+
+fn target_env() -> String {
+    env::var("CARGO_CFG_TARGET_ENV").unwrap_or_default()
+}
+
+/// File name of the dynamic baseline library, as libxml2_dynload expects it
+fn dynamic_lib_file_name() -> &'static str {
+    match target_os().as_str() {
+        "windows" => "liblibxml2_c.dll",
+        "macos" | "ios" => "liblibxml2_c.dylib",
+        _ => "liblibxml2_c.so",
+    }
+}
+
+/// System libraries libxml2 needs on the target
+fn system_libs() -> &'static [&'static str] {
+    match target_os().as_str() {
+        "linux" | "android" => &["m", "dl", "pthread"],
+        // iconv is not part of libSystem
+        "macos" | "ios" => &["m", "iconv"],
+        "windows" => &["ws2_32", "bcrypt"],
+        _ => &["m"],
+    }
+}
+
+/// Linker flags that turn every object of `static_lib` into a shared library
+fn shared_library_args(static_lib: &str) -> Vec<String> {
+    if target_os() == "macos" {
+        // ld64 has no --whole-archive
+        vec!["-dynamiclib".to_string(), format!("-Wl,-force_load,{}", static_lib)]
+    } else {
+        // GNU ld, gold, lld and MinGW's ld all understand this pair
+        vec![
+            "-shared".to_string(),
+            "-Wl,--whole-archive".to_string(),
+            static_lib.to_string(),
+            "-Wl,--no-whole-archive".to_string(),
+        ]
+    }
+}
+
 fn main() {
     let build_start = Instant::now();
     println!("cargo:warning=Starting libxml2 build process...");
     
     println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=build");
     println!("cargo:rerun-if-env-changed=RUST_MODULES");
     
+    // Pick the C compiler before anything is compiled
+    let toolchain = detect_toolchain().expect("No usable C compiler");
+    println!("cargo:warning=Using C compiler {} ({})", toolchain.program, 
+             if toolchain.is_clang { "clang" } else { "gcc-compatible" });
+    if let Some(kinds) = &toolchain.sanitize {
+        println!("cargo:warning=Building C code with -fsanitize={}", kinds);
+    }
+    TOOLCHAIN.set(toolchain).ok();
+    
+    // Everything below reads C sources and headers from this tree
+    let source_dir = resolve_source_dir().unwrap_or_else(|e| panic!("{}", e));
+    println!("cargo:warning=Using libxml2 sources from {}", source_dir.display());
+    // The test harness runs the C test binaries from the same tree
+    println!("cargo:rustc-env=LIBXML2_SRC_DIR={}", source_dir.canonicalize().unwrap_or_else(|_| source_dir.clone()).display());
+    SOURCE_DIR.set(source_dir).ok();
+    
     // Step 0: Ensure configure has been run and config files exist
     let step_start = Instant::now();
+    check_options(&get_rust_modules());
     ensure_configure_generated().expect("Failed to run configure");
+    export_sysconfdir().expect("Failed to read XML_SYSCONFDIR from config.h");
     println!("cargo:warning=Configure step completed in {:.2}s", step_start.elapsed().as_secs_f64());
     
     // Step 1: Determine which modules are implemented in Rust
@@ -83,6 +408,9 @@ fn main() {
     println!("cargo:warning=Module detection completed in {:.2}s - {} Rust modules", 
              step_start.elapsed().as_secs_f64(), rust_modules.len());
     
+    write_rust_modules(&rust_modules).expect("Failed to write rust_modules.rs");
+    generate_chvalid_ranges().expect("Failed to generate chvalid range tables");
+    
     // Step 2: Generate wrapper.h with only C module headers
     let step_start = Instant::now();
     generate_wrapper_header(&rust_modules).expect("Failed to generate wrapper.h");
@@ -146,11 +474,181 @@ fn get_rust_modules() -> Vec<String> {
     rust_modules
 }
 
+/// Write the resolved module list where `lib.rs` includes it as `RUST_MODULES`
+fn write_rust_modules(rust_modules: &[String]) -> Result<(), Box<dyn std::error::Error>> {
+    let path = PathBuf::from(env::var("OUT_DIR")?).join("rust_modules.rs");
+    let list: Vec<String> = rust_modules.iter().map(|module| format!("{:?}", module)).collect();
+    fs::write(path, format!("&[{}]\n", list.join(", ")))?;
+    Ok(())
+}
+
+fn feature_enabled(feature: &str) -> bool {
+    env::var_os(format!("CARGO_FEATURE_{}", feature.to_uppercase().replace('-', "_"))).is_some()
+}
+
+/// Reject option features configure would override, and Rust modules of
+/// parts that are turned off
+fn check_options(rust_modules: &[String]) {
+    if let Err(e) = options::check_options(rust_modules, &feature_enabled) {
+        panic!("{}", e);
+    }
+}
+
+fn configure_args() -> Vec<String> {
+    options::configure_args(&feature_enabled)
+}
+
+/// Arguments the source tree was last configured with, from config.status
+fn configured_args() -> Option<Vec<String>> {
+    let output = Command::new("sh")
+        .args(["config.status", "--config"])
+        .current_dir(source_dir())
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    // Printed shell-quoted: '--disable-shared' '--enable-static' ...
+    Some(String::from_utf8_lossy(&output.stdout)
+        .split_whitespace()
+        .map(|arg| arg.trim_matches('\'').to_string())
+        .collect())
+}
+
+fn c_file_enabled(file: &str) -> bool {
+    options::c_file_enabled(file, &feature_enabled)
+}
+
+// Fewest single-byte runs for which genRanges.py emits a 256-byte table
+const CHVALID_MIN_TABLE_SIZE: usize = 6;
+
+/// One `name` block of ranges.def
+struct CharClass {
+    name: String,
+    bytes: [bool; 256],
+    ranges: Vec<(u32, u32)>,
+}
+
+/// Parse a `ranges.def` value: `0x41`, `'a'` or decimal
+fn parse_range_value(text: &str) -> Option<u32> {
+    if let Some(hex) = text.strip_prefix("0x") {
+        u32::from_str_radix(hex, 16).ok()
+    } else if let Some(quoted) = text.strip_prefix('\'') {
+        quoted.chars().next().map(|c| c as u32)
+    } else {
+        text.parse().ok()
+    }
+}
+
+/// `xmlIsBaseChar` -> `XML_IS_BASE_CHAR`
+fn screaming_snake(name: &str) -> String {
+    let mut out = String::new();
+    for (i, c) in name.chars().enumerate() {
+        if c.is_ascii_uppercase() && i > 0 {
+            out.push('_');
+        }
+        out.push(c.to_ascii_uppercase());
+    }
+    out
+}
+
+/// Generate the chvalid range tables from codegen/ranges.def, following
+/// genRanges.py, which produces the C tables in codegen/ranges.inc from the
+/// same file: values below 0x100 go into a lookup table, wider ranges into
+/// a range group split at 0x10000.
+fn generate_chvalid_ranges() -> Result<(), Box<dyn std::error::Error>> {
+    let def_path = src_path("codegen/ranges.def");
+    println!("cargo:rerun-if-changed={}", def_path);
+    let def = fs::read_to_string(&def_path)?;
+    
+    let mut classes: Vec<CharClass> = Vec::new();
+    let mut in_block = false;
+    for line in def.lines() {
+        if line.starts_with('#') || line.trim().is_empty() {
+            continue;
+        }
+        let mut fields = line.split_whitespace();
+        match fields.next() {
+            Some("name") => {
+                let name = fields.next().ok_or("ranges.def: 'name' without a name")?;
+                classes.push(CharClass { name: name.to_string(), bytes: [false; 256], ranges: Vec::new() });
+                in_block = true;
+            }
+            Some("end") => in_block = false,
+            Some("ur") => {
+                let class = match classes.last_mut() {
+                    Some(class) if in_block => class,
+                    _ => return Err(format!("ranges.def: 'ur' outside a name block: {}", line).into()),
+                };
+                for field in fields {
+                    let (low, high) = field.split_once("..").unwrap_or((field, field));
+                    let (low, high) = match (parse_range_value(low), parse_range_value(high)) {
+                        (Some(low), Some(high)) if low <= high => (low, high),
+                        _ => return Err(format!("ranges.def: invalid value {} for {}", field, class.name).into()),
+                    };
+                    if high < 0x100 {
+                        for ch in low..=high {
+                            class.bytes[ch as usize] = true;
+                        }
+                    } else {
+                        class.ranges.push((low, high));
+                    }
+                }
+            }
+            _ => return Err(format!("ranges.def: unexpected line: {}", line).into()),
+        }
+    }
+    classes.sort_by(|a, b| a.name.cmp(&b.name));
+    
+    let mut out = String::new();
+    for CharClass { name, bytes, .. } in &classes {
+        let runs = bytes.iter().enumerate()
+            .filter(|&(ch, &set)| set && (ch == 0 || !bytes[ch - 1]))
+            .count();
+        if runs < CHVALID_MIN_TABLE_SIZE {
+            continue;
+        }
+        out.push_str(&format!("pub const {}_TAB: [u8; 256] = [\n", screaming_snake(name)));
+        for row in bytes.chunks(12) {
+            let row: Vec<String> = row.iter().map(|&set| format!("0x{:02x}", set as u8)).collect();
+            out.push_str(&format!("    {},\n", row.join(", ")));
+        }
+        out.push_str("];\n\n");
+    }
+    for CharClass { name, ranges, .. } in &mut classes {
+        if ranges.is_empty() {
+            continue;
+        }
+        ranges.sort();
+        let name = screaming_snake(name);
+        let (short, long): (Vec<_>, Vec<_>) = ranges.iter().partition(|&&(_, high)| high < 0x10000);
+        let mut slices = Vec::new();
+        for (suffix, kind, ranges) in [("SRNG", "ChSRange", &short), ("LRNG", "ChLRange", &long)] {
+            if ranges.is_empty() {
+                slices.push("&[]".to_string());
+                continue;
+            }
+            out.push_str(&format!("static {}_{}: &[{}] = &[\n", name, suffix, kind));
+            for (low, high) in ranges {
+                out.push_str(&format!("    {} {{ low: {:#x}, high: {:#x} }},\n", kind, low, high));
+            }
+            out.push_str("];\n\n");
+            slices.push(format!("{}_{}", name, suffix));
+        }
+        out.push_str(&format!(
+            "pub const {}_GROUP: ChRangeGroup = ChRangeGroup {{\n    short_ranges: {},\n    long_ranges: {},\n}};\n\n",
+            name, slices[0], slices[1]));
+    }
+    
+    fs::write(PathBuf::from(env::var("OUT_DIR")?).join("chvalid_ranges.rs"), out)?;
+    Ok(())
+}
+
 fn ensure_configure_generated() -> Result<(), Box<dyn std::error::Error>> {
-    let config_h_path = "../config.h";
-    let xmlversion_h_path = "../include/libxml/xmlversion.h";
-    let configure_path = "../configure";
-    let configure_ac_path = "../configure.ac";
+    let config_h_path = &src_path("config.h");
+    let xmlversion_h_path = &src_path("include/libxml/xmlversion.h");
+    let configure_path = &src_path("configure");
+    let configure_ac_path = &src_path("configure.ac");
 
     // Check if both config.h and xmlversion.h exist
     let config_exists = std::path::Path::new(config_h_path).exists();
@@ -162,12 +660,19 @@ fn ensure_configure_generated() -> Result<(), Box<dyn std::error::Error>> {
         let xmlversion_time = std::fs::metadata(xmlversion_h_path)?.modified()?;
         
         // Check if configure.ac is newer than generated files
-        if let Ok(configure_ac_meta) = std::fs::metadata(configure_ac_path) {
+        let sources_changed = if let Ok(configure_ac_meta) = std::fs::metadata(configure_ac_path) {
             let configure_ac_time = configure_ac_meta.modified()?;
             configure_ac_time > config_time || configure_ac_time > xmlversion_time
         } else {
             false
+        };
+        
+        // Or if the feature selection no longer matches the last configure run
+        let options_changed = configured_args().map_or(true, |args| args != configure_args());
+        if options_changed {
+            println!("cargo:warning=Configure options changed to {}", configure_args().join(" "));
         }
+        sources_changed || options_changed
     } else {
         true
     };
@@ -180,16 +685,16 @@ fn ensure_configure_generated() -> Result<(), Box<dyn std::error::Error>> {
         }
         
         println!("cargo:rerun-if-changed={}", configure_path);
-        println!("cargo:rerun-if-changed=../configure.ac");
-        println!("cargo:rerun-if-changed=../config.h.in");
-        println!("cargo:rerun-if-changed=../include/libxml/xmlversion.h.in");
+        println!("cargo:rerun-if-changed={}", configure_ac_path);
+        println!("cargo:rerun-if-changed={}", src_path("config.h.in"));
+        println!("cargo:rerun-if-changed={}", src_path("include/libxml/xmlversion.h.in"));
 
         // Check if configure script exists, if not run autogen.sh
         if !std::path::Path::new(configure_path).exists() {
             println!("cargo:warning=Configure script not found, running autogen.sh");
             let autogen_start = Instant::now();
             let autogen_output = Command::new("./autogen.sh")
-                .current_dir("..")
+                .current_dir(source_dir())
                 .output()?;
                 
             if !autogen_output.status.success() {
@@ -203,12 +708,10 @@ fn ensure_configure_generated() -> Result<(), Box<dyn std::error::Error>> {
         println!("cargo:warning=Running configure to generate config files");
         let configure_start = Instant::now();
         
-        // Run configure from the parent directory
+        // Run configure from the source tree
         let output = Command::new("./configure")
-            .current_dir("..")
-            .arg("--disable-shared")
-            .arg("--enable-static")
-            .arg("--disable-dependency-tracking")
+            .current_dir(source_dir())
+            .args(configure_args())
             .output()?;
 
         if !output.status.success() {
@@ -233,6 +736,18 @@ fn ensure_configure_generated() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+/// Pass the `XML_SYSCONFDIR` of config.h, where the default catalogs live,
+/// to the Rust catalog module as `LIBXML2_SYSCONFDIR`
+fn export_sysconfdir() -> Result<(), Box<dyn std::error::Error>> {
+    let config_h = fs::read_to_string(src_path("config.h"))?;
+    let dir = config_h.lines()
+        .find_map(|line| line.strip_prefix("#define XML_SYSCONFDIR "))
+        .map(|value| value.trim().trim_matches('"'))
+        .ok_or("config.h does not define XML_SYSCONFDIR")?;
+    println!("cargo:rustc-env=LIBXML2_SYSCONFDIR={}", dir);
+    Ok(())
+}
+
 fn generate_wrapper_header(_rust_modules: &[String]) -> Result<(), Box<dyn std::error::Error>> {
     let wrapper_path = "wrapper.h";
     
@@ -292,7 +807,7 @@ fn generate_wrapper_header(_rust_modules: &[String]) -> Result<(), Box<dyn std::
         
         // Check if any header file is newer than wrapper.h
         public_headers.iter().any(|header| {
-            let header_path = format!("../include/{}", header);
+            let header_path = src_path(&format!("include/{}", header));
             if let Ok(header_meta) = std::fs::metadata(&header_path) {
                 if let Ok(header_time) = header_meta.modified() {
                     header_time > wrapper_time
@@ -321,7 +836,7 @@ fn generate_wrapper_header(_rust_modules: &[String]) -> Result<(), Box<dyn std::
     
     // Add rerun triggers for all headers
     for header in &public_headers {
-        let header_path = format!("../include/{}", header);
+        let header_path = src_path(&format!("include/{}", header));
         println!("cargo:rerun-if-changed={}", header_path);
     }
     println!("cargo:rerun-if-changed={}", wrapper_path);
@@ -342,22 +857,22 @@ fn build_pure_c_library() -> Result<(), Box<dyn std::error::Error>> {
     // Add all module files
     for (_, files) in MODULE_FILES {
         for file in *files {
-            if file.ends_with(".c") {
-                let full_path = format!("../{}", file);
+            if file.ends_with(".c") && c_file_enabled(file) {
+                let full_path = src_path(file);
                 if std::path::Path::new(&full_path).exists() {
                     println!("cargo:rerun-if-changed={}", full_path);
-                    c_files.push(full_path);
+                    c_files.push((full_path, Vec::new()));
                 }
             }
         }
     }
     
     // Add additional C files
-    for file in ADDITIONAL_C_FILES {
-        let full_path = format!("../{}", file);
+    for file in ADDITIONAL_C_FILES.iter().filter(|file| c_file_enabled(file)) {
+        let full_path = src_path(file);
         if std::path::Path::new(&full_path).exists() {
             println!("cargo:rerun-if-changed={}", full_path);
-            c_files.push(full_path);
+            c_files.push((full_path, Vec::new()));
         }
     }
     
@@ -365,74 +880,114 @@ fn build_pure_c_library() -> Result<(), Box<dyn std::error::Error>> {
 }
 
 fn build_hybrid_library(rust_modules: &[String]) -> Result<(), Box<dyn std::error::Error>> {
-    // Build C sources EXCLUDING Rust-replaced modules
+    // Build ALL C sources, with the definitions Rust modules replace renamed
     // Output: libxml2_hybrid.a (C + Rust FFI symbols)
     // Purpose: Integration testing with C test suite
-    let out_dir = env::var("OUT_DIR")?;
-    let out_path = PathBuf::from(&out_dir);
-    
-    // Create mapping of C files to modules for exclusion
-    let mut excluded_files = std::collections::HashSet::new();
-    for module in rust_modules {
-        if let Some((_, files)) = MODULE_FILES.iter().find(|(name, _)| *name == module) {
-            for file in *files {
-                excluded_files.insert(*file);
-            }
-        }
-    }
-    
-    // Collect C files to compile (excluding Rust modules)
+    //
+    // The renamed symbols are the ones `build/exports.rs` declares.
     let mut c_files = Vec::new();
+    let mut renamed_files = Vec::new();
     
-    // Add module files that aren't excluded
-    for (_, files) in MODULE_FILES {
+    for (module, files) in MODULE_FILES {
+        let renames = if rust_modules.iter().any(|name| name == module) {
+            exports::rename_defines(module)
+        } else {
+            Vec::new()
+        };
         for file in *files {
-            if !excluded_files.contains(file) && file.ends_with(".c") {
-                let full_path = format!("../{}", file);
+            if file.ends_with(".c") && c_file_enabled(file) {
+                let full_path = src_path(file);
                 if std::path::Path::new(&full_path).exists() {
                     println!("cargo:rerun-if-changed={}", full_path);
-                    c_files.push(full_path);
+                    if !renames.is_empty() {
+                        renamed_files.push((*module, full_path.clone()));
+                    }
+                    c_files.push((full_path, renames.clone()));
                 }
             }
         }
     }
     
     // Add additional C files
-    for file in ADDITIONAL_C_FILES {
-        let full_path = format!("../{}", file);
+    for file in ADDITIONAL_C_FILES.iter().filter(|file| c_file_enabled(file)) {
+        let full_path = src_path(file);
         if std::path::Path::new(&full_path).exists() {
             println!("cargo:rerun-if-changed={}", full_path);
-            c_files.push(full_path);
+            c_files.push((full_path, Vec::new()));
         }
     }
     
-    compile_c_library(&c_files, "libxml2_hybrid")
+    compile_c_library(&c_files, "libxml2_hybrid")?;
+    check_renamed_definitions(&renamed_files, "libxml2_hybrid")
 }
 
-fn compile_c_library(c_files: &[String], lib_name: &str) -> Result<(), Box<dyn std::error::Error>> {
+/// Fail unless every Rust export of a module has a C definition that got
+/// renamed, in one of `renamed_files`, its module's C files. Without one,
+/// the entry in `build/exports.rs` is misspelt or names a function of
+/// another C file, whose definition would then clash with the Rust one.
+fn check_renamed_definitions(renamed_files: &[(&str, String)], lib_name: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let obj_dir = PathBuf::from(env::var("OUT_DIR")?).join(lib_name);
+    let mut modules: Vec<&str> = renamed_files.iter().map(|(module, _)| *module).collect();
+    modules.dedup();
+    
+    let mut problems = Vec::new();
+    for module in modules {
+        let mut nm_output = String::new();
+        for (_, c_file) in renamed_files.iter().filter(|(name, _)| *name == module) {
+            let obj_path = object_path(&obj_dir, c_file);
+            let output = Command::new("nm").args(["-g", "-P"]).arg(&obj_path).output()
+                .map_err(|e| format!("Failed to run nm on {}: {}", obj_path.display(), e))?;
+            if !output.status.success() {
+                return Err(format!("nm failed on {}:\n{}", obj_path.display(), String::from_utf8_lossy(&output.stderr)).into());
+            }
+            nm_output.push_str(&String::from_utf8_lossy(&output.stdout));
+        }
+        let missing = exports::missing_c_definitions(module, &exports::defined_symbols(&nm_output));
+        if !missing.is_empty() {
+            problems.push(format!("{}: {}", module, missing.join(", ")));
+        }
+    }
+    if !problems.is_empty() {
+        return Err(format!(
+            "Rust exports in build/exports.rs without a C definition in their module's C files:\n{}",
+            problems.join("\n")).into());
+    }
+    Ok(())
+}
+
+/// Where `compile_c_library` puts the object of `c_file`
+fn object_path(obj_dir: &Path, c_file: &str) -> PathBuf {
+    obj_dir.join(format!("{}.o", Path::new(c_file).file_stem().unwrap().to_str().unwrap()))
+}
+
+fn compile_c_library(c_files: &[(String, Vec<String>)], lib_name: &str) -> Result<(), Box<dyn std::error::Error>> {
     let out_dir = env::var("OUT_DIR")?;
     let out_path = PathBuf::from(&out_dir);
     
     println!("cargo:warning=Building {} with {} C files", lib_name, c_files.len());
     
-    // Build each C file to object file individually
+    // Each library keeps its own objects, as the hybrid build compiles some
+    // files with renamed symbols
+    let obj_dir = out_path.join(lib_name);
+    fs::create_dir_all(&obj_dir)?;
+    
+    // Objects built with another compiler, sanitizer, configuration or set
+    // of renamed symbols are all stale
+    let stamp_path = obj_dir.join("cflags.stamp");
+    let renames: Vec<&str> = c_files.iter().flat_map(|(_, defines)| defines.iter().map(String::as_str)).collect();
+    let fingerprint = format!("{} {} {}", toolchain().fingerprint(), configure_args().join(" "), renames.join(" "));
+    let flags_changed = fs::read_to_string(&stamp_path).map_or(true, |old| old != fingerprint);
+    
+    // Work out which object files are stale before compiling anything
     let mut object_files = Vec::new();
-    let mut compiled_count = 0;
-    let mut skipped_count = 0;
+    let mut stale = Vec::new();
     
-    for c_file in c_files {
-        let obj_name = format!("{}.o", 
-            std::path::Path::new(c_file)
-                .file_stem()
-                .unwrap()
-                .to_str()
-                .unwrap()
-        );
-        let obj_path = out_path.join(&obj_name);
+    for (c_file, defines) in c_files {
+        let obj_path = object_path(&obj_dir, c_file);
         
         // Check if we need to rebuild this object file
         let c_path = std::path::Path::new(c_file);
-        let should_rebuild = if obj_path.exists() {
+        let should_rebuild = if obj_path.exists() && !flags_changed {
             let c_time = c_path.metadata()?.modified()?;
             let obj_time = obj_path.metadata()?.modified()?;
             c_time > obj_time
@@ -441,52 +996,54 @@ fn compile_c_library(c_files: &[String], lib_name: &str) -> Result<(), Box<dyn s
         };
         
         if should_rebuild {
-            let compile_start = Instant::now();
-            
-            // Compile to object file using direct cc command
-            let mut cc_cmd = std::process::Command::new("cc");
-            cc_cmd.args(&[
-                "-c",
-                "-fPIC",  // Position-independent code for dynamic linking
-                "-o", obj_path.to_str().unwrap(),
-                c_file,
-                "-I", "..",
-                "-I", "../include", 
-                "-I", "../include/libxml",
-                "-I", ".",
-                "-DHAVE_CONFIG_H",
-                "-DLIBXML_STATIC",
-                "-D_GNU_SOURCE",
-                "-D_DEFAULT_SOURCE",
-                "-Wno-unused-function",
-                "-Wno-implicit-function-declaration",
-                "-Wno-error=implicit-function-declaration",
-                "-Wno-format-extra-args",
-            ]);
-            
-            let cc_status = cc_cmd.status()?;
-            if !cc_status.success() {
-                return Err(format!("Failed to compile {} to object file", c_file).into());
-            }
-            
-            compiled_count += 1;
-            println!("cargo:warning=Compiled {} in {:.3}s", 
-                     std::path::Path::new(c_file).file_name().unwrap().to_str().unwrap(),
-                     compile_start.elapsed().as_secs_f64());
-        } else {
-            skipped_count += 1;
+            stale.push((c_file.as_str(), defines.as_slice(), obj_path.clone()));
         }
-        
         object_files.push(obj_path);
     }
     
+    let compiled_count = stale.len();
+    let skipped_count = c_files.len() - stale.len();
+    
+    // Compile the stale files on a pool of NUM_JOBS workers
+    let jobs = env::var("NUM_JOBS")
+        .ok()
+        .and_then(|n| n.parse::<usize>().ok())
+        .filter(|&n| n > 0)
+        .unwrap_or_else(|| std::thread::available_parallelism().map(|n| n.get()).unwrap_or(1))
+        .min(stale.len().max(1));
+    let next = std::sync::atomic::AtomicUsize::new(0);
+    let errors = std::sync::Mutex::new(Vec::new());
+    
+    std::thread::scope(|scope| {
+        for _ in 0..jobs {
+            scope.spawn(|| loop {
+                let index = next.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+                let Some((c_file, defines, obj_path)) = stale.get(index) else { break };
+                
+                let compile_start = Instant::now();
+                match compile_object(c_file, defines, obj_path) {
+                    Ok(()) => println!("cargo:warning=Compiled {} in {:.3}s", 
+                                       std::path::Path::new(c_file).file_name().unwrap().to_str().unwrap(),
+                                       compile_start.elapsed().as_secs_f64()),
+                    Err(e) => errors.lock().unwrap().push(e),
+                }
+            });
+        }
+    });
+    
+    let errors = errors.into_inner().unwrap();
+    if !errors.is_empty() {
+        return Err(errors.join("\n").into());
+    }
+    fs::write(&stamp_path, &fingerprint)?;
+    
     println!("cargo:warning=Compilation: {} built, {} up-to-date", compiled_count, skipped_count);
     
     // Create static library from object files
     let static_lib_path = out_path.join(&format!("lib{}.a", lib_name));
     
     // Check if static library needs rebuilding
-    let should_rebuild_static = if static_lib_path.exists() {
+    let should_rebuild_static = if static_lib_path.exists() && !flags_changed {
         let static_time = static_lib_path.metadata()?.modified()?;
         object_files.iter().any(|obj_path| {
             if let Ok(obj_meta) = obj_path.metadata() {
@@ -503,7 +1060,9 @@ fn compile_c_library(c_files: &[String], lib_name: &str) -> Result<(), Box<dyn s
         let ar_start = Instant::now();
         println!("cargo:warning=Creating static library {}", lib_name);
         
-        // Use ar to create the static library
+        // Use ar to create the static library; start from scratch, as `ar rcs`
+        // keeps members of files that are no longer built
+        let _ = fs::remove_file(&static_lib_path);
         let mut ar_cmd = std::process::Command::new("ar");
         ar_cmd.arg("rcs").arg(&static_lib_path);
         
@@ -522,9 +1081,13 @@ fn compile_c_library(c_files: &[String], lib_name: &str) -> Result<(), Box<dyn s
         println!("cargo:warning=Static library {} up to date", lib_name);
     }
     
-    // Also create dynamic library for differential testing
-    if lib_name == "libxml2_c" {
-        let dynamic_lib_path = out_path.join("liblibxml2_c.so");
+    // Also create dynamic library for differential testing. The link below
+    // drives a gcc-style compiler, which MSVC targets don't have; without the
+    // library the differential tests report that the baseline is missing.
+    if lib_name == "libxml2_c" && target_env() == "msvc" {
+        println!("cargo:warning=Skipping dynamic baseline library: not supported for MSVC targets");
+    } else if lib_name == "libxml2_c" {
+        let dynamic_lib_path = out_path.join(dynamic_lib_file_name());
         
         let should_rebuild_dynamic = if dynamic_lib_path.exists() {
             let dynamic_time = dynamic_lib_path.metadata()?.modified()?;
@@ -535,27 +1098,27 @@ fn compile_c_library(c_files: &[String], lib_name: &str) -> Result<(), Box<dyn s
         };
         
         if should_rebuild_dynamic {
-            let gcc_start = Instant::now();
+            let link_start = Instant::now();
             println!("cargo:warning=Creating dynamic library for differential testing");
             
-            let mut gcc_cmd = std::process::Command::new("gcc");
-            gcc_cmd.args(&[
-                "-shared",
+            let mut link_cmd = toolchain().command();
+            link_cmd.args(&[
                 "-fPIC",
                 "-o", dynamic_lib_path.to_str().unwrap(),
-                "-Wl,--whole-archive",
-                static_lib_path.to_str().unwrap(),
-                "-Wl,--no-whole-archive",
-                "-lm", "-ldl", "-lpthread"
             ]);
+            link_cmd.args(shared_library_args(static_lib_path.to_str().unwrap()));
+            link_cmd.args(toolchain().link_flags());
+            link_cmd.args(system_libs().iter().map(|lib| format!("-l{}", lib)));
             
-            let gcc_status = gcc_cmd.status()?;
-            if !gcc_status.success() {
-                return Err("Failed to create dynamic library with gcc".into());
+            // Report the linker's complaint instead of a bare failure
+            let output = link_cmd.output()?;
+            if !output.status.success() || !dynamic_lib_path.exists() {
+                return Err(format!("Failed to create dynamic library with {}:\n{}", 
+                                   toolchain().program, String::from_utf8_lossy(&output.stderr)).into());
             }
             
             println!("cargo:warning=Dynamic library created in {:.3}s at {}", 
-                     gcc_start.elapsed().as_secs_f64(), dynamic_lib_path.display());
+                     link_start.elapsed().as_secs_f64(), dynamic_lib_path.display());
         } else {
             println!("cargo:warning=Dynamic library up to date");
         }
@@ -564,6 +1127,40 @@ fn compile_c_library(c_files: &[String], lib_name: &str) -> Result<(), Box<dyn s
     Ok(())
 }
 
+fn compile_object(c_file: &str, defines: &[String], obj_path: &std::path::Path) -> Result<(), String> {
+    // Compile to object file using the detected compiler
+    let mut cc_cmd = toolchain().compile_command();
+    cc_cmd.args(&[
+        "-c",
+        "-fPIC",  // Position-independent code for dynamic linking
+        "-o", obj_path.to_str().unwrap(),
+        c_file,
+    ]);
+    cc_cmd.args(include_args());
+    cc_cmd.args(&[
+        "-I", ".",
+        "-DHAVE_CONFIG_H",
+        "-DLIBXML_STATIC",
+        "-D_GNU_SOURCE",
+        "-D_DEFAULT_SOURCE",
+        "-Wno-unused-function",
+        "-Wno-implicit-function-declaration",
+        "-Wno-error=implicit-function-declaration",
+        "-Wno-format-extra-args",
+    ]);
+    cc_cmd.args(defines);
+    cc_cmd.args(toolchain().compile_flags());
+    
+    // Capture the output so parallel compiles don't interleave diagnostics
+    let output = cc_cmd.output()
+        .map_err(|e| format!("Failed to run {} for {}: {}", toolchain().program, c_file, e))?;
+    if !output.status.success() {
+        return Err(format!("Failed to compile {} to object file:\n{}", 
+                           c_file, String::from_utf8_lossy(&output.stderr)));
+    }
+    Ok(())
+}
+
 fn configure_build_defines(build: &mut cc::Build) {
     // Basic compilation flags
     build.flag("-Wno-unused-function");
@@ -586,21 +1183,76 @@ fn configure_build_defines(build: &mut cc::Build) {
     // Note: LIBXML_MODULE_EXTENSION is now defined in xmlversion.h, no need to duplicate
 }
 
+// Clang arguments shared by both bindgen runs; each adds its own LIBXML_* define
+fn bindgen_clang_args() -> Vec<String> {
+    let mut args = include_args();
+    args.push("-DHAVE_CONFIG_H".to_string());
+    args
+}
+const BINDGEN_VARIANT_DEFINES: &[&str] = &["-DLIBXML_STATIC", "-DLIBXML_DYNAMIC"];
+
+/// 64-bit FNV-1a, stable across Rust versions unlike DefaultHasher
+fn fnv1a(hash: u64, bytes: &[u8]) -> u64 {
+    bytes.iter().fold(hash, |h, &b| (h ^ b as u64).wrapping_mul(0x100000001b3))
+}
+
+/// Hash of everything bindgen sees: the preprocessed wrapper.h for both
+/// variants, the clang arguments and this script's builder settings.
+/// Also returns the headers wrapper.h pulled in.
+fn bindgen_input_hash() -> Result<(String, Vec<String>), Box<dyn std::error::Error>> {
+    let mut hash = fnv1a(0xcbf29ce484222325, include_str!("build.rs").as_bytes());
+    let mut headers = std::collections::BTreeSet::new();
+    
+    for define in BINDGEN_VARIANT_DEFINES {
+        // -dD keeps #defines, which bindgen turns into constants
+        let output = toolchain().command()
+            .args(&["-E", "-dD"])
+            .args(bindgen_clang_args())
+            .arg(define)
+            .arg("wrapper.h")
+            .output()?;
+        if !output.status.success() {
+            return Err(format!("Failed to preprocess wrapper.h: {}", String::from_utf8_lossy(&output.stderr)).into());
+        }
+        hash = fnv1a(hash, bindgen_clang_args().join(" ").as_bytes());
+        hash = fnv1a(hash, define.as_bytes());
+        hash = fnv1a(hash, &output.stdout);
+        
+        // Line markers look like `# 12 "../include/libxml/tree.h" 2`
+        for line in String::from_utf8_lossy(&output.stdout).lines() {
+            if let Some(rest) = line.strip_prefix("# ") {
+                if let Some(file) = rest.split('"').nth(1) {
+                    if std::path::Path::new(file).exists() {
+                        headers.insert(file.to_string());
+                    }
+                }
+            }
+        }
+    }
+    
+    Ok((format!("{:016x}", hash), headers.into_iter().collect()))
+}
+
 fn generate_bindings_with_dynamic_support() -> Result<(), Box<dyn std::error::Error>> {
     let out_path = PathBuf::from(env::var("OUT_DIR")?);
     let static_bindings_path = out_path.join("static_bindings.rs");
     let dynamic_bindings_path = out_path.join("dynamic_bindings.rs");
-    let wrapper_path = std::path::Path::new("wrapper.h");
-    
-    // Check if bindings need regeneration based on wrapper.h timestamp
-    let needs_regeneration = if static_bindings_path.exists() && dynamic_bindings_path.exists() {
-        let wrapper_time = wrapper_path.metadata()?.modified()?;
-        let static_time = static_bindings_path.metadata()?.modified()?;
-        let dynamic_time = dynamic_bindings_path.metadata()?.modified()?;
-        wrapper_time > static_time || wrapper_time > dynamic_time
-    } else {
-        true
-    };
+    let hash_path = out_path.join(".bindgen-hash");
+    
+    // Regenerate only when the header content or arguments changed, not
+    // when a checkout merely touched timestamps
+    let (input_hash, headers) = bindgen_input_hash()?;
+    let needs_regeneration = !static_bindings_path.exists()
+        || !dynamic_bindings_path.exists()
+        || fs::read_to_string(&hash_path).map_or(true, |stored| stored.trim() != input_hash);
+    
+    // bindgen's CargoCallbacks only reports headers when it runs; keep the
+    // same triggers when it is skipped
+    if !needs_regeneration {
+        for header in &headers {
+            println!("cargo:rerun-if-changed={}", header);
+        }
+    }
     
     if needs_regeneration {
         println!("cargo:warning=Generating Rust bindings");
@@ -611,18 +1263,18 @@ fn generate_bindings_with_dynamic_support() -> Result<(), Box<dyn std::error::Er
         let static_bindings = bindgen::Builder::default()
             .header("wrapper.h")
             .parse_callbacks(Box::new(bindgen::CargoCallbacks::default()))
-            .clang_arg("-I../include")
-            .clang_arg("-I../include/libxml")
-            .clang_arg("-I..")
-            .clang_arg("-DHAVE_CONFIG_H")
+            .clang_args(bindgen_clang_args())
             .clang_arg("-DLIBXML_STATIC")
             .allowlist_type(".*xml.*")
             .allowlist_type(".*HTML.*") 
             .allowlist_type(".*LIBXML_.*")
             .allowlist_function(".*xml.*")
             .allowlist_function(".*HTML.*")
+            .allowlist_function("html.*")
+            .allowlist_type("html.*")
             .allowlist_var(".*xml.*")
             .allowlist_var(".*LIBXML_.*")
+            .allowlist_var("html.*")
             .derive_default(true)
             .derive_debug(true)
             .size_t_is_usize(true)
@@ -636,18 +1288,18 @@ fn generate_bindings_with_dynamic_support() -> Result<(), Box<dyn std::error::Er
         let dynamic_bindings = bindgen::Builder::default()
             .header("wrapper.h")
             .parse_callbacks(Box::new(bindgen::CargoCallbacks::default()))
-            .clang_arg("-I../include")
-            .clang_arg("-I../include/libxml")
-            .clang_arg("-I..")
-            .clang_arg("-DHAVE_CONFIG_H")
+            .clang_args(bindgen_clang_args())
             .clang_arg("-DLIBXML_DYNAMIC")  // Different define for dynamic
             .allowlist_type(".*xml.*")
             .allowlist_type(".*HTML.*") 
             .allowlist_type(".*LIBXML_.*")
             .allowlist_function(".*xml.*")
             .allowlist_function(".*HTML.*")
+            .allowlist_function("html.*")
+            .allowlist_type("html.*")
             .allowlist_var(".*xml.*")
             .allowlist_var(".*LIBXML_.*")
+            .allowlist_var("html.*")
             .dynamic_library_name("libxml2_c")
             .derive_default(true)
             .derive_debug(true)
@@ -657,6 +1309,7 @@ fn generate_bindings_with_dynamic_support() -> Result<(), Box<dyn std::error::Er
         dynamic_bindings.write_to_file(&dynamic_bindings_path)?;
         println!("cargo:warning=Dynamic bindings generated in {:.3}s", dynamic_start.elapsed().as_secs_f64());
         
+        fs::write(&hash_path, &input_hash)?;
         println!("cargo:warning=Bindings generation completed in {:.3}s", bindgen_start.elapsed().as_secs_f64());
     } else {
         println!("cargo:warning=Bindings up to date, skipping generation");
@@ -673,7 +1326,7 @@ fn build_test_binaries(rust_modules: &[String]) -> Result<(), Box<dyn std::error
     println!("cargo:warning=Building test binaries");
     
     for test_name in TEST_BINARIES {
-        let test_c_file = format!("../{}.c", test_name);
+        let test_c_file = src_path(&format!("{}.c", test_name));
         
         // Check if the test C file exists
         if !std::path::Path::new(&test_c_file).exists() {
@@ -737,15 +1390,15 @@ fn build_single_test_binary(test_c_file: &str, binary_path: &PathBuf, test_name:
     if should_rebuild {
         let build_start = Instant::now();
         
-        // Use cc to compile the test binary, linking against specified library
-        let mut cmd = std::process::Command::new("cc");
+        // Compile the test binary, linking against specified library
+        let mut cmd = toolchain().command();
         cmd.args(&[
             "-o", binary_path.to_str().unwrap(),
             test_c_file,
-            "-include", "../libxml.h",  // Force include libxml.h first to get XML_HIDDEN
-            "-I", "..",
-            "-I", "../include", 
-            "-I", "../include/libxml",
+            "-include", &src_path("libxml.h"),  // Force include libxml.h first to get XML_HIDDEN
+        ]);
+        cmd.args(include_args());
+        cmd.args(&[
             "-I", ".",
             "-DHAVE_CONFIG_H",
             "-DLIBXML_STATIC",
@@ -758,6 +1411,8 @@ fn build_single_test_binary(test_c_file: &str, binary_path: &PathBuf, test_name:
             "-Wno-format-extra-args",
         ]);
         
+        cmd.args(toolchain().compile_flags());
+        
         // Add link arguments including our static library
         let link_args = get_test_link_args(test_name);
         
@@ -765,6 +1420,7 @@ fn build_single_test_binary(test_c_file: &str, binary_path: &PathBuf, test_name:
         cmd.arg("-L").arg(&out_dir);
         cmd.arg(&format!("-l{}", lib_name));
         cmd.args(&link_args);
+        cmd.args(toolchain().link_flags());
         
         let status = cmd.status()?;
             
@@ -780,22 +1436,15 @@ fn build_single_test_binary(test_c_file: &str, binary_path: &PathBuf, test_name:
     }
 }
 
-fn get_test_link_args(test_name: &str) -> Vec<&str> {
-    let mut args = vec![
-        "-lm",  // Math library
-    ];
-    
-    // Add platform-specific libraries
-    if cfg!(target_os = "linux") {
-        args.push("-ldl");
-        args.push("-lpthread");
-    }
+fn get_test_link_args(test_name: &str) -> Vec<String> {
+    // Platform-specific libraries
+    let mut args: Vec<String> = system_libs().iter().map(|lib| format!("-l{}", lib)).collect();
     
     // Special cases for specific tests
     match test_name {
         "runtest" => {
-            if cfg!(not(target_os = "windows")) {
-                args.push("-pthread");
+            if target_os() != "windows" {
+                args.push("-pthread".to_string());
             }
         },
         _ => {}
@@ -820,15 +1469,10 @@ fn setup_conditional_linking(rust_modules: &[String]) {
         println!("cargo:rustc-env=LIBXML2_VARIANT=hybrid");
     }
     
+    link_sanitizer_runtime(toolchain());
+    
     // Platform-specific system libraries
-    if cfg!(target_os = "windows") {
-        println!("cargo:rustc-link-lib=ws2_32");
-        println!("cargo:rustc-link-lib=bcrypt");
-    } else {
-        println!("cargo:rustc-link-lib=m");
-        if cfg!(target_os = "linux") {
-            println!("cargo:rustc-link-lib=dl");
-            println!("cargo:rustc-link-lib=pthread");
-        }
+    for lib in system_libs() {
+        println!("cargo:rustc-link-lib={}", lib);
     }
 }
\ No newline at end of file
@@ -0,0 +1,146 @@
+//! The C symbols Rust modules export, shared by `build.rs` and
+//! `tests/build_exports_test.rs`.
+//!
+//! Modules are ported a few functions at a time, so a module's C files stay
+//! in the hybrid build for everything the Rust module does not export yet.
+//! The C definitions of the symbols listed here get a `__c` suffix: the
+//! other C files link against the Rust ones, and only the callers inside the
+//! module's own C files keep using the C copy.
+
+// The `#[no_mangle]` items of each module's ffi.rs, in the same order.
+// Modules that are not listed export nothing and keep their C files whole.
+pub const RUST_EXPORTS: &[(&str, &[&str])] = &[
+    ("xmlstring", &[
+        "xmlStrlen", "xmlStrcmp", "xmlStrEqual", "xmlStrcasecmp", "xmlCheckUTF8", "xmlStrstr",
+        "xmlStrcasestr", "xmlStrsub", "xmlStrncat", "xmlStrncatNew", "xmlStrcat",
+    ]),
+    ("chvalid", &[
+        "xmlCharInRange", "xmlIsBaseChar", "xmlIsBlank", "xmlIsChar", "xmlIsCombining",
+        "xmlIsDigit", "xmlIsExtender", "xmlIsIdeographic", "xmlIsPubidChar", "xmlIsPubidChar_tab",
+        "xmlIsBaseCharGroup", "xmlIsCharGroup", "xmlIsCombiningGroup", "xmlIsDigitGroup",
+        "xmlIsExtenderGroup", "xmlIsIdeographicGroup",
+    ]),
+    ("dict", &[
+        "xmlInitializeDict", "xmlInitDictInternal", "xmlDictCleanup", "xmlCleanupDictInternal",
+        "xmlDictCreate", "xmlDictCreateSub", "xmlDictReference", "xmlDictFree", "xmlDictOwns",
+        "xmlDictSize", "xmlDictSetLimit", "xmlDictGetUsage", "xmlDictComputeHash",
+        "xmlDictCombineHash", "xmlDictLookup", "xmlDictLookupHashed", "xmlDictExists",
+        "xmlDictQLookup", "xmlInitRandom", "xmlCleanupRandom", "xmlGlobalRandom", "xmlRandom",
+    ]),
+    ("list", &[
+        "xmlListCreate", "xmlListDelete", "xmlListSearch", "xmlListReverseSearch", "xmlListInsert",
+        "xmlListAppend", "xmlListRemoveFirst", "xmlListRemoveLast", "xmlListRemoveAll",
+        "xmlListClear", "xmlListEmpty", "xmlListFront", "xmlListEnd", "xmlListSize",
+        "xmlListPopFront", "xmlListPopBack", "xmlListPushFront", "xmlListPushBack",
+        "xmlLinkGetData", "xmlListReverse", "xmlListSort", "xmlListWalk", "xmlListReverseWalk",
+        "xmlListMerge", "xmlListDup", "xmlListCopy",
+    ]),
+    ("buf", &[
+        "xmlBufCreate", "xmlBufCreateMem", "xmlBufDetach", "xmlBufFree", "xmlBufEmpty",
+        "xmlBufShrink", "xmlBufGrow", "xmlBufContent", "xmlBufEnd", "xmlBufAddLen", "xmlBufUse",
+        "xmlBufAvail", "xmlBufIsEmpty", "xmlBufAdd", "xmlBufCat", "xmlBufFromBuffer",
+        "xmlBufBackToBuffer", "xmlBufResetInput", "xmlBufUpdateInput",
+        "xmlSetBufferAllocationScheme", "xmlGetBufferAllocationScheme", "xmlBufferCreate",
+        "xmlBufferCreateSize", "xmlBufferDetach", "xmlBufferCreateStatic",
+        "xmlBufferSetAllocationScheme", "xmlBufferFree", "xmlBufferEmpty", "xmlBufferShrink",
+        "xmlBufferGrow", "xmlBufferDump", "xmlBufferContent", "xmlBufferLength",
+        "xmlBufferResize", "xmlBufferAdd", "xmlBufferAddHead", "xmlBufferCat", "xmlBufferCCat",
+        "xmlBufferWriteCHAR", "xmlBufferWriteChar", "xmlBufferWriteQuotedString",
+    ]),
+    ("xmlmemory", &[
+        "xmlMemMalloc", "xmlMemRealloc", "xmlMemFree", "xmlMemoryStrdup", "xmlMallocLoc",
+        "xmlMallocAtomicLoc", "xmlReallocLoc", "xmlMemStrdupLoc", "xmlMemSize", "xmlMemUsed",
+        "xmlMemBlocks", "xmlMemDisplayLast", "xmlMemDisplay", "xmlMemShow", "xmlMemoryDump",
+        "xmlInitMemory", "xmlInitMemoryInternal", "xmlCleanupMemory", "xmlCleanupMemoryInternal",
+        "xmlMemSetup", "xmlMemGet", "xmlGcMemSetup", "xmlGcMemGet",
+    ]),
+    ("threads", &[
+        "xmlInitMutex", "xmlNewMutex", "xmlCleanupMutex", "xmlFreeMutex", "xmlMutexLock",
+        "xmlMutexUnlock", "xmlInitRMutex", "xmlNewRMutex", "xmlCleanupRMutex", "xmlFreeRMutex",
+        "xmlRMutexLock", "xmlRMutexUnlock", "xmlLockLibrary", "xmlUnlockLibrary",
+        "xmlInitThreads", "xmlCleanupThreads", "xmlInitParser", "xmlCleanupParser",
+    ]),
+    ("encoding", &["xmlIsolat1ToUTF8", "xmlUTF8ToIsolat1"]),
+    ("uri", &[
+        "xmlCreateURI", "xmlParseURIReference", "xmlParseURISafe", "xmlParseURI",
+        "xmlParseURIRaw", "xmlSaveUri", "xmlFreeURI", "xmlURIUnescapeString", "xmlURIEscapeStr",
+        "xmlCanonicPath", "xmlPathToURI",
+    ]),
+    ("tree", &[
+        "xmlNodeSetContent", "xmlNodeSetContentLen", "xmlGetLineNo", "xmlNodeGetBaseSafe",
+        "xmlNodeGetBase", "xmlReconciliateNs",
+    ]),
+    ("xmlsave", &[
+        "xmlDocDumpFormatMemoryEnc", "xmlDocDumpMemory", "xmlDocDumpFormatMemory",
+        "xmlDocDumpMemoryEnc",
+    ]),
+    ("sax2", &[
+        "xmlSAX2ProcessingInstruction", "xmlSAX2Comment", "xmlSAX2CDataBlock",
+        "xmlSAX2InternalSubset", "xmlSAX2EntityDecl", "xmlSAX2AttributeDecl",
+        "xmlSAX2ElementDecl", "xmlSAX2NotationDecl",
+    ]),
+    ("xpath", &[
+        "xmlXPathErrMemory", "xmlXPathPErrMemory", "xmlXPathRegisterFunc",
+        "xmlXPathRegisterFuncNS", "xmlXPathRegisterFuncLookup", "xmlXPathRegisteredFuncsCleanup",
+        "xmlXPathValuePop", "xmlXPathValuePush",
+    ]),
+    ("htmltree", &[
+        "htmlParseContentType", "htmlGetMetaEncoding", "htmlSetMetaEncoding", "htmlIsBooleanAttr",
+        "htmlNodeDumpInternal", "htmlNodeDump", "htmlNodeDumpFileFormat", "htmlNodeDumpFile",
+        "htmlDocDumpMemoryFormat", "htmlDocDumpMemory", "htmlNodeDumpFormatOutput",
+        "htmlNodeDumpOutput", "htmlDocContentDumpFormatOutput", "htmlDocContentDumpOutput",
+        "htmlDocDump", "htmlSaveFile", "htmlSaveFileFormat", "htmlSaveFileEnc",
+    ]),
+    ("c14n", &["xmlC14NExecute", "xmlC14NDocSaveTo", "xmlC14NDocDumpMemory", "xmlC14NDocSave"]),
+    ("catalog", &[
+        "xmlInitCatalogInternal", "xmlCleanupCatalogInternal", "xmlInitializeCatalog",
+        "xmlLoadCatalog", "xmlLoadCatalogs", "xmlCatalogCleanup", "xmlParseCatalogFile",
+        "xmlCatalogResolve", "xmlCatalogResolveSystem", "xmlCatalogResolvePublic",
+        "xmlCatalogResolveURI", "xmlCatalogGetDefaults", "xmlCatalogSetDefaults",
+        "xmlCatalogSetDefaultPrefer", "xmlCatalogSetDebug", "xmlCatalogFreeLocal",
+        "xmlCatalogAddLocal", "xmlCatalogLocalResolve", "xmlCatalogLocalResolveURI",
+    ]),
+];
+
+/// The symbols `module` exports from Rust
+pub fn rust_exports(module: &str) -> &'static [&'static str] {
+    RUST_EXPORTS.iter()
+        .find(|(name, _)| *name == module)
+        .map_or(&[], |(_, symbols)| symbols)
+}
+
+/// The name the C definition of `symbol` gets in the hybrid build
+pub fn c_name(symbol: &str) -> String {
+    format!("{}__c", symbol)
+}
+
+/// Compiler flags renaming the C definitions of the Rust exports of `module`
+pub fn rename_defines(module: &str) -> Vec<String> {
+    rust_exports(module).iter().map(|symbol| format!("-D{}={}", symbol, c_name(symbol))).collect()
+}
+
+/// The global symbols `nm -g -P` lists as defined in an object
+pub fn defined_symbols(nm_output: &str) -> Vec<&str> {
+    nm_output.lines()
+        .filter_map(|line| {
+            let mut fields = line.split_whitespace();
+            let name = fields.next()?;
+            let kind = fields.next()?;
+            // Undefined, or weak without a default value
+            (!matches!(kind, "U" | "v" | "w")).then_some(name)
+        })
+        .collect()
+}
+
+/// The Rust exports of `module` whose renamed C definition is not among
+/// `defined`, the symbols of the module's C objects. Mach-O symbols carry
+/// a leading underscore.
+pub fn missing_c_definitions(module: &str, defined: &[&str]) -> Vec<&'static str> {
+    rust_exports(module).iter()
+        .copied()
+        .filter(|symbol| {
+            let name = c_name(symbol);
+            !defined.iter().any(|defined| *defined == name || defined.strip_prefix('_') == Some(name.as_str()))
+        })
+        .collect()
+}
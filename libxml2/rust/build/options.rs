@@ -0,0 +1,109 @@
+//! libxml2 configure options and the Cargo features selecting them, shared
+//! by `build.rs` and `tests/build_options_test.rs`. `features` tells
+//! whether a Cargo feature is enabled.
+
+// libxml2 configure options with their configure default and the C files
+// that are only built when the option is on. With no option features the
+// tree is configured with configure's defaults; a `without-X` Cargo feature
+// turns off an option that is on by default, `with-X` turns on one that is off.
+pub const CONFIG_OPTIONS: &[(&str, bool, &[&str])] = &[
+    ("c14n", true, &["c14n.c"]),
+    ("catalog", true, &["catalog.c"]),
+    ("debug", true, &["debugXML.c"]),
+    ("html", true, &["HTMLparser.c", "HTMLtree.c"]),
+    ("output", true, &["xmlsave.c"]),
+    ("pattern", true, &["pattern.c"]),
+    ("reader", true, &["xmlreader.c"]),
+    ("regexps", true, &["xmlregexp.c"]),
+    ("relaxng", true, &["relaxng.c"]),
+    ("schemas", true, &["xmlschemas.c", "xmlschemastypes.c"]),
+    ("schematron", false, &["schematron.c"]),
+    ("valid", true, &[]),
+    ("writer", true, &["xmlwriter.c"]),
+    ("xinclude", true, &["xinclude.c"]),
+    ("xpath", true, &["xpath.c"]),
+    ("xptr", true, &["xlink.c", "xpointer.c"]),
+];
+
+// Options other options cannot be built without, the hard dependencies of
+// configure.ac. The `without-X` features in Cargo.toml imply the
+// `without-Y` of the dependents, so only `with-schematron` can still ask
+// for a missing dependency.
+pub const OPTION_DEPENDENCIES: &[(&str, &[&str])] = &[
+    ("c14n", &["output", "xpath"]),
+    ("relaxng", &["regexps", "schemas"]),
+    ("schemas", &["pattern", "regexps"]),
+    ("schematron", &["pattern", "xpath"]),
+    ("writer", &["output"]),
+    ("xptr", &["xpath"]),
+];
+
+// Rust modules of optional parts, with the configure option they replace
+pub const MODULE_OPTIONS: &[(&str, &str)] = &[
+    ("xmlsave", "output"),
+    ("xpath", "xpath"),
+    ("pattern", "pattern"),
+    ("xpointer", "xptr"),
+    ("valid", "valid"),
+    ("xmlregexp", "regexps"),
+    ("xmlschemas", "schemas"),
+    ("relaxng", "relaxng"),
+    ("schematron", "schematron"),
+    ("htmlparser", "html"),
+    ("htmltree", "html"),
+    ("xmlreader", "reader"),
+    ("xmlwriter", "writer"),
+    ("c14n", "c14n"),
+    ("catalog", "catalog"),
+];
+
+/// Whether configure `option` is on under the selected features
+pub fn option_enabled(option: &str, features: &dyn Fn(&str) -> bool) -> bool {
+    let (_, default, _) = CONFIG_OPTIONS.iter()
+        .find(|(name, _, _)| *name == option)
+        .unwrap_or_else(|| panic!("unknown configure option {}", option));
+    if *default {
+        !features(&format!("without-{}", option))
+    } else {
+        features(&format!("with-{}", option))
+    }
+}
+
+/// Reject option features configure would override, and Rust modules of
+/// parts that are turned off
+pub fn check_options(rust_modules: &[String], features: &dyn Fn(&str) -> bool) -> Result<(), String> {
+    for (option, dependencies) in OPTION_DEPENDENCIES {
+        if !option_enabled(option, features) {
+            continue;
+        }
+        if let Some(dependency) = dependencies.iter().find(|dependency| !option_enabled(dependency, features)) {
+            return Err(format!("The {} option needs {}, turned off by without-{}", option, dependency, dependency));
+        }
+    }
+    for (module, option) in MODULE_OPTIONS {
+        if rust_modules.iter().any(|name| name == module) && !option_enabled(option, features) {
+            return Err(format!("The Rust {} module replaces part of the {} option, which is turned off", module, option));
+        }
+    }
+    Ok(())
+}
+
+/// Arguments for `./configure`, one `--with`/`--without` per `CONFIG_OPTIONS` entry
+pub fn configure_args(features: &dyn Fn(&str) -> bool) -> Vec<String> {
+    let mut args: Vec<String> = ["--disable-shared", "--enable-static", "--disable-dependency-tracking"]
+        .iter()
+        .map(|arg| arg.to_string())
+        .collect();
+    for (option, _, _) in CONFIG_OPTIONS {
+        let switch = if option_enabled(option, features) { "with" } else { "without" };
+        args.push(format!("--{}-{}", switch, option));
+    }
+    args
+}
+
+/// Whether `file` is part of the build under the selected configure options
+pub fn c_file_enabled(file: &str, features: &dyn Fn(&str) -> bool) -> bool {
+    CONFIG_OPTIONS.iter()
+        .filter(|(_, _, files)| files.contains(&file))
+        .all(|(option, _, _)| option_enabled(option, features))
+}
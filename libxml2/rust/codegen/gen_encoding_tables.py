@@ -0,0 +1,111 @@
+#!/usr/bin/env python3
+#
+# Generates the multi-byte mapping tables of the Rust encoding module from
+# Python's codecs. Run from libxml2/rust:
+#
+#     python3 codegen/gen_encoding_tables.py
+#
+# The tables are formatted with rustfmt afterwards.
+
+import subprocess
+
+HEADER = """//! {title}
+//!
+//! Generated with codegen/gen_encoding_tables.py from Python's `{codec}`
+//! codec. Do not edit.
+
+"""
+
+
+def print_table(out, width, data):
+    per_line = 8 if width == 4 else 6
+    for i in range(0, len(data), per_line):
+        row = data[i : i + per_line]
+        out.write("    " + " ".join(f"0x{v:0{width}x}," for v in row) + "\n")
+
+
+def print_pairs(out, pairs):
+    for i in range(0, len(pairs), 4):
+        row = pairs[i : i + 4]
+        out.write("    " + " ".join(f"(0x{a:04x}, 0x{b:04x})," for a, b in row) + "\n")
+
+
+def decode(codec, seq):
+    try:
+        s = seq.decode(codec)
+    except UnicodeDecodeError:
+        return 0
+    # Multi-byte sequences never stand for ASCII, so markup can't hide in
+    # them.
+    if len(s) != 1 or ord(s) < 0x80 or ord(s) > 0xFFFF:
+        return 0
+    return ord(s)
+
+
+def gen_grid(path, title, codec, name, rows, cells, seq, doc):
+    """A grid of `rows` by `cells` code points, and its inverse sorted by
+    code point. `seq(row, cell)` gives the bytes of a 0-based row and cell,
+    `doc` describes an index into the grid."""
+    decoded = [decode(codec, seq(row, cell)) for row in range(rows) for cell in range(cells)]
+    # Code points mapped twice encode to the position the codec's encoder
+    # picks, or else to the first one.
+    positions = {}
+    for i, cp in enumerate(decoded):
+        if cp == 0:
+            continue
+        try:
+            preferred = chr(cp).encode(codec) == seq(i // cells, i % cells)
+        except UnicodeEncodeError:
+            preferred = False
+        if cp not in positions or preferred:
+            positions[cp] = i
+    inverse = sorted(positions.items())
+
+    with open(path, "w") as out:
+        out.write(HEADER.format(title=title, codec=codec))
+        out.write(f"/// Code point of {doc}, or 0.\n")
+        out.write(f"pub(super) static {name}: [u16; {rows} * {cells}] = [\n")
+        print_table(out, 4, decoded)
+        out.write("];\n\n")
+        out.write("/// `(code point, index into the grid)`, sorted by code point.\n")
+        out.write(f"pub(super) static {name}_INVERSE: [(u16, u16); {len(inverse)}] = [\n")
+        print_pairs(out, inverse)
+        out.write("];\n")
+    subprocess.run(["rustfmt", "--edition", "2021", path], check=True)
+
+
+gen_grid(
+    "src/encoding/jis0208.rs",
+    "JIS X 0208, shared by Shift-JIS and EUC-JP",
+    "euc_jp",
+    "JIS0208",
+    94,
+    94,
+    lambda row, cell: bytes([0xA1 + row, 0xA1 + cell]),
+    "row `i / 94`, cell `i % 94`",
+)
+
+gen_grid(
+    "src/encoding/jis0212.rs",
+    "JIS X 0212, the supplementary kanji of EUC-JP",
+    "euc_jp",
+    "JIS0212",
+    94,
+    94,
+    lambda row, cell: bytes([0x8F, 0xA1 + row, 0xA1 + cell]),
+    "row `i / 94`, cell `i % 94`",
+)
+
+# Big5 trail bytes are 0x40-0x7E and 0xA1-0xFE.
+BIG5_TRAILS = list(range(0x40, 0x7F)) + list(range(0xA1, 0xFF))
+
+gen_grid(
+    "src/encoding/big5_table.rs",
+    "Big5",
+    "big5",
+    "BIG5",
+    0xFA - 0xA1,
+    len(BIG5_TRAILS),
+    lambda row, cell: bytes([0xA1 + row, BIG5_TRAILS[cell]]),
+    "lead byte `0xA1 + i / 157` and the `i % 157`th trail byte",
+)
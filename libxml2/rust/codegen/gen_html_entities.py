@@ -0,0 +1,44 @@
+#!/usr/bin/env python3
+#
+# Generates the HTML5 named character reference table of the Rust
+# htmlparser module from Python's `html.entities.html5`, which holds the
+# same WHATWG entities.json that codegen/genHtml5Ent.py turns into the C
+# table. Run from libxml2/rust:
+#
+#     python3 codegen/gen_html_entities.py
+
+import subprocess
+from html.entities import html5
+
+PATH = "src/htmlparser/entities.rs"
+
+
+def rust_str(s):
+    out = ""
+    for c in s:
+        if c in '"\\':
+            out += "\\" + c
+        elif c == "\n":
+            out += "\\n"
+        elif 0x20 <= ord(c) < 0x7F:
+            out += c
+        else:
+            out += f"\\u{{{ord(c):x}}}"
+    return '"' + out + '"'
+
+
+names = sorted(name[:-1] for name in html5 if name.endswith(";"))
+
+with open(PATH, "w") as out:
+    out.write("//! HTML5 named character references\n")
+    out.write("//!\n")
+    out.write("//! Generated with codegen/gen_html_entities.py from Python's\n")
+    out.write("//! `html.entities.html5`. Do not edit.\n\n")
+    out.write("/// `(name, replacement, semicolon optional)`, sorted by name.\n")
+    out.write(f"pub static ENTITIES: [(&str, &str, bool); {len(names)}] = [\n")
+    for name in names:
+        legacy = "true" if name in html5 else "false"
+        out.write(f"    ({rust_str(name)}, {rust_str(html5[name + ';'])}, {legacy}),\n")
+    out.write("];\n")
+
+subprocess.run(["rustfmt", "--edition", "2021", PATH], check=True)
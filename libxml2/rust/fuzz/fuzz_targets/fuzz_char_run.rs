@@ -0,0 +1,15 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+use libxml2::chvalid;
+
+fuzz_target!(|data: &[u8]| {
+    // The block-at-a-time path must stop at the same byte as the one
+    // code point at a time reference.
+    let result = chvalid::validate_char_run(data);
+    assert_eq!(result, chvalid::validate_char_run_scalar(data));
+
+    // Everything before the reported offset is a run of valid Chars
+    if let Err(offset) = result {
+        assert_eq!(chvalid::validate_char_run(&data[..offset]), Ok(()));
+    }
+});
@@ -0,0 +1,148 @@
+#![no_main]
+use arbitrary::Arbitrary;
+use libfuzzer_sys::fuzz_target;
+use libxml2::encoding::{self, Converter};
+use libxml2::*;
+use std::os::raw::c_int;
+use std::ptr;
+
+/// Encodings with built-in converters. UTF-8 is left out: it has no
+/// handler in C.
+const ENCODINGS: &[xmlCharEncoding] = &[
+    xmlCharEncoding_XML_CHAR_ENCODING_UTF16LE,
+    xmlCharEncoding_XML_CHAR_ENCODING_UTF16BE,
+    xmlCharEncoding_XML_CHAR_ENCODING_8859_1,
+    xmlCharEncoding_XML_CHAR_ENCODING_ASCII,
+    xmlCharEncoding_XML_CHAR_ENCODING_UTF16,
+];
+
+/// Output buffer size, relative to what the whole input needs.
+#[derive(Debug, Arbitrary)]
+enum Space {
+    Empty,
+    /// A fraction of the needed size, always short by at least one byte.
+    TooSmall(u8),
+    Exact,
+    Ample(u8),
+}
+
+#[derive(Debug, Arbitrary)]
+struct FuzzInput {
+    encoding: u8,
+    /// Convert from UTF-8 instead of to it.
+    output: bool,
+    space: Space,
+    data: Vec<u8>,
+}
+
+/// Produced bytes, `*inlen`, `*outlen` and the return value.
+type Outcome = (Vec<u8>, c_int, c_int, c_int);
+
+unsafe fn c_convert(
+    func: xmlCharEncConvFunc,
+    input: *const u8,
+    inlen: usize,
+    space: usize,
+) -> Outcome {
+    let mut out = vec![0u8; space];
+    let mut outlen = space as c_int;
+    let mut inlen = inlen as c_int;
+    let ret = func.unwrap()(
+        ptr::null_mut(),
+        out.as_mut_ptr(),
+        &mut outlen,
+        input,
+        &mut inlen,
+        0,
+    );
+    out.truncate(outlen.max(0) as usize);
+    (out, inlen, outlen, ret)
+}
+
+fn rust_convert(convert: Converter, input: &[u8], space: usize) -> Outcome {
+    let mut out = vec![0u8; space];
+    let res = convert(&mut out, input);
+    out.truncate(res.written);
+    (out, res.read as c_int, res.written as c_int, res.ret)
+}
+
+/// Feed `data` through both converters with a fixed output buffer, the way
+/// a streaming caller resumes after `XML_ENC_ERR_SPACE`, and compare every
+/// call.
+fn drive(c_func: xmlCharEncConvFunc, convert: Converter, data: &[u8], space: usize) {
+    let mut pos = 0;
+    loop {
+        let rest = &data[pos..];
+        let c = unsafe { c_convert(c_func, rest.as_ptr(), rest.len(), space) };
+        let rust = rust_convert(convert, rest, space);
+        assert_eq!(c, rust, "at offset {} with {} bytes of space", pos, space);
+        let (_, read, written, ret) = rust;
+        if ret != encoding::ENC_ERR_SPACE || (read == 0 && written == 0) {
+            break;
+        }
+        pos += read as usize;
+    }
+}
+
+fuzz_target!(|input: FuzzInput| {
+    let enc = ENCODINGS[input.encoding as usize % ENCODINGS.len()];
+    let (to_utf8, from_utf8) = encoding::converters(enc).unwrap();
+    let convert = if input.output { from_utf8 } else { to_utf8 };
+
+    let c_lib = libxml2::libxml2_dynload::get_c_baseline();
+    let mut handler = ptr::null_mut();
+    unsafe { c_lib.xmlLookupCharEncodingHandler(enc, &mut handler) };
+    let c_func = if input.output {
+        unsafe { (*handler).output.func }
+    } else {
+        unsafe { (*handler).input.func }
+    };
+
+    let needed = rust_convert(convert, &input.data, input.data.len() * 4 + 4).2 as usize;
+    let space = match input.space {
+        Space::Empty => 0,
+        Space::TooSmall(k) => needed.saturating_sub(1) * k as usize / 255,
+        Space::Exact => needed,
+        Space::Ample(k) => needed + k as usize,
+    };
+    drive(c_func, convert, &input.data, space);
+
+    // The "UTF-16" encoder starts with a byte order mark.
+    if enc == xmlCharEncoding_XML_CHAR_ENCODING_UTF16 && input.output {
+        let c = unsafe { c_convert(c_func, ptr::null(), 0, space) };
+        let mut out = vec![0u8; space];
+        let res = encoding::utf16_bom(&mut out);
+        out.truncate(res.written);
+        assert_eq!(c, (out, res.read as c_int, res.written as c_int, res.ret));
+    }
+
+    // The exported Latin-1 converters wrap the same code.
+    if enc == xmlCharEncoding_XML_CHAR_ENCODING_8859_1 {
+        type Export = unsafe extern "C" fn(*mut u8, *mut c_int, *const u8, *mut c_int) -> c_int;
+        let (c_fn, rust_fn): (Export, Export) = if input.output {
+            (
+                *c_lib.xmlUTF8ToIsolat1.as_ref().unwrap(),
+                encoding::ffi::xmlUTF8ToIsolat1,
+            )
+        } else {
+            (
+                *c_lib.xmlIsolat1ToUTF8.as_ref().unwrap(),
+                encoding::ffi::xmlIsolat1ToUTF8,
+            )
+        };
+        let run = |f: Export| unsafe {
+            let mut out = vec![0u8; space];
+            let mut outlen = space as c_int;
+            let mut inlen = input.data.len() as c_int;
+            let ret = f(
+                out.as_mut_ptr(),
+                &mut outlen,
+                input.data.as_ptr(),
+                &mut inlen,
+            );
+            out.truncate(outlen.max(0) as usize);
+            (out, inlen, outlen, ret)
+        };
+        assert_eq!(run(c_fn), run(rust_fn));
+    }
+});
@@ -0,0 +1,100 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+use libxml2::libxml2_dynload::get_c_baseline;
+use libxml2::uri::ffi;
+use libxml2::xmlURI;
+use std::ffi::{CStr, CString};
+use std::os::raw::{c_char, c_int, c_void};
+
+/// Every field of an `xmlURI`, or None if parsing failed.
+type Fields = Option<(Vec<Option<Vec<u8>>>, c_int, c_int)>;
+
+unsafe fn opt(s: *const c_char) -> Option<Vec<u8>> {
+    if s.is_null() {
+        None
+    } else {
+        Some(CStr::from_ptr(s).to_bytes().to_vec())
+    }
+}
+
+unsafe fn fields(uri: *const xmlURI) -> Fields {
+    let uri = uri.as_ref()?;
+    let strings = [
+        uri.scheme,
+        uri.opaque,
+        uri.authority,
+        uri.server,
+        uri.user,
+        uri.path,
+        uri.query,
+        uri.fragment,
+        uri.query_raw,
+    ];
+    Some((
+        strings.iter().map(|&s| opt(s)).collect(),
+        uri.port,
+        uri.cleanup,
+    ))
+}
+
+/// Parse with the C baseline; returns the fields and the saved form.
+unsafe fn c_parse(s: &CStr, raw: c_int) -> (Fields, Option<Vec<u8>>) {
+    let c_lib = get_c_baseline();
+    let uri = c_lib.xmlParseURIRaw(s.as_ptr(), raw);
+    let result = fields(uri as *const xmlURI);
+    let mut saved = None;
+    if !uri.is_null() {
+        let out = c_lib.xmlSaveUri(uri);
+        saved = opt(out as *const c_char);
+        libc::free(out as *mut c_void);
+        c_lib.xmlFreeURI(uri);
+    }
+    (result, saved)
+}
+
+/// Parse with the Rust implementation; returns the fields and the saved form.
+unsafe fn rust_parse(s: &CStr, raw: c_int) -> (Fields, Option<Vec<u8>>) {
+    let uri = ffi::xmlParseURIRaw(s.as_ptr(), raw);
+    let result = fields(uri);
+    let mut saved = None;
+    if !uri.is_null() {
+        let out = ffi::xmlSaveUri(uri);
+        saved = opt(out as *const c_char);
+        libxml2::xmlFree.unwrap()(out as *mut c_void);
+        ffi::xmlFreeURI(uri);
+    }
+    (result, saved)
+}
+
+fuzz_target!(|data: &[u8]| {
+    // Arbitrary ASCII without NULs.
+    let input: Vec<u8> = data.iter().map(|&b| b & 0x7F).filter(|&b| b != 0).collect();
+    let input = CString::new(input).unwrap();
+
+    for raw in [0, 1] {
+        let (c_fields, c_saved) = unsafe { c_parse(&input, raw) };
+        let (rust_fields, rust_saved) = unsafe { rust_parse(&input, raw) };
+        assert_eq!(
+            c_fields, rust_fields,
+            "fields differ for {:?} (raw {})",
+            input, raw
+        );
+        assert_eq!(
+            c_saved, rust_saved,
+            "xmlSaveUri differs for {:?} (raw {})",
+            input, raw
+        );
+
+        // Round trip: the saved form must parse the same way in both.
+        if let Some(saved) = c_saved {
+            let saved = CString::new(saved).unwrap();
+            let c_again = unsafe { c_parse(&saved, raw) };
+            let rust_again = unsafe { rust_parse(&saved, raw) };
+            assert_eq!(
+                c_again, rust_again,
+                "round trip differs for {:?} (raw {})",
+                saved, raw
+            );
+        }
+    }
+});
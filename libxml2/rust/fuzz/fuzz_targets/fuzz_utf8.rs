@@ -0,0 +1,38 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+use libxml2::xmlstring;
+
+fuzz_target!(|data: &[u8]| {
+    // The word-at-a-time path must stop at the same byte as the one
+    // sequence at a time loop.
+    let offset = xmlstring::xml_validate_utf8(data);
+    assert_eq!(offset, xmlstring::check_utf8(data));
+
+    // Up to the first NUL, which ends the string in C, the offset is
+    // where xmlCheckUTF8 gives up: it accepts everything before it and
+    // rejects anything from there on.
+    let end = data.iter().position(|&b| b == 0).unwrap_or(data.len());
+    let offset = xmlstring::xml_validate_utf8(&data[..end]);
+    let c_lib = libxml2::libxml2_dynload::get_c_baseline();
+    let check = |bytes: &[u8]| {
+        let mut s = bytes.to_vec();
+        s.push(0);
+        unsafe { c_lib.xmlCheckUTF8(s.as_ptr()) }
+    };
+    match offset {
+        None => assert_eq!(check(&data[..end]), 1),
+        Some(offset) => {
+            assert_eq!(check(&data[..offset]), 1);
+            assert_eq!(check(&data[..end]), 0);
+            assert_eq!(check(&data[offset..end]), 0);
+        }
+    }
+    unsafe {
+        let mut s = data[..end].to_vec();
+        s.push(0);
+        assert_eq!(
+            libxml2::xmlstring::ffi::xmlCheckUTF8(s.as_ptr()),
+            offset.is_none() as i32
+        );
+    }
+});
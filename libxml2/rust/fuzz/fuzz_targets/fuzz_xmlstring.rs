@@ -0,0 +1,75 @@
+#![no_main]
+use arbitrary::Arbitrary;
+use libfuzzer_sys::fuzz_target;
+use libxml2::differential_test;
+use libxml2::xmlstring;
+use std::ptr;
+
+/// Two strings built from a shared prefix, so the comparison loops run
+/// past the first byte.
+#[derive(Debug, Arbitrary)]
+struct FuzzInput {
+    prefix: Vec<u8>,
+    suffix1: Vec<u8>,
+    suffix2: Vec<u8>,
+    /// Uppercase the second string's prefix to exercise xmlStrcasecmp.
+    upper: bool,
+    null1: bool,
+    null2: bool,
+}
+
+/// Cut at the first NUL and terminate, as a C caller would see the bytes.
+fn terminated(parts: &[&[u8]]) -> Vec<u8> {
+    let mut s: Vec<u8> = parts.concat();
+    if let Some(nul) = s.iter().position(|&b| b == 0) {
+        s.truncate(nul);
+    }
+    s.push(0);
+    s
+}
+
+fuzz_target!(|input: FuzzInput| {
+    let prefix2 = if input.upper {
+        input.prefix.to_ascii_uppercase()
+    } else {
+        input.prefix.clone()
+    };
+    let s1 = terminated(&[&input.prefix, &input.suffix1]);
+    let s2 = terminated(&[&prefix2, &input.suffix2]);
+    let p1 = if input.null1 {
+        ptr::null()
+    } else {
+        s1.as_ptr()
+    };
+    let p2 = if input.null2 {
+        ptr::null()
+    } else {
+        s2.as_ptr()
+    };
+
+    differential_test!(xmlStrlen, (p1));
+    differential_test!(xmlStrlen, (p2));
+    differential_test!(xmlStrcmp, (p1, p2));
+    differential_test!(xmlStrcmp, (p2, p1));
+    differential_test!(xmlStrEqual, (p1, p2));
+    differential_test!(xmlStrcasecmp, (p1, p2));
+    differential_test!(xmlStrcasecmp, (p2, p1));
+
+    // Same pointer on both sides takes the identity shortcut.
+    differential_test!(xmlStrcmp, (p1, p1));
+    differential_test!(xmlStrEqual, (p1, p1));
+
+    // The slice-based core must agree with the exports.
+    let (b1, b2) = (&s1[..s1.len() - 1], &s2[..s2.len() - 1]);
+    let c_lib = libxml2::libxml2_dynload::get_c_baseline();
+    unsafe {
+        assert_eq!(
+            xmlstring::str_cmp(b1, b2),
+            c_lib.xmlStrcmp(s1.as_ptr(), s2.as_ptr())
+        );
+        assert_eq!(
+            xmlstring::str_casecmp(b1, b2),
+            c_lib.xmlStrcasecmp(s1.as_ptr(), s2.as_ptr())
+        );
+    }
+});
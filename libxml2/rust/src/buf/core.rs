@@ -0,0 +1,644 @@
+#![allow(clippy::missing_safety_doc)]
+
+use std::os::raw::{c_int, c_uint, c_void};
+use std::ptr;
+use std::slice;
+use std::sync::RwLock;
+
+use crate::static_bindings::{
+    xmlBuffer, xmlBufferAllocationScheme_XML_BUFFER_ALLOC_IO, xmlChar, xmlFree, xmlMalloc,
+    xmlParserInput, xmlRealloc,
+};
+
+/// Largest size of an `xmlBuf`, leaving room for the terminating NUL.
+pub const MAX_SIZE: usize = usize::MAX - 1;
+/// Largest size of an `xmlBuffer`, whose fields are `unsigned int` but
+/// whose API returns `int`.
+pub const MAX_BUFFER_SIZE: usize = c_int::MAX as usize;
+
+const FLAG_OOM: u32 = 1 << 0;
+const FLAG_OVERFLOW: u32 = 1 << 1;
+const FLAG_STATIC: u32 = 1 << 2;
+
+/// How a buffer picks its new capacity when it runs out of space.
+///
+/// Capacities start at `min_capacity` and double until they reach
+/// `threshold`, then grow by half, which keeps the unused tail of huge
+/// buffers under a third of their allocation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GrowthPolicy {
+    pub min_capacity: usize,
+    pub threshold: usize,
+}
+
+impl GrowthPolicy {
+    pub const DEFAULT: GrowthPolicy = GrowthPolicy {
+        min_capacity: 64,
+        threshold: 64 << 20,
+    };
+
+    /// The first capacity on the growth sequence from `capacity` that
+    /// holds `required` bytes, clamped to `max`. `None` if `required`
+    /// exceeds `max`.
+    pub fn next_capacity(&self, capacity: usize, required: usize, max: usize) -> Option<usize> {
+        if required > max {
+            return None;
+        }
+        let mut cap = capacity.max(self.min_capacity).max(1);
+        while cap < required {
+            let step = if cap < self.threshold { cap } else { cap / 2 };
+            cap = cap.saturating_add(step.max(1));
+        }
+        Some(cap.min(max))
+    }
+}
+
+impl Default for GrowthPolicy {
+    fn default() -> Self {
+        GrowthPolicy::DEFAULT
+    }
+}
+
+/// Behind one lock, so a buffer never sees half of a policy being set.
+static POLICY: RwLock<GrowthPolicy> = RwLock::new(GrowthPolicy::DEFAULT);
+
+/// The policy new `Buf`s and all `xmlBuffer`s grow with.
+pub fn growth_policy() -> GrowthPolicy {
+    *POLICY.read().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Set the process-wide growth policy. Buffers which already exist keep
+/// growing with the policy they were created with.
+pub fn set_growth_policy(policy: GrowthPolicy) {
+    *POLICY.write().unwrap_or_else(|e| e.into_inner()) = policy;
+}
+
+/// Errors of buffer operations. The first memory or overflow error puts
+/// the buffer in an error state, which fails every later call.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BufError {
+    /// An allocation failed.
+    Memory,
+    /// The size would exceed `MAX_SIZE`.
+    Overflow,
+    /// The buffer is in an error state or static, or an argument is out
+    /// of range.
+    Invalid,
+}
+
+pub(crate) unsafe fn malloc(size: usize) -> *mut xmlChar {
+    match xmlMalloc {
+        Some(malloc) => malloc(size) as *mut xmlChar,
+        None => ptr::null_mut(),
+    }
+}
+
+unsafe fn realloc(mem: *mut xmlChar, size: usize) -> *mut xmlChar {
+    match xmlRealloc {
+        Some(realloc) => realloc(mem as *mut c_void, size) as *mut xmlChar,
+        None => ptr::null_mut(),
+    }
+}
+
+pub(crate) unsafe fn free(mem: *mut xmlChar) {
+    if !mem.is_null() {
+        xmlFree.unwrap()(mem as *mut c_void);
+    }
+}
+
+/// The `xmlBuf` behind the opaque pointer: a NUL-terminated byte string
+/// in memory from `xmlMalloc`, which can be handed to C.
+///
+/// `content` may start after `mem` once bytes were shrunk off the front;
+/// `size` is the capacity from `content` on, excluding the NUL. The
+/// fields are laid out as in C, where `content` comes first like in
+/// `xmlBuffer` for code which peeks at `input->buf->buffer`.
+#[repr(C)]
+#[derive(Debug)]
+pub struct Buf {
+    content: *mut xmlChar,
+    mem: *mut xmlChar,
+    used: usize,
+    size: usize,
+    max_size: usize,
+    flags: u32,
+    pub policy: GrowthPolicy,
+}
+
+impl Buf {
+    fn with_mem(mem: *mut xmlChar, used: usize, size: usize, flags: u32) -> Buf {
+        Buf {
+            content: mem,
+            mem,
+            used,
+            size,
+            max_size: MAX_SIZE,
+            flags,
+            policy: growth_policy(),
+        }
+    }
+
+    /// An empty buffer with room for `size` bytes.
+    pub fn new(size: usize) -> Option<Buf> {
+        if size == usize::MAX {
+            return None;
+        }
+        unsafe {
+            let mem = malloc(size + 1);
+            if mem.is_null() {
+                return None;
+            }
+            *mem = 0;
+            Some(Buf::with_mem(mem, 0, size, 0))
+        }
+    }
+
+    /// A buffer holding a copy of `data`.
+    pub fn from_bytes(data: &[u8]) -> Option<Buf> {
+        unsafe {
+            let mem = malloc(data.len() + 1);
+            if mem.is_null() {
+                return None;
+            }
+            ptr::copy_nonoverlapping(data.as_ptr(), mem, data.len());
+            *mem.add(data.len()) = 0;
+            Some(Buf::with_mem(mem, data.len(), data.len(), 0))
+        }
+    }
+
+    /// A buffer reading the `size` bytes at `mem` in place. They must be
+    /// followed by a NUL and outlive the buffer, which can only be shrunk.
+    pub unsafe fn from_static(mem: *const xmlChar, size: usize) -> Option<Buf> {
+        if *mem.add(size) != 0 {
+            return None;
+        }
+        Some(Buf::with_mem(mem as *mut xmlChar, size, size, FLAG_STATIC))
+    }
+
+    /// Take over the memory of `buffer` like `xmlBufFromBuffer`. The
+    /// buffer must be handed back with `into_buffer`.
+    pub unsafe fn from_buffer(buffer: &xmlBuffer) -> Buf {
+        if buffer.content.is_null() {
+            let mut ret = Buf::with_mem(malloc(51), buffer.use_ as usize, 50, 0);
+            if ret.mem.is_null() {
+                ret.memory_error();
+            } else {
+                *ret.content = 0;
+            }
+            return ret;
+        }
+        let mem = if buffer.alloc == xmlBufferAllocationScheme_XML_BUFFER_ALLOC_IO {
+            buffer.contentIO
+        } else {
+            buffer.content
+        };
+        let mut ret = Buf::with_mem(mem, buffer.use_ as usize, buffer.size as usize - 1, 0);
+        ret.content = buffer.content;
+        ret
+    }
+
+    /// Store the content in `buffer` like `xmlBufBackToBuffer`. On error
+    /// the memory is freed and `buffer` emptied.
+    pub fn into_buffer(self, buffer: &mut xmlBuffer) -> Result<(), BufError> {
+        if self.is_error() || self.is_static() || self.used >= MAX_BUFFER_SIZE {
+            drop(self);
+            buffer.content = ptr::null_mut();
+            buffer.contentIO = ptr::null_mut();
+            buffer.use_ = 0;
+            buffer.size = 0;
+            return Err(BufError::Invalid);
+        }
+        buffer.use_ = self.used as c_uint;
+        // Keep the memory but provide a truncated size value.
+        buffer.size = (self.size + 1).min(MAX_BUFFER_SIZE) as c_uint;
+        buffer.alloc = xmlBufferAllocationScheme_XML_BUFFER_ALLOC_IO;
+        buffer.content = self.content;
+        buffer.contentIO = self.mem;
+        std::mem::forget(self);
+        Ok(())
+    }
+
+    pub fn is_error(&self) -> bool {
+        self.flags & (FLAG_OOM | FLAG_OVERFLOW) != 0
+    }
+
+    pub fn is_static(&self) -> bool {
+        self.flags & FLAG_STATIC != 0
+    }
+
+    fn is_writable(&self) -> bool {
+        self.flags & (FLAG_OOM | FLAG_OVERFLOW | FLAG_STATIC) == 0
+    }
+
+    fn memory_error(&mut self) -> BufError {
+        if !self.is_error() {
+            self.flags |= FLAG_OOM;
+        }
+        BufError::Memory
+    }
+
+    fn overflow_error(&mut self) -> BufError {
+        if !self.is_error() {
+            self.flags |= FLAG_OVERFLOW;
+        }
+        BufError::Overflow
+    }
+
+    /// The content, NUL-terminated. Null in the error state.
+    pub fn content(&self) -> *mut xmlChar {
+        if self.is_error() {
+            ptr::null_mut()
+        } else {
+            self.content
+        }
+    }
+
+    /// The end of the content. Null in the error state.
+    pub fn end(&self) -> *mut xmlChar {
+        if self.is_error() {
+            ptr::null_mut()
+        } else {
+            unsafe { self.content.add(self.used) }
+        }
+    }
+
+    /// The content as a slice, empty in the error state.
+    pub fn as_bytes(&self) -> &[u8] {
+        if self.is_error() || self.content.is_null() {
+            &[]
+        } else {
+            unsafe { slice::from_raw_parts(self.content, self.used) }
+        }
+    }
+
+    /// Number of bytes in the buffer, 0 in the error state.
+    pub fn len(&self) -> usize {
+        if self.is_error() {
+            0
+        } else {
+            self.used
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.used == 0
+    }
+
+    /// Room left after the content without growing.
+    pub fn avail(&self) -> usize {
+        if self.is_error() {
+            0
+        } else {
+            self.size - self.used
+        }
+    }
+
+    /// Capacity from the start of the content, excluding the NUL.
+    pub fn capacity(&self) -> usize {
+        self.size
+    }
+
+    /// Take the content as an `xmlMalloc`'ed string, leaving the buffer
+    /// empty and without memory. Null for static buffers and in the
+    /// error state.
+    pub fn detach(&mut self) -> *mut xmlChar {
+        if !self.is_writable() {
+            return ptr::null_mut();
+        }
+        let ret = if self.content != self.mem {
+            unsafe {
+                let ret = malloc(self.used + 1);
+                if !ret.is_null() {
+                    ptr::copy_nonoverlapping(self.content, ret, self.used);
+                    *ret.add(self.used) = 0;
+                }
+                free(self.mem);
+                ret
+            }
+        } else {
+            self.mem
+        };
+        self.content = ptr::null_mut();
+        self.mem = ptr::null_mut();
+        self.size = 0;
+        self.used = 0;
+        ret
+    }
+
+    /// Copy the content into a `Vec` and empty the buffer, which stays
+    /// usable. A static buffer is shrunk to nothing instead; in the error
+    /// state the result is empty and the buffer left alone.
+    pub fn take_vec(&mut self) -> Vec<u8> {
+        let ret = self.as_bytes().to_vec();
+        if self.is_static() {
+            self.shrink(self.used);
+        } else {
+            self.empty();
+        }
+        ret
+    }
+
+    /// The content as a `Vec`, freeing the buffer's memory.
+    ///
+    /// The bytes are always copied: the memory comes from `xmlMalloc`,
+    /// which an embedder can point at any allocator, and a `Vec` can only
+    /// take over memory of the Rust global allocator.
+    pub fn into_vec(mut self) -> Vec<u8> {
+        self.take_vec()
+    }
+
+    /// Remove the content, reclaiming the space shrunk off the front.
+    pub fn empty(&mut self) {
+        if !self.is_writable() || self.mem.is_null() {
+            return;
+        }
+        self.used = 0;
+        self.size += self.content as usize - self.mem as usize;
+        self.content = self.mem;
+        unsafe { *self.content = 0 };
+    }
+
+    /// Discard `len` bytes from the front, returning how many were
+    /// removed: `len`, or 0 if there are fewer.
+    pub fn shrink(&mut self, len: usize) -> usize {
+        if self.is_error() || len == 0 || len > self.used {
+            return 0;
+        }
+        self.used -= len;
+        self.content = unsafe { self.content.add(len) };
+        self.size -= len;
+        len
+    }
+
+    /// Make room for `len` bytes after the content, assuming
+    /// `len > avail()`.
+    fn grow_internal(&mut self, len: usize) -> Result<(), BufError> {
+        let start = self.content as usize - self.mem as usize;
+        // If there's enough space at the start of the buffer, move the
+        // contents.
+        if len <= start + self.size - self.used {
+            unsafe { ptr::copy(self.content, self.mem, self.used + 1) };
+            self.size += start;
+            self.content = self.mem;
+            return Ok(());
+        }
+        if len > self.max_size - self.used {
+            return Err(self.overflow_error());
+        }
+        let size = self
+            .policy
+            .next_capacity(self.size, self.used + len, self.max_size)
+            .unwrap();
+
+        let new_mem = unsafe {
+            if self.content == self.mem {
+                realloc(self.mem, size + 1)
+            } else {
+                let new_mem = malloc(size + 1);
+                if !new_mem.is_null() {
+                    if !self.content.is_null() {
+                        ptr::copy_nonoverlapping(self.content, new_mem, self.used + 1);
+                    }
+                    free(self.mem);
+                }
+                new_mem
+            }
+        };
+        if new_mem.is_null() {
+            return Err(self.memory_error());
+        }
+        self.mem = new_mem;
+        self.content = new_mem;
+        self.size = size;
+        Ok(())
+    }
+
+    /// Make sure `len` bytes can be written after the content.
+    pub fn grow(&mut self, len: usize) -> Result<(), BufError> {
+        if !self.is_writable() {
+            return Err(BufError::Invalid);
+        }
+        if len <= self.size - self.used {
+            return Ok(());
+        }
+        self.grow_internal(len)
+    }
+
+    /// Account for `len` bytes written directly after the content.
+    pub fn add_len(&mut self, len: usize) -> Result<(), BufError> {
+        if !self.is_writable() || len > self.size - self.used {
+            return Err(BufError::Invalid);
+        }
+        self.used += len;
+        unsafe { *self.content.add(self.used) = 0 };
+        Ok(())
+    }
+
+    /// Append `data`.
+    pub fn add(&mut self, data: &[u8]) -> Result<(), BufError> {
+        unsafe { self.add_raw(data.as_ptr(), data.len()) }
+    }
+
+    /// Append the `len` bytes at `data`, which may only point into the
+    /// buffer itself if no growth is needed, as in C.
+    pub unsafe fn add_raw(&mut self, data: *const xmlChar, len: usize) -> Result<(), BufError> {
+        if !self.is_writable() {
+            return Err(BufError::Invalid);
+        }
+        if len == 0 {
+            return Ok(());
+        }
+        if len > self.size - self.used {
+            self.grow_internal(len)?;
+        }
+        ptr::copy(data, self.content.add(self.used), len);
+        self.used += len;
+        *self.content.add(self.used) = 0;
+        Ok(())
+    }
+
+    /// Point `input` at the content, with the cursor at `pos`.
+    pub fn update_input(&self, input: &mut xmlParserInput, pos: usize) {
+        input.base = self.content;
+        input.cur = unsafe { self.content.add(pos) };
+        input.end = unsafe { self.content.add(self.used) };
+    }
+}
+
+impl Drop for Buf {
+    fn drop(&mut self) {
+        if !self.is_static() {
+            unsafe { free(self.mem) };
+        }
+    }
+}
+
+/// Grow an `xmlBuffer` to fit `len` more bytes and the NUL, like
+/// `xmlBufferGrow`: returns 0 if that fits already, else the room left
+/// after growing, or -1 on error.
+pub unsafe fn buffer_grow(buf: &mut xmlBuffer, len: c_uint) -> c_int {
+    if len < buf.size.wrapping_sub(buf.use_) {
+        return 0;
+    }
+    let (used, len) = (buf.use_ as usize, len as usize);
+    if len >= MAX_BUFFER_SIZE - used {
+        return -1;
+    }
+    let Some(size) =
+        growth_policy().next_capacity(buf.size as usize, used + len + 1, MAX_BUFFER_SIZE)
+    else {
+        return -1;
+    };
+
+    let io = buf.alloc == xmlBufferAllocationScheme_XML_BUFFER_ALLOC_IO;
+    let new_mem = if io && buf.content != buf.contentIO {
+        let new_mem = malloc(size);
+        if new_mem.is_null() {
+            return -1;
+        }
+        if !buf.content.is_null() {
+            ptr::copy_nonoverlapping(buf.content, new_mem, used + 1);
+        }
+        free(buf.contentIO);
+        new_mem
+    } else {
+        let new_mem = realloc(buf.content, size);
+        if new_mem.is_null() {
+            return -1;
+        }
+        new_mem
+    };
+
+    if io {
+        buf.contentIO = new_mem;
+    }
+    buf.content = new_mem;
+    buf.size = size as c_uint;
+    (size - used - 1) as c_int
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Number of reallocations to append `chunks` chunks of `len` bytes.
+    fn growths(policy: &GrowthPolicy, chunks: usize, len: usize) -> usize {
+        let (mut cap, mut used, mut count) = (0, 0, 0);
+        for _ in 0..chunks {
+            used += len;
+            if used > cap {
+                cap = policy.next_capacity(cap, used, MAX_SIZE).unwrap();
+                count += 1;
+            }
+        }
+        count
+    }
+
+    #[test]
+    fn test_next_capacity_doubles() {
+        let policy = GrowthPolicy::DEFAULT;
+        assert_eq!(policy.next_capacity(0, 1, MAX_SIZE), Some(64));
+        assert_eq!(policy.next_capacity(64, 65, MAX_SIZE), Some(128));
+        assert_eq!(policy.next_capacity(64, 1000, MAX_SIZE), Some(1024));
+        assert_eq!(policy.next_capacity(100, 50, MAX_SIZE), Some(100));
+    }
+
+    #[test]
+    fn test_next_capacity_above_threshold() {
+        let policy = GrowthPolicy {
+            min_capacity: 16,
+            threshold: 1024,
+        };
+        assert_eq!(policy.next_capacity(512, 513, MAX_SIZE), Some(1024));
+        assert_eq!(policy.next_capacity(1024, 1025, MAX_SIZE), Some(1536));
+        assert_eq!(policy.next_capacity(1536, 1537, MAX_SIZE), Some(2304));
+    }
+
+    #[test]
+    fn test_next_capacity_limits() {
+        let policy = GrowthPolicy::DEFAULT;
+        assert_eq!(policy.next_capacity(64, 101, 100), None);
+        assert_eq!(policy.next_capacity(64, 100, 100), Some(100));
+        assert_eq!(
+            policy.next_capacity(usize::MAX / 2 + 1, MAX_SIZE, MAX_SIZE),
+            Some(MAX_SIZE)
+        );
+        let slow = GrowthPolicy {
+            min_capacity: 0,
+            threshold: 0,
+        };
+        assert_eq!(slow.next_capacity(0, 1, MAX_SIZE), Some(1));
+        assert_eq!(slow.next_capacity(1, 2, MAX_SIZE), Some(2));
+        assert_eq!(slow.next_capacity(2, 3, MAX_SIZE), Some(3));
+        assert_eq!(slow.next_capacity(3, 4, MAX_SIZE), Some(4));
+        assert_eq!(slow.next_capacity(4, 5, MAX_SIZE), Some(6));
+    }
+
+    #[test]
+    fn test_growth_is_amortized() {
+        // Exact fit would reallocate on each of the 100,000 appends.
+        assert_eq!(growths(&GrowthPolicy::DEFAULT, 100_000, 10), 15);
+        let policy = GrowthPolicy {
+            min_capacity: 64,
+            threshold: 4096,
+        };
+        assert_eq!(growths(&policy, 100_000, 10), 21);
+    }
+
+    #[test]
+    fn test_buf_add_and_shrink() {
+        let mut buf = Buf::new(4).unwrap();
+        buf.policy = GrowthPolicy {
+            min_capacity: 4,
+            threshold: 16,
+        };
+        buf.add(b"hello").unwrap();
+        buf.add(b", world").unwrap();
+        assert_eq!(buf.as_bytes(), b"hello, world");
+        assert_eq!(buf.capacity(), 16);
+        assert_eq!(buf.shrink(7), 7);
+        assert_eq!(buf.as_bytes(), b"world");
+        assert_eq!(buf.shrink(6), 0);
+
+        // The shrunk space is reused before growing.
+        buf.add(b"0123456789").unwrap();
+        assert_eq!(buf.as_bytes(), b"world0123456789");
+        assert_eq!(buf.capacity(), 16);
+        buf.add(b"!!").unwrap();
+        assert_eq!(buf.capacity(), 24);
+        assert_eq!(unsafe { *buf.end() }, 0);
+
+        buf.empty();
+        assert!(buf.is_empty());
+        assert_eq!(buf.avail(), 24);
+    }
+
+    #[test]
+    fn test_buf_static() {
+        let mut buf = unsafe { Buf::from_static(c"static".as_ptr() as *const xmlChar, 6) }.unwrap();
+        assert_eq!(buf.add(b"x"), Err(BufError::Invalid));
+        assert_eq!(buf.grow(1), Err(BufError::Invalid));
+        assert!(buf.detach().is_null());
+        assert_eq!(buf.shrink(2), 2);
+        assert_eq!(buf.as_bytes(), b"atic");
+        assert!(unsafe { Buf::from_static(c"abc".as_ptr() as *const xmlChar, 2) }.is_none());
+        assert_eq!(buf.take_vec(), b"atic");
+        assert!(buf.is_empty());
+    }
+
+    #[test]
+    fn test_buf_into_vec() {
+        let mut buf = Buf::from_bytes(b"<?xml?><doc/>").unwrap();
+        assert_eq!(buf.shrink(7), 7);
+        assert_eq!(buf.take_vec(), b"<doc/>");
+        assert!(buf.is_empty());
+        assert_eq!(*unsafe { buf.content().as_ref() }.unwrap(), 0);
+        buf.add(b"<next/>").unwrap();
+        assert_eq!(buf.into_vec(), b"<next/>");
+
+        let mut failed = Buf::new(0).unwrap();
+        failed.max_size = 4;
+        assert_eq!(failed.add(b"too long"), Err(BufError::Overflow));
+        assert_eq!(failed.into_vec(), b"");
+    }
+}
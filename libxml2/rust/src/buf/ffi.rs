@@ -0,0 +1,564 @@
+#![allow(clippy::missing_safety_doc)]
+
+use std::ffi::CStr;
+use std::os::raw::{c_char, c_int, c_uint, c_void};
+use std::ptr;
+
+use super::core::{self, Buf, BufError};
+use crate::static_bindings::{
+    xmlBuf, xmlBuffer, xmlBufferAllocationScheme, xmlBufferAllocationScheme_XML_BUFFER_ALLOC_EXACT,
+    xmlBufferAllocationScheme_XML_BUFFER_ALLOC_IO, xmlChar, xmlParserErrors_XML_ERR_ARGUMENT,
+    xmlParserErrors_XML_ERR_NO_MEMORY, xmlParserErrors_XML_ERR_OK, xmlParserInput, FILE,
+};
+
+// Ensure symbols are exported for linking
+#[cfg(feature = "rust-buf")]
+#[used]
+static BUF_FFI_LINKAGE: () = ();
+
+extern "C" {
+    static mut stdout: *mut FILE;
+}
+
+unsafe fn as_buf<'a>(buf: *const xmlBuf) -> Option<&'a mut Buf> {
+    (buf as *mut Buf).as_mut()
+}
+
+fn into_raw(buf: Option<Buf>) -> *mut xmlBuf {
+    buf.map_or(ptr::null_mut(), |buf| {
+        Box::into_raw(Box::new(buf)) as *mut xmlBuf
+    })
+}
+
+fn status(result: Result<(), BufError>) -> c_int {
+    if result.is_ok() {
+        0
+    } else {
+        -1
+    }
+}
+
+/// Length of `str_`, or `len` if it isn't negative.
+unsafe fn str_len(str_: *const xmlChar, len: c_int) -> c_int {
+    if len < 0 {
+        CStr::from_ptr(str_ as *const c_char).to_bytes().len() as c_int
+    } else {
+        len
+    }
+}
+
+/// Create a buffer with room for `size` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn xmlBufCreate(size: usize) -> *mut xmlBuf {
+    into_raw(Buf::new(size))
+}
+
+/// Create a buffer initialized with `size` bytes of `mem`, which is used
+/// in place if `isStatic` is set.
+#[no_mangle]
+pub unsafe extern "C" fn xmlBufCreateMem(
+    mem: *const xmlChar,
+    size: usize,
+    isStatic: c_int,
+) -> *mut xmlBuf {
+    if mem.is_null() {
+        return ptr::null_mut();
+    }
+    if isStatic != 0 {
+        into_raw(Buf::from_static(mem, size))
+    } else {
+        into_raw(Buf::from_bytes(std::slice::from_raw_parts(mem, size)))
+    }
+}
+
+/// Return the content of the buffer, clearing it.
+#[no_mangle]
+pub unsafe extern "C" fn xmlBufDetach(buf: *mut xmlBuf) -> *mut xmlChar {
+    as_buf(buf).map_or(ptr::null_mut(), |buf| buf.detach())
+}
+
+/// Free a buffer.
+#[no_mangle]
+pub unsafe extern "C" fn xmlBufFree(buf: *mut xmlBuf) {
+    if !buf.is_null() {
+        drop(Box::from_raw(buf as *mut Buf));
+    }
+}
+
+/// Empty a buffer.
+#[no_mangle]
+pub unsafe extern "C" fn xmlBufEmpty(buf: *mut xmlBuf) {
+    if let Some(buf) = as_buf(buf) {
+        buf.empty();
+    }
+}
+
+/// Discard `len` bytes at the start of a buffer.
+#[no_mangle]
+pub unsafe extern "C" fn xmlBufShrink(buf: *mut xmlBuf, len: usize) -> usize {
+    as_buf(buf).map_or(0, |buf| buf.shrink(len))
+}
+
+/// Make room for `len` bytes after the end of the buffer.
+#[no_mangle]
+pub unsafe extern "C" fn xmlBufGrow(buf: *mut xmlBuf, len: usize) -> c_int {
+    as_buf(buf).map_or(-1, |buf| status(buf.grow(len)))
+}
+
+/// Pointer to the buffer content.
+#[no_mangle]
+pub unsafe extern "C" fn xmlBufContent(buf: *const xmlBuf) -> *mut xmlChar {
+    as_buf(buf).map_or(ptr::null_mut(), |buf| buf.content())
+}
+
+/// Pointer to the end of the buffer content.
+#[no_mangle]
+pub unsafe extern "C" fn xmlBufEnd(buf: *mut xmlBuf) -> *mut xmlChar {
+    as_buf(buf).map_or(ptr::null_mut(), |buf| buf.end())
+}
+
+/// Account for `len` bytes written directly after the content.
+#[no_mangle]
+pub unsafe extern "C" fn xmlBufAddLen(buf: *mut xmlBuf, len: usize) -> c_int {
+    as_buf(buf).map_or(-1, |buf| status(buf.add_len(len)))
+}
+
+/// Size of the buffer content.
+#[no_mangle]
+pub unsafe extern "C" fn xmlBufUse(buf: *mut xmlBuf) -> usize {
+    as_buf(buf).map_or(0, |buf| buf.len())
+}
+
+/// Space available at the end of the buffer.
+#[no_mangle]
+pub unsafe extern "C" fn xmlBufAvail(buf: *mut xmlBuf) -> usize {
+    as_buf(buf).map_or(0, |buf| buf.avail())
+}
+
+/// Whether the buffer is empty, -1 in case of error.
+#[no_mangle]
+pub unsafe extern "C" fn xmlBufIsEmpty(buf: *mut xmlBuf) -> c_int {
+    match as_buf(buf) {
+        Some(buf) if !buf.is_error() => buf.is_empty() as c_int,
+        _ => -1,
+    }
+}
+
+/// Append `len` bytes of `str_`.
+#[no_mangle]
+pub unsafe extern "C" fn xmlBufAdd(buf: *mut xmlBuf, str_: *const xmlChar, len: usize) -> c_int {
+    let Some(buf) = as_buf(buf) else {
+        return -1;
+    };
+    if len != 0 && str_.is_null() && !buf.is_error() && !buf.is_static() {
+        return -1;
+    }
+    status(buf.add_raw(str_, len))
+}
+
+/// Append a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn xmlBufCat(buf: *mut xmlBuf, str_: *const xmlChar) -> c_int {
+    if str_.is_null() {
+        return 0;
+    }
+    xmlBufAdd(
+        buf,
+        str_,
+        CStr::from_ptr(str_ as *const c_char).to_bytes().len(),
+    )
+}
+
+/// Wrap the memory of an `xmlBuffer` in an `xmlBuf`.
+#[no_mangle]
+pub unsafe extern "C" fn xmlBufFromBuffer(buffer: *mut xmlBuffer) -> *mut xmlBuf {
+    match buffer.as_ref() {
+        Some(buffer) => into_raw(Some(Buf::from_buffer(buffer))),
+        None => ptr::null_mut(),
+    }
+}
+
+/// Move the content of `buf` back into `ret` and free `buf`.
+#[no_mangle]
+pub unsafe extern "C" fn xmlBufBackToBuffer(buf: *mut xmlBuf, ret: *mut xmlBuffer) -> c_int {
+    if buf.is_null() || ret.is_null() {
+        return -1;
+    }
+    status(Box::from_raw(buf as *mut Buf).into_buffer(&mut *ret))
+}
+
+/// Point `input` at the start of the buffer.
+#[no_mangle]
+pub unsafe extern "C" fn xmlBufResetInput(buf: *mut xmlBuf, input: *mut xmlParserInput) -> c_int {
+    xmlBufUpdateInput(buf, input, 0)
+}
+
+/// Point `input` at the buffer, with the cursor at `pos`.
+#[no_mangle]
+pub unsafe extern "C" fn xmlBufUpdateInput(
+    buf: *mut xmlBuf,
+    input: *mut xmlParserInput,
+    pos: usize,
+) -> c_int {
+    match (as_buf(buf), input.as_mut()) {
+        (Some(buf), Some(input)) => {
+            buf.update_input(input, pos);
+            0
+        }
+        _ => -1,
+    }
+}
+
+/// No-op, buffers always grow with the growth policy.
+#[no_mangle]
+pub extern "C" fn xmlSetBufferAllocationScheme(_scheme: xmlBufferAllocationScheme) {}
+
+/// Always `XML_BUFFER_ALLOC_EXACT`, as in C.
+#[no_mangle]
+pub extern "C" fn xmlGetBufferAllocationScheme() -> xmlBufferAllocationScheme {
+    xmlBufferAllocationScheme_XML_BUFFER_ALLOC_EXACT
+}
+
+/// Create a buffer with 256 bytes of room.
+#[no_mangle]
+pub unsafe extern "C" fn xmlBufferCreate() -> *mut xmlBuffer {
+    let ret = core::malloc(std::mem::size_of::<xmlBuffer>()) as *mut xmlBuffer;
+    if ret.is_null() {
+        return ret;
+    }
+    let content = core::malloc(256);
+    if content.is_null() {
+        core::free(ret as *mut xmlChar);
+        return ptr::null_mut();
+    }
+    *content = 0;
+    ret.write(xmlBuffer {
+        content,
+        use_: 0,
+        size: 256,
+        alloc: xmlBufferAllocationScheme_XML_BUFFER_ALLOC_IO,
+        contentIO: content,
+    });
+    ret
+}
+
+/// Create a buffer with room for `size` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn xmlBufferCreateSize(size: usize) -> *mut xmlBuffer {
+    if size >= core::MAX_BUFFER_SIZE {
+        return ptr::null_mut();
+    }
+    let ret = core::malloc(std::mem::size_of::<xmlBuffer>()) as *mut xmlBuffer;
+    if ret.is_null() {
+        return ret;
+    }
+    // +1 for ending null
+    let size = if size != 0 { size + 1 } else { 0 };
+    let mut content = ptr::null_mut();
+    if size != 0 {
+        content = core::malloc(size);
+        if content.is_null() {
+            core::free(ret as *mut xmlChar);
+            return ptr::null_mut();
+        }
+        *content = 0;
+    }
+    ret.write(xmlBuffer {
+        content,
+        use_: 0,
+        size: size as c_uint,
+        alloc: xmlBufferAllocationScheme_XML_BUFFER_ALLOC_IO,
+        contentIO: content,
+    });
+    ret
+}
+
+/// Return the content of the buffer, clearing it.
+#[no_mangle]
+pub unsafe extern "C" fn xmlBufferDetach(buf: *mut xmlBuffer) -> *mut xmlChar {
+    let Some(buf) = buf.as_mut() else {
+        return ptr::null_mut();
+    };
+    let ret = if buf.alloc == xmlBufferAllocationScheme_XML_BUFFER_ALLOC_IO
+        && buf.content != buf.contentIO
+    {
+        let ret = core::malloc(buf.use_ as usize + 1);
+        if !ret.is_null() {
+            ptr::copy_nonoverlapping(buf.content, ret, buf.use_ as usize);
+            *ret.add(buf.use_ as usize) = 0;
+        }
+        core::free(buf.contentIO);
+        ret
+    } else {
+        buf.content
+    };
+    buf.contentIO = ptr::null_mut();
+    buf.content = ptr::null_mut();
+    buf.size = 0;
+    buf.use_ = 0;
+    ret
+}
+
+/// Create a buffer holding a copy of `size` bytes of `mem`.
+#[no_mangle]
+pub unsafe extern "C" fn xmlBufferCreateStatic(mem: *mut c_void, size: usize) -> *mut xmlBuffer {
+    let buf = xmlBufferCreateSize(size);
+    xmlBufferAdd(buf, mem as *const xmlChar, size as c_int);
+    buf
+}
+
+/// No-op, buffers always grow with the growth policy.
+#[no_mangle]
+pub extern "C" fn xmlBufferSetAllocationScheme(
+    _buf: *mut xmlBuffer,
+    _scheme: xmlBufferAllocationScheme,
+) {
+}
+
+/// Free a buffer.
+#[no_mangle]
+pub unsafe extern "C" fn xmlBufferFree(buf: *mut xmlBuffer) {
+    let Some(buffer) = buf.as_ref() else {
+        return;
+    };
+    if buffer.alloc == xmlBufferAllocationScheme_XML_BUFFER_ALLOC_IO {
+        core::free(buffer.contentIO);
+    } else {
+        core::free(buffer.content);
+    }
+    core::free(buf as *mut xmlChar);
+}
+
+/// Empty a buffer.
+#[no_mangle]
+pub unsafe extern "C" fn xmlBufferEmpty(buf: *mut xmlBuffer) {
+    let Some(buf) = buf.as_mut() else {
+        return;
+    };
+    if buf.content.is_null() {
+        return;
+    }
+    buf.use_ = 0;
+    if buf.alloc == xmlBufferAllocationScheme_XML_BUFFER_ALLOC_IO {
+        buf.size += buf.content.offset_from(buf.contentIO) as c_uint;
+        buf.content = buf.contentIO;
+    }
+    *buf.content = 0;
+}
+
+/// Discard `len` bytes at the start of a buffer.
+#[no_mangle]
+pub unsafe extern "C" fn xmlBufferShrink(buf: *mut xmlBuffer, len: c_uint) -> c_int {
+    let Some(buf) = buf.as_mut() else {
+        return -1;
+    };
+    if len == 0 {
+        return 0;
+    }
+    if len > buf.use_ {
+        return -1;
+    }
+    buf.use_ -= len;
+    if buf.alloc == xmlBufferAllocationScheme_XML_BUFFER_ALLOC_IO {
+        buf.content = buf.content.add(len as usize);
+        buf.size -= len;
+    } else {
+        ptr::copy(
+            buf.content.add(len as usize),
+            buf.content,
+            buf.use_ as usize + 1,
+        );
+    }
+    len as c_int
+}
+
+/// Make room for `len` bytes after the end of the buffer.
+#[no_mangle]
+pub unsafe extern "C" fn xmlBufferGrow(buf: *mut xmlBuffer, len: c_uint) -> c_int {
+    match buf.as_mut() {
+        Some(buf) => core::buffer_grow(buf, len),
+        None => -1,
+    }
+}
+
+/// Write the buffer content to `file`, or stdout if it is null.
+#[no_mangle]
+pub unsafe extern "C" fn xmlBufferDump(file: *mut FILE, buf: *mut xmlBuffer) -> c_int {
+    let Some(buf) = buf.as_ref() else {
+        return 0;
+    };
+    if buf.content.is_null() {
+        return 0;
+    }
+    let file = if file.is_null() { stdout } else { file };
+    let ret = libc::fwrite(
+        buf.content as *const c_void,
+        1,
+        buf.use_ as usize,
+        file as *mut libc::FILE,
+    );
+    ret.min(c_int::MAX as usize) as c_int
+}
+
+/// Pointer to the buffer content.
+#[no_mangle]
+pub unsafe extern "C" fn xmlBufferContent(buf: *const xmlBuffer) -> *const xmlChar {
+    buf.as_ref().map_or(ptr::null(), |buf| buf.content)
+}
+
+/// Size of the buffer content.
+#[no_mangle]
+pub unsafe extern "C" fn xmlBufferLength(buf: *const xmlBuffer) -> c_int {
+    buf.as_ref().map_or(0, |buf| buf.use_ as c_int)
+}
+
+/// Make the buffer hold at least `size` bytes. 1 on success, 0 on error.
+#[no_mangle]
+pub unsafe extern "C" fn xmlBufferResize(buf: *mut xmlBuffer, size: c_uint) -> c_int {
+    let Some(buf) = buf.as_mut() else {
+        return 0;
+    };
+    if size < buf.size {
+        return 1;
+    }
+    (core::buffer_grow(buf, size.wrapping_sub(buf.use_)) >= 0) as c_int
+}
+
+/// Append `len` bytes of `str_`, or all of it if `len` is negative.
+#[no_mangle]
+pub unsafe extern "C" fn xmlBufferAdd(
+    buf: *mut xmlBuffer,
+    str_: *const xmlChar,
+    len: c_int,
+) -> c_int {
+    if buf.is_null() || str_.is_null() {
+        return xmlParserErrors_XML_ERR_ARGUMENT as c_int;
+    }
+    let buf = &mut *buf;
+    let len = str_len(str_, len) as c_uint;
+    if len == 0 {
+        return xmlParserErrors_XML_ERR_OK as c_int;
+    }
+    // Note that both buf.size and buf.use can be zero here.
+    if len >= buf.size.wrapping_sub(buf.use_) && core::buffer_grow(buf, len) < 0 {
+        return xmlParserErrors_XML_ERR_NO_MEMORY as c_int;
+    }
+    ptr::copy(str_, buf.content.add(buf.use_ as usize), len as usize);
+    buf.use_ += len;
+    *buf.content.add(buf.use_ as usize) = 0;
+    xmlParserErrors_XML_ERR_OK as c_int
+}
+
+/// Insert `len` bytes of `str_` at the start of the buffer, or all of it
+/// if `len` is negative.
+#[no_mangle]
+pub unsafe extern "C" fn xmlBufferAddHead(
+    buf: *mut xmlBuffer,
+    str_: *const xmlChar,
+    len: c_int,
+) -> c_int {
+    if buf.is_null() || str_.is_null() {
+        return xmlParserErrors_XML_ERR_ARGUMENT as c_int;
+    }
+    let buf = &mut *buf;
+    let len = str_len(str_, len) as c_uint;
+    if len == 0 {
+        return xmlParserErrors_XML_ERR_OK as c_int;
+    }
+
+    if buf.alloc == xmlBufferAllocationScheme_XML_BUFFER_ALLOC_IO {
+        let start = buf.content.offset_from(buf.contentIO) as c_uint;
+        // We can add it in the space previously shrunk
+        if len <= start {
+            buf.content = buf.content.sub(len as usize);
+            ptr::copy(str_, buf.content, len as usize);
+            buf.use_ += len;
+            buf.size += len;
+            return 0;
+        }
+        if len < (buf.size + start).wrapping_sub(buf.use_) {
+            ptr::copy(
+                buf.content,
+                buf.contentIO.add(len as usize),
+                buf.use_ as usize + 1,
+            );
+            ptr::copy(str_, buf.contentIO, len as usize);
+            buf.content = buf.contentIO;
+            buf.use_ += len;
+            buf.size += start;
+            return 0;
+        }
+    }
+
+    if len >= buf.size.wrapping_sub(buf.use_) && core::buffer_grow(buf, len) < 0 {
+        return -1;
+    }
+    ptr::copy(
+        buf.content,
+        buf.content.add(len as usize),
+        buf.use_ as usize + 1,
+    );
+    ptr::copy(str_, buf.content, len as usize);
+    buf.use_ += len;
+    0
+}
+
+/// Append a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn xmlBufferCat(buf: *mut xmlBuffer, str_: *const xmlChar) -> c_int {
+    xmlBufferAdd(buf, str_, -1)
+}
+
+/// Append a NUL-terminated C string.
+#[no_mangle]
+pub unsafe extern "C" fn xmlBufferCCat(buf: *mut xmlBuffer, str_: *const c_char) -> c_int {
+    xmlBufferAdd(buf, str_ as *const xmlChar, -1)
+}
+
+/// Append a string, ignoring errors.
+#[no_mangle]
+pub unsafe extern "C" fn xmlBufferWriteCHAR(buf: *mut xmlBuffer, string: *const xmlChar) {
+    xmlBufferAdd(buf, string, -1);
+}
+
+/// Append a C string, ignoring errors.
+#[no_mangle]
+pub unsafe extern "C" fn xmlBufferWriteChar(buf: *mut xmlBuffer, string: *const c_char) {
+    xmlBufferAdd(buf, string as *const xmlChar, -1);
+}
+
+/// Append `string` as a quoted attribute value: in double quotes, in
+/// single quotes if it contains double quotes, and with `&quot;` if it
+/// contains both.
+#[no_mangle]
+pub unsafe extern "C" fn xmlBufferWriteQuotedString(buf: *mut xmlBuffer, string: *const xmlChar) {
+    if buf.is_null() {
+        return;
+    }
+    if string.is_null() {
+        xmlBufferCCat(buf, c"\"".as_ptr());
+        xmlBufferCCat(buf, c"\"".as_ptr());
+        return;
+    }
+    let bytes = CStr::from_ptr(string as *const c_char).to_bytes();
+    if !bytes.contains(&b'"') {
+        xmlBufferCCat(buf, c"\"".as_ptr());
+        xmlBufferCat(buf, string);
+        xmlBufferCCat(buf, c"\"".as_ptr());
+    } else if !bytes.contains(&b'\'') {
+        xmlBufferCCat(buf, c"'".as_ptr());
+        xmlBufferCat(buf, string);
+        xmlBufferCCat(buf, c"'".as_ptr());
+    } else {
+        xmlBufferCCat(buf, c"\"".as_ptr());
+        for (i, part) in bytes.split(|&c| c == b'"').enumerate() {
+            if i > 0 {
+                xmlBufferAdd(buf, c"&quot;".as_ptr() as *const xmlChar, 6);
+            }
+            if !part.is_empty() {
+                xmlBufferAdd(buf, part.as_ptr(), part.len() as c_int);
+            }
+        }
+        xmlBufferCCat(buf, c"\"".as_ptr());
+    }
+}
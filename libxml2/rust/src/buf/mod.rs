@@ -1 +1,8 @@
-// Empty stub module for buf
+//! Rust implementation of buf module
+//!
+//! Growable byte buffers behind `xmlBuf` and the legacy `xmlBuffer` API
+
+pub mod core;
+pub mod ffi;
+
+pub use core::*;
@@ -0,0 +1,784 @@
+#![allow(clippy::missing_safety_doc)]
+
+use std::cmp::Ordering;
+use std::ffi::CStr;
+use std::os::raw::{c_char, c_int, c_void};
+
+use crate::static_bindings::{
+    xmlAttrPtr, xmlC14NIsVisibleCallback, xmlChar, xmlDocPtr, xmlElementType_XML_ATTRIBUTE_DECL,
+    xmlElementType_XML_ATTRIBUTE_NODE, xmlElementType_XML_CDATA_SECTION_NODE,
+    xmlElementType_XML_COMMENT_NODE, xmlElementType_XML_DOCUMENT_FRAG_NODE,
+    xmlElementType_XML_DOCUMENT_NODE, xmlElementType_XML_DOCUMENT_TYPE_NODE,
+    xmlElementType_XML_DTD_NODE, xmlElementType_XML_ELEMENT_DECL, xmlElementType_XML_ELEMENT_NODE,
+    xmlElementType_XML_ENTITY_DECL, xmlElementType_XML_ENTITY_NODE,
+    xmlElementType_XML_ENTITY_REF_NODE, xmlElementType_XML_HTML_DOCUMENT_NODE,
+    xmlElementType_XML_NAMESPACE_DECL, xmlElementType_XML_NOTATION_NODE,
+    xmlElementType_XML_PI_NODE, xmlElementType_XML_TEXT_NODE, xmlElementType_XML_XINCLUDE_END,
+    xmlElementType_XML_XINCLUDE_START, xmlFree, xmlFreeURI, xmlNodeListGetString, xmlNodePtr,
+    xmlNodeSet, xmlNs, xmlNsPtr, xmlParseURI, xmlSearchNs, xmlXPathNodeSetContains,
+};
+
+const XML_XML_NAMESPACE: &[u8] = b"http://www.w3.org/XML/1998/namespace";
+
+/// Canonicalization methods, numbered like `xmlC14NMode`.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum C14NMode {
+    /// Canonical XML 1.0
+    Inclusive10 = 0,
+    /// Exclusive XML Canonicalization 1.0
+    Exclusive10 = 1,
+}
+
+impl C14NMode {
+    /// Map an `xmlC14NMode` value. Canonical XML 1.1 is not ported.
+    pub fn from_c(mode: c_int) -> Option<C14NMode> {
+        match mode {
+            0 => Some(C14NMode::Inclusive10),
+            1 => Some(C14NMode::Exclusive10),
+            _ => None,
+        }
+    }
+}
+
+/// Where the output is relative to the document element. Top-level PIs
+/// and comments get a line break on the side facing the document element.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Position {
+    Before,
+    Inside,
+    After,
+}
+
+/// Which characters `normalize` replaces with references.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Normalize {
+    Attr,
+    Comment,
+    Pi,
+    Text,
+}
+
+/// Escape `input` for canonical output: `&`, `<`, `>`, `"`, tab, line
+/// feed and carriage return, each only where the spec asks for it.
+pub fn normalize(input: &[u8], mode: Normalize, out: &mut Vec<u8>) {
+    let markup = matches!(mode, Normalize::Attr | Normalize::Text);
+    for &c in input {
+        match c {
+            b'<' if markup => out.extend_from_slice(b"&lt;"),
+            b'>' if mode == Normalize::Text => out.extend_from_slice(b"&gt;"),
+            b'&' if markup => out.extend_from_slice(b"&amp;"),
+            b'"' if mode == Normalize::Attr => out.extend_from_slice(b"&quot;"),
+            b'\t' if mode == Normalize::Attr => out.extend_from_slice(b"&#x9;"),
+            b'\n' if mode == Normalize::Attr => out.extend_from_slice(b"&#xA;"),
+            b'\r' => out.extend_from_slice(b"&#xD;"),
+            _ => out.push(c),
+        }
+    }
+}
+
+/// Bytes of a possibly null string.
+unsafe fn opt<'a>(s: *const xmlChar) -> Option<&'a [u8]> {
+    if s.is_null() {
+        None
+    } else {
+        Some(CStr::from_ptr(s as *const c_char).to_bytes())
+    }
+}
+
+/// Bytes of a string where null means empty.
+unsafe fn bytes<'a>(s: *const xmlChar) -> &'a [u8] {
+    opt(s).unwrap_or(b"")
+}
+
+/// Prefix of `ns`; a null namespace is the empty default namespace.
+unsafe fn ns_prefix<'a>(ns: xmlNsPtr) -> &'a [u8] {
+    if ns.is_null() {
+        b""
+    } else {
+        bytes((*ns).prefix)
+    }
+}
+
+unsafe fn ns_href<'a>(ns: xmlNsPtr) -> &'a [u8] {
+    if ns.is_null() {
+        b""
+    } else {
+        bytes((*ns).href)
+    }
+}
+
+/// Whether `ns` binds the `xml` prefix to its reserved URI.
+unsafe fn is_xml_ns(ns: xmlNsPtr) -> bool {
+    !ns.is_null() && opt((*ns).prefix) == Some(b"xml") && opt((*ns).href) == Some(XML_XML_NAMESPACE)
+}
+
+unsafe fn is_xml_attr(attr: xmlAttrPtr) -> bool {
+    is_xml_ns((*attr).ns)
+}
+
+/// Namespaces sort by prefix, the default namespace first.
+unsafe fn ns_compare(ns1: xmlNsPtr, ns2: xmlNsPtr) -> Ordering {
+    if ns1 == ns2 {
+        return Ordering::Equal;
+    }
+    opt((*ns1).prefix).cmp(&opt((*ns2).prefix))
+}
+
+/// Attributes sort by namespace URI and then local name. Unqualified
+/// attributes come first.
+unsafe fn attr_compare(attr1: xmlAttrPtr, attr2: xmlAttrPtr) -> Ordering {
+    if attr1 == attr2 {
+        return Ordering::Equal;
+    }
+    let (ns1, ns2) = ((*attr1).ns, (*attr2).ns);
+    if ns1 == ns2 {
+        return opt((*attr1).name).cmp(&opt((*attr2).name));
+    }
+    if ns1.is_null() {
+        return Ordering::Less;
+    }
+    if ns2.is_null() {
+        return Ordering::Greater;
+    }
+    if (*ns1).prefix.is_null() {
+        return Ordering::Less;
+    }
+    if (*ns2).prefix.is_null() {
+        return Ordering::Greater;
+    }
+    opt((*ns1).href)
+        .cmp(&opt((*ns2).href))
+        .then_with(|| opt((*attr1).name).cmp(&opt((*attr2).name)))
+}
+
+/// Insert `item` before the first entry that is not less than it, like
+/// `xmlListInsert` on a sorted list.
+fn insert_sorted<T: Copy>(list: &mut Vec<T>, item: T, cmp: impl Fn(T, T) -> Ordering) {
+    let pos = list
+        .iter()
+        .position(|&x| cmp(x, item) != Ordering::Less)
+        .unwrap_or(list.len());
+    list.insert(pos, item);
+}
+
+/// Visibility callback for an XPath node-set, passed as `user_data`.
+/// Namespace nodes are looked up through a copy whose `next` points at
+/// the owning element, which is how XPath stores them in node-sets.
+pub unsafe extern "C" fn is_node_in_nodeset(
+    user_data: *mut c_void,
+    node: xmlNodePtr,
+    parent: xmlNodePtr,
+) -> c_int {
+    let nodes = user_data as *mut xmlNodeSet;
+    if nodes.is_null() || node.is_null() {
+        return 1;
+    }
+    if (*node).type_ != xmlElementType_XML_NAMESPACE_DECL {
+        return xmlXPathNodeSetContains(nodes, node);
+    }
+
+    let mut ns: xmlNs = *(node as xmlNsPtr);
+    ns.next = if !parent.is_null() && (*parent).type_ == xmlElementType_XML_ATTRIBUTE_NODE {
+        (*parent).parent as xmlNsPtr
+    } else {
+        parent as xmlNsPtr
+    };
+    xmlXPathNodeSetContains(nodes, &mut ns as *mut xmlNs as xmlNodePtr)
+}
+
+/// Saved extent of the visible namespace stack.
+#[derive(Debug, Clone, Copy)]
+struct NsStackState {
+    cur_end: usize,
+    prev_start: usize,
+    prev_end: usize,
+}
+
+/// Namespaces rendered by output ancestors, with the element each one
+/// was rendered on. `prev_start..prev_end` covers the nearest visible
+/// ancestor.
+#[derive(Default)]
+struct VisibleNsStack {
+    ns_tab: Vec<xmlNsPtr>,
+    node_tab: Vec<xmlNodePtr>,
+    prev_start: usize,
+    prev_end: usize,
+}
+
+impl VisibleNsStack {
+    fn add(&mut self, ns: xmlNsPtr, node: xmlNodePtr) {
+        self.ns_tab.push(ns);
+        self.node_tab.push(node);
+    }
+
+    fn save(&self) -> NsStackState {
+        NsStackState {
+            cur_end: self.ns_tab.len(),
+            prev_start: self.prev_start,
+            prev_end: self.prev_end,
+        }
+    }
+
+    fn restore(&mut self, state: NsStackState) {
+        self.ns_tab.truncate(state.cur_end);
+        self.node_tab.truncate(state.cur_end);
+        self.prev_start = state.prev_start;
+        self.prev_end = state.prev_end;
+    }
+
+    fn shift(&mut self) {
+        self.prev_start = self.prev_end;
+        self.prev_end = self.ns_tab.len();
+    }
+
+    /// Whether the binding in `ns` is already in scope in the output. The
+    /// empty default namespace counts as rendered until some ancestor
+    /// declares a default namespace.
+    unsafe fn find(&self, ns: xmlNsPtr) -> bool {
+        let prefix = ns_prefix(ns);
+        let href = ns_href(ns);
+        let has_empty_ns = prefix.is_empty() && href.is_empty();
+
+        let start = if has_empty_ns { 0 } else { self.prev_start };
+        for &ns1 in self.ns_tab[start.min(self.ns_tab.len())..].iter().rev() {
+            if prefix == ns_prefix(ns1) {
+                return href == ns_href(ns1);
+            }
+        }
+        has_empty_ns
+    }
+}
+
+/// State for one canonicalization run. Output is collected in memory.
+pub struct Canonicalizer {
+    doc: xmlDocPtr,
+    is_visible: xmlC14NIsVisibleCallback,
+    user_data: *mut c_void,
+    mode: C14NMode,
+    inclusive_ns_prefixes: Vec<Vec<u8>>,
+    with_comments: bool,
+    pos: Position,
+    parent_is_doc: bool,
+    ns_rendered: VisibleNsStack,
+    out: Vec<u8>,
+}
+
+impl Canonicalizer {
+    /// `is_visible` decides which nodes are output; without a callback the
+    /// whole document is. `inclusive_ns_prefixes` only matters for
+    /// exclusive canonicalization, where `#default` or an empty string
+    /// names the default namespace. Comments are only output if
+    /// `with_comments` is set.
+    pub fn new(
+        doc: xmlDocPtr,
+        is_visible: xmlC14NIsVisibleCallback,
+        user_data: *mut c_void,
+        mode: C14NMode,
+        inclusive_ns_prefixes: Vec<Vec<u8>>,
+        with_comments: bool,
+    ) -> Canonicalizer {
+        let inclusive_ns_prefixes = match mode {
+            C14NMode::Exclusive10 => inclusive_ns_prefixes,
+            C14NMode::Inclusive10 => Vec::new(),
+        };
+        Canonicalizer {
+            doc,
+            is_visible,
+            user_data,
+            mode,
+            inclusive_ns_prefixes,
+            with_comments,
+            pos: Position::Before,
+            parent_is_doc: true,
+            ns_rendered: VisibleNsStack::default(),
+            out: Vec::new(),
+        }
+    }
+
+    /// Canonicalize the document. Returns `None` if it contains a node
+    /// that cannot be canonicalized or a relative namespace URI.
+    pub unsafe fn canonicalize(mut self) -> Option<Vec<u8>> {
+        if self.doc.is_null() {
+            return None;
+        }
+        if self.process_node_list((*self.doc).children) < 0 {
+            return None;
+        }
+        Some(self.out)
+    }
+
+    unsafe fn visible<N, P>(&self, node: *mut N, parent: *mut P) -> bool {
+        match self.is_visible {
+            Some(cb) => cb(self.user_data, node as xmlNodePtr, parent as xmlNodePtr) != 0,
+            None => true,
+        }
+    }
+
+    fn is_exclusive(&self) -> bool {
+        self.mode == C14NMode::Exclusive10
+    }
+
+    /// Like `VisibleNsStack::find`, but a match rendered on another
+    /// element only counts if that namespace node is visible there.
+    unsafe fn exc_find(&self, ns: xmlNsPtr) -> bool {
+        let prefix = ns_prefix(ns);
+        let href = ns_href(ns);
+        let has_empty_ns = prefix.is_empty() && href.is_empty();
+
+        let stack = &self.ns_rendered;
+        for i in (0..stack.ns_tab.len()).rev() {
+            let ns1 = stack.ns_tab[i];
+            if prefix == ns_prefix(ns1) {
+                return href == ns_href(ns1) && self.visible(ns1, stack.node_tab[i]);
+            }
+        }
+        has_empty_ns
+    }
+
+    fn write_quoted(&mut self, value: &[u8]) {
+        if value.contains(&b'"') {
+            if value.contains(&b'\'') {
+                self.out.push(b'"');
+                for &c in value {
+                    if c == b'"' {
+                        self.out.extend_from_slice(b"&quot;");
+                    } else {
+                        self.out.push(c);
+                    }
+                }
+                self.out.push(b'"');
+            } else {
+                self.out.push(b'\'');
+                self.out.extend_from_slice(value);
+                self.out.push(b'\'');
+            }
+        } else {
+            self.out.push(b'"');
+            self.out.extend_from_slice(value);
+            self.out.push(b'"');
+        }
+    }
+
+    /// Write a namespace declaration; null is the empty default namespace.
+    unsafe fn print_namespace(&mut self, ns: xmlNsPtr) {
+        let (prefix, href) = if ns.is_null() {
+            (None, None)
+        } else {
+            (opt((*ns).prefix), opt((*ns).href))
+        };
+        match prefix {
+            Some(prefix) => {
+                self.out.extend_from_slice(b" xmlns:");
+                self.out.extend_from_slice(prefix);
+                self.out.push(b'=');
+            }
+            None => self.out.extend_from_slice(b" xmlns="),
+        }
+        match href {
+            Some(href) => self.write_quoted(href),
+            None => self.out.extend_from_slice(b"\"\""),
+        }
+    }
+
+    unsafe fn print_attr(&mut self, attr: xmlAttrPtr) {
+        self.out.push(b' ');
+        let ns = (*attr).ns;
+        if !ns.is_null() && !ns_prefix(ns).is_empty() {
+            self.out.extend_from_slice(ns_prefix(ns));
+            self.out.push(b':');
+        }
+        self.out.extend_from_slice(bytes((*attr).name));
+        self.out.extend_from_slice(b"=\"");
+
+        let value = xmlNodeListGetString(self.doc, (*attr).children, 1);
+        if !value.is_null() {
+            normalize(bytes(value), Normalize::Attr, &mut self.out);
+            xmlFree.unwrap()(value as *mut c_void);
+        }
+        self.out.push(b'"');
+    }
+
+    /// Canonical XML namespace axis: every in-scope namespace node not
+    /// yet rendered by an output ancestor, plus `xmlns=""` when an output
+    /// ancestor had a non-empty default namespace.
+    unsafe fn process_namespaces_axis(&mut self, cur: xmlNodePtr, visible: bool) {
+        let mut list: Vec<xmlNsPtr> = Vec::new();
+        let mut has_empty_ns = false;
+
+        let mut n = cur;
+        while !n.is_null() && (*n).type_ == xmlElementType_XML_ELEMENT_NODE {
+            let mut ns = (*n).nsDef;
+            while !ns.is_null() {
+                let tmp = xmlSearchNs((*cur).doc, cur, (*ns).prefix);
+                if tmp == ns && !is_xml_ns(ns) && self.visible(ns, cur) {
+                    let already_rendered = self.ns_rendered.find(ns);
+                    if visible {
+                        self.ns_rendered.add(ns, cur);
+                    }
+                    if !already_rendered {
+                        insert_sorted(&mut list, ns, |a, b| ns_compare(a, b));
+                    }
+                    if ns_prefix(ns).is_empty() {
+                        has_empty_ns = true;
+                    }
+                }
+                ns = (*ns).next;
+            }
+            n = (*n).parent;
+        }
+
+        if visible && !has_empty_ns && !self.ns_rendered.find(std::ptr::null_mut()) {
+            self.print_namespace(std::ptr::null_mut());
+        }
+        for ns in list {
+            self.print_namespace(ns);
+        }
+    }
+
+    /// Exclusive namespace axis: only namespaces visibly utilized by the
+    /// element or its attributes, or listed as inclusive prefixes, and
+    /// only if an output ancestor has not rendered the same binding.
+    unsafe fn exc_process_namespaces_axis(&mut self, cur: xmlNodePtr, visible: bool) {
+        let mut list: Vec<xmlNsPtr> = Vec::new();
+        let mut has_empty_ns = false;
+        let mut has_visibly_utilized_empty_ns = false;
+        let mut has_empty_ns_in_inclusive_list = false;
+
+        // Namespaces on the inclusive list are handled as in Canonical XML.
+        let prefixes = std::mem::take(&mut self.inclusive_ns_prefixes);
+        for prefix in &prefixes {
+            let mut lookup = prefix.clone();
+            let name = if prefix.is_empty() || prefix == b"#default" {
+                has_empty_ns_in_inclusive_list = true;
+                std::ptr::null()
+            } else {
+                lookup.push(0);
+                lookup.as_ptr()
+            };
+
+            let ns = xmlSearchNs((*cur).doc, cur, name);
+            if !ns.is_null() && !is_xml_ns(ns) && self.visible(ns, cur) {
+                let already_rendered = self.ns_rendered.find(ns);
+                if visible {
+                    self.ns_rendered.add(ns, cur);
+                }
+                if !already_rendered {
+                    insert_sorted(&mut list, ns, |a, b| ns_compare(a, b));
+                }
+                if ns_prefix(ns).is_empty() {
+                    has_empty_ns = true;
+                }
+            }
+        }
+        self.inclusive_ns_prefixes = prefixes;
+
+        // The element's own namespace.
+        let ns = if !(*cur).ns.is_null() {
+            (*cur).ns
+        } else {
+            has_visibly_utilized_empty_ns = true;
+            xmlSearchNs((*cur).doc, cur, std::ptr::null())
+        };
+        if !ns.is_null() && !is_xml_ns(ns) {
+            if visible && self.visible(ns, cur) && !self.exc_find(ns) {
+                insert_sorted(&mut list, ns, |a, b| ns_compare(a, b));
+            }
+            if visible {
+                self.ns_rendered.add(ns, cur);
+            }
+            if ns_prefix(ns).is_empty() {
+                has_empty_ns = true;
+            }
+        }
+
+        // Namespaces of attributes. The default namespace never applies
+        // to attributes.
+        let mut attr = (*cur).properties;
+        while !attr.is_null() {
+            let ns = (*attr).ns;
+            if !ns.is_null() && !is_xml_ns(ns) && self.visible(attr, cur) {
+                let already_rendered = self.exc_find(ns);
+                self.ns_rendered.add(ns, cur);
+                if !already_rendered && visible {
+                    insert_sorted(&mut list, ns, |a, b| ns_compare(a, b));
+                }
+                if ns_prefix(ns).is_empty() {
+                    has_empty_ns = true;
+                }
+            } else if !ns.is_null() && ns_prefix(ns).is_empty() && ns_href(ns).is_empty() {
+                has_visibly_utilized_empty_ns = true;
+            }
+            attr = (*attr).next;
+        }
+
+        let ns_default = std::ptr::null_mut();
+        if visible
+            && has_visibly_utilized_empty_ns
+            && !has_empty_ns
+            && !has_empty_ns_in_inclusive_list
+        {
+            if !self.exc_find(ns_default) {
+                self.print_namespace(ns_default);
+            }
+        } else if visible
+            && !has_empty_ns
+            && has_empty_ns_in_inclusive_list
+            && !self.ns_rendered.find(ns_default)
+        {
+            self.print_namespace(ns_default);
+        }
+
+        for ns in list {
+            self.print_namespace(ns);
+        }
+    }
+
+    /// Attribute axis in sorted order. For Canonical XML, an element
+    /// whose parent is not output also inherits the `xml:*` attributes of
+    /// its ancestors that it does not override.
+    unsafe fn process_attrs_axis(&mut self, cur: xmlNodePtr, parent_visible: bool) {
+        let mut list: Vec<xmlAttrPtr> = Vec::new();
+
+        let mut attr = (*cur).properties;
+        while !attr.is_null() {
+            if self.visible(attr, cur) {
+                insert_sorted(&mut list, attr, |a, b| attr_compare(a, b));
+            }
+            attr = (*attr).next;
+        }
+
+        let parent = (*cur).parent;
+        if !self.is_exclusive()
+            && parent_visible
+            && !parent.is_null()
+            && !self.visible(parent, (*parent).parent)
+        {
+            let mut tmp = parent;
+            while !tmp.is_null() && (*tmp).type_ == xmlElementType_XML_ELEMENT_NODE {
+                let mut attr = (*tmp).properties;
+                while !attr.is_null() {
+                    if is_xml_attr(attr)
+                        && !list
+                            .iter()
+                            .any(|&a| attr_compare(a, attr) == Ordering::Equal)
+                    {
+                        insert_sorted(&mut list, attr, |a, b| attr_compare(a, b));
+                    }
+                    attr = (*attr).next;
+                }
+                tmp = (*tmp).parent;
+            }
+        }
+
+        for attr in list {
+            self.print_attr(attr);
+        }
+    }
+
+    /// Canonicalization must fail on namespace URIs without a scheme.
+    unsafe fn has_relative_namespaces(&self, cur: xmlNodePtr) -> bool {
+        let mut ns = (*cur).nsDef;
+        while !ns.is_null() {
+            if !ns_href(ns).is_empty() {
+                let uri = xmlParseURI((*ns).href as *const c_char);
+                if uri.is_null() {
+                    return true;
+                }
+                let relative = bytes((*uri).scheme as *const xmlChar).is_empty();
+                xmlFreeURI(uri);
+                if relative {
+                    return true;
+                }
+            }
+            ns = (*ns).next;
+        }
+        false
+    }
+
+    unsafe fn write_qname(&mut self, cur: xmlNodePtr) {
+        let ns = (*cur).ns;
+        if !ns.is_null() && !ns_prefix(ns).is_empty() {
+            self.out.extend_from_slice(ns_prefix(ns));
+            self.out.push(b':');
+        }
+        self.out.extend_from_slice(bytes((*cur).name));
+    }
+
+    /// An element that is not output still contributes its namespace and
+    /// attribute axes to the rendering state and has its children
+    /// processed.
+    unsafe fn process_element_node(&mut self, cur: xmlNodePtr, visible: bool) -> c_int {
+        if self.has_relative_namespaces(cur) {
+            return -1;
+        }
+
+        let state = self.ns_rendered.save();
+        let mut parent_is_doc = false;
+
+        if visible {
+            if self.parent_is_doc {
+                parent_is_doc = true;
+                self.parent_is_doc = false;
+                self.pos = Position::Inside;
+            }
+            self.out.push(b'<');
+            self.write_qname(cur);
+        }
+
+        if self.is_exclusive() {
+            self.exc_process_namespaces_axis(cur, visible);
+        } else {
+            self.process_namespaces_axis(cur, visible);
+        }
+        if visible {
+            self.ns_rendered.shift();
+        }
+
+        self.process_attrs_axis(cur, visible);
+
+        if visible {
+            self.out.push(b'>');
+        }
+        if !(*cur).children.is_null() && self.process_node_list((*cur).children) < 0 {
+            return -1;
+        }
+        if visible {
+            self.out.extend_from_slice(b"</");
+            self.write_qname(cur);
+            self.out.push(b'>');
+            if parent_is_doc {
+                self.parent_is_doc = true;
+                self.pos = Position::After;
+            }
+        }
+
+        self.ns_rendered.restore(state);
+        0
+    }
+
+    #[allow(non_upper_case_globals)]
+    unsafe fn process_node(&mut self, cur: xmlNodePtr) -> c_int {
+        let visible = self.visible(cur, (*cur).parent);
+        match (*cur).type_ {
+            xmlElementType_XML_ELEMENT_NODE => return self.process_element_node(cur, visible),
+            xmlElementType_XML_CDATA_SECTION_NODE | xmlElementType_XML_TEXT_NODE => {
+                if visible && !(*cur).content.is_null() {
+                    normalize(bytes((*cur).content), Normalize::Text, &mut self.out);
+                }
+            }
+            xmlElementType_XML_PI_NODE => {
+                if visible {
+                    if self.pos == Position::After {
+                        self.out.extend_from_slice(b"\n<?");
+                    } else {
+                        self.out.extend_from_slice(b"<?");
+                    }
+                    self.out.extend_from_slice(bytes((*cur).name));
+                    let content = bytes((*cur).content);
+                    if !content.is_empty() {
+                        self.out.push(b' ');
+                        normalize(content, Normalize::Pi, &mut self.out);
+                    }
+                    if self.pos == Position::Before {
+                        self.out.extend_from_slice(b"?>\n");
+                    } else {
+                        self.out.extend_from_slice(b"?>");
+                    }
+                }
+            }
+            xmlElementType_XML_COMMENT_NODE => {
+                if visible && self.with_comments {
+                    if self.pos == Position::After {
+                        self.out.extend_from_slice(b"\n<!--");
+                    } else {
+                        self.out.extend_from_slice(b"<!--");
+                    }
+                    normalize(bytes((*cur).content), Normalize::Comment, &mut self.out);
+                    if self.pos == Position::Before {
+                        self.out.extend_from_slice(b"-->\n");
+                    } else {
+                        self.out.extend_from_slice(b"-->");
+                    }
+                }
+            }
+            xmlElementType_XML_DOCUMENT_NODE
+            | xmlElementType_XML_DOCUMENT_FRAG_NODE
+            | xmlElementType_XML_HTML_DOCUMENT_NODE => {
+                if !(*cur).children.is_null() {
+                    self.pos = Position::Before;
+                    self.parent_is_doc = true;
+                    return self.process_node_list((*cur).children);
+                }
+            }
+            xmlElementType_XML_ATTRIBUTE_NODE
+            | xmlElementType_XML_NAMESPACE_DECL
+            | xmlElementType_XML_ENTITY_REF_NODE
+            | xmlElementType_XML_ENTITY_NODE => return -1,
+            // Nothing from the document type declaration is output.
+            xmlElementType_XML_DOCUMENT_TYPE_NODE
+            | xmlElementType_XML_NOTATION_NODE
+            | xmlElementType_XML_DTD_NODE
+            | xmlElementType_XML_ELEMENT_DECL
+            | xmlElementType_XML_ATTRIBUTE_DECL
+            | xmlElementType_XML_ENTITY_DECL
+            | xmlElementType_XML_XINCLUDE_START
+            | xmlElementType_XML_XINCLUDE_END => {}
+            _ => return -1,
+        }
+        0
+    }
+
+    unsafe fn process_node_list(&mut self, mut cur: xmlNodePtr) -> c_int {
+        let mut ret = 0;
+        while !cur.is_null() && ret >= 0 {
+            ret = self.process_node(cur);
+            cur = (*cur).next;
+        }
+        ret
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn normalized(input: &[u8], mode: Normalize) -> String {
+        let mut out = Vec::new();
+        normalize(input, mode, &mut out);
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_normalize() {
+        let input = b"<a> & \"b\"\t\n\r";
+        assert_eq!(
+            normalized(input, Normalize::Text),
+            "&lt;a&gt; &amp; \"b\"\t\n&#xD;"
+        );
+        assert_eq!(
+            normalized(input, Normalize::Attr),
+            "&lt;a> &amp; &quot;b&quot;&#x9;&#xA;&#xD;"
+        );
+        assert_eq!(normalized(input, Normalize::Pi), "<a> & \"b\"\t\n&#xD;");
+        assert_eq!(
+            normalized(input, Normalize::Comment),
+            "<a> & \"b\"\t\n&#xD;"
+        );
+    }
+
+    #[test]
+    fn test_insert_sorted_keeps_later_equal_first() {
+        let mut list = vec![(1, 'a'), (3, 'a')];
+        insert_sorted(&mut list, (3, 'b'), |x, y| x.0.cmp(&y.0));
+        insert_sorted(&mut list, (2, 'a'), |x, y| x.0.cmp(&y.0));
+        assert_eq!(list, vec![(1, 'a'), (2, 'a'), (3, 'b'), (3, 'a')]);
+    }
+
+    #[test]
+    fn test_mode_from_c() {
+        assert_eq!(C14NMode::from_c(0), Some(C14NMode::Inclusive10));
+        assert_eq!(C14NMode::from_c(1), Some(C14NMode::Exclusive10));
+        assert_eq!(C14NMode::from_c(2), None);
+    }
+}
@@ -0,0 +1,180 @@
+#![allow(clippy::missing_safety_doc)]
+
+use std::ffi::CStr;
+use std::os::raw::{c_char, c_int, c_void};
+use std::ptr;
+
+use super::core::{is_node_in_nodeset, C14NMode, Canonicalizer};
+use crate::static_bindings::{
+    xmlC14NIsVisibleCallback, xmlChar, xmlDocPtr, xmlGetCompressMode, xmlNodeSet, xmlOutputBuffer,
+    xmlOutputBufferClose, xmlOutputBufferCreateFilename, xmlOutputBufferFlush,
+    xmlOutputBufferWrite, xmlStrndup,
+};
+
+// Ensure symbols are exported for linking
+#[cfg(feature = "rust-c14n")]
+#[used]
+static C14N_FFI_LINKAGE: () = ();
+
+/// Copy a NULL-terminated list of prefixes.
+unsafe fn prefix_list(prefixes: *mut *mut xmlChar) -> Vec<Vec<u8>> {
+    let mut list = Vec::new();
+    if prefixes.is_null() {
+        return list;
+    }
+    let mut p = prefixes;
+    while !(*p).is_null() {
+        list.push(CStr::from_ptr(*p as *const c_char).to_bytes().to_vec());
+        p = p.add(1);
+    }
+    list
+}
+
+unsafe fn canonicalize(
+    doc: xmlDocPtr,
+    is_visible_callback: xmlC14NIsVisibleCallback,
+    user_data: *mut c_void,
+    mode: c_int,
+    inclusive_ns_prefixes: *mut *mut xmlChar,
+    with_comments: c_int,
+) -> Option<Vec<u8>> {
+    if doc.is_null() {
+        return None;
+    }
+    let mode = C14NMode::from_c(mode)?;
+    let c14n = Canonicalizer::new(
+        doc,
+        is_visible_callback,
+        user_data,
+        mode,
+        prefix_list(inclusive_ns_prefixes),
+        with_comments != 0,
+    );
+    c14n.canonicalize()
+}
+
+/// Dumps the canonized image of given XML document into the provided
+/// buffer, which must not have an encoder. Returns the number of bytes
+/// flushed or -1 on failure.
+#[no_mangle]
+pub unsafe extern "C" fn xmlC14NExecute(
+    doc: xmlDocPtr,
+    is_visible_callback: xmlC14NIsVisibleCallback,
+    user_data: *mut c_void,
+    mode: c_int,
+    inclusive_ns_prefixes: *mut *mut xmlChar,
+    with_comments: c_int,
+    buf: *mut xmlOutputBuffer,
+) -> c_int {
+    if buf.is_null() || !(*buf).encoder.is_null() {
+        return -1;
+    }
+    let Some(out) = canonicalize(
+        doc,
+        is_visible_callback,
+        user_data,
+        mode,
+        inclusive_ns_prefixes,
+        with_comments,
+    ) else {
+        return -1;
+    };
+
+    if !out.is_empty()
+        && xmlOutputBufferWrite(buf, out.len() as c_int, out.as_ptr() as *const c_char) < 0
+    {
+        return -1;
+    }
+    let ret = xmlOutputBufferFlush(buf);
+    if ret < 0 {
+        return -1;
+    }
+    ret
+}
+
+/// Dumps the canonized image of given XML document into the provided
+/// buffer. `nodes` selects the nodes to output, or NULL for all.
+#[no_mangle]
+pub unsafe extern "C" fn xmlC14NDocSaveTo(
+    doc: xmlDocPtr,
+    nodes: *mut xmlNodeSet,
+    mode: c_int,
+    inclusive_ns_prefixes: *mut *mut xmlChar,
+    with_comments: c_int,
+    buf: *mut xmlOutputBuffer,
+) -> c_int {
+    xmlC14NExecute(
+        doc,
+        Some(is_node_in_nodeset),
+        nodes as *mut c_void,
+        mode,
+        inclusive_ns_prefixes,
+        with_comments,
+        buf,
+    )
+}
+
+/// Dumps the canonized image of given XML document into memory. The
+/// caller must free `*doc_txt_ptr`. Returns the number of bytes written
+/// or -1 on failure.
+#[no_mangle]
+pub unsafe extern "C" fn xmlC14NDocDumpMemory(
+    doc: xmlDocPtr,
+    nodes: *mut xmlNodeSet,
+    mode: c_int,
+    inclusive_ns_prefixes: *mut *mut xmlChar,
+    with_comments: c_int,
+    doc_txt_ptr: *mut *mut xmlChar,
+) -> c_int {
+    if doc_txt_ptr.is_null() {
+        return -1;
+    }
+    *doc_txt_ptr = ptr::null_mut();
+
+    let Some(out) = canonicalize(
+        doc,
+        Some(is_node_in_nodeset),
+        nodes as *mut c_void,
+        mode,
+        inclusive_ns_prefixes,
+        with_comments,
+    ) else {
+        return -1;
+    };
+
+    *doc_txt_ptr = xmlStrndup(out.as_ptr(), out.len() as c_int);
+    if (*doc_txt_ptr).is_null() {
+        return -1;
+    }
+    out.len() as c_int
+}
+
+/// Dumps the canonized image of given XML document into the file.
+/// Returns the number of bytes written or -1 on failure.
+#[no_mangle]
+pub unsafe extern "C" fn xmlC14NDocSave(
+    doc: xmlDocPtr,
+    nodes: *mut xmlNodeSet,
+    mode: c_int,
+    inclusive_ns_prefixes: *mut *mut xmlChar,
+    with_comments: c_int,
+    filename: *const c_char,
+    mut compression: c_int,
+) -> c_int {
+    if filename.is_null() {
+        return -1;
+    }
+    if compression < 0 {
+        compression = xmlGetCompressMode();
+    }
+
+    let buf = xmlOutputBufferCreateFilename(filename, ptr::null_mut(), compression);
+    if buf.is_null() {
+        return -1;
+    }
+    if xmlC14NDocSaveTo(doc, nodes, mode, inclusive_ns_prefixes, with_comments, buf) < 0 {
+        xmlOutputBufferClose(buf);
+        return -1;
+    }
+    xmlOutputBufferClose(buf)
+}
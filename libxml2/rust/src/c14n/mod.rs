@@ -1 +1,8 @@
-// Empty stub module for c14n
+//! Rust implementation of c14n module
+//!
+//! Canonical XML 1.0 and Exclusive XML Canonicalization 1.0
+
+pub mod core;
+pub mod ffi;
+
+pub use core::*;
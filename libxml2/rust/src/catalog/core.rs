@@ -0,0 +1,1055 @@
+#![allow(clippy::missing_safety_doc)]
+
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::ffi::{CStr, CString};
+use std::os::raw::{c_char, c_int, c_void};
+use std::ptr;
+use std::sync::atomic::{AtomicBool, AtomicI32, AtomicU32, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+
+use crate::static_bindings::{
+    xmlBuildURI, xmlCatalogAllow, xmlCatalogAllow_XML_CATA_ALLOW_ALL, xmlCatalogPrefer,
+    xmlCatalogPrefer_XML_CATA_PREFER_NONE, xmlCatalogPrefer_XML_CATA_PREFER_PUBLIC,
+    xmlCatalogPrefer_XML_CATA_PREFER_SYSTEM, xmlChar, xmlDocGetRootElement, xmlDocPtr,
+    xmlErrorDomain_XML_FROM_CATALOG, xmlErrorLevel_XML_ERR_ERROR, xmlFree, xmlFreeDoc,
+    xmlGenericErrorFunc, xmlGetProp, xmlInitParser, xmlNodeGetBase, xmlNodePtr,
+    xmlParserErrors_XML_CATALOG_ENTRY_BROKEN, xmlParserErrors_XML_CATALOG_MISSING_ATTR,
+    xmlParserErrors_XML_CATALOG_NOT_CATALOG, xmlParserErrors_XML_CATALOG_PREFER_VALUE,
+    xmlParserErrors_XML_CATALOG_RECURSION, xmlParserOption_XML_PARSE_NO_SYS_CATALOG, xmlReadFile,
+    xmlStructuredErrorFunc,
+};
+
+extern "C" {
+    // Private in error.c
+    fn xmlRaiseError(
+        schannel: xmlStructuredErrorFunc,
+        channel: xmlGenericErrorFunc,
+        data: *mut c_void,
+        ctx: *mut c_void,
+        node: *mut c_void,
+        domain: c_int,
+        code: c_int,
+        level: c_int,
+        file: *const c_char,
+        line: c_int,
+        str1: *const c_char,
+        str2: *const c_char,
+        str3: *const c_char,
+        int1: c_int,
+        col: c_int,
+        msg: *const c_char,
+        ...
+    ) -> c_int;
+    fn xmlPrintErrorMessage(fmt: *const c_char, ...);
+}
+
+/// The namespace of catalog elements, `XML_CATALOGS_NAMESPACE`.
+pub const CATALOGS_NAMESPACE: &[u8] = b"urn:oasis:names:tc:entity:xmlns:xml:catalog";
+/// The processing instruction naming a document's own catalog,
+/// `XML_CATALOG_PI`.
+pub const CATALOG_PI: &[u8] = b"oasis-xml-catalog";
+
+/// The most delegate catalogs one lookup tries.
+const MAX_DELEGATE: usize = 50;
+/// How deep catalogs may refer to each other before it is taken for a
+/// loop.
+const MAX_CATAL_DEPTH: i32 = 50;
+/// How public identifiers are wrapped into URNs, RFC 3151.
+const URN_PUBID: &[u8] = b"urn:publicid:";
+/// The longest identifier a URN unwraps to, as the buffer in C holds.
+const MAX_URN_LENGTH: usize = 1996;
+
+/// The catalogs used when `XML_CATALOG_FILES` is not set,
+/// `XML_XML_DEFAULT_CATALOG`.
+fn default_catalog_files() -> String {
+    format!("file://{}/xml/catalog", env!("LIBXML2_SYSCONFDIR"))
+}
+
+/// The kind of a catalog entry, the XML ones of `xmlCatalogEntryType`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EntryKind {
+    /// A catalog file, as in the default and document lists.
+    Catalog,
+    NextCatalog,
+    Public,
+    System,
+    RewriteSystem,
+    DelegatePublic,
+    DelegateSystem,
+    Uri,
+    RewriteUri,
+    DelegateUri,
+}
+
+impl EntryKind {
+    /// The kind of the catalog element `name`, with the attributes holding
+    /// the identifier matched, if any, and the URI reference.
+    fn of_element(name: &[u8]) -> Option<(EntryKind, Option<&'static CStr>, &'static CStr)> {
+        Some(match name {
+            b"public" => (EntryKind::Public, Some(c"publicId"), c"uri"),
+            b"system" => (EntryKind::System, Some(c"systemId"), c"uri"),
+            b"rewriteSystem" => (
+                EntryKind::RewriteSystem,
+                Some(c"systemIdStartString"),
+                c"rewritePrefix",
+            ),
+            b"delegatePublic" => (
+                EntryKind::DelegatePublic,
+                Some(c"publicIdStartString"),
+                c"catalog",
+            ),
+            b"delegateSystem" => (
+                EntryKind::DelegateSystem,
+                Some(c"systemIdStartString"),
+                c"catalog",
+            ),
+            b"uri" => (EntryKind::Uri, Some(c"name"), c"uri"),
+            b"rewriteURI" => (
+                EntryKind::RewriteUri,
+                Some(c"uriStartString"),
+                c"rewritePrefix",
+            ),
+            b"delegateURI" => (EntryKind::DelegateUri, Some(c"uriStartString"), c"catalog"),
+            b"nextCatalog" => (EntryKind::NextCatalog, None, c"catalog"),
+            _ => return None,
+        })
+    }
+}
+
+/// An entry of a catalog, `xmlCatalogEntry`.
+pub struct CatalogEntry {
+    pub kind: EntryKind,
+    /// The identifier or prefix matched, normalized for public ones.
+    pub name: Option<Vec<u8>>,
+    /// What the entry maps to, or the catalog it refers to.
+    pub url: Vec<u8>,
+    /// The `prefer` in effect where the entry was found.
+    pub prefer: xmlCatalogPrefer,
+    /// The entries of the catalog `url` names for the entries referring
+    /// to one, parsed on first use. `None` once it failed to load, which
+    /// makes the entry inert, as `XML_CATA_BROKEN_CATALOG` does in C.
+    children: OnceLock<Option<Arc<EntryList>>>,
+}
+
+/// The entries of one catalog file.
+pub struct EntryList {
+    pub entries: Vec<CatalogEntry>,
+    /// How many lookups in this catalog are under way, to stop catalogs
+    /// that refer to each other, the `depth` of its first entry in C.
+    depth: AtomicI32,
+}
+
+impl CatalogEntry {
+    fn new(
+        kind: EntryKind,
+        name: Option<&[u8]>,
+        url: &[u8],
+        prefer: xmlCatalogPrefer,
+    ) -> CatalogEntry {
+        let name = match kind {
+            EntryKind::Public | EntryKind::DelegatePublic => {
+                name.map(normalize_public).filter(|name| !name.is_empty())
+            }
+            _ => name.map(<[u8]>::to_vec),
+        };
+        CatalogEntry {
+            kind,
+            name,
+            url: url.to_vec(),
+            prefer,
+            children: OnceLock::new(),
+        }
+    }
+
+    /// An entry referring to the catalog `url`, as those of the default
+    /// and document lists.
+    pub fn catalog(url: &[u8], prefer: xmlCatalogPrefer) -> CatalogEntry {
+        CatalogEntry::new(EntryKind::Catalog, None, url, prefer)
+    }
+
+    /// Whether the catalog the entry refers to failed to load.
+    fn is_broken(&self) -> bool {
+        matches!(self.children.get(), Some(None))
+    }
+
+    fn is(&self, kind: EntryKind) -> bool {
+        self.kind == kind && !self.is_broken()
+    }
+
+    /// The entries of the catalog the entry refers to, loading it the
+    /// first time.
+    fn children(&self) -> Option<&EntryList> {
+        self.children
+            .get_or_init(|| fetch_catalog(&self.url, self.prefer))
+            .as_deref()
+    }
+
+    /// Whether `id` equals the name. An entry without one matches nothing.
+    fn matches(&self, id: &[u8]) -> bool {
+        self.name.as_deref() == Some(id)
+    }
+
+    /// Whether `id` starts with the name. An entry without one is a prefix
+    /// of everything, as for `xmlStrncmp` with a length of 0.
+    fn prefixes(&self, id: &[u8]) -> bool {
+        id.starts_with(self.name.as_deref().unwrap_or_default())
+    }
+}
+
+/// What looking an identifier up in a catalog gave.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Lookup {
+    Found(Vec<u8>),
+    /// Delegates matched but none of them had the identifier, so the
+    /// catalogs after this one are not searched, `XML_CATAL_BREAK`.
+    Break,
+    NotFound,
+}
+
+impl Lookup {
+    fn is_none(&self) -> bool {
+        *self == Lookup::NotFound
+    }
+
+    pub fn found(self) -> Option<Vec<u8>> {
+        match self {
+            Lookup::Found(url) => Some(url),
+            _ => None,
+        }
+    }
+}
+
+static DEBUG: AtomicI32 = AtomicI32::new(0);
+static DEFAULT_ALLOW: AtomicU32 = AtomicU32::new(xmlCatalogAllow_XML_CATA_ALLOW_ALL);
+static DEFAULT_PREFER: AtomicU32 = AtomicU32::new(xmlCatalogPrefer_XML_CATA_PREFER_PUBLIC);
+static INITIALIZED: AtomicBool = AtomicBool::new(false);
+/// The catalogs of `xmlCatalogResolve` and friends, `xmlDefaultCatalog`.
+static DEFAULT_CATALOGS: Mutex<Option<Vec<Arc<CatalogEntry>>>> = Mutex::new(None);
+/// Every catalog file parsed so far by URL, `xmlCatalogXMLFiles`, so
+/// each is parsed once however many entries refer to it.
+static FILES: Mutex<Option<HashMap<Vec<u8>, Arc<EntryList>>>> = Mutex::new(None);
+
+pub fn debug_level() -> c_int {
+    DEBUG.load(Ordering::Relaxed)
+}
+
+fn debug(message: &str) {
+    if debug_level() == 0 {
+        return;
+    }
+    if let Ok(message) = CString::new(message) {
+        unsafe { xmlPrintErrorMessage(c"%s".as_ptr(), message.as_ptr()) };
+    }
+}
+
+fn show(s: &[u8]) -> std::borrow::Cow<'_, str> {
+    String::from_utf8_lossy(s)
+}
+
+/// Report a catalog error about `node`, which may be NULL, with the
+/// strings it is about, `xmlCatalogErr`.
+unsafe fn catalog_err(node: xmlNodePtr, code: u32, message: String, strs: [Option<&[u8]>; 3]) {
+    let strs = strs.map(|s| s.and_then(|s| CString::new(s).ok()));
+    let str_ptr = |s: &Option<CString>| s.as_ref().map_or(ptr::null(), |s| s.as_ptr());
+    let Ok(message) = CString::new(message) else {
+        return;
+    };
+    xmlRaiseError(
+        None,
+        None,
+        ptr::null_mut(),
+        ptr::null_mut(),
+        node as *mut c_void,
+        xmlErrorDomain_XML_FROM_CATALOG as c_int,
+        code as c_int,
+        xmlErrorLevel_XML_ERR_ERROR as c_int,
+        ptr::null(),
+        0,
+        str_ptr(&strs[0]),
+        str_ptr(&strs[1]),
+        str_ptr(&strs[2]),
+        0,
+        0,
+        c"%s".as_ptr(),
+        message.as_ptr(),
+    );
+}
+
+/// Normalize a public identifier: collapse runs of blanks into a space
+/// and drop leading and trailing ones, section 6.2 of the catalog
+/// specification.
+pub fn normalize_public(id: &[u8]) -> Vec<u8> {
+    let blank = |c: &u8| matches!(c, b' ' | b'\t' | b'\n' | b'\r');
+    let mut out = Vec::with_capacity(id.len());
+    for word in id.split(blank).filter(|word| !word.is_empty()) {
+        if !out.is_empty() {
+            out.push(b' ');
+        }
+        out.extend_from_slice(word);
+    }
+    out
+}
+
+/// The public identifier an `urn:publicid:` URN wraps, RFC 3151, or
+/// `None` if `urn` is not one.
+pub fn unwrap_urn(urn: &[u8]) -> Option<Vec<u8>> {
+    let mut rest = urn.strip_prefix(URN_PUBID)?;
+    let mut out = Vec::new();
+    while let Some((&c, tail)) = rest.split_first() {
+        if out.len() > MAX_URN_LENGTH {
+            break;
+        }
+        rest = tail;
+        match c {
+            b'+' => out.push(b' '),
+            b':' => out.extend_from_slice(b"//"),
+            b';' => out.extend_from_slice(b"::"),
+            b'%' => {
+                let decoded = match tail {
+                    [b'2', b'B', ..] => b'+',
+                    [b'3', b'A', ..] => b':',
+                    [b'2', b'F', ..] => b'/',
+                    [b'3', b'B', ..] => b';',
+                    [b'2', b'7', ..] => b'\'',
+                    [b'3', b'F', ..] => b'?',
+                    [b'2', b'3', ..] => b'#',
+                    [b'2', b'5', ..] => b'%',
+                    _ => {
+                        out.push(c);
+                        continue;
+                    }
+                };
+                out.push(decoded);
+                rest = &tail[2..];
+            }
+            _ => out.push(c),
+        }
+    }
+    Some(out)
+}
+
+unsafe fn owned(p: *mut xmlChar) -> Option<Vec<u8>> {
+    if p.is_null() {
+        return None;
+    }
+    let s = CStr::from_ptr(p as *const c_char).to_bytes().to_vec();
+    xmlFree.unwrap()(p as *mut c_void);
+    Some(s)
+}
+
+unsafe fn name_of<'a>(node: xmlNodePtr) -> &'a [u8] {
+    if (*node).name.is_null() {
+        return b"";
+    }
+    CStr::from_ptr((*node).name as *const c_char).to_bytes()
+}
+
+unsafe fn in_catalog_namespace(node: xmlNodePtr) -> bool {
+    let ns = (*node).ns;
+    !ns.is_null()
+        && !(*ns).href.is_null()
+        && CStr::from_ptr((*ns).href as *const c_char).to_bytes() == CATALOGS_NAMESPACE
+}
+
+/// Parse an XML file without looking anything up in the catalogs, as
+/// `xmlParseCatalogFile` does. Returns NULL unless it is well-formed.
+pub unsafe fn parse_catalog_file(filename: *const c_char) -> xmlDocPtr {
+    xmlReadFile(
+        filename,
+        ptr::null(),
+        xmlParserOption_XML_PARSE_NO_SYS_CATALOG as c_int,
+    )
+}
+
+/// Read the `prefer` attribute of `node` into `prefer`, reporting a value
+/// other than `public` or `system`.
+unsafe fn read_prefer(node: xmlNodePtr, prefer: &mut xmlCatalogPrefer) -> bool {
+    let Some(value) = owned(xmlGetProp(node, c"prefer".as_ptr() as *const xmlChar)) else {
+        return false;
+    };
+    match value.as_slice() {
+        b"system" => *prefer = xmlCatalogPrefer_XML_CATA_PREFER_SYSTEM,
+        b"public" => *prefer = xmlCatalogPrefer_XML_CATA_PREFER_PUBLIC,
+        _ => catalog_err(
+            node,
+            xmlParserErrors_XML_CATALOG_PREFER_VALUE,
+            format!("Invalid value for prefer: '{}'\n", show(&value)),
+            [Some(&value), None, None],
+        ),
+    }
+    true
+}
+
+/// The entry a catalog element of `kind` stands for, with its URI
+/// reference resolved against the element's base, or `None` after
+/// reporting a missing attribute or a reference that does not resolve,
+/// `xmlParseXMLCatalogOneNode`.
+unsafe fn parse_entry(
+    node: xmlNodePtr,
+    kind: EntryKind,
+    name_attr: Option<&CStr>,
+    uri_attr: &CStr,
+    prefer: xmlCatalogPrefer,
+) -> Option<CatalogEntry> {
+    let element = name_of(node);
+    let lacks = |attr: &CStr| {
+        catalog_err(
+            node,
+            xmlParserErrors_XML_CATALOG_MISSING_ATTR,
+            format!(
+                "{} entry lacks '{}'\n",
+                show(element),
+                attr.to_string_lossy()
+            ),
+            [Some(element), Some(attr.to_bytes()), None],
+        )
+    };
+    let mut ok = true;
+    let name = name_attr.and_then(|attr| {
+        let value = owned(xmlGetProp(node, attr.as_ptr() as *const xmlChar));
+        if value.is_none() {
+            lacks(attr);
+            ok = false;
+        }
+        value
+    });
+    let uri = owned(xmlGetProp(node, uri_attr.as_ptr() as *const xmlChar));
+    if uri.is_none() {
+        lacks(uri_attr);
+        ok = false;
+    }
+    let uri = uri.filter(|_| ok)?;
+    let uri_c = CString::new(uri.as_slice()).ok()?;
+
+    let base = xmlNodeGetBase((*node).doc, node);
+    let url = owned(xmlBuildURI(uri_c.as_ptr() as *const xmlChar, base));
+    if !base.is_null() {
+        xmlFree.unwrap()(base as *mut c_void);
+    }
+    let Some(url) = url else {
+        catalog_err(
+            node,
+            xmlParserErrors_XML_CATALOG_ENTRY_BROKEN,
+            format!(
+                "{} entry '{}' broken ?: {}\n",
+                show(element),
+                uri_attr.to_string_lossy(),
+                show(&uri)
+            ),
+            [Some(element), Some(uri_attr.to_bytes()), Some(&uri)],
+        );
+        return None;
+    };
+    if debug_level() > 1 {
+        match &name {
+            Some(name) => debug(&format!(
+                "Found {}: '{}' '{}'\n",
+                show(element),
+                show(name),
+                show(&url)
+            )),
+            None => debug(&format!("Found {}: '{}'\n", show(element), show(&url))),
+        }
+    }
+    Some(CatalogEntry::new(kind, name.as_deref(), &url, prefer))
+}
+
+/// Add the entries of the catalog elements among `node` and its
+/// siblings to `entries`. Those of a group follow in its place with the
+/// group's `prefer`; the group itself, which no lookup matches, is not
+/// kept.
+unsafe fn parse_entries(
+    mut node: xmlNodePtr,
+    prefer: xmlCatalogPrefer,
+    entries: &mut Vec<CatalogEntry>,
+) {
+    while !node.is_null() {
+        if in_catalog_namespace(node) {
+            let element = name_of(node);
+            if element == b"group" {
+                let mut group_prefer = prefer;
+                read_prefer(node, &mut group_prefer);
+                parse_entries((*node).children, group_prefer, entries);
+            } else if let Some((kind, name_attr, uri_attr)) = EntryKind::of_element(element) {
+                // A nested <catalog> is not an entry.
+                if let Some(entry) = parse_entry(node, kind, name_attr, uri_attr, prefer) {
+                    entries.push(entry);
+                }
+            }
+        }
+        node = (*node).next;
+    }
+}
+
+/// Parse the catalog file `url` into its entries, `xmlParseXMLCatalogFile`.
+/// Its root must be a `catalog` element in the catalog namespace.
+unsafe fn parse_catalog(url: &[u8], mut prefer: xmlCatalogPrefer) -> Option<EntryList> {
+    let filename = CString::new(url).ok()?;
+    let doc = parse_catalog_file(filename.as_ptr());
+    if doc.is_null() {
+        debug(&format!("Failed to parse catalog {}\n", show(url)));
+        return None;
+    }
+    debug(&format!("Parsing catalog {}\n", show(url)));
+
+    let root = xmlDocGetRootElement(doc);
+    if root.is_null() || name_of(root) != b"catalog" || !in_catalog_namespace(root) {
+        catalog_err(
+            doc as xmlNodePtr,
+            xmlParserErrors_XML_CATALOG_NOT_CATALOG,
+            format!("File {} is not an XML Catalog\n", show(url)),
+            [Some(url), None, None],
+        );
+        xmlFreeDoc(doc);
+        return None;
+    }
+    read_prefer(root, &mut prefer);
+    let mut entries = Vec::new();
+    parse_entries((*root).children, prefer, &mut entries);
+    xmlFreeDoc(doc);
+    Some(EntryList {
+        entries,
+        depth: AtomicI32::new(0),
+    })
+}
+
+/// The entries of the catalog file `url`, parsed the first time any
+/// entry refers to it, `xmlFetchXMLCatalogFile`. Files that fail to
+/// parse are not remembered.
+fn fetch_catalog(url: &[u8], prefer: xmlCatalogPrefer) -> Option<Arc<EntryList>> {
+    // Held while parsing so that a file is parsed once.
+    let mut files = FILES.lock().unwrap_or_else(|e| e.into_inner());
+    let files = files.get_or_insert_with(HashMap::new);
+    if let Some(list) = files.get(url) {
+        debug(&format!("Found {} in file hash\n", show(url)));
+        return Some(list.clone());
+    }
+    debug(&format!("{} not found in file hash\n", show(url)));
+    let list = Arc::new(unsafe { parse_catalog(url, prefer) }?);
+    debug(&format!("{} added to file hash\n", show(url)));
+    files.insert(url.to_vec(), list.clone());
+    Some(list)
+}
+
+/// Try the delegates among `entries` that `is_delegate` picks, each
+/// catalog once, with `lookup`, `Break` if none has the identifier.
+fn try_delegates(
+    entries: &[CatalogEntry],
+    is_delegate: impl Fn(&CatalogEntry) -> bool,
+    what: &str,
+    lookup: impl Fn(&CatalogEntry) -> Lookup,
+) -> Lookup {
+    let mut tried: Vec<&[u8]> = Vec::new();
+    for entry in entries.iter().filter(|e| is_delegate(e)) {
+        if tried.contains(&entry.url.as_slice()) {
+            continue;
+        }
+        if tried.len() < MAX_DELEGATE {
+            tried.push(&entry.url);
+        }
+        if entry.children().is_some() {
+            debug(&format!("Trying {} delegate {}\n", what, show(&entry.url)));
+            let found = lookup(entry);
+            if !found.is_none() {
+                return found;
+            }
+        }
+    }
+    Lookup::Break
+}
+
+/// Look an external identifier up in the entries of one catalog,
+/// `xmlCatalogXMLResolve`: system entries, the longest rewriteSystem
+/// prefix and system delegates if there is a system ID, then public
+/// entries and public delegates if there is a public ID, then the next
+/// catalogs.
+fn resolve_in(list: &EntryList, pub_id: Option<&[u8]>, sys_id: Option<&[u8]>) -> Lookup {
+    let entries = &list.entries;
+    if list.depth.load(Ordering::Relaxed) > MAX_CATAL_DEPTH {
+        let name = entries.first().and_then(|e| e.name.as_deref());
+        unsafe {
+            catalog_err(
+                ptr::null_mut(),
+                xmlParserErrors_XML_CATALOG_RECURSION,
+                format!(
+                    "Detected recursion in catalog {}\n",
+                    name.map_or("(null)".into(), show)
+                ),
+                [name, None, None],
+            )
+        };
+        return Lookup::NotFound;
+    }
+    list.depth.fetch_add(1, Ordering::Relaxed);
+    let done = |found: Lookup| {
+        list.depth.fetch_sub(1, Ordering::Relaxed);
+        found
+    };
+
+    let mut have_next = false;
+    if let Some(sys_id) = sys_id {
+        let mut rewrite: Option<&CatalogEntry> = None;
+        let mut have_delegate = false;
+        for entry in entries.iter().filter(|e| !e.is_broken()) {
+            match entry.kind {
+                EntryKind::System if entry.matches(sys_id) => {
+                    debug(&format!(
+                        "Found system match {}, using {}\n",
+                        show(sys_id),
+                        show(&entry.url)
+                    ));
+                    return done(Lookup::Found(entry.url.clone()));
+                }
+                EntryKind::RewriteSystem => {
+                    let len = entry.name.as_ref().map_or(0, Vec::len);
+                    if len > rewrite.and_then(|r| r.name.as_ref()).map_or(0, Vec::len)
+                        && entry.prefixes(sys_id)
+                    {
+                        rewrite = Some(entry);
+                    }
+                }
+                EntryKind::DelegateSystem if entry.prefixes(sys_id) => have_delegate = true,
+                EntryKind::NextCatalog => have_next = true,
+                _ => {}
+            }
+        }
+        if let Some(rewrite) = rewrite {
+            let prefix = rewrite.name.as_deref().unwrap_or_default();
+            debug(&format!("Using rewriting rule {}\n", show(prefix)));
+            let mut url = rewrite.url.clone();
+            url.extend_from_slice(&sys_id[prefix.len()..]);
+            return done(Lookup::Found(url));
+        }
+        if have_delegate {
+            return done(try_delegates(
+                entries,
+                |e| e.is(EntryKind::DelegateSystem) && e.prefixes(sys_id),
+                "system",
+                |e| list_resolve(&[e], None, Some(sys_id)),
+            ));
+        }
+    }
+
+    if let Some(pub_id) = pub_id {
+        let public_delegate = |e: &CatalogEntry| {
+            e.is(EntryKind::DelegatePublic)
+                && e.prefer == xmlCatalogPrefer_XML_CATA_PREFER_PUBLIC
+                && e.prefixes(pub_id)
+        };
+        let mut have_delegate = false;
+        for entry in entries.iter().filter(|e| !e.is_broken()) {
+            match entry.kind {
+                EntryKind::Public if entry.matches(pub_id) => {
+                    debug(&format!("Found public match {}\n", show(pub_id)));
+                    return done(Lookup::Found(entry.url.clone()));
+                }
+                EntryKind::DelegatePublic if public_delegate(entry) => have_delegate = true,
+                EntryKind::NextCatalog if sys_id.is_none() => have_next = true,
+                _ => {}
+            }
+        }
+        if have_delegate {
+            return done(try_delegates(entries, public_delegate, "public", |e| {
+                list_resolve(&[e], Some(pub_id), None)
+            }));
+        }
+    }
+
+    if have_next {
+        for entry in entries.iter().filter(|e| e.is(EntryKind::NextCatalog)) {
+            if entry.children().is_none() {
+                continue;
+            }
+            let found = list_resolve(&[entry], pub_id, sys_id);
+            if !found.is_none() {
+                return done(found);
+            }
+            if list.depth.load(Ordering::Relaxed) > MAX_CATAL_DEPTH {
+                // Left raised, as in C, so that the loop stays cut.
+                return Lookup::NotFound;
+            }
+        }
+    }
+    done(Lookup::NotFound)
+}
+
+/// Look a URI up in the entries of one catalog, `xmlCatalogXMLResolveURI`:
+/// uri entries, the longest rewriteURI prefix, URI and system delegates,
+/// then the next catalogs.
+fn resolve_uri_in(list: &EntryList, uri: &[u8]) -> Lookup {
+    let entries = &list.entries;
+    // C does not count the depth here, so catalogs referring to each
+    // other recurse without end; this guards URI lookups as it does
+    // identifiers.
+    if list.depth.load(Ordering::Relaxed) > MAX_CATAL_DEPTH {
+        let name = entries.first().and_then(|e| e.name.as_deref());
+        unsafe {
+            catalog_err(
+                ptr::null_mut(),
+                xmlParserErrors_XML_CATALOG_RECURSION,
+                format!(
+                    "Detected recursion in catalog {}\n",
+                    name.map_or("(null)".into(), show)
+                ),
+                [name, None, None],
+            )
+        };
+        return Lookup::NotFound;
+    }
+    list.depth.fetch_add(1, Ordering::Relaxed);
+    let found = resolve_uri_entries(entries, uri);
+    list.depth.fetch_sub(1, Ordering::Relaxed);
+    found
+}
+
+fn resolve_uri_entries(entries: &[CatalogEntry], uri: &[u8]) -> Lookup {
+    let mut rewrite: Option<&CatalogEntry> = None;
+    let mut have_delegate = false;
+    let mut have_next = false;
+    for entry in entries.iter().filter(|e| !e.is_broken()) {
+        match entry.kind {
+            EntryKind::Uri if entry.matches(uri) => {
+                debug(&format!("Found URI match {}\n", show(uri)));
+                return Lookup::Found(entry.url.clone());
+            }
+            EntryKind::RewriteUri => {
+                let len = entry.name.as_ref().map_or(0, Vec::len);
+                if len > rewrite.and_then(|r| r.name.as_ref()).map_or(0, Vec::len)
+                    && entry.prefixes(uri)
+                {
+                    rewrite = Some(entry);
+                }
+            }
+            EntryKind::DelegateUri if entry.prefixes(uri) => have_delegate = true,
+            EntryKind::NextCatalog => have_next = true,
+            _ => {}
+        }
+    }
+    if let Some(rewrite) = rewrite {
+        let prefix = rewrite.name.as_deref().unwrap_or_default();
+        debug(&format!("Using rewriting rule {}\n", show(prefix)));
+        let mut url = rewrite.url.clone();
+        url.extend_from_slice(&uri[prefix.len()..]);
+        return Lookup::Found(url);
+    }
+    if have_delegate {
+        return try_delegates(
+            entries,
+            |e| {
+                (e.is(EntryKind::DelegateSystem) || e.is(EntryKind::DelegateUri)) && e.prefixes(uri)
+            },
+            "URI",
+            |e| list_resolve_uri(&[e], uri),
+        );
+    }
+    if have_next {
+        for entry in entries.iter().filter(|e| e.is(EntryKind::NextCatalog)) {
+            if entry.children().is_none() {
+                continue;
+            }
+            let found = list_resolve_uri(&[entry], uri);
+            if !found.is_none() {
+                return found;
+            }
+        }
+    }
+    Lookup::NotFound
+}
+
+/// Look an external identifier up in each of `catalogs` in turn,
+/// `xmlCatalogListXMLResolve`. The public ID is normalized first, and
+/// identifiers wrapped in `urn:publicid:` URNs are unwrapped.
+pub fn list_resolve<E: Borrow<CatalogEntry>>(
+    catalogs: &[E],
+    pub_id: Option<&[u8]>,
+    sys_id: Option<&[u8]>,
+) -> Lookup {
+    if pub_id.is_none() && sys_id.is_none() {
+        return Lookup::NotFound;
+    }
+    let normalized = pub_id.map(normalize_public);
+    let pub_id = normalized.as_deref().filter(|id| !id.is_empty());
+
+    if let Some(urn) = pub_id.and_then(unwrap_urn) {
+        debug(&format!("Public URN ID expanded to {}\n", show(&urn)));
+        return list_resolve(catalogs, Some(&urn), sys_id);
+    }
+    if let Some(urn) = sys_id.and_then(unwrap_urn) {
+        debug(&format!("System URN ID expanded to {}\n", show(&urn)));
+        return match pub_id {
+            None => list_resolve(catalogs, Some(&urn), None),
+            Some(pub_id) if pub_id == urn => list_resolve(catalogs, Some(pub_id), None),
+            Some(pub_id) => list_resolve(catalogs, Some(pub_id), Some(&urn)),
+        };
+    }
+    for catalog in catalogs {
+        let Some(list) = catalog.borrow().children() else {
+            continue;
+        };
+        let found = resolve_in(list, pub_id, sys_id);
+        if !found.is_none() {
+            return found;
+        }
+        if list.depth.load(Ordering::Relaxed) > MAX_CATAL_DEPTH {
+            break;
+        }
+    }
+    Lookup::NotFound
+}
+
+/// Look a URI up in each of `catalogs` in turn,
+/// `xmlCatalogListXMLResolveURI`. A `urn:publicid:` URN is looked up as
+/// the public identifier it wraps.
+pub fn list_resolve_uri<E: Borrow<CatalogEntry>>(catalogs: &[E], uri: &[u8]) -> Lookup {
+    if let Some(urn) = unwrap_urn(uri) {
+        debug(&format!("URN ID expanded to {}\n", show(&urn)));
+        return list_resolve(catalogs, Some(&urn), None);
+    }
+    for catalog in catalogs {
+        if let Some(list) = catalog.borrow().children() {
+            let found = resolve_uri_in(list, uri);
+            if !found.is_none() {
+                return found;
+            }
+        }
+    }
+    Lookup::NotFound
+}
+
+/// Read the debug level from `XML_DEBUG_CATALOG`, `xmlInitCatalogInternal`.
+pub fn init_catalog_internal() {
+    if std::env::var_os("XML_DEBUG_CATALOG").is_some() {
+        DEBUG.store(1, Ordering::Relaxed);
+    }
+}
+
+/// Set up the default catalogs from `XML_CATALOG_FILES`, a list of
+/// URLs separated by blanks, or the system catalog, unless that happened
+/// already or `load_catalog` set them, `xmlInitializeCatalog`. The files
+/// are only read when first used.
+pub fn initialize() {
+    if INITIALIZED.load(Ordering::Acquire) {
+        return;
+    }
+    unsafe { xmlInitParser() };
+    let mut default = DEFAULT_CATALOGS.lock().unwrap_or_else(|e| e.into_inner());
+    if default.is_none() {
+        let files = std::env::var("XML_CATALOG_FILES").unwrap_or_else(|_| default_catalog_files());
+        let prefer = default_prefer();
+        *default = Some(
+            files
+                .split([' ', '\t', '\n', '\r'])
+                .filter(|path| !path.is_empty())
+                .map(|path| Arc::new(CatalogEntry::catalog(path.as_bytes(), prefer)))
+                .collect(),
+        );
+    }
+    drop(default);
+    INITIALIZED.store(true, Ordering::Release);
+}
+
+/// Whether the file at `path` is an XML catalog, going by its first
+/// character that is `-`, `<` or a letter. Other catalogs are SGML ones.
+fn is_xml_catalog(content: &[u8]) -> bool {
+    content
+        .iter()
+        .take_while(|&&c| c != 0)
+        .find(|c| matches!(c, b'-' | b'<') || c.is_ascii_alphabetic())
+        == Some(&b'<')
+}
+
+/// Add the catalog file `path` to the default catalogs, making them just
+/// that file if there were none, `xmlLoadCatalog`. Only XML catalogs are
+/// supported: an SGML catalog is refused with -1, as is a file that
+/// cannot be read.
+pub fn load_catalog(path: &[u8]) -> c_int {
+    unsafe { xmlInitParser() };
+    let mut default = DEFAULT_CATALOGS.lock().unwrap_or_else(|e| e.into_inner());
+    let prefer = default_prefer();
+    match default.as_mut() {
+        Some(catalogs) => catalogs.push(Arc::new(CatalogEntry::catalog(path, prefer))),
+        None => {
+            let Some(content) = std::str::from_utf8(path)
+                .ok()
+                .and_then(|path| std::fs::read(path).ok())
+            else {
+                return -1;
+            };
+            if !is_xml_catalog(&content) {
+                debug(&format!(
+                    "{} is an SGML catalog, which is not supported\n",
+                    show(path)
+                ));
+                return -1;
+            }
+            *default = Some(vec![Arc::new(CatalogEntry::catalog(path, prefer))]);
+            INITIALIZED.store(true, Ordering::Release);
+        }
+    }
+    0
+}
+
+/// Load each catalog of `paths`, separated by colons or blanks,
+/// `xmlLoadCatalogs`.
+pub fn load_catalogs(paths: &[u8]) {
+    for path in paths
+        .split(|c| matches!(c, b':' | b' ' | b'\t' | b'\n' | b'\r'))
+        .filter(|path| !path.is_empty())
+    {
+        load_catalog(path);
+    }
+}
+
+/// Forget the default catalogs and every parsed file, `xmlCatalogCleanup`.
+pub fn cleanup() {
+    debug("Catalogs cleanup\n");
+    let mut default = DEFAULT_CATALOGS.lock().unwrap_or_else(|e| e.into_inner());
+    FILES.lock().unwrap_or_else(|e| e.into_inner()).take();
+    default.take();
+    DEBUG.store(0, Ordering::Relaxed);
+    INITIALIZED.store(false, Ordering::Release);
+}
+
+/// The default catalogs, set up first if need be.
+fn default_catalogs() -> Option<Vec<Arc<CatalogEntry>>> {
+    initialize();
+    DEFAULT_CATALOGS
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone()
+}
+
+/// Look an external identifier up in the default catalogs,
+/// `xmlCatalogResolve`. Either ID may be `None`, not both.
+pub fn resolve(pub_id: Option<&[u8]>, sys_id: Option<&[u8]>) -> Option<Vec<u8>> {
+    let catalogs = default_catalogs()?;
+    if pub_id.is_none() && sys_id.is_none() {
+        return None;
+    }
+    match (pub_id, sys_id) {
+        (Some(p), Some(s)) => debug(&format!("Resolve: pubID {} sysID {}\n", show(p), show(s))),
+        (Some(p), None) => debug(&format!("Resolve: pubID {}\n", show(p))),
+        (None, Some(s)) => debug(&format!("Resolve: sysID {}\n", show(s))),
+        (None, None) => {}
+    }
+    list_resolve(&catalogs, pub_id, sys_id).found()
+}
+
+/// Look a URI up in the default catalogs, `xmlCatalogResolveURI`.
+pub fn resolve_uri(uri: &[u8]) -> Option<Vec<u8>> {
+    let catalogs = default_catalogs()?;
+    debug(&format!("Resolve URI {}\n", show(uri)));
+    list_resolve_uri(&catalogs, uri).found()
+}
+
+pub fn default_allow() -> xmlCatalogAllow {
+    DEFAULT_ALLOW.load(Ordering::Relaxed)
+}
+
+pub fn set_default_allow(allow: xmlCatalogAllow) {
+    debug(match allow {
+        0 => "Disabling catalog usage\n",
+        1 => "Allowing only global catalogs\n",
+        2 => "Allowing only catalogs from the document\n",
+        _ => "Allowing all catalogs\n",
+    });
+    DEFAULT_ALLOW.store(allow, Ordering::Relaxed);
+}
+
+pub fn default_prefer() -> xmlCatalogPrefer {
+    DEFAULT_PREFER.load(Ordering::Relaxed)
+}
+
+/// Set whether public delegates apply, returning the previous setting.
+/// `XML_CATA_PREFER_NONE` changes nothing.
+pub fn set_default_prefer(prefer: xmlCatalogPrefer) -> xmlCatalogPrefer {
+    let old = default_prefer();
+    match prefer {
+        xmlCatalogPrefer_XML_CATA_PREFER_NONE => return old,
+        xmlCatalogPrefer_XML_CATA_PREFER_PUBLIC => debug("Setting catalog preference to PUBLIC\n"),
+        xmlCatalogPrefer_XML_CATA_PREFER_SYSTEM => debug("Setting catalog preference to SYSTEM\n"),
+        // C only refuses other values when debugging.
+        _ if debug_level() != 0 => return old,
+        _ => {}
+    }
+    DEFAULT_PREFER.store(prefer, Ordering::Relaxed);
+    old
+}
+
+/// Set the debug level, 0 or less for none, returning the previous one.
+pub fn set_debug(level: c_int) -> c_int {
+    DEBUG.swap(level.max(0), Ordering::Relaxed)
+}
+
+/// The catalogs of one document, named by its `oasis-xml-catalog`
+/// processing instructions. The parser keeps them behind a `void *`.
+#[derive(Default)]
+pub struct LocalCatalogs {
+    pub catalogs: Vec<CatalogEntry>,
+}
+
+impl LocalCatalogs {
+    /// Add the catalog `url`, `xmlCatalogAddLocal`.
+    pub fn add(&mut self, url: &[u8]) {
+        debug(&format!("Adding document catalog {}\n", show(url)));
+        self.catalogs
+            .push(CatalogEntry::catalog(url, default_prefer()));
+    }
+
+    /// `xmlCatalogLocalResolve`.
+    pub fn resolve(&self, pub_id: Option<&[u8]>, sys_id: Option<&[u8]>) -> Option<Vec<u8>> {
+        match (pub_id, sys_id) {
+            (Some(p), Some(s)) => debug(&format!(
+                "Local Resolve: pubID {} sysID {}\n",
+                show(p),
+                show(s)
+            )),
+            (Some(p), None) => debug(&format!("Local Resolve: pubID {}\n", show(p))),
+            (None, Some(s)) => debug(&format!("Local Resolve: sysID {}\n", show(s))),
+            (None, None) => return None,
+        }
+        list_resolve(&self.catalogs, pub_id, sys_id).found()
+    }
+
+    /// `xmlCatalogLocalResolveURI`.
+    pub fn resolve_uri(&self, uri: &[u8]) -> Option<Vec<u8>> {
+        debug(&format!("Resolve URI {}\n", show(uri)));
+        list_resolve_uri(&self.catalogs, uri).found()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn public_ids_are_normalized() {
+        assert_eq!(normalize_public(b"  -//A//B \t\n C//EN "), b"-//A//B C//EN");
+        assert_eq!(normalize_public(b"-//A//EN"), b"-//A//EN");
+        assert_eq!(normalize_public(b" \t "), b"");
+    }
+
+    #[test]
+    fn urns_unwrap_to_public_ids() {
+        assert_eq!(
+            unwrap_urn(b"urn:publicid:-:OASIS:DTD+DocBook+XML+V4.1.2:EN").unwrap(),
+            b"-//OASIS//DTD DocBook XML V4.1.2//EN"
+        );
+        assert_eq!(
+            unwrap_urn(b"urn:publicid:a;b%2B%3A%2F%3B%27%3F%23%25%41").unwrap(),
+            b"a::b+:/;'?#%%41"
+        );
+        assert_eq!(unwrap_urn(b"urn:other:a"), None);
+        let long = [b"urn:publicid:".as_slice(), &[b'x'; 3000]].concat();
+        assert_eq!(unwrap_urn(&long).unwrap().len(), MAX_URN_LENGTH + 1);
+    }
+
+    #[test]
+    fn catalogs_are_told_apart_by_their_first_character() {
+        assert!(is_xml_catalog(b"\n  <?xml version='1.0'?><catalog/>"));
+        assert!(!is_xml_catalog(b"-- comment -- PUBLIC \"a\" \"b\""));
+        assert!(!is_xml_catalog(b"PUBLIC \"a\" \"b\""));
+        assert!(!is_xml_catalog(b""));
+    }
+}
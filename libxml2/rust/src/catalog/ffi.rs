@@ -0,0 +1,192 @@
+#![allow(clippy::missing_safety_doc)]
+
+use std::ffi::CStr;
+use std::os::raw::{c_char, c_int, c_void};
+use std::ptr;
+
+use super::core::{self, LocalCatalogs};
+use crate::static_bindings::{
+    xmlCatalogAllow, xmlCatalogPrefer, xmlChar, xmlDocPtr, xmlInitParser, xmlStrndup,
+};
+
+// Ensure symbols are exported for linking
+#[cfg(feature = "rust-catalog")]
+#[used]
+static CATALOG_FFI_LINKAGE: () = ();
+
+unsafe fn bytes<'a, T>(s: *const T) -> Option<&'a [u8]> {
+    if s.is_null() {
+        None
+    } else {
+        Some(CStr::from_ptr(s as *const c_char).to_bytes())
+    }
+}
+
+/// Copy a result for the caller, who frees it.
+unsafe fn to_xml(url: Option<Vec<u8>>) -> *mut xmlChar {
+    match url {
+        Some(url) => xmlStrndup(url.as_ptr(), url.len() as c_int),
+        None => ptr::null_mut(),
+    }
+}
+
+/// Read the catalog debug level from the environment.
+#[no_mangle]
+pub unsafe extern "C" fn xmlInitCatalogInternal() {
+    core::init_catalog_internal();
+}
+
+/// Free global data. Nothing is left once `xmlCatalogCleanup` ran.
+#[no_mangle]
+pub unsafe extern "C" fn xmlCleanupCatalogInternal() {}
+
+/// Set up the default catalogs.
+#[no_mangle]
+pub unsafe extern "C" fn xmlInitializeCatalog() {
+    core::initialize();
+}
+
+/// Add an XML catalog file to the default catalogs. Returns 0 or -1.
+#[no_mangle]
+pub unsafe extern "C" fn xmlLoadCatalog(filename: *const c_char) -> c_int {
+    match bytes(filename) {
+        Some(filename) => core::load_catalog(filename),
+        None => -1,
+    }
+}
+
+/// Add catalog files, separated by colons or blanks, to the default
+/// catalogs.
+#[no_mangle]
+pub unsafe extern "C" fn xmlLoadCatalogs(paths: *const c_char) {
+    if let Some(paths) = bytes(paths) {
+        core::load_catalogs(paths);
+    }
+}
+
+/// Free the default catalogs and every parsed catalog file.
+#[no_mangle]
+pub unsafe extern "C" fn xmlCatalogCleanup() {
+    core::cleanup();
+}
+
+/// Parse an XML file without catalog lookups.
+#[no_mangle]
+pub unsafe extern "C" fn xmlParseCatalogFile(filename: *const c_char) -> xmlDocPtr {
+    core::parse_catalog_file(filename)
+}
+
+/// Resolve an external identifier with the default catalogs.
+#[no_mangle]
+pub unsafe extern "C" fn xmlCatalogResolve(
+    pub_id: *const xmlChar,
+    sys_id: *const xmlChar,
+) -> *mut xmlChar {
+    to_xml(core::resolve(bytes(pub_id), bytes(sys_id)))
+}
+
+/// Resolve a system ID with the default catalogs.
+#[no_mangle]
+pub unsafe extern "C" fn xmlCatalogResolveSystem(sys_id: *const xmlChar) -> *mut xmlChar {
+    match bytes(sys_id) {
+        Some(sys_id) => to_xml(core::resolve(None, Some(sys_id))),
+        None => ptr::null_mut(),
+    }
+}
+
+/// Resolve a public ID with the default catalogs.
+#[no_mangle]
+pub unsafe extern "C" fn xmlCatalogResolvePublic(pub_id: *const xmlChar) -> *mut xmlChar {
+    match bytes(pub_id) {
+        Some(pub_id) => to_xml(core::resolve(Some(pub_id), None)),
+        None => ptr::null_mut(),
+    }
+}
+
+/// Resolve a URI with the default catalogs.
+#[no_mangle]
+pub unsafe extern "C" fn xmlCatalogResolveURI(uri: *const xmlChar) -> *mut xmlChar {
+    match bytes(uri) {
+        Some(uri) => to_xml(core::resolve_uri(uri)),
+        None => ptr::null_mut(),
+    }
+}
+
+/// Which catalogs are used.
+#[no_mangle]
+pub unsafe extern "C" fn xmlCatalogGetDefaults() -> xmlCatalogAllow {
+    core::default_allow()
+}
+
+/// Set which catalogs are used.
+#[no_mangle]
+pub unsafe extern "C" fn xmlCatalogSetDefaults(allow: xmlCatalogAllow) {
+    core::set_default_allow(allow);
+}
+
+/// Set whether public delegates apply, returning the previous setting.
+#[no_mangle]
+pub unsafe extern "C" fn xmlCatalogSetDefaultPrefer(prefer: xmlCatalogPrefer) -> xmlCatalogPrefer {
+    core::set_default_prefer(prefer)
+}
+
+/// Set the debug level, returning the previous one.
+#[no_mangle]
+pub unsafe extern "C" fn xmlCatalogSetDebug(level: c_int) -> c_int {
+    core::set_debug(level)
+}
+
+/// Free a document's catalogs.
+#[no_mangle]
+pub unsafe extern "C" fn xmlCatalogFreeLocal(catalogs: *mut c_void) {
+    if !catalogs.is_null() {
+        drop(Box::from_raw(catalogs as *mut LocalCatalogs));
+    }
+}
+
+/// Add a catalog to a document's catalogs, NULL for none yet. Returns
+/// the updated catalogs.
+#[no_mangle]
+pub unsafe extern "C" fn xmlCatalogAddLocal(
+    catalogs: *mut c_void,
+    url: *const xmlChar,
+) -> *mut c_void {
+    xmlInitParser();
+    let Some(url) = bytes(url) else {
+        return catalogs;
+    };
+    let catalogs = if catalogs.is_null() {
+        Box::into_raw(Box::<LocalCatalogs>::default())
+    } else {
+        catalogs as *mut LocalCatalogs
+    };
+    (*catalogs).add(url);
+    catalogs as *mut c_void
+}
+
+/// Resolve an external identifier with a document's catalogs.
+#[no_mangle]
+pub unsafe extern "C" fn xmlCatalogLocalResolve(
+    catalogs: *mut c_void,
+    pub_id: *const xmlChar,
+    sys_id: *const xmlChar,
+) -> *mut xmlChar {
+    let catalogs = catalogs as *const LocalCatalogs;
+    if catalogs.is_null() {
+        return ptr::null_mut();
+    }
+    to_xml((*catalogs).resolve(bytes(pub_id), bytes(sys_id)))
+}
+
+/// Resolve a URI with a document's catalogs.
+#[no_mangle]
+pub unsafe extern "C" fn xmlCatalogLocalResolveURI(
+    catalogs: *mut c_void,
+    uri: *const xmlChar,
+) -> *mut xmlChar {
+    let catalogs = catalogs as *const LocalCatalogs;
+    match bytes(uri) {
+        Some(uri) if !catalogs.is_null() => to_xml((*catalogs).resolve_uri(uri)),
+        _ => ptr::null_mut(),
+    }
+}
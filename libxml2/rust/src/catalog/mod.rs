@@ -0,0 +1,9 @@
+//! Rust implementation of catalog module
+//!
+//! Loading XML catalogs and resolving public and system identifiers and
+//! URIs with them
+
+pub mod core;
+pub mod ffi;
+
+pub use core::*;
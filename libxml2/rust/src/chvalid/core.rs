@@ -166,6 +166,137 @@ pub fn is_pubid_char(ch: u32) -> bool {
     }
 }
 
+/// Letter ::= BaseChar | Ideographic
+pub fn is_letter(ch: u32) -> bool {
+    is_base_char(ch) || is_ideographic(ch)
+}
+
+/// NameStartChar ranges above ASCII, from the fifth edition of XML 1.0
+static NAME_START_GROUP: ChRangeGroup = ChRangeGroup {
+    short_ranges: &[
+        ChSRange { low: 0xc0, high: 0xd6 },
+        ChSRange { low: 0xd8, high: 0xf6 },
+        ChSRange { low: 0xf8, high: 0x2ff },
+        ChSRange { low: 0x370, high: 0x37d },
+        ChSRange { low: 0x37f, high: 0x1fff },
+        ChSRange { low: 0x200c, high: 0x200d },
+        ChSRange { low: 0x2070, high: 0x218f },
+        ChSRange { low: 0x2c00, high: 0x2fef },
+        ChSRange { low: 0x3001, high: 0xd7ff },
+        ChSRange { low: 0xf900, high: 0xfdcf },
+        ChSRange { low: 0xfdf0, high: 0xfffd },
+    ],
+    long_ranges: &[ChLRange { low: 0x10000, high: 0xeffff }],
+};
+
+/// NameStartChar ::= ":" | [A-Z] | "_" | [a-z] | [#xC0-#xD6] | ...
+///
+/// These follow the fifth edition of XML 1.0, which the parser uses unless
+/// `XML_PARSE_OLD10` asks for the Letter-based productions.
+pub fn is_name_start_char(ch: u32) -> bool {
+    is_ncname_start_char(ch) || ch == b':' as u32
+}
+
+/// NameChar ::= NameStartChar | "-" | "." | [0-9] | #xB7 | [#x0300-#x036F] | [#x203F-#x2040]
+pub fn is_name_char(ch: u32) -> bool {
+    is_ncname_char(ch) || ch == b':' as u32
+}
+
+/// First character of an NCName: a NameStartChar other than ':'
+pub fn is_ncname_start_char(ch: u32) -> bool {
+    if ch < 0x80 {
+        (ch as u8).is_ascii_alphabetic() || ch == b'_' as u32
+    } else {
+        char_in_range(ch, &NAME_START_GROUP)
+    }
+}
+
+/// NCNameChar: a NameChar other than ':'
+pub fn is_ncname_char(ch: u32) -> bool {
+    is_ncname_start_char(ch) ||
+    ch == b'-' as u32 ||
+    ch == b'.' as u32 ||
+    (b'0' as u32..=b'9' as u32).contains(&ch) ||
+    ch == 0xb7 ||
+    (0x300..=0x36f).contains(&ch) ||
+    (0x203f..=0x2040).contains(&ch)
+}
+
+/// Whether all 16 bytes of `block` are in 0x20..0x80, the printable ASCII
+/// range where every byte is an XML Char on its own.
+#[cfg(target_arch = "x86_64")]
+#[inline]
+fn is_plain_ascii_block(block: &[u8; 16]) -> bool {
+    use std::arch::x86_64::*;
+    // SSE2 is part of the x86_64 baseline. As signed bytes, 0x80..=0xff
+    // are negative, so one compare against 0x1f covers both bounds.
+    unsafe {
+        let bytes = _mm_loadu_si128(block.as_ptr() as *const __m128i);
+        let plain = _mm_cmpgt_epi8(bytes, _mm_set1_epi8(0x1f));
+        _mm_movemask_epi8(plain) == 0xffff
+    }
+}
+
+#[cfg(not(target_arch = "x86_64"))]
+#[inline]
+fn is_plain_ascii_block(block: &[u8; 16]) -> bool {
+    block.iter().all(|&b| (0x20..0x80).contains(&b))
+}
+
+/// Byte offset of the first character of `buf` that is not an XML Char,
+/// or of the first malformed UTF-8 sequence.
+///
+/// Runs of printable ASCII are skipped 16 bytes at a time; everything else
+/// is decoded and checked with `is_char`. Agrees with
+/// `validate_char_run_scalar`.
+pub fn validate_char_run(buf: &[u8]) -> Result<(), usize> {
+    let mut i = 0;
+    while i < buf.len() {
+        while let Some(block) = buf.get(i..i + 16) {
+            if !is_plain_ascii_block(block.try_into().unwrap()) {
+                break;
+            }
+            i += 16;
+        }
+        let Some(&lead) = buf.get(i) else {
+            break;
+        };
+        if lead < 0x80 {
+            if !is_char_ascii(lead as u32) {
+                return Err(i);
+            }
+            i += 1;
+            continue;
+        }
+        // A UTF-8 sequence is at most 4 bytes
+        let window = &buf[i..buf.len().min(i + 4)];
+        let valid = match std::str::from_utf8(window) {
+            Ok(s) => s,
+            Err(e) => std::str::from_utf8(&window[..e.valid_up_to()]).unwrap(),
+        };
+        let Some(ch) = valid.chars().next() else {
+            return Err(i);
+        };
+        if !is_char(ch as u32) {
+            return Err(i);
+        }
+        i += ch.len_utf8();
+    }
+    Ok(())
+}
+
+/// One code point at a time reference for `validate_char_run`.
+pub fn validate_char_run_scalar(buf: &[u8]) -> Result<(), usize> {
+    let (text, error) = match std::str::from_utf8(buf) {
+        Ok(text) => (text, None),
+        Err(e) => (std::str::from_utf8(&buf[..e.valid_up_to()]).unwrap(), Some(e.valid_up_to())),
+    };
+    match text.char_indices().find(|&(_, ch)| !is_char(ch as u32)) {
+        Some((offset, _)) => Err(offset),
+        None => error.map_or(Ok(()), Err),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -213,6 +344,94 @@ mod tests {
         assert!(!is_pubid_char(0x100));
     }
 
+    #[test]
+    fn test_name_colon() {
+        let colon = b':' as u32;
+        assert!(is_name_start_char(colon));
+        assert!(is_name_char(colon));
+        assert!(!is_ncname_start_char(colon));
+        assert!(!is_ncname_char(colon));
+    }
+
+    #[test]
+    fn test_name_start_excludes_digits_and_punctuation() {
+        for ch in [b'0', b'9', b'.', b'-'] {
+            let ch = ch as u32;
+            assert!(!is_name_start_char(ch));
+            assert!(!is_ncname_start_char(ch));
+            assert!(is_name_char(ch));
+            assert!(is_ncname_char(ch));
+        }
+        // Combining grave accent, middle dot, undertie
+        for ch in [0x300, 0x36f, 0xb7, 0x203f, 0x2040] {
+            assert!(!is_name_start_char(ch));
+            assert!(is_ncname_char(ch));
+        }
+    }
+
+    #[test]
+    fn test_name_letters() {
+        // Edges of every NameStartChar range
+        for ch in [
+            b'A' as u32, b'Z' as u32, b'a' as u32, b'z' as u32, b'_' as u32,
+            0xc0, 0xd6, 0xd8, 0xf6, 0xf8, 0x2ff, 0x370, 0x37d, 0x37f, 0x1fff,
+            0x200c, 0x200d, 0x2070, 0x218f, 0x2c00, 0x2fef, 0x3001, 0xd7ff,
+            0xf900, 0xfdcf, 0xfdf0, 0xfffd, 0x10000, 0xeffff,
+        ] {
+            assert!(is_name_start_char(ch), "{:#x}", ch);
+            assert!(is_ncname_start_char(ch), "{:#x}", ch);
+            assert!(is_ncname_char(ch), "{:#x}", ch);
+        }
+        // Just outside them
+        for ch in [
+            b'@' as u32, b'[' as u32, b'`' as u32, b'{' as u32, 0xd7, 0xf7, 0x37e,
+            0x2000, 0x2041, 0x2190, 0x2ff0, 0x3000, 0xd800, 0xfdd0, 0xfffe, 0xf0000,
+        ] {
+            assert!(!is_name_start_char(ch), "{:#x}", ch);
+            assert!(!is_name_char(ch), "{:#x}", ch);
+        }
+        for ch in [b' ' as u32, b'>' as u32, b'/' as u32] {
+            assert!(!is_name_char(ch));
+        }
+    }
+
+    #[test]
+    fn test_validate_char_run() {
+        let long = b"a plain ASCII run that spans several sixteen byte blocks";
+        assert_eq!(validate_char_run(long), Ok(()));
+        assert_eq!(validate_char_run(b""), Ok(()));
+        assert_eq!(validate_char_run(b"tab\tnewline\ncr\r"), Ok(()));
+        assert_eq!(validate_char_run("caf\u{e9} \u{4e2d} \u{1f600}".as_bytes()), Ok(()));
+
+        // Control characters, inside and after a full block
+        assert_eq!(validate_char_run(b"\x00"), Err(0));
+        assert_eq!(validate_char_run(b"0123456789abcdefg\x08"), Err(17));
+        assert_eq!(validate_char_run(b"0123456789abcde\x1f"), Err(15));
+        // U+FFFE and U+FFFF are well-formed UTF-8 but not Chars
+        assert_eq!(validate_char_run(b"ab\xef\xbf\xbe"), Err(2));
+        assert_eq!(validate_char_run(b"ab\xef\xbf\xbf"), Err(2));
+        // Malformed: stray continuation, truncated, surrogate, overlong
+        assert_eq!(validate_char_run(b"ab\x80"), Err(2));
+        assert_eq!(validate_char_run(b"ab\xe4\xb8"), Err(2));
+        assert_eq!(validate_char_run(b"ab\xed\xa0\x80"), Err(2));
+        assert_eq!(validate_char_run(b"ab\xc0\xaf"), Err(2));
+    }
+
+    #[test]
+    fn test_validate_char_run_matches_scalar() {
+        let mut inputs: Vec<Vec<u8>> = Vec::new();
+        for bad in [&b"\x01"[..], b"\x7f", b"\x80", b"\xc3\xa9", b"\xef\xbf\xbe", b"\xf0\x9f\x98"] {
+            for offset in 0..40 {
+                let mut input = vec![b'x'; 40];
+                input.splice(offset..offset, bad.iter().copied());
+                inputs.push(input);
+            }
+        }
+        for input in &inputs {
+            assert_eq!(validate_char_run(input), validate_char_run_scalar(input), "{:?}", input);
+        }
+    }
+
     #[test]
     fn test_char_in_range() {
         assert!(char_in_range(0x100, &XML_IS_BASE_CHAR_GROUP));
@@ -1,8 +1,11 @@
 //! Rust implementation of chvalid module
 //!
-//! Character validation for Unicode code points using range tables
+//! Character validation for Unicode code points using range tables. The
+//! classes in `core` are always built; `ffi` replaces `chvalid.c` under
+//! `rust-chvalid`.
 
 pub mod core;
+#[cfg(feature = "rust-chvalid")]
 pub mod ffi;
 mod ranges;
 
@@ -1,399 +1,6 @@
-use super::core::{ChSRange, ChLRange, ChRangeGroup};
-
-pub const XML_IS_PUBID_CHAR_TAB: [u8; 256] = [
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00,
-    0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x01, 0x00, 0x01,
-    0x01, 0x01, 0x00, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
-    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
-    0x00, 0x01, 0x00, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
-    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
-    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x00, 0x00, 0x00, 0x00, 0x01,
-    0x00, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
-    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
-    0x01, 0x01, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00
-];
-
-static XML_IS_BASE_CHAR_SRNG: &[ChSRange] = &[
-    ChSRange { low: 0x100, high: 0x131 },
-    ChSRange { low: 0x134, high: 0x13e },
-    ChSRange { low: 0x141, high: 0x148 },
-    ChSRange { low: 0x14a, high: 0x17e },
-    ChSRange { low: 0x180, high: 0x1c3 },
-    ChSRange { low: 0x1cd, high: 0x1f0 },
-    ChSRange { low: 0x1f4, high: 0x1f5 },
-    ChSRange { low: 0x1fa, high: 0x217 },
-    ChSRange { low: 0x250, high: 0x2a8 },
-    ChSRange { low: 0x2bb, high: 0x2c1 },
-    ChSRange { low: 0x386, high: 0x386 },
-    ChSRange { low: 0x388, high: 0x38a },
-    ChSRange { low: 0x38c, high: 0x38c },
-    ChSRange { low: 0x38e, high: 0x3a1 },
-    ChSRange { low: 0x3a3, high: 0x3ce },
-    ChSRange { low: 0x3d0, high: 0x3d6 },
-    ChSRange { low: 0x3da, high: 0x3da },
-    ChSRange { low: 0x3dc, high: 0x3dc },
-    ChSRange { low: 0x3de, high: 0x3de },
-    ChSRange { low: 0x3e0, high: 0x3e0 },
-    ChSRange { low: 0x3e2, high: 0x3f3 },
-    ChSRange { low: 0x401, high: 0x40c },
-    ChSRange { low: 0x40e, high: 0x44f },
-    ChSRange { low: 0x451, high: 0x45c },
-    ChSRange { low: 0x45e, high: 0x481 },
-    ChSRange { low: 0x490, high: 0x4c4 },
-    ChSRange { low: 0x4c7, high: 0x4c8 },
-    ChSRange { low: 0x4cb, high: 0x4cc },
-    ChSRange { low: 0x4d0, high: 0x4eb },
-    ChSRange { low: 0x4ee, high: 0x4f5 },
-    ChSRange { low: 0x4f8, high: 0x4f9 },
-    ChSRange { low: 0x531, high: 0x556 },
-    ChSRange { low: 0x559, high: 0x559 },
-    ChSRange { low: 0x561, high: 0x586 },
-    ChSRange { low: 0x5d0, high: 0x5ea },
-    ChSRange { low: 0x5f0, high: 0x5f2 },
-    ChSRange { low: 0x621, high: 0x63a },
-    ChSRange { low: 0x641, high: 0x64a },
-    ChSRange { low: 0x671, high: 0x6b7 },
-    ChSRange { low: 0x6ba, high: 0x6be },
-    ChSRange { low: 0x6c0, high: 0x6ce },
-    ChSRange { low: 0x6d0, high: 0x6d3 },
-    ChSRange { low: 0x6d5, high: 0x6d5 },
-    ChSRange { low: 0x6e5, high: 0x6e6 },
-    ChSRange { low: 0x905, high: 0x939 },
-    ChSRange { low: 0x93d, high: 0x93d },
-    ChSRange { low: 0x958, high: 0x961 },
-    ChSRange { low: 0x985, high: 0x98c },
-    ChSRange { low: 0x98f, high: 0x990 },
-    ChSRange { low: 0x993, high: 0x9a8 },
-    ChSRange { low: 0x9aa, high: 0x9b0 },
-    ChSRange { low: 0x9b2, high: 0x9b2 },
-    ChSRange { low: 0x9b6, high: 0x9b9 },
-    ChSRange { low: 0x9dc, high: 0x9dd },
-    ChSRange { low: 0x9df, high: 0x9e1 },
-    ChSRange { low: 0x9f0, high: 0x9f1 },
-    ChSRange { low: 0xa05, high: 0xa0a },
-    ChSRange { low: 0xa0f, high: 0xa10 },
-    ChSRange { low: 0xa13, high: 0xa28 },
-    ChSRange { low: 0xa2a, high: 0xa30 },
-    ChSRange { low: 0xa32, high: 0xa33 },
-    ChSRange { low: 0xa35, high: 0xa36 },
-    ChSRange { low: 0xa38, high: 0xa39 },
-    ChSRange { low: 0xa59, high: 0xa5c },
-    ChSRange { low: 0xa5e, high: 0xa5e },
-    ChSRange { low: 0xa72, high: 0xa74 },
-    ChSRange { low: 0xa85, high: 0xa8b },
-    ChSRange { low: 0xa8d, high: 0xa8d },
-    ChSRange { low: 0xa8f, high: 0xa91 },
-    ChSRange { low: 0xa93, high: 0xaa8 },
-    ChSRange { low: 0xaaa, high: 0xab0 },
-    ChSRange { low: 0xab2, high: 0xab3 },
-    ChSRange { low: 0xab5, high: 0xab9 },
-    ChSRange { low: 0xabd, high: 0xabd },
-    ChSRange { low: 0xae0, high: 0xae0 },
-    ChSRange { low: 0xb05, high: 0xb0c },
-    ChSRange { low: 0xb0f, high: 0xb10 },
-    ChSRange { low: 0xb13, high: 0xb28 },
-    ChSRange { low: 0xb2a, high: 0xb30 },
-    ChSRange { low: 0xb32, high: 0xb33 },
-    ChSRange { low: 0xb36, high: 0xb39 },
-    ChSRange { low: 0xb3d, high: 0xb3d },
-    ChSRange { low: 0xb5c, high: 0xb5d },
-    ChSRange { low: 0xb5f, high: 0xb61 },
-    ChSRange { low: 0xb85, high: 0xb8a },
-    ChSRange { low: 0xb8e, high: 0xb90 },
-    ChSRange { low: 0xb92, high: 0xb95 },
-    ChSRange { low: 0xb99, high: 0xb9a },
-    ChSRange { low: 0xb9c, high: 0xb9c },
-    ChSRange { low: 0xb9e, high: 0xb9f },
-    ChSRange { low: 0xba3, high: 0xba4 },
-    ChSRange { low: 0xba8, high: 0xbaa },
-    ChSRange { low: 0xbae, high: 0xbb5 },
-    ChSRange { low: 0xbb7, high: 0xbb9 },
-    ChSRange { low: 0xc05, high: 0xc0c },
-    ChSRange { low: 0xc0e, high: 0xc10 },
-    ChSRange { low: 0xc12, high: 0xc28 },
-    ChSRange { low: 0xc2a, high: 0xc33 },
-    ChSRange { low: 0xc35, high: 0xc39 },
-    ChSRange { low: 0xc60, high: 0xc61 },
-    ChSRange { low: 0xc85, high: 0xc8c },
-    ChSRange { low: 0xc8e, high: 0xc90 },
-    ChSRange { low: 0xc92, high: 0xca8 },
-    ChSRange { low: 0xcaa, high: 0xcb3 },
-    ChSRange { low: 0xcb5, high: 0xcb9 },
-    ChSRange { low: 0xcde, high: 0xcde },
-    ChSRange { low: 0xce0, high: 0xce1 },
-    ChSRange { low: 0xd05, high: 0xd0c },
-    ChSRange { low: 0xd0e, high: 0xd10 },
-    ChSRange { low: 0xd12, high: 0xd28 },
-    ChSRange { low: 0xd2a, high: 0xd39 },
-    ChSRange { low: 0xd60, high: 0xd61 },
-    ChSRange { low: 0xe01, high: 0xe2e },
-    ChSRange { low: 0xe30, high: 0xe30 },
-    ChSRange { low: 0xe32, high: 0xe33 },
-    ChSRange { low: 0xe40, high: 0xe45 },
-    ChSRange { low: 0xe81, high: 0xe82 },
-    ChSRange { low: 0xe84, high: 0xe84 },
-    ChSRange { low: 0xe87, high: 0xe88 },
-    ChSRange { low: 0xe8a, high: 0xe8a },
-    ChSRange { low: 0xe8d, high: 0xe8d },
-    ChSRange { low: 0xe94, high: 0xe97 },
-    ChSRange { low: 0xe99, high: 0xe9f },
-    ChSRange { low: 0xea1, high: 0xea3 },
-    ChSRange { low: 0xea5, high: 0xea5 },
-    ChSRange { low: 0xea7, high: 0xea7 },
-    ChSRange { low: 0xeaa, high: 0xeab },
-    ChSRange { low: 0xead, high: 0xeae },
-    ChSRange { low: 0xeb0, high: 0xeb0 },
-    ChSRange { low: 0xeb2, high: 0xeb3 },
-    ChSRange { low: 0xebd, high: 0xebd },
-    ChSRange { low: 0xec0, high: 0xec4 },
-    ChSRange { low: 0xf40, high: 0xf47 },
-    ChSRange { low: 0xf49, high: 0xf69 },
-    ChSRange { low: 0x10a0, high: 0x10c5 },
-    ChSRange { low: 0x10d0, high: 0x10f6 },
-    ChSRange { low: 0x1100, high: 0x1100 },
-    ChSRange { low: 0x1102, high: 0x1103 },
-    ChSRange { low: 0x1105, high: 0x1107 },
-    ChSRange { low: 0x1109, high: 0x1109 },
-    ChSRange { low: 0x110b, high: 0x110c },
-    ChSRange { low: 0x110e, high: 0x1112 },
-    ChSRange { low: 0x113c, high: 0x113c },
-    ChSRange { low: 0x113e, high: 0x113e },
-    ChSRange { low: 0x1140, high: 0x1140 },
-    ChSRange { low: 0x114c, high: 0x114c },
-    ChSRange { low: 0x114e, high: 0x114e },
-    ChSRange { low: 0x1150, high: 0x1150 },
-    ChSRange { low: 0x1154, high: 0x1155 },
-    ChSRange { low: 0x1159, high: 0x1159 },
-    ChSRange { low: 0x115f, high: 0x1161 },
-    ChSRange { low: 0x1163, high: 0x1163 },
-    ChSRange { low: 0x1165, high: 0x1165 },
-    ChSRange { low: 0x1167, high: 0x1167 },
-    ChSRange { low: 0x1169, high: 0x1169 },
-    ChSRange { low: 0x116d, high: 0x116e },
-    ChSRange { low: 0x1172, high: 0x1173 },
-    ChSRange { low: 0x1175, high: 0x1175 },
-    ChSRange { low: 0x119e, high: 0x119e },
-    ChSRange { low: 0x11a8, high: 0x11a8 },
-    ChSRange { low: 0x11ab, high: 0x11ab },
-    ChSRange { low: 0x11ae, high: 0x11af },
-    ChSRange { low: 0x11b7, high: 0x11b8 },
-    ChSRange { low: 0x11ba, high: 0x11ba },
-    ChSRange { low: 0x11bc, high: 0x11c2 },
-    ChSRange { low: 0x11eb, high: 0x11eb },
-    ChSRange { low: 0x11f0, high: 0x11f0 },
-    ChSRange { low: 0x11f9, high: 0x11f9 },
-    ChSRange { low: 0x1e00, high: 0x1e9b },
-    ChSRange { low: 0x1ea0, high: 0x1ef9 },
-    ChSRange { low: 0x1f00, high: 0x1f15 },
-    ChSRange { low: 0x1f18, high: 0x1f1d },
-    ChSRange { low: 0x1f20, high: 0x1f45 },
-    ChSRange { low: 0x1f48, high: 0x1f4d },
-    ChSRange { low: 0x1f50, high: 0x1f57 },
-    ChSRange { low: 0x1f59, high: 0x1f59 },
-    ChSRange { low: 0x1f5b, high: 0x1f5b },
-    ChSRange { low: 0x1f5d, high: 0x1f5d },
-    ChSRange { low: 0x1f5f, high: 0x1f7d },
-    ChSRange { low: 0x1f80, high: 0x1fb4 },
-    ChSRange { low: 0x1fb6, high: 0x1fbc },
-    ChSRange { low: 0x1fbe, high: 0x1fbe },
-    ChSRange { low: 0x1fc2, high: 0x1fc4 },
-    ChSRange { low: 0x1fc6, high: 0x1fcc },
-    ChSRange { low: 0x1fd0, high: 0x1fd3 },
-    ChSRange { low: 0x1fd6, high: 0x1fdb },
-    ChSRange { low: 0x1fe0, high: 0x1fec },
-    ChSRange { low: 0x1ff2, high: 0x1ff4 },
-    ChSRange { low: 0x1ff6, high: 0x1ffc },
-    ChSRange { low: 0x2126, high: 0x2126 },
-    ChSRange { low: 0x212a, high: 0x212b },
-    ChSRange { low: 0x212e, high: 0x212e },
-    ChSRange { low: 0x2180, high: 0x2182 },
-    ChSRange { low: 0x3041, high: 0x3094 },
-    ChSRange { low: 0x30a1, high: 0x30fa },
-    ChSRange { low: 0x3105, high: 0x312c },
-    ChSRange { low: 0xac00, high: 0xd7a3 },
-];
-
-pub const XML_IS_BASE_CHAR_GROUP: ChRangeGroup = ChRangeGroup {
-    short_ranges: XML_IS_BASE_CHAR_SRNG,
-    long_ranges: &[],
-};
-
-static XML_IS_CHAR_SRNG: &[ChSRange] = &[
-    ChSRange { low: 0x100, high: 0xd7ff },
-    ChSRange { low: 0xe000, high: 0xfffd },
-];
-
-static XML_IS_CHAR_LRNG: &[ChLRange] = &[
-    ChLRange { low: 0x10000, high: 0x10ffff },
-];
-
-pub const XML_IS_CHAR_GROUP: ChRangeGroup = ChRangeGroup {
-    short_ranges: XML_IS_CHAR_SRNG,
-    long_ranges: XML_IS_CHAR_LRNG,
-};
+//! Character class tables, generated by build.rs from codegen/ranges.def,
+//! the definitions the C tables in codegen/ranges.inc are generated from
 
-static XML_IS_COMBINING_SRNG: &[ChSRange] = &[
-    ChSRange { low: 0x300, high: 0x345 },
-    ChSRange { low: 0x360, high: 0x361 },
-    ChSRange { low: 0x483, high: 0x486 },
-    ChSRange { low: 0x591, high: 0x5a1 },
-    ChSRange { low: 0x5a3, high: 0x5b9 },
-    ChSRange { low: 0x5bb, high: 0x5bd },
-    ChSRange { low: 0x5bf, high: 0x5bf },
-    ChSRange { low: 0x5c1, high: 0x5c2 },
-    ChSRange { low: 0x5c4, high: 0x5c4 },
-    ChSRange { low: 0x64b, high: 0x652 },
-    ChSRange { low: 0x670, high: 0x670 },
-    ChSRange { low: 0x6d6, high: 0x6dc },
-    ChSRange { low: 0x6dd, high: 0x6df },
-    ChSRange { low: 0x6e0, high: 0x6e4 },
-    ChSRange { low: 0x6e7, high: 0x6e8 },
-    ChSRange { low: 0x6ea, high: 0x6ed },
-    ChSRange { low: 0x901, high: 0x903 },
-    ChSRange { low: 0x93c, high: 0x93c },
-    ChSRange { low: 0x93e, high: 0x94c },
-    ChSRange { low: 0x94d, high: 0x94d },
-    ChSRange { low: 0x951, high: 0x954 },
-    ChSRange { low: 0x962, high: 0x963 },
-    ChSRange { low: 0x981, high: 0x983 },
-    ChSRange { low: 0x9bc, high: 0x9bc },
-    ChSRange { low: 0x9be, high: 0x9be },
-    ChSRange { low: 0x9bf, high: 0x9bf },
-    ChSRange { low: 0x9c0, high: 0x9c4 },
-    ChSRange { low: 0x9c7, high: 0x9c8 },
-    ChSRange { low: 0x9cb, high: 0x9cd },
-    ChSRange { low: 0x9d7, high: 0x9d7 },
-    ChSRange { low: 0x9e2, high: 0x9e3 },
-    ChSRange { low: 0xa02, high: 0xa02 },
-    ChSRange { low: 0xa3c, high: 0xa3c },
-    ChSRange { low: 0xa3e, high: 0xa3e },
-    ChSRange { low: 0xa3f, high: 0xa3f },
-    ChSRange { low: 0xa40, high: 0xa42 },
-    ChSRange { low: 0xa47, high: 0xa48 },
-    ChSRange { low: 0xa4b, high: 0xa4d },
-    ChSRange { low: 0xa70, high: 0xa71 },
-    ChSRange { low: 0xa81, high: 0xa83 },
-    ChSRange { low: 0xabc, high: 0xabc },
-    ChSRange { low: 0xabe, high: 0xac5 },
-    ChSRange { low: 0xac7, high: 0xac9 },
-    ChSRange { low: 0xacb, high: 0xacd },
-    ChSRange { low: 0xb01, high: 0xb03 },
-    ChSRange { low: 0xb3c, high: 0xb3c },
-    ChSRange { low: 0xb3e, high: 0xb43 },
-    ChSRange { low: 0xb47, high: 0xb48 },
-    ChSRange { low: 0xb4b, high: 0xb4d },
-    ChSRange { low: 0xb56, high: 0xb57 },
-    ChSRange { low: 0xb82, high: 0xb83 },
-    ChSRange { low: 0xbbe, high: 0xbc2 },
-    ChSRange { low: 0xbc6, high: 0xbc8 },
-    ChSRange { low: 0xbca, high: 0xbcd },
-    ChSRange { low: 0xbd7, high: 0xbd7 },
-    ChSRange { low: 0xc01, high: 0xc03 },
-    ChSRange { low: 0xc3e, high: 0xc44 },
-    ChSRange { low: 0xc46, high: 0xc48 },
-    ChSRange { low: 0xc4a, high: 0xc4d },
-    ChSRange { low: 0xc55, high: 0xc56 },
-    ChSRange { low: 0xc82, high: 0xc83 },
-    ChSRange { low: 0xcbe, high: 0xcc4 },
-    ChSRange { low: 0xcc6, high: 0xcc8 },
-    ChSRange { low: 0xcca, high: 0xccd },
-    ChSRange { low: 0xcd5, high: 0xcd6 },
-    ChSRange { low: 0xd02, high: 0xd03 },
-    ChSRange { low: 0xd3e, high: 0xd43 },
-    ChSRange { low: 0xd46, high: 0xd48 },
-    ChSRange { low: 0xd4a, high: 0xd4d },
-    ChSRange { low: 0xd57, high: 0xd57 },
-    ChSRange { low: 0xe31, high: 0xe31 },
-    ChSRange { low: 0xe34, high: 0xe3a },
-    ChSRange { low: 0xe47, high: 0xe4e },
-    ChSRange { low: 0xeb1, high: 0xeb1 },
-    ChSRange { low: 0xeb4, high: 0xeb9 },
-    ChSRange { low: 0xebb, high: 0xebc },
-    ChSRange { low: 0xec8, high: 0xecd },
-    ChSRange { low: 0xf18, high: 0xf19 },
-    ChSRange { low: 0xf35, high: 0xf35 },
-    ChSRange { low: 0xf37, high: 0xf37 },
-    ChSRange { low: 0xf39, high: 0xf39 },
-    ChSRange { low: 0xf3e, high: 0xf3e },
-    ChSRange { low: 0xf3f, high: 0xf3f },
-    ChSRange { low: 0xf71, high: 0xf84 },
-    ChSRange { low: 0xf86, high: 0xf8b },
-    ChSRange { low: 0xf90, high: 0xf95 },
-    ChSRange { low: 0xf97, high: 0xf97 },
-    ChSRange { low: 0xf99, high: 0xfad },
-    ChSRange { low: 0xfb1, high: 0xfb7 },
-    ChSRange { low: 0xfb9, high: 0xfb9 },
-    ChSRange { low: 0x20d0, high: 0x20dc },
-    ChSRange { low: 0x20e1, high: 0x20e1 },
-    ChSRange { low: 0x302a, high: 0x302f },
-    ChSRange { low: 0x3099, high: 0x3099 },
-    ChSRange { low: 0x309a, high: 0x309a },
-];
-
-pub const XML_IS_COMBINING_GROUP: ChRangeGroup = ChRangeGroup {
-    short_ranges: XML_IS_COMBINING_SRNG,
-    long_ranges: &[],
-};
-
-static XML_IS_DIGIT_SRNG: &[ChSRange] = &[
-    ChSRange { low: 0x660, high: 0x669 },
-    ChSRange { low: 0x6f0, high: 0x6f9 },
-    ChSRange { low: 0x966, high: 0x96f },
-    ChSRange { low: 0x9e6, high: 0x9ef },
-    ChSRange { low: 0xa66, high: 0xa6f },
-    ChSRange { low: 0xae6, high: 0xaef },
-    ChSRange { low: 0xb66, high: 0xb6f },
-    ChSRange { low: 0xbe7, high: 0xbef },
-    ChSRange { low: 0xc66, high: 0xc6f },
-    ChSRange { low: 0xce6, high: 0xcef },
-    ChSRange { low: 0xd66, high: 0xd6f },
-    ChSRange { low: 0xe50, high: 0xe59 },
-    ChSRange { low: 0xed0, high: 0xed9 },
-    ChSRange { low: 0xf20, high: 0xf29 },
-];
-
-pub const XML_IS_DIGIT_GROUP: ChRangeGroup = ChRangeGroup {
-    short_ranges: XML_IS_DIGIT_SRNG,
-    long_ranges: &[],
-};
-
-static XML_IS_EXTENDER_SRNG: &[ChSRange] = &[
-    ChSRange { low: 0x2d0, high: 0x2d0 },
-    ChSRange { low: 0x2d1, high: 0x2d1 },
-    ChSRange { low: 0x387, high: 0x387 },
-    ChSRange { low: 0x640, high: 0x640 },
-    ChSRange { low: 0xe46, high: 0xe46 },
-    ChSRange { low: 0xec6, high: 0xec6 },
-    ChSRange { low: 0x3005, high: 0x3005 },
-    ChSRange { low: 0x3031, high: 0x3035 },
-    ChSRange { low: 0x309d, high: 0x309e },
-    ChSRange { low: 0x30fc, high: 0x30fe },
-];
-
-pub const XML_IS_EXTENDER_GROUP: ChRangeGroup = ChRangeGroup {
-    short_ranges: XML_IS_EXTENDER_SRNG,
-    long_ranges: &[],
-};
-
-static XML_IS_IDEOGRAPHIC_SRNG: &[ChSRange] = &[
-    ChSRange { low: 0x3007, high: 0x3007 },
-    ChSRange { low: 0x3021, high: 0x3029 },
-    ChSRange { low: 0x4e00, high: 0x9fa5 },
-];
+use super::core::{ChSRange, ChLRange, ChRangeGroup};
 
-pub const XML_IS_IDEOGRAPHIC_GROUP: ChRangeGroup = ChRangeGroup {
-    short_ranges: XML_IS_IDEOGRAPHIC_SRNG,
-    long_ranges: &[],
-};
\ No newline at end of file
+include!(concat!(env!("OUT_DIR"), "/chvalid_ranges.rs"));
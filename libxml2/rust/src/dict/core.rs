@@ -0,0 +1,538 @@
+#![allow(clippy::missing_safety_doc)]
+
+use std::slice;
+
+const MAX_FILL_NUM: u32 = 7;
+const MAX_FILL_DENOM: u32 = 8;
+const MIN_HASH_SIZE: u32 = 8;
+const MAX_HASH_SIZE: u32 = 1 << 31;
+
+/// Size of the first string pool, grown by 4 for each new pool.
+const FIRST_POOL_SIZE: usize = 1000;
+/// Names are limited to half of `INT_MAX`, like in C.
+const MAX_NAME_LEN: usize = i32::MAX as usize / 2;
+
+/// Constants of the aHash fallback hasher.
+const MULTIPLE: u64 = 6364136223846793005;
+const PAD: [u64; 2] = [0x243f_6a88_85a3_08d3, 0x1319_8a2e_0370_7344];
+
+#[inline]
+fn folded_multiply(x: u64, y: u64) -> u64 {
+    let full = (x as u128).wrapping_mul(y as u128);
+    (full as u64) ^ ((full >> 64) as u64)
+}
+
+#[inline]
+fn read_u64(b: &[u8], at: usize) -> u64 {
+    u64::from_le_bytes(b[at..at + 8].try_into().unwrap())
+}
+
+#[inline]
+fn read_u32(b: &[u8], at: usize) -> u64 {
+    u32::from_le_bytes(b[at..at + 4].try_into().unwrap()) as u64
+}
+
+/// Seeded hash of a name, the folded multiply of the aHash fallback over
+/// 16-byte blocks.
+///
+/// Short names, the common case, take two possibly overlapping loads and
+/// a single multiply, with no loop. Like the GoodOAAT hash in C, this is
+/// fast rather than collision resistant; the per-dict seed only keeps
+/// bucket order unpredictable. The upper bit of the result is set, since
+/// 0 marks a free bucket.
+pub fn hash_name(seed: u32, name: &[u8]) -> u32 {
+    let len = name.len();
+    let mut acc = folded_multiply(seed as u64 ^ PAD[0], MULTIPLE).wrapping_add(len as u64);
+    if len <= 16 {
+        let (a, b) = if len >= 8 {
+            (read_u64(name, 0), read_u64(name, len - 8))
+        } else if len >= 4 {
+            (read_u32(name, 0), read_u32(name, len - 4))
+        } else if len > 0 {
+            let a = name[0] as u64 | (name[len / 2] as u64) << 8 | (name[len - 1] as u64) << 16;
+            (a, 0)
+        } else {
+            (0, 0)
+        };
+        acc = folded_multiply(a ^ acc, b ^ PAD[1]);
+    } else {
+        let mut at = 0;
+        while len - at > 16 {
+            acc = folded_multiply(read_u64(name, at) ^ acc, read_u64(name, at + 8) ^ PAD[1]);
+            at += 16;
+        }
+        acc = folded_multiply(
+            read_u64(name, len - 16) ^ acc,
+            read_u64(name, len - 8) ^ PAD[1],
+        );
+    }
+    let h = folded_multiply(acc, MULTIPLE);
+    (h ^ h >> 32) as u32 | MAX_HASH_SIZE
+}
+
+/// A string to look up: a plain name or the QName `prefix:name`.
+#[derive(Debug, Clone, Copy)]
+pub enum Key<'a> {
+    Name(&'a [u8]),
+    QName(&'a [u8], &'a [u8]),
+}
+
+impl Key<'_> {
+    /// Length of the interned string.
+    pub fn len(&self) -> usize {
+        match *self {
+            Key::Name(name) => name.len(),
+            Key::QName(prefix, name) => prefix.len() + 1 + name.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Hash value of the interned string. A QName hashes like the
+    /// `prefix:name` string, so both kinds of lookup find the same entry.
+    pub fn hash(&self, seed: u32) -> u32 {
+        match *self {
+            Key::Name(name) => hash_name(seed, name),
+            Key::QName(prefix, name) => {
+                let mut small = [0u8; 64];
+                let mut large = Vec::new();
+                let buf = if self.len() <= small.len() {
+                    &mut small[..self.len()]
+                } else {
+                    large.resize(self.len(), 0);
+                    &mut large[..]
+                };
+                buf[..prefix.len()].copy_from_slice(prefix);
+                buf[prefix.len()] = b':';
+                buf[prefix.len() + 1..].copy_from_slice(name);
+                hash_name(seed, buf)
+            }
+        }
+    }
+
+    fn matches(&self, s: &[u8]) -> bool {
+        match *self {
+            Key::Name(name) => s == name,
+            Key::QName(prefix, name) => {
+                s.len() == self.len()
+                    && s.starts_with(prefix)
+                    && s[prefix.len()] == b':'
+                    && s.ends_with(name)
+            }
+        }
+    }
+}
+
+/// Combine two hash values, as `xmlDictCombineHash`. The upper bit is
+/// always set, so this works on 31-bit values.
+pub fn combine_hash(v1: u32, v2: u32) -> u32 {
+    let rol31 = v2 << 5 | (v2 & 0x7FFF_FFFF) >> 26;
+    (v1 ^ v2).wrapping_add(rol31) | 0x8000_0000
+}
+
+/// A hash table slot. `hash_value` 0 means unoccupied.
+#[derive(Debug, Clone, Copy)]
+pub struct Entry {
+    pub hash_value: u32,
+    len: u32,
+    pub name: *const u8,
+}
+
+impl Entry {
+    const EMPTY: Entry = Entry {
+        hash_value: 0,
+        len: 0,
+        name: std::ptr::null(),
+    };
+
+    fn bytes(&self) -> &[u8] {
+        // SAFETY: occupied entries point into a pool of the dict that
+        // holds them, and pools are never moved or freed before the dict.
+        unsafe { slice::from_raw_parts(self.name, self.len as usize) }
+    }
+}
+
+/// Fixed-size chunk of interned strings. The buffer is never reallocated,
+/// so the strings keep their address for the lifetime of the dict.
+struct Pool {
+    array: Vec<u8>,
+    nb_strings: usize,
+}
+
+impl Pool {
+    fn size(&self) -> usize {
+        self.array.capacity()
+    }
+
+    fn avail(&self) -> usize {
+        self.size() - self.array.len()
+    }
+}
+
+/// A string interner, the Rust side of `xmlDict`.
+///
+/// Strings are stored NUL-terminated in pools and found through a Robin
+/// Hood hash table, with the same growth, pool sizing and limit rules as
+/// `dict.c`.
+pub struct Dict {
+    table: Vec<Entry>,
+    nb_elems: u32,
+    /// Oldest first. New strings go to the newest pool with room, as the
+    /// C list is searched from its head.
+    pools: Vec<Pool>,
+    pub seed: u32,
+    pub limit: usize,
+}
+
+impl Dict {
+    pub fn new(seed: u32) -> Dict {
+        Dict {
+            table: Vec::new(),
+            nb_elems: 0,
+            pools: Vec::new(),
+            seed,
+            limit: 0,
+        }
+    }
+
+    /// Number of strings in this dict, not counting a parent.
+    pub fn len(&self) -> usize {
+        self.nb_elems as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nb_elems == 0
+    }
+
+    /// Bytes allocated for strings, as `xmlDictGetUsage`.
+    pub fn usage(&self) -> usize {
+        self.pools.iter().map(Pool::size).sum()
+    }
+
+    /// Whether `ptr` points into one of this dict's pools, up to and
+    /// including the first free byte like in C.
+    pub fn owns(&self, ptr: *const u8) -> bool {
+        let addr = ptr as usize;
+        self.pools.iter().any(|pool| {
+            let start = pool.array.as_ptr() as usize;
+            addr >= start && addr <= start + pool.array.len()
+        })
+    }
+
+    /// Find the entry for `key` without adding it.
+    pub fn get(&self, key: Key) -> Option<Entry> {
+        if self.table.is_empty() {
+            return None;
+        }
+        let (pos, found) = self.find_entry(key, key.hash(self.seed));
+        found.then(|| self.table[pos])
+    }
+
+    /// Find `key` in this dict, then in `sub`, and add it to this dict if
+    /// it is in neither and `update` is set. Returns `None` when the key is
+    /// missing or cannot be added.
+    pub fn lookup(&mut self, key: Key, sub: Option<&Dict>, update: bool) -> Option<Entry> {
+        let klen = key.len();
+        match key {
+            Key::Name(name) if name.len() > MAX_NAME_LEN => return None,
+            Key::QName(prefix, name)
+                if name.len() > MAX_NAME_LEN || prefix.len() >= MAX_NAME_LEN - name.len() =>
+            {
+                return None
+            }
+            _ => {}
+        }
+        if self.limit > 0 && klen >= self.limit {
+            return None;
+        }
+
+        let hash_value = key.hash(self.seed);
+        let mut pos = 0;
+        let new_size = if self.table.is_empty() {
+            MIN_HASH_SIZE
+        } else {
+            let (found_pos, found) = self.find_entry(key, hash_value);
+            if found {
+                return Some(self.table[found_pos]);
+            }
+            pos = found_pos;
+            let size = self.table.len() as u32;
+            if self.nb_elems + 1 > size / MAX_FILL_DENOM * MAX_FILL_NUM {
+                if size >= MAX_HASH_SIZE {
+                    return None;
+                }
+                size * 2
+            } else {
+                0
+            }
+        };
+
+        if let Some(sub) = sub.filter(|sub| !sub.table.is_empty()) {
+            let sub_hash = if sub.seed == self.seed {
+                hash_value
+            } else {
+                key.hash(sub.seed)
+            };
+            let (sub_pos, found) = sub.find_entry(key, sub_hash);
+            if found {
+                return Some(sub.table[sub_pos]);
+            }
+        }
+
+        if !update {
+            return None;
+        }
+
+        if new_size > 0 {
+            self.grow(new_size as usize);
+            pos = self.insert_pos(hash_value);
+        }
+
+        let name = self.add_string(key)?;
+        let mut carry = Entry {
+            hash_value,
+            len: klen as u32,
+            name,
+        };
+        let entry = carry;
+        // Shift the remainder of the probe sequence to the right.
+        let mask = self.table.len() - 1;
+        loop {
+            std::mem::swap(&mut self.table[pos], &mut carry);
+            if carry.hash_value == 0 {
+                break;
+            }
+            pos = (pos + 1) & mask;
+        }
+        self.nb_elems += 1;
+        Some(entry)
+    }
+
+    /// Robin Hood probe for `key`. Returns the matching slot, or the slot a
+    /// new entry goes to.
+    fn find_entry(&self, key: Key, hash_value: u32) -> (usize, bool) {
+        let mask = self.table.len() as u32 - 1;
+        let mut pos = hash_value & mask;
+        let mut entry = &self.table[pos as usize];
+        if entry.hash_value == 0 {
+            return (pos as usize, false);
+        }
+        // Stop when the displacement of the entry is smaller than the
+        // displacement of the key we look for.
+        let mut displ = 0u32;
+        loop {
+            if entry.hash_value == hash_value && key.matches(entry.bytes()) {
+                return (pos as usize, true);
+            }
+            displ += 1;
+            pos = (pos + 1) & mask;
+            entry = &self.table[pos as usize];
+            if entry.hash_value == 0 || (pos.wrapping_sub(entry.hash_value) & mask) < displ {
+                return (pos as usize, false);
+            }
+        }
+    }
+
+    /// Slot for a new entry right after growing.
+    fn insert_pos(&self, hash_value: u32) -> usize {
+        let mask = self.table.len() as u32 - 1;
+        let mut displ = 0u32;
+        let mut pos = hash_value & mask;
+        while self.table[pos as usize].hash_value != 0
+            && (pos.wrapping_sub(self.table[pos as usize].hash_value) & mask) >= displ
+        {
+            displ += 1;
+            pos = (pos + 1) & mask;
+        }
+        pos as usize
+    }
+
+    fn grow(&mut self, size: usize) {
+        let old = std::mem::replace(&mut self.table, vec![Entry::EMPTY; size]);
+        if old.is_empty() {
+            return;
+        }
+        // Robin Hood order is kept when copying starts at the beginning of
+        // a probe sequence and the size grows by an integer factor.
+        let start = old.iter().position(|e| e.hash_value == 0).unwrap_or(0);
+        let mask = size - 1;
+        for i in 0..old.len() {
+            let entry = old[(start + i) % old.len()];
+            if entry.hash_value != 0 {
+                let mut pos = entry.hash_value as usize & mask;
+                while self.table[pos].hash_value != 0 {
+                    pos = (pos + 1) & mask;
+                }
+                self.table[pos] = entry;
+            }
+        }
+    }
+
+    /// Copy `key` into a pool, NUL-terminated, and return its address.
+    fn add_string(&mut self, key: Key) -> Option<*const u8> {
+        let klen = key.len();
+        let pool = match self.pools.iter().rposition(|pool| pool.avail() > klen) {
+            Some(i) => &mut self.pools[i],
+            None => {
+                if self.limit > 0 && self.usage() > self.limit {
+                    return None;
+                }
+                let mut size = match self.pools.iter().map(Pool::size).max() {
+                    None => FIRST_POOL_SIZE,
+                    Some(size) => size.checked_mul(4)?,
+                };
+                if size / 4 < klen {
+                    size = klen.checked_mul(4)?;
+                }
+                self.pools.push(Pool {
+                    array: Vec::with_capacity(size),
+                    nb_strings: 0,
+                });
+                self.pools.last_mut().unwrap()
+            }
+        };
+        let start = pool.array.len();
+        match key {
+            Key::Name(name) => pool.array.extend_from_slice(name),
+            Key::QName(prefix, name) => {
+                pool.array.extend_from_slice(prefix);
+                pool.array.push(b':');
+                pool.array.extend_from_slice(name);
+            }
+        }
+        pool.array.push(0);
+        pool.nb_strings += 1;
+        // The capacity was reserved up front, so this never reallocates and
+        // earlier strings stay where they are.
+        Some(pool.array[start..].as_ptr())
+    }
+}
+
+/// One step of the xoroshiro64** generator behind `xmlRandom`.
+pub fn xoroshiro64ss(s: &mut [u32; 2]) -> u32 {
+    let s0 = s[0];
+    let mut s1 = s[1];
+    let result = s0.wrapping_mul(0x9E37_79BB).rotate_left(5).wrapping_mul(5);
+    s1 ^= s0;
+    s[0] = s0.rotate_left(26) ^ s1 ^ (s1 << 9);
+    s[1] = s1.rotate_left(13);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::CStr;
+
+    fn text(entry: Entry) -> &'static [u8] {
+        unsafe { CStr::from_ptr(entry.name as *const _).to_bytes() }
+    }
+
+    #[test]
+    fn test_hash_name() {
+        assert_eq!(
+            Key::QName(b"xsl", b"template").hash(7),
+            hash_name(7, b"xsl:template")
+        );
+        let long = [b'p'; 100];
+        assert_eq!(
+            Key::QName(&long, b"x").hash(7),
+            hash_name(7, &[&long[..], b":x"].concat())
+        );
+        // Every length class, and every byte of each, counts.
+        for len in 0..40 {
+            let base: Vec<u8> = (0..len as u8).map(|i| b'a' + i % 26).collect();
+            for i in 0..len {
+                let mut other = base.clone();
+                other[i] = b'_';
+                assert_ne!(hash_name(3, &base), hash_name(3, &other), "{} {}", len, i);
+            }
+            assert_ne!(
+                hash_name(3, &base),
+                hash_name(3, &[&base[..], b"a"].concat())
+            );
+        }
+        assert_ne!(hash_name(1, b"a"), hash_name(2, b"a"));
+        assert_ne!(hash_name(1, b""), hash_name(1, b"\0"));
+        assert!(hash_name(0, b"") & MAX_HASH_SIZE != 0);
+    }
+
+    #[test]
+    fn test_lookup_interns() {
+        let mut dict = Dict::new(3);
+        let a = dict.lookup(Key::Name(b"item"), None, true).unwrap();
+        let b = dict.lookup(Key::Name(b"item"), None, true).unwrap();
+        assert_eq!(a.name, b.name);
+        assert_eq!(text(a), b"item");
+        let q = dict.lookup(Key::QName(b"ns", b"item"), None, true).unwrap();
+        assert_eq!(text(q), b"ns:item");
+        let same = dict.lookup(Key::Name(b"ns:item"), None, false).unwrap();
+        assert_eq!(same.name, q.name);
+        assert!(dict.lookup(Key::Name(b"other"), None, false).is_none());
+        assert_eq!(dict.len(), 2);
+        assert!(dict.owns(a.name));
+        assert!(!dict.owns(b"item".as_ptr()));
+    }
+
+    #[test]
+    fn test_growth_keeps_pointers() {
+        let mut dict = Dict::new(0);
+        let names: Vec<Vec<u8>> = (0..5000).map(|i| format!("n{}", i).into_bytes()).collect();
+        let first: Vec<_> = names
+            .iter()
+            .map(|n| dict.lookup(Key::Name(n), None, true).unwrap().name)
+            .collect();
+        for (n, ptr) in names.iter().zip(&first) {
+            let entry = dict.get(Key::Name(n)).unwrap();
+            assert_eq!(entry.name, *ptr);
+            assert_eq!(text(entry), &n[..]);
+        }
+        assert_eq!(dict.len(), 5000);
+        assert!(dict.table.len().is_power_of_two());
+    }
+
+    #[test]
+    fn test_sub_dict() {
+        let mut sub = Dict::new(9);
+        let shared = sub.lookup(Key::Name(b"shared"), None, true).unwrap();
+        let mut dict = Dict::new(9);
+        let found = dict.lookup(Key::Name(b"shared"), Some(&sub), true).unwrap();
+        assert_eq!(found.name, shared.name);
+        assert!(dict.is_empty());
+        let own = dict.lookup(Key::Name(b"own"), Some(&sub), true).unwrap();
+        assert!(dict.owns(own.name) && !sub.owns(own.name));
+    }
+
+    #[test]
+    fn test_xoroshiro64ss() {
+        // Reference values of the C generator for the state {1, 2}.
+        let mut state = [1, 2];
+        let first = xoroshiro64ss(&mut state);
+        assert_eq!(first, 0x9E37_79BBu32.rotate_left(5).wrapping_mul(5));
+        assert_eq!(
+            state,
+            [1u32.rotate_left(26) ^ 3 ^ (3 << 9), 3u32.rotate_left(13)]
+        );
+    }
+
+    #[test]
+    fn test_pools_and_limit() {
+        let mut dict = Dict::new(0);
+        dict.lookup(Key::Name(b"a"), None, true).unwrap();
+        assert_eq!(dict.usage(), 1000);
+        let long = vec![b'x'; 1500];
+        dict.lookup(Key::Name(&long), None, true).unwrap();
+        assert_eq!(dict.usage(), 1000 + 6000);
+
+        let mut limited = Dict::new(0);
+        limited.limit = 10;
+        assert!(limited
+            .lookup(Key::Name(b"0123456789"), None, true)
+            .is_none());
+        assert!(limited
+            .lookup(Key::Name(b"012345678"), None, true)
+            .is_some());
+    }
+}
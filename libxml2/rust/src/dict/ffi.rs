@@ -0,0 +1,287 @@
+#![allow(clippy::missing_safety_doc)]
+
+use std::collections::hash_map::RandomState;
+use std::ffi::CStr;
+use std::hash::{BuildHasher, Hasher};
+use std::os::raw::{c_char, c_int, c_uint, c_void};
+use std::ptr;
+use std::slice;
+use std::sync::atomic::{AtomicI32, Ordering};
+use std::sync::Mutex;
+
+use super::core::{self, Dict, Key};
+use crate::static_bindings::{xmlChar, xmlDict, xmlInitParser};
+
+// Ensure symbols are exported for linking
+#[cfg(feature = "rust-dict")]
+#[used]
+static DICT_FFI_LINKAGE: () = ();
+
+extern "C" {
+    fn xmlGetLocalRngState() -> *mut c_uint;
+}
+
+/// What an `xmlDict` pointer points to.
+struct DictHandle {
+    refs: AtomicI32,
+    dict: Dict,
+    sub: *mut xmlDict,
+}
+
+/// `xmlHashedString` from `private/dict.h`.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct xmlHashedString {
+    pub hashValue: c_uint,
+    pub name: *const xmlChar,
+}
+
+unsafe fn as_handle<'a>(dict: *const xmlDict) -> Option<&'a DictHandle> {
+    (dict as *const DictHandle).as_ref()
+}
+
+unsafe fn cstr<'a>(s: *const xmlChar) -> &'a [u8] {
+    CStr::from_ptr(s as *const c_char).to_bytes()
+}
+
+/// `name` up to `len` bytes or its first NUL; the whole string if `len` is
+/// negative.
+unsafe fn name_bytes<'a>(name: *const xmlChar, len: c_int) -> &'a [u8] {
+    if len < 0 {
+        return cstr(name);
+    }
+    let nul = libc::memchr(name as *const c_void, 0, len as usize);
+    let end = if nul.is_null() {
+        len as usize
+    } else {
+        nul as usize - name as usize
+    };
+    slice::from_raw_parts(name, end)
+}
+
+unsafe fn lookup_internal(
+    dict: *mut xmlDict,
+    prefix: *const xmlChar,
+    name: *const xmlChar,
+    len: c_int,
+    update: bool,
+) -> Option<core::Entry> {
+    if dict.is_null() || name.is_null() {
+        return None;
+    }
+    let key = if prefix.is_null() {
+        Key::Name(name_bytes(name, len))
+    } else {
+        Key::QName(cstr(prefix), cstr(name))
+    };
+    let handle = &mut *(dict as *mut DictHandle);
+    let sub = as_handle(handle.sub).map(|sub| &sub.dict);
+    handle.dict.lookup(key, sub, update)
+}
+
+/// Alias for `xmlInitParser`.
+#[no_mangle]
+pub unsafe extern "C" fn xmlInitializeDict() -> c_int {
+    xmlInitParser();
+    0
+}
+
+/// Nothing to set up: reference counts are atomic.
+#[no_mangle]
+pub unsafe extern "C" fn xmlInitDictInternal() {}
+
+/// No-op, kept for the ABI.
+#[no_mangle]
+pub unsafe extern "C" fn xmlDictCleanup() {}
+
+/// Nothing to free, see `xmlInitDictInternal`.
+#[no_mangle]
+pub unsafe extern "C" fn xmlCleanupDictInternal() {}
+
+/// Create a new dictionary.
+#[no_mangle]
+pub unsafe extern "C" fn xmlDictCreate() -> *mut xmlDict {
+    xmlInitParser();
+    let handle = DictHandle {
+        refs: AtomicI32::new(1),
+        dict: Dict::new(xmlRandom()),
+        sub: ptr::null_mut(),
+    };
+    Box::into_raw(Box::new(handle)) as *mut xmlDict
+}
+
+/// Create a new dictionary, inheriting strings from the read-only
+/// dictionary `sub`.
+#[no_mangle]
+pub unsafe extern "C" fn xmlDictCreateSub(sub: *mut xmlDict) -> *mut xmlDict {
+    let dict = xmlDictCreate();
+    if let Some(parent) = as_handle(sub) {
+        let handle = &mut *(dict as *mut DictHandle);
+        handle.dict.seed = parent.dict.seed;
+        handle.sub = sub;
+        xmlDictReference(sub);
+    }
+    dict
+}
+
+/// Increment the reference counter of a dictionary.
+#[no_mangle]
+pub unsafe extern "C" fn xmlDictReference(dict: *mut xmlDict) -> c_int {
+    match as_handle(dict) {
+        Some(handle) => {
+            handle.refs.fetch_add(1, Ordering::Relaxed);
+            0
+        }
+        None => -1,
+    }
+}
+
+/// Drop a reference to the dictionary, freeing it with the last one.
+#[no_mangle]
+pub unsafe extern "C" fn xmlDictFree(dict: *mut xmlDict) {
+    let Some(handle) = as_handle(dict) else {
+        return;
+    };
+    if handle.refs.fetch_sub(1, Ordering::AcqRel) > 1 {
+        return;
+    }
+    let handle = Box::from_raw(dict as *mut DictHandle);
+    xmlDictFree(handle.sub);
+}
+
+/// Check if a string is owned by the dictionary or one of its parents.
+#[no_mangle]
+pub unsafe extern "C" fn xmlDictOwns(dict: *mut xmlDict, str_: *const xmlChar) -> c_int {
+    if dict.is_null() || str_.is_null() {
+        return -1;
+    }
+    let mut cur = as_handle(dict);
+    while let Some(handle) = cur {
+        if handle.dict.owns(str_) {
+            return 1;
+        }
+        cur = as_handle(handle.sub);
+    }
+    0
+}
+
+/// Number of strings in the dictionary and its direct parent.
+#[no_mangle]
+pub unsafe extern "C" fn xmlDictSize(dict: *mut xmlDict) -> c_int {
+    match as_handle(dict) {
+        Some(handle) => {
+            let sub = as_handle(handle.sub).map_or(0, |sub| sub.dict.len());
+            (handle.dict.len() + sub) as c_int
+        }
+        None => -1,
+    }
+}
+
+/// Set a size limit for the dictionary, returning the previous one.
+#[no_mangle]
+pub unsafe extern "C" fn xmlDictSetLimit(dict: *mut xmlDict, limit: usize) -> usize {
+    if dict.is_null() {
+        return 0;
+    }
+    let handle = &mut *(dict as *mut DictHandle);
+    std::mem::replace(&mut handle.dict.limit, limit)
+}
+
+/// Memory used by the dictionary for strings.
+#[no_mangle]
+pub unsafe extern "C" fn xmlDictGetUsage(dict: *mut xmlDict) -> usize {
+    as_handle(dict).map_or(0, |handle| handle.dict.usage())
+}
+
+/// Hash value of a C string, consistent with `xmlDictLookupHashed`.
+#[no_mangle]
+pub unsafe extern "C" fn xmlDictComputeHash(
+    dict: *const xmlDict,
+    string: *const xmlChar,
+) -> c_uint {
+    core::hash_name(as_handle(dict).unwrap().dict.seed, cstr(string))
+}
+
+/// Combine two hash values.
+#[no_mangle]
+pub extern "C" fn xmlDictCombineHash(v1: c_uint, v2: c_uint) -> c_uint {
+    core::combine_hash(v1, v2)
+}
+
+/// Lookup a string and add it to the dictionary if it wasn't found.
+#[no_mangle]
+pub unsafe extern "C" fn xmlDictLookup(
+    dict: *mut xmlDict,
+    name: *const xmlChar,
+    len: c_int,
+) -> *const xmlChar {
+    lookup_internal(dict, ptr::null(), name, len, true).map_or(ptr::null(), |e| e.name)
+}
+
+/// Like `xmlDictLookup`, also returning the hash value.
+#[no_mangle]
+pub unsafe extern "C" fn xmlDictLookupHashed(
+    dict: *mut xmlDict,
+    name: *const xmlChar,
+    len: c_int,
+) -> xmlHashedString {
+    match lookup_internal(dict, ptr::null(), name, len, true) {
+        Some(entry) => xmlHashedString {
+            hashValue: entry.hash_value,
+            name: entry.name,
+        },
+        None => xmlHashedString {
+            hashValue: 0,
+            name: ptr::null(),
+        },
+    }
+}
+
+/// Check if a string exists in the dictionary.
+#[no_mangle]
+pub unsafe extern "C" fn xmlDictExists(
+    dict: *mut xmlDict,
+    name: *const xmlChar,
+    len: c_int,
+) -> *const xmlChar {
+    lookup_internal(dict, ptr::null(), name, len, false).map_or(ptr::null(), |e| e.name)
+}
+
+/// Lookup the QName `prefix:name` and add it to the dictionary if it
+/// wasn't found.
+#[no_mangle]
+pub unsafe extern "C" fn xmlDictQLookup(
+    dict: *mut xmlDict,
+    prefix: *const xmlChar,
+    name: *const xmlChar,
+) -> *const xmlChar {
+    lookup_internal(dict, prefix, name, -1, true).map_or(ptr::null(), |e| e.name)
+}
+
+static GLOBAL_RNG: Mutex<[u32; 2]> = Mutex::new([0; 2]);
+
+/// Seed the global PRNG from the OS, through the random keys of std's
+/// `RandomState`.
+#[no_mangle]
+pub extern "C" fn xmlInitRandom() {
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u8(0);
+    let seed = hasher.finish();
+    *GLOBAL_RNG.lock().unwrap_or_else(|e| e.into_inner()) = [seed as u32, (seed >> 32) as u32];
+}
+
+/// Nothing to free.
+#[no_mangle]
+pub extern "C" fn xmlCleanupRandom() {}
+
+/// Random value from the global PRNG.
+#[no_mangle]
+pub extern "C" fn xmlGlobalRandom() -> c_uint {
+    core::xoroshiro64ss(&mut GLOBAL_RNG.lock().unwrap_or_else(|e| e.into_inner()))
+}
+
+/// Random value from the thread-local PRNG.
+#[no_mangle]
+pub unsafe extern "C" fn xmlRandom() -> c_uint {
+    core::xoroshiro64ss(&mut *(xmlGetLocalRngState() as *mut [u32; 2]))
+}
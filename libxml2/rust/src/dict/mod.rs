@@ -2,4 +2,7 @@
 //!
 //! String dictionary/interning service with hash table and memory pools
 
-// Stub implementation - will be filled in during actual porting
+pub mod core;
+pub mod ffi;
+
+pub use core::*;
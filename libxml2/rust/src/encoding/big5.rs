@@ -0,0 +1,109 @@
+//! Big5 converters, built with the `big5` feature.
+//!
+//! Single bytes are ASCII. A lead byte in 0xA1-0xF9 and a trail byte in
+//! 0x40-0x7E or 0xA1-0xFE select a character; the table leaves out the
+//! vendor extensions, so unassigned pairs are errors.
+
+use super::big5_table::{BIG5, BIG5_INVERSE};
+use super::core::{decode_utf8, encode_utf8, utf8_size, Converted, ENC_ERR_INPUT, ENC_ERR_SPACE};
+
+/// Trail bytes per lead byte.
+const TRAILS: usize = 157;
+
+/// Table index of a lead and trail byte.
+fn table_index(lead: u8, trail: u8) -> Option<usize> {
+    let row = match lead {
+        0xA1..=0xF9 => (lead - 0xA1) as usize,
+        _ => return None,
+    };
+    let cell = match trail {
+        0x40..=0x7E => trail - 0x40,
+        0xA1..=0xFE => trail - 0x62,
+        _ => return None,
+    };
+    Some(row * TRAILS + cell as usize)
+}
+
+/// Lead and trail byte of a table index.
+fn table_bytes(index: usize) -> [u8; 2] {
+    let (row, cell) = (index / TRAILS, (index % TRAILS) as u8);
+    let trail = if cell < 0x3F {
+        cell + 0x40
+    } else {
+        cell + 0x62
+    };
+    [row as u8 + 0xA1, trail]
+}
+
+/// Convert Big5 to UTF-8. Bytes that start no character, trail bytes out
+/// of range and unassigned pairs are invalid input. A lead byte at the end
+/// of the input is left unread.
+pub fn big5_to_utf8(out: &mut [u8], input: &[u8]) -> Converted {
+    let mut i = 0;
+    let mut o = 0;
+    while i < input.len() {
+        let b = input[i];
+        let (c, len) = if b < 0x80 {
+            (b as u32, 1)
+        } else {
+            let Some(&trail) = input.get(i + 1) else {
+                if table_index(b, 0x40).is_some() {
+                    break;
+                }
+                return Converted::stop(i, o, ENC_ERR_INPUT);
+            };
+            match table_index(b, trail).map(|k| BIG5[k]) {
+                Some(c) if c != 0 => (c as u32, 2),
+                _ => return Converted::stop(i, o, ENC_ERR_INPUT),
+            }
+        };
+        let size = utf8_size(c);
+        if out.len() - o < size {
+            return Converted::stop(i, o, ENC_ERR_SPACE);
+        }
+        encode_utf8(&mut out[o..o + size], c);
+        i += len;
+        o += size;
+    }
+    Converted::done(i, o)
+}
+
+/// Convert UTF-8 to Big5. Characters outside ASCII and the table are
+/// invalid input, as are UTF-8 errors. A cut-off sequence ends the input
+/// even when the output is full; otherwise a full output is reported
+/// first.
+pub fn utf8_to_big5(out: &mut [u8], input: &[u8]) -> Converted {
+    let mut i = 0;
+    let mut o = 0;
+    while i < input.len() {
+        let decoded = if input[i] < 0x80 {
+            Ok(Some((input[i] as u32, 1)))
+        } else {
+            decode_utf8(&input[i..])
+        };
+        let (c, len) = match decoded {
+            Ok(None) => break,
+            _ if o >= out.len() => return Converted::stop(i, o, ENC_ERR_SPACE),
+            Ok(Some(decoded)) => decoded,
+            Err(()) => return Converted::stop(i, o, ENC_ERR_INPUT),
+        };
+        let (bytes, size) = if c < 0x80 {
+            ([c as u8, 0], 1)
+        } else {
+            let found = u16::try_from(c)
+                .ok()
+                .and_then(|c| BIG5_INVERSE.binary_search_by_key(&c, |&(cp, _)| cp).ok());
+            let Some(found) = found else {
+                return Converted::stop(i, o, ENC_ERR_INPUT);
+            };
+            (table_bytes(BIG5_INVERSE[found].1 as usize), 2)
+        };
+        if out.len() - o < size {
+            return Converted::stop(i, o, ENC_ERR_SPACE);
+        }
+        out[o..o + size].copy_from_slice(&bytes[..size]);
+        i += len;
+        o += size;
+    }
+    Converted::done(i, o)
+}
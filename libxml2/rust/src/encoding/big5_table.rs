@@ -0,0 +1,14883 @@
+//! Big5
+//!
+//! Generated with codegen/gen_encoding_tables.py from Python's `big5`
+//! codec. Do not edit.
+
+/// Code point of lead byte `0xA1 + i / 157` and the `i % 157`th trail byte, or 0.
+pub(super) static BIG5: [u16; 89 * 157] = [
+    0x3000, 0xff0c, 0x3001, 0x3002, 0xff0e, 0x2022, 0xff1b, 0xff1a, 0xff1f, 0xff01, 0xfe30, 0x2026,
+    0x2025, 0xfe50, 0xff64, 0xfe52, 0x00b7, 0xfe54, 0xfe55, 0xfe56, 0xfe57, 0xff5c, 0x2013, 0xfe31,
+    0x2014, 0xfe33, 0x2574, 0xfe34, 0xfe4f, 0xff08, 0xff09, 0xfe35, 0xfe36, 0xff5b, 0xff5d, 0xfe37,
+    0xfe38, 0x3014, 0x3015, 0xfe39, 0xfe3a, 0x3010, 0x3011, 0xfe3b, 0xfe3c, 0x300a, 0x300b, 0xfe3d,
+    0xfe3e, 0x3008, 0x3009, 0xfe3f, 0xfe40, 0x300c, 0x300d, 0xfe41, 0xfe42, 0x300e, 0x300f, 0xfe43,
+    0xfe44, 0xfe59, 0xfe5a, 0xfe5b, 0xfe5c, 0xfe5d, 0xfe5e, 0x2018, 0x2019, 0x201c, 0x201d, 0x301d,
+    0x301e, 0x2035, 0x2032, 0xff03, 0xff06, 0xff0a, 0x203b, 0x00a7, 0x3003, 0x25cb, 0x25cf, 0x25b3,
+    0x25b2, 0x25ce, 0x2606, 0x2605, 0x25c7, 0x25c6, 0x25a1, 0x25a0, 0x25bd, 0x25bc, 0x32a3, 0x2105,
+    0x203e, 0xffe3, 0xff3f, 0x02cd, 0xfe49, 0xfe4a, 0xfe4d, 0xfe4e, 0xfe4b, 0xfe4c, 0xfe5f, 0xfe60,
+    0xfe61, 0xff0b, 0xff0d, 0x00d7, 0x00f7, 0x00b1, 0x221a, 0xff1c, 0xff1e, 0xff1d, 0x2266, 0x2267,
+    0x2260, 0x221e, 0x2252, 0x2261, 0xfe62, 0xfe63, 0xfe64, 0xfe65, 0xfe66, 0x223c, 0x2229, 0x222a,
+    0x22a5, 0x2220, 0x221f, 0x22bf, 0x33d2, 0x33d1, 0x222b, 0x222e, 0x2235, 0x2234, 0x2640, 0x2642,
+    0x2641, 0x2609, 0x2191, 0x2193, 0x2190, 0x2192, 0x2196, 0x2197, 0x2199, 0x2198, 0x2225, 0x2223,
+    0xff0f, 0xff3c, 0xff0f, 0xff3c, 0xff04, 0x00a5, 0x3012, 0x00a2, 0x00a3, 0xff05, 0xff20, 0x2103,
+    0x2109, 0xfe69, 0xfe6a, 0xfe6b, 0x33d5, 0x339c, 0x339d, 0x339e, 0x33ce, 0x33a1, 0x338e, 0x338f,
+    0x33c4, 0x00b0, 0x5159, 0x515b, 0x515e, 0x515d, 0x5161, 0x5163, 0x55e7, 0x74e9, 0x7cce, 0x2581,
+    0x2582, 0x2583, 0x2584, 0x2585, 0x2586, 0x2587, 0x2588, 0x258f, 0x258e, 0x258d, 0x258c, 0x258b,
+    0x258a, 0x2589, 0x253c, 0x2534, 0x252c, 0x2524, 0x251c, 0x2594, 0x2500, 0x2502, 0x2595, 0x250c,
+    0x2510, 0x2514, 0x2518, 0x256d, 0x256e, 0x2570, 0x256f, 0x2550, 0x255e, 0x256a, 0x2561, 0x25e2,
+    0x25e3, 0x25e5, 0x25e4, 0x2571, 0x2572, 0x2573, 0xff10, 0xff11, 0xff12, 0xff13, 0xff14, 0xff15,
+    0xff16, 0xff17, 0xff18, 0xff19, 0x2160, 0x2161, 0x2162, 0x2163, 0x2164, 0x2165, 0x2166, 0x2167,
+    0x2168, 0x2169, 0x3021, 0x3022, 0x3023, 0x3024, 0x3025, 0x3026, 0x3027, 0x3028, 0x3029, 0x5341,
+    0x5344, 0x5345, 0xff21, 0xff22, 0xff23, 0xff24, 0xff25, 0xff26, 0xff27, 0xff28, 0xff29, 0xff2a,
+    0xff2b, 0xff2c, 0xff2d, 0xff2e, 0xff2f, 0xff30, 0xff31, 0xff32, 0xff33, 0xff34, 0xff35, 0xff36,
+    0xff37, 0xff38, 0xff39, 0xff3a, 0xff41, 0xff42, 0xff43, 0xff44, 0xff45, 0xff46, 0xff47, 0xff48,
+    0xff49, 0xff4a, 0xff4b, 0xff4c, 0xff4d, 0xff4e, 0xff4f, 0xff50, 0xff51, 0xff52, 0xff53, 0xff54,
+    0xff55, 0xff56, 0xff57, 0xff58, 0xff59, 0xff5a, 0x0391, 0x0392, 0x0393, 0x0394, 0x0395, 0x0396,
+    0x0397, 0x0398, 0x0399, 0x039a, 0x039b, 0x039c, 0x039d, 0x039e, 0x039f, 0x03a0, 0x03a1, 0x03a3,
+    0x03a4, 0x03a5, 0x03a6, 0x03a7, 0x03a8, 0x03a9, 0x03b1, 0x03b2, 0x03b3, 0x03b4, 0x03b5, 0x03b6,
+    0x03b7, 0x03b8, 0x03b9, 0x03ba, 0x03bb, 0x03bc, 0x03bd, 0x03be, 0x03bf, 0x03c0, 0x03c1, 0x03c3,
+    0x03c4, 0x03c5, 0x03c6, 0x03c7, 0x03c8, 0x03c9, 0x3105, 0x3106, 0x3107, 0x3108, 0x3109, 0x310a,
+    0x310b, 0x310c, 0x310d, 0x310e, 0x310f, 0x3110, 0x3111, 0x3112, 0x3113, 0x3114, 0x3115, 0x3116,
+    0x3117, 0x3118, 0x3119, 0x311a, 0x311b, 0x311c, 0x311d, 0x311e, 0x311f, 0x3120, 0x3121, 0x3122,
+    0x3123, 0x3124, 0x3125, 0x3126, 0x3127, 0x3128, 0x3129, 0x02d9, 0x02c9, 0x02ca, 0x02c7, 0x02cb,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x4e00, 0x4e59, 0x4e01, 0x4e03, 0x4e43, 0x4e5d, 0x4e86, 0x4e8c, 0x4eba,
+    0x513f, 0x5165, 0x516b, 0x51e0, 0x5200, 0x5201, 0x529b, 0x5315, 0x5341, 0x535c, 0x53c8, 0x4e09,
+    0x4e0b, 0x4e08, 0x4e0a, 0x4e2b, 0x4e38, 0x51e1, 0x4e45, 0x4e48, 0x4e5f, 0x4e5e, 0x4e8e, 0x4ea1,
+    0x5140, 0x5203, 0x52fa, 0x5343, 0x53c9, 0x53e3, 0x571f, 0x58eb, 0x5915, 0x5927, 0x5973, 0x5b50,
+    0x5b51, 0x5b53, 0x5bf8, 0x5c0f, 0x5c22, 0x5c38, 0x5c71, 0x5ddd, 0x5de5, 0x5df1, 0x5df2, 0x5df3,
+    0x5dfe, 0x5e72, 0x5efe, 0x5f0b, 0x5f13, 0x624d, 0x4e11, 0x4e10, 0x4e0d, 0x4e2d, 0x4e30, 0x4e39,
+    0x4e4b, 0x5c39, 0x4e88, 0x4e91, 0x4e95, 0x4e92, 0x4e94, 0x4ea2, 0x4ec1, 0x4ec0, 0x4ec3, 0x4ec6,
+    0x4ec7, 0x4ecd, 0x4eca, 0x4ecb, 0x4ec4, 0x5143, 0x5141, 0x5167, 0x516d, 0x516e, 0x516c, 0x5197,
+    0x51f6, 0x5206, 0x5207, 0x5208, 0x52fb, 0x52fe, 0x52ff, 0x5316, 0x5339, 0x5348, 0x5347, 0x5345,
+    0x535e, 0x5384, 0x53cb, 0x53ca, 0x53cd, 0x58ec, 0x5929, 0x592b, 0x592a, 0x592d, 0x5b54, 0x5c11,
+    0x5c24, 0x5c3a, 0x5c6f, 0x5df4, 0x5e7b, 0x5eff, 0x5f14, 0x5f15, 0x5fc3, 0x6208, 0x6236, 0x624b,
+    0x624e, 0x652f, 0x6587, 0x6597, 0x65a4, 0x65b9, 0x65e5, 0x66f0, 0x6708, 0x6728, 0x6b20, 0x6b62,
+    0x6b79, 0x6bcb, 0x6bd4, 0x6bdb, 0x6c0f, 0x6c34, 0x706b, 0x722a, 0x7236, 0x723b, 0x7247, 0x7259,
+    0x725b, 0x72ac, 0x738b, 0x4e19, 0x4e16, 0x4e15, 0x4e14, 0x4e18, 0x4e3b, 0x4e4d, 0x4e4f, 0x4e4e,
+    0x4ee5, 0x4ed8, 0x4ed4, 0x4ed5, 0x4ed6, 0x4ed7, 0x4ee3, 0x4ee4, 0x4ed9, 0x4ede, 0x5145, 0x5144,
+    0x5189, 0x518a, 0x51ac, 0x51f9, 0x51fa, 0x51f8, 0x520a, 0x52a0, 0x529f, 0x5305, 0x5306, 0x5317,
+    0x531d, 0x4edf, 0x534a, 0x5349, 0x5361, 0x5360, 0x536f, 0x536e, 0x53bb, 0x53ef, 0x53e4, 0x53f3,
+    0x53ec, 0x53ee, 0x53e9, 0x53e8, 0x53fc, 0x53f8, 0x53f5, 0x53eb, 0x53e6, 0x53ea, 0x53f2, 0x53f1,
+    0x53f0, 0x53e5, 0x53ed, 0x53fb, 0x56db, 0x56da, 0x5916, 0x592e, 0x5931, 0x5974, 0x5976, 0x5b55,
+    0x5b83, 0x5c3c, 0x5de8, 0x5de7, 0x5de6, 0x5e02, 0x5e03, 0x5e73, 0x5e7c, 0x5f01, 0x5f18, 0x5f17,
+    0x5fc5, 0x620a, 0x6253, 0x6254, 0x6252, 0x6251, 0x65a5, 0x65e6, 0x672e, 0x672c, 0x672a, 0x672b,
+    0x672d, 0x6b63, 0x6bcd, 0x6c11, 0x6c10, 0x6c38, 0x6c41, 0x6c40, 0x6c3e, 0x72af, 0x7384, 0x7389,
+    0x74dc, 0x74e6, 0x7518, 0x751f, 0x7528, 0x7529, 0x7530, 0x7531, 0x7532, 0x7533, 0x758b, 0x767d,
+    0x76ae, 0x76bf, 0x76ee, 0x77db, 0x77e2, 0x77f3, 0x793a, 0x79be, 0x7a74, 0x7acb, 0x4e1e, 0x4e1f,
+    0x4e52, 0x4e53, 0x4e69, 0x4e99, 0x4ea4, 0x4ea6, 0x4ea5, 0x4eff, 0x4f09, 0x4f19, 0x4f0a, 0x4f15,
+    0x4f0d, 0x4f10, 0x4f11, 0x4f0f, 0x4ef2, 0x4ef6, 0x4efb, 0x4ef0, 0x4ef3, 0x4efd, 0x4f01, 0x4f0b,
+    0x5149, 0x5147, 0x5146, 0x5148, 0x5168, 0x5171, 0x518d, 0x51b0, 0x5217, 0x5211, 0x5212, 0x520e,
+    0x5216, 0x52a3, 0x5308, 0x5321, 0x5320, 0x5370, 0x5371, 0x5409, 0x540f, 0x540c, 0x540a, 0x5410,
+    0x5401, 0x540b, 0x5404, 0x5411, 0x540d, 0x5408, 0x5403, 0x540e, 0x5406, 0x5412, 0x56e0, 0x56de,
+    0x56dd, 0x5733, 0x5730, 0x5728, 0x572d, 0x572c, 0x572f, 0x5729, 0x5919, 0x591a, 0x5937, 0x5938,
+    0x5984, 0x5978, 0x5983, 0x597d, 0x5979, 0x5982, 0x5981, 0x5b57, 0x5b58, 0x5b87, 0x5b88, 0x5b85,
+    0x5b89, 0x5bfa, 0x5c16, 0x5c79, 0x5dde, 0x5e06, 0x5e76, 0x5e74, 0x5f0f, 0x5f1b, 0x5fd9, 0x5fd6,
+    0x620e, 0x620c, 0x620d, 0x6210, 0x6263, 0x625b, 0x6258, 0x6536, 0x65e9, 0x65e8, 0x65ec, 0x65ed,
+    0x66f2, 0x66f3, 0x6709, 0x673d, 0x6734, 0x6731, 0x6735, 0x6b21, 0x6b64, 0x6b7b, 0x6c16, 0x6c5d,
+    0x6c57, 0x6c59, 0x6c5f, 0x6c60, 0x6c50, 0x6c55, 0x6c61, 0x6c5b, 0x6c4d, 0x6c4e, 0x7070, 0x725f,
+    0x725d, 0x767e, 0x7af9, 0x7c73, 0x7cf8, 0x7f36, 0x7f8a, 0x7fbd, 0x8001, 0x8003, 0x800c, 0x8012,
+    0x8033, 0x807f, 0x8089, 0x808b, 0x808c, 0x81e3, 0x81ea, 0x81f3, 0x81fc, 0x820c, 0x821b, 0x821f,
+    0x826e, 0x8272, 0x827e, 0x866b, 0x8840, 0x884c, 0x8863, 0x897f, 0x9621, 0x4e32, 0x4ea8, 0x4f4d,
+    0x4f4f, 0x4f47, 0x4f57, 0x4f5e, 0x4f34, 0x4f5b, 0x4f55, 0x4f30, 0x4f50, 0x4f51, 0x4f3d, 0x4f3a,
+    0x4f38, 0x4f43, 0x4f54, 0x4f3c, 0x4f46, 0x4f63, 0x4f5c, 0x4f60, 0x4f2f, 0x4f4e, 0x4f36, 0x4f59,
+    0x4f5d, 0x4f48, 0x4f5a, 0x514c, 0x514b, 0x514d, 0x5175, 0x51b6, 0x51b7, 0x5225, 0x5224, 0x5229,
+    0x522a, 0x5228, 0x52ab, 0x52a9, 0x52aa, 0x52ac, 0x5323, 0x5373, 0x5375, 0x541d, 0x542d, 0x541e,
+    0x543e, 0x5426, 0x544e, 0x5427, 0x5446, 0x5443, 0x5433, 0x5448, 0x5442, 0x541b, 0x5429, 0x544a,
+    0x5439, 0x543b, 0x5438, 0x542e, 0x5435, 0x5436, 0x5420, 0x543c, 0x5440, 0x5431, 0x542b, 0x541f,
+    0x542c, 0x56ea, 0x56f0, 0x56e4, 0x56eb, 0x574a, 0x5751, 0x5740, 0x574d, 0x5747, 0x574e, 0x573e,
+    0x5750, 0x574f, 0x573b, 0x58ef, 0x593e, 0x599d, 0x5992, 0x59a8, 0x599e, 0x59a3, 0x5999, 0x5996,
+    0x598d, 0x59a4, 0x5993, 0x598a, 0x59a5, 0x5b5d, 0x5b5c, 0x5b5a, 0x5b5b, 0x5b8c, 0x5b8b, 0x5b8f,
+    0x5c2c, 0x5c40, 0x5c41, 0x5c3f, 0x5c3e, 0x5c90, 0x5c91, 0x5c94, 0x5c8c, 0x5deb, 0x5e0c, 0x5e8f,
+    0x5e87, 0x5e8a, 0x5ef7, 0x5f04, 0x5f1f, 0x5f64, 0x5f62, 0x5f77, 0x5f79, 0x5fd8, 0x5fcc, 0x5fd7,
+    0x5fcd, 0x5ff1, 0x5feb, 0x5ff8, 0x5fea, 0x6212, 0x6211, 0x6284, 0x6297, 0x6296, 0x6280, 0x6276,
+    0x6289, 0x626d, 0x628a, 0x627c, 0x627e, 0x6279, 0x6273, 0x6292, 0x626f, 0x6298, 0x626e, 0x6295,
+    0x6293, 0x6291, 0x6286, 0x6539, 0x653b, 0x6538, 0x65f1, 0x66f4, 0x675f, 0x674e, 0x674f, 0x6750,
+    0x6751, 0x675c, 0x6756, 0x675e, 0x6749, 0x6746, 0x6760, 0x6753, 0x6757, 0x6b65, 0x6bcf, 0x6c42,
+    0x6c5e, 0x6c99, 0x6c81, 0x6c88, 0x6c89, 0x6c85, 0x6c9b, 0x6c6a, 0x6c7a, 0x6c90, 0x6c70, 0x6c8c,
+    0x6c68, 0x6c96, 0x6c92, 0x6c7d, 0x6c83, 0x6c72, 0x6c7e, 0x6c74, 0x6c86, 0x6c76, 0x6c8d, 0x6c94,
+    0x6c98, 0x6c82, 0x7076, 0x707c, 0x707d, 0x7078, 0x7262, 0x7261, 0x7260, 0x72c4, 0x72c2, 0x7396,
+    0x752c, 0x752b, 0x7537, 0x7538, 0x7682, 0x76ef, 0x77e3, 0x79c1, 0x79c0, 0x79bf, 0x7a76, 0x7cfb,
+    0x7f55, 0x8096, 0x8093, 0x809d, 0x8098, 0x809b, 0x809a, 0x80b2, 0x826f, 0x8292, 0x828b, 0x828d,
+    0x898b, 0x89d2, 0x8a00, 0x8c37, 0x8c46, 0x8c55, 0x8c9d, 0x8d64, 0x8d70, 0x8db3, 0x8eab, 0x8eca,
+    0x8f9b, 0x8fb0, 0x8fc2, 0x8fc6, 0x8fc5, 0x8fc4, 0x5de1, 0x9091, 0x90a2, 0x90aa, 0x90a6, 0x90a3,
+    0x9149, 0x91c6, 0x91cc, 0x9632, 0x962e, 0x9631, 0x962a, 0x962c, 0x4e26, 0x4e56, 0x4e73, 0x4e8b,
+    0x4e9b, 0x4e9e, 0x4eab, 0x4eac, 0x4f6f, 0x4f9d, 0x4f8d, 0x4f73, 0x4f7f, 0x4f6c, 0x4f9b, 0x4f8b,
+    0x4f86, 0x4f83, 0x4f70, 0x4f75, 0x4f88, 0x4f69, 0x4f7b, 0x4f96, 0x4f7e, 0x4f8f, 0x4f91, 0x4f7a,
+    0x5154, 0x5152, 0x5155, 0x5169, 0x5177, 0x5176, 0x5178, 0x51bd, 0x51fd, 0x523b, 0x5238, 0x5237,
+    0x523a, 0x5230, 0x522e, 0x5236, 0x5241, 0x52be, 0x52bb, 0x5352, 0x5354, 0x5353, 0x5351, 0x5366,
+    0x5377, 0x5378, 0x5379, 0x53d6, 0x53d4, 0x53d7, 0x5473, 0x5475, 0x5496, 0x5478, 0x5495, 0x5480,
+    0x547b, 0x5477, 0x5484, 0x5492, 0x5486, 0x547c, 0x5490, 0x5471, 0x5476, 0x548c, 0x549a, 0x5462,
+    0x5468, 0x548b, 0x547d, 0x548e, 0x56fa, 0x5783, 0x5777, 0x576a, 0x5769, 0x5761, 0x5766, 0x5764,
+    0x577c, 0x591c, 0x5949, 0x5947, 0x5948, 0x5944, 0x5954, 0x59be, 0x59bb, 0x59d4, 0x59b9, 0x59ae,
+    0x59d1, 0x59c6, 0x59d0, 0x59cd, 0x59cb, 0x59d3, 0x59ca, 0x59af, 0x59b3, 0x59d2, 0x59c5, 0x5b5f,
+    0x5b64, 0x5b63, 0x5b97, 0x5b9a, 0x5b98, 0x5b9c, 0x5b99, 0x5b9b, 0x5c1a, 0x5c48, 0x5c45, 0x5c46,
+    0x5cb7, 0x5ca1, 0x5cb8, 0x5ca9, 0x5cab, 0x5cb1, 0x5cb3, 0x5e18, 0x5e1a, 0x5e16, 0x5e15, 0x5e1b,
+    0x5e11, 0x5e78, 0x5e9a, 0x5e97, 0x5e9c, 0x5e95, 0x5e96, 0x5ef6, 0x5f26, 0x5f27, 0x5f29, 0x5f80,
+    0x5f81, 0x5f7f, 0x5f7c, 0x5fdd, 0x5fe0, 0x5ffd, 0x5ff5, 0x5fff, 0x600f, 0x6014, 0x602f, 0x6035,
+    0x6016, 0x602a, 0x6015, 0x6021, 0x6027, 0x6029, 0x602b, 0x601b, 0x6216, 0x6215, 0x623f, 0x623e,
+    0x6240, 0x627f, 0x62c9, 0x62cc, 0x62c4, 0x62bf, 0x62c2, 0x62b9, 0x62d2, 0x62db, 0x62ab, 0x62d3,
+    0x62d4, 0x62cb, 0x62c8, 0x62a8, 0x62bd, 0x62bc, 0x62d0, 0x62d9, 0x62c7, 0x62cd, 0x62b5, 0x62da,
+    0x62b1, 0x62d8, 0x62d6, 0x62d7, 0x62c6, 0x62ac, 0x62ce, 0x653e, 0x65a7, 0x65bc, 0x65fa, 0x6614,
+    0x6613, 0x660c, 0x6606, 0x6602, 0x660e, 0x6600, 0x660f, 0x6615, 0x660a, 0x6607, 0x670d, 0x670b,
+    0x676d, 0x678b, 0x6795, 0x6771, 0x679c, 0x6773, 0x6777, 0x6787, 0x679d, 0x6797, 0x676f, 0x6770,
+    0x677f, 0x6789, 0x677e, 0x6790, 0x6775, 0x679a, 0x6793, 0x677c, 0x676a, 0x6772, 0x6b23, 0x6b66,
+    0x6b67, 0x6b7f, 0x6c13, 0x6c1b, 0x6ce3, 0x6ce8, 0x6cf3, 0x6cb1, 0x6ccc, 0x6ce5, 0x6cb3, 0x6cbd,
+    0x6cbe, 0x6cbc, 0x6ce2, 0x6cab, 0x6cd5, 0x6cd3, 0x6cb8, 0x6cc4, 0x6cb9, 0x6cc1, 0x6cae, 0x6cd7,
+    0x6cc5, 0x6cf1, 0x6cbf, 0x6cbb, 0x6ce1, 0x6cdb, 0x6cca, 0x6cac, 0x6cef, 0x6cdc, 0x6cd6, 0x6ce0,
+    0x7095, 0x708e, 0x7092, 0x708a, 0x7099, 0x722c, 0x722d, 0x7238, 0x7248, 0x7267, 0x7269, 0x72c0,
+    0x72ce, 0x72d9, 0x72d7, 0x72d0, 0x73a9, 0x73a8, 0x739f, 0x73ab, 0x73a5, 0x753d, 0x759d, 0x7599,
+    0x759a, 0x7684, 0x76c2, 0x76f2, 0x76f4, 0x77e5, 0x77fd, 0x793e, 0x7940, 0x7941, 0x79c9, 0x79c8,
+    0x7a7a, 0x7a79, 0x7afa, 0x7cfe, 0x7f54, 0x7f8c, 0x7f8b, 0x8005, 0x80ba, 0x80a5, 0x80a2, 0x80b1,
+    0x80a1, 0x80ab, 0x80a9, 0x80b4, 0x80aa, 0x80af, 0x81e5, 0x81fe, 0x820d, 0x82b3, 0x829d, 0x8299,
+    0x82ad, 0x82bd, 0x829f, 0x82b9, 0x82b1, 0x82ac, 0x82a5, 0x82af, 0x82b8, 0x82a3, 0x82b0, 0x82be,
+    0x82b7, 0x864e, 0x8671, 0x521d, 0x8868, 0x8ecb, 0x8fce, 0x8fd4, 0x8fd1, 0x90b5, 0x90b8, 0x90b1,
+    0x90b6, 0x91c7, 0x91d1, 0x9577, 0x9580, 0x961c, 0x9640, 0x963f, 0x963b, 0x9644, 0x9642, 0x96b9,
+    0x96e8, 0x9752, 0x975e, 0x4e9f, 0x4ead, 0x4eae, 0x4fe1, 0x4fb5, 0x4faf, 0x4fbf, 0x4fe0, 0x4fd1,
+    0x4fcf, 0x4fdd, 0x4fc3, 0x4fb6, 0x4fd8, 0x4fdf, 0x4fca, 0x4fd7, 0x4fae, 0x4fd0, 0x4fc4, 0x4fc2,
+    0x4fda, 0x4fce, 0x4fde, 0x4fb7, 0x5157, 0x5192, 0x5191, 0x51a0, 0x524e, 0x5243, 0x524a, 0x524d,
+    0x524c, 0x524b, 0x5247, 0x52c7, 0x52c9, 0x52c3, 0x52c1, 0x530d, 0x5357, 0x537b, 0x539a, 0x53db,
+    0x54ac, 0x54c0, 0x54a8, 0x54ce, 0x54c9, 0x54b8, 0x54a6, 0x54b3, 0x54c7, 0x54c2, 0x54bd, 0x54aa,
+    0x54c1, 0x54c4, 0x54c8, 0x54af, 0x54ab, 0x54b1, 0x54bb, 0x54a9, 0x54a7, 0x54bf, 0x56ff, 0x5782,
+    0x578b, 0x57a0, 0x57a3, 0x57a2, 0x57ce, 0x57ae, 0x5793, 0x5955, 0x5951, 0x594f, 0x594e, 0x5950,
+    0x59dc, 0x59d8, 0x59ff, 0x59e3, 0x59e8, 0x5a03, 0x59e5, 0x59ea, 0x59da, 0x59e6, 0x5a01, 0x59fb,
+    0x5b69, 0x5ba3, 0x5ba6, 0x5ba4, 0x5ba2, 0x5ba5, 0x5c01, 0x5c4e, 0x5c4f, 0x5c4d, 0x5c4b, 0x5cd9,
+    0x5cd2, 0x5df7, 0x5e1d, 0x5e25, 0x5e1f, 0x5e7d, 0x5ea0, 0x5ea6, 0x5efa, 0x5f08, 0x5f2d, 0x5f65,
+    0x5f88, 0x5f85, 0x5f8a, 0x5f8b, 0x5f87, 0x5f8c, 0x5f89, 0x6012, 0x601d, 0x6020, 0x6025, 0x600e,
+    0x6028, 0x604d, 0x6070, 0x6068, 0x6062, 0x6046, 0x6043, 0x606c, 0x606b, 0x606a, 0x6064, 0x6241,
+    0x62dc, 0x6316, 0x6309, 0x62fc, 0x62ed, 0x6301, 0x62ee, 0x62fd, 0x6307, 0x62f1, 0x62f7, 0x62ef,
+    0x62ec, 0x62fe, 0x62f4, 0x6311, 0x6302, 0x653f, 0x6545, 0x65ab, 0x65bd, 0x65e2, 0x6625, 0x662d,
+    0x6620, 0x6627, 0x662f, 0x661f, 0x6628, 0x6631, 0x6624, 0x66f7, 0x67ff, 0x67d3, 0x67f1, 0x67d4,
+    0x67d0, 0x67ec, 0x67b6, 0x67af, 0x67f5, 0x67e9, 0x67ef, 0x67c4, 0x67d1, 0x67b4, 0x67da, 0x67e5,
+    0x67b8, 0x67cf, 0x67de, 0x67f3, 0x67b0, 0x67d9, 0x67e2, 0x67dd, 0x67d2, 0x6b6a, 0x6b83, 0x6b86,
+    0x6bb5, 0x6bd2, 0x6bd7, 0x6c1f, 0x6cc9, 0x6d0b, 0x6d32, 0x6d2a, 0x6d41, 0x6d25, 0x6d0c, 0x6d31,
+    0x6d1e, 0x6d17, 0x6d3b, 0x6d3d, 0x6d3e, 0x6d36, 0x6d1b, 0x6cf5, 0x6d39, 0x6d27, 0x6d38, 0x6d29,
+    0x6d2e, 0x6d35, 0x6d0e, 0x6d2b, 0x70ab, 0x70ba, 0x70b3, 0x70ac, 0x70af, 0x70ad, 0x70b8, 0x70ae,
+    0x70a4, 0x7230, 0x7272, 0x726f, 0x7274, 0x72e9, 0x72e0, 0x72e1, 0x73b7, 0x73ca, 0x73bb, 0x73b2,
+    0x73cd, 0x73c0, 0x73b3, 0x751a, 0x752d, 0x754f, 0x754c, 0x754e, 0x754b, 0x75ab, 0x75a4, 0x75a5,
+    0x75a2, 0x75a3, 0x7678, 0x7686, 0x7687, 0x7688, 0x76c8, 0x76c6, 0x76c3, 0x76c5, 0x7701, 0x76f9,
+    0x76f8, 0x7709, 0x770b, 0x76fe, 0x76fc, 0x7707, 0x77dc, 0x7802, 0x7814, 0x780c, 0x780d, 0x7946,
+    0x7949, 0x7948, 0x7947, 0x79b9, 0x79ba, 0x79d1, 0x79d2, 0x79cb, 0x7a7f, 0x7a81, 0x7aff, 0x7afd,
+    0x7c7d, 0x7d02, 0x7d05, 0x7d00, 0x7d09, 0x7d07, 0x7d04, 0x7d06, 0x7f38, 0x7f8e, 0x7fbf, 0x8004,
+    0x8010, 0x800d, 0x8011, 0x8036, 0x80d6, 0x80e5, 0x80da, 0x80c3, 0x80c4, 0x80cc, 0x80e1, 0x80db,
+    0x80ce, 0x80de, 0x80e4, 0x80dd, 0x81f4, 0x8222, 0x82e7, 0x8303, 0x8305, 0x82e3, 0x82db, 0x82e6,
+    0x8304, 0x82e5, 0x8302, 0x8309, 0x82d2, 0x82d7, 0x82f1, 0x8301, 0x82dc, 0x82d4, 0x82d1, 0x82de,
+    0x82d3, 0x82df, 0x82ef, 0x8306, 0x8650, 0x8679, 0x867b, 0x867a, 0x884d, 0x886b, 0x8981, 0x89d4,
+    0x8a08, 0x8a02, 0x8a03, 0x8c9e, 0x8ca0, 0x8d74, 0x8d73, 0x8db4, 0x8ecd, 0x8ecc, 0x8ff0, 0x8fe6,
+    0x8fe2, 0x8fea, 0x8fe5, 0x8fed, 0x8feb, 0x8fe4, 0x8fe8, 0x90ca, 0x90ce, 0x90c1, 0x90c3, 0x914b,
+    0x914a, 0x91cd, 0x9582, 0x9650, 0x964b, 0x964c, 0x964d, 0x9762, 0x9769, 0x97cb, 0x97ed, 0x97f3,
+    0x9801, 0x98a8, 0x98db, 0x98df, 0x9996, 0x9999, 0x4e58, 0x4eb3, 0x500c, 0x500d, 0x5023, 0x4fef,
+    0x5026, 0x5025, 0x4ff8, 0x5029, 0x5016, 0x5006, 0x503c, 0x501f, 0x501a, 0x5012, 0x5011, 0x4ffa,
+    0x5000, 0x5014, 0x5028, 0x4ff1, 0x5021, 0x500b, 0x5019, 0x5018, 0x4ff3, 0x4fee, 0x502d, 0x502a,
+    0x4ffe, 0x502b, 0x5009, 0x517c, 0x51a4, 0x51a5, 0x51a2, 0x51cd, 0x51cc, 0x51c6, 0x51cb, 0x5256,
+    0x525c, 0x5254, 0x525b, 0x525d, 0x532a, 0x537f, 0x539f, 0x539d, 0x53df, 0x54e8, 0x5510, 0x5501,
+    0x5537, 0x54fc, 0x54e5, 0x54f2, 0x5506, 0x54fa, 0x5514, 0x54e9, 0x54ed, 0x54e1, 0x5509, 0x54ee,
+    0x54ea, 0x54e6, 0x5527, 0x5507, 0x54fd, 0x550f, 0x5703, 0x5704, 0x57c2, 0x57d4, 0x57cb, 0x57c3,
+    0x5809, 0x590f, 0x5957, 0x5958, 0x595a, 0x5a11, 0x5a18, 0x5a1c, 0x5a1f, 0x5a1b, 0x5a13, 0x59ec,
+    0x5a20, 0x5a23, 0x5a29, 0x5a25, 0x5a0c, 0x5a09, 0x5b6b, 0x5c58, 0x5bb0, 0x5bb3, 0x5bb6, 0x5bb4,
+    0x5bae, 0x5bb5, 0x5bb9, 0x5bb8, 0x5c04, 0x5c51, 0x5c55, 0x5c50, 0x5ced, 0x5cfd, 0x5cfb, 0x5cea,
+    0x5ce8, 0x5cf0, 0x5cf6, 0x5d01, 0x5cf4, 0x5dee, 0x5e2d, 0x5e2b, 0x5eab, 0x5ead, 0x5ea7, 0x5f31,
+    0x5f92, 0x5f91, 0x5f90, 0x6059, 0x6063, 0x6065, 0x6050, 0x6055, 0x606d, 0x6069, 0x606f, 0x6084,
+    0x609f, 0x609a, 0x608d, 0x6094, 0x608c, 0x6085, 0x6096, 0x6247, 0x62f3, 0x6308, 0x62ff, 0x634e,
+    0x633e, 0x632f, 0x6355, 0x6342, 0x6346, 0x634f, 0x6349, 0x633a, 0x6350, 0x633d, 0x632a, 0x632b,
+    0x6328, 0x634d, 0x634c, 0x6548, 0x6549, 0x6599, 0x65c1, 0x65c5, 0x6642, 0x6649, 0x664f, 0x6643,
+    0x6652, 0x664c, 0x6645, 0x6641, 0x66f8, 0x6714, 0x6715, 0x6717, 0x6821, 0x6838, 0x6848, 0x6846,
+    0x6853, 0x6839, 0x6842, 0x6854, 0x6829, 0x68b3, 0x6817, 0x684c, 0x6851, 0x683d, 0x67f4, 0x6850,
+    0x6840, 0x683c, 0x6843, 0x682a, 0x6845, 0x6813, 0x6818, 0x6841, 0x6b8a, 0x6b89, 0x6bb7, 0x6c23,
+    0x6c27, 0x6c28, 0x6c26, 0x6c24, 0x6cf0, 0x6d6a, 0x6d95, 0x6d88, 0x6d87, 0x6d66, 0x6d78, 0x6d77,
+    0x6d59, 0x6d93, 0x6d6c, 0x6d89, 0x6d6e, 0x6d5a, 0x6d74, 0x6d69, 0x6d8c, 0x6d8a, 0x6d79, 0x6d85,
+    0x6d65, 0x6d94, 0x70ca, 0x70d8, 0x70e4, 0x70d9, 0x70c8, 0x70cf, 0x7239, 0x7279, 0x72fc, 0x72f9,
+    0x72fd, 0x72f8, 0x72f7, 0x7386, 0x73ed, 0x7409, 0x73ee, 0x73e0, 0x73ea, 0x73de, 0x7554, 0x755d,
+    0x755c, 0x755a, 0x7559, 0x75be, 0x75c5, 0x75c7, 0x75b2, 0x75b3, 0x75bd, 0x75bc, 0x75b9, 0x75c2,
+    0x75b8, 0x768b, 0x76b0, 0x76ca, 0x76cd, 0x76ce, 0x7729, 0x771f, 0x7720, 0x7728, 0x77e9, 0x7830,
+    0x7827, 0x7838, 0x781d, 0x7834, 0x7837, 0x7825, 0x782d, 0x7820, 0x781f, 0x7832, 0x7955, 0x7950,
+    0x7960, 0x795f, 0x7956, 0x795e, 0x795d, 0x7957, 0x795a, 0x79e4, 0x79e3, 0x79e7, 0x79df, 0x79e6,
+    0x79e9, 0x79d8, 0x7a84, 0x7a88, 0x7ad9, 0x7b06, 0x7b11, 0x7c89, 0x7d21, 0x7d17, 0x7d0b, 0x7d0a,
+    0x7d20, 0x7d22, 0x7d14, 0x7d10, 0x7d15, 0x7d1a, 0x7d1c, 0x7d0d, 0x7d19, 0x7d1b, 0x7f3a, 0x7f5f,
+    0x7f94, 0x7fc5, 0x7fc1, 0x8006, 0x8018, 0x8015, 0x8019, 0x8017, 0x803d, 0x803f, 0x80f1, 0x8102,
+    0x80f0, 0x8105, 0x80ed, 0x80f4, 0x8106, 0x80f8, 0x80f3, 0x8108, 0x80fd, 0x810a, 0x80fc, 0x80ef,
+    0x81ed, 0x81ec, 0x8200, 0x8210, 0x822a, 0x822b, 0x8228, 0x822c, 0x82bb, 0x832b, 0x8352, 0x8354,
+    0x834a, 0x8338, 0x8350, 0x8349, 0x8335, 0x8334, 0x834f, 0x8332, 0x8339, 0x8336, 0x8317, 0x8340,
+    0x8331, 0x8328, 0x8343, 0x8654, 0x868a, 0x86aa, 0x8693, 0x86a4, 0x86a9, 0x868c, 0x86a3, 0x869c,
+    0x8870, 0x8877, 0x8881, 0x8882, 0x887d, 0x8879, 0x8a18, 0x8a10, 0x8a0e, 0x8a0c, 0x8a15, 0x8a0a,
+    0x8a17, 0x8a13, 0x8a16, 0x8a0f, 0x8a11, 0x8c48, 0x8c7a, 0x8c79, 0x8ca1, 0x8ca2, 0x8d77, 0x8eac,
+    0x8ed2, 0x8ed4, 0x8ecf, 0x8fb1, 0x9001, 0x9006, 0x8ff7, 0x9000, 0x8ffa, 0x8ff4, 0x9003, 0x8ffd,
+    0x9005, 0x8ff8, 0x9095, 0x90e1, 0x90dd, 0x90e2, 0x9152, 0x914d, 0x914c, 0x91d8, 0x91dd, 0x91d7,
+    0x91dc, 0x91d9, 0x9583, 0x9662, 0x9663, 0x9661, 0x965b, 0x965d, 0x9664, 0x9658, 0x965e, 0x96bb,
+    0x98e2, 0x99ac, 0x9aa8, 0x9ad8, 0x9b25, 0x9b32, 0x9b3c, 0x4e7e, 0x507a, 0x507d, 0x505c, 0x5047,
+    0x5043, 0x504c, 0x505a, 0x5049, 0x5065, 0x5076, 0x504e, 0x5055, 0x5075, 0x5074, 0x5077, 0x504f,
+    0x500f, 0x506f, 0x506d, 0x515c, 0x5195, 0x51f0, 0x526a, 0x526f, 0x52d2, 0x52d9, 0x52d8, 0x52d5,
+    0x5310, 0x530f, 0x5319, 0x533f, 0x5340, 0x533e, 0x53c3, 0x66fc, 0x5546, 0x556a, 0x5566, 0x5544,
+    0x555e, 0x5561, 0x5543, 0x554a, 0x5531, 0x5556, 0x554f, 0x5555, 0x552f, 0x5564, 0x5538, 0x552e,
+    0x555c, 0x552c, 0x5563, 0x5533, 0x5541, 0x5557, 0x5708, 0x570b, 0x5709, 0x57df, 0x5805, 0x580a,
+    0x5806, 0x57e0, 0x57e4, 0x57fa, 0x5802, 0x5835, 0x57f7, 0x57f9, 0x5920, 0x5962, 0x5a36, 0x5a41,
+    0x5a49, 0x5a66, 0x5a6a, 0x5a40, 0x5a3c, 0x5a62, 0x5a5a, 0x5a46, 0x5a4a, 0x5b70, 0x5bc7, 0x5bc5,
+    0x5bc4, 0x5bc2, 0x5bbf, 0x5bc6, 0x5c09, 0x5c08, 0x5c07, 0x5c60, 0x5c5c, 0x5c5d, 0x5d07, 0x5d06,
+    0x5d0e, 0x5d1b, 0x5d16, 0x5d22, 0x5d11, 0x5d29, 0x5d14, 0x5d19, 0x5d24, 0x5d27, 0x5d17, 0x5de2,
+    0x5e38, 0x5e36, 0x5e33, 0x5e37, 0x5eb7, 0x5eb8, 0x5eb6, 0x5eb5, 0x5ebe, 0x5f35, 0x5f37, 0x5f57,
+    0x5f6c, 0x5f69, 0x5f6b, 0x5f97, 0x5f99, 0x5f9e, 0x5f98, 0x5fa1, 0x5fa0, 0x5f9c, 0x607f, 0x60a3,
+    0x6089, 0x60a0, 0x60a8, 0x60cb, 0x60b4, 0x60e6, 0x60bd, 0x60c5, 0x60bb, 0x60b5, 0x60dc, 0x60bc,
+    0x60d8, 0x60d5, 0x60c6, 0x60df, 0x60b8, 0x60da, 0x60c7, 0x621a, 0x621b, 0x6248, 0x63a0, 0x63a7,
+    0x6372, 0x6396, 0x63a2, 0x63a5, 0x6377, 0x6367, 0x6398, 0x63aa, 0x6371, 0x63a9, 0x6389, 0x6383,
+    0x639b, 0x636b, 0x63a8, 0x6384, 0x6388, 0x6399, 0x63a1, 0x63ac, 0x6392, 0x638f, 0x6380, 0x637b,
+    0x6369, 0x6368, 0x637a, 0x655d, 0x6556, 0x6551, 0x6559, 0x6557, 0x555f, 0x654f, 0x6558, 0x6555,
+    0x6554, 0x659c, 0x659b, 0x65ac, 0x65cf, 0x65cb, 0x65cc, 0x65ce, 0x665d, 0x665a, 0x6664, 0x6668,
+    0x6666, 0x665e, 0x66f9, 0x52d7, 0x671b, 0x6881, 0x68af, 0x68a2, 0x6893, 0x68b5, 0x687f, 0x6876,
+    0x68b1, 0x68a7, 0x6897, 0x68b0, 0x6883, 0x68c4, 0x68ad, 0x6886, 0x6885, 0x6894, 0x689d, 0x68a8,
+    0x689f, 0x68a1, 0x6882, 0x6b32, 0x6bba, 0x6beb, 0x6bec, 0x6c2b, 0x6d8e, 0x6dbc, 0x6df3, 0x6dd9,
+    0x6db2, 0x6de1, 0x6dcc, 0x6de4, 0x6dfb, 0x6dfa, 0x6e05, 0x6dc7, 0x6dcb, 0x6daf, 0x6dd1, 0x6dae,
+    0x6dde, 0x6df9, 0x6db8, 0x6df7, 0x6df5, 0x6dc5, 0x6dd2, 0x6e1a, 0x6db5, 0x6dda, 0x6deb, 0x6dd8,
+    0x6dea, 0x6df1, 0x6dee, 0x6de8, 0x6dc6, 0x6dc4, 0x6daa, 0x6dec, 0x6dbf, 0x6de6, 0x70f9, 0x7109,
+    0x710a, 0x70fd, 0x70ef, 0x723d, 0x727d, 0x7281, 0x731c, 0x731b, 0x7316, 0x7313, 0x7319, 0x7387,
+    0x7405, 0x740a, 0x7403, 0x7406, 0x73fe, 0x740d, 0x74e0, 0x74f6, 0x74f7, 0x751c, 0x7522, 0x7565,
+    0x7566, 0x7562, 0x7570, 0x758f, 0x75d4, 0x75d5, 0x75b5, 0x75ca, 0x75cd, 0x768e, 0x76d4, 0x76d2,
+    0x76db, 0x7737, 0x773e, 0x773c, 0x7736, 0x7738, 0x773a, 0x786b, 0x7843, 0x784e, 0x7965, 0x7968,
+    0x796d, 0x79fb, 0x7a92, 0x7a95, 0x7b20, 0x7b28, 0x7b1b, 0x7b2c, 0x7b26, 0x7b19, 0x7b1e, 0x7b2e,
+    0x7c92, 0x7c97, 0x7c95, 0x7d46, 0x7d43, 0x7d71, 0x7d2e, 0x7d39, 0x7d3c, 0x7d40, 0x7d30, 0x7d33,
+    0x7d44, 0x7d2f, 0x7d42, 0x7d32, 0x7d31, 0x7f3d, 0x7f9e, 0x7f9a, 0x7fcc, 0x7fce, 0x7fd2, 0x801c,
+    0x804a, 0x8046, 0x812f, 0x8116, 0x8123, 0x812b, 0x8129, 0x8130, 0x8124, 0x8202, 0x8235, 0x8237,
+    0x8236, 0x8239, 0x838e, 0x839e, 0x8398, 0x8378, 0x83a2, 0x8396, 0x83bd, 0x83ab, 0x8392, 0x838a,
+    0x8393, 0x8389, 0x83a0, 0x8377, 0x837b, 0x837c, 0x8386, 0x83a7, 0x8655, 0x5f6a, 0x86c7, 0x86c0,
+    0x86b6, 0x86c4, 0x86b5, 0x86c6, 0x86cb, 0x86b1, 0x86af, 0x86c9, 0x8853, 0x889e, 0x8888, 0x88ab,
+    0x8892, 0x8896, 0x888d, 0x888b, 0x8993, 0x898f, 0x8a2a, 0x8a1d, 0x8a23, 0x8a25, 0x8a31, 0x8a2d,
+    0x8a1f, 0x8a1b, 0x8a22, 0x8c49, 0x8c5a, 0x8ca9, 0x8cac, 0x8cab, 0x8ca8, 0x8caa, 0x8ca7, 0x8d67,
+    0x8d66, 0x8dbe, 0x8dba, 0x8edb, 0x8edf, 0x9019, 0x900d, 0x901a, 0x9017, 0x9023, 0x901f, 0x901d,
+    0x9010, 0x9015, 0x901e, 0x9020, 0x900f, 0x9022, 0x9016, 0x901b, 0x9014, 0x90e8, 0x90ed, 0x90fd,
+    0x9157, 0x91ce, 0x91f5, 0x91e6, 0x91e3, 0x91e7, 0x91ed, 0x91e9, 0x9589, 0x966a, 0x9675, 0x9673,
+    0x9678, 0x9670, 0x9674, 0x9676, 0x9677, 0x966c, 0x96c0, 0x96ea, 0x96e9, 0x7ae0, 0x7adf, 0x9802,
+    0x9803, 0x9b5a, 0x9ce5, 0x9e75, 0x9e7f, 0x9ea5, 0x9ebb, 0x50a2, 0x508d, 0x5085, 0x5099, 0x5091,
+    0x5080, 0x5096, 0x5098, 0x509a, 0x6700, 0x51f1, 0x5272, 0x5274, 0x5275, 0x5269, 0x52de, 0x52dd,
+    0x52db, 0x535a, 0x53a5, 0x557b, 0x5580, 0x55a7, 0x557c, 0x558a, 0x559d, 0x5598, 0x5582, 0x559c,
+    0x55aa, 0x5594, 0x5587, 0x558b, 0x5583, 0x55b3, 0x55ae, 0x559f, 0x553e, 0x55b2, 0x559a, 0x55bb,
+    0x55ac, 0x55b1, 0x557e, 0x5589, 0x55ab, 0x5599, 0x570d, 0x582f, 0x582a, 0x5834, 0x5824, 0x5830,
+    0x5831, 0x5821, 0x581d, 0x5820, 0x58f9, 0x58fa, 0x5960, 0x5a77, 0x5a9a, 0x5a7f, 0x5a92, 0x5a9b,
+    0x5aa7, 0x5b73, 0x5b71, 0x5bd2, 0x5bcc, 0x5bd3, 0x5bd0, 0x5c0a, 0x5c0b, 0x5c31, 0x5d4c, 0x5d50,
+    0x5d34, 0x5d47, 0x5dfd, 0x5e45, 0x5e3d, 0x5e40, 0x5e43, 0x5e7e, 0x5eca, 0x5ec1, 0x5ec2, 0x5ec4,
+    0x5f3c, 0x5f6d, 0x5fa9, 0x5faa, 0x5fa8, 0x60d1, 0x60e1, 0x60b2, 0x60b6, 0x60e0, 0x611c, 0x6123,
+    0x60fa, 0x6115, 0x60f0, 0x60fb, 0x60f4, 0x6168, 0x60f1, 0x610e, 0x60f6, 0x6109, 0x6100, 0x6112,
+    0x621f, 0x6249, 0x63a3, 0x638c, 0x63cf, 0x63c0, 0x63e9, 0x63c9, 0x63c6, 0x63cd, 0x63d2, 0x63e3,
+    0x63d0, 0x63e1, 0x63d6, 0x63ed, 0x63ee, 0x6376, 0x63f4, 0x63ea, 0x63db, 0x6452, 0x63da, 0x63f9,
+    0x655e, 0x6566, 0x6562, 0x6563, 0x6591, 0x6590, 0x65af, 0x666e, 0x6670, 0x6674, 0x6676, 0x666f,
+    0x6691, 0x667a, 0x667e, 0x6677, 0x66fe, 0x66ff, 0x671f, 0x671d, 0x68fa, 0x68d5, 0x68e0, 0x68d8,
+    0x68d7, 0x6905, 0x68df, 0x68f5, 0x68ee, 0x68e7, 0x68f9, 0x68d2, 0x68f2, 0x68e3, 0x68cb, 0x68cd,
+    0x690d, 0x6912, 0x690e, 0x68c9, 0x68da, 0x696e, 0x68fb, 0x6b3e, 0x6b3a, 0x6b3d, 0x6b98, 0x6b96,
+    0x6bbc, 0x6bef, 0x6c2e, 0x6c2f, 0x6c2c, 0x6e2f, 0x6e38, 0x6e54, 0x6e21, 0x6e32, 0x6e67, 0x6e4a,
+    0x6e20, 0x6e25, 0x6e23, 0x6e1b, 0x6e5b, 0x6e58, 0x6e24, 0x6e56, 0x6e6e, 0x6e2d, 0x6e26, 0x6e6f,
+    0x6e34, 0x6e4d, 0x6e3a, 0x6e2c, 0x6e43, 0x6e1d, 0x6e3e, 0x6ecb, 0x6e89, 0x6e19, 0x6e4e, 0x6e63,
+    0x6e44, 0x6e72, 0x6e69, 0x6e5f, 0x7119, 0x711a, 0x7126, 0x7130, 0x7121, 0x7136, 0x716e, 0x711c,
+    0x724c, 0x7284, 0x7280, 0x7336, 0x7325, 0x7334, 0x7329, 0x743a, 0x742a, 0x7433, 0x7422, 0x7425,
+    0x7435, 0x7436, 0x7434, 0x742f, 0x741b, 0x7426, 0x7428, 0x7525, 0x7526, 0x756b, 0x756a, 0x75e2,
+    0x75db, 0x75e3, 0x75d9, 0x75d8, 0x75de, 0x75e0, 0x767b, 0x767c, 0x7696, 0x7693, 0x76b4, 0x76dc,
+    0x774f, 0x77ed, 0x785d, 0x786c, 0x786f, 0x7a0d, 0x7a08, 0x7a0b, 0x7a05, 0x7a00, 0x7a98, 0x7a97,
+    0x7a96, 0x7ae5, 0x7ae3, 0x7b49, 0x7b56, 0x7b46, 0x7b50, 0x7b52, 0x7b54, 0x7b4d, 0x7b4b, 0x7b4f,
+    0x7b51, 0x7c9f, 0x7ca5, 0x7d5e, 0x7d50, 0x7d68, 0x7d55, 0x7d2b, 0x7d6e, 0x7d72, 0x7d61, 0x7d66,
+    0x7d62, 0x7d70, 0x7d73, 0x5584, 0x7fd4, 0x7fd5, 0x800b, 0x8052, 0x8085, 0x8155, 0x8154, 0x814b,
+    0x8151, 0x814e, 0x8139, 0x8146, 0x813e, 0x814c, 0x8153, 0x8174, 0x8212, 0x821c, 0x83e9, 0x8403,
+    0x83f8, 0x840d, 0x83e0, 0x83c5, 0x840b, 0x83c1, 0x83ef, 0x83f1, 0x83f4, 0x8457, 0x840a, 0x83f0,
+    0x840c, 0x83cc, 0x83fd, 0x83f2, 0x83ca, 0x8438, 0x840e, 0x8404, 0x83dc, 0x8407, 0x83d4, 0x83df,
+    0x865b, 0x86df, 0x86d9, 0x86ed, 0x86d4, 0x86db, 0x86e4, 0x86d0, 0x86de, 0x8857, 0x88c1, 0x88c2,
+    0x88b1, 0x8983, 0x8996, 0x8a3b, 0x8a60, 0x8a55, 0x8a5e, 0x8a3c, 0x8a41, 0x8a54, 0x8a5b, 0x8a50,
+    0x8a46, 0x8a34, 0x8a3a, 0x8a36, 0x8a56, 0x8c61, 0x8c82, 0x8caf, 0x8cbc, 0x8cb3, 0x8cbd, 0x8cc1,
+    0x8cbb, 0x8cc0, 0x8cb4, 0x8cb7, 0x8cb6, 0x8cbf, 0x8cb8, 0x8d8a, 0x8d85, 0x8d81, 0x8dce, 0x8ddd,
+    0x8dcb, 0x8dda, 0x8dd1, 0x8dcc, 0x8ddb, 0x8dc6, 0x8efb, 0x8ef8, 0x8efc, 0x8f9c, 0x902e, 0x9035,
+    0x9031, 0x9038, 0x9032, 0x9036, 0x9102, 0x90f5, 0x9109, 0x90fe, 0x9163, 0x9165, 0x91cf, 0x9214,
+    0x9215, 0x9223, 0x9209, 0x921e, 0x920d, 0x9210, 0x9207, 0x9211, 0x9594, 0x958f, 0x958b, 0x9591,
+    0x9593, 0x9592, 0x958e, 0x968a, 0x968e, 0x968b, 0x967d, 0x9685, 0x9686, 0x968d, 0x9672, 0x9684,
+    0x96c1, 0x96c5, 0x96c4, 0x96c6, 0x96c7, 0x96ef, 0x96f2, 0x97cc, 0x9805, 0x9806, 0x9808, 0x98e7,
+    0x98ea, 0x98ef, 0x98e9, 0x98f2, 0x98ed, 0x99ae, 0x99ad, 0x9ec3, 0x9ecd, 0x9ed1, 0x4e82, 0x50ad,
+    0x50b5, 0x50b2, 0x50b3, 0x50c5, 0x50be, 0x50ac, 0x50b7, 0x50bb, 0x50af, 0x50c7, 0x527f, 0x5277,
+    0x527d, 0x52df, 0x52e6, 0x52e4, 0x52e2, 0x52e3, 0x532f, 0x55df, 0x55e8, 0x55d3, 0x55e6, 0x55ce,
+    0x55dc, 0x55c7, 0x55d1, 0x55e3, 0x55e4, 0x55ef, 0x55da, 0x55e1, 0x55c5, 0x55c6, 0x55e5, 0x55c9,
+    0x5712, 0x5713, 0x585e, 0x5851, 0x5858, 0x5857, 0x585a, 0x5854, 0x586b, 0x584c, 0x586d, 0x584a,
+    0x5862, 0x5852, 0x584b, 0x5967, 0x5ac1, 0x5ac9, 0x5acc, 0x5abe, 0x5abd, 0x5abc, 0x5ab3, 0x5ac2,
+    0x5ab2, 0x5d69, 0x5d6f, 0x5e4c, 0x5e79, 0x5ec9, 0x5ec8, 0x5f12, 0x5f59, 0x5fac, 0x5fae, 0x611a,
+    0x610f, 0x6148, 0x611f, 0x60f3, 0x611b, 0x60f9, 0x6101, 0x6108, 0x614e, 0x614c, 0x6144, 0x614d,
+    0x613e, 0x6134, 0x6127, 0x610d, 0x6106, 0x6137, 0x6221, 0x6222, 0x6413, 0x643e, 0x641e, 0x642a,
+    0x642d, 0x643d, 0x642c, 0x640f, 0x641c, 0x6414, 0x640d, 0x6436, 0x6416, 0x6417, 0x6406, 0x656c,
+    0x659f, 0x65b0, 0x6697, 0x6689, 0x6687, 0x6688, 0x6696, 0x6684, 0x6698, 0x668d, 0x6703, 0x6994,
+    0x696d, 0x695a, 0x6977, 0x6960, 0x6954, 0x6975, 0x6930, 0x6982, 0x694a, 0x6968, 0x696b, 0x695e,
+    0x6953, 0x6979, 0x6986, 0x695d, 0x6963, 0x695b, 0x6b47, 0x6b72, 0x6bc0, 0x6bbf, 0x6bd3, 0x6bfd,
+    0x6ea2, 0x6eaf, 0x6ed3, 0x6eb6, 0x6ec2, 0x6e90, 0x6e9d, 0x6ec7, 0x6ec5, 0x6ea5, 0x6e98, 0x6ebc,
+    0x6eba, 0x6eab, 0x6ed1, 0x6e96, 0x6e9c, 0x6ec4, 0x6ed4, 0x6eaa, 0x6ea7, 0x6eb4, 0x714e, 0x7159,
+    0x7169, 0x7164, 0x7149, 0x7167, 0x715c, 0x716c, 0x7166, 0x714c, 0x7165, 0x715e, 0x7146, 0x7168,
+    0x7156, 0x723a, 0x7252, 0x7337, 0x7345, 0x733f, 0x733e, 0x746f, 0x745a, 0x7455, 0x745f, 0x745e,
+    0x7441, 0x743f, 0x7459, 0x745b, 0x745c, 0x7576, 0x7578, 0x7600, 0x75f0, 0x7601, 0x75f2, 0x75f1,
+    0x75fa, 0x75ff, 0x75f4, 0x75f3, 0x76de, 0x76df, 0x775b, 0x776b, 0x7766, 0x775e, 0x7763, 0x7779,
+    0x776a, 0x776c, 0x775c, 0x7765, 0x7768, 0x7762, 0x77ee, 0x788e, 0x78b0, 0x7897, 0x7898, 0x788c,
+    0x7889, 0x787c, 0x7891, 0x7893, 0x787f, 0x797a, 0x797f, 0x7981, 0x842c, 0x79bd, 0x7a1c, 0x7a1a,
+    0x7a20, 0x7a14, 0x7a1f, 0x7a1e, 0x7a9f, 0x7aa0, 0x7b77, 0x7bc0, 0x7b60, 0x7b6e, 0x7b67, 0x7cb1,
+    0x7cb3, 0x7cb5, 0x7d93, 0x7d79, 0x7d91, 0x7d81, 0x7d8f, 0x7d5b, 0x7f6e, 0x7f69, 0x7f6a, 0x7f72,
+    0x7fa9, 0x7fa8, 0x7fa4, 0x8056, 0x8058, 0x8086, 0x8084, 0x8171, 0x8170, 0x8178, 0x8165, 0x816e,
+    0x8173, 0x816b, 0x8179, 0x817a, 0x8166, 0x8205, 0x8247, 0x8482, 0x8477, 0x843d, 0x8431, 0x8475,
+    0x8466, 0x846b, 0x8449, 0x846c, 0x845b, 0x843c, 0x8435, 0x8461, 0x8463, 0x8469, 0x846d, 0x8446,
+    0x865e, 0x865c, 0x865f, 0x86f9, 0x8713, 0x8708, 0x8707, 0x8700, 0x86fe, 0x86fb, 0x8702, 0x8703,
+    0x8706, 0x870a, 0x8859, 0x88df, 0x88d4, 0x88d9, 0x88dc, 0x88d8, 0x88dd, 0x88e1, 0x88ca, 0x88d5,
+    0x88d2, 0x899c, 0x89e3, 0x8a6b, 0x8a72, 0x8a73, 0x8a66, 0x8a69, 0x8a70, 0x8a87, 0x8a7c, 0x8a63,
+    0x8aa0, 0x8a71, 0x8a85, 0x8a6d, 0x8a62, 0x8a6e, 0x8a6c, 0x8a79, 0x8a7b, 0x8a3e, 0x8a68, 0x8c62,
+    0x8c8a, 0x8c89, 0x8cca, 0x8cc7, 0x8cc8, 0x8cc4, 0x8cb2, 0x8cc3, 0x8cc2, 0x8cc5, 0x8de1, 0x8ddf,
+    0x8de8, 0x8def, 0x8df3, 0x8dfa, 0x8dea, 0x8de4, 0x8de6, 0x8eb2, 0x8f03, 0x8f09, 0x8efe, 0x8f0a,
+    0x8f9f, 0x8fb2, 0x904b, 0x904a, 0x9053, 0x9042, 0x9054, 0x903c, 0x9055, 0x9050, 0x9047, 0x904f,
+    0x904e, 0x904d, 0x9051, 0x903e, 0x9041, 0x9112, 0x9117, 0x916c, 0x916a, 0x9169, 0x91c9, 0x9237,
+    0x9257, 0x9238, 0x923d, 0x9240, 0x923e, 0x925b, 0x924b, 0x9264, 0x9251, 0x9234, 0x9249, 0x924d,
+    0x9245, 0x9239, 0x923f, 0x925a, 0x9598, 0x9698, 0x9694, 0x9695, 0x96cd, 0x96cb, 0x96c9, 0x96ca,
+    0x96f7, 0x96fb, 0x96f9, 0x96f6, 0x9756, 0x9774, 0x9776, 0x9810, 0x9811, 0x9813, 0x980a, 0x9812,
+    0x980c, 0x98fc, 0x98f4, 0x98fd, 0x98fe, 0x99b3, 0x99b1, 0x99b4, 0x9ae1, 0x9ce9, 0x9e82, 0x9f0e,
+    0x9f13, 0x9f20, 0x50e7, 0x50ee, 0x50e5, 0x50d6, 0x50ed, 0x50da, 0x50d5, 0x50cf, 0x50d1, 0x50f1,
+    0x50ce, 0x50e9, 0x5162, 0x51f3, 0x5283, 0x5282, 0x5331, 0x53ad, 0x55fe, 0x5600, 0x561b, 0x5617,
+    0x55fd, 0x5614, 0x5606, 0x5609, 0x560d, 0x560e, 0x55f7, 0x5616, 0x561f, 0x5608, 0x5610, 0x55f6,
+    0x5718, 0x5716, 0x5875, 0x587e, 0x5883, 0x5893, 0x588a, 0x5879, 0x5885, 0x587d, 0x58fd, 0x5925,
+    0x5922, 0x5924, 0x596a, 0x5969, 0x5ae1, 0x5ae6, 0x5ae9, 0x5ad7, 0x5ad6, 0x5ad8, 0x5ae3, 0x5b75,
+    0x5bde, 0x5be7, 0x5be1, 0x5be5, 0x5be6, 0x5be8, 0x5be2, 0x5be4, 0x5bdf, 0x5c0d, 0x5c62, 0x5d84,
+    0x5d87, 0x5e5b, 0x5e63, 0x5e55, 0x5e57, 0x5e54, 0x5ed3, 0x5ed6, 0x5f0a, 0x5f46, 0x5f70, 0x5fb9,
+    0x6147, 0x613f, 0x614b, 0x6177, 0x6162, 0x6163, 0x615f, 0x615a, 0x6158, 0x6175, 0x622a, 0x6487,
+    0x6458, 0x6454, 0x64a4, 0x6478, 0x645f, 0x647a, 0x6451, 0x6467, 0x6434, 0x646d, 0x647b, 0x6572,
+    0x65a1, 0x65d7, 0x65d6, 0x66a2, 0x66a8, 0x669d, 0x699c, 0x69a8, 0x6995, 0x69c1, 0x69ae, 0x69d3,
+    0x69cb, 0x699b, 0x69b7, 0x69bb, 0x69ab, 0x69b4, 0x69d0, 0x69cd, 0x69ad, 0x69cc, 0x69a6, 0x69c3,
+    0x69a3, 0x6b49, 0x6b4c, 0x6c33, 0x6f33, 0x6f14, 0x6efe, 0x6f13, 0x6ef4, 0x6f29, 0x6f3e, 0x6f20,
+    0x6f2c, 0x6f0f, 0x6f02, 0x6f22, 0x6eff, 0x6eef, 0x6f06, 0x6f31, 0x6f38, 0x6f32, 0x6f23, 0x6f15,
+    0x6f2b, 0x6f2f, 0x6f88, 0x6f2a, 0x6eec, 0x6f01, 0x6ef2, 0x6ecc, 0x6ef7, 0x7194, 0x7199, 0x717d,
+    0x718a, 0x7184, 0x7192, 0x723e, 0x7292, 0x7296, 0x7344, 0x7350, 0x7464, 0x7463, 0x746a, 0x7470,
+    0x746d, 0x7504, 0x7591, 0x7627, 0x760d, 0x760b, 0x7609, 0x7613, 0x76e1, 0x76e3, 0x7784, 0x777d,
+    0x777f, 0x7761, 0x78c1, 0x789f, 0x78a7, 0x78b3, 0x78a9, 0x78a3, 0x798e, 0x798f, 0x798d, 0x7a2e,
+    0x7a31, 0x7aaa, 0x7aa9, 0x7aed, 0x7aef, 0x7ba1, 0x7b95, 0x7b8b, 0x7b75, 0x7b97, 0x7b9d, 0x7b94,
+    0x7b8f, 0x7bb8, 0x7b87, 0x7b84, 0x7cb9, 0x7cbd, 0x7cbe, 0x7dbb, 0x7db0, 0x7d9c, 0x7dbd, 0x7dbe,
+    0x7da0, 0x7dca, 0x7db4, 0x7db2, 0x7db1, 0x7dba, 0x7da2, 0x7dbf, 0x7db5, 0x7db8, 0x7dad, 0x7dd2,
+    0x7dc7, 0x7dac, 0x7f70, 0x7fe0, 0x7fe1, 0x7fdf, 0x805e, 0x805a, 0x8087, 0x8150, 0x8180, 0x818f,
+    0x8188, 0x818a, 0x817f, 0x8182, 0x81e7, 0x81fa, 0x8207, 0x8214, 0x821e, 0x824b, 0x84c9, 0x84bf,
+    0x84c6, 0x84c4, 0x8499, 0x849e, 0x84b2, 0x849c, 0x84cb, 0x84b8, 0x84c0, 0x84d3, 0x8490, 0x84bc,
+    0x84d1, 0x84ca, 0x873f, 0x871c, 0x873b, 0x8722, 0x8725, 0x8734, 0x8718, 0x8755, 0x8737, 0x8729,
+    0x88f3, 0x8902, 0x88f4, 0x88f9, 0x88f8, 0x88fd, 0x88e8, 0x891a, 0x88ef, 0x8aa6, 0x8a8c, 0x8a9e,
+    0x8aa3, 0x8a8d, 0x8aa1, 0x8a93, 0x8aa4, 0x8aaa, 0x8aa5, 0x8aa8, 0x8a98, 0x8a91, 0x8a9a, 0x8aa7,
+    0x8c6a, 0x8c8d, 0x8c8c, 0x8cd3, 0x8cd1, 0x8cd2, 0x8d6b, 0x8d99, 0x8d95, 0x8dfc, 0x8f14, 0x8f12,
+    0x8f15, 0x8f13, 0x8fa3, 0x9060, 0x9058, 0x905c, 0x9063, 0x9059, 0x905e, 0x9062, 0x905d, 0x905b,
+    0x9119, 0x9118, 0x911e, 0x9175, 0x9178, 0x9177, 0x9174, 0x9278, 0x9280, 0x9285, 0x9298, 0x9296,
+    0x927b, 0x9293, 0x929c, 0x92a8, 0x927c, 0x9291, 0x95a1, 0x95a8, 0x95a9, 0x95a3, 0x95a5, 0x95a4,
+    0x9699, 0x969c, 0x969b, 0x96cc, 0x96d2, 0x9700, 0x977c, 0x9785, 0x97f6, 0x9817, 0x9818, 0x98af,
+    0x98b1, 0x9903, 0x9905, 0x990c, 0x9909, 0x99c1, 0x9aaf, 0x9ab0, 0x9ae6, 0x9b41, 0x9b42, 0x9cf4,
+    0x9cf6, 0x9cf3, 0x9ebc, 0x9f3b, 0x9f4a, 0x5104, 0x5100, 0x50fb, 0x50f5, 0x50f9, 0x5102, 0x5108,
+    0x5109, 0x5105, 0x51dc, 0x5287, 0x5288, 0x5289, 0x528d, 0x528a, 0x52f0, 0x53b2, 0x562e, 0x563b,
+    0x5639, 0x5632, 0x563f, 0x5634, 0x5629, 0x5653, 0x564e, 0x5657, 0x5674, 0x5636, 0x562f, 0x5630,
+    0x5880, 0x589f, 0x589e, 0x58b3, 0x589c, 0x58ae, 0x58a9, 0x58a6, 0x596d, 0x5b09, 0x5afb, 0x5b0b,
+    0x5af5, 0x5b0c, 0x5b08, 0x5bee, 0x5bec, 0x5be9, 0x5beb, 0x5c64, 0x5c65, 0x5d9d, 0x5d94, 0x5e62,
+    0x5e5f, 0x5e61, 0x5ee2, 0x5eda, 0x5edf, 0x5edd, 0x5ee3, 0x5ee0, 0x5f48, 0x5f71, 0x5fb7, 0x5fb5,
+    0x6176, 0x6167, 0x616e, 0x615d, 0x6155, 0x6182, 0x617c, 0x6170, 0x616b, 0x617e, 0x61a7, 0x6190,
+    0x61ab, 0x618e, 0x61ac, 0x619a, 0x61a4, 0x6194, 0x61ae, 0x622e, 0x6469, 0x646f, 0x6479, 0x649e,
+    0x64b2, 0x6488, 0x6490, 0x64b0, 0x64a5, 0x6493, 0x6495, 0x64a9, 0x6492, 0x64ae, 0x64ad, 0x64ab,
+    0x649a, 0x64ac, 0x6499, 0x64a2, 0x64b3, 0x6575, 0x6577, 0x6578, 0x66ae, 0x66ab, 0x66b4, 0x66b1,
+    0x6a23, 0x6a1f, 0x69e8, 0x6a01, 0x6a1e, 0x6a19, 0x69fd, 0x6a21, 0x6a13, 0x6a0a, 0x69f3, 0x6a02,
+    0x6a05, 0x69ed, 0x6a11, 0x6b50, 0x6b4e, 0x6ba4, 0x6bc5, 0x6bc6, 0x6f3f, 0x6f7c, 0x6f84, 0x6f51,
+    0x6f66, 0x6f54, 0x6f86, 0x6f6d, 0x6f5b, 0x6f78, 0x6f6e, 0x6f8e, 0x6f7a, 0x6f70, 0x6f64, 0x6f97,
+    0x6f58, 0x6ed5, 0x6f6f, 0x6f60, 0x6f5f, 0x719f, 0x71ac, 0x71b1, 0x71a8, 0x7256, 0x729b, 0x734e,
+    0x7357, 0x7469, 0x748b, 0x7483, 0x747e, 0x7480, 0x757f, 0x7620, 0x7629, 0x761f, 0x7624, 0x7626,
+    0x7621, 0x7622, 0x769a, 0x76ba, 0x76e4, 0x778e, 0x7787, 0x778c, 0x7791, 0x778b, 0x78cb, 0x78c5,
+    0x78ba, 0x78ca, 0x78be, 0x78d5, 0x78bc, 0x78d0, 0x7a3f, 0x7a3c, 0x7a40, 0x7a3d, 0x7a37, 0x7a3b,
+    0x7aaf, 0x7aae, 0x7bad, 0x7bb1, 0x7bc4, 0x7bb4, 0x7bc6, 0x7bc7, 0x7bc1, 0x7ba0, 0x7bcc, 0x7cca,
+    0x7de0, 0x7df4, 0x7def, 0x7dfb, 0x7dd8, 0x7dec, 0x7ddd, 0x7de8, 0x7de3, 0x7dda, 0x7dde, 0x7de9,
+    0x7d9e, 0x7dd9, 0x7df2, 0x7df9, 0x7f75, 0x7f77, 0x7faf, 0x7fe9, 0x8026, 0x819b, 0x819c, 0x819d,
+    0x81a0, 0x819a, 0x8198, 0x8517, 0x853d, 0x851a, 0x84ee, 0x852c, 0x852d, 0x8513, 0x8511, 0x8523,
+    0x8521, 0x8514, 0x84ec, 0x8525, 0x84ff, 0x8506, 0x8782, 0x8774, 0x8776, 0x8760, 0x8766, 0x8778,
+    0x8768, 0x8759, 0x8757, 0x874c, 0x8753, 0x885b, 0x885d, 0x8910, 0x8907, 0x8912, 0x8913, 0x8915,
+    0x890a, 0x8abc, 0x8ad2, 0x8ac7, 0x8ac4, 0x8a95, 0x8acb, 0x8af8, 0x8ab2, 0x8ac9, 0x8ac2, 0x8abf,
+    0x8ab0, 0x8ad6, 0x8acd, 0x8ab6, 0x8ab9, 0x8adb, 0x8c4c, 0x8c4e, 0x8c6c, 0x8ce0, 0x8cde, 0x8ce6,
+    0x8ce4, 0x8cec, 0x8ced, 0x8ce2, 0x8ce3, 0x8cdc, 0x8cea, 0x8ce1, 0x8d6d, 0x8d9f, 0x8da3, 0x8e2b,
+    0x8e10, 0x8e1d, 0x8e22, 0x8e0f, 0x8e29, 0x8e1f, 0x8e21, 0x8e1e, 0x8eba, 0x8f1d, 0x8f1b, 0x8f1f,
+    0x8f29, 0x8f26, 0x8f2a, 0x8f1c, 0x8f1e, 0x8f25, 0x9069, 0x906e, 0x9068, 0x906d, 0x9077, 0x9130,
+    0x912d, 0x9127, 0x9131, 0x9187, 0x9189, 0x918b, 0x9183, 0x92c5, 0x92bb, 0x92b7, 0x92ea, 0x92ac,
+    0x92e4, 0x92c1, 0x92b3, 0x92bc, 0x92d2, 0x92c7, 0x92f0, 0x92b2, 0x95ad, 0x95b1, 0x9704, 0x9706,
+    0x9707, 0x9709, 0x9760, 0x978d, 0x978b, 0x978f, 0x9821, 0x982b, 0x981c, 0x98b3, 0x990a, 0x9913,
+    0x9912, 0x9918, 0x99dd, 0x99d0, 0x99df, 0x99db, 0x99d1, 0x99d5, 0x99d2, 0x99d9, 0x9ab7, 0x9aee,
+    0x9aef, 0x9b27, 0x9b45, 0x9b44, 0x9b77, 0x9b6f, 0x9d06, 0x9d09, 0x9d03, 0x9ea9, 0x9ebe, 0x9ece,
+    0x58a8, 0x9f52, 0x5112, 0x5118, 0x5114, 0x5110, 0x5115, 0x5180, 0x51aa, 0x51dd, 0x5291, 0x5293,
+    0x52f3, 0x5659, 0x566b, 0x5679, 0x5669, 0x5664, 0x5678, 0x566a, 0x5668, 0x5665, 0x5671, 0x566f,
+    0x566c, 0x5662, 0x5676, 0x58c1, 0x58be, 0x58c7, 0x58c5, 0x596e, 0x5b1d, 0x5b34, 0x5b78, 0x5bf0,
+    0x5c0e, 0x5f4a, 0x61b2, 0x6191, 0x61a9, 0x618a, 0x61cd, 0x61b6, 0x61be, 0x61ca, 0x61c8, 0x6230,
+    0x64c5, 0x64c1, 0x64cb, 0x64bb, 0x64bc, 0x64da, 0x64c4, 0x64c7, 0x64c2, 0x64cd, 0x64bf, 0x64d2,
+    0x64d4, 0x64be, 0x6574, 0x66c6, 0x66c9, 0x66b9, 0x66c4, 0x66c7, 0x66b8, 0x6a3d, 0x6a38, 0x6a3a,
+    0x6a59, 0x6a6b, 0x6a58, 0x6a39, 0x6a44, 0x6a62, 0x6a61, 0x6a4b, 0x6a47, 0x6a35, 0x6a5f, 0x6a48,
+    0x6b59, 0x6b77, 0x6c05, 0x6fc2, 0x6fb1, 0x6fa1, 0x6fc3, 0x6fa4, 0x6fc1, 0x6fa7, 0x6fb3, 0x6fc0,
+    0x6fb9, 0x6fb6, 0x6fa6, 0x6fa0, 0x6fb4, 0x71be, 0x71c9, 0x71d0, 0x71d2, 0x71c8, 0x71d5, 0x71b9,
+    0x71ce, 0x71d9, 0x71dc, 0x71c3, 0x71c4, 0x7368, 0x749c, 0x74a3, 0x7498, 0x749f, 0x749e, 0x74e2,
+    0x750c, 0x750d, 0x7634, 0x7638, 0x763a, 0x76e7, 0x76e5, 0x77a0, 0x779e, 0x779f, 0x77a5, 0x78e8,
+    0x78da, 0x78ec, 0x78e7, 0x79a6, 0x7a4d, 0x7a4e, 0x7a46, 0x7a4c, 0x7a4b, 0x7aba, 0x7bd9, 0x7c11,
+    0x7bc9, 0x7be4, 0x7bdb, 0x7be1, 0x7be9, 0x7be6, 0x7cd5, 0x7cd6, 0x7e0a, 0x7e11, 0x7e08, 0x7e1b,
+    0x7e23, 0x7e1e, 0x7e1d, 0x7e09, 0x7e10, 0x7f79, 0x7fb2, 0x7ff0, 0x7ff1, 0x7fee, 0x8028, 0x81b3,
+    0x81a9, 0x81a8, 0x81fb, 0x8208, 0x8258, 0x8259, 0x854a, 0x8559, 0x8548, 0x8568, 0x8569, 0x8543,
+    0x8549, 0x856d, 0x856a, 0x855e, 0x8783, 0x879f, 0x879e, 0x87a2, 0x878d, 0x8861, 0x892a, 0x8932,
+    0x8925, 0x892b, 0x8921, 0x89aa, 0x89a6, 0x8ae6, 0x8afa, 0x8aeb, 0x8af1, 0x8b00, 0x8adc, 0x8ae7,
+    0x8aee, 0x8afe, 0x8b01, 0x8b02, 0x8af7, 0x8aed, 0x8af3, 0x8af6, 0x8afc, 0x8c6b, 0x8c6d, 0x8c93,
+    0x8cf4, 0x8e44, 0x8e31, 0x8e34, 0x8e42, 0x8e39, 0x8e35, 0x8f3b, 0x8f2f, 0x8f38, 0x8f33, 0x8fa8,
+    0x8fa6, 0x9075, 0x9074, 0x9078, 0x9072, 0x907c, 0x907a, 0x9134, 0x9192, 0x9320, 0x9336, 0x92f8,
+    0x9333, 0x932f, 0x9322, 0x92fc, 0x932b, 0x9304, 0x931a, 0x9310, 0x9326, 0x9321, 0x9315, 0x932e,
+    0x9319, 0x95bb, 0x96a7, 0x96a8, 0x96aa, 0x96d5, 0x970e, 0x9711, 0x9716, 0x970d, 0x9713, 0x970f,
+    0x975b, 0x975c, 0x9766, 0x9798, 0x9830, 0x9838, 0x983b, 0x9837, 0x982d, 0x9839, 0x9824, 0x9910,
+    0x9928, 0x991e, 0x991b, 0x9921, 0x991a, 0x99ed, 0x99e2, 0x99f1, 0x9ab8, 0x9abc, 0x9afb, 0x9aed,
+    0x9b28, 0x9b91, 0x9d15, 0x9d23, 0x9d26, 0x9d28, 0x9d12, 0x9d1b, 0x9ed8, 0x9ed4, 0x9f8d, 0x9f9c,
+    0x512a, 0x511f, 0x5121, 0x5132, 0x52f5, 0x568e, 0x5680, 0x5690, 0x5685, 0x5687, 0x568f, 0x58d5,
+    0x58d3, 0x58d1, 0x58ce, 0x5b30, 0x5b2a, 0x5b24, 0x5b7a, 0x5c37, 0x5c68, 0x5dbc, 0x5dba, 0x5dbd,
+    0x5db8, 0x5e6b, 0x5f4c, 0x5fbd, 0x61c9, 0x61c2, 0x61c7, 0x61e6, 0x61cb, 0x6232, 0x6234, 0x64ce,
+    0x64ca, 0x64d8, 0x64e0, 0x64f0, 0x64e6, 0x64ec, 0x64f1, 0x64e2, 0x64ed, 0x6582, 0x6583, 0x66d9,
+    0x66d6, 0x6a80, 0x6a94, 0x6a84, 0x6aa2, 0x6a9c, 0x6adb, 0x6aa3, 0x6a7e, 0x6a97, 0x6a90, 0x6aa0,
+    0x6b5c, 0x6bae, 0x6bda, 0x6c08, 0x6fd8, 0x6ff1, 0x6fdf, 0x6fe0, 0x6fdb, 0x6fe4, 0x6feb, 0x6fef,
+    0x6f80, 0x6fec, 0x6fe1, 0x6fe9, 0x6fd5, 0x6fee, 0x6ff0, 0x71e7, 0x71df, 0x71ee, 0x71e6, 0x71e5,
+    0x71ed, 0x71ec, 0x71f4, 0x71e0, 0x7235, 0x7246, 0x7370, 0x7372, 0x74a9, 0x74b0, 0x74a6, 0x74a8,
+    0x7646, 0x7642, 0x764c, 0x76ea, 0x77b3, 0x77aa, 0x77b0, 0x77ac, 0x77a7, 0x77ad, 0x77ef, 0x78f7,
+    0x78fa, 0x78f4, 0x78ef, 0x7901, 0x79a7, 0x79aa, 0x7a57, 0x7abf, 0x7c07, 0x7c0d, 0x7bfe, 0x7bf7,
+    0x7c0c, 0x7be0, 0x7ce0, 0x7cdc, 0x7cde, 0x7ce2, 0x7cdf, 0x7cd9, 0x7cdd, 0x7e2e, 0x7e3e, 0x7e46,
+    0x7e37, 0x7e32, 0x7e43, 0x7e2b, 0x7e3d, 0x7e31, 0x7e45, 0x7e41, 0x7e34, 0x7e39, 0x7e48, 0x7e35,
+    0x7e3f, 0x7e2f, 0x7f44, 0x7ff3, 0x7ffc, 0x8071, 0x8072, 0x8070, 0x806f, 0x8073, 0x81c6, 0x81c3,
+    0x81ba, 0x81c2, 0x81c0, 0x81bf, 0x81bd, 0x81c9, 0x81be, 0x81e8, 0x8209, 0x8271, 0x85aa, 0x8584,
+    0x857e, 0x859c, 0x8591, 0x8594, 0x85af, 0x859b, 0x8587, 0x85a8, 0x858a, 0x8667, 0x87c0, 0x87d1,
+    0x87b3, 0x87d2, 0x87c6, 0x87ab, 0x87bb, 0x87ba, 0x87c8, 0x87cb, 0x893b, 0x8936, 0x8944, 0x8938,
+    0x893d, 0x89ac, 0x8b0e, 0x8b17, 0x8b19, 0x8b1b, 0x8b0a, 0x8b20, 0x8b1d, 0x8b04, 0x8b10, 0x8c41,
+    0x8c3f, 0x8c73, 0x8cfa, 0x8cfd, 0x8cfc, 0x8cf8, 0x8cfb, 0x8da8, 0x8e49, 0x8e4b, 0x8e48, 0x8e4a,
+    0x8f44, 0x8f3e, 0x8f42, 0x8f45, 0x8f3f, 0x907f, 0x907d, 0x9084, 0x9081, 0x9082, 0x9080, 0x9139,
+    0x91a3, 0x919e, 0x919c, 0x934d, 0x9382, 0x9328, 0x9375, 0x934a, 0x9365, 0x934b, 0x9318, 0x937e,
+    0x936c, 0x935b, 0x9370, 0x935a, 0x9354, 0x95ca, 0x95cb, 0x95cc, 0x95c8, 0x95c6, 0x96b1, 0x96b8,
+    0x96d6, 0x971c, 0x971e, 0x97a0, 0x97d3, 0x9846, 0x98b6, 0x9935, 0x9a01, 0x99ff, 0x9bae, 0x9bab,
+    0x9baa, 0x9bad, 0x9d3b, 0x9d3f, 0x9e8b, 0x9ecf, 0x9ede, 0x9edc, 0x9edd, 0x9edb, 0x9f3e, 0x9f4b,
+    0x53e2, 0x5695, 0x56ae, 0x58d9, 0x58d8, 0x5b38, 0x5f5d, 0x61e3, 0x6233, 0x64f4, 0x64f2, 0x64fe,
+    0x6506, 0x64fa, 0x64fb, 0x64f7, 0x65b7, 0x66dc, 0x6726, 0x6ab3, 0x6aac, 0x6ac3, 0x6abb, 0x6ab8,
+    0x6ac2, 0x6aae, 0x6aaf, 0x6b5f, 0x6b78, 0x6baf, 0x7009, 0x700b, 0x6ffe, 0x7006, 0x6ffa, 0x7011,
+    0x700f, 0x71fb, 0x71fc, 0x71fe, 0x71f8, 0x7377, 0x7375, 0x74a7, 0x74bf, 0x7515, 0x7656, 0x7658,
+    0x7652, 0x77bd, 0x77bf, 0x77bb, 0x77bc, 0x790e, 0x79ae, 0x7a61, 0x7a62, 0x7a60, 0x7ac4, 0x7ac5,
+    0x7c2b, 0x7c27, 0x7c2a, 0x7c1e, 0x7c23, 0x7c21, 0x7ce7, 0x7e54, 0x7e55, 0x7e5e, 0x7e5a, 0x7e61,
+    0x7e52, 0x7e59, 0x7f48, 0x7ff9, 0x7ffb, 0x8077, 0x8076, 0x81cd, 0x81cf, 0x820a, 0x85cf, 0x85a9,
+    0x85cd, 0x85d0, 0x85c9, 0x85b0, 0x85ba, 0x85b9, 0x85a6, 0x87ef, 0x87ec, 0x87f2, 0x87e0, 0x8986,
+    0x89b2, 0x89f4, 0x8b28, 0x8b39, 0x8b2c, 0x8b2b, 0x8c50, 0x8d05, 0x8e59, 0x8e63, 0x8e66, 0x8e64,
+    0x8e5f, 0x8e55, 0x8ec0, 0x8f49, 0x8f4d, 0x9087, 0x9083, 0x9088, 0x91ab, 0x91ac, 0x91d0, 0x9394,
+    0x938a, 0x9396, 0x93a2, 0x93b3, 0x93ae, 0x93ac, 0x93b0, 0x9398, 0x939a, 0x9397, 0x95d4, 0x95d6,
+    0x95d0, 0x95d5, 0x96e2, 0x96dc, 0x96d9, 0x96db, 0x96de, 0x9724, 0x97a3, 0x97a6, 0x97ad, 0x97f9,
+    0x984d, 0x984f, 0x984c, 0x984e, 0x9853, 0x98ba, 0x993e, 0x993f, 0x993d, 0x992e, 0x99a5, 0x9a0e,
+    0x9ac1, 0x9b03, 0x9b06, 0x9b4f, 0x9b4e, 0x9b4d, 0x9bca, 0x9bc9, 0x9bfd, 0x9bc8, 0x9bc0, 0x9d51,
+    0x9d5d, 0x9d60, 0x9ee0, 0x9f15, 0x9f2c, 0x5133, 0x56a5, 0x58de, 0x58df, 0x58e2, 0x5bf5, 0x9f90,
+    0x5eec, 0x61f2, 0x61f7, 0x61f6, 0x61f5, 0x6500, 0x650f, 0x66e0, 0x66dd, 0x6ae5, 0x6add, 0x6ada,
+    0x6ad3, 0x701b, 0x701f, 0x7028, 0x701a, 0x701d, 0x7015, 0x7018, 0x7206, 0x720d, 0x7258, 0x72a2,
+    0x7378, 0x737a, 0x74bd, 0x74ca, 0x74e3, 0x7587, 0x7586, 0x765f, 0x7661, 0x77c7, 0x7919, 0x79b1,
+    0x7a6b, 0x7a69, 0x7c3e, 0x7c3f, 0x7c38, 0x7c3d, 0x7c37, 0x7c40, 0x7e6b, 0x7e6d, 0x7e79, 0x7e69,
+    0x7e6a, 0x7f85, 0x7e73, 0x7fb6, 0x7fb9, 0x7fb8, 0x81d8, 0x85e9, 0x85dd, 0x85ea, 0x85d5, 0x85e4,
+    0x85e5, 0x85f7, 0x87fb, 0x8805, 0x880d, 0x87f9, 0x87fe, 0x8960, 0x895f, 0x8956, 0x895e, 0x8b41,
+    0x8b5c, 0x8b58, 0x8b49, 0x8b5a, 0x8b4e, 0x8b4f, 0x8b46, 0x8b59, 0x8d08, 0x8d0a, 0x8e7c, 0x8e72,
+    0x8e87, 0x8e76, 0x8e6c, 0x8e7a, 0x8e74, 0x8f54, 0x8f4e, 0x8fad, 0x908a, 0x908b, 0x91b1, 0x91ae,
+    0x93e1, 0x93d1, 0x93df, 0x93c3, 0x93c8, 0x93dc, 0x93dd, 0x93d6, 0x93e2, 0x93cd, 0x93d8, 0x93e4,
+    0x93d7, 0x93e8, 0x95dc, 0x96b4, 0x96e3, 0x972a, 0x9727, 0x9761, 0x97dc, 0x97fb, 0x985e, 0x9858,
+    0x985b, 0x98bc, 0x9945, 0x9949, 0x9a16, 0x9a19, 0x9b0d, 0x9be8, 0x9be7, 0x9bd6, 0x9bdb, 0x9d89,
+    0x9d61, 0x9d72, 0x9d6a, 0x9d6c, 0x9e92, 0x9e97, 0x9e93, 0x9eb4, 0x52f8, 0x56a8, 0x56b7, 0x56b6,
+    0x56b4, 0x56bc, 0x58e4, 0x5b40, 0x5b43, 0x5b7d, 0x5bf6, 0x5dc9, 0x61f8, 0x61fa, 0x6518, 0x6514,
+    0x6519, 0x66e6, 0x6727, 0x6aec, 0x703e, 0x7030, 0x7032, 0x7210, 0x737b, 0x74cf, 0x7662, 0x7665,
+    0x7926, 0x792a, 0x792c, 0x792b, 0x7ac7, 0x7af6, 0x7c4c, 0x7c43, 0x7c4d, 0x7cef, 0x7cf0, 0x8fae,
+    0x7e7d, 0x7e7c, 0x7e82, 0x7f4c, 0x8000, 0x81da, 0x8266, 0x85fb, 0x85f9, 0x8611, 0x85fa, 0x8606,
+    0x860b, 0x8607, 0x860a, 0x8814, 0x8815, 0x8964, 0x89ba, 0x89f8, 0x8b70, 0x8b6c, 0x8b66, 0x8b6f,
+    0x8b5f, 0x8b6b, 0x8d0f, 0x8d0d, 0x8e89, 0x8e81, 0x8e85, 0x8e82, 0x91b4, 0x91cb, 0x9418, 0x9403,
+    0x93fd, 0x95e1, 0x9730, 0x98c4, 0x9952, 0x9951, 0x99a8, 0x9a2b, 0x9a30, 0x9a37, 0x9a35, 0x9c13,
+    0x9c0d, 0x9e79, 0x9eb5, 0x9ee8, 0x9f2f, 0x9f5f, 0x9f63, 0x9f61, 0x5137, 0x5138, 0x56c1, 0x56c0,
+    0x56c2, 0x5914, 0x5c6c, 0x5dcd, 0x61fc, 0x61fe, 0x651d, 0x651c, 0x6595, 0x66e9, 0x6afb, 0x6b04,
+    0x6afa, 0x6bb2, 0x704c, 0x721b, 0x72a7, 0x74d6, 0x74d4, 0x7669, 0x77d3, 0x7c50, 0x7e8f, 0x7e8c,
+    0x7fbc, 0x8617, 0x862d, 0x861a, 0x8823, 0x8822, 0x8821, 0x881f, 0x896a, 0x896c, 0x89bd, 0x8b74,
+    0x8b77, 0x8b7d, 0x8d13, 0x8e8a, 0x8e8d, 0x8e8b, 0x8f5f, 0x8faf, 0x91ba, 0x942e, 0x9433, 0x9435,
+    0x943a, 0x9438, 0x9432, 0x942b, 0x95e2, 0x9738, 0x9739, 0x9732, 0x97ff, 0x9867, 0x9865, 0x9957,
+    0x9a45, 0x9a43, 0x9a40, 0x9a3e, 0x9acf, 0x9b54, 0x9b51, 0x9c2d, 0x9c25, 0x9daf, 0x9db4, 0x9dc2,
+    0x9db8, 0x9e9d, 0x9eef, 0x9f19, 0x9f5c, 0x9f66, 0x9f67, 0x513c, 0x513b, 0x56c8, 0x56ca, 0x56c9,
+    0x5b7f, 0x5dd4, 0x5dd2, 0x5f4e, 0x61ff, 0x6524, 0x6b0a, 0x6b61, 0x7051, 0x7058, 0x7380, 0x74e4,
+    0x758a, 0x766e, 0x766c, 0x79b3, 0x7c60, 0x7c5f, 0x807e, 0x807d, 0x81df, 0x8972, 0x896f, 0x89fc,
+    0x8b80, 0x8d16, 0x8d17, 0x8e91, 0x8e93, 0x8f61, 0x9148, 0x9444, 0x9451, 0x9452, 0x973d, 0x973e,
+    0x97c3, 0x97c1, 0x986b, 0x9955, 0x9a55, 0x9a4d, 0x9ad2, 0x9b1a, 0x9c49, 0x9c31, 0x9c3e, 0x9c3b,
+    0x9dd3, 0x9dd7, 0x9f34, 0x9f6c, 0x9f6a, 0x9f94, 0x56cc, 0x5dd6, 0x6200, 0x6523, 0x652b, 0x652a,
+    0x66ec, 0x6b10, 0x74da, 0x7aca, 0x7c64, 0x7c63, 0x7c65, 0x7e93, 0x7e96, 0x7e94, 0x81e2, 0x8638,
+    0x863f, 0x8831, 0x8b8a, 0x9090, 0x908f, 0x9463, 0x9460, 0x9464, 0x9768, 0x986f, 0x995c, 0x9a5a,
+    0x9a5b, 0x9a57, 0x9ad3, 0x9ad4, 0x9ad1, 0x9c54, 0x9c57, 0x9c56, 0x9de5, 0x9e9f, 0x9ef4, 0x56d1,
+    0x58e9, 0x652c, 0x705e, 0x7671, 0x7672, 0x77d7, 0x7f50, 0x7f88, 0x8836, 0x8839, 0x8862, 0x8b93,
+    0x8b92, 0x8b96, 0x8277, 0x8d1b, 0x91c0, 0x946a, 0x9742, 0x9748, 0x9744, 0x97c6, 0x9870, 0x9a5f,
+    0x9b22, 0x9b58, 0x9c5f, 0x9df9, 0x9dfa, 0x9e7c, 0x9e7d, 0x9f07, 0x9f77, 0x9f72, 0x5ef3, 0x6b16,
+    0x7063, 0x7c6c, 0x7c6e, 0x883b, 0x89c0, 0x8ea1, 0x91c1, 0x9472, 0x9470, 0x9871, 0x995e, 0x9ad6,
+    0x9b23, 0x9ecc, 0x7064, 0x77da, 0x8b9a, 0x9477, 0x97c9, 0x9a62, 0x9a65, 0x7e9c, 0x8b9c, 0x8eaa,
+    0x91c5, 0x947d, 0x947e, 0x947c, 0x9c77, 0x9c78, 0x9ef7, 0x8c54, 0x947f, 0x9e1a, 0x7228, 0x9a6a,
+    0x9b31, 0x9e1b, 0x9e1e, 0x7c72, 0x30fe, 0x309d, 0x309e, 0x3005, 0x3041, 0x3042, 0x3043, 0x3044,
+    0x3045, 0x3046, 0x3047, 0x3048, 0x3049, 0x304a, 0x304b, 0x304c, 0x304d, 0x304e, 0x304f, 0x3050,
+    0x3051, 0x3052, 0x3053, 0x3054, 0x3055, 0x3056, 0x3057, 0x3058, 0x3059, 0x305a, 0x305b, 0x305c,
+    0x305d, 0x305e, 0x305f, 0x3060, 0x3061, 0x3062, 0x3063, 0x3064, 0x3065, 0x3066, 0x3067, 0x3068,
+    0x3069, 0x306a, 0x306b, 0x306c, 0x306d, 0x306e, 0x306f, 0x3070, 0x3071, 0x3072, 0x3073, 0x3074,
+    0x3075, 0x3076, 0x3077, 0x3078, 0x3079, 0x307a, 0x307b, 0x307c, 0x307d, 0x307e, 0x307f, 0x3080,
+    0x3081, 0x3082, 0x3083, 0x3084, 0x3085, 0x3086, 0x3087, 0x3088, 0x3089, 0x308a, 0x308b, 0x308c,
+    0x308d, 0x308e, 0x308f, 0x3090, 0x3091, 0x3092, 0x3093, 0x30a1, 0x30a2, 0x30a3, 0x30a4, 0x30a5,
+    0x30a6, 0x30a7, 0x30a8, 0x30a9, 0x30aa, 0x30ab, 0x30ac, 0x30ad, 0x30ae, 0x30af, 0x30b0, 0x30b1,
+    0x30b2, 0x30b3, 0x30b4, 0x30b5, 0x30b6, 0x30b7, 0x30b8, 0x30b9, 0x30ba, 0x30bb, 0x30bc, 0x30bd,
+    0x30be, 0x30bf, 0x30c0, 0x30c1, 0x30c2, 0x30c3, 0x30c4, 0x30c5, 0x30c6, 0x30c7, 0x30c8, 0x30c9,
+    0x30ca, 0x30cb, 0x30cc, 0x30cd, 0x30ce, 0x30cf, 0x30d0, 0x30d1, 0x30d2, 0x30d3, 0x30d4, 0x30d5,
+    0x30d6, 0x30d7, 0x30d8, 0x30d9, 0x30da, 0x30db, 0x30dc, 0x30dd, 0x30de, 0x30df, 0x30e0, 0x30e1,
+    0x30e2, 0x30e3, 0x30e4, 0x30e5, 0x30e6, 0x30e7, 0x30e8, 0x30e9, 0x30ea, 0x30eb, 0x30ec, 0x30ed,
+    0x30ee, 0x30ef, 0x30f0, 0x30f1, 0x30f2, 0x30f3, 0x30f4, 0x30f5, 0x30f6, 0x0414, 0x0415, 0x0401,
+    0x0416, 0x0417, 0x0418, 0x0419, 0x041a, 0x041b, 0x041c, 0x0423, 0x0424, 0x0425, 0x0426, 0x0427,
+    0x0428, 0x0429, 0x042a, 0x042b, 0x042c, 0x042d, 0x042e, 0x042f, 0x0430, 0x0431, 0x0432, 0x0433,
+    0x0434, 0x0435, 0x0451, 0x0436, 0x0437, 0x0438, 0x0439, 0x043a, 0x043b, 0x043c, 0x043d, 0x043e,
+    0x043f, 0x0440, 0x0441, 0x0442, 0x0443, 0x0444, 0x0445, 0x0446, 0x0447, 0x0448, 0x0449, 0x044a,
+    0x044b, 0x044c, 0x044d, 0x044e, 0x044f, 0x2460, 0x2461, 0x2462, 0x2463, 0x2464, 0x2465, 0x2466,
+    0x2467, 0x2468, 0x2469, 0x2474, 0x2475, 0x2476, 0x2477, 0x2478, 0x2479, 0x247a, 0x247b, 0x247c,
+    0x247d, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x4e42, 0x4e5c, 0x51f5, 0x531a, 0x5382, 0x4e07, 0x4e0c, 0x4e47,
+    0x4e8d, 0x56d7, 0xfa0c, 0x5c6e, 0x5f73, 0x4e0f, 0x5187, 0x4e0e, 0x4e2e, 0x4e93, 0x4ec2, 0x4ec9,
+    0x4ec8, 0x5198, 0x52fc, 0x536c, 0x53b9, 0x5720, 0x5903, 0x592c, 0x5c10, 0x5dff, 0x65e1, 0x6bb3,
+    0x6bcc, 0x6c14, 0x723f, 0x4e31, 0x4e3c, 0x4ee8, 0x4edc, 0x4ee9, 0x4ee1, 0x4edd, 0x4eda, 0x520c,
+    0x531c, 0x534c, 0x5722, 0x5723, 0x5917, 0x592f, 0x5b81, 0x5b84, 0x5c12, 0x5c3b, 0x5c74, 0x5c73,
+    0x5e04, 0x5e80, 0x5e82, 0x5fc9, 0x6209, 0x6250, 0x6c15, 0x6c36, 0x6c43, 0x6c3f, 0x6c3b, 0x72ae,
+    0x72b0, 0x738a, 0x79b8, 0x808a, 0x961e, 0x4f0e, 0x4f18, 0x4f2c, 0x4ef5, 0x4f14, 0x4ef1, 0x4f00,
+    0x4ef7, 0x4f08, 0x4f1d, 0x4f02, 0x4f05, 0x4f22, 0x4f13, 0x4f04, 0x4ef4, 0x4f12, 0x51b1, 0x5213,
+    0x5209, 0x5210, 0x52a6, 0x5322, 0x531f, 0x534d, 0x538a, 0x5407, 0x56e1, 0x56df, 0x572e, 0x572a,
+    0x5734, 0x593c, 0x5980, 0x597c, 0x5985, 0x597b, 0x597e, 0x5977, 0x597f, 0x5b56, 0x5c15, 0x5c25,
+    0x5c7c, 0x5c7a, 0x5c7b, 0x5c7e, 0x5ddf, 0x5e75, 0x5e84, 0x5f02, 0x5f1a, 0x5f74, 0x5fd5, 0x5fd4,
+    0x5fcf, 0x625c, 0x625e, 0x6264, 0x6261, 0x6266, 0x6262, 0x6259, 0x6260, 0x625a, 0x6265, 0x65ef,
+    0x65ee, 0x673e, 0x6739, 0x6738, 0x673b, 0x673a, 0x673f, 0x673c, 0x6733, 0x6c18, 0x6c46, 0x6c52,
+    0x6c5c, 0x6c4f, 0x6c4a, 0x6c54, 0x6c4b, 0x6c4c, 0x7071, 0x725e, 0x72b4, 0x72b5, 0x738e, 0x752a,
+    0x767f, 0x7a75, 0x7f51, 0x8278, 0x827c, 0x8280, 0x827d, 0x827f, 0x864d, 0x897e, 0x9099, 0x9097,
+    0x9098, 0x909b, 0x9094, 0x9622, 0x9624, 0x9620, 0x9623, 0x4f56, 0x4f3b, 0x4f62, 0x4f49, 0x4f53,
+    0x4f64, 0x4f3e, 0x4f67, 0x4f52, 0x4f5f, 0x4f41, 0x4f58, 0x4f2d, 0x4f33, 0x4f3f, 0x4f61, 0x518f,
+    0x51b9, 0x521c, 0x521e, 0x5221, 0x52ad, 0x52ae, 0x5309, 0x5363, 0x5372, 0x538e, 0x538f, 0x5430,
+    0x5437, 0x542a, 0x5454, 0x5445, 0x5419, 0x541c, 0x5425, 0x5418, 0x543d, 0x544f, 0x5441, 0x5428,
+    0x5424, 0x5447, 0x56ee, 0x56e7, 0x56e5, 0x5741, 0x5745, 0x574c, 0x5749, 0x574b, 0x5752, 0x5906,
+    0x5940, 0x59a6, 0x5998, 0x59a0, 0x5997, 0x598e, 0x59a2, 0x5990, 0x598f, 0x59a7, 0x59a1, 0x5b8e,
+    0x5b92, 0x5c28, 0x5c2a, 0x5c8d, 0x5c8f, 0x5c88, 0x5c8b, 0x5c89, 0x5c92, 0x5c8a, 0x5c86, 0x5c93,
+    0x5c95, 0x5de0, 0x5e0a, 0x5e0e, 0x5e8b, 0x5e89, 0x5e8c, 0x5e88, 0x5e8d, 0x5f05, 0x5f1d, 0x5f78,
+    0x5f76, 0x5fd2, 0x5fd1, 0x5fd0, 0x5fed, 0x5fe8, 0x5fee, 0x5ff3, 0x5fe1, 0x5fe4, 0x5fe3, 0x5ffa,
+    0x5fef, 0x5ff7, 0x5ffb, 0x6000, 0x5ff4, 0x623a, 0x6283, 0x628c, 0x628e, 0x628f, 0x6294, 0x6287,
+    0x6271, 0x627b, 0x627a, 0x6270, 0x6281, 0x6288, 0x6277, 0x627d, 0x6272, 0x6274, 0x6537, 0x65f0,
+    0x65f4, 0x65f3, 0x65f2, 0x65f5, 0x6745, 0x6747, 0x6759, 0x6755, 0x674c, 0x6748, 0x675d, 0x674d,
+    0x675a, 0x674b, 0x6bd0, 0x6c19, 0x6c1a, 0x6c78, 0x6c67, 0x6c6b, 0x6c84, 0x6c8b, 0x6c8f, 0x6c71,
+    0x6c6f, 0x6c69, 0x6c9a, 0x6c6d, 0x6c87, 0x6c95, 0x6c9c, 0x6c66, 0x6c73, 0x6c65, 0x6c7b, 0x6c8e,
+    0x7074, 0x707a, 0x7263, 0x72bf, 0x72bd, 0x72c3, 0x72c6, 0x72c1, 0x72ba, 0x72c5, 0x7395, 0x7397,
+    0x7393, 0x7394, 0x7392, 0x753a, 0x7539, 0x7594, 0x7595, 0x7681, 0x793d, 0x8034, 0x8095, 0x8099,
+    0x8090, 0x8092, 0x809c, 0x8290, 0x828f, 0x8285, 0x828e, 0x8291, 0x8293, 0x828a, 0x8283, 0x8284,
+    0x8c78, 0x8fc9, 0x8fbf, 0x909f, 0x90a1, 0x90a5, 0x909e, 0x90a7, 0x90a0, 0x9630, 0x9628, 0x962f,
+    0x962d, 0x4e33, 0x4f98, 0x4f7c, 0x4f85, 0x4f7d, 0x4f80, 0x4f87, 0x4f76, 0x4f74, 0x4f89, 0x4f84,
+    0x4f77, 0x4f4c, 0x4f97, 0x4f6a, 0x4f9a, 0x4f79, 0x4f81, 0x4f78, 0x4f90, 0x4f9c, 0x4f94, 0x4f9e,
+    0x4f92, 0x4f82, 0x4f95, 0x4f6b, 0x4f6e, 0x519e, 0x51bc, 0x51be, 0x5235, 0x5232, 0x5233, 0x5246,
+    0x5231, 0x52bc, 0x530a, 0x530b, 0x533c, 0x5392, 0x5394, 0x5487, 0x547f, 0x5481, 0x5491, 0x5482,
+    0x5488, 0x546b, 0x547a, 0x547e, 0x5465, 0x546c, 0x5474, 0x5466, 0x548d, 0x546f, 0x5461, 0x5460,
+    0x5498, 0x5463, 0x5467, 0x5464, 0x56f7, 0x56f9, 0x576f, 0x5772, 0x576d, 0x576b, 0x5771, 0x5770,
+    0x5776, 0x5780, 0x5775, 0x577b, 0x5773, 0x5774, 0x5762, 0x5768, 0x577d, 0x590c, 0x5945, 0x59b5,
+    0x59ba, 0x59cf, 0x59ce, 0x59b2, 0x59cc, 0x59c1, 0x59b6, 0x59bc, 0x59c3, 0x59d6, 0x59b1, 0x59bd,
+    0x59c0, 0x59c8, 0x59b4, 0x59c7, 0x5b62, 0x5b65, 0x5b93, 0x5b95, 0x5c44, 0x5c47, 0x5cae, 0x5ca4,
+    0x5ca0, 0x5cb5, 0x5caf, 0x5ca8, 0x5cac, 0x5c9f, 0x5ca3, 0x5cad, 0x5ca2, 0x5caa, 0x5ca7, 0x5c9d,
+    0x5ca5, 0x5cb6, 0x5cb0, 0x5ca6, 0x5e17, 0x5e14, 0x5e19, 0x5f28, 0x5f22, 0x5f23, 0x5f24, 0x5f54,
+    0x5f82, 0x5f7e, 0x5f7d, 0x5fde, 0x5fe5, 0x602d, 0x6026, 0x6019, 0x6032, 0x600b, 0x6034, 0x600a,
+    0x6017, 0x6033, 0x601a, 0x601e, 0x602c, 0x6022, 0x600d, 0x6010, 0x602e, 0x6013, 0x6011, 0x600c,
+    0x6009, 0x601c, 0x6214, 0x623d, 0x62ad, 0x62b4, 0x62d1, 0x62be, 0x62aa, 0x62b6, 0x62ca, 0x62ae,
+    0x62b3, 0x62af, 0x62bb, 0x62a9, 0x62b0, 0x62b8, 0x653d, 0x65a8, 0x65bb, 0x6609, 0x65fc, 0x6604,
+    0x6612, 0x6608, 0x65fb, 0x6603, 0x660b, 0x660d, 0x6605, 0x65fd, 0x6611, 0x6610, 0x66f6, 0x670a,
+    0x6785, 0x676c, 0x678e, 0x6792, 0x6776, 0x677b, 0x6798, 0x6786, 0x6784, 0x6774, 0x678d, 0x678c,
+    0x677a, 0x679f, 0x6791, 0x6799, 0x6783, 0x677d, 0x6781, 0x6778, 0x6779, 0x6794, 0x6b25, 0x6b80,
+    0x6b7e, 0x6bde, 0x6c1d, 0x6c93, 0x6cec, 0x6ceb, 0x6cee, 0x6cd9, 0x6cb6, 0x6cd4, 0x6cad, 0x6ce7,
+    0x6cb7, 0x6cd0, 0x6cc2, 0x6cba, 0x6cc3, 0x6cc6, 0x6ced, 0x6cf2, 0x6cd2, 0x6cdd, 0x6cb4, 0x6c8a,
+    0x6c9d, 0x6c80, 0x6cde, 0x6cc0, 0x6d30, 0x6ccd, 0x6cc7, 0x6cb0, 0x6cf9, 0x6ccf, 0x6ce9, 0x6cd1,
+    0x7094, 0x7098, 0x7085, 0x7093, 0x7086, 0x7084, 0x7091, 0x7096, 0x7082, 0x709a, 0x7083, 0x726a,
+    0x72d6, 0x72cb, 0x72d8, 0x72c9, 0x72dc, 0x72d2, 0x72d4, 0x72da, 0x72cc, 0x72d1, 0x73a4, 0x73a1,
+    0x73ad, 0x73a6, 0x73a2, 0x73a0, 0x73ac, 0x739d, 0x74dd, 0x74e8, 0x753f, 0x7540, 0x753e, 0x758c,
+    0x7598, 0x76af, 0x76f3, 0x76f1, 0x76f0, 0x76f5, 0x77f8, 0x77fc, 0x77f9, 0x77fb, 0x77fa, 0x77f7,
+    0x7942, 0x793f, 0x79c5, 0x7a78, 0x7a7b, 0x7afb, 0x7c75, 0x7cfd, 0x8035, 0x808f, 0x80ae, 0x80a3,
+    0x80b8, 0x80b5, 0x80ad, 0x8220, 0x82a0, 0x82c0, 0x82ab, 0x829a, 0x8298, 0x829b, 0x82b5, 0x82a7,
+    0x82ae, 0x82bc, 0x829e, 0x82ba, 0x82b4, 0x82a8, 0x82a1, 0x82a9, 0x82c2, 0x82a4, 0x82c3, 0x82b6,
+    0x82a2, 0x8670, 0x866f, 0x866d, 0x866e, 0x8c56, 0x8fd2, 0x8fcb, 0x8fd3, 0x8fcd, 0x8fd6, 0x8fd5,
+    0x8fd7, 0x90b2, 0x90b4, 0x90af, 0x90b3, 0x90b0, 0x9639, 0x963d, 0x963c, 0x963a, 0x9643, 0x4fcd,
+    0x4fc5, 0x4fd3, 0x4fb2, 0x4fc9, 0x4fcb, 0x4fc1, 0x4fd4, 0x4fdc, 0x4fd9, 0x4fbb, 0x4fb3, 0x4fdb,
+    0x4fc7, 0x4fd6, 0x4fba, 0x4fc0, 0x4fb9, 0x4fec, 0x5244, 0x5249, 0x52c0, 0x52c2, 0x533d, 0x537c,
+    0x5397, 0x5396, 0x5399, 0x5398, 0x54ba, 0x54a1, 0x54ad, 0x54a5, 0x54cf, 0x54c3, 0x830d, 0x54b7,
+    0x54ae, 0x54d6, 0x54b6, 0x54c5, 0x54c6, 0x54a0, 0x5470, 0x54bc, 0x54a2, 0x54be, 0x5472, 0x54de,
+    0x54b0, 0x57b5, 0x579e, 0x579f, 0x57a4, 0x578c, 0x5797, 0x579d, 0x579b, 0x5794, 0x5798, 0x578f,
+    0x5799, 0x57a5, 0x579a, 0x5795, 0x58f4, 0x590d, 0x5953, 0x59e1, 0x59de, 0x59ee, 0x5a00, 0x59f1,
+    0x59dd, 0x59fa, 0x59fd, 0x59fc, 0x59f6, 0x59e4, 0x59f2, 0x59f7, 0x59db, 0x59e9, 0x59f3, 0x59f5,
+    0x59e0, 0x59fe, 0x59f4, 0x59ed, 0x5ba8, 0x5c4c, 0x5cd0, 0x5cd8, 0x5ccc, 0x5cd7, 0x5ccb, 0x5cdb,
+    0x5cde, 0x5cda, 0x5cc9, 0x5cc7, 0x5cca, 0x5cd6, 0x5cd3, 0x5cd4, 0x5ccf, 0x5cc8, 0x5cc6, 0x5cce,
+    0x5cdf, 0x5cf8, 0x5df9, 0x5e21, 0x5e22, 0x5e23, 0x5e20, 0x5e24, 0x5eb0, 0x5ea4, 0x5ea2, 0x5e9b,
+    0x5ea3, 0x5ea5, 0x5f07, 0x5f2e, 0x5f56, 0x5f86, 0x6037, 0x6039, 0x6054, 0x6072, 0x605e, 0x6045,
+    0x6053, 0x6047, 0x6049, 0x605b, 0x604c, 0x6040, 0x6042, 0x605f, 0x6024, 0x6044, 0x6058, 0x6066,
+    0x606e, 0x6242, 0x6243, 0x62cf, 0x630d, 0x630b, 0x62f5, 0x630e, 0x6303, 0x62eb, 0x62f9, 0x630f,
+    0x630c, 0x62f8, 0x62f6, 0x6300, 0x6313, 0x6314, 0x62fa, 0x6315, 0x62fb, 0x62f0, 0x6541, 0x6543,
+    0x65aa, 0x65bf, 0x6636, 0x6621, 0x6632, 0x6635, 0x661c, 0x6626, 0x6622, 0x6633, 0x662b, 0x663a,
+    0x661d, 0x6634, 0x6639, 0x662e, 0x670f, 0x6710, 0x67c1, 0x67f2, 0x67c8, 0x67ba, 0x67dc, 0x67bb,
+    0x67f8, 0x67d8, 0x67c0, 0x67b7, 0x67c5, 0x67eb, 0x67e4, 0x67df, 0x67b5, 0x67cd, 0x67b3, 0x67f7,
+    0x67f6, 0x67ee, 0x67e3, 0x67c2, 0x67b9, 0x67ce, 0x67e7, 0x67f0, 0x67b2, 0x67fc, 0x67c6, 0x67ed,
+    0x67cc, 0x67ae, 0x67e6, 0x67db, 0x67fa, 0x67c9, 0x67ca, 0x67c3, 0x67ea, 0x67cb, 0x6b28, 0x6b82,
+    0x6b84, 0x6bb6, 0x6bd6, 0x6bd8, 0x6be0, 0x6c20, 0x6c21, 0x6d28, 0x6d34, 0x6d2d, 0x6d1f, 0x6d3c,
+    0x6d3f, 0x6d12, 0x6d0a, 0x6cda, 0x6d33, 0x6d04, 0x6d19, 0x6d3a, 0x6d1a, 0x6d11, 0x6d00, 0x6d1d,
+    0x6d42, 0x6d01, 0x6d18, 0x6d37, 0x6d03, 0x6d0f, 0x6d40, 0x6d07, 0x6d20, 0x6d2c, 0x6d08, 0x6d22,
+    0x6d09, 0x6d10, 0x70b7, 0x709f, 0x70be, 0x70b1, 0x70b0, 0x70a1, 0x70b4, 0x70b5, 0x70a9, 0x7241,
+    0x7249, 0x724a, 0x726c, 0x7270, 0x7273, 0x726e, 0x72ca, 0x72e4, 0x72e8, 0x72eb, 0x72df, 0x72ea,
+    0x72e6, 0x72e3, 0x7385, 0x73cc, 0x73c2, 0x73c8, 0x73c5, 0x73b9, 0x73b6, 0x73b5, 0x73b4, 0x73eb,
+    0x73bf, 0x73c7, 0x73be, 0x73c3, 0x73c6, 0x73b8, 0x73cb, 0x74ec, 0x74ee, 0x752e, 0x7547, 0x7548,
+    0x75a7, 0x75aa, 0x7679, 0x76c4, 0x7708, 0x7703, 0x7704, 0x7705, 0x770a, 0x76f7, 0x76fb, 0x76fa,
+    0x77e7, 0x77e8, 0x7806, 0x7811, 0x7812, 0x7805, 0x7810, 0x780f, 0x780e, 0x7809, 0x7803, 0x7813,
+    0x794a, 0x794c, 0x794b, 0x7945, 0x7944, 0x79d5, 0x79cd, 0x79cf, 0x79d6, 0x79ce, 0x7a80, 0x7a7e,
+    0x7ad1, 0x7b00, 0x7b01, 0x7c7a, 0x7c78, 0x7c79, 0x7c7f, 0x7c80, 0x7c81, 0x7d03, 0x7d08, 0x7d01,
+    0x7f58, 0x7f91, 0x7f8d, 0x7fbe, 0x8007, 0x800e, 0x800f, 0x8014, 0x8037, 0x80d8, 0x80c7, 0x80e0,
+    0x80d1, 0x80c8, 0x80c2, 0x80d0, 0x80c5, 0x80e3, 0x80d9, 0x80dc, 0x80ca, 0x80d5, 0x80c9, 0x80cf,
+    0x80d7, 0x80e6, 0x80cd, 0x81ff, 0x8221, 0x8294, 0x82d9, 0x82fe, 0x82f9, 0x8307, 0x82e8, 0x8300,
+    0x82d5, 0x833a, 0x82eb, 0x82d6, 0x82f4, 0x82ec, 0x82e1, 0x82f2, 0x82f5, 0x830c, 0x82fb, 0x82f6,
+    0x82f0, 0x82ea, 0x82e4, 0x82e0, 0x82fa, 0x82f3, 0x82ed, 0x8677, 0x8674, 0x867c, 0x8673, 0x8841,
+    0x884e, 0x8867, 0x886a, 0x8869, 0x89d3, 0x8a04, 0x8a07, 0x8d72, 0x8fe3, 0x8fe1, 0x8fee, 0x8fe0,
+    0x90f1, 0x90bd, 0x90bf, 0x90d5, 0x90c5, 0x90be, 0x90c7, 0x90cb, 0x90c8, 0x91d4, 0x91d3, 0x9654,
+    0x964f, 0x9651, 0x9653, 0x964a, 0x964e, 0x501e, 0x5005, 0x5007, 0x5013, 0x5022, 0x5030, 0x501b,
+    0x4ff5, 0x4ff4, 0x5033, 0x5037, 0x502c, 0x4ff6, 0x4ff7, 0x5017, 0x501c, 0x5020, 0x5027, 0x5035,
+    0x502f, 0x5031, 0x500e, 0x515a, 0x5194, 0x5193, 0x51ca, 0x51c4, 0x51c5, 0x51c8, 0x51ce, 0x5261,
+    0x525a, 0x5252, 0x525e, 0x525f, 0x5255, 0x5262, 0x52cd, 0x530e, 0x539e, 0x5526, 0x54e2, 0x5517,
+    0x5512, 0x54e7, 0x54f3, 0x54e4, 0x551a, 0x54ff, 0x5504, 0x5508, 0x54eb, 0x5511, 0x5505, 0x54f1,
+    0x550a, 0x54fb, 0x54f7, 0x54f8, 0x54e0, 0x550e, 0x5503, 0x550b, 0x5701, 0x5702, 0x57cc, 0x5832,
+    0x57d5, 0x57d2, 0x57ba, 0x57c6, 0x57bd, 0x57bc, 0x57b8, 0x57b6, 0x57bf, 0x57c7, 0x57d0, 0x57b9,
+    0x57c1, 0x590e, 0x594a, 0x5a19, 0x5a16, 0x5a2d, 0x5a2e, 0x5a15, 0x5a0f, 0x5a17, 0x5a0a, 0x5a1e,
+    0x5a33, 0x5b6c, 0x5ba7, 0x5bad, 0x5bac, 0x5c03, 0x5c56, 0x5c54, 0x5cec, 0x5cff, 0x5cee, 0x5cf1,
+    0x5cf7, 0x5d00, 0x5cf9, 0x5e29, 0x5e28, 0x5ea8, 0x5eae, 0x5eaa, 0x5eac, 0x5f33, 0x5f30, 0x5f67,
+    0x605d, 0x605a, 0x6067, 0x6041, 0x60a2, 0x6088, 0x6080, 0x6092, 0x6081, 0x609d, 0x6083, 0x6095,
+    0x609b, 0x6097, 0x6087, 0x609c, 0x608e, 0x6219, 0x6246, 0x62f2, 0x6310, 0x6356, 0x632c, 0x6344,
+    0x6345, 0x6336, 0x6343, 0x63e4, 0x6339, 0x634b, 0x634a, 0x633c, 0x6329, 0x6341, 0x6334, 0x6358,
+    0x6354, 0x6359, 0x632d, 0x6347, 0x6333, 0x635a, 0x6351, 0x6338, 0x6357, 0x6340, 0x6348, 0x654a,
+    0x6546, 0x65c6, 0x65c3, 0x65c4, 0x65c2, 0x664a, 0x665f, 0x6647, 0x6651, 0x6712, 0x6713, 0x681f,
+    0x681a, 0x6849, 0x6832, 0x6833, 0x683b, 0x684b, 0x684f, 0x6816, 0x6831, 0x681c, 0x6835, 0x682b,
+    0x682d, 0x682f, 0x684e, 0x6844, 0x6834, 0x681d, 0x6812, 0x6814, 0x6826, 0x6828, 0x682e, 0x684d,
+    0x683a, 0x6825, 0x6820, 0x6b2c, 0x6b2f, 0x6b2d, 0x6b31, 0x6b34, 0x6b6d, 0x8082, 0x6b88, 0x6be6,
+    0x6be4, 0x6be8, 0x6be3, 0x6be2, 0x6be7, 0x6c25, 0x6d7a, 0x6d63, 0x6d64, 0x6d76, 0x6d0d, 0x6d61,
+    0x6d92, 0x6d58, 0x6d62, 0x6d6d, 0x6d6f, 0x6d91, 0x6d8d, 0x6def, 0x6d7f, 0x6d86, 0x6d5e, 0x6d67,
+    0x6d60, 0x6d97, 0x6d70, 0x6d7c, 0x6d5f, 0x6d82, 0x6d98, 0x6d2f, 0x6d68, 0x6d8b, 0x6d7e, 0x6d80,
+    0x6d84, 0x6d16, 0x6d83, 0x6d7b, 0x6d7d, 0x6d75, 0x6d90, 0x70dc, 0x70d3, 0x70d1, 0x70dd, 0x70cb,
+    0x7f39, 0x70e2, 0x70d7, 0x70d2, 0x70de, 0x70e0, 0x70d4, 0x70cd, 0x70c5, 0x70c6, 0x70c7, 0x70da,
+    0x70ce, 0x70e1, 0x7242, 0x7278, 0x7277, 0x7276, 0x7300, 0x72fa, 0x72f4, 0x72fe, 0x72f6, 0x72f3,
+    0x72fb, 0x7301, 0x73d3, 0x73d9, 0x73e5, 0x73d6, 0x73bc, 0x73e7, 0x73e3, 0x73e9, 0x73dc, 0x73d2,
+    0x73db, 0x73d4, 0x73dd, 0x73da, 0x73d7, 0x73d8, 0x73e8, 0x74de, 0x74df, 0x74f4, 0x74f5, 0x7521,
+    0x755b, 0x755f, 0x75b0, 0x75c1, 0x75bb, 0x75c4, 0x75c0, 0x75bf, 0x75b6, 0x75ba, 0x768a, 0x76c9,
+    0x771d, 0x771b, 0x7710, 0x7713, 0x7712, 0x7723, 0x7711, 0x7715, 0x7719, 0x771a, 0x7722, 0x7727,
+    0x7823, 0x782c, 0x7822, 0x7835, 0x782f, 0x7828, 0x782e, 0x782b, 0x7821, 0x7829, 0x7833, 0x782a,
+    0x7831, 0x7954, 0x795b, 0x794f, 0x795c, 0x7953, 0x7952, 0x7951, 0x79eb, 0x79ec, 0x79e0, 0x79ee,
+    0x79ed, 0x79ea, 0x79dc, 0x79de, 0x79dd, 0x7a86, 0x7a89, 0x7a85, 0x7a8b, 0x7a8c, 0x7a8a, 0x7a87,
+    0x7ad8, 0x7b10, 0x7b04, 0x7b13, 0x7b05, 0x7b0f, 0x7b08, 0x7b0a, 0x7b0e, 0x7b09, 0x7b12, 0x7c84,
+    0x7c91, 0x7c8a, 0x7c8c, 0x7c88, 0x7c8d, 0x7c85, 0x7d1e, 0x7d1d, 0x7d11, 0x7d0e, 0x7d18, 0x7d16,
+    0x7d13, 0x7d1f, 0x7d12, 0x7d0f, 0x7d0c, 0x7f5c, 0x7f61, 0x7f5e, 0x7f60, 0x7f5d, 0x7f5b, 0x7f96,
+    0x7f92, 0x7fc3, 0x7fc2, 0x7fc0, 0x8016, 0x803e, 0x8039, 0x80fa, 0x80f2, 0x80f9, 0x80f5, 0x8101,
+    0x80fb, 0x8100, 0x8201, 0x822f, 0x8225, 0x8333, 0x832d, 0x8344, 0x8319, 0x8351, 0x8325, 0x8356,
+    0x833f, 0x8341, 0x8326, 0x831c, 0x8322, 0x8342, 0x834e, 0x831b, 0x832a, 0x8308, 0x833c, 0x834d,
+    0x8316, 0x8324, 0x8320, 0x8337, 0x832f, 0x8329, 0x8347, 0x8345, 0x834c, 0x8353, 0x831e, 0x832c,
+    0x834b, 0x8327, 0x8348, 0x8653, 0x8652, 0x86a2, 0x86a8, 0x8696, 0x868d, 0x8691, 0x869e, 0x8687,
+    0x8697, 0x8686, 0x868b, 0x869a, 0x8685, 0x86a5, 0x8699, 0x86a1, 0x86a7, 0x8695, 0x8698, 0x868e,
+    0x869d, 0x8690, 0x8694, 0x8843, 0x8844, 0x886d, 0x8875, 0x8876, 0x8872, 0x8880, 0x8871, 0x887f,
+    0x886f, 0x8883, 0x887e, 0x8874, 0x887c, 0x8a12, 0x8c47, 0x8c57, 0x8c7b, 0x8ca4, 0x8ca3, 0x8d76,
+    0x8d78, 0x8db5, 0x8db7, 0x8db6, 0x8ed1, 0x8ed3, 0x8ffe, 0x8ff5, 0x9002, 0x8fff, 0x8ffb, 0x9004,
+    0x8ffc, 0x8ff6, 0x90d6, 0x90e0, 0x90d9, 0x90da, 0x90e3, 0x90df, 0x90e5, 0x90d8, 0x90db, 0x90d7,
+    0x90dc, 0x90e4, 0x9150, 0x914e, 0x914f, 0x91d5, 0x91e2, 0x91da, 0x965c, 0x965f, 0x96bc, 0x98e3,
+    0x9adf, 0x9b2f, 0x4e7f, 0x5070, 0x506a, 0x5061, 0x505e, 0x5060, 0x5053, 0x504b, 0x505d, 0x5072,
+    0x5048, 0x504d, 0x5041, 0x505b, 0x504a, 0x5062, 0x5015, 0x5045, 0x505f, 0x5069, 0x506b, 0x5063,
+    0x5064, 0x5046, 0x5040, 0x506e, 0x5073, 0x5057, 0x5051, 0x51d0, 0x526b, 0x526d, 0x526c, 0x526e,
+    0x52d6, 0x52d3, 0x532d, 0x539c, 0x5575, 0x5576, 0x553c, 0x554d, 0x5550, 0x5534, 0x552a, 0x5551,
+    0x5562, 0x5536, 0x5535, 0x5530, 0x5552, 0x5545, 0x550c, 0x5532, 0x5565, 0x554e, 0x5539, 0x5548,
+    0x552d, 0x553b, 0x5540, 0x554b, 0x570a, 0x5707, 0x57fb, 0x5814, 0x57e2, 0x57f6, 0x57dc, 0x57f4,
+    0x5800, 0x57ed, 0x57fd, 0x5808, 0x57f8, 0x580b, 0x57f3, 0x57cf, 0x5807, 0x57ee, 0x57e3, 0x57f2,
+    0x57e5, 0x57ec, 0x57e1, 0x580e, 0x57fc, 0x5810, 0x57e7, 0x5801, 0x580c, 0x57f1, 0x57e9, 0x57f0,
+    0x580d, 0x5804, 0x595c, 0x5a60, 0x5a58, 0x5a55, 0x5a67, 0x5a5e, 0x5a38, 0x5a35, 0x5a6d, 0x5a50,
+    0x5a5f, 0x5a65, 0x5a6c, 0x5a53, 0x5a64, 0x5a57, 0x5a43, 0x5a5d, 0x5a52, 0x5a44, 0x5a5b, 0x5a48,
+    0x5a8e, 0x5a3e, 0x5a4d, 0x5a39, 0x5a4c, 0x5a70, 0x5a69, 0x5a47, 0x5a51, 0x5a56, 0x5a42, 0x5a5c,
+    0x5b72, 0x5b6e, 0x5bc1, 0x5bc0, 0x5c59, 0x5d1e, 0x5d0b, 0x5d1d, 0x5d1a, 0x5d20, 0x5d0c, 0x5d28,
+    0x5d0d, 0x5d26, 0x5d25, 0x5d0f, 0x5d30, 0x5d12, 0x5d23, 0x5d1f, 0x5d2e, 0x5e3e, 0x5e34, 0x5eb1,
+    0x5eb4, 0x5eb9, 0x5eb2, 0x5eb3, 0x5f36, 0x5f38, 0x5f9b, 0x5f96, 0x5f9f, 0x608a, 0x6090, 0x6086,
+    0x60be, 0x60b0, 0x60ba, 0x60d3, 0x60d4, 0x60cf, 0x60e4, 0x60d9, 0x60dd, 0x60c8, 0x60b1, 0x60db,
+    0x60b7, 0x60ca, 0x60bf, 0x60c3, 0x60cd, 0x60c0, 0x6332, 0x6365, 0x638a, 0x6382, 0x637d, 0x63bd,
+    0x639e, 0x63ad, 0x639d, 0x6397, 0x63ab, 0x638e, 0x636f, 0x6387, 0x6390, 0x636e, 0x63af, 0x6375,
+    0x639c, 0x636d, 0x63ae, 0x637c, 0x63a4, 0x633b, 0x639f, 0x6378, 0x6385, 0x6381, 0x6391, 0x638d,
+    0x6370, 0x6553, 0x65cd, 0x6665, 0x6661, 0x665b, 0x6659, 0x665c, 0x6662, 0x6718, 0x6879, 0x6887,
+    0x6890, 0x689c, 0x686d, 0x686e, 0x68ae, 0x68ab, 0x6956, 0x686f, 0x68a3, 0x68ac, 0x68a9, 0x6875,
+    0x6874, 0x68b2, 0x688f, 0x6877, 0x6892, 0x687c, 0x686b, 0x6872, 0x68aa, 0x6880, 0x6871, 0x687e,
+    0x689b, 0x6896, 0x688b, 0x68a0, 0x6889, 0x68a4, 0x6878, 0x687b, 0x6891, 0x688c, 0x688a, 0x687d,
+    0x6b36, 0x6b33, 0x6b37, 0x6b38, 0x6b91, 0x6b8f, 0x6b8d, 0x6b8e, 0x6b8c, 0x6c2a, 0x6dc0, 0x6dab,
+    0x6db4, 0x6db3, 0x6e74, 0x6dac, 0x6de9, 0x6de2, 0x6db7, 0x6df6, 0x6dd4, 0x6e00, 0x6dc8, 0x6de0,
+    0x6ddf, 0x6dd6, 0x6dbe, 0x6de5, 0x6ddc, 0x6ddd, 0x6ddb, 0x6df4, 0x6dca, 0x6dbd, 0x6ded, 0x6df0,
+    0x6dba, 0x6dd5, 0x6dc2, 0x6dcf, 0x6dc9, 0x6dd0, 0x6df2, 0x6dd3, 0x6dfd, 0x6dd7, 0x6dcd, 0x6de3,
+    0x6dbb, 0x70fa, 0x710d, 0x70f7, 0x7117, 0x70f4, 0x710c, 0x70f0, 0x7104, 0x70f3, 0x7110, 0x70fc,
+    0x70ff, 0x7106, 0x7113, 0x7100, 0x70f8, 0x70f6, 0x710b, 0x7102, 0x710e, 0x727e, 0x727b, 0x727c,
+    0x727f, 0x731d, 0x7317, 0x7307, 0x7311, 0x7318, 0x730a, 0x7308, 0x72ff, 0x730f, 0x731e, 0x7388,
+    0x73f6, 0x73f8, 0x73f5, 0x7404, 0x7401, 0x73fd, 0x7407, 0x7400, 0x73fa, 0x73fc, 0x73ff, 0x740c,
+    0x740b, 0x73f4, 0x7408, 0x7564, 0x7563, 0x75ce, 0x75d2, 0x75cf, 0x75cb, 0x75cc, 0x75d1, 0x75d0,
+    0x768f, 0x7689, 0x76d3, 0x7739, 0x772f, 0x772d, 0x7731, 0x7732, 0x7734, 0x7733, 0x773d, 0x7725,
+    0x773b, 0x7735, 0x7848, 0x7852, 0x7849, 0x784d, 0x784a, 0x784c, 0x7826, 0x7845, 0x7850, 0x7964,
+    0x7967, 0x7969, 0x796a, 0x7963, 0x796b, 0x7961, 0x79bb, 0x79fa, 0x79f8, 0x79f6, 0x79f7, 0x7a8f,
+    0x7a94, 0x7a90, 0x7b35, 0x7b47, 0x7b34, 0x7b25, 0x7b30, 0x7b22, 0x7b24, 0x7b33, 0x7b18, 0x7b2a,
+    0x7b1d, 0x7b31, 0x7b2b, 0x7b2d, 0x7b2f, 0x7b32, 0x7b38, 0x7b1a, 0x7b23, 0x7c94, 0x7c98, 0x7c96,
+    0x7ca3, 0x7d35, 0x7d3d, 0x7d38, 0x7d36, 0x7d3a, 0x7d45, 0x7d2c, 0x7d29, 0x7d41, 0x7d47, 0x7d3e,
+    0x7d3f, 0x7d4a, 0x7d3b, 0x7d28, 0x7f63, 0x7f95, 0x7f9c, 0x7f9d, 0x7f9b, 0x7fca, 0x7fcb, 0x7fcd,
+    0x7fd0, 0x7fd1, 0x7fc7, 0x7fcf, 0x7fc9, 0x801f, 0x801e, 0x801b, 0x8047, 0x8043, 0x8048, 0x8118,
+    0x8125, 0x8119, 0x811b, 0x812d, 0x811f, 0x812c, 0x811e, 0x8121, 0x8115, 0x8127, 0x811d, 0x8122,
+    0x8211, 0x8238, 0x8233, 0x823a, 0x8234, 0x8232, 0x8274, 0x8390, 0x83a3, 0x83a8, 0x838d, 0x837a,
+    0x8373, 0x83a4, 0x8374, 0x838f, 0x8381, 0x8395, 0x8399, 0x8375, 0x8394, 0x83a9, 0x837d, 0x8383,
+    0x838c, 0x839d, 0x839b, 0x83aa, 0x838b, 0x837e, 0x83a5, 0x83af, 0x8388, 0x8397, 0x83b0, 0x837f,
+    0x83a6, 0x8387, 0x83ae, 0x8376, 0x839a, 0x8659, 0x8656, 0x86bf, 0x86b7, 0x86c2, 0x86c1, 0x86c5,
+    0x86ba, 0x86b0, 0x86c8, 0x86b9, 0x86b3, 0x86b8, 0x86cc, 0x86b4, 0x86bb, 0x86bc, 0x86c3, 0x86bd,
+    0x86be, 0x8852, 0x8889, 0x8895, 0x88a8, 0x88a2, 0x88aa, 0x889a, 0x8891, 0x88a1, 0x889f, 0x8898,
+    0x88a7, 0x8899, 0x889b, 0x8897, 0x88a4, 0x88ac, 0x888c, 0x8893, 0x888e, 0x8982, 0x89d6, 0x89d9,
+    0x89d5, 0x8a30, 0x8a27, 0x8a2c, 0x8a1e, 0x8c39, 0x8c3b, 0x8c5c, 0x8c5d, 0x8c7d, 0x8ca5, 0x8d7d,
+    0x8d7b, 0x8d79, 0x8dbc, 0x8dc2, 0x8db9, 0x8dbf, 0x8dc1, 0x8ed8, 0x8ede, 0x8edd, 0x8edc, 0x8ed7,
+    0x8ee0, 0x8ee1, 0x9024, 0x900b, 0x9011, 0x901c, 0x900c, 0x9021, 0x90ef, 0x90ea, 0x90f0, 0x90f4,
+    0x90f2, 0x90f3, 0x90d4, 0x90eb, 0x90ec, 0x90e9, 0x9156, 0x9158, 0x915a, 0x9153, 0x9155, 0x91ec,
+    0x91f4, 0x91f1, 0x91f3, 0x91f8, 0x91e4, 0x91f9, 0x91ea, 0x91eb, 0x91f7, 0x91e8, 0x91ee, 0x957a,
+    0x9586, 0x9588, 0x967c, 0x966d, 0x966b, 0x9671, 0x966f, 0x96bf, 0x976a, 0x9804, 0x98e5, 0x9997,
+    0x509b, 0x5095, 0x5094, 0x509e, 0x508b, 0x50a3, 0x5083, 0x508c, 0x508e, 0x509d, 0x5068, 0x509c,
+    0x5092, 0x5082, 0x5087, 0x515f, 0x51d4, 0x5312, 0x5311, 0x53a4, 0x53a7, 0x5591, 0x55a8, 0x55a5,
+    0x55ad, 0x5577, 0x5645, 0x55a2, 0x5593, 0x5588, 0x558f, 0x55b5, 0x5581, 0x55a3, 0x5592, 0x55a4,
+    0x557d, 0x558c, 0x55a6, 0x557f, 0x5595, 0x55a1, 0x558e, 0x570c, 0x5829, 0x5837, 0x5819, 0x581e,
+    0x5827, 0x5823, 0x5828, 0x57f5, 0x5848, 0x5825, 0x581c, 0x581b, 0x5833, 0x583f, 0x5836, 0x582e,
+    0x5839, 0x5838, 0x582d, 0x582c, 0x583b, 0x5961, 0x5aaf, 0x5a94, 0x5a9f, 0x5a7a, 0x5aa2, 0x5a9e,
+    0x5a78, 0x5aa6, 0x5a7c, 0x5aa5, 0x5aac, 0x5a95, 0x5aae, 0x5a37, 0x5a84, 0x5a8a, 0x5a97, 0x5a83,
+    0x5a8b, 0x5aa9, 0x5a7b, 0x5a7d, 0x5a8c, 0x5a9c, 0x5a8f, 0x5a93, 0x5a9d, 0x5bea, 0x5bcd, 0x5bcb,
+    0x5bd4, 0x5bd1, 0x5bca, 0x5bce, 0x5c0c, 0x5c30, 0x5d37, 0x5d43, 0x5d6b, 0x5d41, 0x5d4b, 0x5d3f,
+    0x5d35, 0x5d51, 0x5d4e, 0x5d55, 0x5d33, 0x5d3a, 0x5d52, 0x5d3d, 0x5d31, 0x5d59, 0x5d42, 0x5d39,
+    0x5d49, 0x5d38, 0x5d3c, 0x5d32, 0x5d36, 0x5d40, 0x5d45, 0x5e44, 0x5e41, 0x5f58, 0x5fa6, 0x5fa5,
+    0x5fab, 0x60c9, 0x60b9, 0x60cc, 0x60e2, 0x60ce, 0x60c4, 0x6114, 0x60f2, 0x610a, 0x6116, 0x6105,
+    0x60f5, 0x6113, 0x60f8, 0x60fc, 0x60fe, 0x60c1, 0x6103, 0x6118, 0x611d, 0x6110, 0x60ff, 0x6104,
+    0x610b, 0x624a, 0x6394, 0x63b1, 0x63b0, 0x63ce, 0x63e5, 0x63e8, 0x63ef, 0x63c3, 0x649d, 0x63f3,
+    0x63ca, 0x63e0, 0x63f6, 0x63d5, 0x63f2, 0x63f5, 0x6461, 0x63df, 0x63be, 0x63dd, 0x63dc, 0x63c4,
+    0x63d8, 0x63d3, 0x63c2, 0x63c7, 0x63cc, 0x63cb, 0x63c8, 0x63f0, 0x63d7, 0x63d9, 0x6532, 0x6567,
+    0x656a, 0x6564, 0x655c, 0x6568, 0x6565, 0x658c, 0x659d, 0x659e, 0x65ae, 0x65d0, 0x65d2, 0x667c,
+    0x666c, 0x667b, 0x6680, 0x6671, 0x6679, 0x666a, 0x6672, 0x6701, 0x690c, 0x68d3, 0x6904, 0x68dc,
+    0x692a, 0x68ec, 0x68ea, 0x68f1, 0x690f, 0x68d6, 0x68f7, 0x68eb, 0x68e4, 0x68f6, 0x6913, 0x6910,
+    0x68f3, 0x68e1, 0x6907, 0x68cc, 0x6908, 0x6970, 0x68b4, 0x6911, 0x68ef, 0x68c6, 0x6914, 0x68f8,
+    0x68d0, 0x68fd, 0x68fc, 0x68e8, 0x690b, 0x690a, 0x6917, 0x68ce, 0x68c8, 0x68dd, 0x68de, 0x68e6,
+    0x68f4, 0x68d1, 0x6906, 0x68d4, 0x68e9, 0x6915, 0x6925, 0x68c7, 0x6b39, 0x6b3b, 0x6b3f, 0x6b3c,
+    0x6b94, 0x6b97, 0x6b99, 0x6b95, 0x6bbd, 0x6bf0, 0x6bf2, 0x6bf3, 0x6c30, 0x6dfc, 0x6e46, 0x6e47,
+    0x6e1f, 0x6e49, 0x6e88, 0x6e3c, 0x6e3d, 0x6e45, 0x6e62, 0x6e2b, 0x6e3f, 0x6e41, 0x6e5d, 0x6e73,
+    0x6e1c, 0x6e33, 0x6e4b, 0x6e40, 0x6e51, 0x6e3b, 0x6e03, 0x6e2e, 0x6e5e, 0x6e68, 0x6e5c, 0x6e61,
+    0x6e31, 0x6e28, 0x6e60, 0x6e71, 0x6e6b, 0x6e39, 0x6e22, 0x6e30, 0x6e53, 0x6e65, 0x6e27, 0x6e78,
+    0x6e64, 0x6e77, 0x6e55, 0x6e79, 0x6e52, 0x6e66, 0x6e35, 0x6e36, 0x6e5a, 0x7120, 0x711e, 0x712f,
+    0x70fb, 0x712e, 0x7131, 0x7123, 0x7125, 0x7122, 0x7132, 0x711f, 0x7128, 0x713a, 0x711b, 0x724b,
+    0x725a, 0x7288, 0x7289, 0x7286, 0x7285, 0x728b, 0x7312, 0x730b, 0x7330, 0x7322, 0x7331, 0x7333,
+    0x7327, 0x7332, 0x732d, 0x7326, 0x7323, 0x7335, 0x730c, 0x742e, 0x742c, 0x7430, 0x742b, 0x7416,
+    0x741a, 0x7421, 0x742d, 0x7431, 0x7424, 0x7423, 0x741d, 0x7429, 0x7420, 0x7432, 0x74fb, 0x752f,
+    0x756f, 0x756c, 0x75e7, 0x75da, 0x75e1, 0x75e6, 0x75dd, 0x75df, 0x75e4, 0x75d7, 0x7695, 0x7692,
+    0x76da, 0x7746, 0x7747, 0x7744, 0x774d, 0x7745, 0x774a, 0x774e, 0x774b, 0x774c, 0x77de, 0x77ec,
+    0x7860, 0x7864, 0x7865, 0x785c, 0x786d, 0x7871, 0x786a, 0x786e, 0x7870, 0x7869, 0x7868, 0x785e,
+    0x7862, 0x7974, 0x7973, 0x7972, 0x7970, 0x7a02, 0x7a0a, 0x7a03, 0x7a0c, 0x7a04, 0x7a99, 0x7ae6,
+    0x7ae4, 0x7b4a, 0x7b3b, 0x7b44, 0x7b48, 0x7b4c, 0x7b4e, 0x7b40, 0x7b58, 0x7b45, 0x7ca2, 0x7c9e,
+    0x7ca8, 0x7ca1, 0x7d58, 0x7d6f, 0x7d63, 0x7d53, 0x7d56, 0x7d67, 0x7d6a, 0x7d4f, 0x7d6d, 0x7d5c,
+    0x7d6b, 0x7d52, 0x7d54, 0x7d69, 0x7d51, 0x7d5f, 0x7d4e, 0x7f3e, 0x7f3f, 0x7f65, 0x7f66, 0x7fa2,
+    0x7fa0, 0x7fa1, 0x7fd7, 0x8051, 0x804f, 0x8050, 0x80fe, 0x80d4, 0x8143, 0x814a, 0x8152, 0x814f,
+    0x8147, 0x813d, 0x814d, 0x813a, 0x81e6, 0x81ee, 0x81f7, 0x81f8, 0x81f9, 0x8204, 0x823c, 0x823d,
+    0x823f, 0x8275, 0x833b, 0x83cf, 0x83f9, 0x8423, 0x83c0, 0x83e8, 0x8412, 0x83e7, 0x83e4, 0x83fc,
+    0x83f6, 0x8410, 0x83c6, 0x83c8, 0x83eb, 0x83e3, 0x83bf, 0x8401, 0x83dd, 0x83e5, 0x83d8, 0x83ff,
+    0x83e1, 0x83cb, 0x83ce, 0x83d6, 0x83f5, 0x83c9, 0x8409, 0x840f, 0x83de, 0x8411, 0x8406, 0x83c2,
+    0x83f3, 0x83d5, 0x83fa, 0x83c7, 0x83d1, 0x83ea, 0x8413, 0x83c3, 0x83ec, 0x83ee, 0x83c4, 0x83fb,
+    0x83d7, 0x83e2, 0x841b, 0x83db, 0x83fe, 0x86d8, 0x86e2, 0x86e6, 0x86d3, 0x86e3, 0x86da, 0x86ea,
+    0x86dd, 0x86eb, 0x86dc, 0x86ec, 0x86e9, 0x86d7, 0x86e8, 0x86d1, 0x8848, 0x8856, 0x8855, 0x88ba,
+    0x88d7, 0x88b9, 0x88b8, 0x88c0, 0x88be, 0x88b6, 0x88bc, 0x88b7, 0x88bd, 0x88b2, 0x8901, 0x88c9,
+    0x8995, 0x8998, 0x8997, 0x89dd, 0x89da, 0x89db, 0x8a4e, 0x8a4d, 0x8a39, 0x8a59, 0x8a40, 0x8a57,
+    0x8a58, 0x8a44, 0x8a45, 0x8a52, 0x8a48, 0x8a51, 0x8a4a, 0x8a4c, 0x8a4f, 0x8c5f, 0x8c81, 0x8c80,
+    0x8cba, 0x8cbe, 0x8cb0, 0x8cb9, 0x8cb5, 0x8d84, 0x8d80, 0x8d89, 0x8dd8, 0x8dd3, 0x8dcd, 0x8dc7,
+    0x8dd6, 0x8ddc, 0x8dcf, 0x8dd5, 0x8dd9, 0x8dc8, 0x8dd7, 0x8dc5, 0x8eef, 0x8ef7, 0x8efa, 0x8ef9,
+    0x8ee6, 0x8eee, 0x8ee5, 0x8ef5, 0x8ee7, 0x8ee8, 0x8ef6, 0x8eeb, 0x8ef1, 0x8eec, 0x8ef4, 0x8ee9,
+    0x902d, 0x9034, 0x902f, 0x9106, 0x912c, 0x9104, 0x90ff, 0x90fc, 0x9108, 0x90f9, 0x90fb, 0x9101,
+    0x9100, 0x9107, 0x9105, 0x9103, 0x9161, 0x9164, 0x915f, 0x9162, 0x9160, 0x9201, 0x920a, 0x9225,
+    0x9203, 0x921a, 0x9226, 0x920f, 0x920c, 0x9200, 0x9212, 0x91ff, 0x91fd, 0x9206, 0x9204, 0x9227,
+    0x9202, 0x921c, 0x9224, 0x9219, 0x9217, 0x9205, 0x9216, 0x957b, 0x958d, 0x958c, 0x9590, 0x9687,
+    0x967e, 0x9688, 0x9689, 0x9683, 0x9680, 0x96c2, 0x96c8, 0x96c3, 0x96f1, 0x96f0, 0x976c, 0x9770,
+    0x976e, 0x9807, 0x98a9, 0x98eb, 0x9ce6, 0x9ef9, 0x4e83, 0x4e84, 0x4eb6, 0x50bd, 0x50bf, 0x50c6,
+    0x50ae, 0x50c4, 0x50ca, 0x50b4, 0x50c8, 0x50c2, 0x50b0, 0x50c1, 0x50ba, 0x50b1, 0x50cb, 0x50c9,
+    0x50b6, 0x50b8, 0x51d7, 0x527a, 0x5278, 0x527b, 0x527c, 0x55c3, 0x55db, 0x55cc, 0x55d0, 0x55cb,
+    0x55ca, 0x55dd, 0x55c0, 0x55d4, 0x55c4, 0x55e9, 0x55bf, 0x55d2, 0x558d, 0x55cf, 0x55d5, 0x55e2,
+    0x55d6, 0x55c8, 0x55f2, 0x55cd, 0x55d9, 0x55c2, 0x5714, 0x5853, 0x5868, 0x5864, 0x584f, 0x584d,
+    0x5849, 0x586f, 0x5855, 0x584e, 0x585d, 0x5859, 0x5865, 0x585b, 0x583d, 0x5863, 0x5871, 0x58fc,
+    0x5ac7, 0x5ac4, 0x5acb, 0x5aba, 0x5ab8, 0x5ab1, 0x5ab5, 0x5ab0, 0x5abf, 0x5ac8, 0x5abb, 0x5ac6,
+    0x5ab7, 0x5ac0, 0x5aca, 0x5ab4, 0x5ab6, 0x5acd, 0x5ab9, 0x5a90, 0x5bd6, 0x5bd8, 0x5bd9, 0x5c1f,
+    0x5c33, 0x5d71, 0x5d63, 0x5d4a, 0x5d65, 0x5d72, 0x5d6c, 0x5d5e, 0x5d68, 0x5d67, 0x5d62, 0x5df0,
+    0x5e4f, 0x5e4e, 0x5e4a, 0x5e4d, 0x5e4b, 0x5ec5, 0x5ecc, 0x5ec6, 0x5ecb, 0x5ec7, 0x5f40, 0x5faf,
+    0x5fad, 0x60f7, 0x6149, 0x614a, 0x612b, 0x6145, 0x6136, 0x6132, 0x612e, 0x6146, 0x612f, 0x614f,
+    0x6129, 0x6140, 0x6220, 0x9168, 0x6223, 0x6225, 0x6224, 0x63c5, 0x63f1, 0x63eb, 0x6410, 0x6412,
+    0x6409, 0x6420, 0x6424, 0x6433, 0x6443, 0x641f, 0x6415, 0x6418, 0x6439, 0x6437, 0x6422, 0x6423,
+    0x640c, 0x6426, 0x6430, 0x6428, 0x6441, 0x6435, 0x642f, 0x640a, 0x641a, 0x6440, 0x6425, 0x6427,
+    0x640b, 0x63e7, 0x641b, 0x642e, 0x6421, 0x640e, 0x656f, 0x6592, 0x65d3, 0x6686, 0x668c, 0x6695,
+    0x6690, 0x668b, 0x668a, 0x6699, 0x6694, 0x6678, 0x6720, 0x6966, 0x695f, 0x6938, 0x694e, 0x6962,
+    0x6971, 0x693f, 0x6945, 0x696a, 0x6939, 0x6942, 0x6957, 0x6959, 0x697a, 0x6948, 0x6949, 0x6935,
+    0x696c, 0x6933, 0x693d, 0x6965, 0x68f0, 0x6978, 0x6934, 0x6969, 0x6940, 0x696f, 0x6944, 0x6976,
+    0x6958, 0x6941, 0x6974, 0x694c, 0x693b, 0x694b, 0x6937, 0x695c, 0x694f, 0x6951, 0x6932, 0x6952,
+    0x692f, 0x697b, 0x693c, 0x6b46, 0x6b45, 0x6b43, 0x6b42, 0x6b48, 0x6b41, 0x6b9b, 0xfa0d, 0x6bfb,
+    0x6bfc, 0x6bf9, 0x6bf7, 0x6bf8, 0x6e9b, 0x6ed6, 0x6ec8, 0x6e8f, 0x6ec0, 0x6e9f, 0x6e93, 0x6e94,
+    0x6ea0, 0x6eb1, 0x6eb9, 0x6ec6, 0x6ed2, 0x6ebd, 0x6ec1, 0x6e9e, 0x6ec9, 0x6eb7, 0x6eb0, 0x6ecd,
+    0x6ea6, 0x6ecf, 0x6eb2, 0x6ebe, 0x6ec3, 0x6edc, 0x6ed8, 0x6e99, 0x6e92, 0x6e8e, 0x6e8d, 0x6ea4,
+    0x6ea1, 0x6ebf, 0x6eb3, 0x6ed0, 0x6eca, 0x6e97, 0x6eae, 0x6ea3, 0x7147, 0x7154, 0x7152, 0x7163,
+    0x7160, 0x7141, 0x715d, 0x7162, 0x7172, 0x7178, 0x716a, 0x7161, 0x7142, 0x7158, 0x7143, 0x714b,
+    0x7170, 0x715f, 0x7150, 0x7153, 0x7144, 0x714d, 0x715a, 0x724f, 0x728d, 0x728c, 0x7291, 0x7290,
+    0x728e, 0x733c, 0x7342, 0x733b, 0x733a, 0x7340, 0x734a, 0x7349, 0x7444, 0x744a, 0x744b, 0x7452,
+    0x7451, 0x7457, 0x7440, 0x744f, 0x7450, 0x744e, 0x7442, 0x7446, 0x744d, 0x7454, 0x74e1, 0x74ff,
+    0x74fe, 0x74fd, 0x751d, 0x7579, 0x7577, 0x6983, 0x75ef, 0x760f, 0x7603, 0x75f7, 0x75fe, 0x75fc,
+    0x75f9, 0x75f8, 0x7610, 0x75fb, 0x75f6, 0x75ed, 0x75f5, 0x75fd, 0x7699, 0x76b5, 0x76dd, 0x7755,
+    0x775f, 0x7760, 0x7752, 0x7756, 0x775a, 0x7769, 0x7767, 0x7754, 0x7759, 0x776d, 0x77e0, 0x7887,
+    0x789a, 0x7894, 0x788f, 0x7884, 0x7895, 0x7885, 0x7886, 0x78a1, 0x7883, 0x7879, 0x7899, 0x7880,
+    0x7896, 0x787b, 0x797c, 0x7982, 0x797d, 0x7979, 0x7a11, 0x7a18, 0x7a19, 0x7a12, 0x7a17, 0x7a15,
+    0x7a22, 0x7a13, 0x7a1b, 0x7a10, 0x7aa3, 0x7aa2, 0x7a9e, 0x7aeb, 0x7b66, 0x7b64, 0x7b6d, 0x7b74,
+    0x7b69, 0x7b72, 0x7b65, 0x7b73, 0x7b71, 0x7b70, 0x7b61, 0x7b78, 0x7b76, 0x7b63, 0x7cb2, 0x7cb4,
+    0x7caf, 0x7d88, 0x7d86, 0x7d80, 0x7d8d, 0x7d7f, 0x7d85, 0x7d7a, 0x7d8e, 0x7d7b, 0x7d83, 0x7d7c,
+    0x7d8c, 0x7d94, 0x7d84, 0x7d7d, 0x7d92, 0x7f6d, 0x7f6b, 0x7f67, 0x7f68, 0x7f6c, 0x7fa6, 0x7fa5,
+    0x7fa7, 0x7fdb, 0x7fdc, 0x8021, 0x8164, 0x8160, 0x8177, 0x815c, 0x8169, 0x815b, 0x8162, 0x8172,
+    0x6721, 0x815e, 0x8176, 0x8167, 0x816f, 0x8144, 0x8161, 0x821d, 0x8249, 0x8244, 0x8240, 0x8242,
+    0x8245, 0x84f1, 0x843f, 0x8456, 0x8476, 0x8479, 0x848f, 0x848d, 0x8465, 0x8451, 0x8440, 0x8486,
+    0x8467, 0x8430, 0x844d, 0x847d, 0x845a, 0x8459, 0x8474, 0x8473, 0x845d, 0x8507, 0x845e, 0x8437,
+    0x843a, 0x8434, 0x847a, 0x8443, 0x8478, 0x8432, 0x8445, 0x8429, 0x83d9, 0x844b, 0x842f, 0x8442,
+    0x842d, 0x845f, 0x8470, 0x8439, 0x844e, 0x844c, 0x8452, 0x846f, 0x84c5, 0x848e, 0x843b, 0x8447,
+    0x8436, 0x8433, 0x8468, 0x847e, 0x8444, 0x842b, 0x8460, 0x8454, 0x846e, 0x8450, 0x870b, 0x8704,
+    0x86f7, 0x870c, 0x86fa, 0x86d6, 0x86f5, 0x874d, 0x86f8, 0x870e, 0x8709, 0x8701, 0x86f6, 0x870d,
+    0x8705, 0x88d6, 0x88cb, 0x88cd, 0x88ce, 0x88de, 0x88db, 0x88da, 0x88cc, 0x88d0, 0x8985, 0x899b,
+    0x89df, 0x89e5, 0x89e4, 0x89e1, 0x89e0, 0x89e2, 0x89dc, 0x89e6, 0x8a76, 0x8a86, 0x8a7f, 0x8a61,
+    0x8a3f, 0x8a77, 0x8a82, 0x8a84, 0x8a75, 0x8a83, 0x8a81, 0x8a74, 0x8a7a, 0x8c3c, 0x8c4b, 0x8c4a,
+    0x8c65, 0x8c64, 0x8c66, 0x8c86, 0x8c84, 0x8c85, 0x8ccc, 0x8d68, 0x8d69, 0x8d91, 0x8d8c, 0x8d8e,
+    0x8d8f, 0x8d8d, 0x8d93, 0x8d94, 0x8d90, 0x8d92, 0x8df0, 0x8de0, 0x8dec, 0x8df1, 0x8dee, 0x8dd0,
+    0x8de9, 0x8de3, 0x8de2, 0x8de7, 0x8df2, 0x8deb, 0x8df4, 0x8f06, 0x8eff, 0x8f01, 0x8f00, 0x8f05,
+    0x8f07, 0x8f08, 0x8f02, 0x8f0b, 0x9052, 0x903f, 0x9044, 0x9049, 0x903d, 0x9110, 0x910d, 0x910f,
+    0x9111, 0x9116, 0x9114, 0x910b, 0x910e, 0x916e, 0x916f, 0x9248, 0x9252, 0x9230, 0x923a, 0x9266,
+    0x9233, 0x9265, 0x925e, 0x9283, 0x922e, 0x924a, 0x9246, 0x926d, 0x926c, 0x924f, 0x9260, 0x9267,
+    0x926f, 0x9236, 0x9261, 0x9270, 0x9231, 0x9254, 0x9263, 0x9250, 0x9272, 0x924e, 0x9253, 0x924c,
+    0x9256, 0x9232, 0x959f, 0x959c, 0x959e, 0x959b, 0x9692, 0x9693, 0x9691, 0x9697, 0x96ce, 0x96fa,
+    0x96fd, 0x96f8, 0x96f5, 0x9773, 0x9777, 0x9778, 0x9772, 0x980f, 0x980d, 0x980e, 0x98ac, 0x98f6,
+    0x98f9, 0x99af, 0x99b2, 0x99b0, 0x99b5, 0x9aad, 0x9aab, 0x9b5b, 0x9cea, 0x9ced, 0x9ce7, 0x9e80,
+    0x9efd, 0x50e6, 0x50d4, 0x50d7, 0x50e8, 0x50f3, 0x50db, 0x50ea, 0x50dd, 0x50e4, 0x50d3, 0x50ec,
+    0x50f0, 0x50ef, 0x50e3, 0x50e0, 0x51d8, 0x5280, 0x5281, 0x52e9, 0x52eb, 0x5330, 0x53ac, 0x5627,
+    0x5615, 0x560c, 0x5612, 0x55fc, 0x560f, 0x561c, 0x5601, 0x5613, 0x5602, 0x55fa, 0x561d, 0x5604,
+    0x55ff, 0x55f9, 0x5889, 0x587c, 0x5890, 0x5898, 0x5886, 0x5881, 0x587f, 0x5874, 0x588b, 0x587a,
+    0x5887, 0x5891, 0x588e, 0x5876, 0x5882, 0x5888, 0x587b, 0x5894, 0x588f, 0x58fe, 0x596b, 0x5adc,
+    0x5aee, 0x5ae5, 0x5ad5, 0x5aea, 0x5ada, 0x5aed, 0x5aeb, 0x5af3, 0x5ae2, 0x5ae0, 0x5adb, 0x5aec,
+    0x5ade, 0x5add, 0x5ad9, 0x5ae8, 0x5adf, 0x5b77, 0x5be0, 0x5be3, 0x5c63, 0x5d82, 0x5d80, 0x5d7d,
+    0x5d86, 0x5d7a, 0x5d81, 0x5d77, 0x5d8a, 0x5d89, 0x5d88, 0x5d7e, 0x5d7c, 0x5d8d, 0x5d79, 0x5d7f,
+    0x5e58, 0x5e59, 0x5e53, 0x5ed8, 0x5ed1, 0x5ed7, 0x5ece, 0x5edc, 0x5ed5, 0x5ed9, 0x5ed2, 0x5ed4,
+    0x5f44, 0x5f43, 0x5f6f, 0x5fb6, 0x612c, 0x6128, 0x6141, 0x615e, 0x6171, 0x6173, 0x6152, 0x6153,
+    0x6172, 0x616c, 0x6180, 0x6174, 0x6154, 0x617a, 0x615b, 0x6165, 0x613b, 0x616a, 0x6161, 0x6156,
+    0x6229, 0x6227, 0x622b, 0x642b, 0x644d, 0x645b, 0x645d, 0x6474, 0x6476, 0x6472, 0x6473, 0x647d,
+    0x6475, 0x6466, 0x64a6, 0x644e, 0x6482, 0x645e, 0x645c, 0x644b, 0x6453, 0x6460, 0x6450, 0x647f,
+    0x643f, 0x646c, 0x646b, 0x6459, 0x6465, 0x6477, 0x6573, 0x65a0, 0x66a1, 0x66a0, 0x669f, 0x6705,
+    0x6704, 0x6722, 0x69b1, 0x69b6, 0x69c9, 0x69a0, 0x69ce, 0x6996, 0x69b0, 0x69ac, 0x69bc, 0x6991,
+    0x6999, 0x698e, 0x69a7, 0x698d, 0x69a9, 0x69be, 0x69af, 0x69bf, 0x69c4, 0x69bd, 0x69a4, 0x69d4,
+    0x69b9, 0x69ca, 0x699a, 0x69cf, 0x69b3, 0x6993, 0x69aa, 0x69a1, 0x699e, 0x69d9, 0x6997, 0x6990,
+    0x69c2, 0x69b5, 0x69a5, 0x69c6, 0x6b4a, 0x6b4d, 0x6b4b, 0x6b9e, 0x6b9f, 0x6ba0, 0x6bc3, 0x6bc4,
+    0x6bfe, 0x6ece, 0x6ef5, 0x6ef1, 0x6f03, 0x6f25, 0x6ef8, 0x6f37, 0x6efb, 0x6f2e, 0x6f09, 0x6f4e,
+    0x6f19, 0x6f1a, 0x6f27, 0x6f18, 0x6f3b, 0x6f12, 0x6eed, 0x6f0a, 0x6f36, 0x6f73, 0x6ef9, 0x6eee,
+    0x6f2d, 0x6f40, 0x6f30, 0x6f3c, 0x6f35, 0x6eeb, 0x6f07, 0x6f0e, 0x6f43, 0x6f05, 0x6efd, 0x6ef6,
+    0x6f39, 0x6f1c, 0x6efc, 0x6f3a, 0x6f1f, 0x6f0d, 0x6f1e, 0x6f08, 0x6f21, 0x7187, 0x7190, 0x7189,
+    0x7180, 0x7185, 0x7182, 0x718f, 0x717b, 0x7186, 0x7181, 0x7197, 0x7244, 0x7253, 0x7297, 0x7295,
+    0x7293, 0x7343, 0x734d, 0x7351, 0x734c, 0x7462, 0x7473, 0x7471, 0x7475, 0x7472, 0x7467, 0x746e,
+    0x7500, 0x7502, 0x7503, 0x757d, 0x7590, 0x7616, 0x7608, 0x760c, 0x7615, 0x7611, 0x760a, 0x7614,
+    0x76b8, 0x7781, 0x777c, 0x7785, 0x7782, 0x776e, 0x7780, 0x776f, 0x777e, 0x7783, 0x78b2, 0x78aa,
+    0x78b4, 0x78ad, 0x78a8, 0x787e, 0x78ab, 0x789e, 0x78a5, 0x78a0, 0x78ac, 0x78a2, 0x78a4, 0x7998,
+    0x798a, 0x798b, 0x7996, 0x7995, 0x7994, 0x7993, 0x7997, 0x7988, 0x7992, 0x7990, 0x7a2b, 0x7a4a,
+    0x7a30, 0x7a2f, 0x7a28, 0x7a26, 0x7aa8, 0x7aab, 0x7aac, 0x7aee, 0x7b88, 0x7b9c, 0x7b8a, 0x7b91,
+    0x7b90, 0x7b96, 0x7b8d, 0x7b8c, 0x7b9b, 0x7b8e, 0x7b85, 0x7b98, 0x5284, 0x7b99, 0x7ba4, 0x7b82,
+    0x7cbb, 0x7cbf, 0x7cbc, 0x7cba, 0x7da7, 0x7db7, 0x7dc2, 0x7da3, 0x7daa, 0x7dc1, 0x7dc0, 0x7dc5,
+    0x7d9d, 0x7dce, 0x7dc4, 0x7dc6, 0x7dcb, 0x7dcc, 0x7daf, 0x7db9, 0x7d96, 0x7dbc, 0x7d9f, 0x7da6,
+    0x7dae, 0x7da9, 0x7da1, 0x7dc9, 0x7f73, 0x7fe2, 0x7fe3, 0x7fe5, 0x7fde, 0x8024, 0x805d, 0x805c,
+    0x8189, 0x8186, 0x8183, 0x8187, 0x818d, 0x818c, 0x818b, 0x8215, 0x8497, 0x84a4, 0x84a1, 0x849f,
+    0x84ba, 0x84ce, 0x84c2, 0x84ac, 0x84ae, 0x84ab, 0x84b9, 0x84b4, 0x84c1, 0x84cd, 0x84aa, 0x849a,
+    0x84b1, 0x84d0, 0x849d, 0x84a7, 0x84bb, 0x84a2, 0x8494, 0x84c7, 0x84cc, 0x849b, 0x84a9, 0x84af,
+    0x84a8, 0x84d6, 0x8498, 0x84b6, 0x84cf, 0x84a0, 0x84d7, 0x84d4, 0x84d2, 0x84db, 0x84b0, 0x8491,
+    0x8661, 0x8733, 0x8723, 0x8728, 0x876b, 0x8740, 0x872e, 0x871e, 0x8721, 0x8719, 0x871b, 0x8743,
+    0x872c, 0x8741, 0x873e, 0x8746, 0x8720, 0x8732, 0x872a, 0x872d, 0x873c, 0x8712, 0x873a, 0x8731,
+    0x8735, 0x8742, 0x8726, 0x8727, 0x8738, 0x8724, 0x871a, 0x8730, 0x8711, 0x88f7, 0x88e7, 0x88f1,
+    0x88f2, 0x88fa, 0x88fe, 0x88ee, 0x88fc, 0x88f6, 0x88fb, 0x88f0, 0x88ec, 0x88eb, 0x899d, 0x89a1,
+    0x899f, 0x899e, 0x89e9, 0x89eb, 0x89e8, 0x8aab, 0x8a99, 0x8a8b, 0x8a92, 0x8a8f, 0x8a96, 0x8c3d,
+    0x8c68, 0x8c69, 0x8cd5, 0x8ccf, 0x8cd7, 0x8d96, 0x8e09, 0x8e02, 0x8dff, 0x8e0d, 0x8dfd, 0x8e0a,
+    0x8e03, 0x8e07, 0x8e06, 0x8e05, 0x8dfe, 0x8e00, 0x8e04, 0x8f10, 0x8f11, 0x8f0e, 0x8f0d, 0x9123,
+    0x911c, 0x9120, 0x9122, 0x911f, 0x911d, 0x911a, 0x9124, 0x9121, 0x911b, 0x917a, 0x9172, 0x9179,
+    0x9173, 0x92a5, 0x92a4, 0x9276, 0x929b, 0x927a, 0x92a0, 0x9294, 0x92aa, 0x928d, 0x92a6, 0x929a,
+    0x92ab, 0x9279, 0x9297, 0x927f, 0x92a3, 0x92ee, 0x928e, 0x9282, 0x9295, 0x92a2, 0x927d, 0x9288,
+    0x92a1, 0x928a, 0x9286, 0x928c, 0x9299, 0x92a7, 0x927e, 0x9287, 0x92a9, 0x929d, 0x928b, 0x922d,
+    0x969e, 0x96a1, 0x96ff, 0x9758, 0x977d, 0x977a, 0x977e, 0x9783, 0x9780, 0x9782, 0x977b, 0x9784,
+    0x9781, 0x977f, 0x97ce, 0x97cd, 0x9816, 0x98ad, 0x98ae, 0x9902, 0x9900, 0x9907, 0x999d, 0x999c,
+    0x99c3, 0x99b9, 0x99bb, 0x99ba, 0x99c2, 0x99bd, 0x99c7, 0x9ab1, 0x9ae3, 0x9ae7, 0x9b3e, 0x9b3f,
+    0x9b60, 0x9b61, 0x9b5f, 0x9cf1, 0x9cf2, 0x9cf5, 0x9ea7, 0x50ff, 0x5103, 0x5130, 0x50f8, 0x5106,
+    0x5107, 0x50f6, 0x50fe, 0x510b, 0x510c, 0x50fd, 0x510a, 0x528b, 0x528c, 0x52f1, 0x52ef, 0x5648,
+    0x5642, 0x564c, 0x5635, 0x5641, 0x564a, 0x5649, 0x5646, 0x5658, 0x565a, 0x5640, 0x5633, 0x563d,
+    0x562c, 0x563e, 0x5638, 0x562a, 0x563a, 0x571a, 0x58ab, 0x589d, 0x58b1, 0x58a0, 0x58a3, 0x58af,
+    0x58ac, 0x58a5, 0x58a1, 0x58ff, 0x5aff, 0x5af4, 0x5afd, 0x5af7, 0x5af6, 0x5b03, 0x5af8, 0x5b02,
+    0x5af9, 0x5b01, 0x5b07, 0x5b05, 0x5b0f, 0x5c67, 0x5d99, 0x5d97, 0x5d9f, 0x5d92, 0x5da2, 0x5d93,
+    0x5d95, 0x5da0, 0x5d9c, 0x5da1, 0x5d9a, 0x5d9e, 0x5e69, 0x5e5d, 0x5e60, 0x5e5c, 0x7df3, 0x5edb,
+    0x5ede, 0x5ee1, 0x5f49, 0x5fb2, 0x618b, 0x6183, 0x6179, 0x61b1, 0x61b0, 0x61a2, 0x6189, 0x619b,
+    0x6193, 0x61af, 0x61ad, 0x619f, 0x6192, 0x61aa, 0x61a1, 0x618d, 0x6166, 0x61b3, 0x622d, 0x646e,
+    0x6470, 0x6496, 0x64a0, 0x6485, 0x6497, 0x649c, 0x648f, 0x648b, 0x648a, 0x648c, 0x64a3, 0x649f,
+    0x6468, 0x64b1, 0x6498, 0x6576, 0x657a, 0x6579, 0x657b, 0x65b2, 0x65b3, 0x66b5, 0x66b0, 0x66a9,
+    0x66b2, 0x66b7, 0x66aa, 0x66af, 0x6a00, 0x6a06, 0x6a17, 0x69e5, 0x69f8, 0x6a15, 0x69f1, 0x69e4,
+    0x6a20, 0x69ff, 0x69ec, 0x69e2, 0x6a1b, 0x6a1d, 0x69fe, 0x6a27, 0x69f2, 0x69ee, 0x6a14, 0x69f7,
+    0x69e7, 0x6a40, 0x6a08, 0x69e6, 0x69fb, 0x6a0d, 0x69fc, 0x69eb, 0x6a09, 0x6a04, 0x6a18, 0x6a25,
+    0x6a0f, 0x69f6, 0x6a26, 0x6a07, 0x69f4, 0x6a16, 0x6b51, 0x6ba5, 0x6ba3, 0x6ba2, 0x6ba6, 0x6c01,
+    0x6c00, 0x6bff, 0x6c02, 0x6f41, 0x6f26, 0x6f7e, 0x6f87, 0x6fc6, 0x6f92, 0x6f8d, 0x6f89, 0x6f8c,
+    0x6f62, 0x6f4f, 0x6f85, 0x6f5a, 0x6f96, 0x6f76, 0x6f6c, 0x6f82, 0x6f55, 0x6f72, 0x6f52, 0x6f50,
+    0x6f57, 0x6f94, 0x6f93, 0x6f5d, 0x6f00, 0x6f61, 0x6f6b, 0x6f7d, 0x6f67, 0x6f90, 0x6f53, 0x6f8b,
+    0x6f69, 0x6f7f, 0x6f95, 0x6f63, 0x6f77, 0x6f6a, 0x6f7b, 0x71b2, 0x71af, 0x719b, 0x71b0, 0x71a0,
+    0x719a, 0x71a9, 0x71b5, 0x719d, 0x71a5, 0x719e, 0x71a4, 0x71a1, 0x71aa, 0x719c, 0x71a7, 0x71b3,
+    0x7298, 0x729a, 0x7358, 0x7352, 0x735e, 0x735f, 0x7360, 0x735d, 0x735b, 0x7361, 0x735a, 0x7359,
+    0x7362, 0x7487, 0x7489, 0x748a, 0x7486, 0x7481, 0x747d, 0x7485, 0x7488, 0x747c, 0x7479, 0x7508,
+    0x7507, 0x757e, 0x7625, 0x761e, 0x7619, 0x761d, 0x761c, 0x7623, 0x761a, 0x7628, 0x761b, 0x769c,
+    0x769d, 0x769e, 0x769b, 0x778d, 0x778f, 0x7789, 0x7788, 0x78cd, 0x78bb, 0x78cf, 0x78cc, 0x78d1,
+    0x78ce, 0x78d4, 0x78c8, 0x78c3, 0x78c4, 0x78c9, 0x799a, 0x79a1, 0x79a0, 0x799c, 0x79a2, 0x799b,
+    0x6b76, 0x7a39, 0x7ab2, 0x7ab4, 0x7ab3, 0x7bb7, 0x7bcb, 0x7bbe, 0x7bac, 0x7bce, 0x7baf, 0x7bb9,
+    0x7bca, 0x7bb5, 0x7cc5, 0x7cc8, 0x7ccc, 0x7ccb, 0x7df7, 0x7ddb, 0x7dea, 0x7de7, 0x7dd7, 0x7de1,
+    0x7e03, 0x7dfa, 0x7de6, 0x7df6, 0x7df1, 0x7df0, 0x7dee, 0x7ddf, 0x7f76, 0x7fac, 0x7fb0, 0x7fad,
+    0x7fed, 0x7feb, 0x7fea, 0x7fec, 0x7fe6, 0x7fe8, 0x8064, 0x8067, 0x81a3, 0x819f, 0x819e, 0x8195,
+    0x81a2, 0x8199, 0x8197, 0x8216, 0x824f, 0x8253, 0x8252, 0x8250, 0x824e, 0x8251, 0x8524, 0x853b,
+    0x850f, 0x8500, 0x8529, 0x850e, 0x8509, 0x850d, 0x851f, 0x850a, 0x8527, 0x851c, 0x84fb, 0x852b,
+    0x84fa, 0x8508, 0x850c, 0x84f4, 0x852a, 0x84f2, 0x8515, 0x84f7, 0x84eb, 0x84f3, 0x84fc, 0x8512,
+    0x84ea, 0x84e9, 0x8516, 0x84fe, 0x8528, 0x851d, 0x852e, 0x8502, 0x84fd, 0x851e, 0x84f6, 0x8531,
+    0x8526, 0x84e7, 0x84e8, 0x84f0, 0x84ef, 0x84f9, 0x8518, 0x8520, 0x8530, 0x850b, 0x8519, 0x852f,
+    0x8662, 0x8756, 0x8763, 0x8764, 0x8777, 0x87e1, 0x8773, 0x8758, 0x8754, 0x875b, 0x8752, 0x8761,
+    0x875a, 0x8751, 0x875e, 0x876d, 0x876a, 0x8750, 0x874e, 0x875f, 0x875d, 0x876f, 0x876c, 0x877a,
+    0x876e, 0x875c, 0x8765, 0x874f, 0x877b, 0x8775, 0x8762, 0x8767, 0x8769, 0x885a, 0x8905, 0x890c,
+    0x8914, 0x890b, 0x8917, 0x8918, 0x8919, 0x8906, 0x8916, 0x8911, 0x890e, 0x8909, 0x89a2, 0x89a4,
+    0x89a3, 0x89ed, 0x89f0, 0x89ec, 0x8acf, 0x8ac6, 0x8ab8, 0x8ad3, 0x8ad1, 0x8ad4, 0x8ad5, 0x8abb,
+    0x8ad7, 0x8abe, 0x8ac0, 0x8ac5, 0x8ad8, 0x8ac3, 0x8aba, 0x8abd, 0x8ad9, 0x8c3e, 0x8c4d, 0x8c8f,
+    0x8ce5, 0x8cdf, 0x8cd9, 0x8ce8, 0x8cda, 0x8cdd, 0x8ce7, 0x8da0, 0x8d9c, 0x8da1, 0x8d9b, 0x8e20,
+    0x8e23, 0x8e25, 0x8e24, 0x8e2e, 0x8e15, 0x8e1b, 0x8e16, 0x8e11, 0x8e19, 0x8e26, 0x8e27, 0x8e14,
+    0x8e12, 0x8e18, 0x8e13, 0x8e1c, 0x8e17, 0x8e1a, 0x8f2c, 0x8f24, 0x8f18, 0x8f1a, 0x8f20, 0x8f23,
+    0x8f16, 0x8f17, 0x9073, 0x9070, 0x906f, 0x9067, 0x906b, 0x912f, 0x912b, 0x9129, 0x912a, 0x9132,
+    0x9126, 0x912e, 0x9185, 0x9186, 0x918a, 0x9181, 0x9182, 0x9184, 0x9180, 0x92d0, 0x92c3, 0x92c4,
+    0x92c0, 0x92d9, 0x92b6, 0x92cf, 0x92f1, 0x92df, 0x92d8, 0x92e9, 0x92d7, 0x92dd, 0x92cc, 0x92ef,
+    0x92c2, 0x92e8, 0x92ca, 0x92c8, 0x92ce, 0x92e6, 0x92cd, 0x92d5, 0x92c9, 0x92e0, 0x92de, 0x92e7,
+    0x92d1, 0x92d3, 0x92b5, 0x92e1, 0x92c6, 0x92b4, 0x957c, 0x95ac, 0x95ab, 0x95ae, 0x95b0, 0x96a4,
+    0x96a2, 0x96d3, 0x9705, 0x9708, 0x9702, 0x975a, 0x978a, 0x978e, 0x9788, 0x97d0, 0x97cf, 0x981e,
+    0x981d, 0x9826, 0x9829, 0x9828, 0x9820, 0x981b, 0x9827, 0x98b2, 0x9908, 0x98fa, 0x9911, 0x9914,
+    0x9916, 0x9917, 0x9915, 0x99dc, 0x99cd, 0x99cf, 0x99d3, 0x99d4, 0x99ce, 0x99c9, 0x99d6, 0x99d8,
+    0x99cb, 0x99d7, 0x99cc, 0x9ab3, 0x9aec, 0x9aeb, 0x9af3, 0x9af2, 0x9af1, 0x9b46, 0x9b43, 0x9b67,
+    0x9b74, 0x9b71, 0x9b66, 0x9b76, 0x9b75, 0x9b70, 0x9b68, 0x9b64, 0x9b6c, 0x9cfc, 0x9cfa, 0x9cfd,
+    0x9cff, 0x9cf7, 0x9d07, 0x9d00, 0x9cf9, 0x9cfb, 0x9d08, 0x9d05, 0x9d04, 0x9e83, 0x9ed3, 0x9f0f,
+    0x9f10, 0x511c, 0x5113, 0x5117, 0x511a, 0x5111, 0x51de, 0x5334, 0x53e1, 0x5670, 0x5660, 0x566e,
+    0x5673, 0x5666, 0x5663, 0x566d, 0x5672, 0x565e, 0x5677, 0x571c, 0x571b, 0x58c8, 0x58bd, 0x58c9,
+    0x58bf, 0x58ba, 0x58c2, 0x58bc, 0x58c6, 0x5b17, 0x5b19, 0x5b1b, 0x5b21, 0x5b14, 0x5b13, 0x5b10,
+    0x5b16, 0x5b28, 0x5b1a, 0x5b20, 0x5b1e, 0x5bef, 0x5dac, 0x5db1, 0x5da9, 0x5da7, 0x5db5, 0x5db0,
+    0x5dae, 0x5daa, 0x5da8, 0x5db2, 0x5dad, 0x5daf, 0x5db4, 0x5e67, 0x5e68, 0x5e66, 0x5e6f, 0x5ee9,
+    0x5ee7, 0x5ee6, 0x5ee8, 0x5ee5, 0x5f4b, 0x5fbc, 0x619d, 0x61a8, 0x6196, 0x61c5, 0x61b4, 0x61c6,
+    0x61c1, 0x61cc, 0x61ba, 0x61bf, 0x61b8, 0x618c, 0x64d7, 0x64d6, 0x64d0, 0x64cf, 0x64c9, 0x64bd,
+    0x6489, 0x64c3, 0x64db, 0x64f3, 0x64d9, 0x6533, 0x657f, 0x657c, 0x65a2, 0x66c8, 0x66be, 0x66c0,
+    0x66ca, 0x66cb, 0x66cf, 0x66bd, 0x66bb, 0x66ba, 0x66cc, 0x6723, 0x6a34, 0x6a66, 0x6a49, 0x6a67,
+    0x6a32, 0x6a68, 0x6a3e, 0x6a5d, 0x6a6d, 0x6a76, 0x6a5b, 0x6a51, 0x6a28, 0x6a5a, 0x6a3b, 0x6a3f,
+    0x6a41, 0x6a6a, 0x6a64, 0x6a50, 0x6a4f, 0x6a54, 0x6a6f, 0x6a69, 0x6a60, 0x6a3c, 0x6a5e, 0x6a56,
+    0x6a55, 0x6a4d, 0x6a4e, 0x6a46, 0x6b55, 0x6b54, 0x6b56, 0x6ba7, 0x6baa, 0x6bab, 0x6bc8, 0x6bc7,
+    0x6c04, 0x6c03, 0x6c06, 0x6fad, 0x6fcb, 0x6fa3, 0x6fc7, 0x6fbc, 0x6fce, 0x6fc8, 0x6f5e, 0x6fc4,
+    0x6fbd, 0x6f9e, 0x6fca, 0x6fa8, 0x7004, 0x6fa5, 0x6fae, 0x6fba, 0x6fac, 0x6faa, 0x6fcf, 0x6fbf,
+    0x6fb8, 0x6fa2, 0x6fc9, 0x6fab, 0x6fcd, 0x6faf, 0x6fb2, 0x6fb0, 0x71c5, 0x71c2, 0x71bf, 0x71b8,
+    0x71d6, 0x71c0, 0x71c1, 0x71cb, 0x71d4, 0x71ca, 0x71c7, 0x71cf, 0x71bd, 0x71d8, 0x71bc, 0x71c6,
+    0x71da, 0x71db, 0x729d, 0x729e, 0x7369, 0x7366, 0x7367, 0x736c, 0x7365, 0x736b, 0x736a, 0x747f,
+    0x749a, 0x74a0, 0x7494, 0x7492, 0x7495, 0x74a1, 0x750b, 0x7580, 0x762f, 0x762d, 0x7631, 0x763d,
+    0x7633, 0x763c, 0x7635, 0x7632, 0x7630, 0x76bb, 0x76e6, 0x779a, 0x779d, 0x77a1, 0x779c, 0x779b,
+    0x77a2, 0x77a3, 0x7795, 0x7799, 0x7797, 0x78dd, 0x78e9, 0x78e5, 0x78ea, 0x78de, 0x78e3, 0x78db,
+    0x78e1, 0x78e2, 0x78ed, 0x78df, 0x78e0, 0x79a4, 0x7a44, 0x7a48, 0x7a47, 0x7ab6, 0x7ab8, 0x7ab5,
+    0x7ab1, 0x7ab7, 0x7bde, 0x7be3, 0x7be7, 0x7bdd, 0x7bd5, 0x7be5, 0x7bda, 0x7be8, 0x7bf9, 0x7bd4,
+    0x7bea, 0x7be2, 0x7bdc, 0x7beb, 0x7bd8, 0x7bdf, 0x7cd2, 0x7cd4, 0x7cd7, 0x7cd0, 0x7cd1, 0x7e12,
+    0x7e21, 0x7e17, 0x7e0c, 0x7e1f, 0x7e20, 0x7e13, 0x7e0e, 0x7e1c, 0x7e15, 0x7e1a, 0x7e22, 0x7e0b,
+    0x7e0f, 0x7e16, 0x7e0d, 0x7e14, 0x7e25, 0x7e24, 0x7f43, 0x7f7b, 0x7f7c, 0x7f7a, 0x7fb1, 0x7fef,
+    0x802a, 0x8029, 0x806c, 0x81b1, 0x81a6, 0x81ae, 0x81b9, 0x81b5, 0x81ab, 0x81b0, 0x81ac, 0x81b4,
+    0x81b2, 0x81b7, 0x81a7, 0x81f2, 0x8255, 0x8256, 0x8257, 0x8556, 0x8545, 0x856b, 0x854d, 0x8553,
+    0x8561, 0x8558, 0x8540, 0x8546, 0x8564, 0x8541, 0x8562, 0x8544, 0x8551, 0x8547, 0x8563, 0x853e,
+    0x855b, 0x8571, 0x854e, 0x856e, 0x8575, 0x8555, 0x8567, 0x8560, 0x858c, 0x8566, 0x855d, 0x8554,
+    0x8565, 0x856c, 0x8663, 0x8665, 0x8664, 0x879b, 0x878f, 0x8797, 0x8793, 0x8792, 0x8788, 0x8781,
+    0x8796, 0x8798, 0x8779, 0x8787, 0x87a3, 0x8785, 0x8790, 0x8791, 0x879d, 0x8784, 0x8794, 0x879c,
+    0x879a, 0x8789, 0x891e, 0x8926, 0x8930, 0x892d, 0x892e, 0x8927, 0x8931, 0x8922, 0x8929, 0x8923,
+    0x892f, 0x892c, 0x891f, 0x89f1, 0x8ae0, 0x8ae2, 0x8af2, 0x8af4, 0x8af5, 0x8add, 0x8b14, 0x8ae4,
+    0x8adf, 0x8af0, 0x8ac8, 0x8ade, 0x8ae1, 0x8ae8, 0x8aff, 0x8aef, 0x8afb, 0x8c91, 0x8c92, 0x8c90,
+    0x8cf5, 0x8cee, 0x8cf1, 0x8cf0, 0x8cf3, 0x8d6c, 0x8d6e, 0x8da5, 0x8da7, 0x8e33, 0x8e3e, 0x8e38,
+    0x8e40, 0x8e45, 0x8e36, 0x8e3c, 0x8e3d, 0x8e41, 0x8e30, 0x8e3f, 0x8ebd, 0x8f36, 0x8f2e, 0x8f35,
+    0x8f32, 0x8f39, 0x8f37, 0x8f34, 0x9076, 0x9079, 0x907b, 0x9086, 0x90fa, 0x9133, 0x9135, 0x9136,
+    0x9193, 0x9190, 0x9191, 0x918d, 0x918f, 0x9327, 0x931e, 0x9308, 0x931f, 0x9306, 0x930f, 0x937a,
+    0x9338, 0x933c, 0x931b, 0x9323, 0x9312, 0x9301, 0x9346, 0x932d, 0x930e, 0x930d, 0x92cb, 0x931d,
+    0x92fa, 0x9325, 0x9313, 0x92f9, 0x92f7, 0x9334, 0x9302, 0x9324, 0x92ff, 0x9329, 0x9339, 0x9335,
+    0x932a, 0x9314, 0x930c, 0x930b, 0x92fe, 0x9309, 0x9300, 0x92fb, 0x9316, 0x95bc, 0x95cd, 0x95be,
+    0x95b9, 0x95ba, 0x95b6, 0x95bf, 0x95b5, 0x95bd, 0x96a9, 0x96d4, 0x970b, 0x9712, 0x9710, 0x9799,
+    0x9797, 0x9794, 0x97f0, 0x97f8, 0x9835, 0x982f, 0x9832, 0x9924, 0x991f, 0x9927, 0x9929, 0x999e,
+    0x99ee, 0x99ec, 0x99e5, 0x99e4, 0x99f0, 0x99e3, 0x99ea, 0x99e9, 0x99e7, 0x9ab9, 0x9abf, 0x9ab4,
+    0x9abb, 0x9af6, 0x9afa, 0x9af9, 0x9af7, 0x9b33, 0x9b80, 0x9b85, 0x9b87, 0x9b7c, 0x9b7e, 0x9b7b,
+    0x9b82, 0x9b93, 0x9b92, 0x9b90, 0x9b7a, 0x9b95, 0x9b7d, 0x9b88, 0x9d25, 0x9d17, 0x9d20, 0x9d1e,
+    0x9d14, 0x9d29, 0x9d1d, 0x9d18, 0x9d22, 0x9d10, 0x9d19, 0x9d1f, 0x9e88, 0x9e86, 0x9e87, 0x9eae,
+    0x9ead, 0x9ed5, 0x9ed6, 0x9efa, 0x9f12, 0x9f3d, 0x5126, 0x5125, 0x5122, 0x5124, 0x5120, 0x5129,
+    0x52f4, 0x5693, 0x568c, 0x568d, 0x5686, 0x5684, 0x5683, 0x567e, 0x5682, 0x567f, 0x5681, 0x58d6,
+    0x58d4, 0x58cf, 0x58d2, 0x5b2d, 0x5b25, 0x5b32, 0x5b23, 0x5b2c, 0x5b27, 0x5b26, 0x5b2f, 0x5b2e,
+    0x5b7b, 0x5bf1, 0x5bf2, 0x5db7, 0x5e6c, 0x5e6a, 0x5fbe, 0x5fbb, 0x61c3, 0x61b5, 0x61bc, 0x61e7,
+    0x61e0, 0x61e5, 0x61e4, 0x61e8, 0x61de, 0x64ef, 0x64e9, 0x64e3, 0x64eb, 0x64e4, 0x64e8, 0x6581,
+    0x6580, 0x65b6, 0x65da, 0x66d2, 0x6a8d, 0x6a96, 0x6a81, 0x6aa5, 0x6a89, 0x6a9f, 0x6a9b, 0x6aa1,
+    0x6a9e, 0x6a87, 0x6a93, 0x6a8e, 0x6a95, 0x6a83, 0x6aa8, 0x6aa4, 0x6a91, 0x6a7f, 0x6aa6, 0x6a9a,
+    0x6a85, 0x6a8c, 0x6a92, 0x6b5b, 0x6bad, 0x6c09, 0x6fcc, 0x6fa9, 0x6ff4, 0x6fd4, 0x6fe3, 0x6fdc,
+    0x6fed, 0x6fe7, 0x6fe6, 0x6fde, 0x6ff2, 0x6fdd, 0x6fe2, 0x6fe8, 0x71e1, 0x71f1, 0x71e8, 0x71f2,
+    0x71e4, 0x71f0, 0x71e2, 0x7373, 0x736e, 0x736f, 0x7497, 0x74b2, 0x74ab, 0x7490, 0x74aa, 0x74ad,
+    0x74b1, 0x74a5, 0x74af, 0x7510, 0x7511, 0x7512, 0x750f, 0x7584, 0x7643, 0x7648, 0x7649, 0x7647,
+    0x76a4, 0x76e9, 0x77b5, 0x77ab, 0x77b2, 0x77b7, 0x77b6, 0x77b4, 0x77b1, 0x77a8, 0x77f0, 0x78f3,
+    0x78fd, 0x7902, 0x78fb, 0x78fc, 0x78f2, 0x7905, 0x78f9, 0x78fe, 0x7904, 0x79ab, 0x79a8, 0x7a5c,
+    0x7a5b, 0x7a56, 0x7a58, 0x7a54, 0x7a5a, 0x7abe, 0x7ac0, 0x7ac1, 0x7c05, 0x7c0f, 0x7bf2, 0x7c00,
+    0x7bff, 0x7bfb, 0x7c0e, 0x7bf4, 0x7c0b, 0x7bf3, 0x7c02, 0x7c09, 0x7c03, 0x7c01, 0x7bf8, 0x7bfd,
+    0x7c06, 0x7bf0, 0x7bf1, 0x7c10, 0x7c0a, 0x7ce8, 0x7e2d, 0x7e3c, 0x7e42, 0x7e33, 0x9848, 0x7e38,
+    0x7e2a, 0x7e49, 0x7e40, 0x7e47, 0x7e29, 0x7e4c, 0x7e30, 0x7e3b, 0x7e36, 0x7e44, 0x7e3a, 0x7f45,
+    0x7f7f, 0x7f7e, 0x7f7d, 0x7ff4, 0x7ff2, 0x802c, 0x81bb, 0x81c4, 0x81cc, 0x81ca, 0x81c5, 0x81c7,
+    0x81bc, 0x81e9, 0x825b, 0x825a, 0x825c, 0x8583, 0x8580, 0x858f, 0x85a7, 0x8595, 0x85a0, 0x858b,
+    0x85a3, 0x857b, 0x85a4, 0x859a, 0x859e, 0x8577, 0x857c, 0x8589, 0x85a1, 0x857a, 0x8578, 0x8557,
+    0x858e, 0x8596, 0x8586, 0x858d, 0x8599, 0x859d, 0x8581, 0x85a2, 0x8582, 0x8588, 0x8585, 0x8579,
+    0x8576, 0x8598, 0x8590, 0x859f, 0x8668, 0x87be, 0x87aa, 0x87ad, 0x87c5, 0x87b0, 0x87ac, 0x87b9,
+    0x87b5, 0x87bc, 0x87ae, 0x87c9, 0x87c3, 0x87c2, 0x87cc, 0x87b7, 0x87af, 0x87c4, 0x87ca, 0x87b4,
+    0x87b6, 0x87bf, 0x87b8, 0x87bd, 0x87de, 0x87b2, 0x8935, 0x8933, 0x893c, 0x893e, 0x8941, 0x8952,
+    0x8937, 0x8942, 0x89ad, 0x89af, 0x89ae, 0x89f2, 0x89f3, 0x8b1e, 0x8b18, 0x8b16, 0x8b11, 0x8b05,
+    0x8b0b, 0x8b22, 0x8b0f, 0x8b12, 0x8b15, 0x8b07, 0x8b0d, 0x8b08, 0x8b06, 0x8b1c, 0x8b13, 0x8b1a,
+    0x8c4f, 0x8c70, 0x8c72, 0x8c71, 0x8c6f, 0x8c95, 0x8c94, 0x8cf9, 0x8d6f, 0x8e4e, 0x8e4d, 0x8e53,
+    0x8e50, 0x8e4c, 0x8e47, 0x8f43, 0x8f40, 0x9085, 0x907e, 0x9138, 0x919a, 0x91a2, 0x919b, 0x9199,
+    0x919f, 0x91a1, 0x919d, 0x91a0, 0x93a1, 0x9383, 0x93af, 0x9364, 0x9356, 0x9347, 0x937c, 0x9358,
+    0x935c, 0x9376, 0x9349, 0x9350, 0x9351, 0x9360, 0x936d, 0x938f, 0x934c, 0x936a, 0x9379, 0x9357,
+    0x9355, 0x9352, 0x934f, 0x9371, 0x9377, 0x937b, 0x9361, 0x935e, 0x9363, 0x9367, 0x9380, 0x934e,
+    0x9359, 0x95c7, 0x95c0, 0x95c9, 0x95c3, 0x95c5, 0x95b7, 0x96ae, 0x96b0, 0x96ac, 0x9720, 0x971f,
+    0x9718, 0x971d, 0x9719, 0x979a, 0x97a1, 0x979c, 0x979e, 0x979d, 0x97d5, 0x97d4, 0x97f1, 0x9841,
+    0x9844, 0x984a, 0x9849, 0x9845, 0x9843, 0x9925, 0x992b, 0x992c, 0x992a, 0x9933, 0x9932, 0x992f,
+    0x992d, 0x9931, 0x9930, 0x9998, 0x99a3, 0x99a1, 0x9a02, 0x99fa, 0x99f4, 0x99f7, 0x99f9, 0x99f8,
+    0x99f6, 0x99fb, 0x99fd, 0x99fe, 0x99fc, 0x9a03, 0x9abe, 0x9afe, 0x9afd, 0x9b01, 0x9afc, 0x9b48,
+    0x9b9a, 0x9ba8, 0x9b9e, 0x9b9b, 0x9ba6, 0x9ba1, 0x9ba5, 0x9ba4, 0x9b86, 0x9ba2, 0x9ba0, 0x9baf,
+    0x9d33, 0x9d41, 0x9d67, 0x9d36, 0x9d2e, 0x9d2f, 0x9d31, 0x9d38, 0x9d30, 0x9d45, 0x9d42, 0x9d43,
+    0x9d3e, 0x9d37, 0x9d40, 0x9d3d, 0x7ff5, 0x9d2d, 0x9e8a, 0x9e89, 0x9e8d, 0x9eb0, 0x9ec8, 0x9eda,
+    0x9efb, 0x9eff, 0x9f24, 0x9f23, 0x9f22, 0x9f54, 0x9fa0, 0x5131, 0x512d, 0x512e, 0x5698, 0x569c,
+    0x5697, 0x569a, 0x569d, 0x5699, 0x5970, 0x5b3c, 0x5c69, 0x5c6a, 0x5dc0, 0x5e6d, 0x5e6e, 0x61d8,
+    0x61df, 0x61ed, 0x61ee, 0x61f1, 0x61ea, 0x61f0, 0x61eb, 0x61d6, 0x61e9, 0x64ff, 0x6504, 0x64fd,
+    0x64f8, 0x6501, 0x6503, 0x64fc, 0x6594, 0x65db, 0x66da, 0x66db, 0x66d8, 0x6ac5, 0x6ab9, 0x6abd,
+    0x6ae1, 0x6ac6, 0x6aba, 0x6ab6, 0x6ab7, 0x6ac7, 0x6ab4, 0x6aad, 0x6b5e, 0x6bc9, 0x6c0b, 0x7007,
+    0x700c, 0x700d, 0x7001, 0x7005, 0x7014, 0x700e, 0x6fff, 0x7000, 0x6ffb, 0x7026, 0x6ffc, 0x6ff7,
+    0x700a, 0x7201, 0x71ff, 0x71f9, 0x7203, 0x71fd, 0x7376, 0x74b8, 0x74c0, 0x74b5, 0x74c1, 0x74be,
+    0x74b6, 0x74bb, 0x74c2, 0x7514, 0x7513, 0x765c, 0x7664, 0x7659, 0x7650, 0x7653, 0x7657, 0x765a,
+    0x76a6, 0x76bd, 0x76ec, 0x77c2, 0x77ba, 0x78ff, 0x790c, 0x7913, 0x7914, 0x7909, 0x7910, 0x7912,
+    0x7911, 0x79ad, 0x79ac, 0x7a5f, 0x7c1c, 0x7c29, 0x7c19, 0x7c20, 0x7c1f, 0x7c2d, 0x7c1d, 0x7c26,
+    0x7c28, 0x7c22, 0x7c25, 0x7c30, 0x7e5c, 0x7e50, 0x7e56, 0x7e63, 0x7e58, 0x7e62, 0x7e5f, 0x7e51,
+    0x7e60, 0x7e57, 0x7e53, 0x7fb5, 0x7fb3, 0x7ff7, 0x7ff8, 0x8075, 0x81d1, 0x81d2, 0x81d0, 0x825f,
+    0x825e, 0x85b4, 0x85c6, 0x85c0, 0x85c3, 0x85c2, 0x85b3, 0x85b5, 0x85bd, 0x85c7, 0x85c4, 0x85bf,
+    0x85cb, 0x85ce, 0x85c8, 0x85c5, 0x85b1, 0x85b6, 0x85d2, 0x8624, 0x85b8, 0x85b7, 0x85be, 0x8669,
+    0x87e7, 0x87e6, 0x87e2, 0x87db, 0x87eb, 0x87ea, 0x87e5, 0x87df, 0x87f3, 0x87e4, 0x87d4, 0x87dc,
+    0x87d3, 0x87ed, 0x87d8, 0x87e3, 0x87a4, 0x87d7, 0x87d9, 0x8801, 0x87f4, 0x87e8, 0x87dd, 0x8953,
+    0x894b, 0x894f, 0x894c, 0x8946, 0x8950, 0x8951, 0x8949, 0x8b2a, 0x8b27, 0x8b23, 0x8b33, 0x8b30,
+    0x8b35, 0x8b47, 0x8b2f, 0x8b3c, 0x8b3e, 0x8b31, 0x8b25, 0x8b37, 0x8b26, 0x8b36, 0x8b2e, 0x8b24,
+    0x8b3b, 0x8b3d, 0x8b3a, 0x8c42, 0x8c75, 0x8c99, 0x8c98, 0x8c97, 0x8cfe, 0x8d04, 0x8d02, 0x8d00,
+    0x8e5c, 0x8e62, 0x8e60, 0x8e57, 0x8e56, 0x8e5e, 0x8e65, 0x8e67, 0x8e5b, 0x8e5a, 0x8e61, 0x8e5d,
+    0x8e69, 0x8e54, 0x8f46, 0x8f47, 0x8f48, 0x8f4b, 0x9128, 0x913a, 0x913b, 0x913e, 0x91a8, 0x91a5,
+    0x91a7, 0x91af, 0x91aa, 0x93b5, 0x938c, 0x9392, 0x93b7, 0x939b, 0x939d, 0x9389, 0x93a7, 0x938e,
+    0x93aa, 0x939e, 0x93a6, 0x9395, 0x9388, 0x9399, 0x939f, 0x938d, 0x93b1, 0x9391, 0x93b2, 0x93a4,
+    0x93a8, 0x93b4, 0x93a3, 0x93a5, 0x95d2, 0x95d3, 0x95d1, 0x96b3, 0x96d7, 0x96da, 0x5dc2, 0x96df,
+    0x96d8, 0x96dd, 0x9723, 0x9722, 0x9725, 0x97ac, 0x97ae, 0x97a8, 0x97ab, 0x97a4, 0x97aa, 0x97a2,
+    0x97a5, 0x97d7, 0x97d9, 0x97d6, 0x97d8, 0x97fa, 0x9850, 0x9851, 0x9852, 0x98b8, 0x9941, 0x993c,
+    0x993a, 0x9a0f, 0x9a0b, 0x9a09, 0x9a0d, 0x9a04, 0x9a11, 0x9a0a, 0x9a05, 0x9a07, 0x9a06, 0x9ac0,
+    0x9adc, 0x9b08, 0x9b04, 0x9b05, 0x9b29, 0x9b35, 0x9b4a, 0x9b4c, 0x9b4b, 0x9bc7, 0x9bc6, 0x9bc3,
+    0x9bbf, 0x9bc1, 0x9bb5, 0x9bb8, 0x9bd3, 0x9bb6, 0x9bc4, 0x9bb9, 0x9bbd, 0x9d5c, 0x9d53, 0x9d4f,
+    0x9d4a, 0x9d5b, 0x9d4b, 0x9d59, 0x9d56, 0x9d4c, 0x9d57, 0x9d52, 0x9d54, 0x9d5f, 0x9d58, 0x9d5a,
+    0x9e8e, 0x9e8c, 0x9edf, 0x9f01, 0x9f00, 0x9f16, 0x9f25, 0x9f2b, 0x9f2a, 0x9f29, 0x9f28, 0x9f4c,
+    0x9f55, 0x5134, 0x5135, 0x5296, 0x52f7, 0x53b4, 0x56ab, 0x56ad, 0x56a6, 0x56a7, 0x56aa, 0x56ac,
+    0x58da, 0x58dd, 0x58db, 0x5912, 0x5b3d, 0x5b3e, 0x5b3f, 0x5dc3, 0x5e70, 0x5fbf, 0x61fb, 0x6507,
+    0x6510, 0x650d, 0x6509, 0x650c, 0x650e, 0x6584, 0x65de, 0x65dd, 0x66de, 0x6ae7, 0x6ae0, 0x6acc,
+    0x6ad1, 0x6ad9, 0x6acb, 0x6adf, 0x6adc, 0x6ad0, 0x6aeb, 0x6acf, 0x6acd, 0x6ade, 0x6b60, 0x6bb0,
+    0x6c0c, 0x7019, 0x7027, 0x7020, 0x7016, 0x702b, 0x7021, 0x7022, 0x7023, 0x7029, 0x7017, 0x7024,
+    0x701c, 0x702a, 0x720c, 0x720a, 0x7207, 0x7202, 0x7205, 0x72a5, 0x72a6, 0x72a4, 0x72a3, 0x72a1,
+    0x74cb, 0x74c5, 0x74b7, 0x74c3, 0x7516, 0x7660, 0x77c9, 0x77ca, 0x77c4, 0x77f1, 0x791d, 0x791b,
+    0x7921, 0x791c, 0x7917, 0x791e, 0x79b0, 0x7a67, 0x7a68, 0x7c33, 0x7c3c, 0x7c39, 0x7c2c, 0x7c3b,
+    0x7cec, 0x7cea, 0x7e76, 0x7e75, 0x7e78, 0x7e70, 0x7e77, 0x7e6f, 0x7e7a, 0x7e72, 0x7e74, 0x7e68,
+    0x7f4b, 0x7f4a, 0x7f83, 0x7f86, 0x7fb7, 0x7ffd, 0x7ffe, 0x8078, 0x81d7, 0x81d5, 0x8264, 0x8261,
+    0x8263, 0x85eb, 0x85f1, 0x85ed, 0x85d9, 0x85e1, 0x85e8, 0x85da, 0x85d7, 0x85ec, 0x85f2, 0x85f8,
+    0x85d8, 0x85df, 0x85e3, 0x85dc, 0x85d1, 0x85f0, 0x85e6, 0x85ef, 0x85de, 0x85e2, 0x8800, 0x87fa,
+    0x8803, 0x87f6, 0x87f7, 0x8809, 0x880c, 0x880b, 0x8806, 0x87fc, 0x8808, 0x87ff, 0x880a, 0x8802,
+    0x8962, 0x895a, 0x895b, 0x8957, 0x8961, 0x895c, 0x8958, 0x895d, 0x8959, 0x8988, 0x89b7, 0x89b6,
+    0x89f6, 0x8b50, 0x8b48, 0x8b4a, 0x8b40, 0x8b53, 0x8b56, 0x8b54, 0x8b4b, 0x8b55, 0x8b51, 0x8b42,
+    0x8b52, 0x8b57, 0x8c43, 0x8c77, 0x8c76, 0x8c9a, 0x8d06, 0x8d07, 0x8d09, 0x8dac, 0x8daa, 0x8dad,
+    0x8dab, 0x8e6d, 0x8e78, 0x8e73, 0x8e6a, 0x8e6f, 0x8e7b, 0x8ec2, 0x8f52, 0x8f51, 0x8f4f, 0x8f50,
+    0x8f53, 0x8fb4, 0x9140, 0x913f, 0x91b0, 0x91ad, 0x93de, 0x93c7, 0x93cf, 0x93c2, 0x93da, 0x93d0,
+    0x93f9, 0x93ec, 0x93cc, 0x93d9, 0x93a9, 0x93e6, 0x93ca, 0x93d4, 0x93ee, 0x93e3, 0x93d5, 0x93c4,
+    0x93ce, 0x93c0, 0x93d2, 0x93e7, 0x957d, 0x95da, 0x95db, 0x96e1, 0x9729, 0x972b, 0x972c, 0x9728,
+    0x9726, 0x97b3, 0x97b7, 0x97b6, 0x97dd, 0x97de, 0x97df, 0x985c, 0x9859, 0x985d, 0x9857, 0x98bf,
+    0x98bd, 0x98bb, 0x98be, 0x9948, 0x9947, 0x9943, 0x99a6, 0x99a7, 0x9a1a, 0x9a15, 0x9a25, 0x9a1d,
+    0x9a24, 0x9a1b, 0x9a22, 0x9a20, 0x9a27, 0x9a23, 0x9a1e, 0x9a1c, 0x9a14, 0x9ac2, 0x9b0b, 0x9b0a,
+    0x9b0e, 0x9b0c, 0x9b37, 0x9bea, 0x9beb, 0x9be0, 0x9bde, 0x9be4, 0x9be6, 0x9be2, 0x9bf0, 0x9bd4,
+    0x9bd7, 0x9bec, 0x9bdc, 0x9bd9, 0x9be5, 0x9bd5, 0x9be1, 0x9bda, 0x9d77, 0x9d81, 0x9d8a, 0x9d84,
+    0x9d88, 0x9d71, 0x9d80, 0x9d78, 0x9d86, 0x9d8b, 0x9d8c, 0x9d7d, 0x9d6b, 0x9d74, 0x9d75, 0x9d70,
+    0x9d69, 0x9d85, 0x9d73, 0x9d7b, 0x9d82, 0x9d6f, 0x9d79, 0x9d7f, 0x9d87, 0x9d68, 0x9e94, 0x9e91,
+    0x9ec0, 0x9efc, 0x9f2d, 0x9f40, 0x9f41, 0x9f4d, 0x9f56, 0x9f57, 0x9f58, 0x5337, 0x56b2, 0x56b5,
+    0x56b3, 0x58e3, 0x5b45, 0x5dc6, 0x5dc7, 0x5eee, 0x5eef, 0x5fc0, 0x5fc1, 0x61f9, 0x6517, 0x6516,
+    0x6515, 0x6513, 0x65df, 0x66e8, 0x66e3, 0x66e4, 0x6af3, 0x6af0, 0x6aea, 0x6ae8, 0x6af9, 0x6af1,
+    0x6aee, 0x6aef, 0x703c, 0x7035, 0x702f, 0x7037, 0x7034, 0x7031, 0x7042, 0x7038, 0x703f, 0x703a,
+    0x7039, 0x7040, 0x703b, 0x7033, 0x7041, 0x7213, 0x7214, 0x72a8, 0x737d, 0x737c, 0x74ba, 0x76ab,
+    0x76aa, 0x76be, 0x76ed, 0x77cc, 0x77ce, 0x77cf, 0x77cd, 0x77f2, 0x7925, 0x7923, 0x7927, 0x7928,
+    0x7924, 0x7929, 0x79b2, 0x7a6e, 0x7a6c, 0x7a6d, 0x7af7, 0x7c49, 0x7c48, 0x7c4a, 0x7c47, 0x7c45,
+    0x7cee, 0x7e7b, 0x7e7e, 0x7e81, 0x7e80, 0x7fba, 0x7fff, 0x8079, 0x81db, 0x81d9, 0x820b, 0x8268,
+    0x8269, 0x8622, 0x85ff, 0x8601, 0x85fe, 0x861b, 0x8600, 0x85f6, 0x8604, 0x8609, 0x8605, 0x860c,
+    0x85fd, 0x8819, 0x8810, 0x8811, 0x8817, 0x8813, 0x8816, 0x8963, 0x8966, 0x89b9, 0x89f7, 0x8b60,
+    0x8b6a, 0x8b5d, 0x8b68, 0x8b63, 0x8b65, 0x8b67, 0x8b6d, 0x8dae, 0x8e86, 0x8e88, 0x8e84, 0x8f59,
+    0x8f56, 0x8f57, 0x8f55, 0x8f58, 0x8f5a, 0x908d, 0x9143, 0x9141, 0x91b7, 0x91b5, 0x91b2, 0x91b3,
+    0x940b, 0x9413, 0x93fb, 0x9420, 0x940f, 0x9414, 0x93fe, 0x9415, 0x9410, 0x9428, 0x9419, 0x940d,
+    0x93f5, 0x9400, 0x93f7, 0x9407, 0x940e, 0x9416, 0x9412, 0x93fa, 0x9409, 0x93f8, 0x940a, 0x93ff,
+    0x93fc, 0x940c, 0x93f6, 0x9411, 0x9406, 0x95de, 0x95e0, 0x95df, 0x972e, 0x972f, 0x97b9, 0x97bb,
+    0x97fd, 0x97fe, 0x9860, 0x9862, 0x9863, 0x985f, 0x98c1, 0x98c2, 0x9950, 0x994e, 0x9959, 0x994c,
+    0x994b, 0x9953, 0x9a32, 0x9a34, 0x9a31, 0x9a2c, 0x9a2a, 0x9a36, 0x9a29, 0x9a2e, 0x9a38, 0x9a2d,
+    0x9ac7, 0x9aca, 0x9ac6, 0x9b10, 0x9b12, 0x9b11, 0x9c0b, 0x9c08, 0x9bf7, 0x9c05, 0x9c12, 0x9bf8,
+    0x9c40, 0x9c07, 0x9c0e, 0x9c06, 0x9c17, 0x9c14, 0x9c09, 0x9d9f, 0x9d99, 0x9da4, 0x9d9d, 0x9d92,
+    0x9d98, 0x9d90, 0x9d9b, 0x9da0, 0x9d94, 0x9d9c, 0x9daa, 0x9d97, 0x9da1, 0x9d9a, 0x9da2, 0x9da8,
+    0x9d9e, 0x9da3, 0x9dbf, 0x9da9, 0x9d96, 0x9da6, 0x9da7, 0x9e99, 0x9e9b, 0x9e9a, 0x9ee5, 0x9ee4,
+    0x9ee7, 0x9ee6, 0x9f30, 0x9f2e, 0x9f5b, 0x9f60, 0x9f5e, 0x9f5d, 0x9f59, 0x9f91, 0x513a, 0x5139,
+    0x5298, 0x5297, 0x56c3, 0x56bd, 0x56be, 0x5b48, 0x5b47, 0x5dcb, 0x5dcf, 0x5ef1, 0x61fd, 0x651b,
+    0x6b02, 0x6afc, 0x6b03, 0x6af8, 0x6b00, 0x7043, 0x7044, 0x704a, 0x7048, 0x7049, 0x7045, 0x7046,
+    0x721d, 0x721a, 0x7219, 0x737e, 0x7517, 0x766a, 0x77d0, 0x792d, 0x7931, 0x792f, 0x7c54, 0x7c53,
+    0x7cf2, 0x7e8a, 0x7e87, 0x7e88, 0x7e8b, 0x7e86, 0x7e8d, 0x7f4d, 0x7fbb, 0x8030, 0x81dd, 0x8618,
+    0x862a, 0x8626, 0x861f, 0x8623, 0x861c, 0x8619, 0x8627, 0x862e, 0x8621, 0x8620, 0x8629, 0x861e,
+    0x8625, 0x8829, 0x881d, 0x881b, 0x8820, 0x8824, 0x881c, 0x882b, 0x884a, 0x896d, 0x8969, 0x896e,
+    0x896b, 0x89fa, 0x8b79, 0x8b78, 0x8b45, 0x8b7a, 0x8b7b, 0x8d10, 0x8d14, 0x8daf, 0x8e8e, 0x8e8c,
+    0x8f5e, 0x8f5b, 0x8f5d, 0x9146, 0x9144, 0x9145, 0x91b9, 0x943f, 0x943b, 0x9436, 0x9429, 0x943d,
+    0x943c, 0x9430, 0x9439, 0x942a, 0x9437, 0x942c, 0x9440, 0x9431, 0x95e5, 0x95e4, 0x95e3, 0x9735,
+    0x973a, 0x97bf, 0x97e1, 0x9864, 0x98c9, 0x98c6, 0x98c0, 0x9958, 0x9956, 0x9a39, 0x9a3d, 0x9a46,
+    0x9a44, 0x9a42, 0x9a41, 0x9a3a, 0x9a3f, 0x9acd, 0x9b15, 0x9b17, 0x9b18, 0x9b16, 0x9b3a, 0x9b52,
+    0x9c2b, 0x9c1d, 0x9c1c, 0x9c2c, 0x9c23, 0x9c28, 0x9c29, 0x9c24, 0x9c21, 0x9db7, 0x9db6, 0x9dbc,
+    0x9dc1, 0x9dc7, 0x9dca, 0x9dcf, 0x9dbe, 0x9dc5, 0x9dc3, 0x9dbb, 0x9db5, 0x9dce, 0x9db9, 0x9dba,
+    0x9dac, 0x9dc8, 0x9db1, 0x9dad, 0x9dcc, 0x9db3, 0x9dcd, 0x9db2, 0x9e7a, 0x9e9c, 0x9eeb, 0x9eee,
+    0x9eed, 0x9f1b, 0x9f18, 0x9f1a, 0x9f31, 0x9f4e, 0x9f65, 0x9f64, 0x9f92, 0x4eb9, 0x56c6, 0x56c5,
+    0x56cb, 0x5971, 0x5b4b, 0x5b4c, 0x5dd5, 0x5dd1, 0x5ef2, 0x6521, 0x6520, 0x6526, 0x6522, 0x6b0b,
+    0x6b08, 0x6b09, 0x6c0d, 0x7055, 0x7056, 0x7057, 0x7052, 0x721e, 0x721f, 0x72a9, 0x737f, 0x74d8,
+    0x74d5, 0x74d9, 0x74d7, 0x766d, 0x76ad, 0x7935, 0x79b4, 0x7a70, 0x7a71, 0x7c57, 0x7c5c, 0x7c59,
+    0x7c5b, 0x7c5a, 0x7cf4, 0x7cf1, 0x7e91, 0x7f4f, 0x7f87, 0x81de, 0x826b, 0x8634, 0x8635, 0x8633,
+    0x862c, 0x8632, 0x8636, 0x882c, 0x8828, 0x8826, 0x882a, 0x8825, 0x8971, 0x89bf, 0x89be, 0x89fb,
+    0x8b7e, 0x8b84, 0x8b82, 0x8b86, 0x8b85, 0x8b7f, 0x8d15, 0x8e95, 0x8e94, 0x8e9a, 0x8e92, 0x8e90,
+    0x8e96, 0x8e97, 0x8f60, 0x8f62, 0x9147, 0x944c, 0x9450, 0x944a, 0x944b, 0x944f, 0x9447, 0x9445,
+    0x9448, 0x9449, 0x9446, 0x973f, 0x97e3, 0x986a, 0x9869, 0x98cb, 0x9954, 0x995b, 0x9a4e, 0x9a53,
+    0x9a54, 0x9a4c, 0x9a4f, 0x9a48, 0x9a4a, 0x9a49, 0x9a52, 0x9a50, 0x9ad0, 0x9b19, 0x9b2b, 0x9b3b,
+    0x9b56, 0x9b55, 0x9c46, 0x9c48, 0x9c3f, 0x9c44, 0x9c39, 0x9c33, 0x9c41, 0x9c3c, 0x9c37, 0x9c34,
+    0x9c32, 0x9c3d, 0x9c36, 0x9ddb, 0x9dd2, 0x9dde, 0x9dda, 0x9dcb, 0x9dd0, 0x9ddc, 0x9dd1, 0x9ddf,
+    0x9de9, 0x9dd9, 0x9dd8, 0x9dd6, 0x9df5, 0x9dd5, 0x9ddd, 0x9eb6, 0x9ef0, 0x9f35, 0x9f33, 0x9f32,
+    0x9f42, 0x9f6b, 0x9f95, 0x9fa2, 0x513d, 0x5299, 0x58e8, 0x58e7, 0x5972, 0x5b4d, 0x5dd8, 0x882f,
+    0x5f4f, 0x6201, 0x6203, 0x6204, 0x6529, 0x6525, 0x6596, 0x66eb, 0x6b11, 0x6b12, 0x6b0f, 0x6bca,
+    0x705b, 0x705a, 0x7222, 0x7382, 0x7381, 0x7383, 0x7670, 0x77d4, 0x7c67, 0x7c66, 0x7e95, 0x826c,
+    0x863a, 0x8640, 0x8639, 0x863c, 0x8631, 0x863b, 0x863e, 0x8830, 0x8832, 0x882e, 0x8833, 0x8976,
+    0x8974, 0x8973, 0x89fe, 0x8b8c, 0x8b8e, 0x8b8b, 0x8b88, 0x8c45, 0x8d19, 0x8e98, 0x8f64, 0x8f63,
+    0x91bc, 0x9462, 0x9455, 0x945d, 0x9457, 0x945e, 0x97c4, 0x97c5, 0x9800, 0x9a56, 0x9a59, 0x9b1e,
+    0x9b1f, 0x9b20, 0x9c52, 0x9c58, 0x9c50, 0x9c4a, 0x9c4d, 0x9c4b, 0x9c55, 0x9c59, 0x9c4c, 0x9c4e,
+    0x9dfb, 0x9df7, 0x9def, 0x9de3, 0x9deb, 0x9df8, 0x9de4, 0x9df6, 0x9de1, 0x9dee, 0x9de6, 0x9df2,
+    0x9df0, 0x9de2, 0x9dec, 0x9df4, 0x9df3, 0x9de8, 0x9ded, 0x9ec2, 0x9ed0, 0x9ef2, 0x9ef3, 0x9f06,
+    0x9f1c, 0x9f38, 0x9f37, 0x9f36, 0x9f43, 0x9f4f, 0x9f71, 0x9f70, 0x9f6e, 0x9f6f, 0x56d3, 0x56cd,
+    0x5b4e, 0x5c6d, 0x652d, 0x66ed, 0x66ee, 0x6b13, 0x705f, 0x7061, 0x705d, 0x7060, 0x7223, 0x74db,
+    0x74e5, 0x77d5, 0x7938, 0x79b7, 0x79b6, 0x7c6a, 0x7e97, 0x7f89, 0x826d, 0x8643, 0x8838, 0x8837,
+    0x8835, 0x884b, 0x8b94, 0x8b95, 0x8e9e, 0x8e9f, 0x8ea0, 0x8e9d, 0x91be, 0x91bd, 0x91c2, 0x946b,
+    0x9468, 0x9469, 0x96e5, 0x9746, 0x9743, 0x9747, 0x97c7, 0x97e5, 0x9a5e, 0x9ad5, 0x9b59, 0x9c63,
+    0x9c67, 0x9c66, 0x9c62, 0x9c5e, 0x9c60, 0x9e02, 0x9dfe, 0x9e07, 0x9e03, 0x9e06, 0x9e05, 0x9e00,
+    0x9e01, 0x9e09, 0x9dff, 0x9dfd, 0x9e04, 0x9ea0, 0x9f1e, 0x9f46, 0x9f74, 0x9f75, 0x9f76, 0x56d4,
+    0x652e, 0x65b8, 0x6b18, 0x6b19, 0x6b17, 0x6b1a, 0x7062, 0x7226, 0x72aa, 0x77d8, 0x77d9, 0x7939,
+    0x7c69, 0x7c6b, 0x7cf6, 0x7e9a, 0x7e98, 0x7e9b, 0x7e99, 0x81e0, 0x81e1, 0x8646, 0x8647, 0x8648,
+    0x8979, 0x897a, 0x897c, 0x897b, 0x89ff, 0x8b98, 0x8b99, 0x8ea5, 0x8ea4, 0x8ea3, 0x946e, 0x946d,
+    0x946f, 0x9471, 0x9473, 0x9749, 0x9872, 0x995f, 0x9c68, 0x9c6e, 0x9c6d, 0x9e0b, 0x9e0d, 0x9e10,
+    0x9e0f, 0x9e12, 0x9e11, 0x9ea1, 0x9ef5, 0x9f09, 0x9f47, 0x9f78, 0x9f7b, 0x9f7a, 0x9f79, 0x571e,
+    0x7066, 0x7c6f, 0x883c, 0x8db2, 0x8ea6, 0x91c3, 0x9474, 0x9478, 0x9476, 0x9475, 0x9a60, 0x9c74,
+    0x9c73, 0x9c71, 0x9c75, 0x9e14, 0x9e13, 0x9ef6, 0x9f0a, 0x9fa4, 0x7068, 0x7065, 0x7cf7, 0x866a,
+    0x883e, 0x883d, 0x883f, 0x8b9e, 0x8c9c, 0x8ea9, 0x8ec9, 0x974b, 0x9873, 0x9874, 0x98cc, 0x9961,
+    0x99ab, 0x9a64, 0x9a66, 0x9a67, 0x9b24, 0x9e15, 0x9e17, 0x9f48, 0x6207, 0x6b1e, 0x7227, 0x864c,
+    0x8ea8, 0x9482, 0x9480, 0x9481, 0x9a69, 0x9a68, 0x9b2e, 0x9e19, 0x7229, 0x864b, 0x8b9f, 0x9483,
+    0x9c79, 0x9eb7, 0x7675, 0x9a6b, 0x9c7a, 0x9e1d, 0x7069, 0x706a, 0x9ea4, 0x9f7e, 0x9f49, 0x9f98,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+];
+
+/// `(code point, index into the grid)`, sorted by code point.
+pub(super) static BIG5_INVERSE: [(u16, u16); 13706] = [
+    (0x00a2, 0x00a3),
+    (0x00a3, 0x00a4),
+    (0x00a5, 0x00a1),
+    (0x00a7, 0x004f),
+    (0x00b0, 0x00b5),
+    (0x00b1, 0x0071),
+    (0x00b7, 0x0010),
+    (0x00d7, 0x006f),
+    (0x00f7, 0x0070),
+    (0x02c7, 0x0196),
+    (0x02c9, 0x0194),
+    (0x02ca, 0x0195),
+    (0x02cb, 0x0197),
+    (0x02cd, 0x0063),
+    (0x02d9, 0x0193),
+    (0x0391, 0x013e),
+    (0x0392, 0x013f),
+    (0x0393, 0x0140),
+    (0x0394, 0x0141),
+    (0x0395, 0x0142),
+    (0x0396, 0x0143),
+    (0x0397, 0x0144),
+    (0x0398, 0x0145),
+    (0x0399, 0x0146),
+    (0x039a, 0x0147),
+    (0x039b, 0x0148),
+    (0x039c, 0x0149),
+    (0x039d, 0x014a),
+    (0x039e, 0x014b),
+    (0x039f, 0x014c),
+    (0x03a0, 0x014d),
+    (0x03a1, 0x014e),
+    (0x03a3, 0x014f),
+    (0x03a4, 0x0150),
+    (0x03a5, 0x0151),
+    (0x03a6, 0x0152),
+    (0x03a7, 0x0153),
+    (0x03a8, 0x0154),
+    (0x03a9, 0x0155),
+    (0x03b1, 0x0156),
+    (0x03b2, 0x0157),
+    (0x03b3, 0x0158),
+    (0x03b4, 0x0159),
+    (0x03b5, 0x015a),
+    (0x03b6, 0x015b),
+    (0x03b7, 0x015c),
+    (0x03b8, 0x015d),
+    (0x03b9, 0x015e),
+    (0x03ba, 0x015f),
+    (0x03bb, 0x0160),
+    (0x03bc, 0x0161),
+    (0x03bd, 0x0162),
+    (0x03be, 0x0163),
+    (0x03bf, 0x0164),
+    (0x03c0, 0x0165),
+    (0x03c1, 0x0166),
+    (0x03c3, 0x0167),
+    (0x03c4, 0x0168),
+    (0x03c5, 0x0169),
+    (0x03c6, 0x016a),
+    (0x03c7, 0x016b),
+    (0x03c8, 0x016c),
+    (0x03c9, 0x016d),
+    (0x0401, 0x179f),
+    (0x0414, 0x179d),
+    (0x0415, 0x179e),
+    (0x0416, 0x17a0),
+    (0x0417, 0x17a1),
+    (0x0418, 0x17a2),
+    (0x0419, 0x17a3),
+    (0x041a, 0x17a4),
+    (0x041b, 0x17a5),
+    (0x041c, 0x17a6),
+    (0x0423, 0x17a7),
+    (0x0424, 0x17a8),
+    (0x0425, 0x17a9),
+    (0x0426, 0x17aa),
+    (0x0427, 0x17ab),
+    (0x0428, 0x17ac),
+    (0x0429, 0x17ad),
+    (0x042a, 0x17ae),
+    (0x042b, 0x17af),
+    (0x042c, 0x17b0),
+    (0x042d, 0x17b1),
+    (0x042e, 0x17b2),
+    (0x042f, 0x17b3),
+    (0x0430, 0x17b4),
+    (0x0431, 0x17b5),
+    (0x0432, 0x17b6),
+    (0x0433, 0x17b7),
+    (0x0434, 0x17b8),
+    (0x0435, 0x17b9),
+    (0x0436, 0x17bb),
+    (0x0437, 0x17bc),
+    (0x0438, 0x17bd),
+    (0x0439, 0x17be),
+    (0x043a, 0x17bf),
+    (0x043b, 0x17c0),
+    (0x043c, 0x17c1),
+    (0x043d, 0x17c2),
+    (0x043e, 0x17c3),
+    (0x043f, 0x17c4),
+    (0x0440, 0x17c5),
+    (0x0441, 0x17c6),
+    (0x0442, 0x17c7),
+    (0x0443, 0x17c8),
+    (0x0444, 0x17c9),
+    (0x0445, 0x17ca),
+    (0x0446, 0x17cb),
+    (0x0447, 0x17cc),
+    (0x0448, 0x17cd),
+    (0x0449, 0x17ce),
+    (0x044a, 0x17cf),
+    (0x044b, 0x17d0),
+    (0x044c, 0x17d1),
+    (0x044d, 0x17d2),
+    (0x044e, 0x17d3),
+    (0x044f, 0x17d4),
+    (0x0451, 0x17ba),
+    (0x2013, 0x0016),
+    (0x2014, 0x0018),
+    (0x2018, 0x0043),
+    (0x2019, 0x0044),
+    (0x201c, 0x0045),
+    (0x201d, 0x0046),
+    (0x2022, 0x0005),
+    (0x2025, 0x000c),
+    (0x2026, 0x000b),
+    (0x2032, 0x004a),
+    (0x2035, 0x0049),
+    (0x203b, 0x004e),
+    (0x203e, 0x0060),
+    (0x2103, 0x00a7),
+    (0x2105, 0x005f),
+    (0x2109, 0x00a8),
+    (0x2160, 0x00f4),
+    (0x2161, 0x00f5),
+    (0x2162, 0x00f6),
+    (0x2163, 0x00f7),
+    (0x2164, 0x00f8),
+    (0x2165, 0x00f9),
+    (0x2166, 0x00fa),
+    (0x2167, 0x00fb),
+    (0x2168, 0x00fc),
+    (0x2169, 0x00fd),
+    (0x2190, 0x0094),
+    (0x2191, 0x0092),
+    (0x2192, 0x0095),
+    (0x2193, 0x0093),
+    (0x2196, 0x0096),
+    (0x2197, 0x0097),
+    (0x2198, 0x0099),
+    (0x2199, 0x0098),
+    (0x221a, 0x0072),
+    (0x221e, 0x0079),
+    (0x221f, 0x0086),
+    (0x2220, 0x0085),
+    (0x2223, 0x009b),
+    (0x2225, 0x009a),
+    (0x2229, 0x0082),
+    (0x222a, 0x0083),
+    (0x222b, 0x008a),
+    (0x222e, 0x008b),
+    (0x2234, 0x008d),
+    (0x2235, 0x008c),
+    (0x223c, 0x0081),
+    (0x2252, 0x007a),
+    (0x2260, 0x0078),
+    (0x2261, 0x007b),
+    (0x2266, 0x0076),
+    (0x2267, 0x0077),
+    (0x22a5, 0x0084),
+    (0x22bf, 0x0087),
+    (0x2460, 0x17d5),
+    (0x2461, 0x17d6),
+    (0x2462, 0x17d7),
+    (0x2463, 0x17d8),
+    (0x2464, 0x17d9),
+    (0x2465, 0x17da),
+    (0x2466, 0x17db),
+    (0x2467, 0x17dc),
+    (0x2468, 0x17dd),
+    (0x2469, 0x17de),
+    (0x2474, 0x17df),
+    (0x2475, 0x17e0),
+    (0x2476, 0x17e1),
+    (0x2477, 0x17e2),
+    (0x2478, 0x17e3),
+    (0x2479, 0x17e4),
+    (0x247a, 0x17e5),
+    (0x247b, 0x17e6),
+    (0x247c, 0x17e7),
+    (0x247d, 0x17e8),
+    (0x2500, 0x00d4),
+    (0x2502, 0x00d5),
+    (0x250c, 0x00d7),
+    (0x2510, 0x00d8),
+    (0x2514, 0x00d9),
+    (0x2518, 0x00da),
+    (0x251c, 0x00d2),
+    (0x2524, 0x00d1),
+    (0x252c, 0x00d0),
+    (0x2534, 0x00cf),
+    (0x253c, 0x00ce),
+    (0x2550, 0x00df),
+    (0x255e, 0x00e0),
+    (0x2561, 0x00e2),
+    (0x256a, 0x00e1),
+    (0x256d, 0x00db),
+    (0x256e, 0x00dc),
+    (0x256f, 0x00de),
+    (0x2570, 0x00dd),
+    (0x2571, 0x00e7),
+    (0x2572, 0x00e8),
+    (0x2573, 0x00e9),
+    (0x2574, 0x001a),
+    (0x2581, 0x00bf),
+    (0x2582, 0x00c0),
+    (0x2583, 0x00c1),
+    (0x2584, 0x00c2),
+    (0x2585, 0x00c3),
+    (0x2586, 0x00c4),
+    (0x2587, 0x00c5),
+    (0x2588, 0x00c6),
+    (0x2589, 0x00cd),
+    (0x258a, 0x00cc),
+    (0x258b, 0x00cb),
+    (0x258c, 0x00ca),
+    (0x258d, 0x00c9),
+    (0x258e, 0x00c8),
+    (0x258f, 0x00c7),
+    (0x2594, 0x00d3),
+    (0x2595, 0x00d6),
+    (0x25a0, 0x005b),
+    (0x25a1, 0x005a),
+    (0x25b2, 0x0054),
+    (0x25b3, 0x0053),
+    (0x25bc, 0x005d),
+    (0x25bd, 0x005c),
+    (0x25c6, 0x0059),
+    (0x25c7, 0x0058),
+    (0x25cb, 0x0051),
+    (0x25ce, 0x0055),
+    (0x25cf, 0x0052),
+    (0x25e2, 0x00e3),
+    (0x25e3, 0x00e4),
+    (0x25e4, 0x00e6),
+    (0x25e5, 0x00e5),
+    (0x2605, 0x0057),
+    (0x2606, 0x0056),
+    (0x2609, 0x0091),
+    (0x2640, 0x008e),
+    (0x2641, 0x0090),
+    (0x2642, 0x008f),
+    (0x3000, 0x0000),
+    (0x3001, 0x0002),
+    (0x3002, 0x0003),
+    (0x3003, 0x0050),
+    (0x3005, 0x16f3),
+    (0x3008, 0x0031),
+    (0x3009, 0x0032),
+    (0x300a, 0x002d),
+    (0x300b, 0x002e),
+    (0x300c, 0x0035),
+    (0x300d, 0x0036),
+    (0x300e, 0x0039),
+    (0x300f, 0x003a),
+    (0x3010, 0x0029),
+    (0x3011, 0x002a),
+    (0x3012, 0x00a2),
+    (0x3014, 0x0025),
+    (0x3015, 0x0026),
+    (0x301d, 0x0047),
+    (0x301e, 0x0048),
+    (0x3021, 0x00fe),
+    (0x3022, 0x00ff),
+    (0x3023, 0x0100),
+    (0x3024, 0x0101),
+    (0x3025, 0x0102),
+    (0x3026, 0x0103),
+    (0x3027, 0x0104),
+    (0x3028, 0x0105),
+    (0x3029, 0x0106),
+    (0x3041, 0x16f4),
+    (0x3042, 0x16f5),
+    (0x3043, 0x16f6),
+    (0x3044, 0x16f7),
+    (0x3045, 0x16f8),
+    (0x3046, 0x16f9),
+    (0x3047, 0x16fa),
+    (0x3048, 0x16fb),
+    (0x3049, 0x16fc),
+    (0x304a, 0x16fd),
+    (0x304b, 0x16fe),
+    (0x304c, 0x16ff),
+    (0x304d, 0x1700),
+    (0x304e, 0x1701),
+    (0x304f, 0x1702),
+    (0x3050, 0x1703),
+    (0x3051, 0x1704),
+    (0x3052, 0x1705),
+    (0x3053, 0x1706),
+    (0x3054, 0x1707),
+    (0x3055, 0x1708),
+    (0x3056, 0x1709),
+    (0x3057, 0x170a),
+    (0x3058, 0x170b),
+    (0x3059, 0x170c),
+    (0x305a, 0x170d),
+    (0x305b, 0x170e),
+    (0x305c, 0x170f),
+    (0x305d, 0x1710),
+    (0x305e, 0x1711),
+    (0x305f, 0x1712),
+    (0x3060, 0x1713),
+    (0x3061, 0x1714),
+    (0x3062, 0x1715),
+    (0x3063, 0x1716),
+    (0x3064, 0x1717),
+    (0x3065, 0x1718),
+    (0x3066, 0x1719),
+    (0x3067, 0x171a),
+    (0x3068, 0x171b),
+    (0x3069, 0x171c),
+    (0x306a, 0x171d),
+    (0x306b, 0x171e),
+    (0x306c, 0x171f),
+    (0x306d, 0x1720),
+    (0x306e, 0x1721),
+    (0x306f, 0x1722),
+    (0x3070, 0x1723),
+    (0x3071, 0x1724),
+    (0x3072, 0x1725),
+    (0x3073, 0x1726),
+    (0x3074, 0x1727),
+    (0x3075, 0x1728),
+    (0x3076, 0x1729),
+    (0x3077, 0x172a),
+    (0x3078, 0x172b),
+    (0x3079, 0x172c),
+    (0x307a, 0x172d),
+    (0x307b, 0x172e),
+    (0x307c, 0x172f),
+    (0x307d, 0x1730),
+    (0x307e, 0x1731),
+    (0x307f, 0x1732),
+    (0x3080, 0x1733),
+    (0x3081, 0x1734),
+    (0x3082, 0x1735),
+    (0x3083, 0x1736),
+    (0x3084, 0x1737),
+    (0x3085, 0x1738),
+    (0x3086, 0x1739),
+    (0x3087, 0x173a),
+    (0x3088, 0x173b),
+    (0x3089, 0x173c),
+    (0x308a, 0x173d),
+    (0x308b, 0x173e),
+    (0x308c, 0x173f),
+    (0x308d, 0x1740),
+    (0x308e, 0x1741),
+    (0x308f, 0x1742),
+    (0x3090, 0x1743),
+    (0x3091, 0x1744),
+    (0x3092, 0x1745),
+    (0x3093, 0x1746),
+    (0x309d, 0x16f1),
+    (0x309e, 0x16f2),
+    (0x30a1, 0x1747),
+    (0x30a2, 0x1748),
+    (0x30a3, 0x1749),
+    (0x30a4, 0x174a),
+    (0x30a5, 0x174b),
+    (0x30a6, 0x174c),
+    (0x30a7, 0x174d),
+    (0x30a8, 0x174e),
+    (0x30a9, 0x174f),
+    (0x30aa, 0x1750),
+    (0x30ab, 0x1751),
+    (0x30ac, 0x1752),
+    (0x30ad, 0x1753),
+    (0x30ae, 0x1754),
+    (0x30af, 0x1755),
+    (0x30b0, 0x1756),
+    (0x30b1, 0x1757),
+    (0x30b2, 0x1758),
+    (0x30b3, 0x1759),
+    (0x30b4, 0x175a),
+    (0x30b5, 0x175b),
+    (0x30b6, 0x175c),
+    (0x30b7, 0x175d),
+    (0x30b8, 0x175e),
+    (0x30b9, 0x175f),
+    (0x30ba, 0x1760),
+    (0x30bb, 0x1761),
+    (0x30bc, 0x1762),
+    (0x30bd, 0x1763),
+    (0x30be, 0x1764),
+    (0x30bf, 0x1765),
+    (0x30c0, 0x1766),
+    (0x30c1, 0x1767),
+    (0x30c2, 0x1768),
+    (0x30c3, 0x1769),
+    (0x30c4, 0x176a),
+    (0x30c5, 0x176b),
+    (0x30c6, 0x176c),
+    (0x30c7, 0x176d),
+    (0x30c8, 0x176e),
+    (0x30c9, 0x176f),
+    (0x30ca, 0x1770),
+    (0x30cb, 0x1771),
+    (0x30cc, 0x1772),
+    (0x30cd, 0x1773),
+    (0x30ce, 0x1774),
+    (0x30cf, 0x1775),
+    (0x30d0, 0x1776),
+    (0x30d1, 0x1777),
+    (0x30d2, 0x1778),
+    (0x30d3, 0x1779),
+    (0x30d4, 0x177a),
+    (0x30d5, 0x177b),
+    (0x30d6, 0x177c),
+    (0x30d7, 0x177d),
+    (0x30d8, 0x177e),
+    (0x30d9, 0x177f),
+    (0x30da, 0x1780),
+    (0x30db, 0x1781),
+    (0x30dc, 0x1782),
+    (0x30dd, 0x1783),
+    (0x30de, 0x1784),
+    (0x30df, 0x1785),
+    (0x30e0, 0x1786),
+    (0x30e1, 0x1787),
+    (0x30e2, 0x1788),
+    (0x30e3, 0x1789),
+    (0x30e4, 0x178a),
+    (0x30e5, 0x178b),
+    (0x30e6, 0x178c),
+    (0x30e7, 0x178d),
+    (0x30e8, 0x178e),
+    (0x30e9, 0x178f),
+    (0x30ea, 0x1790),
+    (0x30eb, 0x1791),
+    (0x30ec, 0x1792),
+    (0x30ed, 0x1793),
+    (0x30ee, 0x1794),
+    (0x30ef, 0x1795),
+    (0x30f0, 0x1796),
+    (0x30f1, 0x1797),
+    (0x30f2, 0x1798),
+    (0x30f3, 0x1799),
+    (0x30f4, 0x179a),
+    (0x30f5, 0x179b),
+    (0x30f6, 0x179c),
+    (0x30fe, 0x16f0),
+    (0x3105, 0x016e),
+    (0x3106, 0x016f),
+    (0x3107, 0x0170),
+    (0x3108, 0x0171),
+    (0x3109, 0x0172),
+    (0x310a, 0x0173),
+    (0x310b, 0x0174),
+    (0x310c, 0x0175),
+    (0x310d, 0x0176),
+    (0x310e, 0x0177),
+    (0x310f, 0x0178),
+    (0x3110, 0x0179),
+    (0x3111, 0x017a),
+    (0x3112, 0x017b),
+    (0x3113, 0x017c),
+    (0x3114, 0x017d),
+    (0x3115, 0x017e),
+    (0x3116, 0x017f),
+    (0x3117, 0x0180),
+    (0x3118, 0x0181),
+    (0x3119, 0x0182),
+    (0x311a, 0x0183),
+    (0x311b, 0x0184),
+    (0x311c, 0x0185),
+    (0x311d, 0x0186),
+    (0x311e, 0x0187),
+    (0x311f, 0x0188),
+    (0x3120, 0x0189),
+    (0x3121, 0x018a),
+    (0x3122, 0x018b),
+    (0x3123, 0x018c),
+    (0x3124, 0x018d),
+    (0x3125, 0x018e),
+    (0x3126, 0x018f),
+    (0x3127, 0x0190),
+    (0x3128, 0x0191),
+    (0x3129, 0x0192),
+    (0x32a3, 0x005e),
+    (0x338e, 0x00b2),
+    (0x338f, 0x00b3),
+    (0x339c, 0x00ad),
+    (0x339d, 0x00ae),
+    (0x339e, 0x00af),
+    (0x33a1, 0x00b1),
+    (0x33c4, 0x00b4),
+    (0x33ce, 0x00b0),
+    (0x33d1, 0x0089),
+    (0x33d2, 0x0088),
+    (0x33d5, 0x00ac),
+    (0x4e00, 0x01d7),
+    (0x4e01, 0x01d9),
+    (0x4e03, 0x01da),
+    (0x4e07, 0x188d),
+    (0x4e08, 0x01ed),
+    (0x4e09, 0x01eb),
+    (0x4e0a, 0x01ee),
+    (0x4e0b, 0x01ec),
+    (0x4e0c, 0x188e),
+    (0x4e0d, 0x0218),
+    (0x4e0e, 0x1897),
+    (0x4e0f, 0x1895),
+    (0x4e10, 0x0217),
+    (0x4e11, 0x0216),
+    (0x4e14, 0x0276),
+    (0x4e15, 0x0275),
+    (0x4e16, 0x0274),
+    (0x4e18, 0x0277),
+    (0x4e19, 0x0273),
+    (0x4e1e, 0x02f2),
+    (0x4e1f, 0x02f3),
+    (0x4e26, 0x04ac),
+    (0x4e2b, 0x01ef),
+    (0x4e2d, 0x0219),
+    (0x4e2e, 0x1898),
+    (0x4e30, 0x021a),
+    (0x4e31, 0x18ab),
+    (0x4e32, 0x0399),
+    (0x4e33, 0x1a11),
+    (0x4e38, 0x01f0),
+    (0x4e39, 0x021b),
+    (0x4e3b, 0x0278),
+    (0x4e3c, 0x18ac),
+    (0x4e42, 0x1888),
+    (0x4e43, 0x01db),
+    (0x4e45, 0x01f2),
+    (0x4e47, 0x188f),
+    (0x4e48, 0x01f3),
+    (0x4e4b, 0x021c),
+    (0x4e4d, 0x0279),
+    (0x4e4e, 0x027b),
+    (0x4e4f, 0x027a),
+    (0x4e52, 0x02f4),
+    (0x4e53, 0x02f5),
+    (0x4e56, 0x04ad),
+    (0x4e58, 0x07b6),
+    (0x4e59, 0x01d8),
+    (0x4e5c, 0x1889),
+    (0x4e5d, 0x01dc),
+    (0x4e5e, 0x01f5),
+    (0x4e5f, 0x01f4),
+    (0x4e69, 0x02f6),
+    (0x4e73, 0x04ae),
+    (0x4e7e, 0x097f),
+    (0x4e7f, 0x1f52),
+    (0x4e82, 0x0d42),
+    (0x4e83, 0x247e),
+    (0x4e84, 0x247f),
+    (0x4e86, 0x01dd),
+    (0x4e88, 0x021e),
+    (0x4e8b, 0x04af),
+    (0x4e8c, 0x01de),
+    (0x4e8d, 0x1890),
+    (0x4e8e, 0x01f6),
+    (0x4e91, 0x021f),
+    (0x4e92, 0x0221),
+    (0x4e93, 0x1899),
+    (0x4e94, 0x0222),
+    (0x4e95, 0x0220),
+    (0x4e99, 0x02f7),
+    (0x4e9b, 0x04b0),
+    (0x4e9e, 0x04b1),
+    (0x4e9f, 0x0627),
+    (0x4ea1, 0x01f7),
+    (0x4ea2, 0x0223),
+    (0x4ea4, 0x02f8),
+    (0x4ea5, 0x02fa),
+    (0x4ea6, 0x02f9),
+    (0x4ea8, 0x039a),
+    (0x4eab, 0x04b2),
+    (0x4eac, 0x04b3),
+    (0x4ead, 0x0628),
+    (0x4eae, 0x0629),
+    (0x4eb3, 0x07b7),
+    (0x4eb6, 0x2480),
+    (0x4eb9, 0x3495),
+    (0x4eba, 0x01df),
+    (0x4ec0, 0x0225),
+    (0x4ec1, 0x0224),
+    (0x4ec2, 0x189a),
+    (0x4ec3, 0x0226),
+    (0x4ec4, 0x022c),
+    (0x4ec6, 0x0227),
+    (0x4ec7, 0x0228),
+    (0x4ec8, 0x189c),
+    (0x4ec9, 0x189b),
+    (0x4eca, 0x022a),
+    (0x4ecb, 0x022b),
+    (0x4ecd, 0x0229),
+    (0x4ed4, 0x027e),
+    (0x4ed5, 0x027f),
+    (0x4ed6, 0x0280),
+    (0x4ed7, 0x0281),
+    (0x4ed8, 0x027d),
+    (0x4ed9, 0x0284),
+    (0x4eda, 0x18b2),
+    (0x4edc, 0x18ae),
+    (0x4edd, 0x18b1),
+    (0x4ede, 0x0285),
+    (0x4edf, 0x0295),
+    (0x4ee1, 0x18b0),
+    (0x4ee3, 0x0282),
+    (0x4ee4, 0x0283),
+    (0x4ee5, 0x027c),
+    (0x4ee8, 0x18ad),
+    (0x4ee9, 0x18af),
+    (0x4ef0, 0x0307),
+    (0x4ef1, 0x18d6),
+    (0x4ef2, 0x0304),
+    (0x4ef3, 0x0308),
+    (0x4ef4, 0x18e0),
+    (0x4ef5, 0x18d4),
+    (0x4ef6, 0x0305),
+    (0x4ef7, 0x18d8),
+    (0x4efb, 0x0306),
+    (0x4efd, 0x0309),
+    (0x4eff, 0x02fb),
+    (0x4f00, 0x18d7),
+    (0x4f01, 0x030a),
+    (0x4f02, 0x18db),
+    (0x4f04, 0x18df),
+    (0x4f05, 0x18dc),
+    (0x4f08, 0x18d9),
+    (0x4f09, 0x02fc),
+    (0x4f0a, 0x02fe),
+    (0x4f0b, 0x030b),
+    (0x4f0d, 0x0300),
+    (0x4f0e, 0x18d1),
+    (0x4f0f, 0x0303),
+    (0x4f10, 0x0301),
+    (0x4f11, 0x0302),
+    (0x4f12, 0x18e1),
+    (0x4f13, 0x18de),
+    (0x4f14, 0x18d5),
+    (0x4f15, 0x02ff),
+    (0x4f18, 0x18d2),
+    (0x4f19, 0x02fd),
+    (0x4f1d, 0x18da),
+    (0x4f22, 0x18dd),
+    (0x4f2c, 0x18d3),
+    (0x4f2d, 0x194b),
+    (0x4f2f, 0x03b0),
+    (0x4f30, 0x03a3),
+    (0x4f33, 0x194c),
+    (0x4f34, 0x03a0),
+    (0x4f36, 0x03b2),
+    (0x4f38, 0x03a8),
+    (0x4f3a, 0x03a7),
+    (0x4f3b, 0x1940),
+    (0x4f3c, 0x03ab),
+    (0x4f3d, 0x03a6),
+    (0x4f3e, 0x1945),
+    (0x4f3f, 0x194d),
+    (0x4f41, 0x1949),
+    (0x4f43, 0x03a9),
+    (0x4f46, 0x03ac),
+    (0x4f47, 0x039d),
+    (0x4f48, 0x03b5),
+    (0x4f49, 0x1942),
+    (0x4f4c, 0x1a1d),
+    (0x4f4d, 0x039b),
+    (0x4f4e, 0x03b1),
+    (0x4f4f, 0x039c),
+    (0x4f50, 0x03a4),
+    (0x4f51, 0x03a5),
+    (0x4f52, 0x1947),
+    (0x4f53, 0x1943),
+    (0x4f54, 0x03aa),
+    (0x4f55, 0x03a2),
+    (0x4f56, 0x193f),
+    (0x4f57, 0x039e),
+    (0x4f58, 0x194a),
+    (0x4f59, 0x03b3),
+    (0x4f5a, 0x03b6),
+    (0x4f5b, 0x03a1),
+    (0x4f5c, 0x03ae),
+    (0x4f5d, 0x03b4),
+    (0x4f5e, 0x039f),
+    (0x4f5f, 0x1948),
+    (0x4f60, 0x03af),
+    (0x4f61, 0x194e),
+    (0x4f62, 0x1941),
+    (0x4f63, 0x03ad),
+    (0x4f64, 0x1944),
+    (0x4f67, 0x1946),
+    (0x4f69, 0x04c1),
+    (0x4f6a, 0x1a1f),
+    (0x4f6b, 0x1a2b),
+    (0x4f6c, 0x04b9),
+    (0x4f6e, 0x1a2c),
+    (0x4f6f, 0x04b4),
+    (0x4f70, 0x04be),
+    (0x4f73, 0x04b7),
+    (0x4f74, 0x1a19),
+    (0x4f75, 0x04bf),
+    (0x4f76, 0x1a18),
+    (0x4f77, 0x1a1c),
+    (0x4f78, 0x1a23),
+    (0x4f79, 0x1a21),
+    (0x4f7a, 0x04c7),
+    (0x4f7b, 0x04c2),
+    (0x4f7c, 0x1a13),
+    (0x4f7d, 0x1a15),
+    (0x4f7e, 0x04c4),
+    (0x4f7f, 0x04b8),
+    (0x4f80, 0x1a16),
+    (0x4f81, 0x1a22),
+    (0x4f82, 0x1a29),
+    (0x4f83, 0x04bd),
+    (0x4f84, 0x1a1b),
+    (0x4f85, 0x1a14),
+    (0x4f86, 0x04bc),
+    (0x4f87, 0x1a17),
+    (0x4f88, 0x04c0),
+    (0x4f89, 0x1a1a),
+    (0x4f8b, 0x04bb),
+    (0x4f8d, 0x04b6),
+    (0x4f8f, 0x04c5),
+    (0x4f90, 0x1a24),
+    (0x4f91, 0x04c6),
+    (0x4f92, 0x1a28),
+    (0x4f94, 0x1a26),
+    (0x4f95, 0x1a2a),
+    (0x4f96, 0x04c3),
+    (0x4f97, 0x1a1e),
+    (0x4f98, 0x1a12),
+    (0x4f9a, 0x1a20),
+    (0x4f9b, 0x04ba),
+    (0x4f9c, 0x1a25),
+    (0x4f9d, 0x04b5),
+    (0x4f9e, 0x1a27),
+    (0x4fae, 0x0638),
+    (0x4faf, 0x062c),
+    (0x4fb2, 0x1b7a),
+    (0x4fb3, 0x1b82),
+    (0x4fb5, 0x062b),
+    (0x4fb6, 0x0633),
+    (0x4fb7, 0x063f),
+    (0x4fb9, 0x1b88),
+    (0x4fba, 0x1b86),
+    (0x4fbb, 0x1b81),
+    (0x4fbf, 0x062d),
+    (0x4fc0, 0x1b87),
+    (0x4fc1, 0x1b7d),
+    (0x4fc2, 0x063b),
+    (0x4fc3, 0x0632),
+    (0x4fc4, 0x063a),
+    (0x4fc5, 0x1b78),
+    (0x4fc7, 0x1b84),
+    (0x4fc9, 0x1b7b),
+    (0x4fca, 0x0636),
+    (0x4fcb, 0x1b7c),
+    (0x4fcd, 0x1b77),
+    (0x4fce, 0x063d),
+    (0x4fcf, 0x0630),
+    (0x4fd0, 0x0639),
+    (0x4fd1, 0x062f),
+    (0x4fd3, 0x1b79),
+    (0x4fd4, 0x1b7e),
+    (0x4fd6, 0x1b85),
+    (0x4fd7, 0x0637),
+    (0x4fd8, 0x0634),
+    (0x4fd9, 0x1b80),
+    (0x4fda, 0x063c),
+    (0x4fdb, 0x1b83),
+    (0x4fdc, 0x1b7f),
+    (0x4fdd, 0x0631),
+    (0x4fde, 0x063e),
+    (0x4fdf, 0x0635),
+    (0x4fe0, 0x062e),
+    (0x4fe1, 0x062a),
+    (0x4fec, 0x1b89),
+    (0x4fee, 0x07d1),
+    (0x4fef, 0x07bb),
+    (0x4ff1, 0x07cb),
+    (0x4ff3, 0x07d0),
+    (0x4ff4, 0x1d41),
+    (0x4ff5, 0x1d40),
+    (0x4ff6, 0x1d45),
+    (0x4ff7, 0x1d46),
+    (0x4ff8, 0x07be),
+    (0x4ffa, 0x07c7),
+    (0x4ffe, 0x07d4),
+    (0x5000, 0x07c8),
+    (0x5005, 0x1d3a),
+    (0x5006, 0x07c1),
+    (0x5007, 0x1d3b),
+    (0x5009, 0x07d6),
+    (0x500b, 0x07cd),
+    (0x500c, 0x07b8),
+    (0x500d, 0x07b9),
+    (0x500e, 0x1d4e),
+    (0x500f, 0x0990),
+    (0x5011, 0x07c6),
+    (0x5012, 0x07c5),
+    (0x5013, 0x1d3c),
+    (0x5014, 0x07c9),
+    (0x5015, 0x1f62),
+    (0x5016, 0x07c0),
+    (0x5017, 0x1d47),
+    (0x5018, 0x07cf),
+    (0x5019, 0x07ce),
+    (0x501a, 0x07c4),
+    (0x501b, 0x1d3f),
+    (0x501c, 0x1d48),
+    (0x501e, 0x1d39),
+    (0x501f, 0x07c3),
+    (0x5020, 0x1d49),
+    (0x5021, 0x07cc),
+    (0x5022, 0x1d3d),
+    (0x5023, 0x07ba),
+    (0x5025, 0x07bd),
+    (0x5026, 0x07bc),
+    (0x5027, 0x1d4a),
+    (0x5028, 0x07ca),
+    (0x5029, 0x07bf),
+    (0x502a, 0x07d3),
+    (0x502b, 0x07d5),
+    (0x502c, 0x1d44),
+    (0x502d, 0x07d2),
+    (0x502f, 0x1d4c),
+    (0x5030, 0x1d3e),
+    (0x5031, 0x1d4d),
+    (0x5033, 0x1d42),
+    (0x5035, 0x1d4b),
+    (0x5037, 0x1d43),
+    (0x503c, 0x07c2),
+    (0x5040, 0x1f6a),
+    (0x5041, 0x1f5e),
+    (0x5043, 0x0984),
+    (0x5045, 0x1f63),
+    (0x5046, 0x1f69),
+    (0x5047, 0x0983),
+    (0x5048, 0x1f5c),
+    (0x5049, 0x0987),
+    (0x504a, 0x1f60),
+    (0x504b, 0x1f59),
+    (0x504c, 0x0985),
+    (0x504d, 0x1f5d),
+    (0x504e, 0x098a),
+    (0x504f, 0x098f),
+    (0x5051, 0x1f6e),
+    (0x5053, 0x1f58),
+    (0x5055, 0x098b),
+    (0x5057, 0x1f6d),
+    (0x505a, 0x0986),
+    (0x505b, 0x1f5f),
+    (0x505c, 0x0982),
+    (0x505d, 0x1f5a),
+    (0x505e, 0x1f56),
+    (0x505f, 0x1f64),
+    (0x5060, 0x1f57),
+    (0x5061, 0x1f55),
+    (0x5062, 0x1f61),
+    (0x5063, 0x1f67),
+    (0x5064, 0x1f68),
+    (0x5065, 0x0988),
+    (0x5068, 0x21d6),
+    (0x5069, 0x1f65),
+    (0x506a, 0x1f54),
+    (0x506b, 0x1f66),
+    (0x506d, 0x0992),
+    (0x506e, 0x1f6b),
+    (0x506f, 0x0991),
+    (0x5070, 0x1f53),
+    (0x5072, 0x1f5b),
+    (0x5073, 0x1f6c),
+    (0x5074, 0x098d),
+    (0x5075, 0x098c),
+    (0x5076, 0x0989),
+    (0x5077, 0x098e),
+    (0x507a, 0x0980),
+    (0x507d, 0x0981),
+    (0x5080, 0x0b70),
+    (0x5082, 0x21d9),
+    (0x5083, 0x21d2),
+    (0x5085, 0x0b6d),
+    (0x5087, 0x21da),
+    (0x508b, 0x21d0),
+    (0x508c, 0x21d3),
+    (0x508d, 0x0b6c),
+    (0x508e, 0x21d4),
+    (0x5091, 0x0b6f),
+    (0x5092, 0x21d8),
+    (0x5094, 0x21ce),
+    (0x5095, 0x21cd),
+    (0x5096, 0x0b71),
+    (0x5098, 0x0b72),
+    (0x5099, 0x0b6e),
+    (0x509a, 0x0b73),
+    (0x509b, 0x21cc),
+    (0x509c, 0x21d7),
+    (0x509d, 0x21d5),
+    (0x509e, 0x21cf),
+    (0x50a2, 0x0b6b),
+    (0x50a3, 0x21d1),
+    (0x50ac, 0x0d49),
+    (0x50ad, 0x0d43),
+    (0x50ae, 0x2484),
+    (0x50af, 0x0d4c),
+    (0x50b0, 0x248a),
+    (0x50b1, 0x248d),
+    (0x50b2, 0x0d45),
+    (0x50b3, 0x0d46),
+    (0x50b4, 0x2487),
+    (0x50b5, 0x0d44),
+    (0x50b6, 0x2490),
+    (0x50b7, 0x0d4a),
+    (0x50b8, 0x2491),
+    (0x50ba, 0x248c),
+    (0x50bb, 0x0d4b),
+    (0x50bd, 0x2481),
+    (0x50be, 0x0d48),
+    (0x50bf, 0x2482),
+    (0x50c1, 0x248b),
+    (0x50c2, 0x2489),
+    (0x50c4, 0x2485),
+    (0x50c5, 0x0d47),
+    (0x50c6, 0x2483),
+    (0x50c7, 0x0d4d),
+    (0x50c8, 0x2488),
+    (0x50c9, 0x248f),
+    (0x50ca, 0x2486),
+    (0x50cb, 0x248e),
+    (0x50ce, 0x0f0c),
+    (0x50cf, 0x0f09),
+    (0x50d1, 0x0f0a),
+    (0x50d3, 0x273a),
+    (0x50d4, 0x2732),
+    (0x50d5, 0x0f08),
+    (0x50d6, 0x0f05),
+    (0x50d7, 0x2733),
+    (0x50da, 0x0f07),
+    (0x50db, 0x2736),
+    (0x50dd, 0x2738),
+    (0x50e0, 0x273f),
+    (0x50e3, 0x273e),
+    (0x50e4, 0x2739),
+    (0x50e5, 0x0f04),
+    (0x50e6, 0x2731),
+    (0x50e7, 0x0f02),
+    (0x50e8, 0x2734),
+    (0x50e9, 0x0f0d),
+    (0x50ea, 0x2737),
+    (0x50ec, 0x273b),
+    (0x50ed, 0x0f06),
+    (0x50ee, 0x0f03),
+    (0x50ef, 0x273d),
+    (0x50f0, 0x273c),
+    (0x50f1, 0x0f0b),
+    (0x50f3, 0x2735),
+    (0x50f5, 0x1088),
+    (0x50f6, 0x29a1),
+    (0x50f8, 0x299e),
+    (0x50f9, 0x1089),
+    (0x50fb, 0x1087),
+    (0x50fd, 0x29a5),
+    (0x50fe, 0x29a2),
+    (0x50ff, 0x299b),
+    (0x5100, 0x1086),
+    (0x5102, 0x108a),
+    (0x5103, 0x299c),
+    (0x5104, 0x1085),
+    (0x5105, 0x108d),
+    (0x5106, 0x299f),
+    (0x5107, 0x29a0),
+    (0x5108, 0x108b),
+    (0x5109, 0x108c),
+    (0x510a, 0x29a6),
+    (0x510b, 0x29a3),
+    (0x510c, 0x29a4),
+    (0x5110, 0x1211),
+    (0x5111, 0x2c21),
+    (0x5112, 0x120e),
+    (0x5113, 0x2c1e),
+    (0x5114, 0x1210),
+    (0x5115, 0x1212),
+    (0x5117, 0x2c1f),
+    (0x5118, 0x120f),
+    (0x511a, 0x2c20),
+    (0x511c, 0x2c1d),
+    (0x511f, 0x1339),
+    (0x5120, 0x2e5a),
+    (0x5121, 0x133a),
+    (0x5122, 0x2e58),
+    (0x5124, 0x2e59),
+    (0x5125, 0x2e57),
+    (0x5126, 0x2e56),
+    (0x5129, 0x2e5b),
+    (0x512a, 0x1338),
+    (0x512d, 0x302c),
+    (0x512e, 0x302d),
+    (0x5130, 0x299d),
+    (0x5131, 0x302b),
+    (0x5132, 0x133b),
+    (0x5133, 0x14f9),
+    (0x5134, 0x3199),
+    (0x5135, 0x319a),
+    (0x5137, 0x15ec),
+    (0x5138, 0x15ed),
+    (0x5139, 0x33e3),
+    (0x513a, 0x33e2),
+    (0x513b, 0x1640),
+    (0x513c, 0x163f),
+    (0x513d, 0x352c),
+    (0x513f, 0x01e0),
+    (0x5140, 0x01f8),
+    (0x5141, 0x022e),
+    (0x5143, 0x022d),
+    (0x5144, 0x0287),
+    (0x5145, 0x0286),
+    (0x5146, 0x030e),
+    (0x5147, 0x030d),
+    (0x5148, 0x030f),
+    (0x5149, 0x030c),
+    (0x514b, 0x03b8),
+    (0x514c, 0x03b7),
+    (0x514d, 0x03b9),
+    (0x5152, 0x04c9),
+    (0x5154, 0x04c8),
+    (0x5155, 0x04ca),
+    (0x5157, 0x0640),
+    (0x5159, 0x00b6),
+    (0x515a, 0x1d4f),
+    (0x515b, 0x00b7),
+    (0x515c, 0x0993),
+    (0x515d, 0x00b9),
+    (0x515e, 0x00b8),
+    (0x515f, 0x21db),
+    (0x5161, 0x00ba),
+    (0x5162, 0x0f0e),
+    (0x5163, 0x00bb),
+    (0x5165, 0x01e1),
+    (0x5167, 0x022f),
+    (0x5168, 0x0310),
+    (0x5169, 0x04cb),
+    (0x516b, 0x01e2),
+    (0x516c, 0x0232),
+    (0x516d, 0x0230),
+    (0x516e, 0x0231),
+    (0x5171, 0x0311),
+    (0x5175, 0x03ba),
+    (0x5176, 0x04cd),
+    (0x5177, 0x04cc),
+    (0x5178, 0x04ce),
+    (0x517c, 0x07d7),
+    (0x5180, 0x1213),
+    (0x5187, 0x1896),
+    (0x5189, 0x0288),
+    (0x518a, 0x0289),
+    (0x518d, 0x0312),
+    (0x518f, 0x194f),
+    (0x5191, 0x0642),
+    (0x5192, 0x0641),
+    (0x5193, 0x1d51),
+    (0x5194, 0x1d50),
+    (0x5195, 0x0994),
+    (0x5197, 0x0233),
+    (0x5198, 0x189d),
+    (0x519e, 0x1a2d),
+    (0x51a0, 0x0643),
+    (0x51a2, 0x07da),
+    (0x51a4, 0x07d8),
+    (0x51a5, 0x07d9),
+    (0x51aa, 0x1214),
+    (0x51ac, 0x028a),
+    (0x51b0, 0x0313),
+    (0x51b1, 0x18e2),
+    (0x51b6, 0x03bb),
+    (0x51b7, 0x03bc),
+    (0x51b9, 0x1950),
+    (0x51bc, 0x1a2e),
+    (0x51bd, 0x04cf),
+    (0x51be, 0x1a2f),
+    (0x51c4, 0x1d53),
+    (0x51c5, 0x1d54),
+    (0x51c6, 0x07dd),
+    (0x51c8, 0x1d55),
+    (0x51ca, 0x1d52),
+    (0x51cb, 0x07de),
+    (0x51cc, 0x07dc),
+    (0x51cd, 0x07db),
+    (0x51ce, 0x1d56),
+    (0x51d0, 0x1f6f),
+    (0x51d4, 0x21dc),
+    (0x51d7, 0x2492),
+    (0x51d8, 0x2740),
+    (0x51dc, 0x108e),
+    (0x51dd, 0x1215),
+    (0x51de, 0x2c22),
+    (0x51e0, 0x01e3),
+    (0x51e1, 0x01f1),
+    (0x51f0, 0x0995),
+    (0x51f1, 0x0b75),
+    (0x51f3, 0x0f0f),
+    (0x51f5, 0x188a),
+    (0x51f6, 0x0234),
+    (0x51f8, 0x028d),
+    (0x51f9, 0x028b),
+    (0x51fa, 0x028c),
+    (0x51fd, 0x04d0),
+    (0x5200, 0x01e4),
+    (0x5201, 0x01e5),
+    (0x5203, 0x01f9),
+    (0x5206, 0x0235),
+    (0x5207, 0x0236),
+    (0x5208, 0x0237),
+    (0x5209, 0x18e4),
+    (0x520a, 0x028e),
+    (0x520c, 0x18b3),
+    (0x520e, 0x0317),
+    (0x5210, 0x18e5),
+    (0x5211, 0x0315),
+    (0x5212, 0x0316),
+    (0x5213, 0x18e3),
+    (0x5216, 0x0318),
+    (0x5217, 0x0314),
+    (0x521c, 0x1951),
+    (0x521d, 0x060f),
+    (0x521e, 0x1952),
+    (0x5221, 0x1953),
+    (0x5224, 0x03be),
+    (0x5225, 0x03bd),
+    (0x5228, 0x03c1),
+    (0x5229, 0x03bf),
+    (0x522a, 0x03c0),
+    (0x522e, 0x04d6),
+    (0x5230, 0x04d5),
+    (0x5231, 0x1a34),
+    (0x5232, 0x1a31),
+    (0x5233, 0x1a32),
+    (0x5235, 0x1a30),
+    (0x5236, 0x04d7),
+    (0x5237, 0x04d3),
+    (0x5238, 0x04d2),
+    (0x523a, 0x04d4),
+    (0x523b, 0x04d1),
+    (0x5241, 0x04d8),
+    (0x5243, 0x0645),
+    (0x5244, 0x1b8a),
+    (0x5246, 0x1a33),
+    (0x5247, 0x064a),
+    (0x5249, 0x1b8b),
+    (0x524a, 0x0646),
+    (0x524b, 0x0649),
+    (0x524c, 0x0648),
+    (0x524d, 0x0647),
+    (0x524e, 0x0644),
+    (0x5252, 0x1d59),
+    (0x5254, 0x07e1),
+    (0x5255, 0x1d5c),
+    (0x5256, 0x07df),
+    (0x525a, 0x1d58),
+    (0x525b, 0x07e2),
+    (0x525c, 0x07e0),
+    (0x525d, 0x07e3),
+    (0x525e, 0x1d5a),
+    (0x525f, 0x1d5b),
+    (0x5261, 0x1d57),
+    (0x5262, 0x1d5d),
+    (0x5269, 0x0b79),
+    (0x526a, 0x0996),
+    (0x526b, 0x1f70),
+    (0x526c, 0x1f72),
+    (0x526d, 0x1f71),
+    (0x526e, 0x1f73),
+    (0x526f, 0x0997),
+    (0x5272, 0x0b76),
+    (0x5274, 0x0b77),
+    (0x5275, 0x0b78),
+    (0x5277, 0x0d4f),
+    (0x5278, 0x2494),
+    (0x527a, 0x2493),
+    (0x527b, 0x2495),
+    (0x527c, 0x2496),
+    (0x527d, 0x0d50),
+    (0x527f, 0x0d4e),
+    (0x5280, 0x2741),
+    (0x5281, 0x2742),
+    (0x5282, 0x0f11),
+    (0x5283, 0x0f10),
+    (0x5284, 0x2894),
+    (0x5287, 0x108f),
+    (0x5288, 0x1090),
+    (0x5289, 0x1091),
+    (0x528a, 0x1093),
+    (0x528b, 0x29a7),
+    (0x528c, 0x29a8),
+    (0x528d, 0x1092),
+    (0x5291, 0x1216),
+    (0x5293, 0x1217),
+    (0x5296, 0x319b),
+    (0x5297, 0x33e5),
+    (0x5298, 0x33e4),
+    (0x5299, 0x352d),
+    (0x529b, 0x01e6),
+    (0x529f, 0x0290),
+    (0x52a0, 0x028f),
+    (0x52a3, 0x0319),
+    (0x52a6, 0x18e6),
+    (0x52a9, 0x03c3),
+    (0x52aa, 0x03c4),
+    (0x52ab, 0x03c2),
+    (0x52ac, 0x03c5),
+    (0x52ad, 0x1954),
+    (0x52ae, 0x1955),
+    (0x52bb, 0x04da),
+    (0x52bc, 0x1a35),
+    (0x52be, 0x04d9),
+    (0x52c0, 0x1b8c),
+    (0x52c1, 0x064e),
+    (0x52c2, 0x1b8d),
+    (0x52c3, 0x064d),
+    (0x52c7, 0x064b),
+    (0x52c9, 0x064c),
+    (0x52cd, 0x1d5e),
+    (0x52d2, 0x0998),
+    (0x52d3, 0x1f75),
+    (0x52d5, 0x099b),
+    (0x52d6, 0x1f74),
+    (0x52d7, 0x0a53),
+    (0x52d8, 0x099a),
+    (0x52d9, 0x0999),
+    (0x52db, 0x0b7c),
+    (0x52dd, 0x0b7b),
+    (0x52de, 0x0b7a),
+    (0x52df, 0x0d51),
+    (0x52e2, 0x0d54),
+    (0x52e3, 0x0d55),
+    (0x52e4, 0x0d53),
+    (0x52e6, 0x0d52),
+    (0x52e9, 0x2743),
+    (0x52eb, 0x2744),
+    (0x52ef, 0x29aa),
+    (0x52f0, 0x1094),
+    (0x52f1, 0x29a9),
+    (0x52f3, 0x1218),
+    (0x52f4, 0x2e5c),
+    (0x52f5, 0x133c),
+    (0x52f7, 0x319c),
+    (0x52f8, 0x158c),
+    (0x52fa, 0x01fa),
+    (0x52fb, 0x0238),
+    (0x52fc, 0x189e),
+    (0x52fe, 0x0239),
+    (0x52ff, 0x023a),
+    (0x5305, 0x0291),
+    (0x5306, 0x0292),
+    (0x5308, 0x031a),
+    (0x5309, 0x1956),
+    (0x530a, 0x1a36),
+    (0x530b, 0x1a37),
+    (0x530d, 0x064f),
+    (0x530e, 0x1d5f),
+    (0x530f, 0x099d),
+    (0x5310, 0x099c),
+    (0x5311, 0x21de),
+    (0x5312, 0x21dd),
+    (0x5315, 0x01e7),
+    (0x5316, 0x023b),
+    (0x5317, 0x0293),
+    (0x5319, 0x099e),
+    (0x531a, 0x188b),
+    (0x531c, 0x18b4),
+    (0x531d, 0x0294),
+    (0x531f, 0x18e8),
+    (0x5320, 0x031c),
+    (0x5321, 0x031b),
+    (0x5322, 0x18e7),
+    (0x5323, 0x03c6),
+    (0x532a, 0x07e4),
+    (0x532d, 0x1f76),
+    (0x532f, 0x0d56),
+    (0x5330, 0x2745),
+    (0x5331, 0x0f12),
+    (0x5334, 0x2c23),
+    (0x5337, 0x32e5),
+    (0x5339, 0x023c),
+    (0x533c, 0x1a38),
+    (0x533d, 0x1b8e),
+    (0x533e, 0x09a1),
+    (0x533f, 0x099f),
+    (0x5340, 0x09a0),
+    (0x5341, 0x01e8),
+    (0x5343, 0x01fb),
+    (0x5344, 0x0108),
+    (0x5345, 0x023f),
+    (0x5347, 0x023e),
+    (0x5348, 0x023d),
+    (0x5349, 0x0297),
+    (0x534a, 0x0296),
+    (0x534c, 0x18b5),
+    (0x534d, 0x18e9),
+    (0x5351, 0x04de),
+    (0x5352, 0x04db),
+    (0x5353, 0x04dd),
+    (0x5354, 0x04dc),
+    (0x5357, 0x0650),
+    (0x535a, 0x0b7d),
+    (0x535c, 0x01e9),
+    (0x535e, 0x0240),
+    (0x5360, 0x0299),
+    (0x5361, 0x0298),
+    (0x5363, 0x1957),
+    (0x5366, 0x04df),
+    (0x536c, 0x189f),
+    (0x536e, 0x029b),
+    (0x536f, 0x029a),
+    (0x5370, 0x031d),
+    (0x5371, 0x031e),
+    (0x5372, 0x1958),
+    (0x5373, 0x03c7),
+    (0x5375, 0x03c8),
+    (0x5377, 0x04e0),
+    (0x5378, 0x04e1),
+    (0x5379, 0x04e2),
+    (0x537b, 0x0651),
+    (0x537c, 0x1b8f),
+    (0x537f, 0x07e5),
+    (0x5382, 0x188c),
+    (0x5384, 0x0241),
+    (0x538a, 0x18ea),
+    (0x538e, 0x1959),
+    (0x538f, 0x195a),
+    (0x5392, 0x1a39),
+    (0x5394, 0x1a3a),
+    (0x5396, 0x1b91),
+    (0x5397, 0x1b90),
+    (0x5398, 0x1b93),
+    (0x5399, 0x1b92),
+    (0x539a, 0x0652),
+    (0x539c, 0x1f77),
+    (0x539d, 0x07e7),
+    (0x539e, 0x1d60),
+    (0x539f, 0x07e6),
+    (0x53a4, 0x21df),
+    (0x53a5, 0x0b7e),
+    (0x53a7, 0x21e0),
+    (0x53ac, 0x2746),
+    (0x53ad, 0x0f13),
+    (0x53b2, 0x1095),
+    (0x53b4, 0x319d),
+    (0x53b9, 0x18a0),
+    (0x53bb, 0x029c),
+    (0x53c3, 0x09a2),
+    (0x53c8, 0x01ea),
+    (0x53c9, 0x01fc),
+    (0x53ca, 0x0243),
+    (0x53cb, 0x0242),
+    (0x53cd, 0x0244),
+    (0x53d4, 0x04e4),
+    (0x53d6, 0x04e3),
+    (0x53d7, 0x04e5),
+    (0x53db, 0x0653),
+    (0x53df, 0x07e8),
+    (0x53e1, 0x2c24),
+    (0x53e2, 0x144c),
+    (0x53e3, 0x01fd),
+    (0x53e4, 0x029e),
+    (0x53e5, 0x02ad),
+    (0x53e6, 0x02a8),
+    (0x53e8, 0x02a3),
+    (0x53e9, 0x02a2),
+    (0x53ea, 0x02a9),
+    (0x53eb, 0x02a7),
+    (0x53ec, 0x02a0),
+    (0x53ed, 0x02ae),
+    (0x53ee, 0x02a1),
+    (0x53ef, 0x029d),
+    (0x53f0, 0x02ac),
+    (0x53f1, 0x02ab),
+    (0x53f2, 0x02aa),
+    (0x53f3, 0x029f),
+    (0x53f5, 0x02a6),
+    (0x53f8, 0x02a5),
+    (0x53fb, 0x02af),
+    (0x53fc, 0x02a4),
+    (0x5401, 0x0324),
+    (0x5403, 0x032a),
+    (0x5404, 0x0326),
+    (0x5406, 0x032c),
+    (0x5407, 0x18eb),
+    (0x5408, 0x0329),
+    (0x5409, 0x031f),
+    (0x540a, 0x0322),
+    (0x540b, 0x0325),
+    (0x540c, 0x0321),
+    (0x540d, 0x0328),
+    (0x540e, 0x032b),
+    (0x540f, 0x0320),
+    (0x5410, 0x0323),
+    (0x5411, 0x0327),
+    (0x5412, 0x032d),
+    (0x5418, 0x1963),
+    (0x5419, 0x1960),
+    (0x541b, 0x03d5),
+    (0x541c, 0x1961),
+    (0x541d, 0x03c9),
+    (0x541e, 0x03cb),
+    (0x541f, 0x03e3),
+    (0x5420, 0x03de),
+    (0x5424, 0x1968),
+    (0x5425, 0x1962),
+    (0x5426, 0x03cd),
+    (0x5427, 0x03cf),
+    (0x5428, 0x1967),
+    (0x5429, 0x03d6),
+    (0x542a, 0x195d),
+    (0x542b, 0x03e2),
+    (0x542c, 0x03e4),
+    (0x542d, 0x03ca),
+    (0x542e, 0x03db),
+    (0x5430, 0x195b),
+    (0x5431, 0x03e1),
+    (0x5433, 0x03d2),
+    (0x5435, 0x03dc),
+    (0x5436, 0x03dd),
+    (0x5437, 0x195c),
+    (0x5438, 0x03da),
+    (0x5439, 0x03d8),
+    (0x543b, 0x03d9),
+    (0x543c, 0x03df),
+    (0x543d, 0x1964),
+    (0x543e, 0x03cc),
+    (0x5440, 0x03e0),
+    (0x5441, 0x1966),
+    (0x5442, 0x03d4),
+    (0x5443, 0x03d1),
+    (0x5445, 0x195f),
+    (0x5446, 0x03d0),
+    (0x5447, 0x1969),
+    (0x5448, 0x03d3),
+    (0x544a, 0x03d7),
+    (0x544e, 0x03ce),
+    (0x544f, 0x1965),
+    (0x5454, 0x195e),
+    (0x5460, 0x1a4b),
+    (0x5461, 0x1a4a),
+    (0x5462, 0x04f7),
+    (0x5463, 0x1a4d),
+    (0x5464, 0x1a4f),
+    (0x5465, 0x1a44),
+    (0x5466, 0x1a47),
+    (0x5467, 0x1a4e),
+    (0x5468, 0x04f8),
+    (0x546b, 0x1a41),
+    (0x546c, 0x1a45),
+    (0x546f, 0x1a49),
+    (0x5470, 0x1ba2),
+    (0x5471, 0x04f3),
+    (0x5472, 0x1ba6),
+    (0x5473, 0x04e6),
+    (0x5474, 0x1a46),
+    (0x5475, 0x04e7),
+    (0x5476, 0x04f4),
+    (0x5477, 0x04ed),
+    (0x5478, 0x04e9),
+    (0x547a, 0x1a42),
+    (0x547b, 0x04ec),
+    (0x547c, 0x04f1),
+    (0x547d, 0x04fa),
+    (0x547e, 0x1a43),
+    (0x547f, 0x1a3c),
+    (0x5480, 0x04eb),
+    (0x5481, 0x1a3d),
+    (0x5482, 0x1a3f),
+    (0x5484, 0x04ee),
+    (0x5486, 0x04f0),
+    (0x5487, 0x1a3b),
+    (0x5488, 0x1a40),
+    (0x548b, 0x04f9),
+    (0x548c, 0x04f5),
+    (0x548d, 0x1a48),
+    (0x548e, 0x04fb),
+    (0x5490, 0x04f2),
+    (0x5491, 0x1a3e),
+    (0x5492, 0x04ef),
+    (0x5495, 0x04ea),
+    (0x5496, 0x04e8),
+    (0x5498, 0x1a4c),
+    (0x549a, 0x04f6),
+    (0x54a0, 0x1ba1),
+    (0x54a1, 0x1b95),
+    (0x54a2, 0x1ba4),
+    (0x54a5, 0x1b97),
+    (0x54a6, 0x065a),
+    (0x54a7, 0x0668),
+    (0x54a8, 0x0656),
+    (0x54a9, 0x0667),
+    (0x54aa, 0x065f),
+    (0x54ab, 0x0664),
+    (0x54ac, 0x0654),
+    (0x54ad, 0x1b96),
+    (0x54ae, 0x1b9c),
+    (0x54af, 0x0663),
+    (0x54b0, 0x1ba8),
+    (0x54b1, 0x0665),
+    (0x54b3, 0x065b),
+    (0x54b6, 0x1b9e),
+    (0x54b7, 0x1b9b),
+    (0x54b8, 0x0659),
+    (0x54ba, 0x1b94),
+    (0x54bb, 0x0666),
+    (0x54bc, 0x1ba3),
+    (0x54bd, 0x065e),
+    (0x54be, 0x1ba5),
+    (0x54bf, 0x0669),
+    (0x54c0, 0x0655),
+    (0x54c1, 0x0660),
+    (0x54c2, 0x065d),
+    (0x54c3, 0x1b99),
+    (0x54c4, 0x0661),
+    (0x54c5, 0x1b9f),
+    (0x54c6, 0x1ba0),
+    (0x54c7, 0x065c),
+    (0x54c8, 0x0662),
+    (0x54c9, 0x0658),
+    (0x54ce, 0x0657),
+    (0x54cf, 0x1b98),
+    (0x54d6, 0x1b9d),
+    (0x54de, 0x1ba7),
+    (0x54e0, 0x1d74),
+    (0x54e1, 0x07f5),
+    (0x54e2, 0x1d62),
+    (0x54e4, 0x1d67),
+    (0x54e5, 0x07ee),
+    (0x54e6, 0x07f9),
+    (0x54e7, 0x1d65),
+    (0x54e8, 0x07e9),
+    (0x54e9, 0x07f3),
+    (0x54ea, 0x07f8),
+    (0x54eb, 0x1d6c),
+    (0x54ed, 0x07f4),
+    (0x54ee, 0x07f7),
+    (0x54f1, 0x1d6f),
+    (0x54f2, 0x07ef),
+    (0x54f3, 0x1d66),
+    (0x54f7, 0x1d72),
+    (0x54f8, 0x1d73),
+    (0x54fa, 0x07f1),
+    (0x54fb, 0x1d71),
+    (0x54fc, 0x07ed),
+    (0x54fd, 0x07fc),
+    (0x54ff, 0x1d69),
+    (0x5501, 0x07eb),
+    (0x5503, 0x1d76),
+    (0x5504, 0x1d6a),
+    (0x5505, 0x1d6e),
+    (0x5506, 0x07f0),
+    (0x5507, 0x07fb),
+    (0x5508, 0x1d6b),
+    (0x5509, 0x07f6),
+    (0x550a, 0x1d70),
+    (0x550b, 0x1d77),
+    (0x550c, 0x1f86),
+    (0x550e, 0x1d75),
+    (0x550f, 0x07fd),
+    (0x5510, 0x07ea),
+    (0x5511, 0x1d6d),
+    (0x5512, 0x1d64),
+    (0x5514, 0x07f2),
+    (0x5517, 0x1d63),
+    (0x551a, 0x1d68),
+    (0x5526, 0x1d61),
+    (0x5527, 0x07fa),
+    (0x552a, 0x1f7e),
+    (0x552c, 0x09b5),
+    (0x552d, 0x1f8c),
+    (0x552e, 0x09b3),
+    (0x552f, 0x09b0),
+    (0x5530, 0x1f83),
+    (0x5531, 0x09ac),
+    (0x5532, 0x1f87),
+    (0x5533, 0x09b7),
+    (0x5534, 0x1f7d),
+    (0x5535, 0x1f82),
+    (0x5536, 0x1f81),
+    (0x5537, 0x07ec),
+    (0x5538, 0x09b2),
+    (0x5539, 0x1f8a),
+    (0x553b, 0x1f8d),
+    (0x553c, 0x1f7a),
+    (0x553e, 0x0b90),
+    (0x5540, 0x1f8e),
+    (0x5541, 0x09b8),
+    (0x5543, 0x09aa),
+    (0x5544, 0x09a7),
+    (0x5545, 0x1f85),
+    (0x5546, 0x09a4),
+    (0x5548, 0x1f8b),
+    (0x554a, 0x09ab),
+    (0x554b, 0x1f8f),
+    (0x554d, 0x1f7b),
+    (0x554e, 0x1f89),
+    (0x554f, 0x09ae),
+    (0x5550, 0x1f7c),
+    (0x5551, 0x1f7f),
+    (0x5552, 0x1f84),
+    (0x5555, 0x09af),
+    (0x5556, 0x09ad),
+    (0x5557, 0x09b9),
+    (0x555c, 0x09b4),
+    (0x555e, 0x09a8),
+    (0x555f, 0x0a40),
+    (0x5561, 0x09a9),
+    (0x5562, 0x1f80),
+    (0x5563, 0x09b6),
+    (0x5564, 0x09b1),
+    (0x5565, 0x1f88),
+    (0x5566, 0x09a6),
+    (0x556a, 0x09a5),
+    (0x5575, 0x1f78),
+    (0x5576, 0x1f79),
+    (0x5577, 0x21e5),
+    (0x557b, 0x0b7f),
+    (0x557c, 0x0b82),
+    (0x557d, 0x21f0),
+    (0x557e, 0x0b96),
+    (0x557f, 0x21f3),
+    (0x5580, 0x0b80),
+    (0x5581, 0x21ec),
+    (0x5582, 0x0b86),
+    (0x5583, 0x0b8c),
+    (0x5584, 0x0c9f),
+    (0x5587, 0x0b8a),
+    (0x5588, 0x21e9),
+    (0x5589, 0x0b97),
+    (0x558a, 0x0b83),
+    (0x558b, 0x0b8b),
+    (0x558c, 0x21f1),
+    (0x558d, 0x24a4),
+    (0x558e, 0x21f6),
+    (0x558f, 0x21ea),
+    (0x5591, 0x21e1),
+    (0x5592, 0x21ee),
+    (0x5593, 0x21e8),
+    (0x5594, 0x0b89),
+    (0x5595, 0x21f4),
+    (0x5598, 0x0b85),
+    (0x5599, 0x0b99),
+    (0x559a, 0x0b92),
+    (0x559c, 0x0b87),
+    (0x559d, 0x0b84),
+    (0x559f, 0x0b8f),
+    (0x55a1, 0x21f5),
+    (0x55a2, 0x21e7),
+    (0x55a3, 0x21ed),
+    (0x55a4, 0x21ef),
+    (0x55a5, 0x21e3),
+    (0x55a6, 0x21f2),
+    (0x55a7, 0x0b81),
+    (0x55a8, 0x21e2),
+    (0x55aa, 0x0b88),
+    (0x55ab, 0x0b98),
+    (0x55ac, 0x0b94),
+    (0x55ad, 0x21e4),
+    (0x55ae, 0x0b8e),
+    (0x55b1, 0x0b95),
+    (0x55b2, 0x0b91),
+    (0x55b3, 0x0b8d),
+    (0x55b5, 0x21eb),
+    (0x55bb, 0x0b93),
+    (0x55bf, 0x24a2),
+    (0x55c0, 0x249e),
+    (0x55c2, 0x24ad),
+    (0x55c3, 0x2497),
+    (0x55c4, 0x24a0),
+    (0x55c5, 0x0d64),
+    (0x55c6, 0x0d65),
+    (0x55c7, 0x0d5d),
+    (0x55c8, 0x24a9),
+    (0x55c9, 0x0d67),
+    (0x55ca, 0x249c),
+    (0x55cb, 0x249b),
+    (0x55cc, 0x2499),
+    (0x55cd, 0x24ab),
+    (0x55ce, 0x0d5b),
+    (0x55cf, 0x24a5),
+    (0x55d0, 0x249a),
+    (0x55d1, 0x0d5e),
+    (0x55d2, 0x24a3),
+    (0x55d3, 0x0d59),
+    (0x55d4, 0x249f),
+    (0x55d5, 0x24a6),
+    (0x55d6, 0x24a8),
+    (0x55d9, 0x24ac),
+    (0x55da, 0x0d62),
+    (0x55db, 0x2498),
+    (0x55dc, 0x0d5c),
+    (0x55dd, 0x249d),
+    (0x55df, 0x0d57),
+    (0x55e1, 0x0d63),
+    (0x55e2, 0x24a7),
+    (0x55e3, 0x0d5f),
+    (0x55e4, 0x0d60),
+    (0x55e5, 0x0d66),
+    (0x55e6, 0x0d5a),
+    (0x55e7, 0x00bc),
+    (0x55e8, 0x0d58),
+    (0x55e9, 0x24a1),
+    (0x55ef, 0x0d61),
+    (0x55f2, 0x24aa),
+    (0x55f6, 0x0f23),
+    (0x55f7, 0x0f1e),
+    (0x55f9, 0x2755),
+    (0x55fa, 0x2751),
+    (0x55fc, 0x274b),
+    (0x55fd, 0x0f18),
+    (0x55fe, 0x0f14),
+    (0x55ff, 0x2754),
+    (0x5600, 0x0f15),
+    (0x5601, 0x274e),
+    (0x5602, 0x2750),
+    (0x5604, 0x2753),
+    (0x5606, 0x0f1a),
+    (0x5608, 0x0f21),
+    (0x5609, 0x0f1b),
+    (0x560c, 0x2749),
+    (0x560d, 0x0f1c),
+    (0x560e, 0x0f1d),
+    (0x560f, 0x274c),
+    (0x5610, 0x0f22),
+    (0x5612, 0x274a),
+    (0x5613, 0x274f),
+    (0x5614, 0x0f19),
+    (0x5615, 0x2748),
+    (0x5616, 0x0f1f),
+    (0x5617, 0x0f17),
+    (0x561b, 0x0f16),
+    (0x561c, 0x274d),
+    (0x561d, 0x2752),
+    (0x561f, 0x0f20),
+    (0x5627, 0x2747),
+    (0x5629, 0x109c),
+    (0x562a, 0x29bb),
+    (0x562c, 0x29b8),
+    (0x562e, 0x1096),
+    (0x562f, 0x10a2),
+    (0x5630, 0x10a3),
+    (0x5632, 0x1099),
+    (0x5633, 0x29b6),
+    (0x5634, 0x109b),
+    (0x5635, 0x29ae),
+    (0x5636, 0x10a1),
+    (0x5638, 0x29ba),
+    (0x5639, 0x1098),
+    (0x563a, 0x29bc),
+    (0x563b, 0x1097),
+    (0x563d, 0x29b7),
+    (0x563e, 0x29b9),
+    (0x563f, 0x109a),
+    (0x5640, 0x29b5),
+    (0x5641, 0x29af),
+    (0x5642, 0x29ac),
+    (0x5645, 0x21e6),
+    (0x5646, 0x29b2),
+    (0x5648, 0x29ab),
+    (0x5649, 0x29b1),
+    (0x564a, 0x29b0),
+    (0x564c, 0x29ad),
+    (0x564e, 0x109e),
+    (0x5653, 0x109d),
+    (0x5657, 0x109f),
+    (0x5658, 0x29b3),
+    (0x5659, 0x1219),
+    (0x565a, 0x29b4),
+    (0x565e, 0x2c2d),
+    (0x5660, 0x2c26),
+    (0x5662, 0x1225),
+    (0x5663, 0x2c2a),
+    (0x5664, 0x121d),
+    (0x5665, 0x1221),
+    (0x5666, 0x2c29),
+    (0x5668, 0x1220),
+    (0x5669, 0x121c),
+    (0x566a, 0x121f),
+    (0x566b, 0x121a),
+    (0x566c, 0x1224),
+    (0x566d, 0x2c2b),
+    (0x566e, 0x2c27),
+    (0x566f, 0x1223),
+    (0x5670, 0x2c25),
+    (0x5671, 0x1222),
+    (0x5672, 0x2c2c),
+    (0x5673, 0x2c28),
+    (0x5674, 0x10a0),
+    (0x5676, 0x1226),
+    (0x5677, 0x2c2e),
+    (0x5678, 0x121e),
+    (0x5679, 0x121b),
+    (0x567e, 0x2e63),
+    (0x567f, 0x2e65),
+    (0x5680, 0x133e),
+    (0x5681, 0x2e66),
+    (0x5682, 0x2e64),
+    (0x5683, 0x2e62),
+    (0x5684, 0x2e61),
+    (0x5685, 0x1340),
+    (0x5686, 0x2e60),
+    (0x5687, 0x1341),
+    (0x568c, 0x2e5e),
+    (0x568d, 0x2e5f),
+    (0x568e, 0x133d),
+    (0x568f, 0x1342),
+    (0x5690, 0x133f),
+    (0x5693, 0x2e5d),
+    (0x5695, 0x144d),
+    (0x5697, 0x3030),
+    (0x5698, 0x302e),
+    (0x5699, 0x3033),
+    (0x569a, 0x3031),
+    (0x569c, 0x302f),
+    (0x569d, 0x3032),
+    (0x56a5, 0x14fa),
+    (0x56a6, 0x31a0),
+    (0x56a7, 0x31a1),
+    (0x56a8, 0x158d),
+    (0x56aa, 0x31a2),
+    (0x56ab, 0x319e),
+    (0x56ac, 0x31a3),
+    (0x56ad, 0x319f),
+    (0x56ae, 0x144e),
+    (0x56b2, 0x32e6),
+    (0x56b3, 0x32e8),
+    (0x56b4, 0x1590),
+    (0x56b5, 0x32e7),
+    (0x56b6, 0x158f),
+    (0x56b7, 0x158e),
+    (0x56bc, 0x1591),
+    (0x56bd, 0x33e7),
+    (0x56be, 0x33e8),
+    (0x56c0, 0x15ef),
+    (0x56c1, 0x15ee),
+    (0x56c2, 0x15f0),
+    (0x56c3, 0x33e6),
+    (0x56c5, 0x3497),
+    (0x56c6, 0x3496),
+    (0x56c8, 0x1641),
+    (0x56c9, 0x1643),
+    (0x56ca, 0x1642),
+    (0x56cb, 0x3498),
+    (0x56cc, 0x167a),
+    (0x56cd, 0x359f),
+    (0x56d1, 0x16a3),
+    (0x56d3, 0x359e),
+    (0x56d4, 0x35e7),
+    (0x56d7, 0x1891),
+    (0x56da, 0x02b1),
+    (0x56db, 0x02b0),
+    (0x56dd, 0x0330),
+    (0x56de, 0x032f),
+    (0x56df, 0x18ed),
+    (0x56e0, 0x032e),
+    (0x56e1, 0x18ec),
+    (0x56e4, 0x03e7),
+    (0x56e5, 0x196c),
+    (0x56e7, 0x196b),
+    (0x56ea, 0x03e5),
+    (0x56eb, 0x03e8),
+    (0x56ee, 0x196a),
+    (0x56f0, 0x03e6),
+    (0x56f7, 0x1a50),
+    (0x56f9, 0x1a51),
+    (0x56fa, 0x04fc),
+    (0x56ff, 0x066a),
+    (0x5701, 0x1d78),
+    (0x5702, 0x1d79),
+    (0x5703, 0x07fe),
+    (0x5704, 0x07ff),
+    (0x5707, 0x1f91),
+    (0x5708, 0x09ba),
+    (0x5709, 0x09bc),
+    (0x570a, 0x1f90),
+    (0x570b, 0x09bb),
+    (0x570c, 0x21f7),
+    (0x570d, 0x0b9a),
+    (0x5712, 0x0d68),
+    (0x5713, 0x0d69),
+    (0x5714, 0x24ae),
+    (0x5716, 0x0f25),
+    (0x5718, 0x0f24),
+    (0x571a, 0x29bd),
+    (0x571b, 0x2c30),
+    (0x571c, 0x2c2f),
+    (0x571e, 0x3623),
+    (0x571f, 0x01fe),
+    (0x5720, 0x18a1),
+    (0x5722, 0x18b6),
+    (0x5723, 0x18b7),
+    (0x5728, 0x0333),
+    (0x5729, 0x0337),
+    (0x572a, 0x18ef),
+    (0x572c, 0x0335),
+    (0x572d, 0x0334),
+    (0x572e, 0x18ee),
+    (0x572f, 0x0336),
+    (0x5730, 0x0332),
+    (0x5733, 0x0331),
+    (0x5734, 0x18f0),
+    (0x573b, 0x03f2),
+    (0x573e, 0x03ef),
+    (0x5740, 0x03eb),
+    (0x5741, 0x196d),
+    (0x5745, 0x196e),
+    (0x5747, 0x03ed),
+    (0x5749, 0x1970),
+    (0x574a, 0x03e9),
+    (0x574b, 0x1971),
+    (0x574c, 0x196f),
+    (0x574d, 0x03ec),
+    (0x574e, 0x03ee),
+    (0x574f, 0x03f1),
+    (0x5750, 0x03f0),
+    (0x5751, 0x03ea),
+    (0x5752, 0x1972),
+    (0x5761, 0x0501),
+    (0x5762, 0x1a5e),
+    (0x5764, 0x0503),
+    (0x5766, 0x0502),
+    (0x5768, 0x1a5f),
+    (0x5769, 0x0500),
+    (0x576a, 0x04ff),
+    (0x576b, 0x1a55),
+    (0x576d, 0x1a54),
+    (0x576f, 0x1a52),
+    (0x5770, 0x1a57),
+    (0x5771, 0x1a56),
+    (0x5772, 0x1a53),
+    (0x5773, 0x1a5c),
+    (0x5774, 0x1a5d),
+    (0x5775, 0x1a5a),
+    (0x5776, 0x1a58),
+    (0x5777, 0x04fe),
+    (0x577b, 0x1a5b),
+    (0x577c, 0x0504),
+    (0x577d, 0x1a60),
+    (0x5780, 0x1a59),
+    (0x5782, 0x066b),
+    (0x5783, 0x04fd),
+    (0x578b, 0x066c),
+    (0x578c, 0x1bad),
+    (0x578f, 0x1bb3),
+    (0x5793, 0x0672),
+    (0x5794, 0x1bb1),
+    (0x5795, 0x1bb7),
+    (0x5797, 0x1bae),
+    (0x5798, 0x1bb2),
+    (0x5799, 0x1bb4),
+    (0x579a, 0x1bb6),
+    (0x579b, 0x1bb0),
+    (0x579d, 0x1baf),
+    (0x579e, 0x1baa),
+    (0x579f, 0x1bab),
+    (0x57a0, 0x066d),
+    (0x57a2, 0x066f),
+    (0x57a3, 0x066e),
+    (0x57a4, 0x1bac),
+    (0x57a5, 0x1bb5),
+    (0x57ae, 0x0671),
+    (0x57b5, 0x1ba9),
+    (0x57b6, 0x1d83),
+    (0x57b8, 0x1d82),
+    (0x57b9, 0x1d87),
+    (0x57ba, 0x1d7e),
+    (0x57bc, 0x1d81),
+    (0x57bd, 0x1d80),
+    (0x57bf, 0x1d84),
+    (0x57c1, 0x1d88),
+    (0x57c2, 0x0800),
+    (0x57c3, 0x0803),
+    (0x57c6, 0x1d7f),
+    (0x57c7, 0x1d85),
+    (0x57cb, 0x0802),
+    (0x57cc, 0x1d7a),
+    (0x57ce, 0x0670),
+    (0x57cf, 0x1f9f),
+    (0x57d0, 0x1d86),
+    (0x57d2, 0x1d7d),
+    (0x57d4, 0x0801),
+    (0x57d5, 0x1d7c),
+    (0x57dc, 0x1f96),
+    (0x57df, 0x09bd),
+    (0x57e0, 0x09c1),
+    (0x57e1, 0x1fa6),
+    (0x57e2, 0x1f94),
+    (0x57e3, 0x1fa2),
+    (0x57e4, 0x09c2),
+    (0x57e5, 0x1fa4),
+    (0x57e7, 0x1faa),
+    (0x57e9, 0x1fae),
+    (0x57ec, 0x1fa5),
+    (0x57ed, 0x1f99),
+    (0x57ee, 0x1fa1),
+    (0x57f0, 0x1faf),
+    (0x57f1, 0x1fad),
+    (0x57f2, 0x1fa3),
+    (0x57f3, 0x1f9e),
+    (0x57f4, 0x1f97),
+    (0x57f5, 0x21ff),
+    (0x57f6, 0x1f95),
+    (0x57f7, 0x09c6),
+    (0x57f8, 0x1f9c),
+    (0x57f9, 0x09c7),
+    (0x57fa, 0x09c3),
+    (0x57fb, 0x1f92),
+    (0x57fc, 0x1fa8),
+    (0x57fd, 0x1f9a),
+    (0x5800, 0x1f98),
+    (0x5801, 0x1fab),
+    (0x5802, 0x09c4),
+    (0x5804, 0x1fb1),
+    (0x5805, 0x09be),
+    (0x5806, 0x09c0),
+    (0x5807, 0x1fa0),
+    (0x5808, 0x1f9b),
+    (0x5809, 0x0804),
+    (0x580a, 0x09bf),
+    (0x580b, 0x1f9d),
+    (0x580c, 0x1fac),
+    (0x580d, 0x1fb0),
+    (0x580e, 0x1fa7),
+    (0x5810, 0x1fa9),
+    (0x5814, 0x1f93),
+    (0x5819, 0x21fa),
+    (0x581b, 0x2203),
+    (0x581c, 0x2202),
+    (0x581d, 0x0ba2),
+    (0x581e, 0x21fb),
+    (0x5820, 0x0ba3),
+    (0x5821, 0x0ba1),
+    (0x5823, 0x21fd),
+    (0x5824, 0x0b9e),
+    (0x5825, 0x2201),
+    (0x5827, 0x21fc),
+    (0x5828, 0x21fe),
+    (0x5829, 0x21f8),
+    (0x582a, 0x0b9c),
+    (0x582c, 0x220b),
+    (0x582d, 0x220a),
+    (0x582e, 0x2207),
+    (0x582f, 0x0b9b),
+    (0x5830, 0x0b9f),
+    (0x5831, 0x0ba0),
+    (0x5832, 0x1d7b),
+    (0x5833, 0x2204),
+    (0x5834, 0x0b9d),
+    (0x5835, 0x09c5),
+    (0x5836, 0x2206),
+    (0x5837, 0x21f9),
+    (0x5838, 0x2209),
+    (0x5839, 0x2208),
+    (0x583b, 0x220c),
+    (0x583d, 0x24bc),
+    (0x583f, 0x2205),
+    (0x5848, 0x2200),
+    (0x5849, 0x24b4),
+    (0x584a, 0x0d73),
+    (0x584b, 0x0d76),
+    (0x584c, 0x0d71),
+    (0x584d, 0x24b3),
+    (0x584e, 0x24b7),
+    (0x584f, 0x24b2),
+    (0x5851, 0x0d6b),
+    (0x5852, 0x0d75),
+    (0x5853, 0x24af),
+    (0x5854, 0x0d6f),
+    (0x5855, 0x24b6),
+    (0x5857, 0x0d6d),
+    (0x5858, 0x0d6c),
+    (0x5859, 0x24b9),
+    (0x585a, 0x0d6e),
+    (0x585b, 0x24bb),
+    (0x585d, 0x24b8),
+    (0x585e, 0x0d6a),
+    (0x5862, 0x0d74),
+    (0x5863, 0x24bd),
+    (0x5864, 0x24b1),
+    (0x5865, 0x24ba),
+    (0x5868, 0x24b0),
+    (0x586b, 0x0d70),
+    (0x586d, 0x0d72),
+    (0x586f, 0x24b5),
+    (0x5871, 0x24be),
+    (0x5874, 0x275d),
+    (0x5875, 0x0f26),
+    (0x5876, 0x2763),
+    (0x5879, 0x0f2b),
+    (0x587a, 0x275f),
+    (0x587b, 0x2766),
+    (0x587c, 0x2757),
+    (0x587d, 0x0f2d),
+    (0x587e, 0x0f27),
+    (0x587f, 0x275c),
+    (0x5880, 0x10a4),
+    (0x5881, 0x275b),
+    (0x5882, 0x2764),
+    (0x5883, 0x0f28),
+    (0x5885, 0x0f2c),
+    (0x5886, 0x275a),
+    (0x5887, 0x2760),
+    (0x5888, 0x2765),
+    (0x5889, 0x2756),
+    (0x588a, 0x0f2a),
+    (0x588b, 0x275e),
+    (0x588e, 0x2762),
+    (0x588f, 0x2768),
+    (0x5890, 0x2758),
+    (0x5891, 0x2761),
+    (0x5893, 0x0f29),
+    (0x5894, 0x2767),
+    (0x5898, 0x2759),
+    (0x589c, 0x10a8),
+    (0x589d, 0x29bf),
+    (0x589e, 0x10a6),
+    (0x589f, 0x10a5),
+    (0x58a0, 0x29c1),
+    (0x58a1, 0x29c6),
+    (0x58a3, 0x29c2),
+    (0x58a5, 0x29c5),
+    (0x58a6, 0x10ab),
+    (0x58a8, 0x120c),
+    (0x58a9, 0x10aa),
+    (0x58ab, 0x29be),
+    (0x58ac, 0x29c4),
+    (0x58ae, 0x10a9),
+    (0x58af, 0x29c3),
+    (0x58b1, 0x29c0),
+    (0x58b3, 0x10a7),
+    (0x58ba, 0x2c35),
+    (0x58bc, 0x2c37),
+    (0x58bd, 0x2c32),
+    (0x58be, 0x1228),
+    (0x58bf, 0x2c34),
+    (0x58c1, 0x1227),
+    (0x58c2, 0x2c36),
+    (0x58c5, 0x122a),
+    (0x58c6, 0x2c38),
+    (0x58c7, 0x1229),
+    (0x58c8, 0x2c31),
+    (0x58c9, 0x2c33),
+    (0x58ce, 0x1346),
+    (0x58cf, 0x2e69),
+    (0x58d1, 0x1345),
+    (0x58d2, 0x2e6a),
+    (0x58d3, 0x1344),
+    (0x58d4, 0x2e68),
+    (0x58d5, 0x1343),
+    (0x58d6, 0x2e67),
+    (0x58d8, 0x1450),
+    (0x58d9, 0x144f),
+    (0x58da, 0x31a4),
+    (0x58db, 0x31a6),
+    (0x58dd, 0x31a5),
+    (0x58de, 0x14fb),
+    (0x58df, 0x14fc),
+    (0x58e2, 0x14fd),
+    (0x58e3, 0x32e9),
+    (0x58e4, 0x1592),
+    (0x58e7, 0x352f),
+    (0x58e8, 0x352e),
+    (0x58e9, 0x16a4),
+    (0x58eb, 0x01ff),
+    (0x58ec, 0x0245),
+    (0x58ef, 0x03f3),
+    (0x58f4, 0x1bb8),
+    (0x58f9, 0x0ba4),
+    (0x58fa, 0x0ba5),
+    (0x58fc, 0x24bf),
+    (0x58fd, 0x0f2e),
+    (0x58fe, 0x2769),
+    (0x58ff, 0x29c7),
+    (0x5903, 0x18a2),
+    (0x5906, 0x1973),
+    (0x590c, 0x1a61),
+    (0x590d, 0x1bb9),
+    (0x590e, 0x1d89),
+    (0x590f, 0x0805),
+    (0x5912, 0x31a7),
+    (0x5914, 0x15f1),
+    (0x5915, 0x0200),
+    (0x5916, 0x02b2),
+    (0x5917, 0x18b8),
+    (0x5919, 0x0338),
+    (0x591a, 0x0339),
+    (0x591c, 0x0505),
+    (0x5920, 0x09c8),
+    (0x5922, 0x0f30),
+    (0x5924, 0x0f31),
+    (0x5925, 0x0f2f),
+    (0x5927, 0x0201),
+    (0x5929, 0x0246),
+    (0x592a, 0x0248),
+    (0x592b, 0x0247),
+    (0x592c, 0x18a3),
+    (0x592d, 0x0249),
+    (0x592e, 0x02b3),
+    (0x592f, 0x18b9),
+    (0x5931, 0x02b4),
+    (0x5937, 0x033a),
+    (0x5938, 0x033b),
+    (0x593c, 0x18f1),
+    (0x593e, 0x03f4),
+    (0x5940, 0x1974),
+    (0x5944, 0x0509),
+    (0x5945, 0x1a62),
+    (0x5947, 0x0507),
+    (0x5948, 0x0508),
+    (0x5949, 0x0506),
+    (0x594a, 0x1d8a),
+    (0x594e, 0x0676),
+    (0x594f, 0x0675),
+    (0x5950, 0x0677),
+    (0x5951, 0x0674),
+    (0x5953, 0x1bba),
+    (0x5954, 0x050a),
+    (0x5955, 0x0673),
+    (0x5957, 0x0806),
+    (0x5958, 0x0807),
+    (0x595a, 0x0808),
+    (0x595c, 0x1fb2),
+    (0x5960, 0x0ba6),
+    (0x5961, 0x220d),
+    (0x5962, 0x09c9),
+    (0x5967, 0x0d77),
+    (0x5969, 0x0f33),
+    (0x596a, 0x0f32),
+    (0x596b, 0x276a),
+    (0x596d, 0x10ac),
+    (0x596e, 0x122b),
+    (0x5970, 0x3034),
+    (0x5971, 0x3499),
+    (0x5972, 0x3530),
+    (0x5973, 0x0202),
+    (0x5974, 0x02b5),
+    (0x5976, 0x02b6),
+    (0x5977, 0x18f7),
+    (0x5978, 0x033d),
+    (0x5979, 0x0340),
+    (0x597b, 0x18f5),
+    (0x597c, 0x18f3),
+    (0x597d, 0x033f),
+    (0x597e, 0x18f6),
+    (0x597f, 0x18f8),
+    (0x5980, 0x18f2),
+    (0x5981, 0x0342),
+    (0x5982, 0x0341),
+    (0x5983, 0x033e),
+    (0x5984, 0x033c),
+    (0x5985, 0x18f4),
+    (0x598a, 0x03ff),
+    (0x598d, 0x03fc),
+    (0x598e, 0x1979),
+    (0x598f, 0x197c),
+    (0x5990, 0x197b),
+    (0x5992, 0x03f6),
+    (0x5993, 0x03fe),
+    (0x5996, 0x03fb),
+    (0x5997, 0x1978),
+    (0x5998, 0x1976),
+    (0x5999, 0x03fa),
+    (0x599d, 0x03f5),
+    (0x599e, 0x03f8),
+    (0x59a0, 0x1977),
+    (0x59a1, 0x197e),
+    (0x59a2, 0x197a),
+    (0x59a3, 0x03f9),
+    (0x59a4, 0x03fd),
+    (0x59a5, 0x0400),
+    (0x59a6, 0x1975),
+    (0x59a7, 0x197d),
+    (0x59a8, 0x03f7),
+    (0x59ae, 0x050f),
+    (0x59af, 0x0517),
+    (0x59b1, 0x1a6e),
+    (0x59b2, 0x1a67),
+    (0x59b3, 0x0518),
+    (0x59b4, 0x1a72),
+    (0x59b5, 0x1a63),
+    (0x59b6, 0x1a6a),
+    (0x59b9, 0x050e),
+    (0x59ba, 0x1a64),
+    (0x59bb, 0x050c),
+    (0x59bc, 0x1a6b),
+    (0x59bd, 0x1a6f),
+    (0x59be, 0x050b),
+    (0x59c0, 0x1a70),
+    (0x59c1, 0x1a69),
+    (0x59c3, 0x1a6c),
+    (0x59c5, 0x051a),
+    (0x59c6, 0x0511),
+    (0x59c7, 0x1a73),
+    (0x59c8, 0x1a71),
+    (0x59ca, 0x0516),
+    (0x59cb, 0x0514),
+    (0x59cc, 0x1a68),
+    (0x59cd, 0x0513),
+    (0x59ce, 0x1a66),
+    (0x59cf, 0x1a65),
+    (0x59d0, 0x0512),
+    (0x59d1, 0x0510),
+    (0x59d2, 0x0519),
+    (0x59d3, 0x0515),
+    (0x59d4, 0x050d),
+    (0x59d6, 0x1a6d),
+    (0x59d8, 0x0679),
+    (0x59da, 0x0680),
+    (0x59db, 0x1bc8),
+    (0x59dc, 0x0678),
+    (0x59dd, 0x1bc0),
+    (0x59de, 0x1bbc),
+    (0x59e0, 0x1bcc),
+    (0x59e1, 0x1bbb),
+    (0x59e3, 0x067b),
+    (0x59e4, 0x1bc5),
+    (0x59e5, 0x067e),
+    (0x59e6, 0x0681),
+    (0x59e8, 0x067c),
+    (0x59e9, 0x1bc9),
+    (0x59ea, 0x067f),
+    (0x59ec, 0x080f),
+    (0x59ed, 0x1bcf),
+    (0x59ee, 0x1bbd),
+    (0x59f1, 0x1bbf),
+    (0x59f2, 0x1bc6),
+    (0x59f3, 0x1bca),
+    (0x59f4, 0x1bce),
+    (0x59f5, 0x1bcb),
+    (0x59f6, 0x1bc4),
+    (0x59f7, 0x1bc7),
+    (0x59fa, 0x1bc1),
+    (0x59fb, 0x0683),
+    (0x59fc, 0x1bc3),
+    (0x59fd, 0x1bc2),
+    (0x59fe, 0x1bcd),
+    (0x59ff, 0x067a),
+    (0x5a00, 0x1bbe),
+    (0x5a01, 0x0682),
+    (0x5a03, 0x067d),
+    (0x5a09, 0x0815),
+    (0x5a0a, 0x1d92),
+    (0x5a0c, 0x0814),
+    (0x5a0f, 0x1d90),
+    (0x5a11, 0x0809),
+    (0x5a13, 0x080e),
+    (0x5a15, 0x1d8f),
+    (0x5a16, 0x1d8c),
+    (0x5a17, 0x1d91),
+    (0x5a18, 0x080a),
+    (0x5a19, 0x1d8b),
+    (0x5a1b, 0x080d),
+    (0x5a1c, 0x080b),
+    (0x5a1e, 0x1d93),
+    (0x5a1f, 0x080c),
+    (0x5a20, 0x0810),
+    (0x5a23, 0x0811),
+    (0x5a25, 0x0813),
+    (0x5a29, 0x0812),
+    (0x5a2d, 0x1d8d),
+    (0x5a2e, 0x1d8e),
+    (0x5a33, 0x1d94),
+    (0x5a35, 0x1fb9),
+    (0x5a36, 0x09ca),
+    (0x5a37, 0x221b),
+    (0x5a38, 0x1fb8),
+    (0x5a39, 0x1fcb),
+    (0x5a3c, 0x09d0),
+    (0x5a3e, 0x1fc9),
+    (0x5a40, 0x09cf),
+    (0x5a41, 0x09cb),
+    (0x5a42, 0x1fd2),
+    (0x5a43, 0x1fc2),
+    (0x5a44, 0x1fc5),
+    (0x5a46, 0x09d3),
+    (0x5a47, 0x1fcf),
+    (0x5a48, 0x1fc7),
+    (0x5a49, 0x09cc),
+    (0x5a4a, 0x09d4),
+    (0x5a4c, 0x1fcc),
+    (0x5a4d, 0x1fca),
+    (0x5a50, 0x1fbb),
+    (0x5a51, 0x1fd0),
+    (0x5a52, 0x1fc4),
+    (0x5a53, 0x1fbf),
+    (0x5a55, 0x1fb5),
+    (0x5a56, 0x1fd1),
+    (0x5a57, 0x1fc1),
+    (0x5a58, 0x1fb4),
+    (0x5a5a, 0x09d2),
+    (0x5a5b, 0x1fc6),
+    (0x5a5c, 0x1fd3),
+    (0x5a5d, 0x1fc3),
+    (0x5a5e, 0x1fb7),
+    (0x5a5f, 0x1fbc),
+    (0x5a60, 0x1fb3),
+    (0x5a62, 0x09d1),
+    (0x5a64, 0x1fc0),
+    (0x5a65, 0x1fbd),
+    (0x5a66, 0x09cd),
+    (0x5a67, 0x1fb6),
+    (0x5a69, 0x1fce),
+    (0x5a6a, 0x09ce),
+    (0x5a6c, 0x1fbe),
+    (0x5a6d, 0x1fba),
+    (0x5a70, 0x1fcd),
+    (0x5a77, 0x0ba7),
+    (0x5a78, 0x2214),
+    (0x5a7a, 0x2211),
+    (0x5a7b, 0x2222),
+    (0x5a7c, 0x2216),
+    (0x5a7d, 0x2223),
+    (0x5a7f, 0x0ba9),
+    (0x5a83, 0x221f),
+    (0x5a84, 0x221c),
+    (0x5a8a, 0x221d),
+    (0x5a8b, 0x2220),
+    (0x5a8c, 0x2224),
+    (0x5a8e, 0x1fc8),
+    (0x5a8f, 0x2226),
+    (0x5a90, 0x24d3),
+    (0x5a92, 0x0baa),
+    (0x5a93, 0x2227),
+    (0x5a94, 0x220f),
+    (0x5a95, 0x2219),
+    (0x5a97, 0x221e),
+    (0x5a9a, 0x0ba8),
+    (0x5a9b, 0x0bab),
+    (0x5a9c, 0x2225),
+    (0x5a9d, 0x2228),
+    (0x5a9e, 0x2213),
+    (0x5a9f, 0x2210),
+    (0x5aa2, 0x2212),
+    (0x5aa5, 0x2217),
+    (0x5aa6, 0x2215),
+    (0x5aa7, 0x0bac),
+    (0x5aa9, 0x2221),
+    (0x5aac, 0x2218),
+    (0x5aae, 0x221a),
+    (0x5aaf, 0x220e),
+    (0x5ab0, 0x24c7),
+    (0x5ab1, 0x24c5),
+    (0x5ab2, 0x0d80),
+    (0x5ab3, 0x0d7e),
+    (0x5ab4, 0x24cf),
+    (0x5ab5, 0x24c6),
+    (0x5ab6, 0x24d0),
+    (0x5ab7, 0x24cc),
+    (0x5ab8, 0x24c4),
+    (0x5ab9, 0x24d2),
+    (0x5aba, 0x24c3),
+    (0x5abb, 0x24ca),
+    (0x5abc, 0x0d7d),
+    (0x5abd, 0x0d7c),
+    (0x5abe, 0x0d7b),
+    (0x5abf, 0x24c8),
+    (0x5ac0, 0x24cd),
+    (0x5ac1, 0x0d78),
+    (0x5ac2, 0x0d7f),
+    (0x5ac4, 0x24c1),
+    (0x5ac6, 0x24cb),
+    (0x5ac7, 0x24c0),
+    (0x5ac8, 0x24c9),
+    (0x5ac9, 0x0d79),
+    (0x5aca, 0x24ce),
+    (0x5acb, 0x24c2),
+    (0x5acc, 0x0d7a),
+    (0x5acd, 0x24d1),
+    (0x5ad5, 0x276e),
+    (0x5ad6, 0x0f38),
+    (0x5ad7, 0x0f37),
+    (0x5ad8, 0x0f39),
+    (0x5ad9, 0x277a),
+    (0x5ada, 0x2770),
+    (0x5adb, 0x2776),
+    (0x5adc, 0x276b),
+    (0x5add, 0x2779),
+    (0x5ade, 0x2778),
+    (0x5adf, 0x277c),
+    (0x5ae0, 0x2775),
+    (0x5ae1, 0x0f34),
+    (0x5ae2, 0x2774),
+    (0x5ae3, 0x0f3a),
+    (0x5ae5, 0x276d),
+    (0x5ae6, 0x0f35),
+    (0x5ae8, 0x277b),
+    (0x5ae9, 0x0f36),
+    (0x5aea, 0x276f),
+    (0x5aeb, 0x2772),
+    (0x5aec, 0x2777),
+    (0x5aed, 0x2771),
+    (0x5aee, 0x276c),
+    (0x5af3, 0x2773),
+    (0x5af4, 0x29c9),
+    (0x5af5, 0x10b0),
+    (0x5af6, 0x29cc),
+    (0x5af7, 0x29cb),
+    (0x5af8, 0x29ce),
+    (0x5af9, 0x29d0),
+    (0x5afb, 0x10ae),
+    (0x5afd, 0x29ca),
+    (0x5aff, 0x29c8),
+    (0x5b01, 0x29d1),
+    (0x5b02, 0x29cf),
+    (0x5b03, 0x29cd),
+    (0x5b05, 0x29d3),
+    (0x5b07, 0x29d2),
+    (0x5b08, 0x10b2),
+    (0x5b09, 0x10ad),
+    (0x5b0b, 0x10af),
+    (0x5b0c, 0x10b1),
+    (0x5b0f, 0x29d4),
+    (0x5b10, 0x2c3f),
+    (0x5b13, 0x2c3e),
+    (0x5b14, 0x2c3d),
+    (0x5b16, 0x2c40),
+    (0x5b17, 0x2c39),
+    (0x5b19, 0x2c3a),
+    (0x5b1a, 0x2c42),
+    (0x5b1b, 0x2c3b),
+    (0x5b1d, 0x122c),
+    (0x5b1e, 0x2c44),
+    (0x5b20, 0x2c43),
+    (0x5b21, 0x2c3c),
+    (0x5b23, 0x2e6e),
+    (0x5b24, 0x1349),
+    (0x5b25, 0x2e6c),
+    (0x5b26, 0x2e71),
+    (0x5b27, 0x2e70),
+    (0x5b28, 0x2c41),
+    (0x5b2a, 0x1348),
+    (0x5b2c, 0x2e6f),
+    (0x5b2d, 0x2e6b),
+    (0x5b2e, 0x2e73),
+    (0x5b2f, 0x2e72),
+    (0x5b30, 0x1347),
+    (0x5b32, 0x2e6d),
+    (0x5b34, 0x122d),
+    (0x5b38, 0x1451),
+    (0x5b3c, 0x3035),
+    (0x5b3d, 0x31a8),
+    (0x5b3e, 0x31a9),
+    (0x5b3f, 0x31aa),
+    (0x5b40, 0x1593),
+    (0x5b43, 0x1594),
+    (0x5b45, 0x32ea),
+    (0x5b47, 0x33ea),
+    (0x5b48, 0x33e9),
+    (0x5b4b, 0x349a),
+    (0x5b4c, 0x349b),
+    (0x5b4d, 0x3531),
+    (0x5b4e, 0x35a0),
+    (0x5b50, 0x0203),
+    (0x5b51, 0x0204),
+    (0x5b53, 0x0205),
+    (0x5b54, 0x024a),
+    (0x5b55, 0x02b7),
+    (0x5b56, 0x18f9),
+    (0x5b57, 0x0343),
+    (0x5b58, 0x0344),
+    (0x5b5a, 0x0403),
+    (0x5b5b, 0x0404),
+    (0x5b5c, 0x0402),
+    (0x5b5d, 0x0401),
+    (0x5b5f, 0x051b),
+    (0x5b62, 0x1a74),
+    (0x5b63, 0x051d),
+    (0x5b64, 0x051c),
+    (0x5b65, 0x1a75),
+    (0x5b69, 0x0684),
+    (0x5b6b, 0x0816),
+    (0x5b6c, 0x1d95),
+    (0x5b6e, 0x1fd5),
+    (0x5b70, 0x09d5),
+    (0x5b71, 0x0bae),
+    (0x5b72, 0x1fd4),
+    (0x5b73, 0x0bad),
+    (0x5b75, 0x0f3b),
+    (0x5b77, 0x277d),
+    (0x5b78, 0x122e),
+    (0x5b7a, 0x134a),
+    (0x5b7b, 0x2e74),
+    (0x5b7d, 0x1595),
+    (0x5b7f, 0x1644),
+    (0x5b81, 0x18ba),
+    (0x5b83, 0x02b8),
+    (0x5b84, 0x18bb),
+    (0x5b85, 0x0347),
+    (0x5b87, 0x0345),
+    (0x5b88, 0x0346),
+    (0x5b89, 0x0348),
+    (0x5b8b, 0x0406),
+    (0x5b8c, 0x0405),
+    (0x5b8e, 0x197f),
+    (0x5b8f, 0x0407),
+    (0x5b92, 0x1980),
+    (0x5b93, 0x1a76),
+    (0x5b95, 0x1a77),
+    (0x5b97, 0x051e),
+    (0x5b98, 0x0520),
+    (0x5b99, 0x0522),
+    (0x5b9a, 0x051f),
+    (0x5b9b, 0x0523),
+    (0x5b9c, 0x0521),
+    (0x5ba2, 0x0688),
+    (0x5ba3, 0x0685),
+    (0x5ba4, 0x0687),
+    (0x5ba5, 0x0689),
+    (0x5ba6, 0x0686),
+    (0x5ba7, 0x1d96),
+    (0x5ba8, 0x1bd0),
+    (0x5bac, 0x1d98),
+    (0x5bad, 0x1d97),
+    (0x5bae, 0x081c),
+    (0x5bb0, 0x0818),
+    (0x5bb3, 0x0819),
+    (0x5bb4, 0x081b),
+    (0x5bb5, 0x081d),
+    (0x5bb6, 0x081a),
+    (0x5bb8, 0x081f),
+    (0x5bb9, 0x081e),
+    (0x5bbf, 0x09da),
+    (0x5bc0, 0x1fd7),
+    (0x5bc1, 0x1fd6),
+    (0x5bc2, 0x09d9),
+    (0x5bc4, 0x09d8),
+    (0x5bc5, 0x09d7),
+    (0x5bc6, 0x09db),
+    (0x5bc7, 0x09d6),
+    (0x5bca, 0x222e),
+    (0x5bcb, 0x222b),
+    (0x5bcc, 0x0bb0),
+    (0x5bcd, 0x222a),
+    (0x5bce, 0x222f),
+    (0x5bd0, 0x0bb2),
+    (0x5bd1, 0x222d),
+    (0x5bd2, 0x0baf),
+    (0x5bd3, 0x0bb1),
+    (0x5bd4, 0x222c),
+    (0x5bd6, 0x24d4),
+    (0x5bd8, 0x24d5),
+    (0x5bd9, 0x24d6),
+    (0x5bde, 0x0f3c),
+    (0x5bdf, 0x0f44),
+    (0x5be0, 0x277e),
+    (0x5be1, 0x0f3e),
+    (0x5be2, 0x0f42),
+    (0x5be3, 0x277f),
+    (0x5be4, 0x0f43),
+    (0x5be5, 0x0f3f),
+    (0x5be6, 0x0f40),
+    (0x5be7, 0x0f3d),
+    (0x5be8, 0x0f41),
+    (0x5be9, 0x10b5),
+    (0x5bea, 0x2229),
+    (0x5beb, 0x10b6),
+    (0x5bec, 0x10b4),
+    (0x5bee, 0x10b3),
+    (0x5bef, 0x2c45),
+    (0x5bf0, 0x122f),
+    (0x5bf1, 0x2e75),
+    (0x5bf2, 0x2e76),
+    (0x5bf5, 0x14fe),
+    (0x5bf6, 0x1596),
+    (0x5bf8, 0x0206),
+    (0x5bfa, 0x0349),
+    (0x5c01, 0x068a),
+    (0x5c03, 0x1d99),
+    (0x5c04, 0x0820),
+    (0x5c07, 0x09de),
+    (0x5c08, 0x09dd),
+    (0x5c09, 0x09dc),
+    (0x5c0a, 0x0bb3),
+    (0x5c0b, 0x0bb4),
+    (0x5c0c, 0x2230),
+    (0x5c0d, 0x0f45),
+    (0x5c0e, 0x1230),
+    (0x5c0f, 0x0207),
+    (0x5c10, 0x18a4),
+    (0x5c11, 0x024b),
+    (0x5c12, 0x18bc),
+    (0x5c15, 0x18fa),
+    (0x5c16, 0x034a),
+    (0x5c1a, 0x0524),
+    (0x5c1f, 0x24d7),
+    (0x5c22, 0x0208),
+    (0x5c24, 0x024c),
+    (0x5c25, 0x18fb),
+    (0x5c28, 0x1981),
+    (0x5c2a, 0x1982),
+    (0x5c2c, 0x0408),
+    (0x5c30, 0x2231),
+    (0x5c31, 0x0bb5),
+    (0x5c33, 0x24d8),
+    (0x5c37, 0x134b),
+    (0x5c38, 0x0209),
+    (0x5c39, 0x021d),
+    (0x5c3a, 0x024d),
+    (0x5c3b, 0x18bd),
+    (0x5c3c, 0x02b9),
+    (0x5c3e, 0x040c),
+    (0x5c3f, 0x040b),
+    (0x5c40, 0x0409),
+    (0x5c41, 0x040a),
+    (0x5c44, 0x1a78),
+    (0x5c45, 0x0526),
+    (0x5c46, 0x0527),
+    (0x5c47, 0x1a79),
+    (0x5c48, 0x0525),
+    (0x5c4b, 0x068e),
+    (0x5c4c, 0x1bd1),
+    (0x5c4d, 0x068d),
+    (0x5c4e, 0x068b),
+    (0x5c4f, 0x068c),
+    (0x5c50, 0x0823),
+    (0x5c51, 0x0821),
+    (0x5c54, 0x1d9b),
+    (0x5c55, 0x0822),
+    (0x5c56, 0x1d9a),
+    (0x5c58, 0x0817),
+    (0x5c59, 0x1fd8),
+    (0x5c5c, 0x09e0),
+    (0x5c5d, 0x09e1),
+    (0x5c60, 0x09df),
+    (0x5c62, 0x0f46),
+    (0x5c63, 0x2780),
+    (0x5c64, 0x10b7),
+    (0x5c65, 0x10b8),
+    (0x5c67, 0x29d5),
+    (0x5c68, 0x134c),
+    (0x5c69, 0x3036),
+    (0x5c6a, 0x3037),
+    (0x5c6c, 0x15f2),
+    (0x5c6d, 0x35a1),
+    (0x5c6e, 0x1893),
+    (0x5c6f, 0x024e),
+    (0x5c71, 0x020a),
+    (0x5c73, 0x18bf),
+    (0x5c74, 0x18be),
+    (0x5c79, 0x034b),
+    (0x5c7a, 0x18fd),
+    (0x5c7b, 0x18fe),
+    (0x5c7c, 0x18fc),
+    (0x5c7e, 0x18ff),
+    (0x5c86, 0x198a),
+    (0x5c88, 0x1985),
+    (0x5c89, 0x1987),
+    (0x5c8a, 0x1989),
+    (0x5c8b, 0x1986),
+    (0x5c8c, 0x0410),
+    (0x5c8d, 0x1983),
+    (0x5c8f, 0x1984),
+    (0x5c90, 0x040d),
+    (0x5c91, 0x040e),
+    (0x5c92, 0x1988),
+    (0x5c93, 0x198b),
+    (0x5c94, 0x040f),
+    (0x5c95, 0x198c),
+    (0x5c9d, 0x1a87),
+    (0x5c9f, 0x1a81),
+    (0x5ca0, 0x1a7c),
+    (0x5ca1, 0x0529),
+    (0x5ca2, 0x1a84),
+    (0x5ca3, 0x1a82),
+    (0x5ca4, 0x1a7b),
+    (0x5ca5, 0x1a88),
+    (0x5ca6, 0x1a8b),
+    (0x5ca7, 0x1a86),
+    (0x5ca8, 0x1a7f),
+    (0x5ca9, 0x052b),
+    (0x5caa, 0x1a85),
+    (0x5cab, 0x052c),
+    (0x5cac, 0x1a80),
+    (0x5cad, 0x1a83),
+    (0x5cae, 0x1a7a),
+    (0x5caf, 0x1a7e),
+    (0x5cb0, 0x1a8a),
+    (0x5cb1, 0x052d),
+    (0x5cb3, 0x052e),
+    (0x5cb5, 0x1a7d),
+    (0x5cb6, 0x1a89),
+    (0x5cb7, 0x0528),
+    (0x5cb8, 0x052a),
+    (0x5cc6, 0x1be2),
+    (0x5cc7, 0x1bdb),
+    (0x5cc8, 0x1be1),
+    (0x5cc9, 0x1bda),
+    (0x5cca, 0x1bdc),
+    (0x5ccb, 0x1bd6),
+    (0x5ccc, 0x1bd4),
+    (0x5cce, 0x1be3),
+    (0x5ccf, 0x1be0),
+    (0x5cd0, 0x1bd2),
+    (0x5cd2, 0x0690),
+    (0x5cd3, 0x1bde),
+    (0x5cd4, 0x1bdf),
+    (0x5cd6, 0x1bdd),
+    (0x5cd7, 0x1bd5),
+    (0x5cd8, 0x1bd3),
+    (0x5cd9, 0x068f),
+    (0x5cda, 0x1bd9),
+    (0x5cdb, 0x1bd7),
+    (0x5cde, 0x1bd8),
+    (0x5cdf, 0x1be4),
+    (0x5ce8, 0x0828),
+    (0x5cea, 0x0827),
+    (0x5cec, 0x1d9c),
+    (0x5ced, 0x0824),
+    (0x5cee, 0x1d9e),
+    (0x5cf0, 0x0829),
+    (0x5cf1, 0x1d9f),
+    (0x5cf4, 0x082c),
+    (0x5cf6, 0x082a),
+    (0x5cf7, 0x1da0),
+    (0x5cf8, 0x1be5),
+    (0x5cf9, 0x1da2),
+    (0x5cfb, 0x0826),
+    (0x5cfd, 0x0825),
+    (0x5cff, 0x1d9d),
+    (0x5d00, 0x1da1),
+    (0x5d01, 0x082b),
+    (0x5d06, 0x09e3),
+    (0x5d07, 0x09e2),
+    (0x5d0b, 0x1fda),
+    (0x5d0c, 0x1fde),
+    (0x5d0d, 0x1fe0),
+    (0x5d0e, 0x09e4),
+    (0x5d0f, 0x1fe3),
+    (0x5d11, 0x09e8),
+    (0x5d12, 0x1fe5),
+    (0x5d14, 0x09ea),
+    (0x5d16, 0x09e6),
+    (0x5d17, 0x09ee),
+    (0x5d19, 0x09eb),
+    (0x5d1a, 0x1fdc),
+    (0x5d1b, 0x09e5),
+    (0x5d1d, 0x1fdb),
+    (0x5d1e, 0x1fd9),
+    (0x5d1f, 0x1fe7),
+    (0x5d20, 0x1fdd),
+    (0x5d22, 0x09e7),
+    (0x5d23, 0x1fe6),
+    (0x5d24, 0x09ec),
+    (0x5d25, 0x1fe2),
+    (0x5d26, 0x1fe1),
+    (0x5d27, 0x09ed),
+    (0x5d28, 0x1fdf),
+    (0x5d29, 0x09e9),
+    (0x5d2e, 0x1fe8),
+    (0x5d30, 0x1fe4),
+    (0x5d31, 0x2240),
+    (0x5d32, 0x2247),
+    (0x5d33, 0x223c),
+    (0x5d34, 0x0bb8),
+    (0x5d35, 0x2238),
+    (0x5d36, 0x2248),
+    (0x5d37, 0x2232),
+    (0x5d38, 0x2245),
+    (0x5d39, 0x2243),
+    (0x5d3a, 0x223d),
+    (0x5d3c, 0x2246),
+    (0x5d3d, 0x223f),
+    (0x5d3f, 0x2237),
+    (0x5d40, 0x2249),
+    (0x5d41, 0x2235),
+    (0x5d42, 0x2242),
+    (0x5d43, 0x2233),
+    (0x5d45, 0x224a),
+    (0x5d47, 0x0bb9),
+    (0x5d49, 0x2244),
+    (0x5d4a, 0x24db),
+    (0x5d4b, 0x2236),
+    (0x5d4c, 0x0bb6),
+    (0x5d4e, 0x223a),
+    (0x5d50, 0x0bb7),
+    (0x5d51, 0x2239),
+    (0x5d52, 0x223e),
+    (0x5d55, 0x223b),
+    (0x5d59, 0x2241),
+    (0x5d5e, 0x24df),
+    (0x5d62, 0x24e2),
+    (0x5d63, 0x24da),
+    (0x5d65, 0x24dc),
+    (0x5d67, 0x24e1),
+    (0x5d68, 0x24e0),
+    (0x5d69, 0x0d81),
+    (0x5d6b, 0x2234),
+    (0x5d6c, 0x24de),
+    (0x5d6f, 0x0d82),
+    (0x5d71, 0x24d9),
+    (0x5d72, 0x24dd),
+    (0x5d77, 0x2787),
+    (0x5d79, 0x278e),
+    (0x5d7a, 0x2785),
+    (0x5d7c, 0x278c),
+    (0x5d7d, 0x2783),
+    (0x5d7e, 0x278b),
+    (0x5d7f, 0x278f),
+    (0x5d80, 0x2782),
+    (0x5d81, 0x2786),
+    (0x5d82, 0x2781),
+    (0x5d84, 0x0f47),
+    (0x5d86, 0x2784),
+    (0x5d87, 0x0f48),
+    (0x5d88, 0x278a),
+    (0x5d89, 0x2789),
+    (0x5d8a, 0x2788),
+    (0x5d8d, 0x278d),
+    (0x5d92, 0x29d9),
+    (0x5d93, 0x29db),
+    (0x5d94, 0x10ba),
+    (0x5d95, 0x29dc),
+    (0x5d97, 0x29d7),
+    (0x5d99, 0x29d6),
+    (0x5d9a, 0x29e0),
+    (0x5d9c, 0x29de),
+    (0x5d9d, 0x10b9),
+    (0x5d9e, 0x29e1),
+    (0x5d9f, 0x29d8),
+    (0x5da0, 0x29dd),
+    (0x5da1, 0x29df),
+    (0x5da2, 0x29da),
+    (0x5da7, 0x2c49),
+    (0x5da8, 0x2c4e),
+    (0x5da9, 0x2c48),
+    (0x5daa, 0x2c4d),
+    (0x5dac, 0x2c46),
+    (0x5dad, 0x2c50),
+    (0x5dae, 0x2c4c),
+    (0x5daf, 0x2c51),
+    (0x5db0, 0x2c4b),
+    (0x5db1, 0x2c47),
+    (0x5db2, 0x2c4f),
+    (0x5db4, 0x2c52),
+    (0x5db5, 0x2c4a),
+    (0x5db7, 0x2e77),
+    (0x5db8, 0x1350),
+    (0x5dba, 0x134e),
+    (0x5dbc, 0x134d),
+    (0x5dbd, 0x134f),
+    (0x5dc0, 0x3038),
+    (0x5dc2, 0x3142),
+    (0x5dc3, 0x31ab),
+    (0x5dc6, 0x32eb),
+    (0x5dc7, 0x32ec),
+    (0x5dc9, 0x1597),
+    (0x5dcb, 0x33eb),
+    (0x5dcd, 0x15f3),
+    (0x5dcf, 0x33ec),
+    (0x5dd1, 0x349d),
+    (0x5dd2, 0x1646),
+    (0x5dd4, 0x1645),
+    (0x5dd5, 0x349c),
+    (0x5dd6, 0x167b),
+    (0x5dd8, 0x3532),
+    (0x5ddd, 0x020b),
+    (0x5dde, 0x034c),
+    (0x5ddf, 0x1900),
+    (0x5de0, 0x198d),
+    (0x5de1, 0x049e),
+    (0x5de2, 0x09ef),
+    (0x5de5, 0x020c),
+    (0x5de6, 0x02bc),
+    (0x5de7, 0x02bb),
+    (0x5de8, 0x02ba),
+    (0x5deb, 0x0411),
+    (0x5dee, 0x082d),
+    (0x5df0, 0x24e3),
+    (0x5df1, 0x020d),
+    (0x5df2, 0x020e),
+    (0x5df3, 0x020f),
+    (0x5df4, 0x024f),
+    (0x5df7, 0x0691),
+    (0x5df9, 0x1be6),
+    (0x5dfd, 0x0bba),
+    (0x5dfe, 0x0210),
+    (0x5dff, 0x18a5),
+    (0x5e02, 0x02bd),
+    (0x5e03, 0x02be),
+    (0x5e04, 0x18c0),
+    (0x5e06, 0x034d),
+    (0x5e0a, 0x198e),
+    (0x5e0c, 0x0412),
+    (0x5e0e, 0x198f),
+    (0x5e11, 0x0534),
+    (0x5e14, 0x1a8d),
+    (0x5e15, 0x0532),
+    (0x5e16, 0x0531),
+    (0x5e17, 0x1a8c),
+    (0x5e18, 0x052f),
+    (0x5e19, 0x1a8e),
+    (0x5e1a, 0x0530),
+    (0x5e1b, 0x0533),
+    (0x5e1d, 0x0692),
+    (0x5e1f, 0x0694),
+    (0x5e20, 0x1bea),
+    (0x5e21, 0x1be7),
+    (0x5e22, 0x1be8),
+    (0x5e23, 0x1be9),
+    (0x5e24, 0x1beb),
+    (0x5e25, 0x0693),
+    (0x5e28, 0x1da4),
+    (0x5e29, 0x1da3),
+    (0x5e2b, 0x082f),
+    (0x5e2d, 0x082e),
+    (0x5e33, 0x09f2),
+    (0x5e34, 0x1fea),
+    (0x5e36, 0x09f1),
+    (0x5e37, 0x09f3),
+    (0x5e38, 0x09f0),
+    (0x5e3d, 0x0bbc),
+    (0x5e3e, 0x1fe9),
+    (0x5e40, 0x0bbd),
+    (0x5e41, 0x224c),
+    (0x5e43, 0x0bbe),
+    (0x5e44, 0x224b),
+    (0x5e45, 0x0bbb),
+    (0x5e4a, 0x24e6),
+    (0x5e4b, 0x24e8),
+    (0x5e4c, 0x0d83),
+    (0x5e4d, 0x24e7),
+    (0x5e4e, 0x24e5),
+    (0x5e4f, 0x24e4),
+    (0x5e53, 0x2792),
+    (0x5e54, 0x0f4d),
+    (0x5e55, 0x0f4b),
+    (0x5e57, 0x0f4c),
+    (0x5e58, 0x2790),
+    (0x5e59, 0x2791),
+    (0x5e5b, 0x0f49),
+    (0x5e5c, 0x29e5),
+    (0x5e5d, 0x29e3),
+    (0x5e5f, 0x10bc),
+    (0x5e60, 0x29e4),
+    (0x5e61, 0x10bd),
+    (0x5e62, 0x10bb),
+    (0x5e63, 0x0f4a),
+    (0x5e66, 0x2c55),
+    (0x5e67, 0x2c53),
+    (0x5e68, 0x2c54),
+    (0x5e69, 0x29e2),
+    (0x5e6a, 0x2e79),
+    (0x5e6b, 0x1351),
+    (0x5e6c, 0x2e78),
+    (0x5e6d, 0x3039),
+    (0x5e6e, 0x303a),
+    (0x5e6f, 0x2c56),
+    (0x5e70, 0x31ac),
+    (0x5e72, 0x0211),
+    (0x5e73, 0x02bf),
+    (0x5e74, 0x034f),
+    (0x5e75, 0x1901),
+    (0x5e76, 0x034e),
+    (0x5e78, 0x0535),
+    (0x5e79, 0x0d84),
+    (0x5e7b, 0x0250),
+    (0x5e7c, 0x02c0),
+    (0x5e7d, 0x0695),
+    (0x5e7e, 0x0bbf),
+    (0x5e80, 0x18c1),
+    (0x5e82, 0x18c2),
+    (0x5e84, 0x1902),
+    (0x5e87, 0x0414),
+    (0x5e88, 0x1993),
+    (0x5e89, 0x1991),
+    (0x5e8a, 0x0415),
+    (0x5e8b, 0x1990),
+    (0x5e8c, 0x1992),
+    (0x5e8d, 0x1994),
+    (0x5e8f, 0x0413),
+    (0x5e95, 0x0539),
+    (0x5e96, 0x053a),
+    (0x5e97, 0x0537),
+    (0x5e9a, 0x0536),
+    (0x5e9b, 0x1bef),
+    (0x5e9c, 0x0538),
+    (0x5ea0, 0x0696),
+    (0x5ea2, 0x1bee),
+    (0x5ea3, 0x1bf0),
+    (0x5ea4, 0x1bed),
+    (0x5ea5, 0x1bf1),
+    (0x5ea6, 0x0697),
+    (0x5ea7, 0x0832),
+    (0x5ea8, 0x1da5),
+    (0x5eaa, 0x1da7),
+    (0x5eab, 0x0830),
+    (0x5eac, 0x1da8),
+    (0x5ead, 0x0831),
+    (0x5eae, 0x1da6),
+    (0x5eb0, 0x1bec),
+    (0x5eb1, 0x1feb),
+    (0x5eb2, 0x1fee),
+    (0x5eb3, 0x1fef),
+    (0x5eb4, 0x1fec),
+    (0x5eb5, 0x09f7),
+    (0x5eb6, 0x09f6),
+    (0x5eb7, 0x09f4),
+    (0x5eb8, 0x09f5),
+    (0x5eb9, 0x1fed),
+    (0x5ebe, 0x09f8),
+    (0x5ec1, 0x0bc1),
+    (0x5ec2, 0x0bc2),
+    (0x5ec4, 0x0bc3),
+    (0x5ec5, 0x24e9),
+    (0x5ec6, 0x24eb),
+    (0x5ec7, 0x24ed),
+    (0x5ec8, 0x0d86),
+    (0x5ec9, 0x0d85),
+    (0x5eca, 0x0bc0),
+    (0x5ecb, 0x24ec),
+    (0x5ecc, 0x24ea),
+    (0x5ece, 0x2796),
+    (0x5ed1, 0x2794),
+    (0x5ed2, 0x279a),
+    (0x5ed3, 0x0f4e),
+    (0x5ed4, 0x279b),
+    (0x5ed5, 0x2798),
+    (0x5ed6, 0x0f4f),
+    (0x5ed7, 0x2795),
+    (0x5ed8, 0x2793),
+    (0x5ed9, 0x2799),
+    (0x5eda, 0x10bf),
+    (0x5edb, 0x29e7),
+    (0x5edc, 0x2797),
+    (0x5edd, 0x10c1),
+    (0x5ede, 0x29e8),
+    (0x5edf, 0x10c0),
+    (0x5ee0, 0x10c3),
+    (0x5ee1, 0x29e9),
+    (0x5ee2, 0x10be),
+    (0x5ee3, 0x10c2),
+    (0x5ee5, 0x2c5b),
+    (0x5ee6, 0x2c59),
+    (0x5ee7, 0x2c58),
+    (0x5ee8, 0x2c5a),
+    (0x5ee9, 0x2c57),
+    (0x5eec, 0x1500),
+    (0x5eee, 0x32ed),
+    (0x5eef, 0x32ee),
+    (0x5ef1, 0x33ed),
+    (0x5ef2, 0x349e),
+    (0x5ef3, 0x16c6),
+    (0x5ef6, 0x053b),
+    (0x5ef7, 0x0416),
+    (0x5efa, 0x0698),
+    (0x5efe, 0x0212),
+    (0x5eff, 0x0251),
+    (0x5f01, 0x02c1),
+    (0x5f02, 0x1903),
+    (0x5f04, 0x0417),
+    (0x5f05, 0x1995),
+    (0x5f07, 0x1bf2),
+    (0x5f08, 0x0699),
+    (0x5f0a, 0x0f50),
+    (0x5f0b, 0x0213),
+    (0x5f0f, 0x0350),
+    (0x5f12, 0x0d87),
+    (0x5f13, 0x0214),
+    (0x5f14, 0x0252),
+    (0x5f15, 0x0253),
+    (0x5f17, 0x02c3),
+    (0x5f18, 0x02c2),
+    (0x5f1a, 0x1904),
+    (0x5f1b, 0x0351),
+    (0x5f1d, 0x1996),
+    (0x5f1f, 0x0418),
+    (0x5f22, 0x1a90),
+    (0x5f23, 0x1a91),
+    (0x5f24, 0x1a92),
+    (0x5f26, 0x053c),
+    (0x5f27, 0x053d),
+    (0x5f28, 0x1a8f),
+    (0x5f29, 0x053e),
+    (0x5f2d, 0x069a),
+    (0x5f2e, 0x1bf3),
+    (0x5f30, 0x1daa),
+    (0x5f31, 0x0833),
+    (0x5f33, 0x1da9),
+    (0x5f35, 0x09f9),
+    (0x5f36, 0x1ff0),
+    (0x5f37, 0x09fa),
+    (0x5f38, 0x1ff1),
+    (0x5f3c, 0x0bc4),
+    (0x5f40, 0x24ee),
+    (0x5f43, 0x279d),
+    (0x5f44, 0x279c),
+    (0x5f46, 0x0f51),
+    (0x5f48, 0x10c4),
+    (0x5f49, 0x29ea),
+    (0x5f4a, 0x1231),
+    (0x5f4b, 0x2c5c),
+    (0x5f4c, 0x1352),
+    (0x5f4e, 0x1647),
+    (0x5f4f, 0x3534),
+    (0x5f54, 0x1a93),
+    (0x5f56, 0x1bf4),
+    (0x5f57, 0x09fb),
+    (0x5f58, 0x224d),
+    (0x5f59, 0x0d88),
+    (0x5f5d, 0x1452),
+    (0x5f62, 0x041a),
+    (0x5f64, 0x0419),
+    (0x5f65, 0x069b),
+    (0x5f67, 0x1dab),
+    (0x5f69, 0x09fd),
+    (0x5f6a, 0x0b0d),
+    (0x5f6b, 0x09fe),
+    (0x5f6c, 0x09fc),
+    (0x5f6d, 0x0bc5),
+    (0x5f6f, 0x279e),
+    (0x5f70, 0x0f52),
+    (0x5f71, 0x10c5),
+    (0x5f73, 0x1894),
+    (0x5f74, 0x1905),
+    (0x5f76, 0x1998),
+    (0x5f77, 0x041b),
+    (0x5f78, 0x1997),
+    (0x5f79, 0x041c),
+    (0x5f7c, 0x0542),
+    (0x5f7d, 0x1a96),
+    (0x5f7e, 0x1a95),
+    (0x5f7f, 0x0541),
+    (0x5f80, 0x053f),
+    (0x5f81, 0x0540),
+    (0x5f82, 0x1a94),
+    (0x5f85, 0x069d),
+    (0x5f86, 0x1bf5),
+    (0x5f87, 0x06a0),
+    (0x5f88, 0x069c),
+    (0x5f89, 0x06a2),
+    (0x5f8a, 0x069e),
+    (0x5f8b, 0x069f),
+    (0x5f8c, 0x06a1),
+    (0x5f90, 0x0836),
+    (0x5f91, 0x0835),
+    (0x5f92, 0x0834),
+    (0x5f96, 0x1ff3),
+    (0x5f97, 0x09ff),
+    (0x5f98, 0x0a02),
+    (0x5f99, 0x0a00),
+    (0x5f9b, 0x1ff2),
+    (0x5f9c, 0x0a05),
+    (0x5f9e, 0x0a01),
+    (0x5f9f, 0x1ff4),
+    (0x5fa0, 0x0a04),
+    (0x5fa1, 0x0a03),
+    (0x5fa5, 0x224f),
+    (0x5fa6, 0x224e),
+    (0x5fa8, 0x0bc8),
+    (0x5fa9, 0x0bc6),
+    (0x5faa, 0x0bc7),
+    (0x5fab, 0x2250),
+    (0x5fac, 0x0d89),
+    (0x5fad, 0x24f0),
+    (0x5fae, 0x0d8a),
+    (0x5faf, 0x24ef),
+    (0x5fb2, 0x29eb),
+    (0x5fb5, 0x10c7),
+    (0x5fb6, 0x279f),
+    (0x5fb7, 0x10c6),
+    (0x5fb9, 0x0f53),
+    (0x5fbb, 0x2e7b),
+    (0x5fbc, 0x2c5d),
+    (0x5fbd, 0x1353),
+    (0x5fbe, 0x2e7a),
+    (0x5fbf, 0x31ad),
+    (0x5fc0, 0x32ef),
+    (0x5fc1, 0x32f0),
+    (0x5fc3, 0x0254),
+    (0x5fc5, 0x02c4),
+    (0x5fc9, 0x18c3),
+    (0x5fcc, 0x041e),
+    (0x5fcd, 0x0420),
+    (0x5fcf, 0x1908),
+    (0x5fd0, 0x199b),
+    (0x5fd1, 0x199a),
+    (0x5fd2, 0x1999),
+    (0x5fd4, 0x1907),
+    (0x5fd5, 0x1906),
+    (0x5fd6, 0x0353),
+    (0x5fd7, 0x041f),
+    (0x5fd8, 0x041d),
+    (0x5fd9, 0x0352),
+    (0x5fdd, 0x0543),
+    (0x5fde, 0x1a97),
+    (0x5fe0, 0x0544),
+    (0x5fe1, 0x19a0),
+    (0x5fe3, 0x19a2),
+    (0x5fe4, 0x19a1),
+    (0x5fe5, 0x1a98),
+    (0x5fe8, 0x199d),
+    (0x5fea, 0x0424),
+    (0x5feb, 0x0422),
+    (0x5fed, 0x199c),
+    (0x5fee, 0x199e),
+    (0x5fef, 0x19a4),
+    (0x5ff1, 0x0421),
+    (0x5ff3, 0x199f),
+    (0x5ff4, 0x19a8),
+    (0x5ff5, 0x0546),
+    (0x5ff7, 0x19a5),
+    (0x5ff8, 0x0423),
+    (0x5ffa, 0x19a3),
+    (0x5ffb, 0x19a6),
+    (0x5ffd, 0x0545),
+    (0x5fff, 0x0547),
+    (0x6000, 0x19a7),
+    (0x6009, 0x1aac),
+    (0x600a, 0x1a9f),
+    (0x600b, 0x1a9d),
+    (0x600c, 0x1aab),
+    (0x600d, 0x1aa6),
+    (0x600e, 0x06a7),
+    (0x600f, 0x0548),
+    (0x6010, 0x1aa7),
+    (0x6011, 0x1aaa),
+    (0x6012, 0x06a3),
+    (0x6013, 0x1aa9),
+    (0x6014, 0x0549),
+    (0x6015, 0x054e),
+    (0x6016, 0x054c),
+    (0x6017, 0x1aa0),
+    (0x6019, 0x1a9b),
+    (0x601a, 0x1aa2),
+    (0x601b, 0x0553),
+    (0x601c, 0x1aad),
+    (0x601d, 0x06a4),
+    (0x601e, 0x1aa3),
+    (0x6020, 0x06a5),
+    (0x6021, 0x054f),
+    (0x6022, 0x1aa5),
+    (0x6024, 0x1c04),
+    (0x6025, 0x06a6),
+    (0x6026, 0x1a9a),
+    (0x6027, 0x0550),
+    (0x6028, 0x06a8),
+    (0x6029, 0x0551),
+    (0x602a, 0x054d),
+    (0x602b, 0x0552),
+    (0x602c, 0x1aa4),
+    (0x602d, 0x1a99),
+    (0x602e, 0x1aa8),
+    (0x602f, 0x054a),
+    (0x6032, 0x1a9c),
+    (0x6033, 0x1aa1),
+    (0x6034, 0x1a9e),
+    (0x6035, 0x054b),
+    (0x6037, 0x1bf6),
+    (0x6039, 0x1bf7),
+    (0x6040, 0x1c01),
+    (0x6041, 0x1daf),
+    (0x6042, 0x1c02),
+    (0x6043, 0x06ae),
+    (0x6044, 0x1c05),
+    (0x6045, 0x1bfb),
+    (0x6046, 0x06ad),
+    (0x6047, 0x1bfd),
+    (0x6049, 0x1bfe),
+    (0x604c, 0x1c00),
+    (0x604d, 0x06a9),
+    (0x6050, 0x083a),
+    (0x6053, 0x1bfc),
+    (0x6054, 0x1bf8),
+    (0x6055, 0x083b),
+    (0x6058, 0x1c06),
+    (0x6059, 0x0837),
+    (0x605a, 0x1dad),
+    (0x605b, 0x1bff),
+    (0x605d, 0x1dac),
+    (0x605e, 0x1bfa),
+    (0x605f, 0x1c03),
+    (0x6062, 0x06ac),
+    (0x6063, 0x0838),
+    (0x6064, 0x06b2),
+    (0x6065, 0x0839),
+    (0x6066, 0x1c07),
+    (0x6067, 0x1dae),
+    (0x6068, 0x06ab),
+    (0x6069, 0x083d),
+    (0x606a, 0x06b1),
+    (0x606b, 0x06b0),
+    (0x606c, 0x06af),
+    (0x606d, 0x083c),
+    (0x606e, 0x1c08),
+    (0x606f, 0x083e),
+    (0x6070, 0x06aa),
+    (0x6072, 0x1bf9),
+    (0x607f, 0x0a06),
+    (0x6080, 0x1db2),
+    (0x6081, 0x1db4),
+    (0x6083, 0x1db6),
+    (0x6084, 0x083f),
+    (0x6085, 0x0845),
+    (0x6086, 0x1ff7),
+    (0x6087, 0x1dba),
+    (0x6088, 0x1db1),
+    (0x6089, 0x0a08),
+    (0x608a, 0x1ff5),
+    (0x608c, 0x0844),
+    (0x608d, 0x0842),
+    (0x608e, 0x1dbc),
+    (0x6090, 0x1ff6),
+    (0x6092, 0x1db3),
+    (0x6094, 0x0843),
+    (0x6095, 0x1db7),
+    (0x6096, 0x0846),
+    (0x6097, 0x1db9),
+    (0x609a, 0x0841),
+    (0x609b, 0x1db8),
+    (0x609c, 0x1dbb),
+    (0x609d, 0x1db5),
+    (0x609f, 0x0840),
+    (0x60a0, 0x0a09),
+    (0x60a2, 0x1db0),
+    (0x60a3, 0x0a07),
+    (0x60a8, 0x0a0a),
+    (0x60b0, 0x1ff9),
+    (0x60b1, 0x2002),
+    (0x60b2, 0x0bcb),
+    (0x60b4, 0x0a0c),
+    (0x60b5, 0x0a11),
+    (0x60b6, 0x0bcc),
+    (0x60b7, 0x2004),
+    (0x60b8, 0x0a18),
+    (0x60b9, 0x2252),
+    (0x60ba, 0x1ffa),
+    (0x60bb, 0x0a10),
+    (0x60bc, 0x0a13),
+    (0x60bd, 0x0a0e),
+    (0x60be, 0x1ff8),
+    (0x60bf, 0x2006),
+    (0x60c0, 0x2009),
+    (0x60c1, 0x2261),
+    (0x60c3, 0x2007),
+    (0x60c4, 0x2256),
+    (0x60c5, 0x0a0f),
+    (0x60c6, 0x0a16),
+    (0x60c7, 0x0a1a),
+    (0x60c8, 0x2001),
+    (0x60c9, 0x2251),
+    (0x60ca, 0x2005),
+    (0x60cb, 0x0a0b),
+    (0x60cc, 0x2253),
+    (0x60cd, 0x2008),
+    (0x60ce, 0x2255),
+    (0x60cf, 0x1ffd),
+    (0x60d1, 0x0bc9),
+    (0x60d3, 0x1ffb),
+    (0x60d4, 0x1ffc),
+    (0x60d5, 0x0a15),
+    (0x60d8, 0x0a14),
+    (0x60d9, 0x1fff),
+    (0x60da, 0x0a19),
+    (0x60db, 0x2003),
+    (0x60dc, 0x0a12),
+    (0x60dd, 0x2000),
+    (0x60df, 0x0a17),
+    (0x60e0, 0x0bcd),
+    (0x60e1, 0x0bca),
+    (0x60e2, 0x2254),
+    (0x60e4, 0x1ffe),
+    (0x60e6, 0x0a0d),
+    (0x60f0, 0x0bd2),
+    (0x60f1, 0x0bd6),
+    (0x60f2, 0x2258),
+    (0x60f3, 0x0d8f),
+    (0x60f4, 0x0bd4),
+    (0x60f5, 0x225c),
+    (0x60f6, 0x0bd8),
+    (0x60f7, 0x24f1),
+    (0x60f8, 0x225e),
+    (0x60f9, 0x0d91),
+    (0x60fa, 0x0bd0),
+    (0x60fb, 0x0bd3),
+    (0x60fc, 0x225f),
+    (0x60fe, 0x2260),
+    (0x60ff, 0x2266),
+    (0x6100, 0x0bda),
+    (0x6101, 0x0d92),
+    (0x6103, 0x2262),
+    (0x6104, 0x2267),
+    (0x6105, 0x225b),
+    (0x6106, 0x0d9c),
+    (0x6108, 0x0d93),
+    (0x6109, 0x0bd9),
+    (0x610a, 0x2259),
+    (0x610b, 0x2268),
+    (0x610d, 0x0d9b),
+    (0x610e, 0x0bd7),
+    (0x610f, 0x0d8c),
+    (0x6110, 0x2265),
+    (0x6112, 0x0bdb),
+    (0x6113, 0x225d),
+    (0x6114, 0x2257),
+    (0x6115, 0x0bd1),
+    (0x6116, 0x225a),
+    (0x6118, 0x2263),
+    (0x611a, 0x0d8b),
+    (0x611b, 0x0d90),
+    (0x611c, 0x0bce),
+    (0x611d, 0x2264),
+    (0x611f, 0x0d8e),
+    (0x6123, 0x0bcf),
+    (0x6127, 0x0d9a),
+    (0x6128, 0x27a1),
+    (0x6129, 0x24fc),
+    (0x612b, 0x24f4),
+    (0x612c, 0x27a0),
+    (0x612e, 0x24f8),
+    (0x612f, 0x24fa),
+    (0x6132, 0x24f7),
+    (0x6134, 0x0d99),
+    (0x6136, 0x24f6),
+    (0x6137, 0x0d9d),
+    (0x613b, 0x27b0),
+    (0x613e, 0x0d98),
+    (0x613f, 0x0f55),
+    (0x6140, 0x24fd),
+    (0x6141, 0x27a2),
+    (0x6144, 0x0d96),
+    (0x6145, 0x24f5),
+    (0x6146, 0x24f9),
+    (0x6147, 0x0f54),
+    (0x6148, 0x0d8d),
+    (0x6149, 0x24f2),
+    (0x614a, 0x24f3),
+    (0x614b, 0x0f56),
+    (0x614c, 0x0d95),
+    (0x614d, 0x0d97),
+    (0x614e, 0x0d94),
+    (0x614f, 0x24fb),
+    (0x6152, 0x27a6),
+    (0x6153, 0x27a7),
+    (0x6154, 0x27ac),
+    (0x6155, 0x10cc),
+    (0x6156, 0x27b3),
+    (0x6158, 0x0f5c),
+    (0x615a, 0x0f5b),
+    (0x615b, 0x27ae),
+    (0x615d, 0x10cb),
+    (0x615e, 0x27a3),
+    (0x615f, 0x0f5a),
+    (0x6161, 0x27b2),
+    (0x6162, 0x0f58),
+    (0x6163, 0x0f59),
+    (0x6165, 0x27af),
+    (0x6166, 0x29fc),
+    (0x6167, 0x10c9),
+    (0x6168, 0x0bd5),
+    (0x616a, 0x27b1),
+    (0x616b, 0x10d0),
+    (0x616c, 0x27a9),
+    (0x616e, 0x10ca),
+    (0x6170, 0x10cf),
+    (0x6171, 0x27a4),
+    (0x6172, 0x27a8),
+    (0x6173, 0x27a5),
+    (0x6174, 0x27ab),
+    (0x6175, 0x0f5d),
+    (0x6176, 0x10c8),
+    (0x6177, 0x0f57),
+    (0x6179, 0x29ee),
+    (0x617a, 0x27ad),
+    (0x617c, 0x10ce),
+    (0x617e, 0x10d1),
+    (0x6180, 0x27aa),
+    (0x6182, 0x10cd),
+    (0x6183, 0x29ed),
+    (0x6189, 0x29f2),
+    (0x618a, 0x1235),
+    (0x618b, 0x29ec),
+    (0x618c, 0x2c69),
+    (0x618d, 0x29fb),
+    (0x618e, 0x10d5),
+    (0x6190, 0x10d3),
+    (0x6191, 0x1233),
+    (0x6192, 0x29f8),
+    (0x6193, 0x29f4),
+    (0x6194, 0x10d9),
+    (0x6196, 0x2c60),
+    (0x619a, 0x10d7),
+    (0x619b, 0x29f3),
+    (0x619d, 0x2c5e),
+    (0x619f, 0x29f7),
+    (0x61a1, 0x29fa),
+    (0x61a2, 0x29f1),
+    (0x61a4, 0x10d8),
+    (0x61a7, 0x10d2),
+    (0x61a8, 0x2c5f),
+    (0x61a9, 0x1234),
+    (0x61aa, 0x29f9),
+    (0x61ab, 0x10d4),
+    (0x61ac, 0x10d6),
+    (0x61ad, 0x29f6),
+    (0x61ae, 0x10da),
+    (0x61af, 0x29f5),
+    (0x61b0, 0x29f0),
+    (0x61b1, 0x29ef),
+    (0x61b2, 0x1232),
+    (0x61b3, 0x29fd),
+    (0x61b4, 0x2c62),
+    (0x61b5, 0x2e7d),
+    (0x61b6, 0x1237),
+    (0x61b8, 0x2c68),
+    (0x61ba, 0x2c66),
+    (0x61bc, 0x2e7e),
+    (0x61be, 0x1238),
+    (0x61bf, 0x2c67),
+    (0x61c1, 0x2c64),
+    (0x61c2, 0x1355),
+    (0x61c3, 0x2e7c),
+    (0x61c5, 0x2c61),
+    (0x61c6, 0x2c63),
+    (0x61c7, 0x1356),
+    (0x61c8, 0x123a),
+    (0x61c9, 0x1354),
+    (0x61ca, 0x1239),
+    (0x61cb, 0x1358),
+    (0x61cc, 0x2c65),
+    (0x61cd, 0x1236),
+    (0x61d6, 0x3043),
+    (0x61d8, 0x303b),
+    (0x61de, 0x2e84),
+    (0x61df, 0x303c),
+    (0x61e0, 0x2e80),
+    (0x61e3, 0x1453),
+    (0x61e4, 0x2e82),
+    (0x61e5, 0x2e81),
+    (0x61e6, 0x1357),
+    (0x61e7, 0x2e7f),
+    (0x61e8, 0x2e83),
+    (0x61e9, 0x3044),
+    (0x61ea, 0x3040),
+    (0x61eb, 0x3042),
+    (0x61ed, 0x303d),
+    (0x61ee, 0x303e),
+    (0x61f0, 0x3041),
+    (0x61f1, 0x303f),
+    (0x61f2, 0x1501),
+    (0x61f5, 0x1504),
+    (0x61f6, 0x1503),
+    (0x61f7, 0x1502),
+    (0x61f8, 0x1598),
+    (0x61f9, 0x32f1),
+    (0x61fa, 0x1599),
+    (0x61fb, 0x31ae),
+    (0x61fc, 0x15f4),
+    (0x61fd, 0x33ee),
+    (0x61fe, 0x15f5),
+    (0x61ff, 0x1648),
+    (0x6200, 0x167c),
+    (0x6201, 0x3535),
+    (0x6203, 0x3536),
+    (0x6204, 0x3537),
+    (0x6207, 0x3650),
+    (0x6208, 0x0255),
+    (0x6209, 0x18c4),
+    (0x620a, 0x02c5),
+    (0x620c, 0x0355),
+    (0x620d, 0x0356),
+    (0x620e, 0x0354),
+    (0x6210, 0x0357),
+    (0x6211, 0x0426),
+    (0x6212, 0x0425),
+    (0x6214, 0x1aae),
+    (0x6215, 0x0555),
+    (0x6216, 0x0554),
+    (0x6219, 0x1dbd),
+    (0x621a, 0x0a1b),
+    (0x621b, 0x0a1c),
+    (0x621f, 0x0bdc),
+    (0x6220, 0x24fe),
+    (0x6221, 0x0d9e),
+    (0x6222, 0x0d9f),
+    (0x6223, 0x2500),
+    (0x6224, 0x2502),
+    (0x6225, 0x2501),
+    (0x6227, 0x27b5),
+    (0x6229, 0x27b4),
+    (0x622a, 0x0f5e),
+    (0x622b, 0x27b6),
+    (0x622d, 0x29fe),
+    (0x622e, 0x10db),
+    (0x6230, 0x123b),
+    (0x6232, 0x1359),
+    (0x6233, 0x1454),
+    (0x6234, 0x135a),
+    (0x6236, 0x0256),
+    (0x623a, 0x19a9),
+    (0x623d, 0x1aaf),
+    (0x623e, 0x0557),
+    (0x623f, 0x0556),
+    (0x6240, 0x0558),
+    (0x6241, 0x06b3),
+    (0x6242, 0x1c09),
+    (0x6243, 0x1c0a),
+    (0x6246, 0x1dbe),
+    (0x6247, 0x0847),
+    (0x6248, 0x0a1d),
+    (0x6249, 0x0bdd),
+    (0x624a, 0x2269),
+    (0x624b, 0x0257),
+    (0x624d, 0x0215),
+    (0x624e, 0x0258),
+    (0x6250, 0x18c5),
+    (0x6251, 0x02c9),
+    (0x6252, 0x02c8),
+    (0x6253, 0x02c6),
+    (0x6254, 0x02c7),
+    (0x6258, 0x035a),
+    (0x6259, 0x190f),
+    (0x625a, 0x1911),
+    (0x625b, 0x0359),
+    (0x625c, 0x1909),
+    (0x625e, 0x190a),
+    (0x6260, 0x1910),
+    (0x6261, 0x190c),
+    (0x6262, 0x190e),
+    (0x6263, 0x0358),
+    (0x6264, 0x190b),
+    (0x6265, 0x1912),
+    (0x6266, 0x190d),
+    (0x626d, 0x042d),
+    (0x626e, 0x0436),
+    (0x626f, 0x0434),
+    (0x6270, 0x19b3),
+    (0x6271, 0x19b0),
+    (0x6272, 0x19b8),
+    (0x6273, 0x0432),
+    (0x6274, 0x19b9),
+    (0x6276, 0x042b),
+    (0x6277, 0x19b6),
+    (0x6279, 0x0431),
+    (0x627a, 0x19b2),
+    (0x627b, 0x19b1),
+    (0x627c, 0x042f),
+    (0x627d, 0x19b7),
+    (0x627e, 0x0430),
+    (0x627f, 0x0559),
+    (0x6280, 0x042a),
+    (0x6281, 0x19b4),
+    (0x6283, 0x19aa),
+    (0x6284, 0x0427),
+    (0x6286, 0x043a),
+    (0x6287, 0x19af),
+    (0x6288, 0x19b5),
+    (0x6289, 0x042c),
+    (0x628a, 0x042e),
+    (0x628c, 0x19ab),
+    (0x628e, 0x19ac),
+    (0x628f, 0x19ad),
+    (0x6291, 0x0439),
+    (0x6292, 0x0433),
+    (0x6293, 0x0438),
+    (0x6294, 0x19ae),
+    (0x6295, 0x0437),
+    (0x6296, 0x0429),
+    (0x6297, 0x0428),
+    (0x6298, 0x0435),
+    (0x62a8, 0x0567),
+    (0x62a9, 0x1abb),
+    (0x62aa, 0x1ab4),
+    (0x62ab, 0x0562),
+    (0x62ac, 0x0575),
+    (0x62ad, 0x1ab0),
+    (0x62ae, 0x1ab7),
+    (0x62af, 0x1ab9),
+    (0x62b0, 0x1abc),
+    (0x62b1, 0x0570),
+    (0x62b3, 0x1ab8),
+    (0x62b4, 0x1ab1),
+    (0x62b5, 0x056e),
+    (0x62b6, 0x1ab5),
+    (0x62b8, 0x1abd),
+    (0x62b9, 0x055f),
+    (0x62bb, 0x1aba),
+    (0x62bc, 0x0569),
+    (0x62bd, 0x0568),
+    (0x62be, 0x1ab3),
+    (0x62bf, 0x055d),
+    (0x62c2, 0x055e),
+    (0x62c4, 0x055c),
+    (0x62c6, 0x0574),
+    (0x62c7, 0x056c),
+    (0x62c8, 0x0566),
+    (0x62c9, 0x055a),
+    (0x62ca, 0x1ab6),
+    (0x62cb, 0x0565),
+    (0x62cc, 0x055b),
+    (0x62cd, 0x056d),
+    (0x62ce, 0x0576),
+    (0x62cf, 0x1c0b),
+    (0x62d0, 0x056a),
+    (0x62d1, 0x1ab2),
+    (0x62d2, 0x0560),
+    (0x62d3, 0x0563),
+    (0x62d4, 0x0564),
+    (0x62d6, 0x0572),
+    (0x62d7, 0x0573),
+    (0x62d8, 0x0571),
+    (0x62d9, 0x056b),
+    (0x62da, 0x056f),
+    (0x62db, 0x0561),
+    (0x62dc, 0x06b4),
+    (0x62eb, 0x1c11),
+    (0x62ec, 0x06c0),
+    (0x62ed, 0x06b8),
+    (0x62ee, 0x06ba),
+    (0x62ef, 0x06bf),
+    (0x62f0, 0x1c1d),
+    (0x62f1, 0x06bd),
+    (0x62f2, 0x1dbf),
+    (0x62f3, 0x0848),
+    (0x62f4, 0x06c2),
+    (0x62f5, 0x1c0e),
+    (0x62f6, 0x1c16),
+    (0x62f7, 0x06be),
+    (0x62f8, 0x1c15),
+    (0x62f9, 0x1c12),
+    (0x62fa, 0x1c1a),
+    (0x62fb, 0x1c1c),
+    (0x62fc, 0x06b7),
+    (0x62fd, 0x06bb),
+    (0x62fe, 0x06c1),
+    (0x62ff, 0x084a),
+    (0x6300, 0x1c17),
+    (0x6301, 0x06b9),
+    (0x6302, 0x06c4),
+    (0x6303, 0x1c10),
+    (0x6307, 0x06bc),
+    (0x6308, 0x0849),
+    (0x6309, 0x06b6),
+    (0x630b, 0x1c0d),
+    (0x630c, 0x1c14),
+    (0x630d, 0x1c0c),
+    (0x630e, 0x1c0f),
+    (0x630f, 0x1c13),
+    (0x6310, 0x1dc0),
+    (0x6311, 0x06c3),
+    (0x6313, 0x1c18),
+    (0x6314, 0x1c19),
+    (0x6315, 0x1c1b),
+    (0x6316, 0x06b5),
+    (0x6328, 0x0858),
+    (0x6329, 0x1dcc),
+    (0x632a, 0x0856),
+    (0x632b, 0x0857),
+    (0x632c, 0x1dc2),
+    (0x632d, 0x1dd2),
+    (0x632f, 0x084d),
+    (0x6332, 0x200a),
+    (0x6333, 0x1dd4),
+    (0x6334, 0x1dce),
+    (0x6336, 0x1dc5),
+    (0x6338, 0x1dd7),
+    (0x6339, 0x1dc8),
+    (0x633a, 0x0853),
+    (0x633b, 0x2021),
+    (0x633c, 0x1dcb),
+    (0x633d, 0x0855),
+    (0x633e, 0x084c),
+    (0x6340, 0x1dd9),
+    (0x6341, 0x1dcd),
+    (0x6342, 0x084f),
+    (0x6343, 0x1dc6),
+    (0x6344, 0x1dc3),
+    (0x6345, 0x1dc4),
+    (0x6346, 0x0850),
+    (0x6347, 0x1dd3),
+    (0x6348, 0x1dda),
+    (0x6349, 0x0852),
+    (0x634a, 0x1dca),
+    (0x634b, 0x1dc9),
+    (0x634c, 0x085a),
+    (0x634d, 0x0859),
+    (0x634e, 0x084b),
+    (0x634f, 0x0851),
+    (0x6350, 0x0854),
+    (0x6351, 0x1dd6),
+    (0x6354, 0x1dd0),
+    (0x6355, 0x084e),
+    (0x6356, 0x1dc1),
+    (0x6357, 0x1dd8),
+    (0x6358, 0x1dcf),
+    (0x6359, 0x1dd1),
+    (0x635a, 0x1dd5),
+    (0x6365, 0x200b),
+    (0x6367, 0x0a25),
+    (0x6368, 0x0a39),
+    (0x6369, 0x0a38),
+    (0x636b, 0x0a2d),
+    (0x636d, 0x201d),
+    (0x636e, 0x2019),
+    (0x636f, 0x2016),
+    (0x6370, 0x2028),
+    (0x6371, 0x0a28),
+    (0x6372, 0x0a20),
+    (0x6375, 0x201b),
+    (0x6376, 0x0bed),
+    (0x6377, 0x0a24),
+    (0x6378, 0x2023),
+    (0x637a, 0x0a3a),
+    (0x637b, 0x0a37),
+    (0x637c, 0x201f),
+    (0x637d, 0x200e),
+    (0x6380, 0x0a36),
+    (0x6381, 0x2025),
+    (0x6382, 0x200d),
+    (0x6383, 0x0a2b),
+    (0x6384, 0x0a2f),
+    (0x6385, 0x2024),
+    (0x6387, 0x2017),
+    (0x6388, 0x0a30),
+    (0x6389, 0x0a2a),
+    (0x638a, 0x200c),
+    (0x638c, 0x0bdf),
+    (0x638d, 0x2027),
+    (0x638e, 0x2015),
+    (0x638f, 0x0a35),
+    (0x6390, 0x2018),
+    (0x6391, 0x2026),
+    (0x6392, 0x0a34),
+    (0x6394, 0x226a),
+    (0x6396, 0x0a21),
+    (0x6397, 0x2013),
+    (0x6398, 0x0a26),
+    (0x6399, 0x0a31),
+    (0x639b, 0x0a2c),
+    (0x639c, 0x201c),
+    (0x639d, 0x2012),
+    (0x639e, 0x2010),
+    (0x639f, 0x2022),
+    (0x63a0, 0x0a1e),
+    (0x63a1, 0x0a32),
+    (0x63a2, 0x0a22),
+    (0x63a3, 0x0bde),
+    (0x63a4, 0x2020),
+    (0x63a5, 0x0a23),
+    (0x63a7, 0x0a1f),
+    (0x63a8, 0x0a2e),
+    (0x63a9, 0x0a29),
+    (0x63aa, 0x0a27),
+    (0x63ab, 0x2014),
+    (0x63ac, 0x0a33),
+    (0x63ad, 0x2011),
+    (0x63ae, 0x201e),
+    (0x63af, 0x201a),
+    (0x63b0, 0x226c),
+    (0x63b1, 0x226b),
+    (0x63bd, 0x200f),
+    (0x63be, 0x227c),
+    (0x63c0, 0x0be1),
+    (0x63c2, 0x2282),
+    (0x63c3, 0x2271),
+    (0x63c4, 0x227f),
+    (0x63c5, 0x2503),
+    (0x63c6, 0x0be4),
+    (0x63c7, 0x2283),
+    (0x63c8, 0x2286),
+    (0x63c9, 0x0be3),
+    (0x63ca, 0x2274),
+    (0x63cb, 0x2285),
+    (0x63cc, 0x2284),
+    (0x63cd, 0x0be5),
+    (0x63ce, 0x226d),
+    (0x63cf, 0x0be0),
+    (0x63d0, 0x0be8),
+    (0x63d2, 0x0be6),
+    (0x63d3, 0x2281),
+    (0x63d5, 0x2277),
+    (0x63d6, 0x0bea),
+    (0x63d7, 0x2288),
+    (0x63d8, 0x2280),
+    (0x63d9, 0x2289),
+    (0x63da, 0x0bf2),
+    (0x63db, 0x0bf0),
+    (0x63dc, 0x227e),
+    (0x63dd, 0x227d),
+    (0x63df, 0x227b),
+    (0x63e0, 0x2275),
+    (0x63e1, 0x0be9),
+    (0x63e3, 0x0be7),
+    (0x63e4, 0x1dc7),
+    (0x63e5, 0x226e),
+    (0x63e7, 0x2521),
+    (0x63e8, 0x226f),
+    (0x63e9, 0x0be2),
+    (0x63ea, 0x0bef),
+    (0x63eb, 0x2505),
+    (0x63ed, 0x0beb),
+    (0x63ee, 0x0bec),
+    (0x63ef, 0x2270),
+    (0x63f0, 0x2287),
+    (0x63f1, 0x2504),
+    (0x63f2, 0x2278),
+    (0x63f3, 0x2273),
+    (0x63f4, 0x0bee),
+    (0x63f5, 0x2279),
+    (0x63f6, 0x2276),
+    (0x63f9, 0x0bf3),
+    (0x6406, 0x0dae),
+    (0x6409, 0x2508),
+    (0x640a, 0x251b),
+    (0x640b, 0x2520),
+    (0x640c, 0x2514),
+    (0x640d, 0x0daa),
+    (0x640e, 0x2525),
+    (0x640f, 0x0da7),
+    (0x6410, 0x2506),
+    (0x6412, 0x2507),
+    (0x6413, 0x0da0),
+    (0x6414, 0x0da9),
+    (0x6415, 0x250e),
+    (0x6416, 0x0dac),
+    (0x6417, 0x0dad),
+    (0x6418, 0x250f),
+    (0x641a, 0x251c),
+    (0x641b, 0x2522),
+    (0x641c, 0x0da8),
+    (0x641e, 0x0da2),
+    (0x641f, 0x250d),
+    (0x6420, 0x2509),
+    (0x6421, 0x2524),
+    (0x6422, 0x2512),
+    (0x6423, 0x2513),
+    (0x6424, 0x250a),
+    (0x6425, 0x251e),
+    (0x6426, 0x2515),
+    (0x6427, 0x251f),
+    (0x6428, 0x2517),
+    (0x642a, 0x0da3),
+    (0x642b, 0x27b7),
+    (0x642c, 0x0da6),
+    (0x642d, 0x0da4),
+    (0x642e, 0x2523),
+    (0x642f, 0x251a),
+    (0x6430, 0x2516),
+    (0x6433, 0x250b),
+    (0x6434, 0x0f68),
+    (0x6435, 0x2519),
+    (0x6436, 0x0dab),
+    (0x6437, 0x2511),
+    (0x6439, 0x2510),
+    (0x643d, 0x0da5),
+    (0x643e, 0x0da1),
+    (0x643f, 0x27cc),
+    (0x6440, 0x251d),
+    (0x6441, 0x2518),
+    (0x6443, 0x250c),
+    (0x644b, 0x27c7),
+    (0x644d, 0x27b8),
+    (0x644e, 0x27c3),
+    (0x6450, 0x27ca),
+    (0x6451, 0x0f66),
+    (0x6452, 0x0bf1),
+    (0x6453, 0x27c8),
+    (0x6454, 0x0f61),
+    (0x6458, 0x0f60),
+    (0x6459, 0x27cf),
+    (0x645b, 0x27b9),
+    (0x645c, 0x27c6),
+    (0x645d, 0x27ba),
+    (0x645e, 0x27c5),
+    (0x645f, 0x0f64),
+    (0x6460, 0x27c9),
+    (0x6461, 0x227a),
+    (0x6465, 0x27d0),
+    (0x6466, 0x27c1),
+    (0x6467, 0x0f67),
+    (0x6468, 0x2a0c),
+    (0x6469, 0x10dc),
+    (0x646b, 0x27ce),
+    (0x646c, 0x27cd),
+    (0x646d, 0x0f69),
+    (0x646e, 0x29ff),
+    (0x646f, 0x10dd),
+    (0x6470, 0x2a00),
+    (0x6472, 0x27bd),
+    (0x6473, 0x27be),
+    (0x6474, 0x27bb),
+    (0x6475, 0x27c0),
+    (0x6476, 0x27bc),
+    (0x6477, 0x27d1),
+    (0x6478, 0x0f63),
+    (0x6479, 0x10de),
+    (0x647a, 0x0f65),
+    (0x647b, 0x0f6a),
+    (0x647d, 0x27bf),
+    (0x647f, 0x27cb),
+    (0x6482, 0x27c4),
+    (0x6485, 0x2a03),
+    (0x6487, 0x0f5f),
+    (0x6488, 0x10e1),
+    (0x6489, 0x2c70),
+    (0x648a, 0x2a08),
+    (0x648b, 0x2a07),
+    (0x648c, 0x2a09),
+    (0x648f, 0x2a06),
+    (0x6490, 0x10e2),
+    (0x6492, 0x10e8),
+    (0x6493, 0x10e5),
+    (0x6495, 0x10e6),
+    (0x6496, 0x2a01),
+    (0x6497, 0x2a04),
+    (0x6498, 0x2a0e),
+    (0x6499, 0x10ee),
+    (0x649a, 0x10ec),
+    (0x649c, 0x2a05),
+    (0x649d, 0x2272),
+    (0x649e, 0x10df),
+    (0x649f, 0x2a0b),
+    (0x64a0, 0x2a02),
+    (0x64a2, 0x10ef),
+    (0x64a3, 0x2a0a),
+    (0x64a4, 0x0f62),
+    (0x64a5, 0x10e4),
+    (0x64a6, 0x27c2),
+    (0x64a9, 0x10e7),
+    (0x64ab, 0x10eb),
+    (0x64ac, 0x10ed),
+    (0x64ad, 0x10ea),
+    (0x64ae, 0x10e9),
+    (0x64b0, 0x10e3),
+    (0x64b1, 0x2a0d),
+    (0x64b2, 0x10e0),
+    (0x64b3, 0x10f0),
+    (0x64bb, 0x123f),
+    (0x64bc, 0x1240),
+    (0x64bd, 0x2c6f),
+    (0x64be, 0x1249),
+    (0x64bf, 0x1246),
+    (0x64c1, 0x123d),
+    (0x64c2, 0x1244),
+    (0x64c3, 0x2c71),
+    (0x64c4, 0x1242),
+    (0x64c5, 0x123c),
+    (0x64c7, 0x1243),
+    (0x64c9, 0x2c6e),
+    (0x64ca, 0x135c),
+    (0x64cb, 0x123e),
+    (0x64cd, 0x1245),
+    (0x64ce, 0x135b),
+    (0x64cf, 0x2c6d),
+    (0x64d0, 0x2c6c),
+    (0x64d2, 0x1247),
+    (0x64d4, 0x1248),
+    (0x64d6, 0x2c6b),
+    (0x64d7, 0x2c6a),
+    (0x64d8, 0x135d),
+    (0x64d9, 0x2c74),
+    (0x64da, 0x1241),
+    (0x64db, 0x2c72),
+    (0x64e0, 0x135e),
+    (0x64e2, 0x1363),
+    (0x64e3, 0x2e87),
+    (0x64e4, 0x2e89),
+    (0x64e6, 0x1360),
+    (0x64e8, 0x2e8a),
+    (0x64e9, 0x2e86),
+    (0x64eb, 0x2e88),
+    (0x64ec, 0x1361),
+    (0x64ed, 0x1364),
+    (0x64ef, 0x2e85),
+    (0x64f0, 0x135f),
+    (0x64f1, 0x1362),
+    (0x64f2, 0x1456),
+    (0x64f3, 0x2c73),
+    (0x64f4, 0x1455),
+    (0x64f7, 0x145b),
+    (0x64f8, 0x3048),
+    (0x64fa, 0x1459),
+    (0x64fb, 0x145a),
+    (0x64fc, 0x304b),
+    (0x64fd, 0x3047),
+    (0x64fe, 0x1457),
+    (0x64ff, 0x3045),
+    (0x6500, 0x1505),
+    (0x6501, 0x3049),
+    (0x6503, 0x304a),
+    (0x6504, 0x3046),
+    (0x6506, 0x1458),
+    (0x6507, 0x31af),
+    (0x6509, 0x31b2),
+    (0x650c, 0x31b3),
+    (0x650d, 0x31b1),
+    (0x650e, 0x31b4),
+    (0x650f, 0x1506),
+    (0x6510, 0x31b0),
+    (0x6513, 0x32f5),
+    (0x6514, 0x159b),
+    (0x6515, 0x32f4),
+    (0x6516, 0x32f3),
+    (0x6517, 0x32f2),
+    (0x6518, 0x159a),
+    (0x6519, 0x159c),
+    (0x651b, 0x33ef),
+    (0x651c, 0x15f7),
+    (0x651d, 0x15f6),
+    (0x6520, 0x34a0),
+    (0x6521, 0x349f),
+    (0x6522, 0x34a2),
+    (0x6523, 0x167d),
+    (0x6524, 0x1649),
+    (0x6525, 0x3539),
+    (0x6526, 0x34a1),
+    (0x6529, 0x3538),
+    (0x652a, 0x167f),
+    (0x652b, 0x167e),
+    (0x652c, 0x16a5),
+    (0x652d, 0x35a2),
+    (0x652e, 0x35e8),
+    (0x652f, 0x0259),
+    (0x6532, 0x228a),
+    (0x6533, 0x2c75),
+    (0x6536, 0x035b),
+    (0x6537, 0x19ba),
+    (0x6538, 0x043d),
+    (0x6539, 0x043b),
+    (0x653b, 0x043c),
+    (0x653d, 0x1abe),
+    (0x653e, 0x0577),
+    (0x653f, 0x06c5),
+    (0x6541, 0x1c1e),
+    (0x6543, 0x1c1f),
+    (0x6545, 0x06c6),
+    (0x6546, 0x1ddc),
+    (0x6548, 0x085b),
+    (0x6549, 0x085c),
+    (0x654a, 0x1ddb),
+    (0x654f, 0x0a41),
+    (0x6551, 0x0a3d),
+    (0x6553, 0x2029),
+    (0x6554, 0x0a44),
+    (0x6555, 0x0a43),
+    (0x6556, 0x0a3c),
+    (0x6557, 0x0a3f),
+    (0x6558, 0x0a42),
+    (0x6559, 0x0a3e),
+    (0x655c, 0x228e),
+    (0x655d, 0x0a3b),
+    (0x655e, 0x0bf4),
+    (0x6562, 0x0bf6),
+    (0x6563, 0x0bf7),
+    (0x6564, 0x228d),
+    (0x6565, 0x2290),
+    (0x6566, 0x0bf5),
+    (0x6567, 0x228b),
+    (0x6568, 0x228f),
+    (0x656a, 0x228c),
+    (0x656c, 0x0daf),
+    (0x656f, 0x2526),
+    (0x6572, 0x0f6b),
+    (0x6573, 0x27d2),
+    (0x6574, 0x124a),
+    (0x6575, 0x10f1),
+    (0x6576, 0x2a0f),
+    (0x6577, 0x10f2),
+    (0x6578, 0x10f3),
+    (0x6579, 0x2a11),
+    (0x657a, 0x2a10),
+    (0x657b, 0x2a12),
+    (0x657c, 0x2c77),
+    (0x657f, 0x2c76),
+    (0x6580, 0x2e8c),
+    (0x6581, 0x2e8b),
+    (0x6582, 0x1365),
+    (0x6583, 0x1366),
+    (0x6584, 0x31b5),
+    (0x6587, 0x025a),
+    (0x658c, 0x2291),
+    (0x6590, 0x0bf9),
+    (0x6591, 0x0bf8),
+    (0x6592, 0x2527),
+    (0x6594, 0x304c),
+    (0x6595, 0x15f8),
+    (0x6596, 0x353a),
+    (0x6597, 0x025b),
+    (0x6599, 0x085d),
+    (0x659b, 0x0a46),
+    (0x659c, 0x0a45),
+    (0x659d, 0x2292),
+    (0x659e, 0x2293),
+    (0x659f, 0x0db0),
+    (0x65a0, 0x27d3),
+    (0x65a1, 0x0f6c),
+    (0x65a2, 0x2c78),
+    (0x65a4, 0x025c),
+    (0x65a5, 0x02ca),
+    (0x65a7, 0x0578),
+    (0x65a8, 0x1abf),
+    (0x65aa, 0x1c20),
+    (0x65ab, 0x06c7),
+    (0x65ac, 0x0a47),
+    (0x65ae, 0x2294),
+    (0x65af, 0x0bfa),
+    (0x65b0, 0x0db1),
+    (0x65b2, 0x2a13),
+    (0x65b3, 0x2a14),
+    (0x65b6, 0x2e8d),
+    (0x65b7, 0x145c),
+    (0x65b8, 0x35e9),
+    (0x65b9, 0x025d),
+    (0x65bb, 0x1ac0),
+    (0x65bc, 0x0579),
+    (0x65bd, 0x06c8),
+    (0x65bf, 0x1c21),
+    (0x65c1, 0x085e),
+    (0x65c2, 0x1de0),
+    (0x65c3, 0x1dde),
+    (0x65c4, 0x1ddf),
+    (0x65c5, 0x085f),
+    (0x65c6, 0x1ddd),
+    (0x65cb, 0x0a49),
+    (0x65cc, 0x0a4a),
+    (0x65cd, 0x202a),
+    (0x65ce, 0x0a4b),
+    (0x65cf, 0x0a48),
+    (0x65d0, 0x2295),
+    (0x65d2, 0x2296),
+    (0x65d3, 0x2528),
+    (0x65d6, 0x0f6e),
+    (0x65d7, 0x0f6d),
+    (0x65da, 0x2e8e),
+    (0x65db, 0x304d),
+    (0x65dd, 0x31b7),
+    (0x65de, 0x31b6),
+    (0x65df, 0x32f6),
+    (0x65e1, 0x18a6),
+    (0x65e2, 0x06c9),
+    (0x65e5, 0x025e),
+    (0x65e6, 0x02cb),
+    (0x65e8, 0x035d),
+    (0x65e9, 0x035c),
+    (0x65ec, 0x035e),
+    (0x65ed, 0x035f),
+    (0x65ee, 0x1914),
+    (0x65ef, 0x1913),
+    (0x65f0, 0x19bb),
+    (0x65f1, 0x043e),
+    (0x65f2, 0x19be),
+    (0x65f3, 0x19bd),
+    (0x65f4, 0x19bc),
+    (0x65f5, 0x19bf),
+    (0x65fa, 0x057a),
+    (0x65fb, 0x1ac6),
+    (0x65fc, 0x1ac2),
+    (0x65fd, 0x1acb),
+    (0x6600, 0x0581),
+    (0x6602, 0x057f),
+    (0x6603, 0x1ac7),
+    (0x6604, 0x1ac3),
+    (0x6605, 0x1aca),
+    (0x6606, 0x057e),
+    (0x6607, 0x0585),
+    (0x6608, 0x1ac5),
+    (0x6609, 0x1ac1),
+    (0x660a, 0x0584),
+    (0x660b, 0x1ac8),
+    (0x660c, 0x057d),
+    (0x660d, 0x1ac9),
+    (0x660e, 0x0580),
+    (0x660f, 0x0582),
+    (0x6610, 0x1acd),
+    (0x6611, 0x1acc),
+    (0x6612, 0x1ac4),
+    (0x6613, 0x057c),
+    (0x6614, 0x057b),
+    (0x6615, 0x0583),
+    (0x661c, 0x1c26),
+    (0x661d, 0x1c2c),
+    (0x661f, 0x06cf),
+    (0x6620, 0x06cc),
+    (0x6621, 0x1c23),
+    (0x6622, 0x1c28),
+    (0x6624, 0x06d2),
+    (0x6625, 0x06ca),
+    (0x6626, 0x1c27),
+    (0x6627, 0x06cd),
+    (0x6628, 0x06d0),
+    (0x662b, 0x1c2a),
+    (0x662d, 0x06cb),
+    (0x662e, 0x1c2f),
+    (0x662f, 0x06ce),
+    (0x6631, 0x06d1),
+    (0x6632, 0x1c24),
+    (0x6633, 0x1c29),
+    (0x6634, 0x1c2d),
+    (0x6635, 0x1c25),
+    (0x6636, 0x1c22),
+    (0x6639, 0x1c2e),
+    (0x663a, 0x1c2b),
+    (0x6641, 0x0867),
+    (0x6642, 0x0860),
+    (0x6643, 0x0863),
+    (0x6645, 0x0866),
+    (0x6647, 0x1de3),
+    (0x6649, 0x0861),
+    (0x664a, 0x1de1),
+    (0x664c, 0x0865),
+    (0x664f, 0x0862),
+    (0x6651, 0x1de4),
+    (0x6652, 0x0864),
+    (0x6659, 0x202e),
+    (0x665a, 0x0a4d),
+    (0x665b, 0x202d),
+    (0x665c, 0x202f),
+    (0x665d, 0x0a4c),
+    (0x665e, 0x0a51),
+    (0x665f, 0x1de2),
+    (0x6661, 0x202c),
+    (0x6662, 0x2030),
+    (0x6664, 0x0a4e),
+    (0x6665, 0x202b),
+    (0x6666, 0x0a50),
+    (0x6668, 0x0a4f),
+    (0x666a, 0x229d),
+    (0x666c, 0x2298),
+    (0x666e, 0x0bfb),
+    (0x666f, 0x0bff),
+    (0x6670, 0x0bfc),
+    (0x6671, 0x229b),
+    (0x6672, 0x229e),
+    (0x6674, 0x0bfd),
+    (0x6676, 0x0bfe),
+    (0x6677, 0x0c03),
+    (0x6678, 0x2531),
+    (0x6679, 0x229c),
+    (0x667a, 0x0c01),
+    (0x667b, 0x2299),
+    (0x667c, 0x2297),
+    (0x667e, 0x0c02),
+    (0x6680, 0x229a),
+    (0x6684, 0x0db7),
+    (0x6686, 0x2529),
+    (0x6687, 0x0db4),
+    (0x6688, 0x0db5),
+    (0x6689, 0x0db3),
+    (0x668a, 0x252e),
+    (0x668b, 0x252d),
+    (0x668c, 0x252a),
+    (0x668d, 0x0db9),
+    (0x6690, 0x252c),
+    (0x6691, 0x0c00),
+    (0x6694, 0x2530),
+    (0x6695, 0x252b),
+    (0x6696, 0x0db6),
+    (0x6697, 0x0db2),
+    (0x6698, 0x0db8),
+    (0x6699, 0x252f),
+    (0x669d, 0x0f71),
+    (0x669f, 0x27d6),
+    (0x66a0, 0x27d5),
+    (0x66a1, 0x27d4),
+    (0x66a2, 0x0f6f),
+    (0x66a8, 0x0f70),
+    (0x66a9, 0x2a17),
+    (0x66aa, 0x2a1a),
+    (0x66ab, 0x10f5),
+    (0x66ae, 0x10f4),
+    (0x66af, 0x2a1b),
+    (0x66b0, 0x2a16),
+    (0x66b1, 0x10f7),
+    (0x66b2, 0x2a18),
+    (0x66b4, 0x10f6),
+    (0x66b5, 0x2a15),
+    (0x66b7, 0x2a19),
+    (0x66b8, 0x1250),
+    (0x66b9, 0x124d),
+    (0x66ba, 0x2c81),
+    (0x66bb, 0x2c80),
+    (0x66bd, 0x2c7f),
+    (0x66be, 0x2c7a),
+    (0x66c0, 0x2c7b),
+    (0x66c4, 0x124e),
+    (0x66c6, 0x124b),
+    (0x66c7, 0x124f),
+    (0x66c8, 0x2c79),
+    (0x66c9, 0x124c),
+    (0x66ca, 0x2c7c),
+    (0x66cb, 0x2c7d),
+    (0x66cc, 0x2c82),
+    (0x66cf, 0x2c7e),
+    (0x66d2, 0x2e8f),
+    (0x66d6, 0x1368),
+    (0x66d8, 0x3050),
+    (0x66d9, 0x1367),
+    (0x66da, 0x304e),
+    (0x66db, 0x304f),
+    (0x66dc, 0x145d),
+    (0x66dd, 0x1508),
+    (0x66de, 0x31b8),
+    (0x66e0, 0x1507),
+    (0x66e3, 0x32f8),
+    (0x66e4, 0x32f9),
+    (0x66e6, 0x159d),
+    (0x66e8, 0x32f7),
+    (0x66e9, 0x15f9),
+    (0x66eb, 0x353b),
+    (0x66ec, 0x1680),
+    (0x66ed, 0x35a3),
+    (0x66ee, 0x35a4),
+    (0x66f0, 0x025f),
+    (0x66f2, 0x0360),
+    (0x66f3, 0x0361),
+    (0x66f4, 0x043f),
+    (0x66f6, 0x1ace),
+    (0x66f7, 0x06d3),
+    (0x66f8, 0x0868),
+    (0x66f9, 0x0a52),
+    (0x66fc, 0x09a3),
+    (0x66fe, 0x0c04),
+    (0x66ff, 0x0c05),
+    (0x6700, 0x0b74),
+    (0x6701, 0x229f),
+    (0x6703, 0x0dba),
+    (0x6704, 0x27d8),
+    (0x6705, 0x27d7),
+    (0x6708, 0x0260),
+    (0x6709, 0x0362),
+    (0x670a, 0x1acf),
+    (0x670b, 0x0587),
+    (0x670d, 0x0586),
+    (0x670f, 0x1c30),
+    (0x6710, 0x1c31),
+    (0x6712, 0x1de5),
+    (0x6713, 0x1de6),
+    (0x6714, 0x0869),
+    (0x6715, 0x086a),
+    (0x6717, 0x086b),
+    (0x6718, 0x2031),
+    (0x671b, 0x0a54),
+    (0x671d, 0x0c07),
+    (0x671f, 0x0c06),
+    (0x6720, 0x2532),
+    (0x6721, 0x2640),
+    (0x6722, 0x27d9),
+    (0x6723, 0x2c83),
+    (0x6726, 0x145e),
+    (0x6727, 0x159e),
+    (0x6728, 0x0261),
+    (0x672a, 0x02ce),
+    (0x672b, 0x02cf),
+    (0x672c, 0x02cd),
+    (0x672d, 0x02d0),
+    (0x672e, 0x02cc),
+    (0x6731, 0x0365),
+    (0x6733, 0x191c),
+    (0x6734, 0x0364),
+    (0x6735, 0x0366),
+    (0x6738, 0x1917),
+    (0x6739, 0x1916),
+    (0x673a, 0x1919),
+    (0x673b, 0x1918),
+    (0x673c, 0x191b),
+    (0x673d, 0x0363),
+    (0x673e, 0x1915),
+    (0x673f, 0x191a),
+    (0x6745, 0x19c0),
+    (0x6746, 0x0449),
+    (0x6747, 0x19c1),
+    (0x6748, 0x19c5),
+    (0x6749, 0x0448),
+    (0x674b, 0x19c9),
+    (0x674c, 0x19c4),
+    (0x674d, 0x19c7),
+    (0x674e, 0x0441),
+    (0x674f, 0x0442),
+    (0x6750, 0x0443),
+    (0x6751, 0x0444),
+    (0x6753, 0x044b),
+    (0x6755, 0x19c3),
+    (0x6756, 0x0446),
+    (0x6757, 0x044c),
+    (0x6759, 0x19c2),
+    (0x675a, 0x19c8),
+    (0x675c, 0x0445),
+    (0x675d, 0x19c6),
+    (0x675e, 0x0447),
+    (0x675f, 0x0440),
+    (0x6760, 0x044a),
+    (0x676a, 0x059c),
+    (0x676c, 0x1ad1),
+    (0x676d, 0x0588),
+    (0x676f, 0x0592),
+    (0x6770, 0x0593),
+    (0x6771, 0x058b),
+    (0x6772, 0x059d),
+    (0x6773, 0x058d),
+    (0x6774, 0x1ad9),
+    (0x6775, 0x0598),
+    (0x6776, 0x1ad4),
+    (0x6777, 0x058e),
+    (0x6778, 0x1ae3),
+    (0x6779, 0x1ae4),
+    (0x677a, 0x1adc),
+    (0x677b, 0x1ad5),
+    (0x677c, 0x059b),
+    (0x677d, 0x1ae1),
+    (0x677e, 0x0596),
+    (0x677f, 0x0594),
+    (0x6781, 0x1ae2),
+    (0x6783, 0x1ae0),
+    (0x6784, 0x1ad8),
+    (0x6785, 0x1ad0),
+    (0x6786, 0x1ad7),
+    (0x6787, 0x058f),
+    (0x6789, 0x0595),
+    (0x678b, 0x0589),
+    (0x678c, 0x1adb),
+    (0x678d, 0x1ada),
+    (0x678e, 0x1ad2),
+    (0x6790, 0x0597),
+    (0x6791, 0x1ade),
+    (0x6792, 0x1ad3),
+    (0x6793, 0x059a),
+    (0x6794, 0x1ae5),
+    (0x6795, 0x058a),
+    (0x6797, 0x0591),
+    (0x6798, 0x1ad6),
+    (0x6799, 0x1adf),
+    (0x679a, 0x0599),
+    (0x679c, 0x058c),
+    (0x679d, 0x0590),
+    (0x679f, 0x1add),
+    (0x67ae, 0x1c51),
+    (0x67af, 0x06db),
+    (0x67b0, 0x06e8),
+    (0x67b2, 0x1c4c),
+    (0x67b3, 0x1c42),
+    (0x67b4, 0x06e1),
+    (0x67b5, 0x1c40),
+    (0x67b6, 0x06da),
+    (0x67b7, 0x1c3b),
+    (0x67b8, 0x06e4),
+    (0x67b9, 0x1c48),
+    (0x67ba, 0x1c35),
+    (0x67bb, 0x1c37),
+    (0x67c0, 0x1c3a),
+    (0x67c1, 0x1c32),
+    (0x67c2, 0x1c47),
+    (0x67c3, 0x1c57),
+    (0x67c4, 0x06df),
+    (0x67c5, 0x1c3c),
+    (0x67c6, 0x1c4e),
+    (0x67c8, 0x1c34),
+    (0x67c9, 0x1c55),
+    (0x67ca, 0x1c56),
+    (0x67cb, 0x1c59),
+    (0x67cc, 0x1c50),
+    (0x67cd, 0x1c41),
+    (0x67ce, 0x1c49),
+    (0x67cf, 0x06e5),
+    (0x67d0, 0x06d8),
+    (0x67d1, 0x06e0),
+    (0x67d2, 0x06ec),
+    (0x67d3, 0x06d5),
+    (0x67d4, 0x06d7),
+    (0x67d8, 0x1c39),
+    (0x67d9, 0x06e9),
+    (0x67da, 0x06e2),
+    (0x67db, 0x1c53),
+    (0x67dc, 0x1c36),
+    (0x67dd, 0x06eb),
+    (0x67de, 0x06e6),
+    (0x67df, 0x1c3f),
+    (0x67e2, 0x06ea),
+    (0x67e3, 0x1c46),
+    (0x67e4, 0x1c3e),
+    (0x67e5, 0x06e3),
+    (0x67e6, 0x1c52),
+    (0x67e7, 0x1c4a),
+    (0x67e9, 0x06dd),
+    (0x67ea, 0x1c58),
+    (0x67eb, 0x1c3d),
+    (0x67ec, 0x06d9),
+    (0x67ed, 0x1c4f),
+    (0x67ee, 0x1c45),
+    (0x67ef, 0x06de),
+    (0x67f0, 0x1c4b),
+    (0x67f1, 0x06d6),
+    (0x67f2, 0x1c33),
+    (0x67f3, 0x06e7),
+    (0x67f4, 0x087a),
+    (0x67f5, 0x06dc),
+    (0x67f6, 0x1c44),
+    (0x67f7, 0x1c43),
+    (0x67f8, 0x1c38),
+    (0x67fa, 0x1c54),
+    (0x67fc, 0x1c4d),
+    (0x67ff, 0x06d4),
+    (0x6812, 0x1dfa),
+    (0x6813, 0x0881),
+    (0x6814, 0x1dfb),
+    (0x6816, 0x1def),
+    (0x6817, 0x0876),
+    (0x6818, 0x0882),
+    (0x681a, 0x1de8),
+    (0x681c, 0x1df1),
+    (0x681d, 0x1df9),
+    (0x681f, 0x1de7),
+    (0x6820, 0x1e02),
+    (0x6821, 0x086c),
+    (0x6825, 0x1e01),
+    (0x6826, 0x1dfc),
+    (0x6828, 0x1dfd),
+    (0x6829, 0x0874),
+    (0x682a, 0x087f),
+    (0x682b, 0x1df3),
+    (0x682d, 0x1df4),
+    (0x682e, 0x1dfe),
+    (0x682f, 0x1df5),
+    (0x6831, 0x1df0),
+    (0x6832, 0x1dea),
+    (0x6833, 0x1deb),
+    (0x6834, 0x1df8),
+    (0x6835, 0x1df2),
+    (0x6838, 0x086d),
+    (0x6839, 0x0871),
+    (0x683a, 0x1e00),
+    (0x683b, 0x1dec),
+    (0x683c, 0x087d),
+    (0x683d, 0x0879),
+    (0x6840, 0x087c),
+    (0x6841, 0x0883),
+    (0x6842, 0x0872),
+    (0x6843, 0x087e),
+    (0x6844, 0x1df7),
+    (0x6845, 0x0880),
+    (0x6846, 0x086f),
+    (0x6848, 0x086e),
+    (0x6849, 0x1de9),
+    (0x684b, 0x1ded),
+    (0x684c, 0x0877),
+    (0x684d, 0x1dff),
+    (0x684e, 0x1df6),
+    (0x684f, 0x1dee),
+    (0x6850, 0x087b),
+    (0x6851, 0x0878),
+    (0x6853, 0x0870),
+    (0x6854, 0x0873),
+    (0x686b, 0x2046),
+    (0x686d, 0x2036),
+    (0x686e, 0x2037),
+    (0x686f, 0x203b),
+    (0x6871, 0x204a),
+    (0x6872, 0x2047),
+    (0x6874, 0x2040),
+    (0x6875, 0x203f),
+    (0x6876, 0x0a5b),
+    (0x6877, 0x2043),
+    (0x6878, 0x2052),
+    (0x6879, 0x2032),
+    (0x687b, 0x2053),
+    (0x687c, 0x2045),
+    (0x687d, 0x2057),
+    (0x687e, 0x204b),
+    (0x687f, 0x0a5a),
+    (0x6880, 0x2049),
+    (0x6881, 0x0a55),
+    (0x6882, 0x0a6a),
+    (0x6883, 0x0a60),
+    (0x6885, 0x0a64),
+    (0x6886, 0x0a63),
+    (0x6887, 0x2033),
+    (0x6889, 0x2050),
+    (0x688a, 0x2056),
+    (0x688b, 0x204e),
+    (0x688c, 0x2055),
+    (0x688f, 0x2042),
+    (0x6890, 0x2034),
+    (0x6891, 0x2054),
+    (0x6892, 0x2044),
+    (0x6893, 0x0a58),
+    (0x6894, 0x0a65),
+    (0x6896, 0x204d),
+    (0x6897, 0x0a5e),
+    (0x689b, 0x204c),
+    (0x689c, 0x2035),
+    (0x689d, 0x0a66),
+    (0x689f, 0x0a68),
+    (0x68a0, 0x204f),
+    (0x68a1, 0x0a69),
+    (0x68a2, 0x0a57),
+    (0x68a3, 0x203c),
+    (0x68a4, 0x2051),
+    (0x68a7, 0x0a5d),
+    (0x68a8, 0x0a67),
+    (0x68a9, 0x203e),
+    (0x68aa, 0x2048),
+    (0x68ab, 0x2039),
+    (0x68ac, 0x203d),
+    (0x68ad, 0x0a62),
+    (0x68ae, 0x2038),
+    (0x68af, 0x0a56),
+    (0x68b0, 0x0a5f),
+    (0x68b1, 0x0a5c),
+    (0x68b2, 0x2041),
+    (0x68b3, 0x0875),
+    (0x68b4, 0x22b6),
+    (0x68b5, 0x0a59),
+    (0x68c4, 0x0a61),
+    (0x68c6, 0x22b9),
+    (0x68c7, 0x22cf),
+    (0x68c8, 0x22c4),
+    (0x68c9, 0x0c1b),
+    (0x68cb, 0x0c16),
+    (0x68cc, 0x22b3),
+    (0x68cd, 0x0c17),
+    (0x68ce, 0x22c3),
+    (0x68d0, 0x22bc),
+    (0x68d1, 0x22c9),
+    (0x68d2, 0x0c13),
+    (0x68d3, 0x22a1),
+    (0x68d4, 0x22cb),
+    (0x68d5, 0x0c09),
+    (0x68d6, 0x22a9),
+    (0x68d7, 0x0c0c),
+    (0x68d8, 0x0c0b),
+    (0x68da, 0x0c1c),
+    (0x68dc, 0x22a3),
+    (0x68dd, 0x22c5),
+    (0x68de, 0x22c6),
+    (0x68df, 0x0c0e),
+    (0x68e0, 0x0c0a),
+    (0x68e1, 0x22b1),
+    (0x68e3, 0x0c15),
+    (0x68e4, 0x22ac),
+    (0x68e6, 0x22c7),
+    (0x68e7, 0x0c11),
+    (0x68e8, 0x22bf),
+    (0x68e9, 0x22cc),
+    (0x68ea, 0x22a6),
+    (0x68eb, 0x22ab),
+    (0x68ec, 0x22a5),
+    (0x68ee, 0x0c10),
+    (0x68ef, 0x22b8),
+    (0x68f0, 0x2548),
+    (0x68f1, 0x22a7),
+    (0x68f2, 0x0c14),
+    (0x68f3, 0x22b0),
+    (0x68f4, 0x22c8),
+    (0x68f5, 0x0c0f),
+    (0x68f6, 0x22ad),
+    (0x68f7, 0x22aa),
+    (0x68f8, 0x22bb),
+    (0x68f9, 0x0c12),
+    (0x68fa, 0x0c08),
+    (0x68fb, 0x0c1e),
+    (0x68fc, 0x22be),
+    (0x68fd, 0x22bd),
+    (0x6904, 0x22a2),
+    (0x6905, 0x0c0d),
+    (0x6906, 0x22ca),
+    (0x6907, 0x22b2),
+    (0x6908, 0x22b4),
+    (0x690a, 0x22c1),
+    (0x690b, 0x22c0),
+    (0x690c, 0x22a0),
+    (0x690d, 0x0c18),
+    (0x690e, 0x0c1a),
+    (0x690f, 0x22a8),
+    (0x6910, 0x22af),
+    (0x6911, 0x22b7),
+    (0x6912, 0x0c19),
+    (0x6913, 0x22ae),
+    (0x6914, 0x22ba),
+    (0x6915, 0x22cd),
+    (0x6917, 0x22c2),
+    (0x6925, 0x22ce),
+    (0x692a, 0x22a4),
+    (0x692f, 0x255c),
+    (0x6930, 0x0dc2),
+    (0x6932, 0x255a),
+    (0x6933, 0x2545),
+    (0x6934, 0x254a),
+    (0x6935, 0x2543),
+    (0x6937, 0x2556),
+    (0x6938, 0x2535),
+    (0x6939, 0x253c),
+    (0x693b, 0x2554),
+    (0x693c, 0x255e),
+    (0x693d, 0x2546),
+    (0x693f, 0x2539),
+    (0x6940, 0x254c),
+    (0x6941, 0x2551),
+    (0x6942, 0x253d),
+    (0x6944, 0x254e),
+    (0x6945, 0x253a),
+    (0x6948, 0x2541),
+    (0x6949, 0x2542),
+    (0x694a, 0x0dc4),
+    (0x694b, 0x2555),
+    (0x694c, 0x2553),
+    (0x694e, 0x2536),
+    (0x694f, 0x2558),
+    (0x6951, 0x2559),
+    (0x6952, 0x255b),
+    (0x6953, 0x0dc8),
+    (0x6954, 0x0dc0),
+    (0x6956, 0x203a),
+    (0x6957, 0x253e),
+    (0x6958, 0x2550),
+    (0x6959, 0x253f),
+    (0x695a, 0x0dbd),
+    (0x695b, 0x0dcd),
+    (0x695c, 0x2557),
+    (0x695d, 0x0dcb),
+    (0x695e, 0x0dc7),
+    (0x695f, 0x2534),
+    (0x6960, 0x0dbf),
+    (0x6962, 0x2537),
+    (0x6963, 0x0dcc),
+    (0x6965, 0x2547),
+    (0x6966, 0x2533),
+    (0x6968, 0x0dc5),
+    (0x6969, 0x254b),
+    (0x696a, 0x253b),
+    (0x696b, 0x0dc6),
+    (0x696c, 0x2544),
+    (0x696d, 0x0dbc),
+    (0x696e, 0x0c1d),
+    (0x696f, 0x254d),
+    (0x6970, 0x22b5),
+    (0x6971, 0x2538),
+    (0x6974, 0x2552),
+    (0x6975, 0x0dc1),
+    (0x6976, 0x254f),
+    (0x6977, 0x0dbe),
+    (0x6978, 0x2549),
+    (0x6979, 0x0dc9),
+    (0x697a, 0x2540),
+    (0x697b, 0x255d),
+    (0x6982, 0x0dc3),
+    (0x6983, 0x25cd),
+    (0x6986, 0x0dca),
+    (0x698d, 0x27e7),
+    (0x698e, 0x27e5),
+    (0x6990, 0x27fb),
+    (0x6991, 0x27e3),
+    (0x6993, 0x27f5),
+    (0x6994, 0x0dbb),
+    (0x6995, 0x0f74),
+    (0x6996, 0x27df),
+    (0x6997, 0x27fa),
+    (0x6999, 0x27e4),
+    (0x699a, 0x27f2),
+    (0x699b, 0x0f79),
+    (0x699c, 0x0f72),
+    (0x699e, 0x27f8),
+    (0x69a0, 0x27dd),
+    (0x69a1, 0x27f7),
+    (0x69a3, 0x0f84),
+    (0x69a4, 0x27ee),
+    (0x69a5, 0x27fe),
+    (0x69a6, 0x0f82),
+    (0x69a7, 0x27e6),
+    (0x69a8, 0x0f73),
+    (0x69a9, 0x27e8),
+    (0x69aa, 0x27f6),
+    (0x69ab, 0x0f7c),
+    (0x69ac, 0x27e1),
+    (0x69ad, 0x0f80),
+    (0x69ae, 0x0f76),
+    (0x69af, 0x27ea),
+    (0x69b0, 0x27e0),
+    (0x69b1, 0x27da),
+    (0x69b3, 0x27f4),
+    (0x69b4, 0x0f7d),
+    (0x69b5, 0x27fd),
+    (0x69b6, 0x27db),
+    (0x69b7, 0x0f7a),
+    (0x69b9, 0x27f0),
+    (0x69bb, 0x0f7b),
+    (0x69bc, 0x27e2),
+    (0x69bd, 0x27ed),
+    (0x69be, 0x27e9),
+    (0x69bf, 0x27eb),
+    (0x69c1, 0x0f75),
+    (0x69c2, 0x27fc),
+    (0x69c3, 0x0f83),
+    (0x69c4, 0x27ec),
+    (0x69c6, 0x27ff),
+    (0x69c9, 0x27dc),
+    (0x69ca, 0x27f1),
+    (0x69cb, 0x0f78),
+    (0x69cc, 0x0f81),
+    (0x69cd, 0x0f7f),
+    (0x69ce, 0x27de),
+    (0x69cf, 0x27f3),
+    (0x69d0, 0x0f7e),
+    (0x69d3, 0x0f77),
+    (0x69d4, 0x27ef),
+    (0x69d9, 0x27f9),
+    (0x69e2, 0x2a27),
+    (0x69e4, 0x2a23),
+    (0x69e5, 0x2a1f),
+    (0x69e6, 0x2a33),
+    (0x69e7, 0x2a30),
+    (0x69e8, 0x10fa),
+    (0x69eb, 0x2a37),
+    (0x69ec, 0x2a26),
+    (0x69ed, 0x1105),
+    (0x69ee, 0x2a2d),
+    (0x69f1, 0x2a22),
+    (0x69f2, 0x2a2c),
+    (0x69f3, 0x1102),
+    (0x69f4, 0x2a40),
+    (0x69f6, 0x2a3d),
+    (0x69f7, 0x2a2f),
+    (0x69f8, 0x2a20),
+    (0x69fb, 0x2a34),
+    (0x69fc, 0x2a36),
+    (0x69fd, 0x10fe),
+    (0x69fe, 0x2a2a),
+    (0x69ff, 0x2a25),
+    (0x6a00, 0x2a1c),
+    (0x6a01, 0x10fb),
+    (0x6a02, 0x1103),
+    (0x6a04, 0x2a39),
+    (0x6a05, 0x1104),
+    (0x6a06, 0x2a1d),
+    (0x6a07, 0x2a3f),
+    (0x6a08, 0x2a32),
+    (0x6a09, 0x2a38),
+    (0x6a0a, 0x1101),
+    (0x6a0d, 0x2a35),
+    (0x6a0f, 0x2a3c),
+    (0x6a11, 0x1106),
+    (0x6a13, 0x1100),
+    (0x6a14, 0x2a2e),
+    (0x6a15, 0x2a21),
+    (0x6a16, 0x2a41),
+    (0x6a17, 0x2a1e),
+    (0x6a18, 0x2a3a),
+    (0x6a19, 0x10fd),
+    (0x6a1b, 0x2a28),
+    (0x6a1d, 0x2a29),
+    (0x6a1e, 0x10fc),
+    (0x6a1f, 0x10f9),
+    (0x6a20, 0x2a24),
+    (0x6a21, 0x10ff),
+    (0x6a23, 0x10f8),
+    (0x6a25, 0x2a3b),
+    (0x6a26, 0x2a3e),
+    (0x6a27, 0x2a2b),
+    (0x6a28, 0x2c90),
+    (0x6a32, 0x2c88),
+    (0x6a34, 0x2c84),
+    (0x6a35, 0x125d),
+    (0x6a38, 0x1252),
+    (0x6a39, 0x1257),
+    (0x6a3a, 0x1253),
+    (0x6a3b, 0x2c92),
+    (0x6a3c, 0x2c9d),
+    (0x6a3d, 0x1251),
+    (0x6a3e, 0x2c8a),
+    (0x6a3f, 0x2c93),
+    (0x6a40, 0x2a31),
+    (0x6a41, 0x2c94),
+    (0x6a44, 0x1258),
+    (0x6a46, 0x2ca3),
+    (0x6a47, 0x125c),
+    (0x6a48, 0x125f),
+    (0x6a49, 0x2c86),
+    (0x6a4b, 0x125b),
+    (0x6a4d, 0x2ca1),
+    (0x6a4e, 0x2ca2),
+    (0x6a4f, 0x2c98),
+    (0x6a50, 0x2c97),
+    (0x6a51, 0x2c8f),
+    (0x6a54, 0x2c99),
+    (0x6a55, 0x2ca0),
+    (0x6a56, 0x2c9f),
+    (0x6a58, 0x1256),
+    (0x6a59, 0x1254),
+    (0x6a5a, 0x2c91),
+    (0x6a5b, 0x2c8e),
+    (0x6a5d, 0x2c8b),
+    (0x6a5e, 0x2c9e),
+    (0x6a5f, 0x125e),
+    (0x6a60, 0x2c9c),
+    (0x6a61, 0x125a),
+    (0x6a62, 0x1259),
+    (0x6a64, 0x2c96),
+    (0x6a66, 0x2c85),
+    (0x6a67, 0x2c87),
+    (0x6a68, 0x2c89),
+    (0x6a69, 0x2c9b),
+    (0x6a6a, 0x2c95),
+    (0x6a6b, 0x1255),
+    (0x6a6d, 0x2c8c),
+    (0x6a6f, 0x2c9a),
+    (0x6a76, 0x2c8d),
+    (0x6a7e, 0x1370),
+    (0x6a7f, 0x2ea1),
+    (0x6a80, 0x1369),
+    (0x6a81, 0x2e92),
+    (0x6a83, 0x2e9d),
+    (0x6a84, 0x136b),
+    (0x6a85, 0x2ea4),
+    (0x6a87, 0x2e99),
+    (0x6a89, 0x2e94),
+    (0x6a8c, 0x2ea5),
+    (0x6a8d, 0x2e90),
+    (0x6a8e, 0x2e9b),
+    (0x6a90, 0x1372),
+    (0x6a91, 0x2ea0),
+    (0x6a92, 0x2ea6),
+    (0x6a93, 0x2e9a),
+    (0x6a94, 0x136a),
+    (0x6a95, 0x2e9c),
+    (0x6a96, 0x2e91),
+    (0x6a97, 0x1371),
+    (0x6a9a, 0x2ea3),
+    (0x6a9b, 0x2e96),
+    (0x6a9c, 0x136d),
+    (0x6a9e, 0x2e98),
+    (0x6a9f, 0x2e95),
+    (0x6aa0, 0x1373),
+    (0x6aa1, 0x2e97),
+    (0x6aa2, 0x136c),
+    (0x6aa3, 0x136f),
+    (0x6aa4, 0x2e9f),
+    (0x6aa5, 0x2e93),
+    (0x6aa6, 0x2ea2),
+    (0x6aa8, 0x2e9e),
+    (0x6aac, 0x1460),
+    (0x6aad, 0x305b),
+    (0x6aae, 0x1465),
+    (0x6aaf, 0x1466),
+    (0x6ab3, 0x145f),
+    (0x6ab4, 0x305a),
+    (0x6ab6, 0x3057),
+    (0x6ab7, 0x3058),
+    (0x6ab8, 0x1463),
+    (0x6ab9, 0x3052),
+    (0x6aba, 0x3056),
+    (0x6abb, 0x1462),
+    (0x6abd, 0x3053),
+    (0x6ac2, 0x1464),
+    (0x6ac3, 0x1461),
+    (0x6ac5, 0x3051),
+    (0x6ac6, 0x3055),
+    (0x6ac7, 0x3059),
+    (0x6acb, 0x31be),
+    (0x6acc, 0x31bb),
+    (0x6acd, 0x31c4),
+    (0x6acf, 0x31c3),
+    (0x6ad0, 0x31c1),
+    (0x6ad1, 0x31bc),
+    (0x6ad3, 0x150c),
+    (0x6ad9, 0x31bd),
+    (0x6ada, 0x150b),
+    (0x6adb, 0x136e),
+    (0x6adc, 0x31c0),
+    (0x6add, 0x150a),
+    (0x6ade, 0x31c5),
+    (0x6adf, 0x31bf),
+    (0x6ae0, 0x31ba),
+    (0x6ae1, 0x3054),
+    (0x6ae5, 0x1509),
+    (0x6ae7, 0x31b9),
+    (0x6ae8, 0x32fd),
+    (0x6aea, 0x32fc),
+    (0x6aeb, 0x31c2),
+    (0x6aec, 0x159f),
+    (0x6aee, 0x3300),
+    (0x6aef, 0x3301),
+    (0x6af0, 0x32fb),
+    (0x6af1, 0x32ff),
+    (0x6af3, 0x32fa),
+    (0x6af8, 0x33f3),
+    (0x6af9, 0x32fe),
+    (0x6afa, 0x15fc),
+    (0x6afb, 0x15fa),
+    (0x6afc, 0x33f1),
+    (0x6b00, 0x33f4),
+    (0x6b02, 0x33f0),
+    (0x6b03, 0x33f2),
+    (0x6b04, 0x15fb),
+    (0x6b08, 0x34a4),
+    (0x6b09, 0x34a5),
+    (0x6b0a, 0x164a),
+    (0x6b0b, 0x34a3),
+    (0x6b0f, 0x353e),
+    (0x6b10, 0x1681),
+    (0x6b11, 0x353c),
+    (0x6b12, 0x353d),
+    (0x6b13, 0x35a5),
+    (0x6b16, 0x16c7),
+    (0x6b17, 0x35ec),
+    (0x6b18, 0x35ea),
+    (0x6b19, 0x35eb),
+    (0x6b1a, 0x35ed),
+    (0x6b1e, 0x3651),
+    (0x6b20, 0x0262),
+    (0x6b21, 0x0367),
+    (0x6b23, 0x059e),
+    (0x6b25, 0x1ae6),
+    (0x6b28, 0x1c5a),
+    (0x6b2c, 0x1e03),
+    (0x6b2d, 0x1e05),
+    (0x6b2f, 0x1e04),
+    (0x6b31, 0x1e06),
+    (0x6b32, 0x0a6b),
+    (0x6b33, 0x2059),
+    (0x6b34, 0x1e07),
+    (0x6b36, 0x2058),
+    (0x6b37, 0x205a),
+    (0x6b38, 0x205b),
+    (0x6b39, 0x22d0),
+    (0x6b3a, 0x0c20),
+    (0x6b3b, 0x22d1),
+    (0x6b3c, 0x22d3),
+    (0x6b3d, 0x0c21),
+    (0x6b3e, 0x0c1f),
+    (0x6b3f, 0x22d2),
+    (0x6b41, 0x2564),
+    (0x6b42, 0x2562),
+    (0x6b43, 0x2561),
+    (0x6b45, 0x2560),
+    (0x6b46, 0x255f),
+    (0x6b47, 0x0dce),
+    (0x6b48, 0x2563),
+    (0x6b49, 0x0f85),
+    (0x6b4a, 0x2800),
+    (0x6b4b, 0x2802),
+    (0x6b4c, 0x0f86),
+    (0x6b4d, 0x2801),
+    (0x6b4e, 0x1108),
+    (0x6b50, 0x1107),
+    (0x6b51, 0x2a42),
+    (0x6b54, 0x2ca5),
+    (0x6b55, 0x2ca4),
+    (0x6b56, 0x2ca6),
+    (0x6b59, 0x1260),
+    (0x6b5b, 0x2ea7),
+    (0x6b5c, 0x1374),
+    (0x6b5e, 0x305c),
+    (0x6b5f, 0x1467),
+    (0x6b60, 0x31c6),
+    (0x6b61, 0x164b),
+    (0x6b62, 0x0263),
+    (0x6b63, 0x02d1),
+    (0x6b64, 0x0368),
+    (0x6b65, 0x044d),
+    (0x6b66, 0x059f),
+    (0x6b67, 0x05a0),
+    (0x6b6a, 0x06ed),
+    (0x6b6d, 0x1e08),
+    (0x6b72, 0x0dcf),
+    (0x6b76, 0x2ac0),
+    (0x6b77, 0x1261),
+    (0x6b78, 0x1468),
+    (0x6b79, 0x0264),
+    (0x6b7b, 0x0369),
+    (0x6b7e, 0x1ae8),
+    (0x6b7f, 0x05a1),
+    (0x6b80, 0x1ae7),
+    (0x6b82, 0x1c5b),
+    (0x6b83, 0x06ee),
+    (0x6b84, 0x1c5c),
+    (0x6b86, 0x06ef),
+    (0x6b88, 0x1e0a),
+    (0x6b89, 0x0885),
+    (0x6b8a, 0x0884),
+    (0x6b8c, 0x2060),
+    (0x6b8d, 0x205e),
+    (0x6b8e, 0x205f),
+    (0x6b8f, 0x205d),
+    (0x6b91, 0x205c),
+    (0x6b94, 0x22d4),
+    (0x6b95, 0x22d7),
+    (0x6b96, 0x0c23),
+    (0x6b97, 0x22d5),
+    (0x6b98, 0x0c22),
+    (0x6b99, 0x22d6),
+    (0x6b9b, 0x2565),
+    (0x6b9e, 0x2803),
+    (0x6b9f, 0x2804),
+    (0x6ba0, 0x2805),
+    (0x6ba2, 0x2a45),
+    (0x6ba3, 0x2a44),
+    (0x6ba4, 0x1109),
+    (0x6ba5, 0x2a43),
+    (0x6ba6, 0x2a46),
+    (0x6ba7, 0x2ca7),
+    (0x6baa, 0x2ca8),
+    (0x6bab, 0x2ca9),
+    (0x6bad, 0x2ea8),
+    (0x6bae, 0x1375),
+    (0x6baf, 0x1469),
+    (0x6bb0, 0x31c7),
+    (0x6bb2, 0x15fd),
+    (0x6bb3, 0x18a7),
+    (0x6bb5, 0x06f0),
+    (0x6bb6, 0x1c5d),
+    (0x6bb7, 0x0886),
+    (0x6bba, 0x0a6c),
+    (0x6bbc, 0x0c24),
+    (0x6bbd, 0x22d8),
+    (0x6bbf, 0x0dd1),
+    (0x6bc0, 0x0dd0),
+    (0x6bc3, 0x2806),
+    (0x6bc4, 0x2807),
+    (0x6bc5, 0x110a),
+    (0x6bc6, 0x110b),
+    (0x6bc7, 0x2cab),
+    (0x6bc8, 0x2caa),
+    (0x6bc9, 0x305d),
+    (0x6bca, 0x353f),
+    (0x6bcb, 0x0265),
+    (0x6bcc, 0x18a8),
+    (0x6bcd, 0x02d2),
+    (0x6bcf, 0x044e),
+    (0x6bd0, 0x19ca),
+    (0x6bd2, 0x06f1),
+    (0x6bd3, 0x0dd2),
+    (0x6bd4, 0x0266),
+    (0x6bd6, 0x1c5e),
+    (0x6bd7, 0x06f2),
+    (0x6bd8, 0x1c5f),
+    (0x6bda, 0x1376),
+    (0x6bdb, 0x0267),
+    (0x6bde, 0x1ae9),
+    (0x6be0, 0x1c60),
+    (0x6be2, 0x1e0f),
+    (0x6be3, 0x1e0e),
+    (0x6be4, 0x1e0c),
+    (0x6be6, 0x1e0b),
+    (0x6be7, 0x1e10),
+    (0x6be8, 0x1e0d),
+    (0x6beb, 0x0a6d),
+    (0x6bec, 0x0a6e),
+    (0x6bef, 0x0c25),
+    (0x6bf0, 0x22d9),
+    (0x6bf2, 0x22da),
+    (0x6bf3, 0x22db),
+    (0x6bf7, 0x256a),
+    (0x6bf8, 0x256b),
+    (0x6bf9, 0x2569),
+    (0x6bfb, 0x2567),
+    (0x6bfc, 0x2568),
+    (0x6bfd, 0x0dd3),
+    (0x6bfe, 0x2808),
+    (0x6bff, 0x2a49),
+    (0x6c00, 0x2a48),
+    (0x6c01, 0x2a47),
+    (0x6c02, 0x2a4a),
+    (0x6c03, 0x2cad),
+    (0x6c04, 0x2cac),
+    (0x6c05, 0x1262),
+    (0x6c06, 0x2cae),
+    (0x6c08, 0x1377),
+    (0x6c09, 0x2ea9),
+    (0x6c0b, 0x305e),
+    (0x6c0c, 0x31c8),
+    (0x6c0d, 0x34a6),
+    (0x6c0f, 0x0268),
+    (0x6c10, 0x02d4),
+    (0x6c11, 0x02d3),
+    (0x6c13, 0x05a2),
+    (0x6c14, 0x18a9),
+    (0x6c15, 0x18c6),
+    (0x6c16, 0x036a),
+    (0x6c18, 0x191d),
+    (0x6c19, 0x19cb),
+    (0x6c1a, 0x19cc),
+    (0x6c1b, 0x05a3),
+    (0x6c1d, 0x1aea),
+    (0x6c1f, 0x06f3),
+    (0x6c20, 0x1c61),
+    (0x6c21, 0x1c62),
+    (0x6c23, 0x0887),
+    (0x6c24, 0x088b),
+    (0x6c25, 0x1e11),
+    (0x6c26, 0x088a),
+    (0x6c27, 0x0888),
+    (0x6c28, 0x0889),
+    (0x6c2a, 0x2061),
+    (0x6c2b, 0x0a6f),
+    (0x6c2c, 0x0c28),
+    (0x6c2e, 0x0c26),
+    (0x6c2f, 0x0c27),
+    (0x6c30, 0x22dc),
+    (0x6c33, 0x0f87),
+    (0x6c34, 0x0269),
+    (0x6c36, 0x18c7),
+    (0x6c38, 0x02d5),
+    (0x6c3b, 0x18ca),
+    (0x6c3e, 0x02d8),
+    (0x6c3f, 0x18c9),
+    (0x6c40, 0x02d7),
+    (0x6c41, 0x02d6),
+    (0x6c42, 0x044f),
+    (0x6c43, 0x18c8),
+    (0x6c46, 0x191e),
+    (0x6c4a, 0x1922),
+    (0x6c4b, 0x1924),
+    (0x6c4c, 0x1925),
+    (0x6c4d, 0x0374),
+    (0x6c4e, 0x0375),
+    (0x6c4f, 0x1921),
+    (0x6c50, 0x0370),
+    (0x6c52, 0x191f),
+    (0x6c54, 0x1923),
+    (0x6c55, 0x0371),
+    (0x6c57, 0x036c),
+    (0x6c59, 0x036d),
+    (0x6c5b, 0x0373),
+    (0x6c5c, 0x1920),
+    (0x6c5d, 0x036b),
+    (0x6c5e, 0x0450),
+    (0x6c5f, 0x036e),
+    (0x6c60, 0x036f),
+    (0x6c61, 0x0372),
+    (0x6c65, 0x19dd),
+    (0x6c66, 0x19db),
+    (0x6c67, 0x19ce),
+    (0x6c68, 0x045c),
+    (0x6c69, 0x19d5),
+    (0x6c6a, 0x0457),
+    (0x6c6b, 0x19cf),
+    (0x6c6d, 0x19d7),
+    (0x6c6f, 0x19d4),
+    (0x6c70, 0x045a),
+    (0x6c71, 0x19d3),
+    (0x6c72, 0x0461),
+    (0x6c73, 0x19dc),
+    (0x6c74, 0x0463),
+    (0x6c76, 0x0465),
+    (0x6c78, 0x19cd),
+    (0x6c7a, 0x0458),
+    (0x6c7b, 0x19de),
+    (0x6c7d, 0x045f),
+    (0x6c7e, 0x0462),
+    (0x6c80, 0x1b01),
+    (0x6c81, 0x0452),
+    (0x6c82, 0x0469),
+    (0x6c83, 0x0460),
+    (0x6c84, 0x19d0),
+    (0x6c85, 0x0455),
+    (0x6c86, 0x0464),
+    (0x6c87, 0x19d8),
+    (0x6c88, 0x0453),
+    (0x6c89, 0x0454),
+    (0x6c8a, 0x1aff),
+    (0x6c8b, 0x19d1),
+    (0x6c8c, 0x045b),
+    (0x6c8d, 0x0466),
+    (0x6c8e, 0x19df),
+    (0x6c8f, 0x19d2),
+    (0x6c90, 0x0459),
+    (0x6c92, 0x045e),
+    (0x6c93, 0x1aeb),
+    (0x6c94, 0x0467),
+    (0x6c95, 0x19d9),
+    (0x6c96, 0x045d),
+    (0x6c98, 0x0468),
+    (0x6c99, 0x0451),
+    (0x6c9a, 0x19d6),
+    (0x6c9b, 0x0456),
+    (0x6c9c, 0x19da),
+    (0x6c9d, 0x1b00),
+    (0x6cab, 0x05af),
+    (0x6cac, 0x05bf),
+    (0x6cad, 0x1af2),
+    (0x6cae, 0x05b6),
+    (0x6cb0, 0x1b07),
+    (0x6cb1, 0x05a7),
+    (0x6cb3, 0x05aa),
+    (0x6cb4, 0x1afe),
+    (0x6cb6, 0x1af0),
+    (0x6cb7, 0x1af4),
+    (0x6cb8, 0x05b2),
+    (0x6cb9, 0x05b4),
+    (0x6cba, 0x1af7),
+    (0x6cbb, 0x05bb),
+    (0x6cbc, 0x05ad),
+    (0x6cbd, 0x05ab),
+    (0x6cbe, 0x05ac),
+    (0x6cbf, 0x05ba),
+    (0x6cc0, 0x1b03),
+    (0x6cc1, 0x05b5),
+    (0x6cc2, 0x1af6),
+    (0x6cc3, 0x1af8),
+    (0x6cc4, 0x05b3),
+    (0x6cc5, 0x05b8),
+    (0x6cc6, 0x1af9),
+    (0x6cc7, 0x1b06),
+    (0x6cc9, 0x06f4),
+    (0x6cca, 0x05be),
+    (0x6ccc, 0x05a8),
+    (0x6ccd, 0x1b05),
+    (0x6ccf, 0x1b09),
+    (0x6cd0, 0x1af5),
+    (0x6cd1, 0x1b0b),
+    (0x6cd2, 0x1afc),
+    (0x6cd3, 0x05b1),
+    (0x6cd4, 0x1af1),
+    (0x6cd5, 0x05b0),
+    (0x6cd6, 0x05c2),
+    (0x6cd7, 0x05b7),
+    (0x6cd9, 0x1aef),
+    (0x6cda, 0x1c6b),
+    (0x6cdb, 0x05bd),
+    (0x6cdc, 0x05c1),
+    (0x6cdd, 0x1afd),
+    (0x6cde, 0x1b02),
+    (0x6ce0, 0x05c3),
+    (0x6ce1, 0x05bc),
+    (0x6ce2, 0x05ae),
+    (0x6ce3, 0x05a4),
+    (0x6ce5, 0x05a9),
+    (0x6ce7, 0x1af3),
+    (0x6ce8, 0x05a5),
+    (0x6ce9, 0x1b0a),
+    (0x6ceb, 0x1aed),
+    (0x6cec, 0x1aec),
+    (0x6ced, 0x1afa),
+    (0x6cee, 0x1aee),
+    (0x6cef, 0x05c0),
+    (0x6cf0, 0x088c),
+    (0x6cf1, 0x05b9),
+    (0x6cf2, 0x1afb),
+    (0x6cf3, 0x05a6),
+    (0x6cf5, 0x0703),
+    (0x6cf9, 0x1b08),
+    (0x6d00, 0x1c72),
+    (0x6d01, 0x1c75),
+    (0x6d03, 0x1c78),
+    (0x6d04, 0x1c6d),
+    (0x6d07, 0x1c7b),
+    (0x6d08, 0x1c7e),
+    (0x6d09, 0x1c80),
+    (0x6d0a, 0x1c6a),
+    (0x6d0b, 0x06f5),
+    (0x6d0c, 0x06fa),
+    (0x6d0d, 0x1e16),
+    (0x6d0e, 0x070a),
+    (0x6d0f, 0x1c79),
+    (0x6d10, 0x1c81),
+    (0x6d11, 0x1c71),
+    (0x6d12, 0x1c69),
+    (0x6d16, 0x1e31),
+    (0x6d17, 0x06fd),
+    (0x6d18, 0x1c76),
+    (0x6d19, 0x1c6e),
+    (0x6d1a, 0x1c70),
+    (0x6d1b, 0x0702),
+    (0x6d1d, 0x1c73),
+    (0x6d1e, 0x06fc),
+    (0x6d1f, 0x1c66),
+    (0x6d20, 0x1c7c),
+    (0x6d22, 0x1c7f),
+    (0x6d25, 0x06f9),
+    (0x6d27, 0x0705),
+    (0x6d28, 0x1c63),
+    (0x6d29, 0x0707),
+    (0x6d2a, 0x06f7),
+    (0x6d2b, 0x070b),
+    (0x6d2c, 0x1c7d),
+    (0x6d2d, 0x1c65),
+    (0x6d2e, 0x0708),
+    (0x6d2f, 0x1e2b),
+    (0x6d30, 0x1b04),
+    (0x6d31, 0x06fb),
+    (0x6d32, 0x06f6),
+    (0x6d33, 0x1c6c),
+    (0x6d34, 0x1c64),
+    (0x6d35, 0x0709),
+    (0x6d36, 0x0701),
+    (0x6d37, 0x1c77),
+    (0x6d38, 0x0706),
+    (0x6d39, 0x0704),
+    (0x6d3a, 0x1c6f),
+    (0x6d3b, 0x06fe),
+    (0x6d3c, 0x1c67),
+    (0x6d3d, 0x06ff),
+    (0x6d3e, 0x0700),
+    (0x6d3f, 0x1c68),
+    (0x6d40, 0x1c7a),
+    (0x6d41, 0x06f8),
+    (0x6d42, 0x1c74),
+    (0x6d58, 0x1e19),
+    (0x6d59, 0x0894),
+    (0x6d5a, 0x0899),
+    (0x6d5e, 0x1e22),
+    (0x6d5f, 0x1e28),
+    (0x6d60, 0x1e24),
+    (0x6d61, 0x1e17),
+    (0x6d62, 0x1e1a),
+    (0x6d63, 0x1e13),
+    (0x6d64, 0x1e14),
+    (0x6d65, 0x08a0),
+    (0x6d66, 0x0891),
+    (0x6d67, 0x1e23),
+    (0x6d68, 0x1e2c),
+    (0x6d69, 0x089b),
+    (0x6d6a, 0x088d),
+    (0x6d6c, 0x0896),
+    (0x6d6d, 0x1e1b),
+    (0x6d6e, 0x0898),
+    (0x6d6f, 0x1e1c),
+    (0x6d70, 0x1e26),
+    (0x6d74, 0x089a),
+    (0x6d75, 0x1e35),
+    (0x6d76, 0x1e15),
+    (0x6d77, 0x0893),
+    (0x6d78, 0x0892),
+    (0x6d79, 0x089e),
+    (0x6d7a, 0x1e12),
+    (0x6d7b, 0x1e33),
+    (0x6d7c, 0x1e27),
+    (0x6d7d, 0x1e34),
+    (0x6d7e, 0x1e2e),
+    (0x6d7f, 0x1e20),
+    (0x6d80, 0x1e2f),
+    (0x6d82, 0x1e29),
+    (0x6d83, 0x1e32),
+    (0x6d84, 0x1e30),
+    (0x6d85, 0x089f),
+    (0x6d86, 0x1e21),
+    (0x6d87, 0x0890),
+    (0x6d88, 0x088f),
+    (0x6d89, 0x0897),
+    (0x6d8a, 0x089d),
+    (0x6d8b, 0x1e2d),
+    (0x6d8c, 0x089c),
+    (0x6d8d, 0x1e1e),
+    (0x6d8e, 0x0a70),
+    (0x6d90, 0x1e36),
+    (0x6d91, 0x1e1d),
+    (0x6d92, 0x1e18),
+    (0x6d93, 0x0895),
+    (0x6d94, 0x08a1),
+    (0x6d95, 0x088e),
+    (0x6d97, 0x1e25),
+    (0x6d98, 0x1e2a),
+    (0x6daa, 0x0a92),
+    (0x6dab, 0x2063),
+    (0x6dac, 0x2067),
+    (0x6dae, 0x0a7f),
+    (0x6daf, 0x0a7d),
+    (0x6db2, 0x0a74),
+    (0x6db3, 0x2065),
+    (0x6db4, 0x2064),
+    (0x6db5, 0x0a88),
+    (0x6db7, 0x206a),
+    (0x6db8, 0x0a82),
+    (0x6dba, 0x207c),
+    (0x6dbb, 0x2088),
+    (0x6dbc, 0x0a71),
+    (0x6dbd, 0x2079),
+    (0x6dbe, 0x2072),
+    (0x6dbf, 0x0a94),
+    (0x6dc0, 0x2062),
+    (0x6dc2, 0x207e),
+    (0x6dc4, 0x0a91),
+    (0x6dc5, 0x0a85),
+    (0x6dc6, 0x0a90),
+    (0x6dc7, 0x0a7b),
+    (0x6dc8, 0x206e),
+    (0x6dc9, 0x2080),
+    (0x6dca, 0x2078),
+    (0x6dcb, 0x0a7c),
+    (0x6dcc, 0x0a76),
+    (0x6dcd, 0x2086),
+    (0x6dcf, 0x207f),
+    (0x6dd0, 0x2081),
+    (0x6dd1, 0x0a7e),
+    (0x6dd2, 0x0a86),
+    (0x6dd3, 0x2083),
+    (0x6dd4, 0x206c),
+    (0x6dd5, 0x207d),
+    (0x6dd6, 0x2071),
+    (0x6dd7, 0x2085),
+    (0x6dd8, 0x0a8b),
+    (0x6dd9, 0x0a73),
+    (0x6dda, 0x0a89),
+    (0x6ddb, 0x2076),
+    (0x6ddc, 0x2074),
+    (0x6ddd, 0x2075),
+    (0x6dde, 0x0a80),
+    (0x6ddf, 0x2070),
+    (0x6de0, 0x206f),
+    (0x6de1, 0x0a75),
+    (0x6de2, 0x2069),
+    (0x6de3, 0x2087),
+    (0x6de4, 0x0a77),
+    (0x6de5, 0x2073),
+    (0x6de6, 0x0a95),
+    (0x6de8, 0x0a8f),
+    (0x6de9, 0x2068),
+    (0x6dea, 0x0a8c),
+    (0x6deb, 0x0a8a),
+    (0x6dec, 0x0a93),
+    (0x6ded, 0x207a),
+    (0x6dee, 0x0a8e),
+    (0x6def, 0x1e1f),
+    (0x6df0, 0x207b),
+    (0x6df1, 0x0a8d),
+    (0x6df2, 0x2082),
+    (0x6df3, 0x0a72),
+    (0x6df4, 0x2077),
+    (0x6df5, 0x0a84),
+    (0x6df6, 0x206b),
+    (0x6df7, 0x0a83),
+    (0x6df9, 0x0a81),
+    (0x6dfa, 0x0a79),
+    (0x6dfb, 0x0a78),
+    (0x6dfc, 0x22dd),
+    (0x6dfd, 0x2084),
+    (0x6e00, 0x206d),
+    (0x6e03, 0x22f2),
+    (0x6e05, 0x0a7a),
+    (0x6e19, 0x0c45),
+    (0x6e1a, 0x0a87),
+    (0x6e1b, 0x0c33),
+    (0x6e1c, 0x22ec),
+    (0x6e1d, 0x0c41),
+    (0x6e1f, 0x22e0),
+    (0x6e20, 0x0c30),
+    (0x6e21, 0x0c2c),
+    (0x6e22, 0x22fe),
+    (0x6e23, 0x0c32),
+    (0x6e24, 0x0c36),
+    (0x6e25, 0x0c31),
+    (0x6e26, 0x0c3a),
+    (0x6e27, 0x2302),
+    (0x6e28, 0x22f9),
+    (0x6e2b, 0x22e7),
+    (0x6e2c, 0x0c3f),
+    (0x6e2d, 0x0c39),
+    (0x6e2e, 0x22f3),
+    (0x6e2f, 0x0c29),
+    (0x6e30, 0x22ff),
+    (0x6e31, 0x22f8),
+    (0x6e32, 0x0c2d),
+    (0x6e33, 0x22ed),
+    (0x6e34, 0x0c3c),
+    (0x6e35, 0x230a),
+    (0x6e36, 0x230b),
+    (0x6e38, 0x0c2a),
+    (0x6e39, 0x22fd),
+    (0x6e3a, 0x0c3e),
+    (0x6e3b, 0x22f1),
+    (0x6e3c, 0x22e3),
+    (0x6e3d, 0x22e4),
+    (0x6e3e, 0x0c42),
+    (0x6e3f, 0x22e8),
+    (0x6e40, 0x22ef),
+    (0x6e41, 0x22e9),
+    (0x6e43, 0x0c40),
+    (0x6e44, 0x0c48),
+    (0x6e45, 0x22e5),
+    (0x6e46, 0x22de),
+    (0x6e47, 0x22df),
+    (0x6e49, 0x22e1),
+    (0x6e4a, 0x0c2f),
+    (0x6e4b, 0x22ee),
+    (0x6e4d, 0x0c3d),
+    (0x6e4e, 0x0c46),
+    (0x6e51, 0x22f0),
+    (0x6e52, 0x2308),
+    (0x6e53, 0x2300),
+    (0x6e54, 0x0c2b),
+    (0x6e55, 0x2306),
+    (0x6e56, 0x0c37),
+    (0x6e58, 0x0c35),
+    (0x6e5a, 0x230c),
+    (0x6e5b, 0x0c34),
+    (0x6e5c, 0x22f6),
+    (0x6e5d, 0x22ea),
+    (0x6e5e, 0x22f4),
+    (0x6e5f, 0x0c4b),
+    (0x6e60, 0x22fa),
+    (0x6e61, 0x22f7),
+    (0x6e62, 0x22e6),
+    (0x6e63, 0x0c47),
+    (0x6e64, 0x2304),
+    (0x6e65, 0x2301),
+    (0x6e66, 0x2309),
+    (0x6e67, 0x0c2e),
+    (0x6e68, 0x22f5),
+    (0x6e69, 0x0c4a),
+    (0x6e6b, 0x22fc),
+    (0x6e6e, 0x0c38),
+    (0x6e6f, 0x0c3b),
+    (0x6e71, 0x22fb),
+    (0x6e72, 0x0c49),
+    (0x6e73, 0x22eb),
+    (0x6e74, 0x2066),
+    (0x6e77, 0x2305),
+    (0x6e78, 0x2303),
+    (0x6e79, 0x2307),
+    (0x6e88, 0x22e2),
+    (0x6e89, 0x0c44),
+    (0x6e8d, 0x258a),
+    (0x6e8e, 0x2589),
+    (0x6e8f, 0x256f),
+    (0x6e90, 0x0dd9),
+    (0x6e92, 0x2588),
+    (0x6e93, 0x2572),
+    (0x6e94, 0x2573),
+    (0x6e96, 0x0de3),
+    (0x6e97, 0x2591),
+    (0x6e98, 0x0dde),
+    (0x6e99, 0x2587),
+    (0x6e9b, 0x256c),
+    (0x6e9c, 0x0de4),
+    (0x6e9d, 0x0dda),
+    (0x6e9e, 0x257b),
+    (0x6e9f, 0x2571),
+    (0x6ea0, 0x2574),
+    (0x6ea1, 0x258c),
+    (0x6ea2, 0x0dd4),
+    (0x6ea3, 0x2593),
+    (0x6ea4, 0x258b),
+    (0x6ea5, 0x0ddd),
+    (0x6ea6, 0x2580),
+    (0x6ea7, 0x0de8),
+    (0x6eaa, 0x0de7),
+    (0x6eab, 0x0de1),
+    (0x6eae, 0x2592),
+    (0x6eaf, 0x0dd5),
+    (0x6eb0, 0x257e),
+    (0x6eb1, 0x2575),
+    (0x6eb2, 0x2582),
+    (0x6eb3, 0x258e),
+    (0x6eb4, 0x0de9),
+    (0x6eb6, 0x0dd7),
+    (0x6eb7, 0x257d),
+    (0x6eb9, 0x2576),
+    (0x6eba, 0x0de0),
+    (0x6ebc, 0x0ddf),
+    (0x6ebd, 0x2579),
+    (0x6ebe, 0x2583),
+    (0x6ebf, 0x258d),
+    (0x6ec0, 0x2570),
+    (0x6ec1, 0x257a),
+    (0x6ec2, 0x0dd8),
+    (0x6ec3, 0x2584),
+    (0x6ec4, 0x0de5),
+    (0x6ec5, 0x0ddc),
+    (0x6ec6, 0x2577),
+    (0x6ec7, 0x0ddb),
+    (0x6ec8, 0x256e),
+    (0x6ec9, 0x257c),
+    (0x6eca, 0x2590),
+    (0x6ecb, 0x0c43),
+    (0x6ecc, 0x0fa3),
+    (0x6ecd, 0x257f),
+    (0x6ece, 0x2809),
+    (0x6ecf, 0x2581),
+    (0x6ed0, 0x258f),
+    (0x6ed1, 0x0de2),
+    (0x6ed2, 0x2578),
+    (0x6ed3, 0x0dd6),
+    (0x6ed4, 0x0de6),
+    (0x6ed5, 0x111d),
+    (0x6ed6, 0x256d),
+    (0x6ed8, 0x2586),
+    (0x6edc, 0x2585),
+    (0x6eeb, 0x2825),
+    (0x6eec, 0x0fa0),
+    (0x6eed, 0x281a),
+    (0x6eee, 0x281f),
+    (0x6eef, 0x0f95),
+    (0x6ef1, 0x280b),
+    (0x6ef2, 0x0fa2),
+    (0x6ef4, 0x0f8c),
+    (0x6ef5, 0x280a),
+    (0x6ef6, 0x282b),
+    (0x6ef7, 0x0fa4),
+    (0x6ef8, 0x280e),
+    (0x6ef9, 0x281e),
+    (0x6efb, 0x2810),
+    (0x6efc, 0x282e),
+    (0x6efd, 0x282a),
+    (0x6efe, 0x0f8a),
+    (0x6eff, 0x0f94),
+    (0x6f00, 0x2a64),
+    (0x6f01, 0x0fa1),
+    (0x6f02, 0x0f92),
+    (0x6f03, 0x280c),
+    (0x6f05, 0x2829),
+    (0x6f06, 0x0f96),
+    (0x6f07, 0x2826),
+    (0x6f08, 0x2833),
+    (0x6f09, 0x2812),
+    (0x6f0a, 0x281b),
+    (0x6f0d, 0x2831),
+    (0x6f0e, 0x2827),
+    (0x6f0f, 0x0f91),
+    (0x6f12, 0x2819),
+    (0x6f13, 0x0f8b),
+    (0x6f14, 0x0f89),
+    (0x6f15, 0x0f9b),
+    (0x6f18, 0x2817),
+    (0x6f19, 0x2814),
+    (0x6f1a, 0x2815),
+    (0x6f1c, 0x282d),
+    (0x6f1e, 0x2832),
+    (0x6f1f, 0x2830),
+    (0x6f20, 0x0f8f),
+    (0x6f21, 0x2834),
+    (0x6f22, 0x0f93),
+    (0x6f23, 0x0f9a),
+    (0x6f25, 0x280d),
+    (0x6f26, 0x2a4c),
+    (0x6f27, 0x2816),
+    (0x6f29, 0x0f8d),
+    (0x6f2a, 0x0f9f),
+    (0x6f2b, 0x0f9c),
+    (0x6f2c, 0x0f90),
+    (0x6f2d, 0x2820),
+    (0x6f2e, 0x2811),
+    (0x6f2f, 0x0f9d),
+    (0x6f30, 0x2822),
+    (0x6f31, 0x0f97),
+    (0x6f32, 0x0f99),
+    (0x6f33, 0x0f88),
+    (0x6f35, 0x2824),
+    (0x6f36, 0x281c),
+    (0x6f37, 0x280f),
+    (0x6f38, 0x0f98),
+    (0x6f39, 0x282c),
+    (0x6f3a, 0x282f),
+    (0x6f3b, 0x2818),
+    (0x6f3c, 0x2823),
+    (0x6f3e, 0x0f8e),
+    (0x6f3f, 0x110c),
+    (0x6f40, 0x2821),
+    (0x6f41, 0x2a4b),
+    (0x6f43, 0x2828),
+    (0x6f4e, 0x2813),
+    (0x6f4f, 0x2a55),
+    (0x6f50, 0x2a5f),
+    (0x6f51, 0x110f),
+    (0x6f52, 0x2a5e),
+    (0x6f53, 0x2a6a),
+    (0x6f54, 0x1111),
+    (0x6f55, 0x2a5c),
+    (0x6f57, 0x2a60),
+    (0x6f58, 0x111c),
+    (0x6f5a, 0x2a57),
+    (0x6f5b, 0x1114),
+    (0x6f5d, 0x2a63),
+    (0x6f5e, 0x2cb6),
+    (0x6f5f, 0x1120),
+    (0x6f60, 0x111f),
+    (0x6f61, 0x2a65),
+    (0x6f62, 0x2a54),
+    (0x6f63, 0x2a6f),
+    (0x6f64, 0x111a),
+    (0x6f66, 0x1110),
+    (0x6f67, 0x2a68),
+    (0x6f69, 0x2a6c),
+    (0x6f6a, 0x2a71),
+    (0x6f6b, 0x2a66),
+    (0x6f6c, 0x2a5a),
+    (0x6f6d, 0x1113),
+    (0x6f6e, 0x1116),
+    (0x6f6f, 0x111e),
+    (0x6f70, 0x1119),
+    (0x6f72, 0x2a5d),
+    (0x6f73, 0x281d),
+    (0x6f76, 0x2a59),
+    (0x6f77, 0x2a70),
+    (0x6f78, 0x1115),
+    (0x6f7a, 0x1118),
+    (0x6f7b, 0x2a72),
+    (0x6f7c, 0x110d),
+    (0x6f7d, 0x2a67),
+    (0x6f7e, 0x2a4d),
+    (0x6f7f, 0x2a6d),
+    (0x6f80, 0x1380),
+    (0x6f82, 0x2a5b),
+    (0x6f84, 0x110e),
+    (0x6f85, 0x2a56),
+    (0x6f86, 0x1112),
+    (0x6f87, 0x2a4e),
+    (0x6f88, 0x0f9e),
+    (0x6f89, 0x2a52),
+    (0x6f8b, 0x2a6b),
+    (0x6f8c, 0x2a53),
+    (0x6f8d, 0x2a51),
+    (0x6f8e, 0x1117),
+    (0x6f90, 0x2a69),
+    (0x6f92, 0x2a50),
+    (0x6f93, 0x2a62),
+    (0x6f94, 0x2a61),
+    (0x6f95, 0x2a6e),
+    (0x6f96, 0x2a58),
+    (0x6f97, 0x111b),
+    (0x6f9e, 0x2cb9),
+    (0x6fa0, 0x126f),
+    (0x6fa1, 0x1265),
+    (0x6fa2, 0x2cc5),
+    (0x6fa3, 0x2cb1),
+    (0x6fa4, 0x1267),
+    (0x6fa5, 0x2cbd),
+    (0x6fa6, 0x126e),
+    (0x6fa7, 0x1269),
+    (0x6fa8, 0x2cbb),
+    (0x6fa9, 0x2eab),
+    (0x6faa, 0x2cc1),
+    (0x6fab, 0x2cc7),
+    (0x6fac, 0x2cc0),
+    (0x6fad, 0x2caf),
+    (0x6fae, 0x2cbe),
+    (0x6faf, 0x2cc9),
+    (0x6fb0, 0x2ccb),
+    (0x6fb1, 0x1264),
+    (0x6fb2, 0x2cca),
+    (0x6fb3, 0x126a),
+    (0x6fb4, 0x1270),
+    (0x6fb6, 0x126d),
+    (0x6fb8, 0x2cc4),
+    (0x6fb9, 0x126c),
+    (0x6fba, 0x2cbf),
+    (0x6fbc, 0x2cb3),
+    (0x6fbd, 0x2cb8),
+    (0x6fbf, 0x2cc3),
+    (0x6fc0, 0x126b),
+    (0x6fc1, 0x1268),
+    (0x6fc2, 0x1263),
+    (0x6fc3, 0x1266),
+    (0x6fc4, 0x2cb7),
+    (0x6fc6, 0x2a4f),
+    (0x6fc7, 0x2cb2),
+    (0x6fc8, 0x2cb5),
+    (0x6fc9, 0x2cc6),
+    (0x6fca, 0x2cba),
+    (0x6fcb, 0x2cb0),
+    (0x6fcc, 0x2eaa),
+    (0x6fcd, 0x2cc8),
+    (0x6fce, 0x2cb4),
+    (0x6fcf, 0x2cc2),
+    (0x6fd4, 0x2ead),
+    (0x6fd5, 0x1384),
+    (0x6fd8, 0x1378),
+    (0x6fdb, 0x137c),
+    (0x6fdc, 0x2eaf),
+    (0x6fdd, 0x2eb5),
+    (0x6fde, 0x2eb3),
+    (0x6fdf, 0x137a),
+    (0x6fe0, 0x137b),
+    (0x6fe1, 0x1382),
+    (0x6fe2, 0x2eb6),
+    (0x6fe3, 0x2eae),
+    (0x6fe4, 0x137d),
+    (0x6fe6, 0x2eb2),
+    (0x6fe7, 0x2eb1),
+    (0x6fe8, 0x2eb7),
+    (0x6fe9, 0x1383),
+    (0x6feb, 0x137e),
+    (0x6fec, 0x1381),
+    (0x6fed, 0x2eb0),
+    (0x6fee, 0x1385),
+    (0x6fef, 0x137f),
+    (0x6ff0, 0x1386),
+    (0x6ff1, 0x1379),
+    (0x6ff2, 0x2eb4),
+    (0x6ff4, 0x2eac),
+    (0x6ff7, 0x306b),
+    (0x6ffa, 0x146e),
+    (0x6ffb, 0x3068),
+    (0x6ffc, 0x306a),
+    (0x6ffe, 0x146c),
+    (0x6fff, 0x3066),
+    (0x7000, 0x3067),
+    (0x7001, 0x3062),
+    (0x7004, 0x2cbc),
+    (0x7005, 0x3063),
+    (0x7006, 0x146d),
+    (0x7007, 0x305f),
+    (0x7009, 0x146a),
+    (0x700a, 0x306c),
+    (0x700b, 0x146b),
+    (0x700c, 0x3060),
+    (0x700d, 0x3061),
+    (0x700e, 0x3065),
+    (0x700f, 0x1470),
+    (0x7011, 0x146f),
+    (0x7014, 0x3064),
+    (0x7015, 0x1512),
+    (0x7016, 0x31cc),
+    (0x7017, 0x31d2),
+    (0x7018, 0x1513),
+    (0x7019, 0x31c9),
+    (0x701a, 0x1510),
+    (0x701b, 0x150d),
+    (0x701c, 0x31d4),
+    (0x701d, 0x1511),
+    (0x701f, 0x150e),
+    (0x7020, 0x31cb),
+    (0x7021, 0x31ce),
+    (0x7022, 0x31cf),
+    (0x7023, 0x31d0),
+    (0x7024, 0x31d3),
+    (0x7026, 0x3069),
+    (0x7027, 0x31ca),
+    (0x7028, 0x150f),
+    (0x7029, 0x31d1),
+    (0x702a, 0x31d5),
+    (0x702b, 0x31cd),
+    (0x702f, 0x3304),
+    (0x7030, 0x15a1),
+    (0x7031, 0x3307),
+    (0x7032, 0x15a2),
+    (0x7033, 0x330f),
+    (0x7034, 0x3306),
+    (0x7035, 0x3303),
+    (0x7037, 0x3305),
+    (0x7038, 0x3309),
+    (0x7039, 0x330c),
+    (0x703a, 0x330b),
+    (0x703b, 0x330e),
+    (0x703c, 0x3302),
+    (0x703e, 0x15a0),
+    (0x703f, 0x330a),
+    (0x7040, 0x330d),
+    (0x7041, 0x3310),
+    (0x7042, 0x3308),
+    (0x7043, 0x33f5),
+    (0x7044, 0x33f6),
+    (0x7045, 0x33fa),
+    (0x7046, 0x33fb),
+    (0x7048, 0x33f8),
+    (0x7049, 0x33f9),
+    (0x704a, 0x33f7),
+    (0x704c, 0x15fe),
+    (0x7051, 0x164c),
+    (0x7052, 0x34aa),
+    (0x7055, 0x34a7),
+    (0x7056, 0x34a8),
+    (0x7057, 0x34a9),
+    (0x7058, 0x164d),
+    (0x705a, 0x3541),
+    (0x705b, 0x3540),
+    (0x705d, 0x35a8),
+    (0x705e, 0x16a6),
+    (0x705f, 0x35a6),
+    (0x7060, 0x35a9),
+    (0x7061, 0x35a7),
+    (0x7062, 0x35ee),
+    (0x7063, 0x16c8),
+    (0x7064, 0x16d6),
+    (0x7065, 0x3639),
+    (0x7066, 0x3624),
+    (0x7068, 0x3638),
+    (0x7069, 0x3666),
+    (0x706a, 0x3667),
+    (0x706b, 0x026a),
+    (0x7070, 0x0376),
+    (0x7071, 0x1926),
+    (0x7074, 0x19e0),
+    (0x7076, 0x046a),
+    (0x7078, 0x046d),
+    (0x707a, 0x19e1),
+    (0x707c, 0x046b),
+    (0x707d, 0x046c),
+    (0x7082, 0x1b14),
+    (0x7083, 0x1b16),
+    (0x7084, 0x1b11),
+    (0x7085, 0x1b0e),
+    (0x7086, 0x1b10),
+    (0x708a, 0x05c7),
+    (0x708e, 0x05c5),
+    (0x7091, 0x1b12),
+    (0x7092, 0x05c6),
+    (0x7093, 0x1b0f),
+    (0x7094, 0x1b0c),
+    (0x7095, 0x05c4),
+    (0x7096, 0x1b13),
+    (0x7098, 0x1b0d),
+    (0x7099, 0x05c8),
+    (0x709a, 0x1b15),
+    (0x709f, 0x1c83),
+    (0x70a1, 0x1c87),
+    (0x70a4, 0x0714),
+    (0x70a9, 0x1c8a),
+    (0x70ab, 0x070c),
+    (0x70ac, 0x070f),
+    (0x70ad, 0x0711),
+    (0x70ae, 0x0713),
+    (0x70af, 0x0710),
+    (0x70b0, 0x1c86),
+    (0x70b1, 0x1c85),
+    (0x70b3, 0x070e),
+    (0x70b4, 0x1c88),
+    (0x70b5, 0x1c89),
+    (0x70b7, 0x1c82),
+    (0x70b8, 0x0712),
+    (0x70ba, 0x070d),
+    (0x70be, 0x1c84),
+    (0x70c5, 0x1e44),
+    (0x70c6, 0x1e45),
+    (0x70c7, 0x1e46),
+    (0x70c8, 0x08a6),
+    (0x70ca, 0x08a2),
+    (0x70cb, 0x1e3b),
+    (0x70cd, 0x1e43),
+    (0x70ce, 0x1e48),
+    (0x70cf, 0x08a7),
+    (0x70d1, 0x1e39),
+    (0x70d2, 0x1e3f),
+    (0x70d3, 0x1e38),
+    (0x70d4, 0x1e42),
+    (0x70d7, 0x1e3e),
+    (0x70d8, 0x08a3),
+    (0x70d9, 0x08a5),
+    (0x70da, 0x1e47),
+    (0x70dc, 0x1e37),
+    (0x70dd, 0x1e3a),
+    (0x70de, 0x1e40),
+    (0x70e0, 0x1e41),
+    (0x70e1, 0x1e49),
+    (0x70e2, 0x1e3d),
+    (0x70e4, 0x08a4),
+    (0x70ef, 0x0a9a),
+    (0x70f0, 0x208f),
+    (0x70f3, 0x2091),
+    (0x70f4, 0x208d),
+    (0x70f6, 0x2099),
+    (0x70f7, 0x208b),
+    (0x70f8, 0x2098),
+    (0x70f9, 0x0a96),
+    (0x70fa, 0x2089),
+    (0x70fb, 0x2310),
+    (0x70fc, 0x2093),
+    (0x70fd, 0x0a99),
+    (0x70ff, 0x2094),
+    (0x7100, 0x2097),
+    (0x7102, 0x209b),
+    (0x7104, 0x2090),
+    (0x7106, 0x2095),
+    (0x7109, 0x0a97),
+    (0x710a, 0x0a98),
+    (0x710b, 0x209a),
+    (0x710c, 0x208e),
+    (0x710d, 0x208a),
+    (0x710e, 0x209c),
+    (0x7110, 0x2092),
+    (0x7113, 0x2096),
+    (0x7117, 0x208c),
+    (0x7119, 0x0c4c),
+    (0x711a, 0x0c4d),
+    (0x711b, 0x231a),
+    (0x711c, 0x0c53),
+    (0x711e, 0x230e),
+    (0x711f, 0x2317),
+    (0x7120, 0x230d),
+    (0x7121, 0x0c50),
+    (0x7122, 0x2315),
+    (0x7123, 0x2313),
+    (0x7125, 0x2314),
+    (0x7126, 0x0c4e),
+    (0x7128, 0x2318),
+    (0x712e, 0x2311),
+    (0x712f, 0x230f),
+    (0x7130, 0x0c4f),
+    (0x7131, 0x2312),
+    (0x7132, 0x2316),
+    (0x7136, 0x0c51),
+    (0x713a, 0x2319),
+    (0x7141, 0x2599),
+    (0x7142, 0x25a0),
+    (0x7143, 0x25a2),
+    (0x7144, 0x25a8),
+    (0x7146, 0x0df6),
+    (0x7147, 0x2594),
+    (0x7149, 0x0dee),
+    (0x714b, 0x25a3),
+    (0x714c, 0x0df3),
+    (0x714d, 0x25a9),
+    (0x714e, 0x0dea),
+    (0x7150, 0x25a6),
+    (0x7152, 0x2596),
+    (0x7153, 0x25a7),
+    (0x7154, 0x2595),
+    (0x7156, 0x0df8),
+    (0x7158, 0x25a1),
+    (0x7159, 0x0deb),
+    (0x715a, 0x25aa),
+    (0x715c, 0x0df0),
+    (0x715d, 0x259a),
+    (0x715e, 0x0df5),
+    (0x715f, 0x25a5),
+    (0x7160, 0x2598),
+    (0x7161, 0x259f),
+    (0x7162, 0x259b),
+    (0x7163, 0x2597),
+    (0x7164, 0x0ded),
+    (0x7165, 0x0df4),
+    (0x7166, 0x0df2),
+    (0x7167, 0x0def),
+    (0x7168, 0x0df7),
+    (0x7169, 0x0dec),
+    (0x716a, 0x259e),
+    (0x716c, 0x0df1),
+    (0x716e, 0x0c52),
+    (0x7170, 0x25a4),
+    (0x7172, 0x259c),
+    (0x7178, 0x259d),
+    (0x717b, 0x283c),
+    (0x717d, 0x0fa7),
+    (0x7180, 0x2838),
+    (0x7181, 0x283e),
+    (0x7182, 0x283a),
+    (0x7184, 0x0fa9),
+    (0x7185, 0x2839),
+    (0x7186, 0x283d),
+    (0x7187, 0x2835),
+    (0x7189, 0x2837),
+    (0x718a, 0x0fa8),
+    (0x718f, 0x283b),
+    (0x7190, 0x2836),
+    (0x7192, 0x0faa),
+    (0x7194, 0x0fa5),
+    (0x7197, 0x283f),
+    (0x7199, 0x0fa6),
+    (0x719a, 0x2a78),
+    (0x719b, 0x2a75),
+    (0x719c, 0x2a81),
+    (0x719d, 0x2a7b),
+    (0x719e, 0x2a7d),
+    (0x719f, 0x1121),
+    (0x71a0, 0x2a77),
+    (0x71a1, 0x2a7f),
+    (0x71a4, 0x2a7e),
+    (0x71a5, 0x2a7c),
+    (0x71a7, 0x2a82),
+    (0x71a8, 0x1124),
+    (0x71a9, 0x2a79),
+    (0x71aa, 0x2a80),
+    (0x71ac, 0x1122),
+    (0x71af, 0x2a74),
+    (0x71b0, 0x2a76),
+    (0x71b1, 0x1123),
+    (0x71b2, 0x2a73),
+    (0x71b3, 0x2a83),
+    (0x71b5, 0x2a7a),
+    (0x71b8, 0x2ccf),
+    (0x71b9, 0x1277),
+    (0x71bc, 0x2cda),
+    (0x71bd, 0x2cd8),
+    (0x71be, 0x1271),
+    (0x71bf, 0x2cce),
+    (0x71c0, 0x2cd1),
+    (0x71c1, 0x2cd2),
+    (0x71c2, 0x2ccd),
+    (0x71c3, 0x127b),
+    (0x71c4, 0x127c),
+    (0x71c5, 0x2ccc),
+    (0x71c6, 0x2cdb),
+    (0x71c7, 0x2cd6),
+    (0x71c8, 0x1275),
+    (0x71c9, 0x1272),
+    (0x71ca, 0x2cd5),
+    (0x71cb, 0x2cd3),
+    (0x71ce, 0x1278),
+    (0x71cf, 0x2cd7),
+    (0x71d0, 0x1273),
+    (0x71d2, 0x1274),
+    (0x71d4, 0x2cd4),
+    (0x71d5, 0x1276),
+    (0x71d6, 0x2cd0),
+    (0x71d8, 0x2cd9),
+    (0x71d9, 0x1279),
+    (0x71da, 0x2cdc),
+    (0x71db, 0x2cdd),
+    (0x71dc, 0x127a),
+    (0x71df, 0x1388),
+    (0x71e0, 0x138f),
+    (0x71e1, 0x2eb8),
+    (0x71e2, 0x2ebe),
+    (0x71e4, 0x2ebc),
+    (0x71e5, 0x138b),
+    (0x71e6, 0x138a),
+    (0x71e7, 0x1387),
+    (0x71e8, 0x2eba),
+    (0x71ec, 0x138d),
+    (0x71ed, 0x138c),
+    (0x71ee, 0x1389),
+    (0x71f0, 0x2ebd),
+    (0x71f1, 0x2eb9),
+    (0x71f2, 0x2ebb),
+    (0x71f4, 0x138e),
+    (0x71f8, 0x1474),
+    (0x71f9, 0x306f),
+    (0x71fb, 0x1471),
+    (0x71fc, 0x1472),
+    (0x71fd, 0x3071),
+    (0x71fe, 0x1473),
+    (0x71ff, 0x306e),
+    (0x7201, 0x306d),
+    (0x7202, 0x31d9),
+    (0x7203, 0x3070),
+    (0x7205, 0x31da),
+    (0x7206, 0x1514),
+    (0x7207, 0x31d8),
+    (0x720a, 0x31d7),
+    (0x720c, 0x31d6),
+    (0x720d, 0x1515),
+    (0x7210, 0x15a3),
+    (0x7213, 0x3311),
+    (0x7214, 0x3312),
+    (0x7219, 0x33fe),
+    (0x721a, 0x33fd),
+    (0x721b, 0x15ff),
+    (0x721d, 0x33fc),
+    (0x721e, 0x34ab),
+    (0x721f, 0x34ac),
+    (0x7222, 0x3542),
+    (0x7223, 0x35aa),
+    (0x7226, 0x35ef),
+    (0x7227, 0x3652),
+    (0x7228, 0x16ea),
+    (0x7229, 0x365c),
+    (0x722a, 0x026b),
+    (0x722c, 0x05c9),
+    (0x722d, 0x05ca),
+    (0x7230, 0x0715),
+    (0x7235, 0x1390),
+    (0x7236, 0x026c),
+    (0x7238, 0x05cb),
+    (0x7239, 0x08a8),
+    (0x723a, 0x0df9),
+    (0x723b, 0x026d),
+    (0x723d, 0x0a9b),
+    (0x723e, 0x0fab),
+    (0x723f, 0x18aa),
+    (0x7241, 0x1c8b),
+    (0x7242, 0x1e4a),
+    (0x7244, 0x2840),
+    (0x7246, 0x1391),
+    (0x7247, 0x026e),
+    (0x7248, 0x05cc),
+    (0x7249, 0x1c8c),
+    (0x724a, 0x1c8d),
+    (0x724b, 0x231b),
+    (0x724c, 0x0c54),
+    (0x724f, 0x25ab),
+    (0x7252, 0x0dfa),
+    (0x7253, 0x2841),
+    (0x7256, 0x1125),
+    (0x7258, 0x1516),
+    (0x7259, 0x026f),
+    (0x725a, 0x231c),
+    (0x725b, 0x0270),
+    (0x725d, 0x0378),
+    (0x725e, 0x1927),
+    (0x725f, 0x0377),
+    (0x7260, 0x0470),
+    (0x7261, 0x046f),
+    (0x7262, 0x046e),
+    (0x7263, 0x19e2),
+    (0x7267, 0x05cd),
+    (0x7269, 0x05ce),
+    (0x726a, 0x1b17),
+    (0x726c, 0x1c8e),
+    (0x726e, 0x1c91),
+    (0x726f, 0x0717),
+    (0x7270, 0x1c8f),
+    (0x7272, 0x0716),
+    (0x7273, 0x1c90),
+    (0x7274, 0x0718),
+    (0x7276, 0x1e4d),
+    (0x7277, 0x1e4c),
+    (0x7278, 0x1e4b),
+    (0x7279, 0x08a9),
+    (0x727b, 0x209e),
+    (0x727c, 0x209f),
+    (0x727d, 0x0a9c),
+    (0x727e, 0x209d),
+    (0x727f, 0x20a0),
+    (0x7280, 0x0c56),
+    (0x7281, 0x0a9d),
+    (0x7284, 0x0c55),
+    (0x7285, 0x2320),
+    (0x7286, 0x231f),
+    (0x7288, 0x231d),
+    (0x7289, 0x231e),
+    (0x728b, 0x2321),
+    (0x728c, 0x25ad),
+    (0x728d, 0x25ac),
+    (0x728e, 0x25b0),
+    (0x7290, 0x25af),
+    (0x7291, 0x25ae),
+    (0x7292, 0x0fac),
+    (0x7293, 0x2844),
+    (0x7295, 0x2843),
+    (0x7296, 0x0fad),
+    (0x7297, 0x2842),
+    (0x7298, 0x2a84),
+    (0x729a, 0x2a85),
+    (0x729b, 0x1126),
+    (0x729d, 0x2cde),
+    (0x729e, 0x2cdf),
+    (0x72a1, 0x31df),
+    (0x72a2, 0x1517),
+    (0x72a3, 0x31de),
+    (0x72a4, 0x31dd),
+    (0x72a5, 0x31db),
+    (0x72a6, 0x31dc),
+    (0x72a7, 0x1600),
+    (0x72a8, 0x3313),
+    (0x72a9, 0x34ad),
+    (0x72aa, 0x35f0),
+    (0x72ac, 0x0271),
+    (0x72ae, 0x18cb),
+    (0x72af, 0x02d9),
+    (0x72b0, 0x18cc),
+    (0x72b4, 0x1928),
+    (0x72b5, 0x1929),
+    (0x72ba, 0x19e8),
+    (0x72bd, 0x19e4),
+    (0x72bf, 0x19e3),
+    (0x72c0, 0x05cf),
+    (0x72c1, 0x19e7),
+    (0x72c2, 0x0472),
+    (0x72c3, 0x19e5),
+    (0x72c4, 0x0471),
+    (0x72c5, 0x19e9),
+    (0x72c6, 0x19e6),
+    (0x72c9, 0x1b1b),
+    (0x72ca, 0x1c92),
+    (0x72cb, 0x1b19),
+    (0x72cc, 0x1b20),
+    (0x72ce, 0x05d0),
+    (0x72d0, 0x05d3),
+    (0x72d1, 0x1b21),
+    (0x72d2, 0x1b1d),
+    (0x72d4, 0x1b1e),
+    (0x72d6, 0x1b18),
+    (0x72d7, 0x05d2),
+    (0x72d8, 0x1b1a),
+    (0x72d9, 0x05d1),
+    (0x72da, 0x1b1f),
+    (0x72dc, 0x1b1c),
+    (0x72df, 0x1c96),
+    (0x72e0, 0x071a),
+    (0x72e1, 0x071b),
+    (0x72e3, 0x1c99),
+    (0x72e4, 0x1c93),
+    (0x72e6, 0x1c98),
+    (0x72e8, 0x1c94),
+    (0x72e9, 0x0719),
+    (0x72ea, 0x1c97),
+    (0x72eb, 0x1c95),
+    (0x72f3, 0x1e53),
+    (0x72f4, 0x1e50),
+    (0x72f6, 0x1e52),
+    (0x72f7, 0x08ae),
+    (0x72f8, 0x08ad),
+    (0x72f9, 0x08ab),
+    (0x72fa, 0x1e4f),
+    (0x72fb, 0x1e54),
+    (0x72fc, 0x08aa),
+    (0x72fd, 0x08ac),
+    (0x72fe, 0x1e51),
+    (0x72ff, 0x20a8),
+    (0x7300, 0x1e4e),
+    (0x7301, 0x1e55),
+    (0x7307, 0x20a3),
+    (0x7308, 0x20a7),
+    (0x730a, 0x20a6),
+    (0x730b, 0x2323),
+    (0x730c, 0x232e),
+    (0x730f, 0x20a9),
+    (0x7311, 0x20a4),
+    (0x7312, 0x2322),
+    (0x7313, 0x0aa1),
+    (0x7316, 0x0aa0),
+    (0x7317, 0x20a2),
+    (0x7318, 0x20a5),
+    (0x7319, 0x0aa2),
+    (0x731b, 0x0a9f),
+    (0x731c, 0x0a9e),
+    (0x731d, 0x20a1),
+    (0x731e, 0x20aa),
+    (0x7322, 0x2325),
+    (0x7323, 0x232c),
+    (0x7325, 0x0c58),
+    (0x7326, 0x232b),
+    (0x7327, 0x2328),
+    (0x7329, 0x0c5a),
+    (0x732d, 0x232a),
+    (0x7330, 0x2324),
+    (0x7331, 0x2326),
+    (0x7332, 0x2329),
+    (0x7333, 0x2327),
+    (0x7334, 0x0c59),
+    (0x7335, 0x232d),
+    (0x7336, 0x0c57),
+    (0x7337, 0x0dfb),
+    (0x733a, 0x25b4),
+    (0x733b, 0x25b3),
+    (0x733c, 0x25b1),
+    (0x733e, 0x0dfe),
+    (0x733f, 0x0dfd),
+    (0x7340, 0x25b5),
+    (0x7342, 0x25b2),
+    (0x7343, 0x2845),
+    (0x7344, 0x0fae),
+    (0x7345, 0x0dfc),
+    (0x7349, 0x25b7),
+    (0x734a, 0x25b6),
+    (0x734c, 0x2848),
+    (0x734d, 0x2846),
+    (0x734e, 0x1127),
+    (0x7350, 0x0faf),
+    (0x7351, 0x2847),
+    (0x7352, 0x2a87),
+    (0x7357, 0x1128),
+    (0x7358, 0x2a86),
+    (0x7359, 0x2a8f),
+    (0x735a, 0x2a8e),
+    (0x735b, 0x2a8c),
+    (0x735d, 0x2a8b),
+    (0x735e, 0x2a88),
+    (0x735f, 0x2a89),
+    (0x7360, 0x2a8a),
+    (0x7361, 0x2a8d),
+    (0x7362, 0x2a90),
+    (0x7365, 0x2ce4),
+    (0x7366, 0x2ce1),
+    (0x7367, 0x2ce2),
+    (0x7368, 0x127d),
+    (0x7369, 0x2ce0),
+    (0x736a, 0x2ce6),
+    (0x736b, 0x2ce5),
+    (0x736c, 0x2ce3),
+    (0x736e, 0x2ec0),
+    (0x736f, 0x2ec1),
+    (0x7370, 0x1392),
+    (0x7372, 0x1393),
+    (0x7373, 0x2ebf),
+    (0x7375, 0x1476),
+    (0x7376, 0x3072),
+    (0x7377, 0x1475),
+    (0x7378, 0x1518),
+    (0x737a, 0x1519),
+    (0x737b, 0x15a4),
+    (0x737c, 0x3315),
+    (0x737d, 0x3314),
+    (0x737e, 0x33ff),
+    (0x737f, 0x34ae),
+    (0x7380, 0x164e),
+    (0x7381, 0x3544),
+    (0x7382, 0x3543),
+    (0x7383, 0x3545),
+    (0x7384, 0x02da),
+    (0x7385, 0x1c9a),
+    (0x7386, 0x08af),
+    (0x7387, 0x0aa3),
+    (0x7388, 0x20ab),
+    (0x7389, 0x02db),
+    (0x738a, 0x18cd),
+    (0x738b, 0x0272),
+    (0x738e, 0x192a),
+    (0x7392, 0x19ee),
+    (0x7393, 0x19ec),
+    (0x7394, 0x19ed),
+    (0x7395, 0x19ea),
+    (0x7396, 0x0473),
+    (0x7397, 0x19eb),
+    (0x739d, 0x1b29),
+    (0x739f, 0x05d6),
+    (0x73a0, 0x1b27),
+    (0x73a1, 0x1b23),
+    (0x73a2, 0x1b26),
+    (0x73a4, 0x1b22),
+    (0x73a5, 0x05d8),
+    (0x73a6, 0x1b25),
+    (0x73a8, 0x05d5),
+    (0x73a9, 0x05d4),
+    (0x73ab, 0x05d7),
+    (0x73ac, 0x1b28),
+    (0x73ad, 0x1b24),
+    (0x73b2, 0x071f),
+    (0x73b3, 0x0722),
+    (0x73b4, 0x1ca2),
+    (0x73b5, 0x1ca1),
+    (0x73b6, 0x1ca0),
+    (0x73b7, 0x071c),
+    (0x73b8, 0x1ca9),
+    (0x73b9, 0x1c9f),
+    (0x73bb, 0x071e),
+    (0x73bc, 0x1e5a),
+    (0x73be, 0x1ca6),
+    (0x73bf, 0x1ca4),
+    (0x73c0, 0x0721),
+    (0x73c2, 0x1c9c),
+    (0x73c3, 0x1ca7),
+    (0x73c5, 0x1c9e),
+    (0x73c6, 0x1ca8),
+    (0x73c7, 0x1ca5),
+    (0x73c8, 0x1c9d),
+    (0x73ca, 0x071d),
+    (0x73cb, 0x1caa),
+    (0x73cc, 0x1c9b),
+    (0x73cd, 0x0720),
+    (0x73d2, 0x1e5f),
+    (0x73d3, 0x1e56),
+    (0x73d4, 0x1e61),
+    (0x73d6, 0x1e59),
+    (0x73d7, 0x1e64),
+    (0x73d8, 0x1e65),
+    (0x73d9, 0x1e57),
+    (0x73da, 0x1e63),
+    (0x73db, 0x1e60),
+    (0x73dc, 0x1e5e),
+    (0x73dd, 0x1e62),
+    (0x73de, 0x08b5),
+    (0x73e0, 0x08b3),
+    (0x73e3, 0x1e5c),
+    (0x73e5, 0x1e58),
+    (0x73e7, 0x1e5b),
+    (0x73e8, 0x1e66),
+    (0x73e9, 0x1e5d),
+    (0x73ea, 0x08b4),
+    (0x73eb, 0x1ca3),
+    (0x73ed, 0x08b0),
+    (0x73ee, 0x08b2),
+    (0x73f4, 0x20b9),
+    (0x73f5, 0x20ae),
+    (0x73f6, 0x20ac),
+    (0x73f8, 0x20ad),
+    (0x73fa, 0x20b4),
+    (0x73fc, 0x20b5),
+    (0x73fd, 0x20b1),
+    (0x73fe, 0x0aa8),
+    (0x73ff, 0x20b6),
+    (0x7400, 0x20b3),
+    (0x7401, 0x20b0),
+    (0x7403, 0x0aa6),
+    (0x7404, 0x20af),
+    (0x7405, 0x0aa4),
+    (0x7406, 0x0aa7),
+    (0x7407, 0x20b2),
+    (0x7408, 0x20ba),
+    (0x7409, 0x08b1),
+    (0x740a, 0x0aa5),
+    (0x740b, 0x20b8),
+    (0x740c, 0x20b7),
+    (0x740d, 0x0aa9),
+    (0x7416, 0x2333),
+    (0x741a, 0x2334),
+    (0x741b, 0x0c64),
+    (0x741d, 0x233a),
+    (0x7420, 0x233c),
+    (0x7421, 0x2335),
+    (0x7422, 0x0c5e),
+    (0x7423, 0x2339),
+    (0x7424, 0x2338),
+    (0x7425, 0x0c5f),
+    (0x7426, 0x0c65),
+    (0x7428, 0x0c66),
+    (0x7429, 0x233b),
+    (0x742a, 0x0c5c),
+    (0x742b, 0x2332),
+    (0x742c, 0x2330),
+    (0x742d, 0x2336),
+    (0x742e, 0x232f),
+    (0x742f, 0x0c63),
+    (0x7430, 0x2331),
+    (0x7431, 0x2337),
+    (0x7432, 0x233d),
+    (0x7433, 0x0c5d),
+    (0x7434, 0x0c62),
+    (0x7435, 0x0c60),
+    (0x7436, 0x0c61),
+    (0x743a, 0x0c5b),
+    (0x743f, 0x0e05),
+    (0x7440, 0x25be),
+    (0x7441, 0x0e04),
+    (0x7442, 0x25c2),
+    (0x7444, 0x25b8),
+    (0x7446, 0x25c3),
+    (0x744a, 0x25b9),
+    (0x744b, 0x25ba),
+    (0x744d, 0x25c4),
+    (0x744e, 0x25c1),
+    (0x744f, 0x25bf),
+    (0x7450, 0x25c0),
+    (0x7451, 0x25bc),
+    (0x7452, 0x25bb),
+    (0x7454, 0x25c5),
+    (0x7455, 0x0e01),
+    (0x7457, 0x25bd),
+    (0x7459, 0x0e06),
+    (0x745a, 0x0e00),
+    (0x745b, 0x0e07),
+    (0x745c, 0x0e08),
+    (0x745e, 0x0e03),
+    (0x745f, 0x0e02),
+    (0x7462, 0x2849),
+    (0x7463, 0x0fb1),
+    (0x7464, 0x0fb0),
+    (0x7467, 0x284e),
+    (0x7469, 0x1129),
+    (0x746a, 0x0fb2),
+    (0x746d, 0x0fb4),
+    (0x746e, 0x284f),
+    (0x746f, 0x0dff),
+    (0x7470, 0x0fb3),
+    (0x7471, 0x284b),
+    (0x7472, 0x284d),
+    (0x7473, 0x284a),
+    (0x7475, 0x284c),
+    (0x7479, 0x2a9a),
+    (0x747c, 0x2a99),
+    (0x747d, 0x2a96),
+    (0x747e, 0x112c),
+    (0x747f, 0x2ce7),
+    (0x7480, 0x112d),
+    (0x7481, 0x2a95),
+    (0x7483, 0x112b),
+    (0x7485, 0x2a97),
+    (0x7486, 0x2a94),
+    (0x7487, 0x2a91),
+    (0x7488, 0x2a98),
+    (0x7489, 0x2a92),
+    (0x748a, 0x2a93),
+    (0x748b, 0x112a),
+    (0x7490, 0x2ec5),
+    (0x7492, 0x2ceb),
+    (0x7494, 0x2cea),
+    (0x7495, 0x2cec),
+    (0x7497, 0x2ec2),
+    (0x7498, 0x1280),
+    (0x749a, 0x2ce8),
+    (0x749c, 0x127e),
+    (0x749e, 0x1282),
+    (0x749f, 0x1281),
+    (0x74a0, 0x2ce9),
+    (0x74a1, 0x2ced),
+    (0x74a3, 0x127f),
+    (0x74a5, 0x2ec9),
+    (0x74a6, 0x1396),
+    (0x74a7, 0x1477),
+    (0x74a8, 0x1397),
+    (0x74a9, 0x1394),
+    (0x74aa, 0x2ec6),
+    (0x74ab, 0x2ec4),
+    (0x74ad, 0x2ec7),
+    (0x74af, 0x2eca),
+    (0x74b0, 0x1395),
+    (0x74b1, 0x2ec8),
+    (0x74b2, 0x2ec3),
+    (0x74b5, 0x3075),
+    (0x74b6, 0x3078),
+    (0x74b7, 0x31e2),
+    (0x74b8, 0x3073),
+    (0x74ba, 0x3316),
+    (0x74bb, 0x3079),
+    (0x74bd, 0x151a),
+    (0x74be, 0x3077),
+    (0x74bf, 0x1478),
+    (0x74c0, 0x3074),
+    (0x74c1, 0x3076),
+    (0x74c2, 0x307a),
+    (0x74c3, 0x31e3),
+    (0x74c5, 0x31e1),
+    (0x74ca, 0x151b),
+    (0x74cb, 0x31e0),
+    (0x74cf, 0x15a5),
+    (0x74d4, 0x1602),
+    (0x74d5, 0x34b0),
+    (0x74d6, 0x1601),
+    (0x74d7, 0x34b2),
+    (0x74d8, 0x34af),
+    (0x74d9, 0x34b1),
+    (0x74da, 0x1682),
+    (0x74db, 0x35ab),
+    (0x74dc, 0x02dc),
+    (0x74dd, 0x1b2a),
+    (0x74de, 0x1e67),
+    (0x74df, 0x1e68),
+    (0x74e0, 0x0aaa),
+    (0x74e1, 0x25c6),
+    (0x74e2, 0x1283),
+    (0x74e3, 0x151c),
+    (0x74e4, 0x164f),
+    (0x74e5, 0x35ac),
+    (0x74e6, 0x02dd),
+    (0x74e8, 0x1b2b),
+    (0x74e9, 0x00bd),
+    (0x74ec, 0x1cab),
+    (0x74ee, 0x1cac),
+    (0x74f4, 0x1e69),
+    (0x74f5, 0x1e6a),
+    (0x74f6, 0x0aab),
+    (0x74f7, 0x0aac),
+    (0x74fb, 0x233e),
+    (0x74fd, 0x25c9),
+    (0x74fe, 0x25c8),
+    (0x74ff, 0x25c7),
+    (0x7500, 0x2850),
+    (0x7502, 0x2851),
+    (0x7503, 0x2852),
+    (0x7504, 0x0fb5),
+    (0x7507, 0x2a9c),
+    (0x7508, 0x2a9b),
+    (0x750b, 0x2cee),
+    (0x750c, 0x1284),
+    (0x750d, 0x1285),
+    (0x750f, 0x2ece),
+    (0x7510, 0x2ecb),
+    (0x7511, 0x2ecc),
+    (0x7512, 0x2ecd),
+    (0x7513, 0x307c),
+    (0x7514, 0x307b),
+    (0x7515, 0x1479),
+    (0x7516, 0x31e4),
+    (0x7517, 0x3400),
+    (0x7518, 0x02de),
+    (0x751a, 0x0723),
+    (0x751c, 0x0aad),
+    (0x751d, 0x25ca),
+    (0x751f, 0x02df),
+    (0x7521, 0x1e6b),
+    (0x7522, 0x0aae),
+    (0x7525, 0x0c67),
+    (0x7526, 0x0c68),
+    (0x7528, 0x02e0),
+    (0x7529, 0x02e1),
+    (0x752a, 0x192b),
+    (0x752b, 0x0475),
+    (0x752c, 0x0474),
+    (0x752d, 0x0724),
+    (0x752e, 0x1cad),
+    (0x752f, 0x233f),
+    (0x7530, 0x02e2),
+    (0x7531, 0x02e3),
+    (0x7532, 0x02e4),
+    (0x7533, 0x02e5),
+    (0x7537, 0x0476),
+    (0x7538, 0x0477),
+    (0x7539, 0x19f0),
+    (0x753a, 0x19ef),
+    (0x753d, 0x05d9),
+    (0x753e, 0x1b2e),
+    (0x753f, 0x1b2c),
+    (0x7540, 0x1b2d),
+    (0x7547, 0x1cae),
+    (0x7548, 0x1caf),
+    (0x754b, 0x0728),
+    (0x754c, 0x0726),
+    (0x754e, 0x0727),
+    (0x754f, 0x0725),
+    (0x7554, 0x08b6),
+    (0x7559, 0x08ba),
+    (0x755a, 0x08b9),
+    (0x755b, 0x1e6c),
+    (0x755c, 0x08b8),
+    (0x755d, 0x08b7),
+    (0x755f, 0x1e6d),
+    (0x7562, 0x0ab1),
+    (0x7563, 0x20bc),
+    (0x7564, 0x20bb),
+    (0x7565, 0x0aaf),
+    (0x7566, 0x0ab0),
+    (0x756a, 0x0c6a),
+    (0x756b, 0x0c69),
+    (0x756c, 0x2341),
+    (0x756f, 0x2340),
+    (0x7570, 0x0ab2),
+    (0x7576, 0x0e09),
+    (0x7577, 0x25cc),
+    (0x7578, 0x0e0a),
+    (0x7579, 0x25cb),
+    (0x757d, 0x2853),
+    (0x757e, 0x2a9d),
+    (0x757f, 0x112e),
+    (0x7580, 0x2cef),
+    (0x7584, 0x2ecf),
+    (0x7586, 0x151e),
+    (0x7587, 0x151d),
+    (0x758a, 0x1650),
+    (0x758b, 0x02e6),
+    (0x758c, 0x1b2f),
+    (0x758f, 0x0ab3),
+    (0x7590, 0x2854),
+    (0x7591, 0x0fb6),
+    (0x7594, 0x19f1),
+    (0x7595, 0x19f2),
+    (0x7598, 0x1b30),
+    (0x7599, 0x05db),
+    (0x759a, 0x05dc),
+    (0x759d, 0x05da),
+    (0x75a2, 0x072c),
+    (0x75a3, 0x072d),
+    (0x75a4, 0x072a),
+    (0x75a5, 0x072b),
+    (0x75a7, 0x1cb0),
+    (0x75aa, 0x1cb1),
+    (0x75ab, 0x0729),
+    (0x75b0, 0x1e6e),
+    (0x75b2, 0x08be),
+    (0x75b3, 0x08bf),
+    (0x75b5, 0x0ab6),
+    (0x75b6, 0x1e74),
+    (0x75b8, 0x08c4),
+    (0x75b9, 0x08c2),
+    (0x75ba, 0x1e75),
+    (0x75bb, 0x1e70),
+    (0x75bc, 0x08c1),
+    (0x75bd, 0x08c0),
+    (0x75be, 0x08bb),
+    (0x75bf, 0x1e73),
+    (0x75c0, 0x1e72),
+    (0x75c1, 0x1e6f),
+    (0x75c2, 0x08c3),
+    (0x75c4, 0x1e71),
+    (0x75c5, 0x08bc),
+    (0x75c7, 0x08bd),
+    (0x75ca, 0x0ab7),
+    (0x75cb, 0x20c0),
+    (0x75cc, 0x20c1),
+    (0x75cd, 0x0ab8),
+    (0x75ce, 0x20bd),
+    (0x75cf, 0x20bf),
+    (0x75d0, 0x20c3),
+    (0x75d1, 0x20c2),
+    (0x75d2, 0x20be),
+    (0x75d4, 0x0ab4),
+    (0x75d5, 0x0ab5),
+    (0x75d7, 0x2349),
+    (0x75d8, 0x0c6f),
+    (0x75d9, 0x0c6e),
+    (0x75da, 0x2343),
+    (0x75db, 0x0c6c),
+    (0x75dd, 0x2346),
+    (0x75de, 0x0c70),
+    (0x75df, 0x2347),
+    (0x75e0, 0x0c71),
+    (0x75e1, 0x2344),
+    (0x75e2, 0x0c6b),
+    (0x75e3, 0x0c6d),
+    (0x75e4, 0x2348),
+    (0x75e6, 0x2345),
+    (0x75e7, 0x2342),
+    (0x75ed, 0x25d9),
+    (0x75ef, 0x25ce),
+    (0x75f0, 0x0e0c),
+    (0x75f1, 0x0e0f),
+    (0x75f2, 0x0e0e),
+    (0x75f3, 0x0e13),
+    (0x75f4, 0x0e12),
+    (0x75f5, 0x25da),
+    (0x75f6, 0x25d8),
+    (0x75f7, 0x25d1),
+    (0x75f8, 0x25d5),
+    (0x75f9, 0x25d4),
+    (0x75fa, 0x0e10),
+    (0x75fb, 0x25d7),
+    (0x75fc, 0x25d3),
+    (0x75fd, 0x25db),
+    (0x75fe, 0x25d2),
+    (0x75ff, 0x0e11),
+    (0x7600, 0x0e0b),
+    (0x7601, 0x0e0d),
+    (0x7603, 0x25d0),
+    (0x7608, 0x2856),
+    (0x7609, 0x0fba),
+    (0x760a, 0x285a),
+    (0x760b, 0x0fb9),
+    (0x760c, 0x2857),
+    (0x760d, 0x0fb8),
+    (0x760f, 0x25cf),
+    (0x7610, 0x25d6),
+    (0x7611, 0x2859),
+    (0x7613, 0x0fbb),
+    (0x7614, 0x285b),
+    (0x7615, 0x2858),
+    (0x7616, 0x2855),
+    (0x7619, 0x2aa0),
+    (0x761a, 0x2aa4),
+    (0x761b, 0x2aa6),
+    (0x761c, 0x2aa2),
+    (0x761d, 0x2aa1),
+    (0x761e, 0x2a9f),
+    (0x761f, 0x1131),
+    (0x7620, 0x112f),
+    (0x7621, 0x1134),
+    (0x7622, 0x1135),
+    (0x7623, 0x2aa3),
+    (0x7624, 0x1132),
+    (0x7625, 0x2a9e),
+    (0x7626, 0x1133),
+    (0x7627, 0x0fb7),
+    (0x7628, 0x2aa5),
+    (0x7629, 0x1130),
+    (0x762d, 0x2cf1),
+    (0x762f, 0x2cf0),
+    (0x7630, 0x2cf8),
+    (0x7631, 0x2cf2),
+    (0x7632, 0x2cf7),
+    (0x7633, 0x2cf4),
+    (0x7634, 0x1286),
+    (0x7635, 0x2cf6),
+    (0x7638, 0x1287),
+    (0x763a, 0x1288),
+    (0x763c, 0x2cf5),
+    (0x763d, 0x2cf3),
+    (0x7642, 0x1399),
+    (0x7643, 0x2ed0),
+    (0x7646, 0x1398),
+    (0x7647, 0x2ed3),
+    (0x7648, 0x2ed1),
+    (0x7649, 0x2ed2),
+    (0x764c, 0x139a),
+    (0x7650, 0x3080),
+    (0x7652, 0x147c),
+    (0x7653, 0x3081),
+    (0x7656, 0x147a),
+    (0x7657, 0x3082),
+    (0x7658, 0x147b),
+    (0x7659, 0x307f),
+    (0x765a, 0x3083),
+    (0x765c, 0x307d),
+    (0x765f, 0x151f),
+    (0x7660, 0x31e5),
+    (0x7661, 0x1520),
+    (0x7662, 0x15a6),
+    (0x7664, 0x307e),
+    (0x7665, 0x15a7),
+    (0x7669, 0x1603),
+    (0x766a, 0x3401),
+    (0x766c, 0x1652),
+    (0x766d, 0x34b3),
+    (0x766e, 0x1651),
+    (0x7670, 0x3546),
+    (0x7671, 0x16a7),
+    (0x7672, 0x16a8),
+    (0x7675, 0x3662),
+    (0x7678, 0x072e),
+    (0x7679, 0x1cb2),
+    (0x767b, 0x0c72),
+    (0x767c, 0x0c73),
+    (0x767d, 0x02e7),
+    (0x767e, 0x0379),
+    (0x767f, 0x192c),
+    (0x7681, 0x19f3),
+    (0x7682, 0x0478),
+    (0x7684, 0x05dd),
+    (0x7686, 0x072f),
+    (0x7687, 0x0730),
+    (0x7688, 0x0731),
+    (0x7689, 0x20c5),
+    (0x768a, 0x1e76),
+    (0x768b, 0x08c5),
+    (0x768e, 0x0ab9),
+    (0x768f, 0x20c4),
+    (0x7692, 0x234b),
+    (0x7693, 0x0c75),
+    (0x7695, 0x234a),
+    (0x7696, 0x0c74),
+    (0x7699, 0x25dc),
+    (0x769a, 0x1136),
+    (0x769b, 0x2aaa),
+    (0x769c, 0x2aa7),
+    (0x769d, 0x2aa8),
+    (0x769e, 0x2aa9),
+    (0x76a4, 0x2ed4),
+    (0x76a6, 0x3084),
+    (0x76aa, 0x3318),
+    (0x76ab, 0x3317),
+    (0x76ad, 0x34b4),
+    (0x76ae, 0x02e8),
+    (0x76af, 0x1b31),
+    (0x76b0, 0x08c6),
+    (0x76b4, 0x0c76),
+    (0x76b5, 0x25dd),
+    (0x76b8, 0x285c),
+    (0x76ba, 0x1137),
+    (0x76bb, 0x2cf9),
+    (0x76bd, 0x3085),
+    (0x76be, 0x3319),
+    (0x76bf, 0x02e9),
+    (0x76c2, 0x05de),
+    (0x76c3, 0x0734),
+    (0x76c4, 0x1cb3),
+    (0x76c5, 0x0735),
+    (0x76c6, 0x0733),
+    (0x76c8, 0x0732),
+    (0x76c9, 0x1e77),
+    (0x76ca, 0x08c7),
+    (0x76cd, 0x08c8),
+    (0x76ce, 0x08c9),
+    (0x76d2, 0x0abb),
+    (0x76d3, 0x20c6),
+    (0x76d4, 0x0aba),
+    (0x76da, 0x234c),
+    (0x76db, 0x0abc),
+    (0x76dc, 0x0c77),
+    (0x76dd, 0x25de),
+    (0x76de, 0x0e14),
+    (0x76df, 0x0e15),
+    (0x76e1, 0x0fbc),
+    (0x76e3, 0x0fbd),
+    (0x76e4, 0x1138),
+    (0x76e5, 0x128a),
+    (0x76e6, 0x2cfa),
+    (0x76e7, 0x1289),
+    (0x76e9, 0x2ed5),
+    (0x76ea, 0x139b),
+    (0x76ec, 0x3086),
+    (0x76ed, 0x331a),
+    (0x76ee, 0x02ea),
+    (0x76ef, 0x0479),
+    (0x76f0, 0x1b34),
+    (0x76f1, 0x1b33),
+    (0x76f2, 0x05df),
+    (0x76f3, 0x1b32),
+    (0x76f4, 0x05e0),
+    (0x76f5, 0x1b35),
+    (0x76f7, 0x1cb9),
+    (0x76f8, 0x0738),
+    (0x76f9, 0x0737),
+    (0x76fa, 0x1cbb),
+    (0x76fb, 0x1cba),
+    (0x76fc, 0x073c),
+    (0x76fe, 0x073b),
+    (0x7701, 0x0736),
+    (0x7703, 0x1cb5),
+    (0x7704, 0x1cb6),
+    (0x7705, 0x1cb7),
+    (0x7707, 0x073d),
+    (0x7708, 0x1cb4),
+    (0x7709, 0x0739),
+    (0x770a, 0x1cb8),
+    (0x770b, 0x073a),
+    (0x7710, 0x1e7a),
+    (0x7711, 0x1e7e),
+    (0x7712, 0x1e7c),
+    (0x7713, 0x1e7b),
+    (0x7715, 0x1e7f),
+    (0x7719, 0x1e80),
+    (0x771a, 0x1e81),
+    (0x771b, 0x1e79),
+    (0x771d, 0x1e78),
+    (0x771f, 0x08cb),
+    (0x7720, 0x08cc),
+    (0x7722, 0x1e82),
+    (0x7723, 0x1e7d),
+    (0x7725, 0x20cf),
+    (0x7727, 0x1e83),
+    (0x7728, 0x08cd),
+    (0x7729, 0x08ca),
+    (0x772d, 0x20c9),
+    (0x772f, 0x20c8),
+    (0x7731, 0x20ca),
+    (0x7732, 0x20cb),
+    (0x7733, 0x20cd),
+    (0x7734, 0x20cc),
+    (0x7735, 0x20d1),
+    (0x7736, 0x0ac0),
+    (0x7737, 0x0abd),
+    (0x7738, 0x0ac1),
+    (0x7739, 0x20c7),
+    (0x773a, 0x0ac2),
+    (0x773b, 0x20d0),
+    (0x773c, 0x0abf),
+    (0x773d, 0x20ce),
+    (0x773e, 0x0abe),
+    (0x7744, 0x234f),
+    (0x7745, 0x2351),
+    (0x7746, 0x234d),
+    (0x7747, 0x234e),
+    (0x774a, 0x2352),
+    (0x774b, 0x2354),
+    (0x774c, 0x2355),
+    (0x774d, 0x2350),
+    (0x774e, 0x2353),
+    (0x774f, 0x0c78),
+    (0x7752, 0x25e2),
+    (0x7754, 0x25e7),
+    (0x7755, 0x25df),
+    (0x7756, 0x25e3),
+    (0x7759, 0x25e8),
+    (0x775a, 0x25e4),
+    (0x775b, 0x0e16),
+    (0x775c, 0x0e1e),
+    (0x775e, 0x0e19),
+    (0x775f, 0x25e0),
+    (0x7760, 0x25e1),
+    (0x7761, 0x0fc1),
+    (0x7762, 0x0e21),
+    (0x7763, 0x0e1a),
+    (0x7765, 0x0e1f),
+    (0x7766, 0x0e18),
+    (0x7767, 0x25e6),
+    (0x7768, 0x0e20),
+    (0x7769, 0x25e5),
+    (0x776a, 0x0e1c),
+    (0x776b, 0x0e17),
+    (0x776c, 0x0e1d),
+    (0x776d, 0x25e9),
+    (0x776e, 0x2861),
+    (0x776f, 0x2863),
+    (0x7779, 0x0e1b),
+    (0x777c, 0x285e),
+    (0x777d, 0x0fbf),
+    (0x777e, 0x2864),
+    (0x777f, 0x0fc0),
+    (0x7780, 0x2862),
+    (0x7781, 0x285d),
+    (0x7782, 0x2860),
+    (0x7783, 0x2865),
+    (0x7784, 0x0fbe),
+    (0x7785, 0x285f),
+    (0x7787, 0x113a),
+    (0x7788, 0x2aae),
+    (0x7789, 0x2aad),
+    (0x778b, 0x113d),
+    (0x778c, 0x113b),
+    (0x778d, 0x2aab),
+    (0x778e, 0x1139),
+    (0x778f, 0x2aac),
+    (0x7791, 0x113c),
+    (0x7795, 0x2d02),
+    (0x7797, 0x2d04),
+    (0x7799, 0x2d03),
+    (0x779a, 0x2cfb),
+    (0x779b, 0x2cff),
+    (0x779c, 0x2cfe),
+    (0x779d, 0x2cfc),
+    (0x779e, 0x128c),
+    (0x779f, 0x128d),
+    (0x77a0, 0x128b),
+    (0x77a1, 0x2cfd),
+    (0x77a2, 0x2d00),
+    (0x77a3, 0x2d01),
+    (0x77a5, 0x128e),
+    (0x77a7, 0x13a0),
+    (0x77a8, 0x2edd),
+    (0x77aa, 0x139d),
+    (0x77ab, 0x2ed7),
+    (0x77ac, 0x139f),
+    (0x77ad, 0x13a1),
+    (0x77b0, 0x139e),
+    (0x77b1, 0x2edc),
+    (0x77b2, 0x2ed8),
+    (0x77b3, 0x139c),
+    (0x77b4, 0x2edb),
+    (0x77b5, 0x2ed6),
+    (0x77b6, 0x2eda),
+    (0x77b7, 0x2ed9),
+    (0x77ba, 0x3088),
+    (0x77bb, 0x147f),
+    (0x77bc, 0x1480),
+    (0x77bd, 0x147d),
+    (0x77bf, 0x147e),
+    (0x77c2, 0x3087),
+    (0x77c4, 0x31e8),
+    (0x77c7, 0x1521),
+    (0x77c9, 0x31e6),
+    (0x77ca, 0x31e7),
+    (0x77cc, 0x331b),
+    (0x77cd, 0x331e),
+    (0x77ce, 0x331c),
+    (0x77cf, 0x331d),
+    (0x77d0, 0x3402),
+    (0x77d3, 0x1604),
+    (0x77d4, 0x3547),
+    (0x77d5, 0x35ad),
+    (0x77d7, 0x16a9),
+    (0x77d8, 0x35f1),
+    (0x77d9, 0x35f2),
+    (0x77da, 0x16d7),
+    (0x77db, 0x02eb),
+    (0x77dc, 0x073e),
+    (0x77de, 0x2356),
+    (0x77e0, 0x25ea),
+    (0x77e2, 0x02ec),
+    (0x77e3, 0x047a),
+    (0x77e5, 0x05e1),
+    (0x77e7, 0x1cbc),
+    (0x77e8, 0x1cbd),
+    (0x77e9, 0x08ce),
+    (0x77ec, 0x2357),
+    (0x77ed, 0x0c79),
+    (0x77ee, 0x0e22),
+    (0x77ef, 0x13a2),
+    (0x77f0, 0x2ede),
+    (0x77f1, 0x31e9),
+    (0x77f2, 0x331f),
+    (0x77f3, 0x02ed),
+    (0x77f7, 0x1b3b),
+    (0x77f8, 0x1b36),
+    (0x77f9, 0x1b38),
+    (0x77fa, 0x1b3a),
+    (0x77fb, 0x1b39),
+    (0x77fc, 0x1b37),
+    (0x77fd, 0x05e2),
+    (0x7802, 0x073f),
+    (0x7803, 0x1cc6),
+    (0x7805, 0x1cc1),
+    (0x7806, 0x1cbe),
+    (0x7809, 0x1cc5),
+    (0x780c, 0x0741),
+    (0x780d, 0x0742),
+    (0x780e, 0x1cc4),
+    (0x780f, 0x1cc3),
+    (0x7810, 0x1cc2),
+    (0x7811, 0x1cbf),
+    (0x7812, 0x1cc0),
+    (0x7813, 0x1cc7),
+    (0x7814, 0x0740),
+    (0x781d, 0x08d2),
+    (0x781f, 0x08d8),
+    (0x7820, 0x08d7),
+    (0x7821, 0x1e8c),
+    (0x7822, 0x1e86),
+    (0x7823, 0x1e84),
+    (0x7825, 0x08d5),
+    (0x7826, 0x20d8),
+    (0x7827, 0x08d0),
+    (0x7828, 0x1e89),
+    (0x7829, 0x1e8d),
+    (0x782a, 0x1e8f),
+    (0x782b, 0x1e8b),
+    (0x782c, 0x1e85),
+    (0x782d, 0x08d6),
+    (0x782e, 0x1e8a),
+    (0x782f, 0x1e88),
+    (0x7830, 0x08cf),
+    (0x7831, 0x1e90),
+    (0x7832, 0x08d9),
+    (0x7833, 0x1e8e),
+    (0x7834, 0x08d3),
+    (0x7835, 0x1e87),
+    (0x7837, 0x08d4),
+    (0x7838, 0x08d1),
+    (0x7843, 0x0ac4),
+    (0x7845, 0x20d9),
+    (0x7848, 0x20d2),
+    (0x7849, 0x20d4),
+    (0x784a, 0x20d6),
+    (0x784c, 0x20d7),
+    (0x784d, 0x20d5),
+    (0x784e, 0x0ac5),
+    (0x7850, 0x20da),
+    (0x7852, 0x20d3),
+    (0x785c, 0x235b),
+    (0x785d, 0x0c7a),
+    (0x785e, 0x2363),
+    (0x7860, 0x2358),
+    (0x7862, 0x2364),
+    (0x7864, 0x2359),
+    (0x7865, 0x235a),
+    (0x7868, 0x2362),
+    (0x7869, 0x2361),
+    (0x786a, 0x235e),
+    (0x786b, 0x0ac3),
+    (0x786c, 0x0c7b),
+    (0x786d, 0x235c),
+    (0x786e, 0x235f),
+    (0x786f, 0x0c7c),
+    (0x7870, 0x2360),
+    (0x7871, 0x235d),
+    (0x7879, 0x25f5),
+    (0x787b, 0x25f9),
+    (0x787c, 0x0e29),
+    (0x787e, 0x286b),
+    (0x787f, 0x0e2c),
+    (0x7880, 0x25f7),
+    (0x7883, 0x25f4),
+    (0x7884, 0x25ef),
+    (0x7885, 0x25f1),
+    (0x7886, 0x25f2),
+    (0x7887, 0x25eb),
+    (0x7889, 0x0e28),
+    (0x788c, 0x0e27),
+    (0x788e, 0x0e23),
+    (0x788f, 0x25ee),
+    (0x7891, 0x0e2a),
+    (0x7893, 0x0e2b),
+    (0x7894, 0x25ed),
+    (0x7895, 0x25f0),
+    (0x7896, 0x25f8),
+    (0x7897, 0x0e25),
+    (0x7898, 0x0e26),
+    (0x7899, 0x25f6),
+    (0x789a, 0x25ec),
+    (0x789e, 0x286d),
+    (0x789f, 0x0fc3),
+    (0x78a0, 0x286f),
+    (0x78a1, 0x25f3),
+    (0x78a2, 0x2871),
+    (0x78a3, 0x0fc7),
+    (0x78a4, 0x2872),
+    (0x78a5, 0x286e),
+    (0x78a7, 0x0fc4),
+    (0x78a8, 0x286a),
+    (0x78a9, 0x0fc6),
+    (0x78aa, 0x2867),
+    (0x78ab, 0x286c),
+    (0x78ac, 0x2870),
+    (0x78ad, 0x2869),
+    (0x78b0, 0x0e24),
+    (0x78b2, 0x2866),
+    (0x78b3, 0x0fc5),
+    (0x78b4, 0x2868),
+    (0x78ba, 0x1140),
+    (0x78bb, 0x2ab0),
+    (0x78bc, 0x1144),
+    (0x78be, 0x1142),
+    (0x78c1, 0x0fc2),
+    (0x78c3, 0x2ab7),
+    (0x78c4, 0x2ab8),
+    (0x78c5, 0x113f),
+    (0x78c8, 0x2ab6),
+    (0x78c9, 0x2ab9),
+    (0x78ca, 0x1141),
+    (0x78cb, 0x113e),
+    (0x78cc, 0x2ab2),
+    (0x78cd, 0x2aaf),
+    (0x78ce, 0x2ab4),
+    (0x78cf, 0x2ab1),
+    (0x78d0, 0x1145),
+    (0x78d1, 0x2ab3),
+    (0x78d4, 0x2ab5),
+    (0x78d5, 0x1143),
+    (0x78da, 0x1290),
+    (0x78db, 0x2d0b),
+    (0x78dd, 0x2d05),
+    (0x78de, 0x2d09),
+    (0x78df, 0x2d0f),
+    (0x78e0, 0x2d10),
+    (0x78e1, 0x2d0c),
+    (0x78e2, 0x2d0d),
+    (0x78e3, 0x2d0a),
+    (0x78e5, 0x2d07),
+    (0x78e7, 0x1292),
+    (0x78e8, 0x128f),
+    (0x78e9, 0x2d06),
+    (0x78ea, 0x2d08),
+    (0x78ec, 0x1291),
+    (0x78ed, 0x2d0e),
+    (0x78ef, 0x13a6),
+    (0x78f2, 0x2ee4),
+    (0x78f3, 0x2edf),
+    (0x78f4, 0x13a5),
+    (0x78f7, 0x13a3),
+    (0x78f9, 0x2ee6),
+    (0x78fa, 0x13a4),
+    (0x78fb, 0x2ee2),
+    (0x78fc, 0x2ee3),
+    (0x78fd, 0x2ee0),
+    (0x78fe, 0x2ee7),
+    (0x78ff, 0x3089),
+    (0x7901, 0x13a7),
+    (0x7902, 0x2ee1),
+    (0x7904, 0x2ee8),
+    (0x7905, 0x2ee5),
+    (0x7909, 0x308d),
+    (0x790c, 0x308a),
+    (0x790e, 0x1481),
+    (0x7910, 0x308e),
+    (0x7911, 0x3090),
+    (0x7912, 0x308f),
+    (0x7913, 0x308b),
+    (0x7914, 0x308c),
+    (0x7917, 0x31ee),
+    (0x7919, 0x1522),
+    (0x791b, 0x31eb),
+    (0x791c, 0x31ed),
+    (0x791d, 0x31ea),
+    (0x791e, 0x31ef),
+    (0x7921, 0x31ec),
+    (0x7923, 0x3321),
+    (0x7924, 0x3324),
+    (0x7925, 0x3320),
+    (0x7926, 0x15a8),
+    (0x7927, 0x3322),
+    (0x7928, 0x3323),
+    (0x7929, 0x3325),
+    (0x792a, 0x15a9),
+    (0x792b, 0x15ab),
+    (0x792c, 0x15aa),
+    (0x792d, 0x3403),
+    (0x792f, 0x3405),
+    (0x7931, 0x3404),
+    (0x7935, 0x34b5),
+    (0x7938, 0x35ae),
+    (0x7939, 0x35f3),
+    (0x793a, 0x02ee),
+    (0x793d, 0x19f4),
+    (0x793e, 0x05e3),
+    (0x793f, 0x1b3d),
+    (0x7940, 0x05e4),
+    (0x7941, 0x05e5),
+    (0x7942, 0x1b3c),
+    (0x7944, 0x1ccc),
+    (0x7945, 0x1ccb),
+    (0x7946, 0x0743),
+    (0x7947, 0x0746),
+    (0x7948, 0x0745),
+    (0x7949, 0x0744),
+    (0x794a, 0x1cc8),
+    (0x794b, 0x1cca),
+    (0x794c, 0x1cc9),
+    (0x794f, 0x1e93),
+    (0x7950, 0x08db),
+    (0x7951, 0x1e97),
+    (0x7952, 0x1e96),
+    (0x7953, 0x1e95),
+    (0x7954, 0x1e91),
+    (0x7955, 0x08da),
+    (0x7956, 0x08de),
+    (0x7957, 0x08e1),
+    (0x795a, 0x08e2),
+    (0x795b, 0x1e92),
+    (0x795c, 0x1e94),
+    (0x795d, 0x08e0),
+    (0x795e, 0x08df),
+    (0x795f, 0x08dd),
+    (0x7960, 0x08dc),
+    (0x7961, 0x20e1),
+    (0x7963, 0x20df),
+    (0x7964, 0x20db),
+    (0x7965, 0x0ac6),
+    (0x7967, 0x20dc),
+    (0x7968, 0x0ac7),
+    (0x7969, 0x20dd),
+    (0x796a, 0x20de),
+    (0x796b, 0x20e0),
+    (0x796d, 0x0ac8),
+    (0x7970, 0x2368),
+    (0x7972, 0x2367),
+    (0x7973, 0x2366),
+    (0x7974, 0x2365),
+    (0x7979, 0x25fd),
+    (0x797a, 0x0e2d),
+    (0x797c, 0x25fa),
+    (0x797d, 0x25fc),
+    (0x797f, 0x0e2e),
+    (0x7981, 0x0e2f),
+    (0x7982, 0x25fb),
+    (0x7988, 0x287b),
+    (0x798a, 0x2874),
+    (0x798b, 0x2875),
+    (0x798d, 0x0fca),
+    (0x798e, 0x0fc8),
+    (0x798f, 0x0fc9),
+    (0x7990, 0x287d),
+    (0x7992, 0x287c),
+    (0x7993, 0x2879),
+    (0x7994, 0x2878),
+    (0x7995, 0x2877),
+    (0x7996, 0x2876),
+    (0x7997, 0x287a),
+    (0x7998, 0x2873),
+    (0x799a, 0x2aba),
+    (0x799b, 0x2abf),
+    (0x799c, 0x2abd),
+    (0x79a0, 0x2abc),
+    (0x79a1, 0x2abb),
+    (0x79a2, 0x2abe),
+    (0x79a4, 0x2d11),
+    (0x79a6, 0x1293),
+    (0x79a7, 0x13a8),
+    (0x79a8, 0x2eea),
+    (0x79aa, 0x13a9),
+    (0x79ab, 0x2ee9),
+    (0x79ac, 0x3092),
+    (0x79ad, 0x3091),
+    (0x79ae, 0x1482),
+    (0x79b0, 0x31f0),
+    (0x79b1, 0x1523),
+    (0x79b2, 0x3326),
+    (0x79b3, 0x1653),
+    (0x79b4, 0x34b6),
+    (0x79b6, 0x35b0),
+    (0x79b7, 0x35af),
+    (0x79b8, 0x18ce),
+    (0x79b9, 0x0747),
+    (0x79ba, 0x0748),
+    (0x79bb, 0x20e2),
+    (0x79bd, 0x0e31),
+    (0x79be, 0x02ef),
+    (0x79bf, 0x047d),
+    (0x79c0, 0x047c),
+    (0x79c1, 0x047b),
+    (0x79c5, 0x1b3e),
+    (0x79c8, 0x05e7),
+    (0x79c9, 0x05e6),
+    (0x79cb, 0x074b),
+    (0x79cd, 0x1cce),
+    (0x79ce, 0x1cd1),
+    (0x79cf, 0x1ccf),
+    (0x79d1, 0x0749),
+    (0x79d2, 0x074a),
+    (0x79d5, 0x1ccd),
+    (0x79d6, 0x1cd0),
+    (0x79d8, 0x08e9),
+    (0x79dc, 0x1e9e),
+    (0x79dd, 0x1ea0),
+    (0x79de, 0x1e9f),
+    (0x79df, 0x08e6),
+    (0x79e0, 0x1e9a),
+    (0x79e3, 0x08e4),
+    (0x79e4, 0x08e3),
+    (0x79e6, 0x08e7),
+    (0x79e7, 0x08e5),
+    (0x79e9, 0x08e8),
+    (0x79ea, 0x1e9d),
+    (0x79eb, 0x1e98),
+    (0x79ec, 0x1e99),
+    (0x79ed, 0x1e9c),
+    (0x79ee, 0x1e9b),
+    (0x79f6, 0x20e5),
+    (0x79f7, 0x20e6),
+    (0x79f8, 0x20e4),
+    (0x79fa, 0x20e3),
+    (0x79fb, 0x0ac9),
+    (0x7a00, 0x0c81),
+    (0x7a02, 0x2369),
+    (0x7a03, 0x236b),
+    (0x7a04, 0x236d),
+    (0x7a05, 0x0c80),
+    (0x7a08, 0x0c7e),
+    (0x7a0a, 0x236a),
+    (0x7a0b, 0x0c7f),
+    (0x7a0c, 0x236c),
+    (0x7a0d, 0x0c7d),
+    (0x7a10, 0x2607),
+    (0x7a11, 0x25fe),
+    (0x7a12, 0x2601),
+    (0x7a13, 0x2605),
+    (0x7a14, 0x0e35),
+    (0x7a15, 0x2603),
+    (0x7a17, 0x2602),
+    (0x7a18, 0x25ff),
+    (0x7a19, 0x2600),
+    (0x7a1a, 0x0e33),
+    (0x7a1b, 0x2606),
+    (0x7a1c, 0x0e32),
+    (0x7a1e, 0x0e37),
+    (0x7a1f, 0x0e36),
+    (0x7a20, 0x0e34),
+    (0x7a22, 0x2604),
+    (0x7a26, 0x2883),
+    (0x7a28, 0x2882),
+    (0x7a2b, 0x287e),
+    (0x7a2e, 0x0fcb),
+    (0x7a2f, 0x2881),
+    (0x7a30, 0x2880),
+    (0x7a31, 0x0fcc),
+    (0x7a37, 0x114a),
+    (0x7a39, 0x2ac1),
+    (0x7a3b, 0x114b),
+    (0x7a3c, 0x1147),
+    (0x7a3d, 0x1149),
+    (0x7a3f, 0x1146),
+    (0x7a40, 0x1148),
+    (0x7a44, 0x2d12),
+    (0x7a46, 0x1296),
+    (0x7a47, 0x2d14),
+    (0x7a48, 0x2d13),
+    (0x7a4a, 0x287f),
+    (0x7a4b, 0x1298),
+    (0x7a4c, 0x1297),
+    (0x7a4d, 0x1294),
+    (0x7a4e, 0x1295),
+    (0x7a54, 0x2eef),
+    (0x7a56, 0x2eed),
+    (0x7a57, 0x13aa),
+    (0x7a58, 0x2eee),
+    (0x7a5a, 0x2ef0),
+    (0x7a5b, 0x2eec),
+    (0x7a5c, 0x2eeb),
+    (0x7a5f, 0x3093),
+    (0x7a60, 0x1485),
+    (0x7a61, 0x1483),
+    (0x7a62, 0x1484),
+    (0x7a67, 0x31f1),
+    (0x7a68, 0x31f2),
+    (0x7a69, 0x1525),
+    (0x7a6b, 0x1524),
+    (0x7a6c, 0x3328),
+    (0x7a6d, 0x3329),
+    (0x7a6e, 0x3327),
+    (0x7a70, 0x34b7),
+    (0x7a71, 0x34b8),
+    (0x7a74, 0x02f0),
+    (0x7a75, 0x192d),
+    (0x7a76, 0x047e),
+    (0x7a78, 0x1b3f),
+    (0x7a79, 0x05e9),
+    (0x7a7a, 0x05e8),
+    (0x7a7b, 0x1b40),
+    (0x7a7e, 0x1cd3),
+    (0x7a7f, 0x074c),
+    (0x7a80, 0x1cd2),
+    (0x7a81, 0x074d),
+    (0x7a84, 0x08ea),
+    (0x7a85, 0x1ea3),
+    (0x7a86, 0x1ea1),
+    (0x7a87, 0x1ea7),
+    (0x7a88, 0x08eb),
+    (0x7a89, 0x1ea2),
+    (0x7a8a, 0x1ea6),
+    (0x7a8b, 0x1ea4),
+    (0x7a8c, 0x1ea5),
+    (0x7a8f, 0x20e7),
+    (0x7a90, 0x20e9),
+    (0x7a92, 0x0aca),
+    (0x7a94, 0x20e8),
+    (0x7a95, 0x0acb),
+    (0x7a96, 0x0c84),
+    (0x7a97, 0x0c83),
+    (0x7a98, 0x0c82),
+    (0x7a99, 0x236e),
+    (0x7a9e, 0x260a),
+    (0x7a9f, 0x0e38),
+    (0x7aa0, 0x0e39),
+    (0x7aa2, 0x2609),
+    (0x7aa3, 0x2608),
+    (0x7aa8, 0x2884),
+    (0x7aa9, 0x0fce),
+    (0x7aaa, 0x0fcd),
+    (0x7aab, 0x2885),
+    (0x7aac, 0x2886),
+    (0x7aae, 0x114d),
+    (0x7aaf, 0x114c),
+    (0x7ab1, 0x2d18),
+    (0x7ab2, 0x2ac2),
+    (0x7ab3, 0x2ac4),
+    (0x7ab4, 0x2ac3),
+    (0x7ab5, 0x2d17),
+    (0x7ab6, 0x2d15),
+    (0x7ab7, 0x2d19),
+    (0x7ab8, 0x2d16),
+    (0x7aba, 0x1299),
+    (0x7abe, 0x2ef1),
+    (0x7abf, 0x13ab),
+    (0x7ac0, 0x2ef2),
+    (0x7ac1, 0x2ef3),
+    (0x7ac4, 0x1486),
+    (0x7ac5, 0x1487),
+    (0x7ac7, 0x15ac),
+    (0x7aca, 0x1683),
+    (0x7acb, 0x02f1),
+    (0x7ad1, 0x1cd4),
+    (0x7ad8, 0x1ea8),
+    (0x7ad9, 0x08ec),
+    (0x7adf, 0x0b62),
+    (0x7ae0, 0x0b61),
+    (0x7ae3, 0x0c86),
+    (0x7ae4, 0x2370),
+    (0x7ae5, 0x0c85),
+    (0x7ae6, 0x236f),
+    (0x7aeb, 0x260b),
+    (0x7aed, 0x0fcf),
+    (0x7aee, 0x2887),
+    (0x7aef, 0x0fd0),
+    (0x7af6, 0x15ad),
+    (0x7af7, 0x332a),
+    (0x7af9, 0x037a),
+    (0x7afa, 0x05ea),
+    (0x7afb, 0x1b41),
+    (0x7afd, 0x074f),
+    (0x7aff, 0x074e),
+    (0x7b00, 0x1cd5),
+    (0x7b01, 0x1cd6),
+    (0x7b04, 0x1eaa),
+    (0x7b05, 0x1eac),
+    (0x7b06, 0x08ed),
+    (0x7b08, 0x1eae),
+    (0x7b09, 0x1eb1),
+    (0x7b0a, 0x1eaf),
+    (0x7b0e, 0x1eb0),
+    (0x7b0f, 0x1ead),
+    (0x7b10, 0x1ea9),
+    (0x7b11, 0x08ee),
+    (0x7b12, 0x1eb2),
+    (0x7b13, 0x1eab),
+    (0x7b18, 0x20f2),
+    (0x7b19, 0x0ad1),
+    (0x7b1a, 0x20fb),
+    (0x7b1b, 0x0ace),
+    (0x7b1d, 0x20f4),
+    (0x7b1e, 0x0ad2),
+    (0x7b20, 0x0acc),
+    (0x7b22, 0x20ef),
+    (0x7b23, 0x20fc),
+    (0x7b24, 0x20f0),
+    (0x7b25, 0x20ed),
+    (0x7b26, 0x0ad0),
+    (0x7b28, 0x0acd),
+    (0x7b2a, 0x20f3),
+    (0x7b2b, 0x20f6),
+    (0x7b2c, 0x0acf),
+    (0x7b2d, 0x20f7),
+    (0x7b2e, 0x0ad3),
+    (0x7b2f, 0x20f8),
+    (0x7b30, 0x20ee),
+    (0x7b31, 0x20f5),
+    (0x7b32, 0x20f9),
+    (0x7b33, 0x20f1),
+    (0x7b34, 0x20ec),
+    (0x7b35, 0x20ea),
+    (0x7b38, 0x20fa),
+    (0x7b3b, 0x2372),
+    (0x7b40, 0x2377),
+    (0x7b44, 0x2373),
+    (0x7b45, 0x2379),
+    (0x7b46, 0x0c89),
+    (0x7b47, 0x20eb),
+    (0x7b48, 0x2374),
+    (0x7b49, 0x0c87),
+    (0x7b4a, 0x2371),
+    (0x7b4b, 0x0c8e),
+    (0x7b4c, 0x2375),
+    (0x7b4d, 0x0c8d),
+    (0x7b4e, 0x2376),
+    (0x7b4f, 0x0c8f),
+    (0x7b50, 0x0c8a),
+    (0x7b51, 0x0c90),
+    (0x7b52, 0x0c8b),
+    (0x7b54, 0x0c8c),
+    (0x7b56, 0x0c88),
+    (0x7b58, 0x2378),
+    (0x7b60, 0x0e3c),
+    (0x7b61, 0x2616),
+    (0x7b63, 0x2619),
+    (0x7b64, 0x260d),
+    (0x7b65, 0x2612),
+    (0x7b66, 0x260c),
+    (0x7b67, 0x0e3e),
+    (0x7b69, 0x2610),
+    (0x7b6d, 0x260e),
+    (0x7b6e, 0x0e3d),
+    (0x7b70, 0x2615),
+    (0x7b71, 0x2614),
+    (0x7b72, 0x2611),
+    (0x7b73, 0x2613),
+    (0x7b74, 0x260f),
+    (0x7b75, 0x0fd4),
+    (0x7b76, 0x2618),
+    (0x7b77, 0x0e3a),
+    (0x7b78, 0x2617),
+    (0x7b82, 0x2897),
+    (0x7b84, 0x0fdb),
+    (0x7b85, 0x2892),
+    (0x7b87, 0x0fda),
+    (0x7b88, 0x2888),
+    (0x7b8a, 0x288a),
+    (0x7b8b, 0x0fd3),
+    (0x7b8c, 0x288f),
+    (0x7b8d, 0x288e),
+    (0x7b8e, 0x2891),
+    (0x7b8f, 0x0fd8),
+    (0x7b90, 0x288c),
+    (0x7b91, 0x288b),
+    (0x7b94, 0x0fd7),
+    (0x7b95, 0x0fd2),
+    (0x7b96, 0x288d),
+    (0x7b97, 0x0fd5),
+    (0x7b98, 0x2893),
+    (0x7b99, 0x2895),
+    (0x7b9b, 0x2890),
+    (0x7b9c, 0x2889),
+    (0x7b9d, 0x0fd6),
+    (0x7ba0, 0x1155),
+    (0x7ba1, 0x0fd1),
+    (0x7ba4, 0x2896),
+    (0x7bac, 0x2ac8),
+    (0x7bad, 0x114e),
+    (0x7baf, 0x2aca),
+    (0x7bb1, 0x114f),
+    (0x7bb4, 0x1151),
+    (0x7bb5, 0x2acd),
+    (0x7bb7, 0x2ac5),
+    (0x7bb8, 0x0fd9),
+    (0x7bb9, 0x2acb),
+    (0x7bbe, 0x2ac7),
+    (0x7bc0, 0x0e3b),
+    (0x7bc1, 0x1154),
+    (0x7bc4, 0x1150),
+    (0x7bc6, 0x1152),
+    (0x7bc7, 0x1153),
+    (0x7bc9, 0x129c),
+    (0x7bca, 0x2acc),
+    (0x7bcb, 0x2ac6),
+    (0x7bcc, 0x1156),
+    (0x7bce, 0x2ac9),
+    (0x7bd4, 0x2d23),
+    (0x7bd5, 0x2d1e),
+    (0x7bd8, 0x2d28),
+    (0x7bd9, 0x129a),
+    (0x7bda, 0x2d20),
+    (0x7bdb, 0x129e),
+    (0x7bdc, 0x2d26),
+    (0x7bdd, 0x2d1d),
+    (0x7bde, 0x2d1a),
+    (0x7bdf, 0x2d29),
+    (0x7be0, 0x13b1),
+    (0x7be1, 0x129f),
+    (0x7be2, 0x2d25),
+    (0x7be3, 0x2d1b),
+    (0x7be4, 0x129d),
+    (0x7be5, 0x2d1f),
+    (0x7be6, 0x12a1),
+    (0x7be7, 0x2d1c),
+    (0x7be8, 0x2d21),
+    (0x7be9, 0x12a0),
+    (0x7bea, 0x2d24),
+    (0x7beb, 0x2d27),
+    (0x7bf0, 0x2f05),
+    (0x7bf1, 0x2f06),
+    (0x7bf2, 0x2ef6),
+    (0x7bf3, 0x2efd),
+    (0x7bf4, 0x2efb),
+    (0x7bf7, 0x13af),
+    (0x7bf8, 0x2f02),
+    (0x7bf9, 0x2d22),
+    (0x7bfb, 0x2ef9),
+    (0x7bfd, 0x2f03),
+    (0x7bfe, 0x13ae),
+    (0x7bff, 0x2ef8),
+    (0x7c00, 0x2ef7),
+    (0x7c01, 0x2f01),
+    (0x7c02, 0x2efe),
+    (0x7c03, 0x2f00),
+    (0x7c05, 0x2ef4),
+    (0x7c06, 0x2f04),
+    (0x7c07, 0x13ac),
+    (0x7c09, 0x2eff),
+    (0x7c0a, 0x2f08),
+    (0x7c0b, 0x2efc),
+    (0x7c0c, 0x13b0),
+    (0x7c0d, 0x13ad),
+    (0x7c0e, 0x2efa),
+    (0x7c0f, 0x2ef5),
+    (0x7c10, 0x2f07),
+    (0x7c11, 0x129b),
+    (0x7c19, 0x3096),
+    (0x7c1c, 0x3094),
+    (0x7c1d, 0x309a),
+    (0x7c1e, 0x148b),
+    (0x7c1f, 0x3098),
+    (0x7c20, 0x3097),
+    (0x7c21, 0x148d),
+    (0x7c22, 0x309d),
+    (0x7c23, 0x148c),
+    (0x7c25, 0x309e),
+    (0x7c26, 0x309b),
+    (0x7c27, 0x1489),
+    (0x7c28, 0x309c),
+    (0x7c29, 0x3095),
+    (0x7c2a, 0x148a),
+    (0x7c2b, 0x1488),
+    (0x7c2c, 0x31f6),
+    (0x7c2d, 0x3099),
+    (0x7c30, 0x309f),
+    (0x7c33, 0x31f3),
+    (0x7c37, 0x152a),
+    (0x7c38, 0x1528),
+    (0x7c39, 0x31f5),
+    (0x7c3b, 0x31f7),
+    (0x7c3c, 0x31f4),
+    (0x7c3d, 0x1529),
+    (0x7c3e, 0x1526),
+    (0x7c3f, 0x1527),
+    (0x7c40, 0x152b),
+    (0x7c43, 0x15af),
+    (0x7c45, 0x332f),
+    (0x7c47, 0x332e),
+    (0x7c48, 0x332c),
+    (0x7c49, 0x332b),
+    (0x7c4a, 0x332d),
+    (0x7c4c, 0x15ae),
+    (0x7c4d, 0x15b0),
+    (0x7c50, 0x1605),
+    (0x7c53, 0x3407),
+    (0x7c54, 0x3406),
+    (0x7c57, 0x34b9),
+    (0x7c59, 0x34bb),
+    (0x7c5a, 0x34bd),
+    (0x7c5b, 0x34bc),
+    (0x7c5c, 0x34ba),
+    (0x7c5f, 0x1655),
+    (0x7c60, 0x1654),
+    (0x7c63, 0x1685),
+    (0x7c64, 0x1684),
+    (0x7c65, 0x1686),
+    (0x7c66, 0x3549),
+    (0x7c67, 0x3548),
+    (0x7c69, 0x35f4),
+    (0x7c6a, 0x35b1),
+    (0x7c6b, 0x35f5),
+    (0x7c6c, 0x16c9),
+    (0x7c6e, 0x16ca),
+    (0x7c6f, 0x3625),
+    (0x7c72, 0x16ef),
+    (0x7c73, 0x037b),
+    (0x7c75, 0x1b42),
+    (0x7c78, 0x1cd8),
+    (0x7c79, 0x1cd9),
+    (0x7c7a, 0x1cd7),
+    (0x7c7d, 0x0750),
+    (0x7c7f, 0x1cda),
+    (0x7c80, 0x1cdb),
+    (0x7c81, 0x1cdc),
+    (0x7c84, 0x1eb3),
+    (0x7c85, 0x1eb9),
+    (0x7c88, 0x1eb7),
+    (0x7c89, 0x08ef),
+    (0x7c8a, 0x1eb5),
+    (0x7c8c, 0x1eb6),
+    (0x7c8d, 0x1eb8),
+    (0x7c91, 0x1eb4),
+    (0x7c92, 0x0ad4),
+    (0x7c94, 0x20fd),
+    (0x7c95, 0x0ad6),
+    (0x7c96, 0x20ff),
+    (0x7c97, 0x0ad5),
+    (0x7c98, 0x20fe),
+    (0x7c9e, 0x237b),
+    (0x7c9f, 0x0c91),
+    (0x7ca1, 0x237d),
+    (0x7ca2, 0x237a),
+    (0x7ca3, 0x2100),
+    (0x7ca5, 0x0c92),
+    (0x7ca8, 0x237c),
+    (0x7caf, 0x261c),
+    (0x7cb1, 0x0e3f),
+    (0x7cb2, 0x261a),
+    (0x7cb3, 0x0e40),
+    (0x7cb4, 0x261b),
+    (0x7cb5, 0x0e41),
+    (0x7cb9, 0x0fdc),
+    (0x7cba, 0x289b),
+    (0x7cbb, 0x2898),
+    (0x7cbc, 0x289a),
+    (0x7cbd, 0x0fdd),
+    (0x7cbe, 0x0fde),
+    (0x7cbf, 0x2899),
+    (0x7cc5, 0x2ace),
+    (0x7cc8, 0x2acf),
+    (0x7cca, 0x1157),
+    (0x7ccb, 0x2ad1),
+    (0x7ccc, 0x2ad0),
+    (0x7cce, 0x00be),
+    (0x7cd0, 0x2d2d),
+    (0x7cd1, 0x2d2e),
+    (0x7cd2, 0x2d2a),
+    (0x7cd4, 0x2d2b),
+    (0x7cd5, 0x12a2),
+    (0x7cd6, 0x12a3),
+    (0x7cd7, 0x2d2c),
+    (0x7cd9, 0x13b7),
+    (0x7cdc, 0x13b3),
+    (0x7cdd, 0x13b8),
+    (0x7cde, 0x13b4),
+    (0x7cdf, 0x13b6),
+    (0x7ce0, 0x13b2),
+    (0x7ce2, 0x13b5),
+    (0x7ce7, 0x148e),
+    (0x7ce8, 0x2f09),
+    (0x7cea, 0x31f9),
+    (0x7cec, 0x31f8),
+    (0x7cee, 0x3330),
+    (0x7cef, 0x15b1),
+    (0x7cf0, 0x15b2),
+    (0x7cf1, 0x34bf),
+    (0x7cf2, 0x3408),
+    (0x7cf4, 0x34be),
+    (0x7cf6, 0x35f6),
+    (0x7cf7, 0x363a),
+    (0x7cf8, 0x037c),
+    (0x7cfb, 0x047f),
+    (0x7cfd, 0x1b43),
+    (0x7cfe, 0x05eb),
+    (0x7d00, 0x0753),
+    (0x7d01, 0x1cdf),
+    (0x7d02, 0x0751),
+    (0x7d03, 0x1cdd),
+    (0x7d04, 0x0756),
+    (0x7d05, 0x0752),
+    (0x7d06, 0x0757),
+    (0x7d07, 0x0755),
+    (0x7d08, 0x1cde),
+    (0x7d09, 0x0754),
+    (0x7d0a, 0x08f3),
+    (0x7d0b, 0x08f2),
+    (0x7d0c, 0x1ec4),
+    (0x7d0d, 0x08fb),
+    (0x7d0e, 0x1ebd),
+    (0x7d0f, 0x1ec3),
+    (0x7d10, 0x08f7),
+    (0x7d11, 0x1ebc),
+    (0x7d12, 0x1ec2),
+    (0x7d13, 0x1ec0),
+    (0x7d14, 0x08f6),
+    (0x7d15, 0x08f8),
+    (0x7d16, 0x1ebf),
+    (0x7d17, 0x08f1),
+    (0x7d18, 0x1ebe),
+    (0x7d19, 0x08fc),
+    (0x7d1a, 0x08f9),
+    (0x7d1b, 0x08fd),
+    (0x7d1c, 0x08fa),
+    (0x7d1d, 0x1ebb),
+    (0x7d1e, 0x1eba),
+    (0x7d1f, 0x1ec1),
+    (0x7d20, 0x08f4),
+    (0x7d21, 0x08f0),
+    (0x7d22, 0x08f5),
+    (0x7d28, 0x210f),
+    (0x7d29, 0x2108),
+    (0x7d2b, 0x0c97),
+    (0x7d2c, 0x2107),
+    (0x7d2e, 0x0ada),
+    (0x7d2f, 0x0ae1),
+    (0x7d30, 0x0ade),
+    (0x7d31, 0x0ae4),
+    (0x7d32, 0x0ae3),
+    (0x7d33, 0x0adf),
+    (0x7d35, 0x2101),
+    (0x7d36, 0x2104),
+    (0x7d38, 0x2103),
+    (0x7d39, 0x0adb),
+    (0x7d3a, 0x2105),
+    (0x7d3b, 0x210e),
+    (0x7d3c, 0x0adc),
+    (0x7d3d, 0x2102),
+    (0x7d3e, 0x210b),
+    (0x7d3f, 0x210c),
+    (0x7d40, 0x0add),
+    (0x7d41, 0x2109),
+    (0x7d42, 0x0ae2),
+    (0x7d43, 0x0ad8),
+    (0x7d44, 0x0ae0),
+    (0x7d45, 0x2106),
+    (0x7d46, 0x0ad7),
+    (0x7d47, 0x210a),
+    (0x7d4a, 0x210d),
+    (0x7d4e, 0x238e),
+    (0x7d4f, 0x2385),
+    (0x7d50, 0x0c94),
+    (0x7d51, 0x238c),
+    (0x7d52, 0x2389),
+    (0x7d53, 0x2381),
+    (0x7d54, 0x238a),
+    (0x7d55, 0x0c96),
+    (0x7d56, 0x2382),
+    (0x7d58, 0x237e),
+    (0x7d5b, 0x0e47),
+    (0x7d5c, 0x2387),
+    (0x7d5e, 0x0c93),
+    (0x7d5f, 0x238d),
+    (0x7d61, 0x0c9a),
+    (0x7d62, 0x0c9c),
+    (0x7d63, 0x2380),
+    (0x7d66, 0x0c9b),
+    (0x7d67, 0x2383),
+    (0x7d68, 0x0c95),
+    (0x7d69, 0x238b),
+    (0x7d6a, 0x2384),
+    (0x7d6b, 0x2388),
+    (0x7d6d, 0x2386),
+    (0x7d6e, 0x0c98),
+    (0x7d6f, 0x237f),
+    (0x7d70, 0x0c9d),
+    (0x7d71, 0x0ad9),
+    (0x7d72, 0x0c99),
+    (0x7d73, 0x0c9e),
+    (0x7d79, 0x0e43),
+    (0x7d7a, 0x2623),
+    (0x7d7b, 0x2625),
+    (0x7d7c, 0x2627),
+    (0x7d7d, 0x262b),
+    (0x7d7f, 0x2621),
+    (0x7d80, 0x261f),
+    (0x7d81, 0x0e45),
+    (0x7d83, 0x2626),
+    (0x7d84, 0x262a),
+    (0x7d85, 0x2622),
+    (0x7d86, 0x261e),
+    (0x7d88, 0x261d),
+    (0x7d8c, 0x2628),
+    (0x7d8d, 0x2620),
+    (0x7d8e, 0x2624),
+    (0x7d8f, 0x0e46),
+    (0x7d91, 0x0e44),
+    (0x7d92, 0x262c),
+    (0x7d93, 0x0e42),
+    (0x7d94, 0x2629),
+    (0x7d96, 0x28ac),
+    (0x7d9c, 0x0fe1),
+    (0x7d9d, 0x28a4),
+    (0x7d9e, 0x1164),
+    (0x7d9f, 0x28ae),
+    (0x7da0, 0x0fe4),
+    (0x7da1, 0x28b2),
+    (0x7da2, 0x0fea),
+    (0x7da3, 0x289f),
+    (0x7da6, 0x28af),
+    (0x7da7, 0x289c),
+    (0x7da9, 0x28b1),
+    (0x7daa, 0x28a0),
+    (0x7dac, 0x0ff1),
+    (0x7dad, 0x0fee),
+    (0x7dae, 0x28b0),
+    (0x7daf, 0x28aa),
+    (0x7db0, 0x0fe0),
+    (0x7db1, 0x0fe8),
+    (0x7db2, 0x0fe7),
+    (0x7db4, 0x0fe6),
+    (0x7db5, 0x0fec),
+    (0x7db7, 0x289d),
+    (0x7db8, 0x0fed),
+    (0x7db9, 0x28ab),
+    (0x7dba, 0x0fe9),
+    (0x7dbb, 0x0fdf),
+    (0x7dbc, 0x28ad),
+    (0x7dbd, 0x0fe2),
+    (0x7dbe, 0x0fe3),
+    (0x7dbf, 0x0feb),
+    (0x7dc0, 0x28a2),
+    (0x7dc1, 0x28a1),
+    (0x7dc2, 0x289e),
+    (0x7dc4, 0x28a6),
+    (0x7dc5, 0x28a3),
+    (0x7dc6, 0x28a7),
+    (0x7dc7, 0x0ff0),
+    (0x7dc9, 0x28b3),
+    (0x7dca, 0x0fe5),
+    (0x7dcb, 0x28a8),
+    (0x7dcc, 0x28a9),
+    (0x7dce, 0x28a5),
+    (0x7dd2, 0x0fef),
+    (0x7dd7, 0x2ad6),
+    (0x7dd8, 0x115c),
+    (0x7dd9, 0x1165),
+    (0x7dda, 0x1161),
+    (0x7ddb, 0x2ad3),
+    (0x7ddd, 0x115e),
+    (0x7dde, 0x1162),
+    (0x7ddf, 0x2adf),
+    (0x7de0, 0x1158),
+    (0x7de1, 0x2ad7),
+    (0x7de3, 0x1160),
+    (0x7de6, 0x2ada),
+    (0x7de7, 0x2ad5),
+    (0x7de8, 0x115f),
+    (0x7de9, 0x1163),
+    (0x7dea, 0x2ad4),
+    (0x7dec, 0x115d),
+    (0x7dee, 0x2ade),
+    (0x7def, 0x115a),
+    (0x7df0, 0x2add),
+    (0x7df1, 0x2adc),
+    (0x7df2, 0x1166),
+    (0x7df3, 0x29e6),
+    (0x7df4, 0x1159),
+    (0x7df6, 0x2adb),
+    (0x7df7, 0x2ad2),
+    (0x7df9, 0x1167),
+    (0x7dfa, 0x2ad9),
+    (0x7dfb, 0x115b),
+    (0x7e03, 0x2ad8),
+    (0x7e08, 0x12a6),
+    (0x7e09, 0x12ab),
+    (0x7e0a, 0x12a4),
+    (0x7e0b, 0x2d3b),
+    (0x7e0c, 0x2d32),
+    (0x7e0d, 0x2d3e),
+    (0x7e0e, 0x2d36),
+    (0x7e0f, 0x2d3c),
+    (0x7e10, 0x12ac),
+    (0x7e11, 0x12a5),
+    (0x7e12, 0x2d2f),
+    (0x7e13, 0x2d35),
+    (0x7e14, 0x2d3f),
+    (0x7e15, 0x2d38),
+    (0x7e16, 0x2d3d),
+    (0x7e17, 0x2d31),
+    (0x7e1a, 0x2d39),
+    (0x7e1b, 0x12a7),
+    (0x7e1c, 0x2d37),
+    (0x7e1d, 0x12aa),
+    (0x7e1e, 0x12a9),
+    (0x7e1f, 0x2d33),
+    (0x7e20, 0x2d34),
+    (0x7e21, 0x2d30),
+    (0x7e22, 0x2d3a),
+    (0x7e23, 0x12a8),
+    (0x7e24, 0x2d41),
+    (0x7e25, 0x2d40),
+    (0x7e29, 0x2f14),
+    (0x7e2a, 0x2f10),
+    (0x7e2b, 0x13bf),
+    (0x7e2d, 0x2f0a),
+    (0x7e2e, 0x13b9),
+    (0x7e2f, 0x13c9),
+    (0x7e30, 0x2f16),
+    (0x7e31, 0x13c1),
+    (0x7e32, 0x13bd),
+    (0x7e33, 0x2f0d),
+    (0x7e34, 0x13c4),
+    (0x7e35, 0x13c7),
+    (0x7e36, 0x2f18),
+    (0x7e37, 0x13bc),
+    (0x7e38, 0x2f0f),
+    (0x7e39, 0x13c5),
+    (0x7e3a, 0x2f1a),
+    (0x7e3b, 0x2f17),
+    (0x7e3c, 0x2f0b),
+    (0x7e3d, 0x13c0),
+    (0x7e3e, 0x13ba),
+    (0x7e3f, 0x13c8),
+    (0x7e40, 0x2f12),
+    (0x7e41, 0x13c3),
+    (0x7e42, 0x2f0c),
+    (0x7e43, 0x13be),
+    (0x7e44, 0x2f19),
+    (0x7e45, 0x13c2),
+    (0x7e46, 0x13bb),
+    (0x7e47, 0x2f13),
+    (0x7e48, 0x13c6),
+    (0x7e49, 0x2f11),
+    (0x7e4c, 0x2f15),
+    (0x7e50, 0x30a1),
+    (0x7e51, 0x30a7),
+    (0x7e52, 0x1494),
+    (0x7e53, 0x30aa),
+    (0x7e54, 0x148f),
+    (0x7e55, 0x1490),
+    (0x7e56, 0x30a2),
+    (0x7e57, 0x30a9),
+    (0x7e58, 0x30a4),
+    (0x7e59, 0x1495),
+    (0x7e5a, 0x1492),
+    (0x7e5c, 0x30a0),
+    (0x7e5e, 0x1491),
+    (0x7e5f, 0x30a6),
+    (0x7e60, 0x30a8),
+    (0x7e61, 0x1493),
+    (0x7e62, 0x30a5),
+    (0x7e63, 0x30a3),
+    (0x7e68, 0x3203),
+    (0x7e69, 0x152f),
+    (0x7e6a, 0x1530),
+    (0x7e6b, 0x152c),
+    (0x7e6d, 0x152d),
+    (0x7e6f, 0x31ff),
+    (0x7e70, 0x31fd),
+    (0x7e72, 0x3201),
+    (0x7e73, 0x1532),
+    (0x7e74, 0x3202),
+    (0x7e75, 0x31fb),
+    (0x7e76, 0x31fa),
+    (0x7e77, 0x31fe),
+    (0x7e78, 0x31fc),
+    (0x7e79, 0x152e),
+    (0x7e7a, 0x3200),
+    (0x7e7b, 0x3331),
+    (0x7e7c, 0x15b5),
+    (0x7e7d, 0x15b4),
+    (0x7e7e, 0x3332),
+    (0x7e80, 0x3334),
+    (0x7e81, 0x3333),
+    (0x7e82, 0x15b6),
+    (0x7e86, 0x340d),
+    (0x7e87, 0x340a),
+    (0x7e88, 0x340b),
+    (0x7e8a, 0x3409),
+    (0x7e8b, 0x340c),
+    (0x7e8c, 0x1607),
+    (0x7e8d, 0x340e),
+    (0x7e8f, 0x1606),
+    (0x7e91, 0x34c0),
+    (0x7e93, 0x1687),
+    (0x7e94, 0x1689),
+    (0x7e95, 0x354a),
+    (0x7e96, 0x1688),
+    (0x7e97, 0x35b2),
+    (0x7e98, 0x35f8),
+    (0x7e99, 0x35fa),
+    (0x7e9a, 0x35f7),
+    (0x7e9b, 0x35f9),
+    (0x7e9c, 0x16dd),
+    (0x7f36, 0x037d),
+    (0x7f38, 0x0758),
+    (0x7f39, 0x1e3c),
+    (0x7f3a, 0x08fe),
+    (0x7f3d, 0x0ae5),
+    (0x7f3e, 0x238f),
+    (0x7f3f, 0x2390),
+    (0x7f43, 0x2d42),
+    (0x7f44, 0x13ca),
+    (0x7f45, 0x2f1b),
+    (0x7f48, 0x1496),
+    (0x7f4a, 0x3205),
+    (0x7f4b, 0x3204),
+    (0x7f4c, 0x15b7),
+    (0x7f4d, 0x340f),
+    (0x7f4f, 0x34c1),
+    (0x7f50, 0x16aa),
+    (0x7f51, 0x192e),
+    (0x7f54, 0x05ec),
+    (0x7f55, 0x0480),
+    (0x7f58, 0x1ce0),
+    (0x7f5b, 0x1eca),
+    (0x7f5c, 0x1ec5),
+    (0x7f5d, 0x1ec9),
+    (0x7f5e, 0x1ec7),
+    (0x7f5f, 0x08ff),
+    (0x7f60, 0x1ec8),
+    (0x7f61, 0x1ec6),
+    (0x7f63, 0x2110),
+    (0x7f65, 0x2391),
+    (0x7f66, 0x2392),
+    (0x7f67, 0x262f),
+    (0x7f68, 0x2630),
+    (0x7f69, 0x0e49),
+    (0x7f6a, 0x0e4a),
+    (0x7f6b, 0x262e),
+    (0x7f6c, 0x2631),
+    (0x7f6d, 0x262d),
+    (0x7f6e, 0x0e48),
+    (0x7f70, 0x0ff2),
+    (0x7f72, 0x0e4b),
+    (0x7f73, 0x28b4),
+    (0x7f75, 0x1168),
+    (0x7f76, 0x2ae0),
+    (0x7f77, 0x1169),
+    (0x7f79, 0x12ad),
+    (0x7f7a, 0x2d45),
+    (0x7f7b, 0x2d43),
+    (0x7f7c, 0x2d44),
+    (0x7f7d, 0x2f1e),
+    (0x7f7e, 0x2f1d),
+    (0x7f7f, 0x2f1c),
+    (0x7f83, 0x3206),
+    (0x7f85, 0x1531),
+    (0x7f86, 0x3207),
+    (0x7f87, 0x34c2),
+    (0x7f88, 0x16ab),
+    (0x7f89, 0x35b3),
+    (0x7f8a, 0x037e),
+    (0x7f8b, 0x05ee),
+    (0x7f8c, 0x05ed),
+    (0x7f8d, 0x1ce2),
+    (0x7f8e, 0x0759),
+    (0x7f91, 0x1ce1),
+    (0x7f92, 0x1ecc),
+    (0x7f94, 0x0900),
+    (0x7f95, 0x2111),
+    (0x7f96, 0x1ecb),
+    (0x7f9a, 0x0ae7),
+    (0x7f9b, 0x2114),
+    (0x7f9c, 0x2112),
+    (0x7f9d, 0x2113),
+    (0x7f9e, 0x0ae6),
+    (0x7fa0, 0x2394),
+    (0x7fa1, 0x2395),
+    (0x7fa2, 0x2393),
+    (0x7fa4, 0x0e4e),
+    (0x7fa5, 0x2633),
+    (0x7fa6, 0x2632),
+    (0x7fa7, 0x2634),
+    (0x7fa8, 0x0e4d),
+    (0x7fa9, 0x0e4c),
+    (0x7fac, 0x2ae1),
+    (0x7fad, 0x2ae3),
+    (0x7faf, 0x116a),
+    (0x7fb0, 0x2ae2),
+    (0x7fb1, 0x2d46),
+    (0x7fb2, 0x12ae),
+    (0x7fb3, 0x30ac),
+    (0x7fb5, 0x30ab),
+    (0x7fb6, 0x1533),
+    (0x7fb7, 0x3208),
+    (0x7fb8, 0x1535),
+    (0x7fb9, 0x1534),
+    (0x7fba, 0x3335),
+    (0x7fbb, 0x3410),
+    (0x7fbc, 0x1608),
+    (0x7fbd, 0x037f),
+    (0x7fbe, 0x1ce3),
+    (0x7fbf, 0x075a),
+    (0x7fc0, 0x1ecf),
+    (0x7fc1, 0x0902),
+    (0x7fc2, 0x1ece),
+    (0x7fc3, 0x1ecd),
+    (0x7fc5, 0x0901),
+    (0x7fc7, 0x211a),
+    (0x7fc9, 0x211c),
+    (0x7fca, 0x2115),
+    (0x7fcb, 0x2116),
+    (0x7fcc, 0x0ae8),
+    (0x7fcd, 0x2117),
+    (0x7fce, 0x0ae9),
+    (0x7fcf, 0x211b),
+    (0x7fd0, 0x2118),
+    (0x7fd1, 0x2119),
+    (0x7fd2, 0x0aea),
+    (0x7fd4, 0x0ca0),
+    (0x7fd5, 0x0ca1),
+    (0x7fd7, 0x2396),
+    (0x7fdb, 0x2635),
+    (0x7fdc, 0x2636),
+    (0x7fde, 0x28b8),
+    (0x7fdf, 0x0ff5),
+    (0x7fe0, 0x0ff3),
+    (0x7fe1, 0x0ff4),
+    (0x7fe2, 0x28b5),
+    (0x7fe3, 0x28b6),
+    (0x7fe5, 0x28b7),
+    (0x7fe6, 0x2ae8),
+    (0x7fe8, 0x2ae9),
+    (0x7fe9, 0x116b),
+    (0x7fea, 0x2ae6),
+    (0x7feb, 0x2ae5),
+    (0x7fec, 0x2ae7),
+    (0x7fed, 0x2ae4),
+    (0x7fee, 0x12b1),
+    (0x7fef, 0x2d47),
+    (0x7ff0, 0x12af),
+    (0x7ff1, 0x12b0),
+    (0x7ff2, 0x2f20),
+    (0x7ff3, 0x13cb),
+    (0x7ff4, 0x2f1f),
+    (0x7ff5, 0x301c),
+    (0x7ff7, 0x30ad),
+    (0x7ff8, 0x30ae),
+    (0x7ff9, 0x1497),
+    (0x7ffb, 0x1498),
+    (0x7ffc, 0x13cc),
+    (0x7ffd, 0x3209),
+    (0x7ffe, 0x320a),
+    (0x7fff, 0x3336),
+    (0x8000, 0x15b8),
+    (0x8001, 0x0380),
+    (0x8003, 0x0381),
+    (0x8004, 0x075b),
+    (0x8005, 0x05ef),
+    (0x8006, 0x0903),
+    (0x8007, 0x1ce4),
+    (0x800b, 0x0ca2),
+    (0x800c, 0x0382),
+    (0x800d, 0x075d),
+    (0x800e, 0x1ce5),
+    (0x800f, 0x1ce6),
+    (0x8010, 0x075c),
+    (0x8011, 0x075e),
+    (0x8012, 0x0383),
+    (0x8014, 0x1ce7),
+    (0x8015, 0x0905),
+    (0x8016, 0x1ed0),
+    (0x8017, 0x0907),
+    (0x8018, 0x0904),
+    (0x8019, 0x0906),
+    (0x801b, 0x211f),
+    (0x801c, 0x0aeb),
+    (0x801e, 0x211e),
+    (0x801f, 0x211d),
+    (0x8021, 0x2637),
+    (0x8024, 0x28b9),
+    (0x8026, 0x116c),
+    (0x8028, 0x12b2),
+    (0x8029, 0x2d49),
+    (0x802a, 0x2d48),
+    (0x802c, 0x2f21),
+    (0x8030, 0x3411),
+    (0x8033, 0x0384),
+    (0x8034, 0x19f5),
+    (0x8035, 0x1b44),
+    (0x8036, 0x075f),
+    (0x8037, 0x1ce8),
+    (0x8039, 0x1ed2),
+    (0x803d, 0x0908),
+    (0x803e, 0x1ed1),
+    (0x803f, 0x0909),
+    (0x8043, 0x2121),
+    (0x8046, 0x0aed),
+    (0x8047, 0x2120),
+    (0x8048, 0x2122),
+    (0x804a, 0x0aec),
+    (0x804f, 0x2398),
+    (0x8050, 0x2399),
+    (0x8051, 0x2397),
+    (0x8052, 0x0ca3),
+    (0x8056, 0x0e4f),
+    (0x8058, 0x0e50),
+    (0x805a, 0x0ff7),
+    (0x805c, 0x28bb),
+    (0x805d, 0x28ba),
+    (0x805e, 0x0ff6),
+    (0x8064, 0x2aea),
+    (0x8067, 0x2aeb),
+    (0x806c, 0x2d4a),
+    (0x806f, 0x13d0),
+    (0x8070, 0x13cf),
+    (0x8071, 0x13cd),
+    (0x8072, 0x13ce),
+    (0x8073, 0x13d1),
+    (0x8075, 0x30af),
+    (0x8076, 0x149a),
+    (0x8077, 0x1499),
+    (0x8078, 0x320b),
+    (0x8079, 0x3337),
+    (0x807d, 0x1657),
+    (0x807e, 0x1656),
+    (0x807f, 0x0385),
+    (0x8082, 0x1e09),
+    (0x8084, 0x0e52),
+    (0x8085, 0x0ca4),
+    (0x8086, 0x0e51),
+    (0x8087, 0x0ff8),
+    (0x8089, 0x0386),
+    (0x808a, 0x18cf),
+    (0x808b, 0x0387),
+    (0x808c, 0x0388),
+    (0x808f, 0x1b45),
+    (0x8090, 0x19f8),
+    (0x8092, 0x19f9),
+    (0x8093, 0x0482),
+    (0x8095, 0x19f6),
+    (0x8096, 0x0481),
+    (0x8098, 0x0484),
+    (0x8099, 0x19f7),
+    (0x809a, 0x0486),
+    (0x809b, 0x0485),
+    (0x809c, 0x19fa),
+    (0x809d, 0x0483),
+    (0x80a1, 0x05f4),
+    (0x80a2, 0x05f2),
+    (0x80a3, 0x1b47),
+    (0x80a5, 0x05f1),
+    (0x80a9, 0x05f6),
+    (0x80aa, 0x05f8),
+    (0x80ab, 0x05f5),
+    (0x80ad, 0x1b4a),
+    (0x80ae, 0x1b46),
+    (0x80af, 0x05f9),
+    (0x80b1, 0x05f3),
+    (0x80b2, 0x0487),
+    (0x80b4, 0x05f7),
+    (0x80b5, 0x1b49),
+    (0x80b8, 0x1b48),
+    (0x80ba, 0x05f0),
+    (0x80c2, 0x1cee),
+    (0x80c3, 0x0763),
+    (0x80c4, 0x0764),
+    (0x80c5, 0x1cf0),
+    (0x80c7, 0x1cea),
+    (0x80c8, 0x1ced),
+    (0x80c9, 0x1cf6),
+    (0x80ca, 0x1cf4),
+    (0x80cc, 0x0765),
+    (0x80cd, 0x1cfa),
+    (0x80ce, 0x0768),
+    (0x80cf, 0x1cf7),
+    (0x80d0, 0x1cef),
+    (0x80d1, 0x1cec),
+    (0x80d4, 0x239b),
+    (0x80d5, 0x1cf5),
+    (0x80d6, 0x0760),
+    (0x80d7, 0x1cf8),
+    (0x80d8, 0x1ce9),
+    (0x80d9, 0x1cf2),
+    (0x80da, 0x0762),
+    (0x80db, 0x0767),
+    (0x80dc, 0x1cf3),
+    (0x80dd, 0x076b),
+    (0x80de, 0x0769),
+    (0x80e0, 0x1ceb),
+    (0x80e1, 0x0766),
+    (0x80e3, 0x1cf1),
+    (0x80e4, 0x076a),
+    (0x80e5, 0x0761),
+    (0x80e6, 0x1cf9),
+    (0x80ed, 0x090e),
+    (0x80ef, 0x0917),
+    (0x80f0, 0x090c),
+    (0x80f1, 0x090a),
+    (0x80f2, 0x1ed4),
+    (0x80f3, 0x0912),
+    (0x80f4, 0x090f),
+    (0x80f5, 0x1ed6),
+    (0x80f8, 0x0911),
+    (0x80f9, 0x1ed5),
+    (0x80fa, 0x1ed3),
+    (0x80fb, 0x1ed8),
+    (0x80fc, 0x0916),
+    (0x80fd, 0x0914),
+    (0x80fe, 0x239a),
+    (0x8100, 0x1ed9),
+    (0x8101, 0x1ed7),
+    (0x8102, 0x090b),
+    (0x8105, 0x090d),
+    (0x8106, 0x0910),
+    (0x8108, 0x0913),
+    (0x810a, 0x0915),
+    (0x8115, 0x212c),
+    (0x8116, 0x0aef),
+    (0x8118, 0x2123),
+    (0x8119, 0x2125),
+    (0x811b, 0x2126),
+    (0x811d, 0x212e),
+    (0x811e, 0x212a),
+    (0x811f, 0x2128),
+    (0x8121, 0x212b),
+    (0x8122, 0x212f),
+    (0x8123, 0x0af0),
+    (0x8124, 0x0af4),
+    (0x8125, 0x2124),
+    (0x8127, 0x212d),
+    (0x8129, 0x0af2),
+    (0x812b, 0x0af1),
+    (0x812c, 0x2129),
+    (0x812d, 0x2127),
+    (0x812f, 0x0aee),
+    (0x8130, 0x0af3),
+    (0x8139, 0x0caa),
+    (0x813a, 0x23a3),
+    (0x813d, 0x23a1),
+    (0x813e, 0x0cac),
+    (0x8143, 0x239c),
+    (0x8144, 0x2645),
+    (0x8146, 0x0cab),
+    (0x8147, 0x23a0),
+    (0x814a, 0x239d),
+    (0x814b, 0x0ca7),
+    (0x814c, 0x0cad),
+    (0x814d, 0x23a2),
+    (0x814e, 0x0ca9),
+    (0x814f, 0x239f),
+    (0x8150, 0x0ff9),
+    (0x8151, 0x0ca8),
+    (0x8152, 0x239e),
+    (0x8153, 0x0cae),
+    (0x8154, 0x0ca6),
+    (0x8155, 0x0ca5),
+    (0x815b, 0x263d),
+    (0x815c, 0x263b),
+    (0x815e, 0x2641),
+    (0x8160, 0x2639),
+    (0x8161, 0x2646),
+    (0x8162, 0x263e),
+    (0x8164, 0x2638),
+    (0x8165, 0x0e56),
+    (0x8166, 0x0e5c),
+    (0x8167, 0x2643),
+    (0x8169, 0x263c),
+    (0x816b, 0x0e59),
+    (0x816e, 0x0e57),
+    (0x816f, 0x2644),
+    (0x8170, 0x0e54),
+    (0x8171, 0x0e53),
+    (0x8172, 0x263f),
+    (0x8173, 0x0e58),
+    (0x8174, 0x0caf),
+    (0x8176, 0x2642),
+    (0x8177, 0x263a),
+    (0x8178, 0x0e55),
+    (0x8179, 0x0e5a),
+    (0x817a, 0x0e5b),
+    (0x817f, 0x0ffe),
+    (0x8180, 0x0ffa),
+    (0x8182, 0x0fff),
+    (0x8183, 0x28be),
+    (0x8186, 0x28bd),
+    (0x8187, 0x28bf),
+    (0x8188, 0x0ffc),
+    (0x8189, 0x28bc),
+    (0x818a, 0x0ffd),
+    (0x818b, 0x28c2),
+    (0x818c, 0x28c1),
+    (0x818d, 0x28c0),
+    (0x818f, 0x0ffb),
+    (0x8195, 0x2aef),
+    (0x8197, 0x2af2),
+    (0x8198, 0x1172),
+    (0x8199, 0x2af1),
+    (0x819a, 0x1171),
+    (0x819b, 0x116d),
+    (0x819c, 0x116e),
+    (0x819d, 0x116f),
+    (0x819e, 0x2aee),
+    (0x819f, 0x2aed),
+    (0x81a0, 0x1170),
+    (0x81a2, 0x2af0),
+    (0x81a3, 0x2aec),
+    (0x81a6, 0x2d4c),
+    (0x81a7, 0x2d56),
+    (0x81a8, 0x12b5),
+    (0x81a9, 0x12b4),
+    (0x81ab, 0x2d50),
+    (0x81ac, 0x2d52),
+    (0x81ae, 0x2d4d),
+    (0x81b0, 0x2d51),
+    (0x81b1, 0x2d4b),
+    (0x81b2, 0x2d54),
+    (0x81b3, 0x12b3),
+    (0x81b4, 0x2d53),
+    (0x81b5, 0x2d4f),
+    (0x81b7, 0x2d55),
+    (0x81b9, 0x2d4e),
+    (0x81ba, 0x13d4),
+    (0x81bb, 0x2f22),
+    (0x81bc, 0x2f28),
+    (0x81bd, 0x13d8),
+    (0x81be, 0x13da),
+    (0x81bf, 0x13d7),
+    (0x81c0, 0x13d6),
+    (0x81c2, 0x13d5),
+    (0x81c3, 0x13d3),
+    (0x81c4, 0x2f23),
+    (0x81c5, 0x2f26),
+    (0x81c6, 0x13d2),
+    (0x81c7, 0x2f27),
+    (0x81c9, 0x13d9),
+    (0x81ca, 0x2f25),
+    (0x81cc, 0x2f24),
+    (0x81cd, 0x149b),
+    (0x81cf, 0x149c),
+    (0x81d0, 0x30b2),
+    (0x81d1, 0x30b0),
+    (0x81d2, 0x30b1),
+    (0x81d5, 0x320d),
+    (0x81d7, 0x320c),
+    (0x81d8, 0x1536),
+    (0x81d9, 0x3339),
+    (0x81da, 0x15b9),
+    (0x81db, 0x3338),
+    (0x81dd, 0x3412),
+    (0x81de, 0x34c3),
+    (0x81df, 0x1658),
+    (0x81e0, 0x35fb),
+    (0x81e1, 0x35fc),
+    (0x81e2, 0x168a),
+    (0x81e3, 0x0389),
+    (0x81e5, 0x05fa),
+    (0x81e6, 0x23a4),
+    (0x81e7, 0x1000),
+    (0x81e8, 0x13db),
+    (0x81e9, 0x2f29),
+    (0x81ea, 0x038a),
+    (0x81ec, 0x0919),
+    (0x81ed, 0x0918),
+    (0x81ee, 0x23a5),
+    (0x81f2, 0x2d57),
+    (0x81f3, 0x038b),
+    (0x81f4, 0x076c),
+    (0x81f7, 0x23a6),
+    (0x81f8, 0x23a7),
+    (0x81f9, 0x23a8),
+    (0x81fa, 0x1001),
+    (0x81fb, 0x12b6),
+    (0x81fc, 0x038c),
+    (0x81fe, 0x05fb),
+    (0x81ff, 0x1cfb),
+    (0x8200, 0x091a),
+    (0x8201, 0x1eda),
+    (0x8202, 0x0af5),
+    (0x8204, 0x23a9),
+    (0x8205, 0x0e5d),
+    (0x8207, 0x1002),
+    (0x8208, 0x12b7),
+    (0x8209, 0x13dc),
+    (0x820a, 0x149d),
+    (0x820b, 0x333a),
+    (0x820c, 0x038d),
+    (0x820d, 0x05fc),
+    (0x8210, 0x091b),
+    (0x8211, 0x2130),
+    (0x8212, 0x0cb0),
+    (0x8214, 0x1003),
+    (0x8215, 0x28c3),
+    (0x8216, 0x2af3),
+    (0x821b, 0x038e),
+    (0x821c, 0x0cb1),
+    (0x821d, 0x2647),
+    (0x821e, 0x1004),
+    (0x821f, 0x038f),
+    (0x8220, 0x1b4b),
+    (0x8221, 0x1cfc),
+    (0x8222, 0x076d),
+    (0x8225, 0x1edc),
+    (0x8228, 0x091e),
+    (0x822a, 0x091c),
+    (0x822b, 0x091d),
+    (0x822c, 0x091f),
+    (0x822f, 0x1edb),
+    (0x8232, 0x2135),
+    (0x8233, 0x2132),
+    (0x8234, 0x2134),
+    (0x8235, 0x0af6),
+    (0x8236, 0x0af8),
+    (0x8237, 0x0af7),
+    (0x8238, 0x2131),
+    (0x8239, 0x0af9),
+    (0x823a, 0x2133),
+    (0x823c, 0x23aa),
+    (0x823d, 0x23ab),
+    (0x823f, 0x23ac),
+    (0x8240, 0x264a),
+    (0x8242, 0x264b),
+    (0x8244, 0x2649),
+    (0x8245, 0x264c),
+    (0x8247, 0x0e5e),
+    (0x8249, 0x2648),
+    (0x824b, 0x1005),
+    (0x824e, 0x2af8),
+    (0x824f, 0x2af4),
+    (0x8250, 0x2af7),
+    (0x8251, 0x2af9),
+    (0x8252, 0x2af6),
+    (0x8253, 0x2af5),
+    (0x8255, 0x2d58),
+    (0x8256, 0x2d59),
+    (0x8257, 0x2d5a),
+    (0x8258, 0x12b8),
+    (0x8259, 0x12b9),
+    (0x825a, 0x2f2b),
+    (0x825b, 0x2f2a),
+    (0x825c, 0x2f2c),
+    (0x825e, 0x30b4),
+    (0x825f, 0x30b3),
+    (0x8261, 0x320f),
+    (0x8263, 0x3210),
+    (0x8264, 0x320e),
+    (0x8266, 0x15ba),
+    (0x8268, 0x333b),
+    (0x8269, 0x333c),
+    (0x826b, 0x34c4),
+    (0x826c, 0x354b),
+    (0x826d, 0x35b4),
+    (0x826e, 0x0390),
+    (0x826f, 0x0488),
+    (0x8271, 0x13dd),
+    (0x8272, 0x0391),
+    (0x8274, 0x2136),
+    (0x8275, 0x23ad),
+    (0x8277, 0x16b2),
+    (0x8278, 0x192f),
+    (0x827c, 0x1930),
+    (0x827d, 0x1932),
+    (0x827e, 0x0392),
+    (0x827f, 0x1933),
+    (0x8280, 0x1931),
+    (0x8283, 0x1a02),
+    (0x8284, 0x1a03),
+    (0x8285, 0x19fd),
+    (0x828a, 0x1a01),
+    (0x828b, 0x048a),
+    (0x828d, 0x048b),
+    (0x828e, 0x19fe),
+    (0x828f, 0x19fc),
+    (0x8290, 0x19fb),
+    (0x8291, 0x19ff),
+    (0x8292, 0x0489),
+    (0x8293, 0x1a00),
+    (0x8294, 0x1cfd),
+    (0x8298, 0x1b50),
+    (0x8299, 0x05ff),
+    (0x829a, 0x1b4f),
+    (0x829b, 0x1b51),
+    (0x829d, 0x05fe),
+    (0x829e, 0x1b56),
+    (0x829f, 0x0602),
+    (0x82a0, 0x1b4c),
+    (0x82a1, 0x1b5a),
+    (0x82a2, 0x1b60),
+    (0x82a3, 0x0609),
+    (0x82a4, 0x1b5d),
+    (0x82a5, 0x0606),
+    (0x82a7, 0x1b53),
+    (0x82a8, 0x1b59),
+    (0x82a9, 0x1b5b),
+    (0x82ab, 0x1b4e),
+    (0x82ac, 0x0605),
+    (0x82ad, 0x0600),
+    (0x82ae, 0x1b54),
+    (0x82af, 0x0607),
+    (0x82b0, 0x060a),
+    (0x82b1, 0x0604),
+    (0x82b3, 0x05fd),
+    (0x82b4, 0x1b58),
+    (0x82b5, 0x1b52),
+    (0x82b6, 0x1b5f),
+    (0x82b7, 0x060c),
+    (0x82b8, 0x0608),
+    (0x82b9, 0x0603),
+    (0x82ba, 0x1b57),
+    (0x82bb, 0x0920),
+    (0x82bc, 0x1b55),
+    (0x82bd, 0x0601),
+    (0x82be, 0x060b),
+    (0x82c0, 0x1b4d),
+    (0x82c2, 0x1b5c),
+    (0x82c3, 0x1b5e),
+    (0x82d1, 0x077e),
+    (0x82d2, 0x0778),
+    (0x82d3, 0x0780),
+    (0x82d4, 0x077d),
+    (0x82d5, 0x1d04),
+    (0x82d6, 0x1d07),
+    (0x82d7, 0x0779),
+    (0x82d9, 0x1cfe),
+    (0x82db, 0x0772),
+    (0x82dc, 0x077c),
+    (0x82de, 0x077f),
+    (0x82df, 0x0781),
+    (0x82e0, 0x1d13),
+    (0x82e1, 0x1d0a),
+    (0x82e3, 0x0771),
+    (0x82e4, 0x1d12),
+    (0x82e5, 0x0775),
+    (0x82e6, 0x0773),
+    (0x82e7, 0x076e),
+    (0x82e8, 0x1d02),
+    (0x82ea, 0x1d11),
+    (0x82eb, 0x1d06),
+    (0x82ec, 0x1d09),
+    (0x82ed, 0x1d16),
+    (0x82ef, 0x0782),
+    (0x82f0, 0x1d10),
+    (0x82f1, 0x077a),
+    (0x82f2, 0x1d0b),
+    (0x82f3, 0x1d15),
+    (0x82f4, 0x1d08),
+    (0x82f5, 0x1d0c),
+    (0x82f6, 0x1d0f),
+    (0x82f9, 0x1d00),
+    (0x82fa, 0x1d14),
+    (0x82fb, 0x1d0e),
+    (0x82fe, 0x1cff),
+    (0x8300, 0x1d03),
+    (0x8301, 0x077b),
+    (0x8302, 0x0776),
+    (0x8303, 0x076f),
+    (0x8304, 0x0774),
+    (0x8305, 0x0770),
+    (0x8306, 0x0783),
+    (0x8307, 0x1d01),
+    (0x8308, 0x1eed),
+    (0x8309, 0x0777),
+    (0x830c, 0x1d0d),
+    (0x830d, 0x1b9a),
+    (0x8316, 0x1ef0),
+    (0x8317, 0x092e),
+    (0x8319, 0x1ee0),
+    (0x831b, 0x1eeb),
+    (0x831c, 0x1ee7),
+    (0x831e, 0x1efa),
+    (0x8320, 0x1ef2),
+    (0x8322, 0x1ee8),
+    (0x8324, 0x1ef1),
+    (0x8325, 0x1ee2),
+    (0x8326, 0x1ee6),
+    (0x8327, 0x1efd),
+    (0x8328, 0x0931),
+    (0x8329, 0x1ef5),
+    (0x832a, 0x1eec),
+    (0x832b, 0x0921),
+    (0x832c, 0x1efb),
+    (0x832d, 0x1ede),
+    (0x832f, 0x1ef4),
+    (0x8331, 0x0930),
+    (0x8332, 0x092b),
+    (0x8333, 0x1edd),
+    (0x8334, 0x0929),
+    (0x8335, 0x0928),
+    (0x8336, 0x092d),
+    (0x8337, 0x1ef3),
+    (0x8338, 0x0925),
+    (0x8339, 0x092c),
+    (0x833a, 0x1d05),
+    (0x833b, 0x23ae),
+    (0x833c, 0x1eee),
+    (0x833f, 0x1ee4),
+    (0x8340, 0x092f),
+    (0x8341, 0x1ee5),
+    (0x8342, 0x1ee9),
+    (0x8343, 0x0932),
+    (0x8344, 0x1edf),
+    (0x8345, 0x1ef7),
+    (0x8347, 0x1ef6),
+    (0x8348, 0x1efe),
+    (0x8349, 0x0927),
+    (0x834a, 0x0924),
+    (0x834b, 0x1efc),
+    (0x834c, 0x1ef8),
+    (0x834d, 0x1eef),
+    (0x834e, 0x1eea),
+    (0x834f, 0x092a),
+    (0x8350, 0x0926),
+    (0x8351, 0x1ee1),
+    (0x8352, 0x0922),
+    (0x8353, 0x1ef9),
+    (0x8354, 0x0923),
+    (0x8356, 0x1ee3),
+    (0x8373, 0x213c),
+    (0x8374, 0x213e),
+    (0x8375, 0x2143),
+    (0x8376, 0x2157),
+    (0x8377, 0x0b07),
+    (0x8378, 0x0afd),
+    (0x837a, 0x213b),
+    (0x837b, 0x0b08),
+    (0x837c, 0x0b09),
+    (0x837d, 0x2146),
+    (0x837e, 0x214d),
+    (0x837f, 0x2153),
+    (0x8381, 0x2140),
+    (0x8383, 0x2147),
+    (0x8386, 0x0b0a),
+    (0x8387, 0x2155),
+    (0x8388, 0x2150),
+    (0x8389, 0x0b05),
+    (0x838a, 0x0b03),
+    (0x838b, 0x214c),
+    (0x838c, 0x2148),
+    (0x838d, 0x213a),
+    (0x838e, 0x0afa),
+    (0x838f, 0x213f),
+    (0x8390, 0x2137),
+    (0x8392, 0x0b02),
+    (0x8393, 0x0b04),
+    (0x8394, 0x2144),
+    (0x8395, 0x2141),
+    (0x8396, 0x0aff),
+    (0x8397, 0x2151),
+    (0x8398, 0x0afc),
+    (0x8399, 0x2142),
+    (0x839a, 0x2158),
+    (0x839b, 0x214a),
+    (0x839d, 0x2149),
+    (0x839e, 0x0afb),
+    (0x83a0, 0x0b06),
+    (0x83a2, 0x0afe),
+    (0x83a3, 0x2138),
+    (0x83a4, 0x213d),
+    (0x83a5, 0x214e),
+    (0x83a6, 0x2154),
+    (0x83a7, 0x0b0b),
+    (0x83a8, 0x2139),
+    (0x83a9, 0x2145),
+    (0x83aa, 0x214b),
+    (0x83ab, 0x0b01),
+    (0x83ae, 0x2156),
+    (0x83af, 0x214f),
+    (0x83b0, 0x2152),
+    (0x83bd, 0x0b00),
+    (0x83bf, 0x23be),
+    (0x83c0, 0x23b2),
+    (0x83c1, 0x0cb9),
+    (0x83c2, 0x23cf),
+    (0x83c3, 0x23d7),
+    (0x83c4, 0x23da),
+    (0x83c5, 0x0cb7),
+    (0x83c6, 0x23ba),
+    (0x83c7, 0x23d3),
+    (0x83c8, 0x23bb),
+    (0x83c9, 0x23c9),
+    (0x83ca, 0x0cc4),
+    (0x83cb, 0x23c5),
+    (0x83cc, 0x0cc1),
+    (0x83ce, 0x23c6),
+    (0x83cf, 0x23af),
+    (0x83d1, 0x23d4),
+    (0x83d4, 0x0cca),
+    (0x83d5, 0x23d1),
+    (0x83d6, 0x23c7),
+    (0x83d7, 0x23dc),
+    (0x83d8, 0x23c2),
+    (0x83d9, 0x266c),
+    (0x83db, 0x23df),
+    (0x83dc, 0x0cc8),
+    (0x83dd, 0x23c0),
+    (0x83de, 0x23cc),
+    (0x83df, 0x0ccb),
+    (0x83e0, 0x0cb6),
+    (0x83e1, 0x23c4),
+    (0x83e2, 0x23dd),
+    (0x83e3, 0x23bd),
+    (0x83e4, 0x23b6),
+    (0x83e5, 0x23c1),
+    (0x83e7, 0x23b5),
+    (0x83e8, 0x23b3),
+    (0x83e9, 0x0cb2),
+    (0x83ea, 0x23d5),
+    (0x83eb, 0x23bc),
+    (0x83ec, 0x23d8),
+    (0x83ee, 0x23d9),
+    (0x83ef, 0x0cba),
+    (0x83f0, 0x0cbf),
+    (0x83f1, 0x0cbb),
+    (0x83f2, 0x0cc3),
+    (0x83f3, 0x23d0),
+    (0x83f4, 0x0cbc),
+    (0x83f5, 0x23c8),
+    (0x83f6, 0x23b8),
+    (0x83f8, 0x0cb4),
+    (0x83f9, 0x23b0),
+    (0x83fa, 0x23d2),
+    (0x83fb, 0x23db),
+    (0x83fc, 0x23b7),
+    (0x83fd, 0x0cc2),
+    (0x83fe, 0x23e0),
+    (0x83ff, 0x23c3),
+    (0x8401, 0x23bf),
+    (0x8403, 0x0cb3),
+    (0x8404, 0x0cc7),
+    (0x8406, 0x23ce),
+    (0x8407, 0x0cc9),
+    (0x8409, 0x23ca),
+    (0x840a, 0x0cbe),
+    (0x840b, 0x0cb8),
+    (0x840c, 0x0cc0),
+    (0x840d, 0x0cb5),
+    (0x840e, 0x0cc6),
+    (0x840f, 0x23cb),
+    (0x8410, 0x23b9),
+    (0x8411, 0x23cd),
+    (0x8412, 0x23b4),
+    (0x8413, 0x23d6),
+    (0x841b, 0x23de),
+    (0x8423, 0x23b1),
+    (0x8429, 0x266b),
+    (0x842b, 0x2681),
+    (0x842c, 0x0e30),
+    (0x842d, 0x2670),
+    (0x842f, 0x266e),
+    (0x8430, 0x2659),
+    (0x8431, 0x0e62),
+    (0x8432, 0x2669),
+    (0x8433, 0x267d),
+    (0x8434, 0x2665),
+    (0x8435, 0x0e6a),
+    (0x8436, 0x267c),
+    (0x8437, 0x2663),
+    (0x8438, 0x0cc5),
+    (0x8439, 0x2673),
+    (0x843a, 0x2664),
+    (0x843b, 0x267a),
+    (0x843c, 0x0e69),
+    (0x843d, 0x0e61),
+    (0x843f, 0x264e),
+    (0x8440, 0x2656),
+    (0x8442, 0x266f),
+    (0x8443, 0x2667),
+    (0x8444, 0x2680),
+    (0x8445, 0x266a),
+    (0x8446, 0x0e6f),
+    (0x8447, 0x267b),
+    (0x8449, 0x0e66),
+    (0x844b, 0x266d),
+    (0x844c, 0x2675),
+    (0x844d, 0x265a),
+    (0x844e, 0x2674),
+    (0x8450, 0x2685),
+    (0x8451, 0x2655),
+    (0x8452, 0x2676),
+    (0x8454, 0x2683),
+    (0x8456, 0x264f),
+    (0x8457, 0x0cbd),
+    (0x8459, 0x265d),
+    (0x845a, 0x265c),
+    (0x845b, 0x0e68),
+    (0x845d, 0x2660),
+    (0x845e, 0x2662),
+    (0x845f, 0x2671),
+    (0x8460, 0x2682),
+    (0x8461, 0x0e6b),
+    (0x8463, 0x0e6c),
+    (0x8465, 0x2654),
+    (0x8466, 0x0e64),
+    (0x8467, 0x2658),
+    (0x8468, 0x267e),
+    (0x8469, 0x0e6d),
+    (0x846b, 0x0e65),
+    (0x846c, 0x0e67),
+    (0x846d, 0x0e6e),
+    (0x846e, 0x2684),
+    (0x846f, 0x2677),
+    (0x8470, 0x2672),
+    (0x8473, 0x265f),
+    (0x8474, 0x265e),
+    (0x8475, 0x0e63),
+    (0x8476, 0x2650),
+    (0x8477, 0x0e60),
+    (0x8478, 0x2668),
+    (0x8479, 0x2651),
+    (0x847a, 0x2666),
+    (0x847d, 0x265b),
+    (0x847e, 0x267f),
+    (0x8482, 0x0e5f),
+    (0x8486, 0x2657),
+    (0x848d, 0x2653),
+    (0x848e, 0x2679),
+    (0x848f, 0x2652),
+    (0x8490, 0x1012),
+    (0x8491, 0x28eb),
+    (0x8494, 0x28da),
+    (0x8497, 0x28c4),
+    (0x8498, 0x28e2),
+    (0x8499, 0x100a),
+    (0x849a, 0x28d3),
+    (0x849b, 0x28dd),
+    (0x849c, 0x100d),
+    (0x849d, 0x28d6),
+    (0x849e, 0x100b),
+    (0x849f, 0x28c7),
+    (0x84a0, 0x28e5),
+    (0x84a1, 0x28c6),
+    (0x84a2, 0x28d9),
+    (0x84a4, 0x28c5),
+    (0x84a7, 0x28d7),
+    (0x84a8, 0x28e0),
+    (0x84a9, 0x28de),
+    (0x84aa, 0x28d2),
+    (0x84ab, 0x28cd),
+    (0x84ac, 0x28cb),
+    (0x84ae, 0x28cc),
+    (0x84af, 0x28df),
+    (0x84b0, 0x28ea),
+    (0x84b1, 0x28d4),
+    (0x84b2, 0x100c),
+    (0x84b4, 0x28cf),
+    (0x84b6, 0x28e3),
+    (0x84b8, 0x100f),
+    (0x84b9, 0x28ce),
+    (0x84ba, 0x28c8),
+    (0x84bb, 0x28d8),
+    (0x84bc, 0x1013),
+    (0x84bf, 0x1007),
+    (0x84c0, 0x1010),
+    (0x84c1, 0x28d0),
+    (0x84c2, 0x28ca),
+    (0x84c4, 0x1009),
+    (0x84c5, 0x2678),
+    (0x84c6, 0x1008),
+    (0x84c7, 0x28db),
+    (0x84c9, 0x1006),
+    (0x84ca, 0x1015),
+    (0x84cb, 0x100e),
+    (0x84cc, 0x28dc),
+    (0x84cd, 0x28d1),
+    (0x84ce, 0x28c9),
+    (0x84cf, 0x28e4),
+    (0x84d0, 0x28d5),
+    (0x84d1, 0x1014),
+    (0x84d2, 0x28e8),
+    (0x84d3, 0x1011),
+    (0x84d4, 0x28e7),
+    (0x84d6, 0x28e1),
+    (0x84d7, 0x28e6),
+    (0x84db, 0x28e9),
+    (0x84e7, 0x2b21),
+    (0x84e8, 0x2b22),
+    (0x84e9, 0x2b15),
+    (0x84ea, 0x2b14),
+    (0x84eb, 0x2b10),
+    (0x84ec, 0x117e),
+    (0x84ee, 0x1176),
+    (0x84ef, 0x2b24),
+    (0x84f0, 0x2b23),
+    (0x84f1, 0x264d),
+    (0x84f2, 0x2b0d),
+    (0x84f3, 0x2b11),
+    (0x84f4, 0x2b0b),
+    (0x84f6, 0x2b1e),
+    (0x84f7, 0x2b0f),
+    (0x84f9, 0x2b25),
+    (0x84fa, 0x2b08),
+    (0x84fb, 0x2b06),
+    (0x84fc, 0x2b12),
+    (0x84fd, 0x2b1c),
+    (0x84fe, 0x2b17),
+    (0x84ff, 0x1180),
+    (0x8500, 0x2afd),
+    (0x8502, 0x2b1b),
+    (0x8506, 0x1181),
+    (0x8507, 0x2661),
+    (0x8508, 0x2b09),
+    (0x8509, 0x2b00),
+    (0x850a, 0x2b03),
+    (0x850b, 0x2b29),
+    (0x850c, 0x2b0a),
+    (0x850d, 0x2b01),
+    (0x850e, 0x2aff),
+    (0x850f, 0x2afc),
+    (0x8511, 0x117a),
+    (0x8512, 0x2b13),
+    (0x8513, 0x1179),
+    (0x8514, 0x117d),
+    (0x8515, 0x2b0e),
+    (0x8516, 0x2b16),
+    (0x8517, 0x1173),
+    (0x8518, 0x2b26),
+    (0x8519, 0x2b2a),
+    (0x851a, 0x1175),
+    (0x851c, 0x2b05),
+    (0x851d, 0x2b19),
+    (0x851e, 0x2b1d),
+    (0x851f, 0x2b02),
+    (0x8520, 0x2b27),
+    (0x8521, 0x117c),
+    (0x8523, 0x117b),
+    (0x8524, 0x2afa),
+    (0x8525, 0x117f),
+    (0x8526, 0x2b20),
+    (0x8527, 0x2b04),
+    (0x8528, 0x2b18),
+    (0x8529, 0x2afe),
+    (0x852a, 0x2b0c),
+    (0x852b, 0x2b07),
+    (0x852c, 0x1177),
+    (0x852d, 0x1178),
+    (0x852e, 0x2b1a),
+    (0x852f, 0x2b2b),
+    (0x8530, 0x2b28),
+    (0x8531, 0x2b1f),
+    (0x853b, 0x2afb),
+    (0x853d, 0x1174),
+    (0x853e, 0x2d6b),
+    (0x8540, 0x2d62),
+    (0x8541, 0x2d65),
+    (0x8543, 0x12bf),
+    (0x8544, 0x2d67),
+    (0x8545, 0x2d5c),
+    (0x8546, 0x2d63),
+    (0x8547, 0x2d69),
+    (0x8548, 0x12bc),
+    (0x8549, 0x12c0),
+    (0x854a, 0x12ba),
+    (0x854d, 0x2d5e),
+    (0x854e, 0x2d6e),
+    (0x8551, 0x2d68),
+    (0x8553, 0x2d5f),
+    (0x8554, 0x2d77),
+    (0x8555, 0x2d71),
+    (0x8556, 0x2d5b),
+    (0x8557, 0x2f3f),
+    (0x8558, 0x2d61),
+    (0x8559, 0x12bb),
+    (0x855b, 0x2d6c),
+    (0x855d, 0x2d76),
+    (0x855e, 0x12c3),
+    (0x8560, 0x2d73),
+    (0x8561, 0x2d60),
+    (0x8562, 0x2d66),
+    (0x8563, 0x2d6a),
+    (0x8564, 0x2d64),
+    (0x8565, 0x2d78),
+    (0x8566, 0x2d75),
+    (0x8567, 0x2d72),
+    (0x8568, 0x12bd),
+    (0x8569, 0x12be),
+    (0x856a, 0x12c2),
+    (0x856b, 0x2d5d),
+    (0x856c, 0x2d79),
+    (0x856d, 0x12c1),
+    (0x856e, 0x2d6f),
+    (0x8571, 0x2d6d),
+    (0x8575, 0x2d70),
+    (0x8576, 0x2f4c),
+    (0x8577, 0x2f39),
+    (0x8578, 0x2f3e),
+    (0x8579, 0x2f4b),
+    (0x857a, 0x2f3d),
+    (0x857b, 0x2f35),
+    (0x857c, 0x2f3a),
+    (0x857e, 0x13e0),
+    (0x8580, 0x2f2e),
+    (0x8581, 0x2f46),
+    (0x8582, 0x2f48),
+    (0x8583, 0x2f2d),
+    (0x8584, 0x13df),
+    (0x8585, 0x2f4a),
+    (0x8586, 0x2f42),
+    (0x8587, 0x13e6),
+    (0x8588, 0x2f49),
+    (0x8589, 0x2f3b),
+    (0x858a, 0x13e8),
+    (0x858b, 0x2f33),
+    (0x858c, 0x2d74),
+    (0x858d, 0x2f43),
+    (0x858e, 0x2f40),
+    (0x858f, 0x2f2f),
+    (0x8590, 0x2f4e),
+    (0x8591, 0x13e2),
+    (0x8594, 0x13e3),
+    (0x8595, 0x2f31),
+    (0x8596, 0x2f41),
+    (0x8598, 0x2f4d),
+    (0x8599, 0x2f44),
+    (0x859a, 0x2f37),
+    (0x859b, 0x13e5),
+    (0x859c, 0x13e1),
+    (0x859d, 0x2f45),
+    (0x859e, 0x2f38),
+    (0x859f, 0x2f4f),
+    (0x85a0, 0x2f32),
+    (0x85a1, 0x2f3c),
+    (0x85a2, 0x2f47),
+    (0x85a3, 0x2f34),
+    (0x85a4, 0x2f36),
+    (0x85a6, 0x14a6),
+    (0x85a7, 0x2f30),
+    (0x85a8, 0x13e7),
+    (0x85a9, 0x149f),
+    (0x85aa, 0x13de),
+    (0x85af, 0x13e4),
+    (0x85b0, 0x14a3),
+    (0x85b1, 0x30c4),
+    (0x85b3, 0x30ba),
+    (0x85b4, 0x30b5),
+    (0x85b5, 0x30bb),
+    (0x85b6, 0x30c5),
+    (0x85b7, 0x30c9),
+    (0x85b8, 0x30c8),
+    (0x85b9, 0x14a5),
+    (0x85ba, 0x14a4),
+    (0x85bd, 0x30bc),
+    (0x85be, 0x30ca),
+    (0x85bf, 0x30bf),
+    (0x85c0, 0x30b7),
+    (0x85c2, 0x30b9),
+    (0x85c3, 0x30b8),
+    (0x85c4, 0x30be),
+    (0x85c5, 0x30c3),
+    (0x85c6, 0x30b6),
+    (0x85c7, 0x30bd),
+    (0x85c8, 0x30c2),
+    (0x85c9, 0x14a2),
+    (0x85cb, 0x30c0),
+    (0x85cd, 0x14a0),
+    (0x85ce, 0x30c1),
+    (0x85cf, 0x149e),
+    (0x85d0, 0x14a1),
+    (0x85d1, 0x3220),
+    (0x85d2, 0x30c6),
+    (0x85d5, 0x153a),
+    (0x85d7, 0x3218),
+    (0x85d8, 0x321c),
+    (0x85d9, 0x3214),
+    (0x85da, 0x3217),
+    (0x85dc, 0x321f),
+    (0x85dd, 0x1538),
+    (0x85de, 0x3224),
+    (0x85df, 0x321d),
+    (0x85e1, 0x3215),
+    (0x85e2, 0x3225),
+    (0x85e3, 0x321e),
+    (0x85e4, 0x153b),
+    (0x85e5, 0x153c),
+    (0x85e6, 0x3222),
+    (0x85e8, 0x3216),
+    (0x85e9, 0x1537),
+    (0x85ea, 0x1539),
+    (0x85eb, 0x3211),
+    (0x85ec, 0x3219),
+    (0x85ed, 0x3213),
+    (0x85ef, 0x3223),
+    (0x85f0, 0x3221),
+    (0x85f1, 0x3212),
+    (0x85f2, 0x321a),
+    (0x85f6, 0x3343),
+    (0x85f7, 0x153d),
+    (0x85f8, 0x321b),
+    (0x85f9, 0x15bc),
+    (0x85fa, 0x15be),
+    (0x85fb, 0x15bb),
+    (0x85fd, 0x3348),
+    (0x85fe, 0x3340),
+    (0x85ff, 0x333e),
+    (0x8600, 0x3342),
+    (0x8601, 0x333f),
+    (0x8604, 0x3344),
+    (0x8605, 0x3346),
+    (0x8606, 0x15bf),
+    (0x8607, 0x15c1),
+    (0x8609, 0x3345),
+    (0x860a, 0x15c2),
+    (0x860b, 0x15c0),
+    (0x860c, 0x3347),
+    (0x8611, 0x15bd),
+    (0x8617, 0x1609),
+    (0x8618, 0x3413),
+    (0x8619, 0x3419),
+    (0x861a, 0x160b),
+    (0x861b, 0x3341),
+    (0x861c, 0x3418),
+    (0x861e, 0x341f),
+    (0x861f, 0x3416),
+    (0x8620, 0x341d),
+    (0x8621, 0x341c),
+    (0x8622, 0x333d),
+    (0x8623, 0x3417),
+    (0x8624, 0x30c7),
+    (0x8625, 0x3420),
+    (0x8626, 0x3415),
+    (0x8627, 0x341a),
+    (0x8629, 0x341e),
+    (0x862a, 0x3414),
+    (0x862c, 0x34c8),
+    (0x862d, 0x160a),
+    (0x862e, 0x341b),
+    (0x8631, 0x3550),
+    (0x8632, 0x34c9),
+    (0x8633, 0x34c7),
+    (0x8634, 0x34c5),
+    (0x8635, 0x34c6),
+    (0x8636, 0x34ca),
+    (0x8638, 0x168b),
+    (0x8639, 0x354e),
+    (0x863a, 0x354c),
+    (0x863b, 0x3551),
+    (0x863c, 0x354f),
+    (0x863e, 0x3552),
+    (0x863f, 0x168c),
+    (0x8640, 0x354d),
+    (0x8643, 0x35b5),
+    (0x8646, 0x35fd),
+    (0x8647, 0x35fe),
+    (0x8648, 0x35ff),
+    (0x864b, 0x365d),
+    (0x864c, 0x3653),
+    (0x864d, 0x1934),
+    (0x864e, 0x060d),
+    (0x8650, 0x0784),
+    (0x8652, 0x1f00),
+    (0x8653, 0x1eff),
+    (0x8654, 0x0933),
+    (0x8655, 0x0b0c),
+    (0x8656, 0x215a),
+    (0x8659, 0x2159),
+    (0x865b, 0x0ccc),
+    (0x865c, 0x0e71),
+    (0x865e, 0x0e70),
+    (0x865f, 0x0e72),
+    (0x8661, 0x28ec),
+    (0x8662, 0x2b2c),
+    (0x8663, 0x2d7a),
+    (0x8664, 0x2d7c),
+    (0x8665, 0x2d7b),
+    (0x8667, 0x13e9),
+    (0x8668, 0x2f50),
+    (0x8669, 0x30cb),
+    (0x866a, 0x363b),
+    (0x866b, 0x0393),
+    (0x866d, 0x1b63),
+    (0x866e, 0x1b64),
+    (0x866f, 0x1b62),
+    (0x8670, 0x1b61),
+    (0x8671, 0x060e),
+    (0x8673, 0x1d1a),
+    (0x8674, 0x1d18),
+    (0x8677, 0x1d17),
+    (0x8679, 0x0785),
+    (0x867a, 0x0787),
+    (0x867b, 0x0786),
+    (0x867c, 0x1d19),
+    (0x8685, 0x1f0c),
+    (0x8686, 0x1f09),
+    (0x8687, 0x1f07),
+    (0x868a, 0x0934),
+    (0x868b, 0x1f0a),
+    (0x868c, 0x0939),
+    (0x868d, 0x1f04),
+    (0x868e, 0x1f13),
+    (0x8690, 0x1f15),
+    (0x8691, 0x1f05),
+    (0x8693, 0x0936),
+    (0x8694, 0x1f16),
+    (0x8695, 0x1f11),
+    (0x8696, 0x1f03),
+    (0x8697, 0x1f08),
+    (0x8698, 0x1f12),
+    (0x8699, 0x1f0e),
+    (0x869a, 0x1f0b),
+    (0x869c, 0x093b),
+    (0x869d, 0x1f14),
+    (0x869e, 0x1f06),
+    (0x86a1, 0x1f0f),
+    (0x86a2, 0x1f01),
+    (0x86a3, 0x093a),
+    (0x86a4, 0x0937),
+    (0x86a5, 0x1f0d),
+    (0x86a7, 0x1f10),
+    (0x86a8, 0x1f02),
+    (0x86a9, 0x0938),
+    (0x86aa, 0x0935),
+    (0x86af, 0x0b16),
+    (0x86b0, 0x2161),
+    (0x86b1, 0x0b15),
+    (0x86b3, 0x2164),
+    (0x86b4, 0x2167),
+    (0x86b5, 0x0b12),
+    (0x86b6, 0x0b10),
+    (0x86b7, 0x215c),
+    (0x86b8, 0x2165),
+    (0x86b9, 0x2163),
+    (0x86ba, 0x2160),
+    (0x86bb, 0x2168),
+    (0x86bc, 0x2169),
+    (0x86bd, 0x216b),
+    (0x86be, 0x216c),
+    (0x86bf, 0x215b),
+    (0x86c0, 0x0b0f),
+    (0x86c1, 0x215e),
+    (0x86c2, 0x215d),
+    (0x86c3, 0x216a),
+    (0x86c4, 0x0b11),
+    (0x86c5, 0x215f),
+    (0x86c6, 0x0b13),
+    (0x86c7, 0x0b0e),
+    (0x86c8, 0x2162),
+    (0x86c9, 0x0b17),
+    (0x86cb, 0x0b14),
+    (0x86cc, 0x2166),
+    (0x86d0, 0x0cd3),
+    (0x86d1, 0x23ef),
+    (0x86d3, 0x23e4),
+    (0x86d4, 0x0cd0),
+    (0x86d6, 0x268b),
+    (0x86d7, 0x23ed),
+    (0x86d8, 0x23e1),
+    (0x86d9, 0x0cce),
+    (0x86da, 0x23e6),
+    (0x86db, 0x0cd1),
+    (0x86dc, 0x23ea),
+    (0x86dd, 0x23e8),
+    (0x86de, 0x0cd4),
+    (0x86df, 0x0ccd),
+    (0x86e2, 0x23e2),
+    (0x86e3, 0x23e5),
+    (0x86e4, 0x0cd2),
+    (0x86e6, 0x23e3),
+    (0x86e8, 0x23ee),
+    (0x86e9, 0x23ec),
+    (0x86ea, 0x23e7),
+    (0x86eb, 0x23e9),
+    (0x86ec, 0x23eb),
+    (0x86ed, 0x0ccf),
+    (0x86f5, 0x268c),
+    (0x86f6, 0x2692),
+    (0x86f7, 0x2688),
+    (0x86f8, 0x268e),
+    (0x86f9, 0x0e73),
+    (0x86fa, 0x268a),
+    (0x86fb, 0x0e79),
+    (0x86fe, 0x0e78),
+    (0x8700, 0x0e77),
+    (0x8701, 0x2691),
+    (0x8702, 0x0e7a),
+    (0x8703, 0x0e7b),
+    (0x8704, 0x2687),
+    (0x8705, 0x2694),
+    (0x8706, 0x0e7c),
+    (0x8707, 0x0e76),
+    (0x8708, 0x0e75),
+    (0x8709, 0x2690),
+    (0x870a, 0x0e7d),
+    (0x870b, 0x2686),
+    (0x870c, 0x2689),
+    (0x870d, 0x2693),
+    (0x870e, 0x268f),
+    (0x8711, 0x290c),
+    (0x8712, 0x2901),
+    (0x8713, 0x0e74),
+    (0x8718, 0x101c),
+    (0x8719, 0x28f5),
+    (0x871a, 0x290a),
+    (0x871b, 0x28f6),
+    (0x871c, 0x1017),
+    (0x871e, 0x28f3),
+    (0x8720, 0x28fc),
+    (0x8721, 0x28f4),
+    (0x8722, 0x1019),
+    (0x8723, 0x28ee),
+    (0x8724, 0x2909),
+    (0x8725, 0x101a),
+    (0x8726, 0x2906),
+    (0x8727, 0x2907),
+    (0x8728, 0x28ef),
+    (0x8729, 0x101f),
+    (0x872a, 0x28fe),
+    (0x872c, 0x28f8),
+    (0x872d, 0x28ff),
+    (0x872e, 0x28f2),
+    (0x8730, 0x290b),
+    (0x8731, 0x2903),
+    (0x8732, 0x28fd),
+    (0x8733, 0x28ed),
+    (0x8734, 0x101b),
+    (0x8735, 0x2904),
+    (0x8737, 0x101e),
+    (0x8738, 0x2908),
+    (0x873a, 0x2902),
+    (0x873b, 0x1018),
+    (0x873c, 0x2900),
+    (0x873e, 0x28fa),
+    (0x873f, 0x1016),
+    (0x8740, 0x28f1),
+    (0x8741, 0x28f9),
+    (0x8742, 0x2905),
+    (0x8743, 0x28f7),
+    (0x8746, 0x28fb),
+    (0x874c, 0x118b),
+    (0x874d, 0x268d),
+    (0x874e, 0x2b3e),
+    (0x874f, 0x2b47),
+    (0x8750, 0x2b3d),
+    (0x8751, 0x2b39),
+    (0x8752, 0x2b36),
+    (0x8753, 0x118c),
+    (0x8754, 0x2b34),
+    (0x8755, 0x101d),
+    (0x8756, 0x2b2d),
+    (0x8757, 0x118a),
+    (0x8758, 0x2b33),
+    (0x8759, 0x1189),
+    (0x875a, 0x2b38),
+    (0x875b, 0x2b35),
+    (0x875c, 0x2b45),
+    (0x875d, 0x2b40),
+    (0x875e, 0x2b3a),
+    (0x875f, 0x2b3f),
+    (0x8760, 0x1185),
+    (0x8761, 0x2b37),
+    (0x8762, 0x2b4a),
+    (0x8763, 0x2b2e),
+    (0x8764, 0x2b2f),
+    (0x8765, 0x2b46),
+    (0x8766, 0x1186),
+    (0x8767, 0x2b4b),
+    (0x8768, 0x1188),
+    (0x8769, 0x2b4c),
+    (0x876a, 0x2b3c),
+    (0x876b, 0x28f0),
+    (0x876c, 0x2b42),
+    (0x876d, 0x2b3b),
+    (0x876e, 0x2b44),
+    (0x876f, 0x2b41),
+    (0x8773, 0x2b32),
+    (0x8774, 0x1183),
+    (0x8775, 0x2b49),
+    (0x8776, 0x1184),
+    (0x8777, 0x2b30),
+    (0x8778, 0x1187),
+    (0x8779, 0x2d86),
+    (0x877a, 0x2b43),
+    (0x877b, 0x2b48),
+    (0x8781, 0x2d83),
+    (0x8782, 0x1182),
+    (0x8783, 0x12c4),
+    (0x8784, 0x2d8d),
+    (0x8785, 0x2d89),
+    (0x8787, 0x2d87),
+    (0x8788, 0x2d82),
+    (0x8789, 0x2d91),
+    (0x878d, 0x12c8),
+    (0x878f, 0x2d7e),
+    (0x8790, 0x2d8a),
+    (0x8791, 0x2d8b),
+    (0x8792, 0x2d81),
+    (0x8793, 0x2d80),
+    (0x8794, 0x2d8e),
+    (0x8796, 0x2d84),
+    (0x8797, 0x2d7f),
+    (0x8798, 0x2d85),
+    (0x879a, 0x2d90),
+    (0x879b, 0x2d7d),
+    (0x879c, 0x2d8f),
+    (0x879d, 0x2d8c),
+    (0x879e, 0x12c6),
+    (0x879f, 0x12c5),
+    (0x87a2, 0x12c7),
+    (0x87a3, 0x2d88),
+    (0x87a4, 0x30dc),
+    (0x87aa, 0x2f52),
+    (0x87ab, 0x13ef),
+    (0x87ac, 0x2f56),
+    (0x87ad, 0x2f53),
+    (0x87ae, 0x2f5a),
+    (0x87af, 0x2f60),
+    (0x87b0, 0x2f55),
+    (0x87b2, 0x2f69),
+    (0x87b3, 0x13ec),
+    (0x87b4, 0x2f63),
+    (0x87b5, 0x2f58),
+    (0x87b6, 0x2f64),
+    (0x87b7, 0x2f5f),
+    (0x87b8, 0x2f66),
+    (0x87b9, 0x2f57),
+    (0x87ba, 0x13f1),
+    (0x87bb, 0x13f0),
+    (0x87bc, 0x2f59),
+    (0x87bd, 0x2f67),
+    (0x87be, 0x2f51),
+    (0x87bf, 0x2f65),
+    (0x87c0, 0x13ea),
+    (0x87c2, 0x2f5d),
+    (0x87c3, 0x2f5c),
+    (0x87c4, 0x2f61),
+    (0x87c5, 0x2f54),
+    (0x87c6, 0x13ee),
+    (0x87c8, 0x13f2),
+    (0x87c9, 0x2f5b),
+    (0x87ca, 0x2f62),
+    (0x87cb, 0x13f3),
+    (0x87cc, 0x2f5e),
+    (0x87d1, 0x13eb),
+    (0x87d2, 0x13ed),
+    (0x87d3, 0x30d8),
+    (0x87d4, 0x30d6),
+    (0x87d7, 0x30dd),
+    (0x87d8, 0x30da),
+    (0x87d9, 0x30de),
+    (0x87db, 0x30cf),
+    (0x87dc, 0x30d7),
+    (0x87dd, 0x30e2),
+    (0x87de, 0x2f68),
+    (0x87df, 0x30d3),
+    (0x87e0, 0x14aa),
+    (0x87e1, 0x2b31),
+    (0x87e2, 0x30ce),
+    (0x87e3, 0x30db),
+    (0x87e4, 0x30d5),
+    (0x87e5, 0x30d2),
+    (0x87e6, 0x30cd),
+    (0x87e7, 0x30cc),
+    (0x87e8, 0x30e1),
+    (0x87ea, 0x30d1),
+    (0x87eb, 0x30d0),
+    (0x87ec, 0x14a8),
+    (0x87ed, 0x30d9),
+    (0x87ef, 0x14a7),
+    (0x87f2, 0x14a9),
+    (0x87f3, 0x30d4),
+    (0x87f4, 0x30e0),
+    (0x87f6, 0x3229),
+    (0x87f7, 0x322a),
+    (0x87f9, 0x1541),
+    (0x87fa, 0x3227),
+    (0x87fb, 0x153e),
+    (0x87fc, 0x322f),
+    (0x87fe, 0x1542),
+    (0x87ff, 0x3231),
+    (0x8800, 0x3226),
+    (0x8801, 0x30df),
+    (0x8802, 0x3233),
+    (0x8803, 0x3228),
+    (0x8805, 0x153f),
+    (0x8806, 0x322e),
+    (0x8808, 0x3230),
+    (0x8809, 0x322b),
+    (0x880a, 0x3232),
+    (0x880b, 0x322d),
+    (0x880c, 0x322c),
+    (0x880d, 0x1540),
+    (0x8810, 0x334a),
+    (0x8811, 0x334b),
+    (0x8813, 0x334d),
+    (0x8814, 0x15c3),
+    (0x8815, 0x15c4),
+    (0x8816, 0x334e),
+    (0x8817, 0x334c),
+    (0x8819, 0x3349),
+    (0x881b, 0x3423),
+    (0x881c, 0x3426),
+    (0x881d, 0x3422),
+    (0x881f, 0x160f),
+    (0x8820, 0x3424),
+    (0x8821, 0x160e),
+    (0x8822, 0x160d),
+    (0x8823, 0x160c),
+    (0x8824, 0x3425),
+    (0x8825, 0x34cf),
+    (0x8826, 0x34cd),
+    (0x8828, 0x34cc),
+    (0x8829, 0x3421),
+    (0x882a, 0x34ce),
+    (0x882b, 0x3427),
+    (0x882c, 0x34cb),
+    (0x882e, 0x3555),
+    (0x882f, 0x3533),
+    (0x8830, 0x3553),
+    (0x8831, 0x168d),
+    (0x8832, 0x3554),
+    (0x8833, 0x3556),
+    (0x8835, 0x35b8),
+    (0x8836, 0x16ac),
+    (0x8837, 0x35b7),
+    (0x8838, 0x35b6),
+    (0x8839, 0x16ad),
+    (0x883b, 0x16cb),
+    (0x883c, 0x3626),
+    (0x883d, 0x363d),
+    (0x883e, 0x363c),
+    (0x883f, 0x363e),
+    (0x8840, 0x0394),
+    (0x8841, 0x1d1b),
+    (0x8843, 0x1f17),
+    (0x8844, 0x1f18),
+    (0x8848, 0x23f0),
+    (0x884a, 0x3428),
+    (0x884b, 0x35b9),
+    (0x884c, 0x0395),
+    (0x884d, 0x0788),
+    (0x884e, 0x1d1c),
+    (0x8852, 0x216d),
+    (0x8853, 0x0b18),
+    (0x8855, 0x23f2),
+    (0x8856, 0x23f1),
+    (0x8857, 0x0cd5),
+    (0x8859, 0x0e7e),
+    (0x885a, 0x2b4d),
+    (0x885b, 0x118d),
+    (0x885d, 0x118e),
+    (0x8861, 0x12c9),
+    (0x8862, 0x16ae),
+    (0x8863, 0x0396),
+    (0x8867, 0x1d1d),
+    (0x8868, 0x0610),
+    (0x8869, 0x1d1f),
+    (0x886a, 0x1d1e),
+    (0x886b, 0x0789),
+    (0x886d, 0x1f19),
+    (0x886f, 0x1f20),
+    (0x8870, 0x093c),
+    (0x8871, 0x1f1e),
+    (0x8872, 0x1f1c),
+    (0x8874, 0x1f23),
+    (0x8875, 0x1f1a),
+    (0x8876, 0x1f1b),
+    (0x8877, 0x093d),
+    (0x8879, 0x0941),
+    (0x887c, 0x1f24),
+    (0x887d, 0x0940),
+    (0x887e, 0x1f22),
+    (0x887f, 0x1f1f),
+    (0x8880, 0x1f1d),
+    (0x8881, 0x093e),
+    (0x8882, 0x093f),
+    (0x8883, 0x1f21),
+    (0x8888, 0x0b1a),
+    (0x8889, 0x216e),
+    (0x888b, 0x0b1f),
+    (0x888c, 0x217e),
+    (0x888d, 0x0b1e),
+    (0x888e, 0x2180),
+    (0x8891, 0x2174),
+    (0x8892, 0x0b1c),
+    (0x8893, 0x217f),
+    (0x8895, 0x216f),
+    (0x8896, 0x0b1d),
+    (0x8897, 0x217b),
+    (0x8898, 0x2177),
+    (0x8899, 0x2179),
+    (0x889a, 0x2173),
+    (0x889b, 0x217a),
+    (0x889e, 0x0b19),
+    (0x889f, 0x2176),
+    (0x88a1, 0x2175),
+    (0x88a2, 0x2171),
+    (0x88a4, 0x217c),
+    (0x88a7, 0x2178),
+    (0x88a8, 0x2170),
+    (0x88aa, 0x2172),
+    (0x88ab, 0x0b1b),
+    (0x88ac, 0x217d),
+    (0x88b1, 0x0cd8),
+    (0x88b2, 0x23fd),
+    (0x88b6, 0x23f9),
+    (0x88b7, 0x23fb),
+    (0x88b8, 0x23f6),
+    (0x88b9, 0x23f5),
+    (0x88ba, 0x23f3),
+    (0x88bc, 0x23fa),
+    (0x88bd, 0x23fc),
+    (0x88be, 0x23f8),
+    (0x88c0, 0x23f7),
+    (0x88c1, 0x0cd6),
+    (0x88c2, 0x0cd7),
+    (0x88c9, 0x23ff),
+    (0x88ca, 0x0e86),
+    (0x88cb, 0x2696),
+    (0x88cc, 0x269c),
+    (0x88cd, 0x2697),
+    (0x88ce, 0x2698),
+    (0x88d0, 0x269d),
+    (0x88d2, 0x0e88),
+    (0x88d4, 0x0e80),
+    (0x88d5, 0x0e87),
+    (0x88d6, 0x2695),
+    (0x88d7, 0x23f4),
+    (0x88d8, 0x0e83),
+    (0x88d9, 0x0e81),
+    (0x88da, 0x269b),
+    (0x88db, 0x269a),
+    (0x88dc, 0x0e82),
+    (0x88dd, 0x0e84),
+    (0x88de, 0x2699),
+    (0x88df, 0x0e7f),
+    (0x88e1, 0x0e85),
+    (0x88e7, 0x290e),
+    (0x88e8, 0x1026),
+    (0x88eb, 0x2919),
+    (0x88ec, 0x2918),
+    (0x88ee, 0x2913),
+    (0x88ef, 0x1028),
+    (0x88f0, 0x2917),
+    (0x88f1, 0x290f),
+    (0x88f2, 0x2910),
+    (0x88f3, 0x1020),
+    (0x88f4, 0x1022),
+    (0x88f6, 0x2915),
+    (0x88f7, 0x290d),
+    (0x88f8, 0x1024),
+    (0x88f9, 0x1023),
+    (0x88fa, 0x2911),
+    (0x88fb, 0x2916),
+    (0x88fc, 0x2914),
+    (0x88fd, 0x1025),
+    (0x88fe, 0x2912),
+    (0x8901, 0x23fe),
+    (0x8902, 0x1021),
+    (0x8905, 0x2b4e),
+    (0x8906, 0x2b55),
+    (0x8907, 0x1190),
+    (0x8909, 0x2b59),
+    (0x890a, 0x1194),
+    (0x890b, 0x2b51),
+    (0x890c, 0x2b4f),
+    (0x890e, 0x2b58),
+    (0x8910, 0x118f),
+    (0x8911, 0x2b57),
+    (0x8912, 0x1191),
+    (0x8913, 0x1192),
+    (0x8914, 0x2b50),
+    (0x8915, 0x1193),
+    (0x8916, 0x2b56),
+    (0x8917, 0x2b52),
+    (0x8918, 0x2b53),
+    (0x8919, 0x2b54),
+    (0x891a, 0x1027),
+    (0x891e, 0x2d92),
+    (0x891f, 0x2d9e),
+    (0x8921, 0x12ce),
+    (0x8922, 0x2d99),
+    (0x8923, 0x2d9b),
+    (0x8925, 0x12cc),
+    (0x8926, 0x2d93),
+    (0x8927, 0x2d97),
+    (0x8929, 0x2d9a),
+    (0x892a, 0x12ca),
+    (0x892b, 0x12cd),
+    (0x892c, 0x2d9d),
+    (0x892d, 0x2d95),
+    (0x892e, 0x2d96),
+    (0x892f, 0x2d9c),
+    (0x8930, 0x2d94),
+    (0x8931, 0x2d98),
+    (0x8932, 0x12cb),
+    (0x8933, 0x2f6b),
+    (0x8935, 0x2f6a),
+    (0x8936, 0x13f5),
+    (0x8937, 0x2f70),
+    (0x8938, 0x13f7),
+    (0x893b, 0x13f4),
+    (0x893c, 0x2f6c),
+    (0x893d, 0x13f8),
+    (0x893e, 0x2f6d),
+    (0x8941, 0x2f6e),
+    (0x8942, 0x2f71),
+    (0x8944, 0x13f6),
+    (0x8946, 0x30e7),
+    (0x8949, 0x30ea),
+    (0x894b, 0x30e4),
+    (0x894c, 0x30e6),
+    (0x894f, 0x30e5),
+    (0x8950, 0x30e8),
+    (0x8951, 0x30e9),
+    (0x8952, 0x2f6f),
+    (0x8953, 0x30e3),
+    (0x8956, 0x1545),
+    (0x8957, 0x3237),
+    (0x8958, 0x323a),
+    (0x8959, 0x323c),
+    (0x895a, 0x3235),
+    (0x895b, 0x3236),
+    (0x895c, 0x3239),
+    (0x895d, 0x323b),
+    (0x895e, 0x1546),
+    (0x895f, 0x1544),
+    (0x8960, 0x1543),
+    (0x8961, 0x3238),
+    (0x8962, 0x3234),
+    (0x8963, 0x334f),
+    (0x8964, 0x15c5),
+    (0x8966, 0x3350),
+    (0x8969, 0x342a),
+    (0x896a, 0x1610),
+    (0x896b, 0x342c),
+    (0x896c, 0x1611),
+    (0x896d, 0x3429),
+    (0x896e, 0x342b),
+    (0x896f, 0x165a),
+    (0x8971, 0x34d0),
+    (0x8972, 0x1659),
+    (0x8973, 0x3559),
+    (0x8974, 0x3558),
+    (0x8976, 0x3557),
+    (0x8979, 0x3600),
+    (0x897a, 0x3601),
+    (0x897b, 0x3603),
+    (0x897c, 0x3602),
+    (0x897e, 0x1935),
+    (0x897f, 0x0397),
+    (0x8981, 0x078a),
+    (0x8982, 0x2181),
+    (0x8983, 0x0cd9),
+    (0x8985, 0x269e),
+    (0x8986, 0x14ab),
+    (0x8988, 0x323d),
+    (0x898b, 0x048c),
+    (0x898f, 0x0b21),
+    (0x8993, 0x0b20),
+    (0x8995, 0x2400),
+    (0x8996, 0x0cda),
+    (0x8997, 0x2402),
+    (0x8998, 0x2401),
+    (0x899b, 0x269f),
+    (0x899c, 0x0e89),
+    (0x899d, 0x291a),
+    (0x899e, 0x291d),
+    (0x899f, 0x291c),
+    (0x89a1, 0x291b),
+    (0x89a2, 0x2b5a),
+    (0x89a3, 0x2b5c),
+    (0x89a4, 0x2b5b),
+    (0x89a6, 0x12d0),
+    (0x89aa, 0x12cf),
+    (0x89ac, 0x13f9),
+    (0x89ad, 0x2f72),
+    (0x89ae, 0x2f74),
+    (0x89af, 0x2f73),
+    (0x89b2, 0x14ac),
+    (0x89b6, 0x323f),
+    (0x89b7, 0x323e),
+    (0x89b9, 0x3351),
+    (0x89ba, 0x15c6),
+    (0x89bd, 0x1612),
+    (0x89be, 0x34d2),
+    (0x89bf, 0x34d1),
+    (0x89c0, 0x16cc),
+    (0x89d2, 0x048d),
+    (0x89d3, 0x1d20),
+    (0x89d4, 0x078b),
+    (0x89d5, 0x2184),
+    (0x89d6, 0x2182),
+    (0x89d9, 0x2183),
+    (0x89da, 0x2404),
+    (0x89db, 0x2405),
+    (0x89dc, 0x26a6),
+    (0x89dd, 0x2403),
+    (0x89df, 0x26a0),
+    (0x89e0, 0x26a4),
+    (0x89e1, 0x26a3),
+    (0x89e2, 0x26a5),
+    (0x89e3, 0x0e8a),
+    (0x89e4, 0x26a2),
+    (0x89e5, 0x26a1),
+    (0x89e6, 0x26a7),
+    (0x89e8, 0x2920),
+    (0x89e9, 0x291e),
+    (0x89eb, 0x291f),
+    (0x89ec, 0x2b5f),
+    (0x89ed, 0x2b5d),
+    (0x89f0, 0x2b5e),
+    (0x89f1, 0x2d9f),
+    (0x89f2, 0x2f75),
+    (0x89f3, 0x2f76),
+    (0x89f4, 0x14ad),
+    (0x89f6, 0x3240),
+    (0x89f7, 0x3352),
+    (0x89f8, 0x15c7),
+    (0x89fa, 0x342d),
+    (0x89fb, 0x34d3),
+    (0x89fc, 0x165b),
+    (0x89fe, 0x355a),
+    (0x89ff, 0x3604),
+    (0x8a00, 0x048e),
+    (0x8a02, 0x078d),
+    (0x8a03, 0x078e),
+    (0x8a04, 0x1d21),
+    (0x8a07, 0x1d22),
+    (0x8a08, 0x078c),
+    (0x8a0a, 0x0947),
+    (0x8a0c, 0x0945),
+    (0x8a0e, 0x0944),
+    (0x8a0f, 0x094b),
+    (0x8a10, 0x0943),
+    (0x8a11, 0x094c),
+    (0x8a12, 0x1f25),
+    (0x8a13, 0x0949),
+    (0x8a15, 0x0946),
+    (0x8a16, 0x094a),
+    (0x8a17, 0x0948),
+    (0x8a18, 0x0942),
+    (0x8a1b, 0x0b29),
+    (0x8a1d, 0x0b23),
+    (0x8a1e, 0x2188),
+    (0x8a1f, 0x0b28),
+    (0x8a22, 0x0b2a),
+    (0x8a23, 0x0b24),
+    (0x8a25, 0x0b25),
+    (0x8a27, 0x2186),
+    (0x8a2a, 0x0b22),
+    (0x8a2c, 0x2187),
+    (0x8a2d, 0x0b27),
+    (0x8a30, 0x2185),
+    (0x8a31, 0x0b26),
+    (0x8a34, 0x0ce5),
+    (0x8a36, 0x0ce7),
+    (0x8a39, 0x2408),
+    (0x8a3a, 0x0ce6),
+    (0x8a3b, 0x0cdb),
+    (0x8a3c, 0x0cdf),
+    (0x8a3e, 0x0e9d),
+    (0x8a3f, 0x26ac),
+    (0x8a40, 0x240a),
+    (0x8a41, 0x0ce0),
+    (0x8a44, 0x240d),
+    (0x8a45, 0x240e),
+    (0x8a46, 0x0ce4),
+    (0x8a48, 0x2410),
+    (0x8a4a, 0x2412),
+    (0x8a4c, 0x2413),
+    (0x8a4d, 0x2407),
+    (0x8a4e, 0x2406),
+    (0x8a4f, 0x2414),
+    (0x8a50, 0x0ce3),
+    (0x8a51, 0x2411),
+    (0x8a52, 0x240f),
+    (0x8a54, 0x0ce1),
+    (0x8a55, 0x0cdd),
+    (0x8a56, 0x0ce8),
+    (0x8a57, 0x240b),
+    (0x8a58, 0x240c),
+    (0x8a59, 0x2409),
+    (0x8a5b, 0x0ce2),
+    (0x8a5e, 0x0cde),
+    (0x8a60, 0x0cdc),
+    (0x8a61, 0x26ab),
+    (0x8a62, 0x0e98),
+    (0x8a63, 0x0e93),
+    (0x8a66, 0x0e8e),
+    (0x8a68, 0x0e9e),
+    (0x8a69, 0x0e8f),
+    (0x8a6b, 0x0e8b),
+    (0x8a6c, 0x0e9a),
+    (0x8a6d, 0x0e97),
+    (0x8a6e, 0x0e99),
+    (0x8a70, 0x0e90),
+    (0x8a71, 0x0e95),
+    (0x8a72, 0x0e8c),
+    (0x8a73, 0x0e8d),
+    (0x8a74, 0x26b3),
+    (0x8a75, 0x26b0),
+    (0x8a76, 0x26a8),
+    (0x8a77, 0x26ad),
+    (0x8a79, 0x0e9b),
+    (0x8a7a, 0x26b4),
+    (0x8a7b, 0x0e9c),
+    (0x8a7c, 0x0e92),
+    (0x8a7f, 0x26aa),
+    (0x8a81, 0x26b2),
+    (0x8a82, 0x26ae),
+    (0x8a83, 0x26b1),
+    (0x8a84, 0x26af),
+    (0x8a85, 0x0e96),
+    (0x8a86, 0x26a9),
+    (0x8a87, 0x0e91),
+    (0x8a8b, 0x2923),
+    (0x8a8c, 0x102a),
+    (0x8a8d, 0x102d),
+    (0x8a8f, 0x2925),
+    (0x8a91, 0x1035),
+    (0x8a92, 0x2924),
+    (0x8a93, 0x102f),
+    (0x8a95, 0x1199),
+    (0x8a96, 0x2926),
+    (0x8a98, 0x1034),
+    (0x8a99, 0x2922),
+    (0x8a9a, 0x1036),
+    (0x8a9e, 0x102b),
+    (0x8aa0, 0x0e94),
+    (0x8aa1, 0x102e),
+    (0x8aa3, 0x102c),
+    (0x8aa4, 0x1030),
+    (0x8aa5, 0x1032),
+    (0x8aa6, 0x1029),
+    (0x8aa7, 0x1037),
+    (0x8aa8, 0x1033),
+    (0x8aaa, 0x1031),
+    (0x8aab, 0x2921),
+    (0x8ab0, 0x11a0),
+    (0x8ab2, 0x119c),
+    (0x8ab6, 0x11a3),
+    (0x8ab8, 0x2b62),
+    (0x8ab9, 0x11a4),
+    (0x8aba, 0x2b6e),
+    (0x8abb, 0x2b67),
+    (0x8abc, 0x1195),
+    (0x8abd, 0x2b6f),
+    (0x8abe, 0x2b69),
+    (0x8abf, 0x119f),
+    (0x8ac0, 0x2b6a),
+    (0x8ac2, 0x119e),
+    (0x8ac3, 0x2b6d),
+    (0x8ac4, 0x1198),
+    (0x8ac5, 0x2b6b),
+    (0x8ac6, 0x2b61),
+    (0x8ac7, 0x1197),
+    (0x8ac8, 0x2daa),
+    (0x8ac9, 0x119d),
+    (0x8acb, 0x119a),
+    (0x8acd, 0x11a2),
+    (0x8acf, 0x2b60),
+    (0x8ad1, 0x2b64),
+    (0x8ad2, 0x1196),
+    (0x8ad3, 0x2b63),
+    (0x8ad4, 0x2b65),
+    (0x8ad5, 0x2b66),
+    (0x8ad6, 0x11a1),
+    (0x8ad7, 0x2b68),
+    (0x8ad8, 0x2b6c),
+    (0x8ad9, 0x2b70),
+    (0x8adb, 0x11a5),
+    (0x8adc, 0x12d6),
+    (0x8add, 0x2da5),
+    (0x8ade, 0x2dab),
+    (0x8adf, 0x2da8),
+    (0x8ae0, 0x2da0),
+    (0x8ae1, 0x2dac),
+    (0x8ae2, 0x2da1),
+    (0x8ae4, 0x2da7),
+    (0x8ae6, 0x12d1),
+    (0x8ae7, 0x12d7),
+    (0x8ae8, 0x2dad),
+    (0x8aeb, 0x12d3),
+    (0x8aed, 0x12dd),
+    (0x8aee, 0x12d8),
+    (0x8aef, 0x2daf),
+    (0x8af0, 0x2da9),
+    (0x8af1, 0x12d4),
+    (0x8af2, 0x2da2),
+    (0x8af3, 0x12de),
+    (0x8af4, 0x2da3),
+    (0x8af5, 0x2da4),
+    (0x8af6, 0x12df),
+    (0x8af7, 0x12dc),
+    (0x8af8, 0x119b),
+    (0x8afa, 0x12d2),
+    (0x8afb, 0x2db0),
+    (0x8afc, 0x12e0),
+    (0x8afe, 0x12d9),
+    (0x8aff, 0x2dae),
+    (0x8b00, 0x12d5),
+    (0x8b01, 0x12da),
+    (0x8b02, 0x12db),
+    (0x8b04, 0x1401),
+    (0x8b05, 0x2f7b),
+    (0x8b06, 0x2f84),
+    (0x8b07, 0x2f81),
+    (0x8b08, 0x2f83),
+    (0x8b0a, 0x13fe),
+    (0x8b0b, 0x2f7c),
+    (0x8b0d, 0x2f82),
+    (0x8b0e, 0x13fa),
+    (0x8b0f, 0x2f7e),
+    (0x8b10, 0x1402),
+    (0x8b11, 0x2f7a),
+    (0x8b12, 0x2f7f),
+    (0x8b13, 0x2f86),
+    (0x8b14, 0x2da6),
+    (0x8b15, 0x2f80),
+    (0x8b16, 0x2f79),
+    (0x8b17, 0x13fb),
+    (0x8b18, 0x2f78),
+    (0x8b19, 0x13fc),
+    (0x8b1a, 0x2f87),
+    (0x8b1b, 0x13fd),
+    (0x8b1c, 0x2f85),
+    (0x8b1d, 0x1400),
+    (0x8b1e, 0x2f77),
+    (0x8b20, 0x13ff),
+    (0x8b22, 0x2f7d),
+    (0x8b23, 0x30ed),
+    (0x8b24, 0x30fb),
+    (0x8b25, 0x30f6),
+    (0x8b26, 0x30f8),
+    (0x8b27, 0x30ec),
+    (0x8b28, 0x14ae),
+    (0x8b2a, 0x30eb),
+    (0x8b2b, 0x14b1),
+    (0x8b2c, 0x14b0),
+    (0x8b2e, 0x30fa),
+    (0x8b2f, 0x30f2),
+    (0x8b30, 0x30ef),
+    (0x8b31, 0x30f5),
+    (0x8b33, 0x30ee),
+    (0x8b35, 0x30f0),
+    (0x8b36, 0x30f9),
+    (0x8b37, 0x30f7),
+    (0x8b39, 0x14af),
+    (0x8b3a, 0x30fe),
+    (0x8b3b, 0x30fc),
+    (0x8b3c, 0x30f3),
+    (0x8b3d, 0x30fd),
+    (0x8b3e, 0x30f4),
+    (0x8b40, 0x3244),
+    (0x8b41, 0x1547),
+    (0x8b42, 0x324b),
+    (0x8b45, 0x3430),
+    (0x8b46, 0x154e),
+    (0x8b47, 0x30f1),
+    (0x8b48, 0x3242),
+    (0x8b49, 0x154a),
+    (0x8b4a, 0x3243),
+    (0x8b4b, 0x3248),
+    (0x8b4e, 0x154c),
+    (0x8b4f, 0x154d),
+    (0x8b50, 0x3241),
+    (0x8b51, 0x324a),
+    (0x8b52, 0x324c),
+    (0x8b53, 0x3245),
+    (0x8b54, 0x3247),
+    (0x8b55, 0x3249),
+    (0x8b56, 0x3246),
+    (0x8b57, 0x324d),
+    (0x8b58, 0x1549),
+    (0x8b59, 0x154f),
+    (0x8b5a, 0x154b),
+    (0x8b5c, 0x1548),
+    (0x8b5d, 0x3355),
+    (0x8b5f, 0x15cc),
+    (0x8b60, 0x3353),
+    (0x8b63, 0x3357),
+    (0x8b65, 0x3358),
+    (0x8b66, 0x15ca),
+    (0x8b67, 0x3359),
+    (0x8b68, 0x3356),
+    (0x8b6a, 0x3354),
+    (0x8b6b, 0x15cd),
+    (0x8b6c, 0x15c9),
+    (0x8b6d, 0x335a),
+    (0x8b6f, 0x15cb),
+    (0x8b70, 0x15c8),
+    (0x8b74, 0x1613),
+    (0x8b77, 0x1614),
+    (0x8b78, 0x342f),
+    (0x8b79, 0x342e),
+    (0x8b7a, 0x3431),
+    (0x8b7b, 0x3432),
+    (0x8b7d, 0x1615),
+    (0x8b7e, 0x34d4),
+    (0x8b7f, 0x34d9),
+    (0x8b80, 0x165c),
+    (0x8b82, 0x34d6),
+    (0x8b84, 0x34d5),
+    (0x8b85, 0x34d8),
+    (0x8b86, 0x34d7),
+    (0x8b88, 0x355e),
+    (0x8b8a, 0x168e),
+    (0x8b8b, 0x355d),
+    (0x8b8c, 0x355b),
+    (0x8b8e, 0x355c),
+    (0x8b92, 0x16b0),
+    (0x8b93, 0x16af),
+    (0x8b94, 0x35ba),
+    (0x8b95, 0x35bb),
+    (0x8b96, 0x16b1),
+    (0x8b98, 0x3605),
+    (0x8b99, 0x3606),
+    (0x8b9a, 0x16d8),
+    (0x8b9c, 0x16de),
+    (0x8b9e, 0x363f),
+    (0x8b9f, 0x365e),
+    (0x8c37, 0x048f),
+    (0x8c39, 0x2189),
+    (0x8c3b, 0x218a),
+    (0x8c3c, 0x26b5),
+    (0x8c3d, 0x2927),
+    (0x8c3e, 0x2b71),
+    (0x8c3f, 0x1404),
+    (0x8c41, 0x1403),
+    (0x8c42, 0x30ff),
+    (0x8c43, 0x324e),
+    (0x8c45, 0x355f),
+    (0x8c46, 0x0490),
+    (0x8c47, 0x1f26),
+    (0x8c48, 0x094d),
+    (0x8c49, 0x0b2b),
+    (0x8c4a, 0x26b7),
+    (0x8c4b, 0x26b6),
+    (0x8c4c, 0x11a6),
+    (0x8c4d, 0x2b72),
+    (0x8c4e, 0x11a7),
+    (0x8c4f, 0x2f88),
+    (0x8c50, 0x14b2),
+    (0x8c54, 0x16e7),
+    (0x8c55, 0x0491),
+    (0x8c56, 0x1b65),
+    (0x8c57, 0x1f27),
+    (0x8c5a, 0x0b2c),
+    (0x8c5c, 0x218b),
+    (0x8c5d, 0x218c),
+    (0x8c5f, 0x2415),
+    (0x8c61, 0x0ce9),
+    (0x8c62, 0x0e9f),
+    (0x8c64, 0x26b9),
+    (0x8c65, 0x26b8),
+    (0x8c66, 0x26ba),
+    (0x8c68, 0x2928),
+    (0x8c69, 0x2929),
+    (0x8c6a, 0x1038),
+    (0x8c6b, 0x12e1),
+    (0x8c6c, 0x11a8),
+    (0x8c6d, 0x12e2),
+    (0x8c6f, 0x2f8c),
+    (0x8c70, 0x2f89),
+    (0x8c71, 0x2f8b),
+    (0x8c72, 0x2f8a),
+    (0x8c73, 0x1405),
+    (0x8c75, 0x3100),
+    (0x8c76, 0x3250),
+    (0x8c77, 0x324f),
+    (0x8c78, 0x1a04),
+    (0x8c79, 0x094f),
+    (0x8c7a, 0x094e),
+    (0x8c7b, 0x1f28),
+    (0x8c7d, 0x218d),
+    (0x8c80, 0x2417),
+    (0x8c81, 0x2416),
+    (0x8c82, 0x0cea),
+    (0x8c84, 0x26bc),
+    (0x8c85, 0x26bd),
+    (0x8c86, 0x26bb),
+    (0x8c89, 0x0ea1),
+    (0x8c8a, 0x0ea0),
+    (0x8c8c, 0x103a),
+    (0x8c8d, 0x1039),
+    (0x8c8f, 0x2b73),
+    (0x8c90, 0x2db3),
+    (0x8c91, 0x2db1),
+    (0x8c92, 0x2db2),
+    (0x8c93, 0x12e3),
+    (0x8c94, 0x2f8e),
+    (0x8c95, 0x2f8d),
+    (0x8c97, 0x3103),
+    (0x8c98, 0x3102),
+    (0x8c99, 0x3101),
+    (0x8c9a, 0x3251),
+    (0x8c9c, 0x3640),
+    (0x8c9d, 0x0492),
+    (0x8c9e, 0x078f),
+    (0x8ca0, 0x0790),
+    (0x8ca1, 0x0950),
+    (0x8ca2, 0x0951),
+    (0x8ca3, 0x1f2a),
+    (0x8ca4, 0x1f29),
+    (0x8ca5, 0x218e),
+    (0x8ca7, 0x0b32),
+    (0x8ca8, 0x0b30),
+    (0x8ca9, 0x0b2d),
+    (0x8caa, 0x0b31),
+    (0x8cab, 0x0b2f),
+    (0x8cac, 0x0b2e),
+    (0x8caf, 0x0ceb),
+    (0x8cb0, 0x241a),
+    (0x8cb2, 0x0ea6),
+    (0x8cb3, 0x0ced),
+    (0x8cb4, 0x0cf2),
+    (0x8cb5, 0x241c),
+    (0x8cb6, 0x0cf4),
+    (0x8cb7, 0x0cf3),
+    (0x8cb8, 0x0cf6),
+    (0x8cb9, 0x241b),
+    (0x8cba, 0x2418),
+    (0x8cbb, 0x0cf0),
+    (0x8cbc, 0x0cec),
+    (0x8cbd, 0x0cee),
+    (0x8cbe, 0x2419),
+    (0x8cbf, 0x0cf5),
+    (0x8cc0, 0x0cf1),
+    (0x8cc1, 0x0cef),
+    (0x8cc2, 0x0ea8),
+    (0x8cc3, 0x0ea7),
+    (0x8cc4, 0x0ea5),
+    (0x8cc5, 0x0ea9),
+    (0x8cc7, 0x0ea3),
+    (0x8cc8, 0x0ea4),
+    (0x8cca, 0x0ea2),
+    (0x8ccc, 0x26be),
+    (0x8ccf, 0x292b),
+    (0x8cd1, 0x103c),
+    (0x8cd2, 0x103d),
+    (0x8cd3, 0x103b),
+    (0x8cd5, 0x292a),
+    (0x8cd7, 0x292c),
+    (0x8cd9, 0x2b76),
+    (0x8cda, 0x2b78),
+    (0x8cdc, 0x11b1),
+    (0x8cdd, 0x2b79),
+    (0x8cde, 0x11aa),
+    (0x8cdf, 0x2b75),
+    (0x8ce0, 0x11a9),
+    (0x8ce1, 0x11b3),
+    (0x8ce2, 0x11af),
+    (0x8ce3, 0x11b0),
+    (0x8ce4, 0x11ac),
+    (0x8ce5, 0x2b74),
+    (0x8ce6, 0x11ab),
+    (0x8ce7, 0x2b7a),
+    (0x8ce8, 0x2b77),
+    (0x8cea, 0x11b2),
+    (0x8cec, 0x11ad),
+    (0x8ced, 0x11ae),
+    (0x8cee, 0x2db5),
+    (0x8cf0, 0x2db7),
+    (0x8cf1, 0x2db6),
+    (0x8cf3, 0x2db8),
+    (0x8cf4, 0x12e4),
+    (0x8cf5, 0x2db4),
+    (0x8cf8, 0x1409),
+    (0x8cf9, 0x2f8f),
+    (0x8cfa, 0x1406),
+    (0x8cfb, 0x140a),
+    (0x8cfc, 0x1408),
+    (0x8cfd, 0x1407),
+    (0x8cfe, 0x3104),
+    (0x8d00, 0x3107),
+    (0x8d02, 0x3106),
+    (0x8d04, 0x3105),
+    (0x8d05, 0x14b3),
+    (0x8d06, 0x3252),
+    (0x8d07, 0x3253),
+    (0x8d08, 0x1550),
+    (0x8d09, 0x3254),
+    (0x8d0a, 0x1551),
+    (0x8d0d, 0x15cf),
+    (0x8d0f, 0x15ce),
+    (0x8d10, 0x3433),
+    (0x8d13, 0x1616),
+    (0x8d14, 0x3434),
+    (0x8d15, 0x34da),
+    (0x8d16, 0x165d),
+    (0x8d17, 0x165e),
+    (0x8d19, 0x3560),
+    (0x8d1b, 0x16b3),
+    (0x8d64, 0x0493),
+    (0x8d66, 0x0b34),
+    (0x8d67, 0x0b33),
+    (0x8d68, 0x26bf),
+    (0x8d69, 0x26c0),
+    (0x8d6b, 0x103e),
+    (0x8d6c, 0x2db9),
+    (0x8d6d, 0x11b4),
+    (0x8d6e, 0x2dba),
+    (0x8d6f, 0x2f90),
+    (0x8d70, 0x0494),
+    (0x8d72, 0x1d23),
+    (0x8d73, 0x0792),
+    (0x8d74, 0x0791),
+    (0x8d76, 0x1f2b),
+    (0x8d77, 0x0952),
+    (0x8d78, 0x1f2c),
+    (0x8d79, 0x2191),
+    (0x8d7b, 0x2190),
+    (0x8d7d, 0x218f),
+    (0x8d80, 0x241e),
+    (0x8d81, 0x0cf9),
+    (0x8d84, 0x241d),
+    (0x8d85, 0x0cf8),
+    (0x8d89, 0x241f),
+    (0x8d8a, 0x0cf7),
+    (0x8d8c, 0x26c2),
+    (0x8d8d, 0x26c5),
+    (0x8d8e, 0x26c3),
+    (0x8d8f, 0x26c4),
+    (0x8d90, 0x26c8),
+    (0x8d91, 0x26c1),
+    (0x8d92, 0x26c9),
+    (0x8d93, 0x26c6),
+    (0x8d94, 0x26c7),
+    (0x8d95, 0x1040),
+    (0x8d96, 0x292d),
+    (0x8d99, 0x103f),
+    (0x8d9b, 0x2b7e),
+    (0x8d9c, 0x2b7c),
+    (0x8d9f, 0x11b5),
+    (0x8da0, 0x2b7b),
+    (0x8da1, 0x2b7d),
+    (0x8da3, 0x11b6),
+    (0x8da5, 0x2dbb),
+    (0x8da7, 0x2dbc),
+    (0x8da8, 0x140b),
+    (0x8daa, 0x3256),
+    (0x8dab, 0x3258),
+    (0x8dac, 0x3255),
+    (0x8dad, 0x3257),
+    (0x8dae, 0x335b),
+    (0x8daf, 0x3435),
+    (0x8db2, 0x3627),
+    (0x8db3, 0x0495),
+    (0x8db4, 0x0793),
+    (0x8db5, 0x1f2d),
+    (0x8db6, 0x1f2f),
+    (0x8db7, 0x1f2e),
+    (0x8db9, 0x2194),
+    (0x8dba, 0x0b36),
+    (0x8dbc, 0x2192),
+    (0x8dbe, 0x0b35),
+    (0x8dbf, 0x2195),
+    (0x8dc1, 0x2196),
+    (0x8dc2, 0x2193),
+    (0x8dc5, 0x242b),
+    (0x8dc6, 0x0d01),
+    (0x8dc7, 0x2423),
+    (0x8dc8, 0x2429),
+    (0x8dcb, 0x0cfc),
+    (0x8dcc, 0x0cff),
+    (0x8dcd, 0x2422),
+    (0x8dce, 0x0cfa),
+    (0x8dcf, 0x2426),
+    (0x8dd0, 0x26cf),
+    (0x8dd1, 0x0cfe),
+    (0x8dd3, 0x2421),
+    (0x8dd5, 0x2427),
+    (0x8dd6, 0x2424),
+    (0x8dd7, 0x242a),
+    (0x8dd8, 0x2420),
+    (0x8dd9, 0x2428),
+    (0x8dda, 0x0cfd),
+    (0x8ddb, 0x0d00),
+    (0x8ddc, 0x2425),
+    (0x8ddd, 0x0cfb),
+    (0x8ddf, 0x0eab),
+    (0x8de0, 0x26cb),
+    (0x8de1, 0x0eaa),
+    (0x8de2, 0x26d2),
+    (0x8de3, 0x26d1),
+    (0x8de4, 0x0eb1),
+    (0x8de6, 0x0eb2),
+    (0x8de7, 0x26d3),
+    (0x8de8, 0x0eac),
+    (0x8de9, 0x26d0),
+    (0x8dea, 0x0eb0),
+    (0x8deb, 0x26d5),
+    (0x8dec, 0x26cc),
+    (0x8dee, 0x26ce),
+    (0x8def, 0x0ead),
+    (0x8df0, 0x26ca),
+    (0x8df1, 0x26cd),
+    (0x8df2, 0x26d4),
+    (0x8df3, 0x0eae),
+    (0x8df4, 0x26d6),
+    (0x8dfa, 0x0eaf),
+    (0x8dfc, 0x1041),
+    (0x8dfd, 0x2932),
+    (0x8dfe, 0x2938),
+    (0x8dff, 0x2930),
+    (0x8e00, 0x2939),
+    (0x8e02, 0x292f),
+    (0x8e03, 0x2934),
+    (0x8e04, 0x293a),
+    (0x8e05, 0x2937),
+    (0x8e06, 0x2936),
+    (0x8e07, 0x2935),
+    (0x8e09, 0x292e),
+    (0x8e0a, 0x2933),
+    (0x8e0d, 0x2931),
+    (0x8e0f, 0x11bb),
+    (0x8e10, 0x11b8),
+    (0x8e11, 0x2b87),
+    (0x8e12, 0x2b8c),
+    (0x8e13, 0x2b8e),
+    (0x8e14, 0x2b8b),
+    (0x8e15, 0x2b84),
+    (0x8e16, 0x2b86),
+    (0x8e17, 0x2b90),
+    (0x8e18, 0x2b8d),
+    (0x8e19, 0x2b88),
+    (0x8e1a, 0x2b91),
+    (0x8e1b, 0x2b85),
+    (0x8e1c, 0x2b8f),
+    (0x8e1d, 0x11b9),
+    (0x8e1e, 0x11bf),
+    (0x8e1f, 0x11bd),
+    (0x8e20, 0x2b7f),
+    (0x8e21, 0x11be),
+    (0x8e22, 0x11ba),
+    (0x8e23, 0x2b80),
+    (0x8e24, 0x2b82),
+    (0x8e25, 0x2b81),
+    (0x8e26, 0x2b89),
+    (0x8e27, 0x2b8a),
+    (0x8e29, 0x11bc),
+    (0x8e2b, 0x11b7),
+    (0x8e2e, 0x2b83),
+    (0x8e30, 0x2dc6),
+    (0x8e31, 0x12e6),
+    (0x8e33, 0x2dbd),
+    (0x8e34, 0x12e7),
+    (0x8e35, 0x12ea),
+    (0x8e36, 0x2dc2),
+    (0x8e38, 0x2dbf),
+    (0x8e39, 0x12e9),
+    (0x8e3c, 0x2dc3),
+    (0x8e3d, 0x2dc4),
+    (0x8e3e, 0x2dbe),
+    (0x8e3f, 0x2dc7),
+    (0x8e40, 0x2dc0),
+    (0x8e41, 0x2dc5),
+    (0x8e42, 0x12e8),
+    (0x8e44, 0x12e5),
+    (0x8e45, 0x2dc1),
+    (0x8e47, 0x2f96),
+    (0x8e48, 0x140e),
+    (0x8e49, 0x140c),
+    (0x8e4a, 0x140f),
+    (0x8e4b, 0x140d),
+    (0x8e4c, 0x2f95),
+    (0x8e4d, 0x2f92),
+    (0x8e4e, 0x2f91),
+    (0x8e50, 0x2f94),
+    (0x8e53, 0x2f93),
+    (0x8e54, 0x3115),
+    (0x8e55, 0x14b9),
+    (0x8e56, 0x310c),
+    (0x8e57, 0x310b),
+    (0x8e59, 0x14b4),
+    (0x8e5a, 0x3111),
+    (0x8e5b, 0x3110),
+    (0x8e5c, 0x3108),
+    (0x8e5d, 0x3113),
+    (0x8e5e, 0x310d),
+    (0x8e5f, 0x14b8),
+    (0x8e60, 0x310a),
+    (0x8e61, 0x3112),
+    (0x8e62, 0x3109),
+    (0x8e63, 0x14b5),
+    (0x8e64, 0x14b7),
+    (0x8e65, 0x310e),
+    (0x8e66, 0x14b6),
+    (0x8e67, 0x310f),
+    (0x8e69, 0x3114),
+    (0x8e6a, 0x325c),
+    (0x8e6c, 0x1556),
+    (0x8e6d, 0x3259),
+    (0x8e6f, 0x325d),
+    (0x8e72, 0x1553),
+    (0x8e73, 0x325b),
+    (0x8e74, 0x1558),
+    (0x8e76, 0x1555),
+    (0x8e78, 0x325a),
+    (0x8e7a, 0x1557),
+    (0x8e7b, 0x325e),
+    (0x8e7c, 0x1552),
+    (0x8e81, 0x15d1),
+    (0x8e82, 0x15d3),
+    (0x8e84, 0x335e),
+    (0x8e85, 0x15d2),
+    (0x8e86, 0x335c),
+    (0x8e87, 0x1554),
+    (0x8e88, 0x335d),
+    (0x8e89, 0x15d0),
+    (0x8e8a, 0x1617),
+    (0x8e8b, 0x1619),
+    (0x8e8c, 0x3437),
+    (0x8e8d, 0x1618),
+    (0x8e8e, 0x3436),
+    (0x8e90, 0x34df),
+    (0x8e91, 0x165f),
+    (0x8e92, 0x34de),
+    (0x8e93, 0x1660),
+    (0x8e94, 0x34dc),
+    (0x8e95, 0x34db),
+    (0x8e96, 0x34e0),
+    (0x8e97, 0x34e1),
+    (0x8e98, 0x3561),
+    (0x8e9a, 0x34dd),
+    (0x8e9d, 0x35bf),
+    (0x8e9e, 0x35bc),
+    (0x8e9f, 0x35bd),
+    (0x8ea0, 0x35be),
+    (0x8ea1, 0x16cd),
+    (0x8ea3, 0x3609),
+    (0x8ea4, 0x3608),
+    (0x8ea5, 0x3607),
+    (0x8ea6, 0x3628),
+    (0x8ea8, 0x3654),
+    (0x8ea9, 0x3641),
+    (0x8eaa, 0x16df),
+    (0x8eab, 0x0496),
+    (0x8eac, 0x0953),
+    (0x8eb2, 0x0eb3),
+    (0x8eba, 0x11c0),
+    (0x8ebd, 0x2dc8),
+    (0x8ec0, 0x14ba),
+    (0x8ec2, 0x325f),
+    (0x8ec9, 0x3642),
+    (0x8eca, 0x0497),
+    (0x8ecb, 0x0611),
+    (0x8ecc, 0x0795),
+    (0x8ecd, 0x0794),
+    (0x8ecf, 0x0956),
+    (0x8ed1, 0x1f30),
+    (0x8ed2, 0x0954),
+    (0x8ed3, 0x1f31),
+    (0x8ed4, 0x0955),
+    (0x8ed7, 0x219b),
+    (0x8ed8, 0x2197),
+    (0x8edb, 0x0b37),
+    (0x8edc, 0x219a),
+    (0x8edd, 0x2199),
+    (0x8ede, 0x2198),
+    (0x8edf, 0x0b38),
+    (0x8ee0, 0x219c),
+    (0x8ee1, 0x219d),
+    (0x8ee5, 0x2432),
+    (0x8ee6, 0x2430),
+    (0x8ee7, 0x2434),
+    (0x8ee8, 0x2435),
+    (0x8ee9, 0x243b),
+    (0x8eeb, 0x2437),
+    (0x8eec, 0x2439),
+    (0x8eee, 0x2431),
+    (0x8eef, 0x242c),
+    (0x8ef1, 0x2438),
+    (0x8ef4, 0x243a),
+    (0x8ef5, 0x2433),
+    (0x8ef6, 0x2436),
+    (0x8ef7, 0x242d),
+    (0x8ef8, 0x0d03),
+    (0x8ef9, 0x242f),
+    (0x8efa, 0x242e),
+    (0x8efb, 0x0d02),
+    (0x8efc, 0x0d04),
+    (0x8efe, 0x0eb6),
+    (0x8eff, 0x26d8),
+    (0x8f00, 0x26da),
+    (0x8f01, 0x26d9),
+    (0x8f02, 0x26de),
+    (0x8f03, 0x0eb4),
+    (0x8f05, 0x26db),
+    (0x8f06, 0x26d7),
+    (0x8f07, 0x26dc),
+    (0x8f08, 0x26dd),
+    (0x8f09, 0x0eb5),
+    (0x8f0a, 0x0eb7),
+    (0x8f0b, 0x26df),
+    (0x8f0d, 0x293e),
+    (0x8f0e, 0x293d),
+    (0x8f10, 0x293b),
+    (0x8f11, 0x293c),
+    (0x8f12, 0x1043),
+    (0x8f13, 0x1045),
+    (0x8f14, 0x1042),
+    (0x8f15, 0x1044),
+    (0x8f16, 0x2b98),
+    (0x8f17, 0x2b99),
+    (0x8f18, 0x2b94),
+    (0x8f1a, 0x2b95),
+    (0x8f1b, 0x11c2),
+    (0x8f1c, 0x11c7),
+    (0x8f1d, 0x11c1),
+    (0x8f1e, 0x11c8),
+    (0x8f1f, 0x11c3),
+    (0x8f20, 0x2b96),
+    (0x8f23, 0x2b97),
+    (0x8f24, 0x2b93),
+    (0x8f25, 0x11c9),
+    (0x8f26, 0x11c5),
+    (0x8f29, 0x11c4),
+    (0x8f2a, 0x11c6),
+    (0x8f2c, 0x2b92),
+    (0x8f2e, 0x2dca),
+    (0x8f2f, 0x12ec),
+    (0x8f32, 0x2dcc),
+    (0x8f33, 0x12ee),
+    (0x8f34, 0x2dcf),
+    (0x8f35, 0x2dcb),
+    (0x8f36, 0x2dc9),
+    (0x8f37, 0x2dce),
+    (0x8f38, 0x12ed),
+    (0x8f39, 0x2dcd),
+    (0x8f3b, 0x12eb),
+    (0x8f3e, 0x1411),
+    (0x8f3f, 0x1414),
+    (0x8f40, 0x2f98),
+    (0x8f42, 0x1412),
+    (0x8f43, 0x2f97),
+    (0x8f44, 0x1410),
+    (0x8f45, 0x1413),
+    (0x8f46, 0x3116),
+    (0x8f47, 0x3117),
+    (0x8f48, 0x3118),
+    (0x8f49, 0x14bb),
+    (0x8f4b, 0x3119),
+    (0x8f4d, 0x14bc),
+    (0x8f4e, 0x155a),
+    (0x8f4f, 0x3262),
+    (0x8f50, 0x3263),
+    (0x8f51, 0x3261),
+    (0x8f52, 0x3260),
+    (0x8f53, 0x3264),
+    (0x8f54, 0x1559),
+    (0x8f55, 0x3362),
+    (0x8f56, 0x3360),
+    (0x8f57, 0x3361),
+    (0x8f58, 0x3363),
+    (0x8f59, 0x335f),
+    (0x8f5a, 0x3364),
+    (0x8f5b, 0x3439),
+    (0x8f5d, 0x343a),
+    (0x8f5e, 0x3438),
+    (0x8f5f, 0x161a),
+    (0x8f60, 0x34e2),
+    (0x8f61, 0x1661),
+    (0x8f62, 0x34e3),
+    (0x8f63, 0x3563),
+    (0x8f64, 0x3562),
+    (0x8f9b, 0x0498),
+    (0x8f9c, 0x0d05),
+    (0x8f9f, 0x0eb8),
+    (0x8fa3, 0x1046),
+    (0x8fa6, 0x12f0),
+    (0x8fa8, 0x12ef),
+    (0x8fad, 0x155b),
+    (0x8fae, 0x15b3),
+    (0x8faf, 0x161b),
+    (0x8fb0, 0x0499),
+    (0x8fb1, 0x0957),
+    (0x8fb2, 0x0eb9),
+    (0x8fb4, 0x3265),
+    (0x8fbf, 0x1a06),
+    (0x8fc2, 0x049a),
+    (0x8fc4, 0x049d),
+    (0x8fc5, 0x049c),
+    (0x8fc6, 0x049b),
+    (0x8fc9, 0x1a05),
+    (0x8fcb, 0x1b67),
+    (0x8fcd, 0x1b69),
+    (0x8fce, 0x0612),
+    (0x8fd1, 0x0614),
+    (0x8fd2, 0x1b66),
+    (0x8fd3, 0x1b68),
+    (0x8fd4, 0x0613),
+    (0x8fd5, 0x1b6b),
+    (0x8fd6, 0x1b6a),
+    (0x8fd7, 0x1b6c),
+    (0x8fe0, 0x1d27),
+    (0x8fe1, 0x1d25),
+    (0x8fe2, 0x0798),
+    (0x8fe3, 0x1d24),
+    (0x8fe4, 0x079d),
+    (0x8fe5, 0x079a),
+    (0x8fe6, 0x0797),
+    (0x8fe8, 0x079e),
+    (0x8fea, 0x0799),
+    (0x8feb, 0x079c),
+    (0x8fed, 0x079b),
+    (0x8fee, 0x1d26),
+    (0x8ff0, 0x0796),
+    (0x8ff4, 0x095d),
+    (0x8ff5, 0x1f33),
+    (0x8ff6, 0x1f39),
+    (0x8ff7, 0x095a),
+    (0x8ff8, 0x0961),
+    (0x8ffa, 0x095c),
+    (0x8ffb, 0x1f36),
+    (0x8ffc, 0x1f38),
+    (0x8ffd, 0x095f),
+    (0x8ffe, 0x1f32),
+    (0x8fff, 0x1f35),
+    (0x9000, 0x095b),
+    (0x9001, 0x0958),
+    (0x9002, 0x1f34),
+    (0x9003, 0x095e),
+    (0x9004, 0x1f37),
+    (0x9005, 0x0960),
+    (0x9006, 0x0959),
+    (0x900b, 0x219f),
+    (0x900c, 0x21a2),
+    (0x900d, 0x0b3a),
+    (0x900f, 0x0b44),
+    (0x9010, 0x0b40),
+    (0x9011, 0x21a0),
+    (0x9014, 0x0b48),
+    (0x9015, 0x0b41),
+    (0x9016, 0x0b46),
+    (0x9017, 0x0b3c),
+    (0x9019, 0x0b39),
+    (0x901a, 0x0b3b),
+    (0x901b, 0x0b47),
+    (0x901c, 0x21a1),
+    (0x901d, 0x0b3f),
+    (0x901e, 0x0b42),
+    (0x901f, 0x0b3e),
+    (0x9020, 0x0b43),
+    (0x9021, 0x21a3),
+    (0x9022, 0x0b45),
+    (0x9023, 0x0b3d),
+    (0x9024, 0x219e),
+    (0x902d, 0x243c),
+    (0x902e, 0x0d06),
+    (0x902f, 0x243e),
+    (0x9031, 0x0d08),
+    (0x9032, 0x0d0a),
+    (0x9034, 0x243d),
+    (0x9035, 0x0d07),
+    (0x9036, 0x0d0b),
+    (0x9038, 0x0d09),
+    (0x903c, 0x0ebf),
+    (0x903d, 0x26e4),
+    (0x903e, 0x0ec7),
+    (0x903f, 0x26e1),
+    (0x9041, 0x0ec8),
+    (0x9042, 0x0ebd),
+    (0x9044, 0x26e2),
+    (0x9047, 0x0ec2),
+    (0x9049, 0x26e3),
+    (0x904a, 0x0ebb),
+    (0x904b, 0x0eba),
+    (0x904d, 0x0ec5),
+    (0x904e, 0x0ec4),
+    (0x904f, 0x0ec3),
+    (0x9050, 0x0ec1),
+    (0x9051, 0x0ec6),
+    (0x9052, 0x26e0),
+    (0x9053, 0x0ebc),
+    (0x9054, 0x0ebe),
+    (0x9055, 0x0ec0),
+    (0x9058, 0x1048),
+    (0x9059, 0x104b),
+    (0x905b, 0x104f),
+    (0x905c, 0x1049),
+    (0x905d, 0x104e),
+    (0x905e, 0x104c),
+    (0x9060, 0x1047),
+    (0x9062, 0x104d),
+    (0x9063, 0x104a),
+    (0x9067, 0x2b9d),
+    (0x9068, 0x11cc),
+    (0x9069, 0x11ca),
+    (0x906b, 0x2b9e),
+    (0x906d, 0x11cd),
+    (0x906e, 0x11cb),
+    (0x906f, 0x2b9c),
+    (0x9070, 0x2b9b),
+    (0x9072, 0x12f4),
+    (0x9073, 0x2b9a),
+    (0x9074, 0x12f2),
+    (0x9075, 0x12f1),
+    (0x9076, 0x2dd0),
+    (0x9077, 0x11ce),
+    (0x9078, 0x12f3),
+    (0x9079, 0x2dd1),
+    (0x907a, 0x12f6),
+    (0x907b, 0x2dd2),
+    (0x907c, 0x12f5),
+    (0x907d, 0x1416),
+    (0x907e, 0x2f9a),
+    (0x907f, 0x1415),
+    (0x9080, 0x141a),
+    (0x9081, 0x1418),
+    (0x9082, 0x1419),
+    (0x9083, 0x14be),
+    (0x9084, 0x1417),
+    (0x9085, 0x2f99),
+    (0x9086, 0x2dd3),
+    (0x9087, 0x14bd),
+    (0x9088, 0x14bf),
+    (0x908a, 0x155c),
+    (0x908b, 0x155d),
+    (0x908d, 0x3365),
+    (0x908f, 0x1690),
+    (0x9090, 0x168f),
+    (0x9091, 0x049f),
+    (0x9094, 0x193a),
+    (0x9095, 0x0962),
+    (0x9097, 0x1937),
+    (0x9098, 0x1938),
+    (0x9099, 0x1936),
+    (0x909b, 0x1939),
+    (0x909e, 0x1a0a),
+    (0x909f, 0x1a07),
+    (0x90a0, 0x1a0c),
+    (0x90a1, 0x1a08),
+    (0x90a2, 0x04a0),
+    (0x90a3, 0x04a3),
+    (0x90a5, 0x1a09),
+    (0x90a6, 0x04a2),
+    (0x90a7, 0x1a0b),
+    (0x90aa, 0x04a1),
+    (0x90af, 0x1b6f),
+    (0x90b0, 0x1b71),
+    (0x90b1, 0x0617),
+    (0x90b2, 0x1b6d),
+    (0x90b3, 0x1b70),
+    (0x90b4, 0x1b6e),
+    (0x90b5, 0x0615),
+    (0x90b6, 0x0618),
+    (0x90b8, 0x0616),
+    (0x90bd, 0x1d29),
+    (0x90be, 0x1d2d),
+    (0x90bf, 0x1d2a),
+    (0x90c1, 0x07a1),
+    (0x90c3, 0x07a2),
+    (0x90c5, 0x1d2c),
+    (0x90c7, 0x1d2e),
+    (0x90c8, 0x1d30),
+    (0x90ca, 0x079f),
+    (0x90cb, 0x1d2f),
+    (0x90ce, 0x07a0),
+    (0x90d4, 0x21aa),
+    (0x90d5, 0x1d2b),
+    (0x90d6, 0x1f3a),
+    (0x90d7, 0x1f43),
+    (0x90d8, 0x1f41),
+    (0x90d9, 0x1f3c),
+    (0x90da, 0x1f3d),
+    (0x90db, 0x1f42),
+    (0x90dc, 0x1f44),
+    (0x90dd, 0x0964),
+    (0x90df, 0x1f3f),
+    (0x90e0, 0x1f3b),
+    (0x90e1, 0x0963),
+    (0x90e2, 0x0965),
+    (0x90e3, 0x1f3e),
+    (0x90e4, 0x1f45),
+    (0x90e5, 0x1f40),
+    (0x90e8, 0x0b49),
+    (0x90e9, 0x21ad),
+    (0x90ea, 0x21a5),
+    (0x90eb, 0x21ab),
+    (0x90ec, 0x21ac),
+    (0x90ed, 0x0b4a),
+    (0x90ef, 0x21a4),
+    (0x90f0, 0x21a6),
+    (0x90f1, 0x1d28),
+    (0x90f2, 0x21a8),
+    (0x90f3, 0x21a9),
+    (0x90f4, 0x21a7),
+    (0x90f5, 0x0d0d),
+    (0x90f9, 0x2445),
+    (0x90fa, 0x2dd4),
+    (0x90fb, 0x2446),
+    (0x90fc, 0x2443),
+    (0x90fd, 0x0b4b),
+    (0x90fe, 0x0d0f),
+    (0x90ff, 0x2442),
+    (0x9100, 0x2448),
+    (0x9101, 0x2447),
+    (0x9102, 0x0d0c),
+    (0x9103, 0x244b),
+    (0x9104, 0x2441),
+    (0x9105, 0x244a),
+    (0x9106, 0x243f),
+    (0x9107, 0x2449),
+    (0x9108, 0x2444),
+    (0x9109, 0x0d0e),
+    (0x910b, 0x26eb),
+    (0x910d, 0x26e6),
+    (0x910e, 0x26ec),
+    (0x910f, 0x26e7),
+    (0x9110, 0x26e5),
+    (0x9111, 0x26e8),
+    (0x9112, 0x0ec9),
+    (0x9114, 0x26ea),
+    (0x9116, 0x26e9),
+    (0x9117, 0x0eca),
+    (0x9118, 0x1051),
+    (0x9119, 0x1050),
+    (0x911a, 0x2945),
+    (0x911b, 0x2948),
+    (0x911c, 0x2940),
+    (0x911d, 0x2944),
+    (0x911e, 0x1052),
+    (0x911f, 0x2943),
+    (0x9120, 0x2941),
+    (0x9121, 0x2947),
+    (0x9122, 0x2942),
+    (0x9123, 0x293f),
+    (0x9124, 0x2946),
+    (0x9126, 0x2ba4),
+    (0x9127, 0x11d1),
+    (0x9128, 0x311a),
+    (0x9129, 0x2ba1),
+    (0x912a, 0x2ba2),
+    (0x912b, 0x2ba0),
+    (0x912c, 0x2440),
+    (0x912d, 0x11d0),
+    (0x912e, 0x2ba5),
+    (0x912f, 0x2b9f),
+    (0x9130, 0x11cf),
+    (0x9131, 0x11d2),
+    (0x9132, 0x2ba3),
+    (0x9133, 0x2dd5),
+    (0x9134, 0x12f7),
+    (0x9135, 0x2dd6),
+    (0x9136, 0x2dd7),
+    (0x9138, 0x2f9b),
+    (0x9139, 0x141b),
+    (0x913a, 0x311b),
+    (0x913b, 0x311c),
+    (0x913e, 0x311d),
+    (0x913f, 0x3267),
+    (0x9140, 0x3266),
+    (0x9141, 0x3367),
+    (0x9143, 0x3366),
+    (0x9144, 0x343c),
+    (0x9145, 0x343d),
+    (0x9146, 0x343b),
+    (0x9147, 0x34e4),
+    (0x9148, 0x1662),
+    (0x9149, 0x04a4),
+    (0x914a, 0x07a4),
+    (0x914b, 0x07a3),
+    (0x914c, 0x0968),
+    (0x914d, 0x0967),
+    (0x914e, 0x1f47),
+    (0x914f, 0x1f48),
+    (0x9150, 0x1f46),
+    (0x9152, 0x0966),
+    (0x9153, 0x21b1),
+    (0x9155, 0x21b2),
+    (0x9156, 0x21ae),
+    (0x9157, 0x0b4c),
+    (0x9158, 0x21af),
+    (0x915a, 0x21b0),
+    (0x915f, 0x244e),
+    (0x9160, 0x2450),
+    (0x9161, 0x244c),
+    (0x9162, 0x244f),
+    (0x9163, 0x0d10),
+    (0x9164, 0x244d),
+    (0x9165, 0x0d11),
+    (0x9168, 0x24ff),
+    (0x9169, 0x0ecd),
+    (0x916a, 0x0ecc),
+    (0x916c, 0x0ecb),
+    (0x916e, 0x26ed),
+    (0x916f, 0x26ee),
+    (0x9172, 0x294a),
+    (0x9173, 0x294c),
+    (0x9174, 0x1056),
+    (0x9175, 0x1053),
+    (0x9177, 0x1055),
+    (0x9178, 0x1054),
+    (0x9179, 0x294b),
+    (0x917a, 0x2949),
+    (0x9180, 0x2bac),
+    (0x9181, 0x2ba9),
+    (0x9182, 0x2baa),
+    (0x9183, 0x11d6),
+    (0x9184, 0x2bab),
+    (0x9185, 0x2ba6),
+    (0x9186, 0x2ba7),
+    (0x9187, 0x11d3),
+    (0x9189, 0x11d4),
+    (0x918a, 0x2ba8),
+    (0x918b, 0x11d5),
+    (0x918d, 0x2ddb),
+    (0x918f, 0x2ddc),
+    (0x9190, 0x2dd9),
+    (0x9191, 0x2dda),
+    (0x9192, 0x12f8),
+    (0x9193, 0x2dd8),
+    (0x9199, 0x2f9f),
+    (0x919a, 0x2f9c),
+    (0x919b, 0x2f9e),
+    (0x919c, 0x141e),
+    (0x919d, 0x2fa2),
+    (0x919e, 0x141d),
+    (0x919f, 0x2fa0),
+    (0x91a0, 0x2fa3),
+    (0x91a1, 0x2fa1),
+    (0x91a2, 0x2f9d),
+    (0x91a3, 0x141c),
+    (0x91a5, 0x311f),
+    (0x91a7, 0x3120),
+    (0x91a8, 0x311e),
+    (0x91aa, 0x3122),
+    (0x91ab, 0x14c0),
+    (0x91ac, 0x14c1),
+    (0x91ad, 0x3269),
+    (0x91ae, 0x155f),
+    (0x91af, 0x3121),
+    (0x91b0, 0x3268),
+    (0x91b1, 0x155e),
+    (0x91b2, 0x336a),
+    (0x91b3, 0x336b),
+    (0x91b4, 0x15d4),
+    (0x91b5, 0x3369),
+    (0x91b7, 0x3368),
+    (0x91b9, 0x343e),
+    (0x91ba, 0x161c),
+    (0x91bc, 0x3564),
+    (0x91bd, 0x35c1),
+    (0x91be, 0x35c0),
+    (0x91c0, 0x16b4),
+    (0x91c1, 0x16ce),
+    (0x91c2, 0x35c2),
+    (0x91c3, 0x3629),
+    (0x91c5, 0x16e0),
+    (0x91c6, 0x04a5),
+    (0x91c7, 0x0619),
+    (0x91c9, 0x0ece),
+    (0x91cb, 0x15d5),
+    (0x91cc, 0x04a6),
+    (0x91cd, 0x07a5),
+    (0x91ce, 0x0b4d),
+    (0x91cf, 0x0d12),
+    (0x91d0, 0x14c2),
+    (0x91d1, 0x061a),
+    (0x91d3, 0x1d32),
+    (0x91d4, 0x1d31),
+    (0x91d5, 0x1f49),
+    (0x91d7, 0x096b),
+    (0x91d8, 0x0969),
+    (0x91d9, 0x096d),
+    (0x91da, 0x1f4b),
+    (0x91dc, 0x096c),
+    (0x91dd, 0x096a),
+    (0x91e2, 0x1f4a),
+    (0x91e3, 0x0b50),
+    (0x91e4, 0x21b8),
+    (0x91e6, 0x0b4f),
+    (0x91e7, 0x0b51),
+    (0x91e8, 0x21bd),
+    (0x91e9, 0x0b53),
+    (0x91ea, 0x21ba),
+    (0x91eb, 0x21bb),
+    (0x91ec, 0x21b3),
+    (0x91ed, 0x0b52),
+    (0x91ee, 0x21be),
+    (0x91f1, 0x21b5),
+    (0x91f3, 0x21b6),
+    (0x91f4, 0x21b4),
+    (0x91f5, 0x0b4e),
+    (0x91f7, 0x21bc),
+    (0x91f8, 0x21b7),
+    (0x91f9, 0x21b9),
+    (0x91fd, 0x245c),
+    (0x91ff, 0x245b),
+    (0x9200, 0x2459),
+    (0x9201, 0x2451),
+    (0x9202, 0x2460),
+    (0x9203, 0x2454),
+    (0x9204, 0x245e),
+    (0x9205, 0x2465),
+    (0x9206, 0x245d),
+    (0x9207, 0x0d1a),
+    (0x9209, 0x0d16),
+    (0x920a, 0x2452),
+    (0x920c, 0x2458),
+    (0x920d, 0x0d18),
+    (0x920f, 0x2457),
+    (0x9210, 0x0d19),
+    (0x9211, 0x0d1b),
+    (0x9212, 0x245a),
+    (0x9214, 0x0d13),
+    (0x9215, 0x0d14),
+    (0x9216, 0x2466),
+    (0x9217, 0x2464),
+    (0x9219, 0x2463),
+    (0x921a, 0x2455),
+    (0x921c, 0x2461),
+    (0x921e, 0x0d17),
+    (0x9223, 0x0d15),
+    (0x9224, 0x2462),
+    (0x9225, 0x2453),
+    (0x9226, 0x2456),
+    (0x9227, 0x245f),
+    (0x922d, 0x296f),
+    (0x922e, 0x26f8),
+    (0x9230, 0x26f1),
+    (0x9231, 0x2704),
+    (0x9232, 0x270d),
+    (0x9233, 0x26f4),
+    (0x9234, 0x0ed9),
+    (0x9236, 0x2701),
+    (0x9237, 0x0ecf),
+    (0x9238, 0x0ed1),
+    (0x9239, 0x0edd),
+    (0x923a, 0x26f2),
+    (0x923d, 0x0ed2),
+    (0x923e, 0x0ed4),
+    (0x923f, 0x0ede),
+    (0x9240, 0x0ed3),
+    (0x9245, 0x0edc),
+    (0x9246, 0x26fa),
+    (0x9248, 0x26ef),
+    (0x9249, 0x0eda),
+    (0x924a, 0x26f9),
+    (0x924b, 0x0ed6),
+    (0x924c, 0x270b),
+    (0x924d, 0x0edb),
+    (0x924e, 0x2709),
+    (0x924f, 0x26fd),
+    (0x9250, 0x2707),
+    (0x9251, 0x0ed8),
+    (0x9252, 0x26f0),
+    (0x9253, 0x270a),
+    (0x9254, 0x2705),
+    (0x9256, 0x270c),
+    (0x9257, 0x0ed0),
+    (0x925a, 0x0edf),
+    (0x925b, 0x0ed5),
+    (0x925e, 0x26f6),
+    (0x9260, 0x26fe),
+    (0x9261, 0x2702),
+    (0x9263, 0x2706),
+    (0x9264, 0x0ed7),
+    (0x9265, 0x26f5),
+    (0x9266, 0x26f3),
+    (0x9267, 0x26ff),
+    (0x926c, 0x26fc),
+    (0x926d, 0x26fb),
+    (0x926f, 0x2700),
+    (0x9270, 0x2703),
+    (0x9272, 0x2708),
+    (0x9276, 0x294f),
+    (0x9278, 0x1057),
+    (0x9279, 0x2959),
+    (0x927a, 0x2951),
+    (0x927b, 0x105c),
+    (0x927c, 0x1060),
+    (0x927d, 0x2962),
+    (0x927e, 0x296a),
+    (0x927f, 0x295b),
+    (0x9280, 0x1058),
+    (0x9282, 0x295f),
+    (0x9283, 0x26f7),
+    (0x9285, 0x1059),
+    (0x9286, 0x2966),
+    (0x9287, 0x296b),
+    (0x9288, 0x2963),
+    (0x928a, 0x2965),
+    (0x928b, 0x296e),
+    (0x928c, 0x2967),
+    (0x928d, 0x2955),
+    (0x928e, 0x295e),
+    (0x9291, 0x1061),
+    (0x9293, 0x105d),
+    (0x9294, 0x2953),
+    (0x9295, 0x2960),
+    (0x9296, 0x105b),
+    (0x9297, 0x295a),
+    (0x9298, 0x105a),
+    (0x9299, 0x2968),
+    (0x929a, 0x2957),
+    (0x929b, 0x2950),
+    (0x929c, 0x105e),
+    (0x929d, 0x296d),
+    (0x92a0, 0x2952),
+    (0x92a1, 0x2964),
+    (0x92a2, 0x2961),
+    (0x92a3, 0x295c),
+    (0x92a4, 0x294e),
+    (0x92a5, 0x294d),
+    (0x92a6, 0x2956),
+    (0x92a7, 0x2969),
+    (0x92a8, 0x105f),
+    (0x92a9, 0x296c),
+    (0x92aa, 0x2954),
+    (0x92ab, 0x2958),
+    (0x92ac, 0x11db),
+    (0x92b2, 0x11e3),
+    (0x92b3, 0x11de),
+    (0x92b4, 0x2bcd),
+    (0x92b5, 0x2bca),
+    (0x92b6, 0x2bb2),
+    (0x92b7, 0x11d9),
+    (0x92bb, 0x11d8),
+    (0x92bc, 0x11df),
+    (0x92c0, 0x2bb0),
+    (0x92c1, 0x11dd),
+    (0x92c2, 0x2bbc),
+    (0x92c3, 0x2bae),
+    (0x92c4, 0x2baf),
+    (0x92c5, 0x11d7),
+    (0x92c6, 0x2bcc),
+    (0x92c7, 0x11e1),
+    (0x92c8, 0x2bbf),
+    (0x92c9, 0x2bc4),
+    (0x92ca, 0x2bbe),
+    (0x92cb, 0x2dee),
+    (0x92cc, 0x2bba),
+    (0x92cd, 0x2bc2),
+    (0x92ce, 0x2bc0),
+    (0x92cf, 0x2bb3),
+    (0x92d0, 0x2bad),
+    (0x92d1, 0x2bc8),
+    (0x92d2, 0x11e0),
+    (0x92d3, 0x2bc9),
+    (0x92d5, 0x2bc3),
+    (0x92d7, 0x2bb8),
+    (0x92d8, 0x2bb6),
+    (0x92d9, 0x2bb1),
+    (0x92dd, 0x2bb9),
+    (0x92de, 0x2bc6),
+    (0x92df, 0x2bb5),
+    (0x92e0, 0x2bc5),
+    (0x92e1, 0x2bcb),
+    (0x92e4, 0x11dc),
+    (0x92e6, 0x2bc1),
+    (0x92e7, 0x2bc7),
+    (0x92e8, 0x2bbd),
+    (0x92e9, 0x2bb7),
+    (0x92ea, 0x11da),
+    (0x92ee, 0x295d),
+    (0x92ef, 0x2bbb),
+    (0x92f0, 0x11e2),
+    (0x92f1, 0x2bb4),
+    (0x92f7, 0x2df4),
+    (0x92f8, 0x12fb),
+    (0x92f9, 0x2df3),
+    (0x92fa, 0x2df0),
+    (0x92fb, 0x2e03),
+    (0x92fc, 0x12ff),
+    (0x92fe, 0x2e00),
+    (0x92ff, 0x2df8),
+    (0x9300, 0x2e02),
+    (0x9301, 0x2de9),
+    (0x9302, 0x2df6),
+    (0x9304, 0x1301),
+    (0x9306, 0x2de1),
+    (0x9308, 0x2ddf),
+    (0x9309, 0x2e01),
+    (0x930b, 0x2dff),
+    (0x930c, 0x2dfe),
+    (0x930d, 0x2ded),
+    (0x930e, 0x2dec),
+    (0x930f, 0x2de2),
+    (0x9310, 0x1303),
+    (0x9312, 0x2de8),
+    (0x9313, 0x2df2),
+    (0x9314, 0x2dfd),
+    (0x9315, 0x1306),
+    (0x9316, 0x2e04),
+    (0x9318, 0x1426),
+    (0x9319, 0x1308),
+    (0x931a, 0x1302),
+    (0x931b, 0x2de6),
+    (0x931d, 0x2def),
+    (0x931e, 0x2dde),
+    (0x931f, 0x2de0),
+    (0x9320, 0x12f9),
+    (0x9321, 0x1305),
+    (0x9322, 0x12fe),
+    (0x9323, 0x2de7),
+    (0x9324, 0x2df7),
+    (0x9325, 0x2df1),
+    (0x9326, 0x1304),
+    (0x9327, 0x2ddd),
+    (0x9328, 0x1421),
+    (0x9329, 0x2df9),
+    (0x932a, 0x2dfc),
+    (0x932b, 0x1300),
+    (0x932d, 0x2deb),
+    (0x932e, 0x1307),
+    (0x932f, 0x12fd),
+    (0x9333, 0x12fc),
+    (0x9334, 0x2df5),
+    (0x9335, 0x2dfb),
+    (0x9336, 0x12fa),
+    (0x9338, 0x2de4),
+    (0x9339, 0x2dfa),
+    (0x933c, 0x2de5),
+    (0x9346, 0x2dea),
+    (0x9347, 0x2fa9),
+    (0x9349, 0x2fae),
+    (0x934a, 0x1423),
+    (0x934b, 0x1425),
+    (0x934c, 0x2fb4),
+    (0x934d, 0x141f),
+    (0x934e, 0x2fc3),
+    (0x934f, 0x2fba),
+    (0x9350, 0x2faf),
+    (0x9351, 0x2fb0),
+    (0x9352, 0x2fb9),
+    (0x9354, 0x142c),
+    (0x9355, 0x2fb8),
+    (0x9356, 0x2fa8),
+    (0x9357, 0x2fb7),
+    (0x9358, 0x2fab),
+    (0x9359, 0x2fc4),
+    (0x935a, 0x142b),
+    (0x935b, 0x1429),
+    (0x935c, 0x2fac),
+    (0x935e, 0x2fbf),
+    (0x9360, 0x2fb1),
+    (0x9361, 0x2fbe),
+    (0x9363, 0x2fc0),
+    (0x9364, 0x2fa7),
+    (0x9365, 0x1424),
+    (0x9367, 0x2fc1),
+    (0x936a, 0x2fb5),
+    (0x936c, 0x1428),
+    (0x936d, 0x2fb2),
+    (0x9370, 0x142a),
+    (0x9371, 0x2fbb),
+    (0x9375, 0x1422),
+    (0x9376, 0x2fad),
+    (0x9377, 0x2fbc),
+    (0x9379, 0x2fb6),
+    (0x937a, 0x2de3),
+    (0x937b, 0x2fbd),
+    (0x937c, 0x2faa),
+    (0x937e, 0x1427),
+    (0x9380, 0x2fc2),
+    (0x9382, 0x1420),
+    (0x9383, 0x2fa5),
+    (0x9388, 0x3130),
+    (0x9389, 0x3129),
+    (0x938a, 0x14c4),
+    (0x938c, 0x3124),
+    (0x938d, 0x3133),
+    (0x938e, 0x312b),
+    (0x938f, 0x2fb3),
+    (0x9391, 0x3135),
+    (0x9392, 0x3125),
+    (0x9394, 0x14c3),
+    (0x9395, 0x312f),
+    (0x9396, 0x14c5),
+    (0x9397, 0x14cd),
+    (0x9398, 0x14cb),
+    (0x9399, 0x3131),
+    (0x939a, 0x14cc),
+    (0x939b, 0x3127),
+    (0x939d, 0x3128),
+    (0x939e, 0x312d),
+    (0x939f, 0x3132),
+    (0x93a1, 0x2fa4),
+    (0x93a2, 0x14c6),
+    (0x93a3, 0x313a),
+    (0x93a4, 0x3137),
+    (0x93a5, 0x313b),
+    (0x93a6, 0x312e),
+    (0x93a7, 0x312a),
+    (0x93a8, 0x3138),
+    (0x93a9, 0x3274),
+    (0x93aa, 0x312c),
+    (0x93ac, 0x14c9),
+    (0x93ae, 0x14c8),
+    (0x93af, 0x2fa6),
+    (0x93b0, 0x14ca),
+    (0x93b1, 0x3134),
+    (0x93b2, 0x3136),
+    (0x93b3, 0x14c7),
+    (0x93b4, 0x3139),
+    (0x93b5, 0x3123),
+    (0x93b7, 0x3126),
+    (0x93c0, 0x327d),
+    (0x93c2, 0x326d),
+    (0x93c3, 0x1563),
+    (0x93c4, 0x327b),
+    (0x93c7, 0x326b),
+    (0x93c8, 0x1564),
+    (0x93ca, 0x3276),
+    (0x93cc, 0x3272),
+    (0x93cd, 0x1569),
+    (0x93ce, 0x327c),
+    (0x93cf, 0x326c),
+    (0x93d0, 0x326f),
+    (0x93d1, 0x1561),
+    (0x93d2, 0x327e),
+    (0x93d4, 0x3277),
+    (0x93d5, 0x327a),
+    (0x93d6, 0x1567),
+    (0x93d7, 0x156c),
+    (0x93d8, 0x156a),
+    (0x93d9, 0x3273),
+    (0x93da, 0x326e),
+    (0x93dc, 0x1565),
+    (0x93dd, 0x1566),
+    (0x93de, 0x326a),
+    (0x93df, 0x1562),
+    (0x93e1, 0x1560),
+    (0x93e2, 0x1568),
+    (0x93e3, 0x3279),
+    (0x93e4, 0x156b),
+    (0x93e6, 0x3275),
+    (0x93e7, 0x327f),
+    (0x93e8, 0x156d),
+    (0x93ec, 0x3271),
+    (0x93ee, 0x3278),
+    (0x93f5, 0x3378),
+    (0x93f6, 0x3386),
+    (0x93f7, 0x337a),
+    (0x93f8, 0x3381),
+    (0x93f9, 0x3270),
+    (0x93fa, 0x337f),
+    (0x93fb, 0x336e),
+    (0x93fc, 0x3384),
+    (0x93fd, 0x15d8),
+    (0x93fe, 0x3372),
+    (0x93ff, 0x3383),
+    (0x9400, 0x3379),
+    (0x9403, 0x15d7),
+    (0x9406, 0x3388),
+    (0x9407, 0x337b),
+    (0x9409, 0x3380),
+    (0x940a, 0x3382),
+    (0x940b, 0x336c),
+    (0x940c, 0x3385),
+    (0x940d, 0x3377),
+    (0x940e, 0x337c),
+    (0x940f, 0x3370),
+    (0x9410, 0x3374),
+    (0x9411, 0x3387),
+    (0x9412, 0x337e),
+    (0x9413, 0x336d),
+    (0x9414, 0x3371),
+    (0x9415, 0x3373),
+    (0x9416, 0x337d),
+    (0x9418, 0x15d6),
+    (0x9419, 0x3376),
+    (0x9420, 0x336f),
+    (0x9428, 0x3375),
+    (0x9429, 0x3442),
+    (0x942a, 0x3447),
+    (0x942b, 0x1623),
+    (0x942c, 0x3449),
+    (0x942e, 0x161d),
+    (0x9430, 0x3445),
+    (0x9431, 0x344b),
+    (0x9432, 0x1622),
+    (0x9433, 0x161e),
+    (0x9435, 0x161f),
+    (0x9436, 0x3441),
+    (0x9437, 0x3448),
+    (0x9438, 0x1621),
+    (0x9439, 0x3446),
+    (0x943a, 0x1620),
+    (0x943b, 0x3440),
+    (0x943c, 0x3444),
+    (0x943d, 0x3443),
+    (0x943f, 0x343f),
+    (0x9440, 0x344a),
+    (0x9444, 0x1663),
+    (0x9445, 0x34eb),
+    (0x9446, 0x34ee),
+    (0x9447, 0x34ea),
+    (0x9448, 0x34ec),
+    (0x9449, 0x34ed),
+    (0x944a, 0x34e7),
+    (0x944b, 0x34e8),
+    (0x944c, 0x34e5),
+    (0x944f, 0x34e9),
+    (0x9450, 0x34e6),
+    (0x9451, 0x1664),
+    (0x9452, 0x1665),
+    (0x9455, 0x3566),
+    (0x9457, 0x3568),
+    (0x945d, 0x3567),
+    (0x945e, 0x3569),
+    (0x9460, 0x1692),
+    (0x9462, 0x3565),
+    (0x9463, 0x1691),
+    (0x9464, 0x1693),
+    (0x9468, 0x35c4),
+    (0x9469, 0x35c5),
+    (0x946a, 0x16b5),
+    (0x946b, 0x35c3),
+    (0x946d, 0x360b),
+    (0x946e, 0x360a),
+    (0x946f, 0x360c),
+    (0x9470, 0x16d0),
+    (0x9471, 0x360d),
+    (0x9472, 0x16cf),
+    (0x9473, 0x360e),
+    (0x9474, 0x362a),
+    (0x9475, 0x362d),
+    (0x9476, 0x362c),
+    (0x9477, 0x16d9),
+    (0x9478, 0x362b),
+    (0x947c, 0x16e3),
+    (0x947d, 0x16e1),
+    (0x947e, 0x16e2),
+    (0x947f, 0x16e8),
+    (0x9480, 0x3656),
+    (0x9481, 0x3657),
+    (0x9482, 0x3655),
+    (0x9483, 0x365f),
+    (0x9577, 0x061b),
+    (0x957a, 0x21bf),
+    (0x957b, 0x2467),
+    (0x957c, 0x2bce),
+    (0x957d, 0x3280),
+    (0x9580, 0x061c),
+    (0x9582, 0x07a6),
+    (0x9583, 0x096e),
+    (0x9586, 0x21c0),
+    (0x9588, 0x21c1),
+    (0x9589, 0x0b54),
+    (0x958b, 0x0d1e),
+    (0x958c, 0x2469),
+    (0x958d, 0x2468),
+    (0x958e, 0x0d22),
+    (0x958f, 0x0d1d),
+    (0x9590, 0x246a),
+    (0x9591, 0x0d1f),
+    (0x9592, 0x0d21),
+    (0x9593, 0x0d20),
+    (0x9594, 0x0d1c),
+    (0x9598, 0x0ee0),
+    (0x959b, 0x2711),
+    (0x959c, 0x270f),
+    (0x959e, 0x2710),
+    (0x959f, 0x270e),
+    (0x95a1, 0x1062),
+    (0x95a3, 0x1065),
+    (0x95a4, 0x1067),
+    (0x95a5, 0x1066),
+    (0x95a8, 0x1063),
+    (0x95a9, 0x1064),
+    (0x95ab, 0x2bd0),
+    (0x95ac, 0x2bcf),
+    (0x95ad, 0x11e4),
+    (0x95ae, 0x2bd1),
+    (0x95b0, 0x2bd2),
+    (0x95b1, 0x11e5),
+    (0x95b5, 0x2e0c),
+    (0x95b6, 0x2e0a),
+    (0x95b7, 0x2fca),
+    (0x95b9, 0x2e08),
+    (0x95ba, 0x2e09),
+    (0x95bb, 0x1309),
+    (0x95bc, 0x2e05),
+    (0x95bd, 0x2e0d),
+    (0x95be, 0x2e07),
+    (0x95bf, 0x2e0b),
+    (0x95c0, 0x2fc6),
+    (0x95c3, 0x2fc8),
+    (0x95c5, 0x2fc9),
+    (0x95c6, 0x1431),
+    (0x95c7, 0x2fc5),
+    (0x95c8, 0x1430),
+    (0x95c9, 0x2fc7),
+    (0x95ca, 0x142d),
+    (0x95cb, 0x142e),
+    (0x95cc, 0x142f),
+    (0x95cd, 0x2e06),
+    (0x95d0, 0x14d0),
+    (0x95d1, 0x313e),
+    (0x95d2, 0x313c),
+    (0x95d3, 0x313d),
+    (0x95d4, 0x14ce),
+    (0x95d5, 0x14d1),
+    (0x95d6, 0x14cf),
+    (0x95da, 0x3281),
+    (0x95db, 0x3282),
+    (0x95dc, 0x156e),
+    (0x95de, 0x3389),
+    (0x95df, 0x338b),
+    (0x95e0, 0x338a),
+    (0x95e1, 0x15d9),
+    (0x95e2, 0x1624),
+    (0x95e3, 0x344e),
+    (0x95e4, 0x344d),
+    (0x95e5, 0x344c),
+    (0x961c, 0x061d),
+    (0x961e, 0x18d0),
+    (0x9620, 0x193d),
+    (0x9621, 0x0398),
+    (0x9622, 0x193b),
+    (0x9623, 0x193e),
+    (0x9624, 0x193c),
+    (0x9628, 0x1a0e),
+    (0x962a, 0x04aa),
+    (0x962c, 0x04ab),
+    (0x962d, 0x1a10),
+    (0x962e, 0x04a8),
+    (0x962f, 0x1a0f),
+    (0x9630, 0x1a0d),
+    (0x9631, 0x04a9),
+    (0x9632, 0x04a7),
+    (0x9639, 0x1b72),
+    (0x963a, 0x1b75),
+    (0x963b, 0x0620),
+    (0x963c, 0x1b74),
+    (0x963d, 0x1b73),
+    (0x963f, 0x061f),
+    (0x9640, 0x061e),
+    (0x9642, 0x0622),
+    (0x9643, 0x1b76),
+    (0x9644, 0x0621),
+    (0x964a, 0x1d37),
+    (0x964b, 0x07a8),
+    (0x964c, 0x07a9),
+    (0x964d, 0x07aa),
+    (0x964e, 0x1d38),
+    (0x964f, 0x1d34),
+    (0x9650, 0x07a7),
+    (0x9651, 0x1d35),
+    (0x9653, 0x1d36),
+    (0x9654, 0x1d33),
+    (0x9658, 0x0975),
+    (0x965b, 0x0972),
+    (0x965c, 0x1f4c),
+    (0x965d, 0x0973),
+    (0x965e, 0x0976),
+    (0x965f, 0x1f4d),
+    (0x9661, 0x0971),
+    (0x9662, 0x096f),
+    (0x9663, 0x0970),
+    (0x9664, 0x0974),
+    (0x966a, 0x0b55),
+    (0x966b, 0x21c4),
+    (0x966c, 0x0b5d),
+    (0x966d, 0x21c3),
+    (0x966f, 0x21c6),
+    (0x9670, 0x0b59),
+    (0x9671, 0x21c5),
+    (0x9672, 0x0d2a),
+    (0x9673, 0x0b57),
+    (0x9674, 0x0b5a),
+    (0x9675, 0x0b56),
+    (0x9676, 0x0b5b),
+    (0x9677, 0x0b5c),
+    (0x9678, 0x0b58),
+    (0x967c, 0x21c2),
+    (0x967d, 0x0d26),
+    (0x967e, 0x246c),
+    (0x9680, 0x2470),
+    (0x9683, 0x246f),
+    (0x9684, 0x0d2b),
+    (0x9685, 0x0d27),
+    (0x9686, 0x0d28),
+    (0x9687, 0x246b),
+    (0x9688, 0x246d),
+    (0x9689, 0x246e),
+    (0x968a, 0x0d23),
+    (0x968b, 0x0d25),
+    (0x968d, 0x0d29),
+    (0x968e, 0x0d24),
+    (0x9691, 0x2714),
+    (0x9692, 0x2712),
+    (0x9693, 0x2713),
+    (0x9694, 0x0ee2),
+    (0x9695, 0x0ee3),
+    (0x9697, 0x2715),
+    (0x9698, 0x0ee1),
+    (0x9699, 0x1068),
+    (0x969b, 0x106a),
+    (0x969c, 0x1069),
+    (0x969e, 0x2970),
+    (0x96a1, 0x2971),
+    (0x96a2, 0x2bd4),
+    (0x96a4, 0x2bd3),
+    (0x96a7, 0x130a),
+    (0x96a8, 0x130b),
+    (0x96a9, 0x2e0e),
+    (0x96aa, 0x130c),
+    (0x96ac, 0x2fcd),
+    (0x96ae, 0x2fcb),
+    (0x96b0, 0x2fcc),
+    (0x96b1, 0x1432),
+    (0x96b3, 0x313f),
+    (0x96b4, 0x156f),
+    (0x96b8, 0x1433),
+    (0x96b9, 0x0623),
+    (0x96bb, 0x0977),
+    (0x96bc, 0x1f4e),
+    (0x96bf, 0x21c7),
+    (0x96c0, 0x0b5e),
+    (0x96c1, 0x0d2c),
+    (0x96c2, 0x2471),
+    (0x96c3, 0x2473),
+    (0x96c4, 0x0d2e),
+    (0x96c5, 0x0d2d),
+    (0x96c6, 0x0d2f),
+    (0x96c7, 0x0d30),
+    (0x96c8, 0x2472),
+    (0x96c9, 0x0ee6),
+    (0x96ca, 0x0ee7),
+    (0x96cb, 0x0ee5),
+    (0x96cc, 0x106b),
+    (0x96cd, 0x0ee4),
+    (0x96ce, 0x2716),
+    (0x96d2, 0x106c),
+    (0x96d3, 0x2bd5),
+    (0x96d4, 0x2e0f),
+    (0x96d5, 0x130d),
+    (0x96d6, 0x1434),
+    (0x96d7, 0x3140),
+    (0x96d8, 0x3144),
+    (0x96d9, 0x14d4),
+    (0x96da, 0x3141),
+    (0x96db, 0x14d5),
+    (0x96dc, 0x14d3),
+    (0x96dd, 0x3145),
+    (0x96de, 0x14d6),
+    (0x96df, 0x3143),
+    (0x96e1, 0x3283),
+    (0x96e2, 0x14d2),
+    (0x96e3, 0x1570),
+    (0x96e5, 0x35c6),
+    (0x96e8, 0x0624),
+    (0x96e9, 0x0b60),
+    (0x96ea, 0x0b5f),
+    (0x96ef, 0x0d31),
+    (0x96f0, 0x2475),
+    (0x96f1, 0x2474),
+    (0x96f2, 0x0d32),
+    (0x96f5, 0x271a),
+    (0x96f6, 0x0eeb),
+    (0x96f7, 0x0ee8),
+    (0x96f8, 0x2719),
+    (0x96f9, 0x0eea),
+    (0x96fa, 0x2717),
+    (0x96fb, 0x0ee9),
+    (0x96fd, 0x2718),
+    (0x96ff, 0x2972),
+    (0x9700, 0x106d),
+    (0x9702, 0x2bd8),
+    (0x9704, 0x11e6),
+    (0x9705, 0x2bd6),
+    (0x9706, 0x11e7),
+    (0x9707, 0x11e8),
+    (0x9708, 0x2bd7),
+    (0x9709, 0x11e9),
+    (0x970b, 0x2e10),
+    (0x970d, 0x1311),
+    (0x970e, 0x130e),
+    (0x970f, 0x1313),
+    (0x9710, 0x2e12),
+    (0x9711, 0x130f),
+    (0x9712, 0x2e11),
+    (0x9713, 0x1312),
+    (0x9716, 0x1310),
+    (0x9718, 0x2fd0),
+    (0x9719, 0x2fd2),
+    (0x971c, 0x1435),
+    (0x971d, 0x2fd1),
+    (0x971e, 0x1436),
+    (0x971f, 0x2fcf),
+    (0x9720, 0x2fce),
+    (0x9722, 0x3147),
+    (0x9723, 0x3146),
+    (0x9724, 0x14d7),
+    (0x9725, 0x3148),
+    (0x9726, 0x3288),
+    (0x9727, 0x1572),
+    (0x9728, 0x3287),
+    (0x9729, 0x3284),
+    (0x972a, 0x1571),
+    (0x972b, 0x3285),
+    (0x972c, 0x3286),
+    (0x972e, 0x338c),
+    (0x972f, 0x338d),
+    (0x9730, 0x15da),
+    (0x9732, 0x1627),
+    (0x9735, 0x344f),
+    (0x9738, 0x1625),
+    (0x9739, 0x1626),
+    (0x973a, 0x3450),
+    (0x973d, 0x1666),
+    (0x973e, 0x1667),
+    (0x973f, 0x34ef),
+    (0x9742, 0x16b6),
+    (0x9743, 0x35c8),
+    (0x9744, 0x16b8),
+    (0x9746, 0x35c7),
+    (0x9747, 0x35c9),
+    (0x9748, 0x16b7),
+    (0x9749, 0x360f),
+    (0x974b, 0x3643),
+    (0x9752, 0x0625),
+    (0x9756, 0x0eec),
+    (0x9758, 0x2973),
+    (0x975a, 0x2bd9),
+    (0x975b, 0x1314),
+    (0x975c, 0x1315),
+    (0x975e, 0x0626),
+    (0x9760, 0x11ea),
+    (0x9761, 0x1573),
+    (0x9762, 0x07ab),
+    (0x9766, 0x1316),
+    (0x9768, 0x1694),
+    (0x9769, 0x07ac),
+    (0x976a, 0x21c8),
+    (0x976c, 0x2476),
+    (0x976e, 0x2478),
+    (0x9770, 0x2477),
+    (0x9772, 0x271e),
+    (0x9773, 0x271b),
+    (0x9774, 0x0eed),
+    (0x9776, 0x0eee),
+    (0x9777, 0x271c),
+    (0x9778, 0x271d),
+    (0x977a, 0x2975),
+    (0x977b, 0x297a),
+    (0x977c, 0x106e),
+    (0x977d, 0x2974),
+    (0x977e, 0x2976),
+    (0x977f, 0x297d),
+    (0x9780, 0x2978),
+    (0x9781, 0x297c),
+    (0x9782, 0x2979),
+    (0x9783, 0x2977),
+    (0x9784, 0x297b),
+    (0x9785, 0x106f),
+    (0x9788, 0x2bdc),
+    (0x978a, 0x2bda),
+    (0x978b, 0x11ec),
+    (0x978d, 0x11eb),
+    (0x978e, 0x2bdb),
+    (0x978f, 0x11ed),
+    (0x9794, 0x2e15),
+    (0x9797, 0x2e14),
+    (0x9798, 0x1317),
+    (0x9799, 0x2e13),
+    (0x979a, 0x2fd3),
+    (0x979c, 0x2fd5),
+    (0x979d, 0x2fd7),
+    (0x979e, 0x2fd6),
+    (0x97a0, 0x1437),
+    (0x97a1, 0x2fd4),
+    (0x97a2, 0x314f),
+    (0x97a3, 0x14d8),
+    (0x97a4, 0x314d),
+    (0x97a5, 0x3150),
+    (0x97a6, 0x14d9),
+    (0x97a8, 0x314b),
+    (0x97aa, 0x314e),
+    (0x97ab, 0x314c),
+    (0x97ac, 0x3149),
+    (0x97ad, 0x14da),
+    (0x97ae, 0x314a),
+    (0x97b3, 0x3289),
+    (0x97b6, 0x328b),
+    (0x97b7, 0x328a),
+    (0x97b9, 0x338e),
+    (0x97bb, 0x338f),
+    (0x97bf, 0x3451),
+    (0x97c1, 0x1669),
+    (0x97c3, 0x1668),
+    (0x97c4, 0x356a),
+    (0x97c5, 0x356b),
+    (0x97c6, 0x16b9),
+    (0x97c7, 0x35ca),
+    (0x97c9, 0x16da),
+    (0x97cb, 0x07ad),
+    (0x97cc, 0x0d33),
+    (0x97cd, 0x297f),
+    (0x97ce, 0x297e),
+    (0x97cf, 0x2bde),
+    (0x97d0, 0x2bdd),
+    (0x97d3, 0x1438),
+    (0x97d4, 0x2fd9),
+    (0x97d5, 0x2fd8),
+    (0x97d6, 0x3153),
+    (0x97d7, 0x3151),
+    (0x97d8, 0x3154),
+    (0x97d9, 0x3152),
+    (0x97dc, 0x1574),
+    (0x97dd, 0x328c),
+    (0x97de, 0x328d),
+    (0x97df, 0x328e),
+    (0x97e1, 0x3452),
+    (0x97e3, 0x34f0),
+    (0x97e5, 0x35cb),
+    (0x97ed, 0x07ae),
+    (0x97f0, 0x2e16),
+    (0x97f1, 0x2fda),
+    (0x97f3, 0x07af),
+    (0x97f6, 0x1070),
+    (0x97f8, 0x2e17),
+    (0x97f9, 0x14db),
+    (0x97fa, 0x3155),
+    (0x97fb, 0x1575),
+    (0x97fd, 0x3390),
+    (0x97fe, 0x3391),
+    (0x97ff, 0x1628),
+    (0x9800, 0x356c),
+    (0x9801, 0x07b0),
+    (0x9802, 0x0b63),
+    (0x9803, 0x0b64),
+    (0x9804, 0x21c9),
+    (0x9805, 0x0d34),
+    (0x9806, 0x0d35),
+    (0x9807, 0x2479),
+    (0x9808, 0x0d36),
+    (0x980a, 0x0ef2),
+    (0x980c, 0x0ef4),
+    (0x980d, 0x2720),
+    (0x980e, 0x2721),
+    (0x980f, 0x271f),
+    (0x9810, 0x0eef),
+    (0x9811, 0x0ef0),
+    (0x9812, 0x0ef3),
+    (0x9813, 0x0ef1),
+    (0x9816, 0x2980),
+    (0x9817, 0x1071),
+    (0x9818, 0x1072),
+    (0x981b, 0x2be5),
+    (0x981c, 0x11f0),
+    (0x981d, 0x2be0),
+    (0x981e, 0x2bdf),
+    (0x9820, 0x2be4),
+    (0x9821, 0x11ee),
+    (0x9824, 0x131e),
+    (0x9826, 0x2be1),
+    (0x9827, 0x2be6),
+    (0x9828, 0x2be3),
+    (0x9829, 0x2be2),
+    (0x982b, 0x11ef),
+    (0x982d, 0x131c),
+    (0x982f, 0x2e19),
+    (0x9830, 0x1318),
+    (0x9832, 0x2e1a),
+    (0x9835, 0x2e18),
+    (0x9837, 0x131b),
+    (0x9838, 0x1319),
+    (0x9839, 0x131d),
+    (0x983b, 0x131a),
+    (0x9841, 0x2fdb),
+    (0x9843, 0x2fe0),
+    (0x9844, 0x2fdc),
+    (0x9845, 0x2fdf),
+    (0x9846, 0x1439),
+    (0x9848, 0x2f0e),
+    (0x9849, 0x2fde),
+    (0x984a, 0x2fdd),
+    (0x984c, 0x14de),
+    (0x984d, 0x14dc),
+    (0x984e, 0x14df),
+    (0x984f, 0x14dd),
+    (0x9850, 0x3156),
+    (0x9851, 0x3157),
+    (0x9852, 0x3158),
+    (0x9853, 0x14e0),
+    (0x9857, 0x3292),
+    (0x9858, 0x1577),
+    (0x9859, 0x3290),
+    (0x985b, 0x1578),
+    (0x985c, 0x328f),
+    (0x985d, 0x3291),
+    (0x985e, 0x1576),
+    (0x985f, 0x3395),
+    (0x9860, 0x3392),
+    (0x9862, 0x3393),
+    (0x9863, 0x3394),
+    (0x9864, 0x3453),
+    (0x9865, 0x162a),
+    (0x9867, 0x1629),
+    (0x9869, 0x34f2),
+    (0x986a, 0x34f1),
+    (0x986b, 0x166a),
+    (0x986f, 0x1695),
+    (0x9870, 0x16ba),
+    (0x9871, 0x16d1),
+    (0x9872, 0x3610),
+    (0x9873, 0x3644),
+    (0x9874, 0x3645),
+    (0x98a8, 0x07b1),
+    (0x98a9, 0x247a),
+    (0x98ac, 0x2722),
+    (0x98ad, 0x2981),
+    (0x98ae, 0x2982),
+    (0x98af, 0x1073),
+    (0x98b1, 0x1074),
+    (0x98b2, 0x2be7),
+    (0x98b3, 0x11f1),
+    (0x98b6, 0x143a),
+    (0x98b8, 0x3159),
+    (0x98ba, 0x14e1),
+    (0x98bb, 0x3295),
+    (0x98bc, 0x1579),
+    (0x98bd, 0x3294),
+    (0x98be, 0x3296),
+    (0x98bf, 0x3293),
+    (0x98c0, 0x3456),
+    (0x98c1, 0x3396),
+    (0x98c2, 0x3397),
+    (0x98c4, 0x15db),
+    (0x98c6, 0x3455),
+    (0x98c9, 0x3454),
+    (0x98cb, 0x34f3),
+    (0x98cc, 0x3646),
+    (0x98db, 0x07b2),
+    (0x98df, 0x07b3),
+    (0x98e2, 0x0978),
+    (0x98e3, 0x1f4f),
+    (0x98e5, 0x21ca),
+    (0x98e7, 0x0d37),
+    (0x98e9, 0x0d3a),
+    (0x98ea, 0x0d38),
+    (0x98eb, 0x247b),
+    (0x98ed, 0x0d3c),
+    (0x98ef, 0x0d39),
+    (0x98f2, 0x0d3b),
+    (0x98f4, 0x0ef6),
+    (0x98f6, 0x2723),
+    (0x98f9, 0x2724),
+    (0x98fa, 0x2be9),
+    (0x98fc, 0x0ef5),
+    (0x98fd, 0x0ef7),
+    (0x98fe, 0x0ef8),
+    (0x9900, 0x2984),
+    (0x9902, 0x2983),
+    (0x9903, 0x1075),
+    (0x9905, 0x1076),
+    (0x9907, 0x2985),
+    (0x9908, 0x2be8),
+    (0x9909, 0x1078),
+    (0x990a, 0x11f2),
+    (0x990c, 0x1077),
+    (0x9910, 0x131f),
+    (0x9911, 0x2bea),
+    (0x9912, 0x11f4),
+    (0x9913, 0x11f3),
+    (0x9914, 0x2beb),
+    (0x9915, 0x2bee),
+    (0x9916, 0x2bec),
+    (0x9917, 0x2bed),
+    (0x9918, 0x11f5),
+    (0x991a, 0x1324),
+    (0x991b, 0x1322),
+    (0x991e, 0x1321),
+    (0x991f, 0x2e1c),
+    (0x9921, 0x1323),
+    (0x9924, 0x2e1b),
+    (0x9925, 0x2fe1),
+    (0x9927, 0x2e1d),
+    (0x9928, 0x1320),
+    (0x9929, 0x2e1e),
+    (0x992a, 0x2fe4),
+    (0x992b, 0x2fe2),
+    (0x992c, 0x2fe3),
+    (0x992d, 0x2fe8),
+    (0x992e, 0x14e5),
+    (0x992f, 0x2fe7),
+    (0x9930, 0x2fea),
+    (0x9931, 0x2fe9),
+    (0x9932, 0x2fe6),
+    (0x9933, 0x2fe5),
+    (0x9935, 0x143b),
+    (0x993a, 0x315c),
+    (0x993c, 0x315b),
+    (0x993d, 0x14e4),
+    (0x993e, 0x14e2),
+    (0x993f, 0x14e3),
+    (0x9941, 0x315a),
+    (0x9943, 0x3299),
+    (0x9945, 0x157a),
+    (0x9947, 0x3298),
+    (0x9948, 0x3297),
+    (0x9949, 0x157b),
+    (0x994b, 0x339c),
+    (0x994c, 0x339b),
+    (0x994e, 0x3399),
+    (0x9950, 0x3398),
+    (0x9951, 0x15dd),
+    (0x9952, 0x15dc),
+    (0x9953, 0x339d),
+    (0x9954, 0x34f4),
+    (0x9955, 0x166b),
+    (0x9956, 0x3458),
+    (0x9957, 0x162b),
+    (0x9958, 0x3457),
+    (0x9959, 0x339a),
+    (0x995b, 0x34f5),
+    (0x995c, 0x1696),
+    (0x995e, 0x16d2),
+    (0x995f, 0x3611),
+    (0x9961, 0x3647),
+    (0x9996, 0x07b4),
+    (0x9997, 0x21cb),
+    (0x9998, 0x2feb),
+    (0x9999, 0x07b5),
+    (0x999c, 0x2987),
+    (0x999d, 0x2986),
+    (0x999e, 0x2e1f),
+    (0x99a1, 0x2fed),
+    (0x99a3, 0x2fec),
+    (0x99a5, 0x14e6),
+    (0x99a6, 0x329a),
+    (0x99a7, 0x329b),
+    (0x99a8, 0x15de),
+    (0x99ab, 0x3648),
+    (0x99ac, 0x0979),
+    (0x99ad, 0x0d3e),
+    (0x99ae, 0x0d3d),
+    (0x99af, 0x2725),
+    (0x99b0, 0x2727),
+    (0x99b1, 0x0efa),
+    (0x99b2, 0x2726),
+    (0x99b3, 0x0ef9),
+    (0x99b4, 0x0efb),
+    (0x99b5, 0x2728),
+    (0x99b9, 0x2989),
+    (0x99ba, 0x298b),
+    (0x99bb, 0x298a),
+    (0x99bd, 0x298d),
+    (0x99c1, 0x1079),
+    (0x99c2, 0x298c),
+    (0x99c3, 0x2988),
+    (0x99c7, 0x298e),
+    (0x99c9, 0x2bf5),
+    (0x99cb, 0x2bf8),
+    (0x99cc, 0x2bfa),
+    (0x99cd, 0x2bf0),
+    (0x99ce, 0x2bf4),
+    (0x99cf, 0x2bf1),
+    (0x99d0, 0x11f7),
+    (0x99d1, 0x11fa),
+    (0x99d2, 0x11fc),
+    (0x99d3, 0x2bf2),
+    (0x99d4, 0x2bf3),
+    (0x99d5, 0x11fb),
+    (0x99d6, 0x2bf6),
+    (0x99d7, 0x2bf9),
+    (0x99d8, 0x2bf7),
+    (0x99d9, 0x11fd),
+    (0x99db, 0x11f9),
+    (0x99dc, 0x2bef),
+    (0x99dd, 0x11f6),
+    (0x99df, 0x11f8),
+    (0x99e2, 0x1326),
+    (0x99e3, 0x2e25),
+    (0x99e4, 0x2e23),
+    (0x99e5, 0x2e22),
+    (0x99e7, 0x2e28),
+    (0x99e9, 0x2e27),
+    (0x99ea, 0x2e26),
+    (0x99ec, 0x2e21),
+    (0x99ed, 0x1325),
+    (0x99ee, 0x2e20),
+    (0x99f0, 0x2e24),
+    (0x99f1, 0x1327),
+    (0x99f4, 0x2ff0),
+    (0x99f6, 0x2ff4),
+    (0x99f7, 0x2ff1),
+    (0x99f8, 0x2ff3),
+    (0x99f9, 0x2ff2),
+    (0x99fa, 0x2fef),
+    (0x99fb, 0x2ff5),
+    (0x99fc, 0x2ff8),
+    (0x99fd, 0x2ff6),
+    (0x99fe, 0x2ff7),
+    (0x99ff, 0x143d),
+    (0x9a01, 0x143c),
+    (0x9a02, 0x2fee),
+    (0x9a03, 0x2ff9),
+    (0x9a04, 0x3161),
+    (0x9a05, 0x3164),
+    (0x9a06, 0x3166),
+    (0x9a07, 0x3165),
+    (0x9a09, 0x315f),
+    (0x9a0a, 0x3163),
+    (0x9a0b, 0x315e),
+    (0x9a0d, 0x3160),
+    (0x9a0e, 0x14e7),
+    (0x9a0f, 0x315d),
+    (0x9a11, 0x3162),
+    (0x9a14, 0x32a8),
+    (0x9a15, 0x329d),
+    (0x9a16, 0x157c),
+    (0x9a19, 0x157d),
+    (0x9a1a, 0x329c),
+    (0x9a1b, 0x32a1),
+    (0x9a1c, 0x32a7),
+    (0x9a1d, 0x329f),
+    (0x9a1e, 0x32a6),
+    (0x9a20, 0x32a3),
+    (0x9a22, 0x32a2),
+    (0x9a23, 0x32a5),
+    (0x9a24, 0x32a0),
+    (0x9a25, 0x329e),
+    (0x9a27, 0x32a4),
+    (0x9a29, 0x33a4),
+    (0x9a2a, 0x33a2),
+    (0x9a2b, 0x15df),
+    (0x9a2c, 0x33a1),
+    (0x9a2d, 0x33a7),
+    (0x9a2e, 0x33a5),
+    (0x9a30, 0x15e0),
+    (0x9a31, 0x33a0),
+    (0x9a32, 0x339e),
+    (0x9a34, 0x339f),
+    (0x9a35, 0x15e2),
+    (0x9a36, 0x33a3),
+    (0x9a37, 0x15e1),
+    (0x9a38, 0x33a6),
+    (0x9a39, 0x3459),
+    (0x9a3a, 0x345f),
+    (0x9a3d, 0x345a),
+    (0x9a3e, 0x162f),
+    (0x9a3f, 0x3460),
+    (0x9a40, 0x162e),
+    (0x9a41, 0x345e),
+    (0x9a42, 0x345d),
+    (0x9a43, 0x162d),
+    (0x9a44, 0x345c),
+    (0x9a45, 0x162c),
+    (0x9a46, 0x345b),
+    (0x9a48, 0x34fb),
+    (0x9a49, 0x34fd),
+    (0x9a4a, 0x34fc),
+    (0x9a4c, 0x34f9),
+    (0x9a4d, 0x166d),
+    (0x9a4e, 0x34f6),
+    (0x9a4f, 0x34fa),
+    (0x9a50, 0x34ff),
+    (0x9a52, 0x34fe),
+    (0x9a53, 0x34f7),
+    (0x9a54, 0x34f8),
+    (0x9a55, 0x166c),
+    (0x9a56, 0x356d),
+    (0x9a57, 0x1699),
+    (0x9a59, 0x356e),
+    (0x9a5a, 0x1697),
+    (0x9a5b, 0x1698),
+    (0x9a5e, 0x35cc),
+    (0x9a5f, 0x16bb),
+    (0x9a60, 0x362e),
+    (0x9a62, 0x16db),
+    (0x9a64, 0x3649),
+    (0x9a65, 0x16dc),
+    (0x9a66, 0x364a),
+    (0x9a67, 0x364b),
+    (0x9a68, 0x3659),
+    (0x9a69, 0x3658),
+    (0x9a6a, 0x16eb),
+    (0x9a6b, 0x3663),
+    (0x9aa8, 0x097a),
+    (0x9aab, 0x272a),
+    (0x9aad, 0x2729),
+    (0x9aaf, 0x107a),
+    (0x9ab0, 0x107b),
+    (0x9ab1, 0x298f),
+    (0x9ab3, 0x2bfb),
+    (0x9ab4, 0x2e2b),
+    (0x9ab7, 0x11fe),
+    (0x9ab8, 0x1328),
+    (0x9ab9, 0x2e29),
+    (0x9abb, 0x2e2c),
+    (0x9abc, 0x1329),
+    (0x9abe, 0x2ffa),
+    (0x9abf, 0x2e2a),
+    (0x9ac0, 0x3167),
+    (0x9ac1, 0x14e8),
+    (0x9ac2, 0x32a9),
+    (0x9ac6, 0x33aa),
+    (0x9ac7, 0x33a8),
+    (0x9aca, 0x33a9),
+    (0x9acd, 0x3461),
+    (0x9acf, 0x1630),
+    (0x9ad0, 0x3500),
+    (0x9ad1, 0x169c),
+    (0x9ad2, 0x166e),
+    (0x9ad3, 0x169a),
+    (0x9ad4, 0x169b),
+    (0x9ad5, 0x35cd),
+    (0x9ad6, 0x16d3),
+    (0x9ad8, 0x097b),
+    (0x9adc, 0x3168),
+    (0x9adf, 0x1f50),
+    (0x9ae1, 0x0efc),
+    (0x9ae3, 0x2990),
+    (0x9ae6, 0x107c),
+    (0x9ae7, 0x2991),
+    (0x9aeb, 0x2bfd),
+    (0x9aec, 0x2bfc),
+    (0x9aed, 0x132b),
+    (0x9aee, 0x11ff),
+    (0x9aef, 0x1200),
+    (0x9af1, 0x2c00),
+    (0x9af2, 0x2bff),
+    (0x9af3, 0x2bfe),
+    (0x9af6, 0x2e2d),
+    (0x9af7, 0x2e30),
+    (0x9af9, 0x2e2f),
+    (0x9afa, 0x2e2e),
+    (0x9afb, 0x132a),
+    (0x9afc, 0x2ffe),
+    (0x9afd, 0x2ffc),
+    (0x9afe, 0x2ffb),
+    (0x9b01, 0x2ffd),
+    (0x9b03, 0x14e9),
+    (0x9b04, 0x316a),
+    (0x9b05, 0x316b),
+    (0x9b06, 0x14ea),
+    (0x9b08, 0x3169),
+    (0x9b0a, 0x32ab),
+    (0x9b0b, 0x32aa),
+    (0x9b0c, 0x32ad),
+    (0x9b0d, 0x157e),
+    (0x9b0e, 0x32ac),
+    (0x9b10, 0x33ab),
+    (0x9b11, 0x33ad),
+    (0x9b12, 0x33ac),
+    (0x9b15, 0x3462),
+    (0x9b16, 0x3465),
+    (0x9b17, 0x3463),
+    (0x9b18, 0x3464),
+    (0x9b19, 0x3501),
+    (0x9b1a, 0x166f),
+    (0x9b1e, 0x356f),
+    (0x9b1f, 0x3570),
+    (0x9b20, 0x3571),
+    (0x9b22, 0x16bc),
+    (0x9b23, 0x16d4),
+    (0x9b24, 0x364c),
+    (0x9b25, 0x097c),
+    (0x9b27, 0x1201),
+    (0x9b28, 0x132c),
+    (0x9b29, 0x316c),
+    (0x9b2b, 0x3502),
+    (0x9b2e, 0x365a),
+    (0x9b2f, 0x1f51),
+    (0x9b31, 0x16ec),
+    (0x9b32, 0x097d),
+    (0x9b33, 0x2e31),
+    (0x9b35, 0x316d),
+    (0x9b37, 0x32ae),
+    (0x9b3a, 0x3466),
+    (0x9b3b, 0x3503),
+    (0x9b3c, 0x097e),
+    (0x9b3e, 0x2992),
+    (0x9b3f, 0x2993),
+    (0x9b41, 0x107d),
+    (0x9b42, 0x107e),
+    (0x9b43, 0x2c02),
+    (0x9b44, 0x1203),
+    (0x9b45, 0x1202),
+    (0x9b46, 0x2c01),
+    (0x9b48, 0x2fff),
+    (0x9b4a, 0x316e),
+    (0x9b4b, 0x3170),
+    (0x9b4c, 0x316f),
+    (0x9b4d, 0x14ed),
+    (0x9b4e, 0x14ec),
+    (0x9b4f, 0x14eb),
+    (0x9b51, 0x1632),
+    (0x9b52, 0x3467),
+    (0x9b54, 0x1631),
+    (0x9b55, 0x3505),
+    (0x9b56, 0x3504),
+    (0x9b58, 0x16bd),
+    (0x9b59, 0x35ce),
+    (0x9b5a, 0x0b65),
+    (0x9b5b, 0x272b),
+    (0x9b5f, 0x2996),
+    (0x9b60, 0x2994),
+    (0x9b61, 0x2995),
+    (0x9b64, 0x2c0b),
+    (0x9b66, 0x2c06),
+    (0x9b67, 0x2c03),
+    (0x9b68, 0x2c0a),
+    (0x9b6c, 0x2c0c),
+    (0x9b6f, 0x1205),
+    (0x9b70, 0x2c09),
+    (0x9b71, 0x2c05),
+    (0x9b74, 0x2c04),
+    (0x9b75, 0x2c08),
+    (0x9b76, 0x2c07),
+    (0x9b77, 0x1204),
+    (0x9b7a, 0x2e3c),
+    (0x9b7b, 0x2e37),
+    (0x9b7c, 0x2e35),
+    (0x9b7d, 0x2e3e),
+    (0x9b7e, 0x2e36),
+    (0x9b80, 0x2e32),
+    (0x9b82, 0x2e38),
+    (0x9b85, 0x2e33),
+    (0x9b86, 0x3008),
+    (0x9b87, 0x2e34),
+    (0x9b88, 0x2e3f),
+    (0x9b90, 0x2e3b),
+    (0x9b91, 0x132d),
+    (0x9b92, 0x2e3a),
+    (0x9b93, 0x2e39),
+    (0x9b95, 0x2e3d),
+    (0x9b9a, 0x3000),
+    (0x9b9b, 0x3003),
+    (0x9b9e, 0x3002),
+    (0x9ba0, 0x300a),
+    (0x9ba1, 0x3005),
+    (0x9ba2, 0x3009),
+    (0x9ba4, 0x3007),
+    (0x9ba5, 0x3006),
+    (0x9ba6, 0x3004),
+    (0x9ba8, 0x3001),
+    (0x9baa, 0x1440),
+    (0x9bab, 0x143f),
+    (0x9bad, 0x1441),
+    (0x9bae, 0x143e),
+    (0x9baf, 0x300b),
+    (0x9bb5, 0x3176),
+    (0x9bb6, 0x3179),
+    (0x9bb8, 0x3177),
+    (0x9bb9, 0x317b),
+    (0x9bbd, 0x317c),
+    (0x9bbf, 0x3174),
+    (0x9bc0, 0x14f2),
+    (0x9bc1, 0x3175),
+    (0x9bc3, 0x3173),
+    (0x9bc4, 0x317a),
+    (0x9bc6, 0x3172),
+    (0x9bc7, 0x3171),
+    (0x9bc8, 0x14f1),
+    (0x9bc9, 0x14ef),
+    (0x9bca, 0x14ee),
+    (0x9bd3, 0x3178),
+    (0x9bd4, 0x32b7),
+    (0x9bd5, 0x32bd),
+    (0x9bd6, 0x1581),
+    (0x9bd7, 0x32b8),
+    (0x9bd9, 0x32bb),
+    (0x9bda, 0x32bf),
+    (0x9bdb, 0x1582),
+    (0x9bdc, 0x32ba),
+    (0x9bde, 0x32b2),
+    (0x9be0, 0x32b1),
+    (0x9be1, 0x32be),
+    (0x9be2, 0x32b5),
+    (0x9be4, 0x32b3),
+    (0x9be5, 0x32bc),
+    (0x9be6, 0x32b4),
+    (0x9be7, 0x1580),
+    (0x9be8, 0x157f),
+    (0x9bea, 0x32af),
+    (0x9beb, 0x32b0),
+    (0x9bec, 0x32b9),
+    (0x9bf0, 0x32b6),
+    (0x9bf7, 0x33b0),
+    (0x9bf8, 0x33b3),
+    (0x9bfd, 0x14f0),
+    (0x9c05, 0x33b1),
+    (0x9c06, 0x33b7),
+    (0x9c07, 0x33b5),
+    (0x9c08, 0x33af),
+    (0x9c09, 0x33ba),
+    (0x9c0b, 0x33ae),
+    (0x9c0d, 0x15e4),
+    (0x9c0e, 0x33b6),
+    (0x9c12, 0x33b2),
+    (0x9c13, 0x15e3),
+    (0x9c14, 0x33b9),
+    (0x9c17, 0x33b8),
+    (0x9c1c, 0x346a),
+    (0x9c1d, 0x3469),
+    (0x9c21, 0x3470),
+    (0x9c23, 0x346c),
+    (0x9c24, 0x346f),
+    (0x9c25, 0x1634),
+    (0x9c28, 0x346d),
+    (0x9c29, 0x346e),
+    (0x9c2b, 0x3468),
+    (0x9c2c, 0x346b),
+    (0x9c2d, 0x1633),
+    (0x9c31, 0x1671),
+    (0x9c32, 0x3510),
+    (0x9c33, 0x350b),
+    (0x9c34, 0x350f),
+    (0x9c36, 0x3512),
+    (0x9c37, 0x350e),
+    (0x9c39, 0x350a),
+    (0x9c3b, 0x1673),
+    (0x9c3c, 0x350d),
+    (0x9c3d, 0x3511),
+    (0x9c3e, 0x1672),
+    (0x9c3f, 0x3508),
+    (0x9c40, 0x33b4),
+    (0x9c41, 0x350c),
+    (0x9c44, 0x3509),
+    (0x9c46, 0x3506),
+    (0x9c48, 0x3507),
+    (0x9c49, 0x1670),
+    (0x9c4a, 0x3575),
+    (0x9c4b, 0x3577),
+    (0x9c4c, 0x357a),
+    (0x9c4d, 0x3576),
+    (0x9c4e, 0x357b),
+    (0x9c50, 0x3574),
+    (0x9c52, 0x3572),
+    (0x9c54, 0x169d),
+    (0x9c55, 0x3578),
+    (0x9c56, 0x169f),
+    (0x9c57, 0x169e),
+    (0x9c58, 0x3573),
+    (0x9c59, 0x3579),
+    (0x9c5e, 0x35d3),
+    (0x9c5f, 0x16be),
+    (0x9c60, 0x35d4),
+    (0x9c62, 0x35d2),
+    (0x9c63, 0x35cf),
+    (0x9c66, 0x35d1),
+    (0x9c67, 0x35d0),
+    (0x9c68, 0x3612),
+    (0x9c6d, 0x3614),
+    (0x9c6e, 0x3613),
+    (0x9c71, 0x3631),
+    (0x9c73, 0x3630),
+    (0x9c74, 0x362f),
+    (0x9c75, 0x3632),
+    (0x9c77, 0x16e4),
+    (0x9c78, 0x16e5),
+    (0x9c79, 0x3660),
+    (0x9c7a, 0x3664),
+    (0x9ce5, 0x0b66),
+    (0x9ce6, 0x247c),
+    (0x9ce7, 0x272e),
+    (0x9ce9, 0x0efd),
+    (0x9cea, 0x272c),
+    (0x9ced, 0x272d),
+    (0x9cf1, 0x2997),
+    (0x9cf2, 0x2998),
+    (0x9cf3, 0x1081),
+    (0x9cf4, 0x107f),
+    (0x9cf5, 0x2999),
+    (0x9cf6, 0x1080),
+    (0x9cf7, 0x2c11),
+    (0x9cf9, 0x2c14),
+    (0x9cfa, 0x2c0e),
+    (0x9cfb, 0x2c15),
+    (0x9cfc, 0x2c0d),
+    (0x9cfd, 0x2c0f),
+    (0x9cff, 0x2c10),
+    (0x9d00, 0x2c13),
+    (0x9d03, 0x1208),
+    (0x9d04, 0x2c18),
+    (0x9d05, 0x2c17),
+    (0x9d06, 0x1206),
+    (0x9d07, 0x2c12),
+    (0x9d08, 0x2c16),
+    (0x9d09, 0x1207),
+    (0x9d10, 0x2e49),
+    (0x9d12, 0x1332),
+    (0x9d14, 0x2e44),
+    (0x9d15, 0x132e),
+    (0x9d17, 0x2e41),
+    (0x9d18, 0x2e47),
+    (0x9d19, 0x2e4a),
+    (0x9d1b, 0x1333),
+    (0x9d1d, 0x2e46),
+    (0x9d1e, 0x2e43),
+    (0x9d1f, 0x2e4b),
+    (0x9d20, 0x2e42),
+    (0x9d22, 0x2e48),
+    (0x9d23, 0x132f),
+    (0x9d25, 0x2e40),
+    (0x9d26, 0x1330),
+    (0x9d28, 0x1331),
+    (0x9d29, 0x2e45),
+    (0x9d2d, 0x301d),
+    (0x9d2e, 0x3010),
+    (0x9d2f, 0x3011),
+    (0x9d30, 0x3014),
+    (0x9d31, 0x3012),
+    (0x9d33, 0x300c),
+    (0x9d36, 0x300f),
+    (0x9d37, 0x3019),
+    (0x9d38, 0x3013),
+    (0x9d3b, 0x1442),
+    (0x9d3d, 0x301b),
+    (0x9d3e, 0x3018),
+    (0x9d3f, 0x1443),
+    (0x9d40, 0x301a),
+    (0x9d41, 0x300d),
+    (0x9d42, 0x3016),
+    (0x9d43, 0x3017),
+    (0x9d45, 0x3015),
+    (0x9d4a, 0x3180),
+    (0x9d4b, 0x3182),
+    (0x9d4c, 0x3185),
+    (0x9d4f, 0x317f),
+    (0x9d51, 0x14f3),
+    (0x9d52, 0x3187),
+    (0x9d53, 0x317e),
+    (0x9d54, 0x3188),
+    (0x9d56, 0x3184),
+    (0x9d57, 0x3186),
+    (0x9d58, 0x318a),
+    (0x9d59, 0x3183),
+    (0x9d5a, 0x318b),
+    (0x9d5b, 0x3181),
+    (0x9d5c, 0x317d),
+    (0x9d5d, 0x14f4),
+    (0x9d5f, 0x3189),
+    (0x9d60, 0x14f5),
+    (0x9d61, 0x1584),
+    (0x9d67, 0x300e),
+    (0x9d68, 0x32d9),
+    (0x9d69, 0x32d0),
+    (0x9d6a, 0x1586),
+    (0x9d6b, 0x32cc),
+    (0x9d6c, 0x1587),
+    (0x9d6f, 0x32d5),
+    (0x9d70, 0x32cf),
+    (0x9d71, 0x32c5),
+    (0x9d72, 0x1585),
+    (0x9d73, 0x32d2),
+    (0x9d74, 0x32cd),
+    (0x9d75, 0x32ce),
+    (0x9d77, 0x32c0),
+    (0x9d78, 0x32c7),
+    (0x9d79, 0x32d6),
+    (0x9d7b, 0x32d3),
+    (0x9d7d, 0x32cb),
+    (0x9d7f, 0x32d7),
+    (0x9d80, 0x32c6),
+    (0x9d81, 0x32c1),
+    (0x9d82, 0x32d4),
+    (0x9d84, 0x32c3),
+    (0x9d85, 0x32d1),
+    (0x9d86, 0x32c8),
+    (0x9d87, 0x32d8),
+    (0x9d88, 0x32c4),
+    (0x9d89, 0x1583),
+    (0x9d8a, 0x32c2),
+    (0x9d8b, 0x32c9),
+    (0x9d8c, 0x32ca),
+    (0x9d90, 0x33c1),
+    (0x9d92, 0x33bf),
+    (0x9d94, 0x33c4),
+    (0x9d96, 0x33d0),
+    (0x9d97, 0x33c7),
+    (0x9d98, 0x33c0),
+    (0x9d99, 0x33bc),
+    (0x9d9a, 0x33c9),
+    (0x9d9b, 0x33c2),
+    (0x9d9c, 0x33c5),
+    (0x9d9d, 0x33be),
+    (0x9d9e, 0x33cc),
+    (0x9d9f, 0x33bb),
+    (0x9da0, 0x33c3),
+    (0x9da1, 0x33c8),
+    (0x9da2, 0x33ca),
+    (0x9da3, 0x33cd),
+    (0x9da4, 0x33bd),
+    (0x9da6, 0x33d1),
+    (0x9da7, 0x33d2),
+    (0x9da8, 0x33cb),
+    (0x9da9, 0x33cf),
+    (0x9daa, 0x33c6),
+    (0x9dac, 0x3480),
+    (0x9dad, 0x3483),
+    (0x9daf, 0x1635),
+    (0x9db1, 0x3482),
+    (0x9db2, 0x3487),
+    (0x9db3, 0x3485),
+    (0x9db4, 0x1636),
+    (0x9db5, 0x347c),
+    (0x9db6, 0x3472),
+    (0x9db7, 0x3471),
+    (0x9db8, 0x1638),
+    (0x9db9, 0x347e),
+    (0x9dba, 0x347f),
+    (0x9dbb, 0x347b),
+    (0x9dbc, 0x3473),
+    (0x9dbe, 0x3478),
+    (0x9dbf, 0x33ce),
+    (0x9dc1, 0x3474),
+    (0x9dc2, 0x1637),
+    (0x9dc3, 0x347a),
+    (0x9dc5, 0x3479),
+    (0x9dc7, 0x3475),
+    (0x9dc8, 0x3481),
+    (0x9dca, 0x3476),
+    (0x9dcb, 0x3517),
+    (0x9dcc, 0x3484),
+    (0x9dcd, 0x3486),
+    (0x9dce, 0x347d),
+    (0x9dcf, 0x3477),
+    (0x9dd0, 0x3518),
+    (0x9dd1, 0x351a),
+    (0x9dd2, 0x3514),
+    (0x9dd3, 0x1674),
+    (0x9dd5, 0x3521),
+    (0x9dd6, 0x351f),
+    (0x9dd7, 0x1675),
+    (0x9dd8, 0x351e),
+    (0x9dd9, 0x351d),
+    (0x9dda, 0x3516),
+    (0x9ddb, 0x3513),
+    (0x9ddc, 0x3519),
+    (0x9ddd, 0x3522),
+    (0x9dde, 0x3515),
+    (0x9ddf, 0x351b),
+    (0x9de1, 0x3584),
+    (0x9de2, 0x3589),
+    (0x9de3, 0x357f),
+    (0x9de4, 0x3582),
+    (0x9de5, 0x16a0),
+    (0x9de6, 0x3586),
+    (0x9de8, 0x358d),
+    (0x9de9, 0x351c),
+    (0x9deb, 0x3580),
+    (0x9dec, 0x358a),
+    (0x9ded, 0x358e),
+    (0x9dee, 0x3585),
+    (0x9def, 0x357e),
+    (0x9df0, 0x3588),
+    (0x9df2, 0x3587),
+    (0x9df3, 0x358c),
+    (0x9df4, 0x358b),
+    (0x9df5, 0x3520),
+    (0x9df6, 0x3583),
+    (0x9df7, 0x357d),
+    (0x9df8, 0x3581),
+    (0x9df9, 0x16bf),
+    (0x9dfa, 0x16c0),
+    (0x9dfb, 0x357c),
+    (0x9dfd, 0x35df),
+    (0x9dfe, 0x35d6),
+    (0x9dff, 0x35de),
+    (0x9e00, 0x35db),
+    (0x9e01, 0x35dc),
+    (0x9e02, 0x35d5),
+    (0x9e03, 0x35d8),
+    (0x9e04, 0x35e0),
+    (0x9e05, 0x35da),
+    (0x9e06, 0x35d9),
+    (0x9e07, 0x35d7),
+    (0x9e09, 0x35dd),
+    (0x9e0b, 0x3615),
+    (0x9e0d, 0x3616),
+    (0x9e0f, 0x3618),
+    (0x9e10, 0x3617),
+    (0x9e11, 0x361a),
+    (0x9e12, 0x3619),
+    (0x9e13, 0x3634),
+    (0x9e14, 0x3633),
+    (0x9e15, 0x364d),
+    (0x9e17, 0x364e),
+    (0x9e19, 0x365b),
+    (0x9e1a, 0x16e9),
+    (0x9e1b, 0x16ed),
+    (0x9e1d, 0x3665),
+    (0x9e1e, 0x16ee),
+    (0x9e75, 0x0b67),
+    (0x9e79, 0x15e5),
+    (0x9e7a, 0x3488),
+    (0x9e7c, 0x16c1),
+    (0x9e7d, 0x16c2),
+    (0x9e7f, 0x0b68),
+    (0x9e80, 0x272f),
+    (0x9e82, 0x0efe),
+    (0x9e83, 0x2c19),
+    (0x9e86, 0x2e4d),
+    (0x9e87, 0x2e4e),
+    (0x9e88, 0x2e4c),
+    (0x9e89, 0x301f),
+    (0x9e8a, 0x301e),
+    (0x9e8b, 0x1444),
+    (0x9e8c, 0x318d),
+    (0x9e8d, 0x3020),
+    (0x9e8e, 0x318c),
+    (0x9e91, 0x32db),
+    (0x9e92, 0x1588),
+    (0x9e93, 0x158a),
+    (0x9e94, 0x32da),
+    (0x9e97, 0x1589),
+    (0x9e99, 0x33d3),
+    (0x9e9a, 0x33d5),
+    (0x9e9b, 0x33d4),
+    (0x9e9c, 0x3489),
+    (0x9e9d, 0x1639),
+    (0x9e9f, 0x16a1),
+    (0x9ea0, 0x35e1),
+    (0x9ea1, 0x361b),
+    (0x9ea4, 0x3668),
+    (0x9ea5, 0x0b69),
+    (0x9ea7, 0x299a),
+    (0x9ea9, 0x1209),
+    (0x9ead, 0x2e50),
+    (0x9eae, 0x2e4f),
+    (0x9eb0, 0x3021),
+    (0x9eb4, 0x158b),
+    (0x9eb5, 0x15e6),
+    (0x9eb6, 0x3523),
+    (0x9eb7, 0x3661),
+    (0x9ebb, 0x0b6a),
+    (0x9ebc, 0x1082),
+    (0x9ebe, 0x120a),
+    (0x9ec0, 0x32dc),
+    (0x9ec2, 0x358f),
+    (0x9ec3, 0x0d3f),
+    (0x9ec8, 0x3022),
+    (0x9ecc, 0x16d5),
+    (0x9ecd, 0x0d40),
+    (0x9ece, 0x120b),
+    (0x9ecf, 0x1445),
+    (0x9ed0, 0x3590),
+    (0x9ed1, 0x0d41),
+    (0x9ed3, 0x2c1a),
+    (0x9ed4, 0x1335),
+    (0x9ed5, 0x2e51),
+    (0x9ed6, 0x2e52),
+    (0x9ed8, 0x1334),
+    (0x9eda, 0x3023),
+    (0x9edb, 0x1449),
+    (0x9edc, 0x1447),
+    (0x9edd, 0x1448),
+    (0x9ede, 0x1446),
+    (0x9edf, 0x318e),
+    (0x9ee0, 0x14f6),
+    (0x9ee4, 0x33d7),
+    (0x9ee5, 0x33d6),
+    (0x9ee6, 0x33d9),
+    (0x9ee7, 0x33d8),
+    (0x9ee8, 0x15e7),
+    (0x9eeb, 0x348a),
+    (0x9eed, 0x348c),
+    (0x9eee, 0x348b),
+    (0x9eef, 0x163a),
+    (0x9ef0, 0x3524),
+    (0x9ef2, 0x3591),
+    (0x9ef3, 0x3592),
+    (0x9ef4, 0x16a2),
+    (0x9ef5, 0x361c),
+    (0x9ef6, 0x3635),
+    (0x9ef7, 0x16e6),
+    (0x9ef9, 0x247d),
+    (0x9efa, 0x2e53),
+    (0x9efb, 0x3024),
+    (0x9efc, 0x32dd),
+    (0x9efd, 0x2730),
+    (0x9eff, 0x3025),
+    (0x9f00, 0x3190),
+    (0x9f01, 0x318f),
+    (0x9f06, 0x3593),
+    (0x9f07, 0x16c3),
+    (0x9f09, 0x361d),
+    (0x9f0a, 0x3636),
+    (0x9f0e, 0x0eff),
+    (0x9f0f, 0x2c1b),
+    (0x9f10, 0x2c1c),
+    (0x9f12, 0x2e54),
+    (0x9f13, 0x0f00),
+    (0x9f15, 0x14f7),
+    (0x9f16, 0x3191),
+    (0x9f18, 0x348e),
+    (0x9f19, 0x163b),
+    (0x9f1a, 0x348f),
+    (0x9f1b, 0x348d),
+    (0x9f1c, 0x3594),
+    (0x9f1e, 0x35e2),
+    (0x9f20, 0x0f01),
+    (0x9f22, 0x3028),
+    (0x9f23, 0x3027),
+    (0x9f24, 0x3026),
+    (0x9f25, 0x3192),
+    (0x9f28, 0x3196),
+    (0x9f29, 0x3195),
+    (0x9f2a, 0x3194),
+    (0x9f2b, 0x3193),
+    (0x9f2c, 0x14f8),
+    (0x9f2d, 0x32de),
+    (0x9f2e, 0x33db),
+    (0x9f2f, 0x15e8),
+    (0x9f30, 0x33da),
+    (0x9f31, 0x3490),
+    (0x9f32, 0x3527),
+    (0x9f33, 0x3526),
+    (0x9f34, 0x1676),
+    (0x9f35, 0x3525),
+    (0x9f36, 0x3597),
+    (0x9f37, 0x3596),
+    (0x9f38, 0x3595),
+    (0x9f3b, 0x1083),
+    (0x9f3d, 0x2e55),
+    (0x9f3e, 0x144a),
+    (0x9f40, 0x32df),
+    (0x9f41, 0x32e0),
+    (0x9f42, 0x3528),
+    (0x9f43, 0x3598),
+    (0x9f46, 0x35e3),
+    (0x9f47, 0x361e),
+    (0x9f48, 0x364f),
+    (0x9f49, 0x366a),
+    (0x9f4a, 0x1084),
+    (0x9f4b, 0x144b),
+    (0x9f4c, 0x3197),
+    (0x9f4d, 0x32e1),
+    (0x9f4e, 0x3491),
+    (0x9f4f, 0x3599),
+    (0x9f52, 0x120d),
+    (0x9f54, 0x3029),
+    (0x9f55, 0x3198),
+    (0x9f56, 0x32e2),
+    (0x9f57, 0x32e3),
+    (0x9f58, 0x32e4),
+    (0x9f59, 0x33e0),
+    (0x9f5b, 0x33dc),
+    (0x9f5c, 0x163c),
+    (0x9f5d, 0x33df),
+    (0x9f5e, 0x33de),
+    (0x9f5f, 0x15e9),
+    (0x9f60, 0x33dd),
+    (0x9f61, 0x15eb),
+    (0x9f63, 0x15ea),
+    (0x9f64, 0x3493),
+    (0x9f65, 0x3492),
+    (0x9f66, 0x163d),
+    (0x9f67, 0x163e),
+    (0x9f6a, 0x1678),
+    (0x9f6b, 0x3529),
+    (0x9f6c, 0x1677),
+    (0x9f6e, 0x359c),
+    (0x9f6f, 0x359d),
+    (0x9f70, 0x359b),
+    (0x9f71, 0x359a),
+    (0x9f72, 0x16c5),
+    (0x9f74, 0x35e4),
+    (0x9f75, 0x35e5),
+    (0x9f76, 0x35e6),
+    (0x9f77, 0x16c4),
+    (0x9f78, 0x361f),
+    (0x9f79, 0x3622),
+    (0x9f7a, 0x3621),
+    (0x9f7b, 0x3620),
+    (0x9f7e, 0x3669),
+    (0x9f8d, 0x1336),
+    (0x9f90, 0x14ff),
+    (0x9f91, 0x33e1),
+    (0x9f92, 0x3494),
+    (0x9f94, 0x1679),
+    (0x9f95, 0x352a),
+    (0x9f98, 0x366b),
+    (0x9f9c, 0x1337),
+    (0x9fa0, 0x302a),
+    (0x9fa2, 0x352b),
+    (0x9fa4, 0x3637),
+    (0xfa0c, 0x1892),
+    (0xfa0d, 0x2566),
+    (0xfe30, 0x000a),
+    (0xfe31, 0x0017),
+    (0xfe33, 0x0019),
+    (0xfe34, 0x001b),
+    (0xfe35, 0x001f),
+    (0xfe36, 0x0020),
+    (0xfe37, 0x0023),
+    (0xfe38, 0x0024),
+    (0xfe39, 0x0027),
+    (0xfe3a, 0x0028),
+    (0xfe3b, 0x002b),
+    (0xfe3c, 0x002c),
+    (0xfe3d, 0x002f),
+    (0xfe3e, 0x0030),
+    (0xfe3f, 0x0033),
+    (0xfe40, 0x0034),
+    (0xfe41, 0x0037),
+    (0xfe42, 0x0038),
+    (0xfe43, 0x003b),
+    (0xfe44, 0x003c),
+    (0xfe49, 0x0064),
+    (0xfe4a, 0x0065),
+    (0xfe4b, 0x0068),
+    (0xfe4c, 0x0069),
+    (0xfe4d, 0x0066),
+    (0xfe4e, 0x0067),
+    (0xfe4f, 0x001c),
+    (0xfe50, 0x000d),
+    (0xfe52, 0x000f),
+    (0xfe54, 0x0011),
+    (0xfe55, 0x0012),
+    (0xfe56, 0x0013),
+    (0xfe57, 0x0014),
+    (0xfe59, 0x003d),
+    (0xfe5a, 0x003e),
+    (0xfe5b, 0x003f),
+    (0xfe5c, 0x0040),
+    (0xfe5d, 0x0041),
+    (0xfe5e, 0x0042),
+    (0xfe5f, 0x006a),
+    (0xfe60, 0x006b),
+    (0xfe61, 0x006c),
+    (0xfe62, 0x007c),
+    (0xfe63, 0x007d),
+    (0xfe64, 0x007e),
+    (0xfe65, 0x007f),
+    (0xfe66, 0x0080),
+    (0xfe69, 0x00a9),
+    (0xfe6a, 0x00aa),
+    (0xfe6b, 0x00ab),
+    (0xff01, 0x0009),
+    (0xff03, 0x004b),
+    (0xff04, 0x00a0),
+    (0xff05, 0x00a5),
+    (0xff06, 0x004c),
+    (0xff08, 0x001d),
+    (0xff09, 0x001e),
+    (0xff0a, 0x004d),
+    (0xff0b, 0x006d),
+    (0xff0c, 0x0001),
+    (0xff0d, 0x006e),
+    (0xff0e, 0x0004),
+    (0xff0f, 0x009e),
+    (0xff10, 0x00ea),
+    (0xff11, 0x00eb),
+    (0xff12, 0x00ec),
+    (0xff13, 0x00ed),
+    (0xff14, 0x00ee),
+    (0xff15, 0x00ef),
+    (0xff16, 0x00f0),
+    (0xff17, 0x00f1),
+    (0xff18, 0x00f2),
+    (0xff19, 0x00f3),
+    (0xff1a, 0x0007),
+    (0xff1b, 0x0006),
+    (0xff1c, 0x0073),
+    (0xff1d, 0x0075),
+    (0xff1e, 0x0074),
+    (0xff1f, 0x0008),
+    (0xff20, 0x00a6),
+    (0xff21, 0x010a),
+    (0xff22, 0x010b),
+    (0xff23, 0x010c),
+    (0xff24, 0x010d),
+    (0xff25, 0x010e),
+    (0xff26, 0x010f),
+    (0xff27, 0x0110),
+    (0xff28, 0x0111),
+    (0xff29, 0x0112),
+    (0xff2a, 0x0113),
+    (0xff2b, 0x0114),
+    (0xff2c, 0x0115),
+    (0xff2d, 0x0116),
+    (0xff2e, 0x0117),
+    (0xff2f, 0x0118),
+    (0xff30, 0x0119),
+    (0xff31, 0x011a),
+    (0xff32, 0x011b),
+    (0xff33, 0x011c),
+    (0xff34, 0x011d),
+    (0xff35, 0x011e),
+    (0xff36, 0x011f),
+    (0xff37, 0x0120),
+    (0xff38, 0x0121),
+    (0xff39, 0x0122),
+    (0xff3a, 0x0123),
+    (0xff3c, 0x009f),
+    (0xff3f, 0x0062),
+    (0xff41, 0x0124),
+    (0xff42, 0x0125),
+    (0xff43, 0x0126),
+    (0xff44, 0x0127),
+    (0xff45, 0x0128),
+    (0xff46, 0x0129),
+    (0xff47, 0x012a),
+    (0xff48, 0x012b),
+    (0xff49, 0x012c),
+    (0xff4a, 0x012d),
+    (0xff4b, 0x012e),
+    (0xff4c, 0x012f),
+    (0xff4d, 0x0130),
+    (0xff4e, 0x0131),
+    (0xff4f, 0x0132),
+    (0xff50, 0x0133),
+    (0xff51, 0x0134),
+    (0xff52, 0x0135),
+    (0xff53, 0x0136),
+    (0xff54, 0x0137),
+    (0xff55, 0x0138),
+    (0xff56, 0x0139),
+    (0xff57, 0x013a),
+    (0xff58, 0x013b),
+    (0xff59, 0x013c),
+    (0xff5a, 0x013d),
+    (0xff5b, 0x0021),
+    (0xff5c, 0x0015),
+    (0xff5d, 0x0022),
+    (0xff64, 0x000e),
+    (0xffe3, 0x0061),
+];
@@ -0,0 +1,561 @@
+#![allow(clippy::missing_safety_doc)]
+
+use std::os::raw::c_int;
+
+/// `xmlCharEncError` codes returned by the converters.
+pub const ENC_ERR_SUCCESS: c_int = 0;
+pub const ENC_ERR_INTERNAL: c_int = -1;
+pub const ENC_ERR_INPUT: c_int = -2;
+pub const ENC_ERR_SPACE: c_int = -3;
+
+/// `xmlCharEncoding` values with a built-in converter.
+pub const CHAR_ENCODING_UTF8: c_int = 1;
+pub const CHAR_ENCODING_UTF16LE: c_int = 2;
+pub const CHAR_ENCODING_UTF16BE: c_int = 3;
+pub const CHAR_ENCODING_UCS4LE: c_int = 4;
+pub const CHAR_ENCODING_UCS4BE: c_int = 5;
+pub const CHAR_ENCODING_8859_1: c_int = 10;
+pub const CHAR_ENCODING_SHIFT_JIS: c_int = 20;
+pub const CHAR_ENCODING_EUC_JP: c_int = 21;
+pub const CHAR_ENCODING_ASCII: c_int = 22;
+pub const CHAR_ENCODING_UTF16: c_int = 23;
+
+/// Result of one converter call: what C reports through `*inlen`,
+/// `*outlen` and the return value.
+///
+/// `ret` is the number of bytes written when all the input that could be
+/// converted was, or one of the `ENC_ERR_*` codes. A truncated multi-byte
+/// sequence at the end of the input is left unread and is not an error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Converted {
+    pub read: usize,
+    pub written: usize,
+    pub ret: c_int,
+}
+
+impl Converted {
+    pub(super) fn done(read: usize, written: usize) -> Self {
+        Converted {
+            read,
+            written,
+            ret: written as c_int,
+        }
+    }
+
+    pub(super) fn stop(read: usize, written: usize, ret: c_int) -> Self {
+        Converted { read, written, ret }
+    }
+}
+
+/// A converter from the built-in handler table.
+pub type Converter = fn(&mut [u8], &[u8]) -> Converted;
+
+/// Input (to UTF-8) and output (from UTF-8) converters of the built-in
+/// handler for `enc`, as in the C `defaultHandlers` table. UCS-4 and,
+/// with the `shift-jis` and `euc-jp` features, Shift-JIS and EUC-JP have
+/// converters here while C leaves them to iconv.
+pub fn converters(enc: c_int) -> Option<(Converter, Converter)> {
+    match enc {
+        CHAR_ENCODING_UTF8 => Some((utf8_to_utf8, utf8_to_utf8)),
+        CHAR_ENCODING_UTF16LE | CHAR_ENCODING_UTF16 => Some((utf16le_to_utf8, utf8_to_utf16le)),
+        CHAR_ENCODING_UTF16BE => Some((utf16be_to_utf8, utf8_to_utf16be)),
+        CHAR_ENCODING_UCS4LE => Some((utf32le_to_utf8, utf8_to_utf32le)),
+        CHAR_ENCODING_UCS4BE => Some((utf32be_to_utf8, utf8_to_utf32be)),
+        CHAR_ENCODING_8859_1 => Some((latin1_to_utf8, utf8_to_latin1)),
+        CHAR_ENCODING_ASCII => Some((ascii_to_ascii, ascii_to_ascii)),
+        #[cfg(feature = "shift-jis")]
+        CHAR_ENCODING_SHIFT_JIS => Some((super::sjis_to_utf8, super::utf8_to_sjis)),
+        #[cfg(feature = "euc-jp")]
+        CHAR_ENCODING_EUC_JP => Some((super::euc_jp_to_utf8, super::utf8_to_euc_jp)),
+        _ => None,
+    }
+}
+
+/// Converters for an encoding name, ignoring case. Covers the names that
+/// only these converters handle and which C hands to iconv; the names in
+/// the C alias table resolve through `converters`. Big5, which has no
+/// `xmlCharEncoding` value, is only found by name.
+pub fn converters_by_name(name: &str) -> Option<(Converter, Converter)> {
+    match name.to_ascii_lowercase().as_str() {
+        "utf-32" | "utf32" | "utf-32le" | "utf32le" | "ucs-4" | "ucs4" | "ucs-4le"
+        | "iso-10646-ucs-4" => converters(CHAR_ENCODING_UCS4LE),
+        "utf-32be" | "utf32be" | "ucs-4be" => converters(CHAR_ENCODING_UCS4BE),
+        #[cfg(feature = "shift-jis")]
+        "shift_jis" | "shift-jis" | "sjis" | "x-sjis" | "ms_kanji" | "csshiftjis" => {
+            converters(CHAR_ENCODING_SHIFT_JIS)
+        }
+        #[cfg(feature = "euc-jp")]
+        "euc-jp" | "eucjp" | "euc_jp" | "x-euc-jp" | "cseucpkdfmtjapanese" => {
+            converters(CHAR_ENCODING_EUC_JP)
+        }
+        #[cfg(feature = "big5")]
+        "big5" | "big-5" | "big-five" | "bigfive" | "csbig5" | "cn-big5" => {
+            Some((super::big5_to_utf8, super::utf8_to_big5))
+        }
+        _ => None,
+    }
+}
+
+/// The `xmlCharEncoding` that the C alias table `xmlEncTable` gives
+/// `name`, ignoring case, as `xmlParseCharEncoding` looks it up. Only the
+/// entries for encodings with a constant here are kept; names of the
+/// other 8859 parts, UCS-2, ISO-2022-JP, windows-1252 and HTML give
+/// `None`. Like C, `iso8859-10`, `-13`, `-14` and `-15` map to Latin-1.
+pub fn char_encoding_by_name(name: &str) -> Option<c_int> {
+    match name.to_ascii_lowercase().as_str() {
+        "utf-8" | "utf8" | "unicode-1-1-utf-8" | "unicode11utf8" | "unicode20utf8"
+        | "x-unicode20utf8" => Some(CHAR_ENCODING_UTF8),
+        "utf-16le" | "unicodefeff" => Some(CHAR_ENCODING_UTF16LE),
+        "utf-16be" | "unicodefffe" => Some(CHAR_ENCODING_UTF16BE),
+        "utf-16" | "utf16" | "unicode" | "csunicode" => Some(CHAR_ENCODING_UTF16),
+        "ucs-4" | "ucs4" | "iso-10646-ucs-4" => Some(CHAR_ENCODING_UCS4LE),
+        "iso-8859-1" | "iso8859-1" | "iso88591" | "iso_8859-1" | "iso_8859-1:1987"
+        | "iso-latin-1" | "iso latin 1" | "iso-ir-100" | "latin1" | "l1" | "cp819" | "ibm819"
+        | "csisolatin1" | "iso8859-10" | "iso8859-13" | "iso8859-14" | "iso8859-15" => {
+            Some(CHAR_ENCODING_8859_1)
+        }
+        "us-ascii" | "ascii" | "ansi_x3.4-1968" => Some(CHAR_ENCODING_ASCII),
+        "shift_jis" | "shift-jis" | "sjis" | "x-sjis" | "ms_kanji" | "ms932" | "windows-31j"
+        | "csshiftjis" => Some(CHAR_ENCODING_SHIFT_JIS),
+        "euc-jp" | "x-euc-jp" | "cseucpkdfmtjapanese" => Some(CHAR_ENCODING_EUC_JP),
+        _ => None,
+    }
+}
+
+/// The UTF-32 byte order mark at the start of `input`, as the encoding it
+/// selects. The mark is 4 bytes long and is not passed to the converter.
+pub fn detect_utf32_bom(input: &[u8]) -> Option<c_int> {
+    match input.get(..4)? {
+        [0xFF, 0xFE, 0x00, 0x00] => Some(CHAR_ENCODING_UCS4LE),
+        [0x00, 0x00, 0xFE, 0xFF] => Some(CHAR_ENCODING_UCS4BE),
+        _ => None,
+    }
+}
+
+/// Copy ASCII, failing on the first byte above 0x7F.
+pub fn ascii_to_ascii(out: &mut [u8], input: &[u8]) -> Converted {
+    let (len, ret) = if out.len() < input.len() {
+        (out.len(), ENC_ERR_SPACE)
+    } else {
+        (input.len(), input.len() as c_int)
+    };
+    if let Some(bad) = input[..len].iter().position(|&c| c >= 0x80) {
+        out[..bad].copy_from_slice(&input[..bad]);
+        return Converted::stop(bad, bad, ENC_ERR_INPUT);
+    }
+    out[..len].copy_from_slice(&input[..len]);
+    Converted::stop(len, len, ret)
+}
+
+/// Copy UTF-8 without checking it.
+pub fn utf8_to_utf8(out: &mut [u8], input: &[u8]) -> Converted {
+    let (len, ret) = if out.len() < input.len() {
+        (out.len(), ENC_ERR_SPACE)
+    } else {
+        (input.len(), input.len() as c_int)
+    };
+    out[..len].copy_from_slice(&input[..len]);
+    Converted::stop(len, len, ret)
+}
+
+pub fn latin1_to_utf8(out: &mut [u8], input: &[u8]) -> Converted {
+    let mut o = 0;
+    for (i, &c) in input.iter().enumerate() {
+        if c < 0x80 {
+            if o >= out.len() {
+                return Converted::stop(i, o, ENC_ERR_SPACE);
+            }
+            out[o] = c;
+            o += 1;
+        } else {
+            if out.len() - o < 2 {
+                return Converted::stop(i, o, ENC_ERR_SPACE);
+            }
+            out[o] = (c >> 6) | 0xC0;
+            out[o + 1] = (c & 0x3F) | 0x80;
+            o += 2;
+        }
+    }
+    Converted::done(input.len(), o)
+}
+
+/// Convert UTF-8 to Latin-1. Like C, the continuation byte after C2 or C3
+/// is not checked.
+pub fn utf8_to_latin1(out: &mut [u8], input: &[u8]) -> Converted {
+    let mut i = 0;
+    let mut o = 0;
+    while i < input.len() {
+        if o >= out.len() {
+            return Converted::stop(i, o, ENC_ERR_SPACE);
+        }
+        let c = input[i];
+        if c < 0x80 {
+            out[o] = c;
+        } else if c == 0xC2 || c == 0xC3 {
+            if input.len() - i < 2 {
+                break;
+            }
+            i += 1;
+            out[o] = (c << 6) | (input[i] & 0x3F);
+        } else {
+            return Converted::stop(i, o, ENC_ERR_INPUT);
+        }
+        o += 1;
+        i += 1;
+    }
+    Converted::done(i, o)
+}
+
+fn utf16_to_utf8(out: &mut [u8], input: &[u8], unit: fn(u8, u8) -> u32) -> Converted {
+    let end = input.len() & !1;
+    let mut i = 0;
+    let mut o = 0;
+    while i < end {
+        let mut c = unit(input[i], input[i + 1]);
+        let (len, size) = if c < 0x80 {
+            (2, 1)
+        } else if c < 0x800 {
+            (2, 2)
+        } else if c & 0xF800 != 0xD800 {
+            (2, 3)
+        } else {
+            if c & 0xFC00 != 0xD800 {
+                return Converted::stop(i, o, ENC_ERR_INPUT);
+            }
+            if end - i < 4 {
+                break;
+            }
+            let d = unit(input[i + 2], input[i + 3]);
+            if d & 0xFC00 != 0xDC00 {
+                return Converted::stop(i, o, ENC_ERR_INPUT);
+            }
+            c = 0x10000 + ((c - 0xD800) << 10) + (d - 0xDC00);
+            (4, 4)
+        };
+        if out.len() - o < size {
+            return Converted::stop(i, o, ENC_ERR_SPACE);
+        }
+        encode_utf8(&mut out[o..o + size], c);
+        i += len;
+        o += size;
+    }
+    Converted::done(i, o)
+}
+
+/// Number of UTF-8 bytes for the code point `c`.
+pub(super) fn utf8_size(c: u32) -> usize {
+    match c {
+        0..=0x7F => 1,
+        0x80..=0x7FF => 2,
+        0x800..=0xFFFF => 3,
+        _ => 4,
+    }
+}
+
+/// Write `c` as UTF-8 into exactly `out.len()` bytes.
+pub(super) fn encode_utf8(out: &mut [u8], c: u32) {
+    let lead: u32 = match out.len() {
+        1 => 0,
+        2 => 0xC0,
+        3 => 0xE0,
+        _ => 0xF0,
+    };
+    let last = out.len() - 1;
+    for (k, byte) in out.iter_mut().enumerate() {
+        let shift = 6 * (last - k);
+        *byte = if k == 0 {
+            (lead | (c >> shift)) as u8
+        } else {
+            (((c >> shift) & 0x3F) | 0x80) as u8
+        };
+    }
+}
+
+/// Decode the multi-byte UTF-8 sequence at the start of `input`, whose
+/// lead byte is at least 0x80. `Ok(None)` if the sequence is cut off,
+/// `Err` for bad lead or continuation bytes, overlong forms, surrogates
+/// and values above U+10FFFF.
+pub(super) fn decode_utf8(input: &[u8]) -> Result<Option<(u32, usize)>, ()> {
+    let c = input[0] as u32;
+    let (mut c, len, min) = if c < 0xE0 {
+        if c < 0xC2 {
+            return Err(());
+        }
+        (c & 0x1F, 2, 0x80)
+    } else if c < 0xF0 {
+        (c & 0x0F, 3, 0x800)
+    } else {
+        (c & 0x0F, 4, 0x10000)
+    };
+    if input.len() < len {
+        return Ok(None);
+    }
+    for &b in &input[1..len] {
+        if b & 0xC0 != 0x80 {
+            return Err(());
+        }
+        c = (c << 6) | (b & 0x3F) as u32;
+    }
+    if c < min || (0xD800..=0xDFFF).contains(&c) || c > 0x10FFFF {
+        return Err(());
+    }
+    Ok(Some((c, len)))
+}
+
+fn utf8_to_utf16(out: &mut [u8], input: &[u8], put: fn(&mut [u8], u16)) -> Converted {
+    let end = out.len() & !1;
+    let mut i = 0;
+    let mut o = 0;
+    while i < input.len() {
+        let c = input[i] as u32;
+        if c < 0x80 {
+            if o >= end {
+                return Converted::stop(i, o, ENC_ERR_SPACE);
+            }
+            put(&mut out[o..], c as u16);
+            i += 1;
+            o += 2;
+            continue;
+        }
+
+        let (c, len) = match decode_utf8(&input[i..]) {
+            Ok(Some(decoded)) => decoded,
+            Ok(None) => break,
+            Err(()) => return Converted::stop(i, o, ENC_ERR_INPUT),
+        };
+        if c < 0x10000 {
+            if o >= end {
+                return Converted::stop(i, o, ENC_ERR_SPACE);
+            }
+            put(&mut out[o..], c as u16);
+            o += 2;
+        } else {
+            if end - o < 4 {
+                return Converted::stop(i, o, ENC_ERR_SPACE);
+            }
+            let c = c - 0x10000;
+            put(&mut out[o..], (0xD800 | (c >> 10)) as u16);
+            put(&mut out[o + 2..], (0xDC00 | (c & 0x03FF)) as u16);
+            o += 4;
+        }
+        i += len;
+    }
+    Converted::done(i, o)
+}
+
+pub fn utf16le_to_utf8(out: &mut [u8], input: &[u8]) -> Converted {
+    utf16_to_utf8(out, input, |lo, hi| u16::from_le_bytes([lo, hi]) as u32)
+}
+
+pub fn utf16be_to_utf8(out: &mut [u8], input: &[u8]) -> Converted {
+    utf16_to_utf8(out, input, |hi, lo| u16::from_be_bytes([hi, lo]) as u32)
+}
+
+pub fn utf8_to_utf16le(out: &mut [u8], input: &[u8]) -> Converted {
+    utf8_to_utf16(out, input, |out, unit| {
+        out[..2].copy_from_slice(&unit.to_le_bytes())
+    })
+}
+
+pub fn utf8_to_utf16be(out: &mut [u8], input: &[u8]) -> Converted {
+    utf8_to_utf16(out, input, |out, unit| {
+        out[..2].copy_from_slice(&unit.to_be_bytes())
+    })
+}
+
+/// The initialization call of the "UTF-16" output converter, which writes
+/// a little-endian byte order mark if there is room for it.
+pub fn utf16_bom(out: &mut [u8]) -> Converted {
+    if out.len() < 2 {
+        return Converted::done(0, 0);
+    }
+    out[..2].copy_from_slice(&[0xFF, 0xFE]);
+    Converted::done(0, 2)
+}
+
+fn utf32_to_utf8(out: &mut [u8], input: &[u8], unit: fn([u8; 4]) -> u32) -> Converted {
+    let end = input.len() & !3;
+    let mut i = 0;
+    let mut o = 0;
+    while i < end {
+        let c = unit([input[i], input[i + 1], input[i + 2], input[i + 3]]);
+        if c > 0x10FFFF || (0xD800..=0xDFFF).contains(&c) {
+            return Converted::stop(i, o, ENC_ERR_INPUT);
+        }
+        let size = utf8_size(c);
+        if out.len() - o < size {
+            return Converted::stop(i, o, ENC_ERR_SPACE);
+        }
+        encode_utf8(&mut out[o..o + size], c);
+        i += 4;
+        o += size;
+    }
+    Converted::done(i, o)
+}
+
+fn utf8_to_utf32(out: &mut [u8], input: &[u8], put: fn(u32) -> [u8; 4]) -> Converted {
+    let end = out.len() & !3;
+    let mut i = 0;
+    let mut o = 0;
+    while i < input.len() {
+        let decoded = if input[i] < 0x80 {
+            Ok(Some((input[i] as u32, 1)))
+        } else {
+            decode_utf8(&input[i..])
+        };
+        // A cut-off sequence ends the input even when the output is full,
+        // and a full output is reported before invalid input, as iconv
+        // does.
+        let (c, len) = match decoded {
+            Ok(None) => break,
+            _ if o >= end => return Converted::stop(i, o, ENC_ERR_SPACE),
+            Ok(Some(decoded)) => decoded,
+            Err(()) => return Converted::stop(i, o, ENC_ERR_INPUT),
+        };
+        out[o..o + 4].copy_from_slice(&put(c));
+        i += len;
+        o += 4;
+    }
+    Converted::done(i, o)
+}
+
+/// Convert UTF-32LE to UTF-8, rejecting surrogates and values above
+/// U+10FFFF. Like the UTF-16 readers, a trailing partial unit is left
+/// unread.
+pub fn utf32le_to_utf8(out: &mut [u8], input: &[u8]) -> Converted {
+    utf32_to_utf8(out, input, u32::from_le_bytes)
+}
+
+pub fn utf32be_to_utf8(out: &mut [u8], input: &[u8]) -> Converted {
+    utf32_to_utf8(out, input, u32::from_be_bytes)
+}
+
+/// Convert UTF-8 to UTF-32LE. Only whole units of the output are used.
+pub fn utf8_to_utf32le(out: &mut [u8], input: &[u8]) -> Converted {
+    utf8_to_utf32(out, input, u32::to_le_bytes)
+}
+
+pub fn utf8_to_utf32be(out: &mut [u8], input: &[u8]) -> Converted {
+    utf8_to_utf32(out, input, u32::to_be_bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(convert: Converter, input: &[u8], space: usize) -> (Vec<u8>, Converted) {
+        let mut out = vec![0; space];
+        let res = convert(&mut out, input);
+        out.truncate(res.written);
+        (out, res)
+    }
+
+    #[test]
+    fn test_latin1_round_trip() {
+        let (utf8, res) = run(latin1_to_utf8, b"caf\xe9", 16);
+        assert_eq!(utf8, "café".as_bytes());
+        assert_eq!(res, Converted::done(4, 5));
+        let (latin1, res) = run(utf8_to_latin1, &utf8, 16);
+        assert_eq!(latin1, b"caf\xe9");
+        assert_eq!(res, Converted::done(5, 4));
+    }
+
+    #[test]
+    fn test_partial_output() {
+        // No room for the second byte of é.
+        let (out, res) = run(latin1_to_utf8, b"caf\xe9", 4);
+        assert_eq!(out, b"caf");
+        assert_eq!(res, Converted::stop(3, 3, ENC_ERR_SPACE));
+
+        let (out, res) = run(utf8_to_utf16le, "a€".as_bytes(), 3);
+        assert_eq!(out, b"a\0");
+        assert_eq!(res, Converted::stop(1, 2, ENC_ERR_SPACE));
+    }
+
+    #[test]
+    fn test_truncated_input_is_not_an_error() {
+        let (out, res) = run(utf8_to_utf16be, b"a\xe2\x82", 16);
+        assert_eq!(out, b"\0a");
+        assert_eq!(res, Converted::done(1, 2));
+
+        // Lone high surrogate at the end, then an odd trailing byte.
+        let (out, res) = run(utf16le_to_utf8, b"a\0\x3d\xd8\x00", 16);
+        assert_eq!(out, b"a");
+        assert_eq!(res, Converted::done(2, 1));
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let (out, res) = run(ascii_to_ascii, b"ab\x80c", 16);
+        assert_eq!(out, b"ab");
+        assert_eq!(res, Converted::stop(2, 2, ENC_ERR_INPUT));
+
+        // Overlong encoding of '/'.
+        let (_, res) = run(utf8_to_utf16le, b"\xc0\xaf", 16);
+        assert_eq!(res, Converted::stop(0, 0, ENC_ERR_INPUT));
+
+        // Low surrogate without a high one.
+        let (_, res) = run(utf16be_to_utf8, b"\xdc\x00", 16);
+        assert_eq!(res, Converted::stop(0, 0, ENC_ERR_INPUT));
+    }
+
+    #[test]
+    fn test_utf16_surrogate_pairs() {
+        let text = "x😀";
+        let (le, res) = run(utf8_to_utf16le, text.as_bytes(), 16);
+        let expected: Vec<u8> = text.encode_utf16().flat_map(u16::to_le_bytes).collect();
+        assert_eq!(le, expected);
+        assert_eq!(res, Converted::done(5, 6));
+        let (utf8, res) = run(utf16le_to_utf8, &le, 16);
+        assert_eq!(utf8, text.as_bytes());
+        assert_eq!(res, Converted::done(6, 5));
+    }
+
+    #[test]
+    fn test_utf32() {
+        let text = "a\u{e9}\u{20ac}\u{1f600}";
+        let (be, res) = run(utf8_to_utf32be, text.as_bytes(), 64);
+        let expected: Vec<u8> = text
+            .chars()
+            .flat_map(|c| (c as u32).to_be_bytes())
+            .collect();
+        assert_eq!(be, expected);
+        assert_eq!(res, Converted::done(10, 16));
+        let (utf8, res) = run(utf32be_to_utf8, &be, 64);
+        assert_eq!(utf8, text.as_bytes());
+        assert_eq!(res, Converted::done(16, 10));
+
+        let (out, res) = run(utf32le_to_utf8, b"A\0\0\0\0\0\x11\0", 16);
+        assert_eq!(out, b"A");
+        assert_eq!(res, Converted::stop(4, 1, ENC_ERR_INPUT));
+        let (_, res) = run(utf32le_to_utf8, b"\x00\xd8\0\0", 16);
+        assert_eq!(res, Converted::stop(0, 0, ENC_ERR_INPUT));
+        // No room for a whole unit.
+        let (_, res) = run(utf8_to_utf32le, b"ab", 7);
+        assert_eq!(res, Converted::stop(1, 4, ENC_ERR_SPACE));
+    }
+
+    #[test]
+    fn test_utf16_bom() {
+        let (out, res) = run(|out, _| utf16_bom(out), b"", 4);
+        assert_eq!(out, [0xFF, 0xFE]);
+        assert_eq!(res, Converted::done(0, 2));
+        assert_eq!(
+            run(|out, _| utf16_bom(out), b"", 1).1,
+            Converted::done(0, 0)
+        );
+    }
+
+    #[test]
+    fn test_char_encoding_by_name() {
+        assert_eq!(char_encoding_by_name("UTF-8"), Some(CHAR_ENCODING_UTF8));
+        assert_eq!(char_encoding_by_name("Latin1"), Some(CHAR_ENCODING_8859_1));
+        assert_eq!(
+            char_encoding_by_name("ISO8859-15"),
+            Some(CHAR_ENCODING_8859_1)
+        );
+        assert_eq!(char_encoding_by_name("unicode"), Some(CHAR_ENCODING_UTF16));
+        assert_eq!(char_encoding_by_name("ISO-8859-2"), None);
+        assert_eq!(char_encoding_by_name("utf-32"), None);
+    }
+}
@@ -0,0 +1,194 @@
+use std::os::raw::c_int;
+
+use super::core::{
+    converters, decode_utf8, Converter, CHAR_ENCODING_UCS4BE, CHAR_ENCODING_UCS4LE,
+    CHAR_ENCODING_UTF16, CHAR_ENCODING_UTF16BE, CHAR_ENCODING_UTF16LE, ENC_ERR_INPUT,
+    ENC_ERR_SPACE,
+};
+
+/// Longest sequence any input converter reads as one character: a UTF-8
+/// or UTF-32 character, or a UTF-16 surrogate pair.
+const MAX_SEQUENCE: usize = 4;
+
+/// U+FFFD REPLACEMENT CHARACTER in UTF-8.
+const REPLACEMENT: &[u8] = "\u{FFFD}".as_bytes();
+
+/// What a `Decoder` does with invalid input.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorPolicy {
+    /// Stop with an error at the first invalid sequence, like the C
+    /// converters.
+    #[default]
+    Strict,
+    /// Write U+FFFD for each invalid sequence and go on.
+    Replace,
+}
+
+/// Invalid input met by a strict `Decoder`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DecodeError {
+    /// `ENC_ERR_INPUT`, or another code a converter returned.
+    pub code: c_int,
+    /// Offset of the invalid sequence from the start of all input fed to
+    /// the decoder.
+    pub offset: usize,
+}
+
+/// A converter to UTF-8 that can be fed its input in pieces.
+///
+/// A character cut off at the end of one piece is kept and completed by
+/// the next, so the output of every call ends on a character boundary and
+/// the pieces decode to the same text as the whole input.
+pub struct Decoder {
+    convert: Converter,
+    unit: usize,
+    policy: ErrorPolicy,
+    pending: Vec<u8>,
+    /// Offset of the first byte not converted yet, the start of `pending`.
+    offset: usize,
+}
+
+impl Decoder {
+    /// A decoder over the input converter `convert`, reading code units
+    /// of `unit` bytes. Under `ErrorPolicy::Replace`, an invalid sequence
+    /// is skipped one unit at a time.
+    pub fn new(convert: Converter, unit: usize) -> Self {
+        Decoder {
+            convert,
+            unit: unit.max(1),
+            policy: ErrorPolicy::default(),
+            pending: Vec::new(),
+            offset: 0,
+        }
+    }
+
+    /// A decoder for the built-in handler of `enc`, see `converters`.
+    pub fn for_encoding(enc: c_int) -> Option<Self> {
+        let unit = match enc {
+            CHAR_ENCODING_UTF16LE | CHAR_ENCODING_UTF16BE | CHAR_ENCODING_UTF16 => 2,
+            CHAR_ENCODING_UCS4LE | CHAR_ENCODING_UCS4BE => 4,
+            _ => 1,
+        };
+        converters(enc).map(|(input, _)| Decoder::new(input, unit))
+    }
+
+    /// What the decoder does with invalid input, `Strict` by default.
+    pub fn policy(&self) -> ErrorPolicy {
+        self.policy
+    }
+
+    /// Choose what to do with invalid input from now on.
+    pub fn set_policy(&mut self, policy: ErrorPolicy) {
+        self.policy = policy;
+    }
+
+    /// The bytes of a cut-off character waiting for the next piece.
+    pub fn pending(&self) -> &[u8] {
+        &self.pending
+    }
+
+    /// Decode the next piece of input, appending to `out`.
+    ///
+    /// Returns the number of bytes appended. A strict decoder fails at the
+    /// first invalid sequence; what came before it is still appended, and
+    /// the decoder should not be fed any further.
+    pub fn decode(&mut self, mut input: &[u8], out: &mut Vec<u8>) -> Result<usize, DecodeError> {
+        let start = out.len();
+        if !self.pending.is_empty() {
+            // Complete the cut-off character with the start of the input,
+            // without copying all of it.
+            let take = input.len().min(MAX_SEQUENCE);
+            let mut head = self.pending.clone();
+            head.extend_from_slice(&input[..take]);
+            let read = self.convert_all(&head, out)?;
+            if read < self.pending.len() {
+                self.pending = head[read..].to_vec();
+                self.pending.extend_from_slice(&input[take..]);
+                return Ok(out.len() - start);
+            }
+            input = &input[read - self.pending.len()..];
+            self.pending.clear();
+        }
+        let read = self.convert_all(input, out)?;
+        self.pending.extend_from_slice(&input[read..]);
+        Ok(out.len() - start)
+    }
+
+    /// End the input. A character still cut off is invalid input, which a
+    /// replacing decoder writes as one U+FFFD to `out`.
+    ///
+    /// Returns the number of bytes appended.
+    pub fn finish(&mut self, out: &mut Vec<u8>) -> Result<usize, DecodeError> {
+        if self.pending.is_empty() {
+            return Ok(0);
+        }
+        match self.policy {
+            ErrorPolicy::Strict => Err(DecodeError {
+                code: ENC_ERR_INPUT,
+                offset: self.offset,
+            }),
+            ErrorPolicy::Replace => {
+                self.offset += self.pending.len();
+                self.pending.clear();
+                out.extend_from_slice(REPLACEMENT);
+                Ok(REPLACEMENT.len())
+            }
+        }
+    }
+
+    /// Run the converter over `input`, which starts at `self.offset`,
+    /// until it stops short of the end, growing `out` as needed. Returns
+    /// the number of bytes read.
+    fn convert_all(&mut self, input: &[u8], out: &mut Vec<u8>) -> Result<usize, DecodeError> {
+        let start = out.len();
+        let mut read = 0;
+        loop {
+            let len = out.len();
+            out.resize(len + (input.len() - read) * 2 + MAX_SEQUENCE, 0);
+            let res = (self.convert)(&mut out[len..], &input[read..]);
+            out.truncate(len + res.written);
+            read += res.read;
+            if res.ret == ENC_ERR_SPACE {
+                continue;
+            }
+            if res.ret < 0 {
+                if self.policy == ErrorPolicy::Strict {
+                    return Err(DecodeError {
+                        code: res.ret,
+                        offset: self.offset + read,
+                    });
+                }
+                out.extend_from_slice(REPLACEMENT);
+                read = (read + self.unit).min(input.len());
+                continue;
+            }
+            break;
+        }
+        // Converters write whole characters, except the UTF-8 one, which
+        // copies its input unchecked. A cut-off character at the end of
+        // its output is handed back as unread.
+        let tail = out.len() - start;
+        let cut = (tail.saturating_sub(MAX_SEQUENCE - 1)..tail)
+            .find(|&i| out[start + i] >= 0xC0 && decode_utf8(&out[start + i..]) == Ok(None));
+        if let Some(i) = cut {
+            read -= tail - i;
+            out.truncate(start + i);
+        }
+        if self.policy == ErrorPolicy::Replace {
+            replace_invalid_utf8(out, start);
+        }
+        self.offset += read;
+        Ok(read)
+    }
+}
+
+/// Replace each maximal invalid subpart of the UTF-8 in `out[start..]`
+/// by U+FFFD, as `String::from_utf8_lossy` does.
+fn replace_invalid_utf8(out: &mut Vec<u8>, start: usize) {
+    if std::str::from_utf8(&out[start..]).is_ok() {
+        return;
+    }
+    let fixed = String::from_utf8_lossy(&out[start..]).into_owned();
+    out.truncate(start);
+    out.extend_from_slice(fixed.as_bytes());
+}
@@ -0,0 +1,148 @@
+//! EUC-JP converters, built with the `euc-jp` feature.
+//!
+//! Single bytes are ASCII and, as in glibc, C1 controls. Two bytes in
+//! 0xA1-0xFE select a character from JIS X 0208, 0x8E and a byte in
+//! 0xA1-0xDF a half-width katakana, and 0x8F and two bytes in 0xA1-0xFE a
+//! character from JIS X 0212.
+
+use super::core::{decode_utf8, encode_utf8, utf8_size, Converted, ENC_ERR_INPUT, ENC_ERR_SPACE};
+use super::jis0208::{JIS0208, JIS0208_INVERSE};
+use super::jis0212::{JIS0212, JIS0212_INVERSE};
+
+/// Single shift to half-width katakana.
+const SS2: u8 = 0x8E;
+/// Single shift to JIS X 0212.
+const SS3: u8 = 0x8F;
+
+fn is_grid_byte(b: u8) -> bool {
+    (0xA1..=0xFE).contains(&b)
+}
+
+/// Code point of a row and cell pair from `table`, if assigned.
+fn grid_char(table: &[u16; 94 * 94], row: u8, cell: u8) -> Option<u32> {
+    if !is_grid_byte(row) || !is_grid_byte(cell) {
+        return None;
+    }
+    match table[(row - 0xA1) as usize * 94 + (cell - 0xA1) as usize] {
+        0 => None,
+        c => Some(c as u32),
+    }
+}
+
+/// The sequence starting at `input[0]`, which is at least 0x80, as a code
+/// point and its length. `Ok(None)` if it is cut off by the end of the
+/// input, `Err` if it is invalid.
+fn decode_sequence(input: &[u8]) -> Result<Option<(u32, usize)>, ()> {
+    let (table, len) = match input[0] {
+        b @ (0x80..=0x8D | 0x90..=0x9F) => return Ok(Some((b as u32, 1))),
+        SS2 => {
+            return match input.get(1) {
+                None => Ok(None),
+                Some(&b @ 0xA1..=0xDF) => Ok(Some((0xFF61 + (b - 0xA1) as u32, 2))),
+                Some(_) => Err(()),
+            };
+        }
+        SS3 => (&JIS0212, 3),
+        b if is_grid_byte(b) => (&JIS0208, 2),
+        _ => return Err(()),
+    };
+    let grid = &input[len - 2..input.len().min(len)];
+    if !grid.iter().all(|&b| is_grid_byte(b)) {
+        return Err(());
+    }
+    match grid {
+        // Like glibc, reject a JIS X 0212 row outside the assigned rows 2-77
+        // without waiting for the cell.
+        &[row] if len == 3 && !(0xA2..=0xED).contains(&row) => return Err(()),
+        [] | [_] => return Ok(None),
+        _ => {}
+    }
+    match grid_char(table, grid[0], grid[1]) {
+        Some(c) => Ok(Some((c, len))),
+        None => Err(()),
+    }
+}
+
+/// EUC-JP bytes for `c`, and how many of them are used.
+fn euc_jp_bytes(c: u32) -> Option<([u8; 3], usize)> {
+    let find = |inverse: &[(u16, u16)]| {
+        let c = u16::try_from(c).ok()?;
+        let k = inverse.binary_search_by_key(&c, |&(cp, _)| cp).ok()?;
+        let index = inverse[k].1 as usize;
+        Some([(index / 94) as u8 + 0xA1, (index % 94) as u8 + 0xA1])
+    };
+    match c {
+        0..=0x7F => Some(([c as u8, 0, 0], 1)),
+        0x80..=0x9F if c != SS2 as u32 && c != SS3 as u32 => Some(([c as u8, 0, 0], 1)),
+        0xFF61..=0xFF9F => Some(([SS2, (c - 0xFF61) as u8 + 0xA1, 0], 2)),
+        _ => {
+            if let Some([row, cell]) = find(&JIS0208_INVERSE) {
+                Some(([row, cell, 0], 2))
+            } else {
+                let [row, cell] = find(&JIS0212_INVERSE)?;
+                Some(([SS3, row, cell], 3))
+            }
+        }
+    }
+}
+
+/// Convert EUC-JP to UTF-8. Bytes that start no character, bytes out of
+/// range after a lead byte or single shift, and unassigned characters are
+/// invalid input. A sequence cut off by the end of the input is left
+/// unread.
+pub fn euc_jp_to_utf8(out: &mut [u8], input: &[u8]) -> Converted {
+    let mut i = 0;
+    let mut o = 0;
+    while i < input.len() {
+        let decoded = if input[i] < 0x80 {
+            Ok(Some((input[i] as u32, 1)))
+        } else {
+            decode_sequence(&input[i..])
+        };
+        let (c, len) = match decoded {
+            Ok(Some(decoded)) => decoded,
+            Ok(None) => break,
+            Err(()) => return Converted::stop(i, o, ENC_ERR_INPUT),
+        };
+        let size = utf8_size(c);
+        if out.len() - o < size {
+            return Converted::stop(i, o, ENC_ERR_SPACE);
+        }
+        encode_utf8(&mut out[o..o + size], c);
+        i += len;
+        o += size;
+    }
+    Converted::done(i, o)
+}
+
+/// Convert UTF-8 to EUC-JP. Characters outside ASCII, the C1 controls,
+/// half-width katakana, JIS X 0208 and JIS X 0212 are invalid input, as
+/// are UTF-8 errors. A cut-off sequence ends the input even when the
+/// output is full; otherwise a full output is reported first.
+pub fn utf8_to_euc_jp(out: &mut [u8], input: &[u8]) -> Converted {
+    let mut i = 0;
+    let mut o = 0;
+    while i < input.len() {
+        let decoded = if input[i] < 0x80 {
+            Ok(Some((input[i] as u32, 1)))
+        } else {
+            decode_utf8(&input[i..])
+        };
+        let (c, len) = match decoded {
+            Ok(None) => break,
+            _ if o >= out.len() => return Converted::stop(i, o, ENC_ERR_SPACE),
+            Ok(Some(decoded)) => decoded,
+            Err(()) => return Converted::stop(i, o, ENC_ERR_INPUT),
+        };
+        let Some((bytes, size)) = euc_jp_bytes(c) else {
+            return Converted::stop(i, o, ENC_ERR_INPUT);
+        };
+        if out.len() - o < size {
+            return Converted::stop(i, o, ENC_ERR_SPACE);
+        }
+        out[o..o + size].copy_from_slice(&bytes[..size]);
+        i += len;
+        o += size;
+    }
+    Converted::done(i, o)
+}
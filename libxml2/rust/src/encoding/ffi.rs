@@ -0,0 +1,57 @@
+#![allow(clippy::missing_safety_doc)]
+
+use std::os::raw::c_int;
+use std::slice;
+
+use super::core::{self, Converter};
+
+// Ensure symbols are exported for linking
+#[cfg(feature = "rust-encoding")]
+#[used]
+static ENCODING_FFI_LINKAGE: () = ();
+
+/// Run `convert` on C buffers and report back through the length pointers.
+/// Negative lengths are read as 0.
+unsafe fn convert(
+    convert: Converter,
+    out: *mut u8,
+    outlen: *mut c_int,
+    input: *const u8,
+    inlen: *mut c_int,
+) -> c_int {
+    if out.is_null() || outlen.is_null() || input.is_null() || inlen.is_null() {
+        return core::ENC_ERR_INTERNAL;
+    }
+    let out = slice::from_raw_parts_mut(out, (*outlen).max(0) as usize);
+    let input = slice::from_raw_parts(input, (*inlen).max(0) as usize);
+    let res = convert(out, input);
+    *outlen = res.written as c_int;
+    *inlen = res.read as c_int;
+    res.ret
+}
+
+/// Take a block of ISO Latin 1 chars in and try to convert it to an UTF-8
+/// block of chars out. On return `*inlen` is the number of bytes consumed
+/// and `*outlen` the number of bytes produced.
+#[no_mangle]
+pub unsafe extern "C" fn xmlIsolat1ToUTF8(
+    out: *mut u8,
+    outlen: *mut c_int,
+    input: *const u8,
+    inlen: *mut c_int,
+) -> c_int {
+    convert(core::latin1_to_utf8, out, outlen, input, inlen)
+}
+
+/// Take a block of UTF-8 chars in and try to convert it to an ISO Latin 1
+/// block of chars out. On return `*inlen` is the number of bytes consumed
+/// and `*outlen` the number of bytes produced.
+#[no_mangle]
+pub unsafe extern "C" fn xmlUTF8ToIsolat1(
+    out: *mut u8,
+    outlen: *mut c_int,
+    input: *const u8,
+    inlen: *mut c_int,
+) -> c_int {
+    convert(core::utf8_to_latin1, out, outlen, input, inlen)
+}
@@ -0,0 +1,6815 @@
+//! JIS X 0212, the supplementary kanji of EUC-JP
+//!
+//! Generated with codegen/gen_encoding_tables.py from Python's `euc_jp`
+//! codec. Do not edit.
+
+/// Code point of row `i / 94`, cell `i % 94`, or 0.
+pub(super) static JIS0212: [u16; 94 * 94] = [
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x02d8, 0x02c7, 0x00b8, 0x02d9, 0x02dd, 0x00af, 0x02db, 0x02da, 0x0000, 0x0384, 0x0385, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x00a1, 0x00a6, 0x00bf, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x00ba, 0x00aa, 0x00a9, 0x00ae, 0x2122, 0x00a4, 0x2116, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0386, 0x0388, 0x0389, 0x038a, 0x03aa, 0x0000,
+    0x038c, 0x0000, 0x038e, 0x03ab, 0x0000, 0x038f, 0x0000, 0x0000, 0x0000, 0x0000, 0x03ac, 0x03ad,
+    0x03ae, 0x03af, 0x03ca, 0x0390, 0x03cc, 0x03c2, 0x03cd, 0x03cb, 0x03b0, 0x03ce, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0402, 0x0403, 0x0404,
+    0x0405, 0x0406, 0x0407, 0x0408, 0x0409, 0x040a, 0x040b, 0x040c, 0x040e, 0x040f, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0452, 0x0453, 0x0454,
+    0x0455, 0x0456, 0x0457, 0x0458, 0x0459, 0x045a, 0x045b, 0x045c, 0x045e, 0x045f, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x00c6, 0x0110, 0x0000, 0x0126,
+    0x0000, 0x0132, 0x0000, 0x0141, 0x013f, 0x0000, 0x014a, 0x00d8, 0x0152, 0x0000, 0x0166, 0x00de,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x00e6, 0x0111, 0x00f0, 0x0127, 0x0131, 0x0133, 0x0138, 0x0142,
+    0x0140, 0x0149, 0x014b, 0x00f8, 0x0153, 0x00df, 0x0167, 0x00fe, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x00c1, 0x00c0, 0x00c4, 0x00c2, 0x0102, 0x01cd,
+    0x0100, 0x0104, 0x00c5, 0x00c3, 0x0106, 0x0108, 0x010c, 0x00c7, 0x010a, 0x010e, 0x00c9, 0x00c8,
+    0x00cb, 0x00ca, 0x011a, 0x0116, 0x0112, 0x0118, 0x0000, 0x011c, 0x011e, 0x0122, 0x0120, 0x0124,
+    0x00cd, 0x00cc, 0x00cf, 0x00ce, 0x01cf, 0x0130, 0x012a, 0x012e, 0x0128, 0x0134, 0x0136, 0x0139,
+    0x013d, 0x013b, 0x0143, 0x0147, 0x0145, 0x00d1, 0x00d3, 0x00d2, 0x00d6, 0x00d4, 0x01d1, 0x0150,
+    0x014c, 0x00d5, 0x0154, 0x0158, 0x0156, 0x015a, 0x015c, 0x0160, 0x015e, 0x0164, 0x0162, 0x00da,
+    0x00d9, 0x00dc, 0x00db, 0x016c, 0x01d3, 0x0170, 0x016a, 0x0172, 0x016e, 0x0168, 0x01d7, 0x01db,
+    0x01d9, 0x01d5, 0x0174, 0x00dd, 0x0178, 0x0176, 0x0179, 0x017d, 0x017b, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x00e1, 0x00e0, 0x00e4, 0x00e2, 0x0103, 0x01ce, 0x0101, 0x0105,
+    0x00e5, 0x00e3, 0x0107, 0x0109, 0x010d, 0x00e7, 0x010b, 0x010f, 0x00e9, 0x00e8, 0x00eb, 0x00ea,
+    0x011b, 0x0117, 0x0113, 0x0119, 0x01f5, 0x011d, 0x011f, 0x0000, 0x0121, 0x0125, 0x00ed, 0x00ec,
+    0x00ef, 0x00ee, 0x01d0, 0x0000, 0x012b, 0x012f, 0x0129, 0x0135, 0x0137, 0x013a, 0x013e, 0x013c,
+    0x0144, 0x0148, 0x0146, 0x00f1, 0x00f3, 0x00f2, 0x00f6, 0x00f4, 0x01d2, 0x0151, 0x014d, 0x00f5,
+    0x0155, 0x0159, 0x0157, 0x015b, 0x015d, 0x0161, 0x015f, 0x0165, 0x0163, 0x00fa, 0x00f9, 0x00fc,
+    0x00fb, 0x016d, 0x01d4, 0x0171, 0x016b, 0x0173, 0x016f, 0x0169, 0x01d8, 0x01dc, 0x01da, 0x01d6,
+    0x0175, 0x00fd, 0x00ff, 0x0177, 0x017a, 0x017e, 0x017c, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x4e02, 0x4e04, 0x4e05, 0x4e0c, 0x4e12, 0x4e1f,
+    0x4e23, 0x4e24, 0x4e28, 0x4e2b, 0x4e2e, 0x4e2f, 0x4e30, 0x4e35, 0x4e40, 0x4e41, 0x4e44, 0x4e47,
+    0x4e51, 0x4e5a, 0x4e5c, 0x4e63, 0x4e68, 0x4e69, 0x4e74, 0x4e75, 0x4e79, 0x4e7f, 0x4e8d, 0x4e96,
+    0x4e97, 0x4e9d, 0x4eaf, 0x4eb9, 0x4ec3, 0x4ed0, 0x4eda, 0x4edb, 0x4ee0, 0x4ee1, 0x4ee2, 0x4ee8,
+    0x4eef, 0x4ef1, 0x4ef3, 0x4ef5, 0x4efd, 0x4efe, 0x4eff, 0x4f00, 0x4f02, 0x4f03, 0x4f08, 0x4f0b,
+    0x4f0c, 0x4f12, 0x4f15, 0x4f16, 0x4f17, 0x4f19, 0x4f2e, 0x4f31, 0x4f60, 0x4f33, 0x4f35, 0x4f37,
+    0x4f39, 0x4f3b, 0x4f3e, 0x4f40, 0x4f42, 0x4f48, 0x4f49, 0x4f4b, 0x4f4c, 0x4f52, 0x4f54, 0x4f56,
+    0x4f58, 0x4f5f, 0x4f63, 0x4f6a, 0x4f6c, 0x4f6e, 0x4f71, 0x4f77, 0x4f78, 0x4f79, 0x4f7a, 0x4f7d,
+    0x4f7e, 0x4f81, 0x4f82, 0x4f84, 0x4f85, 0x4f89, 0x4f8a, 0x4f8c, 0x4f8e, 0x4f90, 0x4f92, 0x4f93,
+    0x4f94, 0x4f97, 0x4f99, 0x4f9a, 0x4f9e, 0x4f9f, 0x4fb2, 0x4fb7, 0x4fb9, 0x4fbb, 0x4fbc, 0x4fbd,
+    0x4fbe, 0x4fc0, 0x4fc1, 0x4fc5, 0x4fc6, 0x4fc8, 0x4fc9, 0x4fcb, 0x4fcc, 0x4fcd, 0x4fcf, 0x4fd2,
+    0x4fdc, 0x4fe0, 0x4fe2, 0x4ff0, 0x4ff2, 0x4ffc, 0x4ffd, 0x4fff, 0x5000, 0x5001, 0x5004, 0x5007,
+    0x500a, 0x500c, 0x500e, 0x5010, 0x5013, 0x5017, 0x5018, 0x501b, 0x501c, 0x501d, 0x501e, 0x5022,
+    0x5027, 0x502e, 0x5030, 0x5032, 0x5033, 0x5035, 0x5040, 0x5041, 0x5042, 0x5045, 0x5046, 0x504a,
+    0x504c, 0x504e, 0x5051, 0x5052, 0x5053, 0x5057, 0x5059, 0x505f, 0x5060, 0x5062, 0x5063, 0x5066,
+    0x5067, 0x506a, 0x506d, 0x5070, 0x5071, 0x503b, 0x5081, 0x5083, 0x5084, 0x5086, 0x508a, 0x508e,
+    0x508f, 0x5090, 0x5092, 0x5093, 0x5094, 0x5096, 0x509b, 0x509c, 0x509e, 0x509f, 0x50a0, 0x50a1,
+    0x50a2, 0x50aa, 0x50af, 0x50b0, 0x50b9, 0x50ba, 0x50bd, 0x50c0, 0x50c3, 0x50c4, 0x50c7, 0x50cc,
+    0x50ce, 0x50d0, 0x50d3, 0x50d4, 0x50d8, 0x50dc, 0x50dd, 0x50df, 0x50e2, 0x50e4, 0x50e6, 0x50e8,
+    0x50e9, 0x50ef, 0x50f1, 0x50f6, 0x50fa, 0x50fe, 0x5103, 0x5106, 0x5107, 0x5108, 0x510b, 0x510c,
+    0x510d, 0x510e, 0x50f2, 0x5110, 0x5117, 0x5119, 0x511b, 0x511c, 0x511d, 0x511e, 0x5123, 0x5127,
+    0x5128, 0x512c, 0x512d, 0x512f, 0x5131, 0x5133, 0x5134, 0x5135, 0x5138, 0x5139, 0x5142, 0x514a,
+    0x514f, 0x5153, 0x5155, 0x5157, 0x5158, 0x515f, 0x5164, 0x5166, 0x517e, 0x5183, 0x5184, 0x518b,
+    0x518e, 0x5198, 0x519d, 0x51a1, 0x51a3, 0x51ad, 0x51b8, 0x51ba, 0x51bc, 0x51be, 0x51bf, 0x51c2,
+    0x51c8, 0x51cf, 0x51d1, 0x51d2, 0x51d3, 0x51d5, 0x51d8, 0x51de, 0x51e2, 0x51e5, 0x51ee, 0x51f2,
+    0x51f3, 0x51f4, 0x51f7, 0x5201, 0x5202, 0x5205, 0x5212, 0x5213, 0x5215, 0x5216, 0x5218, 0x5222,
+    0x5228, 0x5231, 0x5232, 0x5235, 0x523c, 0x5245, 0x5249, 0x5255, 0x5257, 0x5258, 0x525a, 0x525c,
+    0x525f, 0x5260, 0x5261, 0x5266, 0x526e, 0x5277, 0x5278, 0x5279, 0x5280, 0x5282, 0x5285, 0x528a,
+    0x528c, 0x5293, 0x5295, 0x5296, 0x5297, 0x5298, 0x529a, 0x529c, 0x52a4, 0x52a5, 0x52a6, 0x52a7,
+    0x52af, 0x52b0, 0x52b6, 0x52b7, 0x52b8, 0x52ba, 0x52bb, 0x52bd, 0x52c0, 0x52c4, 0x52c6, 0x52c8,
+    0x52cc, 0x52cf, 0x52d1, 0x52d4, 0x52d6, 0x52db, 0x52dc, 0x52e1, 0x52e5, 0x52e8, 0x52e9, 0x52ea,
+    0x52ec, 0x52f0, 0x52f1, 0x52f4, 0x52f6, 0x52f7, 0x5300, 0x5303, 0x530a, 0x530b, 0x530c, 0x5311,
+    0x5313, 0x5318, 0x531b, 0x531c, 0x531e, 0x531f, 0x5325, 0x5327, 0x5328, 0x5329, 0x532b, 0x532c,
+    0x532d, 0x5330, 0x5332, 0x5335, 0x533c, 0x533d, 0x533e, 0x5342, 0x534c, 0x534b, 0x5359, 0x535b,
+    0x5361, 0x5363, 0x5365, 0x536c, 0x536d, 0x5372, 0x5379, 0x537e, 0x5383, 0x5387, 0x5388, 0x538e,
+    0x5393, 0x5394, 0x5399, 0x539d, 0x53a1, 0x53a4, 0x53aa, 0x53ab, 0x53af, 0x53b2, 0x53b4, 0x53b5,
+    0x53b7, 0x53b8, 0x53ba, 0x53bd, 0x53c0, 0x53c5, 0x53cf, 0x53d2, 0x53d3, 0x53d5, 0x53da, 0x53dd,
+    0x53de, 0x53e0, 0x53e6, 0x53e7, 0x53f5, 0x5402, 0x5413, 0x541a, 0x5421, 0x5427, 0x5428, 0x542a,
+    0x542f, 0x5431, 0x5434, 0x5435, 0x5443, 0x5444, 0x5447, 0x544d, 0x544f, 0x545e, 0x5462, 0x5464,
+    0x5466, 0x5467, 0x5469, 0x546b, 0x546d, 0x546e, 0x5474, 0x547f, 0x5481, 0x5483, 0x5485, 0x5488,
+    0x5489, 0x548d, 0x5491, 0x5495, 0x5496, 0x549c, 0x549f, 0x54a1, 0x54a6, 0x54a7, 0x54a9, 0x54aa,
+    0x54ad, 0x54ae, 0x54b1, 0x54b7, 0x54b9, 0x54ba, 0x54bb, 0x54bf, 0x54c6, 0x54ca, 0x54cd, 0x54ce,
+    0x54e0, 0x54ea, 0x54ec, 0x54ef, 0x54f6, 0x54fc, 0x54fe, 0x54ff, 0x5500, 0x5501, 0x5505, 0x5508,
+    0x5509, 0x550c, 0x550d, 0x550e, 0x5515, 0x552a, 0x552b, 0x5532, 0x5535, 0x5536, 0x553b, 0x553c,
+    0x553d, 0x5541, 0x5547, 0x5549, 0x554a, 0x554d, 0x5550, 0x5551, 0x5558, 0x555a, 0x555b, 0x555e,
+    0x5560, 0x5561, 0x5564, 0x5566, 0x557f, 0x5581, 0x5582, 0x5586, 0x5588, 0x558e, 0x558f, 0x5591,
+    0x5592, 0x5593, 0x5594, 0x5597, 0x55a3, 0x55a4, 0x55ad, 0x55b2, 0x55bf, 0x55c1, 0x55c3, 0x55c6,
+    0x55c9, 0x55cb, 0x55cc, 0x55ce, 0x55d1, 0x55d2, 0x55d3, 0x55d7, 0x55d8, 0x55db, 0x55de, 0x55e2,
+    0x55e9, 0x55f6, 0x55ff, 0x5605, 0x5608, 0x560a, 0x560d, 0x560e, 0x560f, 0x5610, 0x5611, 0x5612,
+    0x5619, 0x562c, 0x5630, 0x5633, 0x5635, 0x5637, 0x5639, 0x563b, 0x563c, 0x563d, 0x563f, 0x5640,
+    0x5641, 0x5643, 0x5644, 0x5646, 0x5649, 0x564b, 0x564d, 0x564f, 0x5654, 0x565e, 0x5660, 0x5661,
+    0x5662, 0x5663, 0x5666, 0x5669, 0x566d, 0x566f, 0x5671, 0x5672, 0x5675, 0x5684, 0x5685, 0x5688,
+    0x568b, 0x568c, 0x5695, 0x5699, 0x569a, 0x569d, 0x569e, 0x569f, 0x56a6, 0x56a7, 0x56a8, 0x56a9,
+    0x56ab, 0x56ac, 0x56ad, 0x56b1, 0x56b3, 0x56b7, 0x56be, 0x56c5, 0x56c9, 0x56ca, 0x56cb, 0x56cf,
+    0x56d0, 0x56cc, 0x56cd, 0x56d9, 0x56dc, 0x56dd, 0x56df, 0x56e1, 0x56e4, 0x56e5, 0x56e6, 0x56e7,
+    0x56e8, 0x56f1, 0x56eb, 0x56ed, 0x56f6, 0x56f7, 0x5701, 0x5702, 0x5707, 0x570a, 0x570c, 0x5711,
+    0x5715, 0x571a, 0x571b, 0x571d, 0x5720, 0x5722, 0x5723, 0x5724, 0x5725, 0x5729, 0x572a, 0x572c,
+    0x572e, 0x572f, 0x5733, 0x5734, 0x573d, 0x573e, 0x573f, 0x5745, 0x5746, 0x574c, 0x574d, 0x5752,
+    0x5762, 0x5765, 0x5767, 0x5768, 0x576b, 0x576d, 0x576e, 0x576f, 0x5770, 0x5771, 0x5773, 0x5774,
+    0x5775, 0x5777, 0x5779, 0x577a, 0x577b, 0x577c, 0x577e, 0x5781, 0x5783, 0x578c, 0x5794, 0x5797,
+    0x5799, 0x579a, 0x579c, 0x579d, 0x579e, 0x579f, 0x57a1, 0x5795, 0x57a7, 0x57a8, 0x57a9, 0x57ac,
+    0x57b8, 0x57bd, 0x57c7, 0x57c8, 0x57cc, 0x57cf, 0x57d5, 0x57dd, 0x57de, 0x57e4, 0x57e6, 0x57e7,
+    0x57e9, 0x57ed, 0x57f0, 0x57f5, 0x57f6, 0x57f8, 0x57fd, 0x57fe, 0x57ff, 0x5803, 0x5804, 0x5808,
+    0x5809, 0x57e1, 0x580c, 0x580d, 0x581b, 0x581e, 0x581f, 0x5820, 0x5826, 0x5827, 0x582d, 0x5832,
+    0x5839, 0x583f, 0x5849, 0x584c, 0x584d, 0x584f, 0x5850, 0x5855, 0x585f, 0x5861, 0x5864, 0x5867,
+    0x5868, 0x5878, 0x587c, 0x587f, 0x5880, 0x5881, 0x5887, 0x5888, 0x5889, 0x588a, 0x588c, 0x588d,
+    0x588f, 0x5890, 0x5894, 0x5896, 0x589d, 0x58a0, 0x58a1, 0x58a2, 0x58a6, 0x58a9, 0x58b1, 0x58b2,
+    0x58c4, 0x58bc, 0x58c2, 0x58c8, 0x58cd, 0x58ce, 0x58d0, 0x58d2, 0x58d4, 0x58d6, 0x58da, 0x58dd,
+    0x58e1, 0x58e2, 0x58e9, 0x58f3, 0x5905, 0x5906, 0x590b, 0x590c, 0x5912, 0x5913, 0x5914, 0x8641,
+    0x591d, 0x5921, 0x5923, 0x5924, 0x5928, 0x592f, 0x5930, 0x5933, 0x5935, 0x5936, 0x593f, 0x5943,
+    0x5946, 0x5952, 0x5953, 0x5959, 0x595b, 0x595d, 0x595e, 0x595f, 0x5961, 0x5963, 0x596b, 0x596d,
+    0x596f, 0x5972, 0x5975, 0x5976, 0x5979, 0x597b, 0x597c, 0x598b, 0x598c, 0x598e, 0x5992, 0x5995,
+    0x5997, 0x599f, 0x59a4, 0x59a7, 0x59ad, 0x59ae, 0x59af, 0x59b0, 0x59b3, 0x59b7, 0x59ba, 0x59bc,
+    0x59c1, 0x59c3, 0x59c4, 0x59c8, 0x59ca, 0x59cd, 0x59d2, 0x59dd, 0x59de, 0x59df, 0x59e3, 0x59e4,
+    0x59e7, 0x59ee, 0x59ef, 0x59f1, 0x59f2, 0x59f4, 0x59f7, 0x5a00, 0x5a04, 0x5a0c, 0x5a0d, 0x5a0e,
+    0x5a12, 0x5a13, 0x5a1e, 0x5a23, 0x5a24, 0x5a27, 0x5a28, 0x5a2a, 0x5a2d, 0x5a30, 0x5a44, 0x5a45,
+    0x5a47, 0x5a48, 0x5a4c, 0x5a50, 0x5a55, 0x5a5e, 0x5a63, 0x5a65, 0x5a67, 0x5a6d, 0x5a77, 0x5a7a,
+    0x5a7b, 0x5a7e, 0x5a8b, 0x5a90, 0x5a93, 0x5a96, 0x5a99, 0x5a9c, 0x5a9e, 0x5a9f, 0x5aa0, 0x5aa2,
+    0x5aa7, 0x5aac, 0x5ab1, 0x5ab2, 0x5ab3, 0x5ab5, 0x5ab8, 0x5aba, 0x5abb, 0x5abf, 0x5ac4, 0x5ac6,
+    0x5ac8, 0x5acf, 0x5ada, 0x5adc, 0x5ae0, 0x5ae5, 0x5aea, 0x5aee, 0x5af5, 0x5af6, 0x5afd, 0x5b00,
+    0x5b01, 0x5b08, 0x5b17, 0x5b34, 0x5b19, 0x5b1b, 0x5b1d, 0x5b21, 0x5b25, 0x5b2d, 0x5b38, 0x5b41,
+    0x5b4b, 0x5b4c, 0x5b52, 0x5b56, 0x5b5e, 0x5b68, 0x5b6e, 0x5b6f, 0x5b7c, 0x5b7d, 0x5b7e, 0x5b7f,
+    0x5b81, 0x5b84, 0x5b86, 0x5b8a, 0x5b8e, 0x5b90, 0x5b91, 0x5b93, 0x5b94, 0x5b96, 0x5ba8, 0x5ba9,
+    0x5bac, 0x5bad, 0x5baf, 0x5bb1, 0x5bb2, 0x5bb7, 0x5bba, 0x5bbc, 0x5bc0, 0x5bc1, 0x5bcd, 0x5bcf,
+    0x5bd6, 0x5bd7, 0x5bd8, 0x5bd9, 0x5bda, 0x5be0, 0x5bef, 0x5bf1, 0x5bf4, 0x5bfd, 0x5c0c, 0x5c17,
+    0x5c1e, 0x5c1f, 0x5c23, 0x5c26, 0x5c29, 0x5c2b, 0x5c2c, 0x5c2e, 0x5c30, 0x5c32, 0x5c35, 0x5c36,
+    0x5c59, 0x5c5a, 0x5c5c, 0x5c62, 0x5c63, 0x5c67, 0x5c68, 0x5c69, 0x5c6d, 0x5c70, 0x5c74, 0x5c75,
+    0x5c7a, 0x5c7b, 0x5c7c, 0x5c7d, 0x5c87, 0x5c88, 0x5c8a, 0x5c8f, 0x5c92, 0x5c9d, 0x5c9f, 0x5ca0,
+    0x5ca2, 0x5ca3, 0x5ca6, 0x5caa, 0x5cb2, 0x5cb4, 0x5cb5, 0x5cba, 0x5cc9, 0x5ccb, 0x5cd2, 0x5cdd,
+    0x5cd7, 0x5cee, 0x5cf1, 0x5cf2, 0x5cf4, 0x5d01, 0x5d06, 0x5d0d, 0x5d12, 0x5d2b, 0x5d23, 0x5d24,
+    0x5d26, 0x5d27, 0x5d31, 0x5d34, 0x5d39, 0x5d3d, 0x5d3f, 0x5d42, 0x5d43, 0x5d46, 0x5d48, 0x5d55,
+    0x5d51, 0x5d59, 0x5d4a, 0x5d5f, 0x5d60, 0x5d61, 0x5d62, 0x5d64, 0x5d6a, 0x5d6d, 0x5d70, 0x5d79,
+    0x5d7a, 0x5d7e, 0x5d7f, 0x5d81, 0x5d83, 0x5d88, 0x5d8a, 0x5d92, 0x5d93, 0x5d94, 0x5d95, 0x5d99,
+    0x5d9b, 0x5d9f, 0x5da0, 0x5da7, 0x5dab, 0x5db0, 0x5db4, 0x5db8, 0x5db9, 0x5dc3, 0x5dc7, 0x5dcb,
+    0x5dd0, 0x5dce, 0x5dd8, 0x5dd9, 0x5de0, 0x5de4, 0x5de9, 0x5df8, 0x5df9, 0x5e00, 0x5e07, 0x5e0d,
+    0x5e12, 0x5e14, 0x5e15, 0x5e18, 0x5e1f, 0x5e20, 0x5e2e, 0x5e28, 0x5e32, 0x5e35, 0x5e3e, 0x5e4b,
+    0x5e50, 0x5e49, 0x5e51, 0x5e56, 0x5e58, 0x5e5b, 0x5e5c, 0x5e5e, 0x5e68, 0x5e6a, 0x5e6b, 0x5e6c,
+    0x5e6d, 0x5e6e, 0x5e70, 0x5e80, 0x5e8b, 0x5e8e, 0x5ea2, 0x5ea4, 0x5ea5, 0x5ea8, 0x5eaa, 0x5eac,
+    0x5eb1, 0x5eb3, 0x5ebd, 0x5ebe, 0x5ebf, 0x5ec6, 0x5ecc, 0x5ecb, 0x5ece, 0x5ed1, 0x5ed2, 0x5ed4,
+    0x5ed5, 0x5edc, 0x5ede, 0x5ee5, 0x5eeb, 0x5f02, 0x5f06, 0x5f07, 0x5f08, 0x5f0e, 0x5f19, 0x5f1c,
+    0x5f1d, 0x5f21, 0x5f22, 0x5f23, 0x5f24, 0x5f28, 0x5f2b, 0x5f2c, 0x5f2e, 0x5f30, 0x5f34, 0x5f36,
+    0x5f3b, 0x5f3d, 0x5f3f, 0x5f40, 0x5f44, 0x5f45, 0x5f47, 0x5f4d, 0x5f50, 0x5f54, 0x5f58, 0x5f5b,
+    0x5f60, 0x5f63, 0x5f64, 0x5f67, 0x5f6f, 0x5f72, 0x5f74, 0x5f75, 0x5f78, 0x5f7a, 0x5f7d, 0x5f7e,
+    0x5f89, 0x5f8d, 0x5f8f, 0x5f96, 0x5f9c, 0x5f9d, 0x5fa2, 0x5fa7, 0x5fab, 0x5fa4, 0x5fac, 0x5faf,
+    0x5fb0, 0x5fb1, 0x5fb8, 0x5fc4, 0x5fc7, 0x5fc8, 0x5fc9, 0x5fcb, 0x5fd0, 0x5fd1, 0x5fd2, 0x5fd3,
+    0x5fd4, 0x5fde, 0x5fe1, 0x5fe2, 0x5fe8, 0x5fe9, 0x5fea, 0x5fec, 0x5fed, 0x5fee, 0x5fef, 0x5ff2,
+    0x5ff3, 0x5ff6, 0x5ffa, 0x5ffc, 0x6007, 0x600a, 0x600d, 0x6013, 0x6014, 0x6017, 0x6018, 0x601a,
+    0x601f, 0x6024, 0x602d, 0x6033, 0x6035, 0x6040, 0x6047, 0x6048, 0x6049, 0x604c, 0x6051, 0x6054,
+    0x6056, 0x6057, 0x605d, 0x6061, 0x6067, 0x6071, 0x607e, 0x607f, 0x6082, 0x6086, 0x6088, 0x608a,
+    0x608e, 0x6091, 0x6093, 0x6095, 0x6098, 0x609d, 0x609e, 0x60a2, 0x60a4, 0x60a5, 0x60a8, 0x60b0,
+    0x60b1, 0x60b7, 0x60bb, 0x60be, 0x60c2, 0x60c4, 0x60c8, 0x60c9, 0x60ca, 0x60cb, 0x60ce, 0x60cf,
+    0x60d4, 0x60d5, 0x60d9, 0x60db, 0x60dd, 0x60de, 0x60e2, 0x60e5, 0x60f2, 0x60f5, 0x60f8, 0x60fc,
+    0x60fd, 0x6102, 0x6107, 0x610a, 0x610c, 0x6110, 0x6111, 0x6112, 0x6113, 0x6114, 0x6116, 0x6117,
+    0x6119, 0x611c, 0x611e, 0x6122, 0x612a, 0x612b, 0x6130, 0x6131, 0x6135, 0x6136, 0x6137, 0x6139,
+    0x6141, 0x6145, 0x6146, 0x6149, 0x615e, 0x6160, 0x616c, 0x6172, 0x6178, 0x617b, 0x617c, 0x617f,
+    0x6180, 0x6181, 0x6183, 0x6184, 0x618b, 0x618d, 0x6192, 0x6193, 0x6197, 0x6198, 0x619c, 0x619d,
+    0x619f, 0x61a0, 0x61a5, 0x61a8, 0x61aa, 0x61ad, 0x61b8, 0x61b9, 0x61bc, 0x61c0, 0x61c1, 0x61c2,
+    0x61ce, 0x61cf, 0x61d5, 0x61dc, 0x61dd, 0x61de, 0x61df, 0x61e1, 0x61e2, 0x61e7, 0x61e9, 0x61e5,
+    0x61ec, 0x61ed, 0x61ef, 0x6201, 0x6203, 0x6204, 0x6207, 0x6213, 0x6215, 0x621c, 0x6220, 0x6222,
+    0x6223, 0x6227, 0x6229, 0x622b, 0x6239, 0x623d, 0x6242, 0x6243, 0x6244, 0x6246, 0x624c, 0x6250,
+    0x6251, 0x6252, 0x6254, 0x6256, 0x625a, 0x625c, 0x6264, 0x626d, 0x626f, 0x6273, 0x627a, 0x627d,
+    0x628d, 0x628e, 0x628f, 0x6290, 0x62a6, 0x62a8, 0x62b3, 0x62b6, 0x62b7, 0x62ba, 0x62be, 0x62bf,
+    0x62c4, 0x62ce, 0x62d5, 0x62d6, 0x62da, 0x62ea, 0x62f2, 0x62f4, 0x62fc, 0x62fd, 0x6303, 0x6304,
+    0x630a, 0x630b, 0x630d, 0x6310, 0x6313, 0x6316, 0x6318, 0x6329, 0x632a, 0x632d, 0x6335, 0x6336,
+    0x6339, 0x633c, 0x6341, 0x6342, 0x6343, 0x6344, 0x6346, 0x634a, 0x634b, 0x634e, 0x6352, 0x6353,
+    0x6354, 0x6358, 0x635b, 0x6365, 0x6366, 0x636c, 0x636d, 0x6371, 0x6374, 0x6375, 0x6378, 0x637c,
+    0x637d, 0x637f, 0x6382, 0x6384, 0x6387, 0x638a, 0x6390, 0x6394, 0x6395, 0x6399, 0x639a, 0x639e,
+    0x63a4, 0x63a6, 0x63ad, 0x63ae, 0x63af, 0x63bd, 0x63c1, 0x63c5, 0x63c8, 0x63ce, 0x63d1, 0x63d3,
+    0x63d4, 0x63d5, 0x63dc, 0x63e0, 0x63e5, 0x63ea, 0x63ec, 0x63f2, 0x63f3, 0x63f5, 0x63f8, 0x63f9,
+    0x6409, 0x640a, 0x6410, 0x6412, 0x6414, 0x6418, 0x641e, 0x6420, 0x6422, 0x6424, 0x6425, 0x6429,
+    0x642a, 0x642f, 0x6430, 0x6435, 0x643d, 0x643f, 0x644b, 0x644f, 0x6451, 0x6452, 0x6453, 0x6454,
+    0x645a, 0x645b, 0x645c, 0x645d, 0x645f, 0x6460, 0x6461, 0x6463, 0x646d, 0x6473, 0x6474, 0x647b,
+    0x647d, 0x6485, 0x6487, 0x648f, 0x6490, 0x6491, 0x6498, 0x6499, 0x649b, 0x649d, 0x649f, 0x64a1,
+    0x64a3, 0x64a6, 0x64a8, 0x64ac, 0x64b3, 0x64bd, 0x64be, 0x64bf, 0x64c4, 0x64c9, 0x64ca, 0x64cb,
+    0x64cc, 0x64ce, 0x64d0, 0x64d1, 0x64d5, 0x64d7, 0x64e4, 0x64e5, 0x64e9, 0x64ea, 0x64ed, 0x64f0,
+    0x64f5, 0x64f7, 0x64fb, 0x64ff, 0x6501, 0x6504, 0x6508, 0x6509, 0x650a, 0x650f, 0x6513, 0x6514,
+    0x6516, 0x6519, 0x651b, 0x651e, 0x651f, 0x6522, 0x6526, 0x6529, 0x652e, 0x6531, 0x653a, 0x653c,
+    0x653d, 0x6543, 0x6547, 0x6549, 0x6550, 0x6552, 0x6554, 0x655f, 0x6560, 0x6567, 0x656b, 0x657a,
+    0x657d, 0x6581, 0x6585, 0x658a, 0x6592, 0x6595, 0x6598, 0x659d, 0x65a0, 0x65a3, 0x65a6, 0x65ae,
+    0x65b2, 0x65b3, 0x65b4, 0x65bf, 0x65c2, 0x65c8, 0x65c9, 0x65ce, 0x65d0, 0x65d4, 0x65d6, 0x65d8,
+    0x65df, 0x65f0, 0x65f2, 0x65f4, 0x65f5, 0x65f9, 0x65fe, 0x65ff, 0x6600, 0x6604, 0x6608, 0x6609,
+    0x660d, 0x6611, 0x6612, 0x6615, 0x6616, 0x661d, 0x661e, 0x6621, 0x6622, 0x6623, 0x6624, 0x6626,
+    0x6629, 0x662a, 0x662b, 0x662c, 0x662e, 0x6630, 0x6631, 0x6633, 0x6639, 0x6637, 0x6640, 0x6645,
+    0x6646, 0x664a, 0x664c, 0x6651, 0x664e, 0x6657, 0x6658, 0x6659, 0x665b, 0x665c, 0x6660, 0x6661,
+    0x66fb, 0x666a, 0x666b, 0x666c, 0x667e, 0x6673, 0x6675, 0x667f, 0x6677, 0x6678, 0x6679, 0x667b,
+    0x6680, 0x667c, 0x668b, 0x668c, 0x668d, 0x6690, 0x6692, 0x6699, 0x669a, 0x669b, 0x669c, 0x669f,
+    0x66a0, 0x66a4, 0x66ad, 0x66b1, 0x66b2, 0x66b5, 0x66bb, 0x66bf, 0x66c0, 0x66c2, 0x66c3, 0x66c8,
+    0x66cc, 0x66ce, 0x66cf, 0x66d4, 0x66db, 0x66df, 0x66e8, 0x66eb, 0x66ec, 0x66ee, 0x66fa, 0x6705,
+    0x6707, 0x670e, 0x6713, 0x6719, 0x671c, 0x6720, 0x6722, 0x6733, 0x673e, 0x6745, 0x6747, 0x6748,
+    0x674c, 0x6754, 0x6755, 0x675d, 0x6766, 0x676c, 0x676e, 0x6774, 0x6776, 0x677b, 0x6781, 0x6784,
+    0x678e, 0x678f, 0x6791, 0x6793, 0x6796, 0x6798, 0x6799, 0x679b, 0x67b0, 0x67b1, 0x67b2, 0x67b5,
+    0x67bb, 0x67bc, 0x67bd, 0x67f9, 0x67c0, 0x67c2, 0x67c3, 0x67c5, 0x67c8, 0x67c9, 0x67d2, 0x67d7,
+    0x67d9, 0x67dc, 0x67e1, 0x67e6, 0x67f0, 0x67f2, 0x67f6, 0x67f7, 0x6852, 0x6814, 0x6819, 0x681d,
+    0x681f, 0x6828, 0x6827, 0x682c, 0x682d, 0x682f, 0x6830, 0x6831, 0x6833, 0x683b, 0x683f, 0x6844,
+    0x6845, 0x684a, 0x684c, 0x6855, 0x6857, 0x6858, 0x685b, 0x686b, 0x686e, 0x686f, 0x6870, 0x6871,
+    0x6872, 0x6875, 0x6879, 0x687a, 0x687b, 0x687c, 0x6882, 0x6884, 0x6886, 0x6888, 0x6896, 0x6898,
+    0x689a, 0x689c, 0x68a1, 0x68a3, 0x68a5, 0x68a9, 0x68aa, 0x68ae, 0x68b2, 0x68bb, 0x68c5, 0x68c8,
+    0x68cc, 0x68cf, 0x68d0, 0x68d1, 0x68d3, 0x68d6, 0x68d9, 0x68dc, 0x68dd, 0x68e5, 0x68e8, 0x68ea,
+    0x68eb, 0x68ec, 0x68ed, 0x68f0, 0x68f1, 0x68f5, 0x68f6, 0x68fb, 0x68fc, 0x68fd, 0x6906, 0x6909,
+    0x690a, 0x6910, 0x6911, 0x6913, 0x6916, 0x6917, 0x6931, 0x6933, 0x6935, 0x6938, 0x693b, 0x6942,
+    0x6945, 0x6949, 0x694e, 0x6957, 0x695b, 0x6963, 0x6964, 0x6965, 0x6966, 0x6968, 0x6969, 0x696c,
+    0x6970, 0x6971, 0x6972, 0x697a, 0x697b, 0x697f, 0x6980, 0x698d, 0x6992, 0x6996, 0x6998, 0x69a1,
+    0x69a5, 0x69a6, 0x69a8, 0x69ab, 0x69ad, 0x69af, 0x69b7, 0x69b8, 0x69ba, 0x69bc, 0x69c5, 0x69c8,
+    0x69d1, 0x69d6, 0x69d7, 0x69e2, 0x69e5, 0x69ee, 0x69ef, 0x69f1, 0x69f3, 0x69f5, 0x69fe, 0x6a00,
+    0x6a01, 0x6a03, 0x6a0f, 0x6a11, 0x6a15, 0x6a1a, 0x6a1d, 0x6a20, 0x6a24, 0x6a28, 0x6a30, 0x6a32,
+    0x6a34, 0x6a37, 0x6a3b, 0x6a3e, 0x6a3f, 0x6a45, 0x6a46, 0x6a49, 0x6a4a, 0x6a4e, 0x6a50, 0x6a51,
+    0x6a52, 0x6a55, 0x6a56, 0x6a5b, 0x6a64, 0x6a67, 0x6a6a, 0x6a71, 0x6a73, 0x6a7e, 0x6a81, 0x6a83,
+    0x6a86, 0x6a87, 0x6a89, 0x6a8b, 0x6a91, 0x6a9b, 0x6a9d, 0x6a9e, 0x6a9f, 0x6aa5, 0x6aab, 0x6aaf,
+    0x6ab0, 0x6ab1, 0x6ab4, 0x6abd, 0x6abe, 0x6abf, 0x6ac6, 0x6ac9, 0x6ac8, 0x6acc, 0x6ad0, 0x6ad4,
+    0x6ad5, 0x6ad6, 0x6adc, 0x6add, 0x6ae4, 0x6ae7, 0x6aec, 0x6af0, 0x6af1, 0x6af2, 0x6afc, 0x6afd,
+    0x6b02, 0x6b03, 0x6b06, 0x6b07, 0x6b09, 0x6b0f, 0x6b10, 0x6b11, 0x6b17, 0x6b1b, 0x6b1e, 0x6b24,
+    0x6b28, 0x6b2b, 0x6b2c, 0x6b2f, 0x6b35, 0x6b36, 0x6b3b, 0x6b3f, 0x6b46, 0x6b4a, 0x6b4d, 0x6b52,
+    0x6b56, 0x6b58, 0x6b5d, 0x6b60, 0x6b67, 0x6b6b, 0x6b6e, 0x6b70, 0x6b75, 0x6b7d, 0x6b7e, 0x6b82,
+    0x6b85, 0x6b97, 0x6b9b, 0x6b9f, 0x6ba0, 0x6ba2, 0x6ba3, 0x6ba8, 0x6ba9, 0x6bac, 0x6bad, 0x6bae,
+    0x6bb0, 0x6bb8, 0x6bb9, 0x6bbd, 0x6bbe, 0x6bc3, 0x6bc4, 0x6bc9, 0x6bcc, 0x6bd6, 0x6bda, 0x6be1,
+    0x6be3, 0x6be6, 0x6be7, 0x6bee, 0x6bf1, 0x6bf7, 0x6bf9, 0x6bff, 0x6c02, 0x6c04, 0x6c05, 0x6c09,
+    0x6c0d, 0x6c0e, 0x6c10, 0x6c12, 0x6c19, 0x6c1f, 0x6c26, 0x6c27, 0x6c28, 0x6c2c, 0x6c2e, 0x6c33,
+    0x6c35, 0x6c36, 0x6c3a, 0x6c3b, 0x6c3f, 0x6c4a, 0x6c4b, 0x6c4d, 0x6c4f, 0x6c52, 0x6c54, 0x6c59,
+    0x6c5b, 0x6c5c, 0x6c6b, 0x6c6d, 0x6c6f, 0x6c74, 0x6c76, 0x6c78, 0x6c79, 0x6c7b, 0x6c85, 0x6c86,
+    0x6c87, 0x6c89, 0x6c94, 0x6c95, 0x6c97, 0x6c98, 0x6c9c, 0x6c9f, 0x6cb0, 0x6cb2, 0x6cb4, 0x6cc2,
+    0x6cc6, 0x6ccd, 0x6ccf, 0x6cd0, 0x6cd1, 0x6cd2, 0x6cd4, 0x6cd6, 0x6cda, 0x6cdc, 0x6ce0, 0x6ce7,
+    0x6ce9, 0x6ceb, 0x6cec, 0x6cee, 0x6cf2, 0x6cf4, 0x6d04, 0x6d07, 0x6d0a, 0x6d0e, 0x6d0f, 0x6d11,
+    0x6d13, 0x6d1a, 0x6d26, 0x6d27, 0x6d28, 0x6c67, 0x6d2e, 0x6d2f, 0x6d31, 0x6d39, 0x6d3c, 0x6d3f,
+    0x6d57, 0x6d5e, 0x6d5f, 0x6d61, 0x6d65, 0x6d67, 0x6d6f, 0x6d70, 0x6d7c, 0x6d82, 0x6d87, 0x6d91,
+    0x6d92, 0x6d94, 0x6d96, 0x6d97, 0x6d98, 0x6daa, 0x6dac, 0x6db4, 0x6db7, 0x6db9, 0x6dbd, 0x6dbf,
+    0x6dc4, 0x6dc8, 0x6dca, 0x6dce, 0x6dcf, 0x6dd6, 0x6ddb, 0x6ddd, 0x6ddf, 0x6de0, 0x6de2, 0x6de5,
+    0x6de9, 0x6def, 0x6df0, 0x6df4, 0x6df6, 0x6dfc, 0x6e00, 0x6e04, 0x6e1e, 0x6e22, 0x6e27, 0x6e32,
+    0x6e36, 0x6e39, 0x6e3b, 0x6e3c, 0x6e44, 0x6e45, 0x6e48, 0x6e49, 0x6e4b, 0x6e4f, 0x6e51, 0x6e52,
+    0x6e53, 0x6e54, 0x6e57, 0x6e5c, 0x6e5d, 0x6e5e, 0x6e62, 0x6e63, 0x6e68, 0x6e73, 0x6e7b, 0x6e7d,
+    0x6e8d, 0x6e93, 0x6e99, 0x6ea0, 0x6ea7, 0x6ead, 0x6eae, 0x6eb1, 0x6eb3, 0x6ebb, 0x6ebf, 0x6ec0,
+    0x6ec1, 0x6ec3, 0x6ec7, 0x6ec8, 0x6eca, 0x6ecd, 0x6ece, 0x6ecf, 0x6eeb, 0x6eed, 0x6eee, 0x6ef9,
+    0x6efb, 0x6efd, 0x6f04, 0x6f08, 0x6f0a, 0x6f0c, 0x6f0d, 0x6f16, 0x6f18, 0x6f1a, 0x6f1b, 0x6f26,
+    0x6f29, 0x6f2a, 0x6f2f, 0x6f30, 0x6f33, 0x6f36, 0x6f3b, 0x6f3c, 0x6f2d, 0x6f4f, 0x6f51, 0x6f52,
+    0x6f53, 0x6f57, 0x6f59, 0x6f5a, 0x6f5d, 0x6f5e, 0x6f61, 0x6f62, 0x6f68, 0x6f6c, 0x6f7d, 0x6f7e,
+    0x6f83, 0x6f87, 0x6f88, 0x6f8b, 0x6f8c, 0x6f8d, 0x6f90, 0x6f92, 0x6f93, 0x6f94, 0x6f96, 0x6f9a,
+    0x6f9f, 0x6fa0, 0x6fa5, 0x6fa6, 0x6fa7, 0x6fa8, 0x6fae, 0x6faf, 0x6fb0, 0x6fb5, 0x6fb6, 0x6fbc,
+    0x6fc5, 0x6fc7, 0x6fc8, 0x6fca, 0x6fda, 0x6fde, 0x6fe8, 0x6fe9, 0x6ff0, 0x6ff5, 0x6ff9, 0x6ffc,
+    0x6ffd, 0x7000, 0x7005, 0x7006, 0x7007, 0x700d, 0x7017, 0x7020, 0x7023, 0x702f, 0x7034, 0x7037,
+    0x7039, 0x703c, 0x7043, 0x7044, 0x7048, 0x7049, 0x704a, 0x704b, 0x7054, 0x7055, 0x705d, 0x705e,
+    0x704e, 0x7064, 0x7065, 0x706c, 0x706e, 0x7075, 0x7076, 0x707e, 0x7081, 0x7085, 0x7086, 0x7094,
+    0x7095, 0x7096, 0x7097, 0x7098, 0x709b, 0x70a4, 0x70ab, 0x70b0, 0x70b1, 0x70b4, 0x70b7, 0x70ca,
+    0x70d1, 0x70d3, 0x70d4, 0x70d5, 0x70d6, 0x70d8, 0x70dc, 0x70e4, 0x70fa, 0x7103, 0x7104, 0x7105,
+    0x7106, 0x7107, 0x710b, 0x710c, 0x710f, 0x711e, 0x7120, 0x712b, 0x712d, 0x712f, 0x7130, 0x7131,
+    0x7138, 0x7141, 0x7145, 0x7146, 0x7147, 0x714a, 0x714b, 0x7150, 0x7152, 0x7157, 0x715a, 0x715c,
+    0x715e, 0x7160, 0x7168, 0x7179, 0x7180, 0x7185, 0x7187, 0x718c, 0x7192, 0x719a, 0x719b, 0x71a0,
+    0x71a2, 0x71af, 0x71b0, 0x71b2, 0x71b3, 0x71ba, 0x71bf, 0x71c0, 0x71c1, 0x71c4, 0x71cb, 0x71cc,
+    0x71d3, 0x71d6, 0x71d9, 0x71da, 0x71dc, 0x71f8, 0x71fe, 0x7200, 0x7207, 0x7208, 0x7209, 0x7213,
+    0x7217, 0x721a, 0x721d, 0x721f, 0x7224, 0x722b, 0x722f, 0x7234, 0x7238, 0x7239, 0x7241, 0x7242,
+    0x7243, 0x7245, 0x724e, 0x724f, 0x7250, 0x7253, 0x7255, 0x7256, 0x725a, 0x725c, 0x725e, 0x7260,
+    0x7263, 0x7268, 0x726b, 0x726e, 0x726f, 0x7271, 0x7277, 0x7278, 0x727b, 0x727c, 0x727f, 0x7284,
+    0x7289, 0x728d, 0x728e, 0x7293, 0x729b, 0x72a8, 0x72ad, 0x72ae, 0x72b1, 0x72b4, 0x72be, 0x72c1,
+    0x72c7, 0x72c9, 0x72cc, 0x72d5, 0x72d6, 0x72d8, 0x72df, 0x72e5, 0x72f3, 0x72f4, 0x72fa, 0x72fb,
+    0x72fe, 0x7302, 0x7304, 0x7305, 0x7307, 0x730b, 0x730d, 0x7312, 0x7313, 0x7318, 0x7319, 0x731e,
+    0x7322, 0x7324, 0x7327, 0x7328, 0x732c, 0x7331, 0x7332, 0x7335, 0x733a, 0x733b, 0x733d, 0x7343,
+    0x734d, 0x7350, 0x7352, 0x7356, 0x7358, 0x735d, 0x735e, 0x735f, 0x7360, 0x7366, 0x7367, 0x7369,
+    0x736b, 0x736c, 0x736e, 0x736f, 0x7371, 0x7377, 0x7379, 0x737c, 0x7380, 0x7381, 0x7383, 0x7385,
+    0x7386, 0x738e, 0x7390, 0x7393, 0x7395, 0x7397, 0x7398, 0x739c, 0x739e, 0x739f, 0x73a0, 0x73a2,
+    0x73a5, 0x73a6, 0x73aa, 0x73ab, 0x73ad, 0x73b5, 0x73b7, 0x73b9, 0x73bc, 0x73bd, 0x73bf, 0x73c5,
+    0x73c6, 0x73c9, 0x73cb, 0x73cc, 0x73cf, 0x73d2, 0x73d3, 0x73d6, 0x73d9, 0x73dd, 0x73e1, 0x73e3,
+    0x73e6, 0x73e7, 0x73e9, 0x73f4, 0x73f5, 0x73f7, 0x73f9, 0x73fa, 0x73fb, 0x73fd, 0x73ff, 0x7400,
+    0x7401, 0x7404, 0x7407, 0x740a, 0x7411, 0x741a, 0x741b, 0x7424, 0x7426, 0x7428, 0x7429, 0x742a,
+    0x742b, 0x742c, 0x742d, 0x742e, 0x742f, 0x7430, 0x7431, 0x7439, 0x7440, 0x7443, 0x7444, 0x7446,
+    0x7447, 0x744b, 0x744d, 0x7451, 0x7452, 0x7457, 0x745d, 0x7462, 0x7466, 0x7467, 0x7468, 0x746b,
+    0x746d, 0x746e, 0x7471, 0x7472, 0x7480, 0x7481, 0x7485, 0x7486, 0x7487, 0x7489, 0x748f, 0x7490,
+    0x7491, 0x7492, 0x7498, 0x7499, 0x749a, 0x749c, 0x749f, 0x74a0, 0x74a1, 0x74a3, 0x74a6, 0x74a8,
+    0x74a9, 0x74aa, 0x74ab, 0x74ae, 0x74af, 0x74b1, 0x74b2, 0x74b5, 0x74b9, 0x74bb, 0x74bf, 0x74c8,
+    0x74c9, 0x74cc, 0x74d0, 0x74d3, 0x74d8, 0x74da, 0x74db, 0x74de, 0x74df, 0x74e4, 0x74e8, 0x74ea,
+    0x74eb, 0x74ef, 0x74f4, 0x74fa, 0x74fb, 0x74fc, 0x74ff, 0x7506, 0x7512, 0x7516, 0x7517, 0x7520,
+    0x7521, 0x7524, 0x7527, 0x7529, 0x752a, 0x752f, 0x7536, 0x7539, 0x753d, 0x753e, 0x753f, 0x7540,
+    0x7543, 0x7547, 0x7548, 0x754e, 0x7550, 0x7552, 0x7557, 0x755e, 0x755f, 0x7561, 0x756f, 0x7571,
+    0x7579, 0x757a, 0x757b, 0x757c, 0x757d, 0x757e, 0x7581, 0x7585, 0x7590, 0x7592, 0x7593, 0x7595,
+    0x7599, 0x759c, 0x75a2, 0x75a4, 0x75b4, 0x75ba, 0x75bf, 0x75c0, 0x75c1, 0x75c4, 0x75c6, 0x75cc,
+    0x75ce, 0x75cf, 0x75d7, 0x75dc, 0x75df, 0x75e0, 0x75e1, 0x75e4, 0x75e7, 0x75ec, 0x75ee, 0x75ef,
+    0x75f1, 0x75f9, 0x7600, 0x7602, 0x7603, 0x7604, 0x7607, 0x7608, 0x760a, 0x760c, 0x760f, 0x7612,
+    0x7613, 0x7615, 0x7616, 0x7619, 0x761b, 0x761c, 0x761d, 0x761e, 0x7623, 0x7625, 0x7626, 0x7629,
+    0x762d, 0x7632, 0x7633, 0x7635, 0x7638, 0x7639, 0x763a, 0x763c, 0x764a, 0x7640, 0x7641, 0x7643,
+    0x7644, 0x7645, 0x7649, 0x764b, 0x7655, 0x7659, 0x765f, 0x7664, 0x7665, 0x766d, 0x766e, 0x766f,
+    0x7671, 0x7674, 0x7681, 0x7685, 0x768c, 0x768d, 0x7695, 0x769b, 0x769c, 0x769d, 0x769f, 0x76a0,
+    0x76a2, 0x76a3, 0x76a4, 0x76a5, 0x76a6, 0x76a7, 0x76a8, 0x76aa, 0x76ad, 0x76bd, 0x76c1, 0x76c5,
+    0x76c9, 0x76cb, 0x76cc, 0x76ce, 0x76d4, 0x76d9, 0x76e0, 0x76e6, 0x76e8, 0x76ec, 0x76f0, 0x76f1,
+    0x76f6, 0x76f9, 0x76fc, 0x7700, 0x7706, 0x770a, 0x770e, 0x7712, 0x7714, 0x7715, 0x7717, 0x7719,
+    0x771a, 0x771c, 0x7722, 0x7728, 0x772d, 0x772e, 0x772f, 0x7734, 0x7735, 0x7736, 0x7739, 0x773d,
+    0x773e, 0x7742, 0x7745, 0x7746, 0x774a, 0x774d, 0x774e, 0x774f, 0x7752, 0x7756, 0x7757, 0x775c,
+    0x775e, 0x775f, 0x7760, 0x7762, 0x7764, 0x7767, 0x776a, 0x776c, 0x7770, 0x7772, 0x7773, 0x7774,
+    0x777a, 0x777d, 0x7780, 0x7784, 0x778c, 0x778d, 0x7794, 0x7795, 0x7796, 0x779a, 0x779f, 0x77a2,
+    0x77a7, 0x77aa, 0x77ae, 0x77af, 0x77b1, 0x77b5, 0x77be, 0x77c3, 0x77c9, 0x77d1, 0x77d2, 0x77d5,
+    0x77d9, 0x77de, 0x77df, 0x77e0, 0x77e4, 0x77e6, 0x77ea, 0x77ec, 0x77f0, 0x77f1, 0x77f4, 0x77f8,
+    0x77fb, 0x7805, 0x7806, 0x7809, 0x780d, 0x780e, 0x7811, 0x781d, 0x7821, 0x7822, 0x7823, 0x782d,
+    0x782e, 0x7830, 0x7835, 0x7837, 0x7843, 0x7844, 0x7847, 0x7848, 0x784c, 0x784e, 0x7852, 0x785c,
+    0x785e, 0x7860, 0x7861, 0x7863, 0x7864, 0x7868, 0x786a, 0x786e, 0x787a, 0x787e, 0x788a, 0x788f,
+    0x7894, 0x7898, 0x78a1, 0x789d, 0x789e, 0x789f, 0x78a4, 0x78a8, 0x78ac, 0x78ad, 0x78b0, 0x78b1,
+    0x78b2, 0x78b3, 0x78bb, 0x78bd, 0x78bf, 0x78c7, 0x78c8, 0x78c9, 0x78cc, 0x78ce, 0x78d2, 0x78d3,
+    0x78d5, 0x78d6, 0x78e4, 0x78db, 0x78df, 0x78e0, 0x78e1, 0x78e6, 0x78ea, 0x78f2, 0x78f3, 0x7900,
+    0x78f6, 0x78f7, 0x78fa, 0x78fb, 0x78ff, 0x7906, 0x790c, 0x7910, 0x791a, 0x791c, 0x791e, 0x791f,
+    0x7920, 0x7925, 0x7927, 0x7929, 0x792d, 0x7931, 0x7934, 0x7935, 0x793b, 0x793d, 0x793f, 0x7944,
+    0x7945, 0x7946, 0x794a, 0x794b, 0x794f, 0x7951, 0x7954, 0x7958, 0x795b, 0x795c, 0x7967, 0x7969,
+    0x796b, 0x7972, 0x7979, 0x797b, 0x797c, 0x797e, 0x798b, 0x798c, 0x7991, 0x7993, 0x7994, 0x7995,
+    0x7996, 0x7998, 0x799b, 0x799c, 0x79a1, 0x79a8, 0x79a9, 0x79ab, 0x79af, 0x79b1, 0x79b4, 0x79b8,
+    0x79bb, 0x79c2, 0x79c4, 0x79c7, 0x79c8, 0x79ca, 0x79cf, 0x79d4, 0x79d6, 0x79da, 0x79dd, 0x79de,
+    0x79e0, 0x79e2, 0x79e5, 0x79ea, 0x79eb, 0x79ed, 0x79f1, 0x79f8, 0x79fc, 0x7a02, 0x7a03, 0x7a07,
+    0x7a09, 0x7a0a, 0x7a0c, 0x7a11, 0x7a15, 0x7a1b, 0x7a1e, 0x7a21, 0x7a27, 0x7a2b, 0x7a2d, 0x7a2f,
+    0x7a30, 0x7a34, 0x7a35, 0x7a38, 0x7a39, 0x7a3a, 0x7a44, 0x7a45, 0x7a47, 0x7a48, 0x7a4c, 0x7a55,
+    0x7a56, 0x7a59, 0x7a5c, 0x7a5d, 0x7a5f, 0x7a60, 0x7a65, 0x7a67, 0x7a6a, 0x7a6d, 0x7a75, 0x7a78,
+    0x7a7e, 0x7a80, 0x7a82, 0x7a85, 0x7a86, 0x7a8a, 0x7a8b, 0x7a90, 0x7a91, 0x7a94, 0x7a9e, 0x7aa0,
+    0x7aa3, 0x7aac, 0x7ab3, 0x7ab5, 0x7ab9, 0x7abb, 0x7abc, 0x7ac6, 0x7ac9, 0x7acc, 0x7ace, 0x7ad1,
+    0x7adb, 0x7ae8, 0x7ae9, 0x7aeb, 0x7aec, 0x7af1, 0x7af4, 0x7afb, 0x7afd, 0x7afe, 0x7b07, 0x7b14,
+    0x7b1f, 0x7b23, 0x7b27, 0x7b29, 0x7b2a, 0x7b2b, 0x7b2d, 0x7b2e, 0x7b2f, 0x7b30, 0x7b31, 0x7b34,
+    0x7b3d, 0x7b3f, 0x7b40, 0x7b41, 0x7b47, 0x7b4e, 0x7b55, 0x7b60, 0x7b64, 0x7b66, 0x7b69, 0x7b6a,
+    0x7b6d, 0x7b6f, 0x7b72, 0x7b73, 0x7b77, 0x7b84, 0x7b89, 0x7b8e, 0x7b90, 0x7b91, 0x7b96, 0x7b9b,
+    0x7b9e, 0x7ba0, 0x7ba5, 0x7bac, 0x7baf, 0x7bb0, 0x7bb2, 0x7bb5, 0x7bb6, 0x7bba, 0x7bbb, 0x7bbc,
+    0x7bbd, 0x7bc2, 0x7bc5, 0x7bc8, 0x7bca, 0x7bd4, 0x7bd6, 0x7bd7, 0x7bd9, 0x7bda, 0x7bdb, 0x7be8,
+    0x7bea, 0x7bf2, 0x7bf4, 0x7bf5, 0x7bf8, 0x7bf9, 0x7bfa, 0x7bfc, 0x7bfe, 0x7c01, 0x7c02, 0x7c03,
+    0x7c04, 0x7c06, 0x7c09, 0x7c0b, 0x7c0c, 0x7c0e, 0x7c0f, 0x7c19, 0x7c1b, 0x7c20, 0x7c25, 0x7c26,
+    0x7c28, 0x7c2c, 0x7c31, 0x7c33, 0x7c34, 0x7c36, 0x7c39, 0x7c3a, 0x7c46, 0x7c4a, 0x7c55, 0x7c51,
+    0x7c52, 0x7c53, 0x7c59, 0x7c5a, 0x7c5b, 0x7c5c, 0x7c5d, 0x7c5e, 0x7c61, 0x7c63, 0x7c67, 0x7c69,
+    0x7c6d, 0x7c6e, 0x7c70, 0x7c72, 0x7c79, 0x7c7c, 0x7c7d, 0x7c86, 0x7c87, 0x7c8f, 0x7c94, 0x7c9e,
+    0x7ca0, 0x7ca6, 0x7cb0, 0x7cb6, 0x7cb7, 0x7cba, 0x7cbb, 0x7cbc, 0x7cbf, 0x7cc4, 0x7cc7, 0x7cc8,
+    0x7cc9, 0x7ccd, 0x7ccf, 0x7cd3, 0x7cd4, 0x7cd5, 0x7cd7, 0x7cd9, 0x7cda, 0x7cdd, 0x7ce6, 0x7ce9,
+    0x7ceb, 0x7cf5, 0x7d03, 0x7d07, 0x7d08, 0x7d09, 0x7d0f, 0x7d11, 0x7d12, 0x7d13, 0x7d16, 0x7d1d,
+    0x7d1e, 0x7d23, 0x7d26, 0x7d2a, 0x7d2d, 0x7d31, 0x7d3c, 0x7d3d, 0x7d3e, 0x7d40, 0x7d41, 0x7d47,
+    0x7d48, 0x7d4d, 0x7d51, 0x7d53, 0x7d57, 0x7d59, 0x7d5a, 0x7d5c, 0x7d5d, 0x7d65, 0x7d67, 0x7d6a,
+    0x7d70, 0x7d78, 0x7d7a, 0x7d7b, 0x7d7f, 0x7d81, 0x7d82, 0x7d83, 0x7d85, 0x7d86, 0x7d88, 0x7d8b,
+    0x7d8c, 0x7d8d, 0x7d91, 0x7d96, 0x7d97, 0x7d9d, 0x7d9e, 0x7da6, 0x7da7, 0x7daa, 0x7db3, 0x7db6,
+    0x7db7, 0x7db9, 0x7dc2, 0x7dc3, 0x7dc4, 0x7dc5, 0x7dc6, 0x7dcc, 0x7dcd, 0x7dce, 0x7dd7, 0x7dd9,
+    0x7e00, 0x7de2, 0x7de5, 0x7de6, 0x7dea, 0x7deb, 0x7ded, 0x7df1, 0x7df5, 0x7df6, 0x7df9, 0x7dfa,
+    0x7e08, 0x7e10, 0x7e11, 0x7e15, 0x7e17, 0x7e1c, 0x7e1d, 0x7e20, 0x7e27, 0x7e28, 0x7e2c, 0x7e2d,
+    0x7e2f, 0x7e33, 0x7e36, 0x7e3f, 0x7e44, 0x7e45, 0x7e47, 0x7e4e, 0x7e50, 0x7e52, 0x7e58, 0x7e5f,
+    0x7e61, 0x7e62, 0x7e65, 0x7e6b, 0x7e6e, 0x7e6f, 0x7e73, 0x7e78, 0x7e7e, 0x7e81, 0x7e86, 0x7e87,
+    0x7e8a, 0x7e8d, 0x7e91, 0x7e95, 0x7e98, 0x7e9a, 0x7e9d, 0x7e9e, 0x7f3c, 0x7f3b, 0x7f3d, 0x7f3e,
+    0x7f3f, 0x7f43, 0x7f44, 0x7f47, 0x7f4f, 0x7f52, 0x7f53, 0x7f5b, 0x7f5c, 0x7f5d, 0x7f61, 0x7f63,
+    0x7f64, 0x7f65, 0x7f66, 0x7f6d, 0x7f71, 0x7f7d, 0x7f7e, 0x7f7f, 0x7f80, 0x7f8b, 0x7f8d, 0x7f8f,
+    0x7f90, 0x7f91, 0x7f96, 0x7f97, 0x7f9c, 0x7fa1, 0x7fa2, 0x7fa6, 0x7faa, 0x7fad, 0x7fb4, 0x7fbc,
+    0x7fbf, 0x7fc0, 0x7fc3, 0x7fc8, 0x7fce, 0x7fcf, 0x7fdb, 0x7fdf, 0x7fe3, 0x7fe5, 0x7fe8, 0x7fec,
+    0x7fee, 0x7fef, 0x7ff2, 0x7ffa, 0x7ffd, 0x7ffe, 0x7fff, 0x8007, 0x8008, 0x800a, 0x800d, 0x800e,
+    0x800f, 0x8011, 0x8013, 0x8014, 0x8016, 0x801d, 0x801e, 0x801f, 0x8020, 0x8024, 0x8026, 0x802c,
+    0x802e, 0x8030, 0x8034, 0x8035, 0x8037, 0x8039, 0x803a, 0x803c, 0x803e, 0x8040, 0x8044, 0x8060,
+    0x8064, 0x8066, 0x806d, 0x8071, 0x8075, 0x8081, 0x8088, 0x808e, 0x809c, 0x809e, 0x80a6, 0x80a7,
+    0x80ab, 0x80b8, 0x80b9, 0x80c8, 0x80cd, 0x80cf, 0x80d2, 0x80d4, 0x80d5, 0x80d7, 0x80d8, 0x80e0,
+    0x80ed, 0x80ee, 0x80f0, 0x80f2, 0x80f3, 0x80f6, 0x80f9, 0x80fa, 0x80fe, 0x8103, 0x810b, 0x8116,
+    0x8117, 0x8118, 0x811c, 0x811e, 0x8120, 0x8124, 0x8127, 0x812c, 0x8130, 0x8135, 0x813a, 0x813c,
+    0x8145, 0x8147, 0x814a, 0x814c, 0x8152, 0x8157, 0x8160, 0x8161, 0x8167, 0x8168, 0x8169, 0x816d,
+    0x816f, 0x8177, 0x8181, 0x8190, 0x8184, 0x8185, 0x8186, 0x818b, 0x818e, 0x8196, 0x8198, 0x819b,
+    0x819e, 0x81a2, 0x81ae, 0x81b2, 0x81b4, 0x81bb, 0x81cb, 0x81c3, 0x81c5, 0x81ca, 0x81ce, 0x81cf,
+    0x81d5, 0x81d7, 0x81db, 0x81dd, 0x81de, 0x81e1, 0x81e4, 0x81eb, 0x81ec, 0x81f0, 0x81f1, 0x81f2,
+    0x81f5, 0x81f6, 0x81f8, 0x81f9, 0x81fd, 0x81ff, 0x8200, 0x8203, 0x820f, 0x8213, 0x8214, 0x8219,
+    0x821a, 0x821d, 0x8221, 0x8222, 0x8228, 0x8232, 0x8234, 0x823a, 0x8243, 0x8244, 0x8245, 0x8246,
+    0x824b, 0x824e, 0x824f, 0x8251, 0x8256, 0x825c, 0x8260, 0x8263, 0x8267, 0x826d, 0x8274, 0x827b,
+    0x827d, 0x827f, 0x8280, 0x8281, 0x8283, 0x8284, 0x8287, 0x8289, 0x828a, 0x828e, 0x8291, 0x8294,
+    0x8296, 0x8298, 0x829a, 0x829b, 0x82a0, 0x82a1, 0x82a3, 0x82a4, 0x82a7, 0x82a8, 0x82a9, 0x82aa,
+    0x82ae, 0x82b0, 0x82b2, 0x82b4, 0x82b7, 0x82ba, 0x82bc, 0x82be, 0x82bf, 0x82c6, 0x82d0, 0x82d5,
+    0x82da, 0x82e0, 0x82e2, 0x82e4, 0x82e8, 0x82ea, 0x82ed, 0x82ef, 0x82f6, 0x82f7, 0x82fd, 0x82fe,
+    0x8300, 0x8301, 0x8307, 0x8308, 0x830a, 0x830b, 0x8354, 0x831b, 0x831d, 0x831e, 0x831f, 0x8321,
+    0x8322, 0x832c, 0x832d, 0x832e, 0x8330, 0x8333, 0x8337, 0x833a, 0x833c, 0x833d, 0x8342, 0x8343,
+    0x8344, 0x8347, 0x834d, 0x834e, 0x8351, 0x8355, 0x8356, 0x8357, 0x8370, 0x8378, 0x837d, 0x837f,
+    0x8380, 0x8382, 0x8384, 0x8386, 0x838d, 0x8392, 0x8394, 0x8395, 0x8398, 0x8399, 0x839b, 0x839c,
+    0x839d, 0x83a6, 0x83a7, 0x83a9, 0x83ac, 0x83be, 0x83bf, 0x83c0, 0x83c7, 0x83c9, 0x83cf, 0x83d0,
+    0x83d1, 0x83d4, 0x83dd, 0x8353, 0x83e8, 0x83ea, 0x83f6, 0x83f8, 0x83f9, 0x83fc, 0x8401, 0x8406,
+    0x840a, 0x840f, 0x8411, 0x8415, 0x8419, 0x83ad, 0x842f, 0x8439, 0x8445, 0x8447, 0x8448, 0x844a,
+    0x844d, 0x844f, 0x8451, 0x8452, 0x8456, 0x8458, 0x8459, 0x845a, 0x845c, 0x8460, 0x8464, 0x8465,
+    0x8467, 0x846a, 0x8470, 0x8473, 0x8474, 0x8476, 0x8478, 0x847c, 0x847d, 0x8481, 0x8485, 0x8492,
+    0x8493, 0x8495, 0x849e, 0x84a6, 0x84a8, 0x84a9, 0x84aa, 0x84af, 0x84b1, 0x84b4, 0x84ba, 0x84bd,
+    0x84be, 0x84c0, 0x84c2, 0x84c7, 0x84c8, 0x84cc, 0x84cf, 0x84d3, 0x84dc, 0x84e7, 0x84ea, 0x84ef,
+    0x84f0, 0x84f1, 0x84f2, 0x84f7, 0x8532, 0x84fa, 0x84fb, 0x84fd, 0x8502, 0x8503, 0x8507, 0x850c,
+    0x850e, 0x8510, 0x851c, 0x851e, 0x8522, 0x8523, 0x8524, 0x8525, 0x8527, 0x852a, 0x852b, 0x852f,
+    0x8533, 0x8534, 0x8536, 0x853f, 0x8546, 0x854f, 0x8550, 0x8551, 0x8552, 0x8553, 0x8556, 0x8559,
+    0x855c, 0x855d, 0x855e, 0x855f, 0x8560, 0x8561, 0x8562, 0x8564, 0x856b, 0x856f, 0x8579, 0x857a,
+    0x857b, 0x857d, 0x857f, 0x8581, 0x8585, 0x8586, 0x8589, 0x858b, 0x858c, 0x858f, 0x8593, 0x8598,
+    0x859d, 0x859f, 0x85a0, 0x85a2, 0x85a5, 0x85a7, 0x85b4, 0x85b6, 0x85b7, 0x85b8, 0x85bc, 0x85bd,
+    0x85be, 0x85bf, 0x85c2, 0x85c7, 0x85ca, 0x85cb, 0x85ce, 0x85ad, 0x85d8, 0x85da, 0x85df, 0x85e0,
+    0x85e6, 0x85e8, 0x85ed, 0x85f3, 0x85f6, 0x85fc, 0x85ff, 0x8600, 0x8604, 0x8605, 0x860d, 0x860e,
+    0x8610, 0x8611, 0x8612, 0x8618, 0x8619, 0x861b, 0x861e, 0x8621, 0x8627, 0x8629, 0x8636, 0x8638,
+    0x863a, 0x863c, 0x863d, 0x8640, 0x8642, 0x8646, 0x8652, 0x8653, 0x8656, 0x8657, 0x8658, 0x8659,
+    0x865d, 0x8660, 0x8661, 0x8662, 0x8663, 0x8664, 0x8669, 0x866c, 0x866f, 0x8675, 0x8676, 0x8677,
+    0x867a, 0x868d, 0x8691, 0x8696, 0x8698, 0x869a, 0x869c, 0x86a1, 0x86a6, 0x86a7, 0x86a8, 0x86ad,
+    0x86b1, 0x86b3, 0x86b4, 0x86b5, 0x86b7, 0x86b8, 0x86b9, 0x86bf, 0x86c0, 0x86c1, 0x86c3, 0x86c5,
+    0x86d1, 0x86d2, 0x86d5, 0x86d7, 0x86da, 0x86dc, 0x86e0, 0x86e3, 0x86e5, 0x86e7, 0x8688, 0x86fa,
+    0x86fc, 0x86fd, 0x8704, 0x8705, 0x8707, 0x870b, 0x870e, 0x870f, 0x8710, 0x8713, 0x8714, 0x8719,
+    0x871e, 0x871f, 0x8721, 0x8723, 0x8728, 0x872e, 0x872f, 0x8731, 0x8732, 0x8739, 0x873a, 0x873c,
+    0x873d, 0x873e, 0x8740, 0x8743, 0x8745, 0x874d, 0x8758, 0x875d, 0x8761, 0x8764, 0x8765, 0x876f,
+    0x8771, 0x8772, 0x877b, 0x8783, 0x8784, 0x8785, 0x8786, 0x8787, 0x8788, 0x8789, 0x878b, 0x878c,
+    0x8790, 0x8793, 0x8795, 0x8797, 0x8798, 0x8799, 0x879e, 0x87a0, 0x87a3, 0x87a7, 0x87ac, 0x87ad,
+    0x87ae, 0x87b1, 0x87b5, 0x87be, 0x87bf, 0x87c1, 0x87c8, 0x87c9, 0x87ca, 0x87ce, 0x87d5, 0x87d6,
+    0x87d9, 0x87da, 0x87dc, 0x87df, 0x87e2, 0x87e3, 0x87e4, 0x87ea, 0x87eb, 0x87ed, 0x87f1, 0x87f3,
+    0x87f8, 0x87fa, 0x87ff, 0x8801, 0x8803, 0x8806, 0x8809, 0x880a, 0x880b, 0x8810, 0x8819, 0x8812,
+    0x8813, 0x8814, 0x8818, 0x881a, 0x881b, 0x881c, 0x881e, 0x881f, 0x8828, 0x882d, 0x882e, 0x8830,
+    0x8832, 0x8835, 0x883a, 0x883c, 0x8841, 0x8843, 0x8845, 0x8848, 0x8849, 0x884a, 0x884b, 0x884e,
+    0x8851, 0x8855, 0x8856, 0x8858, 0x885a, 0x885c, 0x885f, 0x8860, 0x8864, 0x8869, 0x8871, 0x8879,
+    0x887b, 0x8880, 0x8898, 0x889a, 0x889b, 0x889c, 0x889f, 0x88a0, 0x88a8, 0x88aa, 0x88ba, 0x88bd,
+    0x88be, 0x88c0, 0x88ca, 0x88cb, 0x88cc, 0x88cd, 0x88ce, 0x88d1, 0x88d2, 0x88d3, 0x88db, 0x88de,
+    0x88e7, 0x88ef, 0x88f0, 0x88f1, 0x88f5, 0x88f7, 0x8901, 0x8906, 0x890d, 0x890e, 0x890f, 0x8915,
+    0x8916, 0x8918, 0x8919, 0x891a, 0x891c, 0x8920, 0x8926, 0x8927, 0x8928, 0x8930, 0x8931, 0x8932,
+    0x8935, 0x8939, 0x893a, 0x893e, 0x8940, 0x8942, 0x8945, 0x8946, 0x8949, 0x894f, 0x8952, 0x8957,
+    0x895a, 0x895b, 0x895c, 0x8961, 0x8962, 0x8963, 0x896b, 0x896e, 0x8970, 0x8973, 0x8975, 0x897a,
+    0x897b, 0x897c, 0x897d, 0x8989, 0x898d, 0x8990, 0x8994, 0x8995, 0x899b, 0x899c, 0x899f, 0x89a0,
+    0x89a5, 0x89b0, 0x89b4, 0x89b5, 0x89b6, 0x89b7, 0x89bc, 0x89d4, 0x89d5, 0x89d6, 0x89d7, 0x89d8,
+    0x89e5, 0x89e9, 0x89eb, 0x89ed, 0x89f1, 0x89f3, 0x89f6, 0x89f9, 0x89fd, 0x89ff, 0x8a04, 0x8a05,
+    0x8a07, 0x8a0f, 0x8a11, 0x8a12, 0x8a14, 0x8a15, 0x8a1e, 0x8a20, 0x8a22, 0x8a24, 0x8a26, 0x8a2b,
+    0x8a2c, 0x8a2f, 0x8a35, 0x8a37, 0x8a3d, 0x8a3e, 0x8a40, 0x8a43, 0x8a45, 0x8a47, 0x8a49, 0x8a4d,
+    0x8a4e, 0x8a53, 0x8a56, 0x8a57, 0x8a58, 0x8a5c, 0x8a5d, 0x8a61, 0x8a65, 0x8a67, 0x8a75, 0x8a76,
+    0x8a77, 0x8a79, 0x8a7a, 0x8a7b, 0x8a7e, 0x8a7f, 0x8a80, 0x8a83, 0x8a86, 0x8a8b, 0x8a8f, 0x8a90,
+    0x8a92, 0x8a96, 0x8a97, 0x8a99, 0x8a9f, 0x8aa7, 0x8aa9, 0x8aae, 0x8aaf, 0x8ab3, 0x8ab6, 0x8ab7,
+    0x8abb, 0x8abe, 0x8ac3, 0x8ac6, 0x8ac8, 0x8ac9, 0x8aca, 0x8ad1, 0x8ad3, 0x8ad4, 0x8ad5, 0x8ad7,
+    0x8add, 0x8adf, 0x8aec, 0x8af0, 0x8af4, 0x8af5, 0x8af6, 0x8afc, 0x8aff, 0x8b05, 0x8b06, 0x8b0b,
+    0x8b11, 0x8b1c, 0x8b1e, 0x8b1f, 0x8b0a, 0x8b2d, 0x8b30, 0x8b37, 0x8b3c, 0x8b42, 0x8b43, 0x8b44,
+    0x8b45, 0x8b46, 0x8b48, 0x8b52, 0x8b53, 0x8b54, 0x8b59, 0x8b4d, 0x8b5e, 0x8b63, 0x8b6d, 0x8b76,
+    0x8b78, 0x8b79, 0x8b7c, 0x8b7e, 0x8b81, 0x8b84, 0x8b85, 0x8b8b, 0x8b8d, 0x8b8f, 0x8b94, 0x8b95,
+    0x8b9c, 0x8b9e, 0x8b9f, 0x8c38, 0x8c39, 0x8c3d, 0x8c3e, 0x8c45, 0x8c47, 0x8c49, 0x8c4b, 0x8c4f,
+    0x8c51, 0x8c53, 0x8c54, 0x8c57, 0x8c58, 0x8c5b, 0x8c5d, 0x8c59, 0x8c63, 0x8c64, 0x8c66, 0x8c68,
+    0x8c69, 0x8c6d, 0x8c73, 0x8c75, 0x8c76, 0x8c7b, 0x8c7e, 0x8c86, 0x8c87, 0x8c8b, 0x8c90, 0x8c92,
+    0x8c93, 0x8c99, 0x8c9b, 0x8c9c, 0x8ca4, 0x8cb9, 0x8cba, 0x8cc5, 0x8cc6, 0x8cc9, 0x8ccb, 0x8ccf,
+    0x8cd6, 0x8cd5, 0x8cd9, 0x8cdd, 0x8ce1, 0x8ce8, 0x8cec, 0x8cef, 0x8cf0, 0x8cf2, 0x8cf5, 0x8cf7,
+    0x8cf8, 0x8cfe, 0x8cff, 0x8d01, 0x8d03, 0x8d09, 0x8d12, 0x8d17, 0x8d1b, 0x8d65, 0x8d69, 0x8d6c,
+    0x8d6e, 0x8d7f, 0x8d82, 0x8d84, 0x8d88, 0x8d8d, 0x8d90, 0x8d91, 0x8d95, 0x8d9e, 0x8d9f, 0x8da0,
+    0x8da6, 0x8dab, 0x8dac, 0x8daf, 0x8db2, 0x8db5, 0x8db7, 0x8db9, 0x8dbb, 0x8dc0, 0x8dc5, 0x8dc6,
+    0x8dc7, 0x8dc8, 0x8dca, 0x8dce, 0x8dd1, 0x8dd4, 0x8dd5, 0x8dd7, 0x8dd9, 0x8de4, 0x8de5, 0x8de7,
+    0x8dec, 0x8df0, 0x8dbc, 0x8df1, 0x8df2, 0x8df4, 0x8dfd, 0x8e01, 0x8e04, 0x8e05, 0x8e06, 0x8e0b,
+    0x8e11, 0x8e14, 0x8e16, 0x8e20, 0x8e21, 0x8e22, 0x8e23, 0x8e26, 0x8e27, 0x8e31, 0x8e33, 0x8e36,
+    0x8e37, 0x8e38, 0x8e39, 0x8e3d, 0x8e40, 0x8e41, 0x8e4b, 0x8e4d, 0x8e4e, 0x8e4f, 0x8e54, 0x8e5b,
+    0x8e5c, 0x8e5d, 0x8e5e, 0x8e61, 0x8e62, 0x8e69, 0x8e6c, 0x8e6d, 0x8e6f, 0x8e70, 0x8e71, 0x8e79,
+    0x8e7a, 0x8e7b, 0x8e82, 0x8e83, 0x8e89, 0x8e90, 0x8e92, 0x8e95, 0x8e9a, 0x8e9b, 0x8e9d, 0x8e9e,
+    0x8ea2, 0x8ea7, 0x8ea9, 0x8ead, 0x8eae, 0x8eb3, 0x8eb5, 0x8eba, 0x8ebb, 0x8ec0, 0x8ec1, 0x8ec3,
+    0x8ec4, 0x8ec7, 0x8ecf, 0x8ed1, 0x8ed4, 0x8edc, 0x8ee8, 0x8eee, 0x8ef0, 0x8ef1, 0x8ef7, 0x8ef9,
+    0x8efa, 0x8eed, 0x8f00, 0x8f02, 0x8f07, 0x8f08, 0x8f0f, 0x8f10, 0x8f16, 0x8f17, 0x8f18, 0x8f1e,
+    0x8f20, 0x8f21, 0x8f23, 0x8f25, 0x8f27, 0x8f28, 0x8f2c, 0x8f2d, 0x8f2e, 0x8f34, 0x8f35, 0x8f36,
+    0x8f37, 0x8f3a, 0x8f40, 0x8f41, 0x8f43, 0x8f47, 0x8f4f, 0x8f51, 0x8f52, 0x8f53, 0x8f54, 0x8f55,
+    0x8f58, 0x8f5d, 0x8f5e, 0x8f65, 0x8f9d, 0x8fa0, 0x8fa1, 0x8fa4, 0x8fa5, 0x8fa6, 0x8fb5, 0x8fb6,
+    0x8fb8, 0x8fbe, 0x8fc0, 0x8fc1, 0x8fc6, 0x8fca, 0x8fcb, 0x8fcd, 0x8fd0, 0x8fd2, 0x8fd3, 0x8fd5,
+    0x8fe0, 0x8fe3, 0x8fe4, 0x8fe8, 0x8fee, 0x8ff1, 0x8ff5, 0x8ff6, 0x8ffb, 0x8ffe, 0x9002, 0x9004,
+    0x9008, 0x900c, 0x9018, 0x901b, 0x9028, 0x9029, 0x902f, 0x902a, 0x902c, 0x902d, 0x9033, 0x9034,
+    0x9037, 0x903f, 0x9043, 0x9044, 0x904c, 0x905b, 0x905d, 0x9062, 0x9066, 0x9067, 0x906c, 0x9070,
+    0x9074, 0x9079, 0x9085, 0x9088, 0x908b, 0x908c, 0x908e, 0x9090, 0x9095, 0x9097, 0x9098, 0x9099,
+    0x909b, 0x90a0, 0x90a1, 0x90a2, 0x90a5, 0x90b0, 0x90b2, 0x90b3, 0x90b4, 0x90b6, 0x90bd, 0x90cc,
+    0x90be, 0x90c3, 0x90c4, 0x90c5, 0x90c7, 0x90c8, 0x90d5, 0x90d7, 0x90d8, 0x90d9, 0x90dc, 0x90dd,
+    0x90df, 0x90e5, 0x90d2, 0x90f6, 0x90eb, 0x90ef, 0x90f0, 0x90f4, 0x90fe, 0x90ff, 0x9100, 0x9104,
+    0x9105, 0x9106, 0x9108, 0x910d, 0x9110, 0x9114, 0x9116, 0x9117, 0x9118, 0x911a, 0x911c, 0x911e,
+    0x9120, 0x9125, 0x9122, 0x9123, 0x9127, 0x9129, 0x912e, 0x912f, 0x9131, 0x9134, 0x9136, 0x9137,
+    0x9139, 0x913a, 0x913c, 0x913d, 0x9143, 0x9147, 0x9148, 0x914f, 0x9153, 0x9157, 0x9159, 0x915a,
+    0x915b, 0x9161, 0x9164, 0x9167, 0x916d, 0x9174, 0x9179, 0x917a, 0x917b, 0x9181, 0x9183, 0x9185,
+    0x9186, 0x918a, 0x918e, 0x9191, 0x9193, 0x9194, 0x9195, 0x9198, 0x919e, 0x91a1, 0x91a6, 0x91a8,
+    0x91ac, 0x91ad, 0x91ae, 0x91b0, 0x91b1, 0x91b2, 0x91b3, 0x91b6, 0x91bb, 0x91bc, 0x91bd, 0x91bf,
+    0x91c2, 0x91c3, 0x91c5, 0x91d3, 0x91d4, 0x91d7, 0x91d9, 0x91da, 0x91de, 0x91e4, 0x91e5, 0x91e9,
+    0x91ea, 0x91ec, 0x91ed, 0x91ee, 0x91ef, 0x91f0, 0x91f1, 0x91f7, 0x91f9, 0x91fb, 0x91fd, 0x9200,
+    0x9201, 0x9204, 0x9205, 0x9206, 0x9207, 0x9209, 0x920a, 0x920c, 0x9210, 0x9212, 0x9213, 0x9216,
+    0x9218, 0x921c, 0x921d, 0x9223, 0x9224, 0x9225, 0x9226, 0x9228, 0x922e, 0x922f, 0x9230, 0x9233,
+    0x9235, 0x9236, 0x9238, 0x9239, 0x923a, 0x923c, 0x923e, 0x9240, 0x9242, 0x9243, 0x9246, 0x9247,
+    0x924a, 0x924d, 0x924e, 0x924f, 0x9251, 0x9258, 0x9259, 0x925c, 0x925d, 0x9260, 0x9261, 0x9265,
+    0x9267, 0x9268, 0x9269, 0x926e, 0x926f, 0x9270, 0x9275, 0x9276, 0x9277, 0x9278, 0x9279, 0x927b,
+    0x927c, 0x927d, 0x927f, 0x9288, 0x9289, 0x928a, 0x928d, 0x928e, 0x9292, 0x9297, 0x9299, 0x929f,
+    0x92a0, 0x92a4, 0x92a5, 0x92a7, 0x92a8, 0x92ab, 0x92af, 0x92b2, 0x92b6, 0x92b8, 0x92ba, 0x92bb,
+    0x92bc, 0x92bd, 0x92bf, 0x92c0, 0x92c1, 0x92c2, 0x92c3, 0x92c5, 0x92c6, 0x92c7, 0x92c8, 0x92cb,
+    0x92cc, 0x92cd, 0x92ce, 0x92d0, 0x92d3, 0x92d5, 0x92d7, 0x92d8, 0x92d9, 0x92dc, 0x92dd, 0x92df,
+    0x92e0, 0x92e1, 0x92e3, 0x92e5, 0x92e7, 0x92e8, 0x92ec, 0x92ee, 0x92f0, 0x92f9, 0x92fb, 0x92ff,
+    0x9300, 0x9302, 0x9308, 0x930d, 0x9311, 0x9314, 0x9315, 0x931c, 0x931d, 0x931e, 0x931f, 0x9321,
+    0x9324, 0x9325, 0x9327, 0x9329, 0x932a, 0x9333, 0x9334, 0x9336, 0x9337, 0x9347, 0x9348, 0x9349,
+    0x9350, 0x9351, 0x9352, 0x9355, 0x9357, 0x9358, 0x935a, 0x935e, 0x9364, 0x9365, 0x9367, 0x9369,
+    0x936a, 0x936d, 0x936f, 0x9370, 0x9371, 0x9373, 0x9374, 0x9376, 0x937a, 0x937d, 0x937f, 0x9380,
+    0x9381, 0x9382, 0x9388, 0x938a, 0x938b, 0x938d, 0x938f, 0x9392, 0x9395, 0x9398, 0x939b, 0x939e,
+    0x93a1, 0x93a3, 0x93a4, 0x93a6, 0x93a8, 0x93ab, 0x93b4, 0x93b5, 0x93b6, 0x93ba, 0x93a9, 0x93c1,
+    0x93c4, 0x93c5, 0x93c6, 0x93c7, 0x93c9, 0x93ca, 0x93cb, 0x93cc, 0x93cd, 0x93d3, 0x93d9, 0x93dc,
+    0x93de, 0x93df, 0x93e2, 0x93e6, 0x93e7, 0x93f9, 0x93f7, 0x93f8, 0x93fa, 0x93fb, 0x93fd, 0x9401,
+    0x9402, 0x9404, 0x9408, 0x9409, 0x940d, 0x940e, 0x940f, 0x9415, 0x9416, 0x9417, 0x941f, 0x942e,
+    0x942f, 0x9431, 0x9432, 0x9433, 0x9434, 0x943b, 0x943f, 0x943d, 0x9443, 0x9445, 0x9448, 0x944a,
+    0x944c, 0x9455, 0x9459, 0x945c, 0x945f, 0x9461, 0x9463, 0x9468, 0x946b, 0x946d, 0x946e, 0x946f,
+    0x9471, 0x9472, 0x9484, 0x9483, 0x9578, 0x9579, 0x957e, 0x9584, 0x9588, 0x958c, 0x958d, 0x958e,
+    0x959d, 0x959e, 0x959f, 0x95a1, 0x95a6, 0x95a9, 0x95ab, 0x95ac, 0x95b4, 0x95b6, 0x95ba, 0x95bd,
+    0x95bf, 0x95c6, 0x95c8, 0x95c9, 0x95cb, 0x95d0, 0x95d1, 0x95d2, 0x95d3, 0x95d9, 0x95da, 0x95dd,
+    0x95de, 0x95df, 0x95e0, 0x95e4, 0x95e6, 0x961d, 0x961e, 0x9622, 0x9624, 0x9625, 0x9626, 0x962c,
+    0x9631, 0x9633, 0x9637, 0x9638, 0x9639, 0x963a, 0x963c, 0x963d, 0x9641, 0x9652, 0x9654, 0x9656,
+    0x9657, 0x9658, 0x9661, 0x966e, 0x9674, 0x967b, 0x967c, 0x967e, 0x967f, 0x9681, 0x9682, 0x9683,
+    0x9684, 0x9689, 0x9691, 0x9696, 0x969a, 0x969d, 0x969f, 0x96a4, 0x96a5, 0x96a6, 0x96a9, 0x96ae,
+    0x96af, 0x96b3, 0x96ba, 0x96ca, 0x96d2, 0x5db2, 0x96d8, 0x96da, 0x96dd, 0x96de, 0x96df, 0x96e9,
+    0x96ef, 0x96f1, 0x96fa, 0x9702, 0x9703, 0x9705, 0x9709, 0x971a, 0x971b, 0x971d, 0x9721, 0x9722,
+    0x9723, 0x9728, 0x9731, 0x9733, 0x9741, 0x9743, 0x974a, 0x974e, 0x974f, 0x9755, 0x9757, 0x9758,
+    0x975a, 0x975b, 0x9763, 0x9767, 0x976a, 0x976e, 0x9773, 0x9776, 0x9777, 0x9778, 0x977b, 0x977d,
+    0x977f, 0x9780, 0x9789, 0x9795, 0x9796, 0x9797, 0x9799, 0x979a, 0x979e, 0x979f, 0x97a2, 0x97ac,
+    0x97ae, 0x97b1, 0x97b2, 0x97b5, 0x97b6, 0x97b8, 0x97b9, 0x97ba, 0x97bc, 0x97be, 0x97bf, 0x97c1,
+    0x97c4, 0x97c5, 0x97c7, 0x97c9, 0x97ca, 0x97cc, 0x97cd, 0x97ce, 0x97d0, 0x97d1, 0x97d4, 0x97d7,
+    0x97d8, 0x97d9, 0x97dd, 0x97de, 0x97e0, 0x97db, 0x97e1, 0x97e4, 0x97ef, 0x97f1, 0x97f4, 0x97f7,
+    0x97f8, 0x97fa, 0x9807, 0x980a, 0x9819, 0x980d, 0x980e, 0x9814, 0x9816, 0x981c, 0x981e, 0x9820,
+    0x9823, 0x9826, 0x982b, 0x982e, 0x982f, 0x9830, 0x9832, 0x9833, 0x9835, 0x9825, 0x983e, 0x9844,
+    0x9847, 0x984a, 0x9851, 0x9852, 0x9853, 0x9856, 0x9857, 0x9859, 0x985a, 0x9862, 0x9863, 0x9865,
+    0x9866, 0x986a, 0x986c, 0x98ab, 0x98ad, 0x98ae, 0x98b0, 0x98b4, 0x98b7, 0x98b8, 0x98ba, 0x98bb,
+    0x98bf, 0x98c2, 0x98c5, 0x98c8, 0x98cc, 0x98e1, 0x98e3, 0x98e5, 0x98e6, 0x98e7, 0x98ea, 0x98f3,
+    0x98f6, 0x9902, 0x9907, 0x9908, 0x9911, 0x9915, 0x9916, 0x9917, 0x991a, 0x991b, 0x991c, 0x991f,
+    0x9922, 0x9926, 0x9927, 0x992b, 0x9931, 0x9932, 0x9933, 0x9934, 0x9935, 0x9939, 0x993a, 0x993b,
+    0x993c, 0x9940, 0x9941, 0x9946, 0x9947, 0x9948, 0x994d, 0x994e, 0x9954, 0x9958, 0x9959, 0x995b,
+    0x995c, 0x995e, 0x995f, 0x9960, 0x999b, 0x999d, 0x999f, 0x99a6, 0x99b0, 0x99b1, 0x99b2, 0x99b5,
+    0x99b9, 0x99ba, 0x99bd, 0x99bf, 0x99c3, 0x99c9, 0x99d3, 0x99d4, 0x99d9, 0x99da, 0x99dc, 0x99de,
+    0x99e7, 0x99ea, 0x99eb, 0x99ec, 0x99f0, 0x99f4, 0x99f5, 0x99f9, 0x99fd, 0x99fe, 0x9a02, 0x9a03,
+    0x9a04, 0x9a0b, 0x9a0c, 0x9a10, 0x9a11, 0x9a16, 0x9a1e, 0x9a20, 0x9a22, 0x9a23, 0x9a24, 0x9a27,
+    0x9a2d, 0x9a2e, 0x9a33, 0x9a35, 0x9a36, 0x9a38, 0x9a47, 0x9a41, 0x9a44, 0x9a4a, 0x9a4b, 0x9a4c,
+    0x9a4e, 0x9a51, 0x9a54, 0x9a56, 0x9a5d, 0x9aaa, 0x9aac, 0x9aae, 0x9aaf, 0x9ab2, 0x9ab4, 0x9ab5,
+    0x9ab6, 0x9ab9, 0x9abb, 0x9abe, 0x9abf, 0x9ac1, 0x9ac3, 0x9ac6, 0x9ac8, 0x9ace, 0x9ad0, 0x9ad2,
+    0x9ad5, 0x9ad6, 0x9ad7, 0x9adb, 0x9adc, 0x9ae0, 0x9ae4, 0x9ae5, 0x9ae7, 0x9ae9, 0x9aec, 0x9af2,
+    0x9af3, 0x9af5, 0x9af9, 0x9afa, 0x9afd, 0x9aff, 0x9b00, 0x9b01, 0x9b02, 0x9b03, 0x9b04, 0x9b05,
+    0x9b08, 0x9b09, 0x9b0b, 0x9b0c, 0x9b0d, 0x9b0e, 0x9b10, 0x9b12, 0x9b16, 0x9b19, 0x9b1b, 0x9b1c,
+    0x9b20, 0x9b26, 0x9b2b, 0x9b2d, 0x9b33, 0x9b34, 0x9b35, 0x9b37, 0x9b39, 0x9b3a, 0x9b3d, 0x9b48,
+    0x9b4b, 0x9b4c, 0x9b55, 0x9b56, 0x9b57, 0x9b5b, 0x9b5e, 0x9b61, 0x9b63, 0x9b65, 0x9b66, 0x9b68,
+    0x9b6a, 0x9b6b, 0x9b6c, 0x9b6d, 0x9b6e, 0x9b73, 0x9b75, 0x9b77, 0x9b78, 0x9b79, 0x9b7f, 0x9b80,
+    0x9b84, 0x9b85, 0x9b86, 0x9b87, 0x9b89, 0x9b8a, 0x9b8b, 0x9b8d, 0x9b8f, 0x9b90, 0x9b94, 0x9b9a,
+    0x9b9d, 0x9b9e, 0x9ba6, 0x9ba7, 0x9ba9, 0x9bac, 0x9bb0, 0x9bb1, 0x9bb2, 0x9bb7, 0x9bb8, 0x9bbb,
+    0x9bbc, 0x9bbe, 0x9bbf, 0x9bc1, 0x9bc7, 0x9bc8, 0x9bce, 0x9bd0, 0x9bd7, 0x9bd8, 0x9bdd, 0x9bdf,
+    0x9be5, 0x9be7, 0x9bea, 0x9beb, 0x9bef, 0x9bf3, 0x9bf7, 0x9bf8, 0x9bf9, 0x9bfa, 0x9bfd, 0x9bff,
+    0x9c00, 0x9c02, 0x9c0b, 0x9c0f, 0x9c11, 0x9c16, 0x9c18, 0x9c19, 0x9c1a, 0x9c1c, 0x9c1e, 0x9c22,
+    0x9c23, 0x9c26, 0x9c27, 0x9c28, 0x9c29, 0x9c2a, 0x9c31, 0x9c35, 0x9c36, 0x9c37, 0x9c3d, 0x9c41,
+    0x9c43, 0x9c44, 0x9c45, 0x9c49, 0x9c4a, 0x9c4e, 0x9c4f, 0x9c50, 0x9c53, 0x9c54, 0x9c56, 0x9c58,
+    0x9c5b, 0x9c5d, 0x9c5e, 0x9c5f, 0x9c63, 0x9c69, 0x9c6a, 0x9c5c, 0x9c6b, 0x9c68, 0x9c6e, 0x9c70,
+    0x9c72, 0x9c75, 0x9c77, 0x9c7b, 0x9ce6, 0x9cf2, 0x9cf7, 0x9cf9, 0x9d0b, 0x9d02, 0x9d11, 0x9d17,
+    0x9d18, 0x9d1c, 0x9d1d, 0x9d1e, 0x9d2f, 0x9d30, 0x9d32, 0x9d33, 0x9d34, 0x9d3a, 0x9d3c, 0x9d45,
+    0x9d3d, 0x9d42, 0x9d43, 0x9d47, 0x9d4a, 0x9d53, 0x9d54, 0x9d5f, 0x9d63, 0x9d62, 0x9d65, 0x9d69,
+    0x9d6a, 0x9d6b, 0x9d70, 0x9d76, 0x9d77, 0x9d7b, 0x9d7c, 0x9d7e, 0x9d83, 0x9d84, 0x9d86, 0x9d8a,
+    0x9d8d, 0x9d8e, 0x9d92, 0x9d93, 0x9d95, 0x9d96, 0x9d97, 0x9d98, 0x9da1, 0x9daa, 0x9dac, 0x9dae,
+    0x9db1, 0x9db5, 0x9db9, 0x9dbc, 0x9dbf, 0x9dc3, 0x9dc7, 0x9dc9, 0x9dca, 0x9dd4, 0x9dd5, 0x9dd6,
+    0x9dd7, 0x9dda, 0x9dde, 0x9ddf, 0x9de0, 0x9de5, 0x9de7, 0x9de9, 0x9deb, 0x9dee, 0x9df0, 0x9df3,
+    0x9df4, 0x9dfe, 0x9e0a, 0x9e02, 0x9e07, 0x9e0e, 0x9e10, 0x9e11, 0x9e12, 0x9e15, 0x9e16, 0x9e19,
+    0x9e1c, 0x9e1d, 0x9e7a, 0x9e7b, 0x9e7c, 0x9e80, 0x9e82, 0x9e83, 0x9e84, 0x9e85, 0x9e87, 0x9e8e,
+    0x9e8f, 0x9e96, 0x9e98, 0x9e9b, 0x9e9e, 0x9ea4, 0x9ea8, 0x9eac, 0x9eae, 0x9eaf, 0x9eb0, 0x9eb3,
+    0x9eb4, 0x9eb5, 0x9ec6, 0x9ec8, 0x9ecb, 0x9ed5, 0x9edf, 0x9ee4, 0x9ee7, 0x9eec, 0x9eed, 0x9eee,
+    0x9ef0, 0x9ef1, 0x9ef2, 0x9ef5, 0x9ef8, 0x9eff, 0x9f02, 0x9f03, 0x9f09, 0x9f0f, 0x9f10, 0x9f11,
+    0x9f12, 0x9f14, 0x9f16, 0x9f17, 0x9f19, 0x9f1a, 0x9f1b, 0x9f1f, 0x9f22, 0x9f26, 0x9f2a, 0x9f2b,
+    0x9f2f, 0x9f31, 0x9f32, 0x9f34, 0x9f37, 0x9f39, 0x9f3a, 0x9f3c, 0x9f3d, 0x9f3f, 0x9f41, 0x9f43,
+    0x9f44, 0x9f45, 0x9f46, 0x9f47, 0x9f53, 0x9f55, 0x9f56, 0x9f57, 0x9f58, 0x9f5a, 0x9f5d, 0x9f5e,
+    0x9f68, 0x9f69, 0x9f6d, 0x9f6e, 0x9f6f, 0x9f70, 0x9f71, 0x9f73, 0x9f75, 0x9f7a, 0x9f7d, 0x9f8f,
+    0x9f90, 0x9f91, 0x9f92, 0x9f94, 0x9f96, 0x9f97, 0x9f9e, 0x9fa1, 0x9fa2, 0x9fa3, 0x9fa5, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000,
+];
+
+/// `(code point, index into the grid)`, sorted by code point.
+pub(super) static JIS0212_INVERSE: [(u16, u16); 6066] = [
+    (0x00a1, 0x007f),
+    (0x00a4, 0x00ad),
+    (0x00a6, 0x0080),
+    (0x00a9, 0x00aa),
+    (0x00aa, 0x00a9),
+    (0x00ae, 0x00ab),
+    (0x00af, 0x0071),
+    (0x00b8, 0x006e),
+    (0x00ba, 0x00a8),
+    (0x00bf, 0x0081),
+    (0x00c0, 0x034f),
+    (0x00c1, 0x034e),
+    (0x00c2, 0x0351),
+    (0x00c3, 0x0357),
+    (0x00c4, 0x0350),
+    (0x00c5, 0x0356),
+    (0x00c6, 0x02f0),
+    (0x00c7, 0x035b),
+    (0x00c8, 0x035f),
+    (0x00c9, 0x035e),
+    (0x00ca, 0x0361),
+    (0x00cb, 0x0360),
+    (0x00cc, 0x036d),
+    (0x00cd, 0x036c),
+    (0x00ce, 0x036f),
+    (0x00cf, 0x036e),
+    (0x00d1, 0x037d),
+    (0x00d2, 0x037f),
+    (0x00d3, 0x037e),
+    (0x00d4, 0x0381),
+    (0x00d5, 0x0385),
+    (0x00d6, 0x0380),
+    (0x00d8, 0x02fb),
+    (0x00d9, 0x0390),
+    (0x00da, 0x038f),
+    (0x00db, 0x0392),
+    (0x00dc, 0x0391),
+    (0x00dd, 0x039f),
+    (0x00de, 0x02ff),
+    (0x00df, 0x031d),
+    (0x00e0, 0x03ad),
+    (0x00e1, 0x03ac),
+    (0x00e2, 0x03af),
+    (0x00e3, 0x03b5),
+    (0x00e4, 0x03ae),
+    (0x00e5, 0x03b4),
+    (0x00e6, 0x0310),
+    (0x00e7, 0x03b9),
+    (0x00e8, 0x03bd),
+    (0x00e9, 0x03bc),
+    (0x00ea, 0x03bf),
+    (0x00eb, 0x03be),
+    (0x00ec, 0x03cb),
+    (0x00ed, 0x03ca),
+    (0x00ee, 0x03cd),
+    (0x00ef, 0x03cc),
+    (0x00f0, 0x0312),
+    (0x00f1, 0x03db),
+    (0x00f2, 0x03dd),
+    (0x00f3, 0x03dc),
+    (0x00f4, 0x03df),
+    (0x00f5, 0x03e3),
+    (0x00f6, 0x03de),
+    (0x00f8, 0x031b),
+    (0x00f9, 0x03ee),
+    (0x00fa, 0x03ed),
+    (0x00fb, 0x03f0),
+    (0x00fc, 0x03ef),
+    (0x00fd, 0x03fd),
+    (0x00fe, 0x031f),
+    (0x00ff, 0x03fe),
+    (0x0100, 0x0354),
+    (0x0101, 0x03b2),
+    (0x0102, 0x0352),
+    (0x0103, 0x03b0),
+    (0x0104, 0x0355),
+    (0x0105, 0x03b3),
+    (0x0106, 0x0358),
+    (0x0107, 0x03b6),
+    (0x0108, 0x0359),
+    (0x0109, 0x03b7),
+    (0x010a, 0x035c),
+    (0x010b, 0x03ba),
+    (0x010c, 0x035a),
+    (0x010d, 0x03b8),
+    (0x010e, 0x035d),
+    (0x010f, 0x03bb),
+    (0x0110, 0x02f1),
+    (0x0111, 0x0311),
+    (0x0112, 0x0364),
+    (0x0113, 0x03c2),
+    (0x0116, 0x0363),
+    (0x0117, 0x03c1),
+    (0x0118, 0x0365),
+    (0x0119, 0x03c3),
+    (0x011a, 0x0362),
+    (0x011b, 0x03c0),
+    (0x011c, 0x0367),
+    (0x011d, 0x03c5),
+    (0x011e, 0x0368),
+    (0x011f, 0x03c6),
+    (0x0120, 0x036a),
+    (0x0121, 0x03c8),
+    (0x0122, 0x0369),
+    (0x0124, 0x036b),
+    (0x0125, 0x03c9),
+    (0x0126, 0x02f3),
+    (0x0127, 0x0313),
+    (0x0128, 0x0374),
+    (0x0129, 0x03d2),
+    (0x012a, 0x0372),
+    (0x012b, 0x03d0),
+    (0x012e, 0x0373),
+    (0x012f, 0x03d1),
+    (0x0130, 0x0371),
+    (0x0131, 0x0314),
+    (0x0132, 0x02f5),
+    (0x0133, 0x0315),
+    (0x0134, 0x0375),
+    (0x0135, 0x03d3),
+    (0x0136, 0x0376),
+    (0x0137, 0x03d4),
+    (0x0138, 0x0316),
+    (0x0139, 0x0377),
+    (0x013a, 0x03d5),
+    (0x013b, 0x0379),
+    (0x013c, 0x03d7),
+    (0x013d, 0x0378),
+    (0x013e, 0x03d6),
+    (0x013f, 0x02f8),
+    (0x0140, 0x0318),
+    (0x0141, 0x02f7),
+    (0x0142, 0x0317),
+    (0x0143, 0x037a),
+    (0x0144, 0x03d8),
+    (0x0145, 0x037c),
+    (0x0146, 0x03da),
+    (0x0147, 0x037b),
+    (0x0148, 0x03d9),
+    (0x0149, 0x0319),
+    (0x014a, 0x02fa),
+    (0x014b, 0x031a),
+    (0x014c, 0x0384),
+    (0x014d, 0x03e2),
+    (0x0150, 0x0383),
+    (0x0151, 0x03e1),
+    (0x0152, 0x02fc),
+    (0x0153, 0x031c),
+    (0x0154, 0x0386),
+    (0x0155, 0x03e4),
+    (0x0156, 0x0388),
+    (0x0157, 0x03e6),
+    (0x0158, 0x0387),
+    (0x0159, 0x03e5),
+    (0x015a, 0x0389),
+    (0x015b, 0x03e7),
+    (0x015c, 0x038a),
+    (0x015d, 0x03e8),
+    (0x015e, 0x038c),
+    (0x015f, 0x03ea),
+    (0x0160, 0x038b),
+    (0x0161, 0x03e9),
+    (0x0162, 0x038e),
+    (0x0163, 0x03ec),
+    (0x0164, 0x038d),
+    (0x0165, 0x03eb),
+    (0x0166, 0x02fe),
+    (0x0167, 0x031e),
+    (0x0168, 0x0399),
+    (0x0169, 0x03f7),
+    (0x016a, 0x0396),
+    (0x016b, 0x03f4),
+    (0x016c, 0x0393),
+    (0x016d, 0x03f1),
+    (0x016e, 0x0398),
+    (0x016f, 0x03f6),
+    (0x0170, 0x0395),
+    (0x0171, 0x03f3),
+    (0x0172, 0x0397),
+    (0x0173, 0x03f5),
+    (0x0174, 0x039e),
+    (0x0175, 0x03fc),
+    (0x0176, 0x03a1),
+    (0x0177, 0x03ff),
+    (0x0178, 0x03a0),
+    (0x0179, 0x03a2),
+    (0x017a, 0x0400),
+    (0x017b, 0x03a4),
+    (0x017c, 0x0402),
+    (0x017d, 0x03a3),
+    (0x017e, 0x0401),
+    (0x01cd, 0x0353),
+    (0x01ce, 0x03b1),
+    (0x01cf, 0x0370),
+    (0x01d0, 0x03ce),
+    (0x01d1, 0x0382),
+    (0x01d2, 0x03e0),
+    (0x01d3, 0x0394),
+    (0x01d4, 0x03f2),
+    (0x01d5, 0x039d),
+    (0x01d6, 0x03fb),
+    (0x01d7, 0x039a),
+    (0x01d8, 0x03f8),
+    (0x01d9, 0x039c),
+    (0x01da, 0x03fa),
+    (0x01db, 0x039b),
+    (0x01dc, 0x03f9),
+    (0x01f5, 0x03c4),
+    (0x02c7, 0x006d),
+    (0x02d8, 0x006c),
+    (0x02d9, 0x006f),
+    (0x02da, 0x0073),
+    (0x02db, 0x0072),
+    (0x02dd, 0x0070),
+    (0x0384, 0x0075),
+    (0x0385, 0x0076),
+    (0x0386, 0x0216),
+    (0x0388, 0x0217),
+    (0x0389, 0x0218),
+    (0x038a, 0x0219),
+    (0x038c, 0x021c),
+    (0x038e, 0x021e),
+    (0x038f, 0x0221),
+    (0x0390, 0x022b),
+    (0x03aa, 0x021a),
+    (0x03ab, 0x021f),
+    (0x03ac, 0x0226),
+    (0x03ad, 0x0227),
+    (0x03ae, 0x0228),
+    (0x03af, 0x0229),
+    (0x03b0, 0x0230),
+    (0x03c2, 0x022d),
+    (0x03ca, 0x022a),
+    (0x03cb, 0x022f),
+    (0x03cc, 0x022c),
+    (0x03cd, 0x022e),
+    (0x03ce, 0x0231),
+    (0x0402, 0x0255),
+    (0x0403, 0x0256),
+    (0x0404, 0x0257),
+    (0x0405, 0x0258),
+    (0x0406, 0x0259),
+    (0x0407, 0x025a),
+    (0x0408, 0x025b),
+    (0x0409, 0x025c),
+    (0x040a, 0x025d),
+    (0x040b, 0x025e),
+    (0x040c, 0x025f),
+    (0x040e, 0x0260),
+    (0x040f, 0x0261),
+    (0x0452, 0x0285),
+    (0x0453, 0x0286),
+    (0x0454, 0x0287),
+    (0x0455, 0x0288),
+    (0x0456, 0x0289),
+    (0x0457, 0x028a),
+    (0x0458, 0x028b),
+    (0x0459, 0x028c),
+    (0x045a, 0x028d),
+    (0x045b, 0x028e),
+    (0x045c, 0x028f),
+    (0x045e, 0x0290),
+    (0x045f, 0x0291),
+    (0x2116, 0x00ae),
+    (0x2122, 0x00ac),
+    (0x4e02, 0x0582),
+    (0x4e04, 0x0583),
+    (0x4e05, 0x0584),
+    (0x4e0c, 0x0585),
+    (0x4e12, 0x0586),
+    (0x4e1f, 0x0587),
+    (0x4e23, 0x0588),
+    (0x4e24, 0x0589),
+    (0x4e28, 0x058a),
+    (0x4e2b, 0x058b),
+    (0x4e2e, 0x058c),
+    (0x4e2f, 0x058d),
+    (0x4e30, 0x058e),
+    (0x4e35, 0x058f),
+    (0x4e40, 0x0590),
+    (0x4e41, 0x0591),
+    (0x4e44, 0x0592),
+    (0x4e47, 0x0593),
+    (0x4e51, 0x0594),
+    (0x4e5a, 0x0595),
+    (0x4e5c, 0x0596),
+    (0x4e63, 0x0597),
+    (0x4e68, 0x0598),
+    (0x4e69, 0x0599),
+    (0x4e74, 0x059a),
+    (0x4e75, 0x059b),
+    (0x4e79, 0x059c),
+    (0x4e7f, 0x059d),
+    (0x4e8d, 0x059e),
+    (0x4e96, 0x059f),
+    (0x4e97, 0x05a0),
+    (0x4e9d, 0x05a1),
+    (0x4eaf, 0x05a2),
+    (0x4eb9, 0x05a3),
+    (0x4ec3, 0x05a4),
+    (0x4ed0, 0x05a5),
+    (0x4eda, 0x05a6),
+    (0x4edb, 0x05a7),
+    (0x4ee0, 0x05a8),
+    (0x4ee1, 0x05a9),
+    (0x4ee2, 0x05aa),
+    (0x4ee8, 0x05ab),
+    (0x4eef, 0x05ac),
+    (0x4ef1, 0x05ad),
+    (0x4ef3, 0x05ae),
+    (0x4ef5, 0x05af),
+    (0x4efd, 0x05b0),
+    (0x4efe, 0x05b1),
+    (0x4eff, 0x05b2),
+    (0x4f00, 0x05b3),
+    (0x4f02, 0x05b4),
+    (0x4f03, 0x05b5),
+    (0x4f08, 0x05b6),
+    (0x4f0b, 0x05b7),
+    (0x4f0c, 0x05b8),
+    (0x4f12, 0x05b9),
+    (0x4f15, 0x05ba),
+    (0x4f16, 0x05bb),
+    (0x4f17, 0x05bc),
+    (0x4f19, 0x05bd),
+    (0x4f2e, 0x05be),
+    (0x4f31, 0x05bf),
+    (0x4f33, 0x05c1),
+    (0x4f35, 0x05c2),
+    (0x4f37, 0x05c3),
+    (0x4f39, 0x05c4),
+    (0x4f3b, 0x05c5),
+    (0x4f3e, 0x05c6),
+    (0x4f40, 0x05c7),
+    (0x4f42, 0x05c8),
+    (0x4f48, 0x05c9),
+    (0x4f49, 0x05ca),
+    (0x4f4b, 0x05cb),
+    (0x4f4c, 0x05cc),
+    (0x4f52, 0x05cd),
+    (0x4f54, 0x05ce),
+    (0x4f56, 0x05cf),
+    (0x4f58, 0x05d0),
+    (0x4f5f, 0x05d1),
+    (0x4f60, 0x05c0),
+    (0x4f63, 0x05d2),
+    (0x4f6a, 0x05d3),
+    (0x4f6c, 0x05d4),
+    (0x4f6e, 0x05d5),
+    (0x4f71, 0x05d6),
+    (0x4f77, 0x05d7),
+    (0x4f78, 0x05d8),
+    (0x4f79, 0x05d9),
+    (0x4f7a, 0x05da),
+    (0x4f7d, 0x05db),
+    (0x4f7e, 0x05dc),
+    (0x4f81, 0x05dd),
+    (0x4f82, 0x05de),
+    (0x4f84, 0x05df),
+    (0x4f85, 0x05e0),
+    (0x4f89, 0x05e1),
+    (0x4f8a, 0x05e2),
+    (0x4f8c, 0x05e3),
+    (0x4f8e, 0x05e4),
+    (0x4f90, 0x05e5),
+    (0x4f92, 0x05e6),
+    (0x4f93, 0x05e7),
+    (0x4f94, 0x05e8),
+    (0x4f97, 0x05e9),
+    (0x4f99, 0x05ea),
+    (0x4f9a, 0x05eb),
+    (0x4f9e, 0x05ec),
+    (0x4f9f, 0x05ed),
+    (0x4fb2, 0x05ee),
+    (0x4fb7, 0x05ef),
+    (0x4fb9, 0x05f0),
+    (0x4fbb, 0x05f1),
+    (0x4fbc, 0x05f2),
+    (0x4fbd, 0x05f3),
+    (0x4fbe, 0x05f4),
+    (0x4fc0, 0x05f5),
+    (0x4fc1, 0x05f6),
+    (0x4fc5, 0x05f7),
+    (0x4fc6, 0x05f8),
+    (0x4fc8, 0x05f9),
+    (0x4fc9, 0x05fa),
+    (0x4fcb, 0x05fb),
+    (0x4fcc, 0x05fc),
+    (0x4fcd, 0x05fd),
+    (0x4fcf, 0x05fe),
+    (0x4fd2, 0x05ff),
+    (0x4fdc, 0x0600),
+    (0x4fe0, 0x0601),
+    (0x4fe2, 0x0602),
+    (0x4ff0, 0x0603),
+    (0x4ff2, 0x0604),
+    (0x4ffc, 0x0605),
+    (0x4ffd, 0x0606),
+    (0x4fff, 0x0607),
+    (0x5000, 0x0608),
+    (0x5001, 0x0609),
+    (0x5004, 0x060a),
+    (0x5007, 0x060b),
+    (0x500a, 0x060c),
+    (0x500c, 0x060d),
+    (0x500e, 0x060e),
+    (0x5010, 0x060f),
+    (0x5013, 0x0610),
+    (0x5017, 0x0611),
+    (0x5018, 0x0612),
+    (0x501b, 0x0613),
+    (0x501c, 0x0614),
+    (0x501d, 0x0615),
+    (0x501e, 0x0616),
+    (0x5022, 0x0617),
+    (0x5027, 0x0618),
+    (0x502e, 0x0619),
+    (0x5030, 0x061a),
+    (0x5032, 0x061b),
+    (0x5033, 0x061c),
+    (0x5035, 0x061d),
+    (0x503b, 0x0635),
+    (0x5040, 0x061e),
+    (0x5041, 0x061f),
+    (0x5042, 0x0620),
+    (0x5045, 0x0621),
+    (0x5046, 0x0622),
+    (0x504a, 0x0623),
+    (0x504c, 0x0624),
+    (0x504e, 0x0625),
+    (0x5051, 0x0626),
+    (0x5052, 0x0627),
+    (0x5053, 0x0628),
+    (0x5057, 0x0629),
+    (0x5059, 0x062a),
+    (0x505f, 0x062b),
+    (0x5060, 0x062c),
+    (0x5062, 0x062d),
+    (0x5063, 0x062e),
+    (0x5066, 0x062f),
+    (0x5067, 0x0630),
+    (0x506a, 0x0631),
+    (0x506d, 0x0632),
+    (0x5070, 0x0633),
+    (0x5071, 0x0634),
+    (0x5081, 0x0636),
+    (0x5083, 0x0637),
+    (0x5084, 0x0638),
+    (0x5086, 0x0639),
+    (0x508a, 0x063a),
+    (0x508e, 0x063b),
+    (0x508f, 0x063c),
+    (0x5090, 0x063d),
+    (0x5092, 0x063e),
+    (0x5093, 0x063f),
+    (0x5094, 0x0640),
+    (0x5096, 0x0641),
+    (0x509b, 0x0642),
+    (0x509c, 0x0643),
+    (0x509e, 0x0644),
+    (0x509f, 0x0645),
+    (0x50a0, 0x0646),
+    (0x50a1, 0x0647),
+    (0x50a2, 0x0648),
+    (0x50aa, 0x0649),
+    (0x50af, 0x064a),
+    (0x50b0, 0x064b),
+    (0x50b9, 0x064c),
+    (0x50ba, 0x064d),
+    (0x50bd, 0x064e),
+    (0x50c0, 0x064f),
+    (0x50c3, 0x0650),
+    (0x50c4, 0x0651),
+    (0x50c7, 0x0652),
+    (0x50cc, 0x0653),
+    (0x50ce, 0x0654),
+    (0x50d0, 0x0655),
+    (0x50d3, 0x0656),
+    (0x50d4, 0x0657),
+    (0x50d8, 0x0658),
+    (0x50dc, 0x0659),
+    (0x50dd, 0x065a),
+    (0x50df, 0x065b),
+    (0x50e2, 0x065c),
+    (0x50e4, 0x065d),
+    (0x50e6, 0x065e),
+    (0x50e8, 0x065f),
+    (0x50e9, 0x0660),
+    (0x50ef, 0x0661),
+    (0x50f1, 0x0662),
+    (0x50f2, 0x066e),
+    (0x50f6, 0x0663),
+    (0x50fa, 0x0664),
+    (0x50fe, 0x0665),
+    (0x5103, 0x0666),
+    (0x5106, 0x0667),
+    (0x5107, 0x0668),
+    (0x5108, 0x0669),
+    (0x510b, 0x066a),
+    (0x510c, 0x066b),
+    (0x510d, 0x066c),
+    (0x510e, 0x066d),
+    (0x5110, 0x066f),
+    (0x5117, 0x0670),
+    (0x5119, 0x0671),
+    (0x511b, 0x0672),
+    (0x511c, 0x0673),
+    (0x511d, 0x0674),
+    (0x511e, 0x0675),
+    (0x5123, 0x0676),
+    (0x5127, 0x0677),
+    (0x5128, 0x0678),
+    (0x512c, 0x0679),
+    (0x512d, 0x067a),
+    (0x512f, 0x067b),
+    (0x5131, 0x067c),
+    (0x5133, 0x067d),
+    (0x5134, 0x067e),
+    (0x5135, 0x067f),
+    (0x5138, 0x0680),
+    (0x5139, 0x0681),
+    (0x5142, 0x0682),
+    (0x514a, 0x0683),
+    (0x514f, 0x0684),
+    (0x5153, 0x0685),
+    (0x5155, 0x0686),
+    (0x5157, 0x0687),
+    (0x5158, 0x0688),
+    (0x515f, 0x0689),
+    (0x5164, 0x068a),
+    (0x5166, 0x068b),
+    (0x517e, 0x068c),
+    (0x5183, 0x068d),
+    (0x5184, 0x068e),
+    (0x518b, 0x068f),
+    (0x518e, 0x0690),
+    (0x5198, 0x0691),
+    (0x519d, 0x0692),
+    (0x51a1, 0x0693),
+    (0x51a3, 0x0694),
+    (0x51ad, 0x0695),
+    (0x51b8, 0x0696),
+    (0x51ba, 0x0697),
+    (0x51bc, 0x0698),
+    (0x51be, 0x0699),
+    (0x51bf, 0x069a),
+    (0x51c2, 0x069b),
+    (0x51c8, 0x069c),
+    (0x51cf, 0x069d),
+    (0x51d1, 0x069e),
+    (0x51d2, 0x069f),
+    (0x51d3, 0x06a0),
+    (0x51d5, 0x06a1),
+    (0x51d8, 0x06a2),
+    (0x51de, 0x06a3),
+    (0x51e2, 0x06a4),
+    (0x51e5, 0x06a5),
+    (0x51ee, 0x06a6),
+    (0x51f2, 0x06a7),
+    (0x51f3, 0x06a8),
+    (0x51f4, 0x06a9),
+    (0x51f7, 0x06aa),
+    (0x5201, 0x06ab),
+    (0x5202, 0x06ac),
+    (0x5205, 0x06ad),
+    (0x5212, 0x06ae),
+    (0x5213, 0x06af),
+    (0x5215, 0x06b0),
+    (0x5216, 0x06b1),
+    (0x5218, 0x06b2),
+    (0x5222, 0x06b3),
+    (0x5228, 0x06b4),
+    (0x5231, 0x06b5),
+    (0x5232, 0x06b6),
+    (0x5235, 0x06b7),
+    (0x523c, 0x06b8),
+    (0x5245, 0x06b9),
+    (0x5249, 0x06ba),
+    (0x5255, 0x06bb),
+    (0x5257, 0x06bc),
+    (0x5258, 0x06bd),
+    (0x525a, 0x06be),
+    (0x525c, 0x06bf),
+    (0x525f, 0x06c0),
+    (0x5260, 0x06c1),
+    (0x5261, 0x06c2),
+    (0x5266, 0x06c3),
+    (0x526e, 0x06c4),
+    (0x5277, 0x06c5),
+    (0x5278, 0x06c6),
+    (0x5279, 0x06c7),
+    (0x5280, 0x06c8),
+    (0x5282, 0x06c9),
+    (0x5285, 0x06ca),
+    (0x528a, 0x06cb),
+    (0x528c, 0x06cc),
+    (0x5293, 0x06cd),
+    (0x5295, 0x06ce),
+    (0x5296, 0x06cf),
+    (0x5297, 0x06d0),
+    (0x5298, 0x06d1),
+    (0x529a, 0x06d2),
+    (0x529c, 0x06d3),
+    (0x52a4, 0x06d4),
+    (0x52a5, 0x06d5),
+    (0x52a6, 0x06d6),
+    (0x52a7, 0x06d7),
+    (0x52af, 0x06d8),
+    (0x52b0, 0x06d9),
+    (0x52b6, 0x06da),
+    (0x52b7, 0x06db),
+    (0x52b8, 0x06dc),
+    (0x52ba, 0x06dd),
+    (0x52bb, 0x06de),
+    (0x52bd, 0x06df),
+    (0x52c0, 0x06e0),
+    (0x52c4, 0x06e1),
+    (0x52c6, 0x06e2),
+    (0x52c8, 0x06e3),
+    (0x52cc, 0x06e4),
+    (0x52cf, 0x06e5),
+    (0x52d1, 0x06e6),
+    (0x52d4, 0x06e7),
+    (0x52d6, 0x06e8),
+    (0x52db, 0x06e9),
+    (0x52dc, 0x06ea),
+    (0x52e1, 0x06eb),
+    (0x52e5, 0x06ec),
+    (0x52e8, 0x06ed),
+    (0x52e9, 0x06ee),
+    (0x52ea, 0x06ef),
+    (0x52ec, 0x06f0),
+    (0x52f0, 0x06f1),
+    (0x52f1, 0x06f2),
+    (0x52f4, 0x06f3),
+    (0x52f6, 0x06f4),
+    (0x52f7, 0x06f5),
+    (0x5300, 0x06f6),
+    (0x5303, 0x06f7),
+    (0x530a, 0x06f8),
+    (0x530b, 0x06f9),
+    (0x530c, 0x06fa),
+    (0x5311, 0x06fb),
+    (0x5313, 0x06fc),
+    (0x5318, 0x06fd),
+    (0x531b, 0x06fe),
+    (0x531c, 0x06ff),
+    (0x531e, 0x0700),
+    (0x531f, 0x0701),
+    (0x5325, 0x0702),
+    (0x5327, 0x0703),
+    (0x5328, 0x0704),
+    (0x5329, 0x0705),
+    (0x532b, 0x0706),
+    (0x532c, 0x0707),
+    (0x532d, 0x0708),
+    (0x5330, 0x0709),
+    (0x5332, 0x070a),
+    (0x5335, 0x070b),
+    (0x533c, 0x070c),
+    (0x533d, 0x070d),
+    (0x533e, 0x070e),
+    (0x5342, 0x070f),
+    (0x534b, 0x0711),
+    (0x534c, 0x0710),
+    (0x5359, 0x0712),
+    (0x535b, 0x0713),
+    (0x5361, 0x0714),
+    (0x5363, 0x0715),
+    (0x5365, 0x0716),
+    (0x536c, 0x0717),
+    (0x536d, 0x0718),
+    (0x5372, 0x0719),
+    (0x5379, 0x071a),
+    (0x537e, 0x071b),
+    (0x5383, 0x071c),
+    (0x5387, 0x071d),
+    (0x5388, 0x071e),
+    (0x538e, 0x071f),
+    (0x5393, 0x0720),
+    (0x5394, 0x0721),
+    (0x5399, 0x0722),
+    (0x539d, 0x0723),
+    (0x53a1, 0x0724),
+    (0x53a4, 0x0725),
+    (0x53aa, 0x0726),
+    (0x53ab, 0x0727),
+    (0x53af, 0x0728),
+    (0x53b2, 0x0729),
+    (0x53b4, 0x072a),
+    (0x53b5, 0x072b),
+    (0x53b7, 0x072c),
+    (0x53b8, 0x072d),
+    (0x53ba, 0x072e),
+    (0x53bd, 0x072f),
+    (0x53c0, 0x0730),
+    (0x53c5, 0x0731),
+    (0x53cf, 0x0732),
+    (0x53d2, 0x0733),
+    (0x53d3, 0x0734),
+    (0x53d5, 0x0735),
+    (0x53da, 0x0736),
+    (0x53dd, 0x0737),
+    (0x53de, 0x0738),
+    (0x53e0, 0x0739),
+    (0x53e6, 0x073a),
+    (0x53e7, 0x073b),
+    (0x53f5, 0x073c),
+    (0x5402, 0x073d),
+    (0x5413, 0x073e),
+    (0x541a, 0x073f),
+    (0x5421, 0x0740),
+    (0x5427, 0x0741),
+    (0x5428, 0x0742),
+    (0x542a, 0x0743),
+    (0x542f, 0x0744),
+    (0x5431, 0x0745),
+    (0x5434, 0x0746),
+    (0x5435, 0x0747),
+    (0x5443, 0x0748),
+    (0x5444, 0x0749),
+    (0x5447, 0x074a),
+    (0x544d, 0x074b),
+    (0x544f, 0x074c),
+    (0x545e, 0x074d),
+    (0x5462, 0x074e),
+    (0x5464, 0x074f),
+    (0x5466, 0x0750),
+    (0x5467, 0x0751),
+    (0x5469, 0x0752),
+    (0x546b, 0x0753),
+    (0x546d, 0x0754),
+    (0x546e, 0x0755),
+    (0x5474, 0x0756),
+    (0x547f, 0x0757),
+    (0x5481, 0x0758),
+    (0x5483, 0x0759),
+    (0x5485, 0x075a),
+    (0x5488, 0x075b),
+    (0x5489, 0x075c),
+    (0x548d, 0x075d),
+    (0x5491, 0x075e),
+    (0x5495, 0x075f),
+    (0x5496, 0x0760),
+    (0x549c, 0x0761),
+    (0x549f, 0x0762),
+    (0x54a1, 0x0763),
+    (0x54a6, 0x0764),
+    (0x54a7, 0x0765),
+    (0x54a9, 0x0766),
+    (0x54aa, 0x0767),
+    (0x54ad, 0x0768),
+    (0x54ae, 0x0769),
+    (0x54b1, 0x076a),
+    (0x54b7, 0x076b),
+    (0x54b9, 0x076c),
+    (0x54ba, 0x076d),
+    (0x54bb, 0x076e),
+    (0x54bf, 0x076f),
+    (0x54c6, 0x0770),
+    (0x54ca, 0x0771),
+    (0x54cd, 0x0772),
+    (0x54ce, 0x0773),
+    (0x54e0, 0x0774),
+    (0x54ea, 0x0775),
+    (0x54ec, 0x0776),
+    (0x54ef, 0x0777),
+    (0x54f6, 0x0778),
+    (0x54fc, 0x0779),
+    (0x54fe, 0x077a),
+    (0x54ff, 0x077b),
+    (0x5500, 0x077c),
+    (0x5501, 0x077d),
+    (0x5505, 0x077e),
+    (0x5508, 0x077f),
+    (0x5509, 0x0780),
+    (0x550c, 0x0781),
+    (0x550d, 0x0782),
+    (0x550e, 0x0783),
+    (0x5515, 0x0784),
+    (0x552a, 0x0785),
+    (0x552b, 0x0786),
+    (0x5532, 0x0787),
+    (0x5535, 0x0788),
+    (0x5536, 0x0789),
+    (0x553b, 0x078a),
+    (0x553c, 0x078b),
+    (0x553d, 0x078c),
+    (0x5541, 0x078d),
+    (0x5547, 0x078e),
+    (0x5549, 0x078f),
+    (0x554a, 0x0790),
+    (0x554d, 0x0791),
+    (0x5550, 0x0792),
+    (0x5551, 0x0793),
+    (0x5558, 0x0794),
+    (0x555a, 0x0795),
+    (0x555b, 0x0796),
+    (0x555e, 0x0797),
+    (0x5560, 0x0798),
+    (0x5561, 0x0799),
+    (0x5564, 0x079a),
+    (0x5566, 0x079b),
+    (0x557f, 0x079c),
+    (0x5581, 0x079d),
+    (0x5582, 0x079e),
+    (0x5586, 0x079f),
+    (0x5588, 0x07a0),
+    (0x558e, 0x07a1),
+    (0x558f, 0x07a2),
+    (0x5591, 0x07a3),
+    (0x5592, 0x07a4),
+    (0x5593, 0x07a5),
+    (0x5594, 0x07a6),
+    (0x5597, 0x07a7),
+    (0x55a3, 0x07a8),
+    (0x55a4, 0x07a9),
+    (0x55ad, 0x07aa),
+    (0x55b2, 0x07ab),
+    (0x55bf, 0x07ac),
+    (0x55c1, 0x07ad),
+    (0x55c3, 0x07ae),
+    (0x55c6, 0x07af),
+    (0x55c9, 0x07b0),
+    (0x55cb, 0x07b1),
+    (0x55cc, 0x07b2),
+    (0x55ce, 0x07b3),
+    (0x55d1, 0x07b4),
+    (0x55d2, 0x07b5),
+    (0x55d3, 0x07b6),
+    (0x55d7, 0x07b7),
+    (0x55d8, 0x07b8),
+    (0x55db, 0x07b9),
+    (0x55de, 0x07ba),
+    (0x55e2, 0x07bb),
+    (0x55e9, 0x07bc),
+    (0x55f6, 0x07bd),
+    (0x55ff, 0x07be),
+    (0x5605, 0x07bf),
+    (0x5608, 0x07c0),
+    (0x560a, 0x07c1),
+    (0x560d, 0x07c2),
+    (0x560e, 0x07c3),
+    (0x560f, 0x07c4),
+    (0x5610, 0x07c5),
+    (0x5611, 0x07c6),
+    (0x5612, 0x07c7),
+    (0x5619, 0x07c8),
+    (0x562c, 0x07c9),
+    (0x5630, 0x07ca),
+    (0x5633, 0x07cb),
+    (0x5635, 0x07cc),
+    (0x5637, 0x07cd),
+    (0x5639, 0x07ce),
+    (0x563b, 0x07cf),
+    (0x563c, 0x07d0),
+    (0x563d, 0x07d1),
+    (0x563f, 0x07d2),
+    (0x5640, 0x07d3),
+    (0x5641, 0x07d4),
+    (0x5643, 0x07d5),
+    (0x5644, 0x07d6),
+    (0x5646, 0x07d7),
+    (0x5649, 0x07d8),
+    (0x564b, 0x07d9),
+    (0x564d, 0x07da),
+    (0x564f, 0x07db),
+    (0x5654, 0x07dc),
+    (0x565e, 0x07dd),
+    (0x5660, 0x07de),
+    (0x5661, 0x07df),
+    (0x5662, 0x07e0),
+    (0x5663, 0x07e1),
+    (0x5666, 0x07e2),
+    (0x5669, 0x07e3),
+    (0x566d, 0x07e4),
+    (0x566f, 0x07e5),
+    (0x5671, 0x07e6),
+    (0x5672, 0x07e7),
+    (0x5675, 0x07e8),
+    (0x5684, 0x07e9),
+    (0x5685, 0x07ea),
+    (0x5688, 0x07eb),
+    (0x568b, 0x07ec),
+    (0x568c, 0x07ed),
+    (0x5695, 0x07ee),
+    (0x5699, 0x07ef),
+    (0x569a, 0x07f0),
+    (0x569d, 0x07f1),
+    (0x569e, 0x07f2),
+    (0x569f, 0x07f3),
+    (0x56a6, 0x07f4),
+    (0x56a7, 0x07f5),
+    (0x56a8, 0x07f6),
+    (0x56a9, 0x07f7),
+    (0x56ab, 0x07f8),
+    (0x56ac, 0x07f9),
+    (0x56ad, 0x07fa),
+    (0x56b1, 0x07fb),
+    (0x56b3, 0x07fc),
+    (0x56b7, 0x07fd),
+    (0x56be, 0x07fe),
+    (0x56c5, 0x07ff),
+    (0x56c9, 0x0800),
+    (0x56ca, 0x0801),
+    (0x56cb, 0x0802),
+    (0x56cc, 0x0805),
+    (0x56cd, 0x0806),
+    (0x56cf, 0x0803),
+    (0x56d0, 0x0804),
+    (0x56d9, 0x0807),
+    (0x56dc, 0x0808),
+    (0x56dd, 0x0809),
+    (0x56df, 0x080a),
+    (0x56e1, 0x080b),
+    (0x56e4, 0x080c),
+    (0x56e5, 0x080d),
+    (0x56e6, 0x080e),
+    (0x56e7, 0x080f),
+    (0x56e8, 0x0810),
+    (0x56eb, 0x0812),
+    (0x56ed, 0x0813),
+    (0x56f1, 0x0811),
+    (0x56f6, 0x0814),
+    (0x56f7, 0x0815),
+    (0x5701, 0x0816),
+    (0x5702, 0x0817),
+    (0x5707, 0x0818),
+    (0x570a, 0x0819),
+    (0x570c, 0x081a),
+    (0x5711, 0x081b),
+    (0x5715, 0x081c),
+    (0x571a, 0x081d),
+    (0x571b, 0x081e),
+    (0x571d, 0x081f),
+    (0x5720, 0x0820),
+    (0x5722, 0x0821),
+    (0x5723, 0x0822),
+    (0x5724, 0x0823),
+    (0x5725, 0x0824),
+    (0x5729, 0x0825),
+    (0x572a, 0x0826),
+    (0x572c, 0x0827),
+    (0x572e, 0x0828),
+    (0x572f, 0x0829),
+    (0x5733, 0x082a),
+    (0x5734, 0x082b),
+    (0x573d, 0x082c),
+    (0x573e, 0x082d),
+    (0x573f, 0x082e),
+    (0x5745, 0x082f),
+    (0x5746, 0x0830),
+    (0x574c, 0x0831),
+    (0x574d, 0x0832),
+    (0x5752, 0x0833),
+    (0x5762, 0x0834),
+    (0x5765, 0x0835),
+    (0x5767, 0x0836),
+    (0x5768, 0x0837),
+    (0x576b, 0x0838),
+    (0x576d, 0x0839),
+    (0x576e, 0x083a),
+    (0x576f, 0x083b),
+    (0x5770, 0x083c),
+    (0x5771, 0x083d),
+    (0x5773, 0x083e),
+    (0x5774, 0x083f),
+    (0x5775, 0x0840),
+    (0x5777, 0x0841),
+    (0x5779, 0x0842),
+    (0x577a, 0x0843),
+    (0x577b, 0x0844),
+    (0x577c, 0x0845),
+    (0x577e, 0x0846),
+    (0x5781, 0x0847),
+    (0x5783, 0x0848),
+    (0x578c, 0x0849),
+    (0x5794, 0x084a),
+    (0x5795, 0x0853),
+    (0x5797, 0x084b),
+    (0x5799, 0x084c),
+    (0x579a, 0x084d),
+    (0x579c, 0x084e),
+    (0x579d, 0x084f),
+    (0x579e, 0x0850),
+    (0x579f, 0x0851),
+    (0x57a1, 0x0852),
+    (0x57a7, 0x0854),
+    (0x57a8, 0x0855),
+    (0x57a9, 0x0856),
+    (0x57ac, 0x0857),
+    (0x57b8, 0x0858),
+    (0x57bd, 0x0859),
+    (0x57c7, 0x085a),
+    (0x57c8, 0x085b),
+    (0x57cc, 0x085c),
+    (0x57cf, 0x085d),
+    (0x57d5, 0x085e),
+    (0x57dd, 0x085f),
+    (0x57de, 0x0860),
+    (0x57e1, 0x0871),
+    (0x57e4, 0x0861),
+    (0x57e6, 0x0862),
+    (0x57e7, 0x0863),
+    (0x57e9, 0x0864),
+    (0x57ed, 0x0865),
+    (0x57f0, 0x0866),
+    (0x57f5, 0x0867),
+    (0x57f6, 0x0868),
+    (0x57f8, 0x0869),
+    (0x57fd, 0x086a),
+    (0x57fe, 0x086b),
+    (0x57ff, 0x086c),
+    (0x5803, 0x086d),
+    (0x5804, 0x086e),
+    (0x5808, 0x086f),
+    (0x5809, 0x0870),
+    (0x580c, 0x0872),
+    (0x580d, 0x0873),
+    (0x581b, 0x0874),
+    (0x581e, 0x0875),
+    (0x581f, 0x0876),
+    (0x5820, 0x0877),
+    (0x5826, 0x0878),
+    (0x5827, 0x0879),
+    (0x582d, 0x087a),
+    (0x5832, 0x087b),
+    (0x5839, 0x087c),
+    (0x583f, 0x087d),
+    (0x5849, 0x087e),
+    (0x584c, 0x087f),
+    (0x584d, 0x0880),
+    (0x584f, 0x0881),
+    (0x5850, 0x0882),
+    (0x5855, 0x0883),
+    (0x585f, 0x0884),
+    (0x5861, 0x0885),
+    (0x5864, 0x0886),
+    (0x5867, 0x0887),
+    (0x5868, 0x0888),
+    (0x5878, 0x0889),
+    (0x587c, 0x088a),
+    (0x587f, 0x088b),
+    (0x5880, 0x088c),
+    (0x5881, 0x088d),
+    (0x5887, 0x088e),
+    (0x5888, 0x088f),
+    (0x5889, 0x0890),
+    (0x588a, 0x0891),
+    (0x588c, 0x0892),
+    (0x588d, 0x0893),
+    (0x588f, 0x0894),
+    (0x5890, 0x0895),
+    (0x5894, 0x0896),
+    (0x5896, 0x0897),
+    (0x589d, 0x0898),
+    (0x58a0, 0x0899),
+    (0x58a1, 0x089a),
+    (0x58a2, 0x089b),
+    (0x58a6, 0x089c),
+    (0x58a9, 0x089d),
+    (0x58b1, 0x089e),
+    (0x58b2, 0x089f),
+    (0x58bc, 0x08a1),
+    (0x58c2, 0x08a2),
+    (0x58c4, 0x08a0),
+    (0x58c8, 0x08a3),
+    (0x58cd, 0x08a4),
+    (0x58ce, 0x08a5),
+    (0x58d0, 0x08a6),
+    (0x58d2, 0x08a7),
+    (0x58d4, 0x08a8),
+    (0x58d6, 0x08a9),
+    (0x58da, 0x08aa),
+    (0x58dd, 0x08ab),
+    (0x58e1, 0x08ac),
+    (0x58e2, 0x08ad),
+    (0x58e9, 0x08ae),
+    (0x58f3, 0x08af),
+    (0x5905, 0x08b0),
+    (0x5906, 0x08b1),
+    (0x590b, 0x08b2),
+    (0x590c, 0x08b3),
+    (0x5912, 0x08b4),
+    (0x5913, 0x08b5),
+    (0x5914, 0x08b6),
+    (0x591d, 0x08b8),
+    (0x5921, 0x08b9),
+    (0x5923, 0x08ba),
+    (0x5924, 0x08bb),
+    (0x5928, 0x08bc),
+    (0x592f, 0x08bd),
+    (0x5930, 0x08be),
+    (0x5933, 0x08bf),
+    (0x5935, 0x08c0),
+    (0x5936, 0x08c1),
+    (0x593f, 0x08c2),
+    (0x5943, 0x08c3),
+    (0x5946, 0x08c4),
+    (0x5952, 0x08c5),
+    (0x5953, 0x08c6),
+    (0x5959, 0x08c7),
+    (0x595b, 0x08c8),
+    (0x595d, 0x08c9),
+    (0x595e, 0x08ca),
+    (0x595f, 0x08cb),
+    (0x5961, 0x08cc),
+    (0x5963, 0x08cd),
+    (0x596b, 0x08ce),
+    (0x596d, 0x08cf),
+    (0x596f, 0x08d0),
+    (0x5972, 0x08d1),
+    (0x5975, 0x08d2),
+    (0x5976, 0x08d3),
+    (0x5979, 0x08d4),
+    (0x597b, 0x08d5),
+    (0x597c, 0x08d6),
+    (0x598b, 0x08d7),
+    (0x598c, 0x08d8),
+    (0x598e, 0x08d9),
+    (0x5992, 0x08da),
+    (0x5995, 0x08db),
+    (0x5997, 0x08dc),
+    (0x599f, 0x08dd),
+    (0x59a4, 0x08de),
+    (0x59a7, 0x08df),
+    (0x59ad, 0x08e0),
+    (0x59ae, 0x08e1),
+    (0x59af, 0x08e2),
+    (0x59b0, 0x08e3),
+    (0x59b3, 0x08e4),
+    (0x59b7, 0x08e5),
+    (0x59ba, 0x08e6),
+    (0x59bc, 0x08e7),
+    (0x59c1, 0x08e8),
+    (0x59c3, 0x08e9),
+    (0x59c4, 0x08ea),
+    (0x59c8, 0x08eb),
+    (0x59ca, 0x08ec),
+    (0x59cd, 0x08ed),
+    (0x59d2, 0x08ee),
+    (0x59dd, 0x08ef),
+    (0x59de, 0x08f0),
+    (0x59df, 0x08f1),
+    (0x59e3, 0x08f2),
+    (0x59e4, 0x08f3),
+    (0x59e7, 0x08f4),
+    (0x59ee, 0x08f5),
+    (0x59ef, 0x08f6),
+    (0x59f1, 0x08f7),
+    (0x59f2, 0x08f8),
+    (0x59f4, 0x08f9),
+    (0x59f7, 0x08fa),
+    (0x5a00, 0x08fb),
+    (0x5a04, 0x08fc),
+    (0x5a0c, 0x08fd),
+    (0x5a0d, 0x08fe),
+    (0x5a0e, 0x08ff),
+    (0x5a12, 0x0900),
+    (0x5a13, 0x0901),
+    (0x5a1e, 0x0902),
+    (0x5a23, 0x0903),
+    (0x5a24, 0x0904),
+    (0x5a27, 0x0905),
+    (0x5a28, 0x0906),
+    (0x5a2a, 0x0907),
+    (0x5a2d, 0x0908),
+    (0x5a30, 0x0909),
+    (0x5a44, 0x090a),
+    (0x5a45, 0x090b),
+    (0x5a47, 0x090c),
+    (0x5a48, 0x090d),
+    (0x5a4c, 0x090e),
+    (0x5a50, 0x090f),
+    (0x5a55, 0x0910),
+    (0x5a5e, 0x0911),
+    (0x5a63, 0x0912),
+    (0x5a65, 0x0913),
+    (0x5a67, 0x0914),
+    (0x5a6d, 0x0915),
+    (0x5a77, 0x0916),
+    (0x5a7a, 0x0917),
+    (0x5a7b, 0x0918),
+    (0x5a7e, 0x0919),
+    (0x5a8b, 0x091a),
+    (0x5a90, 0x091b),
+    (0x5a93, 0x091c),
+    (0x5a96, 0x091d),
+    (0x5a99, 0x091e),
+    (0x5a9c, 0x091f),
+    (0x5a9e, 0x0920),
+    (0x5a9f, 0x0921),
+    (0x5aa0, 0x0922),
+    (0x5aa2, 0x0923),
+    (0x5aa7, 0x0924),
+    (0x5aac, 0x0925),
+    (0x5ab1, 0x0926),
+    (0x5ab2, 0x0927),
+    (0x5ab3, 0x0928),
+    (0x5ab5, 0x0929),
+    (0x5ab8, 0x092a),
+    (0x5aba, 0x092b),
+    (0x5abb, 0x092c),
+    (0x5abf, 0x092d),
+    (0x5ac4, 0x092e),
+    (0x5ac6, 0x092f),
+    (0x5ac8, 0x0930),
+    (0x5acf, 0x0931),
+    (0x5ada, 0x0932),
+    (0x5adc, 0x0933),
+    (0x5ae0, 0x0934),
+    (0x5ae5, 0x0935),
+    (0x5aea, 0x0936),
+    (0x5aee, 0x0937),
+    (0x5af5, 0x0938),
+    (0x5af6, 0x0939),
+    (0x5afd, 0x093a),
+    (0x5b00, 0x093b),
+    (0x5b01, 0x093c),
+    (0x5b08, 0x093d),
+    (0x5b17, 0x093e),
+    (0x5b19, 0x0940),
+    (0x5b1b, 0x0941),
+    (0x5b1d, 0x0942),
+    (0x5b21, 0x0943),
+    (0x5b25, 0x0944),
+    (0x5b2d, 0x0945),
+    (0x5b34, 0x093f),
+    (0x5b38, 0x0946),
+    (0x5b41, 0x0947),
+    (0x5b4b, 0x0948),
+    (0x5b4c, 0x0949),
+    (0x5b52, 0x094a),
+    (0x5b56, 0x094b),
+    (0x5b5e, 0x094c),
+    (0x5b68, 0x094d),
+    (0x5b6e, 0x094e),
+    (0x5b6f, 0x094f),
+    (0x5b7c, 0x0950),
+    (0x5b7d, 0x0951),
+    (0x5b7e, 0x0952),
+    (0x5b7f, 0x0953),
+    (0x5b81, 0x0954),
+    (0x5b84, 0x0955),
+    (0x5b86, 0x0956),
+    (0x5b8a, 0x0957),
+    (0x5b8e, 0x0958),
+    (0x5b90, 0x0959),
+    (0x5b91, 0x095a),
+    (0x5b93, 0x095b),
+    (0x5b94, 0x095c),
+    (0x5b96, 0x095d),
+    (0x5ba8, 0x095e),
+    (0x5ba9, 0x095f),
+    (0x5bac, 0x0960),
+    (0x5bad, 0x0961),
+    (0x5baf, 0x0962),
+    (0x5bb1, 0x0963),
+    (0x5bb2, 0x0964),
+    (0x5bb7, 0x0965),
+    (0x5bba, 0x0966),
+    (0x5bbc, 0x0967),
+    (0x5bc0, 0x0968),
+    (0x5bc1, 0x0969),
+    (0x5bcd, 0x096a),
+    (0x5bcf, 0x096b),
+    (0x5bd6, 0x096c),
+    (0x5bd7, 0x096d),
+    (0x5bd8, 0x096e),
+    (0x5bd9, 0x096f),
+    (0x5bda, 0x0970),
+    (0x5be0, 0x0971),
+    (0x5bef, 0x0972),
+    (0x5bf1, 0x0973),
+    (0x5bf4, 0x0974),
+    (0x5bfd, 0x0975),
+    (0x5c0c, 0x0976),
+    (0x5c17, 0x0977),
+    (0x5c1e, 0x0978),
+    (0x5c1f, 0x0979),
+    (0x5c23, 0x097a),
+    (0x5c26, 0x097b),
+    (0x5c29, 0x097c),
+    (0x5c2b, 0x097d),
+    (0x5c2c, 0x097e),
+    (0x5c2e, 0x097f),
+    (0x5c30, 0x0980),
+    (0x5c32, 0x0981),
+    (0x5c35, 0x0982),
+    (0x5c36, 0x0983),
+    (0x5c59, 0x0984),
+    (0x5c5a, 0x0985),
+    (0x5c5c, 0x0986),
+    (0x5c62, 0x0987),
+    (0x5c63, 0x0988),
+    (0x5c67, 0x0989),
+    (0x5c68, 0x098a),
+    (0x5c69, 0x098b),
+    (0x5c6d, 0x098c),
+    (0x5c70, 0x098d),
+    (0x5c74, 0x098e),
+    (0x5c75, 0x098f),
+    (0x5c7a, 0x0990),
+    (0x5c7b, 0x0991),
+    (0x5c7c, 0x0992),
+    (0x5c7d, 0x0993),
+    (0x5c87, 0x0994),
+    (0x5c88, 0x0995),
+    (0x5c8a, 0x0996),
+    (0x5c8f, 0x0997),
+    (0x5c92, 0x0998),
+    (0x5c9d, 0x0999),
+    (0x5c9f, 0x099a),
+    (0x5ca0, 0x099b),
+    (0x5ca2, 0x099c),
+    (0x5ca3, 0x099d),
+    (0x5ca6, 0x099e),
+    (0x5caa, 0x099f),
+    (0x5cb2, 0x09a0),
+    (0x5cb4, 0x09a1),
+    (0x5cb5, 0x09a2),
+    (0x5cba, 0x09a3),
+    (0x5cc9, 0x09a4),
+    (0x5ccb, 0x09a5),
+    (0x5cd2, 0x09a6),
+    (0x5cd7, 0x09a8),
+    (0x5cdd, 0x09a7),
+    (0x5cee, 0x09a9),
+    (0x5cf1, 0x09aa),
+    (0x5cf2, 0x09ab),
+    (0x5cf4, 0x09ac),
+    (0x5d01, 0x09ad),
+    (0x5d06, 0x09ae),
+    (0x5d0d, 0x09af),
+    (0x5d12, 0x09b0),
+    (0x5d23, 0x09b2),
+    (0x5d24, 0x09b3),
+    (0x5d26, 0x09b4),
+    (0x5d27, 0x09b5),
+    (0x5d2b, 0x09b1),
+    (0x5d31, 0x09b6),
+    (0x5d34, 0x09b7),
+    (0x5d39, 0x09b8),
+    (0x5d3d, 0x09b9),
+    (0x5d3f, 0x09ba),
+    (0x5d42, 0x09bb),
+    (0x5d43, 0x09bc),
+    (0x5d46, 0x09bd),
+    (0x5d48, 0x09be),
+    (0x5d4a, 0x09c2),
+    (0x5d51, 0x09c0),
+    (0x5d55, 0x09bf),
+    (0x5d59, 0x09c1),
+    (0x5d5f, 0x09c3),
+    (0x5d60, 0x09c4),
+    (0x5d61, 0x09c5),
+    (0x5d62, 0x09c6),
+    (0x5d64, 0x09c7),
+    (0x5d6a, 0x09c8),
+    (0x5d6d, 0x09c9),
+    (0x5d70, 0x09ca),
+    (0x5d79, 0x09cb),
+    (0x5d7a, 0x09cc),
+    (0x5d7e, 0x09cd),
+    (0x5d7f, 0x09ce),
+    (0x5d81, 0x09cf),
+    (0x5d83, 0x09d0),
+    (0x5d88, 0x09d1),
+    (0x5d8a, 0x09d2),
+    (0x5d92, 0x09d3),
+    (0x5d93, 0x09d4),
+    (0x5d94, 0x09d5),
+    (0x5d95, 0x09d6),
+    (0x5d99, 0x09d7),
+    (0x5d9b, 0x09d8),
+    (0x5d9f, 0x09d9),
+    (0x5da0, 0x09da),
+    (0x5da7, 0x09db),
+    (0x5dab, 0x09dc),
+    (0x5db0, 0x09dd),
+    (0x5db2, 0x19a9),
+    (0x5db4, 0x09de),
+    (0x5db8, 0x09df),
+    (0x5db9, 0x09e0),
+    (0x5dc3, 0x09e1),
+    (0x5dc7, 0x09e2),
+    (0x5dcb, 0x09e3),
+    (0x5dce, 0x09e5),
+    (0x5dd0, 0x09e4),
+    (0x5dd8, 0x09e6),
+    (0x5dd9, 0x09e7),
+    (0x5de0, 0x09e8),
+    (0x5de4, 0x09e9),
+    (0x5de9, 0x09ea),
+    (0x5df8, 0x09eb),
+    (0x5df9, 0x09ec),
+    (0x5e00, 0x09ed),
+    (0x5e07, 0x09ee),
+    (0x5e0d, 0x09ef),
+    (0x5e12, 0x09f0),
+    (0x5e14, 0x09f1),
+    (0x5e15, 0x09f2),
+    (0x5e18, 0x09f3),
+    (0x5e1f, 0x09f4),
+    (0x5e20, 0x09f5),
+    (0x5e28, 0x09f7),
+    (0x5e2e, 0x09f6),
+    (0x5e32, 0x09f8),
+    (0x5e35, 0x09f9),
+    (0x5e3e, 0x09fa),
+    (0x5e49, 0x09fd),
+    (0x5e4b, 0x09fb),
+    (0x5e50, 0x09fc),
+    (0x5e51, 0x09fe),
+    (0x5e56, 0x09ff),
+    (0x5e58, 0x0a00),
+    (0x5e5b, 0x0a01),
+    (0x5e5c, 0x0a02),
+    (0x5e5e, 0x0a03),
+    (0x5e68, 0x0a04),
+    (0x5e6a, 0x0a05),
+    (0x5e6b, 0x0a06),
+    (0x5e6c, 0x0a07),
+    (0x5e6d, 0x0a08),
+    (0x5e6e, 0x0a09),
+    (0x5e70, 0x0a0a),
+    (0x5e80, 0x0a0b),
+    (0x5e8b, 0x0a0c),
+    (0x5e8e, 0x0a0d),
+    (0x5ea2, 0x0a0e),
+    (0x5ea4, 0x0a0f),
+    (0x5ea5, 0x0a10),
+    (0x5ea8, 0x0a11),
+    (0x5eaa, 0x0a12),
+    (0x5eac, 0x0a13),
+    (0x5eb1, 0x0a14),
+    (0x5eb3, 0x0a15),
+    (0x5ebd, 0x0a16),
+    (0x5ebe, 0x0a17),
+    (0x5ebf, 0x0a18),
+    (0x5ec6, 0x0a19),
+    (0x5ecb, 0x0a1b),
+    (0x5ecc, 0x0a1a),
+    (0x5ece, 0x0a1c),
+    (0x5ed1, 0x0a1d),
+    (0x5ed2, 0x0a1e),
+    (0x5ed4, 0x0a1f),
+    (0x5ed5, 0x0a20),
+    (0x5edc, 0x0a21),
+    (0x5ede, 0x0a22),
+    (0x5ee5, 0x0a23),
+    (0x5eeb, 0x0a24),
+    (0x5f02, 0x0a25),
+    (0x5f06, 0x0a26),
+    (0x5f07, 0x0a27),
+    (0x5f08, 0x0a28),
+    (0x5f0e, 0x0a29),
+    (0x5f19, 0x0a2a),
+    (0x5f1c, 0x0a2b),
+    (0x5f1d, 0x0a2c),
+    (0x5f21, 0x0a2d),
+    (0x5f22, 0x0a2e),
+    (0x5f23, 0x0a2f),
+    (0x5f24, 0x0a30),
+    (0x5f28, 0x0a31),
+    (0x5f2b, 0x0a32),
+    (0x5f2c, 0x0a33),
+    (0x5f2e, 0x0a34),
+    (0x5f30, 0x0a35),
+    (0x5f34, 0x0a36),
+    (0x5f36, 0x0a37),
+    (0x5f3b, 0x0a38),
+    (0x5f3d, 0x0a39),
+    (0x5f3f, 0x0a3a),
+    (0x5f40, 0x0a3b),
+    (0x5f44, 0x0a3c),
+    (0x5f45, 0x0a3d),
+    (0x5f47, 0x0a3e),
+    (0x5f4d, 0x0a3f),
+    (0x5f50, 0x0a40),
+    (0x5f54, 0x0a41),
+    (0x5f58, 0x0a42),
+    (0x5f5b, 0x0a43),
+    (0x5f60, 0x0a44),
+    (0x5f63, 0x0a45),
+    (0x5f64, 0x0a46),
+    (0x5f67, 0x0a47),
+    (0x5f6f, 0x0a48),
+    (0x5f72, 0x0a49),
+    (0x5f74, 0x0a4a),
+    (0x5f75, 0x0a4b),
+    (0x5f78, 0x0a4c),
+    (0x5f7a, 0x0a4d),
+    (0x5f7d, 0x0a4e),
+    (0x5f7e, 0x0a4f),
+    (0x5f89, 0x0a50),
+    (0x5f8d, 0x0a51),
+    (0x5f8f, 0x0a52),
+    (0x5f96, 0x0a53),
+    (0x5f9c, 0x0a54),
+    (0x5f9d, 0x0a55),
+    (0x5fa2, 0x0a56),
+    (0x5fa4, 0x0a59),
+    (0x5fa7, 0x0a57),
+    (0x5fab, 0x0a58),
+    (0x5fac, 0x0a5a),
+    (0x5faf, 0x0a5b),
+    (0x5fb0, 0x0a5c),
+    (0x5fb1, 0x0a5d),
+    (0x5fb8, 0x0a5e),
+    (0x5fc4, 0x0a5f),
+    (0x5fc7, 0x0a60),
+    (0x5fc8, 0x0a61),
+    (0x5fc9, 0x0a62),
+    (0x5fcb, 0x0a63),
+    (0x5fd0, 0x0a64),
+    (0x5fd1, 0x0a65),
+    (0x5fd2, 0x0a66),
+    (0x5fd3, 0x0a67),
+    (0x5fd4, 0x0a68),
+    (0x5fde, 0x0a69),
+    (0x5fe1, 0x0a6a),
+    (0x5fe2, 0x0a6b),
+    (0x5fe8, 0x0a6c),
+    (0x5fe9, 0x0a6d),
+    (0x5fea, 0x0a6e),
+    (0x5fec, 0x0a6f),
+    (0x5fed, 0x0a70),
+    (0x5fee, 0x0a71),
+    (0x5fef, 0x0a72),
+    (0x5ff2, 0x0a73),
+    (0x5ff3, 0x0a74),
+    (0x5ff6, 0x0a75),
+    (0x5ffa, 0x0a76),
+    (0x5ffc, 0x0a77),
+    (0x6007, 0x0a78),
+    (0x600a, 0x0a79),
+    (0x600d, 0x0a7a),
+    (0x6013, 0x0a7b),
+    (0x6014, 0x0a7c),
+    (0x6017, 0x0a7d),
+    (0x6018, 0x0a7e),
+    (0x601a, 0x0a7f),
+    (0x601f, 0x0a80),
+    (0x6024, 0x0a81),
+    (0x602d, 0x0a82),
+    (0x6033, 0x0a83),
+    (0x6035, 0x0a84),
+    (0x6040, 0x0a85),
+    (0x6047, 0x0a86),
+    (0x6048, 0x0a87),
+    (0x6049, 0x0a88),
+    (0x604c, 0x0a89),
+    (0x6051, 0x0a8a),
+    (0x6054, 0x0a8b),
+    (0x6056, 0x0a8c),
+    (0x6057, 0x0a8d),
+    (0x605d, 0x0a8e),
+    (0x6061, 0x0a8f),
+    (0x6067, 0x0a90),
+    (0x6071, 0x0a91),
+    (0x607e, 0x0a92),
+    (0x607f, 0x0a93),
+    (0x6082, 0x0a94),
+    (0x6086, 0x0a95),
+    (0x6088, 0x0a96),
+    (0x608a, 0x0a97),
+    (0x608e, 0x0a98),
+    (0x6091, 0x0a99),
+    (0x6093, 0x0a9a),
+    (0x6095, 0x0a9b),
+    (0x6098, 0x0a9c),
+    (0x609d, 0x0a9d),
+    (0x609e, 0x0a9e),
+    (0x60a2, 0x0a9f),
+    (0x60a4, 0x0aa0),
+    (0x60a5, 0x0aa1),
+    (0x60a8, 0x0aa2),
+    (0x60b0, 0x0aa3),
+    (0x60b1, 0x0aa4),
+    (0x60b7, 0x0aa5),
+    (0x60bb, 0x0aa6),
+    (0x60be, 0x0aa7),
+    (0x60c2, 0x0aa8),
+    (0x60c4, 0x0aa9),
+    (0x60c8, 0x0aaa),
+    (0x60c9, 0x0aab),
+    (0x60ca, 0x0aac),
+    (0x60cb, 0x0aad),
+    (0x60ce, 0x0aae),
+    (0x60cf, 0x0aaf),
+    (0x60d4, 0x0ab0),
+    (0x60d5, 0x0ab1),
+    (0x60d9, 0x0ab2),
+    (0x60db, 0x0ab3),
+    (0x60dd, 0x0ab4),
+    (0x60de, 0x0ab5),
+    (0x60e2, 0x0ab6),
+    (0x60e5, 0x0ab7),
+    (0x60f2, 0x0ab8),
+    (0x60f5, 0x0ab9),
+    (0x60f8, 0x0aba),
+    (0x60fc, 0x0abb),
+    (0x60fd, 0x0abc),
+    (0x6102, 0x0abd),
+    (0x6107, 0x0abe),
+    (0x610a, 0x0abf),
+    (0x610c, 0x0ac0),
+    (0x6110, 0x0ac1),
+    (0x6111, 0x0ac2),
+    (0x6112, 0x0ac3),
+    (0x6113, 0x0ac4),
+    (0x6114, 0x0ac5),
+    (0x6116, 0x0ac6),
+    (0x6117, 0x0ac7),
+    (0x6119, 0x0ac8),
+    (0x611c, 0x0ac9),
+    (0x611e, 0x0aca),
+    (0x6122, 0x0acb),
+    (0x612a, 0x0acc),
+    (0x612b, 0x0acd),
+    (0x6130, 0x0ace),
+    (0x6131, 0x0acf),
+    (0x6135, 0x0ad0),
+    (0x6136, 0x0ad1),
+    (0x6137, 0x0ad2),
+    (0x6139, 0x0ad3),
+    (0x6141, 0x0ad4),
+    (0x6145, 0x0ad5),
+    (0x6146, 0x0ad6),
+    (0x6149, 0x0ad7),
+    (0x615e, 0x0ad8),
+    (0x6160, 0x0ad9),
+    (0x616c, 0x0ada),
+    (0x6172, 0x0adb),
+    (0x6178, 0x0adc),
+    (0x617b, 0x0add),
+    (0x617c, 0x0ade),
+    (0x617f, 0x0adf),
+    (0x6180, 0x0ae0),
+    (0x6181, 0x0ae1),
+    (0x6183, 0x0ae2),
+    (0x6184, 0x0ae3),
+    (0x618b, 0x0ae4),
+    (0x618d, 0x0ae5),
+    (0x6192, 0x0ae6),
+    (0x6193, 0x0ae7),
+    (0x6197, 0x0ae8),
+    (0x6198, 0x0ae9),
+    (0x619c, 0x0aea),
+    (0x619d, 0x0aeb),
+    (0x619f, 0x0aec),
+    (0x61a0, 0x0aed),
+    (0x61a5, 0x0aee),
+    (0x61a8, 0x0aef),
+    (0x61aa, 0x0af0),
+    (0x61ad, 0x0af1),
+    (0x61b8, 0x0af2),
+    (0x61b9, 0x0af3),
+    (0x61bc, 0x0af4),
+    (0x61c0, 0x0af5),
+    (0x61c1, 0x0af6),
+    (0x61c2, 0x0af7),
+    (0x61ce, 0x0af8),
+    (0x61cf, 0x0af9),
+    (0x61d5, 0x0afa),
+    (0x61dc, 0x0afb),
+    (0x61dd, 0x0afc),
+    (0x61de, 0x0afd),
+    (0x61df, 0x0afe),
+    (0x61e1, 0x0aff),
+    (0x61e2, 0x0b00),
+    (0x61e5, 0x0b03),
+    (0x61e7, 0x0b01),
+    (0x61e9, 0x0b02),
+    (0x61ec, 0x0b04),
+    (0x61ed, 0x0b05),
+    (0x61ef, 0x0b06),
+    (0x6201, 0x0b07),
+    (0x6203, 0x0b08),
+    (0x6204, 0x0b09),
+    (0x6207, 0x0b0a),
+    (0x6213, 0x0b0b),
+    (0x6215, 0x0b0c),
+    (0x621c, 0x0b0d),
+    (0x6220, 0x0b0e),
+    (0x6222, 0x0b0f),
+    (0x6223, 0x0b10),
+    (0x6227, 0x0b11),
+    (0x6229, 0x0b12),
+    (0x622b, 0x0b13),
+    (0x6239, 0x0b14),
+    (0x623d, 0x0b15),
+    (0x6242, 0x0b16),
+    (0x6243, 0x0b17),
+    (0x6244, 0x0b18),
+    (0x6246, 0x0b19),
+    (0x624c, 0x0b1a),
+    (0x6250, 0x0b1b),
+    (0x6251, 0x0b1c),
+    (0x6252, 0x0b1d),
+    (0x6254, 0x0b1e),
+    (0x6256, 0x0b1f),
+    (0x625a, 0x0b20),
+    (0x625c, 0x0b21),
+    (0x6264, 0x0b22),
+    (0x626d, 0x0b23),
+    (0x626f, 0x0b24),
+    (0x6273, 0x0b25),
+    (0x627a, 0x0b26),
+    (0x627d, 0x0b27),
+    (0x628d, 0x0b28),
+    (0x628e, 0x0b29),
+    (0x628f, 0x0b2a),
+    (0x6290, 0x0b2b),
+    (0x62a6, 0x0b2c),
+    (0x62a8, 0x0b2d),
+    (0x62b3, 0x0b2e),
+    (0x62b6, 0x0b2f),
+    (0x62b7, 0x0b30),
+    (0x62ba, 0x0b31),
+    (0x62be, 0x0b32),
+    (0x62bf, 0x0b33),
+    (0x62c4, 0x0b34),
+    (0x62ce, 0x0b35),
+    (0x62d5, 0x0b36),
+    (0x62d6, 0x0b37),
+    (0x62da, 0x0b38),
+    (0x62ea, 0x0b39),
+    (0x62f2, 0x0b3a),
+    (0x62f4, 0x0b3b),
+    (0x62fc, 0x0b3c),
+    (0x62fd, 0x0b3d),
+    (0x6303, 0x0b3e),
+    (0x6304, 0x0b3f),
+    (0x630a, 0x0b40),
+    (0x630b, 0x0b41),
+    (0x630d, 0x0b42),
+    (0x6310, 0x0b43),
+    (0x6313, 0x0b44),
+    (0x6316, 0x0b45),
+    (0x6318, 0x0b46),
+    (0x6329, 0x0b47),
+    (0x632a, 0x0b48),
+    (0x632d, 0x0b49),
+    (0x6335, 0x0b4a),
+    (0x6336, 0x0b4b),
+    (0x6339, 0x0b4c),
+    (0x633c, 0x0b4d),
+    (0x6341, 0x0b4e),
+    (0x6342, 0x0b4f),
+    (0x6343, 0x0b50),
+    (0x6344, 0x0b51),
+    (0x6346, 0x0b52),
+    (0x634a, 0x0b53),
+    (0x634b, 0x0b54),
+    (0x634e, 0x0b55),
+    (0x6352, 0x0b56),
+    (0x6353, 0x0b57),
+    (0x6354, 0x0b58),
+    (0x6358, 0x0b59),
+    (0x635b, 0x0b5a),
+    (0x6365, 0x0b5b),
+    (0x6366, 0x0b5c),
+    (0x636c, 0x0b5d),
+    (0x636d, 0x0b5e),
+    (0x6371, 0x0b5f),
+    (0x6374, 0x0b60),
+    (0x6375, 0x0b61),
+    (0x6378, 0x0b62),
+    (0x637c, 0x0b63),
+    (0x637d, 0x0b64),
+    (0x637f, 0x0b65),
+    (0x6382, 0x0b66),
+    (0x6384, 0x0b67),
+    (0x6387, 0x0b68),
+    (0x638a, 0x0b69),
+    (0x6390, 0x0b6a),
+    (0x6394, 0x0b6b),
+    (0x6395, 0x0b6c),
+    (0x6399, 0x0b6d),
+    (0x639a, 0x0b6e),
+    (0x639e, 0x0b6f),
+    (0x63a4, 0x0b70),
+    (0x63a6, 0x0b71),
+    (0x63ad, 0x0b72),
+    (0x63ae, 0x0b73),
+    (0x63af, 0x0b74),
+    (0x63bd, 0x0b75),
+    (0x63c1, 0x0b76),
+    (0x63c5, 0x0b77),
+    (0x63c8, 0x0b78),
+    (0x63ce, 0x0b79),
+    (0x63d1, 0x0b7a),
+    (0x63d3, 0x0b7b),
+    (0x63d4, 0x0b7c),
+    (0x63d5, 0x0b7d),
+    (0x63dc, 0x0b7e),
+    (0x63e0, 0x0b7f),
+    (0x63e5, 0x0b80),
+    (0x63ea, 0x0b81),
+    (0x63ec, 0x0b82),
+    (0x63f2, 0x0b83),
+    (0x63f3, 0x0b84),
+    (0x63f5, 0x0b85),
+    (0x63f8, 0x0b86),
+    (0x63f9, 0x0b87),
+    (0x6409, 0x0b88),
+    (0x640a, 0x0b89),
+    (0x6410, 0x0b8a),
+    (0x6412, 0x0b8b),
+    (0x6414, 0x0b8c),
+    (0x6418, 0x0b8d),
+    (0x641e, 0x0b8e),
+    (0x6420, 0x0b8f),
+    (0x6422, 0x0b90),
+    (0x6424, 0x0b91),
+    (0x6425, 0x0b92),
+    (0x6429, 0x0b93),
+    (0x642a, 0x0b94),
+    (0x642f, 0x0b95),
+    (0x6430, 0x0b96),
+    (0x6435, 0x0b97),
+    (0x643d, 0x0b98),
+    (0x643f, 0x0b99),
+    (0x644b, 0x0b9a),
+    (0x644f, 0x0b9b),
+    (0x6451, 0x0b9c),
+    (0x6452, 0x0b9d),
+    (0x6453, 0x0b9e),
+    (0x6454, 0x0b9f),
+    (0x645a, 0x0ba0),
+    (0x645b, 0x0ba1),
+    (0x645c, 0x0ba2),
+    (0x645d, 0x0ba3),
+    (0x645f, 0x0ba4),
+    (0x6460, 0x0ba5),
+    (0x6461, 0x0ba6),
+    (0x6463, 0x0ba7),
+    (0x646d, 0x0ba8),
+    (0x6473, 0x0ba9),
+    (0x6474, 0x0baa),
+    (0x647b, 0x0bab),
+    (0x647d, 0x0bac),
+    (0x6485, 0x0bad),
+    (0x6487, 0x0bae),
+    (0x648f, 0x0baf),
+    (0x6490, 0x0bb0),
+    (0x6491, 0x0bb1),
+    (0x6498, 0x0bb2),
+    (0x6499, 0x0bb3),
+    (0x649b, 0x0bb4),
+    (0x649d, 0x0bb5),
+    (0x649f, 0x0bb6),
+    (0x64a1, 0x0bb7),
+    (0x64a3, 0x0bb8),
+    (0x64a6, 0x0bb9),
+    (0x64a8, 0x0bba),
+    (0x64ac, 0x0bbb),
+    (0x64b3, 0x0bbc),
+    (0x64bd, 0x0bbd),
+    (0x64be, 0x0bbe),
+    (0x64bf, 0x0bbf),
+    (0x64c4, 0x0bc0),
+    (0x64c9, 0x0bc1),
+    (0x64ca, 0x0bc2),
+    (0x64cb, 0x0bc3),
+    (0x64cc, 0x0bc4),
+    (0x64ce, 0x0bc5),
+    (0x64d0, 0x0bc6),
+    (0x64d1, 0x0bc7),
+    (0x64d5, 0x0bc8),
+    (0x64d7, 0x0bc9),
+    (0x64e4, 0x0bca),
+    (0x64e5, 0x0bcb),
+    (0x64e9, 0x0bcc),
+    (0x64ea, 0x0bcd),
+    (0x64ed, 0x0bce),
+    (0x64f0, 0x0bcf),
+    (0x64f5, 0x0bd0),
+    (0x64f7, 0x0bd1),
+    (0x64fb, 0x0bd2),
+    (0x64ff, 0x0bd3),
+    (0x6501, 0x0bd4),
+    (0x6504, 0x0bd5),
+    (0x6508, 0x0bd6),
+    (0x6509, 0x0bd7),
+    (0x650a, 0x0bd8),
+    (0x650f, 0x0bd9),
+    (0x6513, 0x0bda),
+    (0x6514, 0x0bdb),
+    (0x6516, 0x0bdc),
+    (0x6519, 0x0bdd),
+    (0x651b, 0x0bde),
+    (0x651e, 0x0bdf),
+    (0x651f, 0x0be0),
+    (0x6522, 0x0be1),
+    (0x6526, 0x0be2),
+    (0x6529, 0x0be3),
+    (0x652e, 0x0be4),
+    (0x6531, 0x0be5),
+    (0x653a, 0x0be6),
+    (0x653c, 0x0be7),
+    (0x653d, 0x0be8),
+    (0x6543, 0x0be9),
+    (0x6547, 0x0bea),
+    (0x6549, 0x0beb),
+    (0x6550, 0x0bec),
+    (0x6552, 0x0bed),
+    (0x6554, 0x0bee),
+    (0x655f, 0x0bef),
+    (0x6560, 0x0bf0),
+    (0x6567, 0x0bf1),
+    (0x656b, 0x0bf2),
+    (0x657a, 0x0bf3),
+    (0x657d, 0x0bf4),
+    (0x6581, 0x0bf5),
+    (0x6585, 0x0bf6),
+    (0x658a, 0x0bf7),
+    (0x6592, 0x0bf8),
+    (0x6595, 0x0bf9),
+    (0x6598, 0x0bfa),
+    (0x659d, 0x0bfb),
+    (0x65a0, 0x0bfc),
+    (0x65a3, 0x0bfd),
+    (0x65a6, 0x0bfe),
+    (0x65ae, 0x0bff),
+    (0x65b2, 0x0c00),
+    (0x65b3, 0x0c01),
+    (0x65b4, 0x0c02),
+    (0x65bf, 0x0c03),
+    (0x65c2, 0x0c04),
+    (0x65c8, 0x0c05),
+    (0x65c9, 0x0c06),
+    (0x65ce, 0x0c07),
+    (0x65d0, 0x0c08),
+    (0x65d4, 0x0c09),
+    (0x65d6, 0x0c0a),
+    (0x65d8, 0x0c0b),
+    (0x65df, 0x0c0c),
+    (0x65f0, 0x0c0d),
+    (0x65f2, 0x0c0e),
+    (0x65f4, 0x0c0f),
+    (0x65f5, 0x0c10),
+    (0x65f9, 0x0c11),
+    (0x65fe, 0x0c12),
+    (0x65ff, 0x0c13),
+    (0x6600, 0x0c14),
+    (0x6604, 0x0c15),
+    (0x6608, 0x0c16),
+    (0x6609, 0x0c17),
+    (0x660d, 0x0c18),
+    (0x6611, 0x0c19),
+    (0x6612, 0x0c1a),
+    (0x6615, 0x0c1b),
+    (0x6616, 0x0c1c),
+    (0x661d, 0x0c1d),
+    (0x661e, 0x0c1e),
+    (0x6621, 0x0c1f),
+    (0x6622, 0x0c20),
+    (0x6623, 0x0c21),
+    (0x6624, 0x0c22),
+    (0x6626, 0x0c23),
+    (0x6629, 0x0c24),
+    (0x662a, 0x0c25),
+    (0x662b, 0x0c26),
+    (0x662c, 0x0c27),
+    (0x662e, 0x0c28),
+    (0x6630, 0x0c29),
+    (0x6631, 0x0c2a),
+    (0x6633, 0x0c2b),
+    (0x6637, 0x0c2d),
+    (0x6639, 0x0c2c),
+    (0x6640, 0x0c2e),
+    (0x6645, 0x0c2f),
+    (0x6646, 0x0c30),
+    (0x664a, 0x0c31),
+    (0x664c, 0x0c32),
+    (0x664e, 0x0c34),
+    (0x6651, 0x0c33),
+    (0x6657, 0x0c35),
+    (0x6658, 0x0c36),
+    (0x6659, 0x0c37),
+    (0x665b, 0x0c38),
+    (0x665c, 0x0c39),
+    (0x6660, 0x0c3a),
+    (0x6661, 0x0c3b),
+    (0x666a, 0x0c3d),
+    (0x666b, 0x0c3e),
+    (0x666c, 0x0c3f),
+    (0x6673, 0x0c41),
+    (0x6675, 0x0c42),
+    (0x6677, 0x0c44),
+    (0x6678, 0x0c45),
+    (0x6679, 0x0c46),
+    (0x667b, 0x0c47),
+    (0x667c, 0x0c49),
+    (0x667e, 0x0c40),
+    (0x667f, 0x0c43),
+    (0x6680, 0x0c48),
+    (0x668b, 0x0c4a),
+    (0x668c, 0x0c4b),
+    (0x668d, 0x0c4c),
+    (0x6690, 0x0c4d),
+    (0x6692, 0x0c4e),
+    (0x6699, 0x0c4f),
+    (0x669a, 0x0c50),
+    (0x669b, 0x0c51),
+    (0x669c, 0x0c52),
+    (0x669f, 0x0c53),
+    (0x66a0, 0x0c54),
+    (0x66a4, 0x0c55),
+    (0x66ad, 0x0c56),
+    (0x66b1, 0x0c57),
+    (0x66b2, 0x0c58),
+    (0x66b5, 0x0c59),
+    (0x66bb, 0x0c5a),
+    (0x66bf, 0x0c5b),
+    (0x66c0, 0x0c5c),
+    (0x66c2, 0x0c5d),
+    (0x66c3, 0x0c5e),
+    (0x66c8, 0x0c5f),
+    (0x66cc, 0x0c60),
+    (0x66ce, 0x0c61),
+    (0x66cf, 0x0c62),
+    (0x66d4, 0x0c63),
+    (0x66db, 0x0c64),
+    (0x66df, 0x0c65),
+    (0x66e8, 0x0c66),
+    (0x66eb, 0x0c67),
+    (0x66ec, 0x0c68),
+    (0x66ee, 0x0c69),
+    (0x66fa, 0x0c6a),
+    (0x66fb, 0x0c3c),
+    (0x6705, 0x0c6b),
+    (0x6707, 0x0c6c),
+    (0x670e, 0x0c6d),
+    (0x6713, 0x0c6e),
+    (0x6719, 0x0c6f),
+    (0x671c, 0x0c70),
+    (0x6720, 0x0c71),
+    (0x6722, 0x0c72),
+    (0x6733, 0x0c73),
+    (0x673e, 0x0c74),
+    (0x6745, 0x0c75),
+    (0x6747, 0x0c76),
+    (0x6748, 0x0c77),
+    (0x674c, 0x0c78),
+    (0x6754, 0x0c79),
+    (0x6755, 0x0c7a),
+    (0x675d, 0x0c7b),
+    (0x6766, 0x0c7c),
+    (0x676c, 0x0c7d),
+    (0x676e, 0x0c7e),
+    (0x6774, 0x0c7f),
+    (0x6776, 0x0c80),
+    (0x677b, 0x0c81),
+    (0x6781, 0x0c82),
+    (0x6784, 0x0c83),
+    (0x678e, 0x0c84),
+    (0x678f, 0x0c85),
+    (0x6791, 0x0c86),
+    (0x6793, 0x0c87),
+    (0x6796, 0x0c88),
+    (0x6798, 0x0c89),
+    (0x6799, 0x0c8a),
+    (0x679b, 0x0c8b),
+    (0x67b0, 0x0c8c),
+    (0x67b1, 0x0c8d),
+    (0x67b2, 0x0c8e),
+    (0x67b5, 0x0c8f),
+    (0x67bb, 0x0c90),
+    (0x67bc, 0x0c91),
+    (0x67bd, 0x0c92),
+    (0x67c0, 0x0c94),
+    (0x67c2, 0x0c95),
+    (0x67c3, 0x0c96),
+    (0x67c5, 0x0c97),
+    (0x67c8, 0x0c98),
+    (0x67c9, 0x0c99),
+    (0x67d2, 0x0c9a),
+    (0x67d7, 0x0c9b),
+    (0x67d9, 0x0c9c),
+    (0x67dc, 0x0c9d),
+    (0x67e1, 0x0c9e),
+    (0x67e6, 0x0c9f),
+    (0x67f0, 0x0ca0),
+    (0x67f2, 0x0ca1),
+    (0x67f6, 0x0ca2),
+    (0x67f7, 0x0ca3),
+    (0x67f9, 0x0c93),
+    (0x6814, 0x0ca5),
+    (0x6819, 0x0ca6),
+    (0x681d, 0x0ca7),
+    (0x681f, 0x0ca8),
+    (0x6827, 0x0caa),
+    (0x6828, 0x0ca9),
+    (0x682c, 0x0cab),
+    (0x682d, 0x0cac),
+    (0x682f, 0x0cad),
+    (0x6830, 0x0cae),
+    (0x6831, 0x0caf),
+    (0x6833, 0x0cb0),
+    (0x683b, 0x0cb1),
+    (0x683f, 0x0cb2),
+    (0x6844, 0x0cb3),
+    (0x6845, 0x0cb4),
+    (0x684a, 0x0cb5),
+    (0x684c, 0x0cb6),
+    (0x6852, 0x0ca4),
+    (0x6855, 0x0cb7),
+    (0x6857, 0x0cb8),
+    (0x6858, 0x0cb9),
+    (0x685b, 0x0cba),
+    (0x686b, 0x0cbb),
+    (0x686e, 0x0cbc),
+    (0x686f, 0x0cbd),
+    (0x6870, 0x0cbe),
+    (0x6871, 0x0cbf),
+    (0x6872, 0x0cc0),
+    (0x6875, 0x0cc1),
+    (0x6879, 0x0cc2),
+    (0x687a, 0x0cc3),
+    (0x687b, 0x0cc4),
+    (0x687c, 0x0cc5),
+    (0x6882, 0x0cc6),
+    (0x6884, 0x0cc7),
+    (0x6886, 0x0cc8),
+    (0x6888, 0x0cc9),
+    (0x6896, 0x0cca),
+    (0x6898, 0x0ccb),
+    (0x689a, 0x0ccc),
+    (0x689c, 0x0ccd),
+    (0x68a1, 0x0cce),
+    (0x68a3, 0x0ccf),
+    (0x68a5, 0x0cd0),
+    (0x68a9, 0x0cd1),
+    (0x68aa, 0x0cd2),
+    (0x68ae, 0x0cd3),
+    (0x68b2, 0x0cd4),
+    (0x68bb, 0x0cd5),
+    (0x68c5, 0x0cd6),
+    (0x68c8, 0x0cd7),
+    (0x68cc, 0x0cd8),
+    (0x68cf, 0x0cd9),
+    (0x68d0, 0x0cda),
+    (0x68d1, 0x0cdb),
+    (0x68d3, 0x0cdc),
+    (0x68d6, 0x0cdd),
+    (0x68d9, 0x0cde),
+    (0x68dc, 0x0cdf),
+    (0x68dd, 0x0ce0),
+    (0x68e5, 0x0ce1),
+    (0x68e8, 0x0ce2),
+    (0x68ea, 0x0ce3),
+    (0x68eb, 0x0ce4),
+    (0x68ec, 0x0ce5),
+    (0x68ed, 0x0ce6),
+    (0x68f0, 0x0ce7),
+    (0x68f1, 0x0ce8),
+    (0x68f5, 0x0ce9),
+    (0x68f6, 0x0cea),
+    (0x68fb, 0x0ceb),
+    (0x68fc, 0x0cec),
+    (0x68fd, 0x0ced),
+    (0x6906, 0x0cee),
+    (0x6909, 0x0cef),
+    (0x690a, 0x0cf0),
+    (0x6910, 0x0cf1),
+    (0x6911, 0x0cf2),
+    (0x6913, 0x0cf3),
+    (0x6916, 0x0cf4),
+    (0x6917, 0x0cf5),
+    (0x6931, 0x0cf6),
+    (0x6933, 0x0cf7),
+    (0x6935, 0x0cf8),
+    (0x6938, 0x0cf9),
+    (0x693b, 0x0cfa),
+    (0x6942, 0x0cfb),
+    (0x6945, 0x0cfc),
+    (0x6949, 0x0cfd),
+    (0x694e, 0x0cfe),
+    (0x6957, 0x0cff),
+    (0x695b, 0x0d00),
+    (0x6963, 0x0d01),
+    (0x6964, 0x0d02),
+    (0x6965, 0x0d03),
+    (0x6966, 0x0d04),
+    (0x6968, 0x0d05),
+    (0x6969, 0x0d06),
+    (0x696c, 0x0d07),
+    (0x6970, 0x0d08),
+    (0x6971, 0x0d09),
+    (0x6972, 0x0d0a),
+    (0x697a, 0x0d0b),
+    (0x697b, 0x0d0c),
+    (0x697f, 0x0d0d),
+    (0x6980, 0x0d0e),
+    (0x698d, 0x0d0f),
+    (0x6992, 0x0d10),
+    (0x6996, 0x0d11),
+    (0x6998, 0x0d12),
+    (0x69a1, 0x0d13),
+    (0x69a5, 0x0d14),
+    (0x69a6, 0x0d15),
+    (0x69a8, 0x0d16),
+    (0x69ab, 0x0d17),
+    (0x69ad, 0x0d18),
+    (0x69af, 0x0d19),
+    (0x69b7, 0x0d1a),
+    (0x69b8, 0x0d1b),
+    (0x69ba, 0x0d1c),
+    (0x69bc, 0x0d1d),
+    (0x69c5, 0x0d1e),
+    (0x69c8, 0x0d1f),
+    (0x69d1, 0x0d20),
+    (0x69d6, 0x0d21),
+    (0x69d7, 0x0d22),
+    (0x69e2, 0x0d23),
+    (0x69e5, 0x0d24),
+    (0x69ee, 0x0d25),
+    (0x69ef, 0x0d26),
+    (0x69f1, 0x0d27),
+    (0x69f3, 0x0d28),
+    (0x69f5, 0x0d29),
+    (0x69fe, 0x0d2a),
+    (0x6a00, 0x0d2b),
+    (0x6a01, 0x0d2c),
+    (0x6a03, 0x0d2d),
+    (0x6a0f, 0x0d2e),
+    (0x6a11, 0x0d2f),
+    (0x6a15, 0x0d30),
+    (0x6a1a, 0x0d31),
+    (0x6a1d, 0x0d32),
+    (0x6a20, 0x0d33),
+    (0x6a24, 0x0d34),
+    (0x6a28, 0x0d35),
+    (0x6a30, 0x0d36),
+    (0x6a32, 0x0d37),
+    (0x6a34, 0x0d38),
+    (0x6a37, 0x0d39),
+    (0x6a3b, 0x0d3a),
+    (0x6a3e, 0x0d3b),
+    (0x6a3f, 0x0d3c),
+    (0x6a45, 0x0d3d),
+    (0x6a46, 0x0d3e),
+    (0x6a49, 0x0d3f),
+    (0x6a4a, 0x0d40),
+    (0x6a4e, 0x0d41),
+    (0x6a50, 0x0d42),
+    (0x6a51, 0x0d43),
+    (0x6a52, 0x0d44),
+    (0x6a55, 0x0d45),
+    (0x6a56, 0x0d46),
+    (0x6a5b, 0x0d47),
+    (0x6a64, 0x0d48),
+    (0x6a67, 0x0d49),
+    (0x6a6a, 0x0d4a),
+    (0x6a71, 0x0d4b),
+    (0x6a73, 0x0d4c),
+    (0x6a7e, 0x0d4d),
+    (0x6a81, 0x0d4e),
+    (0x6a83, 0x0d4f),
+    (0x6a86, 0x0d50),
+    (0x6a87, 0x0d51),
+    (0x6a89, 0x0d52),
+    (0x6a8b, 0x0d53),
+    (0x6a91, 0x0d54),
+    (0x6a9b, 0x0d55),
+    (0x6a9d, 0x0d56),
+    (0x6a9e, 0x0d57),
+    (0x6a9f, 0x0d58),
+    (0x6aa5, 0x0d59),
+    (0x6aab, 0x0d5a),
+    (0x6aaf, 0x0d5b),
+    (0x6ab0, 0x0d5c),
+    (0x6ab1, 0x0d5d),
+    (0x6ab4, 0x0d5e),
+    (0x6abd, 0x0d5f),
+    (0x6abe, 0x0d60),
+    (0x6abf, 0x0d61),
+    (0x6ac6, 0x0d62),
+    (0x6ac8, 0x0d64),
+    (0x6ac9, 0x0d63),
+    (0x6acc, 0x0d65),
+    (0x6ad0, 0x0d66),
+    (0x6ad4, 0x0d67),
+    (0x6ad5, 0x0d68),
+    (0x6ad6, 0x0d69),
+    (0x6adc, 0x0d6a),
+    (0x6add, 0x0d6b),
+    (0x6ae4, 0x0d6c),
+    (0x6ae7, 0x0d6d),
+    (0x6aec, 0x0d6e),
+    (0x6af0, 0x0d6f),
+    (0x6af1, 0x0d70),
+    (0x6af2, 0x0d71),
+    (0x6afc, 0x0d72),
+    (0x6afd, 0x0d73),
+    (0x6b02, 0x0d74),
+    (0x6b03, 0x0d75),
+    (0x6b06, 0x0d76),
+    (0x6b07, 0x0d77),
+    (0x6b09, 0x0d78),
+    (0x6b0f, 0x0d79),
+    (0x6b10, 0x0d7a),
+    (0x6b11, 0x0d7b),
+    (0x6b17, 0x0d7c),
+    (0x6b1b, 0x0d7d),
+    (0x6b1e, 0x0d7e),
+    (0x6b24, 0x0d7f),
+    (0x6b28, 0x0d80),
+    (0x6b2b, 0x0d81),
+    (0x6b2c, 0x0d82),
+    (0x6b2f, 0x0d83),
+    (0x6b35, 0x0d84),
+    (0x6b36, 0x0d85),
+    (0x6b3b, 0x0d86),
+    (0x6b3f, 0x0d87),
+    (0x6b46, 0x0d88),
+    (0x6b4a, 0x0d89),
+    (0x6b4d, 0x0d8a),
+    (0x6b52, 0x0d8b),
+    (0x6b56, 0x0d8c),
+    (0x6b58, 0x0d8d),
+    (0x6b5d, 0x0d8e),
+    (0x6b60, 0x0d8f),
+    (0x6b67, 0x0d90),
+    (0x6b6b, 0x0d91),
+    (0x6b6e, 0x0d92),
+    (0x6b70, 0x0d93),
+    (0x6b75, 0x0d94),
+    (0x6b7d, 0x0d95),
+    (0x6b7e, 0x0d96),
+    (0x6b82, 0x0d97),
+    (0x6b85, 0x0d98),
+    (0x6b97, 0x0d99),
+    (0x6b9b, 0x0d9a),
+    (0x6b9f, 0x0d9b),
+    (0x6ba0, 0x0d9c),
+    (0x6ba2, 0x0d9d),
+    (0x6ba3, 0x0d9e),
+    (0x6ba8, 0x0d9f),
+    (0x6ba9, 0x0da0),
+    (0x6bac, 0x0da1),
+    (0x6bad, 0x0da2),
+    (0x6bae, 0x0da3),
+    (0x6bb0, 0x0da4),
+    (0x6bb8, 0x0da5),
+    (0x6bb9, 0x0da6),
+    (0x6bbd, 0x0da7),
+    (0x6bbe, 0x0da8),
+    (0x6bc3, 0x0da9),
+    (0x6bc4, 0x0daa),
+    (0x6bc9, 0x0dab),
+    (0x6bcc, 0x0dac),
+    (0x6bd6, 0x0dad),
+    (0x6bda, 0x0dae),
+    (0x6be1, 0x0daf),
+    (0x6be3, 0x0db0),
+    (0x6be6, 0x0db1),
+    (0x6be7, 0x0db2),
+    (0x6bee, 0x0db3),
+    (0x6bf1, 0x0db4),
+    (0x6bf7, 0x0db5),
+    (0x6bf9, 0x0db6),
+    (0x6bff, 0x0db7),
+    (0x6c02, 0x0db8),
+    (0x6c04, 0x0db9),
+    (0x6c05, 0x0dba),
+    (0x6c09, 0x0dbb),
+    (0x6c0d, 0x0dbc),
+    (0x6c0e, 0x0dbd),
+    (0x6c10, 0x0dbe),
+    (0x6c12, 0x0dbf),
+    (0x6c19, 0x0dc0),
+    (0x6c1f, 0x0dc1),
+    (0x6c26, 0x0dc2),
+    (0x6c27, 0x0dc3),
+    (0x6c28, 0x0dc4),
+    (0x6c2c, 0x0dc5),
+    (0x6c2e, 0x0dc6),
+    (0x6c33, 0x0dc7),
+    (0x6c35, 0x0dc8),
+    (0x6c36, 0x0dc9),
+    (0x6c3a, 0x0dca),
+    (0x6c3b, 0x0dcb),
+    (0x6c3f, 0x0dcc),
+    (0x6c4a, 0x0dcd),
+    (0x6c4b, 0x0dce),
+    (0x6c4d, 0x0dcf),
+    (0x6c4f, 0x0dd0),
+    (0x6c52, 0x0dd1),
+    (0x6c54, 0x0dd2),
+    (0x6c59, 0x0dd3),
+    (0x6c5b, 0x0dd4),
+    (0x6c5c, 0x0dd5),
+    (0x6c67, 0x0e09),
+    (0x6c6b, 0x0dd6),
+    (0x6c6d, 0x0dd7),
+    (0x6c6f, 0x0dd8),
+    (0x6c74, 0x0dd9),
+    (0x6c76, 0x0dda),
+    (0x6c78, 0x0ddb),
+    (0x6c79, 0x0ddc),
+    (0x6c7b, 0x0ddd),
+    (0x6c85, 0x0dde),
+    (0x6c86, 0x0ddf),
+    (0x6c87, 0x0de0),
+    (0x6c89, 0x0de1),
+    (0x6c94, 0x0de2),
+    (0x6c95, 0x0de3),
+    (0x6c97, 0x0de4),
+    (0x6c98, 0x0de5),
+    (0x6c9c, 0x0de6),
+    (0x6c9f, 0x0de7),
+    (0x6cb0, 0x0de8),
+    (0x6cb2, 0x0de9),
+    (0x6cb4, 0x0dea),
+    (0x6cc2, 0x0deb),
+    (0x6cc6, 0x0dec),
+    (0x6ccd, 0x0ded),
+    (0x6ccf, 0x0dee),
+    (0x6cd0, 0x0def),
+    (0x6cd1, 0x0df0),
+    (0x6cd2, 0x0df1),
+    (0x6cd4, 0x0df2),
+    (0x6cd6, 0x0df3),
+    (0x6cda, 0x0df4),
+    (0x6cdc, 0x0df5),
+    (0x6ce0, 0x0df6),
+    (0x6ce7, 0x0df7),
+    (0x6ce9, 0x0df8),
+    (0x6ceb, 0x0df9),
+    (0x6cec, 0x0dfa),
+    (0x6cee, 0x0dfb),
+    (0x6cf2, 0x0dfc),
+    (0x6cf4, 0x0dfd),
+    (0x6d04, 0x0dfe),
+    (0x6d07, 0x0dff),
+    (0x6d0a, 0x0e00),
+    (0x6d0e, 0x0e01),
+    (0x6d0f, 0x0e02),
+    (0x6d11, 0x0e03),
+    (0x6d13, 0x0e04),
+    (0x6d1a, 0x0e05),
+    (0x6d26, 0x0e06),
+    (0x6d27, 0x0e07),
+    (0x6d28, 0x0e08),
+    (0x6d2e, 0x0e0a),
+    (0x6d2f, 0x0e0b),
+    (0x6d31, 0x0e0c),
+    (0x6d39, 0x0e0d),
+    (0x6d3c, 0x0e0e),
+    (0x6d3f, 0x0e0f),
+    (0x6d57, 0x0e10),
+    (0x6d5e, 0x0e11),
+    (0x6d5f, 0x0e12),
+    (0x6d61, 0x0e13),
+    (0x6d65, 0x0e14),
+    (0x6d67, 0x0e15),
+    (0x6d6f, 0x0e16),
+    (0x6d70, 0x0e17),
+    (0x6d7c, 0x0e18),
+    (0x6d82, 0x0e19),
+    (0x6d87, 0x0e1a),
+    (0x6d91, 0x0e1b),
+    (0x6d92, 0x0e1c),
+    (0x6d94, 0x0e1d),
+    (0x6d96, 0x0e1e),
+    (0x6d97, 0x0e1f),
+    (0x6d98, 0x0e20),
+    (0x6daa, 0x0e21),
+    (0x6dac, 0x0e22),
+    (0x6db4, 0x0e23),
+    (0x6db7, 0x0e24),
+    (0x6db9, 0x0e25),
+    (0x6dbd, 0x0e26),
+    (0x6dbf, 0x0e27),
+    (0x6dc4, 0x0e28),
+    (0x6dc8, 0x0e29),
+    (0x6dca, 0x0e2a),
+    (0x6dce, 0x0e2b),
+    (0x6dcf, 0x0e2c),
+    (0x6dd6, 0x0e2d),
+    (0x6ddb, 0x0e2e),
+    (0x6ddd, 0x0e2f),
+    (0x6ddf, 0x0e30),
+    (0x6de0, 0x0e31),
+    (0x6de2, 0x0e32),
+    (0x6de5, 0x0e33),
+    (0x6de9, 0x0e34),
+    (0x6def, 0x0e35),
+    (0x6df0, 0x0e36),
+    (0x6df4, 0x0e37),
+    (0x6df6, 0x0e38),
+    (0x6dfc, 0x0e39),
+    (0x6e00, 0x0e3a),
+    (0x6e04, 0x0e3b),
+    (0x6e1e, 0x0e3c),
+    (0x6e22, 0x0e3d),
+    (0x6e27, 0x0e3e),
+    (0x6e32, 0x0e3f),
+    (0x6e36, 0x0e40),
+    (0x6e39, 0x0e41),
+    (0x6e3b, 0x0e42),
+    (0x6e3c, 0x0e43),
+    (0x6e44, 0x0e44),
+    (0x6e45, 0x0e45),
+    (0x6e48, 0x0e46),
+    (0x6e49, 0x0e47),
+    (0x6e4b, 0x0e48),
+    (0x6e4f, 0x0e49),
+    (0x6e51, 0x0e4a),
+    (0x6e52, 0x0e4b),
+    (0x6e53, 0x0e4c),
+    (0x6e54, 0x0e4d),
+    (0x6e57, 0x0e4e),
+    (0x6e5c, 0x0e4f),
+    (0x6e5d, 0x0e50),
+    (0x6e5e, 0x0e51),
+    (0x6e62, 0x0e52),
+    (0x6e63, 0x0e53),
+    (0x6e68, 0x0e54),
+    (0x6e73, 0x0e55),
+    (0x6e7b, 0x0e56),
+    (0x6e7d, 0x0e57),
+    (0x6e8d, 0x0e58),
+    (0x6e93, 0x0e59),
+    (0x6e99, 0x0e5a),
+    (0x6ea0, 0x0e5b),
+    (0x6ea7, 0x0e5c),
+    (0x6ead, 0x0e5d),
+    (0x6eae, 0x0e5e),
+    (0x6eb1, 0x0e5f),
+    (0x6eb3, 0x0e60),
+    (0x6ebb, 0x0e61),
+    (0x6ebf, 0x0e62),
+    (0x6ec0, 0x0e63),
+    (0x6ec1, 0x0e64),
+    (0x6ec3, 0x0e65),
+    (0x6ec7, 0x0e66),
+    (0x6ec8, 0x0e67),
+    (0x6eca, 0x0e68),
+    (0x6ecd, 0x0e69),
+    (0x6ece, 0x0e6a),
+    (0x6ecf, 0x0e6b),
+    (0x6eeb, 0x0e6c),
+    (0x6eed, 0x0e6d),
+    (0x6eee, 0x0e6e),
+    (0x6ef9, 0x0e6f),
+    (0x6efb, 0x0e70),
+    (0x6efd, 0x0e71),
+    (0x6f04, 0x0e72),
+    (0x6f08, 0x0e73),
+    (0x6f0a, 0x0e74),
+    (0x6f0c, 0x0e75),
+    (0x6f0d, 0x0e76),
+    (0x6f16, 0x0e77),
+    (0x6f18, 0x0e78),
+    (0x6f1a, 0x0e79),
+    (0x6f1b, 0x0e7a),
+    (0x6f26, 0x0e7b),
+    (0x6f29, 0x0e7c),
+    (0x6f2a, 0x0e7d),
+    (0x6f2d, 0x0e84),
+    (0x6f2f, 0x0e7e),
+    (0x6f30, 0x0e7f),
+    (0x6f33, 0x0e80),
+    (0x6f36, 0x0e81),
+    (0x6f3b, 0x0e82),
+    (0x6f3c, 0x0e83),
+    (0x6f4f, 0x0e85),
+    (0x6f51, 0x0e86),
+    (0x6f52, 0x0e87),
+    (0x6f53, 0x0e88),
+    (0x6f57, 0x0e89),
+    (0x6f59, 0x0e8a),
+    (0x6f5a, 0x0e8b),
+    (0x6f5d, 0x0e8c),
+    (0x6f5e, 0x0e8d),
+    (0x6f61, 0x0e8e),
+    (0x6f62, 0x0e8f),
+    (0x6f68, 0x0e90),
+    (0x6f6c, 0x0e91),
+    (0x6f7d, 0x0e92),
+    (0x6f7e, 0x0e93),
+    (0x6f83, 0x0e94),
+    (0x6f87, 0x0e95),
+    (0x6f88, 0x0e96),
+    (0x6f8b, 0x0e97),
+    (0x6f8c, 0x0e98),
+    (0x6f8d, 0x0e99),
+    (0x6f90, 0x0e9a),
+    (0x6f92, 0x0e9b),
+    (0x6f93, 0x0e9c),
+    (0x6f94, 0x0e9d),
+    (0x6f96, 0x0e9e),
+    (0x6f9a, 0x0e9f),
+    (0x6f9f, 0x0ea0),
+    (0x6fa0, 0x0ea1),
+    (0x6fa5, 0x0ea2),
+    (0x6fa6, 0x0ea3),
+    (0x6fa7, 0x0ea4),
+    (0x6fa8, 0x0ea5),
+    (0x6fae, 0x0ea6),
+    (0x6faf, 0x0ea7),
+    (0x6fb0, 0x0ea8),
+    (0x6fb5, 0x0ea9),
+    (0x6fb6, 0x0eaa),
+    (0x6fbc, 0x0eab),
+    (0x6fc5, 0x0eac),
+    (0x6fc7, 0x0ead),
+    (0x6fc8, 0x0eae),
+    (0x6fca, 0x0eaf),
+    (0x6fda, 0x0eb0),
+    (0x6fde, 0x0eb1),
+    (0x6fe8, 0x0eb2),
+    (0x6fe9, 0x0eb3),
+    (0x6ff0, 0x0eb4),
+    (0x6ff5, 0x0eb5),
+    (0x6ff9, 0x0eb6),
+    (0x6ffc, 0x0eb7),
+    (0x6ffd, 0x0eb8),
+    (0x7000, 0x0eb9),
+    (0x7005, 0x0eba),
+    (0x7006, 0x0ebb),
+    (0x7007, 0x0ebc),
+    (0x700d, 0x0ebd),
+    (0x7017, 0x0ebe),
+    (0x7020, 0x0ebf),
+    (0x7023, 0x0ec0),
+    (0x702f, 0x0ec1),
+    (0x7034, 0x0ec2),
+    (0x7037, 0x0ec3),
+    (0x7039, 0x0ec4),
+    (0x703c, 0x0ec5),
+    (0x7043, 0x0ec6),
+    (0x7044, 0x0ec7),
+    (0x7048, 0x0ec8),
+    (0x7049, 0x0ec9),
+    (0x704a, 0x0eca),
+    (0x704b, 0x0ecb),
+    (0x704e, 0x0ed0),
+    (0x7054, 0x0ecc),
+    (0x7055, 0x0ecd),
+    (0x705d, 0x0ece),
+    (0x705e, 0x0ecf),
+    (0x7064, 0x0ed1),
+    (0x7065, 0x0ed2),
+    (0x706c, 0x0ed3),
+    (0x706e, 0x0ed4),
+    (0x7075, 0x0ed5),
+    (0x7076, 0x0ed6),
+    (0x707e, 0x0ed7),
+    (0x7081, 0x0ed8),
+    (0x7085, 0x0ed9),
+    (0x7086, 0x0eda),
+    (0x7094, 0x0edb),
+    (0x7095, 0x0edc),
+    (0x7096, 0x0edd),
+    (0x7097, 0x0ede),
+    (0x7098, 0x0edf),
+    (0x709b, 0x0ee0),
+    (0x70a4, 0x0ee1),
+    (0x70ab, 0x0ee2),
+    (0x70b0, 0x0ee3),
+    (0x70b1, 0x0ee4),
+    (0x70b4, 0x0ee5),
+    (0x70b7, 0x0ee6),
+    (0x70ca, 0x0ee7),
+    (0x70d1, 0x0ee8),
+    (0x70d3, 0x0ee9),
+    (0x70d4, 0x0eea),
+    (0x70d5, 0x0eeb),
+    (0x70d6, 0x0eec),
+    (0x70d8, 0x0eed),
+    (0x70dc, 0x0eee),
+    (0x70e4, 0x0eef),
+    (0x70fa, 0x0ef0),
+    (0x7103, 0x0ef1),
+    (0x7104, 0x0ef2),
+    (0x7105, 0x0ef3),
+    (0x7106, 0x0ef4),
+    (0x7107, 0x0ef5),
+    (0x710b, 0x0ef6),
+    (0x710c, 0x0ef7),
+    (0x710f, 0x0ef8),
+    (0x711e, 0x0ef9),
+    (0x7120, 0x0efa),
+    (0x712b, 0x0efb),
+    (0x712d, 0x0efc),
+    (0x712f, 0x0efd),
+    (0x7130, 0x0efe),
+    (0x7131, 0x0eff),
+    (0x7138, 0x0f00),
+    (0x7141, 0x0f01),
+    (0x7145, 0x0f02),
+    (0x7146, 0x0f03),
+    (0x7147, 0x0f04),
+    (0x714a, 0x0f05),
+    (0x714b, 0x0f06),
+    (0x7150, 0x0f07),
+    (0x7152, 0x0f08),
+    (0x7157, 0x0f09),
+    (0x715a, 0x0f0a),
+    (0x715c, 0x0f0b),
+    (0x715e, 0x0f0c),
+    (0x7160, 0x0f0d),
+    (0x7168, 0x0f0e),
+    (0x7179, 0x0f0f),
+    (0x7180, 0x0f10),
+    (0x7185, 0x0f11),
+    (0x7187, 0x0f12),
+    (0x718c, 0x0f13),
+    (0x7192, 0x0f14),
+    (0x719a, 0x0f15),
+    (0x719b, 0x0f16),
+    (0x71a0, 0x0f17),
+    (0x71a2, 0x0f18),
+    (0x71af, 0x0f19),
+    (0x71b0, 0x0f1a),
+    (0x71b2, 0x0f1b),
+    (0x71b3, 0x0f1c),
+    (0x71ba, 0x0f1d),
+    (0x71bf, 0x0f1e),
+    (0x71c0, 0x0f1f),
+    (0x71c1, 0x0f20),
+    (0x71c4, 0x0f21),
+    (0x71cb, 0x0f22),
+    (0x71cc, 0x0f23),
+    (0x71d3, 0x0f24),
+    (0x71d6, 0x0f25),
+    (0x71d9, 0x0f26),
+    (0x71da, 0x0f27),
+    (0x71dc, 0x0f28),
+    (0x71f8, 0x0f29),
+    (0x71fe, 0x0f2a),
+    (0x7200, 0x0f2b),
+    (0x7207, 0x0f2c),
+    (0x7208, 0x0f2d),
+    (0x7209, 0x0f2e),
+    (0x7213, 0x0f2f),
+    (0x7217, 0x0f30),
+    (0x721a, 0x0f31),
+    (0x721d, 0x0f32),
+    (0x721f, 0x0f33),
+    (0x7224, 0x0f34),
+    (0x722b, 0x0f35),
+    (0x722f, 0x0f36),
+    (0x7234, 0x0f37),
+    (0x7238, 0x0f38),
+    (0x7239, 0x0f39),
+    (0x7241, 0x0f3a),
+    (0x7242, 0x0f3b),
+    (0x7243, 0x0f3c),
+    (0x7245, 0x0f3d),
+    (0x724e, 0x0f3e),
+    (0x724f, 0x0f3f),
+    (0x7250, 0x0f40),
+    (0x7253, 0x0f41),
+    (0x7255, 0x0f42),
+    (0x7256, 0x0f43),
+    (0x725a, 0x0f44),
+    (0x725c, 0x0f45),
+    (0x725e, 0x0f46),
+    (0x7260, 0x0f47),
+    (0x7263, 0x0f48),
+    (0x7268, 0x0f49),
+    (0x726b, 0x0f4a),
+    (0x726e, 0x0f4b),
+    (0x726f, 0x0f4c),
+    (0x7271, 0x0f4d),
+    (0x7277, 0x0f4e),
+    (0x7278, 0x0f4f),
+    (0x727b, 0x0f50),
+    (0x727c, 0x0f51),
+    (0x727f, 0x0f52),
+    (0x7284, 0x0f53),
+    (0x7289, 0x0f54),
+    (0x728d, 0x0f55),
+    (0x728e, 0x0f56),
+    (0x7293, 0x0f57),
+    (0x729b, 0x0f58),
+    (0x72a8, 0x0f59),
+    (0x72ad, 0x0f5a),
+    (0x72ae, 0x0f5b),
+    (0x72b1, 0x0f5c),
+    (0x72b4, 0x0f5d),
+    (0x72be, 0x0f5e),
+    (0x72c1, 0x0f5f),
+    (0x72c7, 0x0f60),
+    (0x72c9, 0x0f61),
+    (0x72cc, 0x0f62),
+    (0x72d5, 0x0f63),
+    (0x72d6, 0x0f64),
+    (0x72d8, 0x0f65),
+    (0x72df, 0x0f66),
+    (0x72e5, 0x0f67),
+    (0x72f3, 0x0f68),
+    (0x72f4, 0x0f69),
+    (0x72fa, 0x0f6a),
+    (0x72fb, 0x0f6b),
+    (0x72fe, 0x0f6c),
+    (0x7302, 0x0f6d),
+    (0x7304, 0x0f6e),
+    (0x7305, 0x0f6f),
+    (0x7307, 0x0f70),
+    (0x730b, 0x0f71),
+    (0x730d, 0x0f72),
+    (0x7312, 0x0f73),
+    (0x7313, 0x0f74),
+    (0x7318, 0x0f75),
+    (0x7319, 0x0f76),
+    (0x731e, 0x0f77),
+    (0x7322, 0x0f78),
+    (0x7324, 0x0f79),
+    (0x7327, 0x0f7a),
+    (0x7328, 0x0f7b),
+    (0x732c, 0x0f7c),
+    (0x7331, 0x0f7d),
+    (0x7332, 0x0f7e),
+    (0x7335, 0x0f7f),
+    (0x733a, 0x0f80),
+    (0x733b, 0x0f81),
+    (0x733d, 0x0f82),
+    (0x7343, 0x0f83),
+    (0x734d, 0x0f84),
+    (0x7350, 0x0f85),
+    (0x7352, 0x0f86),
+    (0x7356, 0x0f87),
+    (0x7358, 0x0f88),
+    (0x735d, 0x0f89),
+    (0x735e, 0x0f8a),
+    (0x735f, 0x0f8b),
+    (0x7360, 0x0f8c),
+    (0x7366, 0x0f8d),
+    (0x7367, 0x0f8e),
+    (0x7369, 0x0f8f),
+    (0x736b, 0x0f90),
+    (0x736c, 0x0f91),
+    (0x736e, 0x0f92),
+    (0x736f, 0x0f93),
+    (0x7371, 0x0f94),
+    (0x7377, 0x0f95),
+    (0x7379, 0x0f96),
+    (0x737c, 0x0f97),
+    (0x7380, 0x0f98),
+    (0x7381, 0x0f99),
+    (0x7383, 0x0f9a),
+    (0x7385, 0x0f9b),
+    (0x7386, 0x0f9c),
+    (0x738e, 0x0f9d),
+    (0x7390, 0x0f9e),
+    (0x7393, 0x0f9f),
+    (0x7395, 0x0fa0),
+    (0x7397, 0x0fa1),
+    (0x7398, 0x0fa2),
+    (0x739c, 0x0fa3),
+    (0x739e, 0x0fa4),
+    (0x739f, 0x0fa5),
+    (0x73a0, 0x0fa6),
+    (0x73a2, 0x0fa7),
+    (0x73a5, 0x0fa8),
+    (0x73a6, 0x0fa9),
+    (0x73aa, 0x0faa),
+    (0x73ab, 0x0fab),
+    (0x73ad, 0x0fac),
+    (0x73b5, 0x0fad),
+    (0x73b7, 0x0fae),
+    (0x73b9, 0x0faf),
+    (0x73bc, 0x0fb0),
+    (0x73bd, 0x0fb1),
+    (0x73bf, 0x0fb2),
+    (0x73c5, 0x0fb3),
+    (0x73c6, 0x0fb4),
+    (0x73c9, 0x0fb5),
+    (0x73cb, 0x0fb6),
+    (0x73cc, 0x0fb7),
+    (0x73cf, 0x0fb8),
+    (0x73d2, 0x0fb9),
+    (0x73d3, 0x0fba),
+    (0x73d6, 0x0fbb),
+    (0x73d9, 0x0fbc),
+    (0x73dd, 0x0fbd),
+    (0x73e1, 0x0fbe),
+    (0x73e3, 0x0fbf),
+    (0x73e6, 0x0fc0),
+    (0x73e7, 0x0fc1),
+    (0x73e9, 0x0fc2),
+    (0x73f4, 0x0fc3),
+    (0x73f5, 0x0fc4),
+    (0x73f7, 0x0fc5),
+    (0x73f9, 0x0fc6),
+    (0x73fa, 0x0fc7),
+    (0x73fb, 0x0fc8),
+    (0x73fd, 0x0fc9),
+    (0x73ff, 0x0fca),
+    (0x7400, 0x0fcb),
+    (0x7401, 0x0fcc),
+    (0x7404, 0x0fcd),
+    (0x7407, 0x0fce),
+    (0x740a, 0x0fcf),
+    (0x7411, 0x0fd0),
+    (0x741a, 0x0fd1),
+    (0x741b, 0x0fd2),
+    (0x7424, 0x0fd3),
+    (0x7426, 0x0fd4),
+    (0x7428, 0x0fd5),
+    (0x7429, 0x0fd6),
+    (0x742a, 0x0fd7),
+    (0x742b, 0x0fd8),
+    (0x742c, 0x0fd9),
+    (0x742d, 0x0fda),
+    (0x742e, 0x0fdb),
+    (0x742f, 0x0fdc),
+    (0x7430, 0x0fdd),
+    (0x7431, 0x0fde),
+    (0x7439, 0x0fdf),
+    (0x7440, 0x0fe0),
+    (0x7443, 0x0fe1),
+    (0x7444, 0x0fe2),
+    (0x7446, 0x0fe3),
+    (0x7447, 0x0fe4),
+    (0x744b, 0x0fe5),
+    (0x744d, 0x0fe6),
+    (0x7451, 0x0fe7),
+    (0x7452, 0x0fe8),
+    (0x7457, 0x0fe9),
+    (0x745d, 0x0fea),
+    (0x7462, 0x0feb),
+    (0x7466, 0x0fec),
+    (0x7467, 0x0fed),
+    (0x7468, 0x0fee),
+    (0x746b, 0x0fef),
+    (0x746d, 0x0ff0),
+    (0x746e, 0x0ff1),
+    (0x7471, 0x0ff2),
+    (0x7472, 0x0ff3),
+    (0x7480, 0x0ff4),
+    (0x7481, 0x0ff5),
+    (0x7485, 0x0ff6),
+    (0x7486, 0x0ff7),
+    (0x7487, 0x0ff8),
+    (0x7489, 0x0ff9),
+    (0x748f, 0x0ffa),
+    (0x7490, 0x0ffb),
+    (0x7491, 0x0ffc),
+    (0x7492, 0x0ffd),
+    (0x7498, 0x0ffe),
+    (0x7499, 0x0fff),
+    (0x749a, 0x1000),
+    (0x749c, 0x1001),
+    (0x749f, 0x1002),
+    (0x74a0, 0x1003),
+    (0x74a1, 0x1004),
+    (0x74a3, 0x1005),
+    (0x74a6, 0x1006),
+    (0x74a8, 0x1007),
+    (0x74a9, 0x1008),
+    (0x74aa, 0x1009),
+    (0x74ab, 0x100a),
+    (0x74ae, 0x100b),
+    (0x74af, 0x100c),
+    (0x74b1, 0x100d),
+    (0x74b2, 0x100e),
+    (0x74b5, 0x100f),
+    (0x74b9, 0x1010),
+    (0x74bb, 0x1011),
+    (0x74bf, 0x1012),
+    (0x74c8, 0x1013),
+    (0x74c9, 0x1014),
+    (0x74cc, 0x1015),
+    (0x74d0, 0x1016),
+    (0x74d3, 0x1017),
+    (0x74d8, 0x1018),
+    (0x74da, 0x1019),
+    (0x74db, 0x101a),
+    (0x74de, 0x101b),
+    (0x74df, 0x101c),
+    (0x74e4, 0x101d),
+    (0x74e8, 0x101e),
+    (0x74ea, 0x101f),
+    (0x74eb, 0x1020),
+    (0x74ef, 0x1021),
+    (0x74f4, 0x1022),
+    (0x74fa, 0x1023),
+    (0x74fb, 0x1024),
+    (0x74fc, 0x1025),
+    (0x74ff, 0x1026),
+    (0x7506, 0x1027),
+    (0x7512, 0x1028),
+    (0x7516, 0x1029),
+    (0x7517, 0x102a),
+    (0x7520, 0x102b),
+    (0x7521, 0x102c),
+    (0x7524, 0x102d),
+    (0x7527, 0x102e),
+    (0x7529, 0x102f),
+    (0x752a, 0x1030),
+    (0x752f, 0x1031),
+    (0x7536, 0x1032),
+    (0x7539, 0x1033),
+    (0x753d, 0x1034),
+    (0x753e, 0x1035),
+    (0x753f, 0x1036),
+    (0x7540, 0x1037),
+    (0x7543, 0x1038),
+    (0x7547, 0x1039),
+    (0x7548, 0x103a),
+    (0x754e, 0x103b),
+    (0x7550, 0x103c),
+    (0x7552, 0x103d),
+    (0x7557, 0x103e),
+    (0x755e, 0x103f),
+    (0x755f, 0x1040),
+    (0x7561, 0x1041),
+    (0x756f, 0x1042),
+    (0x7571, 0x1043),
+    (0x7579, 0x1044),
+    (0x757a, 0x1045),
+    (0x757b, 0x1046),
+    (0x757c, 0x1047),
+    (0x757d, 0x1048),
+    (0x757e, 0x1049),
+    (0x7581, 0x104a),
+    (0x7585, 0x104b),
+    (0x7590, 0x104c),
+    (0x7592, 0x104d),
+    (0x7593, 0x104e),
+    (0x7595, 0x104f),
+    (0x7599, 0x1050),
+    (0x759c, 0x1051),
+    (0x75a2, 0x1052),
+    (0x75a4, 0x1053),
+    (0x75b4, 0x1054),
+    (0x75ba, 0x1055),
+    (0x75bf, 0x1056),
+    (0x75c0, 0x1057),
+    (0x75c1, 0x1058),
+    (0x75c4, 0x1059),
+    (0x75c6, 0x105a),
+    (0x75cc, 0x105b),
+    (0x75ce, 0x105c),
+    (0x75cf, 0x105d),
+    (0x75d7, 0x105e),
+    (0x75dc, 0x105f),
+    (0x75df, 0x1060),
+    (0x75e0, 0x1061),
+    (0x75e1, 0x1062),
+    (0x75e4, 0x1063),
+    (0x75e7, 0x1064),
+    (0x75ec, 0x1065),
+    (0x75ee, 0x1066),
+    (0x75ef, 0x1067),
+    (0x75f1, 0x1068),
+    (0x75f9, 0x1069),
+    (0x7600, 0x106a),
+    (0x7602, 0x106b),
+    (0x7603, 0x106c),
+    (0x7604, 0x106d),
+    (0x7607, 0x106e),
+    (0x7608, 0x106f),
+    (0x760a, 0x1070),
+    (0x760c, 0x1071),
+    (0x760f, 0x1072),
+    (0x7612, 0x1073),
+    (0x7613, 0x1074),
+    (0x7615, 0x1075),
+    (0x7616, 0x1076),
+    (0x7619, 0x1077),
+    (0x761b, 0x1078),
+    (0x761c, 0x1079),
+    (0x761d, 0x107a),
+    (0x761e, 0x107b),
+    (0x7623, 0x107c),
+    (0x7625, 0x107d),
+    (0x7626, 0x107e),
+    (0x7629, 0x107f),
+    (0x762d, 0x1080),
+    (0x7632, 0x1081),
+    (0x7633, 0x1082),
+    (0x7635, 0x1083),
+    (0x7638, 0x1084),
+    (0x7639, 0x1085),
+    (0x763a, 0x1086),
+    (0x763c, 0x1087),
+    (0x7640, 0x1089),
+    (0x7641, 0x108a),
+    (0x7643, 0x108b),
+    (0x7644, 0x108c),
+    (0x7645, 0x108d),
+    (0x7649, 0x108e),
+    (0x764a, 0x1088),
+    (0x764b, 0x108f),
+    (0x7655, 0x1090),
+    (0x7659, 0x1091),
+    (0x765f, 0x1092),
+    (0x7664, 0x1093),
+    (0x7665, 0x1094),
+    (0x766d, 0x1095),
+    (0x766e, 0x1096),
+    (0x766f, 0x1097),
+    (0x7671, 0x1098),
+    (0x7674, 0x1099),
+    (0x7681, 0x109a),
+    (0x7685, 0x109b),
+    (0x768c, 0x109c),
+    (0x768d, 0x109d),
+    (0x7695, 0x109e),
+    (0x769b, 0x109f),
+    (0x769c, 0x10a0),
+    (0x769d, 0x10a1),
+    (0x769f, 0x10a2),
+    (0x76a0, 0x10a3),
+    (0x76a2, 0x10a4),
+    (0x76a3, 0x10a5),
+    (0x76a4, 0x10a6),
+    (0x76a5, 0x10a7),
+    (0x76a6, 0x10a8),
+    (0x76a7, 0x10a9),
+    (0x76a8, 0x10aa),
+    (0x76aa, 0x10ab),
+    (0x76ad, 0x10ac),
+    (0x76bd, 0x10ad),
+    (0x76c1, 0x10ae),
+    (0x76c5, 0x10af),
+    (0x76c9, 0x10b0),
+    (0x76cb, 0x10b1),
+    (0x76cc, 0x10b2),
+    (0x76ce, 0x10b3),
+    (0x76d4, 0x10b4),
+    (0x76d9, 0x10b5),
+    (0x76e0, 0x10b6),
+    (0x76e6, 0x10b7),
+    (0x76e8, 0x10b8),
+    (0x76ec, 0x10b9),
+    (0x76f0, 0x10ba),
+    (0x76f1, 0x10bb),
+    (0x76f6, 0x10bc),
+    (0x76f9, 0x10bd),
+    (0x76fc, 0x10be),
+    (0x7700, 0x10bf),
+    (0x7706, 0x10c0),
+    (0x770a, 0x10c1),
+    (0x770e, 0x10c2),
+    (0x7712, 0x10c3),
+    (0x7714, 0x10c4),
+    (0x7715, 0x10c5),
+    (0x7717, 0x10c6),
+    (0x7719, 0x10c7),
+    (0x771a, 0x10c8),
+    (0x771c, 0x10c9),
+    (0x7722, 0x10ca),
+    (0x7728, 0x10cb),
+    (0x772d, 0x10cc),
+    (0x772e, 0x10cd),
+    (0x772f, 0x10ce),
+    (0x7734, 0x10cf),
+    (0x7735, 0x10d0),
+    (0x7736, 0x10d1),
+    (0x7739, 0x10d2),
+    (0x773d, 0x10d3),
+    (0x773e, 0x10d4),
+    (0x7742, 0x10d5),
+    (0x7745, 0x10d6),
+    (0x7746, 0x10d7),
+    (0x774a, 0x10d8),
+    (0x774d, 0x10d9),
+    (0x774e, 0x10da),
+    (0x774f, 0x10db),
+    (0x7752, 0x10dc),
+    (0x7756, 0x10dd),
+    (0x7757, 0x10de),
+    (0x775c, 0x10df),
+    (0x775e, 0x10e0),
+    (0x775f, 0x10e1),
+    (0x7760, 0x10e2),
+    (0x7762, 0x10e3),
+    (0x7764, 0x10e4),
+    (0x7767, 0x10e5),
+    (0x776a, 0x10e6),
+    (0x776c, 0x10e7),
+    (0x7770, 0x10e8),
+    (0x7772, 0x10e9),
+    (0x7773, 0x10ea),
+    (0x7774, 0x10eb),
+    (0x777a, 0x10ec),
+    (0x777d, 0x10ed),
+    (0x7780, 0x10ee),
+    (0x7784, 0x10ef),
+    (0x778c, 0x10f0),
+    (0x778d, 0x10f1),
+    (0x7794, 0x10f2),
+    (0x7795, 0x10f3),
+    (0x7796, 0x10f4),
+    (0x779a, 0x10f5),
+    (0x779f, 0x10f6),
+    (0x77a2, 0x10f7),
+    (0x77a7, 0x10f8),
+    (0x77aa, 0x10f9),
+    (0x77ae, 0x10fa),
+    (0x77af, 0x10fb),
+    (0x77b1, 0x10fc),
+    (0x77b5, 0x10fd),
+    (0x77be, 0x10fe),
+    (0x77c3, 0x10ff),
+    (0x77c9, 0x1100),
+    (0x77d1, 0x1101),
+    (0x77d2, 0x1102),
+    (0x77d5, 0x1103),
+    (0x77d9, 0x1104),
+    (0x77de, 0x1105),
+    (0x77df, 0x1106),
+    (0x77e0, 0x1107),
+    (0x77e4, 0x1108),
+    (0x77e6, 0x1109),
+    (0x77ea, 0x110a),
+    (0x77ec, 0x110b),
+    (0x77f0, 0x110c),
+    (0x77f1, 0x110d),
+    (0x77f4, 0x110e),
+    (0x77f8, 0x110f),
+    (0x77fb, 0x1110),
+    (0x7805, 0x1111),
+    (0x7806, 0x1112),
+    (0x7809, 0x1113),
+    (0x780d, 0x1114),
+    (0x780e, 0x1115),
+    (0x7811, 0x1116),
+    (0x781d, 0x1117),
+    (0x7821, 0x1118),
+    (0x7822, 0x1119),
+    (0x7823, 0x111a),
+    (0x782d, 0x111b),
+    (0x782e, 0x111c),
+    (0x7830, 0x111d),
+    (0x7835, 0x111e),
+    (0x7837, 0x111f),
+    (0x7843, 0x1120),
+    (0x7844, 0x1121),
+    (0x7847, 0x1122),
+    (0x7848, 0x1123),
+    (0x784c, 0x1124),
+    (0x784e, 0x1125),
+    (0x7852, 0x1126),
+    (0x785c, 0x1127),
+    (0x785e, 0x1128),
+    (0x7860, 0x1129),
+    (0x7861, 0x112a),
+    (0x7863, 0x112b),
+    (0x7864, 0x112c),
+    (0x7868, 0x112d),
+    (0x786a, 0x112e),
+    (0x786e, 0x112f),
+    (0x787a, 0x1130),
+    (0x787e, 0x1131),
+    (0x788a, 0x1132),
+    (0x788f, 0x1133),
+    (0x7894, 0x1134),
+    (0x7898, 0x1135),
+    (0x789d, 0x1137),
+    (0x789e, 0x1138),
+    (0x789f, 0x1139),
+    (0x78a1, 0x1136),
+    (0x78a4, 0x113a),
+    (0x78a8, 0x113b),
+    (0x78ac, 0x113c),
+    (0x78ad, 0x113d),
+    (0x78b0, 0x113e),
+    (0x78b1, 0x113f),
+    (0x78b2, 0x1140),
+    (0x78b3, 0x1141),
+    (0x78bb, 0x1142),
+    (0x78bd, 0x1143),
+    (0x78bf, 0x1144),
+    (0x78c7, 0x1145),
+    (0x78c8, 0x1146),
+    (0x78c9, 0x1147),
+    (0x78cc, 0x1148),
+    (0x78ce, 0x1149),
+    (0x78d2, 0x114a),
+    (0x78d3, 0x114b),
+    (0x78d5, 0x114c),
+    (0x78d6, 0x114d),
+    (0x78db, 0x114f),
+    (0x78df, 0x1150),
+    (0x78e0, 0x1151),
+    (0x78e1, 0x1152),
+    (0x78e4, 0x114e),
+    (0x78e6, 0x1153),
+    (0x78ea, 0x1154),
+    (0x78f2, 0x1155),
+    (0x78f3, 0x1156),
+    (0x78f6, 0x1158),
+    (0x78f7, 0x1159),
+    (0x78fa, 0x115a),
+    (0x78fb, 0x115b),
+    (0x78ff, 0x115c),
+    (0x7900, 0x1157),
+    (0x7906, 0x115d),
+    (0x790c, 0x115e),
+    (0x7910, 0x115f),
+    (0x791a, 0x1160),
+    (0x791c, 0x1161),
+    (0x791e, 0x1162),
+    (0x791f, 0x1163),
+    (0x7920, 0x1164),
+    (0x7925, 0x1165),
+    (0x7927, 0x1166),
+    (0x7929, 0x1167),
+    (0x792d, 0x1168),
+    (0x7931, 0x1169),
+    (0x7934, 0x116a),
+    (0x7935, 0x116b),
+    (0x793b, 0x116c),
+    (0x793d, 0x116d),
+    (0x793f, 0x116e),
+    (0x7944, 0x116f),
+    (0x7945, 0x1170),
+    (0x7946, 0x1171),
+    (0x794a, 0x1172),
+    (0x794b, 0x1173),
+    (0x794f, 0x1174),
+    (0x7951, 0x1175),
+    (0x7954, 0x1176),
+    (0x7958, 0x1177),
+    (0x795b, 0x1178),
+    (0x795c, 0x1179),
+    (0x7967, 0x117a),
+    (0x7969, 0x117b),
+    (0x796b, 0x117c),
+    (0x7972, 0x117d),
+    (0x7979, 0x117e),
+    (0x797b, 0x117f),
+    (0x797c, 0x1180),
+    (0x797e, 0x1181),
+    (0x798b, 0x1182),
+    (0x798c, 0x1183),
+    (0x7991, 0x1184),
+    (0x7993, 0x1185),
+    (0x7994, 0x1186),
+    (0x7995, 0x1187),
+    (0x7996, 0x1188),
+    (0x7998, 0x1189),
+    (0x799b, 0x118a),
+    (0x799c, 0x118b),
+    (0x79a1, 0x118c),
+    (0x79a8, 0x118d),
+    (0x79a9, 0x118e),
+    (0x79ab, 0x118f),
+    (0x79af, 0x1190),
+    (0x79b1, 0x1191),
+    (0x79b4, 0x1192),
+    (0x79b8, 0x1193),
+    (0x79bb, 0x1194),
+    (0x79c2, 0x1195),
+    (0x79c4, 0x1196),
+    (0x79c7, 0x1197),
+    (0x79c8, 0x1198),
+    (0x79ca, 0x1199),
+    (0x79cf, 0x119a),
+    (0x79d4, 0x119b),
+    (0x79d6, 0x119c),
+    (0x79da, 0x119d),
+    (0x79dd, 0x119e),
+    (0x79de, 0x119f),
+    (0x79e0, 0x11a0),
+    (0x79e2, 0x11a1),
+    (0x79e5, 0x11a2),
+    (0x79ea, 0x11a3),
+    (0x79eb, 0x11a4),
+    (0x79ed, 0x11a5),
+    (0x79f1, 0x11a6),
+    (0x79f8, 0x11a7),
+    (0x79fc, 0x11a8),
+    (0x7a02, 0x11a9),
+    (0x7a03, 0x11aa),
+    (0x7a07, 0x11ab),
+    (0x7a09, 0x11ac),
+    (0x7a0a, 0x11ad),
+    (0x7a0c, 0x11ae),
+    (0x7a11, 0x11af),
+    (0x7a15, 0x11b0),
+    (0x7a1b, 0x11b1),
+    (0x7a1e, 0x11b2),
+    (0x7a21, 0x11b3),
+    (0x7a27, 0x11b4),
+    (0x7a2b, 0x11b5),
+    (0x7a2d, 0x11b6),
+    (0x7a2f, 0x11b7),
+    (0x7a30, 0x11b8),
+    (0x7a34, 0x11b9),
+    (0x7a35, 0x11ba),
+    (0x7a38, 0x11bb),
+    (0x7a39, 0x11bc),
+    (0x7a3a, 0x11bd),
+    (0x7a44, 0x11be),
+    (0x7a45, 0x11bf),
+    (0x7a47, 0x11c0),
+    (0x7a48, 0x11c1),
+    (0x7a4c, 0x11c2),
+    (0x7a55, 0x11c3),
+    (0x7a56, 0x11c4),
+    (0x7a59, 0x11c5),
+    (0x7a5c, 0x11c6),
+    (0x7a5d, 0x11c7),
+    (0x7a5f, 0x11c8),
+    (0x7a60, 0x11c9),
+    (0x7a65, 0x11ca),
+    (0x7a67, 0x11cb),
+    (0x7a6a, 0x11cc),
+    (0x7a6d, 0x11cd),
+    (0x7a75, 0x11ce),
+    (0x7a78, 0x11cf),
+    (0x7a7e, 0x11d0),
+    (0x7a80, 0x11d1),
+    (0x7a82, 0x11d2),
+    (0x7a85, 0x11d3),
+    (0x7a86, 0x11d4),
+    (0x7a8a, 0x11d5),
+    (0x7a8b, 0x11d6),
+    (0x7a90, 0x11d7),
+    (0x7a91, 0x11d8),
+    (0x7a94, 0x11d9),
+    (0x7a9e, 0x11da),
+    (0x7aa0, 0x11db),
+    (0x7aa3, 0x11dc),
+    (0x7aac, 0x11dd),
+    (0x7ab3, 0x11de),
+    (0x7ab5, 0x11df),
+    (0x7ab9, 0x11e0),
+    (0x7abb, 0x11e1),
+    (0x7abc, 0x11e2),
+    (0x7ac6, 0x11e3),
+    (0x7ac9, 0x11e4),
+    (0x7acc, 0x11e5),
+    (0x7ace, 0x11e6),
+    (0x7ad1, 0x11e7),
+    (0x7adb, 0x11e8),
+    (0x7ae8, 0x11e9),
+    (0x7ae9, 0x11ea),
+    (0x7aeb, 0x11eb),
+    (0x7aec, 0x11ec),
+    (0x7af1, 0x11ed),
+    (0x7af4, 0x11ee),
+    (0x7afb, 0x11ef),
+    (0x7afd, 0x11f0),
+    (0x7afe, 0x11f1),
+    (0x7b07, 0x11f2),
+    (0x7b14, 0x11f3),
+    (0x7b1f, 0x11f4),
+    (0x7b23, 0x11f5),
+    (0x7b27, 0x11f6),
+    (0x7b29, 0x11f7),
+    (0x7b2a, 0x11f8),
+    (0x7b2b, 0x11f9),
+    (0x7b2d, 0x11fa),
+    (0x7b2e, 0x11fb),
+    (0x7b2f, 0x11fc),
+    (0x7b30, 0x11fd),
+    (0x7b31, 0x11fe),
+    (0x7b34, 0x11ff),
+    (0x7b3d, 0x1200),
+    (0x7b3f, 0x1201),
+    (0x7b40, 0x1202),
+    (0x7b41, 0x1203),
+    (0x7b47, 0x1204),
+    (0x7b4e, 0x1205),
+    (0x7b55, 0x1206),
+    (0x7b60, 0x1207),
+    (0x7b64, 0x1208),
+    (0x7b66, 0x1209),
+    (0x7b69, 0x120a),
+    (0x7b6a, 0x120b),
+    (0x7b6d, 0x120c),
+    (0x7b6f, 0x120d),
+    (0x7b72, 0x120e),
+    (0x7b73, 0x120f),
+    (0x7b77, 0x1210),
+    (0x7b84, 0x1211),
+    (0x7b89, 0x1212),
+    (0x7b8e, 0x1213),
+    (0x7b90, 0x1214),
+    (0x7b91, 0x1215),
+    (0x7b96, 0x1216),
+    (0x7b9b, 0x1217),
+    (0x7b9e, 0x1218),
+    (0x7ba0, 0x1219),
+    (0x7ba5, 0x121a),
+    (0x7bac, 0x121b),
+    (0x7baf, 0x121c),
+    (0x7bb0, 0x121d),
+    (0x7bb2, 0x121e),
+    (0x7bb5, 0x121f),
+    (0x7bb6, 0x1220),
+    (0x7bba, 0x1221),
+    (0x7bbb, 0x1222),
+    (0x7bbc, 0x1223),
+    (0x7bbd, 0x1224),
+    (0x7bc2, 0x1225),
+    (0x7bc5, 0x1226),
+    (0x7bc8, 0x1227),
+    (0x7bca, 0x1228),
+    (0x7bd4, 0x1229),
+    (0x7bd6, 0x122a),
+    (0x7bd7, 0x122b),
+    (0x7bd9, 0x122c),
+    (0x7bda, 0x122d),
+    (0x7bdb, 0x122e),
+    (0x7be8, 0x122f),
+    (0x7bea, 0x1230),
+    (0x7bf2, 0x1231),
+    (0x7bf4, 0x1232),
+    (0x7bf5, 0x1233),
+    (0x7bf8, 0x1234),
+    (0x7bf9, 0x1235),
+    (0x7bfa, 0x1236),
+    (0x7bfc, 0x1237),
+    (0x7bfe, 0x1238),
+    (0x7c01, 0x1239),
+    (0x7c02, 0x123a),
+    (0x7c03, 0x123b),
+    (0x7c04, 0x123c),
+    (0x7c06, 0x123d),
+    (0x7c09, 0x123e),
+    (0x7c0b, 0x123f),
+    (0x7c0c, 0x1240),
+    (0x7c0e, 0x1241),
+    (0x7c0f, 0x1242),
+    (0x7c19, 0x1243),
+    (0x7c1b, 0x1244),
+    (0x7c20, 0x1245),
+    (0x7c25, 0x1246),
+    (0x7c26, 0x1247),
+    (0x7c28, 0x1248),
+    (0x7c2c, 0x1249),
+    (0x7c31, 0x124a),
+    (0x7c33, 0x124b),
+    (0x7c34, 0x124c),
+    (0x7c36, 0x124d),
+    (0x7c39, 0x124e),
+    (0x7c3a, 0x124f),
+    (0x7c46, 0x1250),
+    (0x7c4a, 0x1251),
+    (0x7c51, 0x1253),
+    (0x7c52, 0x1254),
+    (0x7c53, 0x1255),
+    (0x7c55, 0x1252),
+    (0x7c59, 0x1256),
+    (0x7c5a, 0x1257),
+    (0x7c5b, 0x1258),
+    (0x7c5c, 0x1259),
+    (0x7c5d, 0x125a),
+    (0x7c5e, 0x125b),
+    (0x7c61, 0x125c),
+    (0x7c63, 0x125d),
+    (0x7c67, 0x125e),
+    (0x7c69, 0x125f),
+    (0x7c6d, 0x1260),
+    (0x7c6e, 0x1261),
+    (0x7c70, 0x1262),
+    (0x7c72, 0x1263),
+    (0x7c79, 0x1264),
+    (0x7c7c, 0x1265),
+    (0x7c7d, 0x1266),
+    (0x7c86, 0x1267),
+    (0x7c87, 0x1268),
+    (0x7c8f, 0x1269),
+    (0x7c94, 0x126a),
+    (0x7c9e, 0x126b),
+    (0x7ca0, 0x126c),
+    (0x7ca6, 0x126d),
+    (0x7cb0, 0x126e),
+    (0x7cb6, 0x126f),
+    (0x7cb7, 0x1270),
+    (0x7cba, 0x1271),
+    (0x7cbb, 0x1272),
+    (0x7cbc, 0x1273),
+    (0x7cbf, 0x1274),
+    (0x7cc4, 0x1275),
+    (0x7cc7, 0x1276),
+    (0x7cc8, 0x1277),
+    (0x7cc9, 0x1278),
+    (0x7ccd, 0x1279),
+    (0x7ccf, 0x127a),
+    (0x7cd3, 0x127b),
+    (0x7cd4, 0x127c),
+    (0x7cd5, 0x127d),
+    (0x7cd7, 0x127e),
+    (0x7cd9, 0x127f),
+    (0x7cda, 0x1280),
+    (0x7cdd, 0x1281),
+    (0x7ce6, 0x1282),
+    (0x7ce9, 0x1283),
+    (0x7ceb, 0x1284),
+    (0x7cf5, 0x1285),
+    (0x7d03, 0x1286),
+    (0x7d07, 0x1287),
+    (0x7d08, 0x1288),
+    (0x7d09, 0x1289),
+    (0x7d0f, 0x128a),
+    (0x7d11, 0x128b),
+    (0x7d12, 0x128c),
+    (0x7d13, 0x128d),
+    (0x7d16, 0x128e),
+    (0x7d1d, 0x128f),
+    (0x7d1e, 0x1290),
+    (0x7d23, 0x1291),
+    (0x7d26, 0x1292),
+    (0x7d2a, 0x1293),
+    (0x7d2d, 0x1294),
+    (0x7d31, 0x1295),
+    (0x7d3c, 0x1296),
+    (0x7d3d, 0x1297),
+    (0x7d3e, 0x1298),
+    (0x7d40, 0x1299),
+    (0x7d41, 0x129a),
+    (0x7d47, 0x129b),
+    (0x7d48, 0x129c),
+    (0x7d4d, 0x129d),
+    (0x7d51, 0x129e),
+    (0x7d53, 0x129f),
+    (0x7d57, 0x12a0),
+    (0x7d59, 0x12a1),
+    (0x7d5a, 0x12a2),
+    (0x7d5c, 0x12a3),
+    (0x7d5d, 0x12a4),
+    (0x7d65, 0x12a5),
+    (0x7d67, 0x12a6),
+    (0x7d6a, 0x12a7),
+    (0x7d70, 0x12a8),
+    (0x7d78, 0x12a9),
+    (0x7d7a, 0x12aa),
+    (0x7d7b, 0x12ab),
+    (0x7d7f, 0x12ac),
+    (0x7d81, 0x12ad),
+    (0x7d82, 0x12ae),
+    (0x7d83, 0x12af),
+    (0x7d85, 0x12b0),
+    (0x7d86, 0x12b1),
+    (0x7d88, 0x12b2),
+    (0x7d8b, 0x12b3),
+    (0x7d8c, 0x12b4),
+    (0x7d8d, 0x12b5),
+    (0x7d91, 0x12b6),
+    (0x7d96, 0x12b7),
+    (0x7d97, 0x12b8),
+    (0x7d9d, 0x12b9),
+    (0x7d9e, 0x12ba),
+    (0x7da6, 0x12bb),
+    (0x7da7, 0x12bc),
+    (0x7daa, 0x12bd),
+    (0x7db3, 0x12be),
+    (0x7db6, 0x12bf),
+    (0x7db7, 0x12c0),
+    (0x7db9, 0x12c1),
+    (0x7dc2, 0x12c2),
+    (0x7dc3, 0x12c3),
+    (0x7dc4, 0x12c4),
+    (0x7dc5, 0x12c5),
+    (0x7dc6, 0x12c6),
+    (0x7dcc, 0x12c7),
+    (0x7dcd, 0x12c8),
+    (0x7dce, 0x12c9),
+    (0x7dd7, 0x12ca),
+    (0x7dd9, 0x12cb),
+    (0x7de2, 0x12cd),
+    (0x7de5, 0x12ce),
+    (0x7de6, 0x12cf),
+    (0x7dea, 0x12d0),
+    (0x7deb, 0x12d1),
+    (0x7ded, 0x12d2),
+    (0x7df1, 0x12d3),
+    (0x7df5, 0x12d4),
+    (0x7df6, 0x12d5),
+    (0x7df9, 0x12d6),
+    (0x7dfa, 0x12d7),
+    (0x7e00, 0x12cc),
+    (0x7e08, 0x12d8),
+    (0x7e10, 0x12d9),
+    (0x7e11, 0x12da),
+    (0x7e15, 0x12db),
+    (0x7e17, 0x12dc),
+    (0x7e1c, 0x12dd),
+    (0x7e1d, 0x12de),
+    (0x7e20, 0x12df),
+    (0x7e27, 0x12e0),
+    (0x7e28, 0x12e1),
+    (0x7e2c, 0x12e2),
+    (0x7e2d, 0x12e3),
+    (0x7e2f, 0x12e4),
+    (0x7e33, 0x12e5),
+    (0x7e36, 0x12e6),
+    (0x7e3f, 0x12e7),
+    (0x7e44, 0x12e8),
+    (0x7e45, 0x12e9),
+    (0x7e47, 0x12ea),
+    (0x7e4e, 0x12eb),
+    (0x7e50, 0x12ec),
+    (0x7e52, 0x12ed),
+    (0x7e58, 0x12ee),
+    (0x7e5f, 0x12ef),
+    (0x7e61, 0x12f0),
+    (0x7e62, 0x12f1),
+    (0x7e65, 0x12f2),
+    (0x7e6b, 0x12f3),
+    (0x7e6e, 0x12f4),
+    (0x7e6f, 0x12f5),
+    (0x7e73, 0x12f6),
+    (0x7e78, 0x12f7),
+    (0x7e7e, 0x12f8),
+    (0x7e81, 0x12f9),
+    (0x7e86, 0x12fa),
+    (0x7e87, 0x12fb),
+    (0x7e8a, 0x12fc),
+    (0x7e8d, 0x12fd),
+    (0x7e91, 0x12fe),
+    (0x7e95, 0x12ff),
+    (0x7e98, 0x1300),
+    (0x7e9a, 0x1301),
+    (0x7e9d, 0x1302),
+    (0x7e9e, 0x1303),
+    (0x7f3b, 0x1305),
+    (0x7f3c, 0x1304),
+    (0x7f3d, 0x1306),
+    (0x7f3e, 0x1307),
+    (0x7f3f, 0x1308),
+    (0x7f43, 0x1309),
+    (0x7f44, 0x130a),
+    (0x7f47, 0x130b),
+    (0x7f4f, 0x130c),
+    (0x7f52, 0x130d),
+    (0x7f53, 0x130e),
+    (0x7f5b, 0x130f),
+    (0x7f5c, 0x1310),
+    (0x7f5d, 0x1311),
+    (0x7f61, 0x1312),
+    (0x7f63, 0x1313),
+    (0x7f64, 0x1314),
+    (0x7f65, 0x1315),
+    (0x7f66, 0x1316),
+    (0x7f6d, 0x1317),
+    (0x7f71, 0x1318),
+    (0x7f7d, 0x1319),
+    (0x7f7e, 0x131a),
+    (0x7f7f, 0x131b),
+    (0x7f80, 0x131c),
+    (0x7f8b, 0x131d),
+    (0x7f8d, 0x131e),
+    (0x7f8f, 0x131f),
+    (0x7f90, 0x1320),
+    (0x7f91, 0x1321),
+    (0x7f96, 0x1322),
+    (0x7f97, 0x1323),
+    (0x7f9c, 0x1324),
+    (0x7fa1, 0x1325),
+    (0x7fa2, 0x1326),
+    (0x7fa6, 0x1327),
+    (0x7faa, 0x1328),
+    (0x7fad, 0x1329),
+    (0x7fb4, 0x132a),
+    (0x7fbc, 0x132b),
+    (0x7fbf, 0x132c),
+    (0x7fc0, 0x132d),
+    (0x7fc3, 0x132e),
+    (0x7fc8, 0x132f),
+    (0x7fce, 0x1330),
+    (0x7fcf, 0x1331),
+    (0x7fdb, 0x1332),
+    (0x7fdf, 0x1333),
+    (0x7fe3, 0x1334),
+    (0x7fe5, 0x1335),
+    (0x7fe8, 0x1336),
+    (0x7fec, 0x1337),
+    (0x7fee, 0x1338),
+    (0x7fef, 0x1339),
+    (0x7ff2, 0x133a),
+    (0x7ffa, 0x133b),
+    (0x7ffd, 0x133c),
+    (0x7ffe, 0x133d),
+    (0x7fff, 0x133e),
+    (0x8007, 0x133f),
+    (0x8008, 0x1340),
+    (0x800a, 0x1341),
+    (0x800d, 0x1342),
+    (0x800e, 0x1343),
+    (0x800f, 0x1344),
+    (0x8011, 0x1345),
+    (0x8013, 0x1346),
+    (0x8014, 0x1347),
+    (0x8016, 0x1348),
+    (0x801d, 0x1349),
+    (0x801e, 0x134a),
+    (0x801f, 0x134b),
+    (0x8020, 0x134c),
+    (0x8024, 0x134d),
+    (0x8026, 0x134e),
+    (0x802c, 0x134f),
+    (0x802e, 0x1350),
+    (0x8030, 0x1351),
+    (0x8034, 0x1352),
+    (0x8035, 0x1353),
+    (0x8037, 0x1354),
+    (0x8039, 0x1355),
+    (0x803a, 0x1356),
+    (0x803c, 0x1357),
+    (0x803e, 0x1358),
+    (0x8040, 0x1359),
+    (0x8044, 0x135a),
+    (0x8060, 0x135b),
+    (0x8064, 0x135c),
+    (0x8066, 0x135d),
+    (0x806d, 0x135e),
+    (0x8071, 0x135f),
+    (0x8075, 0x1360),
+    (0x8081, 0x1361),
+    (0x8088, 0x1362),
+    (0x808e, 0x1363),
+    (0x809c, 0x1364),
+    (0x809e, 0x1365),
+    (0x80a6, 0x1366),
+    (0x80a7, 0x1367),
+    (0x80ab, 0x1368),
+    (0x80b8, 0x1369),
+    (0x80b9, 0x136a),
+    (0x80c8, 0x136b),
+    (0x80cd, 0x136c),
+    (0x80cf, 0x136d),
+    (0x80d2, 0x136e),
+    (0x80d4, 0x136f),
+    (0x80d5, 0x1370),
+    (0x80d7, 0x1371),
+    (0x80d8, 0x1372),
+    (0x80e0, 0x1373),
+    (0x80ed, 0x1374),
+    (0x80ee, 0x1375),
+    (0x80f0, 0x1376),
+    (0x80f2, 0x1377),
+    (0x80f3, 0x1378),
+    (0x80f6, 0x1379),
+    (0x80f9, 0x137a),
+    (0x80fa, 0x137b),
+    (0x80fe, 0x137c),
+    (0x8103, 0x137d),
+    (0x810b, 0x137e),
+    (0x8116, 0x137f),
+    (0x8117, 0x1380),
+    (0x8118, 0x1381),
+    (0x811c, 0x1382),
+    (0x811e, 0x1383),
+    (0x8120, 0x1384),
+    (0x8124, 0x1385),
+    (0x8127, 0x1386),
+    (0x812c, 0x1387),
+    (0x8130, 0x1388),
+    (0x8135, 0x1389),
+    (0x813a, 0x138a),
+    (0x813c, 0x138b),
+    (0x8145, 0x138c),
+    (0x8147, 0x138d),
+    (0x814a, 0x138e),
+    (0x814c, 0x138f),
+    (0x8152, 0x1390),
+    (0x8157, 0x1391),
+    (0x8160, 0x1392),
+    (0x8161, 0x1393),
+    (0x8167, 0x1394),
+    (0x8168, 0x1395),
+    (0x8169, 0x1396),
+    (0x816d, 0x1397),
+    (0x816f, 0x1398),
+    (0x8177, 0x1399),
+    (0x8181, 0x139a),
+    (0x8184, 0x139c),
+    (0x8185, 0x139d),
+    (0x8186, 0x139e),
+    (0x818b, 0x139f),
+    (0x818e, 0x13a0),
+    (0x8190, 0x139b),
+    (0x8196, 0x13a1),
+    (0x8198, 0x13a2),
+    (0x819b, 0x13a3),
+    (0x819e, 0x13a4),
+    (0x81a2, 0x13a5),
+    (0x81ae, 0x13a6),
+    (0x81b2, 0x13a7),
+    (0x81b4, 0x13a8),
+    (0x81bb, 0x13a9),
+    (0x81c3, 0x13ab),
+    (0x81c5, 0x13ac),
+    (0x81ca, 0x13ad),
+    (0x81cb, 0x13aa),
+    (0x81ce, 0x13ae),
+    (0x81cf, 0x13af),
+    (0x81d5, 0x13b0),
+    (0x81d7, 0x13b1),
+    (0x81db, 0x13b2),
+    (0x81dd, 0x13b3),
+    (0x81de, 0x13b4),
+    (0x81e1, 0x13b5),
+    (0x81e4, 0x13b6),
+    (0x81eb, 0x13b7),
+    (0x81ec, 0x13b8),
+    (0x81f0, 0x13b9),
+    (0x81f1, 0x13ba),
+    (0x81f2, 0x13bb),
+    (0x81f5, 0x13bc),
+    (0x81f6, 0x13bd),
+    (0x81f8, 0x13be),
+    (0x81f9, 0x13bf),
+    (0x81fd, 0x13c0),
+    (0x81ff, 0x13c1),
+    (0x8200, 0x13c2),
+    (0x8203, 0x13c3),
+    (0x820f, 0x13c4),
+    (0x8213, 0x13c5),
+    (0x8214, 0x13c6),
+    (0x8219, 0x13c7),
+    (0x821a, 0x13c8),
+    (0x821d, 0x13c9),
+    (0x8221, 0x13ca),
+    (0x8222, 0x13cb),
+    (0x8228, 0x13cc),
+    (0x8232, 0x13cd),
+    (0x8234, 0x13ce),
+    (0x823a, 0x13cf),
+    (0x8243, 0x13d0),
+    (0x8244, 0x13d1),
+    (0x8245, 0x13d2),
+    (0x8246, 0x13d3),
+    (0x824b, 0x13d4),
+    (0x824e, 0x13d5),
+    (0x824f, 0x13d6),
+    (0x8251, 0x13d7),
+    (0x8256, 0x13d8),
+    (0x825c, 0x13d9),
+    (0x8260, 0x13da),
+    (0x8263, 0x13db),
+    (0x8267, 0x13dc),
+    (0x826d, 0x13dd),
+    (0x8274, 0x13de),
+    (0x827b, 0x13df),
+    (0x827d, 0x13e0),
+    (0x827f, 0x13e1),
+    (0x8280, 0x13e2),
+    (0x8281, 0x13e3),
+    (0x8283, 0x13e4),
+    (0x8284, 0x13e5),
+    (0x8287, 0x13e6),
+    (0x8289, 0x13e7),
+    (0x828a, 0x13e8),
+    (0x828e, 0x13e9),
+    (0x8291, 0x13ea),
+    (0x8294, 0x13eb),
+    (0x8296, 0x13ec),
+    (0x8298, 0x13ed),
+    (0x829a, 0x13ee),
+    (0x829b, 0x13ef),
+    (0x82a0, 0x13f0),
+    (0x82a1, 0x13f1),
+    (0x82a3, 0x13f2),
+    (0x82a4, 0x13f3),
+    (0x82a7, 0x13f4),
+    (0x82a8, 0x13f5),
+    (0x82a9, 0x13f6),
+    (0x82aa, 0x13f7),
+    (0x82ae, 0x13f8),
+    (0x82b0, 0x13f9),
+    (0x82b2, 0x13fa),
+    (0x82b4, 0x13fb),
+    (0x82b7, 0x13fc),
+    (0x82ba, 0x13fd),
+    (0x82bc, 0x13fe),
+    (0x82be, 0x13ff),
+    (0x82bf, 0x1400),
+    (0x82c6, 0x1401),
+    (0x82d0, 0x1402),
+    (0x82d5, 0x1403),
+    (0x82da, 0x1404),
+    (0x82e0, 0x1405),
+    (0x82e2, 0x1406),
+    (0x82e4, 0x1407),
+    (0x82e8, 0x1408),
+    (0x82ea, 0x1409),
+    (0x82ed, 0x140a),
+    (0x82ef, 0x140b),
+    (0x82f6, 0x140c),
+    (0x82f7, 0x140d),
+    (0x82fd, 0x140e),
+    (0x82fe, 0x140f),
+    (0x8300, 0x1410),
+    (0x8301, 0x1411),
+    (0x8307, 0x1412),
+    (0x8308, 0x1413),
+    (0x830a, 0x1414),
+    (0x830b, 0x1415),
+    (0x831b, 0x1417),
+    (0x831d, 0x1418),
+    (0x831e, 0x1419),
+    (0x831f, 0x141a),
+    (0x8321, 0x141b),
+    (0x8322, 0x141c),
+    (0x832c, 0x141d),
+    (0x832d, 0x141e),
+    (0x832e, 0x141f),
+    (0x8330, 0x1420),
+    (0x8333, 0x1421),
+    (0x8337, 0x1422),
+    (0x833a, 0x1423),
+    (0x833c, 0x1424),
+    (0x833d, 0x1425),
+    (0x8342, 0x1426),
+    (0x8343, 0x1427),
+    (0x8344, 0x1428),
+    (0x8347, 0x1429),
+    (0x834d, 0x142a),
+    (0x834e, 0x142b),
+    (0x8351, 0x142c),
+    (0x8353, 0x144f),
+    (0x8354, 0x1416),
+    (0x8355, 0x142d),
+    (0x8356, 0x142e),
+    (0x8357, 0x142f),
+    (0x8370, 0x1430),
+    (0x8378, 0x1431),
+    (0x837d, 0x1432),
+    (0x837f, 0x1433),
+    (0x8380, 0x1434),
+    (0x8382, 0x1435),
+    (0x8384, 0x1436),
+    (0x8386, 0x1437),
+    (0x838d, 0x1438),
+    (0x8392, 0x1439),
+    (0x8394, 0x143a),
+    (0x8395, 0x143b),
+    (0x8398, 0x143c),
+    (0x8399, 0x143d),
+    (0x839b, 0x143e),
+    (0x839c, 0x143f),
+    (0x839d, 0x1440),
+    (0x83a6, 0x1441),
+    (0x83a7, 0x1442),
+    (0x83a9, 0x1443),
+    (0x83ac, 0x1444),
+    (0x83ad, 0x145d),
+    (0x83be, 0x1445),
+    (0x83bf, 0x1446),
+    (0x83c0, 0x1447),
+    (0x83c7, 0x1448),
+    (0x83c9, 0x1449),
+    (0x83cf, 0x144a),
+    (0x83d0, 0x144b),
+    (0x83d1, 0x144c),
+    (0x83d4, 0x144d),
+    (0x83dd, 0x144e),
+    (0x83e8, 0x1450),
+    (0x83ea, 0x1451),
+    (0x83f6, 0x1452),
+    (0x83f8, 0x1453),
+    (0x83f9, 0x1454),
+    (0x83fc, 0x1455),
+    (0x8401, 0x1456),
+    (0x8406, 0x1457),
+    (0x840a, 0x1458),
+    (0x840f, 0x1459),
+    (0x8411, 0x145a),
+    (0x8415, 0x145b),
+    (0x8419, 0x145c),
+    (0x842f, 0x145e),
+    (0x8439, 0x145f),
+    (0x8445, 0x1460),
+    (0x8447, 0x1461),
+    (0x8448, 0x1462),
+    (0x844a, 0x1463),
+    (0x844d, 0x1464),
+    (0x844f, 0x1465),
+    (0x8451, 0x1466),
+    (0x8452, 0x1467),
+    (0x8456, 0x1468),
+    (0x8458, 0x1469),
+    (0x8459, 0x146a),
+    (0x845a, 0x146b),
+    (0x845c, 0x146c),
+    (0x8460, 0x146d),
+    (0x8464, 0x146e),
+    (0x8465, 0x146f),
+    (0x8467, 0x1470),
+    (0x846a, 0x1471),
+    (0x8470, 0x1472),
+    (0x8473, 0x1473),
+    (0x8474, 0x1474),
+    (0x8476, 0x1475),
+    (0x8478, 0x1476),
+    (0x847c, 0x1477),
+    (0x847d, 0x1478),
+    (0x8481, 0x1479),
+    (0x8485, 0x147a),
+    (0x8492, 0x147b),
+    (0x8493, 0x147c),
+    (0x8495, 0x147d),
+    (0x849e, 0x147e),
+    (0x84a6, 0x147f),
+    (0x84a8, 0x1480),
+    (0x84a9, 0x1481),
+    (0x84aa, 0x1482),
+    (0x84af, 0x1483),
+    (0x84b1, 0x1484),
+    (0x84b4, 0x1485),
+    (0x84ba, 0x1486),
+    (0x84bd, 0x1487),
+    (0x84be, 0x1488),
+    (0x84c0, 0x1489),
+    (0x84c2, 0x148a),
+    (0x84c7, 0x148b),
+    (0x84c8, 0x148c),
+    (0x84cc, 0x148d),
+    (0x84cf, 0x148e),
+    (0x84d3, 0x148f),
+    (0x84dc, 0x1490),
+    (0x84e7, 0x1491),
+    (0x84ea, 0x1492),
+    (0x84ef, 0x1493),
+    (0x84f0, 0x1494),
+    (0x84f1, 0x1495),
+    (0x84f2, 0x1496),
+    (0x84f7, 0x1497),
+    (0x84fa, 0x1499),
+    (0x84fb, 0x149a),
+    (0x84fd, 0x149b),
+    (0x8502, 0x149c),
+    (0x8503, 0x149d),
+    (0x8507, 0x149e),
+    (0x850c, 0x149f),
+    (0x850e, 0x14a0),
+    (0x8510, 0x14a1),
+    (0x851c, 0x14a2),
+    (0x851e, 0x14a3),
+    (0x8522, 0x14a4),
+    (0x8523, 0x14a5),
+    (0x8524, 0x14a6),
+    (0x8525, 0x14a7),
+    (0x8527, 0x14a8),
+    (0x852a, 0x14a9),
+    (0x852b, 0x14aa),
+    (0x852f, 0x14ab),
+    (0x8532, 0x1498),
+    (0x8533, 0x14ac),
+    (0x8534, 0x14ad),
+    (0x8536, 0x14ae),
+    (0x853f, 0x14af),
+    (0x8546, 0x14b0),
+    (0x854f, 0x14b1),
+    (0x8550, 0x14b2),
+    (0x8551, 0x14b3),
+    (0x8552, 0x14b4),
+    (0x8553, 0x14b5),
+    (0x8556, 0x14b6),
+    (0x8559, 0x14b7),
+    (0x855c, 0x14b8),
+    (0x855d, 0x14b9),
+    (0x855e, 0x14ba),
+    (0x855f, 0x14bb),
+    (0x8560, 0x14bc),
+    (0x8561, 0x14bd),
+    (0x8562, 0x14be),
+    (0x8564, 0x14bf),
+    (0x856b, 0x14c0),
+    (0x856f, 0x14c1),
+    (0x8579, 0x14c2),
+    (0x857a, 0x14c3),
+    (0x857b, 0x14c4),
+    (0x857d, 0x14c5),
+    (0x857f, 0x14c6),
+    (0x8581, 0x14c7),
+    (0x8585, 0x14c8),
+    (0x8586, 0x14c9),
+    (0x8589, 0x14ca),
+    (0x858b, 0x14cb),
+    (0x858c, 0x14cc),
+    (0x858f, 0x14cd),
+    (0x8593, 0x14ce),
+    (0x8598, 0x14cf),
+    (0x859d, 0x14d0),
+    (0x859f, 0x14d1),
+    (0x85a0, 0x14d2),
+    (0x85a2, 0x14d3),
+    (0x85a5, 0x14d4),
+    (0x85a7, 0x14d5),
+    (0x85ad, 0x14e3),
+    (0x85b4, 0x14d6),
+    (0x85b6, 0x14d7),
+    (0x85b7, 0x14d8),
+    (0x85b8, 0x14d9),
+    (0x85bc, 0x14da),
+    (0x85bd, 0x14db),
+    (0x85be, 0x14dc),
+    (0x85bf, 0x14dd),
+    (0x85c2, 0x14de),
+    (0x85c7, 0x14df),
+    (0x85ca, 0x14e0),
+    (0x85cb, 0x14e1),
+    (0x85ce, 0x14e2),
+    (0x85d8, 0x14e4),
+    (0x85da, 0x14e5),
+    (0x85df, 0x14e6),
+    (0x85e0, 0x14e7),
+    (0x85e6, 0x14e8),
+    (0x85e8, 0x14e9),
+    (0x85ed, 0x14ea),
+    (0x85f3, 0x14eb),
+    (0x85f6, 0x14ec),
+    (0x85fc, 0x14ed),
+    (0x85ff, 0x14ee),
+    (0x8600, 0x14ef),
+    (0x8604, 0x14f0),
+    (0x8605, 0x14f1),
+    (0x860d, 0x14f2),
+    (0x860e, 0x14f3),
+    (0x8610, 0x14f4),
+    (0x8611, 0x14f5),
+    (0x8612, 0x14f6),
+    (0x8618, 0x14f7),
+    (0x8619, 0x14f8),
+    (0x861b, 0x14f9),
+    (0x861e, 0x14fa),
+    (0x8621, 0x14fb),
+    (0x8627, 0x14fc),
+    (0x8629, 0x14fd),
+    (0x8636, 0x14fe),
+    (0x8638, 0x14ff),
+    (0x863a, 0x1500),
+    (0x863c, 0x1501),
+    (0x863d, 0x1502),
+    (0x8640, 0x1503),
+    (0x8641, 0x08b7),
+    (0x8642, 0x1504),
+    (0x8646, 0x1505),
+    (0x8652, 0x1506),
+    (0x8653, 0x1507),
+    (0x8656, 0x1508),
+    (0x8657, 0x1509),
+    (0x8658, 0x150a),
+    (0x8659, 0x150b),
+    (0x865d, 0x150c),
+    (0x8660, 0x150d),
+    (0x8661, 0x150e),
+    (0x8662, 0x150f),
+    (0x8663, 0x1510),
+    (0x8664, 0x1511),
+    (0x8669, 0x1512),
+    (0x866c, 0x1513),
+    (0x866f, 0x1514),
+    (0x8675, 0x1515),
+    (0x8676, 0x1516),
+    (0x8677, 0x1517),
+    (0x867a, 0x1518),
+    (0x8688, 0x153a),
+    (0x868d, 0x1519),
+    (0x8691, 0x151a),
+    (0x8696, 0x151b),
+    (0x8698, 0x151c),
+    (0x869a, 0x151d),
+    (0x869c, 0x151e),
+    (0x86a1, 0x151f),
+    (0x86a6, 0x1520),
+    (0x86a7, 0x1521),
+    (0x86a8, 0x1522),
+    (0x86ad, 0x1523),
+    (0x86b1, 0x1524),
+    (0x86b3, 0x1525),
+    (0x86b4, 0x1526),
+    (0x86b5, 0x1527),
+    (0x86b7, 0x1528),
+    (0x86b8, 0x1529),
+    (0x86b9, 0x152a),
+    (0x86bf, 0x152b),
+    (0x86c0, 0x152c),
+    (0x86c1, 0x152d),
+    (0x86c3, 0x152e),
+    (0x86c5, 0x152f),
+    (0x86d1, 0x1530),
+    (0x86d2, 0x1531),
+    (0x86d5, 0x1532),
+    (0x86d7, 0x1533),
+    (0x86da, 0x1534),
+    (0x86dc, 0x1535),
+    (0x86e0, 0x1536),
+    (0x86e3, 0x1537),
+    (0x86e5, 0x1538),
+    (0x86e7, 0x1539),
+    (0x86fa, 0x153b),
+    (0x86fc, 0x153c),
+    (0x86fd, 0x153d),
+    (0x8704, 0x153e),
+    (0x8705, 0x153f),
+    (0x8707, 0x1540),
+    (0x870b, 0x1541),
+    (0x870e, 0x1542),
+    (0x870f, 0x1543),
+    (0x8710, 0x1544),
+    (0x8713, 0x1545),
+    (0x8714, 0x1546),
+    (0x8719, 0x1547),
+    (0x871e, 0x1548),
+    (0x871f, 0x1549),
+    (0x8721, 0x154a),
+    (0x8723, 0x154b),
+    (0x8728, 0x154c),
+    (0x872e, 0x154d),
+    (0x872f, 0x154e),
+    (0x8731, 0x154f),
+    (0x8732, 0x1550),
+    (0x8739, 0x1551),
+    (0x873a, 0x1552),
+    (0x873c, 0x1553),
+    (0x873d, 0x1554),
+    (0x873e, 0x1555),
+    (0x8740, 0x1556),
+    (0x8743, 0x1557),
+    (0x8745, 0x1558),
+    (0x874d, 0x1559),
+    (0x8758, 0x155a),
+    (0x875d, 0x155b),
+    (0x8761, 0x155c),
+    (0x8764, 0x155d),
+    (0x8765, 0x155e),
+    (0x876f, 0x155f),
+    (0x8771, 0x1560),
+    (0x8772, 0x1561),
+    (0x877b, 0x1562),
+    (0x8783, 0x1563),
+    (0x8784, 0x1564),
+    (0x8785, 0x1565),
+    (0x8786, 0x1566),
+    (0x8787, 0x1567),
+    (0x8788, 0x1568),
+    (0x8789, 0x1569),
+    (0x878b, 0x156a),
+    (0x878c, 0x156b),
+    (0x8790, 0x156c),
+    (0x8793, 0x156d),
+    (0x8795, 0x156e),
+    (0x8797, 0x156f),
+    (0x8798, 0x1570),
+    (0x8799, 0x1571),
+    (0x879e, 0x1572),
+    (0x87a0, 0x1573),
+    (0x87a3, 0x1574),
+    (0x87a7, 0x1575),
+    (0x87ac, 0x1576),
+    (0x87ad, 0x1577),
+    (0x87ae, 0x1578),
+    (0x87b1, 0x1579),
+    (0x87b5, 0x157a),
+    (0x87be, 0x157b),
+    (0x87bf, 0x157c),
+    (0x87c1, 0x157d),
+    (0x87c8, 0x157e),
+    (0x87c9, 0x157f),
+    (0x87ca, 0x1580),
+    (0x87ce, 0x1581),
+    (0x87d5, 0x1582),
+    (0x87d6, 0x1583),
+    (0x87d9, 0x1584),
+    (0x87da, 0x1585),
+    (0x87dc, 0x1586),
+    (0x87df, 0x1587),
+    (0x87e2, 0x1588),
+    (0x87e3, 0x1589),
+    (0x87e4, 0x158a),
+    (0x87ea, 0x158b),
+    (0x87eb, 0x158c),
+    (0x87ed, 0x158d),
+    (0x87f1, 0x158e),
+    (0x87f3, 0x158f),
+    (0x87f8, 0x1590),
+    (0x87fa, 0x1591),
+    (0x87ff, 0x1592),
+    (0x8801, 0x1593),
+    (0x8803, 0x1594),
+    (0x8806, 0x1595),
+    (0x8809, 0x1596),
+    (0x880a, 0x1597),
+    (0x880b, 0x1598),
+    (0x8810, 0x1599),
+    (0x8812, 0x159b),
+    (0x8813, 0x159c),
+    (0x8814, 0x159d),
+    (0x8818, 0x159e),
+    (0x8819, 0x159a),
+    (0x881a, 0x159f),
+    (0x881b, 0x15a0),
+    (0x881c, 0x15a1),
+    (0x881e, 0x15a2),
+    (0x881f, 0x15a3),
+    (0x8828, 0x15a4),
+    (0x882d, 0x15a5),
+    (0x882e, 0x15a6),
+    (0x8830, 0x15a7),
+    (0x8832, 0x15a8),
+    (0x8835, 0x15a9),
+    (0x883a, 0x15aa),
+    (0x883c, 0x15ab),
+    (0x8841, 0x15ac),
+    (0x8843, 0x15ad),
+    (0x8845, 0x15ae),
+    (0x8848, 0x15af),
+    (0x8849, 0x15b0),
+    (0x884a, 0x15b1),
+    (0x884b, 0x15b2),
+    (0x884e, 0x15b3),
+    (0x8851, 0x15b4),
+    (0x8855, 0x15b5),
+    (0x8856, 0x15b6),
+    (0x8858, 0x15b7),
+    (0x885a, 0x15b8),
+    (0x885c, 0x15b9),
+    (0x885f, 0x15ba),
+    (0x8860, 0x15bb),
+    (0x8864, 0x15bc),
+    (0x8869, 0x15bd),
+    (0x8871, 0x15be),
+    (0x8879, 0x15bf),
+    (0x887b, 0x15c0),
+    (0x8880, 0x15c1),
+    (0x8898, 0x15c2),
+    (0x889a, 0x15c3),
+    (0x889b, 0x15c4),
+    (0x889c, 0x15c5),
+    (0x889f, 0x15c6),
+    (0x88a0, 0x15c7),
+    (0x88a8, 0x15c8),
+    (0x88aa, 0x15c9),
+    (0x88ba, 0x15ca),
+    (0x88bd, 0x15cb),
+    (0x88be, 0x15cc),
+    (0x88c0, 0x15cd),
+    (0x88ca, 0x15ce),
+    (0x88cb, 0x15cf),
+    (0x88cc, 0x15d0),
+    (0x88cd, 0x15d1),
+    (0x88ce, 0x15d2),
+    (0x88d1, 0x15d3),
+    (0x88d2, 0x15d4),
+    (0x88d3, 0x15d5),
+    (0x88db, 0x15d6),
+    (0x88de, 0x15d7),
+    (0x88e7, 0x15d8),
+    (0x88ef, 0x15d9),
+    (0x88f0, 0x15da),
+    (0x88f1, 0x15db),
+    (0x88f5, 0x15dc),
+    (0x88f7, 0x15dd),
+    (0x8901, 0x15de),
+    (0x8906, 0x15df),
+    (0x890d, 0x15e0),
+    (0x890e, 0x15e1),
+    (0x890f, 0x15e2),
+    (0x8915, 0x15e3),
+    (0x8916, 0x15e4),
+    (0x8918, 0x15e5),
+    (0x8919, 0x15e6),
+    (0x891a, 0x15e7),
+    (0x891c, 0x15e8),
+    (0x8920, 0x15e9),
+    (0x8926, 0x15ea),
+    (0x8927, 0x15eb),
+    (0x8928, 0x15ec),
+    (0x8930, 0x15ed),
+    (0x8931, 0x15ee),
+    (0x8932, 0x15ef),
+    (0x8935, 0x15f0),
+    (0x8939, 0x15f1),
+    (0x893a, 0x15f2),
+    (0x893e, 0x15f3),
+    (0x8940, 0x15f4),
+    (0x8942, 0x15f5),
+    (0x8945, 0x15f6),
+    (0x8946, 0x15f7),
+    (0x8949, 0x15f8),
+    (0x894f, 0x15f9),
+    (0x8952, 0x15fa),
+    (0x8957, 0x15fb),
+    (0x895a, 0x15fc),
+    (0x895b, 0x15fd),
+    (0x895c, 0x15fe),
+    (0x8961, 0x15ff),
+    (0x8962, 0x1600),
+    (0x8963, 0x1601),
+    (0x896b, 0x1602),
+    (0x896e, 0x1603),
+    (0x8970, 0x1604),
+    (0x8973, 0x1605),
+    (0x8975, 0x1606),
+    (0x897a, 0x1607),
+    (0x897b, 0x1608),
+    (0x897c, 0x1609),
+    (0x897d, 0x160a),
+    (0x8989, 0x160b),
+    (0x898d, 0x160c),
+    (0x8990, 0x160d),
+    (0x8994, 0x160e),
+    (0x8995, 0x160f),
+    (0x899b, 0x1610),
+    (0x899c, 0x1611),
+    (0x899f, 0x1612),
+    (0x89a0, 0x1613),
+    (0x89a5, 0x1614),
+    (0x89b0, 0x1615),
+    (0x89b4, 0x1616),
+    (0x89b5, 0x1617),
+    (0x89b6, 0x1618),
+    (0x89b7, 0x1619),
+    (0x89bc, 0x161a),
+    (0x89d4, 0x161b),
+    (0x89d5, 0x161c),
+    (0x89d6, 0x161d),
+    (0x89d7, 0x161e),
+    (0x89d8, 0x161f),
+    (0x89e5, 0x1620),
+    (0x89e9, 0x1621),
+    (0x89eb, 0x1622),
+    (0x89ed, 0x1623),
+    (0x89f1, 0x1624),
+    (0x89f3, 0x1625),
+    (0x89f6, 0x1626),
+    (0x89f9, 0x1627),
+    (0x89fd, 0x1628),
+    (0x89ff, 0x1629),
+    (0x8a04, 0x162a),
+    (0x8a05, 0x162b),
+    (0x8a07, 0x162c),
+    (0x8a0f, 0x162d),
+    (0x8a11, 0x162e),
+    (0x8a12, 0x162f),
+    (0x8a14, 0x1630),
+    (0x8a15, 0x1631),
+    (0x8a1e, 0x1632),
+    (0x8a20, 0x1633),
+    (0x8a22, 0x1634),
+    (0x8a24, 0x1635),
+    (0x8a26, 0x1636),
+    (0x8a2b, 0x1637),
+    (0x8a2c, 0x1638),
+    (0x8a2f, 0x1639),
+    (0x8a35, 0x163a),
+    (0x8a37, 0x163b),
+    (0x8a3d, 0x163c),
+    (0x8a3e, 0x163d),
+    (0x8a40, 0x163e),
+    (0x8a43, 0x163f),
+    (0x8a45, 0x1640),
+    (0x8a47, 0x1641),
+    (0x8a49, 0x1642),
+    (0x8a4d, 0x1643),
+    (0x8a4e, 0x1644),
+    (0x8a53, 0x1645),
+    (0x8a56, 0x1646),
+    (0x8a57, 0x1647),
+    (0x8a58, 0x1648),
+    (0x8a5c, 0x1649),
+    (0x8a5d, 0x164a),
+    (0x8a61, 0x164b),
+    (0x8a65, 0x164c),
+    (0x8a67, 0x164d),
+    (0x8a75, 0x164e),
+    (0x8a76, 0x164f),
+    (0x8a77, 0x1650),
+    (0x8a79, 0x1651),
+    (0x8a7a, 0x1652),
+    (0x8a7b, 0x1653),
+    (0x8a7e, 0x1654),
+    (0x8a7f, 0x1655),
+    (0x8a80, 0x1656),
+    (0x8a83, 0x1657),
+    (0x8a86, 0x1658),
+    (0x8a8b, 0x1659),
+    (0x8a8f, 0x165a),
+    (0x8a90, 0x165b),
+    (0x8a92, 0x165c),
+    (0x8a96, 0x165d),
+    (0x8a97, 0x165e),
+    (0x8a99, 0x165f),
+    (0x8a9f, 0x1660),
+    (0x8aa7, 0x1661),
+    (0x8aa9, 0x1662),
+    (0x8aae, 0x1663),
+    (0x8aaf, 0x1664),
+    (0x8ab3, 0x1665),
+    (0x8ab6, 0x1666),
+    (0x8ab7, 0x1667),
+    (0x8abb, 0x1668),
+    (0x8abe, 0x1669),
+    (0x8ac3, 0x166a),
+    (0x8ac6, 0x166b),
+    (0x8ac8, 0x166c),
+    (0x8ac9, 0x166d),
+    (0x8aca, 0x166e),
+    (0x8ad1, 0x166f),
+    (0x8ad3, 0x1670),
+    (0x8ad4, 0x1671),
+    (0x8ad5, 0x1672),
+    (0x8ad7, 0x1673),
+    (0x8add, 0x1674),
+    (0x8adf, 0x1675),
+    (0x8aec, 0x1676),
+    (0x8af0, 0x1677),
+    (0x8af4, 0x1678),
+    (0x8af5, 0x1679),
+    (0x8af6, 0x167a),
+    (0x8afc, 0x167b),
+    (0x8aff, 0x167c),
+    (0x8b05, 0x167d),
+    (0x8b06, 0x167e),
+    (0x8b0a, 0x1684),
+    (0x8b0b, 0x167f),
+    (0x8b11, 0x1680),
+    (0x8b1c, 0x1681),
+    (0x8b1e, 0x1682),
+    (0x8b1f, 0x1683),
+    (0x8b2d, 0x1685),
+    (0x8b30, 0x1686),
+    (0x8b37, 0x1687),
+    (0x8b3c, 0x1688),
+    (0x8b42, 0x1689),
+    (0x8b43, 0x168a),
+    (0x8b44, 0x168b),
+    (0x8b45, 0x168c),
+    (0x8b46, 0x168d),
+    (0x8b48, 0x168e),
+    (0x8b4d, 0x1693),
+    (0x8b52, 0x168f),
+    (0x8b53, 0x1690),
+    (0x8b54, 0x1691),
+    (0x8b59, 0x1692),
+    (0x8b5e, 0x1694),
+    (0x8b63, 0x1695),
+    (0x8b6d, 0x1696),
+    (0x8b76, 0x1697),
+    (0x8b78, 0x1698),
+    (0x8b79, 0x1699),
+    (0x8b7c, 0x169a),
+    (0x8b7e, 0x169b),
+    (0x8b81, 0x169c),
+    (0x8b84, 0x169d),
+    (0x8b85, 0x169e),
+    (0x8b8b, 0x169f),
+    (0x8b8d, 0x16a0),
+    (0x8b8f, 0x16a1),
+    (0x8b94, 0x16a2),
+    (0x8b95, 0x16a3),
+    (0x8b9c, 0x16a4),
+    (0x8b9e, 0x16a5),
+    (0x8b9f, 0x16a6),
+    (0x8c38, 0x16a7),
+    (0x8c39, 0x16a8),
+    (0x8c3d, 0x16a9),
+    (0x8c3e, 0x16aa),
+    (0x8c45, 0x16ab),
+    (0x8c47, 0x16ac),
+    (0x8c49, 0x16ad),
+    (0x8c4b, 0x16ae),
+    (0x8c4f, 0x16af),
+    (0x8c51, 0x16b0),
+    (0x8c53, 0x16b1),
+    (0x8c54, 0x16b2),
+    (0x8c57, 0x16b3),
+    (0x8c58, 0x16b4),
+    (0x8c59, 0x16b7),
+    (0x8c5b, 0x16b5),
+    (0x8c5d, 0x16b6),
+    (0x8c63, 0x16b8),
+    (0x8c64, 0x16b9),
+    (0x8c66, 0x16ba),
+    (0x8c68, 0x16bb),
+    (0x8c69, 0x16bc),
+    (0x8c6d, 0x16bd),
+    (0x8c73, 0x16be),
+    (0x8c75, 0x16bf),
+    (0x8c76, 0x16c0),
+    (0x8c7b, 0x16c1),
+    (0x8c7e, 0x16c2),
+    (0x8c86, 0x16c3),
+    (0x8c87, 0x16c4),
+    (0x8c8b, 0x16c5),
+    (0x8c90, 0x16c6),
+    (0x8c92, 0x16c7),
+    (0x8c93, 0x16c8),
+    (0x8c99, 0x16c9),
+    (0x8c9b, 0x16ca),
+    (0x8c9c, 0x16cb),
+    (0x8ca4, 0x16cc),
+    (0x8cb9, 0x16cd),
+    (0x8cba, 0x16ce),
+    (0x8cc5, 0x16cf),
+    (0x8cc6, 0x16d0),
+    (0x8cc9, 0x16d1),
+    (0x8ccb, 0x16d2),
+    (0x8ccf, 0x16d3),
+    (0x8cd5, 0x16d5),
+    (0x8cd6, 0x16d4),
+    (0x8cd9, 0x16d6),
+    (0x8cdd, 0x16d7),
+    (0x8ce1, 0x16d8),
+    (0x8ce8, 0x16d9),
+    (0x8cec, 0x16da),
+    (0x8cef, 0x16db),
+    (0x8cf0, 0x16dc),
+    (0x8cf2, 0x16dd),
+    (0x8cf5, 0x16de),
+    (0x8cf7, 0x16df),
+    (0x8cf8, 0x16e0),
+    (0x8cfe, 0x16e1),
+    (0x8cff, 0x16e2),
+    (0x8d01, 0x16e3),
+    (0x8d03, 0x16e4),
+    (0x8d09, 0x16e5),
+    (0x8d12, 0x16e6),
+    (0x8d17, 0x16e7),
+    (0x8d1b, 0x16e8),
+    (0x8d65, 0x16e9),
+    (0x8d69, 0x16ea),
+    (0x8d6c, 0x16eb),
+    (0x8d6e, 0x16ec),
+    (0x8d7f, 0x16ed),
+    (0x8d82, 0x16ee),
+    (0x8d84, 0x16ef),
+    (0x8d88, 0x16f0),
+    (0x8d8d, 0x16f1),
+    (0x8d90, 0x16f2),
+    (0x8d91, 0x16f3),
+    (0x8d95, 0x16f4),
+    (0x8d9e, 0x16f5),
+    (0x8d9f, 0x16f6),
+    (0x8da0, 0x16f7),
+    (0x8da6, 0x16f8),
+    (0x8dab, 0x16f9),
+    (0x8dac, 0x16fa),
+    (0x8daf, 0x16fb),
+    (0x8db2, 0x16fc),
+    (0x8db5, 0x16fd),
+    (0x8db7, 0x16fe),
+    (0x8db9, 0x16ff),
+    (0x8dbb, 0x1700),
+    (0x8dbc, 0x1712),
+    (0x8dc0, 0x1701),
+    (0x8dc5, 0x1702),
+    (0x8dc6, 0x1703),
+    (0x8dc7, 0x1704),
+    (0x8dc8, 0x1705),
+    (0x8dca, 0x1706),
+    (0x8dce, 0x1707),
+    (0x8dd1, 0x1708),
+    (0x8dd4, 0x1709),
+    (0x8dd5, 0x170a),
+    (0x8dd7, 0x170b),
+    (0x8dd9, 0x170c),
+    (0x8de4, 0x170d),
+    (0x8de5, 0x170e),
+    (0x8de7, 0x170f),
+    (0x8dec, 0x1710),
+    (0x8df0, 0x1711),
+    (0x8df1, 0x1713),
+    (0x8df2, 0x1714),
+    (0x8df4, 0x1715),
+    (0x8dfd, 0x1716),
+    (0x8e01, 0x1717),
+    (0x8e04, 0x1718),
+    (0x8e05, 0x1719),
+    (0x8e06, 0x171a),
+    (0x8e0b, 0x171b),
+    (0x8e11, 0x171c),
+    (0x8e14, 0x171d),
+    (0x8e16, 0x171e),
+    (0x8e20, 0x171f),
+    (0x8e21, 0x1720),
+    (0x8e22, 0x1721),
+    (0x8e23, 0x1722),
+    (0x8e26, 0x1723),
+    (0x8e27, 0x1724),
+    (0x8e31, 0x1725),
+    (0x8e33, 0x1726),
+    (0x8e36, 0x1727),
+    (0x8e37, 0x1728),
+    (0x8e38, 0x1729),
+    (0x8e39, 0x172a),
+    (0x8e3d, 0x172b),
+    (0x8e40, 0x172c),
+    (0x8e41, 0x172d),
+    (0x8e4b, 0x172e),
+    (0x8e4d, 0x172f),
+    (0x8e4e, 0x1730),
+    (0x8e4f, 0x1731),
+    (0x8e54, 0x1732),
+    (0x8e5b, 0x1733),
+    (0x8e5c, 0x1734),
+    (0x8e5d, 0x1735),
+    (0x8e5e, 0x1736),
+    (0x8e61, 0x1737),
+    (0x8e62, 0x1738),
+    (0x8e69, 0x1739),
+    (0x8e6c, 0x173a),
+    (0x8e6d, 0x173b),
+    (0x8e6f, 0x173c),
+    (0x8e70, 0x173d),
+    (0x8e71, 0x173e),
+    (0x8e79, 0x173f),
+    (0x8e7a, 0x1740),
+    (0x8e7b, 0x1741),
+    (0x8e82, 0x1742),
+    (0x8e83, 0x1743),
+    (0x8e89, 0x1744),
+    (0x8e90, 0x1745),
+    (0x8e92, 0x1746),
+    (0x8e95, 0x1747),
+    (0x8e9a, 0x1748),
+    (0x8e9b, 0x1749),
+    (0x8e9d, 0x174a),
+    (0x8e9e, 0x174b),
+    (0x8ea2, 0x174c),
+    (0x8ea7, 0x174d),
+    (0x8ea9, 0x174e),
+    (0x8ead, 0x174f),
+    (0x8eae, 0x1750),
+    (0x8eb3, 0x1751),
+    (0x8eb5, 0x1752),
+    (0x8eba, 0x1753),
+    (0x8ebb, 0x1754),
+    (0x8ec0, 0x1755),
+    (0x8ec1, 0x1756),
+    (0x8ec3, 0x1757),
+    (0x8ec4, 0x1758),
+    (0x8ec7, 0x1759),
+    (0x8ecf, 0x175a),
+    (0x8ed1, 0x175b),
+    (0x8ed4, 0x175c),
+    (0x8edc, 0x175d),
+    (0x8ee8, 0x175e),
+    (0x8eed, 0x1765),
+    (0x8eee, 0x175f),
+    (0x8ef0, 0x1760),
+    (0x8ef1, 0x1761),
+    (0x8ef7, 0x1762),
+    (0x8ef9, 0x1763),
+    (0x8efa, 0x1764),
+    (0x8f00, 0x1766),
+    (0x8f02, 0x1767),
+    (0x8f07, 0x1768),
+    (0x8f08, 0x1769),
+    (0x8f0f, 0x176a),
+    (0x8f10, 0x176b),
+    (0x8f16, 0x176c),
+    (0x8f17, 0x176d),
+    (0x8f18, 0x176e),
+    (0x8f1e, 0x176f),
+    (0x8f20, 0x1770),
+    (0x8f21, 0x1771),
+    (0x8f23, 0x1772),
+    (0x8f25, 0x1773),
+    (0x8f27, 0x1774),
+    (0x8f28, 0x1775),
+    (0x8f2c, 0x1776),
+    (0x8f2d, 0x1777),
+    (0x8f2e, 0x1778),
+    (0x8f34, 0x1779),
+    (0x8f35, 0x177a),
+    (0x8f36, 0x177b),
+    (0x8f37, 0x177c),
+    (0x8f3a, 0x177d),
+    (0x8f40, 0x177e),
+    (0x8f41, 0x177f),
+    (0x8f43, 0x1780),
+    (0x8f47, 0x1781),
+    (0x8f4f, 0x1782),
+    (0x8f51, 0x1783),
+    (0x8f52, 0x1784),
+    (0x8f53, 0x1785),
+    (0x8f54, 0x1786),
+    (0x8f55, 0x1787),
+    (0x8f58, 0x1788),
+    (0x8f5d, 0x1789),
+    (0x8f5e, 0x178a),
+    (0x8f65, 0x178b),
+    (0x8f9d, 0x178c),
+    (0x8fa0, 0x178d),
+    (0x8fa1, 0x178e),
+    (0x8fa4, 0x178f),
+    (0x8fa5, 0x1790),
+    (0x8fa6, 0x1791),
+    (0x8fb5, 0x1792),
+    (0x8fb6, 0x1793),
+    (0x8fb8, 0x1794),
+    (0x8fbe, 0x1795),
+    (0x8fc0, 0x1796),
+    (0x8fc1, 0x1797),
+    (0x8fc6, 0x1798),
+    (0x8fca, 0x1799),
+    (0x8fcb, 0x179a),
+    (0x8fcd, 0x179b),
+    (0x8fd0, 0x179c),
+    (0x8fd2, 0x179d),
+    (0x8fd3, 0x179e),
+    (0x8fd5, 0x179f),
+    (0x8fe0, 0x17a0),
+    (0x8fe3, 0x17a1),
+    (0x8fe4, 0x17a2),
+    (0x8fe8, 0x17a3),
+    (0x8fee, 0x17a4),
+    (0x8ff1, 0x17a5),
+    (0x8ff5, 0x17a6),
+    (0x8ff6, 0x17a7),
+    (0x8ffb, 0x17a8),
+    (0x8ffe, 0x17a9),
+    (0x9002, 0x17aa),
+    (0x9004, 0x17ab),
+    (0x9008, 0x17ac),
+    (0x900c, 0x17ad),
+    (0x9018, 0x17ae),
+    (0x901b, 0x17af),
+    (0x9028, 0x17b0),
+    (0x9029, 0x17b1),
+    (0x902a, 0x17b3),
+    (0x902c, 0x17b4),
+    (0x902d, 0x17b5),
+    (0x902f, 0x17b2),
+    (0x9033, 0x17b6),
+    (0x9034, 0x17b7),
+    (0x9037, 0x17b8),
+    (0x903f, 0x17b9),
+    (0x9043, 0x17ba),
+    (0x9044, 0x17bb),
+    (0x904c, 0x17bc),
+    (0x905b, 0x17bd),
+    (0x905d, 0x17be),
+    (0x9062, 0x17bf),
+    (0x9066, 0x17c0),
+    (0x9067, 0x17c1),
+    (0x906c, 0x17c2),
+    (0x9070, 0x17c3),
+    (0x9074, 0x17c4),
+    (0x9079, 0x17c5),
+    (0x9085, 0x17c6),
+    (0x9088, 0x17c7),
+    (0x908b, 0x17c8),
+    (0x908c, 0x17c9),
+    (0x908e, 0x17ca),
+    (0x9090, 0x17cb),
+    (0x9095, 0x17cc),
+    (0x9097, 0x17cd),
+    (0x9098, 0x17ce),
+    (0x9099, 0x17cf),
+    (0x909b, 0x17d0),
+    (0x90a0, 0x17d1),
+    (0x90a1, 0x17d2),
+    (0x90a2, 0x17d3),
+    (0x90a5, 0x17d4),
+    (0x90b0, 0x17d5),
+    (0x90b2, 0x17d6),
+    (0x90b3, 0x17d7),
+    (0x90b4, 0x17d8),
+    (0x90b6, 0x17d9),
+    (0x90bd, 0x17da),
+    (0x90be, 0x17dc),
+    (0x90c3, 0x17dd),
+    (0x90c4, 0x17de),
+    (0x90c5, 0x17df),
+    (0x90c7, 0x17e0),
+    (0x90c8, 0x17e1),
+    (0x90cc, 0x17db),
+    (0x90d2, 0x17ea),
+    (0x90d5, 0x17e2),
+    (0x90d7, 0x17e3),
+    (0x90d8, 0x17e4),
+    (0x90d9, 0x17e5),
+    (0x90dc, 0x17e6),
+    (0x90dd, 0x17e7),
+    (0x90df, 0x17e8),
+    (0x90e5, 0x17e9),
+    (0x90eb, 0x17ec),
+    (0x90ef, 0x17ed),
+    (0x90f0, 0x17ee),
+    (0x90f4, 0x17ef),
+    (0x90f6, 0x17eb),
+    (0x90fe, 0x17f0),
+    (0x90ff, 0x17f1),
+    (0x9100, 0x17f2),
+    (0x9104, 0x17f3),
+    (0x9105, 0x17f4),
+    (0x9106, 0x17f5),
+    (0x9108, 0x17f6),
+    (0x910d, 0x17f7),
+    (0x9110, 0x17f8),
+    (0x9114, 0x17f9),
+    (0x9116, 0x17fa),
+    (0x9117, 0x17fb),
+    (0x9118, 0x17fc),
+    (0x911a, 0x17fd),
+    (0x911c, 0x17fe),
+    (0x911e, 0x17ff),
+    (0x9120, 0x1800),
+    (0x9122, 0x1802),
+    (0x9123, 0x1803),
+    (0x9125, 0x1801),
+    (0x9127, 0x1804),
+    (0x9129, 0x1805),
+    (0x912e, 0x1806),
+    (0x912f, 0x1807),
+    (0x9131, 0x1808),
+    (0x9134, 0x1809),
+    (0x9136, 0x180a),
+    (0x9137, 0x180b),
+    (0x9139, 0x180c),
+    (0x913a, 0x180d),
+    (0x913c, 0x180e),
+    (0x913d, 0x180f),
+    (0x9143, 0x1810),
+    (0x9147, 0x1811),
+    (0x9148, 0x1812),
+    (0x914f, 0x1813),
+    (0x9153, 0x1814),
+    (0x9157, 0x1815),
+    (0x9159, 0x1816),
+    (0x915a, 0x1817),
+    (0x915b, 0x1818),
+    (0x9161, 0x1819),
+    (0x9164, 0x181a),
+    (0x9167, 0x181b),
+    (0x916d, 0x181c),
+    (0x9174, 0x181d),
+    (0x9179, 0x181e),
+    (0x917a, 0x181f),
+    (0x917b, 0x1820),
+    (0x9181, 0x1821),
+    (0x9183, 0x1822),
+    (0x9185, 0x1823),
+    (0x9186, 0x1824),
+    (0x918a, 0x1825),
+    (0x918e, 0x1826),
+    (0x9191, 0x1827),
+    (0x9193, 0x1828),
+    (0x9194, 0x1829),
+    (0x9195, 0x182a),
+    (0x9198, 0x182b),
+    (0x919e, 0x182c),
+    (0x91a1, 0x182d),
+    (0x91a6, 0x182e),
+    (0x91a8, 0x182f),
+    (0x91ac, 0x1830),
+    (0x91ad, 0x1831),
+    (0x91ae, 0x1832),
+    (0x91b0, 0x1833),
+    (0x91b1, 0x1834),
+    (0x91b2, 0x1835),
+    (0x91b3, 0x1836),
+    (0x91b6, 0x1837),
+    (0x91bb, 0x1838),
+    (0x91bc, 0x1839),
+    (0x91bd, 0x183a),
+    (0x91bf, 0x183b),
+    (0x91c2, 0x183c),
+    (0x91c3, 0x183d),
+    (0x91c5, 0x183e),
+    (0x91d3, 0x183f),
+    (0x91d4, 0x1840),
+    (0x91d7, 0x1841),
+    (0x91d9, 0x1842),
+    (0x91da, 0x1843),
+    (0x91de, 0x1844),
+    (0x91e4, 0x1845),
+    (0x91e5, 0x1846),
+    (0x91e9, 0x1847),
+    (0x91ea, 0x1848),
+    (0x91ec, 0x1849),
+    (0x91ed, 0x184a),
+    (0x91ee, 0x184b),
+    (0x91ef, 0x184c),
+    (0x91f0, 0x184d),
+    (0x91f1, 0x184e),
+    (0x91f7, 0x184f),
+    (0x91f9, 0x1850),
+    (0x91fb, 0x1851),
+    (0x91fd, 0x1852),
+    (0x9200, 0x1853),
+    (0x9201, 0x1854),
+    (0x9204, 0x1855),
+    (0x9205, 0x1856),
+    (0x9206, 0x1857),
+    (0x9207, 0x1858),
+    (0x9209, 0x1859),
+    (0x920a, 0x185a),
+    (0x920c, 0x185b),
+    (0x9210, 0x185c),
+    (0x9212, 0x185d),
+    (0x9213, 0x185e),
+    (0x9216, 0x185f),
+    (0x9218, 0x1860),
+    (0x921c, 0x1861),
+    (0x921d, 0x1862),
+    (0x9223, 0x1863),
+    (0x9224, 0x1864),
+    (0x9225, 0x1865),
+    (0x9226, 0x1866),
+    (0x9228, 0x1867),
+    (0x922e, 0x1868),
+    (0x922f, 0x1869),
+    (0x9230, 0x186a),
+    (0x9233, 0x186b),
+    (0x9235, 0x186c),
+    (0x9236, 0x186d),
+    (0x9238, 0x186e),
+    (0x9239, 0x186f),
+    (0x923a, 0x1870),
+    (0x923c, 0x1871),
+    (0x923e, 0x1872),
+    (0x9240, 0x1873),
+    (0x9242, 0x1874),
+    (0x9243, 0x1875),
+    (0x9246, 0x1876),
+    (0x9247, 0x1877),
+    (0x924a, 0x1878),
+    (0x924d, 0x1879),
+    (0x924e, 0x187a),
+    (0x924f, 0x187b),
+    (0x9251, 0x187c),
+    (0x9258, 0x187d),
+    (0x9259, 0x187e),
+    (0x925c, 0x187f),
+    (0x925d, 0x1880),
+    (0x9260, 0x1881),
+    (0x9261, 0x1882),
+    (0x9265, 0x1883),
+    (0x9267, 0x1884),
+    (0x9268, 0x1885),
+    (0x9269, 0x1886),
+    (0x926e, 0x1887),
+    (0x926f, 0x1888),
+    (0x9270, 0x1889),
+    (0x9275, 0x188a),
+    (0x9276, 0x188b),
+    (0x9277, 0x188c),
+    (0x9278, 0x188d),
+    (0x9279, 0x188e),
+    (0x927b, 0x188f),
+    (0x927c, 0x1890),
+    (0x927d, 0x1891),
+    (0x927f, 0x1892),
+    (0x9288, 0x1893),
+    (0x9289, 0x1894),
+    (0x928a, 0x1895),
+    (0x928d, 0x1896),
+    (0x928e, 0x1897),
+    (0x9292, 0x1898),
+    (0x9297, 0x1899),
+    (0x9299, 0x189a),
+    (0x929f, 0x189b),
+    (0x92a0, 0x189c),
+    (0x92a4, 0x189d),
+    (0x92a5, 0x189e),
+    (0x92a7, 0x189f),
+    (0x92a8, 0x18a0),
+    (0x92ab, 0x18a1),
+    (0x92af, 0x18a2),
+    (0x92b2, 0x18a3),
+    (0x92b6, 0x18a4),
+    (0x92b8, 0x18a5),
+    (0x92ba, 0x18a6),
+    (0x92bb, 0x18a7),
+    (0x92bc, 0x18a8),
+    (0x92bd, 0x18a9),
+    (0x92bf, 0x18aa),
+    (0x92c0, 0x18ab),
+    (0x92c1, 0x18ac),
+    (0x92c2, 0x18ad),
+    (0x92c3, 0x18ae),
+    (0x92c5, 0x18af),
+    (0x92c6, 0x18b0),
+    (0x92c7, 0x18b1),
+    (0x92c8, 0x18b2),
+    (0x92cb, 0x18b3),
+    (0x92cc, 0x18b4),
+    (0x92cd, 0x18b5),
+    (0x92ce, 0x18b6),
+    (0x92d0, 0x18b7),
+    (0x92d3, 0x18b8),
+    (0x92d5, 0x18b9),
+    (0x92d7, 0x18ba),
+    (0x92d8, 0x18bb),
+    (0x92d9, 0x18bc),
+    (0x92dc, 0x18bd),
+    (0x92dd, 0x18be),
+    (0x92df, 0x18bf),
+    (0x92e0, 0x18c0),
+    (0x92e1, 0x18c1),
+    (0x92e3, 0x18c2),
+    (0x92e5, 0x18c3),
+    (0x92e7, 0x18c4),
+    (0x92e8, 0x18c5),
+    (0x92ec, 0x18c6),
+    (0x92ee, 0x18c7),
+    (0x92f0, 0x18c8),
+    (0x92f9, 0x18c9),
+    (0x92fb, 0x18ca),
+    (0x92ff, 0x18cb),
+    (0x9300, 0x18cc),
+    (0x9302, 0x18cd),
+    (0x9308, 0x18ce),
+    (0x930d, 0x18cf),
+    (0x9311, 0x18d0),
+    (0x9314, 0x18d1),
+    (0x9315, 0x18d2),
+    (0x931c, 0x18d3),
+    (0x931d, 0x18d4),
+    (0x931e, 0x18d5),
+    (0x931f, 0x18d6),
+    (0x9321, 0x18d7),
+    (0x9324, 0x18d8),
+    (0x9325, 0x18d9),
+    (0x9327, 0x18da),
+    (0x9329, 0x18db),
+    (0x932a, 0x18dc),
+    (0x9333, 0x18dd),
+    (0x9334, 0x18de),
+    (0x9336, 0x18df),
+    (0x9337, 0x18e0),
+    (0x9347, 0x18e1),
+    (0x9348, 0x18e2),
+    (0x9349, 0x18e3),
+    (0x9350, 0x18e4),
+    (0x9351, 0x18e5),
+    (0x9352, 0x18e6),
+    (0x9355, 0x18e7),
+    (0x9357, 0x18e8),
+    (0x9358, 0x18e9),
+    (0x935a, 0x18ea),
+    (0x935e, 0x18eb),
+    (0x9364, 0x18ec),
+    (0x9365, 0x18ed),
+    (0x9367, 0x18ee),
+    (0x9369, 0x18ef),
+    (0x936a, 0x18f0),
+    (0x936d, 0x18f1),
+    (0x936f, 0x18f2),
+    (0x9370, 0x18f3),
+    (0x9371, 0x18f4),
+    (0x9373, 0x18f5),
+    (0x9374, 0x18f6),
+    (0x9376, 0x18f7),
+    (0x937a, 0x18f8),
+    (0x937d, 0x18f9),
+    (0x937f, 0x18fa),
+    (0x9380, 0x18fb),
+    (0x9381, 0x18fc),
+    (0x9382, 0x18fd),
+    (0x9388, 0x18fe),
+    (0x938a, 0x18ff),
+    (0x938b, 0x1900),
+    (0x938d, 0x1901),
+    (0x938f, 0x1902),
+    (0x9392, 0x1903),
+    (0x9395, 0x1904),
+    (0x9398, 0x1905),
+    (0x939b, 0x1906),
+    (0x939e, 0x1907),
+    (0x93a1, 0x1908),
+    (0x93a3, 0x1909),
+    (0x93a4, 0x190a),
+    (0x93a6, 0x190b),
+    (0x93a8, 0x190c),
+    (0x93a9, 0x1912),
+    (0x93ab, 0x190d),
+    (0x93b4, 0x190e),
+    (0x93b5, 0x190f),
+    (0x93b6, 0x1910),
+    (0x93ba, 0x1911),
+    (0x93c1, 0x1913),
+    (0x93c4, 0x1914),
+    (0x93c5, 0x1915),
+    (0x93c6, 0x1916),
+    (0x93c7, 0x1917),
+    (0x93c9, 0x1918),
+    (0x93ca, 0x1919),
+    (0x93cb, 0x191a),
+    (0x93cc, 0x191b),
+    (0x93cd, 0x191c),
+    (0x93d3, 0x191d),
+    (0x93d9, 0x191e),
+    (0x93dc, 0x191f),
+    (0x93de, 0x1920),
+    (0x93df, 0x1921),
+    (0x93e2, 0x1922),
+    (0x93e6, 0x1923),
+    (0x93e7, 0x1924),
+    (0x93f7, 0x1926),
+    (0x93f8, 0x1927),
+    (0x93f9, 0x1925),
+    (0x93fa, 0x1928),
+    (0x93fb, 0x1929),
+    (0x93fd, 0x192a),
+    (0x9401, 0x192b),
+    (0x9402, 0x192c),
+    (0x9404, 0x192d),
+    (0x9408, 0x192e),
+    (0x9409, 0x192f),
+    (0x940d, 0x1930),
+    (0x940e, 0x1931),
+    (0x940f, 0x1932),
+    (0x9415, 0x1933),
+    (0x9416, 0x1934),
+    (0x9417, 0x1935),
+    (0x941f, 0x1936),
+    (0x942e, 0x1937),
+    (0x942f, 0x1938),
+    (0x9431, 0x1939),
+    (0x9432, 0x193a),
+    (0x9433, 0x193b),
+    (0x9434, 0x193c),
+    (0x943b, 0x193d),
+    (0x943d, 0x193f),
+    (0x943f, 0x193e),
+    (0x9443, 0x1940),
+    (0x9445, 0x1941),
+    (0x9448, 0x1942),
+    (0x944a, 0x1943),
+    (0x944c, 0x1944),
+    (0x9455, 0x1945),
+    (0x9459, 0x1946),
+    (0x945c, 0x1947),
+    (0x945f, 0x1948),
+    (0x9461, 0x1949),
+    (0x9463, 0x194a),
+    (0x9468, 0x194b),
+    (0x946b, 0x194c),
+    (0x946d, 0x194d),
+    (0x946e, 0x194e),
+    (0x946f, 0x194f),
+    (0x9471, 0x1950),
+    (0x9472, 0x1951),
+    (0x9483, 0x1953),
+    (0x9484, 0x1952),
+    (0x9578, 0x1954),
+    (0x9579, 0x1955),
+    (0x957e, 0x1956),
+    (0x9584, 0x1957),
+    (0x9588, 0x1958),
+    (0x958c, 0x1959),
+    (0x958d, 0x195a),
+    (0x958e, 0x195b),
+    (0x959d, 0x195c),
+    (0x959e, 0x195d),
+    (0x959f, 0x195e),
+    (0x95a1, 0x195f),
+    (0x95a6, 0x1960),
+    (0x95a9, 0x1961),
+    (0x95ab, 0x1962),
+    (0x95ac, 0x1963),
+    (0x95b4, 0x1964),
+    (0x95b6, 0x1965),
+    (0x95ba, 0x1966),
+    (0x95bd, 0x1967),
+    (0x95bf, 0x1968),
+    (0x95c6, 0x1969),
+    (0x95c8, 0x196a),
+    (0x95c9, 0x196b),
+    (0x95cb, 0x196c),
+    (0x95d0, 0x196d),
+    (0x95d1, 0x196e),
+    (0x95d2, 0x196f),
+    (0x95d3, 0x1970),
+    (0x95d9, 0x1971),
+    (0x95da, 0x1972),
+    (0x95dd, 0x1973),
+    (0x95de, 0x1974),
+    (0x95df, 0x1975),
+    (0x95e0, 0x1976),
+    (0x95e4, 0x1977),
+    (0x95e6, 0x1978),
+    (0x961d, 0x1979),
+    (0x961e, 0x197a),
+    (0x9622, 0x197b),
+    (0x9624, 0x197c),
+    (0x9625, 0x197d),
+    (0x9626, 0x197e),
+    (0x962c, 0x197f),
+    (0x9631, 0x1980),
+    (0x9633, 0x1981),
+    (0x9637, 0x1982),
+    (0x9638, 0x1983),
+    (0x9639, 0x1984),
+    (0x963a, 0x1985),
+    (0x963c, 0x1986),
+    (0x963d, 0x1987),
+    (0x9641, 0x1988),
+    (0x9652, 0x1989),
+    (0x9654, 0x198a),
+    (0x9656, 0x198b),
+    (0x9657, 0x198c),
+    (0x9658, 0x198d),
+    (0x9661, 0x198e),
+    (0x966e, 0x198f),
+    (0x9674, 0x1990),
+    (0x967b, 0x1991),
+    (0x967c, 0x1992),
+    (0x967e, 0x1993),
+    (0x967f, 0x1994),
+    (0x9681, 0x1995),
+    (0x9682, 0x1996),
+    (0x9683, 0x1997),
+    (0x9684, 0x1998),
+    (0x9689, 0x1999),
+    (0x9691, 0x199a),
+    (0x9696, 0x199b),
+    (0x969a, 0x199c),
+    (0x969d, 0x199d),
+    (0x969f, 0x199e),
+    (0x96a4, 0x199f),
+    (0x96a5, 0x19a0),
+    (0x96a6, 0x19a1),
+    (0x96a9, 0x19a2),
+    (0x96ae, 0x19a3),
+    (0x96af, 0x19a4),
+    (0x96b3, 0x19a5),
+    (0x96ba, 0x19a6),
+    (0x96ca, 0x19a7),
+    (0x96d2, 0x19a8),
+    (0x96d8, 0x19aa),
+    (0x96da, 0x19ab),
+    (0x96dd, 0x19ac),
+    (0x96de, 0x19ad),
+    (0x96df, 0x19ae),
+    (0x96e9, 0x19af),
+    (0x96ef, 0x19b0),
+    (0x96f1, 0x19b1),
+    (0x96fa, 0x19b2),
+    (0x9702, 0x19b3),
+    (0x9703, 0x19b4),
+    (0x9705, 0x19b5),
+    (0x9709, 0x19b6),
+    (0x971a, 0x19b7),
+    (0x971b, 0x19b8),
+    (0x971d, 0x19b9),
+    (0x9721, 0x19ba),
+    (0x9722, 0x19bb),
+    (0x9723, 0x19bc),
+    (0x9728, 0x19bd),
+    (0x9731, 0x19be),
+    (0x9733, 0x19bf),
+    (0x9741, 0x19c0),
+    (0x9743, 0x19c1),
+    (0x974a, 0x19c2),
+    (0x974e, 0x19c3),
+    (0x974f, 0x19c4),
+    (0x9755, 0x19c5),
+    (0x9757, 0x19c6),
+    (0x9758, 0x19c7),
+    (0x975a, 0x19c8),
+    (0x975b, 0x19c9),
+    (0x9763, 0x19ca),
+    (0x9767, 0x19cb),
+    (0x976a, 0x19cc),
+    (0x976e, 0x19cd),
+    (0x9773, 0x19ce),
+    (0x9776, 0x19cf),
+    (0x9777, 0x19d0),
+    (0x9778, 0x19d1),
+    (0x977b, 0x19d2),
+    (0x977d, 0x19d3),
+    (0x977f, 0x19d4),
+    (0x9780, 0x19d5),
+    (0x9789, 0x19d6),
+    (0x9795, 0x19d7),
+    (0x9796, 0x19d8),
+    (0x9797, 0x19d9),
+    (0x9799, 0x19da),
+    (0x979a, 0x19db),
+    (0x979e, 0x19dc),
+    (0x979f, 0x19dd),
+    (0x97a2, 0x19de),
+    (0x97ac, 0x19df),
+    (0x97ae, 0x19e0),
+    (0x97b1, 0x19e1),
+    (0x97b2, 0x19e2),
+    (0x97b5, 0x19e3),
+    (0x97b6, 0x19e4),
+    (0x97b8, 0x19e5),
+    (0x97b9, 0x19e6),
+    (0x97ba, 0x19e7),
+    (0x97bc, 0x19e8),
+    (0x97be, 0x19e9),
+    (0x97bf, 0x19ea),
+    (0x97c1, 0x19eb),
+    (0x97c4, 0x19ec),
+    (0x97c5, 0x19ed),
+    (0x97c7, 0x19ee),
+    (0x97c9, 0x19ef),
+    (0x97ca, 0x19f0),
+    (0x97cc, 0x19f1),
+    (0x97cd, 0x19f2),
+    (0x97ce, 0x19f3),
+    (0x97d0, 0x19f4),
+    (0x97d1, 0x19f5),
+    (0x97d4, 0x19f6),
+    (0x97d7, 0x19f7),
+    (0x97d8, 0x19f8),
+    (0x97d9, 0x19f9),
+    (0x97db, 0x19fd),
+    (0x97dd, 0x19fa),
+    (0x97de, 0x19fb),
+    (0x97e0, 0x19fc),
+    (0x97e1, 0x19fe),
+    (0x97e4, 0x19ff),
+    (0x97ef, 0x1a00),
+    (0x97f1, 0x1a01),
+    (0x97f4, 0x1a02),
+    (0x97f7, 0x1a03),
+    (0x97f8, 0x1a04),
+    (0x97fa, 0x1a05),
+    (0x9807, 0x1a06),
+    (0x980a, 0x1a07),
+    (0x980d, 0x1a09),
+    (0x980e, 0x1a0a),
+    (0x9814, 0x1a0b),
+    (0x9816, 0x1a0c),
+    (0x9819, 0x1a08),
+    (0x981c, 0x1a0d),
+    (0x981e, 0x1a0e),
+    (0x9820, 0x1a0f),
+    (0x9823, 0x1a10),
+    (0x9825, 0x1a19),
+    (0x9826, 0x1a11),
+    (0x982b, 0x1a12),
+    (0x982e, 0x1a13),
+    (0x982f, 0x1a14),
+    (0x9830, 0x1a15),
+    (0x9832, 0x1a16),
+    (0x9833, 0x1a17),
+    (0x9835, 0x1a18),
+    (0x983e, 0x1a1a),
+    (0x9844, 0x1a1b),
+    (0x9847, 0x1a1c),
+    (0x984a, 0x1a1d),
+    (0x9851, 0x1a1e),
+    (0x9852, 0x1a1f),
+    (0x9853, 0x1a20),
+    (0x9856, 0x1a21),
+    (0x9857, 0x1a22),
+    (0x9859, 0x1a23),
+    (0x985a, 0x1a24),
+    (0x9862, 0x1a25),
+    (0x9863, 0x1a26),
+    (0x9865, 0x1a27),
+    (0x9866, 0x1a28),
+    (0x986a, 0x1a29),
+    (0x986c, 0x1a2a),
+    (0x98ab, 0x1a2b),
+    (0x98ad, 0x1a2c),
+    (0x98ae, 0x1a2d),
+    (0x98b0, 0x1a2e),
+    (0x98b4, 0x1a2f),
+    (0x98b7, 0x1a30),
+    (0x98b8, 0x1a31),
+    (0x98ba, 0x1a32),
+    (0x98bb, 0x1a33),
+    (0x98bf, 0x1a34),
+    (0x98c2, 0x1a35),
+    (0x98c5, 0x1a36),
+    (0x98c8, 0x1a37),
+    (0x98cc, 0x1a38),
+    (0x98e1, 0x1a39),
+    (0x98e3, 0x1a3a),
+    (0x98e5, 0x1a3b),
+    (0x98e6, 0x1a3c),
+    (0x98e7, 0x1a3d),
+    (0x98ea, 0x1a3e),
+    (0x98f3, 0x1a3f),
+    (0x98f6, 0x1a40),
+    (0x9902, 0x1a41),
+    (0x9907, 0x1a42),
+    (0x9908, 0x1a43),
+    (0x9911, 0x1a44),
+    (0x9915, 0x1a45),
+    (0x9916, 0x1a46),
+    (0x9917, 0x1a47),
+    (0x991a, 0x1a48),
+    (0x991b, 0x1a49),
+    (0x991c, 0x1a4a),
+    (0x991f, 0x1a4b),
+    (0x9922, 0x1a4c),
+    (0x9926, 0x1a4d),
+    (0x9927, 0x1a4e),
+    (0x992b, 0x1a4f),
+    (0x9931, 0x1a50),
+    (0x9932, 0x1a51),
+    (0x9933, 0x1a52),
+    (0x9934, 0x1a53),
+    (0x9935, 0x1a54),
+    (0x9939, 0x1a55),
+    (0x993a, 0x1a56),
+    (0x993b, 0x1a57),
+    (0x993c, 0x1a58),
+    (0x9940, 0x1a59),
+    (0x9941, 0x1a5a),
+    (0x9946, 0x1a5b),
+    (0x9947, 0x1a5c),
+    (0x9948, 0x1a5d),
+    (0x994d, 0x1a5e),
+    (0x994e, 0x1a5f),
+    (0x9954, 0x1a60),
+    (0x9958, 0x1a61),
+    (0x9959, 0x1a62),
+    (0x995b, 0x1a63),
+    (0x995c, 0x1a64),
+    (0x995e, 0x1a65),
+    (0x995f, 0x1a66),
+    (0x9960, 0x1a67),
+    (0x999b, 0x1a68),
+    (0x999d, 0x1a69),
+    (0x999f, 0x1a6a),
+    (0x99a6, 0x1a6b),
+    (0x99b0, 0x1a6c),
+    (0x99b1, 0x1a6d),
+    (0x99b2, 0x1a6e),
+    (0x99b5, 0x1a6f),
+    (0x99b9, 0x1a70),
+    (0x99ba, 0x1a71),
+    (0x99bd, 0x1a72),
+    (0x99bf, 0x1a73),
+    (0x99c3, 0x1a74),
+    (0x99c9, 0x1a75),
+    (0x99d3, 0x1a76),
+    (0x99d4, 0x1a77),
+    (0x99d9, 0x1a78),
+    (0x99da, 0x1a79),
+    (0x99dc, 0x1a7a),
+    (0x99de, 0x1a7b),
+    (0x99e7, 0x1a7c),
+    (0x99ea, 0x1a7d),
+    (0x99eb, 0x1a7e),
+    (0x99ec, 0x1a7f),
+    (0x99f0, 0x1a80),
+    (0x99f4, 0x1a81),
+    (0x99f5, 0x1a82),
+    (0x99f9, 0x1a83),
+    (0x99fd, 0x1a84),
+    (0x99fe, 0x1a85),
+    (0x9a02, 0x1a86),
+    (0x9a03, 0x1a87),
+    (0x9a04, 0x1a88),
+    (0x9a0b, 0x1a89),
+    (0x9a0c, 0x1a8a),
+    (0x9a10, 0x1a8b),
+    (0x9a11, 0x1a8c),
+    (0x9a16, 0x1a8d),
+    (0x9a1e, 0x1a8e),
+    (0x9a20, 0x1a8f),
+    (0x9a22, 0x1a90),
+    (0x9a23, 0x1a91),
+    (0x9a24, 0x1a92),
+    (0x9a27, 0x1a93),
+    (0x9a2d, 0x1a94),
+    (0x9a2e, 0x1a95),
+    (0x9a33, 0x1a96),
+    (0x9a35, 0x1a97),
+    (0x9a36, 0x1a98),
+    (0x9a38, 0x1a99),
+    (0x9a41, 0x1a9b),
+    (0x9a44, 0x1a9c),
+    (0x9a47, 0x1a9a),
+    (0x9a4a, 0x1a9d),
+    (0x9a4b, 0x1a9e),
+    (0x9a4c, 0x1a9f),
+    (0x9a4e, 0x1aa0),
+    (0x9a51, 0x1aa1),
+    (0x9a54, 0x1aa2),
+    (0x9a56, 0x1aa3),
+    (0x9a5d, 0x1aa4),
+    (0x9aaa, 0x1aa5),
+    (0x9aac, 0x1aa6),
+    (0x9aae, 0x1aa7),
+    (0x9aaf, 0x1aa8),
+    (0x9ab2, 0x1aa9),
+    (0x9ab4, 0x1aaa),
+    (0x9ab5, 0x1aab),
+    (0x9ab6, 0x1aac),
+    (0x9ab9, 0x1aad),
+    (0x9abb, 0x1aae),
+    (0x9abe, 0x1aaf),
+    (0x9abf, 0x1ab0),
+    (0x9ac1, 0x1ab1),
+    (0x9ac3, 0x1ab2),
+    (0x9ac6, 0x1ab3),
+    (0x9ac8, 0x1ab4),
+    (0x9ace, 0x1ab5),
+    (0x9ad0, 0x1ab6),
+    (0x9ad2, 0x1ab7),
+    (0x9ad5, 0x1ab8),
+    (0x9ad6, 0x1ab9),
+    (0x9ad7, 0x1aba),
+    (0x9adb, 0x1abb),
+    (0x9adc, 0x1abc),
+    (0x9ae0, 0x1abd),
+    (0x9ae4, 0x1abe),
+    (0x9ae5, 0x1abf),
+    (0x9ae7, 0x1ac0),
+    (0x9ae9, 0x1ac1),
+    (0x9aec, 0x1ac2),
+    (0x9af2, 0x1ac3),
+    (0x9af3, 0x1ac4),
+    (0x9af5, 0x1ac5),
+    (0x9af9, 0x1ac6),
+    (0x9afa, 0x1ac7),
+    (0x9afd, 0x1ac8),
+    (0x9aff, 0x1ac9),
+    (0x9b00, 0x1aca),
+    (0x9b01, 0x1acb),
+    (0x9b02, 0x1acc),
+    (0x9b03, 0x1acd),
+    (0x9b04, 0x1ace),
+    (0x9b05, 0x1acf),
+    (0x9b08, 0x1ad0),
+    (0x9b09, 0x1ad1),
+    (0x9b0b, 0x1ad2),
+    (0x9b0c, 0x1ad3),
+    (0x9b0d, 0x1ad4),
+    (0x9b0e, 0x1ad5),
+    (0x9b10, 0x1ad6),
+    (0x9b12, 0x1ad7),
+    (0x9b16, 0x1ad8),
+    (0x9b19, 0x1ad9),
+    (0x9b1b, 0x1ada),
+    (0x9b1c, 0x1adb),
+    (0x9b20, 0x1adc),
+    (0x9b26, 0x1add),
+    (0x9b2b, 0x1ade),
+    (0x9b2d, 0x1adf),
+    (0x9b33, 0x1ae0),
+    (0x9b34, 0x1ae1),
+    (0x9b35, 0x1ae2),
+    (0x9b37, 0x1ae3),
+    (0x9b39, 0x1ae4),
+    (0x9b3a, 0x1ae5),
+    (0x9b3d, 0x1ae6),
+    (0x9b48, 0x1ae7),
+    (0x9b4b, 0x1ae8),
+    (0x9b4c, 0x1ae9),
+    (0x9b55, 0x1aea),
+    (0x9b56, 0x1aeb),
+    (0x9b57, 0x1aec),
+    (0x9b5b, 0x1aed),
+    (0x9b5e, 0x1aee),
+    (0x9b61, 0x1aef),
+    (0x9b63, 0x1af0),
+    (0x9b65, 0x1af1),
+    (0x9b66, 0x1af2),
+    (0x9b68, 0x1af3),
+    (0x9b6a, 0x1af4),
+    (0x9b6b, 0x1af5),
+    (0x9b6c, 0x1af6),
+    (0x9b6d, 0x1af7),
+    (0x9b6e, 0x1af8),
+    (0x9b73, 0x1af9),
+    (0x9b75, 0x1afa),
+    (0x9b77, 0x1afb),
+    (0x9b78, 0x1afc),
+    (0x9b79, 0x1afd),
+    (0x9b7f, 0x1afe),
+    (0x9b80, 0x1aff),
+    (0x9b84, 0x1b00),
+    (0x9b85, 0x1b01),
+    (0x9b86, 0x1b02),
+    (0x9b87, 0x1b03),
+    (0x9b89, 0x1b04),
+    (0x9b8a, 0x1b05),
+    (0x9b8b, 0x1b06),
+    (0x9b8d, 0x1b07),
+    (0x9b8f, 0x1b08),
+    (0x9b90, 0x1b09),
+    (0x9b94, 0x1b0a),
+    (0x9b9a, 0x1b0b),
+    (0x9b9d, 0x1b0c),
+    (0x9b9e, 0x1b0d),
+    (0x9ba6, 0x1b0e),
+    (0x9ba7, 0x1b0f),
+    (0x9ba9, 0x1b10),
+    (0x9bac, 0x1b11),
+    (0x9bb0, 0x1b12),
+    (0x9bb1, 0x1b13),
+    (0x9bb2, 0x1b14),
+    (0x9bb7, 0x1b15),
+    (0x9bb8, 0x1b16),
+    (0x9bbb, 0x1b17),
+    (0x9bbc, 0x1b18),
+    (0x9bbe, 0x1b19),
+    (0x9bbf, 0x1b1a),
+    (0x9bc1, 0x1b1b),
+    (0x9bc7, 0x1b1c),
+    (0x9bc8, 0x1b1d),
+    (0x9bce, 0x1b1e),
+    (0x9bd0, 0x1b1f),
+    (0x9bd7, 0x1b20),
+    (0x9bd8, 0x1b21),
+    (0x9bdd, 0x1b22),
+    (0x9bdf, 0x1b23),
+    (0x9be5, 0x1b24),
+    (0x9be7, 0x1b25),
+    (0x9bea, 0x1b26),
+    (0x9beb, 0x1b27),
+    (0x9bef, 0x1b28),
+    (0x9bf3, 0x1b29),
+    (0x9bf7, 0x1b2a),
+    (0x9bf8, 0x1b2b),
+    (0x9bf9, 0x1b2c),
+    (0x9bfa, 0x1b2d),
+    (0x9bfd, 0x1b2e),
+    (0x9bff, 0x1b2f),
+    (0x9c00, 0x1b30),
+    (0x9c02, 0x1b31),
+    (0x9c0b, 0x1b32),
+    (0x9c0f, 0x1b33),
+    (0x9c11, 0x1b34),
+    (0x9c16, 0x1b35),
+    (0x9c18, 0x1b36),
+    (0x9c19, 0x1b37),
+    (0x9c1a, 0x1b38),
+    (0x9c1c, 0x1b39),
+    (0x9c1e, 0x1b3a),
+    (0x9c22, 0x1b3b),
+    (0x9c23, 0x1b3c),
+    (0x9c26, 0x1b3d),
+    (0x9c27, 0x1b3e),
+    (0x9c28, 0x1b3f),
+    (0x9c29, 0x1b40),
+    (0x9c2a, 0x1b41),
+    (0x9c31, 0x1b42),
+    (0x9c35, 0x1b43),
+    (0x9c36, 0x1b44),
+    (0x9c37, 0x1b45),
+    (0x9c3d, 0x1b46),
+    (0x9c41, 0x1b47),
+    (0x9c43, 0x1b48),
+    (0x9c44, 0x1b49),
+    (0x9c45, 0x1b4a),
+    (0x9c49, 0x1b4b),
+    (0x9c4a, 0x1b4c),
+    (0x9c4e, 0x1b4d),
+    (0x9c4f, 0x1b4e),
+    (0x9c50, 0x1b4f),
+    (0x9c53, 0x1b50),
+    (0x9c54, 0x1b51),
+    (0x9c56, 0x1b52),
+    (0x9c58, 0x1b53),
+    (0x9c5b, 0x1b54),
+    (0x9c5c, 0x1b5b),
+    (0x9c5d, 0x1b55),
+    (0x9c5e, 0x1b56),
+    (0x9c5f, 0x1b57),
+    (0x9c63, 0x1b58),
+    (0x9c68, 0x1b5d),
+    (0x9c69, 0x1b59),
+    (0x9c6a, 0x1b5a),
+    (0x9c6b, 0x1b5c),
+    (0x9c6e, 0x1b5e),
+    (0x9c70, 0x1b5f),
+    (0x9c72, 0x1b60),
+    (0x9c75, 0x1b61),
+    (0x9c77, 0x1b62),
+    (0x9c7b, 0x1b63),
+    (0x9ce6, 0x1b64),
+    (0x9cf2, 0x1b65),
+    (0x9cf7, 0x1b66),
+    (0x9cf9, 0x1b67),
+    (0x9d02, 0x1b69),
+    (0x9d0b, 0x1b68),
+    (0x9d11, 0x1b6a),
+    (0x9d17, 0x1b6b),
+    (0x9d18, 0x1b6c),
+    (0x9d1c, 0x1b6d),
+    (0x9d1d, 0x1b6e),
+    (0x9d1e, 0x1b6f),
+    (0x9d2f, 0x1b70),
+    (0x9d30, 0x1b71),
+    (0x9d32, 0x1b72),
+    (0x9d33, 0x1b73),
+    (0x9d34, 0x1b74),
+    (0x9d3a, 0x1b75),
+    (0x9d3c, 0x1b76),
+    (0x9d3d, 0x1b78),
+    (0x9d42, 0x1b79),
+    (0x9d43, 0x1b7a),
+    (0x9d45, 0x1b77),
+    (0x9d47, 0x1b7b),
+    (0x9d4a, 0x1b7c),
+    (0x9d53, 0x1b7d),
+    (0x9d54, 0x1b7e),
+    (0x9d5f, 0x1b7f),
+    (0x9d62, 0x1b81),
+    (0x9d63, 0x1b80),
+    (0x9d65, 0x1b82),
+    (0x9d69, 0x1b83),
+    (0x9d6a, 0x1b84),
+    (0x9d6b, 0x1b85),
+    (0x9d70, 0x1b86),
+    (0x9d76, 0x1b87),
+    (0x9d77, 0x1b88),
+    (0x9d7b, 0x1b89),
+    (0x9d7c, 0x1b8a),
+    (0x9d7e, 0x1b8b),
+    (0x9d83, 0x1b8c),
+    (0x9d84, 0x1b8d),
+    (0x9d86, 0x1b8e),
+    (0x9d8a, 0x1b8f),
+    (0x9d8d, 0x1b90),
+    (0x9d8e, 0x1b91),
+    (0x9d92, 0x1b92),
+    (0x9d93, 0x1b93),
+    (0x9d95, 0x1b94),
+    (0x9d96, 0x1b95),
+    (0x9d97, 0x1b96),
+    (0x9d98, 0x1b97),
+    (0x9da1, 0x1b98),
+    (0x9daa, 0x1b99),
+    (0x9dac, 0x1b9a),
+    (0x9dae, 0x1b9b),
+    (0x9db1, 0x1b9c),
+    (0x9db5, 0x1b9d),
+    (0x9db9, 0x1b9e),
+    (0x9dbc, 0x1b9f),
+    (0x9dbf, 0x1ba0),
+    (0x9dc3, 0x1ba1),
+    (0x9dc7, 0x1ba2),
+    (0x9dc9, 0x1ba3),
+    (0x9dca, 0x1ba4),
+    (0x9dd4, 0x1ba5),
+    (0x9dd5, 0x1ba6),
+    (0x9dd6, 0x1ba7),
+    (0x9dd7, 0x1ba8),
+    (0x9dda, 0x1ba9),
+    (0x9dde, 0x1baa),
+    (0x9ddf, 0x1bab),
+    (0x9de0, 0x1bac),
+    (0x9de5, 0x1bad),
+    (0x9de7, 0x1bae),
+    (0x9de9, 0x1baf),
+    (0x9deb, 0x1bb0),
+    (0x9dee, 0x1bb1),
+    (0x9df0, 0x1bb2),
+    (0x9df3, 0x1bb3),
+    (0x9df4, 0x1bb4),
+    (0x9dfe, 0x1bb5),
+    (0x9e02, 0x1bb7),
+    (0x9e07, 0x1bb8),
+    (0x9e0a, 0x1bb6),
+    (0x9e0e, 0x1bb9),
+    (0x9e10, 0x1bba),
+    (0x9e11, 0x1bbb),
+    (0x9e12, 0x1bbc),
+    (0x9e15, 0x1bbd),
+    (0x9e16, 0x1bbe),
+    (0x9e19, 0x1bbf),
+    (0x9e1c, 0x1bc0),
+    (0x9e1d, 0x1bc1),
+    (0x9e7a, 0x1bc2),
+    (0x9e7b, 0x1bc3),
+    (0x9e7c, 0x1bc4),
+    (0x9e80, 0x1bc5),
+    (0x9e82, 0x1bc6),
+    (0x9e83, 0x1bc7),
+    (0x9e84, 0x1bc8),
+    (0x9e85, 0x1bc9),
+    (0x9e87, 0x1bca),
+    (0x9e8e, 0x1bcb),
+    (0x9e8f, 0x1bcc),
+    (0x9e96, 0x1bcd),
+    (0x9e98, 0x1bce),
+    (0x9e9b, 0x1bcf),
+    (0x9e9e, 0x1bd0),
+    (0x9ea4, 0x1bd1),
+    (0x9ea8, 0x1bd2),
+    (0x9eac, 0x1bd3),
+    (0x9eae, 0x1bd4),
+    (0x9eaf, 0x1bd5),
+    (0x9eb0, 0x1bd6),
+    (0x9eb3, 0x1bd7),
+    (0x9eb4, 0x1bd8),
+    (0x9eb5, 0x1bd9),
+    (0x9ec6, 0x1bda),
+    (0x9ec8, 0x1bdb),
+    (0x9ecb, 0x1bdc),
+    (0x9ed5, 0x1bdd),
+    (0x9edf, 0x1bde),
+    (0x9ee4, 0x1bdf),
+    (0x9ee7, 0x1be0),
+    (0x9eec, 0x1be1),
+    (0x9eed, 0x1be2),
+    (0x9eee, 0x1be3),
+    (0x9ef0, 0x1be4),
+    (0x9ef1, 0x1be5),
+    (0x9ef2, 0x1be6),
+    (0x9ef5, 0x1be7),
+    (0x9ef8, 0x1be8),
+    (0x9eff, 0x1be9),
+    (0x9f02, 0x1bea),
+    (0x9f03, 0x1beb),
+    (0x9f09, 0x1bec),
+    (0x9f0f, 0x1bed),
+    (0x9f10, 0x1bee),
+    (0x9f11, 0x1bef),
+    (0x9f12, 0x1bf0),
+    (0x9f14, 0x1bf1),
+    (0x9f16, 0x1bf2),
+    (0x9f17, 0x1bf3),
+    (0x9f19, 0x1bf4),
+    (0x9f1a, 0x1bf5),
+    (0x9f1b, 0x1bf6),
+    (0x9f1f, 0x1bf7),
+    (0x9f22, 0x1bf8),
+    (0x9f26, 0x1bf9),
+    (0x9f2a, 0x1bfa),
+    (0x9f2b, 0x1bfb),
+    (0x9f2f, 0x1bfc),
+    (0x9f31, 0x1bfd),
+    (0x9f32, 0x1bfe),
+    (0x9f34, 0x1bff),
+    (0x9f37, 0x1c00),
+    (0x9f39, 0x1c01),
+    (0x9f3a, 0x1c02),
+    (0x9f3c, 0x1c03),
+    (0x9f3d, 0x1c04),
+    (0x9f3f, 0x1c05),
+    (0x9f41, 0x1c06),
+    (0x9f43, 0x1c07),
+    (0x9f44, 0x1c08),
+    (0x9f45, 0x1c09),
+    (0x9f46, 0x1c0a),
+    (0x9f47, 0x1c0b),
+    (0x9f53, 0x1c0c),
+    (0x9f55, 0x1c0d),
+    (0x9f56, 0x1c0e),
+    (0x9f57, 0x1c0f),
+    (0x9f58, 0x1c10),
+    (0x9f5a, 0x1c11),
+    (0x9f5d, 0x1c12),
+    (0x9f5e, 0x1c13),
+    (0x9f68, 0x1c14),
+    (0x9f69, 0x1c15),
+    (0x9f6d, 0x1c16),
+    (0x9f6e, 0x1c17),
+    (0x9f6f, 0x1c18),
+    (0x9f70, 0x1c19),
+    (0x9f71, 0x1c1a),
+    (0x9f73, 0x1c1b),
+    (0x9f75, 0x1c1c),
+    (0x9f7a, 0x1c1d),
+    (0x9f7d, 0x1c1e),
+    (0x9f8f, 0x1c1f),
+    (0x9f90, 0x1c20),
+    (0x9f91, 0x1c21),
+    (0x9f92, 0x1c22),
+    (0x9f94, 0x1c23),
+    (0x9f96, 0x1c24),
+    (0x9f97, 0x1c25),
+    (0x9f9e, 0x1c26),
+    (0x9fa1, 0x1c27),
+    (0x9fa2, 0x1c28),
+    (0x9fa3, 0x1c29),
+    (0x9fa5, 0x1c2a),
+];
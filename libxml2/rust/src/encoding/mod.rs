@@ -1 +1,33 @@
-// Empty stub module for encoding
+//! Rust implementation of encoding module
+//!
+//! Built-in converters between UTF-8 and Latin-1, ASCII, UTF-16 and UTF-32,
+//! and Shift-JIS, EUC-JP and Big5 with the `shift-jis`, `euc-jp` and `big5`
+//! features, and a decoder for input that arrives in pieces. The
+//! converters are always built; `ffi` replaces the `encoding.c` exports
+//! under `rust-encoding`.
+
+#[cfg(feature = "big5")]
+pub mod big5;
+#[cfg(feature = "big5")]
+mod big5_table;
+pub mod core;
+pub mod decoder;
+#[cfg(feature = "euc-jp")]
+pub mod euc_jp;
+#[cfg(feature = "rust-encoding")]
+pub mod ffi;
+#[cfg(any(feature = "shift-jis", feature = "euc-jp"))]
+mod jis0208;
+#[cfg(feature = "euc-jp")]
+mod jis0212;
+#[cfg(feature = "shift-jis")]
+pub mod sjis;
+
+#[cfg(feature = "big5")]
+pub use big5::*;
+pub use core::*;
+pub use decoder::*;
+#[cfg(feature = "euc-jp")]
+pub use euc_jp::*;
+#[cfg(feature = "shift-jis")]
+pub use sjis::*;
@@ -0,0 +1,120 @@
+//! Shift-JIS converters, built with the `shift-jis` feature.
+//!
+//! Single bytes are ASCII and half-width katakana. A lead byte in
+//! 0x81-0x9F or 0xE0-0xEA and a trail byte in 0x40-0xFC (but 0x7F) select
+//! a character from two rows of JIS X 0208. Rows past 84 are unassigned,
+//! so 0xEB-0xEF are not lead bytes, as in glibc.
+
+use super::core::{decode_utf8, encode_utf8, utf8_size, Converted, ENC_ERR_INPUT, ENC_ERR_SPACE};
+use super::jis0208::{JIS0208, JIS0208_INVERSE};
+
+/// JIS X 0208 grid index of a lead and trail byte.
+fn grid_index(lead: u8, trail: u8) -> Option<usize> {
+    let row = match lead {
+        0x81..=0x9F => lead - 0x81,
+        0xE0..=0xEA => lead - 0xC1,
+        _ => return None,
+    } as usize
+        * 2;
+    let (row, cell) = match trail {
+        0x40..=0x7E => (row, trail - 0x40),
+        0x80..=0x9E => (row, trail - 0x41),
+        0x9F..=0xFC => (row + 1, trail - 0x9F),
+        _ => return None,
+    };
+    Some(row * 94 + cell as usize)
+}
+
+/// Lead and trail byte of a JIS X 0208 grid index.
+fn grid_bytes(index: usize) -> [u8; 2] {
+    let (row, cell) = (index / 94, (index % 94) as u8);
+    let lead = (row / 2) as u8 + if row < 62 { 0x81 } else { 0xC1 };
+    let trail = if row % 2 == 1 {
+        cell + 0x9F
+    } else if cell < 63 {
+        cell + 0x40
+    } else {
+        cell + 0x41
+    };
+    [lead, trail]
+}
+
+/// Shift-JIS bytes for `c`, and how many of them are used.
+fn sjis_bytes(c: u32) -> Option<([u8; 2], usize)> {
+    match c {
+        0..=0x7F => Some(([c as u8, 0], 1)),
+        0xFF61..=0xFF9F => Some(([(c - 0xFF61) as u8 + 0xA1, 0], 1)),
+        0x80..=0xFFFF => {
+            let found = JIS0208_INVERSE.binary_search_by_key(&(c as u16), |&(cp, _)| cp);
+            Some((grid_bytes(JIS0208_INVERSE[found.ok()?].1 as usize), 2))
+        }
+        _ => None,
+    }
+}
+
+/// Convert Shift-JIS to UTF-8. Bytes that start no character, trail bytes
+/// out of range and unassigned pairs are invalid input. A lead byte at the
+/// end of the input is left unread.
+pub fn sjis_to_utf8(out: &mut [u8], input: &[u8]) -> Converted {
+    let mut i = 0;
+    let mut o = 0;
+    while i < input.len() {
+        let b = input[i];
+        let (c, len) = match b {
+            0x00..=0x7F => (b as u32, 1),
+            0xA1..=0xDF => (0xFF61 + (b - 0xA1) as u32, 1),
+            _ => {
+                let Some(&trail) = input.get(i + 1) else {
+                    if grid_index(b, 0x40).is_some() {
+                        break;
+                    }
+                    return Converted::stop(i, o, ENC_ERR_INPUT);
+                };
+                match grid_index(b, trail).map(|k| JIS0208[k]) {
+                    Some(c) if c != 0 => (c as u32, 2),
+                    _ => return Converted::stop(i, o, ENC_ERR_INPUT),
+                }
+            }
+        };
+        let size = utf8_size(c);
+        if out.len() - o < size {
+            return Converted::stop(i, o, ENC_ERR_SPACE);
+        }
+        encode_utf8(&mut out[o..o + size], c);
+        i += len;
+        o += size;
+    }
+    Converted::done(i, o)
+}
+
+/// Convert UTF-8 to Shift-JIS. Characters outside ASCII, half-width
+/// katakana and JIS X 0208 are invalid input, as are UTF-8 errors. A
+/// cut-off sequence ends the input even when the output is full;
+/// otherwise a full output is reported first.
+pub fn utf8_to_sjis(out: &mut [u8], input: &[u8]) -> Converted {
+    let mut i = 0;
+    let mut o = 0;
+    while i < input.len() {
+        let decoded = if input[i] < 0x80 {
+            Ok(Some((input[i] as u32, 1)))
+        } else {
+            decode_utf8(&input[i..])
+        };
+        let (c, len) = match decoded {
+            Ok(None) => break,
+            _ if o >= out.len() => return Converted::stop(i, o, ENC_ERR_SPACE),
+            Ok(Some(decoded)) => decoded,
+            Err(()) => return Converted::stop(i, o, ENC_ERR_INPUT),
+        };
+        let Some((bytes, size)) = sjis_bytes(c) else {
+            return Converted::stop(i, o, ENC_ERR_INPUT);
+        };
+        if out.len() - o < size {
+            return Converted::stop(i, o, ENC_ERR_SPACE);
+        }
+        out[o..o + size].copy_from_slice(&bytes[..size]);
+        i += len;
+        o += size;
+    }
+    Converted::done(i, o)
+}
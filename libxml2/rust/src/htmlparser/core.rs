@@ -0,0 +1,143 @@
+use super::entities::ENTITIES;
+
+/// Longest entity name, `CounterClockwiseContourIntegral`.
+const MAX_NAME_LEN: usize = 31;
+
+/// What numeric references to 0x80-0x9F stand for, `htmlC1Remap` in C.
+const C1_REMAP: [u16; 32] = [
+    0x20AC, 0x0081, 0x201A, 0x0192, 0x201E, 0x2026, 0x2020, 0x2021, 0x02C6, 0x2030, 0x0160, 0x2039,
+    0x0152, 0x008D, 0x017D, 0x008F, 0x0090, 0x2018, 0x2019, 0x201C, 0x201D, 0x2022, 0x2013, 0x2014,
+    0x02DC, 0x2122, 0x0161, 0x203A, 0x0153, 0x009D, 0x017E, 0x0178,
+];
+
+/// The named reference at the start of `input`, which follows an `&`, as
+/// the number of bytes it takes up and its replacement. Like
+/// `htmlFindEntityPrefix`, the longest name wins. The trailing `;` may be
+/// left out for the entities HTML 4 had, unless in an attribute the name
+/// is followed by a letter, a digit or `=`.
+pub fn find_entity_prefix(input: &[u8], is_attr: bool) -> Option<(usize, &'static str)> {
+    if !input.first()?.is_ascii_alphabetic() {
+        return None;
+    }
+    let run = input
+        .iter()
+        .take(MAX_NAME_LEN)
+        .take_while(|b| b.is_ascii_alphanumeric())
+        .count();
+    let mut found = None;
+    for len in 2..=run {
+        let Ok(k) = ENTITIES.binary_search_by(|entity| entity.0.as_bytes().cmp(&input[..len]))
+        else {
+            continue;
+        };
+        let (_, replacement, semicolon_optional) = ENTITIES[k];
+        let term = input.get(len).copied();
+        let terminated = term == Some(b';')
+            || semicolon_optional
+                && (!is_attr || !term.is_some_and(|t| t.is_ascii_alphanumeric() || t == b'='));
+        if terminated {
+            found = Some((len + (term == Some(b';')) as usize, replacement));
+        }
+    }
+    found
+}
+
+/// The character a numeric reference stands for, as
+/// `htmlCodePointToUtf8` maps it: 0x80-0x9F as Windows-1252, and NUL,
+/// surrogates and values past U+10FFFF as U+FFFD.
+pub fn code_point_to_char(cp: u32) -> char {
+    let cp = match cp {
+        0x80..=0x9F => C1_REMAP[(cp - 0x80) as usize] as u32,
+        cp => cp,
+    };
+    char::from_u32(cp)
+        .filter(|&c| c != '\0')
+        .unwrap_or(char::REPLACEMENT_CHARACTER)
+}
+
+/// The numeric reference at the start of `input`, which follows `&#`, as
+/// the number of bytes it takes up and its character. `None` without a
+/// digit, in which case the `&` is kept.
+pub(super) fn numeric_ref(input: &[u8]) -> Option<(usize, char)> {
+    let (radix, start) = match input {
+        [b'x' | b'X', d, ..] if d.is_ascii_hexdigit() => (16, 1),
+        [d, ..] if d.is_ascii_digit() => (10, 0),
+        _ => return None,
+    };
+    let mut cp: u32 = 0;
+    let mut len = start;
+    while let Some(digit) = input.get(len).and_then(|&b| (b as char).to_digit(radix)) {
+        cp = (cp * radix + digit).min(0x110000);
+        len += 1;
+    }
+    if input.get(len) == Some(&b';') {
+        len += 1;
+    }
+    Some((len, code_point_to_char(cp)))
+}
+
+/// Replace the named and numeric character references in `input`, text
+/// when `is_attr` is false and an attribute value otherwise. An `&` that
+/// starts no reference is kept. Nothing else is changed: the parser's
+/// handling of NUL and carriage returns is separate.
+pub fn decode_refs(input: &[u8], is_attr: bool) -> Vec<u8> {
+    let mut out = Vec::with_capacity(input.len());
+    let mut i = 0;
+    while let Some(amp) = input[i..].iter().position(|&b| b == b'&') {
+        out.extend_from_slice(&input[i..i + amp]);
+        i += amp + 1;
+        let rest = &input[i..];
+        let decoded = match rest.first() {
+            Some(b'#') => numeric_ref(&rest[1..]).map(|(len, c)| {
+                out.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes());
+                len + 1
+            }),
+            _ => find_entity_prefix(rest, is_attr).map(|(len, replacement)| {
+                out.extend_from_slice(replacement.as_bytes());
+                len
+            }),
+        };
+        match decoded {
+            Some(len) => i += len,
+            None => out.push(b'&'),
+        }
+    }
+    out.extend_from_slice(&input[i..]);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decode(input: &str, is_attr: bool) -> String {
+        String::from_utf8(decode_refs(input.as_bytes(), is_attr)).unwrap()
+    }
+
+    #[test]
+    fn test_table_is_sorted() {
+        assert!(ENTITIES.windows(2).all(|w| w[0].0 < w[1].0));
+        assert_eq!(ENTITIES.iter().map(|e| e.0.len()).max(), Some(MAX_NAME_LEN));
+    }
+
+    #[test]
+    fn test_longest_match() {
+        assert_eq!(
+            decode("&notin; &notit; &not", false),
+            "\u{2209} \u{ac}it; \u{ac}"
+        );
+        assert_eq!(decode("&amp;&ampx &ampersand", false), "&&x &ersand");
+        assert_eq!(decode("&ampx &amp= &amp", true), "&ampx &amp= &");
+        assert_eq!(decode("&bogus; & &; &1", false), "&bogus; & &; &1");
+    }
+
+    #[test]
+    fn test_numeric() {
+        assert_eq!(
+            decode("&#65;&#x42&#X43;&#0;&#128;", false),
+            "ABC\u{fffd}\u{20ac}"
+        );
+        assert_eq!(decode("&#xD800;&#99999999999;", false), "\u{fffd}\u{fffd}");
+        assert_eq!(decode("&#; &#x; &#xg", false), "&#; &#x; &#xg");
+    }
+}
@@ -1 +1,15 @@
-// Empty stub module for htmlparser
+//! Rust implementation of htmlparser module
+//!
+//! Character references as the HTML5 tokenizer decodes them in text and
+//! attribute values, with the HTML5 named entity table, the text of the
+//! elements whose content is not parsed as markup, and the placement of
+//! elements with implied `html`, `head` and `body`
+
+pub mod core;
+pub mod entities;
+pub mod text;
+pub mod tree;
+
+pub use core::*;
+pub use text::*;
+pub use tree::*;
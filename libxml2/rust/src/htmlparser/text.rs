@@ -0,0 +1,205 @@
+use super::core::{find_entity_prefix, numeric_ref};
+
+/// How the tokenizer reads the content of an element that is not parsed
+/// as markup, the `dataMode` of its `htmlElemDesc`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DataMode {
+    /// `textarea` and `title`: text with character references, up to the
+    /// end tag.
+    RcData,
+    /// `style`, `xmp`, `iframe`, `noembed` and `noframes`: text as is, up
+    /// to the end tag.
+    RawText,
+    /// `plaintext`: text as is, up to the end of the input.
+    PlainText,
+    /// `script`: text as is, up to an end tag that is not inside a
+    /// `<!--` escape holding a `<script>`.
+    Script,
+}
+
+/// The data mode of the element `name`, lower case as the parser stores
+/// it, or `None` for elements whose content is markup.
+pub fn data_mode(name: &[u8]) -> Option<DataMode> {
+    match name {
+        b"textarea" | b"title" => Some(DataMode::RcData),
+        b"style" | b"xmp" | b"iframe" | b"noembed" | b"noframes" => Some(DataMode::RawText),
+        b"plaintext" => Some(DataMode::PlainText),
+        b"script" => Some(DataMode::Script),
+        _ => None,
+    }
+}
+
+/// Where script data stands with respect to `<!--` escapes,
+/// `DATA_SCRIPT_ESC1` and `DATA_SCRIPT_ESC2` in C.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Escape {
+    None,
+    Escaped,
+    DoubleEscaped,
+}
+
+/// Whether `tag`, which follows `<` or `</`, is `name` in any case and
+/// then `>`, `/` or whitespace.
+fn is_tag(tag: &[u8], name: &[u8]) -> bool {
+    tag.len() > name.len()
+        && name.iter().zip(tag).all(|(&n, &c)| n == c | 0x20)
+        && matches!(
+            tag[name.len()],
+            b'>' | b'/' | b'\t' | b'\n' | b'\x0c' | b'\r' | b' '
+        )
+}
+
+/// The length of the UTF-8 sequence at the start of `input`, which starts
+/// with a byte from 0x80, or `None` if it is invalid, as
+/// `htmlValidateUtf8` checks it.
+fn utf8_len(input: &[u8]) -> Option<usize> {
+    let c = input[0] as u32;
+    let byte = |i: usize| input.get(i).map(|&b| b as u32);
+    if c < 0xC2 {
+        None
+    } else if c < 0xE0 {
+        (byte(1)? & 0xC0 == 0x80).then_some(2)
+    } else if c < 0xF0 {
+        let v = c << 16 | byte(1)? << 8 | byte(2)?;
+        let valid =
+            v & 0x00C0C0 == 0x008080 && v & 0x0F2000 != 0x000000 && v & 0x0F2000 != 0x0D2000;
+        valid.then_some(3)
+    } else {
+        let v = c << 24 | byte(1)? << 16 | byte(2)? << 8 | byte(3)?;
+        let valid = v & 0x00C0C0C0 == 0x00808080 && (0xF0900000..0xF4900000).contains(&v);
+        valid.then_some(4)
+    }
+}
+
+/// The text content of the element `name`, lower case, read in `mode`
+/// from `input`, which follows its start tag. Returns the text and the
+/// offset of the end tag, or the length of `input` if there is none.
+///
+/// This is `htmlParseCharData` on complete input in a non-zero mode: `<`
+/// never starts markup, only a `</name` followed by `>`, `/` or
+/// whitespace ends the text. Character references are decoded in RCDATA
+/// only. NUL becomes U+FFFD, CR LF and lone CR become LF, and invalid
+/// UTF-8 bytes each become U+FFFD.
+pub fn parse_raw_text(input: &[u8], name: &[u8], mode: DataMode) -> (Vec<u8>, usize) {
+    let mut out = Vec::with_capacity(input.len());
+    let mut escape = Escape::None;
+    let mut i = 0;
+    while i < input.len() {
+        let rest = &input[i..];
+        let size = match rest[0] {
+            b'<' if mode != DataMode::PlainText => {
+                if mode == DataMode::Script && escape == Escape::None && rest.get(1) == Some(&b'!')
+                {
+                    if rest[2..].starts_with(b"--") {
+                        escape = Escape::Escaped;
+                    }
+                } else {
+                    let solidus = rest.get(1) == Some(&b'/');
+                    if (solidus || escape == Escape::Escaped)
+                        && is_tag(&rest[1 + solidus as usize..], name)
+                    {
+                        match (escape, solidus) {
+                            (Escape::Escaped, false) => escape = Escape::DoubleEscaped,
+                            (Escape::DoubleEscaped, _) => escape = Escape::Escaped,
+                            _ => return (out, i),
+                        }
+                    }
+                }
+                1
+            }
+            b'-' if escape != Escape::None => {
+                if rest[1..].starts_with(b"->") {
+                    escape = Escape::None;
+                }
+                1
+            }
+            b'&' if mode == DataMode::RcData => {
+                let decoded = match rest.get(1) {
+                    Some(b'#') => numeric_ref(&rest[2..]).map(|(len, c)| {
+                        out.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes());
+                        len + 2
+                    }),
+                    _ => find_entity_prefix(&rest[1..], false).map(|(len, replacement)| {
+                        out.extend_from_slice(replacement.as_bytes());
+                        len + 1
+                    }),
+                };
+                if let Some(len) = decoded {
+                    i += len;
+                    continue;
+                }
+                1
+            }
+            b'\0' => {
+                out.extend_from_slice("\u{fffd}".as_bytes());
+                i += 1;
+                continue;
+            }
+            b'\r' => {
+                out.push(b'\n');
+                i += if rest.get(1) == Some(&b'\n') { 2 } else { 1 };
+                continue;
+            }
+            0x80.. => match utf8_len(rest) {
+                Some(len) => len,
+                None => {
+                    out.extend_from_slice("\u{fffd}".as_bytes());
+                    i += 1;
+                    continue;
+                }
+            },
+            _ => 1,
+        };
+        out.extend_from_slice(&rest[..size]);
+        i += size;
+    }
+    (out, i)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(input: &str, name: &str) -> (String, usize) {
+        let mode = data_mode(name.as_bytes()).unwrap();
+        let (text, end) = parse_raw_text(input.as_bytes(), name.as_bytes(), mode);
+        (String::from_utf8(text).unwrap(), end)
+    }
+
+    #[test]
+    fn test_end_tag() {
+        assert_eq!(
+            parse("a<b>&amp;</STYLE >", "style"),
+            ("a<b>&amp;".into(), 9)
+        );
+        assert_eq!(
+            parse("</stylex></style", "style"),
+            ("</stylex></style".into(), 16)
+        );
+        assert_eq!(parse("&amp;&lt</title>", "title"), ("&<".into(), 8));
+        assert_eq!(
+            parse("</plaintext>", "plaintext"),
+            ("</plaintext>".into(), 12)
+        );
+    }
+
+    #[test]
+    fn test_script_escapes() {
+        let input = "<!--<script>x</script>--></script>";
+        assert_eq!(parse(input, "script"), (input[..25].into(), 25));
+        let input = "<!--<script></script></script>";
+        assert_eq!(parse(input, "script"), (input[..21].into(), 21));
+        let input = "<!-- x --><!-</script>";
+        assert_eq!(parse(input, "script"), (input[..13].into(), 13));
+    }
+
+    #[test]
+    fn test_replacements() {
+        assert_eq!(
+            parse("a\0b\r\nc\rd\u{e9}", "xmp"),
+            ("a\u{fffd}b\nc\nd\u{e9}".into(), 10)
+        );
+        let (text, _) = parse_raw_text(b"\xc3\xe0\x80\x80\xf4\x90", b"xmp", DataMode::RawText);
+        assert_eq!(String::from_utf8(text).unwrap(), "\u{fffd}".repeat(6));
+    }
+}
@@ -0,0 +1,546 @@
+use super::text::{data_mode, DataMode};
+
+/// Pairs of an open element and a start tag that closes it,
+/// `htmlStartClose` in C, sorted for binary search.
+const START_CLOSE: &[(&str, &str)] = &[
+    ("a", "a"),
+    ("a", "fieldset"),
+    ("a", "table"),
+    ("a", "td"),
+    ("a", "th"),
+    ("address", "dd"),
+    ("address", "dl"),
+    ("address", "dt"),
+    ("address", "form"),
+    ("address", "li"),
+    ("address", "ul"),
+    ("b", "center"),
+    ("b", "p"),
+    ("b", "td"),
+    ("b", "th"),
+    ("big", "p"),
+    ("caption", "col"),
+    ("caption", "colgroup"),
+    ("caption", "tbody"),
+    ("caption", "tfoot"),
+    ("caption", "thead"),
+    ("caption", "tr"),
+    ("col", "col"),
+    ("col", "colgroup"),
+    ("col", "tbody"),
+    ("col", "tfoot"),
+    ("col", "thead"),
+    ("col", "tr"),
+    ("colgroup", "colgroup"),
+    ("colgroup", "tbody"),
+    ("colgroup", "tfoot"),
+    ("colgroup", "thead"),
+    ("colgroup", "tr"),
+    ("dd", "dt"),
+    ("dir", "dd"),
+    ("dir", "dl"),
+    ("dir", "dt"),
+    ("dir", "form"),
+    ("dir", "ul"),
+    ("dl", "form"),
+    ("dl", "li"),
+    ("dt", "dd"),
+    ("dt", "dl"),
+    ("font", "center"),
+    ("font", "td"),
+    ("font", "th"),
+    ("form", "form"),
+    ("h1", "fieldset"),
+    ("h1", "form"),
+    ("h1", "li"),
+    ("h1", "p"),
+    ("h1", "table"),
+    ("h2", "fieldset"),
+    ("h2", "form"),
+    ("h2", "li"),
+    ("h2", "p"),
+    ("h2", "table"),
+    ("h3", "fieldset"),
+    ("h3", "form"),
+    ("h3", "li"),
+    ("h3", "p"),
+    ("h3", "table"),
+    ("h4", "fieldset"),
+    ("h4", "form"),
+    ("h4", "li"),
+    ("h4", "p"),
+    ("h4", "table"),
+    ("h5", "fieldset"),
+    ("h5", "form"),
+    ("h5", "li"),
+    ("h5", "p"),
+    ("h5", "table"),
+    ("h6", "fieldset"),
+    ("h6", "form"),
+    ("h6", "li"),
+    ("h6", "p"),
+    ("h6", "table"),
+    ("head", "a"),
+    ("head", "abbr"),
+    ("head", "acronym"),
+    ("head", "address"),
+    ("head", "b"),
+    ("head", "bdo"),
+    ("head", "big"),
+    ("head", "blockquote"),
+    ("head", "body"),
+    ("head", "br"),
+    ("head", "center"),
+    ("head", "cite"),
+    ("head", "code"),
+    ("head", "dd"),
+    ("head", "dfn"),
+    ("head", "dir"),
+    ("head", "div"),
+    ("head", "dl"),
+    ("head", "dt"),
+    ("head", "em"),
+    ("head", "fieldset"),
+    ("head", "font"),
+    ("head", "form"),
+    ("head", "frameset"),
+    ("head", "h1"),
+    ("head", "h2"),
+    ("head", "h3"),
+    ("head", "h4"),
+    ("head", "h5"),
+    ("head", "h6"),
+    ("head", "hr"),
+    ("head", "i"),
+    ("head", "iframe"),
+    ("head", "img"),
+    ("head", "kbd"),
+    ("head", "li"),
+    ("head", "listing"),
+    ("head", "map"),
+    ("head", "menu"),
+    ("head", "ol"),
+    ("head", "p"),
+    ("head", "pre"),
+    ("head", "q"),
+    ("head", "s"),
+    ("head", "samp"),
+    ("head", "small"),
+    ("head", "span"),
+    ("head", "strike"),
+    ("head", "strong"),
+    ("head", "sub"),
+    ("head", "sup"),
+    ("head", "table"),
+    ("head", "tt"),
+    ("head", "u"),
+    ("head", "ul"),
+    ("head", "var"),
+    ("head", "xmp"),
+    ("hr", "form"),
+    ("i", "center"),
+    ("i", "p"),
+    ("i", "td"),
+    ("i", "th"),
+    ("legend", "fieldset"),
+    ("li", "li"),
+    ("link", "body"),
+    ("link", "frameset"),
+    ("listing", "dd"),
+    ("listing", "dl"),
+    ("listing", "dt"),
+    ("listing", "fieldset"),
+    ("listing", "form"),
+    ("listing", "li"),
+    ("listing", "table"),
+    ("listing", "ul"),
+    ("menu", "dd"),
+    ("menu", "dl"),
+    ("menu", "dt"),
+    ("menu", "form"),
+    ("menu", "ul"),
+    ("ol", "form"),
+    ("option", "optgroup"),
+    ("option", "option"),
+    ("p", "address"),
+    ("p", "blockquote"),
+    ("p", "body"),
+    ("p", "caption"),
+    ("p", "center"),
+    ("p", "col"),
+    ("p", "colgroup"),
+    ("p", "dd"),
+    ("p", "dir"),
+    ("p", "div"),
+    ("p", "dl"),
+    ("p", "dt"),
+    ("p", "fieldset"),
+    ("p", "form"),
+    ("p", "frameset"),
+    ("p", "h1"),
+    ("p", "h2"),
+    ("p", "h3"),
+    ("p", "h4"),
+    ("p", "h5"),
+    ("p", "h6"),
+    ("p", "head"),
+    ("p", "hr"),
+    ("p", "li"),
+    ("p", "listing"),
+    ("p", "menu"),
+    ("p", "ol"),
+    ("p", "p"),
+    ("p", "pre"),
+    ("p", "table"),
+    ("p", "tbody"),
+    ("p", "td"),
+    ("p", "tfoot"),
+    ("p", "th"),
+    ("p", "title"),
+    ("p", "tr"),
+    ("p", "ul"),
+    ("p", "xmp"),
+    ("pre", "dd"),
+    ("pre", "dl"),
+    ("pre", "dt"),
+    ("pre", "fieldset"),
+    ("pre", "form"),
+    ("pre", "li"),
+    ("pre", "table"),
+    ("pre", "ul"),
+    ("s", "p"),
+    ("script", "noscript"),
+    ("small", "p"),
+    ("span", "td"),
+    ("span", "th"),
+    ("strike", "p"),
+    ("style", "body"),
+    ("style", "frameset"),
+    ("tbody", "tbody"),
+    ("tbody", "tfoot"),
+    ("td", "tbody"),
+    ("td", "td"),
+    ("td", "tfoot"),
+    ("td", "th"),
+    ("td", "tr"),
+    ("tfoot", "tbody"),
+    ("th", "tbody"),
+    ("th", "td"),
+    ("th", "tfoot"),
+    ("th", "th"),
+    ("th", "tr"),
+    ("thead", "tbody"),
+    ("thead", "tfoot"),
+    ("title", "body"),
+    ("title", "frameset"),
+    ("tr", "tbody"),
+    ("tr", "tfoot"),
+    ("tr", "tr"),
+    ("tt", "p"),
+    ("u", "p"),
+    ("u", "td"),
+    ("u", "th"),
+    ("ul", "address"),
+    ("ul", "form"),
+    ("ul", "menu"),
+    ("ul", "pre"),
+    ("xmp", "dd"),
+    ("xmp", "dl"),
+    ("xmp", "dt"),
+    ("xmp", "fieldset"),
+    ("xmp", "form"),
+    ("xmp", "li"),
+    ("xmp", "table"),
+    ("xmp", "ul"),
+];
+
+/// Elements that never have content, the `empty` flag of
+/// `html40ElementTable`.
+const EMPTY: &[&str] = &[
+    "area", "base", "basefont", "bgsound", "br", "col", "embed", "frame", "hr", "img", "input",
+    "isindex", "keygen", "link", "meta", "param", "source", "track", "wbr",
+];
+
+/// Whether the start tag `new` closes the open element `old`,
+/// `htmlCheckAutoClose`.
+fn closes(new: &[u8], old: &[u8]) -> bool {
+    START_CLOSE
+        .binary_search_by(|(o, n)| (o.as_bytes(), n.as_bytes()).cmp(&(old, new)))
+        .is_ok()
+}
+
+/// `htmlGetEndPriority`: an end tag only closes the elements above its
+/// own if none of them has a higher priority.
+fn end_priority(name: &[u8]) -> u32 {
+    match name {
+        b"div" => 150,
+        b"td" | b"th" => 160,
+        b"tr" => 170,
+        b"thead" | b"tbody" | b"tfoot" => 180,
+        b"table" => 190,
+        b"head" | b"body" => 200,
+        b"html" => 220,
+        _ => 100,
+    }
+}
+
+/// Which of `head` and `body` have been opened so far, `ctxt->html`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Insert {
+    #[default]
+    Initial,
+    InHead,
+    InBody,
+}
+
+/// What the tree builder reports, the SAX callbacks C makes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Event {
+    Start(Vec<u8>),
+    End(Vec<u8>),
+    Text(Vec<u8>),
+    Comment(Vec<u8>),
+}
+
+/// Places elements the way the HTML 4 parser of C does without
+/// `HTML_PARSE_NOIMPLIED`. Start tags close the open elements they may
+/// not be nested in, `html`, `head` and `body` are opened when content
+/// needs them, misplaced `html`, `head` and `body` tags are dropped, and
+/// an end tag only closes the elements above its own if they have no
+/// higher priority.
+///
+/// Element names are lower case, as the parser stores them, and text is
+/// already decoded.
+#[derive(Debug, Default)]
+pub struct TreeBuilder {
+    names: Vec<Vec<u8>>,
+    insert: Insert,
+    /// Dropped `html`, `head` and `body` start tags whose end tags are
+    /// still to be ignored, `ctxt->depth`.
+    depth: usize,
+    events: Vec<Event>,
+}
+
+impl TreeBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The innermost open element.
+    pub fn current(&self) -> Option<&[u8]> {
+        self.names.last().map(Vec::as_slice)
+    }
+
+    fn push(&mut self, name: &[u8]) {
+        if name == b"head" {
+            self.insert = self.insert.max(Insert::InHead);
+        } else if name == b"body" {
+            self.insert = self.insert.max(Insert::InBody);
+        }
+        self.names.push(name.to_vec());
+        self.events.push(Event::Start(name.to_vec()));
+    }
+
+    fn pop(&mut self) {
+        if let Some(name) = self.names.pop() {
+            self.events.push(Event::End(name));
+        }
+    }
+
+    /// `htmlAutoClose`: close the open elements `new` closes.
+    fn auto_close(&mut self, new: &[u8]) {
+        while self.current().is_some_and(|old| closes(new, old)) {
+            self.pop();
+        }
+    }
+
+    /// `htmlCheckImplied`: open `html`, and `head` or `body`, as `new`
+    /// needs them.
+    fn check_implied(&mut self, new: &[u8]) {
+        if new == b"html" {
+            return;
+        }
+        if self.names.is_empty() {
+            self.push(b"html");
+        }
+        if new == b"body" || new == b"head" {
+            return;
+        }
+        if self.names.len() <= 1
+            && matches!(
+                new,
+                b"script" | b"style" | b"meta" | b"link" | b"title" | b"base"
+            )
+        {
+            if self.insert >= Insert::InHead {
+                return;
+            }
+            self.push(b"head");
+        } else if !matches!(new, b"noframes" | b"frame" | b"frameset") {
+            if self.insert >= Insert::InBody
+                || self
+                    .names
+                    .iter()
+                    .any(|name| name == b"body" || name == b"head")
+            {
+                return;
+            }
+            self.push(b"body");
+        }
+    }
+
+    /// A start tag, `htmlParseStartTag` and `htmlParseElementInternal`.
+    /// Returns the data mode of the element if it is left open and its
+    /// content is not markup.
+    ///
+    /// Like C, when the tag is dropped the rest applies to the current
+    /// element instead: `<body/>` inside a body closes the current
+    /// element.
+    pub fn start_tag(&mut self, name: &[u8], self_closing: bool) -> Option<DataMode> {
+        self.auto_close(name);
+        self.check_implied(name);
+
+        let misplaced = match name {
+            b"html" => !self.names.is_empty(),
+            b"head" => self.names.len() != 1,
+            b"body" => self.names.iter().any(|open| open == b"body"),
+            _ => false,
+        };
+        if misplaced {
+            self.depth += 1;
+        } else {
+            self.push(name);
+        }
+
+        let current = self.current()?;
+        if self_closing || EMPTY.iter().any(|empty| empty.as_bytes() == current) {
+            self.pop();
+            return None;
+        }
+        data_mode(current)
+    }
+
+    /// An end tag, `htmlParseEndTag` and `htmlAutoCloseOnClose`. Tags
+    /// matching no open element are ignored.
+    pub fn end_tag(&mut self, name: &[u8]) {
+        if self.depth > 0 && matches!(name, b"html" | b"body" | b"head") {
+            self.depth -= 1;
+            return;
+        }
+        let Some(index) = self.names.iter().rposition(|open| open == name) else {
+            return;
+        };
+        let priority = end_priority(name);
+        if self.names[index + 1..]
+            .iter()
+            .any(|open| end_priority(open) > priority)
+        {
+            return;
+        }
+        while self.names.len() > index {
+            self.pop();
+        }
+    }
+
+    /// Character data, `htmlCharDataSAXCallback`. Outside `html` and in
+    /// `html` and `head` leading whitespace stays where it is, while the
+    /// rest of the text first closes `head` and opens `body`.
+    pub fn text(&mut self, data: &[u8]) {
+        let mut data = data;
+        if data.is_empty() {
+            return;
+        }
+        if matches!(self.current(), None | Some(b"html" | b"head")) {
+            let blanks = data
+                .iter()
+                .take_while(|&&b| matches!(b, b'\t' | b'\n' | b'\x0c' | b'\r' | b' '))
+                .count();
+            if blanks > 0 {
+                self.events.push(Event::Text(data[..blanks].to_vec()));
+                data = &data[blanks..];
+            }
+            if data.is_empty() {
+                return;
+            }
+            // htmlStartCharData
+            if self.current() == Some(b"head") {
+                self.auto_close(b"p");
+            }
+            self.check_implied(b"p");
+        }
+        self.events.push(Event::Text(data.to_vec()));
+    }
+
+    pub fn comment(&mut self, data: &[u8]) {
+        self.events.push(Event::Comment(data.to_vec()));
+    }
+
+    /// Close the elements still open, `htmlAutoCloseOnEnd`, and return
+    /// the events.
+    pub fn finish(mut self) -> Vec<Event> {
+        while !self.names.is_empty() {
+            self.pop();
+        }
+        self.events
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(events: &[Event]) -> Vec<String> {
+        events
+            .iter()
+            .map(|event| match event {
+                Event::Start(name) => String::from_utf8_lossy(name).into_owned(),
+                Event::End(name) => format!("/{}", String::from_utf8_lossy(name)),
+                Event::Text(data) | Event::Comment(data) => {
+                    format!("{:?}", String::from_utf8_lossy(data))
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_table_is_sorted() {
+        assert!(START_CLOSE.windows(2).all(|w| w[0] < w[1]));
+        assert!(closes(b"p", b"head"));
+        assert!(!closes(b"span", b"p"));
+    }
+
+    #[test]
+    fn test_implied() {
+        let mut builder = TreeBuilder::new();
+        assert_eq!(builder.start_tag(b"title", false), Some(DataMode::RcData));
+        builder.text(b"t");
+        builder.end_tag(b"title");
+        builder.text(b" x");
+        assert_eq!(
+            names(&builder.finish()),
+            [
+                "html", "head", "title", "\"t\"", "/title", "\" \"", "/head", "body", "\"x\"",
+                "/body", "/html"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_end_tags() {
+        let mut builder = TreeBuilder::new();
+        builder.start_tag(b"div", false);
+        builder.start_tag(b"body", false);
+        builder.start_tag(b"span", false);
+        // Dropped with the misplaced start tag.
+        builder.end_tag(b"body");
+        builder.end_tag(b"div");
+        builder.start_tag(b"td", false);
+        // td has a higher priority than span.
+        builder.end_tag(b"span");
+        assert_eq!(builder.current(), Some(&b"td"[..]));
+        assert_eq!(
+            names(&builder.finish()),
+            ["html", "body", "div", "span", "/span", "/div", "td", "/td", "/body", "/html"]
+        );
+    }
+}
@@ -0,0 +1,213 @@
+/// Offsets of the charset value inside a meta `content`/`charset` attribute.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MetaEncodingOffsets {
+    pub start: usize,
+    pub end: usize,
+    pub size: usize,
+}
+
+#[inline]
+pub fn is_ws_html(c: u8) -> bool {
+    c == 0x20 || ((0x09..=0x0d).contains(&c) && c != 0x0b)
+}
+
+/// Attributes serialized in minimized form (`<option selected>`), as per
+/// XSLT 1.0 16.2 "HTML Output Method". HTML5 additions are not handled.
+pub fn is_boolean_attr(name: &[u8]) -> bool {
+    const BOOLEAN_ATTRS: &[&[u8]] = &[
+        b"checked", b"compact", b"declare", b"defer", b"disabled", b"ismap",
+        b"multiple", b"nohref", b"noresize", b"noshade", b"nowrap",
+        b"readonly", b"selected",
+    ];
+
+    BOOLEAN_ATTRS.iter().any(|attr| attr.eq_ignore_ascii_case(name))
+}
+
+/// Locate the `charset=` value inside an http-equiv `Content-Type` value.
+pub fn parse_content_type(val: &[u8]) -> Option<MetaEncodingOffsets> {
+    let at = |i: usize| val.get(i).copied().unwrap_or(0);
+    let mut p = 0;
+
+    loop {
+        while at(p) != b'c' && at(p) != b'C' {
+            if at(p) == 0 {
+                return None;
+            }
+            p += 1;
+        }
+        p += 1;
+
+        if p + 6 > val.len() || !val[p..p + 6].eq_ignore_ascii_case(b"harset") {
+            continue;
+        }
+
+        p += 6;
+        while is_ws_html(at(p)) {
+            p += 1;
+        }
+
+        if at(p) != b'=' {
+            continue;
+        }
+
+        p += 1;
+        while is_ws_html(at(p)) {
+            p += 1;
+        }
+
+        if at(p) == 0 {
+            return None;
+        }
+
+        let start;
+        let mut end;
+
+        if at(p) == b'"' || at(p) == b'\'' {
+            let quote = at(p);
+
+            p += 1;
+            while is_ws_html(at(p)) {
+                p += 1;
+            }
+
+            start = p;
+            end = start;
+
+            while at(p) != quote {
+                if at(p) == 0 {
+                    return None;
+                }
+                if !is_ws_html(at(p)) {
+                    end = p + 1;
+                }
+                p += 1;
+            }
+        } else {
+            start = p;
+
+            while at(p) != 0 && at(p) != b';' && !is_ws_html(at(p)) {
+                p += 1;
+            }
+
+            end = p;
+        }
+
+        let size = val.iter().position(|&c| c == 0).unwrap_or(val.len());
+
+        return Some(MetaEncodingOffsets { start, end, size });
+    }
+}
+
+/// Offsets of a bare `charset` attribute value with HTML whitespace trimmed.
+pub fn trim_charset(val: &[u8]) -> MetaEncodingOffsets {
+    let size = val.len();
+    let mut start = 0;
+    let mut end = size;
+
+    while start < size && is_ws_html(val[start]) {
+        start += 1;
+    }
+    while end > 0 && is_ws_html(val[end - 1]) {
+        end -= 1;
+    }
+
+    MetaEncodingOffsets { start, end, size }
+}
+
+/// Escape text for HTML output. Text content escapes `&`, `<` and `>`,
+/// attribute values only `&` and `"`. Non-ASCII bytes are left to the
+/// output encoder. Stops at the first NUL byte.
+pub fn escape_html(text: &[u8], attr: bool, out: &mut Vec<u8>) {
+    for &c in text {
+        match c {
+            0 => break,
+            b'&' => out.extend_from_slice(b"&amp;"),
+            b'<' if !attr => out.extend_from_slice(b"&lt;"),
+            b'>' if !attr => out.extend_from_slice(b"&gt;"),
+            b'"' if attr => out.extend_from_slice(b"&quot;"),
+            _ => out.push(c),
+        }
+    }
+}
+
+/// Serialize a URI attribute value. Non-ASCII characters, space and control
+/// characters are percent-escaped per HTML 4.01 appendix B.2.1, `"` and
+/// `&` become character references. Leading whitespace is kept verbatim.
+pub fn serialize_uri(content: &[u8], out: &mut Vec<u8>) {
+    const HEX: &[u8; 16] = b"0123456789ABCDEF";
+
+    let content = match content.iter().position(|&c| c == 0) {
+        Some(nul) => &content[..nul],
+        None => content,
+    };
+    let leading = content.iter().take_while(|&&c| is_ws_html(c)).count();
+    out.extend_from_slice(&content[..leading]);
+
+    for &c in &content[leading..] {
+        if c <= 0x20 || c >= 0x7f {
+            out.extend_from_slice(&[b'%', HEX[(c >> 4) as usize], HEX[(c & 0x0f) as usize]]);
+        } else if c == b'"' {
+            out.extend_from_slice(b"&quot;");
+        } else if c == b'&' {
+            out.extend_from_slice(b"&amp;");
+        } else {
+            out.push(c);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_boolean_attr() {
+        assert!(is_boolean_attr(b"checked"));
+        assert!(is_boolean_attr(b"SELECTED"));
+        assert!(is_boolean_attr(b"noShade"));
+        assert!(!is_boolean_attr(b"check"));
+        assert!(!is_boolean_attr(b"async"));
+        assert!(!is_boolean_attr(b""));
+    }
+
+    #[test]
+    fn test_parse_content_type() {
+        let val = b"text/html; charset=ISO-8859-1";
+        let off = parse_content_type(val).unwrap();
+        assert_eq!(&val[off.start..off.end], b"ISO-8859-1");
+        assert_eq!(off.size, val.len());
+
+        let val = b"text/html; Charset = \" utf-8 \" ";
+        let off = parse_content_type(val).unwrap();
+        assert_eq!(&val[off.start..off.end], b"utf-8");
+
+        assert_eq!(parse_content_type(b"text/html"), None);
+        assert_eq!(parse_content_type(b"text/html; charset="), None);
+        assert_eq!(parse_content_type(b"charset=\"unterminated"), None);
+    }
+
+    #[test]
+    fn test_trim_charset() {
+        let off = trim_charset(b"  utf-8\t");
+        assert_eq!((off.start, off.end, off.size), (2, 7, 8));
+    }
+
+    #[test]
+    fn test_escape_html() {
+        let mut out = Vec::new();
+        escape_html(b"a<b & \"c\" > d", false, &mut out);
+        assert_eq!(out, b"a&lt;b &amp; \"c\" &gt; d");
+
+        out.clear();
+        escape_html(b"<\"q\"&>\0ignored", true, &mut out);
+        assert_eq!(out, b"<&quot;q&quot;&amp;>");
+    }
+
+    #[test]
+    fn test_serialize_uri() {
+        let mut out = Vec::new();
+        serialize_uri(b" a b.html?x=1&y=\"2\"\xc3\xa9", &mut out);
+        assert_eq!(out, b" a%20b.html?x=1&amp;y=&quot;2&quot;%C3%A9");
+    }
+}
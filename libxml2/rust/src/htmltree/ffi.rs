@@ -0,0 +1,389 @@
+#![allow(clippy::missing_safety_doc)]
+
+use std::os::raw::{c_char, c_int};
+use std::ptr;
+
+use super::core::{self, MetaEncodingOffsets};
+use super::serialize;
+use crate::static_bindings::{
+    xmlAddChild, xmlAddPrevSibling, xmlAllocOutputBuffer, xmlBuf, xmlBufContent, xmlBufUse,
+    xmlBuffer, xmlChar, xmlCharEncCloseFunc, xmlCharEncodingHandler, xmlDoc, xmlFree,
+    xmlFreeNode, xmlInitParser, xmlMalloc, xmlNewDocNode, xmlNewProp, xmlNode, xmlNodeAddContent,
+    xmlNodePtr, xmlNodeSetContent, xmlOpenCharEncodingHandler, xmlOutputBuffer,
+    xmlOutputBufferClose, xmlOutputBufferCreateFile, xmlOutputBufferCreateFilename,
+    xmlOutputBufferFlush, xmlParserErrors_XML_ERR_OK, xmlStrndup, FILE,
+};
+
+// Ensure symbols are exported for linking
+#[cfg(feature = "rust-htmltree")]
+#[used]
+static HTMLTREE_FFI_LINKAGE: () = ();
+
+extern "C" {
+    // Private buffer conversion helpers from buf.c.
+    fn xmlBufFromBuffer(buffer: *mut xmlBuffer) -> *mut xmlBuf;
+    fn xmlBufBackToBuffer(buf: *mut xmlBuf, ret: *mut xmlBuffer) -> c_int;
+}
+
+/// Fallback to the pseudo "HTML" encoding if none is specified.
+unsafe fn find_output_encoder(
+    encoding: *const c_char,
+    out: *mut *mut xmlCharEncodingHandler,
+) -> bool {
+    let encoding = if encoding.is_null() {
+        c"HTML".as_ptr()
+    } else {
+        encoding
+    };
+
+    xmlOpenCharEncodingHandler(encoding, 1, out) == xmlParserErrors_XML_ERR_OK
+}
+
+unsafe fn update_meta_encoding(menc: &serialize::MetaEncoding, encoding: &[u8]) -> *mut xmlChar {
+    // The pseudo "HTML" encoding only produces ASCII.
+    let encoding: &[u8] = if encoding.eq_ignore_ascii_case(b"HTML") {
+        b"ASCII"
+    } else {
+        encoding
+    };
+
+    let value = serialize::cstr_bytes(menc.value);
+    let mut new_val = Vec::with_capacity(menc.off.size - (menc.off.end - menc.off.start) + encoding.len());
+    new_val.extend_from_slice(&value[..menc.off.start]);
+    new_val.extend_from_slice(encoding);
+    new_val.extend_from_slice(&value[menc.off.end..menc.off.size]);
+
+    let malloc = match xmlMalloc {
+        Some(malloc) => malloc,
+        None => return ptr::null_mut(),
+    };
+    let mem = malloc(new_val.len() + 1) as *mut xmlChar;
+    if mem.is_null() {
+        return ptr::null_mut();
+    }
+    ptr::copy_nonoverlapping(new_val.as_ptr(), mem, new_val.len());
+    *mem.add(new_val.len()) = 0;
+
+    mem
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn htmlParseContentType(
+    val: *const xmlChar,
+    off: *mut MetaEncodingOffsets,
+) -> c_int {
+    match core::parse_content_type(serialize::cstr_bytes(val)) {
+        Some(found) => {
+            *off = found;
+            1
+        }
+        None => 0,
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn htmlGetMetaEncoding(doc: *mut xmlDoc) -> *const xmlChar {
+    let head = serialize::find_head(doc);
+    if head.is_null() {
+        return ptr::null();
+    }
+
+    let mut node = (*head).children;
+    while !node.is_null() {
+        if let Some(menc) = serialize::parse_meta_encoding(node) {
+            // Only a suffix can be returned, so trailing data after the
+            // charset in http-equiv meta tags is included.
+            return menc.value.add(menc.off.start);
+        }
+        node = (*node).next;
+    }
+
+    ptr::null()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn htmlSetMetaEncoding(doc: *mut xmlDoc, encoding: *const xmlChar) -> c_int {
+    if encoding.is_null() {
+        return 1;
+    }
+
+    let head = serialize::find_head(doc);
+    if head.is_null() {
+        return 1;
+    }
+
+    let mut found = false;
+    let mut meta = (*head).children;
+    while !meta.is_null() {
+        if let Some(menc) = serialize::parse_meta_encoding(meta) {
+            found = true;
+
+            let new_val = update_meta_encoding(&menc, serialize::cstr_bytes(encoding));
+            if new_val.is_null() {
+                return -1;
+            }
+            xmlNodeSetContent(menc.attr as xmlNodePtr, ptr::null());
+            let ret = xmlNodeAddContent(menc.attr as xmlNodePtr, new_val);
+            if let Some(free) = xmlFree {
+                free(new_val as *mut _);
+            }
+
+            if ret < 0 {
+                return -1;
+            }
+        }
+        meta = (*meta).next;
+    }
+
+    if found {
+        return 0;
+    }
+
+    let meta = xmlNewDocNode((*head).doc, ptr::null_mut(), c"meta".as_ptr() as *const xmlChar, ptr::null());
+    if meta.is_null() {
+        return -1;
+    }
+
+    if xmlNewProp(meta, c"charset".as_ptr() as *const xmlChar, encoding).is_null() {
+        xmlFreeNode(meta);
+        return -1;
+    }
+
+    if (*head).children.is_null() {
+        xmlAddChild(head, meta);
+    } else {
+        xmlAddPrevSibling((*head).children, meta);
+    }
+
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn htmlIsBooleanAttr(name: *const xmlChar) -> c_int {
+    if name.is_null() {
+        return 0;
+    }
+    core::is_boolean_attr(serialize::cstr_bytes(name)) as c_int
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn htmlNodeDumpInternal(
+    buf: *mut xmlOutputBuffer,
+    cur: *mut xmlNode,
+    encoding: *const c_char,
+    format: c_int,
+) {
+    serialize::node_dump_internal(buf, cur, encoding, format);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn htmlNodeDump(buf: *mut xmlBuffer, doc: *mut xmlDoc, cur: *mut xmlNode) -> c_int {
+    if buf.is_null() || cur.is_null() {
+        return -1;
+    }
+
+    xmlInitParser();
+    let buffer = xmlBufFromBuffer(buf);
+    if buffer.is_null() {
+        return -1;
+    }
+
+    let mut outbuf: xmlOutputBuffer = std::mem::zeroed();
+    outbuf.buffer = buffer;
+
+    let used = xmlBufUse(buffer);
+    serialize::node_dump_internal(&mut outbuf, cur, ptr::null(), 1);
+    let written = if outbuf.error != 0 {
+        None
+    } else {
+        Some(xmlBufUse(buffer) - used)
+    };
+
+    let ret = xmlBufBackToBuffer(buffer, buf);
+
+    match written {
+        Some(n) if ret >= 0 => n.min(c_int::MAX as usize) as c_int,
+        _ => -1,
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn htmlNodeDumpFileFormat(
+    out: *mut FILE,
+    _doc: *mut xmlDoc,
+    cur: *mut xmlNode,
+    encoding: *const c_char,
+    format: c_int,
+) -> c_int {
+    xmlInitParser();
+
+    let mut handler = ptr::null_mut();
+    if !find_output_encoder(encoding, &mut handler) {
+        return -1;
+    }
+    let buf = xmlOutputBufferCreateFile(out, handler);
+    if buf.is_null() {
+        xmlCharEncCloseFunc(handler);
+        return -1;
+    }
+
+    serialize::node_dump_internal(buf, cur, ptr::null(), format);
+
+    xmlOutputBufferClose(buf)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn htmlNodeDumpFile(out: *mut FILE, doc: *mut xmlDoc, cur: *mut xmlNode) {
+    htmlNodeDumpFileFormat(out, doc, cur, ptr::null(), 1);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn htmlDocDumpMemoryFormat(
+    cur: *mut xmlDoc,
+    mem: *mut *mut xmlChar,
+    size: *mut c_int,
+    format: c_int,
+) {
+    xmlInitParser();
+
+    if mem.is_null() || size.is_null() {
+        return;
+    }
+    *mem = ptr::null_mut();
+    *size = 0;
+    if cur.is_null() {
+        return;
+    }
+
+    let mut handler = ptr::null_mut();
+    if !find_output_encoder((*cur).encoding as *const c_char, &mut handler) {
+        return;
+    }
+    let buf = xmlAllocOutputBuffer(handler);
+    if buf.is_null() {
+        xmlCharEncCloseFunc(handler);
+        return;
+    }
+
+    serialize::node_dump_internal(buf, cur as xmlNodePtr, ptr::null(), format);
+
+    xmlOutputBufferFlush(buf);
+
+    if (*buf).error == 0 {
+        let out = if (*buf).conv.is_null() { (*buf).buffer } else { (*buf).conv };
+        *size = xmlBufUse(out) as c_int;
+        *mem = xmlStrndup(xmlBufContent(out), *size);
+    }
+
+    xmlOutputBufferClose(buf);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn htmlDocDumpMemory(cur: *mut xmlDoc, mem: *mut *mut xmlChar, size: *mut c_int) {
+    htmlDocDumpMemoryFormat(cur, mem, size, 1);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn htmlNodeDumpFormatOutput(
+    buf: *mut xmlOutputBuffer,
+    _doc: *mut xmlDoc,
+    cur: *mut xmlNode,
+    _encoding: *const c_char,
+    format: c_int,
+) {
+    serialize::node_dump_internal(buf, cur, ptr::null(), format);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn htmlNodeDumpOutput(
+    buf: *mut xmlOutputBuffer,
+    _doc: *mut xmlDoc,
+    cur: *mut xmlNode,
+    _encoding: *const c_char,
+) {
+    serialize::node_dump_internal(buf, cur, ptr::null(), 1);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn htmlDocContentDumpFormatOutput(
+    buf: *mut xmlOutputBuffer,
+    cur: *mut xmlDoc,
+    _encoding: *const c_char,
+    format: c_int,
+) {
+    serialize::node_dump_internal(buf, cur as xmlNodePtr, ptr::null(), format);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn htmlDocContentDumpOutput(
+    buf: *mut xmlOutputBuffer,
+    cur: *mut xmlDoc,
+    _encoding: *const c_char,
+) {
+    serialize::node_dump_internal(buf, cur as xmlNodePtr, ptr::null(), 1);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn htmlDocDump(f: *mut FILE, cur: *mut xmlDoc) -> c_int {
+    xmlInitParser();
+
+    if cur.is_null() || f.is_null() {
+        return -1;
+    }
+
+    let mut handler = ptr::null_mut();
+    if !find_output_encoder((*cur).encoding as *const c_char, &mut handler) {
+        return -1;
+    }
+    let buf = xmlOutputBufferCreateFile(f, handler);
+    if buf.is_null() {
+        xmlCharEncCloseFunc(handler);
+        return -1;
+    }
+    serialize::node_dump_internal(buf, cur as xmlNodePtr, ptr::null(), 1);
+
+    xmlOutputBufferClose(buf)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn htmlSaveFile(filename: *const c_char, cur: *mut xmlDoc) -> c_int {
+    htmlSaveFileFormat(filename, cur, ptr::null(), 1)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn htmlSaveFileFormat(
+    filename: *const c_char,
+    cur: *mut xmlDoc,
+    encoding: *const c_char,
+    format: c_int,
+) -> c_int {
+    if cur.is_null() || filename.is_null() {
+        return -1;
+    }
+
+    xmlInitParser();
+
+    let mut handler = ptr::null_mut();
+    if !find_output_encoder(encoding, &mut handler) {
+        return -1;
+    }
+
+    let buf = xmlOutputBufferCreateFilename(filename, handler, (*cur).compression);
+    if buf.is_null() {
+        xmlCharEncCloseFunc(handler);
+        return 0;
+    }
+
+    serialize::node_dump_internal(buf, cur as xmlNodePtr, ptr::null(), format);
+
+    xmlOutputBufferClose(buf)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn htmlSaveFileEnc(
+    filename: *const c_char,
+    cur: *mut xmlDoc,
+    encoding: *const c_char,
+) -> c_int {
+    htmlSaveFileFormat(filename, cur, encoding, 1)
+}
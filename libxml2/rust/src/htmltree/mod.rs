@@ -1 +1,10 @@
-// Empty stub module for htmltree
+//! Rust implementation of htmltree module
+//!
+//! HTML tree serialization: void elements, boolean attributes, raw-text
+//! elements and the HTML doctype
+
+pub mod core;
+pub mod ffi;
+mod serialize;
+
+pub use core::*;
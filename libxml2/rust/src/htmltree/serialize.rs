@@ -0,0 +1,519 @@
+use std::ffi::CStr;
+use std::os::raw::{c_char, c_int};
+use std::ptr;
+
+use super::core::{self, MetaEncodingOffsets};
+use crate::static_bindings::{
+    htmlTagLookup, xmlAttrPtr, xmlDocPtr, xmlElementType_XML_ATTRIBUTE_NODE,
+    xmlElementType_XML_COMMENT_NODE, xmlElementType_XML_DOCUMENT_NODE,
+    xmlElementType_XML_ELEMENT_NODE, xmlElementType_XML_ENTITY_REF_NODE,
+    xmlElementType_XML_HTML_DOCUMENT_NODE, xmlElementType_XML_PI_NODE,
+    xmlElementType_XML_TEXT_NODE, xmlInitParser, xmlNodePtr, xmlNs,
+    xmlOutputBuffer, xmlOutputBufferWrite, xmlOutputBufferWriteString,
+    xmlStringTextNoenc, xmlChar, htmlElemDesc,
+};
+
+/// `XML_PRESERVE_NODE`, the HTML alias of `XML_CDATA_SECTION_NODE`.
+const HTML_PRESERVE_NODE: u32 = 4;
+
+/// `DATA_RAWTEXT` from `private/html.h`: script, style and friends.
+const DATA_RAWTEXT: c_int = 2;
+
+extern "C" {
+    // Private helpers that stay in C (xmlsave.c, xmlIO.c).
+    fn xmlNsListDumpOutput(buf: *mut xmlOutputBuffer, cur: *mut xmlNs);
+    fn xmlOutputBufferWriteQuotedString(buf: *mut xmlOutputBuffer, string: *const xmlChar);
+}
+
+/// A charset declaration found in a `<meta>` element.
+pub(crate) struct MetaEncoding {
+    pub attr: xmlAttrPtr,
+    pub value: *const xmlChar,
+    pub off: MetaEncodingOffsets,
+}
+
+pub(crate) unsafe fn cstr_bytes<'a>(p: *const xmlChar) -> &'a [u8] {
+    if p.is_null() {
+        &[]
+    } else {
+        CStr::from_ptr(p as *const c_char).to_bytes()
+    }
+}
+
+unsafe fn name_is(name: *const xmlChar, expected: &[u8]) -> bool {
+    !name.is_null() && cstr_bytes(name).eq_ignore_ascii_case(expected)
+}
+
+unsafe fn write(buf: *mut xmlOutputBuffer, data: &[u8]) {
+    if !data.is_empty() {
+        xmlOutputBufferWrite(buf, data.len() as c_int, data.as_ptr() as *const c_char);
+    }
+}
+
+unsafe fn write_str(buf: *mut xmlOutputBuffer, s: *const xmlChar) {
+    xmlOutputBufferWriteString(buf, s as *const c_char);
+}
+
+unsafe fn write_escaped(buf: *mut xmlOutputBuffer, text: &[u8], attr: bool) {
+    let mut out = Vec::with_capacity(text.len());
+    core::escape_html(text, attr, &mut out);
+    write(buf, &out);
+}
+
+unsafe fn write_qname(buf: *mut xmlOutputBuffer, cur: xmlNodePtr) {
+    let ns = (*cur).ns;
+    if !ns.is_null() && !(*ns).prefix.is_null() {
+        write_str(buf, (*ns).prefix);
+        write(buf, b":");
+    }
+    write_str(buf, (*cur).name);
+}
+
+unsafe fn is_text_or_entity_ref(node: xmlNodePtr) -> bool {
+    (*node).type_ == xmlElementType_XML_TEXT_NODE
+        || (*node).type_ == xmlElementType_XML_ENTITY_REF_NODE
+}
+
+/// `p`, `pre` and `param` never get formatting newlines.
+unsafe fn starts_with_p(name: *const xmlChar) -> bool {
+    !name.is_null() && *name == b'p'
+}
+
+pub(crate) unsafe fn find_first_child(parent: xmlNodePtr, name: &[u8]) -> xmlNodePtr {
+    let mut child = (*parent).children;
+    while !child.is_null() {
+        if (*child).type_ == xmlElementType_XML_ELEMENT_NODE && name_is((*child).name, name) {
+            return child;
+        }
+        child = (*child).next;
+    }
+    ptr::null_mut()
+}
+
+pub(crate) unsafe fn find_head(doc: xmlDocPtr) -> xmlNodePtr {
+    if doc.is_null() {
+        return ptr::null_mut();
+    }
+    let html = find_first_child(doc as xmlNodePtr, b"html");
+    if html.is_null() {
+        return ptr::null_mut();
+    }
+    find_first_child(html, b"head")
+}
+
+/// Returns the attribute carrying the charset and whether it is an
+/// http-equiv `content` attribute rather than a plain `charset`.
+pub(crate) unsafe fn find_meta_encoding_attr(elem: xmlNodePtr) -> Option<(xmlAttrPtr, bool)> {
+    if !name_is((*elem).name, b"meta") {
+        return None;
+    }
+
+    let mut content_attr: xmlAttrPtr = ptr::null_mut();
+    let mut is_content_type = false;
+    let mut attr = (*elem).properties;
+
+    while !attr.is_null() {
+        if (*attr).ns.is_null() {
+            if name_is((*attr).name, b"charset") {
+                return Some((attr, false));
+            }
+            if name_is((*attr).name, b"content") {
+                content_attr = attr;
+            }
+            let child = (*attr).children;
+            if name_is((*attr).name, b"http-equiv")
+                && !child.is_null()
+                && (*child).type_ == xmlElementType_XML_TEXT_NODE
+                && (*child).next.is_null()
+                && name_is((*child).content, b"Content-Type")
+            {
+                is_content_type = true;
+            }
+        }
+        attr = (*attr).next;
+    }
+
+    if is_content_type && !content_attr.is_null() {
+        return Some((content_attr, true));
+    }
+    None
+}
+
+pub(crate) unsafe fn parse_meta_encoding(elem: xmlNodePtr) -> Option<MetaEncoding> {
+    if (*elem).type_ != xmlElementType_XML_ELEMENT_NODE || !name_is((*elem).name, b"meta") {
+        return None;
+    }
+
+    let (attr, is_content_type) = find_meta_encoding_attr(elem)?;
+
+    let child = (*attr).children;
+    let value: *const xmlChar = if !child.is_null()
+        && (*child).type_ == xmlElementType_XML_TEXT_NODE
+        && (*child).next.is_null()
+        && !(*child).content.is_null()
+    {
+        (*child).content
+    } else {
+        c"".as_ptr() as *const xmlChar
+    };
+
+    let off = if is_content_type {
+        core::parse_content_type(cstr_bytes(value))?
+    } else {
+        core::trim_charset(cstr_bytes(value))
+    };
+
+    Some(MetaEncoding { attr, value, off })
+}
+
+unsafe fn dtd_dump_output(buf: *mut xmlOutputBuffer, doc: xmlDocPtr) {
+    let cur = (*doc).intSubset;
+    if cur.is_null() {
+        return;
+    }
+
+    write(buf, b"<!DOCTYPE ");
+    write_str(buf, (*cur).name);
+    if !(*cur).ExternalID.is_null() {
+        write(buf, b" PUBLIC ");
+        xmlOutputBufferWriteQuotedString(buf, (*cur).ExternalID);
+        if !(*cur).SystemID.is_null() {
+            write(buf, b" ");
+            xmlOutputBufferWriteQuotedString(buf, (*cur).SystemID);
+        }
+    } else if !(*cur).SystemID.is_null() && cstr_bytes((*cur).SystemID) != b"about:legacy-compat" {
+        write(buf, b" SYSTEM ");
+        xmlOutputBufferWriteQuotedString(buf, (*cur).SystemID);
+    }
+    write(buf, b">\n");
+}
+
+unsafe fn attr_dump_output(buf: *mut xmlOutputBuffer, cur: xmlAttrPtr) {
+    write(buf, b" ");
+
+    let ns = (*cur).ns;
+    if !ns.is_null() && !(*ns).prefix.is_null() {
+        write_str(buf, (*ns).prefix);
+        write(buf, b":");
+    }
+    write_str(buf, (*cur).name);
+
+    if (*cur).children.is_null() || core::is_boolean_attr(cstr_bytes((*cur).name)) {
+        return;
+    }
+
+    write(buf, b"=\"");
+
+    let parent = (*cur).parent;
+    let is_uri = ns.is_null()
+        && !parent.is_null()
+        && (*parent).ns.is_null()
+        && (name_is((*cur).name, b"href")
+            || name_is((*cur).name, b"action")
+            || name_is((*cur).name, b"src")
+            || (name_is((*cur).name, b"name") && name_is((*parent).name, b"a")));
+
+    let mut child = (*cur).children;
+    while !child.is_null() {
+        if (*child).type_ == xmlElementType_XML_TEXT_NODE {
+            if !(*child).content.is_null() {
+                let content = cstr_bytes((*child).content);
+                if is_uri {
+                    let mut out = Vec::with_capacity(content.len());
+                    core::serialize_uri(content, &mut out);
+                    write(buf, &out);
+                } else {
+                    write_escaped(buf, content, true);
+                }
+            }
+        } else if (*child).type_ == xmlElementType_XML_ENTITY_REF_NODE {
+            write(buf, b"&");
+            write_str(buf, (*child).name);
+            write(buf, b";");
+        }
+        child = (*child).next;
+    }
+
+    write(buf, b"\"");
+}
+
+unsafe fn tag_info(cur: xmlNodePtr) -> *const htmlElemDesc {
+    if (*cur).ns.is_null() {
+        htmlTagLookup((*cur).name)
+    } else {
+        ptr::null()
+    }
+}
+
+/// Serialize an HTML node and its subtree without recursion.
+///
+/// If `encoding` is non-NULL, it is used to create or update meta tags
+/// containing the character encoding.
+pub(crate) unsafe fn node_dump_internal(
+    buf: *mut xmlOutputBuffer,
+    mut cur: xmlNodePtr,
+    enc_ptr: *const c_char,
+    format: c_int,
+) {
+    xmlInitParser();
+
+    if cur.is_null() || buf.is_null() {
+        return;
+    }
+
+    let pretty = format != 0;
+    let encoding = if enc_ptr.is_null() {
+        None
+    } else {
+        Some(CStr::from_ptr(enc_ptr).to_bytes())
+    };
+    let root = cur;
+    let mut parent = (*cur).parent;
+    let mut meta_head: xmlNodePtr = ptr::null_mut();
+    let mut is_raw = false;
+
+    loop {
+        match (*cur).type_ {
+            xmlElementType_XML_HTML_DOCUMENT_NODE | xmlElementType_XML_DOCUMENT_NODE => {
+                dtd_dump_output(buf, cur as xmlDocPtr);
+                if !(*cur).children.is_null() {
+                    // Always validate cur->parent when descending.
+                    if (*cur).parent == parent {
+                        parent = cur;
+                        cur = (*cur).children;
+                        continue;
+                    }
+                } else {
+                    write(buf, b"\n");
+                }
+            }
+
+            xmlElementType_XML_ELEMENT_NODE => {
+                // Some users like lxml pass nodes with a corrupted tree
+                // structure. Fall back to a recursive call for those.
+                if (*cur).parent != parent && !(*cur).children.is_null() {
+                    node_dump_internal(buf, cur, enc_ptr, format);
+                } else if start_element(buf, cur, parent, encoding, pretty, &mut meta_head, &mut is_raw) {
+                    parent = cur;
+                    cur = (*cur).children;
+                    continue;
+                }
+            }
+
+            xmlElementType_XML_ATTRIBUTE_NODE => attr_dump_output(buf, cur as xmlAttrPtr),
+
+            xmlElementType_XML_TEXT_NODE if !(*cur).content.is_null() => {
+                if (*cur).name == xmlStringTextNoenc.as_ptr() || is_raw {
+                    write_str(buf, (*cur).content);
+                } else {
+                    write_escaped(buf, cstr_bytes((*cur).content), false);
+                }
+            }
+
+            xmlElementType_XML_COMMENT_NODE if !(*cur).content.is_null() => {
+                write(buf, b"<!--");
+                write_str(buf, (*cur).content);
+                write(buf, b"-->");
+            }
+
+            xmlElementType_XML_PI_NODE if !(*cur).name.is_null() => {
+                write(buf, b"<?");
+                write_str(buf, (*cur).name);
+                if !(*cur).content.is_null() {
+                    write(buf, b" ");
+                    write_str(buf, (*cur).content);
+                }
+                write(buf, b">");
+            }
+
+            xmlElementType_XML_ENTITY_REF_NODE => {
+                write(buf, b"&");
+                write_str(buf, (*cur).name);
+                write(buf, b";");
+            }
+
+            HTML_PRESERVE_NODE if !(*cur).content.is_null() => write_str(buf, (*cur).content),
+
+            _ => {}
+        }
+
+        loop {
+            if cur == root {
+                return;
+            }
+            if !(*cur).next.is_null() {
+                cur = (*cur).next;
+                break;
+            }
+
+            is_raw = false;
+
+            cur = parent;
+            // cur->parent was validated when descending.
+            parent = (*cur).parent;
+
+            if (*cur).type_ == xmlElementType_XML_HTML_DOCUMENT_NODE
+                || (*cur).type_ == xmlElementType_XML_DOCUMENT_NODE
+            {
+                write(buf, b"\n");
+                continue;
+            }
+
+            let info = if pretty { tag_info(cur) } else { ptr::null() };
+            let is_block = !info.is_null() && (*info).isinline == 0;
+
+            if pretty
+                && is_block
+                && !is_text_or_entity_ref((*cur).last)
+                && ((*cur).children != (*cur).last || cur == meta_head)
+                && !(*cur).name.is_null()
+                && !starts_with_p((*cur).name)
+            {
+                write(buf, b"\n");
+            }
+
+            write(buf, b"</");
+            write_qname(buf, cur);
+            write(buf, b">");
+
+            if pretty
+                && is_block
+                && !(*cur).next.is_null()
+                && !is_text_or_entity_ref((*cur).next)
+                && !parent.is_null()
+                && !(*parent).name.is_null()
+                && !starts_with_p((*parent).name)
+            {
+                write(buf, b"\n");
+            }
+
+            if cur == meta_head {
+                meta_head = ptr::null_mut();
+            }
+        }
+    }
+}
+
+/// Emit the start tag (and end tag for empty or void elements) of `cur`.
+/// Returns true if the caller should descend into the children.
+unsafe fn start_element(
+    buf: *mut xmlOutputBuffer,
+    cur: xmlNodePtr,
+    parent: xmlNodePtr,
+    encoding: Option<&[u8]>,
+    format: bool,
+    meta_head: &mut xmlNodePtr,
+    is_raw: &mut bool,
+) -> bool {
+    let info = tag_info(cur);
+    let mut menc = None;
+    let mut add_meta = false;
+
+    if let Some(encoding) = encoding {
+        menc = parse_meta_encoding(cur);
+
+        // Don't add a meta tag for the pseudo "HTML" encoding.
+        if !encoding.eq_ignore_ascii_case(b"HTML")
+            && name_is((*cur).name, b"head")
+            && !parent.is_null()
+            && name_is((*parent).name, b"html")
+            && !(*parent).parent.is_null()
+            && (*(*parent).parent).parent.is_null()
+            && meta_head.is_null()
+        {
+            *meta_head = cur;
+            add_meta = true;
+
+            let mut n = (*cur).children;
+            while !n.is_null() {
+                if find_meta_encoding_attr(n).is_some() {
+                    *meta_head = ptr::null_mut();
+                    add_meta = false;
+                    break;
+                }
+                n = (*n).next;
+            }
+        }
+    }
+
+    write(buf, b"<");
+    write_qname(buf, cur);
+    if !(*cur).nsDef.is_null() {
+        xmlNsListDumpOutput(buf, (*cur).nsDef);
+    }
+
+    let mut attr = (*cur).properties;
+    while !attr.is_null() {
+        match (&menc, encoding) {
+            (Some(menc), Some(encoding)) if menc.attr == attr => {
+                let value = cstr_bytes(menc.value);
+                write(buf, b" ");
+                write_str(buf, (*attr).name);
+                write(buf, b"=\"");
+                write_escaped(buf, &value[..menc.off.start], true);
+                write_escaped(buf, encoding, true);
+                write_escaped(buf, &value[menc.off.end..menc.off.size], true);
+                write(buf, b"\"");
+            }
+            _ => attr_dump_output(buf, attr),
+        }
+        attr = (*attr).next;
+    }
+
+    if !info.is_null() && (*info).empty != 0 {
+        write(buf, b">");
+    } else if (*cur).children.is_null() {
+        match encoding {
+            Some(encoding) if add_meta => {
+                write(buf, b"><meta charset=\"");
+                write_escaped(buf, encoding, true);
+                write(buf, b"\"></");
+            }
+            _ => write(buf, b"></"),
+        }
+        write_qname(buf, cur);
+        write(buf, b">");
+    } else {
+        let children = (*cur).children;
+
+        write(buf, b">");
+        if format
+            && (add_meta
+                || (!info.is_null()
+                    && (*info).isinline == 0
+                    && !is_text_or_entity_ref(children)
+                    && children != (*cur).last
+                    && !(*cur).name.is_null()
+                    && !starts_with_p((*cur).name)))
+        {
+            write(buf, b"\n");
+        }
+        if let (true, Some(encoding)) = (add_meta, encoding) {
+            write(buf, b"<meta charset=\"");
+            write_escaped(buf, encoding, true);
+            write(buf, b"\">");
+            if format && !is_text_or_entity_ref(children) {
+                write(buf, b"\n");
+            }
+        }
+
+        if !info.is_null() && (*info).dataMode >= DATA_RAWTEXT {
+            *is_raw = true;
+        }
+
+        return true;
+    }
+
+    if format && !(*cur).next.is_null() && !info.is_null() && (*info).isinline == 0 {
+        let next = (*cur).next;
+        if !is_text_or_entity_ref(next)
+            && !parent.is_null()
+            && !(*parent).name.is_null()
+            && !starts_with_p((*parent).name)
+        {
+            write(buf, b"\n");
+        }
+    }
+
+    false
+}
@@ -28,13 +28,20 @@ pub mod libxml2_dynload;
 // Rust implementations will override these when their features are enabled
 pub use static_bindings::*;
 
-// Conditionally include Rust implementations
-#[cfg(feature = "rust-xmlstring")]
+// Safe wrappers, built on whichever implementation is linked
+pub mod safe;
+pub use safe::*;
+
+// String conversions are always available; the rest of the module is
+// gated on `rust-xmlstring` inside it
 pub mod xmlstring;
 
-#[cfg(feature = "rust-chvalid")]
+// Character classes are always available for the Rust parser; the C
+// exports are gated on `rust-chvalid` inside it
 pub mod chvalid;
 
+// Conditionally include Rust implementations
+
 #[cfg(feature = "rust-dict")]
 pub mod dict;
 
@@ -56,7 +63,8 @@ pub mod error;
 #[cfg(feature = "rust-threads")]
 pub mod threads;
 
-#[cfg(feature = "rust-encoding")]
+// The converters are always available; the C exports are gated on
+// `rust-encoding` inside it
 pub mod encoding;
 
 #[cfg(feature = "rust-xmlio")]
@@ -122,6 +130,9 @@ pub mod xmlwriter;
 #[cfg(feature = "rust-c14n")]
 pub mod c14n;
 
+#[cfg(feature = "rust-catalog")]
+pub mod catalog;
+
 // Re-export Rust implementations when enabled
 #[cfg(feature = "rust-xmlstring")]
 pub use xmlstring::*;
@@ -216,9 +227,59 @@ pub use xmlwriter::*;
 #[cfg(feature = "rust-c14n")]
 pub use c14n::*;
 
-// Initialization function if needed
+#[cfg(feature = "rust-catalog")]
+pub use catalog::*;
+
+/// One-time global initialization of libxml2.
+///
+/// Embedders should call this before using any parsing API. The first call
+/// runs `xmlInitParser`, which sets up threads, globals, dictionaries and
+/// the encoding handlers; a `Once` makes every later call, and every call
+/// racing with the first, return only once that setup is done. Parsing
+/// entry points also initialize lazily, so a missing call is not fatal,
+/// but it then happens on whichever thread parses first.
+///
+/// The `Once` is not reset by `cleanup_chimera`: after a cleanup this does
+/// nothing, and the library is set up again by the next parse, or by
+/// calling `xmlInitParser` directly.
 pub fn init_chimera() {
-    // Any global initialization required
+    static INIT: std::sync::Once = std::sync::Once::new();
+    INIT.call_once(|| unsafe { xmlInitParser() });
+}
+
+/// Free libxml2's global state with `xmlCleanupParser`.
+///
+/// Cleanup also runs automatically at process exit on most platforms, so
+/// this is only needed to silence leak checkers.
+///
+/// # Safety
+///
+/// No other thread may be using the library, and nothing from before the
+/// call (documents, dictionaries, handlers) may be used after it. The next
+/// parse initializes the library again; `init_chimera` does not, see there.
+pub unsafe fn cleanup_chimera() {
+    xmlCleanupParser();
+}
+
+/// The modules linked as Rust, as resolved by `build.rs` from the
+/// `rust-*` features and the `RUST_MODULES` variable, e.g. `"chvalid"`.
+pub const RUST_MODULES: &[&str] = include!(concat!(env!("OUT_DIR"), "/rust_modules.rs"));
+
+/// Which implementation of a module is linked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Impl {
+    Rust,
+    C,
+}
+
+/// Whether `module`, named as in `RUST_MODULES`, runs the Rust or the C
+/// implementation. Modules without a Rust port, and unknown names, are C.
+pub fn implementation_of(module: &str) -> Impl {
+    if RUST_MODULES.contains(&module) {
+        Impl::Rust
+    } else {
+        Impl::C
+    }
 }
 
 #[cfg(test)]
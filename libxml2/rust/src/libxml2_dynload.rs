@@ -1,60 +1,488 @@
+//! Loader for the C baseline library used by differential tests.
+//!
+//! `build.rs` links the untouched C sources into `liblibxml2_c` next to the
+//! chimera library. The baseline is opened once per process and shared.
+
+use std::ffi::{c_void, CStr};
+use std::fmt;
+use std::ops::Deref;
+use std::os::raw::{c_char, c_int};
+use std::path::{Path, PathBuf};
+use std::ptr;
 use std::sync::OnceLock;
+
 use crate::dynamic_bindings::libxml2_c;
 
-static C_LIBRARY: OnceLock<libxml2_c> = OnceLock::new();
+static BASELINE: OnceLock<Result<Baseline, LoadError>> = OnceLock::new();
 
-/// Get the global C baseline library instance (lazy-loaded)
-/// Uses bindgen-generated dynamic library loader with smart path resolution
-pub fn get_c_baseline() -> &'static libxml2_c {
-    C_LIBRARY.get_or_init(|| {
-        // Try multiple possible paths for the dynamic library
-        let lib_path = if cfg!(target_os = "windows") {
-            "liblibxml2_c.dll"
-        } else if cfg!(target_os = "macos") {
-            "liblibxml2_c.dylib"
-        } else {
-            "liblibxml2_c.so"
-        };
-        
-        let paths_to_try = [
-            format!("target/release/build/{}", lib_path),
-            format!("target/debug/build/{}", lib_path),
-            format!("../target/release/build/{}", lib_path),
-            format!("../target/debug/build/{}", lib_path),
-            format!("../../target/release/build/{}", lib_path),
-            format!("../../target/debug/build/{}", lib_path),
-        ];
-        
-        // Try direct paths first
-        for path in &paths_to_try {
-            if std::path::Path::new(path).exists() {
-                if let Ok(lib) = unsafe { libxml2_c::new(path) } {
-                    return lib;
-                }
+/// Errors raised while loading the C baseline library.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LoadError {
+    /// No baseline library exists in any of the searched locations.
+    NotFound { tried: Vec<PathBuf> },
+    /// A baseline library was found but could not be opened.
+    Open { path: PathBuf, message: String },
+    /// The library does not export the requested symbol.
+    MissingSymbol { name: String, message: String },
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LoadError::NotFound { tried } => write!(
+                f,
+                "C baseline library not found (was it built by build.rs?). Tried paths: {:?}",
+                tried
+            ),
+            LoadError::Open { path, message } => write!(
+                f,
+                "failed to open C baseline library {}: {}",
+                path.display(),
+                message
+            ),
+            LoadError::MissingSymbol { name, message } => {
+                write!(f, "C baseline library has no symbol {}: {}", name, message)
             }
         }
-        
-        // Fallback: find the library using glob pattern
-        let pattern = if cfg!(target_os = "windows") {
-            "target/*/build/libxml2-*/out/libxml2_c.dll"
-        } else if cfg!(target_os = "macos") {
-            "target/*/build/libxml2-*/out/liblibxml2_c.dylib"
-        } else {
-            "target/*/build/libxml2-*/out/liblibxml2_c.so"
+    }
+}
+
+impl std::error::Error for LoadError {}
+
+struct Baseline {
+    path: PathBuf,
+    bindings: libxml2_c,
+    library: libloading::Library,
+}
+
+/// Handle to the process-wide C baseline library.
+///
+/// Dereferences to the bindgen-generated `libxml2_c` table, so baseline
+/// functions are called as `baseline.xmlStrlen(s)`.
+#[derive(Clone, Copy)]
+pub struct BaselineLib {
+    inner: &'static Baseline,
+}
+
+impl BaselineLib {
+    /// Load the C baseline library, or return the cached handle. A failed
+    /// load is cached as well, so every caller sees the same error.
+    pub fn load() -> Result<BaselineLib, LoadError> {
+        match BASELINE.get_or_init(open_baseline) {
+            Ok(inner) => Ok(BaselineLib { inner }),
+            Err(err) => Err(err.clone()),
+        }
+    }
+
+    /// Path the library was loaded from.
+    pub fn path(&self) -> &'static Path {
+        &self.inner.path
+    }
+
+    /// Bindings for the library's exported functions and variables.
+    pub fn bindings(&self) -> &'static libxml2_c {
+        &self.inner.bindings
+    }
+
+    /// Resolve the address of an exported symbol.
+    pub fn symbol(&self, name: &str) -> Result<*const c_void, LoadError> {
+        let mut cname = name.as_bytes().to_vec();
+        cname.push(0);
+        unsafe {
+            self.inner
+                .library
+                .get::<*const c_void>(&cname)
+                .map(|sym| *sym)
+                .map_err(|e| LoadError::MissingSymbol {
+                    name: name.to_string(),
+                    message: e.to_string(),
+                })
+        }
+    }
+}
+
+impl Deref for BaselineLib {
+    type Target = libxml2_c;
+
+    fn deref(&self) -> &libxml2_c {
+        self.bindings()
+    }
+}
+
+impl fmt::Debug for BaselineLib {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BaselineLib")
+            .field("path", &self.inner.path)
+            .finish()
+    }
+}
+
+fn open(path: &Path) -> Result<Baseline, LoadError> {
+    let open_error = |e: libloading::Error| LoadError::Open {
+        path: path.to_path_buf(),
+        message: e.to_string(),
+    };
+    let bindings = unsafe { libxml2_c::new(path) }.map_err(open_error)?;
+    let library = unsafe { libloading::Library::new(path) }.map_err(open_error)?;
+    Ok(Baseline {
+        path: path.to_path_buf(),
+        bindings,
+        library,
+    })
+}
+
+fn open_baseline() -> Result<Baseline, LoadError> {
+    // Try multiple possible paths for the dynamic library
+    let lib_path = if cfg!(target_os = "windows") {
+        "liblibxml2_c.dll"
+    } else if cfg!(target_os = "macos") {
+        "liblibxml2_c.dylib"
+    } else {
+        "liblibxml2_c.so"
+    };
+
+    let mut tried: Vec<PathBuf> = [
+        "target/release/build",
+        "target/debug/build",
+        "../target/release/build",
+        "../target/debug/build",
+        "../../target/release/build",
+        "../../target/debug/build",
+    ]
+    .iter()
+    .map(|dir| Path::new(dir).join(lib_path))
+    .collect();
+
+    // Fallback: find the library using glob pattern
+    let pattern = if cfg!(target_os = "windows") {
+        "target/*/build/libxml2-*/out/liblibxml2_c.dll"
+    } else if cfg!(target_os = "macos") {
+        "target/*/build/libxml2-*/out/liblibxml2_c.dylib"
+    } else {
+        "target/*/build/libxml2-*/out/liblibxml2_c.so"
+    };
+    if let Ok(entries) = glob::glob(pattern) {
+        tried.extend(entries.flatten());
+    }
+
+    let mut last_error = None;
+    for path in &tried {
+        if !path.exists() {
+            continue;
+        }
+        match open(path) {
+            Ok(baseline) => return Ok(baseline),
+            Err(err) => last_error = Some(err),
+        }
+    }
+    Err(last_error.unwrap_or(LoadError::NotFound { tried }))
+}
+
+/// Get the global C baseline library instance (lazy-loaded).
+///
+/// Panics if the library cannot be loaded; use `BaselineLib::load` to
+/// handle the error instead.
+pub fn get_c_baseline() -> &'static libxml2_c {
+    match BaselineLib::load() {
+        Ok(lib) => lib.bindings(),
+        Err(err) => panic!("{}", err),
+    }
+}
+
+/// First structural difference between the C baseline tree and the
+/// hybrid tree for the same input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TreeDiff {
+    /// Location of the differing node, e.g. `/doc[0]/item[3]/#text[0]`.
+    pub path: String,
+    /// What differs: `type`, `name`, `namespace`, `attributes`, ...
+    pub field: &'static str,
+    pub c: String,
+    pub rust: String,
+}
+
+impl fmt::Display for TreeDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} differs at {}: C baseline {:?}, hybrid {:?}",
+            self.field, self.path, self.c, self.rust
+        )
+    }
+}
+
+/// Parse `input` with `xmlReadMemory` in both the C baseline and the
+/// hybrid build and compare the resulting trees.
+pub fn diff_parse(input: &[u8], options: c_int) -> Result<(), TreeDiff> {
+    let baseline = BaselineLib::load().unwrap_or_else(|err| panic!("{}", err));
+    let len = input.len() as c_int;
+    let buffer = input.as_ptr() as *const c_char;
+    unsafe {
+        let c_doc = baseline.xmlReadMemory(buffer, len, ptr::null(), ptr::null(), options);
+        let rust_doc = crate::xmlReadMemory(buffer, len, ptr::null(), ptr::null(), options);
+        // Both bindings are generated from the same headers, so the baseline
+        // tree can be read through the static struct definitions.
+        let ret = diff_trees(c_doc as crate::xmlDocPtr, rust_doc);
+        if !c_doc.is_null() {
+            baseline.xmlFreeDoc(c_doc);
+        }
+        if !rust_doc.is_null() {
+            crate::xmlFreeDoc(rust_doc);
+        }
+        ret
+    }
+}
+
+/// Like `diff_parse`, but each build parses with a parser context of its
+/// own, and the domain and code of the last error or warning each context
+/// reports must match as well. Returns what the builds agreed on.
+pub fn diff_parse_outcome(input: &[u8], options: c_int) -> Result<ParseOutcome, TreeDiff> {
+    let baseline = BaselineLib::load().unwrap_or_else(|err| panic!("{}", err));
+    let len = input.len() as c_int;
+    let buffer = input.as_ptr() as *const c_char;
+    unsafe {
+        let c_ctxt = baseline.xmlNewParserCtxt();
+        let rust_ctxt = crate::xmlNewParserCtxt();
+        assert!(!c_ctxt.is_null() && !rust_ctxt.is_null(), "out of memory");
+        let c_doc =
+            baseline.xmlCtxtReadMemory(c_ctxt, buffer, len, ptr::null(), ptr::null(), options);
+        let rust_doc =
+            crate::xmlCtxtReadMemory(rust_ctxt, buffer, len, ptr::null(), ptr::null(), options);
+        let error =
+            |err: *const crate::xmlError| err.as_ref().map_or((0, 0), |err| (err.domain, err.code));
+        let c_error =
+            error(baseline.xmlCtxtGetLastError(c_ctxt as *mut c_void) as *const crate::xmlError);
+        let rust_error = error(crate::xmlCtxtGetLastError(rust_ctxt as *mut c_void));
+        let outcome = ParseOutcome {
+            parsed: !c_doc.is_null(),
+            domain: c_error.0,
+            code: c_error.1,
         };
-        
-        if let Ok(entries) = glob::glob(pattern) {
-            for entry in entries.flatten() {
-                if let Ok(lib) = unsafe { libxml2_c::new(entry) } {
-                    return lib;
-                }
-            }
+        let ret = diff_trees(c_doc as crate::xmlDocPtr, rust_doc)
+            .and_then(|()| check("", "last error", c_error, rust_error))
+            .map(|()| outcome);
+        if !c_doc.is_null() {
+            baseline.xmlFreeDoc(c_doc);
         }
-        
-        panic!("Failed to load C baseline library from any location. Tried paths: {:?}", paths_to_try);
+        if !rust_doc.is_null() {
+            crate::xmlFreeDoc(rust_doc);
+        }
+        baseline.xmlFreeParserCtxt(c_ctxt);
+        crate::xmlFreeParserCtxt(rust_ctxt);
+        ret
+    }
+}
+
+/// What both builds agreed on in `diff_parse_outcome`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseOutcome {
+    /// Whether a document was built.
+    pub parsed: bool,
+    /// Domain and code of the last error or warning, 0 if there was none.
+    pub domain: c_int,
+    pub code: c_int,
+}
+
+/// Walk two documents in lockstep and report the first difference in
+/// node types, names, namespaces, attributes or text content.
+///
+/// # Safety
+///
+/// Both documents must be null or valid trees.
+pub unsafe fn diff_trees(c: crate::xmlDocPtr, rust: crate::xmlDocPtr) -> Result<(), TreeDiff> {
+    if c.is_null() || rust.is_null() {
+        return check("", "parsed", !c.is_null(), !rust.is_null());
+    }
+    diff_node(c as crate::xmlNodePtr, rust as crate::xmlNodePtr, "")
+}
+
+fn check<T: fmt::Debug + PartialEq>(
+    path: &str,
+    field: &'static str,
+    c: T,
+    rust: T,
+) -> Result<(), TreeDiff> {
+    if c == rust {
+        return Ok(());
+    }
+    Err(TreeDiff {
+        path: if path.is_empty() {
+            "/".to_string()
+        } else {
+            path.to_string()
+        },
+        field,
+        c: format!("{:?}", c),
+        rust: format!("{:?}", rust),
     })
 }
 
+unsafe fn string(s: *const crate::xmlChar) -> Option<String> {
+    if s.is_null() {
+        None
+    } else {
+        Some(
+            CStr::from_ptr(s as *const c_char)
+                .to_string_lossy()
+                .into_owned(),
+        )
+    }
+}
+
+unsafe fn ns_string(ns: crate::xmlNsPtr) -> Option<(Option<String>, Option<String>)> {
+    ns.as_ref().map(|ns| (string(ns.prefix), string(ns.href)))
+}
+
+unsafe fn ns_defs(mut ns: crate::xmlNsPtr) -> Vec<(Option<String>, Option<String>)> {
+    let mut defs = Vec::new();
+    while let Some(def) = ns.as_ref() {
+        defs.push((string(def.prefix), string(def.href)));
+        ns = def.next;
+    }
+    defs
+}
+
+/// Value of an attribute; entity references show up as `&name;`.
+unsafe fn attr_value(attr: &crate::_xmlAttr) -> String {
+    let mut value = String::new();
+    let mut child = attr.children;
+    while let Some(node) = child.as_ref() {
+        if node.type_ == crate::xmlElementType_XML_ENTITY_REF_NODE {
+            value.push('&');
+            value.push_str(&string(node.name).unwrap_or_default());
+            value.push(';');
+        } else {
+            value.push_str(&string(node.content).unwrap_or_default());
+        }
+        child = node.next;
+    }
+    value
+}
+
+type AttrSummary = (
+    Option<String>,
+    Option<(Option<String>, Option<String>)>,
+    String,
+);
+
+unsafe fn attributes(mut attr: crate::xmlAttrPtr) -> Vec<AttrSummary> {
+    let mut attrs = Vec::new();
+    while let Some(a) = attr.as_ref() {
+        attrs.push((string(a.name), ns_string(a.ns), attr_value(a)));
+        attr = a.next;
+    }
+    attrs
+}
+
+unsafe fn children(node: &crate::_xmlNode) -> Vec<crate::xmlNodePtr> {
+    let mut list = Vec::new();
+    let mut child = node.children;
+    while !child.is_null() {
+        list.push(child);
+        child = (*child).next;
+    }
+    list
+}
+
+unsafe fn diff_node(
+    c: crate::xmlNodePtr,
+    rust: crate::xmlNodePtr,
+    path: &str,
+) -> Result<(), TreeDiff> {
+    let (c, rust) = (&*c, &*rust);
+    check(path, "type", c.type_, rust.type_)?;
+    if c.type_ == crate::xmlElementType_XML_DOCUMENT_NODE
+        || c.type_ == crate::xmlElementType_XML_HTML_DOCUMENT_NODE
+    {
+        // The document's name holds the URL, and its content nothing.
+    } else {
+        check(path, "name", string(c.name), string(rust.name))?;
+    }
+
+    match c.type_ {
+        crate::xmlElementType_XML_ELEMENT_NODE => {
+            check(path, "namespace", ns_string(c.ns), ns_string(rust.ns))?;
+            check(
+                path,
+                "namespace definitions",
+                ns_defs(c.nsDef),
+                ns_defs(rust.nsDef),
+            )?;
+            check(
+                path,
+                "attributes",
+                attributes(c.properties),
+                attributes(rust.properties),
+            )?;
+        }
+        crate::xmlElementType_XML_TEXT_NODE
+        | crate::xmlElementType_XML_CDATA_SECTION_NODE
+        | crate::xmlElementType_XML_COMMENT_NODE
+        | crate::xmlElementType_XML_PI_NODE => {
+            check(path, "content", string(c.content), string(rust.content))?;
+        }
+        _ => {}
+    }
+
+    // Entity references share their children with the entity declaration,
+    // and DTD children are declarations rather than content.
+    if c.type_ == crate::xmlElementType_XML_ENTITY_REF_NODE
+        || c.type_ == crate::xmlElementType_XML_DTD_NODE
+    {
+        return Ok(());
+    }
+
+    let c_children = children(c);
+    let rust_children = children(rust);
+    check(path, "child count", c_children.len(), rust_children.len())?;
+    for (i, (&c_child, &rust_child)) in c_children.iter().zip(&rust_children).enumerate() {
+        let name = match (*c_child).type_ {
+            crate::xmlElementType_XML_TEXT_NODE => "#text".to_string(),
+            crate::xmlElementType_XML_CDATA_SECTION_NODE => "#cdata".to_string(),
+            crate::xmlElementType_XML_COMMENT_NODE => "#comment".to_string(),
+            _ => string((*c_child).name).unwrap_or_default(),
+        };
+        diff_node(c_child, rust_child, &format!("{}/{}[{}]", path, name, i))?;
+    }
+    Ok(())
+}
+
+/// Call a function in both the C baseline and the linked implementation
+/// and assert that the results agree. Returns the linked result.
+///
+/// The arguments are evaluated once per implementation, so they should be
+/// cheap and free of side effects. Functions whose results are not
+/// directly comparable (e.g. pointers to freshly allocated buffers) take a
+/// `-> |c, rust| ...` closure that receives both results by reference and
+/// returns whether they match.
+///
+/// ```no_run
+/// use libxml2::differential_test;
+///
+/// let s = b"hello\0";
+/// let len = differential_test!(xmlStrlen, (s.as_ptr()));
+/// assert_eq!(len, 5);
+/// ```
+#[macro_export]
+macro_rules! differential_test {
+    ($func:ident, ($($arg:expr),* $(,)?)) => {
+        $crate::differential_test!($func, ($($arg),*) -> |c, rust| c == rust)
+    };
+    ($func:ident, ($($arg:expr),* $(,)?) -> $compare:expr) => {{
+        let c_result = unsafe { $crate::libxml2_dynload::get_c_baseline().$func($($arg),*) };
+        let rust_result = unsafe { $crate::$func($($arg),*) };
+        let compare = $compare;
+        assert!(
+            compare(&c_result, &rust_result),
+            "{} differs from the C baseline: {:?} vs {:?}",
+            stringify!($func),
+            c_result,
+            rust_result
+        );
+        rust_result
+    }};
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -82,4 +510,43 @@ mod tests {
             assert_eq!(result, 0, "Null character should not be a valid XML character");
         }
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_differential_macro() {
+        let s = b"differential\0";
+        assert_eq!(differential_test!(xmlStrlen, (s.as_ptr())), 12);
+        assert_eq!(differential_test!(xmlStrlen, (std::ptr::null())), 0);
+
+        // Buffer outputs are compared by content rather than by address.
+        let dup = differential_test!(xmlStrndup, (s.as_ptr(), 4) -> |c: &*mut crate::xmlChar, rust: &*mut crate::xmlChar| unsafe {
+            let equal = std::ffi::CStr::from_ptr(*c as *const _) == std::ffi::CStr::from_ptr(*rust as *const _);
+            crate::xmlFree.unwrap()(*c as *mut _);
+            equal
+        });
+        unsafe {
+            assert_eq!(std::ffi::CStr::from_ptr(dup as *const _).to_bytes(), b"diff");
+            crate::xmlFree.unwrap()(dup as *mut _);
+        }
+    }
+
+    #[test]
+    fn test_baseline_lib_load() {
+        let lib = BaselineLib::load().expect("C baseline library should load");
+        assert!(lib.path().exists());
+
+        let strlen = lib.symbol("xmlStrlen").expect("xmlStrlen should resolve");
+        assert!(!strlen.is_null());
+
+        let s = b"baseline\0";
+        assert_eq!(unsafe { lib.xmlStrlen(s.as_ptr()) }, 8);
+    }
+
+    #[test]
+    fn test_baseline_lib_missing_symbol() {
+        let lib = BaselineLib::load().expect("C baseline library should load");
+        match lib.symbol("xmlNoSuchFunction") {
+            Err(LoadError::MissingSymbol { name, .. }) => assert_eq!(name, "xmlNoSuchFunction"),
+            other => panic!("expected a missing symbol error, got {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,484 @@
+#![allow(clippy::missing_safety_doc)]
+
+use std::os::raw::{c_int, c_void};
+use std::ptr;
+
+/// A link of a `List`, laid out like `xmlLink` since deallocators get it
+/// and read the data back with `xmlLinkGetData`.
+#[repr(C)]
+#[derive(Debug)]
+pub struct Link {
+    next: *mut Link,
+    prev: *mut Link,
+    pub data: *mut c_void,
+}
+
+/// Called on a link before it is freed, to release its data.
+pub type Deallocator = unsafe extern "C" fn(*mut Link);
+/// Orders two data pointers like `xmlListDataCompare`: negative, zero or
+/// positive when the first is smaller, equal or greater.
+pub type Compare = unsafe extern "C" fn(*const c_void, *const c_void) -> c_int;
+
+/// Orders data by address, the comparator of lists created without one.
+unsafe extern "C" fn link_compare(data0: *const c_void, data1: *const c_void) -> c_int {
+    match data0.cmp(&data1) {
+        std::cmp::Ordering::Less => -1,
+        std::cmp::Ordering::Equal => 0,
+        std::cmp::Ordering::Greater => 1,
+    }
+}
+
+/// A circular doubly linked list of data pointers around a sentinel, the
+/// C `xmlList`. Data is kept ordered by the comparator when added with
+/// `insert` and `append`; the push operations ignore it.
+///
+/// The methods that compare data are unsafe, as the comparator gets the
+/// raw pointers.
+#[derive(Debug)]
+pub struct List {
+    sentinel: *mut Link,
+    deallocator: Option<Deallocator>,
+    compare: Compare,
+}
+
+impl List {
+    /// An empty list. Without a comparator, data is ordered by address.
+    pub fn new(deallocator: Option<Deallocator>, compare: Option<Compare>) -> List {
+        let sentinel = Box::into_raw(Box::new(Link {
+            next: ptr::null_mut(),
+            prev: ptr::null_mut(),
+            data: ptr::null_mut(),
+        }));
+        unsafe {
+            (*sentinel).next = sentinel;
+            (*sentinel).prev = sentinel;
+        }
+        List {
+            sentinel,
+            deallocator,
+            compare: compare.unwrap_or(link_compare),
+        }
+    }
+
+    unsafe fn cmp(&self, lk: *mut Link, data: *const c_void) -> c_int {
+        (self.compare)((*lk).data, data)
+    }
+
+    /// The first link whose data is not smaller than `data`, or the
+    /// sentinel.
+    unsafe fn lower_search(&self, data: *const c_void) -> *mut Link {
+        let mut lk = (*self.sentinel).next;
+        while lk != self.sentinel && self.cmp(lk, data) < 0 {
+            lk = (*lk).next;
+        }
+        lk
+    }
+
+    /// The last link whose data is not greater than `data`, or the
+    /// sentinel.
+    unsafe fn higher_search(&self, data: *const c_void) -> *mut Link {
+        let mut lk = (*self.sentinel).prev;
+        while lk != self.sentinel && self.cmp(lk, data) > 0 {
+            lk = (*lk).prev;
+        }
+        lk
+    }
+
+    /// The first link whose data compares equal to `data`.
+    unsafe fn link_search(&self, data: *const c_void) -> Option<*mut Link> {
+        let lk = self.lower_search(data);
+        (lk != self.sentinel && self.cmp(lk, data) == 0).then_some(lk)
+    }
+
+    /// The last link whose data compares equal to `data`.
+    unsafe fn link_reverse_search(&self, data: *const c_void) -> Option<*mut Link> {
+        let lk = self.higher_search(data);
+        (lk != self.sentinel && self.cmp(lk, data) == 0).then_some(lk)
+    }
+
+    /// Link `lk` in after `place`.
+    unsafe fn link_after(place: *mut Link, lk: *mut Link) {
+        (*lk).next = (*place).next;
+        (*lk).prev = place;
+        (*(*place).next).prev = lk;
+        (*place).next = lk;
+    }
+
+    unsafe fn unlink(lk: *mut Link) {
+        (*(*lk).prev).next = (*lk).next;
+        (*(*lk).next).prev = (*lk).prev;
+    }
+
+    fn add_after(place: *mut Link, data: *mut c_void) {
+        let lk = Box::into_raw(Box::new(Link {
+            next: ptr::null_mut(),
+            prev: ptr::null_mut(),
+            data,
+        }));
+        unsafe { List::link_after(place, lk) }
+    }
+
+    /// Unlink `lk`, hand it to the deallocator and free it.
+    unsafe fn deallocate(&mut self, lk: *mut Link) {
+        List::unlink(lk);
+        if let Some(deallocator) = self.deallocator {
+            deallocator(lk);
+        }
+        drop(Box::from_raw(lk));
+    }
+
+    /// The first data comparing equal to `data`.
+    pub unsafe fn search(&self, data: *const c_void) -> Option<*mut c_void> {
+        self.link_search(data).map(|lk| (*lk).data)
+    }
+
+    /// The last data comparing equal to `data`.
+    pub unsafe fn reverse_search(&self, data: *const c_void) -> Option<*mut c_void> {
+        self.link_reverse_search(data).map(|lk| (*lk).data)
+    }
+
+    /// Add `data` in order, before the data comparing equal to it.
+    pub unsafe fn insert(&mut self, data: *mut c_void) {
+        let place = (*self.lower_search(data)).prev;
+        List::add_after(place, data);
+    }
+
+    /// Add `data` in order, after the data comparing equal to it.
+    pub unsafe fn append(&mut self, data: *mut c_void) {
+        let place = self.higher_search(data);
+        List::add_after(place, data);
+    }
+
+    /// Remove the first data comparing equal to `data`. Returns whether
+    /// there was one.
+    pub unsafe fn remove_first(&mut self, data: *const c_void) -> bool {
+        match self.link_search(data) {
+            Some(lk) => {
+                self.deallocate(lk);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Remove the last data comparing equal to `data`. Returns whether
+    /// there was one.
+    pub unsafe fn remove_last(&mut self, data: *const c_void) -> bool {
+        match self.link_reverse_search(data) {
+            Some(lk) => {
+                self.deallocate(lk);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Remove every data comparing equal to `data`. Returns how many there
+    /// were.
+    pub unsafe fn remove_all(&mut self, data: *const c_void) -> usize {
+        let mut count = 0;
+        while self.remove_first(data) {
+            count += 1;
+        }
+        count
+    }
+
+    /// Remove every link.
+    pub fn clear(&mut self) {
+        unsafe {
+            let mut lk = (*self.sentinel).next;
+            while lk != self.sentinel {
+                let next = (*lk).next;
+                self.deallocate(lk);
+                lk = next;
+            }
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        unsafe { (*self.sentinel).next == self.sentinel }
+    }
+
+    /// The first link, which is the sentinel, with null data, when the
+    /// list is empty.
+    pub fn front(&self) -> *mut Link {
+        unsafe { (*self.sentinel).next }
+    }
+
+    /// The last link, or the sentinel when the list is empty.
+    pub fn end(&self) -> *mut Link {
+        unsafe { (*self.sentinel).prev }
+    }
+
+    pub fn len(&self) -> usize {
+        self.iter().count()
+    }
+
+    /// Remove the first link, if any.
+    pub fn pop_front(&mut self) {
+        if !self.is_empty() {
+            unsafe { self.deallocate(self.front()) }
+        }
+    }
+
+    /// Remove the last link, if any.
+    pub fn pop_back(&mut self) {
+        if !self.is_empty() {
+            unsafe { self.deallocate(self.end()) }
+        }
+    }
+
+    /// Add `data` first, whatever its order.
+    pub fn push_front(&mut self, data: *mut c_void) {
+        List::add_after(self.sentinel, data);
+    }
+
+    /// Add `data` last, whatever its order.
+    pub fn push_back(&mut self, data: *mut c_void) {
+        List::add_after(self.end(), data);
+    }
+
+    /// Reverse the order of the links.
+    pub fn reverse(&mut self) {
+        unsafe {
+            let mut lk = self.sentinel;
+            loop {
+                std::mem::swap(&mut (*lk).next, &mut (*lk).prev);
+                lk = (*lk).prev;
+                if lk == self.sentinel {
+                    break;
+                }
+            }
+        }
+    }
+
+    /// Move every link of `other`, sorted by the same comparator, into
+    /// this sorted list, leaving `other` empty. Each one goes after the
+    /// data comparing equal to it, so equal data keeps its order, that of
+    /// `self` first. No deallocator is called: `self` now owns the data.
+    ///
+    /// The search for the place of a link starts from the one before, so
+    /// merging sorted lists takes linear time. A link smaller than the one
+    /// before restarts it from the front, so the result is sorted whatever
+    /// the order of `other`.
+    pub unsafe fn merge(&mut self, other: &mut List) {
+        let mut place = self.sentinel;
+        let mut lk = (*other.sentinel).next;
+        while lk != other.sentinel {
+            let next = (*lk).next;
+            let data = (*lk).data;
+            if place != self.sentinel && self.cmp(place, data) > 0 {
+                place = self.sentinel;
+            }
+            while (*place).next != self.sentinel && self.cmp((*place).next, data) <= 0 {
+                place = (*place).next;
+            }
+            List::link_after(place, lk);
+            place = lk;
+            lk = next;
+        }
+        (*other.sentinel).next = other.sentinel;
+        (*other.sentinel).prev = other.sentinel;
+    }
+
+    /// Sort the links with the comparator, keeping the order of equal
+    /// data.
+    pub unsafe fn sort(&mut self) {
+        if self.is_empty() {
+            return;
+        }
+        let mut links = List::new(None, Some(self.compare));
+        // Move the links over to an empty list, then merge them back.
+        let (first, last) = (self.front(), self.end());
+        (*first).prev = links.sentinel;
+        (*last).next = links.sentinel;
+        (*links.sentinel).next = first;
+        (*links.sentinel).prev = last;
+        (*self.sentinel).next = self.sentinel;
+        (*self.sentinel).prev = self.sentinel;
+        self.merge(&mut links);
+    }
+
+    /// Insert the data of `other` with `insert`, as `xmlListCopy` does:
+    /// the data is sorted by the comparator of `self`, equal data of
+    /// `other` in reverse order. The data itself is shared, not copied.
+    pub unsafe fn copy_from(&mut self, other: &List) {
+        for data in other.iter() {
+            self.insert(data);
+        }
+    }
+
+    /// A new list with the comparator and data of this one, as
+    /// `xmlListDup` makes. It has no deallocator, so the data stays owned
+    /// by this list and must outlive the copy.
+    pub unsafe fn dup(&self) -> List {
+        let mut copy = List::new(None, Some(self.compare));
+        copy.copy_from(self);
+        copy
+    }
+
+    /// Call `walker` on the data from first to last until it returns
+    /// false.
+    pub fn walk(&self, mut walker: impl FnMut(*mut c_void) -> bool) {
+        for data in self.iter() {
+            if !walker(data) {
+                break;
+            }
+        }
+    }
+
+    /// Call `walker` on the data from last to first until it returns
+    /// false.
+    pub fn reverse_walk(&self, mut walker: impl FnMut(*mut c_void) -> bool) {
+        unsafe {
+            let mut lk = (*self.sentinel).prev;
+            while lk != self.sentinel {
+                if !walker((*lk).data) {
+                    break;
+                }
+                lk = (*lk).prev;
+            }
+        }
+    }
+
+    /// The data from first to last.
+    pub fn iter(&self) -> impl Iterator<Item = *mut c_void> + '_ {
+        let mut lk = self.front();
+        std::iter::from_fn(move || unsafe {
+            if lk == self.sentinel {
+                return None;
+            }
+            let data = (*lk).data;
+            lk = (*lk).next;
+            Some(data)
+        })
+    }
+}
+
+impl Drop for List {
+    fn drop(&mut self) {
+        self.clear();
+        unsafe { drop(Box::from_raw(self.sentinel)) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    unsafe extern "C" fn compare_first(data0: *const c_void, data1: *const c_void) -> c_int {
+        let (a, b) = (*(data0 as *const (i32, i32)), *(data1 as *const (i32, i32)));
+        a.0 - b.0
+    }
+
+    fn list_of(items: &mut [(i32, i32)]) -> List {
+        let mut list = List::new(None, Some(compare_first));
+        for item in items {
+            list.push_back(item as *mut _ as *mut c_void);
+        }
+        list
+    }
+
+    fn contents(list: &List) -> Vec<(i32, i32)> {
+        list.iter()
+            .map(|data| unsafe { *(data as *const (i32, i32)) })
+            .collect()
+    }
+
+    #[test]
+    fn test_insert_append_remove() {
+        unsafe {
+            let mut items = [(2, 0), (1, 0), (2, 1), (3, 0), (2, 2)];
+            let mut list = List::new(None, Some(compare_first));
+            for (i, item) in items.iter_mut().enumerate() {
+                let data = item as *mut _ as *mut c_void;
+                if i % 2 == 0 {
+                    list.insert(data);
+                } else {
+                    list.append(data);
+                }
+            }
+            assert_eq!(contents(&list), [(1, 0), (2, 2), (2, 1), (2, 0), (3, 0)]);
+            let key = &(2, 9) as *const _ as *const c_void;
+            assert_eq!(
+                list.search(key),
+                Some(&mut items[4] as *mut _ as *mut c_void)
+            );
+            assert!(list.remove_last(key));
+            assert_eq!(list.remove_all(key), 2);
+            assert!(!list.remove_first(key));
+            assert_eq!(contents(&list), [(1, 0), (3, 0)]);
+
+            list.reverse();
+            assert_eq!(contents(&list), [(3, 0), (1, 0)]);
+            list.pop_back();
+            list.pop_front();
+            assert!(list.is_empty());
+            assert_eq!(list.front(), list.end());
+            assert!((*list.front()).data.is_null());
+        }
+    }
+
+    #[test]
+    fn test_merge() {
+        unsafe {
+            let mut a = [(1, 0), (3, 0), (3, 1), (5, 0)];
+            let mut b = [(0, 1), (3, 2), (3, 3), (5, 1), (6, 1)];
+            let mut first = list_of(&mut a);
+            let mut second = list_of(&mut b);
+            first.merge(&mut second);
+            assert!(second.is_empty());
+            assert_eq!(
+                contents(&first),
+                [
+                    (0, 1),
+                    (1, 0),
+                    (3, 0),
+                    (3, 1),
+                    (3, 2),
+                    (3, 3),
+                    (5, 0),
+                    (5, 1),
+                    (6, 1)
+                ]
+            );
+
+            // An unsorted second list still gives a sorted one.
+            let mut c = [(4, 2), (2, 2), (4, 3)];
+            first.merge(&mut list_of(&mut c));
+            let keys: Vec<i32> = contents(&first).iter().map(|item| item.0).collect();
+            assert!(keys.windows(2).all(|pair| pair[0] <= pair[1]));
+            assert_eq!(contents(&first)[7..9], [(4, 2), (4, 3)]);
+        }
+    }
+
+    #[test]
+    fn test_dup() {
+        unsafe {
+            let mut items = [(2, 0), (1, 0), (2, 1)];
+            let mut list = list_of(&mut items);
+            let copy = list.dup();
+            assert_eq!(contents(&copy), [(1, 0), (2, 1), (2, 0)]);
+            list.pop_front();
+            list.reverse();
+            assert_eq!(contents(&list), [(2, 1), (1, 0)]);
+            assert_eq!(copy.len(), 3);
+        }
+    }
+
+    #[test]
+    fn test_sort_stable() {
+        unsafe {
+            let mut items = [(3, 0), (1, 0), (3, 1), (2, 0), (1, 1), (3, 2)];
+            let mut list = list_of(&mut items);
+            list.sort();
+            assert_eq!(
+                contents(&list),
+                [(1, 0), (1, 1), (2, 0), (3, 0), (3, 1), (3, 2)]
+            );
+            let mut empty = List::new(None, None);
+            empty.sort();
+            assert!(empty.is_empty());
+        }
+    }
+}
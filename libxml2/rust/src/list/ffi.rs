@@ -0,0 +1,255 @@
+#![allow(clippy::missing_safety_doc)]
+
+use std::mem;
+use std::os::raw::{c_int, c_void};
+use std::ptr;
+
+use super::core::{Deallocator, Link, List};
+use crate::static_bindings::{
+    xmlLink, xmlList, xmlListDataCompare, xmlListDeallocator, xmlListWalker,
+};
+
+// Ensure symbols are exported for linking
+#[cfg(feature = "rust-list")]
+#[used]
+static LIST_FFI_LINKAGE: () = ();
+
+unsafe fn as_list<'a>(l: *mut xmlList) -> Option<&'a mut List> {
+    (l as *mut List).as_mut()
+}
+
+/// Create a new list with an optional deallocator and comparator.
+#[no_mangle]
+pub unsafe extern "C" fn xmlListCreate(
+    deallocator: xmlListDeallocator,
+    compare: xmlListDataCompare,
+) -> *mut xmlList {
+    // `xmlLink` is the opaque C name of `Link`.
+    let deallocator =
+        deallocator.map(|f| mem::transmute::<unsafe extern "C" fn(*mut xmlLink), Deallocator>(f));
+    Box::into_raw(Box::new(List::new(deallocator, compare))) as *mut xmlList
+}
+
+/// Delete the list, calling the deallocator on each link.
+#[no_mangle]
+pub unsafe extern "C" fn xmlListDelete(l: *mut xmlList) {
+    if !l.is_null() {
+        drop(Box::from_raw(l as *mut List));
+    }
+}
+
+/// Search the list for the first data comparing equal to `data`.
+#[no_mangle]
+pub unsafe extern "C" fn xmlListSearch(l: *mut xmlList, data: *mut c_void) -> *mut c_void {
+    as_list(l)
+        .and_then(|l| l.search(data))
+        .unwrap_or(ptr::null_mut())
+}
+
+/// Search the list for the last data comparing equal to `data`.
+#[no_mangle]
+pub unsafe extern "C" fn xmlListReverseSearch(l: *mut xmlList, data: *mut c_void) -> *mut c_void {
+    as_list(l)
+        .and_then(|l| l.reverse_search(data))
+        .unwrap_or(ptr::null_mut())
+}
+
+/// Insert data in order, before the data comparing equal to it. Returns 0,
+/// or 1 if `l` is NULL.
+#[no_mangle]
+pub unsafe extern "C" fn xmlListInsert(l: *mut xmlList, data: *mut c_void) -> c_int {
+    match as_list(l) {
+        Some(l) => {
+            l.insert(data);
+            0
+        }
+        None => 1,
+    }
+}
+
+/// Insert data in order, after the data comparing equal to it. Returns 0,
+/// or 1 if `l` is NULL.
+#[no_mangle]
+pub unsafe extern "C" fn xmlListAppend(l: *mut xmlList, data: *mut c_void) -> c_int {
+    match as_list(l) {
+        Some(l) => {
+            l.append(data);
+            0
+        }
+        None => 1,
+    }
+}
+
+/// Remove the first data comparing equal to `data`. Returns 1 if there was
+/// one, else 0.
+#[no_mangle]
+pub unsafe extern "C" fn xmlListRemoveFirst(l: *mut xmlList, data: *mut c_void) -> c_int {
+    as_list(l).is_some_and(|l| l.remove_first(data)) as c_int
+}
+
+/// Remove the last data comparing equal to `data`. Returns 1 if there was
+/// one, else 0.
+#[no_mangle]
+pub unsafe extern "C" fn xmlListRemoveLast(l: *mut xmlList, data: *mut c_void) -> c_int {
+    as_list(l).is_some_and(|l| l.remove_last(data)) as c_int
+}
+
+/// Remove every data comparing equal to `data`. Returns how many there
+/// were.
+#[no_mangle]
+pub unsafe extern "C" fn xmlListRemoveAll(l: *mut xmlList, data: *mut c_void) -> c_int {
+    as_list(l).map_or(0, |l| l.remove_all(data) as c_int)
+}
+
+/// Remove every link.
+#[no_mangle]
+pub unsafe extern "C" fn xmlListClear(l: *mut xmlList) {
+    if let Some(l) = as_list(l) {
+        l.clear();
+    }
+}
+
+/// Returns 1 if the list is empty, 0 if not and -1 if `l` is NULL.
+#[no_mangle]
+pub unsafe extern "C" fn xmlListEmpty(l: *mut xmlList) -> c_int {
+    as_list(l).map_or(-1, |l| l.is_empty() as c_int)
+}
+
+/// The first link, which is the sentinel when the list is empty.
+#[no_mangle]
+pub unsafe extern "C" fn xmlListFront(l: *mut xmlList) -> *mut xmlLink {
+    as_list(l).map_or(ptr::null_mut(), |l| l.front() as *mut xmlLink)
+}
+
+/// The last link, which is the sentinel when the list is empty.
+#[no_mangle]
+pub unsafe extern "C" fn xmlListEnd(l: *mut xmlList) -> *mut xmlLink {
+    as_list(l).map_or(ptr::null_mut(), |l| l.end() as *mut xmlLink)
+}
+
+/// The number of links, or -1 if `l` is NULL.
+#[no_mangle]
+pub unsafe extern "C" fn xmlListSize(l: *mut xmlList) -> c_int {
+    as_list(l).map_or(-1, |l| l.len() as c_int)
+}
+
+/// Remove the first link.
+#[no_mangle]
+pub unsafe extern "C" fn xmlListPopFront(l: *mut xmlList) {
+    if let Some(l) = as_list(l) {
+        l.pop_front();
+    }
+}
+
+/// Remove the last link.
+#[no_mangle]
+pub unsafe extern "C" fn xmlListPopBack(l: *mut xmlList) {
+    if let Some(l) = as_list(l) {
+        l.pop_back();
+    }
+}
+
+/// Add data at the front. Returns 1, or 0 if `l` is NULL.
+#[no_mangle]
+pub unsafe extern "C" fn xmlListPushFront(l: *mut xmlList, data: *mut c_void) -> c_int {
+    match as_list(l) {
+        Some(l) => {
+            l.push_front(data);
+            1
+        }
+        None => 0,
+    }
+}
+
+/// Add data at the back. Returns 1, or 0 if `l` is NULL.
+#[no_mangle]
+pub unsafe extern "C" fn xmlListPushBack(l: *mut xmlList, data: *mut c_void) -> c_int {
+    match as_list(l) {
+        Some(l) => {
+            l.push_back(data);
+            1
+        }
+        None => 0,
+    }
+}
+
+/// The data of a link, or NULL.
+#[no_mangle]
+pub unsafe extern "C" fn xmlLinkGetData(lk: *mut xmlLink) -> *mut c_void {
+    match (lk as *mut Link).as_ref() {
+        Some(lk) => lk.data,
+        None => ptr::null_mut(),
+    }
+}
+
+/// Reverse the order of the links.
+#[no_mangle]
+pub unsafe extern "C" fn xmlListReverse(l: *mut xmlList) {
+    if let Some(l) = as_list(l) {
+        l.reverse();
+    }
+}
+
+/// Sort the links, keeping the order of equal data.
+#[no_mangle]
+pub unsafe extern "C" fn xmlListSort(l: *mut xmlList) {
+    if let Some(l) = as_list(l) {
+        l.sort();
+    }
+}
+
+/// Call `walker` on the data from first to last until it returns 0.
+#[no_mangle]
+pub unsafe extern "C" fn xmlListWalk(l: *mut xmlList, walker: xmlListWalker, user: *mut c_void) {
+    if let (Some(l), Some(walker)) = (as_list(l), walker) {
+        l.walk(|data| walker(data, user) != 0);
+    }
+}
+
+/// Call `walker` on the data from last to first until it returns 0.
+#[no_mangle]
+pub unsafe extern "C" fn xmlListReverseWalk(
+    l: *mut xmlList,
+    walker: xmlListWalker,
+    user: *mut c_void,
+) {
+    if let (Some(l), Some(walker)) = (as_list(l), walker) {
+        l.reverse_walk(|data| walker(data, user) != 0);
+    }
+}
+
+/// Move the data of `l2` into the sorted list `l1`, leaving `l2` empty.
+#[no_mangle]
+pub unsafe extern "C" fn xmlListMerge(l1: *mut xmlList, l2: *mut xmlList) {
+    if l1 == l2 {
+        return;
+    }
+    if let (Some(l1), Some(l2)) = (as_list(l1), as_list(l2)) {
+        l1.merge(l2);
+    }
+}
+
+/// A new list sharing the data of `old`, sorted, without a deallocator.
+/// Returns NULL if `old` is NULL.
+#[no_mangle]
+pub unsafe extern "C" fn xmlListDup(old: *mut xmlList) -> *mut xmlList {
+    match as_list(old) {
+        Some(old) => Box::into_raw(Box::new(old.dup())) as *mut xmlList,
+        None => ptr::null_mut(),
+    }
+}
+
+/// Insert the data of `old` into `cur`. Returns 0, or 1 if either is NULL.
+#[no_mangle]
+pub unsafe extern "C" fn xmlListCopy(cur: *mut xmlList, old: *mut xmlList) -> c_int {
+    if cur == old {
+        return cur.is_null() as c_int;
+    }
+    match (as_list(cur), as_list(old)) {
+        (Some(cur), Some(old)) => {
+            cur.copy_from(old);
+            0
+        }
+        _ => 1,
+    }
+}
@@ -1 +1,8 @@
-// Empty stub module for list
+//! Rust implementation of list module
+//!
+//! Doubly linked lists of data pointers, kept ordered by a comparator
+
+pub mod core;
+pub mod ffi;
+
+pub use core::*;
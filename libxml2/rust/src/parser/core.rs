@@ -0,0 +1,370 @@
+//! Namespace well-formedness checks of `xmlParseStartTag2`
+
+use std::ffi::CString;
+use std::os::raw::c_char;
+use std::ptr;
+
+use crate::chvalid::{is_ncname_char, is_ncname_start_char};
+use crate::static_bindings::{
+    xmlErrorLevel, xmlErrorLevel_XML_ERR_ERROR, xmlErrorLevel_XML_ERR_WARNING, xmlFreeURI,
+    xmlParseURISafe, xmlParserErrors, xmlParserErrors_XML_NS_ERR_ATTRIBUTE_REDEFINED,
+    xmlParserErrors_XML_NS_ERR_QNAME, xmlParserErrors_XML_NS_ERR_UNDEFINED_NAMESPACE,
+    xmlParserErrors_XML_NS_ERR_XML_NAMESPACE, xmlParserErrors_XML_WAR_NS_URI,
+    xmlParserErrors_XML_WAR_NS_URI_RELATIVE, xmlURIPtr,
+};
+
+/// The namespace bound to the `xml` prefix.
+pub const XML_NAMESPACE: &[u8] = b"http://www.w3.org/XML/1998/namespace";
+/// The namespace of `xmlns` declarations, which nothing may bind.
+pub const XMLNS_NAMESPACE: &[u8] = b"http://www.w3.org/2000/xmlns/";
+
+/// A namespace error or warning, with the code, level and message
+/// libxml2 reports it with in the `XML_FROM_NAMESPACE` domain.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NsError {
+    pub code: xmlParserErrors,
+    pub level: xmlErrorLevel,
+    pub message: String,
+}
+
+impl NsError {
+    fn error(code: xmlParserErrors, message: String) -> Self {
+        NsError {
+            code,
+            level: xmlErrorLevel_XML_ERR_ERROR,
+            message,
+        }
+    }
+
+    fn warning(code: xmlParserErrors, message: String) -> Self {
+        NsError {
+            code,
+            level: xmlErrorLevel_XML_ERR_WARNING,
+            message,
+        }
+    }
+}
+
+/// Lossy text of a name or value for an error message.
+fn text(s: &[u8]) -> std::borrow::Cow<'_, str> {
+    String::from_utf8_lossy(s)
+}
+
+/// Whether `s` can be the part of a QName around the colon, by the fifth
+/// edition NCName productions `xmlParseNCName` uses.
+fn is_ncname(s: &[u8]) -> bool {
+    let Ok(s) = std::str::from_utf8(s) else {
+        return false;
+    };
+    let mut chars = s.chars().map(u32::from);
+    chars.next().is_some_and(is_ncname_start_char) && chars.all(is_ncname_char)
+}
+
+/// A qualified name split like `xmlParseQNameHashed`.
+struct QName<'a> {
+    prefix: Option<&'a [u8]>,
+    local: &'a [u8],
+}
+
+/// Split `name` at its colon. A name with an empty part or more than one
+/// colon is reported and kept whole, without a prefix.
+fn split_qname<'a>(name: &'a [u8], errors: &mut Vec<NsError>) -> QName<'a> {
+    let Some(colon) = name.iter().position(|&c| c == b':') else {
+        return QName {
+            prefix: None,
+            local: name,
+        };
+    };
+    let (prefix, local) = (&name[..colon], &name[colon + 1..]);
+    if is_ncname(prefix) && is_ncname(local) && !local.contains(&b':') {
+        return QName {
+            prefix: Some(prefix),
+            local,
+        };
+    }
+    errors.push(NsError::error(
+        xmlParserErrors_XML_NS_ERR_QNAME,
+        format!("Failed to parse QName '{}'\n", text(name)),
+    ));
+    QName {
+        prefix: None,
+        local: name,
+    }
+}
+
+/// How `xmlParseURISafe` takes a namespace name.
+enum UriKind {
+    Invalid,
+    Relative,
+    Absolute,
+}
+
+/// Parse `uri` with whichever `xmlParseURISafe` is linked. `None` means
+/// the allocation failed, which C reports and skips the declaration for.
+fn uri_kind(uri: &[u8]) -> Option<UriKind> {
+    // Attribute values can't hold a NUL
+    let value = CString::new(uri).ok()?;
+    let mut parsed: xmlURIPtr = ptr::null_mut();
+    unsafe {
+        if xmlParseURISafe(value.as_ptr() as *const c_char, &mut parsed) < 0 {
+            return None;
+        }
+        if parsed.is_null() {
+            return Some(UriKind::Invalid);
+        }
+        let relative = (*parsed).scheme.is_null();
+        xmlFreeURI(parsed);
+        Some(if relative {
+            UriKind::Relative
+        } else {
+            UriKind::Absolute
+        })
+    }
+}
+
+/// A namespace binding in scope, `None` being the default namespace.
+struct Binding {
+    prefix: Option<Vec<u8>>,
+    uri: Vec<u8>,
+}
+
+/// Namespace scopes of the elements being parsed, checked the way
+/// `xmlParseStartTag2` checks a start tag in namespace mode.
+///
+/// Call [`NsChecker::start_element`] for each start tag with the names
+/// and normalized values of its attributes, and
+/// [`NsChecker::end_element`] when the element ends, empty elements
+/// included.
+#[derive(Default)]
+pub struct NsChecker {
+    bindings: Vec<Binding>,
+    scopes: Vec<usize>,
+    pedantic: bool,
+}
+
+impl NsChecker {
+    /// A checker with nothing but `xml` in scope. `pedantic` also warns
+    /// about relative URIs bound to a prefix, like `XML_PARSE_PEDANTIC`.
+    pub fn new(pedantic: bool) -> Self {
+        NsChecker {
+            pedantic,
+            ..Default::default()
+        }
+    }
+
+    /// The namespace `prefix` is bound to, `None` being the default one.
+    /// An empty default namespace counts as none.
+    pub fn lookup(&self, prefix: Option<&[u8]>) -> Option<&[u8]> {
+        if prefix == Some(b"xml") {
+            return Some(XML_NAMESPACE);
+        }
+        self.bindings
+            .iter()
+            .rev()
+            .find(|b| b.prefix.as_deref() == prefix)
+            .map(|b| b.uri.as_slice())
+            .filter(|uri| !uri.is_empty())
+    }
+
+    /// Whether the element being started already declares `prefix`.
+    fn declared_here(&self, prefix: Option<&[u8]>) -> bool {
+        let start = self.scopes.last().copied().unwrap_or(0);
+        self.bindings[start..]
+            .iter()
+            .any(|b| b.prefix.as_deref() == prefix)
+    }
+
+    /// Bind `prefix` for the element being started. A second declaration
+    /// of the same prefix is a plain duplicate attribute, which is the
+    /// parser's to report, and is ignored.
+    fn bind(&mut self, prefix: Option<&[u8]>, uri: &[u8]) {
+        if !self.declared_here(prefix) {
+            self.bindings.push(Binding {
+                prefix: prefix.map(<[u8]>::to_vec),
+                uri: uri.to_vec(),
+            });
+        }
+    }
+
+    /// Check `xmlns="uri"`.
+    fn declare_default(&mut self, uri: &[u8], errors: &mut Vec<NsError>) {
+        if !uri.is_empty() {
+            match uri_kind(uri) {
+                None => return,
+                Some(UriKind::Invalid) => errors.push(NsError::error(
+                    xmlParserErrors_XML_WAR_NS_URI,
+                    format!("xmlns: '{}' is not a valid URI\n", text(uri)),
+                )),
+                Some(UriKind::Relative) => errors.push(NsError::warning(
+                    xmlParserErrors_XML_WAR_NS_URI_RELATIVE,
+                    format!("xmlns: URI {} is not absolute\n", text(uri)),
+                )),
+                Some(UriKind::Absolute) => {}
+            }
+            if uri == XML_NAMESPACE {
+                errors.push(NsError::error(
+                    xmlParserErrors_XML_NS_ERR_XML_NAMESPACE,
+                    "xml namespace URI cannot be the default namespace\n".to_string(),
+                ));
+                return;
+            }
+            if uri == XMLNS_NAMESPACE {
+                errors.push(NsError::error(
+                    xmlParserErrors_XML_NS_ERR_XML_NAMESPACE,
+                    "reuse of the xmlns namespace name is forbidden\n".to_string(),
+                ));
+                return;
+            }
+        }
+        self.bind(None, uri);
+    }
+
+    /// Check `xmlns:prefix="uri"`.
+    fn declare_prefix(&mut self, prefix: &[u8], uri: &[u8], errors: &mut Vec<NsError>) {
+        let xml_error = if prefix == b"xml" {
+            // Right or wrong, xml is never rebound
+            if uri == XML_NAMESPACE {
+                return;
+            }
+            "xml namespace prefix mapped to wrong URI\n"
+        } else if uri == XML_NAMESPACE {
+            "xml namespace URI mapped to wrong prefix\n"
+        } else if prefix == b"xmlns" {
+            "redefinition of the xmlns prefix is forbidden\n"
+        } else if uri == XMLNS_NAMESPACE {
+            "reuse of the xmlns namespace name is forbidden\n"
+        } else {
+            ""
+        };
+        if !xml_error.is_empty() {
+            errors.push(NsError::error(
+                xmlParserErrors_XML_NS_ERR_XML_NAMESPACE,
+                xml_error.to_string(),
+            ));
+            return;
+        }
+        if uri.is_empty() {
+            errors.push(NsError::error(
+                xmlParserErrors_XML_NS_ERR_XML_NAMESPACE,
+                format!(
+                    "xmlns:{}: Empty XML namespace is not allowed\n",
+                    text(prefix)
+                ),
+            ));
+            return;
+        }
+        match uri_kind(uri) {
+            None => return,
+            Some(UriKind::Invalid) => errors.push(NsError::error(
+                xmlParserErrors_XML_WAR_NS_URI,
+                format!(
+                    "xmlns:{}: '{}' is not a valid URI\n",
+                    text(prefix),
+                    text(uri)
+                ),
+            )),
+            Some(UriKind::Relative) if self.pedantic => errors.push(NsError::warning(
+                xmlParserErrors_XML_WAR_NS_URI_RELATIVE,
+                format!(
+                    "xmlns:{}: URI {} is not absolute\n",
+                    text(prefix),
+                    text(uri)
+                ),
+            )),
+            Some(_) => {}
+        }
+        self.bind(Some(prefix), uri);
+    }
+
+    /// Enter an element named `name` with attributes `attrs` and return
+    /// the namespace errors of its start tag, in the order C reports them:
+    /// bad QNames and declarations as they are read, then undeclared
+    /// attribute prefixes, attributes that expand to the same name and
+    /// finally an undeclared element prefix.
+    pub fn start_element(&mut self, name: &[u8], attrs: &[(&[u8], &[u8])]) -> Vec<NsError> {
+        let mut errors = Vec::new();
+        self.scopes.push(self.bindings.len());
+
+        let element = split_qname(name, &mut errors);
+        let mut plain = Vec::new();
+        for &(attname, value) in attrs {
+            let attr = split_qname(attname, &mut errors);
+            match attr.prefix {
+                None if attr.local == b"xmlns" => self.declare_default(value, &mut errors),
+                Some(b"xmlns") => self.declare_prefix(attr.local, value, &mut errors),
+                _ => plain.push(attr),
+            }
+        }
+
+        // Resolve attribute prefixes; the default namespace doesn't apply
+        let mut resolved = Vec::with_capacity(plain.len());
+        for attr in &plain {
+            let uri = match attr.prefix {
+                None => Some(None),
+                Some(prefix) => {
+                    let uri = self.lookup(Some(prefix));
+                    if uri.is_none() {
+                        errors.push(NsError::error(
+                            xmlParserErrors_XML_NS_ERR_UNDEFINED_NAMESPACE,
+                            format!(
+                                "Namespace prefix {} for {} on {} is not defined\n",
+                                text(prefix),
+                                text(attr.local),
+                                text(element.local)
+                            ),
+                        ));
+                        // Already reported, and left out of the duplicate check
+                        None
+                    } else {
+                        Some(uri)
+                    }
+                }
+            };
+            resolved.push(uri);
+        }
+
+        // [ WFC: Unique Att Spec ] as extended by Namespaces in XML. Each
+        // attribute is compared with the first one of its expanded name;
+        // identical QNames are the parser's plain duplicates.
+        let mut seen: Vec<(&QName, Option<&[u8]>)> = Vec::new();
+        for (attr, uri) in plain.iter().zip(&resolved) {
+            let Some(uri) = *uri else { continue };
+            match seen
+                .iter()
+                .find(|(first, first_uri)| first.local == attr.local && *first_uri == uri)
+            {
+                Some((first, _)) if first.prefix == attr.prefix => {}
+                Some(_) => errors.push(NsError::error(
+                    xmlParserErrors_XML_NS_ERR_ATTRIBUTE_REDEFINED,
+                    format!(
+                        "Namespaced Attribute {} in '{}' redefined\n",
+                        text(attr.local),
+                        uri.map_or("(null)".into(), text)
+                    ),
+                )),
+                None => seen.push((attr, uri)),
+            }
+        }
+
+        if let Some(prefix) = element.prefix {
+            if self.lookup(Some(prefix)).is_none() {
+                errors.push(NsError::error(
+                    xmlParserErrors_XML_NS_ERR_UNDEFINED_NAMESPACE,
+                    format!(
+                        "Namespace prefix {} on {} is not defined\n",
+                        text(prefix),
+                        text(element.local)
+                    ),
+                ));
+            }
+        }
+        errors
+    }
+
+    /// Leave the innermost element, dropping the namespaces it declared.
+    pub fn end_element(&mut self) {
+        if let Some(start) = self.scopes.pop() {
+            self.bindings.truncate(start);
+        }
+    }
+}
@@ -1 +1,10 @@
-// Empty stub module for parser
+//! Rust implementation of parser module
+//!
+//! Namespace well-formedness checks of start tags, and a parse that runs
+//! them
+
+pub mod core;
+pub mod sax;
+
+pub use core::*;
+pub use sax::*;
@@ -0,0 +1,186 @@
+//! Namespace checks on the start tags of a real parse
+//!
+//! In namespace mode `parser.c` resolves prefixes and drops bad
+//! declarations before `startElementNs` sees a tag, so the checks run on
+//! the SAX1 `startElement` callback instead, which gets each tag as
+//! written, with its attribute values normalized.
+
+use std::borrow::Cow;
+use std::ffi::{CStr, CString};
+use std::os::raw::{c_char, c_int, c_void};
+use std::ptr;
+
+use super::core::{NsChecker, NsError};
+use crate::static_bindings::{
+    xmlChar, xmlCtxtReadMemory, xmlErrorDomain, xmlErrorDomain_XML_FROM_NAMESPACE, xmlErrorLevel,
+    xmlErrorLevel_XML_ERR_ERROR, xmlFree, xmlFreeDoc, xmlNodePtr, xmlParserCtxtPtr,
+    xmlParserErrors, xmlParserOption_XML_PARSE_PEDANTIC, xmlSAXHandler, xmlStringDecodeEntities,
+};
+
+/// `XML_SUBSTITUTE_REF` of parserInternals.h, which bindgen leaves out.
+const XML_SUBSTITUTE_REF: c_int = 1;
+
+extern "C" {
+    // Private in parserInternals.c
+    fn xmlCtxtErr(
+        ctxt: xmlParserCtxtPtr,
+        node: xmlNodePtr,
+        domain: xmlErrorDomain,
+        code: xmlParserErrors,
+        level: xmlErrorLevel,
+        str1: *const xmlChar,
+        str2: *const xmlChar,
+        str3: *const xmlChar,
+        int1: c_int,
+        msg: *const c_char,
+        ...
+    );
+}
+
+unsafe fn checker_from_ctxt<'a>(ctx: *mut c_void) -> Option<&'a mut NsChecker> {
+    let ctxt = ctx as xmlParserCtxtPtr;
+    if ctxt.is_null() {
+        return None;
+    }
+    ((*ctxt)._private as *mut NsChecker).as_mut()
+}
+
+/// Report `error` like `xmlNsErr` and `xmlNsWarn` do.
+unsafe fn report(ctxt: xmlParserCtxtPtr, error: &NsError) {
+    if error.level == xmlErrorLevel_XML_ERR_ERROR {
+        (*ctxt).nsWellFormed = 0;
+    }
+    // Built from C strings, so no NUL inside
+    let message = CString::new(error.message.as_str()).unwrap_or_default();
+    xmlCtxtErr(
+        ctxt,
+        ptr::null_mut(),
+        xmlErrorDomain_XML_FROM_NAMESPACE,
+        error.code,
+        error.level,
+        ptr::null(),
+        ptr::null(),
+        ptr::null(),
+        0,
+        c"%s".as_ptr(),
+        message.as_ptr(),
+    );
+}
+
+/// The value of attribute `name` as `xmlParseStartTag2` sees it. SAX1
+/// leaves entity references in values unless they are replaced, while
+/// namespace mode always expands them in namespace declarations.
+unsafe fn namespace_value<'a>(
+    ctx: *mut c_void,
+    name: &[u8],
+    value: *const xmlChar,
+    bytes: &'a [u8],
+) -> Cow<'a, [u8]> {
+    let declaration = name == b"xmlns" || name.starts_with(b"xmlns:");
+    if !declaration || !bytes.contains(&b'&') {
+        return Cow::Borrowed(bytes);
+    }
+    let expanded =
+        xmlStringDecodeEntities(ctx as xmlParserCtxtPtr, value, XML_SUBSTITUTE_REF, 0, 0, 0);
+    if expanded.is_null() {
+        return Cow::Borrowed(bytes);
+    }
+    let owned = CStr::from_ptr(expanded as *const c_char)
+        .to_bytes()
+        .to_vec();
+    xmlFree.unwrap()(expanded as *mut c_void);
+    Cow::Owned(owned)
+}
+
+unsafe extern "C" fn start_element_hook(
+    ctx: *mut c_void,
+    name: *const xmlChar,
+    atts: *mut *const xmlChar,
+) {
+    let Some(checker) = checker_from_ctxt(ctx) else {
+        return;
+    };
+    let bytes = |s: *const xmlChar| {
+        if s.is_null() {
+            &b""[..]
+        } else {
+            CStr::from_ptr(s as *const c_char).to_bytes()
+        }
+    };
+    let mut attrs: Vec<(&[u8], Cow<[u8]>)> = Vec::new();
+    if !atts.is_null() {
+        let mut i = 0;
+        while !(*atts.add(i)).is_null() {
+            let (attname, value) = (bytes(*atts.add(i)), *atts.add(i + 1));
+            attrs.push((attname, namespace_value(ctx, attname, value, bytes(value))));
+            i += 2;
+        }
+    }
+    let attrs: Vec<_> = attrs.iter().map(|(n, v)| (*n, &v[..])).collect();
+    for error in checker.start_element(bytes(name), &attrs) {
+        report(ctx as xmlParserCtxtPtr, &error);
+    }
+}
+
+unsafe extern "C" fn end_element_hook(ctx: *mut c_void, _name: *const xmlChar) {
+    if let Some(checker) = checker_from_ctxt(ctx) {
+        checker.end_element();
+    }
+}
+
+/// Parse `buffer` with `ctxt` and `options`, checking the start tags with
+/// an [`NsChecker`] instead of `xmlParseStartTag2`. Namespace errors go to
+/// the error handlers of `ctxt` in the `XML_FROM_NAMESPACE` domain, as C
+/// reports them; `XML_PARSE_PEDANTIC` makes the checker pedantic.
+///
+/// The DTD is read as usual, but no element tree is built. Returns 0 if
+/// the document is well-formed, namespaces included, 1 if only its
+/// namespaces are wrong, and -1 otherwise.
+pub unsafe fn check_namespaces(ctxt: xmlParserCtxtPtr, buffer: &[u8], options: c_int) -> c_int {
+    if ctxt.is_null() || (*ctxt).sax.is_null() || buffer.len() > c_int::MAX as usize {
+        return -1;
+    }
+
+    // The handlers of `ctxt`, so DTDs and errors go where they would.
+    // Without element handlers for namespace mode, parser.c uses SAX1.
+    let mut sax: xmlSAXHandler = ptr::read((*ctxt).sax);
+    sax.startElementNs = None;
+    sax.endElementNs = None;
+    sax.startElement = Some(start_element_hook);
+    sax.endElement = Some(end_element_hook);
+    // Content would have no element to go into
+    sax.characters = None;
+    sax.ignorableWhitespace = None;
+    sax.cdataBlock = None;
+    sax.reference = None;
+    sax.comment = None;
+    sax.processingInstruction = None;
+
+    let pedantic = options & xmlParserOption_XML_PARSE_PEDANTIC as c_int != 0;
+    let mut checker = NsChecker::new(pedantic);
+    let saved_sax = ptr::replace((*ctxt).sax, sax);
+    let saved_private = (*ctxt)._private;
+    (*ctxt)._private = &mut checker as *mut NsChecker as *mut c_void;
+    // Only ever turned on, so a parse before this one may have left it on
+    (*ctxt).sax2 = 0;
+
+    let doc = xmlCtxtReadMemory(
+        ctxt,
+        buffer.as_ptr() as *const c_char,
+        buffer.len() as c_int,
+        ptr::null(),
+        ptr::null(),
+        options,
+    );
+    xmlFreeDoc(doc);
+
+    *(*ctxt).sax = saved_sax;
+    (*ctxt)._private = saved_private;
+    if (*ctxt).wellFormed == 0 {
+        -1
+    } else if (*ctxt).nsWellFormed == 0 {
+        1
+    } else {
+        0
+    }
+}
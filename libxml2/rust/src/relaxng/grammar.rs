@@ -0,0 +1,1522 @@
+#![allow(clippy::missing_safety_doc)]
+
+use std::collections::HashMap;
+use std::ffi::{CStr, CString};
+use std::os::raw::{c_char, c_int, c_void};
+use std::ptr;
+
+use crate::static_bindings::{
+    xmlAutomataCompile, xmlAutomataGetInitState, xmlAutomataNewEpsilon, xmlAutomataNewTransition2,
+    xmlAutomataPtr, xmlAutomataSetFinalState, xmlAutomataStatePtr, xmlChar, xmlDocGetRootElement,
+    xmlDocPtr, xmlElementType_XML_CDATA_SECTION_NODE, xmlElementType_XML_ELEMENT_NODE,
+    xmlElementType_XML_TEXT_NODE, xmlFree, xmlFreeAutomata, xmlGetProp, xmlNewAutomata,
+    xmlNodeGetContent, xmlNodePtr, xmlParserErrors_XML_RNGP_ATTRIBUTE_CHILDREN,
+    xmlParserErrors_XML_RNGP_ATTRIBUTE_EMPTY, xmlParserErrors_XML_RNGP_CHOICE_CONTENT,
+    xmlParserErrors_XML_RNGP_DEFINE_EMPTY, xmlParserErrors_XML_RNGP_DEFINE_NAME_MISSING,
+    xmlParserErrors_XML_RNGP_ELEMENT_EMPTY, xmlParserErrors_XML_RNGP_ELEMENT_NAME,
+    xmlParserErrors_XML_RNGP_ELEMENT_NO_CONTENT, xmlParserErrors_XML_RNGP_ELEM_CONTENT_EMPTY,
+    xmlParserErrors_XML_RNGP_ELEM_CONTENT_ERROR, xmlParserErrors_XML_RNGP_EMPTY,
+    xmlParserErrors_XML_RNGP_EMPTY_CONSTRUCT, xmlParserErrors_XML_RNGP_EMPTY_CONTENT,
+    xmlParserErrors_XML_RNGP_EMPTY_NOT_EMPTY, xmlParserErrors_XML_RNGP_GRAMMAR_CONTENT,
+    xmlParserErrors_XML_RNGP_GRAMMAR_EMPTY, xmlParserErrors_XML_RNGP_GRAMMAR_NO_START,
+    xmlParserErrors_XML_RNGP_GROUP_ATTR_CONFLICT, xmlParserErrors_XML_RNGP_INVALID_DEFINE_NAME,
+    xmlParserErrors_XML_RNGP_NOTALLOWED_NOT_EMPTY, xmlParserErrors_XML_RNGP_PAT_ATTR_ATTR,
+    xmlParserErrors_XML_RNGP_PAT_ATTR_ELEM, xmlParserErrors_XML_RNGP_PAT_ONEMORE_GROUP_ATTR,
+    xmlParserErrors_XML_RNGP_PAT_START_ATTR, xmlParserErrors_XML_RNGP_PAT_START_EMPTY,
+    xmlParserErrors_XML_RNGP_PAT_START_GROUP, xmlParserErrors_XML_RNGP_PAT_START_ONEMORE,
+    xmlParserErrors_XML_RNGP_PAT_START_TEXT, xmlParserErrors_XML_RNGP_REF_CYCLE,
+    xmlParserErrors_XML_RNGP_REF_NAME_INVALID, xmlParserErrors_XML_RNGP_REF_NOT_EMPTY,
+    xmlParserErrors_XML_RNGP_REF_NO_DEF, xmlParserErrors_XML_RNGP_REF_NO_NAME,
+    xmlParserErrors_XML_RNGP_START_CONTENT, xmlParserErrors_XML_RNGP_START_EMPTY,
+    xmlParserErrors_XML_RNGP_TEXT_HAS_CHILD, xmlParserErrors_XML_RNGP_UNKNOWN_CONSTRUCT,
+    xmlRegFreeRegexp, xmlRegexpIsDeterminist, xmlRegexpPtr, xmlValidateNCName,
+};
+
+extern "C" {
+    fn xmlAutomataSetFlags(am: xmlAutomataPtr, flags: c_int);
+}
+
+const RELAXNG_NS: &[u8] = b"http://relaxng.org/ns/structure/1.0";
+
+/// The pattern kinds of `xmlRelaxNGType` the subset supports.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) enum Kind {
+    Empty,
+    NotAllowed,
+    Text,
+    Element,
+    Attribute,
+    Def,
+    Ref,
+    Optional,
+    ZeroOrMore,
+    OneOrMore,
+    Choice,
+    Group,
+    Start,
+    Noop,
+}
+
+pub(crate) const IS_NULLABLE: u32 = 1 << 0;
+pub(crate) const IS_NOT_NULLABLE: u32 = 1 << 1;
+pub(crate) const IS_TRIABLE: u32 = 1 << 4;
+pub(crate) const IS_PROCESSED: u32 = 1 << 5;
+pub(crate) const IS_COMPILABLE: u32 = 1 << 6;
+pub(crate) const IS_NOT_COMPILABLE: u32 = 1 << 7;
+
+const IN_ATTRIBUTE: u32 = 1 << 0;
+const IN_ONEORMORE: u32 = 1 << 1;
+const IN_START: u32 = 1 << 4;
+const IN_OOMGROUP: u32 = 1 << 5;
+
+/// `xmlRelaxNGContentType`.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ContentType {
+    Error = -1,
+    Empty = 0,
+    Simple = 1,
+    Complex = 2,
+}
+
+impl ContentType {
+    fn from_i32(value: i32) -> Self {
+        match value {
+            0 => ContentType::Empty,
+            1 => ContentType::Simple,
+            2 => ContentType::Complex,
+            _ => ContentType::Error,
+        }
+    }
+
+    fn group(self, other: Self) -> Self {
+        use ContentType::*;
+        match (self, other) {
+            (Error, _) | (_, Error) => Error,
+            (Empty, ct) | (ct, Empty) => ct,
+            (Complex, Complex) => Complex,
+            _ => Error,
+        }
+    }
+
+    fn max(self, other: Self) -> Self {
+        use ContentType::*;
+        match (self, other) {
+            (Error, _) | (_, Error) => Error,
+            (Simple, _) | (_, Simple) => Simple,
+            (Complex, _) | (_, Complex) => Complex,
+            _ => Empty,
+        }
+    }
+}
+
+/// Key of a choice branch in the triage table: the element name and
+/// namespace, or `#text` without one.
+pub(crate) type TriageKey = (Vec<u8>, Option<Vec<u8>>);
+
+/// A name, its namespace and the children left after the name class.
+type NameClass = (Vec<u8>, Vec<u8>, Vec<xmlNodePtr>);
+
+/// A node of the simplified grammar, `xmlRelaxNGDefine` with the links
+/// replaced by indexes into `Grammar::defines`.
+pub(crate) struct Define {
+    pub(crate) kind: Kind,
+    pub(crate) name: Option<Vec<u8>>,
+    /// The namespace of an element or attribute name, empty for none.
+    pub(crate) ns: Option<Vec<u8>>,
+    pub(crate) content: Option<usize>,
+    pub(crate) attrs: Option<usize>,
+    pub(crate) next: Option<usize>,
+    pub(crate) depth: i32,
+    pub(crate) dflags: u32,
+    /// The branch of a triable choice to try for each element name.
+    pub(crate) triage: Option<HashMap<TriageKey, usize>>,
+    /// The compiled content model of an element, if deterministic.
+    pub(crate) cont_model: xmlRegexpPtr,
+}
+
+impl Define {
+    fn new(kind: Kind) -> Self {
+        Define {
+            kind,
+            name: None,
+            ns: None,
+            content: None,
+            attrs: None,
+            next: None,
+            depth: -1,
+            dflags: 0,
+            triage: None,
+            cont_model: ptr::null_mut(),
+        }
+    }
+}
+
+/// A RELAX NG error the parser reports before any schema is built.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GrammarError {
+    /// The schema is invalid: the `xmlParserErrors` code and message of
+    /// the first error xmlRelaxNGParse reports.
+    Invalid { code: c_int, message: String },
+    /// The schema uses a construct the port does not support yet.
+    Unsupported(String),
+}
+
+impl GrammarError {
+    /// The `xmlParserErrors` code, `XML_RNGP_UNKNOWN_CONSTRUCT` for
+    /// unsupported constructs.
+    pub fn code(&self) -> c_int {
+        match self {
+            GrammarError::Invalid { code, .. } => *code,
+            GrammarError::Unsupported(_) => xmlParserErrors_XML_RNGP_UNKNOWN_CONSTRUCT as c_int,
+        }
+    }
+}
+
+fn invalid(code: u32, message: String) -> GrammarError {
+    GrammarError::Invalid {
+        code: code as c_int,
+        message,
+    }
+}
+
+pub(super) fn text(value: &[u8]) -> std::borrow::Cow<'_, str> {
+    String::from_utf8_lossy(value)
+}
+
+/// A compiled RELAX NG grammar, the simplified define graph of
+/// xmlRelaxNGParse.
+pub struct Grammar {
+    pub(crate) defines: Vec<Define>,
+    pub(crate) start: usize,
+}
+
+impl Drop for Grammar {
+    fn drop(&mut self) {
+        for def in &self.defines {
+            if !def.cont_model.is_null() {
+                unsafe { xmlRegFreeRegexp(def.cont_model) };
+            }
+        }
+    }
+}
+
+pub(super) unsafe fn bytes<'a>(p: *const xmlChar) -> &'a [u8] {
+    if p.is_null() {
+        b""
+    } else {
+        CStr::from_ptr(p as *const c_char).to_bytes()
+    }
+}
+
+/// Take ownership of a string returned by libxml2.
+unsafe fn owned(p: *mut xmlChar) -> Option<Vec<u8>> {
+    if p.is_null() {
+        return None;
+    }
+    let value = bytes(p).to_vec();
+    xmlFree.unwrap()(p as *mut c_void);
+    Some(value)
+}
+
+unsafe fn prop(node: xmlNodePtr, name: &CStr) -> Option<Vec<u8>> {
+    owned(xmlGetProp(node, name.as_ptr() as *const xmlChar))
+}
+
+unsafe fn is_relaxng(node: xmlNodePtr) -> bool {
+    (*node).type_ == xmlElementType_XML_ELEMENT_NODE
+        && !(*node).ns.is_null()
+        && bytes((*(*node).ns).href) == RELAXNG_NS
+}
+
+unsafe fn is_rng(node: xmlNodePtr, name: &[u8]) -> bool {
+    is_relaxng(node) && bytes((*node).name) == name
+}
+
+fn is_blank(c: u8) -> bool {
+    matches!(c, 0x20 | 0x09 | 0x0A | 0x0D)
+}
+
+/// The children xmlRelaxNGCleanupTree keeps: RELAX NG elements and
+/// text that is not blank. Annotations, comments and processing
+/// instructions are dropped.
+unsafe fn children(node: xmlNodePtr) -> Vec<xmlNodePtr> {
+    let mut ret = Vec::new();
+    let mut cur = (*node).children;
+    while !cur.is_null() {
+        let keep = match (*cur).type_ {
+            xmlElementType_XML_ELEMENT_NODE => is_relaxng(cur),
+            xmlElementType_XML_TEXT_NODE | xmlElementType_XML_CDATA_SECTION_NODE => {
+                !bytes((*cur).content).iter().all(|&c| is_blank(c))
+            }
+            _ => false,
+        };
+        if keep {
+            ret.push(cur);
+        }
+        cur = (*cur).next;
+    }
+    ret
+}
+
+/// xmlRelaxNGNormExtSpace: without leading blanks only trailing ones
+/// are removed, otherwise all blanks are.
+fn norm_ext_space(value: &[u8]) -> Vec<u8> {
+    if value.first().is_some_and(|&c| is_blank(c)) {
+        value.iter().copied().filter(|&c| !is_blank(c)).collect()
+    } else {
+        let end = value
+            .iter()
+            .rposition(|&c| !is_blank(c))
+            .map_or(0, |i| i + 1);
+        value[..end].to_vec()
+    }
+}
+
+fn is_ncname(value: &[u8]) -> bool {
+    match CString::new(value) {
+        Ok(value) => unsafe { xmlValidateNCName(value.as_ptr() as *const xmlChar, 0) == 0 },
+        Err(_) => false,
+    }
+}
+
+/// The `ns` attribute of `node` or of its nearest ancestor carrying one.
+unsafe fn inherited_ns(mut node: xmlNodePtr) -> Vec<u8> {
+    while !node.is_null() && (*node).type_ == xmlElementType_XML_ELEMENT_NODE {
+        if let Some(ns) = prop(node, c"ns") {
+            return ns;
+        }
+        node = (*node).parent;
+    }
+    Vec::new()
+}
+
+/// The define graph under construction, with the state xmlRelaxNGParse
+/// keeps in its parser context.
+struct Parser {
+    defines: Vec<Define>,
+    defs: HashMap<Vec<u8>, usize>,
+    refs: Vec<usize>,
+    start: Option<usize>,
+    am: xmlAutomataPtr,
+    state: xmlAutomataStatePtr,
+}
+
+impl Grammar {
+    /// Parse the RELAX NG schema `doc`: its grammar, start, defines and
+    /// references, then simplify and compile it as xmlRelaxNGParse does.
+    /// Only the first error is returned.
+    pub unsafe fn parse(doc: xmlDocPtr) -> Result<Grammar, GrammarError> {
+        let mut parser = Parser {
+            defines: Vec::new(),
+            defs: HashMap::new(),
+            refs: Vec::new(),
+            start: None,
+            am: ptr::null_mut(),
+            state: ptr::null_mut(),
+        };
+        let root = xmlDocGetRootElement(doc);
+        if root.is_null() || !is_relaxng(root) {
+            return Err(invalid(
+                xmlParserErrors_XML_RNGP_EMPTY,
+                "xmlRelaxNGParse: schemas is empty\n".to_string(),
+            ));
+        }
+        if is_rng(root, b"grammar") {
+            parser.parse_grammar(root)?;
+        } else {
+            parser.parse_start(&[root])?;
+        }
+        let start = match parser.start {
+            Some(start) => start,
+            None => {
+                return Err(invalid(
+                    xmlParserErrors_XML_RNGP_EMPTY,
+                    "xmlRelaxNGParse: schemas is empty\n".to_string(),
+                ))
+            }
+        };
+        parser.check_cycles(Some(start), 0)?;
+        parser.simplify(Some(start), None);
+        let mut start = start;
+        while parser.defines[start].kind == Kind::Noop && parser.defines[start].next.is_some() {
+            match parser.defines[start].content {
+                Some(content) => start = content,
+                None => break,
+            }
+        }
+        parser.check_rules(Some(start), IN_START, Kind::Noop)?;
+        // Attribute values are only checked against `text` and `empty`;
+        // anything else is rejected once the rules C checks have passed.
+        for def in &parser.defines {
+            if def.kind == Kind::Attribute {
+                if let Some(content) = def.content {
+                    if !matches!(parser.defines[content].kind, Kind::Text | Kind::Empty) {
+                        return Err(GrammarError::Unsupported("attribute content".to_string()));
+                    }
+                }
+            }
+        }
+
+        let mut def = Define::new(Kind::Start);
+        def.content = Some(start);
+        let start = parser.add(def);
+        parser.try_compile(Some(start));
+        Ok(Grammar {
+            defines: std::mem::take(&mut parser.defines),
+            start,
+        })
+    }
+}
+
+impl Parser {
+    fn add(&mut self, def: Define) -> usize {
+        self.defines.push(def);
+        self.defines.len() - 1
+    }
+
+    fn def(&self, idx: usize) -> &Define {
+        &self.defines[idx]
+    }
+
+    fn def_mut(&mut self, idx: usize) -> &mut Define {
+        &mut self.defines[idx]
+    }
+
+    /// The defines linked from `first` through `next`.
+    fn chain(&self, first: Option<usize>) -> Vec<usize> {
+        let mut ret = Vec::new();
+        let mut cur = first;
+        while let Some(idx) = cur {
+            ret.push(idx);
+            cur = self.defines[idx].next;
+        }
+        ret
+    }
+
+    /// Link `defs` through `next`, returning the head.
+    fn link(&mut self, defs: &[usize]) -> Option<usize> {
+        for pair in defs.windows(2) {
+            self.defines[pair[0]].next = Some(pair[1]);
+        }
+        defs.first().copied()
+    }
+
+    /// xmlRelaxNGParseGrammar for the top-level grammar.
+    unsafe fn parse_grammar(&mut self, node: xmlNodePtr) -> Result<(), GrammarError> {
+        let nodes = children(node);
+        if nodes.is_empty() {
+            return Err(invalid(
+                xmlParserErrors_XML_RNGP_GRAMMAR_EMPTY,
+                "grammar has no children\n".to_string(),
+            ));
+        }
+        for &child in &nodes {
+            if is_rng(child, b"start") {
+                let content = children(child);
+                if content.is_empty() {
+                    return Err(invalid(
+                        xmlParserErrors_XML_RNGP_START_EMPTY,
+                        "start has no children\n".to_string(),
+                    ));
+                }
+                if self.start.is_some() {
+                    return Err(GrammarError::Unsupported("combine".to_string()));
+                }
+                self.parse_start(&content)?;
+            } else if is_rng(child, b"define") {
+                self.parse_define(child)?;
+            } else if is_rng(child, b"include") {
+                return Err(GrammarError::Unsupported("include".to_string()));
+            } else {
+                return Err(invalid(
+                    xmlParserErrors_XML_RNGP_GRAMMAR_CONTENT,
+                    format!(
+                        "grammar has unexpected child {}\n",
+                        text(bytes((*child).name))
+                    ),
+                ));
+            }
+        }
+        if self.start.is_none() {
+            return Err(invalid(
+                xmlParserErrors_XML_RNGP_GRAMMAR_NO_START,
+                "Element <grammar> has no <start>\n".to_string(),
+            ));
+        }
+        for &r in &self.refs {
+            let name = self.defines[r].name.clone().unwrap_or_default();
+            match self.defs.get(&name) {
+                Some(&def) => self.defines[r].content = Some(def),
+                None => {
+                    return Err(invalid(
+                        xmlParserErrors_XML_RNGP_REF_NO_DEF,
+                        format!("Reference {} has no matching definition\n", text(&name)),
+                    ))
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// xmlRelaxNGParseStart, with `nodes` the children of `<start>` or
+    /// the root pattern of a schema without a grammar.
+    unsafe fn parse_start(&mut self, nodes: &[xmlNodePtr]) -> Result<(), GrammarError> {
+        let first = nodes[0];
+        let def = if is_rng(first, b"empty") {
+            if !children(first).is_empty() {
+                return Err(invalid(
+                    xmlParserErrors_XML_RNGP_EMPTY_CONTENT,
+                    "element empty is not empty\n".to_string(),
+                ));
+            }
+            Some(self.add(Define::new(Kind::Empty)))
+        } else if is_rng(first, b"notAllowed") {
+            if !children(first).is_empty() {
+                return Err(invalid(
+                    xmlParserErrors_XML_RNGP_NOTALLOWED_NOT_EMPTY,
+                    "element notAllowed is not empty\n".to_string(),
+                ));
+            }
+            Some(self.add(Define::new(Kind::NotAllowed)))
+        } else {
+            self.parse_patterns(nodes, true)?
+        };
+        self.start = def;
+        if nodes.len() > 1 {
+            return Err(invalid(
+                xmlParserErrors_XML_RNGP_START_CONTENT,
+                "start more than one children\n".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    unsafe fn parse_define(&mut self, node: xmlNodePtr) -> Result<(), GrammarError> {
+        let name = match prop(node, c"name") {
+            Some(name) => norm_ext_space(&name),
+            None => {
+                return Err(invalid(
+                    xmlParserErrors_XML_RNGP_DEFINE_NAME_MISSING,
+                    "define has no name\n".to_string(),
+                ))
+            }
+        };
+        if !is_ncname(&name) {
+            return Err(invalid(
+                xmlParserErrors_XML_RNGP_INVALID_DEFINE_NAME,
+                format!("define name '{}' is not an NCName\n", text(&name)),
+            ));
+        }
+        let nodes = children(node);
+        if nodes.is_empty() {
+            return Err(invalid(
+                xmlParserErrors_XML_RNGP_DEFINE_EMPTY,
+                "define has no children\n".to_string(),
+            ));
+        }
+        if self.defs.contains_key(&name) {
+            return Err(GrammarError::Unsupported("combine".to_string()));
+        }
+        let mut def = Define::new(Kind::Def);
+        def.name = Some(name.clone());
+        def.content = self.parse_patterns(&nodes, false)?;
+        let def = self.add(def);
+        self.defs.insert(name, def);
+        Ok(())
+    }
+
+    /// xmlRelaxNGParsePatterns: with `group`, elements followed by other
+    /// patterns are wrapped in an implicit group.
+    unsafe fn parse_patterns(
+        &mut self,
+        nodes: &[xmlNodePtr],
+        group: bool,
+    ) -> Result<Option<usize>, GrammarError> {
+        let mut items = Vec::new();
+        let mut wrap = false;
+        for &node in nodes {
+            let cur = self.parse_pattern(node)?;
+            if is_rng(node, b"element")
+                && group
+                && items.len() == 1
+                && self.def(items[0]).kind == Kind::Element
+            {
+                wrap = true;
+            }
+            items.push(cur);
+        }
+        let head = self.link(&items);
+        if wrap {
+            let mut def = Define::new(Kind::Group);
+            def.content = head;
+            return Ok(Some(self.add(def)));
+        }
+        Ok(head)
+    }
+
+    unsafe fn parse_pattern(&mut self, node: xmlNodePtr) -> Result<usize, GrammarError> {
+        let name = bytes((*node).name);
+        if !is_relaxng(node) {
+            return Err(invalid(
+                xmlParserErrors_XML_RNGP_UNKNOWN_CONSTRUCT,
+                format!("Unexpected node {} is not a pattern\n", text(name)),
+            ));
+        }
+        let nodes = children(node);
+        let kind = match name {
+            b"element" => return self.parse_element(node),
+            b"attribute" => return self.parse_attribute(node),
+            b"empty" => {
+                if !nodes.is_empty() {
+                    return Err(invalid(
+                        xmlParserErrors_XML_RNGP_EMPTY_NOT_EMPTY,
+                        "empty: had a child node\n".to_string(),
+                    ));
+                }
+                Kind::Empty
+            }
+            b"text" => {
+                if !nodes.is_empty() {
+                    return Err(invalid(
+                        xmlParserErrors_XML_RNGP_TEXT_HAS_CHILD,
+                        "text: had a child node\n".to_string(),
+                    ));
+                }
+                Kind::Text
+            }
+            b"notAllowed" => {
+                if !nodes.is_empty() {
+                    return Err(invalid(
+                        xmlParserErrors_XML_RNGP_NOTALLOWED_NOT_EMPTY,
+                        "xmlRelaxNGParse: notAllowed element is not empty\n".to_string(),
+                    ));
+                }
+                Kind::NotAllowed
+            }
+            b"zeroOrMore" | b"oneOrMore" | b"optional" | b"choice" | b"group" => {
+                if nodes.is_empty() {
+                    return Err(invalid(
+                        xmlParserErrors_XML_RNGP_EMPTY_CONSTRUCT,
+                        format!("Element {} is empty\n", text(name)),
+                    ));
+                }
+                let (kind, group) = match name {
+                    b"zeroOrMore" => (Kind::ZeroOrMore, true),
+                    b"oneOrMore" => (Kind::OneOrMore, true),
+                    b"optional" => (Kind::Optional, true),
+                    b"choice" => (Kind::Choice, false),
+                    _ => (Kind::Group, false),
+                };
+                let mut def = Define::new(kind);
+                def.content = self.parse_patterns(&nodes, group)?;
+                return Ok(self.add(def));
+            }
+            b"ref" => return self.parse_ref(node, nodes.is_empty()),
+            b"data" | b"value" | b"list" | b"interleave" | b"externalRef" | b"grammar"
+            | b"parentRef" | b"mixed" => {
+                return Err(GrammarError::Unsupported(text(name).into_owned()))
+            }
+            _ => {
+                return Err(invalid(
+                    xmlParserErrors_XML_RNGP_UNKNOWN_CONSTRUCT,
+                    format!("Unexpected node {} is not a pattern\n", text(name)),
+                ))
+            }
+        };
+        Ok(self.add(Define::new(kind)))
+    }
+
+    unsafe fn parse_ref(&mut self, node: xmlNodePtr, empty: bool) -> Result<usize, GrammarError> {
+        let name = match prop(node, c"name") {
+            Some(name) => norm_ext_space(&name),
+            None => {
+                return Err(invalid(
+                    xmlParserErrors_XML_RNGP_REF_NO_NAME,
+                    "ref has no name\n".to_string(),
+                ))
+            }
+        };
+        if !is_ncname(&name) {
+            return Err(invalid(
+                xmlParserErrors_XML_RNGP_REF_NAME_INVALID,
+                format!("ref name '{}' is not an NCName\n", text(&name)),
+            ));
+        }
+        if !empty {
+            return Err(invalid(
+                xmlParserErrors_XML_RNGP_REF_NOT_EMPTY,
+                "ref is not empty\n".to_string(),
+            ));
+        }
+        let mut def = Define::new(Kind::Ref);
+        def.name = Some(name);
+        let def = self.add(def);
+        self.refs.push(def);
+        Ok(def)
+    }
+
+    /// The name and namespace of an `element` or `attribute`, from its
+    /// `name` attribute or its first child, with the children left for
+    /// the content. Returns `None` when there are no children at all.
+    unsafe fn parse_name_class(
+        &self,
+        node: xmlNodePtr,
+        attribute: bool,
+    ) -> Result<Option<NameClass>, GrammarError> {
+        let mut nodes = children(node);
+        let (value, ns) = if let Some(value) = prop(node, c"name") {
+            let ns = match prop(node, c"ns") {
+                Some(ns) => ns,
+                None if attribute => Vec::new(),
+                None => inherited_ns(node),
+            };
+            (value, ns)
+        } else {
+            let Some(&first) = nodes.first() else {
+                return Ok(None);
+            };
+            if is_rng(first, b"name") {
+                nodes.remove(0);
+                let value = owned(xmlNodeGetContent(first)).unwrap_or_default();
+                (value, inherited_ns(first))
+            } else if is_rng(first, b"anyName") || is_rng(first, b"nsName") {
+                return Err(GrammarError::Unsupported(
+                    text(bytes((*first).name)).into_owned(),
+                ));
+            } else if is_rng(first, b"choice") {
+                return Err(GrammarError::Unsupported("name class choice".to_string()));
+            } else {
+                return Err(invalid(
+                    xmlParserErrors_XML_RNGP_CHOICE_CONTENT,
+                    format!(
+                        "expecting name, anyName, nsName or choice : got {}\n",
+                        text(bytes((*first).name))
+                    ),
+                ));
+            }
+        };
+        if value.contains(&b':') {
+            return Err(GrammarError::Unsupported("QName".to_string()));
+        }
+        let value = norm_ext_space(&value);
+        if !is_ncname(&value) {
+            return Err(invalid(
+                xmlParserErrors_XML_RNGP_ELEMENT_NAME,
+                format!(
+                    "Element {} name '{}' is not an NCName\n",
+                    text(bytes((*node).name)),
+                    text(&value)
+                ),
+            ));
+        }
+        Ok(Some((value, ns, nodes)))
+    }
+
+    unsafe fn parse_element(&mut self, node: xmlNodePtr) -> Result<usize, GrammarError> {
+        let Some((name, ns, nodes)) = self.parse_name_class(node, false)? else {
+            return Err(invalid(
+                xmlParserErrors_XML_RNGP_ELEMENT_EMPTY,
+                "xmlRelaxNGParseElement: element has no children\n".to_string(),
+            ));
+        };
+        if nodes.is_empty() {
+            return Err(invalid(
+                xmlParserErrors_XML_RNGP_ELEMENT_NO_CONTENT,
+                "xmlRelaxNGParseElement: element has no content\n".to_string(),
+            ));
+        }
+        let mut def = Define::new(Kind::Element);
+        def.name = Some(name);
+        def.ns = Some(ns);
+        let mut content = Vec::new();
+        let mut wrap = false;
+        for &child in &nodes {
+            let cur = self.parse_pattern(child)?;
+            if self.def(cur).kind == Kind::Attribute {
+                self.def_mut(cur).next = def.attrs;
+                def.attrs = Some(cur);
+                continue;
+            }
+            if content.len() == 1 && self.def(content[0]).kind == Kind::Element {
+                wrap = true;
+            }
+            content.push(cur);
+        }
+        let head = self.link(&content);
+        def.content = if wrap {
+            let mut group = Define::new(Kind::Group);
+            group.content = head;
+            Some(self.add(group))
+        } else {
+            head
+        };
+        Ok(self.add(def))
+    }
+
+    unsafe fn parse_attribute(&mut self, node: xmlNodePtr) -> Result<usize, GrammarError> {
+        let Some((name, ns, nodes)) = self.parse_name_class(node, true)? else {
+            return Err(invalid(
+                xmlParserErrors_XML_RNGP_ATTRIBUTE_EMPTY,
+                "xmlRelaxNGParseattribute: attribute has no children\n".to_string(),
+            ));
+        };
+        let mut def = Define::new(Kind::Attribute);
+        def.name = Some(name);
+        def.ns = Some(ns);
+        if let Some(&child) = nodes.first() {
+            def.content = Some(self.parse_pattern(child)?);
+        }
+        if nodes.len() > 1 {
+            return Err(invalid(
+                xmlParserErrors_XML_RNGP_ATTRIBUTE_CHILDREN,
+                "attribute has multiple children\n".to_string(),
+            ));
+        }
+        Ok(self.add(def))
+    }
+
+    /// xmlRelaxNGCheckCycles: references must cross an element before
+    /// reaching themselves again.
+    fn check_cycles(&mut self, mut cur: Option<usize>, depth: i32) -> Result<(), GrammarError> {
+        while let Some(idx) = cur {
+            let content = self.def(idx).content;
+            match self.def(idx).kind {
+                Kind::Ref => {
+                    if self.def(idx).depth == -1 {
+                        self.def_mut(idx).depth = depth;
+                        self.check_cycles(content, depth)?;
+                        self.def_mut(idx).depth = -2;
+                    } else if self.def(idx).depth == depth {
+                        let name = self.def(idx).name.clone().unwrap_or_default();
+                        return Err(invalid(
+                            xmlParserErrors_XML_RNGP_REF_CYCLE,
+                            format!("Detected a cycle in {} references\n", text(&name)),
+                        ));
+                    }
+                }
+                Kind::Element => self.check_cycles(content, depth + 1)?,
+                _ => self.check_cycles(content, depth)?,
+            }
+            cur = self.def(idx).next;
+        }
+        Ok(())
+    }
+
+    /// xmlRelaxNGTryUnlink.
+    fn try_unlink(
+        &mut self,
+        cur: usize,
+        parent: Option<usize>,
+        prev: Option<usize>,
+    ) -> Option<usize> {
+        let next = self.def(cur).next;
+        if let Some(prev) = prev {
+            self.def_mut(prev).next = next;
+            return Some(prev);
+        }
+        match parent {
+            Some(parent) => {
+                if self.def(parent).content == Some(cur) {
+                    self.def_mut(parent).content = next;
+                } else if self.def(parent).attrs == Some(cur) {
+                    self.def_mut(parent).attrs = next;
+                }
+                None
+            }
+            None => {
+                self.def_mut(cur).kind = Kind::Noop;
+                Some(cur)
+            }
+        }
+    }
+
+    /// xmlRelaxNGGenerateAttributes: whether `def` can only generate
+    /// attributes.
+    fn generates_attributes_only(&self, def: usize) -> bool {
+        match self.def(def).kind {
+            Kind::Element | Kind::Text | Kind::Empty => false,
+            Kind::Choice
+            | Kind::Group
+            | Kind::OneOrMore
+            | Kind::ZeroOrMore
+            | Kind::Optional
+            | Kind::Ref
+            | Kind::Def
+                if self.def(def).content.is_some() =>
+            {
+                self.chain(self.def(def).content)
+                    .into_iter()
+                    .all(|child| self.generates_attributes_only(child))
+            }
+            _ => true,
+        }
+    }
+
+    /// xmlRelaxNGSimplify: propagate `notAllowed` and `empty`, move
+    /// attribute-only content to the attributes of elements and drop
+    /// groups of a single pattern.
+    fn simplify(&mut self, mut cur: Option<usize>, parent: Option<usize>) {
+        let parent_kind = |p: &Parser| parent.map(|p2| p.def(p2).kind);
+        let mut prev: Option<usize> = None;
+        while let Some(mut idx) = cur {
+            match self.def(idx).kind {
+                Kind::Ref => {
+                    if self.def(idx).depth != -3 {
+                        self.def_mut(idx).depth = -3;
+                        self.simplify(self.def(idx).content, Some(idx));
+                    }
+                }
+                Kind::NotAllowed => match parent_kind(self) {
+                    Some(Kind::Attribute | Kind::Group | Kind::OneOrMore | Kind::ZeroOrMore) => {
+                        self.def_mut(parent.unwrap()).kind = Kind::NotAllowed;
+                        break;
+                    }
+                    Some(Kind::Choice) => prev = self.try_unlink(idx, parent, prev),
+                    _ => prev = Some(idx),
+                },
+                Kind::Empty => match parent_kind(self) {
+                    Some(Kind::OneOrMore | Kind::ZeroOrMore) => {
+                        self.def_mut(parent.unwrap()).kind = Kind::Empty;
+                        break;
+                    }
+                    Some(Kind::Group) => prev = self.try_unlink(idx, parent, prev),
+                    _ => prev = Some(idx),
+                },
+                _ => {
+                    if self.def(idx).content.is_some() {
+                        self.simplify(self.def(idx).content, Some(idx));
+                    }
+                    if self.def(idx).attrs.is_some() {
+                        self.simplify(self.def(idx).attrs, Some(idx));
+                    }
+                    if self.def(idx).kind == Kind::Element {
+                        self.migrate_attributes(idx);
+                    }
+                    if self.def(idx).kind == Kind::Group {
+                        let content = self.def(idx).content;
+                        match content {
+                            None => self.def_mut(idx).kind = Kind::Empty,
+                            Some(child) if self.def(child).next.is_none() => match (parent, prev) {
+                                (None, None) => self.def_mut(idx).kind = Kind::Noop,
+                                (Some(parent), None) => {
+                                    if self.def(parent).content != content {
+                                        self.def_mut(parent).content = content;
+                                        self.def_mut(child).next = self.def(idx).next;
+                                        idx = child;
+                                    }
+                                }
+                                (_, Some(prev)) => {
+                                    self.def_mut(child).next = self.def(idx).next;
+                                    self.def_mut(prev).next = content;
+                                    idx = child;
+                                }
+                            },
+                            Some(_) => {}
+                        }
+                    }
+                    match self.def(idx).kind {
+                        Kind::NotAllowed => match parent_kind(self) {
+                            Some(
+                                Kind::Attribute | Kind::Group | Kind::OneOrMore | Kind::ZeroOrMore,
+                            ) => {
+                                self.def_mut(parent.unwrap()).kind = Kind::NotAllowed;
+                                break;
+                            }
+                            Some(Kind::Choice) => prev = self.try_unlink(idx, parent, prev),
+                            _ => prev = Some(idx),
+                        },
+                        Kind::Empty => match parent_kind(self) {
+                            Some(Kind::OneOrMore | Kind::ZeroOrMore) => {
+                                self.def_mut(parent.unwrap()).kind = Kind::Empty;
+                                break;
+                            }
+                            Some(Kind::Group | Kind::Choice) => {
+                                prev = self.try_unlink(idx, parent, prev)
+                            }
+                            _ => prev = Some(idx),
+                        },
+                        _ => prev = Some(idx),
+                    }
+                }
+            }
+            cur = self.def(idx).next;
+        }
+    }
+
+    /// Move the content patterns of an element that only generate
+    /// attributes to its attribute list.
+    fn migrate_attributes(&mut self, elem: usize) {
+        while let Some(first) = self.def(elem).content {
+            if !self.generates_attributes_only(first) {
+                break;
+            }
+            self.def_mut(elem).content = self.def(first).next;
+            self.def_mut(first).next = self.def(elem).attrs;
+            self.def_mut(elem).attrs = Some(first);
+        }
+        let mut pre = self.def(elem).content;
+        while let Some(p) = pre {
+            let Some(tmp) = self.def(p).next else {
+                break;
+            };
+            if self.generates_attributes_only(tmp) {
+                self.def_mut(p).next = self.def(tmp).next;
+                self.def_mut(tmp).next = self.def(elem).attrs;
+                self.def_mut(elem).attrs = Some(tmp);
+            } else {
+                pre = Some(tmp);
+            }
+        }
+    }
+
+    /// xmlRelaxNGGetElements: the elements and text patterns `def` can
+    /// start with, or with `attributes` the attributes it can generate.
+    fn get_elements(&self, def: usize, attributes: bool, ret: &mut Vec<usize>) {
+        let d = self.def(def);
+        let collect = if attributes {
+            d.kind == Kind::Attribute
+        } else {
+            matches!(d.kind, Kind::Element | Kind::Text)
+        };
+        if collect {
+            ret.push(def);
+        } else if matches!(
+            d.kind,
+            Kind::Choice
+                | Kind::Group
+                | Kind::OneOrMore
+                | Kind::ZeroOrMore
+                | Kind::Optional
+                | Kind::Ref
+                | Kind::Def
+        ) {
+            for child in self.chain(d.content) {
+                self.get_elements(child, attributes, ret);
+            }
+        }
+    }
+
+    /// xmlRelaxNGIsNullable.
+    fn is_nullable(&mut self, def: Option<usize>) -> i32 {
+        let Some(idx) = def else {
+            return -1;
+        };
+        if self.def(idx).dflags & IS_NULLABLE != 0 {
+            return 1;
+        }
+        if self.def(idx).dflags & IS_NOT_NULLABLE != 0 {
+            return 0;
+        }
+        let content = self.def(idx).content;
+        let ret = match self.def(idx).kind {
+            Kind::Empty | Kind::Text => 1,
+            Kind::Noop | Kind::Def | Kind::Ref | Kind::OneOrMore => self.is_nullable(content),
+            Kind::NotAllowed | Kind::Element | Kind::Attribute => 0,
+            Kind::Choice => {
+                let mut ret = 0;
+                for child in self.chain(content) {
+                    ret = self.is_nullable(Some(child));
+                    if ret != 0 {
+                        break;
+                    }
+                }
+                ret
+            }
+            Kind::Start | Kind::Group => {
+                for child in self.chain(content) {
+                    let ret = self.is_nullable(Some(child));
+                    if ret != 1 {
+                        self.cache_nullable(idx, ret);
+                        return ret;
+                    }
+                }
+                return 1;
+            }
+            Kind::Optional | Kind::ZeroOrMore => return -1,
+        };
+        self.cache_nullable(idx, ret);
+        ret
+    }
+
+    fn cache_nullable(&mut self, idx: usize, ret: i32) {
+        if ret == 0 {
+            self.def_mut(idx).dflags |= IS_NOT_NULLABLE;
+        }
+        if ret == 1 {
+            self.def_mut(idx).dflags |= IS_NULLABLE;
+        }
+    }
+
+    /// xmlRelaxNGCheckGroupAttrs: the patterns of a group or element
+    /// must not generate the same attribute.
+    fn check_group_attrs(&mut self, def: usize) -> Result<(), GrammarError> {
+        if self.def(def).dflags & IS_PROCESSED != 0 {
+            return Ok(());
+        }
+        let mut lists = Vec::new();
+        for child in self
+            .chain(self.def(def).attrs)
+            .into_iter()
+            .chain(self.chain(self.def(def).content))
+        {
+            let mut list = Vec::new();
+            self.get_elements(child, true, &mut list);
+            lists.push(list);
+        }
+        for i in 0..lists.len() {
+            for j in 0..i {
+                let conflict = lists[i].iter().any(|&a| {
+                    lists[j].iter().any(|&b| {
+                        self.def(a).name == self.def(b).name && self.def(a).ns == self.def(b).ns
+                    })
+                });
+                if conflict {
+                    return Err(invalid(
+                        xmlParserErrors_XML_RNGP_GROUP_ATTR_CONFLICT,
+                        "Attributes conflicts in group\n".to_string(),
+                    ));
+                }
+            }
+        }
+        self.def_mut(def).dflags |= IS_PROCESSED;
+        Ok(())
+    }
+
+    /// xmlRelaxNGCheckChoiceDeterminism: a choice whose branches start
+    /// with distinct elements gets a triage table picking the branch.
+    fn check_choice_determinism(&mut self, def: usize) {
+        if self.def(def).dflags & IS_PROCESSED != 0 {
+            return;
+        }
+        let is_nullable = self.is_nullable(Some(def));
+        let mut triage = HashMap::new();
+        let mut is_triable = is_nullable == 0;
+        for cur in self.chain(self.def(def).content) {
+            let mut list = Vec::new();
+            self.get_elements(cur, false, &mut list);
+            if list.is_empty() {
+                is_triable = false;
+                continue;
+            }
+            for tmp in list {
+                if !is_triable {
+                    break;
+                }
+                let d = self.def(tmp);
+                let key = if d.kind == Kind::Text {
+                    (b"#text".to_vec(), None)
+                } else {
+                    let ns = d.ns.clone().filter(|ns| !ns.is_empty());
+                    (d.name.clone().unwrap_or_default(), ns)
+                };
+                if triage.insert(key, cur).is_some() {
+                    is_triable = false;
+                }
+            }
+        }
+        let d = self.def_mut(def);
+        if is_triable {
+            d.dflags |= IS_TRIABLE;
+            d.triage = Some(triage);
+        }
+        d.dflags |= IS_PROCESSED;
+    }
+
+    fn name_text(&self, idx: usize) -> String {
+        text(self.def(idx).name.as_deref().unwrap_or(b"")).into_owned()
+    }
+
+    /// xmlRelaxNGCheckRules: the restrictions of section 7 of the
+    /// specification on the simplified grammar, returning the content
+    /// type of the patterns.
+    fn check_rules(
+        &mut self,
+        mut cur: Option<usize>,
+        flags: u32,
+        ptype: Kind,
+    ) -> Result<ContentType, GrammarError> {
+        let mut val = ContentType::Empty;
+        while let Some(idx) = cur {
+            let content = self.def(idx).content;
+            let ret = match self.def(idx).kind {
+                Kind::Ref => {
+                    if content.is_none() {
+                        return Err(invalid(
+                            xmlParserErrors_XML_RNGP_REF_NO_DEF,
+                            format!("Internal found no define for ref {}\n", self.name_text(idx)),
+                        ));
+                    }
+                    let depth = self.def(idx).depth;
+                    if depth > -4 {
+                        self.def_mut(idx).depth = -4;
+                        let ret = self.check_rules(content, flags, Kind::Ref)?;
+                        self.def_mut(idx).depth = ret as i32 - 15;
+                        ret
+                    } else if depth == -4 {
+                        ContentType::Complex
+                    } else {
+                        ContentType::from_i32(depth + 15)
+                    }
+                }
+                Kind::Element => {
+                    self.check_group_attrs(idx)?;
+                    if flags & IN_ATTRIBUTE != 0 {
+                        return Err(invalid(
+                            xmlParserErrors_XML_RNGP_PAT_ATTR_ELEM,
+                            "Found forbidden pattern attribute//element(ref)\n".to_string(),
+                        ));
+                    }
+                    let attrs = self.def(idx).attrs;
+                    if self.check_rules(attrs, 0, Kind::Element)? != ContentType::Empty {
+                        return Err(invalid(
+                            xmlParserErrors_XML_RNGP_ELEM_CONTENT_EMPTY,
+                            format!(
+                                "Element {} attributes have a content type error\n",
+                                self.name_text(idx)
+                            ),
+                        ));
+                    }
+                    if self.check_rules(content, 0, Kind::Element)? == ContentType::Error {
+                        return Err(invalid(
+                            xmlParserErrors_XML_RNGP_ELEM_CONTENT_ERROR,
+                            format!("Element {} has a content type error\n", self.name_text(idx)),
+                        ));
+                    }
+                    ContentType::Complex
+                }
+                Kind::Attribute => {
+                    if flags & IN_ATTRIBUTE != 0 {
+                        return Err(invalid(
+                            xmlParserErrors_XML_RNGP_PAT_ATTR_ATTR,
+                            "Found forbidden pattern attribute//attribute\n".to_string(),
+                        ));
+                    }
+                    if flags & IN_OOMGROUP != 0 {
+                        return Err(invalid(
+                            xmlParserErrors_XML_RNGP_PAT_ONEMORE_GROUP_ATTR,
+                            "Found forbidden pattern oneOrMore//group//attribute\n".to_string(),
+                        ));
+                    }
+                    if flags & IN_START != 0 {
+                        return Err(invalid(
+                            xmlParserErrors_XML_RNGP_PAT_START_ATTR,
+                            "Found forbidden pattern start//attribute\n".to_string(),
+                        ));
+                    }
+                    self.check_rules(content, flags | IN_ATTRIBUTE, Kind::Attribute)?;
+                    ContentType::Empty
+                }
+                Kind::OneOrMore | Kind::ZeroOrMore => {
+                    if flags & IN_START != 0 {
+                        return Err(invalid(
+                            xmlParserErrors_XML_RNGP_PAT_START_ONEMORE,
+                            "Found forbidden pattern start//oneOrMore\n".to_string(),
+                        ));
+                    }
+                    let kind = self.def(idx).kind;
+                    let ret = self.check_rules(content, flags | IN_ONEORMORE, kind)?;
+                    ret.group(ret)
+                }
+                Kind::Group => {
+                    if flags & IN_START != 0 {
+                        return Err(invalid(
+                            xmlParserErrors_XML_RNGP_PAT_START_GROUP,
+                            "Found forbidden pattern start//group\n".to_string(),
+                        ));
+                    }
+                    let nflags = if flags & IN_ONEORMORE != 0 {
+                        flags | IN_OOMGROUP
+                    } else {
+                        flags
+                    };
+                    let ret = self.check_rules(content, nflags, Kind::Group)?;
+                    self.check_group_attrs(idx)?;
+                    ret
+                }
+                Kind::Text => {
+                    if flags & IN_START != 0 {
+                        return Err(invalid(
+                            xmlParserErrors_XML_RNGP_PAT_START_TEXT,
+                            "Found forbidden pattern start//text\n".to_string(),
+                        ));
+                    }
+                    ContentType::Complex
+                }
+                Kind::Empty => {
+                    if flags & IN_START != 0 {
+                        return Err(invalid(
+                            xmlParserErrors_XML_RNGP_PAT_START_EMPTY,
+                            "Found forbidden pattern start//empty\n".to_string(),
+                        ));
+                    }
+                    ContentType::Empty
+                }
+                Kind::Choice => {
+                    self.check_choice_determinism(idx);
+                    self.check_rules(content, flags, Kind::Choice)?
+                }
+                kind => self.check_rules(content, flags, kind)?,
+            };
+            cur = self.def(idx).next;
+            val = if ptype == Kind::Choice {
+                val.max(ret)
+            } else {
+                val.group(ret)
+            };
+        }
+        Ok(val)
+    }
+
+    /// xmlRelaxNGIsCompilable.
+    fn is_compilable(&mut self, def: Option<usize>) -> i32 {
+        let Some(idx) = def else {
+            return -1;
+        };
+        let kind = self.def(idx).kind;
+        if kind != Kind::Element && self.def(idx).dflags & IS_COMPILABLE != 0 {
+            return 1;
+        }
+        if kind != Kind::Element && self.def(idx).dflags & IS_NOT_COMPILABLE != 0 {
+            return 0;
+        }
+        let content = self.def(idx).content;
+        let ret = match kind {
+            Kind::Noop => self.is_compilable(content),
+            Kind::Text | Kind::Empty => 1,
+            Kind::Element => {
+                if self.def(idx).dflags & (IS_COMPILABLE | IS_NOT_COMPILABLE) == 0 {
+                    let ret = self.is_compilable_list(content);
+                    let d = self.def_mut(idx);
+                    if ret == 0 {
+                        d.dflags &= !IS_COMPILABLE;
+                        d.dflags |= IS_NOT_COMPILABLE;
+                    }
+                    if ret == 1 {
+                        // As in libxml2, the test clears every other flag.
+                        d.dflags &= IS_NOT_COMPILABLE;
+                        if d.dflags == 0 {
+                            d.dflags |= IS_COMPILABLE;
+                        }
+                    }
+                }
+                return 1;
+            }
+            Kind::Ref => {
+                if self.def(idx).depth == -20 {
+                    return 1;
+                }
+                self.def_mut(idx).depth = -20;
+                self.is_compilable_list(content)
+            }
+            Kind::Start
+            | Kind::Optional
+            | Kind::ZeroOrMore
+            | Kind::OneOrMore
+            | Kind::Choice
+            | Kind::Group
+            | Kind::Def => self.is_compilable_list(content),
+            Kind::Attribute | Kind::NotAllowed => 0,
+        };
+        if ret == 0 {
+            self.def_mut(idx).dflags |= IS_NOT_COMPILABLE;
+        }
+        if ret == 1 {
+            self.def_mut(idx).dflags |= IS_COMPILABLE;
+        }
+        ret
+    }
+
+    fn is_compilable_list(&mut self, first: Option<usize>) -> i32 {
+        let mut ret = -1;
+        for child in self.chain(first) {
+            ret = self.is_compilable(Some(child));
+            if ret != 1 {
+                break;
+            }
+        }
+        ret
+    }
+
+    /// Build the automaton of `first` and its siblings, returning the
+    /// compiled regexp.
+    unsafe fn compile_model(&mut self, first: Option<usize>) -> xmlRegexpPtr {
+        let (oldam, oldstate) = (self.am, self.state);
+        self.am = xmlNewAutomata();
+        if self.am.is_null() {
+            self.am = oldam;
+            return ptr::null_mut();
+        }
+        xmlAutomataSetFlags(self.am, 1);
+        self.state = xmlAutomataGetInitState(self.am);
+        for child in self.chain(first) {
+            self.compile(Some(child));
+        }
+        xmlAutomataSetFinalState(self.am, self.state);
+        let model = xmlAutomataCompile(self.am);
+        xmlFreeAutomata(self.am);
+        self.am = oldam;
+        self.state = oldstate;
+        model
+    }
+
+    /// xmlRelaxNGCompile. The content model of the start pattern is not
+    /// kept since validation never uses it.
+    unsafe fn compile(&mut self, def: Option<usize>) -> i32 {
+        let Some(idx) = def else {
+            return -1;
+        };
+        let content = self.def(idx).content;
+        let mut ret = 0;
+        match self.def(idx).kind {
+            Kind::Start => {
+                if self.is_compilable(Some(idx)) == 1 && self.def(idx).depth != -25 {
+                    self.def_mut(idx).depth = -25;
+                    let model = self.compile_model(content);
+                    if !model.is_null() {
+                        xmlRegFreeRegexp(model);
+                    }
+                }
+            }
+            Kind::Element => {
+                if !self.am.is_null() {
+                    let name = CString::new(self.def(idx).name.clone().unwrap_or_default())
+                        .unwrap_or_default();
+                    let ns = CString::new(self.def(idx).ns.clone().unwrap_or_default())
+                        .unwrap_or_default();
+                    self.state = xmlAutomataNewTransition2(
+                        self.am,
+                        self.state,
+                        ptr::null_mut(),
+                        name.as_ptr() as *const xmlChar,
+                        ns.as_ptr() as *const xmlChar,
+                        (idx + 1) as *mut c_void,
+                    );
+                }
+                if self.def(idx).dflags & IS_COMPILABLE != 0 && self.def(idx).depth != -25 {
+                    self.def_mut(idx).depth = -25;
+                    let mut model = self.compile_model(content);
+                    if xmlRegexpIsDeterminist(model) == 0 {
+                        xmlRegFreeRegexp(model);
+                        model = ptr::null_mut();
+                    }
+                    self.def_mut(idx).cont_model = model;
+                } else {
+                    let oldam = self.am;
+                    ret = self.try_compile(Some(idx));
+                    self.am = oldam;
+                }
+            }
+            Kind::Noop => ret = self.compile(content),
+            Kind::Optional => {
+                let oldstate = self.state;
+                for child in self.chain(content) {
+                    self.compile(Some(child));
+                }
+                xmlAutomataNewEpsilon(self.am, oldstate, self.state);
+            }
+            Kind::ZeroOrMore => {
+                self.state = xmlAutomataNewEpsilon(self.am, self.state, ptr::null_mut());
+                let oldstate = self.state;
+                for child in self.chain(content) {
+                    self.compile(Some(child));
+                }
+                xmlAutomataNewEpsilon(self.am, self.state, oldstate);
+                self.state = xmlAutomataNewEpsilon(self.am, oldstate, ptr::null_mut());
+            }
+            Kind::OneOrMore => {
+                for child in self.chain(content) {
+                    self.compile(Some(child));
+                }
+                let oldstate = self.state;
+                for child in self.chain(content) {
+                    self.compile(Some(child));
+                }
+                xmlAutomataNewEpsilon(self.am, self.state, oldstate);
+                self.state = xmlAutomataNewEpsilon(self.am, oldstate, ptr::null_mut());
+            }
+            Kind::Choice => {
+                let mut target: xmlAutomataStatePtr = ptr::null_mut();
+                let oldstate = self.state;
+                for child in self.chain(content) {
+                    self.state = oldstate;
+                    ret = self.compile(Some(child));
+                    if ret != 0 {
+                        break;
+                    }
+                    if target.is_null() {
+                        target = self.state;
+                    } else {
+                        xmlAutomataNewEpsilon(self.am, self.state, target);
+                    }
+                }
+                self.state = target;
+            }
+            Kind::Ref | Kind::Group | Kind::Def => {
+                for child in self.chain(content) {
+                    ret = self.compile(Some(child));
+                    if ret != 0 {
+                        break;
+                    }
+                }
+            }
+            Kind::Text => {
+                self.state = xmlAutomataNewEpsilon(self.am, self.state, ptr::null_mut());
+                let oldstate = self.state;
+                self.compile(content);
+                xmlAutomataNewTransition2(
+                    self.am,
+                    self.state,
+                    self.state,
+                    c"#text".as_ptr() as *const xmlChar,
+                    ptr::null(),
+                    ptr::null_mut(),
+                );
+                self.state = xmlAutomataNewEpsilon(self.am, oldstate, ptr::null_mut());
+            }
+            Kind::Empty => {
+                self.state = xmlAutomataNewEpsilon(self.am, self.state, ptr::null_mut());
+            }
+            // Never compilable, so never reached.
+            Kind::Attribute | Kind::NotAllowed => {}
+        }
+        ret
+    }
+
+    /// xmlRelaxNGTryCompile: compile the content models of the elements
+    /// whose content allows it.
+    unsafe fn try_compile(&mut self, def: Option<usize>) -> i32 {
+        let Some(idx) = def else {
+            return -1;
+        };
+        let kind = self.def(idx).kind;
+        if matches!(kind, Kind::Start | Kind::Element) {
+            self.is_compilable(Some(idx));
+            if self.def(idx).dflags & IS_COMPILABLE != 0 && self.def(idx).depth != -25 {
+                self.am = ptr::null_mut();
+                return self.compile(Some(idx));
+            }
+        }
+        let content = self.def(idx).content;
+        match kind {
+            Kind::Noop => self.try_compile(content),
+            Kind::Text | Kind::Empty | Kind::Element | Kind::Attribute | Kind::NotAllowed => 0,
+            Kind::Optional
+            | Kind::ZeroOrMore
+            | Kind::OneOrMore
+            | Kind::Choice
+            | Kind::Group
+            | Kind::Def
+            | Kind::Start
+            | Kind::Ref => {
+                let mut ret = 0;
+                for child in self.chain(content) {
+                    ret = self.try_compile(Some(child));
+                    if ret != 0 {
+                        break;
+                    }
+                }
+                ret
+            }
+        }
+    }
+}
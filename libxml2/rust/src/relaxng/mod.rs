@@ -1 +1,10 @@
-// Empty stub module for relaxng
+//! Rust implementation of relaxng module
+//!
+//! Parsing of RELAX NG grammars into simplified defines and validation
+//! of documents against them
+
+mod grammar;
+mod validate;
+
+pub use grammar::*;
+pub use validate::*;
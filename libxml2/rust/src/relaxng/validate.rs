@@ -0,0 +1,1184 @@
+#![allow(clippy::missing_safety_doc)]
+
+use std::collections::HashMap;
+use std::os::raw::{c_int, c_void};
+use std::ptr;
+
+use crate::static_bindings::{
+    xmlAttrPtr, xmlChar, xmlDocGetRootElement, xmlDocPtr, xmlElementType_XML_CDATA_SECTION_NODE,
+    xmlElementType_XML_COMMENT_NODE, xmlElementType_XML_ELEMENT_NODE, xmlElementType_XML_PI_NODE,
+    xmlElementType_XML_TEXT_NODE, xmlElementType_XML_XINCLUDE_END,
+    xmlElementType_XML_XINCLUDE_START, xmlFree, xmlIsBlankNode, xmlNodeListGetString, xmlNodePtr,
+    xmlRegExecCtxtPtr, xmlRegExecPushString2, xmlRegFreeExecCtxt, xmlRegNewExecCtxt, xmlRegexpPtr,
+    xmlRelaxNGValidErr_XML_RELAXNG_ERR_ATTRVALID, xmlRelaxNGValidErr_XML_RELAXNG_ERR_CONTENTVALID,
+    xmlRelaxNGValidErr_XML_RELAXNG_ERR_ELEMEXTRANS, xmlRelaxNGValidErr_XML_RELAXNG_ERR_ELEMNAME,
+    xmlRelaxNGValidErr_XML_RELAXNG_ERR_ELEMNONS, xmlRelaxNGValidErr_XML_RELAXNG_ERR_ELEMWRONG,
+    xmlRelaxNGValidErr_XML_RELAXNG_ERR_ELEMWRONGNS,
+    xmlRelaxNGValidErr_XML_RELAXNG_ERR_EXTRACONTENT, xmlRelaxNGValidErr_XML_RELAXNG_ERR_EXTRADATA,
+    xmlRelaxNGValidErr_XML_RELAXNG_ERR_INTERNAL, xmlRelaxNGValidErr_XML_RELAXNG_ERR_INVALIDATTR,
+    xmlRelaxNGValidErr_XML_RELAXNG_ERR_NODEFINE, xmlRelaxNGValidErr_XML_RELAXNG_ERR_NOELEM,
+    xmlRelaxNGValidErr_XML_RELAXNG_ERR_NOSTATE, xmlRelaxNGValidErr_XML_RELAXNG_ERR_NOTELEM,
+    xmlRelaxNGValidErr_XML_RELAXNG_ERR_TEXTWRONG, xmlRelaxNGValidErr_XML_RELAXNG_OK,
+};
+
+use super::grammar::{bytes, text, Grammar, Kind, IS_TRIABLE};
+
+/// Errors found while trying alternatives are stacked instead of
+/// reported, `FLAGS_IGNORABLE` in relaxng.c.
+const FLAGS_IGNORABLE: u32 = 1;
+/// At most this many stacked errors are reported at once.
+const MAX_ERROR: usize = 5;
+
+/// A RELAX NG validity error, as xmlRelaxNGValidateDoc reports it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidError {
+    /// The `xmlRelaxNGValidErr` code.
+    pub code: c_int,
+    /// The node the error is reported on: the first element up from
+    /// the node being checked, as xmlRaiseError picks it.
+    pub node: xmlNodePtr,
+    pub message: String,
+}
+
+/// `xmlRelaxNGValidState`: the element being validated, the next child
+/// to match and the attributes not consumed yet.
+#[derive(Clone, PartialEq)]
+struct State {
+    node: xmlNodePtr,
+    seq: xmlNodePtr,
+    attrs: Vec<xmlAttrPtr>,
+    nb_attr_left: usize,
+}
+
+/// `xmlRelaxNGValidError`, an entry of the error stack.
+#[derive(Clone)]
+struct Entry {
+    err: u32,
+    arg1: Option<Vec<u8>>,
+    arg2: Option<Vec<u8>>,
+    node: xmlNodePtr,
+    seq: xmlNodePtr,
+}
+
+/// Add `state` to `states` unless an equal state is already there,
+/// like xmlRelaxNGAddStates.
+fn add_state(states: &mut Vec<State>, state: State) -> bool {
+    if states.contains(&state) {
+        return false;
+    }
+    states.push(state);
+    true
+}
+
+unsafe fn new_state(doc: xmlDocPtr, node: xmlNodePtr) -> State {
+    if node.is_null() {
+        return State {
+            node: doc as xmlNodePtr,
+            seq: xmlDocGetRootElement(doc),
+            attrs: Vec::new(),
+            nb_attr_left: 0,
+        };
+    }
+    let mut attrs = Vec::new();
+    let mut attr = (*node).properties;
+    while !attr.is_null() {
+        attrs.push(attr);
+        attr = (*attr).next;
+    }
+    State {
+        node,
+        seq: (*node).children,
+        nb_attr_left: attrs.len(),
+        attrs,
+    }
+}
+
+/// Skip the nodes validation ignores: comments, processing
+/// instructions, XInclude markers and blank text.
+unsafe fn skip_ignored(mut node: xmlNodePtr) -> xmlNodePtr {
+    while !node.is_null()
+        && matches!(
+            (*node).type_,
+            xmlElementType_XML_COMMENT_NODE
+                | xmlElementType_XML_PI_NODE
+                | xmlElementType_XML_XINCLUDE_START
+                | xmlElementType_XML_XINCLUDE_END
+        )
+        || (!node.is_null()
+            && matches!(
+                (*node).type_,
+                xmlElementType_XML_TEXT_NODE | xmlElementType_XML_CDATA_SECTION_NODE
+            )
+            && xmlIsBlankNode(node) != 0)
+    {
+        node = (*node).next;
+    }
+    node
+}
+
+/// The element an error on `node` is reported on, at most ten levels
+/// up, like xmlVUpdateError.
+unsafe fn error_node(mut node: xmlNodePtr) -> xmlNodePtr {
+    if node.is_null() {
+        return node;
+    }
+    for _ in 0..10 {
+        if (*node).type_ == xmlElementType_XML_ELEMENT_NODE || (*node).parent.is_null() {
+            break;
+        }
+        node = (*node).parent;
+    }
+    node
+}
+
+unsafe fn ns_href(node: xmlNodePtr) -> *const xmlChar {
+    if (*node).ns.is_null() {
+        ptr::null()
+    } else {
+        (*(*node).ns).href
+    }
+}
+
+/// The message xmlRelaxNGGetErrorString builds for `err`.
+fn error_string(err: u32, arg1: &[u8], arg2: &[u8]) -> String {
+    let (arg1, arg2) = (text(arg1), text(arg2));
+    match err {
+        xmlRelaxNGValidErr_XML_RELAXNG_ERR_NOSTATE => "Internal error: no state\n".into(),
+        xmlRelaxNGValidErr_XML_RELAXNG_ERR_NODEFINE => "Internal error: no define\n".into(),
+        xmlRelaxNGValidErr_XML_RELAXNG_ERR_INTERNAL => format!("Internal error: {arg1}\n"),
+        xmlRelaxNGValidErr_XML_RELAXNG_ERR_ELEMNAME => {
+            format!("Expecting element {arg1}, got {arg2}\n")
+        }
+        xmlRelaxNGValidErr_XML_RELAXNG_ERR_ELEMNONS => {
+            format!("Expecting a namespace for element {arg1}\n")
+        }
+        xmlRelaxNGValidErr_XML_RELAXNG_ERR_ELEMWRONGNS => {
+            format!("Element {arg1} has wrong namespace: expecting {arg2}\n")
+        }
+        xmlRelaxNGValidErr_XML_RELAXNG_ERR_ELEMWRONG => {
+            format!("Did not expect element {arg1} there\n")
+        }
+        xmlRelaxNGValidErr_XML_RELAXNG_ERR_TEXTWRONG => {
+            format!("Did not expect text in element {arg1} content\n")
+        }
+        xmlRelaxNGValidErr_XML_RELAXNG_ERR_ELEMEXTRANS => {
+            format!("Expecting no namespace for element {arg1}\n")
+        }
+        xmlRelaxNGValidErr_XML_RELAXNG_ERR_NOELEM => {
+            format!("Expecting an element {arg1}, got nothing\n")
+        }
+        xmlRelaxNGValidErr_XML_RELAXNG_ERR_NOTELEM => "Expecting an element got text\n".into(),
+        xmlRelaxNGValidErr_XML_RELAXNG_ERR_ATTRVALID => {
+            format!("Element {arg1} failed to validate attributes\n")
+        }
+        xmlRelaxNGValidErr_XML_RELAXNG_ERR_CONTENTVALID => {
+            format!("Element {arg1} failed to validate content\n")
+        }
+        xmlRelaxNGValidErr_XML_RELAXNG_ERR_EXTRACONTENT => {
+            format!("Element {arg1} has extra content: {arg2}\n")
+        }
+        xmlRelaxNGValidErr_XML_RELAXNG_ERR_INVALIDATTR => {
+            format!("Invalid attribute {arg1} for element {arg2}\n")
+        }
+        xmlRelaxNGValidErr_XML_RELAXNG_ERR_EXTRADATA => "Extra data in the document\n".into(),
+        _ => "Unknown error !\n".into(),
+    }
+}
+
+/// Record the define of each element transition the automaton takes,
+/// like xmlRelaxNGValidateCompiledCallback. `#text` transitions carry
+/// no define.
+unsafe extern "C" fn record_define(
+    _exec: xmlRegExecCtxtPtr,
+    _token: *const xmlChar,
+    transdata: *mut c_void,
+    inputdata: *mut c_void,
+) {
+    if !transdata.is_null() && !inputdata.is_null() {
+        (*(inputdata as *mut Vec<usize>)).push(transdata as usize - 1);
+    }
+}
+
+/// `xmlRelaxNGValidCtxt`, reduced to what the supported patterns use.
+struct Validator<'a> {
+    grammar: &'a Grammar,
+    doc: xmlDocPtr,
+    state: Option<State>,
+    states: Option<Vec<State>>,
+    flags: u32,
+    /// The error stack. Slots past `nr` keep their old entries, and
+    /// `top` may point at one of them, as `ctxt->err` does.
+    tab: Vec<Entry>,
+    nr: usize,
+    max: usize,
+    top: Option<usize>,
+    err_no: u32,
+    perr: c_int,
+    /// The define each element was last validated against, the `psvi`
+    /// relaxng.c stores on the node.
+    validated: HashMap<xmlNodePtr, usize>,
+    errors: Vec<ValidError>,
+}
+
+impl Grammar {
+    /// Validate `doc` against the grammar, like xmlRelaxNGValidateDoc.
+    /// Returns the errors reported if the document is not valid.
+    pub unsafe fn validate(&self, doc: xmlDocPtr) -> Result<(), Vec<ValidError>> {
+        let mut ctxt = Validator {
+            grammar: self,
+            doc,
+            state: None,
+            states: None,
+            flags: 0,
+            tab: Vec::new(),
+            nr: 0,
+            max: 0,
+            top: None,
+            err_no: xmlRelaxNGValidErr_XML_RELAXNG_OK,
+            perr: 0,
+            validated: HashMap::new(),
+            errors: Vec::new(),
+        };
+        if ctxt.validate_document() == 0 && ctxt.errors.is_empty() {
+            Ok(())
+        } else {
+            Err(ctxt.errors)
+        }
+    }
+}
+
+impl Validator<'_> {
+    fn ignorable(&self) -> bool {
+        self.flags & FLAGS_IGNORABLE != 0
+    }
+
+    /// The defines of the list starting at `first`.
+    fn chain(&self, first: Option<usize>) -> Vec<usize> {
+        let mut ret = Vec::new();
+        let mut cur = first;
+        while let Some(idx) = cur {
+            ret.push(idx);
+            cur = self.grammar.defines[idx].next;
+        }
+        ret
+    }
+
+    fn push_error(&mut self, err: u32, arg1: Option<&[u8]>, arg2: Option<&[u8]>) {
+        if self.max == 0 {
+            self.max = 8;
+            self.nr = 0;
+            self.top = None;
+        }
+        if self.nr >= self.max {
+            self.max *= 2;
+            self.top = Some(self.nr - 1);
+        }
+        if let (Some(top), Some(state)) = (self.top, &self.state) {
+            if self.tab[top].node == state.node && self.tab[top].err == err {
+                return;
+            }
+        }
+        let (node, seq) = match &self.state {
+            Some(state) => (state.node, state.seq),
+            None => (ptr::null_mut(), ptr::null_mut()),
+        };
+        let entry = Entry {
+            err,
+            arg1: arg1.map(<[u8]>::to_vec),
+            arg2: arg2.map(<[u8]>::to_vec),
+            node,
+            seq,
+        };
+        if self.nr < self.tab.len() {
+            self.tab[self.nr] = entry;
+        } else {
+            self.tab.push(entry);
+        }
+        self.top = Some(self.nr);
+        self.nr += 1;
+    }
+
+    fn pop_error(&mut self) {
+        if self.nr == 0 {
+            self.top = None;
+            return;
+        }
+        self.nr -= 1;
+        self.top = self.nr.checked_sub(1);
+    }
+
+    fn pop_errors(&mut self, level: usize) {
+        self.nr = level;
+        if self.nr == 0 {
+            self.top = None;
+        }
+    }
+
+    /// Whether the top of the stack is an element mismatch for `node`
+    /// that a later successful match makes irrelevant.
+    unsafe fn top_is_mismatch(&self, node: xmlNodePtr) -> bool {
+        let Some(top) = self.top else {
+            return false;
+        };
+        let entry = &self.tab[top];
+        let name = bytes((*node).name);
+        match entry.err {
+            xmlRelaxNGValidErr_XML_RELAXNG_ERR_ELEMNAME => entry.arg2.as_deref() == Some(name),
+            xmlRelaxNGValidErr_XML_RELAXNG_ERR_ELEMEXTRANS => entry.arg1.as_deref() == Some(name),
+            xmlRelaxNGValidErr_XML_RELAXNG_ERR_NOELEM
+            | xmlRelaxNGValidErr_XML_RELAXNG_ERR_NOTELEM => true,
+            _ => false,
+        }
+    }
+
+    /// Report the stacked errors, skipping duplicates, then clear the
+    /// stack.
+    fn dump_errors(&mut self) {
+        let mut shown = 0;
+        for i in 0..self.nr {
+            if shown >= MAX_ERROR {
+                break;
+            }
+            let entry = &self.tab[i];
+            let dup = self.tab[..i].iter().any(|prev| {
+                prev.err == entry.err
+                    && prev.node == entry.node
+                    && prev.arg1 == entry.arg1
+                    && prev.arg2 == entry.arg2
+            });
+            if dup {
+                continue;
+            }
+            let entry = entry.clone();
+            self.show_error(
+                entry.err,
+                entry.node,
+                entry.seq,
+                entry.arg1.as_deref(),
+                entry.arg2.as_deref(),
+            );
+            shown += 1;
+        }
+        self.nr = 0;
+    }
+
+    fn show_error(
+        &mut self,
+        err: u32,
+        node: xmlNodePtr,
+        seq: xmlNodePtr,
+        arg1: Option<&[u8]>,
+        arg2: Option<&[u8]>,
+    ) {
+        let message = error_string(err, arg1.unwrap_or(b""), arg2.unwrap_or(b""));
+        if self.err_no == xmlRelaxNGValidErr_XML_RELAXNG_OK {
+            self.err_no = err;
+        }
+        self.errors.push(ValidError {
+            code: err as c_int,
+            node: unsafe { error_node(if seq.is_null() { node } else { seq }) },
+            message,
+        });
+    }
+
+    /// Report an error now, or stack it while alternatives are tried.
+    fn add_error(&mut self, err: u32, arg1: Option<&[u8]>, arg2: Option<&[u8]>) {
+        if self.ignorable() {
+            self.push_error(err, arg1, arg2);
+            return;
+        }
+        if self.nr != 0 {
+            self.dump_errors();
+        }
+        let (node, seq) = match &self.state {
+            Some(state) => (state.node, state.seq),
+            None => (ptr::null_mut(), ptr::null_mut()),
+        };
+        self.show_error(err, node, seq, arg1, arg2);
+    }
+
+    unsafe fn validate_document(&mut self) -> c_int {
+        self.err_no = xmlRelaxNGValidErr_XML_RELAXNG_OK;
+        self.state = Some(new_state(self.doc, ptr::null_mut()));
+        let mut ret = self.validate_definition(Some(self.grammar.start));
+        let seq = self.state.as_ref().map_or(ptr::null_mut(), |s| s.seq);
+        if !seq.is_null() {
+            if !skip_ignored(seq).is_null() && ret != -1 {
+                self.add_error(xmlRelaxNGValidErr_XML_RELAXNG_ERR_EXTRADATA, None, None);
+                ret = -1;
+            }
+        } else if let Some(states) = self.states.take() {
+            let done = states.iter().any(|s| skip_ignored(s.seq).is_null());
+            if !done && ret != -1 {
+                self.add_error(xmlRelaxNGValidErr_XML_RELAXNG_ERR_EXTRADATA, None, None);
+                ret = -1;
+            }
+        }
+        self.state = None;
+        if ret != 0 {
+            self.dump_errors();
+        }
+        if ret == 0 && self.err_no != xmlRelaxNGValidErr_XML_RELAXNG_OK {
+            ret = -1;
+        }
+        ret
+    }
+
+    /// Validate `define` against the current state or each of the
+    /// current states, keeping the states it succeeds from.
+    unsafe fn validate_definition(&mut self, define: Option<usize>) -> c_int {
+        if self.state.is_some() && self.states.is_some() {
+            self.state = None;
+        }
+        if self.states.as_ref().is_none_or(|s| s.len() == 1) {
+            if let Some(mut states) = self.states.take() {
+                self.state = states.pop();
+            }
+            let ret = self.validate_state(define);
+            if self.state.is_some() && self.states.is_some() {
+                self.state = None;
+            }
+            if self.states.as_ref().is_some_and(|s| s.len() == 1) {
+                self.state = self.states.take().and_then(|mut s| s.pop());
+            }
+            return ret;
+        }
+
+        let mut states = self.states.take().unwrap();
+        let mut res: Option<Vec<State>> = None;
+        let mut kept = 0;
+        let oldflags = self.flags;
+        self.flags |= FLAGS_IGNORABLE;
+        for i in 0..states.len() {
+            self.state = Some(states[i].clone());
+            self.states = None;
+            let ret = self.validate_state(define);
+            if self.state.is_some() && self.states.is_some() {
+                self.state = None;
+            }
+            if ret != 0 {
+                self.state = None;
+                self.states = None;
+                continue;
+            }
+            match (self.states.take(), &mut res) {
+                (None, Some(res)) => {
+                    if let Some(state) = self.state.take() {
+                        add_state(res, state);
+                    }
+                }
+                (None, None) => {
+                    if let Some(state) = self.state.take() {
+                        states[kept] = state;
+                        kept += 1;
+                    }
+                }
+                (Some(new), Some(res)) => {
+                    for state in new {
+                        add_state(res, state);
+                    }
+                }
+                (Some(mut new), None) => {
+                    for state in &states[..kept] {
+                        add_state(&mut new, state.clone());
+                    }
+                    res = Some(new);
+                }
+            }
+        }
+        self.flags = oldflags;
+        let ret = if let Some(res) = res {
+            self.states = Some(res);
+            0
+        } else if kept > 1 {
+            states.truncate(kept);
+            self.states = Some(states);
+            0
+        } else if kept == 1 {
+            self.state = Some(states.swap_remove(0));
+            0
+        } else {
+            self.states = None;
+            -1
+        };
+        if self.state.is_some() && self.states.is_some() {
+            self.state = None;
+        }
+        ret
+    }
+
+    unsafe fn validate_definition_list(&mut self, defines: Option<usize>) -> c_int {
+        if defines.is_none() {
+            self.add_error(
+                xmlRelaxNGValidErr_XML_RELAXNG_ERR_INTERNAL,
+                Some(b"NULL definition list"),
+                None,
+            );
+            return -1;
+        }
+        let mut ret = 0;
+        for define in self.chain(defines) {
+            if self.state.is_none() && self.states.is_none() {
+                self.add_error(xmlRelaxNGValidErr_XML_RELAXNG_ERR_NOSTATE, None, None);
+                return -1;
+            }
+            let res = self.validate_definition(Some(define));
+            if res < 0 {
+                ret = -1;
+            }
+            if res == -1 {
+                break;
+            }
+        }
+        ret
+    }
+
+    unsafe fn validate_state(&mut self, define: Option<usize>) -> c_int {
+        let Some(idx) = define else {
+            self.add_error(xmlRelaxNGValidErr_XML_RELAXNG_ERR_NODEFINE, None, None);
+            return -1;
+        };
+        let grammar = self.grammar;
+        let def = &grammar.defines[idx];
+        let node = self.state.as_ref().map_or(ptr::null_mut(), |s| s.seq);
+        match def.kind {
+            Kind::Empty => 0,
+            Kind::NotAllowed => -1,
+            Kind::Text => {
+                let mut node = node;
+                while !node.is_null()
+                    && matches!(
+                        (*node).type_,
+                        xmlElementType_XML_TEXT_NODE
+                            | xmlElementType_XML_COMMENT_NODE
+                            | xmlElementType_XML_PI_NODE
+                            | xmlElementType_XML_CDATA_SECTION_NODE
+                    )
+                {
+                    node = (*node).next;
+                }
+                if let Some(state) = &mut self.state {
+                    state.seq = node;
+                }
+                0
+            }
+            Kind::Element => self.validate_element(idx, node),
+            Kind::Optional => {
+                let err_nr = self.nr;
+                let oldflags = self.flags;
+                self.flags |= FLAGS_IGNORABLE;
+                let oldstate = self.state.clone();
+                let ret = self.validate_definition_list(def.content);
+                if ret != 0 {
+                    self.state = oldstate;
+                } else if let Some(states) = &mut self.states {
+                    if let Some(oldstate) = oldstate {
+                        add_state(states, oldstate);
+                    }
+                } else {
+                    let mut states = Vec::new();
+                    if let Some(oldstate) = oldstate {
+                        add_state(&mut states, oldstate);
+                    }
+                    if let Some(state) = self.state.take() {
+                        add_state(&mut states, state);
+                    }
+                    self.states = Some(states);
+                }
+                self.flags = oldflags;
+                if self.nr > err_nr {
+                    self.pop_errors(err_nr);
+                }
+                0
+            }
+            Kind::OneOrMore => {
+                let err_nr = self.nr;
+                let ret = self.validate_definition_list(def.content);
+                if ret != 0 {
+                    return ret;
+                }
+                if self.nr > err_nr {
+                    self.pop_errors(err_nr);
+                }
+                self.validate_zero_or_more(def.content)
+            }
+            Kind::ZeroOrMore => self.validate_zero_or_more(def.content),
+            Kind::Choice => self.validate_choice(idx, node),
+            Kind::Def | Kind::Group => self.validate_definition_list(def.content),
+            Kind::Attribute => self.validate_attribute(idx),
+            Kind::Start | Kind::Noop | Kind::Ref => self.validate_definition(def.content),
+        }
+    }
+
+    unsafe fn validate_element(&mut self, idx: usize, node: xmlNodePtr) -> c_int {
+        let grammar = self.grammar;
+        let def = &grammar.defines[idx];
+        let err_nr = self.nr;
+        let node = skip_ignored(node);
+        if node.is_null() {
+            self.add_error(
+                xmlRelaxNGValidErr_XML_RELAXNG_ERR_NOELEM,
+                def.name.as_deref(),
+                None,
+            );
+            if !self.ignorable() {
+                self.dump_errors();
+            }
+            return -1;
+        }
+        if (*node).type_ != xmlElementType_XML_ELEMENT_NODE {
+            self.add_error(xmlRelaxNGValidErr_XML_RELAXNG_ERR_NOTELEM, None, None);
+            if !self.ignorable() {
+                self.dump_errors();
+            }
+            return -1;
+        }
+        if self.validated.get(&node) == Some(&idx) {
+            if let Some(state) = &mut self.state {
+                state.seq = skip_ignored((*node).next);
+            }
+            if self.nr > err_nr {
+                self.pop_errors(err_nr);
+            }
+            if self.nr != 0 {
+                while self.top_is_mismatch(node) {
+                    self.pop_error();
+                }
+            }
+            return 0;
+        }
+        if self.element_match(idx, node) <= 0 {
+            if !self.ignorable() {
+                self.dump_errors();
+            }
+            return -1;
+        }
+        let mut ret = 0;
+        if self.nr != 0 {
+            if self.nr > err_nr {
+                self.pop_errors(err_nr);
+            }
+            while self.top_is_mismatch(node) {
+                self.pop_error();
+            }
+        }
+        let err_nr = self.nr;
+        let oldflags = self.flags;
+        let state = new_state(self.doc, node);
+        let mut oldstate = self.state.replace(state);
+
+        if def.attrs.is_some() && self.validate_attribute_list(def.attrs) != 0 {
+            ret = -1;
+            self.add_error(
+                xmlRelaxNGValidErr_XML_RELAXNG_ERR_ATTRVALID,
+                Some(bytes((*node).name)),
+                None,
+            );
+        }
+        if !def.cont_model.is_null() {
+            let tmpstate = self.state.take();
+            let tmpstates = self.states.take();
+            let nstate = new_state(self.doc, node);
+            let seq = nstate.seq;
+            self.state = Some(nstate);
+            let tmp = self.validate_compiled_content(def.cont_model, seq);
+            let nseq = self.state.as_ref().map_or(ptr::null_mut(), |s| s.seq);
+            self.state = tmpstate;
+            self.states = tmpstates;
+            if tmp != 0 {
+                ret = -1;
+            }
+            if self.states.is_some() {
+                let found = self.end_any_state(Some(nseq));
+                self.flags = oldflags;
+                if ret == 0 && !found {
+                    ret = -1;
+                }
+            } else {
+                if let Some(state) = &mut self.state {
+                    state.seq = nseq;
+                }
+                if ret == 0 {
+                    ret = self.element_end(true);
+                }
+            }
+        } else {
+            if def.content.is_some() && self.validate_definition_list(def.content) != 0 {
+                ret = -1;
+                let name = Some(bytes((*node).name));
+                if self.state.is_none() {
+                    self.state = oldstate.take();
+                    self.add_error(xmlRelaxNGValidErr_XML_RELAXNG_ERR_CONTENTVALID, name, None);
+                    oldstate = self.state.take();
+                } else {
+                    self.add_error(xmlRelaxNGValidErr_XML_RELAXNG_ERR_CONTENTVALID, name, None);
+                }
+            }
+            if self.states.is_some() {
+                let found = self.end_any_state(None);
+                self.flags = oldflags;
+                if ret == 0 && !found {
+                    ret = -1;
+                }
+            } else if ret == 0 {
+                ret = self.element_end(true);
+            }
+        }
+        if ret == 0 {
+            self.validated.insert(node, idx);
+        }
+        self.flags = oldflags;
+        self.state = oldstate;
+        if let Some(state) = &mut self.state {
+            state.seq = skip_ignored((*node).next);
+        }
+        if ret != 0 {
+            if !self.ignorable() {
+                self.dump_errors();
+                ret = 0;
+            } else {
+                ret = -2;
+            }
+        } else if self.nr > err_nr {
+            self.pop_errors(err_nr);
+        }
+        ret
+    }
+
+    /// Check whether one of the current states ends the element, after
+    /// moving each to `seq` if given. If none does, the errors of the
+    /// state closest to the end are logged. The states are dropped.
+    unsafe fn end_any_state(&mut self, seq: Option<xmlNodePtr>) -> bool {
+        let mut states = self.states.take().unwrap_or_default();
+        let mut found = false;
+        for state in states.iter_mut() {
+            if let Some(seq) = seq {
+                state.seq = seq;
+            }
+            self.state = Some(state.clone());
+            let ret = self.element_end(false);
+            *state = self.state.take().unwrap();
+            if ret == 0 {
+                found = true;
+                break;
+            }
+        }
+        if !found {
+            self.flags |= FLAGS_IGNORABLE;
+            if let Some(best) = best_state(&states) {
+                self.state = Some(states[best].clone());
+                self.element_end(true);
+            }
+        }
+        found
+    }
+
+    unsafe fn validate_zero_or_more(&mut self, content: Option<usize>) -> c_int {
+        let mut res = Vec::new();
+        if let Some(state) = &self.state {
+            add_state(&mut res, state.clone());
+        } else if let Some(states) = &self.states {
+            for state in states {
+                add_state(&mut res, state.clone());
+            }
+        }
+        let oldflags = self.flags;
+        self.flags |= FLAGS_IGNORABLE;
+        loop {
+            let mut progress = false;
+            let mut base = res.len();
+            if let Some(states) = self.states.take() {
+                for state in states {
+                    self.state = Some(state);
+                    self.states = None;
+                    if self.validate_definition_list(content) == 0 {
+                        if let Some(state) = self.state.take() {
+                            progress |= add_state(&mut res, state);
+                        } else if let Some(states) = self.states.take() {
+                            for state in states {
+                                progress |= add_state(&mut res, state);
+                            }
+                        }
+                    } else {
+                        self.state = None;
+                    }
+                }
+                self.states = None;
+            } else if self.validate_definition_list(content) != 0 {
+                self.state = None;
+            } else {
+                base = res.len();
+                if let Some(state) = self.state.take() {
+                    progress |= add_state(&mut res, state);
+                } else if let Some(states) = self.states.take() {
+                    for state in states {
+                        progress |= add_state(&mut res, state);
+                    }
+                }
+            }
+            if !progress {
+                break;
+            }
+            if res.len() - base == 1 {
+                self.state = Some(res[base].clone());
+            } else {
+                let mut states = Vec::new();
+                for state in &res[base..] {
+                    add_state(&mut states, state.clone());
+                }
+                self.states = Some(states);
+            }
+        }
+        // Errors stay stacked: relaxng.c leaves the pop disabled so that
+        // they can surface if the content after the repetition fails.
+        self.states = Some(res);
+        self.state = None;
+        self.flags = oldflags;
+        0
+    }
+
+    unsafe fn validate_choice(&mut self, idx: usize, node: xmlNodePtr) -> c_int {
+        let grammar = self.grammar;
+        let def = &grammar.defines[idx];
+        let node = skip_ignored(node);
+        let err_nr = self.nr;
+        if def.dflags & IS_TRIABLE != 0 && !node.is_null() {
+            if let Some(triage) = &def.triage {
+                let key = match (*node).type_ {
+                    xmlElementType_XML_TEXT_NODE | xmlElementType_XML_CDATA_SECTION_NODE => {
+                        Some((b"#text".to_vec(), None))
+                    }
+                    xmlElementType_XML_ELEMENT_NODE => {
+                        let ns = ns_href(node);
+                        let ns = (!ns.is_null()).then(|| bytes(ns).to_vec());
+                        Some((bytes((*node).name).to_vec(), ns))
+                    }
+                    _ => None,
+                };
+                let Some(&branch) = key.as_ref().and_then(|key| triage.get(key)) else {
+                    self.add_error(
+                        xmlRelaxNGValidErr_XML_RELAXNG_ERR_ELEMWRONG,
+                        Some(bytes((*node).name)),
+                        None,
+                    );
+                    return -1;
+                };
+                return self.validate_definition(Some(branch));
+            }
+        }
+
+        let oldflags = self.flags;
+        self.flags |= FLAGS_IGNORABLE;
+        let mut ret = 0;
+        let mut states: Option<Vec<State>> = None;
+        for branch in self.chain(def.content) {
+            let oldstate = self.state.clone();
+            ret = self.validate_definition(Some(branch));
+            if ret == 0 {
+                let states = states.get_or_insert_with(Vec::new);
+                if let Some(state) = self.state.take() {
+                    add_state(states, state);
+                } else if let Some(new) = self.states.take() {
+                    for state in new {
+                        add_state(states, state);
+                    }
+                }
+            }
+            self.state = oldstate;
+        }
+        if let Some(states) = states {
+            self.states = Some(states);
+            self.state = None;
+            ret = 0;
+        } else {
+            self.states = None;
+        }
+        self.flags = oldflags;
+        if ret != 0 {
+            if !self.ignorable() {
+                self.dump_errors();
+            }
+        } else if self.nr > err_nr {
+            self.pop_errors(err_nr);
+        }
+        ret
+    }
+
+    /// Check the name and namespace of `elem` against the element
+    /// define, 1 on a match.
+    unsafe fn element_match(&mut self, idx: usize, elem: xmlNodePtr) -> c_int {
+        let grammar = self.grammar;
+        let def = &grammar.defines[idx];
+        let name = bytes((*elem).name);
+        if let Some(expected) = &def.name {
+            if expected.as_slice() != name {
+                self.add_error(
+                    xmlRelaxNGValidErr_XML_RELAXNG_ERR_ELEMNAME,
+                    Some(expected),
+                    Some(name),
+                );
+                return 0;
+            }
+        }
+        let ns = def.ns.as_deref().unwrap_or_default();
+        let href = ns_href(elem);
+        if !ns.is_empty() {
+            if href.is_null() {
+                self.add_error(
+                    xmlRelaxNGValidErr_XML_RELAXNG_ERR_ELEMNONS,
+                    Some(name),
+                    None,
+                );
+                return 0;
+            }
+            if bytes(href) != ns {
+                self.add_error(
+                    xmlRelaxNGValidErr_XML_RELAXNG_ERR_ELEMWRONGNS,
+                    Some(name),
+                    Some(ns),
+                );
+                return 0;
+            }
+        } else if !href.is_null() {
+            self.add_error(
+                xmlRelaxNGValidErr_XML_RELAXNG_ERR_ELEMEXTRANS,
+                def.name.as_deref(),
+                None,
+            );
+            return 0;
+        }
+        1
+    }
+
+    /// Check that the current state consumed all the content and
+    /// attributes of its element.
+    unsafe fn element_end(&mut self, dolog: bool) -> c_int {
+        let Some(state) = &mut self.state else {
+            return 0;
+        };
+        let node = state.node;
+        if !state.seq.is_null() {
+            state.seq = skip_ignored(state.seq);
+            let seq = state.seq;
+            if !seq.is_null() {
+                if dolog {
+                    self.add_error(
+                        xmlRelaxNGValidErr_XML_RELAXNG_ERR_EXTRACONTENT,
+                        Some(bytes((*node).name)),
+                        Some(bytes((*seq).name)),
+                    );
+                }
+                return -1;
+            }
+        }
+        let left = state.attrs.iter().position(|attr| !attr.is_null());
+        if let Some(i) = left {
+            let attr = state.attrs[i];
+            if dolog {
+                self.add_error(
+                    xmlRelaxNGValidErr_XML_RELAXNG_ERR_INVALIDATTR,
+                    Some(bytes((*attr).name)),
+                    Some(bytes((*node).name)),
+                );
+            }
+            return -1 - i as c_int;
+        }
+        0
+    }
+
+    unsafe fn validate_attribute_list(&mut self, defines: Option<usize>) -> c_int {
+        let grammar = self.grammar;
+        let mut ret = 0;
+        let mut needmore = false;
+        let list = self.chain(defines);
+        for &define in &list {
+            if grammar.defines[define].kind == Kind::Attribute {
+                if self.validate_attribute(define) != 0 {
+                    ret = -1;
+                }
+            } else {
+                needmore = true;
+            }
+        }
+        if !needmore {
+            return ret;
+        }
+        for &define in &list {
+            if grammar.defines[define].kind == Kind::Attribute {
+                continue;
+            }
+            if self.state.is_none() && self.states.is_none() {
+                self.add_error(xmlRelaxNGValidErr_XML_RELAXNG_ERR_NOSTATE, None, None);
+                return -1;
+            }
+            let res = self.validate_definition(Some(define));
+            if res < 0 {
+                ret = -1;
+            }
+            if res == -1 {
+                break;
+            }
+        }
+        ret
+    }
+
+    /// Consume the attribute of the current element matching the
+    /// attribute define, checking its value.
+    unsafe fn validate_attribute(&mut self, idx: usize) -> c_int {
+        let grammar = self.grammar;
+        let def = &grammar.defines[idx];
+        let Some(state) = &mut self.state else {
+            return -1;
+        };
+        if state.nb_attr_left == 0 {
+            return -1;
+        }
+        let name = def.name.as_deref().unwrap_or_default();
+        let ns = def.ns.as_deref().unwrap_or_default();
+        let found = state.attrs.iter().position(|&attr| {
+            if attr.is_null() || bytes((*attr).name) != name {
+                return false;
+            }
+            if (*attr).ns.is_null() {
+                ns.is_empty()
+            } else {
+                bytes((*(*attr).ns).href) == ns
+            }
+        });
+        let Some(i) = found else {
+            return -1;
+        };
+        let attr = state.attrs[i];
+        let value = xmlNodeListGetString((*attr).doc, (*attr).children, 1);
+        let ok = self.validate_value(def.content, bytes(value));
+        if !value.is_null() {
+            xmlFree.unwrap()(value as *mut c_void);
+        }
+        if !ok {
+            return -1;
+        }
+        let state = self.state.as_mut().unwrap();
+        state.attrs[i] = ptr::null_mut();
+        state.nb_attr_left -= 1;
+        0
+    }
+
+    /// Check an attribute value against the value patterns the port
+    /// supports: `text` accepts anything and `empty` only blanks.
+    fn validate_value(&self, content: Option<usize>, value: &[u8]) -> bool {
+        let Some(idx) = content else {
+            return true;
+        };
+        match self.grammar.defines[idx].kind {
+            Kind::Empty => value
+                .iter()
+                .all(|&c| matches!(c, 0x20 | 0x09 | 0x0A | 0x0D)),
+            _ => true,
+        }
+    }
+
+    /// Run the children from `content` through the compiled model of
+    /// their element, validating each matched child against its define.
+    unsafe fn validate_compiled_content(
+        &mut self,
+        regexp: xmlRegexpPtr,
+        content: xmlNodePtr,
+    ) -> c_int {
+        let oldperr = self.perr;
+        let mut matched: Vec<usize> = Vec::new();
+        let exec = xmlRegNewExecCtxt(regexp, Some(record_define), ptr::null_mut());
+        self.perr = 0;
+        let mut ret = 0;
+        let mut cur = content;
+        while !cur.is_null() {
+            if let Some(state) = &mut self.state {
+                state.seq = cur;
+            }
+            match (*cur).type_ {
+                xmlElementType_XML_TEXT_NODE | xmlElementType_XML_CDATA_SECTION_NODE
+                    if xmlIsBlankNode(cur) == 0 =>
+                {
+                    ret = xmlRegExecPushString2(
+                        exec,
+                        c"#text".as_ptr() as *const xmlChar,
+                        ptr::null(),
+                        &mut matched as *mut Vec<usize> as *mut c_void,
+                    );
+                    if ret < 0 {
+                        self.add_error(
+                            xmlRelaxNGValidErr_XML_RELAXNG_ERR_TEXTWRONG,
+                            Some(bytes((*(*cur).parent).name)),
+                            None,
+                        );
+                    }
+                }
+                xmlElementType_XML_ELEMENT_NODE => {
+                    ret = xmlRegExecPushString2(
+                        exec,
+                        (*cur).name,
+                        ns_href(cur),
+                        &mut matched as *mut Vec<usize> as *mut c_void,
+                    );
+                    if ret < 0 {
+                        self.add_error(
+                            xmlRelaxNGValidErr_XML_RELAXNG_ERR_ELEMWRONG,
+                            Some(bytes((*cur).name)),
+                            None,
+                        );
+                    }
+                }
+                _ => {}
+            }
+            for define in std::mem::take(&mut matched) {
+                let res = self.validate_definition(Some(define));
+                if res != 0 {
+                    self.perr = res;
+                }
+            }
+            if ret < 0 {
+                break;
+            }
+            cur = (*cur).next;
+        }
+        ret = xmlRegExecPushString2(exec, ptr::null(), ptr::null(), ptr::null_mut());
+        if ret == 1 {
+            ret = 0;
+            if let Some(state) = &mut self.state {
+                state.seq = ptr::null_mut();
+            }
+        } else if ret == 0 {
+            self.add_error(xmlRelaxNGValidErr_XML_RELAXNG_ERR_NOELEM, Some(b""), None);
+            ret = -1;
+            if !self.ignorable() {
+                self.dump_errors();
+            }
+        } else {
+            ret = -1;
+        }
+        xmlRegFreeExecCtxt(exec);
+        if ret == 0 && self.perr != 0 {
+            ret = self.perr;
+        }
+        self.perr = oldperr;
+        ret
+    }
+}
+
+/// The state that got closest to ending its element: no content left
+/// over, then the fewest attributes left.
+fn best_state(states: &[State]) -> Option<usize> {
+    let mut best = None;
+    let mut value = usize::MAX;
+    for (i, state) in states.iter().enumerate() {
+        let score = if state.seq.is_null() {
+            state.nb_attr_left
+        } else {
+            100000
+        };
+        if best.is_none() || value > score {
+            value = score;
+            best = Some(i);
+        }
+    }
+    best
+}
@@ -0,0 +1,773 @@
+//! A debugging dump of a document tree, in the format of
+//! `xmlDebugDumpDocument`.
+
+use std::ffi::CStr;
+use std::io::{self, Write};
+use std::os::raw::{c_char, c_int};
+use std::ptr;
+
+use super::XmlDoc;
+use crate::static_bindings::{
+    xmlAttrPtr, xmlAttributeDefault_XML_ATTRIBUTE_FIXED, xmlAttributeDefault_XML_ATTRIBUTE_IMPLIED,
+    xmlAttributeDefault_XML_ATTRIBUTE_REQUIRED, xmlAttributePtr,
+    xmlAttributeType_XML_ATTRIBUTE_CDATA, xmlAttributeType_XML_ATTRIBUTE_ENTITIES,
+    xmlAttributeType_XML_ATTRIBUTE_ENTITY, xmlAttributeType_XML_ATTRIBUTE_ENUMERATION,
+    xmlAttributeType_XML_ATTRIBUTE_ID, xmlAttributeType_XML_ATTRIBUTE_IDREF,
+    xmlAttributeType_XML_ATTRIBUTE_IDREFS, xmlAttributeType_XML_ATTRIBUTE_NMTOKEN,
+    xmlAttributeType_XML_ATTRIBUTE_NMTOKENS, xmlAttributeType_XML_ATTRIBUTE_NOTATION, xmlChar,
+    xmlDictOwns, xmlDictPtr, xmlDocPtr, xmlDtdPtr, xmlElementPtr, xmlElementType,
+    xmlElementTypeVal_XML_ELEMENT_TYPE_ANY, xmlElementTypeVal_XML_ELEMENT_TYPE_ELEMENT,
+    xmlElementTypeVal_XML_ELEMENT_TYPE_EMPTY, xmlElementTypeVal_XML_ELEMENT_TYPE_MIXED,
+    xmlElementTypeVal_XML_ELEMENT_TYPE_UNDEFINED, xmlElementType_XML_ATTRIBUTE_DECL,
+    xmlElementType_XML_ATTRIBUTE_NODE, xmlElementType_XML_CDATA_SECTION_NODE,
+    xmlElementType_XML_COMMENT_NODE, xmlElementType_XML_DOCUMENT_FRAG_NODE,
+    xmlElementType_XML_DOCUMENT_NODE, xmlElementType_XML_DOCUMENT_TYPE_NODE,
+    xmlElementType_XML_DTD_NODE, xmlElementType_XML_ELEMENT_DECL, xmlElementType_XML_ELEMENT_NODE,
+    xmlElementType_XML_ENTITY_DECL, xmlElementType_XML_ENTITY_NODE,
+    xmlElementType_XML_ENTITY_REF_NODE, xmlElementType_XML_HTML_DOCUMENT_NODE,
+    xmlElementType_XML_NAMESPACE_DECL, xmlElementType_XML_NOTATION_NODE,
+    xmlElementType_XML_PI_NODE, xmlElementType_XML_TEXT_NODE, xmlElementType_XML_XINCLUDE_END,
+    xmlElementType_XML_XINCLUDE_START, xmlEntityPtr,
+    xmlEntityType_XML_EXTERNAL_GENERAL_PARSED_ENTITY,
+    xmlEntityType_XML_EXTERNAL_GENERAL_UNPARSED_ENTITY,
+    xmlEntityType_XML_EXTERNAL_PARAMETER_ENTITY, xmlEntityType_XML_INTERNAL_GENERAL_ENTITY,
+    xmlEntityType_XML_INTERNAL_PARAMETER_ENTITY, xmlEntityType_XML_INTERNAL_PREDEFINED_ENTITY,
+    xmlGetDocEntity, xmlNodePtr, xmlNsPtr, xmlParserErrors_XML_CHECK_FOUND_ATTRIBUTE,
+    xmlParserErrors_XML_CHECK_FOUND_CDATA, xmlParserErrors_XML_CHECK_FOUND_COMMENT,
+    xmlParserErrors_XML_CHECK_FOUND_DOCTYPE, xmlParserErrors_XML_CHECK_FOUND_ELEMENT,
+    xmlParserErrors_XML_CHECK_FOUND_ENTITY, xmlParserErrors_XML_CHECK_FOUND_ENTITYREF,
+    xmlParserErrors_XML_CHECK_FOUND_FRAGMENT, xmlParserErrors_XML_CHECK_FOUND_NOTATION,
+    xmlParserErrors_XML_CHECK_FOUND_PI, xmlParserErrors_XML_CHECK_FOUND_TEXT,
+    xmlParserErrors_XML_CHECK_NAME_NOT_NULL, xmlParserErrors_XML_CHECK_NO_DOC,
+    xmlParserErrors_XML_CHECK_NO_ELEM, xmlParserErrors_XML_CHECK_NO_HREF,
+    xmlParserErrors_XML_CHECK_NO_NAME, xmlParserErrors_XML_CHECK_NO_NEXT,
+    xmlParserErrors_XML_CHECK_NO_PARENT, xmlParserErrors_XML_CHECK_NO_PREV,
+    xmlParserErrors_XML_CHECK_NS_ANCESTOR, xmlParserErrors_XML_CHECK_NS_SCOPE,
+    xmlParserErrors_XML_CHECK_UNKNOWN_NODE, xmlParserErrors_XML_CHECK_WRONG_DOC,
+    xmlParserErrors_XML_CHECK_WRONG_NAME, xmlParserErrors_XML_CHECK_WRONG_NEXT,
+    xmlParserErrors_XML_CHECK_WRONG_PARENT, xmlParserErrors_XML_CHECK_WRONG_PREV,
+    xmlSnprintfElementContent,
+};
+
+/// Write the tree of `doc` to `out` the way `xmlDebugDumpDocument` prints
+/// it: a line per node with its type and name, namespace declarations
+/// and attributes indented below their element, the first 40 bytes of
+/// text content and the declarations of the DTD. Broken links between
+/// nodes and references to namespaces out of scope are reported inline
+/// as `ERROR <code>: ...` with an `XML_CHECK_*` code.
+pub fn debug_dump(doc: &XmlDoc, out: &mut impl Write) -> io::Result<()> {
+    let mut dumper = Dumper {
+        out: Vec::new(),
+        depth: 0,
+        dict: ptr::null_mut(),
+    };
+    unsafe { dumper.document(doc.as_ptr()) };
+    out.write_all(&dumper.out)
+}
+
+/// The bytes of a C string, `(null)` like glibc's `%s` if there is none.
+unsafe fn text<'a>(p: *const xmlChar) -> &'a [u8] {
+    if p.is_null() {
+        b"(null)"
+    } else {
+        CStr::from_ptr(p as *const c_char).to_bytes()
+    }
+}
+
+/// `xmlDebugCtxt` without the options `xmlDebugDumpDocument` leaves off.
+struct Dumper {
+    out: Vec<u8>,
+    depth: usize,
+    /// The dictionary of the first node checked, which tells interned
+    /// text from the rest.
+    dict: xmlDictPtr,
+}
+
+impl Dumper {
+    fn write(&mut self, s: &[u8]) {
+        self.out.extend_from_slice(s);
+    }
+
+    fn spaces(&mut self) {
+        let n = if self.depth < 50 { 2 * self.depth } else { 100 };
+        self.out.resize(self.out.len() + n, b' ');
+    }
+
+    fn error(&mut self, code: u32, message: &[u8]) {
+        self.write(format!("ERROR {code}: ").as_bytes());
+        self.write(message);
+    }
+
+    /// xmlCtxtDumpString: at most 40 bytes, blanks as spaces and
+    /// non-ASCII bytes in hexadecimal, with `...` if there is more.
+    unsafe fn string(&mut self, s: *const xmlChar) {
+        if s.is_null() {
+            self.write(b"(NULL)");
+            return;
+        }
+        let s = text(s);
+        for &c in s.iter().take(40) {
+            match c {
+                b' ' | b'\t' | b'\n' | b'\r' => self.out.push(b' '),
+                0x80.. => self.write(format!("#{c:X}").as_bytes()),
+                _ => self.out.push(c),
+            }
+        }
+        if s.len() >= 40 {
+            self.write(b"...");
+        }
+    }
+
+    /// xmlNsCheckScope: 1 if `ns` is declared on `node` or an ancestor,
+    /// -2 if a declaration of the same prefix hides it, -3 otherwise.
+    unsafe fn ns_scope(mut node: xmlNodePtr, ns: xmlNsPtr) -> c_int {
+        let in_tree = |t: xmlElementType| {
+            matches!(
+                t,
+                xmlElementType_XML_ELEMENT_NODE
+                    | xmlElementType_XML_ATTRIBUTE_NODE
+                    | xmlElementType_XML_TEXT_NODE
+                    | xmlElementType_XML_XINCLUDE_START
+            )
+        };
+        if !in_tree((*node).type_)
+            && (*node).type_ != xmlElementType_XML_DOCUMENT_NODE
+            && (*node).type_ != xmlElementType_XML_HTML_DOCUMENT_NODE
+        {
+            return -2;
+        }
+        while !node.is_null() && in_tree((*node).type_) {
+            if matches!(
+                (*node).type_,
+                xmlElementType_XML_ELEMENT_NODE | xmlElementType_XML_XINCLUDE_START
+            ) {
+                let mut cur = (*node).nsDef;
+                while !cur.is_null() {
+                    if cur == ns {
+                        return 1;
+                    }
+                    if (*cur).prefix.is_null() == (*ns).prefix.is_null()
+                        && ((*ns).prefix.is_null() || text((*cur).prefix) == text((*ns).prefix))
+                    {
+                        return -2;
+                    }
+                    cur = (*cur).next;
+                }
+            }
+            node = (*node).parent;
+        }
+        // The xml namespace may be declared on the document.
+        if !node.is_null()
+            && matches!(
+                (*node).type_,
+                xmlElementType_XML_DOCUMENT_NODE | xmlElementType_XML_HTML_DOCUMENT_NODE
+            )
+            && (*(node as xmlDocPtr)).oldNs == ns
+        {
+            return 1;
+        }
+        -3
+    }
+
+    unsafe fn check_ns_scope(&mut self, node: xmlNodePtr, ns: xmlNsPtr) {
+        let (code, what) = match Self::ns_scope(node, ns) {
+            -2 => (xmlParserErrors_XML_CHECK_NS_SCOPE, "in scope"),
+            -3 => (xmlParserErrors_XML_CHECK_NS_ANCESTOR, "on ancestor"),
+            _ => return,
+        };
+        let message = if (*ns).prefix.is_null() {
+            format!("Reference to default namespace not {what}\n").into_bytes()
+        } else {
+            let mut message = b"Reference to namespace '".to_vec();
+            message.extend_from_slice(text((*ns).prefix));
+            message.extend_from_slice(format!("' not {what}\n").as_bytes());
+            message
+        };
+        self.error(code, &message);
+    }
+
+    /// xmlCtxtGenericNodeCheck without the checks of names and strings
+    /// only made in check mode. Text and comment names are compared by
+    /// value, as the tree may come from another copy of the library.
+    unsafe fn check(&mut self, node: xmlNodePtr) {
+        let parent = (*node).parent;
+        if parent.is_null() {
+            self.error(xmlParserErrors_XML_CHECK_NO_PARENT, b"Node has no parent\n");
+        }
+        if (*node).doc.is_null() {
+            self.error(xmlParserErrors_XML_CHECK_NO_DOC, b"Node has no doc\n");
+        } else if self.dict.is_null() {
+            self.dict = (*(*node).doc).dict;
+        }
+        if !parent.is_null() && (*node).doc != (*parent).doc && text((*node).name) != b"pseudoroot"
+        {
+            self.error(
+                xmlParserErrors_XML_CHECK_WRONG_DOC,
+                b"Node doc differs from parent's one\n",
+            );
+        }
+        let is_attr = (*node).type_ == xmlElementType_XML_ATTRIBUTE_NODE;
+        if (*node).prev.is_null() {
+            if is_attr {
+                if !parent.is_null() && node != (*parent).properties as xmlNodePtr {
+                    self.error(
+                        xmlParserErrors_XML_CHECK_NO_PREV,
+                        b"Attr has no prev and not first of attr list\n",
+                    );
+                }
+            } else if !parent.is_null() && (*parent).children != node {
+                self.error(
+                    xmlParserErrors_XML_CHECK_NO_PREV,
+                    b"Node has no prev and not first of parent list\n",
+                );
+            }
+        } else if (*(*node).prev).next != node {
+            self.error(
+                xmlParserErrors_XML_CHECK_WRONG_PREV,
+                b"Node prev->next : back link wrong\n",
+            );
+        }
+        if (*node).next.is_null() {
+            if !parent.is_null()
+                && !is_attr
+                && (*parent).last != node
+                && (*parent).type_ == xmlElementType_XML_ELEMENT_NODE
+            {
+                self.error(
+                    xmlParserErrors_XML_CHECK_NO_NEXT,
+                    b"Node has no next and not last of parent list\n",
+                );
+            }
+        } else {
+            if (*(*node).next).prev != node {
+                self.error(
+                    xmlParserErrors_XML_CHECK_WRONG_NEXT,
+                    b"Node next->prev : forward link wrong\n",
+                );
+            }
+            if (*(*node).next).parent != parent {
+                self.error(
+                    xmlParserErrors_XML_CHECK_WRONG_PARENT,
+                    b"Node next->prev : forward link wrong\n",
+                );
+            }
+        }
+        match (*node).type_ {
+            xmlElementType_XML_ELEMENT_NODE => {
+                let mut ns = (*node).nsDef;
+                while !ns.is_null() {
+                    self.check_ns_scope(node, ns);
+                    ns = (*ns).next;
+                }
+                if !(*node).ns.is_null() {
+                    self.check_ns_scope(node, (*node).ns);
+                }
+            }
+            xmlElementType_XML_ATTRIBUTE_NODE if !(*node).ns.is_null() => {
+                self.check_ns_scope(node, (*node).ns);
+            }
+            _ => {}
+        }
+        let name = text((*node).name);
+        let wrong_name: Option<(u32, &[u8])> = match (*node).type_ {
+            xmlElementType_XML_TEXT_NODE
+                if !matches!(name, b"text" | b"textnoenc" | b"nbktext") =>
+            {
+                Some((
+                    xmlParserErrors_XML_CHECK_WRONG_NAME,
+                    b"Text node has wrong name",
+                ))
+            }
+            xmlElementType_XML_COMMENT_NODE if name != b"comment" => Some((
+                xmlParserErrors_XML_CHECK_WRONG_NAME,
+                b"Comment node has wrong name",
+            )),
+            xmlElementType_XML_CDATA_SECTION_NODE if !(*node).name.is_null() => Some((
+                xmlParserErrors_XML_CHECK_NAME_NOT_NULL,
+                b"CData section has non NULL name",
+            )),
+            _ => None,
+        };
+        if let Some((code, what)) = wrong_name {
+            let mut message = what.to_vec();
+            message.extend_from_slice(b" '");
+            message.extend_from_slice(name);
+            message.push(b'\'');
+            self.error(code, &message);
+        }
+    }
+
+    unsafe fn dtd_node(&mut self, dtd: xmlDtdPtr) {
+        self.spaces();
+        if (*dtd).name.is_null() {
+            self.write(b"DTD");
+        } else {
+            self.write(b"DTD(");
+            self.write(text((*dtd).name));
+            self.write(b")");
+        }
+        if !(*dtd).ExternalID.is_null() {
+            self.write(b", PUBLIC ");
+            self.write(text((*dtd).ExternalID));
+        }
+        if !(*dtd).SystemID.is_null() {
+            self.write(b", SYSTEM ");
+            self.write(text((*dtd).SystemID));
+        }
+        self.write(b"\n");
+        self.check(dtd as xmlNodePtr);
+    }
+
+    unsafe fn attr_decl(&mut self, attr: xmlAttributePtr) {
+        self.spaces();
+        if (*attr).name.is_null() {
+            self.error(
+                xmlParserErrors_XML_CHECK_NO_NAME,
+                b"Node attribute declaration has no name",
+            );
+        } else {
+            self.write(b"ATTRDECL(");
+            self.write(text((*attr).name));
+            self.write(b")");
+        }
+        if (*attr).elem.is_null() {
+            self.error(
+                xmlParserErrors_XML_CHECK_NO_ELEM,
+                b"Node attribute declaration has no element name",
+            );
+        } else {
+            self.write(b" for ");
+            self.write(text((*attr).elem));
+        }
+        self.write(match (*attr).atype {
+            xmlAttributeType_XML_ATTRIBUTE_CDATA => b" CDATA",
+            xmlAttributeType_XML_ATTRIBUTE_ID => b" ID",
+            xmlAttributeType_XML_ATTRIBUTE_IDREF => b" IDREF",
+            xmlAttributeType_XML_ATTRIBUTE_IDREFS => b" IDREFS",
+            xmlAttributeType_XML_ATTRIBUTE_ENTITY => b" ENTITY",
+            xmlAttributeType_XML_ATTRIBUTE_ENTITIES => b" ENTITIES",
+            xmlAttributeType_XML_ATTRIBUTE_NMTOKEN => b" NMTOKEN",
+            xmlAttributeType_XML_ATTRIBUTE_NMTOKENS => b" NMTOKENS",
+            xmlAttributeType_XML_ATTRIBUTE_ENUMERATION => b" ENUMERATION",
+            xmlAttributeType_XML_ATTRIBUTE_NOTATION => b" NOTATION ",
+            _ => b"",
+        });
+        // At most five values of an enumeration are shown.
+        let mut cur = (*attr).tree;
+        if !cur.is_null() {
+            for i in 0..5 {
+                self.write(if i == 0 { b" (" } else { b"|" });
+                self.write(text((*cur).name));
+                cur = (*cur).next;
+                if cur.is_null() {
+                    break;
+                }
+            }
+            self.write(if cur.is_null() { b")" } else { b"...)" });
+        }
+        self.write(match (*attr).def {
+            xmlAttributeDefault_XML_ATTRIBUTE_REQUIRED => b" REQUIRED",
+            xmlAttributeDefault_XML_ATTRIBUTE_IMPLIED => b" IMPLIED",
+            xmlAttributeDefault_XML_ATTRIBUTE_FIXED => b" FIXED",
+            _ => b"",
+        });
+        if !(*attr).defaultValue.is_null() {
+            self.write(b"\"");
+            self.string((*attr).defaultValue);
+            self.write(b"\"");
+        }
+        self.write(b"\n");
+        self.check(attr as xmlNodePtr);
+    }
+
+    unsafe fn elem_decl(&mut self, elem: xmlElementPtr) {
+        self.spaces();
+        if (*elem).name.is_null() {
+            self.error(
+                xmlParserErrors_XML_CHECK_NO_NAME,
+                b"Element declaration has no name",
+            );
+        } else {
+            self.write(b"ELEMDECL(");
+            self.string((*elem).name);
+            self.write(b")");
+        }
+        self.write(match (*elem).etype {
+            xmlElementTypeVal_XML_ELEMENT_TYPE_UNDEFINED => b", UNDEFINED",
+            xmlElementTypeVal_XML_ELEMENT_TYPE_EMPTY => b", EMPTY",
+            xmlElementTypeVal_XML_ELEMENT_TYPE_ANY => b", ANY",
+            xmlElementTypeVal_XML_ELEMENT_TYPE_MIXED
+            | xmlElementTypeVal_XML_ELEMENT_TYPE_ELEMENT => b", MIXED ",
+            _ => b"",
+        });
+        if !(*elem).content.is_null() {
+            let mut buf = [0 as c_char; 5001];
+            xmlSnprintfElementContent(buf.as_mut_ptr(), 5000, (*elem).content, 1);
+            self.write(CStr::from_ptr(buf.as_ptr()).to_bytes());
+        }
+        self.write(b"\n");
+        self.check(elem as xmlNodePtr);
+    }
+
+    unsafe fn entity_decl(&mut self, ent: xmlEntityPtr) {
+        self.spaces();
+        if (*ent).name.is_null() {
+            self.error(
+                xmlParserErrors_XML_CHECK_NO_NAME,
+                b"Entity declaration has no name",
+            );
+        } else {
+            self.write(b"ENTITYDECL(");
+            self.string((*ent).name);
+            self.write(b")");
+        }
+        self.write(match (*ent).etype {
+            xmlEntityType_XML_INTERNAL_GENERAL_ENTITY => b", internal\n",
+            xmlEntityType_XML_EXTERNAL_GENERAL_PARSED_ENTITY => b", external parsed\n",
+            xmlEntityType_XML_EXTERNAL_GENERAL_UNPARSED_ENTITY => b", unparsed\n",
+            xmlEntityType_XML_INTERNAL_PARAMETER_ENTITY => b", parameter\n",
+            xmlEntityType_XML_EXTERNAL_PARAMETER_ENTITY => b", external parameter\n",
+            xmlEntityType_XML_INTERNAL_PREDEFINED_ENTITY => b", predefined\n",
+            _ => b"",
+        });
+        self.entity_fields(ent, b" ");
+        self.check(ent as xmlNodePtr);
+    }
+
+    /// The identifiers and content of an entity, each on its own line
+    /// starting with `prefix`.
+    unsafe fn entity_fields(&mut self, ent: xmlEntityPtr, prefix: &[u8]) {
+        for (name, value) in [
+            (&b"ExternalID="[..], (*ent).ExternalID),
+            (b"SystemID=", (*ent).SystemID),
+            (b"URI=", (*ent).URI),
+        ] {
+            if !value.is_null() {
+                self.spaces();
+                self.write(prefix);
+                self.write(name);
+                self.write(text(value));
+                self.write(b"\n");
+            }
+        }
+        if !(*ent).content.is_null() {
+            self.spaces();
+            self.write(prefix);
+            self.write(b"content=");
+            self.string((*ent).content);
+            self.write(b"\n");
+        }
+    }
+
+    unsafe fn namespace(&mut self, ns: xmlNsPtr) {
+        self.spaces();
+        if (*ns).href.is_null() {
+            if (*ns).prefix.is_null() {
+                self.error(
+                    xmlParserErrors_XML_CHECK_NO_HREF,
+                    b"Incomplete default namespace href=NULL\n",
+                );
+            } else {
+                let mut message = b"Incomplete namespace ".to_vec();
+                message.extend_from_slice(text((*ns).prefix));
+                message.extend_from_slice(b" href=NULL\n");
+                self.error(xmlParserErrors_XML_CHECK_NO_HREF, &message);
+            }
+            return;
+        }
+        if (*ns).prefix.is_null() {
+            self.write(b"default namespace href=");
+        } else {
+            self.write(b"namespace ");
+            self.write(text((*ns).prefix));
+            self.write(b" href=");
+        }
+        self.string((*ns).href);
+        self.write(b"\n");
+    }
+
+    /// xmlCtxtDumpEntity: the declaration an entity reference points to.
+    unsafe fn entity(&mut self, ent: xmlEntityPtr) {
+        self.spaces();
+        match (*ent).etype {
+            xmlEntityType_XML_INTERNAL_GENERAL_ENTITY => self.write(b"INTERNAL_GENERAL_ENTITY "),
+            xmlEntityType_XML_EXTERNAL_GENERAL_PARSED_ENTITY => {
+                self.write(b"EXTERNAL_GENERAL_PARSED_ENTITY ")
+            }
+            xmlEntityType_XML_EXTERNAL_GENERAL_UNPARSED_ENTITY => {
+                self.write(b"EXTERNAL_GENERAL_UNPARSED_ENTITY ")
+            }
+            xmlEntityType_XML_INTERNAL_PARAMETER_ENTITY => {
+                self.write(b"INTERNAL_PARAMETER_ENTITY ")
+            }
+            xmlEntityType_XML_EXTERNAL_PARAMETER_ENTITY => {
+                self.write(b"EXTERNAL_PARAMETER_ENTITY ")
+            }
+            etype => self.write(format!("ENTITY_{etype} ! ").as_bytes()),
+        }
+        self.write(text((*ent).name));
+        self.write(b"\n");
+        self.entity_fields(ent, b"");
+    }
+
+    unsafe fn attr(&mut self, attr: xmlAttrPtr) {
+        self.spaces();
+        self.write(b"ATTRIBUTE ");
+        self.string((*attr).name);
+        self.write(b"\n");
+        if !(*attr).children.is_null() {
+            self.depth += 1;
+            self.node_list((*attr).children);
+            self.depth -= 1;
+        }
+        if (*attr).name.is_null() {
+            self.error(xmlParserErrors_XML_CHECK_NO_NAME, b"Attribute has no name");
+        }
+        self.check(attr as xmlNodePtr);
+    }
+
+    /// xmlCtxtDumpOneNode: a node with its namespaces, attributes and
+    /// content, not its children.
+    unsafe fn one_node(&mut self, node: xmlNodePtr) {
+        let label: &[u8] = match (*node).type_ {
+            xmlElementType_XML_ELEMENT_NODE => {
+                self.spaces();
+                self.write(b"ELEMENT ");
+                let ns = (*node).ns;
+                if !ns.is_null() && !(*ns).prefix.is_null() {
+                    self.string((*ns).prefix);
+                    self.write(b":");
+                }
+                self.string((*node).name);
+                b"\n"
+            }
+            xmlElementType_XML_ATTRIBUTE_NODE => {
+                self.spaces();
+                self.write(b"Error, ATTRIBUTE found here\n");
+                self.check(node);
+                return;
+            }
+            xmlElementType_XML_TEXT_NODE => {
+                self.spaces();
+                if text((*node).name) == b"textnoenc" {
+                    self.write(b"TEXT no enc");
+                } else {
+                    self.write(b"TEXT");
+                }
+                let content = (*node).content;
+                if content == ptr::addr_of_mut!((*node).properties) as *mut xmlChar {
+                    b" compact\n"
+                } else if !self.dict.is_null() && xmlDictOwns(self.dict, content) == 1 {
+                    b" interned\n"
+                } else {
+                    b"\n"
+                }
+            }
+            xmlElementType_XML_CDATA_SECTION_NODE => {
+                self.spaces();
+                b"CDATA_SECTION\n"
+            }
+            xmlElementType_XML_ENTITY_REF_NODE => {
+                self.spaces();
+                self.write(b"ENTITY_REF(");
+                self.write(text((*node).name));
+                b")\n"
+            }
+            xmlElementType_XML_ENTITY_NODE => {
+                self.spaces();
+                b"ENTITY\n"
+            }
+            xmlElementType_XML_PI_NODE => {
+                self.spaces();
+                self.write(b"PI ");
+                self.write(text((*node).name));
+                b"\n"
+            }
+            xmlElementType_XML_COMMENT_NODE => {
+                self.spaces();
+                b"COMMENT\n"
+            }
+            xmlElementType_XML_DOCUMENT_NODE | xmlElementType_XML_HTML_DOCUMENT_NODE => {
+                self.spaces();
+                self.write(b"Error, DOCUMENT found here\n");
+                self.check(node);
+                return;
+            }
+            xmlElementType_XML_DOCUMENT_TYPE_NODE => {
+                self.spaces();
+                b"DOCUMENT_TYPE\n"
+            }
+            xmlElementType_XML_DOCUMENT_FRAG_NODE => {
+                self.spaces();
+                b"DOCUMENT_FRAG\n"
+            }
+            xmlElementType_XML_NOTATION_NODE => {
+                self.spaces();
+                b"NOTATION\n"
+            }
+            xmlElementType_XML_DTD_NODE => return self.dtd_node(node as xmlDtdPtr),
+            xmlElementType_XML_ELEMENT_DECL => return self.elem_decl(node as xmlElementPtr),
+            xmlElementType_XML_ATTRIBUTE_DECL => return self.attr_decl(node as xmlAttributePtr),
+            xmlElementType_XML_ENTITY_DECL => return self.entity_decl(node as xmlEntityPtr),
+            xmlElementType_XML_NAMESPACE_DECL => return self.namespace(node as xmlNsPtr),
+            xmlElementType_XML_XINCLUDE_START => {
+                self.spaces();
+                self.write(b"INCLUDE START\n");
+                return;
+            }
+            xmlElementType_XML_XINCLUDE_END => {
+                self.spaces();
+                self.write(b"INCLUDE END\n");
+                return;
+            }
+            other => {
+                self.spaces();
+                self.error(
+                    xmlParserErrors_XML_CHECK_UNKNOWN_NODE,
+                    format!("Unknown node type {other}\n").as_bytes(),
+                );
+                return;
+            }
+        };
+        self.write(label);
+        if (*node).doc.is_null() {
+            self.spaces();
+            self.write(b"PBM: doc == NULL !!!\n");
+        }
+        self.depth += 1;
+        if (*node).type_ == xmlElementType_XML_ELEMENT_NODE {
+            let mut ns = (*node).nsDef;
+            while !ns.is_null() {
+                self.namespace(ns);
+                ns = (*ns).next;
+            }
+            let mut attr = (*node).properties;
+            while !attr.is_null() {
+                self.attr(attr);
+                attr = (*attr).next;
+            }
+        }
+        if (*node).type_ == xmlElementType_XML_ENTITY_REF_NODE {
+            let ent = xmlGetDocEntity((*node).doc, (*node).name);
+            if !ent.is_null() {
+                self.entity(ent);
+            }
+        } else if (*node).type_ != xmlElementType_XML_ELEMENT_NODE && !(*node).content.is_null() {
+            self.spaces();
+            self.write(b"content=");
+            self.string((*node).content);
+            self.write(b"\n");
+        }
+        self.depth -= 1;
+        self.check(node);
+    }
+
+    /// xmlCtxtDumpNode: a node and its subtree.
+    unsafe fn node(&mut self, node: xmlNodePtr) {
+        self.one_node(node);
+        if (*node).type_ != xmlElementType_XML_NAMESPACE_DECL
+            && (*node).type_ != xmlElementType_XML_ENTITY_REF_NODE
+            && !(*node).children.is_null()
+        {
+            self.depth += 1;
+            self.node_list((*node).children);
+            self.depth -= 1;
+        }
+    }
+
+    unsafe fn node_list(&mut self, mut node: xmlNodePtr) {
+        while !node.is_null() {
+            self.node(node);
+            node = (*node).next;
+        }
+    }
+
+    /// xmlCtxtDumpDocument: the document properties, then its tree.
+    unsafe fn document(&mut self, doc: xmlDocPtr) {
+        let misplaced = |code, what: &str| (code, format!("Misplaced {what} node\n"));
+        let err = match (*doc).type_ {
+            xmlElementType_XML_DOCUMENT_NODE => {
+                self.write(b"DOCUMENT\n");
+                None
+            }
+            xmlElementType_XML_HTML_DOCUMENT_NODE => {
+                self.write(b"HTML DOCUMENT\n");
+                None
+            }
+            xmlElementType_XML_ELEMENT_NODE => Some(misplaced(
+                xmlParserErrors_XML_CHECK_FOUND_ELEMENT,
+                "ELEMENT",
+            )),
+            xmlElementType_XML_ATTRIBUTE_NODE => Some(misplaced(
+                xmlParserErrors_XML_CHECK_FOUND_ATTRIBUTE,
+                "ATTRIBUTE",
+            )),
+            xmlElementType_XML_TEXT_NODE => {
+                Some(misplaced(xmlParserErrors_XML_CHECK_FOUND_TEXT, "TEXT"))
+            }
+            xmlElementType_XML_CDATA_SECTION_NODE => {
+                Some(misplaced(xmlParserErrors_XML_CHECK_FOUND_CDATA, "CDATA"))
+            }
+            xmlElementType_XML_ENTITY_REF_NODE => Some(misplaced(
+                xmlParserErrors_XML_CHECK_FOUND_ENTITYREF,
+                "ENTITYREF",
+            )),
+            xmlElementType_XML_ENTITY_NODE => {
+                Some(misplaced(xmlParserErrors_XML_CHECK_FOUND_ENTITY, "ENTITY"))
+            }
+            xmlElementType_XML_PI_NODE => Some(misplaced(xmlParserErrors_XML_CHECK_FOUND_PI, "PI")),
+            xmlElementType_XML_COMMENT_NODE => Some(misplaced(
+                xmlParserErrors_XML_CHECK_FOUND_COMMENT,
+                "COMMENT",
+            )),
+            xmlElementType_XML_DOCUMENT_TYPE_NODE => Some(misplaced(
+                xmlParserErrors_XML_CHECK_FOUND_DOCTYPE,
+                "DOCTYPE",
+            )),
+            xmlElementType_XML_DOCUMENT_FRAG_NODE => Some(misplaced(
+                xmlParserErrors_XML_CHECK_FOUND_FRAGMENT,
+                "FRAGMENT",
+            )),
+            xmlElementType_XML_NOTATION_NODE => Some(misplaced(
+                xmlParserErrors_XML_CHECK_FOUND_NOTATION,
+                "NOTATION",
+            )),
+            other => Some((
+                xmlParserErrors_XML_CHECK_UNKNOWN_NODE,
+                format!("Unknown node type {other}\n"),
+            )),
+        };
+        if let Some((code, message)) = err {
+            self.error(code, message.as_bytes());
+        }
+        for (name, value) in [
+            (&b"name="[..], (*doc).name as *const xmlChar),
+            (b"version=", (*doc).version),
+            (b"encoding=", (*doc).encoding),
+            (b"URL=", (*doc).URL),
+        ] {
+            if !value.is_null() {
+                self.write(name);
+                self.string(value);
+                self.write(b"\n");
+            }
+        }
+        if (*doc).standalone != 0 {
+            self.write(b"standalone=true\n");
+        }
+        let mut ns = (*doc).oldNs;
+        while !ns.is_null() {
+            self.namespace(ns);
+            ns = (*ns).next;
+        }
+        if matches!(
+            (*doc).type_,
+            xmlElementType_XML_DOCUMENT_NODE | xmlElementType_XML_HTML_DOCUMENT_NODE
+        ) && !(*doc).children.is_null()
+        {
+            self.depth += 1;
+            self.node_list((*doc).children);
+            self.depth -= 1;
+        }
+    }
+}
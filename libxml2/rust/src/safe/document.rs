@@ -0,0 +1,302 @@
+//! Owned documents and node handles borrowed from them.
+
+use std::borrow::Cow;
+use std::ffi::CStr;
+use std::fmt;
+use std::iter::FusedIterator;
+use std::marker::PhantomData;
+use std::os::raw::{c_char, c_int, c_void};
+use std::ptr::{self, NonNull};
+
+use crate::static_bindings::{
+    xmlAttr, xmlChar, xmlDoc, xmlDocGetRootElement, xmlElementType,
+    xmlElementType_XML_DOCUMENT_NODE, xmlElementType_XML_ELEMENT_NODE,
+    xmlElementType_XML_ENTITY_REF_NODE, xmlElementType_XML_HTML_DOCUMENT_NODE, xmlFree, xmlFreeDoc,
+    xmlGetProp, xmlNode, xmlNodeGetContent, xmlNodeListGetString,
+};
+use crate::xmlstring::{str_to_xmlchar, xmlchar_to_str};
+
+/// Copy a string allocated by libxml2 and free it.
+unsafe fn take_string(s: *mut xmlChar) -> Option<String> {
+    let ret = xmlchar_to_str(s).map(Cow::into_owned)?;
+    xmlFree.unwrap()(s as *mut c_void);
+    Some(ret)
+}
+
+/// A document, freed with `xmlFreeDoc` on drop. Nodes borrowed from it
+/// can't outlive it.
+pub struct XmlDoc {
+    doc: NonNull<xmlDoc>,
+}
+
+impl XmlDoc {
+    /// Take ownership of a document.
+    ///
+    /// # Safety
+    ///
+    /// `doc` must be null or a valid document which nothing else frees.
+    pub unsafe fn from_raw(doc: *mut xmlDoc) -> Option<XmlDoc> {
+        NonNull::new(doc).map(|doc| XmlDoc { doc })
+    }
+
+    /// Give up ownership of the document, which must then be freed with
+    /// `xmlFreeDoc`.
+    pub fn into_raw(self) -> *mut xmlDoc {
+        let doc = self.doc.as_ptr();
+        std::mem::forget(self);
+        doc
+    }
+
+    pub fn as_ptr(&self) -> *mut xmlDoc {
+        self.doc.as_ptr()
+    }
+
+    /// The document element.
+    pub fn root(&self) -> Option<XmlNode<'_>> {
+        unsafe { XmlNode::from_ptr(xmlDocGetRootElement(self.doc.as_ptr())) }
+    }
+
+    /// Top-level nodes: the DTD, comments and processing instructions
+    /// around the document element, and the element itself.
+    pub fn children(&self) -> Children<'_> {
+        Children::new(unsafe { self.doc.as_ref().children })
+    }
+}
+
+// A document is only reached through its `XmlDoc`, and the dictionary it
+// may share with other documents is reference counted under a lock, so it
+// can be moved to, used and freed on another thread.
+unsafe impl Send for XmlDoc {}
+
+impl Drop for XmlDoc {
+    fn drop(&mut self) {
+        unsafe { xmlFreeDoc(self.doc.as_ptr()) };
+    }
+}
+
+impl fmt::Debug for XmlDoc {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("XmlDoc").field("doc", &self.doc).finish()
+    }
+}
+
+/// A node of an `XmlDoc`, valid as long as the document is borrowed.
+///
+/// ```compile_fail
+/// use libxml2::XmlDoc;
+///
+/// let doc = XmlDoc::parse_memory(b"<a/>", 0).unwrap();
+/// let root = doc.root().unwrap();
+/// drop(doc);
+/// root.name();
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct XmlNode<'a> {
+    node: NonNull<xmlNode>,
+    doc: PhantomData<&'a XmlDoc>,
+}
+
+impl<'a> XmlNode<'a> {
+    /// # Safety
+    ///
+    /// `node` must be null or a node of a document borrowed for `'a`.
+    pub unsafe fn from_ptr(node: *mut xmlNode) -> Option<XmlNode<'a>> {
+        NonNull::new(node).map(|node| XmlNode {
+            node,
+            doc: PhantomData,
+        })
+    }
+
+    pub fn as_ptr(self) -> *mut xmlNode {
+        self.node.as_ptr()
+    }
+
+    fn raw(self) -> &'a xmlNode {
+        unsafe { &*self.node.as_ptr() }
+    }
+
+    /// One of the `XML_*_NODE` constants.
+    pub fn node_type(self) -> xmlElementType {
+        self.raw().type_
+    }
+
+    pub fn is_element(self) -> bool {
+        self.node_type() == xmlElementType_XML_ELEMENT_NODE
+    }
+
+    /// The local name. Text nodes are named `text`, comments `comment`.
+    pub fn name(self) -> Option<&'a str> {
+        let name = self.raw().name;
+        if name.is_null() {
+            return None;
+        }
+        unsafe { CStr::from_ptr(name as *const c_char) }
+            .to_str()
+            .ok()
+    }
+
+    /// The text content, as `xmlNodeGetContent` computes it.
+    pub fn content(self) -> Option<String> {
+        unsafe { take_string(xmlNodeGetContent(self.node.as_ptr())) }
+    }
+
+    /// The parent node, `None` for the document element and top-level
+    /// nodes.
+    pub fn parent(self) -> Option<XmlNode<'a>> {
+        let parent = unsafe { XmlNode::from_ptr(self.raw().parent)? };
+        match parent.node_type() {
+            xmlElementType_XML_DOCUMENT_NODE | xmlElementType_XML_HTML_DOCUMENT_NODE => None,
+            _ => Some(parent),
+        }
+    }
+
+    pub fn next_sibling(self) -> Option<XmlNode<'a>> {
+        unsafe { XmlNode::from_ptr(self.raw().next) }
+    }
+
+    pub fn prev_sibling(self) -> Option<XmlNode<'a>> {
+        unsafe { XmlNode::from_ptr(self.raw().prev) }
+    }
+
+    /// The child nodes in document order, following `children` and `next`.
+    /// Entity references yield nothing: their `children` point to the
+    /// entity declaration, which isn't part of the tree.
+    pub fn children(self) -> Children<'a> {
+        if self.node_type() == xmlElementType_XML_ENTITY_REF_NODE {
+            return Children::new(ptr::null_mut());
+        }
+        Children::new(self.raw().children)
+    }
+
+    /// The attributes of an element in document order; nothing for other
+    /// nodes.
+    pub fn attributes(self) -> Attributes<'a> {
+        let first = if self.is_element() {
+            self.raw().properties
+        } else {
+            ptr::null_mut()
+        };
+        Attributes {
+            next: first,
+            doc: PhantomData,
+        }
+    }
+
+    /// The value of the attribute `name` without a namespace, as
+    /// `xmlGetProp` finds it. Defaulted attributes from the DTD count.
+    pub fn attribute(self, name: &str) -> Option<String> {
+        if !self.is_element() {
+            return None;
+        }
+        let name = str_to_xmlchar(name)?;
+        unsafe { take_string(xmlGetProp(self.node.as_ptr(), name.as_ptr())) }
+    }
+}
+
+impl fmt::Debug for XmlNode<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("XmlNode")
+            .field("type", &self.node_type())
+            .field("name", &self.name())
+            .finish()
+    }
+}
+
+/// An attribute of an element in an `XmlDoc`.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct XmlAttr<'a> {
+    attr: NonNull<xmlAttr>,
+    doc: PhantomData<&'a XmlDoc>,
+}
+
+impl<'a> XmlAttr<'a> {
+    pub fn as_ptr(self) -> *mut xmlAttr {
+        self.attr.as_ptr()
+    }
+
+    fn raw(self) -> &'a xmlAttr {
+        unsafe { &*self.attr.as_ptr() }
+    }
+
+    /// The local name.
+    pub fn name(self) -> Option<&'a str> {
+        let name = self.raw().name;
+        if name.is_null() {
+            return None;
+        }
+        unsafe { CStr::from_ptr(name as *const c_char) }
+            .to_str()
+            .ok()
+    }
+
+    /// The value, with entity references substituted.
+    pub fn value(self) -> String {
+        let attr = self.raw();
+        unsafe { take_string(xmlNodeListGetString(attr.doc, attr.children, 1)) }.unwrap_or_default()
+    }
+
+    /// The element the attribute belongs to.
+    pub fn parent(self) -> Option<XmlNode<'a>> {
+        unsafe { XmlNode::from_ptr(self.raw().parent) }
+    }
+}
+
+impl fmt::Debug for XmlAttr<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("XmlAttr")
+            .field("name", &self.name())
+            .field("value", &self.value())
+            .finish()
+    }
+}
+
+/// Iterator over sibling nodes, from `XmlNode::children`.
+#[derive(Clone)]
+pub struct Children<'a> {
+    next: *mut xmlNode,
+    doc: PhantomData<&'a XmlDoc>,
+}
+
+impl Children<'_> {
+    fn new(first: *mut xmlNode) -> Self {
+        Children {
+            next: first,
+            doc: PhantomData,
+        }
+    }
+}
+
+impl<'a> Iterator for Children<'a> {
+    type Item = XmlNode<'a>;
+
+    fn next(&mut self) -> Option<XmlNode<'a>> {
+        let node = unsafe { XmlNode::from_ptr(self.next)? };
+        self.next = node.raw().next;
+        Some(node)
+    }
+}
+
+impl FusedIterator for Children<'_> {}
+
+/// Iterator over the attributes of an element, from
+/// `XmlNode::attributes`.
+#[derive(Clone)]
+pub struct Attributes<'a> {
+    next: *mut xmlAttr,
+    doc: PhantomData<&'a XmlDoc>,
+}
+
+impl<'a> Iterator for Attributes<'a> {
+    type Item = XmlAttr<'a>;
+
+    fn next(&mut self) -> Option<XmlAttr<'a>> {
+        let attr = XmlAttr {
+            attr: NonNull::new(self.next)?,
+            doc: PhantomData,
+        };
+        self.next = attr.raw().next;
+        Some(attr)
+    }
+}
+
+impl FusedIterator for Attributes<'_> {}
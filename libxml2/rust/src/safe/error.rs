@@ -0,0 +1,93 @@
+//! Owned copies of libxml2 errors.
+
+use std::borrow::Cow;
+use std::error::Error;
+use std::fmt;
+use std::os::raw::{c_char, c_int};
+
+use crate::static_bindings::{
+    xmlChar, xmlError, xmlErrorLevel, xmlErrorLevel_XML_ERR_ERROR, xmlErrorLevel_XML_ERR_FATAL,
+    xmlErrorLevel_XML_ERR_WARNING, xmlParserErrors_XML_ERR_OK,
+};
+use crate::xmlstring::xmlchar_to_str;
+
+/// An `xmlError` copied out of libxml2, so it outlives the parser context
+/// or thread-local slot it was reported in.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct XmlError {
+    /// An `xmlErrorDomain` value.
+    pub domain: c_int,
+    /// An `xmlParserErrors` value.
+    pub code: c_int,
+    pub level: xmlErrorLevel,
+    /// The message, without the trailing newline.
+    pub message: String,
+    pub file: Option<String>,
+    /// 1-based, 0 if unknown.
+    pub line: c_int,
+}
+
+unsafe fn to_string(s: *const c_char) -> Option<String> {
+    xmlchar_to_str(s as *const xmlChar).map(Cow::into_owned)
+}
+
+impl XmlError {
+    pub(crate) fn new(domain: u32, code: u32, message: &str) -> XmlError {
+        XmlError {
+            domain: domain as c_int,
+            code: code as c_int,
+            level: xmlErrorLevel_XML_ERR_FATAL,
+            message: message.to_owned(),
+            file: None,
+            line: 0,
+        }
+    }
+
+    /// Copy an error. `None` for a null pointer or an error with code
+    /// `XML_ERR_OK`, which is what `xmlGetLastError` and
+    /// `xmlCtxtGetLastError` return when nothing went wrong.
+    ///
+    /// # Safety
+    ///
+    /// `err` must be null or point to a valid `xmlError`.
+    pub unsafe fn from_raw(err: *const xmlError) -> Option<XmlError> {
+        let err = err.as_ref()?;
+        if err.code == xmlParserErrors_XML_ERR_OK as c_int {
+            return None;
+        }
+        let message = to_string(err.message).unwrap_or_default();
+        Some(XmlError {
+            domain: err.domain,
+            code: err.code,
+            level: err.level,
+            message: message.trim_end().to_owned(),
+            file: to_string(err.file),
+            line: err.line,
+        })
+    }
+
+    pub fn is_warning(&self) -> bool {
+        self.level == xmlErrorLevel_XML_ERR_WARNING
+    }
+}
+
+impl fmt::Display for XmlError {
+    /// `file:line: error 76: message`, leaving out whatever is unknown.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (&self.file, self.line) {
+            (Some(file), 0) => write!(f, "{file}: ")?,
+            (Some(file), line) => write!(f, "{file}:{line}: ")?,
+            (None, 0) => {}
+            (None, line) => write!(f, "line {line}: ")?,
+        }
+        let level = match self.level {
+            xmlErrorLevel_XML_ERR_WARNING => "warning",
+            xmlErrorLevel_XML_ERR_ERROR => "error",
+            xmlErrorLevel_XML_ERR_FATAL => "fatal error",
+            _ => "note",
+        };
+        write!(f, "{level} {}: {}", self.code, self.message)
+    }
+}
+
+impl Error for XmlError {}
@@ -0,0 +1,21 @@
+//! Safe Rust API over the linked implementation
+//!
+//! Owned documents and borrowed node handles, for Rust users who would
+//! otherwise walk raw `xmlNodePtr`s. The wrappers call whichever
+//! implementation of each module is linked, C or Rust.
+
+mod debug;
+mod document;
+mod error;
+#[cfg(feature = "net")]
+mod net;
+mod parse;
+mod xinclude;
+
+pub use debug::*;
+pub use document::*;
+pub use error::*;
+#[cfg(feature = "net")]
+pub use net::*;
+pub use parse::*;
+pub use xinclude::*;
@@ -0,0 +1,351 @@
+//! Fetching external DTDs and entities over HTTP, which libxml2 no longer
+//! does since nanohttp was reduced to stubs.
+
+use std::ffi::{CStr, CString};
+use std::io::{Read, Write};
+use std::net::{TcpStream, ToSocketAddrs};
+use std::os::raw::{c_char, c_int, c_void};
+use std::ptr;
+use std::sync::{OnceLock, RwLock};
+use std::time::Duration;
+
+use super::XmlError;
+use crate::static_bindings::{
+    xmlBuildURI, xmlErrorDomain_XML_FROM_IO, xmlExternalEntityLoader, xmlFree,
+    xmlGetExternalEntityLoader, xmlNewInputFromMemory, xmlParserCtxtPtr,
+    xmlParserErrors_XML_IO_ECONNREFUSED, xmlParserErrors_XML_IO_EIO, xmlParserErrors_XML_IO_ENOENT,
+    xmlParserErrors_XML_IO_NETWORK_ATTEMPT, xmlParserErrors_XML_IO_UNSUPPORTED_PROTOCOL,
+    xmlParserInputPtr, xmlParserOption_XML_PARSE_NONET, xmlSetExternalEntityLoader,
+};
+
+extern "C" {
+    // Private in parserInternals.c
+    fn xmlCtxtErrIO(ctxt: xmlParserCtxtPtr, code: c_int, uri: *const c_char);
+}
+
+/// How long to wait to connect, and then for each read.
+const TIMEOUT: Duration = Duration::from_secs(60);
+/// The most redirects followed for one resource.
+const MAX_REDIRECTS: usize = 10;
+/// The largest response read, headers included.
+const MAX_RESPONSE: u64 = 64 << 20;
+
+/// The URLs the network loader may fetch: those with one of `schemes` and
+/// one of `hosts`, both compared without case. Empty lists, the default,
+/// allow nothing. Only `http` can be fetched; other schemes allowed fail
+/// with `XML_IO_UNSUPPORTED_PROTOCOL`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct NetAllowlist {
+    pub schemes: Vec<String>,
+    /// Host names or IP addresses, without ports. IPv6 addresses go
+    /// without brackets.
+    pub hosts: Vec<String>,
+}
+
+impl NetAllowlist {
+    /// Whether `url` may be fetched. Redirects are checked again.
+    pub fn allows(&self, url: &str) -> bool {
+        Url::parse(url).is_some_and(|url| self.allows_url(&url))
+    }
+
+    fn allows_url(&self, url: &Url) -> bool {
+        self.schemes
+            .iter()
+            .any(|s| s.eq_ignore_ascii_case(url.scheme))
+            && self.hosts.iter().any(|h| h.eq_ignore_ascii_case(url.host))
+    }
+}
+
+/// The parts of a `scheme://host[:port]/path` URL a request needs.
+#[derive(Debug)]
+struct Url<'a> {
+    scheme: &'a str,
+    host: &'a str,
+    port: Option<u16>,
+    /// The path and query, `/` if empty, without the fragment.
+    path: &'a str,
+}
+
+impl<'a> Url<'a> {
+    /// Split a network URL, `None` for a file name, a `file:` URL or
+    /// anything with user information or a bad port.
+    fn parse(url: &'a str) -> Option<Url<'a>> {
+        let (scheme, rest) = url.split_once("://")?;
+        if scheme.is_empty()
+            || !scheme
+                .bytes()
+                .all(|b| b.is_ascii_alphanumeric() || b"+-.".contains(&b))
+            || scheme.eq_ignore_ascii_case("file")
+        {
+            return None;
+        }
+        let rest = rest.split('#').next().unwrap_or_default();
+        let end = rest.find(['/', '?']).unwrap_or(rest.len());
+        let (authority, path) = rest.split_at(end);
+        if authority.contains('@') {
+            return None;
+        }
+        let (host, port) = match authority.strip_prefix('[') {
+            Some(v6) => {
+                let (host, after) = v6.split_once(']')?;
+                (host, after.strip_prefix(':'))
+            }
+            None => match authority.rsplit_once(':') {
+                Some((host, port)) => (host, Some(port)),
+                None => (authority, None),
+            },
+        };
+        if host.is_empty() {
+            return None;
+        }
+        let port = match port {
+            Some(port) => Some(port.parse().ok()?),
+            None => None,
+        };
+        let path = if path.is_empty() { "/" } else { path };
+        Some(Url {
+            scheme,
+            host,
+            port,
+            path,
+        })
+    }
+
+    /// The `Host` header: the host, bracketed if IPv6, and any port.
+    fn host_header(&self) -> String {
+        let host = if self.host.contains(':') {
+            format!("[{}]", self.host)
+        } else {
+            self.host.to_owned()
+        };
+        match self.port {
+            Some(port) => format!("{host}:{port}"),
+            None => host,
+        }
+    }
+}
+
+fn io_error(code: u32, message: String) -> XmlError {
+    XmlError::new(xmlErrorDomain_XML_FROM_IO, code, &message)
+}
+
+/// A response: the status code, the `Location` header and the body.
+type Response = (u16, Option<String>, Vec<u8>);
+
+/// One HTTP/1.0 GET of `url`, which the server answers without chunks and
+/// ends by closing the connection.
+fn get(url: &Url) -> Result<Response, XmlError> {
+    let target = url.host_header();
+    let failed = |e: std::io::Error| {
+        let code = match e.kind() {
+            std::io::ErrorKind::ConnectionRefused => xmlParserErrors_XML_IO_ECONNREFUSED,
+            _ => xmlParserErrors_XML_IO_EIO,
+        };
+        io_error(code, format!("{target}: {e}"))
+    };
+    let addrs = (url.host, url.port.unwrap_or(80))
+        .to_socket_addrs()
+        .map_err(failed)?;
+    let mut last = None;
+    let mut stream = None;
+    for addr in addrs {
+        match TcpStream::connect_timeout(&addr, TIMEOUT) {
+            Ok(s) => {
+                stream = Some(s);
+                break;
+            }
+            Err(e) => last = Some(e),
+        }
+    }
+    let mut stream = match (stream, last) {
+        (Some(stream), _) => stream,
+        (None, Some(e)) => return Err(failed(e)),
+        (None, None) => {
+            return Err(io_error(
+                xmlParserErrors_XML_IO_EIO,
+                format!("{target}: no address"),
+            ))
+        }
+    };
+    stream.set_read_timeout(Some(TIMEOUT)).map_err(failed)?;
+    stream.set_write_timeout(Some(TIMEOUT)).map_err(failed)?;
+    let request = format!(
+        "GET {} HTTP/1.0\r\nHost: {target}\r\nAccept: */*\r\nUser-Agent: libxml2\r\n\r\n",
+        url.path
+    );
+    stream.write_all(request.as_bytes()).map_err(failed)?;
+    let mut response = Vec::new();
+    stream
+        .take(MAX_RESPONSE + 1)
+        .read_to_end(&mut response)
+        .map_err(failed)?;
+    if response.len() as u64 > MAX_RESPONSE {
+        return Err(io_error(
+            xmlParserErrors_XML_IO_EIO,
+            format!("{target}: response larger than {MAX_RESPONSE} bytes"),
+        ));
+    }
+
+    let malformed = || {
+        io_error(
+            xmlParserErrors_XML_IO_EIO,
+            format!("{target}: malformed HTTP response"),
+        )
+    };
+    let split = response
+        .windows(4)
+        .position(|w| w == b"\r\n\r\n")
+        .ok_or_else(malformed)?;
+    let head = std::str::from_utf8(&response[..split]).map_err(|_| malformed())?;
+    let mut lines = head.split("\r\n");
+    let status = lines
+        .next()
+        .filter(|line| line.starts_with("HTTP/"))
+        .and_then(|line| line.split(' ').nth(1))
+        .and_then(|code| code.parse().ok())
+        .ok_or_else(malformed)?;
+    let location = lines
+        .filter_map(|line| line.split_once(':'))
+        .find(|(name, _)| name.trim().eq_ignore_ascii_case("location"))
+        .map(|(_, value)| value.trim().to_owned());
+    Ok((status, location, response[split + 4..].to_vec()))
+}
+
+/// `location` resolved against `base` with `xmlBuildURI`, `None` if either
+/// is not a valid URI.
+fn resolve(location: &str, base: &str) -> Option<String> {
+    let location = CString::new(location).ok()?;
+    let base = CString::new(base).ok()?;
+    unsafe {
+        let uri = xmlBuildURI(location.as_ptr() as *const _, base.as_ptr() as *const _);
+        if uri.is_null() {
+            return None;
+        }
+        let resolved = CStr::from_ptr(uri as *const c_char)
+            .to_string_lossy()
+            .into_owned();
+        xmlFree.unwrap()(uri as *mut c_void);
+        Some(resolved)
+    }
+}
+
+/// Fetch `url` if `allowlist` allows it, following redirects it also
+/// allows, with a relative `Location` resolved against the URL redirected. Errors are in the `XML_FROM_IO` domain: `XML_IO_NETWORK_ATTEMPT`
+/// for a URL not allowed, `XML_IO_UNSUPPORTED_PROTOCOL` for a scheme other
+/// than `http`, `XML_IO_ENOENT` for a 404 and `XML_IO_EIO` for any other
+/// failure, a response over 64 MiB included.
+pub fn fetch(url: &str, allowlist: &NetAllowlist) -> Result<Vec<u8>, XmlError> {
+    let mut current = url.to_owned();
+    for _ in 0..=MAX_REDIRECTS {
+        let parsed = Url::parse(&current).filter(|url| allowlist.allows_url(url));
+        let Some(parsed) = parsed else {
+            return Err(io_error(
+                xmlParserErrors_XML_IO_NETWORK_ATTEMPT,
+                format!("{current}: not in the network allowlist"),
+            ));
+        };
+        if !parsed.scheme.eq_ignore_ascii_case("http") {
+            return Err(io_error(
+                xmlParserErrors_XML_IO_UNSUPPORTED_PROTOCOL,
+                format!("{current}: only http is supported"),
+            ));
+        }
+        let (status, location, body) = get(&parsed)?;
+        match (status, location) {
+            (200..=299, _) => return Ok(body),
+            (301 | 302 | 303 | 307 | 308, Some(location)) => {
+                current = resolve(&location, &current).ok_or_else(|| {
+                    io_error(
+                        xmlParserErrors_XML_IO_EIO,
+                        format!("{current}: bad redirect to {location}"),
+                    )
+                })?;
+            }
+            (404 | 410, _) => {
+                return Err(io_error(
+                    xmlParserErrors_XML_IO_ENOENT,
+                    format!("{current}: HTTP {status}"),
+                ))
+            }
+            _ => {
+                return Err(io_error(
+                    xmlParserErrors_XML_IO_EIO,
+                    format!("{current}: HTTP {status}"),
+                ))
+            }
+        }
+    }
+    Err(io_error(
+        xmlParserErrors_XML_IO_EIO,
+        format!("{url}: too many redirects"),
+    ))
+}
+
+/// The allowlist of the installed loader.
+static ALLOWLIST: RwLock<NetAllowlist> = RwLock::new(NetAllowlist {
+    schemes: Vec::new(),
+    hosts: Vec::new(),
+});
+/// The loader installed before, which loads everything but network URLs.
+static FALLBACK: OnceLock<xmlExternalEntityLoader> = OnceLock::new();
+
+/// `xmlExternalEntityLoader` fetching network URLs with `fetch` and
+/// handing the rest to the loader it replaced.
+unsafe extern "C" fn net_entity_loader(
+    url: *const c_char,
+    public_id: *const c_char,
+    ctxt: xmlParserCtxtPtr,
+) -> xmlParserInputPtr {
+    if url.is_null() {
+        return ptr::null_mut();
+    }
+    let name = CStr::from_ptr(url).to_string_lossy();
+    if Url::parse(&name).is_none() {
+        return match FALLBACK.get().copied().flatten() {
+            Some(fallback) => fallback(url, public_id, ctxt),
+            None => ptr::null_mut(),
+        };
+    }
+    if !ctxt.is_null() && (*ctxt).options & xmlParserOption_XML_PARSE_NONET as c_int != 0 {
+        xmlCtxtErrIO(ctxt, xmlParserErrors_XML_IO_NETWORK_ATTEMPT as c_int, url);
+        return ptr::null_mut();
+    }
+    let fetched = fetch(&name, &ALLOWLIST.read().unwrap_or_else(|e| e.into_inner()));
+    match fetched {
+        Ok(body) => xmlNewInputFromMemory(url, body.as_ptr() as *const c_void, body.len(), 0),
+        Err(err) => {
+            if !ctxt.is_null() {
+                xmlCtxtErrIO(ctxt, err.code, url);
+            }
+            ptr::null_mut()
+        }
+    }
+}
+
+/// Install a loader for external DTDs and entities that fetches the
+/// network URLs `allowlist` allows over HTTP, with `xmlSetExternalEntityLoader`.
+/// Everything else goes to the loader installed before, libxml2's own by
+/// default, so files and catalogs work as they did. Installing it again
+/// only replaces the allowlist.
+///
+/// The loader honours `XML_PARSE_NONET`, failing with
+/// `XML_IO_NETWORK_ATTEMPT`, so the safe API only fetches with
+/// `ParserOptions::allow_network`. A `ParserOptions::loader` replaces it,
+/// as it does libxml2's loader. Unlike libxml2's loader, it does not look
+/// network URLs up in catalogs.
+///
+/// # Safety
+///
+/// Like `xmlSetExternalEntityLoader`, the first call sets a global that
+/// parsers read without synchronization: no other thread may be parsing.
+pub unsafe fn install_net_loader(allowlist: NetAllowlist) {
+    *ALLOWLIST.write().unwrap_or_else(|e| e.into_inner()) = allowlist;
+    let mut installed = false;
+    FALLBACK.get_or_init(|| {
+        installed = true;
+        xmlGetExternalEntityLoader()
+    });
+    if installed {
+        xmlSetExternalEntityLoader(Some(net_entity_loader));
+    }
+}
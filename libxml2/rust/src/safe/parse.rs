@@ -0,0 +1,574 @@
+//! Parsing into an `XmlDoc` from memory, a `str` or any `Read`.
+
+use std::any::Any;
+use std::ffi::CStr;
+use std::io::{self, Read};
+#[cfg(feature = "rust-xmlmemory")]
+use std::marker::PhantomData;
+use std::os::raw::{c_char, c_int, c_void};
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr;
+use std::slice;
+
+use super::{XmlDoc, XmlError};
+#[cfg(feature = "rust-xmlmemory")]
+use crate::static_bindings::xmlResetLastError;
+use crate::static_bindings::{
+    startElementNsSAX2Func, startElementSAXFunc, xmlChar, xmlCtxtGetLastError, xmlCtxtReadDoc,
+    xmlCtxtReadIO, xmlCtxtReadMemory, xmlCtxtSetErrorHandler, xmlCtxtSetResourceLoader, xmlDoc,
+    xmlError, xmlErrorDomain_XML_FROM_IO, xmlErrorDomain_XML_FROM_PARSER, xmlFreeDoc,
+    xmlFreeParserCtxt, xmlNewInputFromMemory, xmlNewParserCtxt, xmlParserCtxt, xmlParserErrors,
+    xmlParserErrors_XML_ERR_NO_MEMORY, xmlParserErrors_XML_ERR_OK,
+    xmlParserErrors_XML_ERR_RESOURCE_LIMIT, xmlParserErrors_XML_IO_ENOENT,
+    xmlParserErrors_XML_IO_UNKNOWN, xmlParserInput, xmlParserInputFlags,
+    xmlParserOption_XML_PARSE_HUGE, xmlParserOption_XML_PARSE_NONET,
+    xmlParserOption_XML_PARSE_RECOVER, xmlResourceType, xmlStopParser,
+};
+#[cfg(feature = "rust-xmlmemory")]
+use crate::xmlmemory::Arena;
+
+/// The deepest element nesting the parser accepts, `xmlParserMaxDepth`.
+pub const PARSER_MAX_DEPTH: usize = 256;
+
+/// The deepest element nesting the parser accepts with `XML_PARSE_HUGE`.
+pub const PARSER_MAX_DEPTH_HUGE: usize = 2048;
+
+/// Loads an external DTD or entity in place of libxml2's loader. It is
+/// given the URL or system ID, the public ID if there is one, and the
+/// kind of resource, and returns the content, or `None` if there is none,
+/// which the parser reports as `XML_IO_ENOENT`.
+pub type ResourceLoader = fn(&str, Option<&str>, xmlResourceType) -> Option<Vec<u8>>;
+
+/// How `XmlDoc::parse_memory` and `XmlDoc::parse_reader` parse. A bare
+/// `c_int` of `XML_PARSE_*` flags converts into options with no other
+/// setting.
+///
+/// Which external resources are fetched at all is up to the flags, as in
+/// C: with none of them, neither the external DTD nor external entities
+/// are loaded. `XML_PARSE_DTDLOAD` loads the DTD and the parameter
+/// entities it references, `XML_PARSE_NOENT` the general entities it
+/// substitutes, and `XML_PARSE_NO_XXE` neither.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ParserOptions {
+    /// The `XML_PARSE_*` flags.
+    pub flags: c_int,
+    /// The deepest element nesting to accept. A document nesting deeper
+    /// fails with `XML_ERR_RESOURCE_LIMIT`, recovery or not. `None`, or a
+    /// limit above it, leaves the parser's own: `PARSER_MAX_DEPTH`, or
+    /// `PARSER_MAX_DEPTH_HUGE` with `XML_PARSE_HUGE`.
+    pub max_depth: Option<usize>,
+    /// Let libxml2's loader fetch resources over the network. Unlike in C,
+    /// where `XML_PARSE_NONET` opts out, it is blocked unless this is set,
+    /// with `XML_IO_NETWORK_ATTEMPT`; `XML_PARSE_NONET` still blocks it.
+    pub allow_network: bool,
+    /// Loads every external resource the flags let the parser fetch, in
+    /// place of libxml2's loader, whatever `allow_network` says. A panic
+    /// in it stops the parser and is resumed once the parser has been torn
+    /// down.
+    pub loader: Option<ResourceLoader>,
+}
+
+impl ParserOptions {
+    /// The nesting limit of the parser itself with these flags.
+    fn parser_max_depth(&self) -> usize {
+        if self.flags & xmlParserOption_XML_PARSE_HUGE as c_int != 0 {
+            PARSER_MAX_DEPTH_HUGE
+        } else {
+            PARSER_MAX_DEPTH
+        }
+    }
+
+    /// The flags the parser is run with.
+    fn parser_flags(&self) -> c_int {
+        if self.allow_network {
+            self.flags
+        } else {
+            self.flags | xmlParserOption_XML_PARSE_NONET as c_int
+        }
+    }
+
+    /// The nesting limit parsing with these options applies.
+    pub fn effective_max_depth(&self) -> usize {
+        let builtin = self.parser_max_depth();
+        self.max_depth.map_or(builtin, |max| max.min(builtin))
+    }
+}
+
+impl From<c_int> for ParserOptions {
+    fn from(flags: c_int) -> Self {
+        ParserOptions {
+            flags,
+            ..Default::default()
+        }
+    }
+}
+
+fn no_memory() -> XmlError {
+    XmlError::new(
+        xmlErrorDomain_XML_FROM_PARSER,
+        xmlParserErrors_XML_ERR_NO_MEMORY,
+        "out of memory",
+    )
+}
+
+/// The state behind `_private` of a parser context enforcing a nesting
+/// limit below the parser's own, and the SAX handlers it wraps.
+struct DepthLimit {
+    max: c_int,
+    exceeded: Option<c_int>,
+    start_element: startElementSAXFunc,
+    start_element_ns: startElementNsSAX2Func,
+}
+
+/// Stop the parser if the element about to start would nest too deep,
+/// which `nodePush` would otherwise do at its own limit.
+unsafe fn enter_element<'a>(ctx: *mut c_void) -> Option<&'a mut DepthLimit> {
+    let ctxt = ctx as *mut xmlParserCtxt;
+    let limit = &mut *((*ctxt)._private as *mut DepthLimit);
+    if (*ctxt).nodeNr >= limit.max {
+        limit.exceeded = Some((*ctxt).nodeNr);
+        xmlStopParser(ctxt);
+        return None;
+    }
+    Some(limit)
+}
+
+unsafe extern "C" fn depth_start_element(
+    ctx: *mut c_void,
+    name: *const xmlChar,
+    atts: *mut *const xmlChar,
+) {
+    if let Some(start) = enter_element(ctx).and_then(|limit| limit.start_element) {
+        start(ctx, name, atts);
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+unsafe extern "C" fn depth_start_element_ns(
+    ctx: *mut c_void,
+    localname: *const xmlChar,
+    prefix: *const xmlChar,
+    uri: *const xmlChar,
+    nb_namespaces: c_int,
+    namespaces: *mut *const xmlChar,
+    nb_attributes: c_int,
+    nb_defaulted: c_int,
+    attributes: *mut *const xmlChar,
+) {
+    if let Some(start) = enter_element(ctx).and_then(|limit| limit.start_element_ns) {
+        start(
+            ctx,
+            localname,
+            prefix,
+            uri,
+            nb_namespaces,
+            namespaces,
+            nb_attributes,
+            nb_defaulted,
+            attributes,
+        );
+    }
+}
+
+/// The state behind the `vctxt` pointer of `load_resource`.
+struct LoaderState {
+    loader: ResourceLoader,
+    ctxt: *mut xmlParserCtxt,
+    panic: Option<Box<dyn Any + Send>>,
+}
+
+/// `xmlResourceLoader` calling a `ResourceLoader`. A panic is stashed in
+/// the state and stops the parser.
+unsafe extern "C" fn load_resource(
+    vctxt: *mut c_void,
+    url: *const c_char,
+    public_id: *const c_char,
+    kind: xmlResourceType,
+    _flags: xmlParserInputFlags,
+    out: *mut *mut xmlParserInput,
+) -> xmlParserErrors {
+    let state = &mut *(vctxt as *mut LoaderState);
+    *out = ptr::null_mut();
+    if state.panic.is_some() {
+        return xmlParserErrors_XML_IO_ENOENT;
+    }
+    let name = CStr::from_ptr(url).to_string_lossy();
+    let public_id = (!public_id.is_null()).then(|| CStr::from_ptr(public_id).to_string_lossy());
+    let loader = state.loader;
+    match panic::catch_unwind(|| loader(&name, public_id.as_deref(), kind)) {
+        Ok(Some(content)) => {
+            // Without `XML_INPUT_BUF_STATIC` the content is copied.
+            *out = xmlNewInputFromMemory(url, content.as_ptr() as *const c_void, content.len(), 0);
+            if (*out).is_null() {
+                xmlParserErrors_XML_ERR_NO_MEMORY
+            } else {
+                xmlParserErrors_XML_ERR_OK
+            }
+        }
+        Ok(None) => xmlParserErrors_XML_IO_ENOENT,
+        Err(payload) => {
+            state.panic = Some(payload);
+            xmlStopParser(state.ctxt);
+            xmlParserErrors_XML_IO_ENOENT
+        }
+    }
+}
+
+/// `xmlStructuredErrorFunc` copying every error into a `Vec<XmlError>`.
+unsafe extern "C" fn collect_error(data: *mut c_void, error: *const xmlError) {
+    let errors = &mut *(data as *mut Vec<XmlError>);
+    errors.extend(XmlError::from_raw(error));
+}
+
+/// Parse with a fresh parser context, which `read` reads with `flags`. If
+/// it builds no document, the error is the last one the parser reported.
+/// With `errors`, every error reported is copied there instead of being
+/// printed.
+unsafe fn parse_with(
+    options: ParserOptions,
+    errors: Option<&mut Vec<XmlError>>,
+    read: impl FnOnce(*mut xmlParserCtxt, c_int) -> *mut xmlDoc,
+) -> Result<XmlDoc, XmlError> {
+    let ctxt = xmlNewParserCtxt();
+    if ctxt.is_null() {
+        return Err(no_memory());
+    }
+    if let Some(errors) = errors {
+        xmlCtxtSetErrorHandler(
+            ctxt,
+            Some(collect_error),
+            errors as *mut Vec<XmlError> as *mut c_void,
+        );
+    }
+
+    // The parser enforces its own limit; only a lower one needs the SAX
+    // handlers wrapped.
+    let max_depth = options.effective_max_depth();
+    let mut limit = None;
+    if max_depth < options.parser_max_depth() {
+        let sax = &mut *(*ctxt).sax;
+        let limit = limit.insert(DepthLimit {
+            max: max_depth as c_int,
+            exceeded: None,
+            start_element: sax.startElement,
+            start_element_ns: sax.startElementNs,
+        });
+        (*ctxt)._private = limit as *mut DepthLimit as *mut c_void;
+        sax.startElement = Some(depth_start_element);
+        sax.startElementNs = Some(depth_start_element_ns);
+    }
+
+    let mut loader = options.loader.map(|loader| LoaderState {
+        loader,
+        ctxt,
+        panic: None,
+    });
+    if let Some(loader) = &mut loader {
+        xmlCtxtSetResourceLoader(
+            ctxt,
+            Some(load_resource),
+            loader as *mut LoaderState as *mut c_void,
+        );
+    }
+
+    let doc = read(ctxt, options.parser_flags());
+    let ret = match limit.and_then(|limit| limit.exceeded) {
+        Some(depth) => {
+            if !doc.is_null() {
+                xmlFreeDoc(doc);
+            }
+            Err(XmlError::new(
+                xmlErrorDomain_XML_FROM_PARSER,
+                xmlParserErrors_XML_ERR_RESOURCE_LIMIT,
+                &format!("Excessive depth in document: {depth}, limit is {max_depth}"),
+            ))
+        }
+        None => XmlDoc::from_raw(doc).ok_or_else(|| {
+            XmlError::from_raw(xmlCtxtGetLastError(ctxt as *mut c_void)).unwrap_or_else(no_memory)
+        }),
+    };
+    xmlFreeParserCtxt(ctxt);
+    if let Some(payload) = loader.and_then(|loader| loader.panic) {
+        drop(ret);
+        panic::resume_unwind(payload);
+    }
+    ret
+}
+
+/// `parse_with` collecting every error and warning, returned with the
+/// document. If no document could be built, they are returned, last the
+/// one that failed the parse.
+unsafe fn parse_collecting(
+    options: ParserOptions,
+    read: impl FnOnce(*mut xmlParserCtxt, c_int) -> *mut xmlDoc,
+) -> Result<(XmlDoc, Vec<XmlError>), Vec<XmlError>> {
+    let mut errors = Vec::new();
+    match parse_with(options, Some(&mut errors), read) {
+        Ok(doc) => Ok((doc, errors)),
+        Err(err) => {
+            // The error that failed the parse was already reported, unless
+            // it came from a depth limit or the allocator.
+            if errors.last() != Some(&err) {
+                errors.push(err);
+            }
+            Err(errors)
+        }
+    }
+}
+
+fn too_large() -> XmlError {
+    XmlError::new(
+        xmlErrorDomain_XML_FROM_PARSER,
+        xmlParserErrors_XML_ERR_RESOURCE_LIMIT,
+        "input too large",
+    )
+}
+
+/// Read `input` with `xmlCtxtReadMemory`. It must fit in a `c_int`.
+unsafe fn read_memory(ctxt: *mut xmlParserCtxt, input: &[u8], flags: c_int) -> *mut xmlDoc {
+    xmlCtxtReadMemory(
+        ctxt,
+        input.as_ptr() as *const c_char,
+        input.len() as c_int,
+        ptr::null(),
+        ptr::null(),
+        flags,
+    )
+}
+
+/// The state behind the `ioctx` pointer of `read_callback`.
+struct ReadSource<R> {
+    reader: R,
+    error: Option<io::Error>,
+    panic: Option<Box<dyn Any + Send>>,
+}
+
+/// Read until `buf` is full or `reader` is at its end. The parser takes a
+/// short read at the start of the document for the whole of what is
+/// there, and misdetects the encoding and XML declaration, so small reads
+/// are reassembled into full chunks here.
+fn fill(reader: &mut impl Read, buf: &mut [u8]) -> io::Result<usize> {
+    let mut filled = 0;
+    while filled < buf.len() {
+        match reader.read(&mut buf[filled..]) {
+            Ok(0) => break,
+            Ok(n) => filled += n,
+            Err(err) if err.kind() == io::ErrorKind::Interrupted => {}
+            Err(err) => return Err(err),
+        }
+    }
+    Ok(filled)
+}
+
+/// `xmlInputReadCallback` filling the parser's buffer from a `Read`. A read
+/// error or panic is stashed in the source and reported to the parser as
+/// -1, which stops it.
+unsafe extern "C" fn read_callback<R: Read>(
+    context: *mut c_void,
+    buffer: *mut c_char,
+    len: c_int,
+) -> c_int {
+    let source = &mut *(context as *mut ReadSource<R>);
+    if len <= 0 {
+        return 0;
+    }
+    if source.error.is_some() || source.panic.is_some() {
+        return -1;
+    }
+    // The buffer may be uninitialized; `Read` needs initialized bytes.
+    ptr::write_bytes(buffer, 0, len as usize);
+    let buf = slice::from_raw_parts_mut(buffer as *mut u8, len as usize);
+    match panic::catch_unwind(AssertUnwindSafe(|| fill(&mut source.reader, buf))) {
+        Ok(Ok(n)) => n as c_int,
+        Ok(Err(err)) => {
+            source.error = Some(err);
+            -1
+        }
+        Err(payload) => {
+            source.panic = Some(payload);
+            -1
+        }
+    }
+}
+
+impl XmlDoc {
+    /// Parse `input` with `xmlCtxtReadMemory` and `options`, or just the
+    /// `XML_PARSE_*` flags. If no document could be built, the error is the
+    /// last one the parser reported.
+    pub fn parse_memory(
+        input: &[u8],
+        options: impl Into<ParserOptions>,
+    ) -> Result<XmlDoc, XmlError> {
+        if c_int::try_from(input.len()).is_err() {
+            return Err(too_large());
+        }
+        unsafe {
+            parse_with(options.into(), None, |ctxt, flags| {
+                read_memory(ctxt, input, flags)
+            })
+        }
+    }
+
+    /// Parse `input` with `xmlCtxtReadDoc`, the context flavour of
+    /// `xmlReadDoc`. The encoding is forced to UTF-8, whatever the XML
+    /// declaration says, since that is what a `str` holds. The text ends at
+    /// the first NUL, as it would for C reading the same bytes.
+    ///
+    /// Every error and warning the parser reports is collected instead of
+    /// printed. They are returned, last the one that failed the parse, if
+    /// no document could be built.
+    pub fn parse_str(
+        input: &str,
+        options: impl Into<ParserOptions>,
+    ) -> Result<XmlDoc, Vec<XmlError>> {
+        let mut text = input
+            .split('\0')
+            .next()
+            .unwrap_or_default()
+            .as_bytes()
+            .to_vec();
+        text.push(0);
+        unsafe {
+            parse_collecting(options.into(), |ctxt, flags| {
+                xmlCtxtReadDoc(ctxt, text.as_ptr(), ptr::null(), c"UTF-8".as_ptr(), flags)
+            })
+            .map(|(doc, _)| doc)
+        }
+    }
+
+    /// Parse `input` like `parse_memory`, with `XML_PARSE_RECOVER` added to
+    /// `options`, and return the best-effort document the parser builds
+    /// past well-formedness errors along with every error and warning it
+    /// reported, in order. Recovery still fails on input it cannot make a
+    /// tree of at all, like an empty document, and on a depth limit; the
+    /// errors are then returned as `parse_str` returns them.
+    pub fn parse_recover(
+        input: &[u8],
+        options: impl Into<ParserOptions>,
+    ) -> Result<(XmlDoc, Vec<XmlError>), Vec<XmlError>> {
+        if c_int::try_from(input.len()).is_err() {
+            return Err(vec![too_large()]);
+        }
+        let mut options = options.into();
+        options.flags |= xmlParserOption_XML_PARSE_RECOVER as c_int;
+        unsafe { parse_collecting(options, |ctxt, flags| read_memory(ctxt, input, flags)) }
+    }
+
+    /// Parse everything `reader` yields, pulled through an input buffer as
+    /// the parser needs it, like `parse_memory` otherwise.
+    ///
+    /// A read error fails the parse with an `XML_FROM_IO` error carrying its
+    /// message, even if recovery would have kept a partial document. A
+    /// panic in `reader` is resumed once the parser has been torn down.
+    pub fn parse_reader<R: Read>(
+        reader: R,
+        options: impl Into<ParserOptions>,
+    ) -> Result<XmlDoc, XmlError> {
+        let mut source = ReadSource {
+            reader,
+            error: None,
+            panic: None,
+        };
+        let ret = unsafe {
+            parse_with(options.into(), None, |ctxt, flags| {
+                xmlCtxtReadIO(
+                    ctxt,
+                    Some(read_callback::<R>),
+                    None,
+                    &mut source as *mut ReadSource<R> as *mut c_void,
+                    ptr::null(),
+                    ptr::null(),
+                    flags,
+                )
+            })
+        };
+        if let Some(payload) = source.panic {
+            panic::resume_unwind(payload);
+        }
+        match source.error {
+            Some(err) => Err(XmlError::new(
+                xmlErrorDomain_XML_FROM_IO,
+                xmlParserErrors_XML_IO_UNKNOWN,
+                &err.to_string(),
+            )),
+            None => ret,
+        }
+    }
+}
+
+/// A document parsed into an `Arena` with `XmlDoc::parse_memory_in`, only
+/// valid as long as the arena. Dropping it frees what was allocated for
+/// the document outside the arena; the rest goes with the arena.
+#[cfg(feature = "rust-xmlmemory")]
+#[derive(Debug)]
+pub struct ArenaDoc<'a> {
+    doc: XmlDoc,
+    _arena: PhantomData<&'a Arena>,
+}
+
+#[cfg(feature = "rust-xmlmemory")]
+impl std::ops::Deref for ArenaDoc<'_> {
+    type Target = XmlDoc;
+
+    fn deref(&self) -> &XmlDoc {
+        &self.doc
+    }
+}
+
+#[cfg(feature = "rust-xmlmemory")]
+impl XmlDoc {
+    /// Parse `input` like `parse_memory`, with everything allocated for
+    /// it, the document and the parser alike, taken from `arena`. The
+    /// last error of the thread is cleared before the arena is left,
+    /// since it would point into it.
+    pub fn parse_memory_in<'a>(
+        arena: &'a Arena,
+        input: &[u8],
+        options: impl Into<ParserOptions>,
+    ) -> Result<ArenaDoc<'a>, XmlError> {
+        let options = options.into();
+        let ret = unsafe {
+            arena.scope(|| {
+                let ret = XmlDoc::parse_memory(input, options);
+                xmlResetLastError();
+                ret
+            })
+        };
+        ret.map(|doc| ArenaDoc {
+            doc,
+            _arena: PhantomData,
+        })
+    }
+}
+
+/// Parse each of `inputs` like `XmlDoc::parse_memory`, in parallel on the
+/// rayon thread pool, and return the results in the order of `inputs`.
+/// Every error and warning is collected for each input, each parse having
+/// its own context, and returned if no document could be built, last the
+/// one that failed the parse, as `XmlDoc::parse_str` does.
+///
+/// Documents parsed on different threads share no state but what
+/// libxml2 keeps global, which `init_chimera` sets up here, before the
+/// threads start, and which the threads module locks from then on.
+#[cfg(feature = "rayon")]
+pub fn parse_many(
+    inputs: &[&[u8]],
+    options: impl Into<ParserOptions>,
+) -> Vec<Result<XmlDoc, Vec<XmlError>>> {
+    use rayon::prelude::*;
+
+    let options = options.into();
+    crate::init_chimera();
+    inputs
+        .par_iter()
+        .map(|input| {
+            if c_int::try_from(input.len()).is_err() {
+                return Err(vec![too_large()]);
+            }
+            unsafe {
+                parse_collecting(options, |ctxt, flags| read_memory(ctxt, input, flags))
+                    .map(|(doc, _)| doc)
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,1388 @@
+//! XInclude processing, as `xmlXIncludeProcessFlags` does it.
+
+use std::ffi::{CStr, CString};
+use std::mem;
+use std::os::raw::{c_char, c_int, c_void};
+use std::ptr;
+
+use super::{XmlDoc, XmlError};
+use crate::static_bindings::{
+    xmlAddDocEntity, xmlAddNextSibling, xmlAddPrevSibling, xmlBufContent, xmlBufUse,
+    xmlBuildRelativeURISafe, xmlBuildURISafe, xmlChar, xmlCharEncCloseFunc,
+    xmlCharEncodingHandlerPtr, xmlCreateIntSubset, xmlCtxtPushInput, xmlCtxtUseOptions,
+    xmlDictFree, xmlDictReference, xmlDoc, xmlDocCopyNode, xmlDocGetRootElement, xmlDocPtr,
+    xmlDtdPtr, xmlElementType_XML_ATTRIBUTE_NODE, xmlElementType_XML_CDATA_SECTION_NODE,
+    xmlElementType_XML_COMMENT_NODE, xmlElementType_XML_DOCUMENT_NODE, xmlElementType_XML_DTD_NODE,
+    xmlElementType_XML_ELEMENT_NODE, xmlElementType_XML_ENTITY_NODE,
+    xmlElementType_XML_ENTITY_REF_NODE, xmlElementType_XML_HTML_DOCUMENT_NODE,
+    xmlElementType_XML_NAMESPACE_DECL, xmlElementType_XML_PI_NODE, xmlElementType_XML_TEXT_NODE,
+    xmlElementType_XML_XINCLUDE_END, xmlElementType_XML_XINCLUDE_START, xmlEntityPtr,
+    xmlEntityType_XML_EXTERNAL_GENERAL_UNPARSED_ENTITY,
+    xmlEntityType_XML_EXTERNAL_PARAMETER_ENTITY, xmlEntityType_XML_INTERNAL_PARAMETER_ENTITY,
+    xmlEntityType_XML_INTERNAL_PREDEFINED_ENTITY, xmlErrorDomain_XML_FROM_XINCLUDE,
+    xmlErrorLevel_XML_ERR_ERROR, xmlErrorLevel_XML_ERR_FATAL, xmlFree, xmlFreeDoc,
+    xmlFreeInputStream, xmlFreeNode, xmlFreeNodeList, xmlFreeParserCtxt, xmlFreeURI,
+    xmlGetDocEntity, xmlGetLineNo, xmlHashScan, xmlNewDocNode, xmlNewDocText, xmlNewDocTextLen,
+    xmlNewParserCtxt, xmlNode, xmlNodeGetAttrValue, xmlNodeGetBaseSafe, xmlNodePtr, xmlNodeSetBase,
+    xmlNs, xmlNsPtr, xmlOpenCharEncodingHandler, xmlParseDocument, xmlParseURISafe,
+    xmlParserCtxtPtr, xmlParserErrors_XML_ERR_ARGUMENT, xmlParserErrors_XML_ERR_INTERNAL_ERROR,
+    xmlParserErrors_XML_ERR_NO_MEMORY, xmlParserErrors_XML_ERR_OK, xmlParserErrors_XML_ERR_SYSTEM,
+    xmlParserErrors_XML_ERR_UNSUPPORTED_ENCODING, xmlParserErrors_XML_IO_ENOENT,
+    xmlParserErrors_XML_IO_UNKNOWN, xmlParserErrors_XML_XINCLUDE_ENTITY_DEF_MISMATCH,
+    xmlParserErrors_XML_XINCLUDE_FALLBACKS_IN_INCLUDE,
+    xmlParserErrors_XML_XINCLUDE_FALLBACK_NOT_IN_INCLUDE, xmlParserErrors_XML_XINCLUDE_FRAGMENT_ID,
+    xmlParserErrors_XML_XINCLUDE_HREF_URI, xmlParserErrors_XML_XINCLUDE_INCLUDE_IN_INCLUDE,
+    xmlParserErrors_XML_XINCLUDE_INVALID_CHAR, xmlParserErrors_XML_XINCLUDE_MULTIPLE_ROOT,
+    xmlParserErrors_XML_XINCLUDE_NO_FALLBACK, xmlParserErrors_XML_XINCLUDE_NO_HREF,
+    xmlParserErrors_XML_XINCLUDE_PARSE_VALUE, xmlParserErrors_XML_XINCLUDE_RECURSION,
+    xmlParserErrors_XML_XINCLUDE_TEXT_DOCUMENT, xmlParserErrors_XML_XINCLUDE_UNKNOWN_ENCODING,
+    xmlParserErrors_XML_XINCLUDE_XPTR_FAILED, xmlParserErrors_XML_XINCLUDE_XPTR_RESULT,
+    xmlParserInputBufferRead, xmlParserInputPtr, xmlParserOption_XML_PARSE_DTDLOAD,
+    xmlParserOption_XML_PARSE_NOBASEFIX, xmlParserOption_XML_PARSE_NOENT,
+    xmlParserOption_XML_PARSE_NONET, xmlParserOption_XML_PARSE_NOXINCNODE,
+    xmlParserOption_XML_PARSE_UNZIP, xmlResourceType, xmlResourceType_XML_RESOURCE_XINCLUDE,
+    xmlResourceType_XML_RESOURCE_XINCLUDE_TEXT, xmlSaveUri, xmlStrdup, xmlUnlinkNode,
+    xmlUnsetNsProp, xmlUnsetProp, xmlXPathContextPtr, xmlXPathFreeContext, xmlXPathFreeObject,
+    xmlXPathNewContext, xmlXPathObjectPtr, xmlXPathObjectType_XPATH_NODESET, xmlXPtrEval,
+};
+
+extern "C" {
+    // Private in parserInternals.c
+    fn xmlLoadResource(
+        ctxt: xmlParserCtxtPtr,
+        url: *const c_char,
+        public_id: *const c_char,
+        kind: xmlResourceType,
+    ) -> xmlParserInputPtr;
+
+    // Private copy from tree.c, which looks namespaces up from the parent
+    // the copy is going to be linked under.
+    fn xmlStaticCopyNode(
+        node: *mut xmlNode,
+        doc: *mut xmlDoc,
+        parent: *mut xmlNode,
+        extended: c_int,
+    ) -> *mut xmlNode;
+}
+
+const XINCLUDE_NS: &[u8] = b"http://www.w3.org/2003/XInclude";
+const XINCLUDE_OLD_NS: &[u8] = b"http://www.w3.org/2001/XInclude";
+const XML_XML_NAMESPACE: &CStr = c"http://www.w3.org/XML/1998/namespace";
+
+/// How deep includes may nest, `XINCLUDE_MAX_DEPTH`.
+const MAX_DEPTH: usize = 40;
+
+/// Longest URI an `href` or base may be, from private/parser.h.
+const XML_MAX_URI_LENGTH: usize = 2000;
+
+/// Expand the XInclude elements of `doc` in place, as
+/// `xmlXIncludeProcessFlags` does, and return how many were expanded.
+///
+/// `href`s are resolved against the base URI of their element with the
+/// uri module, included documents are parsed with `flags` and
+/// `XML_PARSE_DTDLOAD`, and `xpointer` fragments are evaluated with the
+/// xpointer module. With `XML_PARSE_NONET` nothing is fetched from the
+/// network, not even for `parse="text"` includes, which C loads
+/// regardless of the flag. `XML_PARSE_NOXINCNODE` leaves out the `XML_XINCLUDE_START` and
+/// `XML_XINCLUDE_END` markers around included content, and
+/// `XML_PARSE_NOBASEFIX` the `xml:base` attributes that keep relative
+/// references of included elements pointing where they did.
+///
+/// If any include fails and has no fallback, or any XInclude element is
+/// misused, every error is returned, with the document expanded as far
+/// as it could be. A document without a root element has nothing to
+/// expand.
+pub fn process_xincludes(doc: &mut XmlDoc, flags: c_int) -> Result<usize, Vec<XmlError>> {
+    unsafe {
+        let root = xmlDocGetRootElement(doc.as_ptr());
+        if root.is_null() {
+            return Ok(0);
+        }
+        let mut processor = Processor {
+            doc: doc.as_ptr(),
+            flags,
+            includes: Vec::new(),
+            docs: Vec::new(),
+            texts: Vec::new(),
+            xpath: ptr::null_mut(),
+            errors: Vec::new(),
+            fatal: false,
+            legacy: false,
+            depth: 0,
+        };
+        let count = processor.process(root);
+        if processor.errors.is_empty() {
+            Ok(count)
+        } else {
+            Err(mem::take(&mut processor.errors))
+        }
+    }
+}
+
+/// The bytes of a C string, `None` for a null pointer.
+unsafe fn bytes<'a>(s: *const xmlChar) -> Option<&'a [u8]> {
+    (!s.is_null()).then(|| CStr::from_ptr(s as *const c_char).to_bytes())
+}
+
+/// Copy a string allocated by libxml2 and free it.
+unsafe fn take(s: *mut xmlChar) -> Option<CString> {
+    if s.is_null() {
+        return None;
+    }
+    let ret = CStr::from_ptr(s as *const c_char).to_owned();
+    xmlFree.unwrap()(s as *mut c_void);
+    Some(ret)
+}
+
+fn xml(s: &CStr) -> *const xmlChar {
+    s.as_ptr() as *const xmlChar
+}
+
+unsafe fn is_xinclude_ns(ns: xmlNsPtr) -> bool {
+    !ns.is_null() && matches!(bytes((*ns).href), Some(XINCLUDE_NS | XINCLUDE_OLD_NS))
+}
+
+/// Whether `node` is the element `name` in either XInclude namespace.
+unsafe fn is_xinclude(node: xmlNodePtr, name: &[u8]) -> bool {
+    (*node).type_ == xmlElementType_XML_ELEMENT_NODE
+        && is_xinclude_ns((*node).ns)
+        && bytes((*node).name) == Some(name)
+}
+
+/// `xmlIsCatastrophicError` for a fatal error: running out of memory,
+/// internal and I/O errors.
+fn is_catastrophic(code: c_int) -> bool {
+    matches!(
+        code as u32,
+        xmlParserErrors_XML_ERR_NO_MEMORY
+            | xmlParserErrors_XML_ERR_SYSTEM
+            | xmlParserErrors_XML_ERR_ARGUMENT
+            | xmlParserErrors_XML_ERR_INTERNAL_ERROR
+    ) || (1500..=1599).contains(&code)
+}
+
+/// Whether `c` may appear in XML text, the `IS_CHAR` test.
+fn is_xml_char(c: char) -> bool {
+    matches!(c, '\t' | '\n' | '\r' | '\u{20}'..='\u{D7FF}' | '\u{E000}'..='\u{FFFD}')
+        || c >= '\u{10000}'
+}
+
+/// Link `copy` after `last`, or as the first child of `parent` if there
+/// is no `last`, and make it the new `last`.
+unsafe fn link(
+    copy: xmlNodePtr,
+    parent: xmlNodePtr,
+    last: &mut xmlNodePtr,
+    result: &mut xmlNodePtr,
+) {
+    if result.is_null() {
+        *result = copy;
+    }
+    if !last.is_null() {
+        (**last).next = copy;
+        (*copy).prev = *last;
+    } else if !parent.is_null() {
+        (*parent).children = copy;
+    }
+    *last = copy;
+}
+
+/// An include element and what it expands to, `xmlXIncludeRef`.
+struct Include {
+    /// The resolved URL, empty for the including document itself.
+    uri: CString,
+    fragment: Option<CString>,
+    /// The base URI of the element, which included elements get as
+    /// `xml:base`. `None` with `XML_PARSE_NOBASEFIX`.
+    base: Option<CString>,
+    elem: xmlNodePtr,
+    /// The nodes to put in place of `elem`, owned until then.
+    inc: xmlNodePtr,
+    xml: bool,
+    /// Whether `inc` came from the `fallback` child.
+    fallback: bool,
+    /// Set while loading, to detect inclusion loops.
+    expanding: bool,
+    /// Whether `elem` is in the tree being processed, rather than one
+    /// only expanded to be copied.
+    replace: bool,
+}
+
+impl Drop for Include {
+    fn drop(&mut self) {
+        if !self.inc.is_null() {
+            unsafe { xmlFreeNodeList(self.inc) };
+        }
+    }
+}
+
+/// A document loaded for inclusion, null if it could not be parsed.
+struct Loaded {
+    url: CString,
+    doc: xmlDocPtr,
+    /// Set while its own includes are expanded, to detect loops.
+    expanding: bool,
+}
+
+/// The state of one `process_xincludes` call, `xmlXIncludeCtxt`.
+struct Processor {
+    /// The document whose includes are being expanded: the one passed
+    /// in, or a loaded document while it is expanded itself.
+    doc: xmlDocPtr,
+    flags: c_int,
+    includes: Vec<Include>,
+    docs: Vec<Loaded>,
+    /// Text resources already read, by URL.
+    texts: Vec<(CString, CString)>,
+    xpath: xmlXPathContextPtr,
+    errors: Vec<XmlError>,
+    /// Set by running out of memory, I/O and internal errors, after which
+    /// nothing more is reported or expanded.
+    fatal: bool,
+    /// Set once the 2001 namespace is seen, allowing fragment identifiers
+    /// in `href`.
+    legacy: bool,
+    depth: usize,
+}
+
+impl Drop for Processor {
+    fn drop(&mut self) {
+        unsafe {
+            for loaded in &self.docs {
+                if !loaded.doc.is_null() {
+                    xmlFreeDoc(loaded.doc);
+                }
+            }
+            if !self.xpath.is_null() {
+                xmlXPathFreeContext(self.xpath);
+            }
+        }
+    }
+}
+
+impl Processor {
+    fn no_memory(&mut self) {
+        self.fatal = true;
+        self.errors.push(XmlError::new(
+            xmlErrorDomain_XML_FROM_XINCLUDE,
+            xmlParserErrors_XML_ERR_NO_MEMORY,
+            "out of memory",
+        ));
+    }
+
+    /// Record an error about `node`, `xmlXIncludeErr`. Nothing more is
+    /// recorded after a fatal error.
+    unsafe fn error(&mut self, node: xmlNodePtr, code: c_int, message: String) {
+        if code == xmlParserErrors_XML_ERR_NO_MEMORY as c_int {
+            return self.no_memory();
+        }
+        if self.fatal {
+            return;
+        }
+        let mut err = XmlError::new(xmlErrorDomain_XML_FROM_XINCLUDE, code as u32, &message);
+        err.level = xmlErrorLevel_XML_ERR_ERROR;
+        if !node.is_null() {
+            if !(*node).doc.is_null() {
+                err.file = bytes((*(*node).doc).URL).map(|url| String::from_utf8_lossy(url).into());
+            }
+            if (*node).type_ == xmlElementType_XML_ELEMENT_NODE {
+                err.line = xmlGetLineNo(node) as c_int;
+            }
+        }
+        self.errors.push(err);
+        self.fatal = is_catastrophic(code);
+    }
+
+    /// The attribute `name` of an include element, in the XInclude
+    /// namespace or, more usually, in none.
+    unsafe fn attribute(&mut self, elem: xmlNodePtr, name: &CStr) -> Option<CString> {
+        let mut namespaces = vec![Some(XINCLUDE_NS)];
+        if self.legacy {
+            namespaces.push(Some(XINCLUDE_OLD_NS));
+        }
+        namespaces.push(None);
+        for ns in namespaces {
+            let ns = ns.map(|ns| CString::new(ns).unwrap());
+            let mut value = ptr::null_mut();
+            let ns_ptr = ns.as_deref().map_or(ptr::null(), xml);
+            if xmlNodeGetAttrValue(elem, xml(name), ns_ptr, &mut value) < 0 {
+                self.no_memory();
+            }
+            if let Some(value) = take(value) {
+                return Some(value);
+            }
+        }
+        None
+    }
+
+    /// Check `node` for XInclude elements, `xmlXIncludeTestNode`: true
+    /// for an include to expand. Includes inside includes, several
+    /// fallbacks and fallbacks outside includes are errors.
+    unsafe fn test_node(&mut self, node: xmlNodePtr) -> bool {
+        if (*node).type_ != xmlElementType_XML_ELEMENT_NODE || !is_xinclude_ns((*node).ns) {
+            return false;
+        }
+        if bytes((*(*node).ns).href) == Some(XINCLUDE_OLD_NS) {
+            self.legacy = true;
+        }
+        if is_xinclude(node, b"include") {
+            let mut fallbacks = 0;
+            let mut child = (*node).children;
+            while !child.is_null() {
+                if is_xinclude(child, b"include") {
+                    self.error(
+                        node,
+                        xmlParserErrors_XML_XINCLUDE_INCLUDE_IN_INCLUDE as c_int,
+                        "include has an 'include' child".into(),
+                    );
+                    return false;
+                }
+                if is_xinclude(child, b"fallback") {
+                    fallbacks += 1;
+                }
+                child = (*child).next;
+            }
+            if fallbacks > 1 {
+                self.error(
+                    node,
+                    xmlParserErrors_XML_XINCLUDE_FALLBACKS_IN_INCLUDE as c_int,
+                    "include has multiple fallback children".into(),
+                );
+                return false;
+            }
+            return true;
+        }
+        if is_xinclude(node, b"fallback") {
+            let parent = (*node).parent;
+            if parent.is_null() || !is_xinclude(parent, b"include") {
+                self.error(
+                    node,
+                    xmlParserErrors_XML_XINCLUDE_FALLBACK_NOT_IN_INCLUDE as c_int,
+                    "fallback is not the child of an 'include'".into(),
+                );
+            }
+        }
+        false
+    }
+
+    /// Read the attributes of an include element and resolve its URL,
+    /// `xmlXIncludeAddNode`, adding it to `includes`.
+    unsafe fn add_include(&mut self, elem: xmlNodePtr) -> Option<usize> {
+        let mut fragment = self.attribute(elem, c"xpointer");
+        let href = match self.attribute(elem, c"href") {
+            Some(href) if href.to_bytes().len() > XML_MAX_URI_LENGTH => {
+                self.error(
+                    elem,
+                    xmlParserErrors_XML_XINCLUDE_HREF_URI as c_int,
+                    "URI too long".into(),
+                );
+                return None;
+            }
+            Some(href) => href,
+            None if fragment.is_none() => {
+                self.error(
+                    elem,
+                    xmlParserErrors_XML_XINCLUDE_NO_HREF as c_int,
+                    "href or xpointer must be present".into(),
+                );
+                return None;
+            }
+            None => CString::default(),
+        };
+        let is_xml = match self.attribute(elem, c"parse") {
+            None => true,
+            Some(parse) => match parse.to_bytes() {
+                b"xml" => true,
+                b"text" => false,
+                _ => {
+                    let message = format!("invalid value {} for 'parse'", parse.to_string_lossy());
+                    self.error(
+                        elem,
+                        xmlParserErrors_XML_XINCLUDE_PARSE_VALUE as c_int,
+                        message,
+                    );
+                    return None;
+                }
+            },
+        };
+
+        // Take the fragment identifier off the URI.
+        let mut uri = ptr::null_mut();
+        let res = xmlParseURISafe(href.as_ptr(), &mut uri);
+        if uri.is_null() {
+            if res < 0 {
+                self.no_memory();
+            } else {
+                let message = format!("invalid value href {}", href.to_string_lossy());
+                self.error(
+                    elem,
+                    xmlParserErrors_XML_XINCLUDE_HREF_URI as c_int,
+                    message,
+                );
+            }
+            return None;
+        }
+        if !(*uri).fragment.is_null() {
+            let uri_fragment = take((*uri).fragment as *mut xmlChar);
+            (*uri).fragment = ptr::null_mut();
+            if !self.legacy {
+                xmlFreeURI(uri);
+                let message = format!(
+                    "Invalid fragment identifier in URI {} use the xpointer attribute",
+                    href.to_string_lossy()
+                );
+                self.error(
+                    elem,
+                    xmlParserErrors_XML_XINCLUDE_FRAGMENT_ID as c_int,
+                    message,
+                );
+                return None;
+            }
+            if fragment.is_none() {
+                fragment = uri_fragment;
+            }
+        }
+        let href = take(xmlSaveUri(uri));
+        xmlFreeURI(uri);
+        let Some(mut href) = href else {
+            self.no_memory();
+            return None;
+        };
+
+        let mut base = ptr::null_mut();
+        if xmlNodeGetBaseSafe(self.doc, elem, &mut base) < 0 {
+            self.no_memory();
+            return None;
+        }
+        let base = take(base);
+        let local = if href.is_empty() {
+            true
+        } else {
+            let mut resolved = ptr::null_mut();
+            let base_ptr = base.as_deref().map_or(ptr::null(), xml);
+            if xmlBuildURISafe(xml(&href), base_ptr, &mut resolved) < 0 {
+                self.no_memory();
+                return None;
+            }
+            let Some(resolved) = take(resolved) else {
+                self.error(
+                    elem,
+                    xmlParserErrors_XML_XINCLUDE_HREF_URI as c_int,
+                    "failed build URL".into(),
+                );
+                return None;
+            };
+            href = resolved;
+            bytes((*self.doc).URL) == Some(href.to_bytes())
+        };
+        if local && is_xml && fragment.as_ref().is_none_or(|f| f.is_empty()) {
+            let message = format!(
+                "detected a local recursion with no xpointer in {}",
+                href.to_string_lossy()
+            );
+            self.error(
+                elem,
+                xmlParserErrors_XML_XINCLUDE_RECURSION as c_int,
+                message,
+            );
+            return None;
+        }
+
+        let nobasefix = xmlParserOption_XML_PARSE_NOBASEFIX as c_int;
+        let fixup = self.flags & nobasefix == 0
+            && !(*elem).doc.is_null()
+            && (*(*elem).doc).parseFlags & nobasefix == 0;
+        self.includes.push(Include {
+            uri: href,
+            fragment,
+            base: fixup.then(|| base.unwrap_or_default()),
+            elem,
+            inc: ptr::null_mut(),
+            xml: is_xml,
+            fallback: false,
+            expanding: false,
+            replace: false,
+        });
+        Some(self.includes.len() - 1)
+    }
+
+    /// Load what the include element `elem` stands for, unless that was
+    /// done already, `xmlXIncludeExpandNode`.
+    unsafe fn expand(&mut self, elem: xmlNodePtr) -> Option<usize> {
+        if self.fatal {
+            return None;
+        }
+        if self.depth >= MAX_DEPTH {
+            self.error(
+                elem,
+                xmlParserErrors_XML_XINCLUDE_RECURSION as c_int,
+                "maximum recursion depth exceeded".into(),
+            );
+            self.fatal = true;
+            return None;
+        }
+        if let Some(i) = self
+            .includes
+            .iter()
+            .position(|include| include.elem == elem)
+        {
+            if self.includes[i].expanding {
+                self.error(
+                    elem,
+                    xmlParserErrors_XML_XINCLUDE_RECURSION as c_int,
+                    "inclusion loop detected".into(),
+                );
+                return None;
+            }
+            return Some(i);
+        }
+        let i = self.add_include(elem)?;
+        self.includes[i].expanding = true;
+        self.depth += 1;
+        self.load(i);
+        self.depth -= 1;
+        self.includes[i].expanding = false;
+        Some(i)
+    }
+
+    /// Load the included nodes or text, or the fallback if that fails,
+    /// `xmlXIncludeLoadNode`.
+    unsafe fn load(&mut self, i: usize) {
+        let mut loaded = if self.includes[i].xml {
+            self.load_doc(i)
+        } else {
+            self.load_text(i)
+        };
+        if !loaded {
+            let mut child = (*self.includes[i].elem).children;
+            while !child.is_null() {
+                if is_xinclude(child, b"fallback") {
+                    loaded = self.load_fallback(child, i);
+                    break;
+                }
+                child = (*child).next;
+            }
+        }
+        if !loaded {
+            let message = format!(
+                "could not load {}, and no fallback was found",
+                self.includes[i].uri.to_string_lossy()
+            );
+            self.error(
+                self.includes[i].elem,
+                xmlParserErrors_XML_XINCLUDE_NO_FALLBACK as c_int,
+                message,
+            );
+        }
+    }
+
+    /// Parse the document at `url`, `xmlXIncludeParseFile`, loaded as an
+    /// `XML_RESOURCE_XINCLUDE` and sharing the dictionary of the including
+    /// one. Documents that are not well
+    /// formed are dropped even with `XML_PARSE_RECOVER`.
+    unsafe fn parse_file(&mut self, url: &CStr, flags: c_int) -> xmlDocPtr {
+        let pctxt = xmlNewParserCtxt();
+        if pctxt.is_null() {
+            self.no_memory();
+            return ptr::null_mut();
+        }
+        let dict = (*self.doc).dict;
+        if !dict.is_null() {
+            if !(*pctxt).dict.is_null() {
+                xmlDictFree((*pctxt).dict);
+            }
+            (*pctxt).dict = dict;
+            xmlDictReference(dict);
+        }
+        // DTDLOAD makes sure IDs declared in an external subset are found.
+        xmlCtxtUseOptions(pctxt, flags | xmlParserOption_XML_PARSE_DTDLOAD as c_int);
+        let mut doc = ptr::null_mut();
+        let input = xmlLoadResource(
+            pctxt,
+            url.as_ptr(),
+            ptr::null(),
+            xmlResourceType_XML_RESOURCE_XINCLUDE,
+        );
+        if !input.is_null() {
+            if xmlCtxtPushInput(pctxt, input) < 0 {
+                xmlFreeInputStream(input);
+            } else {
+                xmlParseDocument(pctxt);
+                doc = (*pctxt).myDoc;
+                if !doc.is_null() && (*pctxt).wellFormed == 0 {
+                    xmlFreeDoc(doc);
+                    doc = ptr::null_mut();
+                }
+            }
+        }
+        let last = &(*pctxt).lastError;
+        if last.level == xmlErrorLevel_XML_ERR_FATAL && is_catastrophic(last.code) {
+            self.error(ptr::null_mut(), (*pctxt).errNo, "parser error".into());
+        }
+        xmlFreeParserCtxt(pctxt);
+        doc
+    }
+
+    /// Load the document or fragment an XML include refers to,
+    /// `xmlXIncludeLoadDoc`. Documents are parsed and have their own
+    /// includes expanded once.
+    unsafe fn load_doc(&mut self, i: usize) -> bool {
+        let url = self.includes[i].uri.clone();
+        let fragment = self.includes[i].fragment.clone();
+        let base = self.includes[i].base.clone();
+        let elem = self.includes[i].elem;
+
+        let doc = if url.is_empty()
+            || url.to_bytes()[0] == b'#'
+            || bytes((*self.doc).URL) == Some(url.to_bytes())
+        {
+            self.doc
+        } else if let Some(cached) = self.docs.iter().position(|loaded| loaded.url == url) {
+            if self.docs[cached].expanding {
+                self.error(
+                    elem,
+                    xmlParserErrors_XML_XINCLUDE_RECURSION as c_int,
+                    "inclusion loop detected".into(),
+                );
+                return false;
+            }
+            if self.docs[cached].doc.is_null() {
+                return false;
+            }
+            self.docs[cached].doc
+        } else {
+            // Entities must be resolved for an XPointer to see through them.
+            let mut flags = self.flags;
+            if fragment.is_some() {
+                flags |= xmlParserOption_XML_PARSE_NOENT as c_int;
+            }
+            let doc = self.parse_file(&url, flags);
+            self.docs.push(Loaded {
+                url,
+                doc,
+                expanding: false,
+            });
+            if doc.is_null() {
+                return false;
+            }
+            let cached = self.docs.len() - 1;
+            self.merge_entities(doc);
+            self.docs[cached].expanding = true;
+            self.process_doc(doc);
+            self.docs[cached].expanding = false;
+            doc
+        };
+
+        let Some(fragment) = fragment else {
+            let root = xmlDocGetRootElement(doc);
+            if root.is_null() {
+                self.error(
+                    elem,
+                    xmlParserErrors_XML_ERR_INTERNAL_ERROR as c_int,
+                    "document without root".into(),
+                );
+                return false;
+            }
+            let inc = xmlDocCopyNode(root, self.doc, 1);
+            if inc.is_null() {
+                self.no_memory();
+                return false;
+            }
+            self.includes[i].inc = inc;
+            if let Some(base) = &base {
+                self.fix_base(root, inc, base);
+            }
+            return true;
+        };
+
+        if self.xpath.is_null() {
+            self.xpath = xmlXPathNewContext(doc);
+            if self.xpath.is_null() {
+                self.no_memory();
+                return false;
+            }
+        } else {
+            (*self.xpath).doc = doc;
+        }
+        let xptr = xmlXPtrEval(xml(&fragment), self.xpath);
+        let code = (*self.xpath).lastError.code;
+        if code != xmlParserErrors_XML_ERR_OK as c_int {
+            if !xptr.is_null() {
+                xmlXPathFreeObject(xptr);
+            }
+            if code == xmlParserErrors_XML_ERR_NO_MEMORY as c_int {
+                self.no_memory();
+            } else {
+                let message = format!(
+                    "XPointer evaluation failed: #{}",
+                    fragment.to_string_lossy()
+                );
+                self.error(
+                    elem,
+                    xmlParserErrors_XML_XINCLUDE_XPTR_FAILED as c_int,
+                    message,
+                );
+            }
+            return false;
+        }
+        if xptr.is_null() {
+            return true;
+        }
+        if (*xptr).type_ != xmlXPathObjectType_XPATH_NODESET {
+            let message = format!("XPointer is not a range: #{}", fragment.to_string_lossy());
+            self.error(
+                elem,
+                xmlParserErrors_XML_XINCLUDE_XPTR_RESULT as c_int,
+                message,
+            );
+            xmlXPathFreeObject(xptr);
+            return false;
+        }
+        // A selection that can't be included is reported, but leaves the
+        // include empty rather than failing it.
+        if let Some(what) = Self::unincludable(xptr) {
+            let message = format!("XPointer selects {what}: #{}", fragment.to_string_lossy());
+            self.error(
+                elem,
+                xmlParserErrors_XML_XINCLUDE_XPTR_RESULT as c_int,
+                message,
+            );
+        } else {
+            self.includes[i].inc = self.copy_xpointer(xptr, base.as_deref());
+        }
+        xmlXPathFreeObject(xptr);
+        true
+    }
+
+    /// What in the node-set of `xptr` can't be included, if anything.
+    unsafe fn unincludable(xptr: xmlXPathObjectPtr) -> Option<&'static str> {
+        let set = (*xptr).nodesetval;
+        if set.is_null() {
+            return None;
+        }
+        for k in 0..(*set).nodeNr.max(0) as usize {
+            let node = *(*set).nodeTab.add(k);
+            if node.is_null() {
+                continue;
+            }
+            match (*node).type_ {
+                xmlElementType_XML_ELEMENT_NODE
+                | xmlElementType_XML_TEXT_NODE
+                | xmlElementType_XML_CDATA_SECTION_NODE
+                | xmlElementType_XML_ENTITY_REF_NODE
+                | xmlElementType_XML_ENTITY_NODE
+                | xmlElementType_XML_PI_NODE
+                | xmlElementType_XML_COMMENT_NODE
+                | xmlElementType_XML_DOCUMENT_NODE
+                | xmlElementType_XML_HTML_DOCUMENT_NODE => {}
+                xmlElementType_XML_ATTRIBUTE_NODE => return Some("an attribute"),
+                xmlElementType_XML_NAMESPACE_DECL => return Some("a namespace"),
+                _ => return Some("unexpected nodes"),
+            }
+        }
+        None
+    }
+
+    /// Copy the nodes an XPointer selected, `xmlXIncludeCopyXPointer`:
+    /// documents as their root element, with their includes expanded.
+    unsafe fn copy_xpointer(&mut self, xptr: xmlXPathObjectPtr, base: Option<&CStr>) -> xmlNodePtr {
+        let set = (*xptr).nodesetval;
+        if set.is_null() {
+            return ptr::null_mut();
+        }
+        let mut list: xmlNodePtr = ptr::null_mut();
+        let mut last: xmlNodePtr = ptr::null_mut();
+        for k in 0..(*set).nodeNr.max(0) as usize {
+            let mut node = *(*set).nodeTab.add(k);
+            if node.is_null() {
+                continue;
+            }
+            match (*node).type_ {
+                xmlElementType_XML_DOCUMENT_NODE | xmlElementType_XML_HTML_DOCUMENT_NODE => {
+                    let root = xmlDocGetRootElement(node as xmlDocPtr);
+                    if root.is_null() {
+                        self.error(
+                            node,
+                            xmlParserErrors_XML_ERR_INTERNAL_ERROR as c_int,
+                            "document without root".into(),
+                        );
+                        continue;
+                    }
+                    node = root;
+                }
+                xmlElementType_XML_TEXT_NODE
+                | xmlElementType_XML_CDATA_SECTION_NODE
+                | xmlElementType_XML_ELEMENT_NODE
+                | xmlElementType_XML_PI_NODE
+                | xmlElementType_XML_COMMENT_NODE => {}
+                _ => {
+                    self.error(
+                        node,
+                        xmlParserErrors_XML_XINCLUDE_XPTR_RESULT as c_int,
+                        "invalid node type in XPtr result".into(),
+                    );
+                    continue;
+                }
+            }
+            let copy = self.copy_node(node, false, base);
+            if copy.is_null() {
+                if !list.is_null() {
+                    xmlFreeNodeList(list);
+                }
+                return ptr::null_mut();
+            }
+            if last.is_null() {
+                list = copy;
+            } else {
+                while !(*last).next.is_null() {
+                    last = (*last).next;
+                }
+                (*copy).prev = last;
+                (*last).next = copy;
+            }
+            last = copy;
+        }
+        list
+    }
+
+    /// Copy `elem`, or its children, into the processed document,
+    /// expanding the includes in it, `xmlXIncludeCopyNode`. Copies at the
+    /// top get the `xml:base` fixup for `target_base`. Null if there is
+    /// nothing to copy or an include in it failed.
+    unsafe fn copy_node(
+        &mut self,
+        elem: xmlNodePtr,
+        copy_children: bool,
+        target_base: Option<&CStr>,
+    ) -> xmlNodePtr {
+        let mut result: xmlNodePtr = ptr::null_mut();
+        let mut insert_parent: xmlNodePtr = ptr::null_mut();
+        let mut insert_last: xmlNodePtr = ptr::null_mut();
+        let mut depth = 0;
+        let mut cur = if copy_children {
+            (*elem).children
+        } else {
+            elem
+        };
+        if cur.is_null() {
+            return ptr::null_mut();
+        }
+
+        loop {
+            let mut recurse = false;
+            let kind = (*cur).type_;
+            if kind == xmlElementType_XML_DOCUMENT_NODE || kind == xmlElementType_XML_DTD_NODE {
+            } else if is_xinclude(cur, b"include") {
+                let Some(i) = self.expand(cur) else {
+                    if !result.is_null() {
+                        xmlFreeNodeList(result);
+                    }
+                    return ptr::null_mut();
+                };
+                let mut item = self.includes[i].inc;
+                while !item.is_null() {
+                    let copy = xmlStaticCopyNode(item, self.doc, insert_parent, 1);
+                    if copy.is_null() {
+                        self.no_memory();
+                        if !result.is_null() {
+                            xmlFreeNodeList(result);
+                        }
+                        return ptr::null_mut();
+                    }
+                    link(copy, insert_parent, &mut insert_last, &mut result);
+                    if let (0, Some(base)) = (depth, target_base) {
+                        self.fix_base(item, copy, base);
+                    }
+                    item = (*item).next;
+                }
+            } else {
+                let copy = xmlStaticCopyNode(cur, self.doc, insert_parent, 2);
+                if copy.is_null() {
+                    self.no_memory();
+                    if !result.is_null() {
+                        xmlFreeNodeList(result);
+                    }
+                    return ptr::null_mut();
+                }
+                link(copy, insert_parent, &mut insert_last, &mut result);
+                if let (0, Some(base)) = (depth, target_base) {
+                    self.fix_base(cur, copy, base);
+                }
+                recurse = kind != xmlElementType_XML_ENTITY_REF_NODE && !(*cur).children.is_null();
+            }
+
+            if recurse {
+                cur = (*cur).children;
+                insert_parent = insert_last;
+                insert_last = ptr::null_mut();
+                depth += 1;
+                continue;
+            }
+            if cur == elem {
+                return result;
+            }
+            while (*cur).next.is_null() {
+                if !insert_parent.is_null() {
+                    (*insert_parent).last = insert_last;
+                }
+                cur = (*cur).parent;
+                if cur == elem {
+                    return result;
+                }
+                insert_last = insert_parent;
+                insert_parent = (*insert_parent).parent;
+                depth -= 1;
+            }
+            cur = (*cur).next;
+        }
+    }
+
+    /// Give `copy` the `xml:base` that keeps it resolving relative
+    /// references as `cur` did, now that it is included where the base is
+    /// `target_base`, `xmlXIncludeBaseFixup`. A base without a `/` is
+    /// dropped, as is one equal to the target's.
+    unsafe fn fix_base(&mut self, cur: xmlNodePtr, copy: xmlNodePtr, target_base: &CStr) {
+        if (*cur).type_ != xmlElementType_XML_ELEMENT_NODE {
+            return;
+        }
+        let mut base = ptr::null_mut();
+        if xmlNodeGetBaseSafe((*cur).doc, cur, &mut base) < 0 {
+            self.no_memory();
+        }
+        if let Some(base) = take(base).filter(|base| base.as_c_str() != target_base) {
+            let relative = if base.to_bytes().len() > XML_MAX_URI_LENGTH
+                || target_base.to_bytes().len() > XML_MAX_URI_LENGTH
+            {
+                Some(base.clone())
+            } else {
+                let mut relative = ptr::null_mut();
+                if xmlBuildRelativeURISafe(xml(&base), xml(target_base), &mut relative) < 0 {
+                    self.no_memory();
+                    return;
+                }
+                take(relative)
+            };
+            let Some(relative) = relative else {
+                let message = format!("Building relative URI failed: {}", base.to_string_lossy());
+                self.error(cur, xmlParserErrors_XML_XINCLUDE_HREF_URI as c_int, message);
+                return;
+            };
+            if relative.to_bytes().contains(&b'/') {
+                if xmlNodeSetBase(copy, xml(&relative)) < 0 {
+                    self.no_memory();
+                }
+                return;
+            }
+        }
+        let mut ns: xmlNs = mem::zeroed();
+        ns.href = xml(XML_XML_NAMESPACE);
+        xmlUnsetNsProp(copy, &mut ns, xml(c"base"));
+    }
+
+    /// Declare the general entities of `from` in the processed document,
+    /// `xmlXIncludeMergeEntities`, so references in included nodes still
+    /// resolve. Only conflicting unparsed entities are errors.
+    unsafe fn merge_entities(&mut self, from: xmlDocPtr) {
+        if (*from).intSubset.is_null() {
+            return;
+        }
+        let mut target = (*self.doc).intSubset;
+        if target.is_null() {
+            let root = xmlDocGetRootElement(self.doc);
+            if root.is_null() {
+                return;
+            }
+            target = xmlCreateIntSubset(self.doc, (*root).name, ptr::null(), ptr::null());
+            if target.is_null() {
+                self.no_memory();
+                return;
+            }
+        }
+        let data = self as *mut Processor as *mut c_void;
+        let source = (*from).intSubset;
+        if !(*source).entities.is_null() {
+            xmlHashScan((*source).entities as _, Some(merge_entity), data);
+        }
+        // The external subset is skipped if it is the processed document's.
+        let source: xmlDtdPtr = (*from).extSubset;
+        if !source.is_null()
+            && !(*source).entities.is_null()
+            && bytes((*target).ExternalID) != bytes((*source).ExternalID)
+            && bytes((*target).SystemID) != bytes((*source).SystemID)
+        {
+            xmlHashScan((*source).entities as _, Some(merge_entity), data);
+        }
+    }
+
+    /// Read the text resource a text include refers to,
+    /// `xmlXIncludeLoadTxt`, decoded from its `encoding` attribute, UTF-8
+    /// by default. Text that isn't all XML characters fails the include.
+    unsafe fn load_text(&mut self, i: usize) -> bool {
+        let url = self.includes[i].uri.clone();
+        let elem = self.includes[i].elem;
+        if url.is_empty() {
+            self.error(
+                elem,
+                xmlParserErrors_XML_XINCLUDE_TEXT_DOCUMENT as c_int,
+                "text serialization of document not available".into(),
+            );
+            return false;
+        }
+        if let Some((_, text)) = self.texts.iter().find(|(cached, _)| *cached == url) {
+            let node = xmlNewDocText(self.doc, xml(text));
+            if node.is_null() {
+                self.no_memory();
+            }
+            self.includes[i].inc = node;
+            return true;
+        }
+
+        let mut handler: xmlCharEncodingHandlerPtr = ptr::null_mut();
+        if let Some(encoding) = self.attribute(elem, c"encoding") {
+            let code = xmlOpenCharEncodingHandler(encoding.as_ptr(), 0, &mut handler);
+            if code == xmlParserErrors_XML_ERR_NO_MEMORY {
+                self.no_memory();
+            } else if code == xmlParserErrors_XML_ERR_UNSUPPORTED_ENCODING {
+                let message = format!("encoding {} not supported", encoding.to_string_lossy());
+                self.error(
+                    elem,
+                    xmlParserErrors_XML_XINCLUDE_UNKNOWN_ENCODING as c_int,
+                    message,
+                );
+                return false;
+            } else if code != xmlParserErrors_XML_ERR_OK {
+                self.error(
+                    elem,
+                    code as c_int,
+                    "unexpected error from iconv or ICU".into(),
+                );
+                return false;
+            }
+        }
+
+        let text = self.read_text(&url, elem, handler);
+        let Some(text) = text else {
+            return false;
+        };
+        let node = xmlNewDocTextLen(self.doc, xml(&text), text.to_bytes().len() as c_int);
+        if node.is_null() {
+            self.no_memory();
+            return false;
+        }
+        self.includes[i].inc = node;
+        self.texts.push((url, text));
+        true
+    }
+
+    /// Read the resource at `url` through `handler`, which is consumed,
+    /// and check it is text.
+    unsafe fn read_text(
+        &mut self,
+        url: &CStr,
+        elem: xmlNodePtr,
+        mut handler: xmlCharEncodingHandlerPtr,
+    ) -> Option<CString> {
+        let pctxt = xmlNewParserCtxt();
+        if pctxt.is_null() {
+            self.no_memory();
+            xmlCharEncCloseFunc(handler);
+            return None;
+        }
+        // Unlike C, which ignores them here, NONET and UNZIP apply.
+        let flags = xmlParserOption_XML_PARSE_NONET | xmlParserOption_XML_PARSE_UNZIP;
+        xmlCtxtUseOptions(pctxt, self.flags & flags as c_int);
+        let input = xmlLoadResource(
+            pctxt,
+            url.as_ptr(),
+            ptr::null(),
+            xmlResourceType_XML_RESOURCE_XINCLUDE_TEXT,
+        );
+
+        let mut text = None;
+        if input.is_null() {
+            // A missing file is only a warning, not kept in errNo, and the
+            // include just fails.
+            let code = (*pctxt).errNo as u32;
+            if code == xmlParserErrors_XML_ERR_NO_MEMORY {
+                self.no_memory();
+            } else if code != xmlParserErrors_XML_ERR_OK
+                && code != xmlParserErrors_XML_IO_ENOENT
+                && code != xmlParserErrors_XML_IO_UNKNOWN
+            {
+                self.error(ptr::null_mut(), code as c_int, "load error".into());
+            }
+        } else if !(*input).buf.is_null() {
+            let buf = (*input).buf;
+            if !(*buf).encoder.is_null() {
+                xmlCharEncCloseFunc((*buf).encoder);
+            }
+            (*buf).encoder = mem::replace(&mut handler, ptr::null_mut());
+            let mut res;
+            loop {
+                res = xmlParserInputBufferRead(buf, 4096);
+                if res <= 0 {
+                    break;
+                }
+            }
+            if res < 0 {
+                self.error(ptr::null_mut(), (*buf).error, "read error".into());
+            } else {
+                let content = xmlBufContent((*buf).buffer);
+                let len = xmlBufUse((*buf).buffer);
+                let content = if len == 0 {
+                    &[][..]
+                } else {
+                    std::slice::from_raw_parts(content, len)
+                };
+                match std::str::from_utf8(content) {
+                    Ok(s) if s.chars().all(is_xml_char) => {
+                        text = Some(CString::new(content).unwrap());
+                    }
+                    _ => {
+                        let message = format!("{} contains invalid char", url.to_string_lossy());
+                        self.error(
+                            elem,
+                            xmlParserErrors_XML_XINCLUDE_INVALID_CHAR as c_int,
+                            message,
+                        );
+                    }
+                }
+            }
+        }
+        if !input.is_null() {
+            xmlFreeInputStream(input);
+        }
+        xmlFreeParserCtxt(pctxt);
+        if !handler.is_null() {
+            xmlCharEncCloseFunc(handler);
+        }
+        text
+    }
+
+    /// Take the content of the `fallback` element instead,
+    /// `xmlXIncludeLoadFallback`, expanding the includes in it.
+    unsafe fn load_fallback(&mut self, fallback: xmlNodePtr, i: usize) -> bool {
+        let mut loaded = true;
+        if !(*fallback).children.is_null() {
+            let errors = self.errors.len();
+            let base = self.includes[i].base.clone();
+            self.includes[i].inc = self.copy_node(fallback, true, base.as_deref());
+            loaded = self.errors.len() == errors;
+        }
+        self.includes[i].fallback = true;
+        loaded
+    }
+
+    /// Put the loaded nodes in place of their include element,
+    /// `xmlXIncludeIncludeNode`: between `XML_XINCLUDE_START` and
+    /// `XML_XINCLUDE_END` markers made from the element, unless
+    /// `XML_PARSE_NOXINCNODE` asks for the element to go.
+    unsafe fn include(&mut self, i: usize) {
+        let mut cur = self.includes[i].elem;
+        let mut list = mem::replace(&mut self.includes[i].inc, ptr::null_mut());
+
+        // The document element must be replaced by exactly one element.
+        let parent = (*cur).parent;
+        if !parent.is_null() && (*parent).type_ != xmlElementType_XML_ELEMENT_NODE {
+            let mut elements = 0;
+            let mut node = list;
+            while !node.is_null() {
+                if (*node).type_ == xmlElementType_XML_ELEMENT_NODE {
+                    elements += 1;
+                }
+                node = (*node).next;
+            }
+            if elements != 1 {
+                let message = if elements > 1 {
+                    "XInclude error: would result in multiple root nodes"
+                } else {
+                    "XInclude error: would result in no root node"
+                };
+                self.error(
+                    cur,
+                    xmlParserErrors_XML_XINCLUDE_MULTIPLE_ROOT as c_int,
+                    message.into(),
+                );
+                if !list.is_null() {
+                    xmlFreeNodeList(list);
+                }
+                return;
+            }
+        }
+
+        let end = if self.flags & xmlParserOption_XML_PARSE_NOXINCNODE as c_int != 0 {
+            cur
+        } else {
+            if self.includes[i].fallback {
+                xmlUnsetProp(cur, xml(c"href"));
+            }
+            (*cur).type_ = xmlElementType_XML_XINCLUDE_START;
+            // The fallback, if any, goes.
+            let mut child = (*cur).children;
+            while !child.is_null() {
+                let next = (*child).next;
+                xmlUnlinkNode(child);
+                xmlFreeNode(child);
+                child = next;
+            }
+            let end = xmlNewDocNode((*cur).doc, (*cur).ns, (*cur).name, ptr::null());
+            if end.is_null() {
+                self.no_memory();
+                if !list.is_null() {
+                    xmlFreeNodeList(list);
+                }
+                return;
+            }
+            (*end).type_ = xmlElementType_XML_XINCLUDE_END;
+            if xmlAddNextSibling(cur, end).is_null() {
+                xmlFreeNode(end);
+                self.no_memory();
+                if !list.is_null() {
+                    xmlFreeNodeList(list);
+                }
+                return;
+            }
+            end
+        };
+        while !list.is_null() {
+            cur = list;
+            list = (*list).next;
+            if xmlAddPrevSibling(end, cur).is_null() {
+                xmlUnlinkNode(cur);
+                xmlFreeNode(cur);
+                self.no_memory();
+                if !list.is_null() {
+                    xmlFreeNodeList(list);
+                }
+                return;
+            }
+        }
+        if end == self.includes[i].elem {
+            xmlUnlinkNode(end);
+            xmlFreeNode(end);
+        }
+    }
+
+    /// Expand the includes of another document, with an include table of
+    /// its own, `xmlXIncludeRecurseDoc`.
+    unsafe fn process_doc(&mut self, doc: xmlDocPtr) {
+        let old_doc = mem::replace(&mut self.doc, doc);
+        let old_includes = mem::take(&mut self.includes);
+        self.process(xmlDocGetRootElement(doc));
+        self.includes = old_includes;
+        self.doc = old_doc;
+    }
+
+    /// Find the include elements below `tree` and put what they refer to
+    /// in their place, `xmlXIncludeDoProcess`. Returns how many includes
+    /// were expanded, counting those only met in copies.
+    unsafe fn process(&mut self, tree: xmlNodePtr) -> usize {
+        if tree.is_null() {
+            return 0;
+        }
+        let start = self.includes.len();
+        let mut cur = tree;
+        loop {
+            if self.test_node(cur) {
+                if let Some(i) = self.expand(cur) {
+                    self.includes[i].replace = true;
+                }
+            } else if !(*cur).children.is_null()
+                && matches!(
+                    (*cur).type_,
+                    xmlElementType_XML_DOCUMENT_NODE | xmlElementType_XML_ELEMENT_NODE
+                )
+            {
+                cur = (*cur).children;
+                continue;
+            }
+            loop {
+                if cur == tree {
+                    break;
+                }
+                if !(*cur).next.is_null() {
+                    cur = (*cur).next;
+                    break;
+                }
+                cur = (*cur).parent;
+                if cur.is_null() {
+                    break;
+                }
+            }
+            if cur.is_null() || cur == tree {
+                break;
+            }
+        }
+
+        for i in start..self.includes.len() {
+            if self.includes[i].replace {
+                self.include(i);
+                self.includes[i].replace = false;
+            } else {
+                // Includes only expanded to be copied, as in a fallback.
+                let inc = mem::replace(&mut self.includes[i].inc, ptr::null_mut());
+                if !inc.is_null() {
+                    xmlFreeNodeList(inc);
+                }
+            }
+        }
+        self.includes.len() - start
+    }
+}
+
+/// `xmlHashScanner` merging one entity of an included document into the
+/// processed one, `xmlXIncludeMergeEntity`.
+unsafe extern "C" fn merge_entity(payload: *mut c_void, data: *mut c_void, _name: *const xmlChar) {
+    let ent = payload as xmlEntityPtr;
+    let processor = &mut *(data as *mut Processor);
+    let etype = (*ent).etype;
+    if matches!(
+        etype,
+        xmlEntityType_XML_INTERNAL_PARAMETER_ENTITY
+            | xmlEntityType_XML_EXTERNAL_PARAMETER_ENTITY
+            | xmlEntityType_XML_INTERNAL_PREDEFINED_ENTITY
+    ) {
+        return;
+    }
+    let prev = xmlGetDocEntity(processor.doc, (*ent).name);
+    if prev.is_null() {
+        let ret = xmlAddDocEntity(
+            processor.doc,
+            (*ent).name,
+            etype as c_int,
+            (*ent).ExternalID,
+            (*ent).SystemID,
+            (*ent).content,
+        );
+        if ret.is_null() {
+            processor.no_memory();
+            return;
+        }
+        if !(*ent).URI.is_null() {
+            (*ret).URI = xmlStrdup((*ent).URI);
+            if (*ret).URI.is_null() {
+                processor.no_memory();
+            }
+        }
+        return;
+    }
+    let same = etype == (*prev).etype
+        && match (
+            ((*ent).SystemID, (*prev).SystemID),
+            ((*ent).ExternalID, (*prev).ExternalID),
+            ((*ent).content, (*prev).content),
+        ) {
+            ((a, b), _, _) if !a.is_null() && !b.is_null() => bytes(a) == bytes(b),
+            (_, (a, b), _) if !a.is_null() && !b.is_null() => bytes(a) == bytes(b),
+            (_, _, (a, b)) if !a.is_null() && !b.is_null() => bytes(a) == bytes(b),
+            _ => false,
+        };
+    if !same && etype == xmlEntityType_XML_EXTERNAL_GENERAL_UNPARSED_ENTITY {
+        let name = String::from_utf8_lossy(bytes((*ent).name).unwrap_or_default());
+        processor.error(
+            ent as xmlNodePtr,
+            xmlParserErrors_XML_XINCLUDE_ENTITY_DEF_MISMATCH as c_int,
+            format!("mismatch in redefinition of entity {name}"),
+        );
+    }
+}
@@ -0,0 +1,590 @@
+#![allow(clippy::missing_safety_doc)]
+
+use std::borrow::Cow;
+use std::ffi::{CStr, CString};
+use std::os::raw::{c_char, c_int, c_ushort};
+use std::ptr;
+
+use crate::static_bindings::{
+    xmlAddAttributeDecl, xmlAddElementDecl, xmlAddEntity, xmlAddNotationDecl,
+    xmlAttributeType_XML_ATTRIBUTE_ID, xmlBuildURISafe, xmlChar, xmlCreateIntSubset,
+    xmlCtxtErrMemory, xmlDtdPtr, xmlElementContentPtr, xmlElementType_XML_CDATA_SECTION_NODE,
+    xmlElementType_XML_TEXT_NODE, xmlEntityPtr, xmlEnumerationPtr, xmlErrorDomain,
+    xmlErrorDomain_XML_FROM_PARSER, xmlErrorLevel, xmlErrorLevel_XML_ERR_ERROR,
+    xmlErrorLevel_XML_ERR_FATAL, xmlErrorLevel_XML_ERR_WARNING, xmlFree, xmlFreeDtd,
+    xmlFreeEnumeration, xmlGetIntSubset, xmlNewCDataBlock, xmlNewDocComment, xmlNewDocPI,
+    xmlNodeAddContentLen, xmlNodePtr, xmlParserCtxtPtr, xmlParserErrors,
+    xmlParserErrors_XML_DTD_XMLID_TYPE, xmlParserErrors_XML_ERR_INTERNAL_ERROR,
+    xmlParserErrors_XML_ERR_INVALID_URI, xmlParserErrors_XML_ERR_NOTATION_PROCESSING,
+    xmlParserErrors_XML_ERR_NO_MEMORY, xmlParserErrors_XML_ERR_OK,
+    xmlParserErrors_XML_ERR_REDECL_PREDEF_ENTITY, xmlParserErrors_XML_ERR_RESOURCE_LIMIT,
+    xmlParserErrors_XML_WAR_ENTITY_REDEFINED, xmlParserInputState_XML_PARSER_MISC, xmlUnlinkNode,
+};
+#[cfg(not(feature = "without-valid"))]
+use crate::static_bindings::{
+    xmlValidateAttributeDecl, xmlValidateElementDecl, xmlValidateNotationDecl,
+};
+
+/// Longest URI an entity declaration may resolve to, from private/parser.h.
+const XML_MAX_URI_LENGTH: usize = 2000;
+
+extern "C" {
+    // Private error reporting from parserInternals.c
+    fn xmlCtxtErr(
+        ctxt: xmlParserCtxtPtr,
+        node: xmlNodePtr,
+        domain: xmlErrorDomain,
+        code: xmlParserErrors,
+        level: xmlErrorLevel,
+        str1: *const xmlChar,
+        str2: *const xmlChar,
+        str3: *const xmlChar,
+        int1: c_int,
+        msg: *const c_char,
+        ...
+    );
+    fn xmlFatalErr(ctxt: xmlParserCtxtPtr, code: xmlParserErrors, info: *const c_char);
+}
+
+/// Text of a name for an error message, `(null)` like printf.
+unsafe fn text<'a>(s: *const xmlChar) -> Cow<'a, str> {
+    if s.is_null() {
+        return Cow::Borrowed("(null)");
+    }
+    CStr::from_ptr(s as *const c_char).to_string_lossy()
+}
+
+/// Report a parser error with an already formatted `message`, keeping
+/// `str1` and `str2` in the error like the SAX2.c helpers.
+unsafe fn report(
+    ctxt: xmlParserCtxtPtr,
+    code: xmlParserErrors,
+    level: xmlErrorLevel,
+    str1: *const xmlChar,
+    str2: *const xmlChar,
+    message: String,
+) {
+    // Names and values are C strings, so the message has no NUL
+    let message = CString::new(message).unwrap_or_default();
+    xmlCtxtErr(
+        ctxt,
+        ptr::null_mut(),
+        xmlErrorDomain_XML_FROM_PARSER,
+        code,
+        level,
+        str1,
+        str2,
+        ptr::null(),
+        0,
+        c"%s".as_ptr(),
+        message.as_ptr(),
+    );
+}
+
+/// The DTD declarations go to: the internal subset when `inSubset` is 1,
+/// the external one when it is 2, else none.
+unsafe fn current_subset(ctxt: xmlParserCtxtPtr) -> Option<xmlDtdPtr> {
+    match (*ctxt).inSubset {
+        1 => Some((*(*ctxt).myDoc).intSubset),
+        2 => Some((*(*ctxt).myDoc).extSubset),
+        _ => None,
+    }
+}
+
+/// Append `node` to the node being built: the DTD inside the internal
+/// (`inSubset == 1`) or external (`inSubset == 2`) subset, else the open
+/// element, else the document. Nodes other than text get the current
+/// line, capped at `u16::MAX`.
+pub unsafe fn append_child(ctxt: xmlParserCtxtPtr, node: xmlNodePtr) {
+    let parent = match (*ctxt).inSubset {
+        1 => (*(*ctxt).myDoc).intSubset as xmlNodePtr,
+        2 => (*(*ctxt).myDoc).extSubset as xmlNodePtr,
+        _ if (*ctxt).node.is_null() => (*ctxt).myDoc as xmlNodePtr,
+        _ => (*ctxt).node,
+    };
+
+    let last = (*parent).last;
+    if last.is_null() {
+        (*parent).children = node;
+    } else {
+        (*last).next = node;
+        (*node).prev = last;
+    }
+    (*parent).last = node;
+    (*node).parent = parent;
+
+    if (*node).type_ != xmlElementType_XML_TEXT_NODE && !(*ctxt).input.is_null() {
+        (*node).line = (*(*ctxt).input).line.clamp(0, c_ushort::MAX as c_int) as c_ushort;
+    }
+}
+
+/// Add a processing instruction `target` with `data`, which may be null.
+pub unsafe fn add_processing_instruction(
+    ctxt: xmlParserCtxtPtr,
+    target: *const xmlChar,
+    data: *const xmlChar,
+) {
+    let node = xmlNewDocPI((*ctxt).myDoc, target, data);
+    if node.is_null() {
+        xmlCtxtErrMemory(ctxt);
+        return;
+    }
+    append_child(ctxt, node);
+}
+
+/// Add a comment holding `value`.
+pub unsafe fn add_comment(ctxt: xmlParserCtxtPtr, value: *const xmlChar) {
+    let node = xmlNewDocComment((*ctxt).myDoc, value);
+    if node.is_null() {
+        xmlCtxtErrMemory(ctxt);
+        return;
+    }
+    append_child(ctxt, node);
+}
+
+/// Add a CDATA section holding `value` to the open element. Outside of an
+/// element there is nothing to add to and the block is dropped.
+///
+/// Each block is its own node, except in HTML, where the parser reports
+/// the raw text of `<script>` and `<style>` as CDATA blocks which are
+/// merged into one.
+pub unsafe fn add_cdata_block(ctxt: xmlParserCtxtPtr, value: &[u8]) {
+    let parent = (*ctxt).node;
+    if parent.is_null() {
+        return;
+    }
+    let Ok(len) = c_int::try_from(value.len()) else {
+        xmlCtxtErrMemory(ctxt);
+        return;
+    };
+
+    let last = (*parent).last;
+    if (*ctxt).html != 0
+        && !last.is_null()
+        && (*last).type_ == xmlElementType_XML_CDATA_SECTION_NODE
+    {
+        if xmlNodeAddContentLen(last, value.as_ptr(), len) != 0 {
+            xmlCtxtErrMemory(ctxt);
+        }
+        return;
+    }
+
+    let node = xmlNewCDataBlock((*parent).doc, value.as_ptr(), len);
+    if node.is_null() {
+        xmlCtxtErrMemory(ctxt);
+        return;
+    }
+    if last.is_null() {
+        // As in C, a first child is linked without a line number.
+        (*parent).children = node;
+        (*parent).last = node;
+        (*node).parent = parent;
+    } else {
+        append_child(ctxt, node);
+    }
+}
+
+/// Start the internal subset of the document, replacing any earlier one.
+/// HTML documents only take a doctype before the root element.
+pub unsafe fn internal_subset(
+    ctxt: xmlParserCtxtPtr,
+    name: *const xmlChar,
+    public_id: *const xmlChar,
+    system_id: *const xmlChar,
+) {
+    let doc = (*ctxt).myDoc;
+    if doc.is_null() {
+        return;
+    }
+    if (*ctxt).html != 0 && (*ctxt).instate != xmlParserInputState_XML_PARSER_MISC {
+        return;
+    }
+    let dtd = xmlGetIntSubset(doc);
+    if !dtd.is_null() {
+        xmlUnlinkNode(dtd as xmlNodePtr);
+        xmlFreeDtd(dtd);
+        (*doc).intSubset = ptr::null_mut();
+    }
+    (*doc).intSubset = xmlCreateIntSubset(doc, name, public_id, system_id);
+    if (*doc).intSubset.is_null() {
+        xmlCtxtErrMemory(ctxt);
+    }
+}
+
+/// The base to resolve the system ID of an entity against: the name of
+/// the innermost input that has one, else `directory`.
+unsafe fn entity_base(ctxt: xmlParserCtxtPtr) -> *const xmlChar {
+    for i in (0..(*ctxt).inputNr.max(0) as usize).rev() {
+        let filename = (**(*ctxt).inputTab.add(i)).filename;
+        if !filename.is_null() {
+            return filename as *const xmlChar;
+        }
+    }
+    (*ctxt).directory as *const xmlChar
+}
+
+/// Declare an entity in the subset being parsed. An external entity gets
+/// its system ID resolved into `URI`.
+pub unsafe fn entity_decl(
+    ctxt: xmlParserCtxtPtr,
+    name: *const xmlChar,
+    type_: c_int,
+    public_id: *const xmlChar,
+    system_id: *const xmlChar,
+    content: *const xmlChar,
+) {
+    if (*ctxt).myDoc.is_null() {
+        return;
+    }
+    let ext_subset = (*ctxt).inSubset == 2;
+    let mut ent: xmlEntityPtr = ptr::null_mut();
+    let res = xmlAddEntity(
+        (*ctxt).myDoc,
+        ext_subset as c_int,
+        name,
+        type_,
+        public_id,
+        system_id,
+        content,
+        &mut ent,
+    ) as xmlParserErrors;
+    match res {
+        xmlParserErrors_XML_ERR_OK => {}
+        xmlParserErrors_XML_ERR_NO_MEMORY => {
+            xmlCtxtErrMemory(ctxt);
+            return;
+        }
+        xmlParserErrors_XML_WAR_ENTITY_REDEFINED => {
+            // The first declaration is binding
+            if (*ctxt).pedantic != 0 {
+                let subset = if ext_subset { "external" } else { "internal" };
+                report(
+                    ctxt,
+                    res,
+                    xmlErrorLevel_XML_ERR_WARNING,
+                    name,
+                    ptr::null(),
+                    format!(
+                        "Entity({}) already defined in the {subset} subset\n",
+                        text(name)
+                    ),
+                );
+            }
+            return;
+        }
+        xmlParserErrors_XML_ERR_REDECL_PREDEF_ENTITY => {
+            // Technically an error, but getting the double escaping of
+            // predefined entities wrong is common
+            report(
+                ctxt,
+                res,
+                xmlErrorLevel_XML_ERR_WARNING,
+                name,
+                ptr::null(),
+                format!(
+                    "Invalid redeclaration of predefined entity '{}'",
+                    text(name)
+                ),
+            );
+            return;
+        }
+        _ => {
+            report(
+                ctxt,
+                xmlParserErrors_XML_ERR_INTERNAL_ERROR,
+                xmlErrorLevel_XML_ERR_FATAL,
+                ptr::null(),
+                ptr::null(),
+                "Unexpected error code from xmlAddEntity\n".to_string(),
+            );
+            return;
+        }
+    }
+
+    if !(*ent).URI.is_null() || system_id.is_null() {
+        return;
+    }
+    let mut uri: *mut xmlChar = ptr::null_mut();
+    let res = xmlBuildURISafe(system_id, entity_base(ctxt), &mut uri);
+    if uri.is_null() {
+        if res < 0 {
+            xmlCtxtErrMemory(ctxt);
+        } else {
+            report(
+                ctxt,
+                xmlParserErrors_XML_ERR_INVALID_URI,
+                xmlErrorLevel_XML_ERR_WARNING,
+                system_id,
+                ptr::null(),
+                format!("Can't resolve URI: {}\n", text(system_id)),
+            );
+        }
+    } else if CStr::from_ptr(uri as *const c_char).to_bytes().len() > XML_MAX_URI_LENGTH {
+        xmlFatalErr(
+            ctxt,
+            xmlParserErrors_XML_ERR_RESOURCE_LIMIT,
+            c"URI too long".as_ptr(),
+        );
+        xmlFree.unwrap()(uri as *mut _);
+    } else {
+        (*ent).URI = uri;
+    }
+}
+
+/// Declare the attribute `fullname` of `elem` in the subset being parsed.
+/// `tree` lists the values of an enumerated type and is owned by the
+/// declaration from then on.
+#[allow(clippy::too_many_arguments)]
+pub unsafe fn attribute_decl(
+    ctxt: xmlParserCtxtPtr,
+    elem: *const xmlChar,
+    fullname: *const xmlChar,
+    type_: c_int,
+    def: c_int,
+    default_value: *const xmlChar,
+    tree: xmlEnumerationPtr,
+) {
+    if (*ctxt).myDoc.is_null() {
+        return;
+    }
+    let full = if fullname.is_null() {
+        None
+    } else {
+        Some(CStr::from_ptr(fullname as *const c_char).to_bytes())
+    };
+    if full == Some(b"xml:id") && type_ != xmlAttributeType_XML_ATTRIBUTE_ID as c_int {
+        report(
+            ctxt,
+            xmlParserErrors_XML_DTD_XMLID_TYPE,
+            xmlErrorLevel_XML_ERR_ERROR,
+            ptr::null(),
+            ptr::null(),
+            "xml:id : attribute type should be ID\n".to_string(),
+        );
+    }
+
+    // Cut at the first colon like xmlSplitQName4, unless the name starts
+    // or ends with it
+    let mut name = fullname;
+    let mut prefix = None;
+    match full {
+        None => xmlCtxtErrMemory(ctxt),
+        Some(full) => {
+            if let Some(colon) = full.iter().position(|&c| c == b':') {
+                if colon > 0 && colon + 1 < full.len() {
+                    prefix = CString::new(&full[..colon]).ok();
+                    name = fullname.add(colon + 1);
+                }
+            }
+        }
+    }
+    let prefix_ptr = prefix
+        .as_ref()
+        .map_or(ptr::null(), |p| p.as_ptr() as *const xmlChar);
+
+    (*ctxt).vctxt.valid = 1;
+    let Some(dtd) = current_subset(ctxt) else {
+        report(
+            ctxt,
+            xmlParserErrors_XML_ERR_INTERNAL_ERROR,
+            xmlErrorLevel_XML_ERR_FATAL,
+            name,
+            ptr::null(),
+            format!(
+                "SAX.xmlSAX2AttributeDecl({}) called while not in subset\n",
+                text(name)
+            ),
+        );
+        xmlFreeEnumeration(tree);
+        return;
+    };
+    let attr = xmlAddAttributeDecl(
+        &mut (*ctxt).vctxt,
+        dtd,
+        elem,
+        name,
+        prefix_ptr,
+        type_ as _,
+        def as _,
+        default_value,
+        tree,
+    );
+    #[cfg(not(feature = "without-valid"))]
+    {
+        if (*ctxt).vctxt.valid == 0 {
+            (*ctxt).valid = 0;
+        }
+        if !attr.is_null()
+            && (*ctxt).validate != 0
+            && (*ctxt).wellFormed != 0
+            && !(*(*ctxt).myDoc).intSubset.is_null()
+        {
+            (*ctxt).valid &= xmlValidateAttributeDecl(&mut (*ctxt).vctxt, (*ctxt).myDoc, attr);
+        }
+    }
+}
+
+/// Declare the element `name` with its content model in the subset being
+/// parsed. `content` stays the caller's; the declaration keeps a copy.
+pub unsafe fn element_decl(
+    ctxt: xmlParserCtxtPtr,
+    name: *const xmlChar,
+    type_: c_int,
+    content: xmlElementContentPtr,
+) {
+    if (*ctxt).myDoc.is_null() {
+        return;
+    }
+    let Some(dtd) = current_subset(ctxt) else {
+        report(
+            ctxt,
+            xmlParserErrors_XML_ERR_INTERNAL_ERROR,
+            xmlErrorLevel_XML_ERR_FATAL,
+            name,
+            ptr::null(),
+            format!(
+                "SAX.xmlSAX2ElementDecl({}) called while not in subset\n",
+                text(name)
+            ),
+        );
+        return;
+    };
+    let elem = xmlAddElementDecl(&mut (*ctxt).vctxt, dtd, name, type_ as _, content);
+    #[cfg(not(feature = "without-valid"))]
+    {
+        if elem.is_null() {
+            (*ctxt).valid = 0;
+        }
+        if (*ctxt).validate != 0 && (*ctxt).wellFormed != 0 && !(*(*ctxt).myDoc).intSubset.is_null()
+        {
+            (*ctxt).valid &= xmlValidateElementDecl(&mut (*ctxt).vctxt, (*ctxt).myDoc, elem);
+        }
+    }
+}
+
+/// Declare the notation `name` in the subset being parsed. One of the IDs
+/// is required.
+pub unsafe fn notation_decl(
+    ctxt: xmlParserCtxtPtr,
+    name: *const xmlChar,
+    public_id: *const xmlChar,
+    system_id: *const xmlChar,
+) {
+    if (*ctxt).myDoc.is_null() {
+        return;
+    }
+    let dtd = current_subset(ctxt);
+    let problem = if public_id.is_null() && system_id.is_null() {
+        Some("externalID or PublicID missing")
+    } else if dtd.is_none() {
+        Some("called while not in subset")
+    } else {
+        None
+    };
+    if let Some(problem) = problem {
+        report(
+            ctxt,
+            xmlParserErrors_XML_ERR_NOTATION_PROCESSING,
+            xmlErrorLevel_XML_ERR_FATAL,
+            name,
+            ptr::null(),
+            format!("SAX.xmlSAX2NotationDecl({}) {problem}\n", text(name)),
+        );
+        return;
+    }
+    let nota = xmlAddNotationDecl(
+        &mut (*ctxt).vctxt,
+        dtd.unwrap_or(ptr::null_mut()),
+        name,
+        public_id,
+        system_id,
+    );
+    #[cfg(not(feature = "without-valid"))]
+    {
+        if nota.is_null() {
+            (*ctxt).valid = 0;
+        }
+        if (*ctxt).validate != 0 && (*ctxt).wellFormed != 0 && !(*(*ctxt).myDoc).intSubset.is_null()
+        {
+            (*ctxt).valid &= xmlValidateNotationDecl(&mut (*ctxt).vctxt, (*ctxt).myDoc, nota);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::static_bindings::{
+        xmlDocGetRootElement, xmlFreeDoc, xmlFreeParserCtxt, xmlNewDoc, xmlNewDocNode,
+        xmlNewParserCtxt, xmlNodeGetContent,
+    };
+    use crate::static_bindings::{xmlElementType_XML_COMMENT_NODE, xmlFree};
+    use std::ffi::CStr;
+    use std::os::raw::{c_char, c_void};
+    use std::ptr;
+
+    #[test]
+    fn test_add_nodes() {
+        unsafe {
+            let ctxt = xmlNewParserCtxt();
+            let doc = xmlNewDoc(c"1.0".as_ptr() as *const xmlChar);
+            (*ctxt).myDoc = doc;
+
+            // Outside of an element nodes go to the document.
+            add_comment(ctxt, c" top ".as_ptr() as *const xmlChar);
+            assert_eq!((*(*doc).children).type_, xmlElementType_XML_COMMENT_NODE);
+
+            let root = xmlNewDocNode(
+                doc,
+                ptr::null_mut(),
+                c"r".as_ptr() as *const xmlChar,
+                ptr::null(),
+            );
+            append_child(ctxt, root);
+            assert_eq!(xmlDocGetRootElement(doc), root);
+            (*ctxt).node = root;
+
+            add_cdata_block(ctxt, b"a<b");
+            add_cdata_block(ctxt, b"]]");
+            add_processing_instruction(ctxt, c"pi".as_ptr() as *const xmlChar, ptr::null());
+            let first = (*root).children;
+            assert_eq!((*first).type_, xmlElementType_XML_CDATA_SECTION_NODE);
+            assert_eq!(
+                (*(*first).next).type_,
+                xmlElementType_XML_CDATA_SECTION_NODE
+            );
+            assert_eq!((*(*root).last).prev, (*first).next);
+
+            let content = xmlNodeGetContent(root);
+            assert_eq!(
+                CStr::from_ptr(content as *const c_char).to_bytes(),
+                b"a<b]]"
+            );
+            xmlFree.unwrap()(content as *mut c_void);
+
+            // HTML merges the blocks of raw text.
+            let script = xmlNewDocNode(
+                doc,
+                ptr::null_mut(),
+                c"script".as_ptr() as *const xmlChar,
+                ptr::null(),
+            );
+            append_child(ctxt, script);
+            (*ctxt).node = script;
+            (*ctxt).html = 1;
+            add_cdata_block(ctxt, b"var a;");
+            add_cdata_block(ctxt, b" var b;");
+            assert_eq!((*script).children, (*script).last);
+            let content = xmlNodeGetContent(script);
+            assert_eq!(
+                CStr::from_ptr(content as *const c_char).to_bytes(),
+                b"var a; var b;"
+            );
+            xmlFree.unwrap()(content as *mut c_void);
+
+            (*ctxt).myDoc = ptr::null_mut();
+            xmlFreeDoc(doc);
+            xmlFreeParserCtxt(ctxt);
+        }
+    }
+}
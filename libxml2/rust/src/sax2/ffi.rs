@@ -0,0 +1,142 @@
+#![allow(clippy::missing_safety_doc)]
+
+use std::os::raw::{c_int, c_void};
+use std::slice;
+
+use super::core::{
+    add_cdata_block, add_comment, add_processing_instruction, attribute_decl, element_decl,
+    entity_decl, internal_subset, notation_decl,
+};
+use crate::static_bindings::{xmlChar, xmlElementContentPtr, xmlEnumerationPtr, xmlParserCtxtPtr};
+
+// Ensure symbols are exported for linking
+#[cfg(feature = "rust-sax2")]
+#[used]
+static SAX2_FFI_LINKAGE: () = ();
+
+/// A processing instruction has been parsed.
+#[no_mangle]
+pub unsafe extern "C" fn xmlSAX2ProcessingInstruction(
+    ctx: *mut c_void,
+    target: *const xmlChar,
+    data: *const xmlChar,
+) {
+    if ctx.is_null() {
+        return;
+    }
+    add_processing_instruction(ctx as xmlParserCtxtPtr, target, data);
+}
+
+/// A comment has been parsed.
+#[no_mangle]
+pub unsafe extern "C" fn xmlSAX2Comment(ctx: *mut c_void, value: *const xmlChar) {
+    if ctx.is_null() {
+        return;
+    }
+    add_comment(ctx as xmlParserCtxtPtr, value);
+}
+
+/// A CDATA block of `len` bytes has been parsed.
+#[no_mangle]
+pub unsafe extern "C" fn xmlSAX2CDataBlock(ctx: *mut c_void, value: *const xmlChar, len: c_int) {
+    if ctx.is_null() || (value.is_null() && len != 0) {
+        return;
+    }
+    let value = if len <= 0 {
+        &[][..]
+    } else {
+        slice::from_raw_parts(value, len as usize)
+    };
+    add_cdata_block(ctx as xmlParserCtxtPtr, value);
+}
+
+/// A doctype with an internal subset has been parsed.
+#[no_mangle]
+pub unsafe extern "C" fn xmlSAX2InternalSubset(
+    ctx: *mut c_void,
+    name: *const xmlChar,
+    ExternalID: *const xmlChar,
+    SystemID: *const xmlChar,
+) {
+    if ctx.is_null() {
+        return;
+    }
+    internal_subset(ctx as xmlParserCtxtPtr, name, ExternalID, SystemID);
+}
+
+/// An entity declaration has been parsed.
+#[no_mangle]
+pub unsafe extern "C" fn xmlSAX2EntityDecl(
+    ctx: *mut c_void,
+    name: *const xmlChar,
+    type_: c_int,
+    publicId: *const xmlChar,
+    systemId: *const xmlChar,
+    content: *mut xmlChar,
+) {
+    if ctx.is_null() {
+        return;
+    }
+    entity_decl(
+        ctx as xmlParserCtxtPtr,
+        name,
+        type_,
+        publicId,
+        systemId,
+        content,
+    );
+}
+
+/// An attribute declaration has been parsed. The declaration takes
+/// `tree` over.
+#[no_mangle]
+pub unsafe extern "C" fn xmlSAX2AttributeDecl(
+    ctx: *mut c_void,
+    elem: *const xmlChar,
+    fullname: *const xmlChar,
+    type_: c_int,
+    def: c_int,
+    defaultValue: *const xmlChar,
+    tree: xmlEnumerationPtr,
+) {
+    if ctx.is_null() {
+        return;
+    }
+    attribute_decl(
+        ctx as xmlParserCtxtPtr,
+        elem,
+        fullname,
+        type_,
+        def,
+        defaultValue,
+        tree,
+    );
+}
+
+/// An element declaration has been parsed.
+#[no_mangle]
+pub unsafe extern "C" fn xmlSAX2ElementDecl(
+    ctx: *mut c_void,
+    name: *const xmlChar,
+    type_: c_int,
+    content: xmlElementContentPtr,
+) {
+    if ctx.is_null() {
+        return;
+    }
+    element_decl(ctx as xmlParserCtxtPtr, name, type_, content);
+}
+
+/// A notation declaration has been parsed.
+#[no_mangle]
+pub unsafe extern "C" fn xmlSAX2NotationDecl(
+    ctx: *mut c_void,
+    name: *const xmlChar,
+    publicId: *const xmlChar,
+    systemId: *const xmlChar,
+) {
+    if ctx.is_null() {
+        return;
+    }
+    notation_decl(ctx as xmlParserCtxtPtr, name, publicId, systemId);
+}
@@ -1 +1,10 @@
-// Empty stub module for sax2
+//! Rust implementation of SAX2 module
+//!
+//! Default SAX2 handlers building the tree for comments, processing
+//! instructions and CDATA sections, and storing the declarations of the
+//! internal subset
+
+pub mod core;
+pub mod ffi;
+
+pub use core::*;
@@ -1 +1,10 @@
-// Empty stub module for schematron
+//! Rust implementation of schematron module
+//!
+//! Compilation of Schematron schemas and evaluation of their rules
+//! against documents
+
+mod schema;
+mod validate;
+
+pub use schema::*;
+pub use validate::*;
@@ -0,0 +1,490 @@
+#![allow(clippy::missing_safety_doc)]
+
+use std::ffi::{CStr, CString};
+use std::os::raw::{c_char, c_int, c_void};
+use std::ptr;
+
+use crate::static_bindings::{
+    xmlChar, xmlDocGetRootElement, xmlDocPtr, xmlElementType_XML_CDATA_SECTION_NODE,
+    xmlElementType_XML_ELEMENT_NODE, xmlElementType_XML_TEXT_NODE, xmlFree, xmlFreePattern,
+    xmlGetNoNsProp, xmlNodePtr, xmlParserErrors_XML_SCHEMAP_NOROOT,
+    xmlParserErrors_XML_SCHEMAV_ATTRINVALID, xmlPatternFlags_XML_PATTERN_XPATH, xmlPatternPtr,
+    xmlPatterncompile, xmlXPathCompExprPtr, xmlXPathContextPtr, xmlXPathCtxtCompile,
+    xmlXPathFreeCompExpr, xmlXPathFreeContext, xmlXPathNewContext, xmlXPathRegisterNs,
+};
+
+const SCHEMATRON_NS: &[u8] = b"http://purl.oclc.org/dsdl/schematron";
+const OLD_SCHEMATRON_NS: &[u8] = b"http://www.ascc.net/xml/schematron";
+
+/// An error xmlSchematronParse reports. Parsing goes on after an error,
+/// so a schema can give several.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchematronParseError {
+    /// The `xmlParserErrors` code, `XML_SCHEMAP_NOROOT` for nearly all
+    /// of them.
+    pub code: c_int,
+    /// The schema node the error is about.
+    pub node: xmlNodePtr,
+    pub message: String,
+}
+
+/// `xmlSchematronTestType`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum TestKind {
+    Assert,
+    Report,
+}
+
+/// A piece of the message of an assert or report, the children
+/// xmlSchematronFormatReport handles.
+pub(super) enum Segment {
+    /// Text or CDATA content.
+    Text(Vec<u8>),
+    /// `<name/>`, with the XPath of its `path` attribute if any.
+    Name(Option<CString>),
+    /// `<value-of/>` with its compiled `select` expression.
+    ValueOf(xmlXPathCompExprPtr),
+}
+
+pub(super) struct Test {
+    pub(super) kind: TestKind,
+    pub(super) comp: xmlXPathCompExprPtr,
+    pub(super) message: Vec<Segment>,
+}
+
+pub(super) struct Let {
+    pub(super) name: CString,
+    pub(super) comp: xmlXPathCompExprPtr,
+}
+
+pub(super) struct Rule {
+    /// The compiled `context`, null if it did not compile.
+    pub(super) pattern: xmlPatternPtr,
+    /// In reverse document order, as C prepends them.
+    pub(super) lets: Vec<Let>,
+    pub(super) tests: Vec<Test>,
+}
+
+pub(super) struct Pattern {
+    pub(super) name: Vec<u8>,
+    pub(super) rules: Vec<Rule>,
+}
+
+/// A compiled Schematron schema, the patterns, rules and tests of
+/// xmlSchematronParse.
+pub struct Schematron {
+    pub(super) patterns: Vec<Pattern>,
+    /// The prefix and URI of each `ns` element.
+    pub(super) namespaces: Vec<(CString, CString)>,
+}
+
+impl Drop for Schematron {
+    fn drop(&mut self) {
+        unsafe {
+            for rule in self.patterns.iter().flat_map(|p| &p.rules) {
+                if !rule.pattern.is_null() {
+                    xmlFreePattern(rule.pattern);
+                }
+                for var in &rule.lets {
+                    xmlXPathFreeCompExpr(var.comp);
+                }
+                for test in &rule.tests {
+                    xmlXPathFreeCompExpr(test.comp);
+                    free_message(&test.message);
+                }
+            }
+        }
+    }
+}
+
+unsafe fn free_message(message: &[Segment]) {
+    for segment in message {
+        if let Segment::ValueOf(comp) = segment {
+            xmlXPathFreeCompExpr(*comp);
+        }
+    }
+}
+
+pub(super) unsafe fn bytes<'a>(p: *const xmlChar) -> &'a [u8] {
+    if p.is_null() {
+        b""
+    } else {
+        CStr::from_ptr(p as *const c_char).to_bytes()
+    }
+}
+
+/// Take ownership of a string returned by libxml2.
+pub(super) unsafe fn owned(p: *mut xmlChar) -> Option<Vec<u8>> {
+    if p.is_null() {
+        return None;
+    }
+    let value = bytes(p).to_vec();
+    xmlFree.unwrap()(p as *mut c_void);
+    Some(value)
+}
+
+unsafe fn prop(node: xmlNodePtr, name: &CStr) -> Option<Vec<u8>> {
+    owned(xmlGetNoNsProp(node, name.as_ptr() as *const xmlChar))
+}
+
+/// `NEXT_SCHEMATRON`: an element in either Schematron namespace.
+unsafe fn in_schematron_ns(node: xmlNodePtr) -> bool {
+    (*node).type_ == xmlElementType_XML_ELEMENT_NODE
+        && !(*node).ns.is_null()
+        && matches!(bytes((*(*node).ns).href), SCHEMATRON_NS | OLD_SCHEMATRON_NS)
+}
+
+/// `IS_SCHEMATRON`.
+pub(super) unsafe fn is_schematron(node: xmlNodePtr, name: &[u8]) -> bool {
+    !node.is_null() && in_schematron_ns(node) && bytes((*node).name) == name
+}
+
+/// `node` or its first following sibling in a Schematron namespace.
+unsafe fn next_schematron(mut node: xmlNodePtr) -> xmlNodePtr {
+    while !node.is_null() && !in_schematron_ns(node) {
+        node = (*node).next;
+    }
+    node
+}
+
+/// The parser context of xmlSchematronParse.
+struct Parser {
+    /// Used to compile the XPath expressions of lets and tests.
+    xctxt: xmlXPathContextPtr,
+    namespaces: Vec<(CString, CString)>,
+    errors: Vec<SchematronParseError>,
+}
+
+impl Schematron {
+    /// Compile the Schematron schema in `doc`, like xmlSchematronParse
+    /// with a document parser context. Returns every error reported if
+    /// the schema is invalid.
+    pub unsafe fn parse(doc: xmlDocPtr) -> Result<Schematron, Vec<SchematronParseError>> {
+        let mut parser = Parser {
+            xctxt: xmlXPathNewContext(doc),
+            namespaces: Vec::new(),
+            errors: Vec::new(),
+        };
+        let schema = parser.parse_schema(doc);
+        xmlXPathFreeContext(parser.xctxt);
+        match schema {
+            Some(schema) if parser.errors.is_empty() => Ok(schema),
+            _ => Err(parser.errors),
+        }
+    }
+}
+
+impl Parser {
+    fn error(&mut self, node: xmlNodePtr, code: u32, message: String) {
+        self.errors.push(SchematronParseError {
+            code: code as c_int,
+            node,
+            message,
+        });
+    }
+
+    fn noroot(&mut self, node: xmlNodePtr, message: &str) {
+        self.error(
+            node,
+            xmlParserErrors_XML_SCHEMAP_NOROOT,
+            message.to_string(),
+        );
+    }
+
+    unsafe fn compile(&self, expr: &[u8]) -> xmlXPathCompExprPtr {
+        let expr = CString::new(expr).unwrap();
+        xmlXPathCtxtCompile(self.xctxt, expr.as_ptr() as *const xmlChar)
+    }
+
+    unsafe fn parse_schema(&mut self, doc: xmlDocPtr) -> Option<Schematron> {
+        let root = xmlDocGetRootElement(doc);
+        if root.is_null() {
+            self.noroot(doc as xmlNodePtr, "The schema has no document element.\n");
+            return None;
+        }
+        // The URL of a document parser context is always NULL.
+        if !is_schematron(root, b"schema") {
+            self.noroot(
+                root,
+                "The XML document '(null)' is not a XML schematron document",
+            );
+            return None;
+        }
+        let mut schema = Schematron {
+            patterns: Vec::new(),
+            namespaces: Vec::new(),
+        };
+
+        let mut cur = next_schematron((*root).children);
+        if is_schematron(cur, b"title") {
+            cur = next_schematron((*cur).next);
+        }
+        while is_schematron(cur, b"ns") {
+            let prefix = prop(cur, c"prefix");
+            let uri = prop(cur, c"uri");
+            if uri.as_ref().is_none_or(|u| u.is_empty()) {
+                self.noroot(cur, "ns element has no uri");
+            }
+            if prefix.as_ref().is_none_or(|p| p.is_empty()) {
+                self.noroot(cur, "ns element has no prefix");
+            }
+            if let (Some(prefix), Some(uri)) = (prefix, uri) {
+                let prefix = CString::new(prefix).unwrap();
+                let uri = CString::new(uri).unwrap();
+                xmlXPathRegisterNs(
+                    self.xctxt,
+                    prefix.as_ptr() as *const xmlChar,
+                    uri.as_ptr() as *const xmlChar,
+                );
+                self.namespaces.push((prefix, uri));
+            }
+            cur = next_schematron((*cur).next);
+        }
+        let mut nb_pattern = 0;
+        while !cur.is_null() {
+            if is_schematron(cur, b"pattern") {
+                if let Some(pattern) = self.parse_pattern(cur) {
+                    schema.patterns.push(pattern);
+                }
+                nb_pattern += 1;
+            } else {
+                let message = format!(
+                    "Expecting a pattern element instead of {}",
+                    String::from_utf8_lossy(bytes((*cur).name))
+                );
+                self.error(cur, xmlParserErrors_XML_SCHEMAP_NOROOT, message);
+            }
+            cur = next_schematron((*cur).next);
+        }
+        if nb_pattern == 0 {
+            self.noroot(root, "The schematron document '(null)' has no pattern");
+            return None;
+        }
+        schema.namespaces = std::mem::take(&mut self.namespaces);
+        Some(schema)
+    }
+
+    /// xmlSchematronParsePattern. A pattern with neither an `id` nor a
+    /// `name` is skipped without looking at its rules.
+    unsafe fn parse_pattern(&mut self, pat: xmlNodePtr) -> Option<Pattern> {
+        let name = prop(pat, c"id").or_else(|| prop(pat, c"name"))?;
+        let mut pattern = Pattern {
+            name,
+            rules: Vec::new(),
+        };
+        let mut nb_rules = 0;
+        let mut cur = next_schematron((*pat).children);
+        while !cur.is_null() {
+            if is_schematron(cur, b"rule") {
+                if let Some(rule) = self.parse_rule(cur) {
+                    pattern.rules.push(rule);
+                }
+                nb_rules += 1;
+            } else {
+                let message = format!(
+                    "Expecting a rule element instead of {}",
+                    String::from_utf8_lossy(bytes((*cur).name))
+                );
+                self.error(cur, xmlParserErrors_XML_SCHEMAP_NOROOT, message);
+            }
+            cur = next_schematron((*cur).next);
+        }
+        if nb_rules == 0 {
+            self.noroot(pat, "Pattern has no rule element");
+        }
+        Some(pattern)
+    }
+
+    /// xmlSchematronParseRule. An error on the context or on a `let`
+    /// ends the rule, but the rule is kept as far as it was parsed.
+    unsafe fn parse_rule(&mut self, node: xmlNodePtr) -> Option<Rule> {
+        let context = match prop(node, c"context") {
+            None => {
+                self.noroot(node, "rule has no context attribute");
+                return None;
+            }
+            Some(context) if context.is_empty() => {
+                self.noroot(node, "rule has an empty context attribute");
+                return None;
+            }
+            Some(context) => context,
+        };
+        let mut rule = Rule {
+            pattern: self.compile_context(node, &context),
+            lets: Vec::new(),
+            tests: Vec::new(),
+        };
+
+        let mut nb_checks = 0;
+        let mut cur = next_schematron((*node).children);
+        while !cur.is_null() {
+            if is_schematron(cur, b"let") {
+                match self.parse_let(cur) {
+                    Some(var) => rule.lets.insert(0, var),
+                    None => return Some(rule),
+                }
+            } else if is_schematron(cur, b"assert") || is_schematron(cur, b"report") {
+                nb_checks += 1;
+                let kind = if bytes((*cur).name) == b"assert" {
+                    TestKind::Assert
+                } else {
+                    TestKind::Report
+                };
+                if let Some(test) = self.parse_test(cur, kind) {
+                    rule.tests.push(test);
+                }
+            } else {
+                let message = format!(
+                    "Expecting an assert or a report element instead of {}",
+                    String::from_utf8_lossy(bytes((*cur).name))
+                );
+                self.error(cur, xmlParserErrors_XML_SCHEMAP_NOROOT, message);
+            }
+            cur = next_schematron((*cur).next);
+        }
+        if nb_checks == 0 {
+            self.noroot(node, "rule has no assert nor report element");
+        }
+        Some(rule)
+    }
+
+    /// Compile the context of a rule as xmlSchematronAddRule does, with
+    /// the namespaces declared so far.
+    unsafe fn compile_context(&mut self, node: xmlNodePtr, context: &[u8]) -> xmlPatternPtr {
+        let mut namespaces: Vec<*const xmlChar> = Vec::new();
+        for (prefix, uri) in &self.namespaces {
+            namespaces.push(uri.as_ptr() as *const xmlChar);
+            namespaces.push(prefix.as_ptr() as *const xmlChar);
+        }
+        let table = if namespaces.is_empty() {
+            ptr::null_mut()
+        } else {
+            namespaces.extend([ptr::null(), ptr::null()]);
+            namespaces.as_mut_ptr()
+        };
+        let expr = CString::new(context).unwrap();
+        let pattern = xmlPatterncompile(
+            expr.as_ptr() as *const xmlChar,
+            ptr::null_mut(),
+            xmlPatternFlags_XML_PATTERN_XPATH as c_int,
+            table,
+        );
+        if pattern.is_null() {
+            let message = format!(
+                "Failed to compile context expression {}",
+                String::from_utf8_lossy(context)
+            );
+            self.error(node, xmlParserErrors_XML_SCHEMAP_NOROOT, message);
+        }
+        pattern
+    }
+
+    unsafe fn parse_let(&mut self, node: xmlNodePtr) -> Option<Let> {
+        let name = match prop(node, c"name") {
+            None => {
+                self.noroot(node, "let has no name attribute");
+                return None;
+            }
+            Some(name) if name.is_empty() => {
+                self.noroot(node, "let has an empty name attribute");
+                return None;
+            }
+            Some(name) => name,
+        };
+        let value = match prop(node, c"value") {
+            None => {
+                self.noroot(node, "let has no value attribute");
+                return None;
+            }
+            Some(value) if value.is_empty() => {
+                self.noroot(node, "let has an empty value attribute");
+                return None;
+            }
+            Some(value) => value,
+        };
+        let comp = self.compile(&value);
+        if comp.is_null() {
+            let message = format!(
+                "Failed to compile let expression {}",
+                String::from_utf8_lossy(&value)
+            );
+            self.error(node, xmlParserErrors_XML_SCHEMAP_NOROOT, message);
+            return None;
+        }
+        Some(Let {
+            name: CString::new(name).unwrap(),
+            comp,
+        })
+    }
+
+    /// Parse an `assert` or a `report`. C reports a missing test as an
+    /// assert error for both.
+    unsafe fn parse_test(&mut self, node: xmlNodePtr, kind: TestKind) -> Option<Test> {
+        let test = match prop(node, c"test") {
+            None => {
+                self.noroot(node, "assert has no test attribute");
+                return None;
+            }
+            Some(test) if test.is_empty() => {
+                self.noroot(node, "assert has an empty test attribute");
+                return None;
+            }
+            Some(test) => test,
+        };
+        let message = self.parse_message(node);
+        let comp = self.compile(&test);
+        if comp.is_null() {
+            free_message(&message);
+            let message = format!(
+                "Failed to compile test expression {}",
+                String::from_utf8_lossy(&test)
+            );
+            self.error(node, xmlParserErrors_XML_SCHEMAP_NOROOT, message);
+            return None;
+        }
+        Some(Test {
+            kind,
+            comp,
+            message,
+        })
+    }
+
+    /// xmlSchematronParseTestReportMsg: check the `value-of` children of
+    /// a test and keep the parts of its message.
+    unsafe fn parse_message(&mut self, node: xmlNodePtr) -> Vec<Segment> {
+        let mut segments = Vec::new();
+        let mut child = (*node).children;
+        while !child.is_null() {
+            if (*child).type_ == xmlElementType_XML_TEXT_NODE
+                || (*child).type_ == xmlElementType_XML_CDATA_SECTION_NODE
+            {
+                segments.push(Segment::Text(bytes((*child).content).to_vec()));
+            } else if is_schematron(child, b"name") {
+                let path = prop(child, c"path").map(|p| CString::new(p).unwrap());
+                segments.push(Segment::Name(path));
+            } else if is_schematron(child, b"value-of") {
+                match prop(child, c"select") {
+                    None => self.error(
+                        child,
+                        xmlParserErrors_XML_SCHEMAV_ATTRINVALID,
+                        "value-of has no select attribute".to_string(),
+                    ),
+                    Some(select) => {
+                        let comp = self.compile(&select);
+                        if comp.is_null() {
+                            let message = format!(
+                                "Failed to compile select expression {}",
+                                String::from_utf8_lossy(&select)
+                            );
+                            self.error(child, xmlParserErrors_XML_SCHEMAV_ATTRINVALID, message);
+                        } else {
+                            segments.push(Segment::ValueOf(comp));
+                        }
+                    }
+                }
+            }
+            child = (*child).next;
+        }
+        segments
+    }
+}
@@ -0,0 +1,375 @@
+#![allow(clippy::missing_safety_doc)]
+
+use std::os::raw::{c_int, c_long};
+use std::ptr;
+
+use crate::static_bindings::{
+    xmlChar, xmlDocGetRootElement, xmlDocPtr, xmlElementType_XML_DOCUMENT_NODE,
+    xmlElementType_XML_DTD_NODE, xmlElementType_XML_ELEMENT_NODE, xmlElementType_XML_ENTITY_DECL,
+    xmlGetLineNo, xmlGetNodePath, xmlNodePtr, xmlParserErrors_XML_SCHEMATRONV_ASSERT,
+    xmlParserErrors_XML_SCHEMATRONV_REPORT, xmlPatternMatch, xmlXPathCompExprPtr,
+    xmlXPathCompiledEval, xmlXPathContextPtr, xmlXPathEval, xmlXPathFreeContext,
+    xmlXPathFreeObject, xmlXPathNewContext, xmlXPathObjectPtr, xmlXPathObjectType_XPATH_BOOLEAN,
+    xmlXPathObjectType_XPATH_NODESET, xmlXPathObjectType_XPATH_NUMBER,
+    xmlXPathObjectType_XPATH_STRING, xmlXPathObjectType_XPATH_XSLT_TREE, xmlXPathRegisterNs,
+    xmlXPathRegisterVariableNS,
+};
+
+use super::schema::{bytes, owned, Let, Pattern, Schematron, Segment, Test, TestKind};
+
+/// The message buffer of xmlSchematronReportSuccess holds this many
+/// bytes before its terminator.
+const MAX_MESSAGE: usize = 998;
+
+/// A failed assert or a successful report, as xmlSchematronValidateDoc
+/// raises it with `XML_SCHEMATRON_OUT_ERROR`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchematronFailure {
+    /// `XML_SCHEMATRONV_ASSERT` or `XML_SCHEMATRONV_REPORT`.
+    pub code: c_int,
+    /// The node the rule matched, or its first element ancestor as
+    /// xmlRaiseError picks it.
+    pub node: xmlNodePtr,
+    /// The line of the matched node, of `node` if it has none.
+    pub line: c_long,
+    /// The name of the pattern holding the rule.
+    pub pattern: String,
+    /// The XPath of the node, from xmlGetNodePath.
+    pub path: String,
+    /// The formatted message of the assert or report.
+    pub report: String,
+    /// `"<path> line <line>: <report>\n"`, truncated like C.
+    pub message: String,
+}
+
+/// xmlSchematronNextNode: the next node in document order, not going
+/// into entity declarations and skipping DTDs.
+unsafe fn next_node(mut cur: xmlNodePtr) -> xmlNodePtr {
+    let children = (*cur).children;
+    if !children.is_null()
+        && (*children).type_ != xmlElementType_XML_ENTITY_DECL
+        && (*children).type_ != xmlElementType_XML_DTD_NODE
+    {
+        return children;
+    }
+    if !children.is_null() && (*children).type_ != xmlElementType_XML_ENTITY_DECL {
+        cur = children;
+    }
+    while !(*cur).next.is_null() {
+        cur = (*cur).next;
+        if (*cur).type_ != xmlElementType_XML_ENTITY_DECL
+            && (*cur).type_ != xmlElementType_XML_DTD_NODE
+        {
+            return cur;
+        }
+    }
+    loop {
+        cur = (*cur).parent;
+        if cur.is_null() || (*cur).type_ == xmlElementType_XML_DOCUMENT_NODE {
+            return ptr::null_mut();
+        }
+        if !(*cur).next.is_null() {
+            return (*cur).next;
+        }
+    }
+}
+
+unsafe fn error_node(mut node: xmlNodePtr) -> xmlNodePtr {
+    for _ in 0..10 {
+        if (*node).type_ == xmlElementType_XML_ELEMENT_NODE || (*node).parent.is_null() {
+            break;
+        }
+        node = (*node).parent;
+    }
+    node
+}
+
+/// `printf("%g")`: six significant digits without trailing zeros, in
+/// exponent form for exponents below -4 or from 6 on.
+fn format_g(value: f64) -> String {
+    if value.is_nan() {
+        return if value.is_sign_negative() {
+            "-nan"
+        } else {
+            "nan"
+        }
+        .to_string();
+    }
+    if value.is_infinite() {
+        return if value < 0.0 { "-inf" } else { "inf" }.to_string();
+    }
+    if value == 0.0 {
+        return if value.is_sign_negative() { "-0" } else { "0" }.to_string();
+    }
+    fn trim(digits: &str) -> &str {
+        if digits.contains('.') {
+            digits.trim_end_matches('0').trim_end_matches('.')
+        } else {
+            digits
+        }
+    }
+    let sci = format!("{value:.5e}");
+    let (mantissa, exp) = sci.split_once('e').unwrap();
+    let exp: i32 = exp.parse().unwrap();
+    if !(-4..6).contains(&exp) {
+        let sign = if exp < 0 { '-' } else { '+' };
+        format!("{}e{sign}{:02}", trim(mantissa), exp.abs())
+    } else {
+        trim(&format!("{value:.*}", (5 - exp) as usize)).to_string()
+    }
+}
+
+/// The state of xmlSchematronValidateDoc.
+struct Validator {
+    xctxt: xmlXPathContextPtr,
+    doc: xmlDocPtr,
+    failures: Vec<SchematronFailure>,
+}
+
+impl Schematron {
+    /// Check `doc` against the rules of each pattern in turn, like
+    /// xmlSchematronValidateDoc with `XML_SCHEMATRON_OUT_ERROR`. Returns
+    /// the failed asserts and successful reports if any. A document
+    /// without a root element is invalid with nothing to report.
+    pub unsafe fn validate(&self, doc: xmlDocPtr) -> Result<(), Vec<SchematronFailure>> {
+        let root = xmlDocGetRootElement(doc);
+        if root.is_null() {
+            return Err(Vec::new());
+        }
+        let mut ctxt = Validator {
+            xctxt: xmlXPathNewContext(ptr::null_mut()),
+            doc,
+            failures: Vec::new(),
+        };
+        for (prefix, uri) in &self.namespaces {
+            xmlXPathRegisterNs(
+                ctxt.xctxt,
+                prefix.as_ptr() as *const xmlChar,
+                uri.as_ptr() as *const xmlChar,
+            );
+        }
+        for pattern in &self.patterns {
+            ctxt.validate_pattern(pattern, root);
+        }
+        xmlXPathFreeContext(ctxt.xctxt);
+        if ctxt.failures.is_empty() {
+            Ok(())
+        } else {
+            Err(ctxt.failures)
+        }
+    }
+}
+
+impl Validator {
+    unsafe fn validate_pattern(&mut self, pattern: &Pattern, root: xmlNodePtr) {
+        let mut cur = root;
+        while !cur.is_null() {
+            for rule in &pattern.rules {
+                if xmlPatternMatch(rule.pattern, cur) == 1 {
+                    self.register_variables(&rule.lets, cur);
+                    for test in &rule.tests {
+                        self.run_test(test, cur, pattern);
+                    }
+                    self.unregister_variables(&rule.lets);
+                }
+            }
+            cur = next_node(cur);
+        }
+    }
+
+    /// Evaluate the lets of a rule on `cur` and bind them. C stops at
+    /// the first one failing to evaluate but still runs the tests.
+    unsafe fn register_variables(&mut self, lets: &[Let], cur: xmlNodePtr) {
+        (*self.xctxt).doc = self.doc;
+        (*self.xctxt).node = cur;
+        for var in lets {
+            let value = xmlXPathCompiledEval(var.comp, self.xctxt);
+            if value.is_null()
+                || xmlXPathRegisterVariableNS(
+                    self.xctxt,
+                    var.name.as_ptr() as *const xmlChar,
+                    ptr::null(),
+                    value,
+                ) != 0
+            {
+                return;
+            }
+        }
+    }
+
+    unsafe fn unregister_variables(&mut self, lets: &[Let]) {
+        for var in lets {
+            if xmlXPathRegisterVariableNS(
+                self.xctxt,
+                var.name.as_ptr() as *const xmlChar,
+                ptr::null(),
+                ptr::null_mut(),
+            ) != 0
+            {
+                return;
+            }
+        }
+    }
+
+    /// xmlSchematronRunTest: a test fails when its result is empty,
+    /// false, zero or NaN.
+    unsafe fn run_test(&mut self, test: &Test, cur: xmlNodePtr, pattern: &Pattern) {
+        (*self.xctxt).doc = self.doc;
+        (*self.xctxt).node = cur;
+        let ret = xmlXPathCompiledEval(test.comp, self.xctxt);
+        let failed = if ret.is_null() {
+            true
+        } else {
+            let failed = match (*ret).type_ {
+                xmlXPathObjectType_XPATH_NODESET | xmlXPathObjectType_XPATH_XSLT_TREE => {
+                    (*ret).nodesetval.is_null() || (*(*ret).nodesetval).nodeNr == 0
+                }
+                xmlXPathObjectType_XPATH_BOOLEAN => (*ret).boolval == 0,
+                xmlXPathObjectType_XPATH_NUMBER => {
+                    (*ret).floatval.is_nan() || (*ret).floatval == 0.0
+                }
+                xmlXPathObjectType_XPATH_STRING => bytes((*ret).stringval).is_empty(),
+                _ => true,
+            };
+            xmlXPathFreeObject(ret);
+            failed
+        };
+        match test.kind {
+            TestKind::Assert if failed => self.report(test, cur, pattern),
+            TestKind::Report if !failed => self.report(test, cur, pattern),
+            _ => {}
+        }
+    }
+
+    /// xmlSchematronReportSuccess.
+    unsafe fn report(&mut self, test: &Test, cur: xmlNodePtr, pattern: &Pattern) {
+        let line = xmlGetLineNo(cur);
+        let path = owned(xmlGetNodePath(cur)).unwrap_or_else(|| bytes((*cur).name).to_vec());
+        let (code, fallback) = match test.kind {
+            TestKind::Assert => (xmlParserErrors_XML_SCHEMATRONV_ASSERT, "node failed assert"),
+            TestKind::Report => (xmlParserErrors_XML_SCHEMATRONV_REPORT, "node failed report"),
+        };
+        let report = self
+            .format_report(&test.message, cur)
+            .unwrap_or_else(|| fallback.as_bytes().to_vec());
+
+        let mut message = path.clone();
+        message.extend_from_slice(format!(" line {line}: ").as_bytes());
+        message.extend_from_slice(&report);
+        message.push(b'\n');
+        message.truncate(MAX_MESSAGE);
+        let node = error_node(cur);
+        self.failures.push(SchematronFailure {
+            code: code as c_int,
+            node,
+            line: if line == 0 { xmlGetLineNo(node) } else { line },
+            pattern: String::from_utf8_lossy(&pattern.name).into_owned(),
+            path: String::from_utf8_lossy(&path).into_owned(),
+            report: String::from_utf8_lossy(&report).into_owned(),
+            message: String::from_utf8_lossy(&message).into_owned(),
+        });
+    }
+
+    /// xmlSchematronFormatReport: the message of a test for `cur`, none
+    /// if it has no content. Trailing blanks after each part are folded
+    /// into a single space.
+    unsafe fn format_report(&mut self, message: &[Segment], cur: xmlNodePtr) -> Option<Vec<u8>> {
+        let mut ret: Option<Vec<u8>> = None;
+        for segment in message {
+            match segment {
+                Segment::Text(text) => ret.get_or_insert_with(Vec::new).extend_from_slice(text),
+                Segment::Name(path) => {
+                    let mut node = cur;
+                    if let Some(path) = path {
+                        let found = self.get_node(cur, path.as_ptr() as *const xmlChar);
+                        if !found.is_null() {
+                            node = found;
+                        }
+                    }
+                    let out = ret.get_or_insert_with(Vec::new);
+                    if !(*node).ns.is_null() && !(*(*node).ns).prefix.is_null() {
+                        out.extend_from_slice(bytes((*(*node).ns).prefix));
+                        out.push(b':');
+                    }
+                    out.extend_from_slice(bytes((*node).name));
+                }
+                Segment::ValueOf(comp) => self.value_of(*comp, &mut ret),
+            }
+            if let Some(out) = ret.as_mut() {
+                if out
+                    .last()
+                    .is_some_and(|c| matches!(c, b' ' | b'\n' | b'\r' | b'\t'))
+                {
+                    while out
+                        .last()
+                        .is_some_and(|c| matches!(c, b' ' | b'\n' | b'\r' | b'\t'))
+                    {
+                        out.pop();
+                    }
+                    out.push(b' ');
+                }
+            }
+        }
+        ret
+    }
+
+    /// xmlSchematronGetNode: the first node `xpath` selects from `cur`.
+    unsafe fn get_node(&mut self, cur: xmlNodePtr, xpath: *const xmlChar) -> xmlNodePtr {
+        (*self.xctxt).doc = (*cur).doc;
+        (*self.xctxt).node = cur;
+        let ret = xmlXPathEval(xpath, self.xctxt);
+        if ret.is_null() {
+            return ptr::null_mut();
+        }
+        let set = (*ret).nodesetval;
+        let node = if (*ret).type_ == xmlXPathObjectType_XPATH_NODESET
+            && !set.is_null()
+            && (*set).nodeNr > 0
+        {
+            *(*set).nodeTab
+        } else {
+            ptr::null_mut()
+        };
+        xmlXPathFreeObject(ret);
+        node
+    }
+
+    /// Append the value of a `value-of` select: the names of the nodes
+    /// of a node-set, booleans as `True` or `False`, numbers as `%g`.
+    unsafe fn value_of(&mut self, comp: xmlXPathCompExprPtr, ret: &mut Option<Vec<u8>>) {
+        let eval: xmlXPathObjectPtr = xmlXPathCompiledEval(comp, self.xctxt);
+        if eval.is_null() {
+            return;
+        }
+        match (*eval).type_ {
+            xmlXPathObjectType_XPATH_NODESET => {
+                let set = (*eval).nodesetval;
+                if !set.is_null() {
+                    for i in 0..(*set).nodeNr as usize {
+                        let out = ret.get_or_insert_with(Vec::new);
+                        if i > 0 {
+                            out.push(b' ');
+                        }
+                        out.extend_from_slice(bytes((**(*set).nodeTab.add(i)).name));
+                    }
+                }
+            }
+            xmlXPathObjectType_XPATH_BOOLEAN => {
+                let value: &[u8] = if (*eval).boolval != 0 {
+                    b"True"
+                } else {
+                    b"False"
+                };
+                ret.get_or_insert_with(Vec::new).extend_from_slice(value);
+            }
+            xmlXPathObjectType_XPATH_NUMBER => ret
+                .get_or_insert_with(Vec::new)
+                .extend_from_slice(format_g((*eval).floatval).as_bytes()),
+            xmlXPathObjectType_XPATH_STRING if !(*eval).stringval.is_null() => ret
+                .get_or_insert_with(Vec::new)
+                .extend_from_slice(bytes((*eval).stringval)),
+            _ => {}
+        }
+        xmlXPathFreeObject(eval);
+    }
+}
@@ -0,0 +1,280 @@
+#![allow(clippy::missing_safety_doc)]
+
+use std::cell::UnsafeCell;
+use std::mem;
+use std::os::raw::c_uint;
+use std::ptr;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Once, RwLock};
+
+#[cfg(not(feature = "without-catalog"))]
+use crate::static_bindings::xmlCatalogCleanup;
+use crate::static_bindings::xmlCleanupCharEncodingHandlers;
+#[cfg(not(feature = "without-relaxng"))]
+use crate::static_bindings::xmlRelaxNGCleanupTypes;
+#[cfg(not(feature = "without-schemas"))]
+use crate::static_bindings::xmlSchemaCleanupTypes;
+
+extern "C" {
+    // Private in dict.c
+    fn xmlInitRandom();
+    fn xmlCleanupRandom();
+    fn xmlInitDictInternal();
+    fn xmlCleanupDictInternal();
+    // Private in xmlmemory.c
+    fn xmlInitMemoryInternal();
+    fn xmlCleanupMemoryInternal();
+    // Private in globals.c
+    fn xmlInitGlobalsInternal();
+    fn xmlCleanupGlobalsInternal();
+    // Private in encoding.c
+    fn xmlInitEncodingInternal();
+    // Private in xmlIO.c
+    fn xmlInitIOCallbacks();
+    // Private in xpath.c
+    #[cfg(not(feature = "without-xpath"))]
+    fn xmlInitXPathInternal();
+    // Private in catalog.c
+    #[cfg(not(feature = "without-catalog"))]
+    fn xmlInitCatalogInternal();
+    #[cfg(not(feature = "without-catalog"))]
+    fn xmlCleanupCatalogInternal();
+}
+
+/// `xmlMutex`, a plain mutex, laid out as in `private/threads.h` since C
+/// code embeds them in its own statics.
+#[repr(C)]
+pub struct XmlMutex {
+    lock: UnsafeCell<libc::pthread_mutex_t>,
+}
+
+unsafe impl Send for XmlMutex {}
+unsafe impl Sync for XmlMutex {}
+
+impl XmlMutex {
+    /// An unlocked mutex.
+    pub const fn new() -> XmlMutex {
+        XmlMutex {
+            lock: UnsafeCell::new(libc::PTHREAD_MUTEX_INITIALIZER),
+        }
+    }
+
+    /// Set up the mutex at `mutex`, `xmlInitMutex`.
+    pub unsafe fn init(mutex: *mut XmlMutex) {
+        libc::pthread_mutex_init((*mutex).lock.get(), ptr::null());
+    }
+
+    /// Release what `init` set up, `xmlCleanupMutex`.
+    pub unsafe fn cleanup(mutex: *mut XmlMutex) {
+        libc::pthread_mutex_destroy((*mutex).lock.get());
+    }
+
+    /// Wait for the mutex and take it.
+    pub fn lock(&self) {
+        unsafe { libc::pthread_mutex_lock(self.lock.get()) };
+    }
+
+    /// Release the mutex, which this thread must hold.
+    pub unsafe fn unlock(&self) {
+        libc::pthread_mutex_unlock(self.lock.get());
+    }
+}
+
+impl Default for XmlMutex {
+    fn default() -> Self {
+        XmlMutex::new()
+    }
+}
+
+/// `xmlRMutex`, a mutex the thread holding it can take again, laid out as
+/// in `private/threads.h`. The fields after `lock` are guarded by it.
+#[repr(C)]
+pub struct XmlRMutex {
+    lock: UnsafeCell<libc::pthread_mutex_t>,
+    /// How many times the holder took it, 0 if free.
+    held: UnsafeCell<c_uint>,
+    /// Threads waiting on `cv` for it to be free.
+    waiters: UnsafeCell<c_uint>,
+    tid: UnsafeCell<libc::pthread_t>,
+    cv: UnsafeCell<libc::pthread_cond_t>,
+}
+
+unsafe impl Send for XmlRMutex {}
+unsafe impl Sync for XmlRMutex {}
+
+impl XmlRMutex {
+    /// A free mutex.
+    pub const fn new() -> XmlRMutex {
+        XmlRMutex {
+            lock: UnsafeCell::new(libc::PTHREAD_MUTEX_INITIALIZER),
+            held: UnsafeCell::new(0),
+            waiters: UnsafeCell::new(0),
+            tid: UnsafeCell::new(0),
+            cv: UnsafeCell::new(libc::PTHREAD_COND_INITIALIZER),
+        }
+    }
+
+    /// Set up the mutex at `mutex`, `xmlInitRMutex`.
+    pub unsafe fn init(mutex: *mut XmlRMutex) {
+        libc::pthread_mutex_init((*mutex).lock.get(), ptr::null());
+        *(*mutex).held.get() = 0;
+        *(*mutex).waiters.get() = 0;
+        libc::pthread_cond_init((*mutex).cv.get(), ptr::null());
+    }
+
+    /// Release what `init` set up, `xmlCleanupRMutex`.
+    pub unsafe fn cleanup(mutex: *mut XmlRMutex) {
+        libc::pthread_mutex_destroy((*mutex).lock.get());
+        libc::pthread_cond_destroy((*mutex).cv.get());
+    }
+
+    /// Take the mutex, waiting for it unless this thread holds it already.
+    pub fn lock(&self) {
+        unsafe {
+            libc::pthread_mutex_lock(self.lock.get());
+            let me = libc::pthread_self();
+            if *self.held.get() > 0 {
+                if *self.tid.get() == me {
+                    *self.held.get() += 1;
+                    libc::pthread_mutex_unlock(self.lock.get());
+                    return;
+                }
+                *self.waiters.get() += 1;
+                while *self.held.get() > 0 {
+                    libc::pthread_cond_wait(self.cv.get(), self.lock.get());
+                }
+                *self.waiters.get() -= 1;
+            }
+            *self.tid.get() = me;
+            *self.held.get() = 1;
+            libc::pthread_mutex_unlock(self.lock.get());
+        }
+    }
+
+    /// Release the mutex once, which this thread must hold, waking a
+    /// waiter when it is free.
+    pub unsafe fn unlock(&self) {
+        libc::pthread_mutex_lock(self.lock.get());
+        let held = &mut *self.held.get();
+        if *held > 0 {
+            *held -= 1;
+            if *held == 0 {
+                if *self.waiters.get() > 0 {
+                    libc::pthread_cond_signal(self.cv.get());
+                }
+                *self.tid.get() = mem::zeroed();
+            }
+        }
+        libc::pthread_mutex_unlock(self.lock.get());
+    }
+}
+
+impl Default for XmlRMutex {
+    fn default() -> Self {
+        XmlRMutex::new()
+    }
+}
+
+/// The lock of `xmlLockLibrary`. Initialized statically, so unlike in C
+/// setting the library up or cleaning it up leaves it alone, and it can
+/// be held meanwhile.
+static LIBRARY_LOCK: XmlRMutex = XmlRMutex::new();
+
+/// Take the library-wide reentrant lock, `xmlLockLibrary`.
+pub fn lock_library() {
+    LIBRARY_LOCK.lock();
+}
+
+/// Release the library-wide lock, `xmlUnlockLibrary`.
+pub unsafe fn unlock_library() {
+    LIBRARY_LOCK.unlock();
+}
+
+/// Whether the library was set up since it was last cleaned up. Checked
+/// before taking `INIT_ONCE`, so that initialized calls cost one load.
+static INITIALIZED: AtomicBool = AtomicBool::new(false);
+/// Runs the setup. `cleanup_parser` puts a new one in so that the next
+/// use sets the library up again, as it does with `onceControl` in C;
+/// callers only read it otherwise.
+static INIT_ONCE: RwLock<Once> = RwLock::new(Once::new());
+/// How many times the setup ran.
+static INITIALIZATIONS: AtomicUsize = AtomicUsize::new(0);
+
+/// `xmlInitParserInternal`. Nothing called may allocate, since the debug
+/// allocator calls `xmlInitParser` and would wait on itself.
+unsafe fn init_parser_internal() {
+    // Required by xmlInitGlobalsInternal
+    xmlInitRandom();
+    xmlInitMemoryInternal();
+    xmlInitGlobalsInternal();
+    xmlInitDictInternal();
+    xmlInitEncodingInternal();
+    #[cfg(not(feature = "without-xpath"))]
+    xmlInitXPathInternal();
+    xmlInitIOCallbacks();
+    #[cfg(not(feature = "without-catalog"))]
+    xmlInitCatalogInternal();
+
+    INITIALIZATIONS.fetch_add(1, Ordering::Relaxed);
+    INITIALIZED.store(true, Ordering::Release);
+}
+
+/// Set up the global state of libxml2, `xmlInitParser`: the random
+/// seed, the globals, the encoding handlers, the I/O
+/// callbacks and the rest. It runs once, on the first call; concurrent
+/// first calls wait for that one to finish, and later ones return at
+/// once.
+pub fn init_parser() {
+    if INITIALIZED.load(Ordering::Acquire) {
+        return;
+    }
+    INIT_ONCE
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .call_once(|| unsafe { init_parser_internal() });
+}
+
+/// Whether `init_parser` ran since the last `cleanup_parser`.
+pub fn is_initialized() -> bool {
+    INITIALIZED.load(Ordering::Acquire)
+}
+
+/// How many times the global state was set up: once, plus once for each
+/// `cleanup_parser` followed by another use.
+pub fn initializations() -> usize {
+    INITIALIZATIONS.load(Ordering::Relaxed)
+}
+
+/// Free the global state, `xmlCleanupParser`, after which the next use
+/// sets it up again. Does nothing if it is not set up.
+///
+/// # Safety
+///
+/// No other thread may be using libxml2, and nothing it allocated before
+/// may be used after.
+pub unsafe fn cleanup_parser() {
+    if !INITIALIZED.load(Ordering::Acquire) {
+        return;
+    }
+
+    xmlCleanupCharEncodingHandlers();
+    #[cfg(not(feature = "without-catalog"))]
+    {
+        xmlCatalogCleanup();
+        xmlCleanupCatalogInternal();
+    }
+    #[cfg(not(feature = "without-schemas"))]
+    xmlSchemaCleanupTypes();
+    #[cfg(not(feature = "without-relaxng"))]
+    xmlRelaxNGCleanupTypes();
+
+    xmlCleanupDictInternal();
+    xmlCleanupRandom();
+    xmlCleanupGlobalsInternal();
+
+    // Must come after all cleanup functions that call xmlFree
+    xmlCleanupMemoryInternal();
+
+    INITIALIZED.store(false, Ordering::Release);
+    *INIT_ONCE.write().unwrap_or_else(|e| e.into_inner()) = Once::new();
+}
@@ -0,0 +1,133 @@
+#![allow(clippy::missing_safety_doc)]
+
+use super::core::{self, XmlMutex, XmlRMutex};
+use crate::static_bindings::{xmlMutex, xmlRMutex};
+
+// Ensure symbols are exported for linking
+#[cfg(feature = "rust-threads")]
+#[used]
+static THREADS_FFI_LINKAGE: () = ();
+
+/// Set up a mutex embedded in C data.
+#[no_mangle]
+pub unsafe extern "C" fn xmlInitMutex(mutex: *mut xmlMutex) {
+    XmlMutex::init(mutex as *mut XmlMutex);
+}
+
+/// A new mutex, or NULL if out of memory.
+#[no_mangle]
+pub extern "C" fn xmlNewMutex() -> *mut xmlMutex {
+    Box::into_raw(Box::new(XmlMutex::new())) as *mut xmlMutex
+}
+
+/// Release what `xmlInitMutex` set up.
+#[no_mangle]
+pub unsafe extern "C" fn xmlCleanupMutex(mutex: *mut xmlMutex) {
+    XmlMutex::cleanup(mutex as *mut XmlMutex);
+}
+
+/// Free a mutex of `xmlNewMutex`. Ignores NULL.
+#[no_mangle]
+pub unsafe extern "C" fn xmlFreeMutex(tok: *mut xmlMutex) {
+    if tok.is_null() {
+        return;
+    }
+    XmlMutex::cleanup(tok as *mut XmlMutex);
+    drop(Box::from_raw(tok as *mut XmlMutex));
+}
+
+/// Take a mutex. Ignores NULL.
+#[no_mangle]
+pub unsafe extern "C" fn xmlMutexLock(tok: *mut xmlMutex) {
+    if let Some(mutex) = (tok as *const XmlMutex).as_ref() {
+        mutex.lock();
+    }
+}
+
+/// Release a mutex. Ignores NULL.
+#[no_mangle]
+pub unsafe extern "C" fn xmlMutexUnlock(tok: *mut xmlMutex) {
+    if let Some(mutex) = (tok as *const XmlMutex).as_ref() {
+        mutex.unlock();
+    }
+}
+
+/// Set up a reentrant mutex embedded in C data.
+#[no_mangle]
+pub unsafe extern "C" fn xmlInitRMutex(tok: *mut xmlRMutex) {
+    XmlRMutex::init(tok as *mut XmlRMutex);
+}
+
+/// A new reentrant mutex, or NULL if out of memory.
+#[no_mangle]
+pub extern "C" fn xmlNewRMutex() -> *mut xmlRMutex {
+    Box::into_raw(Box::new(XmlRMutex::new())) as *mut xmlRMutex
+}
+
+/// Release what `xmlInitRMutex` set up.
+#[no_mangle]
+pub unsafe extern "C" fn xmlCleanupRMutex(tok: *mut xmlRMutex) {
+    XmlRMutex::cleanup(tok as *mut XmlRMutex);
+}
+
+/// Free a mutex of `xmlNewRMutex`. Ignores NULL.
+#[no_mangle]
+pub unsafe extern "C" fn xmlFreeRMutex(tok: *mut xmlRMutex) {
+    if tok.is_null() {
+        return;
+    }
+    XmlRMutex::cleanup(tok as *mut XmlRMutex);
+    drop(Box::from_raw(tok as *mut XmlRMutex));
+}
+
+/// Take a reentrant mutex, once more if this thread holds it. Ignores
+/// NULL.
+#[no_mangle]
+pub unsafe extern "C" fn xmlRMutexLock(tok: *mut xmlRMutex) {
+    if let Some(mutex) = (tok as *const XmlRMutex).as_ref() {
+        mutex.lock();
+    }
+}
+
+/// Release a reentrant mutex once. Ignores NULL.
+#[no_mangle]
+pub unsafe extern "C" fn xmlRMutexUnlock(tok: *mut xmlRMutex) {
+    if let Some(mutex) = (tok as *const XmlRMutex).as_ref() {
+        mutex.unlock();
+    }
+}
+
+/// Take the library-wide reentrant lock.
+#[no_mangle]
+pub extern "C" fn xmlLockLibrary() {
+    core::lock_library();
+}
+
+/// Release the library-wide lock.
+#[no_mangle]
+pub unsafe extern "C" fn xmlUnlockLibrary() {
+    core::unlock_library();
+}
+
+/// Deprecated alias for `xmlInitParser`.
+#[no_mangle]
+pub extern "C" fn xmlInitThreads() {
+    core::init_parser();
+}
+
+/// Deprecated no-op; `xmlCleanupParser` frees the global state.
+#[no_mangle]
+pub extern "C" fn xmlCleanupThreads() {}
+
+/// Set up the global state, once however many threads call it first.
+#[no_mangle]
+pub extern "C" fn xmlInitParser() {
+    core::init_parser();
+}
+
+/// Free the global state. Only safe at exit, with no other thread in
+/// libxml2; the next call initializes the library again.
+#[no_mangle]
+pub unsafe extern "C" fn xmlCleanupParser() {
+    core::cleanup_parser();
+}
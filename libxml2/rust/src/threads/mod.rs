@@ -1 +1,9 @@
-// Empty stub module for threads
+//! Rust implementation of threads module
+//!
+//! Mutexes laid out as C embeds them, the library lock, and the
+//! once-only setup and cleanup of the global state
+
+pub mod core;
+pub mod ffi;
+
+pub use core::*;
@@ -0,0 +1,879 @@
+#![allow(clippy::missing_safety_doc)]
+
+use std::ffi::CStr;
+use std::os::raw::{c_char, c_int, c_long, c_ushort, c_void};
+use std::ptr;
+
+use crate::static_bindings::{
+    __xmlRegisterNodeDefaultValue, xmlBuildURISafe, xmlChar, xmlDictOwns, xmlDoc, xmlDocPtr,
+    xmlElementType_XML_ATTRIBUTE_NODE, xmlElementType_XML_COMMENT_NODE,
+    xmlElementType_XML_ELEMENT_NODE, xmlElementType_XML_ENTITY_DECL,
+    xmlElementType_XML_ENTITY_REF_NODE, xmlElementType_XML_HTML_DOCUMENT_NODE,
+    xmlElementType_XML_NAMESPACE_DECL, xmlElementType_XML_PI_NODE, xmlElementType_XML_TEXT_NODE,
+    xmlEntityPtr, xmlEntityType_XML_INTERNAL_PREDEFINED_ENTITY, xmlFree, xmlFreeNodeList,
+    xmlGetDocEntity, xmlMalloc, xmlNewDocText, xmlNewNs, xmlNode, xmlNodeGetAttrValue, xmlNodePtr,
+    xmlNsPtr, xmlStrdup,
+};
+
+const XML_XML_NAMESPACE: &CStr = c"http://www.w3.org/XML/1998/namespace";
+
+// Entity flags from private/entities.h
+const XML_ENT_PARSED: c_int = 1 << 0;
+const XML_ENT_EXPANDING: c_int = 1 << 3;
+
+extern "C" {
+    // Private flag from tree.c, set once node callbacks are registered.
+    static xmlRegisterCallbacks: c_int;
+}
+
+/// An allocation failed while building nodes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MemoryError;
+
+/// A piece of node content as `xmlNodeSetContent` reads it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContentToken<'a> {
+    /// Literal text.
+    Text(&'a [u8]),
+    /// A character reference, decoded. Values past the Unicode range are
+    /// clamped to 0x110000; references to 0 and malformed ones are dropped.
+    Char(u32),
+    /// An entity reference by name, without `&` and `;`.
+    Reference(&'a [u8]),
+}
+
+/// Split `value` into text, character references and entity references.
+///
+/// `terminated` says whether the string ends with a NUL right after
+/// `value`, rather than at a length limit: a character reference cut off
+/// by the NUL is dropped, one cut off by the limit is kept, as in C. A
+/// named reference missing its `;` is kept as text, without the `&`.
+pub fn tokenize_content(value: &[u8], terminated: bool) -> Vec<ContentToken<'_>> {
+    let mut tokens = Vec::new();
+    let end = value.len();
+    let mut cur = 0;
+    let mut q = 0;
+
+    while cur < end {
+        if value[cur] != b'&' {
+            cur += 1;
+            continue;
+        }
+        if cur != q {
+            tokens.push(ContentToken::Text(&value[q..cur]));
+        }
+
+        let (radix, digits) = match value.get(cur + 1..cur + 3) {
+            Some(b"#x") => (16, cur + 3),
+            _ if value.get(cur + 1) == Some(&b'#') => (10, cur + 2),
+            _ => (0, cur + 1),
+        };
+        if radix == 0 {
+            cur += 1;
+            q = cur;
+            while cur < end && value[cur] != b';' {
+                cur += 1;
+            }
+            if cur == end {
+                break;
+            }
+            if cur != q {
+                tokens.push(ContentToken::Reference(&value[q..cur]));
+            }
+            cur += 1;
+            q = cur;
+            continue;
+        }
+
+        let mut charval: u32 = 0;
+        cur = digits;
+        loop {
+            if cur == end {
+                if terminated {
+                    // C reads the NUL as a bad digit
+                    charval = 0;
+                }
+                break;
+            }
+            let c = value[cur];
+            if c == b';' {
+                cur += 1;
+                break;
+            }
+            match (c as char).to_digit(radix) {
+                Some(d) => charval = (charval * radix + d).min(0x110000),
+                None => {
+                    charval = 0;
+                    break;
+                }
+            }
+            cur += 1;
+        }
+        q = cur;
+        if charval != 0 {
+            tokens.push(ContentToken::Char(charval));
+        }
+    }
+
+    if cur != q {
+        tokens.push(ContentToken::Text(&value[q..cur]));
+    }
+    tokens
+}
+
+/// Append `val` as UTF-8 the way `xmlCopyCharMultiByte` writes it:
+/// surrogates are encoded like any other value, and anything past the
+/// Unicode range becomes U+FFFD.
+pub fn push_char(buf: &mut Vec<u8>, val: u32) {
+    let val = if val >= 0x110000 { 0xfffd } else { val };
+    if val < 0x80 {
+        buf.push(val as u8);
+        return;
+    }
+    let (lead, mut bits) = if val < 0x800 {
+        ((val >> 6) | 0xc0, 0)
+    } else if val < 0x10000 {
+        ((val >> 12) | 0xe0, 6)
+    } else {
+        ((val >> 18) | 0xf0, 12)
+    };
+    buf.push(lead as u8);
+    loop {
+        buf.push((((val >> bits) & 0x3f) | 0x80) as u8);
+        if bits == 0 {
+            break;
+        }
+        bits -= 6;
+    }
+}
+
+unsafe fn c_bytes<'a>(s: *const xmlChar) -> &'a [u8] {
+    CStr::from_ptr(s as *const c_char).to_bytes()
+}
+
+/// Copy `s` into a NUL-terminated xmlMalloc'ed string.
+pub(crate) unsafe fn dup(s: &[u8]) -> *mut xmlChar {
+    let Some(malloc) = xmlMalloc else {
+        return ptr::null_mut();
+    };
+    let mem = malloc(s.len() + 1) as *mut xmlChar;
+    if mem.is_null() {
+        return ptr::null_mut();
+    }
+    ptr::copy_nonoverlapping(s.as_ptr(), mem, s.len());
+    *mem.add(s.len()) = 0;
+    mem
+}
+
+/// Replace the content of a text-like node with `content`, which the node
+/// takes over, freeing the old content unless it lives in the node itself
+/// or in the document's dictionary.
+pub unsafe fn set_text_content(text: xmlNodePtr, content: *mut xmlChar) {
+    let old = (*text).content;
+    let inline = ptr::addr_of_mut!((*text).properties) as *mut xmlChar;
+    if !old.is_null() && old != inline {
+        let doc = (*text).doc;
+        if doc.is_null() || (*doc).dict.is_null() || xmlDictOwns((*doc).dict, old) == 0 {
+            xmlFree.unwrap()(old as *mut c_void);
+        }
+    }
+    (*text).content = content;
+    (*text).properties = ptr::null_mut();
+}
+
+/// An entity reference node named `name`, which it takes over.
+unsafe fn new_entity_ref(doc: xmlDocPtr, name: *mut xmlChar) -> xmlNodePtr {
+    let node = xmlMalloc.unwrap()(std::mem::size_of::<xmlNode>()) as xmlNodePtr;
+    if node.is_null() {
+        xmlFree.unwrap()(name as *mut c_void);
+        return ptr::null_mut();
+    }
+    ptr::write_bytes(node, 0, 1);
+    (*node).type_ = xmlElementType_XML_ENTITY_REF_NODE;
+    (*node).doc = doc;
+    (*node).name = name;
+
+    if xmlRegisterCallbacks != 0 {
+        if let Some(register) = *__xmlRegisterNodeDefaultValue() {
+            register(node);
+        }
+    }
+    node
+}
+
+/// Nodes of a list being built, linked as they are appended.
+struct NodeList {
+    head: xmlNodePtr,
+    last: xmlNodePtr,
+    parent: xmlNodePtr,
+}
+
+impl NodeList {
+    unsafe fn push(&mut self, node: xmlNodePtr) {
+        (*node).parent = self.parent;
+        if self.last.is_null() {
+            self.head = node;
+        } else {
+            (*self.last).next = node;
+            (*node).prev = self.last;
+        }
+        self.last = node;
+    }
+
+    /// Turn the pending text into a text node.
+    unsafe fn flush(&mut self, doc: xmlDocPtr, text: &mut Vec<u8>) -> Result<(), MemoryError> {
+        if text.is_empty() {
+            return Ok(());
+        }
+        let node = xmlNewDocText(doc, ptr::null());
+        if node.is_null() {
+            return Err(MemoryError);
+        }
+        (*node).content = dup(text);
+        text.clear();
+        if (*node).content.is_null() {
+            xmlFreeNodeList(node);
+            return Err(MemoryError);
+        }
+        self.push(node);
+        Ok(())
+    }
+}
+
+/// Build the text and entity reference nodes for `value`, as read by
+/// `tokenize_content`, and make them the children of `parent` if it is not
+/// null. Predefined entities become text; other references get a node
+/// pointing at the entity, whose own content is parsed the first time.
+///
+/// `None` stands for a NULL or empty string and builds no nodes. Content
+/// that yields no text, such as `&#0;` or a string cut to length 0, is
+/// still an empty text node.
+///
+/// Returns the first node, or `Err` if an allocation failed.
+pub unsafe fn parse_content(
+    doc: xmlDocPtr,
+    parent: xmlNodePtr,
+    value: Option<&[u8]>,
+    terminated: bool,
+) -> Result<xmlNodePtr, MemoryError> {
+    let mut list = NodeList {
+        head: ptr::null_mut(),
+        last: ptr::null_mut(),
+        parent,
+    };
+
+    if let Some(value) = value {
+        if let Err(err) = build_list(doc, &mut list, value, terminated) {
+            if !list.head.is_null() {
+                xmlFreeNodeList(list.head);
+            }
+            return Err(err);
+        }
+    }
+
+    if !parent.is_null() {
+        if !(*parent).children.is_null() {
+            xmlFreeNodeList((*parent).children);
+        }
+        (*parent).children = list.head;
+        (*parent).last = list.last;
+    }
+    Ok(list.head)
+}
+
+unsafe fn build_list(
+    doc: xmlDocPtr,
+    list: &mut NodeList,
+    value: &[u8],
+    terminated: bool,
+) -> Result<(), MemoryError> {
+    let mut text = Vec::new();
+    for token in tokenize_content(value, terminated) {
+        let name = match token {
+            ContentToken::Text(s) => {
+                text.extend_from_slice(s);
+                continue;
+            }
+            ContentToken::Char(val) => {
+                push_char(&mut text, val);
+                continue;
+            }
+            ContentToken::Reference(name) => name,
+        };
+
+        let name = dup(name);
+        if name.is_null() {
+            return Err(MemoryError);
+        }
+        let ent = xmlGetDocEntity(doc, name);
+        if !ent.is_null() && (*ent).etype == xmlEntityType_XML_INTERNAL_PREDEFINED_ENTITY {
+            // Predefined entities don't generate nodes
+            xmlFree.unwrap()(name as *mut c_void);
+            if !(*ent).content.is_null() {
+                text.extend_from_slice(c_bytes((*ent).content));
+            }
+            continue;
+        }
+
+        if let Err(err) = list.flush(doc, &mut text) {
+            xmlFree.unwrap()(name as *mut c_void);
+            return Err(err);
+        }
+        if let Err(err) = expand_entity(doc, ent) {
+            xmlFree.unwrap()(name as *mut c_void);
+            return Err(err);
+        }
+
+        let node = new_entity_ref(doc, name);
+        if node.is_null() {
+            return Err(MemoryError);
+        }
+        (*node).last = ent as xmlNodePtr;
+        if !ent.is_null() {
+            (*node).children = ent as xmlNodePtr;
+            (*node).content = (*ent).content;
+        }
+        list.push(node);
+    }
+
+    if !text.is_empty() {
+        list.flush(doc, &mut text)?;
+    } else if list.head.is_null() {
+        let node = xmlNewDocText(doc, c"".as_ptr() as *const xmlChar);
+        if node.is_null() {
+            return Err(MemoryError);
+        }
+        list.push(node);
+    }
+    Ok(())
+}
+
+/// Parse the content of `ent` into its children the first time it is
+/// referenced, skipping entities that are being expanded already.
+unsafe fn expand_entity(doc: xmlDocPtr, ent: xmlEntityPtr) -> Result<(), MemoryError> {
+    if ent.is_null()
+        || (*ent).flags & (XML_ENT_PARSED | XML_ENT_EXPANDING) != 0
+        || (*ent).content.is_null()
+    {
+        return Ok(());
+    }
+    let content = c_bytes((*ent).content);
+    let value = (!content.is_empty()).then_some(content);
+    (*ent).flags |= XML_ENT_EXPANDING;
+    let ret = parse_content(doc, ent as xmlNodePtr, value, true);
+    (*ent).flags &= !XML_ENT_EXPANDING;
+    ret?;
+    (*ent).flags |= XML_ENT_PARSED;
+    Ok(())
+}
+
+/// Whether `node` has a line number of its own.
+unsafe fn has_line(node: *const xmlNode) -> bool {
+    matches!(
+        (*node).type_,
+        xmlElementType_XML_ELEMENT_NODE
+            | xmlElementType_XML_TEXT_NODE
+            | xmlElementType_XML_COMMENT_NODE
+            | xmlElementType_XML_PI_NODE
+    )
+}
+
+/// The line `node` starts on, or -1 if unknown, as `xmlGetLineNo` finds it.
+///
+/// The `line` field is 16 bits, so the parser stores `u16::MAX` for later
+/// lines. With `XML_PARSE_BIG_LINES` a text node keeps the real line in
+/// `psvi`; otherwise the line is borrowed from the first child or a
+/// sibling. Nodes without a line of their own, such as attributes, use
+/// the previous sibling or the parent element. The search stops five
+/// nodes away.
+pub unsafe fn get_line_no(node: *const xmlNode) -> c_long {
+    line_no(node, 0)
+}
+
+unsafe fn line_no(node: *const xmlNode, depth: u32) -> c_long {
+    if depth >= 5 || node.is_null() {
+        return -1;
+    }
+    let n = &*node;
+    if has_line(node) {
+        let mut result = -1;
+        if n.line == c_ushort::MAX {
+            if n.type_ == xmlElementType_XML_TEXT_NODE && !n.psvi.is_null() {
+                result = n.psvi as isize as c_long;
+            } else if n.type_ == xmlElementType_XML_ELEMENT_NODE && !n.children.is_null() {
+                result = line_no(n.children, depth + 1);
+            } else if !n.next.is_null() {
+                result = line_no(n.next, depth + 1);
+            } else if !n.prev.is_null() {
+                result = line_no(n.prev, depth + 1);
+            }
+        }
+        if result == -1 || result == c_ushort::MAX as c_long {
+            result = n.line as c_long;
+        }
+        result
+    } else if !n.prev.is_null() && has_line(n.prev) {
+        line_no(n.prev, depth + 1)
+    } else if !n.parent.is_null() && (*n.parent).type_ == xmlElementType_XML_ELEMENT_NODE {
+        line_no(n.parent, depth + 1)
+    } else {
+        -1
+    }
+}
+
+/// Whether a base URI is absolute enough to stop looking at ancestors.
+/// Only these three schemes are recognized, as in C.
+fn is_final_base(base: &[u8]) -> bool {
+    base.starts_with(b"http://") || base.starts_with(b"ftp://") || base.starts_with(b"urn:")
+}
+
+/// The base URI of `cur`, as `xmlNodeGetBaseSafe` computes it, or NULL if
+/// there is none. `doc` defaults to the node's document.
+///
+/// In XML, the `xml:base` attributes of `cur` and its ancestors are
+/// resolved from the innermost out with `xmlBuildURISafe`, stopping at an
+/// `http://`, `ftp://` or `urn:` base or at an external entity's URI,
+/// and the result is resolved against the document URL. In HTML, the
+/// `href` of the first `<base>` in `<html>` or `<head>` is used as is.
+///
+/// Errors are the C return codes: 1 for bad arguments or an HTML `<base>`
+/// without `href`, -1 if an allocation failed, and what `xmlBuildURISafe`
+/// returns if resolving failed.
+pub unsafe fn node_get_base(
+    mut doc: *const xmlDoc,
+    mut cur: *const xmlNode,
+) -> Result<*mut xmlChar, c_int> {
+    if cur.is_null() && doc.is_null() {
+        return Err(1);
+    }
+    if !cur.is_null() && (*cur).type_ == xmlElementType_XML_NAMESPACE_DECL {
+        return Err(1);
+    }
+    if doc.is_null() {
+        doc = (*cur).doc;
+    }
+    if !doc.is_null() && (*doc).type_ == xmlElementType_XML_HTML_DOCUMENT_NODE {
+        return html_base(doc);
+    }
+
+    let free = xmlFree.unwrap();
+    let mut ret: *mut xmlChar = ptr::null_mut();
+    while !cur.is_null() {
+        if (*cur).type_ == xmlElementType_XML_ENTITY_DECL {
+            let uri = (*(cur as xmlEntityPtr)).URI;
+            if uri.is_null() {
+                break;
+            }
+            free(ret as *mut c_void);
+            ret = xmlStrdup(uri);
+            return if ret.is_null() { Err(-1) } else { Ok(ret) };
+        }
+        if (*cur).type_ == xmlElementType_XML_ELEMENT_NODE {
+            let mut base = ptr::null_mut();
+            let name = c"base".as_ptr() as *const xmlChar;
+            let ns = XML_XML_NAMESPACE.as_ptr() as *const xmlChar;
+            if xmlNodeGetAttrValue(cur, name, ns, &mut base) < 0 {
+                free(ret as *mut c_void);
+                return Err(-1);
+            }
+            if !base.is_null() {
+                if ret.is_null() {
+                    ret = base;
+                } else {
+                    let mut resolved = ptr::null_mut();
+                    let res = xmlBuildURISafe(ret, base, &mut resolved);
+                    free(ret as *mut c_void);
+                    free(base as *mut c_void);
+                    if res != 0 {
+                        return Err(res);
+                    }
+                    ret = resolved;
+                }
+                if is_final_base(c_bytes(ret)) {
+                    return Ok(ret);
+                }
+            }
+        }
+        cur = (*cur).parent;
+    }
+
+    if !doc.is_null() && !(*doc).URL.is_null() {
+        if ret.is_null() {
+            ret = xmlStrdup((*doc).URL);
+            if ret.is_null() {
+                return Err(-1);
+            }
+        } else {
+            let mut resolved = ptr::null_mut();
+            let res = xmlBuildURISafe(ret, (*doc).URL, &mut resolved);
+            free(ret as *mut c_void);
+            if res != 0 {
+                return Err(res);
+            }
+            ret = resolved;
+        }
+    }
+    Ok(ret)
+}
+
+/// The `href` of the `<base>` of an HTML document, looking through
+/// `<html>` and `<head>` only.
+unsafe fn html_base(doc: *const xmlDoc) -> Result<*mut xmlChar, c_int> {
+    let mut cur = (*doc).children as *const xmlNode;
+    while !cur.is_null() && !(*cur).name.is_null() {
+        if (*cur).type_ != xmlElementType_XML_ELEMENT_NODE {
+            cur = (*cur).next;
+            continue;
+        }
+        let name = c_bytes((*cur).name);
+        if name.eq_ignore_ascii_case(b"html") || name.eq_ignore_ascii_case(b"head") {
+            cur = (*cur).children;
+            continue;
+        }
+        if name.eq_ignore_ascii_case(b"base") {
+            let mut href = ptr::null_mut();
+            let attr = c"href".as_ptr() as *const xmlChar;
+            if xmlNodeGetAttrValue(cur, attr, ptr::null(), &mut href) < 0 {
+                return Err(-1);
+            }
+            return if href.is_null() { Err(1) } else { Ok(href) };
+        }
+        cur = (*cur).next;
+    }
+    Ok(ptr::null_mut())
+}
+
+unsafe fn str_equal(a: *const xmlChar, b: *const xmlChar) -> bool {
+    if a.is_null() || b.is_null() {
+        return a == b;
+    }
+    c_bytes(a) == c_bytes(b)
+}
+
+/// The `xml` namespace declaration of `doc`, kept in `oldNs` and made
+/// on first use.
+unsafe fn ensure_xml_decl(doc: xmlDocPtr) -> Result<xmlNsPtr, MemoryError> {
+    if (*doc).oldNs.is_null() {
+        (*doc).oldNs = new_xml_ns()?;
+    }
+    Ok((*doc).oldNs)
+}
+
+/// An unattached declaration of the `xml` prefix.
+unsafe fn new_xml_ns() -> Result<xmlNsPtr, MemoryError> {
+    let href = XML_XML_NAMESPACE.as_ptr() as *const xmlChar;
+    let ns = xmlNewNs(ptr::null_mut(), href, c"xml".as_ptr() as *const xmlChar);
+    if ns.is_null() {
+        Err(MemoryError)
+    } else {
+        Ok(ns)
+    }
+}
+
+/// The namespace bound to `prefix`, NULL for the default namespace, in
+/// scope of `node`, as `xmlSearchNsSafe` finds it. The `xml` prefix
+/// always resolves, to the document's declaration or, outside a
+/// document, to one added to the nearest element.
+///
+/// The namespace of `node` itself does not count, only its declarations
+/// and the namespaces of its ancestors. NULL is returned if nothing is
+/// in scope, or for a NULL or namespace node.
+pub unsafe fn search_ns(
+    mut node: xmlNodePtr,
+    prefix: *const xmlChar,
+) -> Result<xmlNsPtr, MemoryError> {
+    if node.is_null() || (*node).type_ == xmlElementType_XML_NAMESPACE_DECL {
+        return Ok(ptr::null_mut());
+    }
+    let orig = node;
+    let doc = (*node).doc;
+    let is_xml = !prefix.is_null() && c_bytes(prefix) == b"xml";
+    if !doc.is_null() && is_xml {
+        return ensure_xml_decl(doc);
+    }
+    while (*node).type_ != xmlElementType_XML_ELEMENT_NODE {
+        node = (*node).parent;
+        if node.is_null() {
+            return Ok(ptr::null_mut());
+        }
+    }
+    let parent = node;
+    while !node.is_null() && (*node).type_ == xmlElementType_XML_ELEMENT_NODE {
+        let mut cur = (*node).nsDef;
+        while !cur.is_null() {
+            if str_equal((*cur).prefix, prefix) && !(*cur).href.is_null() {
+                return Ok(cur);
+            }
+            cur = (*cur).next;
+        }
+        if node != orig {
+            let cur = (*node).ns;
+            if !cur.is_null() && str_equal((*cur).prefix, prefix) && !(*cur).href.is_null() {
+                return Ok(cur);
+            }
+        }
+        node = (*node).parent;
+    }
+    if doc.is_null() && is_xml {
+        let ns = new_xml_ns()?;
+        (*ns).next = (*parent).nsDef;
+        (*parent).nsDef = ns;
+        return Ok(ns);
+    }
+    Ok(ptr::null_mut())
+}
+
+/// Whether `prefix`, declared on `ancestor`, is not redeclared on the way
+/// down to `node`: 1 if so, 0 if it is, -1 if `ancestor` is not an
+/// ancestor or an entity lies between, as `xmlNsInScope` answers.
+unsafe fn ns_in_scope(mut node: xmlNodePtr, ancestor: xmlNodePtr, prefix: *const xmlChar) -> c_int {
+    while !node.is_null() && node != ancestor {
+        if (*node).type_ == xmlElementType_XML_ENTITY_REF_NODE
+            || (*node).type_ == xmlElementType_XML_ENTITY_DECL
+        {
+            return -1;
+        }
+        if (*node).type_ == xmlElementType_XML_ELEMENT_NODE {
+            let mut tst = (*node).nsDef;
+            while !tst.is_null() {
+                if str_equal((*tst).prefix, prefix) {
+                    return 0;
+                }
+                tst = (*tst).next;
+            }
+        }
+        node = (*node).parent;
+    }
+    if node != ancestor {
+        return -1;
+    }
+    1
+}
+
+/// A namespace with the URI `href` in scope of `node`, as
+/// `xmlSearchNsByHrefSafe` finds it: declared on the element or an
+/// ancestor and not masked by a redeclaration of its prefix on the way
+/// down. Attributes only take prefixed namespaces. The `xml` namespace
+/// always resolves, as in `search_ns`.
+pub unsafe fn search_ns_by_href(
+    mut node: xmlNodePtr,
+    href: *const xmlChar,
+) -> Result<xmlNsPtr, MemoryError> {
+    if node.is_null() || (*node).type_ == xmlElementType_XML_NAMESPACE_DECL {
+        return Ok(ptr::null_mut());
+    }
+    let orig = node;
+    let doc = (*node).doc;
+    let is_xml = str_equal(href, XML_XML_NAMESPACE.as_ptr() as *const xmlChar);
+    if !doc.is_null() && is_xml {
+        return ensure_xml_decl(doc);
+    }
+    let is_attr = (*node).type_ == xmlElementType_XML_ATTRIBUTE_NODE;
+    while (*node).type_ != xmlElementType_XML_ELEMENT_NODE {
+        node = (*node).parent;
+        if node.is_null() {
+            return Ok(ptr::null_mut());
+        }
+    }
+    let parent = node;
+    let usable = |cur: xmlNsPtr, node: xmlNodePtr| {
+        str_equal((*cur).href, href)
+            && (!is_attr || !(*cur).prefix.is_null())
+            && ns_in_scope(orig, node, (*cur).prefix) == 1
+    };
+    while !node.is_null() && (*node).type_ == xmlElementType_XML_ELEMENT_NODE {
+        let mut cur = (*node).nsDef;
+        while !cur.is_null() {
+            if usable(cur, node) {
+                return Ok(cur);
+            }
+            cur = (*cur).next;
+        }
+        if node != orig {
+            let cur = (*node).ns;
+            if !cur.is_null() && usable(cur, node) {
+                return Ok(cur);
+            }
+        }
+        node = (*node).parent;
+    }
+    if doc.is_null() && is_xml {
+        let ns = new_xml_ns()?;
+        (*ns).next = (*parent).nsDef;
+        (*parent).nsDef = ns;
+        return Ok(ns);
+    }
+    Ok(ptr::null_mut())
+}
+
+/// A declaration of the URI of `ns` for use within `tree`, as
+/// `xmlNewReconciledNs` makes it: one already in scope, or a new one on
+/// `tree`. The new one keeps the prefix, cut to 20 bytes, unless that is
+/// taken; then, or for the default namespace, a number is appended to
+/// the prefix or to `default`. NULL after 1000 numbers or on error.
+unsafe fn new_reconciled_ns(tree: xmlNodePtr, ns: xmlNsPtr) -> xmlNsPtr {
+    if tree.is_null() || (*tree).type_ != xmlElementType_XML_ELEMENT_NODE {
+        return ptr::null_mut();
+    }
+    if ns.is_null() || (*ns).type_ != xmlElementType_XML_NAMESPACE_DECL {
+        return ptr::null_mut();
+    }
+    match search_ns_by_href(tree, (*ns).href) {
+        Ok(def) if !def.is_null() => return def,
+        Ok(_) => {}
+        Err(MemoryError) => return ptr::null_mut(),
+    }
+
+    let base: &[u8] = if (*ns).prefix.is_null() {
+        b"default"
+    } else {
+        let prefix = c_bytes((*ns).prefix);
+        &prefix[..prefix.len().min(20)]
+    };
+    let mut prefix = base.to_vec();
+    let mut counter = 1;
+    loop {
+        prefix.push(0);
+        match search_ns(tree, prefix.as_ptr()) {
+            Ok(def) if def.is_null() => break,
+            Ok(_) => {}
+            Err(MemoryError) => return ptr::null_mut(),
+        }
+        if counter > 1000 {
+            return ptr::null_mut();
+        }
+        prefix = base.to_vec();
+        prefix.extend_from_slice(counter.to_string().as_bytes());
+        counter += 1;
+    }
+    xmlNewNs(tree, (*ns).href, prefix.as_ptr())
+}
+
+/// Point every namespace reference in the subtree at `tree` to a
+/// declaration in scope, as `xmlReconciliateNs` does after a subtree was
+/// moved or copied. Declarations in scope with the same URI are reused;
+/// missing ones are added to `tree` by `new_reconciled_ns`. Each old
+/// namespace is resolved once, so references that shared one keep
+/// sharing its replacement. Entity references are not entered.
+///
+/// Returns 0 on success and -1 if `tree` is not an element of `doc` or
+/// a namespace could not be declared. In that case the reference is
+/// cleared and the walk goes on.
+pub unsafe fn reconciliate_ns(doc: xmlDocPtr, tree: xmlNodePtr) -> c_int {
+    if tree.is_null() || (*tree).type_ != xmlElementType_XML_ELEMENT_NODE {
+        return -1;
+    }
+    if (*tree).doc != doc {
+        return -1;
+    }
+    let mut cache: Vec<(xmlNsPtr, xmlNsPtr)> = Vec::new();
+    let mut ret = 0;
+    let mut reconcile = |ns: &mut xmlNsPtr| {
+        if ns.is_null() {
+            return;
+        }
+        if let Some(&(_, new)) = cache.iter().find(|(old, _)| *old == *ns) {
+            *ns = new;
+            return;
+        }
+        let new = new_reconciled_ns(tree, *ns);
+        if new.is_null() {
+            ret = -1;
+        } else {
+            cache.push((*ns, new));
+        }
+        *ns = new;
+    };
+
+    let mut node = tree;
+    while !node.is_null() {
+        reconcile(&mut (*node).ns);
+        if (*node).type_ == xmlElementType_XML_ELEMENT_NODE {
+            let mut attr = (*node).properties;
+            while !attr.is_null() {
+                reconcile(&mut (*attr).ns);
+                attr = (*attr).next;
+            }
+        }
+
+        // Depth first
+        if !(*node).children.is_null() && (*node).type_ != xmlElementType_XML_ENTITY_REF_NODE {
+            node = (*node).children;
+        } else if node != tree && !(*node).next.is_null() {
+            node = (*node).next;
+        } else if node != tree {
+            while node != tree {
+                if !(*node).parent.is_null() {
+                    node = (*node).parent;
+                }
+                if node != tree && !(*node).next.is_null() {
+                    node = (*node).next;
+                    break;
+                }
+                if (*node).parent.is_null() {
+                    node = ptr::null_mut();
+                    break;
+                }
+            }
+            if node == tree {
+                node = ptr::null_mut();
+            }
+        } else {
+            break;
+        }
+    }
+    ret
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ContentToken::*;
+
+    #[test]
+    fn test_tokenize_text_and_references() {
+        assert_eq!(tokenize_content(b"", true), []);
+        assert_eq!(tokenize_content(b"plain", true), [Text(b"plain")]);
+        assert_eq!(
+            tokenize_content(b"a&amp;b&unknown;c", true),
+            [
+                Text(b"a"),
+                Reference(b"amp"),
+                Text(b"b"),
+                Reference(b"unknown"),
+                Text(b"c")
+            ]
+        );
+        assert_eq!(tokenize_content(b"&;x", true), [Text(b"x")]);
+        // A reference without `;` is kept as text, less the `&`
+        assert_eq!(tokenize_content(b"a&lt", true), [Text(b"a"), Text(b"lt")]);
+    }
+
+    #[test]
+    fn test_tokenize_char_references() {
+        // Only a lowercase `x` starts a hex reference
+        assert_eq!(
+            tokenize_content(b"&#65;&#x42;&#X43;", true),
+            [Char(65), Char(0x42), Text(b"X43;")]
+        );
+        assert_eq!(tokenize_content(b"&#0;&#;&#x;", true), []);
+        assert_eq!(tokenize_content(b"&#9999999;", true), [Char(0x110000)]);
+        // A bad digit drops the reference and keeps what follows
+        assert_eq!(tokenize_content(b"&#1z;", true), [Text(b"z;")]);
+        // Cut off by the end of the string, or by the length limit
+        assert_eq!(tokenize_content(b"&#65", true), []);
+        assert_eq!(tokenize_content(b"&#65", false), [Char(65)]);
+    }
+
+    #[test]
+    fn test_push_char() {
+        let mut buf = Vec::new();
+        for val in [0x41, 0xe9, 0x4e2d, 0x1f600, 0xd800, 0x110000] {
+            push_char(&mut buf, val);
+        }
+        assert_eq!(
+            buf,
+            b"A\xc3\xa9\xe4\xb8\xad\xf0\x9f\x98\x80\xed\xa0\x80\xef\xbf\xbd"
+        );
+    }
+}
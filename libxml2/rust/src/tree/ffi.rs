@@ -0,0 +1,149 @@
+#![allow(clippy::missing_safety_doc)]
+
+use std::ffi::CStr;
+use std::os::raw::{c_char, c_int, c_long};
+use std::ptr;
+use std::slice;
+
+use super::core::{
+    dup, get_line_no, node_get_base, parse_content, reconciliate_ns, set_text_content,
+};
+use crate::static_bindings::{
+    xmlChar, xmlDoc, xmlElementType_XML_ATTRIBUTE_NODE, xmlElementType_XML_CDATA_SECTION_NODE,
+    xmlElementType_XML_COMMENT_NODE, xmlElementType_XML_DOCUMENT_FRAG_NODE,
+    xmlElementType_XML_ELEMENT_NODE, xmlElementType_XML_PI_NODE, xmlElementType_XML_TEXT_NODE,
+    xmlNode, xmlNodePtr,
+};
+
+// Ensure symbols are exported for linking
+#[cfg(feature = "rust-tree")]
+#[used]
+static TREE_FFI_LINKAGE: () = ();
+
+/// The bytes of `content` up to its NUL or the first `len` of them, and
+/// whether the NUL came first. `len < 0` means no limit.
+unsafe fn content_bytes<'a>(content: *const xmlChar, len: c_int) -> (&'a [u8], bool) {
+    if len < 0 {
+        return (CStr::from_ptr(content as *const c_char).to_bytes(), true);
+    }
+    let len = len as usize;
+    let mut n = 0;
+    while n < len && *content.add(n) != 0 {
+        n += 1;
+    }
+    (slice::from_raw_parts(content, n), n < len)
+}
+
+unsafe fn set_content(cur: xmlNodePtr, content: *const xmlChar, len: c_int) -> c_int {
+    if cur.is_null() {
+        return 1;
+    }
+    match (*cur).type_ {
+        xmlElementType_XML_DOCUMENT_FRAG_NODE
+        | xmlElementType_XML_ELEMENT_NODE
+        | xmlElementType_XML_ATTRIBUTE_NODE => {
+            let value = if content.is_null() || *content == 0 {
+                None
+            } else {
+                Some(content_bytes(content, len))
+            };
+            let terminated = value.is_none_or(|(_, terminated)| terminated);
+            if parse_content((*cur).doc, cur, value.map(|(bytes, _)| bytes), terminated).is_err() {
+                return -1;
+            }
+        }
+        xmlElementType_XML_TEXT_NODE
+        | xmlElementType_XML_CDATA_SECTION_NODE
+        | xmlElementType_XML_PI_NODE
+        | xmlElementType_XML_COMMENT_NODE => {
+            // Raw text: no references are parsed, and a length copies that
+            // many bytes even past a NUL, like xmlStrndup
+            let mut copy = ptr::null_mut();
+            if !content.is_null() {
+                let bytes = if len < 0 {
+                    CStr::from_ptr(content as *const c_char).to_bytes()
+                } else {
+                    slice::from_raw_parts(content, len as usize)
+                };
+                copy = dup(bytes);
+                if copy.is_null() {
+                    return -1;
+                }
+            }
+            set_text_content(cur, copy);
+        }
+        _ => {}
+    }
+    0
+}
+
+/// Replace the content of a node. Text, CDATA, comment and PI nodes take
+/// `content` as raw text; elements and attributes get their children
+/// replaced by text and entity reference nodes parsed from it.
+///
+/// Returns 0 on success, 1 for a NULL node and -1 if an allocation failed.
+#[no_mangle]
+pub unsafe extern "C" fn xmlNodeSetContent(cur: *mut xmlNode, content: *const xmlChar) -> c_int {
+    set_content(cur, content, -1)
+}
+
+/// `xmlNodeSetContent` with at most `len` bytes of `content`.
+#[no_mangle]
+pub unsafe extern "C" fn xmlNodeSetContentLen(
+    cur: *mut xmlNode,
+    content: *const xmlChar,
+    len: c_int,
+) -> c_int {
+    set_content(cur, content, len)
+}
+
+/// The line `node` starts on, or -1 if unknown. Lines past 65535 are only
+/// found for nodes parsed with `XML_PARSE_BIG_LINES` or next to such nodes.
+#[no_mangle]
+pub unsafe extern "C" fn xmlGetLineNo(node: *const xmlNode) -> c_long {
+    get_line_no(node)
+}
+
+/// Store the base URI of `cur` in `baseOut`, or NULL if there is none.
+/// `doc` defaults to the node's document.
+///
+/// Returns 0 on success, 1 for bad arguments, -1 if an allocation failed,
+/// or the error of `xmlBuildURISafe`.
+#[no_mangle]
+pub unsafe extern "C" fn xmlNodeGetBaseSafe(
+    doc: *const xmlDoc,
+    cur: *const xmlNode,
+    baseOut: *mut *mut xmlChar,
+) -> c_int {
+    if baseOut.is_null() {
+        return 1;
+    }
+    match node_get_base(doc, cur) {
+        Ok(base) => {
+            *baseOut = base;
+            0
+        }
+        Err(err) => {
+            *baseOut = ptr::null_mut();
+            err
+        }
+    }
+}
+
+/// The base URI of `cur`, to be freed with `xmlFree`, or NULL if there is
+/// none or an error occurred.
+#[no_mangle]
+pub unsafe extern "C" fn xmlNodeGetBase(doc: *const xmlDoc, cur: *const xmlNode) -> *mut xmlChar {
+    node_get_base(doc, cur).unwrap_or(ptr::null_mut())
+}
+
+/// Make every namespace used in `tree` refer to a declaration in scope,
+/// after the subtree was moved or copied. Missing declarations are added
+/// to `tree`.
+///
+/// Returns 0 on success, or -1 if `tree` is not an element of `doc` or
+/// a namespace could not be declared.
+#[no_mangle]
+pub unsafe extern "C" fn xmlReconciliateNs(doc: *mut xmlDoc, tree: *mut xmlNode) -> c_int {
+    reconciliate_ns(doc, tree)
+}
@@ -1 +1,8 @@
-// Empty stub module for tree
+//! Rust implementation of tree module
+//!
+//! Tree manipulation API, starting with setting node content
+
+pub mod core;
+pub mod ffi;
+
+pub use core::*;
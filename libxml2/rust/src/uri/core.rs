@@ -0,0 +1,859 @@
+#![allow(clippy::missing_safety_doc)]
+
+use std::os::raw::c_int;
+
+/// Output limit of `xmlSaveUri`.
+pub const MAX_URI_LENGTH: usize = 1024 * 1024;
+
+/// No port was given.
+pub const PORT_EMPTY: c_int = 0;
+/// An authority with an empty server was given, as in `file:///x`.
+pub const PORT_EMPTY_SERVER: c_int = -1;
+
+/// Bits of `Uri::cleanup`.
+pub const URI_ALLOW_UNWISE: c_int = 1;
+pub const URI_NO_UNESCAPE: c_int = 2;
+pub const URI_ALLOW_UCSCHAR: c_int = 4;
+
+/// A parsed URI reference, field for field the C `xmlURI`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Uri {
+    pub scheme: Option<Vec<u8>>,
+    pub opaque: Option<Vec<u8>>,
+    pub authority: Option<Vec<u8>>,
+    pub server: Option<Vec<u8>>,
+    pub user: Option<Vec<u8>>,
+    pub port: c_int,
+    pub path: Option<Vec<u8>>,
+    pub query: Option<Vec<u8>>,
+    pub fragment: Option<Vec<u8>>,
+    pub cleanup: c_int,
+    pub query_raw: Option<Vec<u8>>,
+}
+
+fn is_alpha(c: u8) -> bool {
+    c.is_ascii_alphabetic()
+}
+
+fn is_digit(c: u8) -> bool {
+    c.is_ascii_digit()
+}
+
+fn is_hex(c: u8) -> bool {
+    c.is_ascii_hexdigit()
+}
+
+/// RFC 2396 mark, used by the serializer.
+fn is_mark(c: u8) -> bool {
+    matches!(
+        c,
+        b'-' | b'_' | b'.' | b'!' | b'~' | b'*' | b'\'' | b'(' | b')'
+    )
+}
+
+/// RFC 2396 unreserved, used by the serializer.
+fn is_unreserved(c: u8) -> bool {
+    c.is_ascii_alphanumeric() || is_mark(c)
+}
+
+/// RFC 2396 reserved plus `[` and `]`, used by the serializer.
+fn is_reserved(c: u8) -> bool {
+    matches!(
+        c,
+        b';' | b'/' | b'?' | b':' | b'@' | b'&' | b'=' | b'+' | b'$' | b',' | b'[' | b']'
+    )
+}
+
+fn is_unwise(c: u8) -> bool {
+    matches!(c, b'{' | b'}' | b'|' | b'\\' | b'^' | b'[' | b']' | b'`')
+}
+
+fn is_sub_delim(c: u8) -> bool {
+    matches!(
+        c,
+        b'!' | b'$' | b'&' | b'(' | b')' | b'*' | b'+' | b',' | b';' | b'=' | b'\''
+    )
+}
+
+fn is_strictly_unreserved(c: u8) -> bool {
+    c.is_ascii_alphanumeric() || matches!(c, b'-' | b'.' | b'_' | b'~')
+}
+
+/// Characters LEIRI allows on top of RFC 3986. The terminating NUL counts
+/// too, as in C; callers stop at the end of input before asking.
+fn is_ucschar(c: u8) -> bool {
+    c <= 0x20
+        || c >= 0x7F
+        || matches!(
+            c,
+            b'<' | b'>' | b'"' | b'{' | b'}' | b'|' | b'\\' | b'^' | b'`'
+        )
+}
+
+/// Replace `%XX` escapes by the byte they encode.
+pub fn unescape(s: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(s.len());
+    let mut i = 0;
+    while i < s.len() {
+        if s.len() - i > 2 && s[i] == b'%' && is_hex(s[i + 1]) && is_hex(s[i + 2]) {
+            let hex = |c: u8| (c as char).to_digit(16).unwrap() as u8;
+            out.push(hex(s[i + 1]) * 16 + hex(s[i + 2]));
+            i += 3;
+        } else {
+            out.push(s[i]);
+            i += 1;
+        }
+    }
+    out
+}
+
+/// The `key=value` pairs of the query of `uri`, split on `&` and `;` and
+/// with keys and values unescaped. libxml2 only keeps the query as one
+/// string, so this is for application code.
+///
+/// The raw query is split when there is one, so an escaped `&` or `=` is
+/// part of a key or value. Otherwise `query` is split as it is, since it
+/// is already unescaped. Empty pairs are skipped, a pair without `=` has
+/// an empty value, and `+` is kept as is. Bytes that are not UTF-8 after
+/// unescaping are replaced by U+FFFD.
+pub fn query_pairs(uri: &Uri) -> Vec<(String, String)> {
+    let (query, escaped) = match (&uri.query_raw, &uri.query) {
+        (Some(raw), _) => (raw, true),
+        (None, Some(query)) => (query, false),
+        (None, None) => return Vec::new(),
+    };
+    let decode = |s: &[u8]| {
+        if escaped {
+            String::from_utf8_lossy(&unescape(s)).into_owned()
+        } else {
+            String::from_utf8_lossy(s).into_owned()
+        }
+    };
+    query
+        .split(|&c| c == b'&' || c == b';')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| match pair.iter().position(|&c| c == b'=') {
+            Some(eq) => (decode(&pair[..eq]), decode(&pair[eq + 1..])),
+            None => (decode(pair), String::new()),
+        })
+        .collect()
+}
+
+/// Escape every byte of `s` as `%XX` except unreserved characters, `@`
+/// and the bytes in `keep`, like `xmlURIEscapeStr`.
+pub fn escape_str(s: &[u8], keep: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(s.len() + 20);
+    for &c in s {
+        if c == b'@' || is_unreserved(c) || keep.contains(&c) {
+            out.push(c);
+        } else {
+            out.push(b'%');
+            out.push(b"0123456789ABCDEF"[(c >> 4) as usize]);
+            out.push(b"0123456789ABCDEF"[(c & 0xF) as usize]);
+        }
+    }
+    out
+}
+
+/// Prepare a resource locator for use as a URI, like `xmlCanonicPath`.
+///
+/// A path containing `://` is a legacy extended IRI: everything but
+/// reserved and unreserved characters and `%` is escaped, so spaces and
+/// non-ASCII bytes are while existing escapes are kept. Any other path is
+/// a filesystem path and is returned unchanged, on every platform.
+pub fn canonic_path(path: &[u8]) -> Vec<u8> {
+    if path.windows(3).any(|w| w == b"://") {
+        escape_str(path, b":/?#[]@!$&()*+,;='%")
+    } else {
+        path.to_vec()
+    }
+}
+
+/// RFC 3986 parser over a string without its terminating NUL. Each
+/// production takes the cursor, advances it on success and returns
+/// whether it matched, like the `xmlParse3986*` functions.
+struct Parser<'a> {
+    uri: &'a mut Uri,
+    s: &'a [u8],
+}
+
+impl Parser<'_> {
+    /// Byte at `i`, reading NUL at the end of input.
+    fn at(&self, i: usize) -> u8 {
+        self.s.get(i).copied().unwrap_or(0)
+    }
+
+    fn unreserved(&self, i: usize) -> bool {
+        let c = self.at(i);
+        if is_strictly_unreserved(c) {
+            return true;
+        }
+        if i >= self.s.len() {
+            return false;
+        }
+        if self.uri.cleanup & URI_ALLOW_UNWISE != 0 {
+            is_unwise(c)
+        } else if self.uri.cleanup & URI_ALLOW_UCSCHAR != 0 {
+            is_ucschar(c)
+        } else {
+            false
+        }
+    }
+
+    fn pct_encoded(&self, i: usize) -> bool {
+        self.at(i) == b'%' && is_hex(self.at(i + 1)) && is_hex(self.at(i + 2))
+    }
+
+    fn pchar(&self, i: usize) -> bool {
+        let c = self.at(i);
+        self.unreserved(i) || self.pct_encoded(i) || is_sub_delim(c) || c == b':' || c == b'@'
+    }
+
+    /// Skip one character, or a whole escape sequence.
+    fn next(&self, i: usize) -> usize {
+        if self.at(i) == b'%' {
+            i + 3
+        } else {
+            i + 1
+        }
+    }
+
+    /// Value of a component, unescaped unless `URI_NO_UNESCAPE` is set.
+    fn component(&self, start: usize, end: usize) -> Vec<u8> {
+        if self.uri.cleanup & URI_NO_UNESCAPE != 0 {
+            return self.s[start..end].to_vec();
+        }
+        // xmlURIUnescapeString reads a length of 0 as "up to the NUL".
+        let end = if end == start { self.s.len() } else { end };
+        let mut value = unescape(&self.s[start..end]);
+        // C readers of the component stop at an escaped NUL.
+        if let Some(nul) = value.iter().position(|&c| c == 0) {
+            value.truncate(nul);
+        }
+        value
+    }
+
+    /// ALPHA *( ALPHA / DIGIT / "+" / "-" / "." )
+    fn scheme(&mut self, cur: &mut usize) -> bool {
+        let start = *cur;
+        let mut i = start;
+        if !is_alpha(self.at(i)) {
+            return false;
+        }
+        i += 1;
+        while is_alpha(self.at(i))
+            || is_digit(self.at(i))
+            || matches!(self.at(i), b'+' | b'-' | b'.')
+        {
+            i += 1;
+        }
+        self.uri.scheme = Some(self.s[start..i].to_vec());
+        *cur = i;
+        true
+    }
+
+    /// *( pchar / "/" / "?" ), plus `[` and `]` for XPointer.
+    fn fragment(&mut self, cur: &mut usize) -> bool {
+        let start = *cur;
+        let mut i = start;
+        while self.pchar(i) || matches!(self.at(i), b'/' | b'?' | b'[' | b']') {
+            i = self.next(i);
+        }
+        self.uri.fragment = Some(self.component(start, i));
+        *cur = i;
+        true
+    }
+
+    /// *( pchar / "/" / "?" )
+    fn query(&mut self, cur: &mut usize) -> bool {
+        let start = *cur;
+        let mut i = start;
+        while self.pchar(i) || matches!(self.at(i), b'/' | b'?') {
+            i = self.next(i);
+        }
+        self.uri.query = Some(self.component(start, i));
+        self.uri.query_raw = Some(self.s[start..i].to_vec());
+        *cur = i;
+        true
+    }
+
+    /// *DIGIT, failing on int overflow.
+    fn port(&mut self, cur: &mut usize) -> bool {
+        let mut i = *cur;
+        if !is_digit(self.at(i)) {
+            return false;
+        }
+        let mut port: c_int = 0;
+        while is_digit(self.at(i)) {
+            let digit = (self.at(i) - b'0') as c_int;
+            port = match port.checked_mul(10).and_then(|p| p.checked_add(digit)) {
+                Some(port) => port,
+                None => return false,
+            };
+            i += 1;
+        }
+        self.uri.port = port;
+        *cur = i;
+        true
+    }
+
+    /// *( unreserved / pct-encoded / sub-delims / ":" ) "@"
+    fn userinfo(&mut self, cur: &mut usize) -> bool {
+        let start = *cur;
+        let mut i = start;
+        while self.unreserved(i)
+            || self.pct_encoded(i)
+            || is_sub_delim(self.at(i))
+            || self.at(i) == b':'
+        {
+            i = self.next(i);
+        }
+        if self.at(i) != b'@' {
+            return false;
+        }
+        self.uri.user = Some(self.component(start, i));
+        *cur = i;
+        true
+    }
+
+    /// Skip a dec-octet. For `25x` C checks the second digit where it
+    /// means the third, so any `x` from `0` up is accepted here too.
+    fn dec_octet(&self, cur: &mut usize) -> bool {
+        let i = *cur;
+        let (c0, c1, c2) = (self.at(i), self.at(i + 1), self.at(i + 2));
+        if !is_digit(c0) {
+            return false;
+        }
+        let len = if !is_digit(c1) {
+            1
+        } else if c0 != b'0' && is_digit(c1) && !is_digit(c2) {
+            2
+        } else if (c0 == b'1' && is_digit(c1) && is_digit(c2))
+            || (c0 == b'2' && (b'0'..=b'4').contains(&c1) && is_digit(c2))
+            || (c0 == b'2' && c1 == b'5' && c2 >= b'0')
+        {
+            3
+        } else {
+            return false;
+        };
+        *cur = i + len;
+        true
+    }
+
+    /// The C parser never advances past the second dot, so this never
+    /// matches; the reg-name branch accepts the same characters anyway.
+    fn ipv4(&self, cur: &mut usize) -> bool {
+        let mut i = *cur;
+        if !self.dec_octet(&mut i) || self.at(i) != b'.' {
+            return false;
+        }
+        i += 1;
+        if !self.dec_octet(&mut i) || self.at(i) != b'.' {
+            return false;
+        }
+        if !self.dec_octet(&mut i) || self.at(i) != b'.' {
+            return false;
+        }
+        if !self.dec_octet(&mut i) {
+            return false;
+        }
+        *cur = i;
+        true
+    }
+
+    /// IP-literal / IPv4address / reg-name
+    fn host(&mut self, cur: &mut usize) -> bool {
+        let start = *cur;
+        let mut i = start;
+        if self.at(i) == b'[' {
+            i += 1;
+            while self.at(i) != b']' && self.at(i) != 0 {
+                i += 1;
+            }
+            if self.at(i) != b']' {
+                return false;
+            }
+            i += 1;
+        } else if !(is_digit(self.at(i)) && self.ipv4(&mut i)) {
+            while self.unreserved(i) || self.pct_encoded(i) || is_sub_delim(self.at(i)) {
+                i = self.next(i);
+            }
+        }
+        self.uri.authority = None;
+        self.uri.server = if i != start {
+            Some(self.component(start, i))
+        } else {
+            None
+        };
+        *cur = i;
+        true
+    }
+
+    /// [ userinfo "@" ] host [ ":" port ]
+    fn authority(&mut self, cur: &mut usize) -> bool {
+        let mut i = *cur;
+        if self.userinfo(&mut i) {
+            i += 1;
+        } else {
+            i = *cur;
+        }
+        if !self.host(&mut i) {
+            return false;
+        }
+        if self.at(i) == b':' {
+            i += 1;
+            if !self.port(&mut i) {
+                return false;
+            }
+        }
+        *cur = i;
+        true
+    }
+
+    /// A segment, optionally non-empty and without `forbid`.
+    fn segment(&mut self, cur: &mut usize, forbid: u8, empty: bool) -> bool {
+        let mut i = *cur;
+        if !self.pchar(i) || self.at(i) == forbid {
+            return empty;
+        }
+        i = self.next(i);
+        while self.pchar(i) && self.at(i) != forbid {
+            i = self.next(i);
+        }
+        *cur = i;
+        true
+    }
+
+    fn set_path(&mut self, start: usize, end: usize) {
+        self.uri.path = if end != start {
+            Some(self.component(start, end))
+        } else {
+            None
+        };
+    }
+
+    /// *( "/" segment )
+    fn path_abempty(&mut self, cur: &mut usize) -> bool {
+        let start = *cur;
+        let mut i = start;
+        while self.at(i) == b'/' {
+            i += 1;
+            if !self.segment(&mut i, 0, true) {
+                return false;
+            }
+        }
+        self.set_path(start, i);
+        *cur = i;
+        true
+    }
+
+    /// "/" [ segment-nz *( "/" segment ) ]
+    fn path_absolute(&mut self, cur: &mut usize) -> bool {
+        let start = *cur;
+        let mut i = start;
+        if self.at(i) != b'/' {
+            return false;
+        }
+        i += 1;
+        if self.segment(&mut i, 0, false) {
+            while self.at(i) == b'/' {
+                i += 1;
+                if !self.segment(&mut i, 0, true) {
+                    return false;
+                }
+            }
+        }
+        self.set_path(start, i);
+        *cur = i;
+        true
+    }
+
+    /// segment-nz *( "/" segment ), or segment-nz-nc first when `forbid`
+    /// is `:`.
+    fn path_rootless(&mut self, cur: &mut usize, forbid: u8) -> bool {
+        let start = *cur;
+        let mut i = start;
+        if !self.segment(&mut i, forbid, false) {
+            return false;
+        }
+        while self.at(i) == b'/' {
+            i += 1;
+            if !self.segment(&mut i, 0, true) {
+                return false;
+            }
+        }
+        self.set_path(start, i);
+        *cur = i;
+        true
+    }
+
+    /// [ "?" query ] [ "#" fragment ] and the end of input.
+    fn query_fragment_end(&mut self, cur: &mut usize) -> bool {
+        if self.at(*cur) == b'?' {
+            *cur += 1;
+            self.query(cur);
+        }
+        if self.at(*cur) == b'#' {
+            *cur += 1;
+            self.fragment(cur);
+        }
+        if *cur < self.s.len() {
+            self.uri.clean();
+            return false;
+        }
+        true
+    }
+
+    /// "//" authority path-abempty / path-absolute / path-rootless /
+    /// path-empty
+    fn hier_part(&mut self, cur: &mut usize) -> bool {
+        let mut i = *cur;
+        if self.at(i) == b'/' && self.at(i + 1) == b'/' {
+            i += 2;
+            if !self.authority(&mut i) {
+                return false;
+            }
+            // An empty server is marked with a special port value.
+            if self.uri.server.is_none() && self.uri.port == PORT_EMPTY {
+                self.uri.port = PORT_EMPTY_SERVER;
+            }
+            if !self.path_abempty(&mut i) {
+                return false;
+            }
+        } else if self.at(i) == b'/' {
+            if !self.path_absolute(&mut i) {
+                return false;
+            }
+        } else if self.pchar(i) {
+            if !self.path_rootless(&mut i, 0) {
+                return false;
+            }
+        } else {
+            self.uri.path = None;
+        }
+        *cur = i;
+        true
+    }
+
+    /// relative-part [ "?" query ] [ "#" fragment ]
+    fn relative_ref(&mut self) -> bool {
+        let mut i = 0;
+        if self.at(0) == b'/' && self.at(1) == b'/' {
+            i = 2;
+            if !self.authority(&mut i) || !self.path_abempty(&mut i) {
+                return false;
+            }
+        } else if self.at(0) == b'/' {
+            if !self.path_absolute(&mut i) {
+                return false;
+            }
+        } else if self.pchar(0) {
+            if !self.path_rootless(&mut i, b':') {
+                return false;
+            }
+        } else {
+            self.uri.path = None;
+        }
+        self.query_fragment_end(&mut i)
+    }
+
+    /// scheme ":" hier-part [ "?" query ] [ "#" fragment ]
+    fn absolute(&mut self) -> bool {
+        let mut i = 0;
+        if !self.scheme(&mut i) || self.at(i) != b':' {
+            return false;
+        }
+        i += 1;
+        if !self.hier_part(&mut i) {
+            return false;
+        }
+        self.query_fragment_end(&mut i)
+    }
+}
+
+impl Uri {
+    /// An empty URI, as returned by `xmlCreateURI`.
+    pub fn new() -> Uri {
+        Uri::default()
+    }
+
+    /// Parse a URI reference. Returns `None` if it is invalid.
+    pub fn parse(s: &[u8]) -> Option<Uri> {
+        Uri::parse_raw(s, false)
+    }
+
+    /// Parse a URI reference, keeping escapes intact if `raw` is set.
+    pub fn parse_raw(s: &[u8], raw: bool) -> Option<Uri> {
+        let mut uri = Uri::new();
+        if raw {
+            uri.cleanup |= URI_NO_UNESCAPE;
+        }
+        match uri.parse_reference(s) {
+            0 => Some(uri),
+            _ => None,
+        }
+    }
+
+    /// Parse a URI reference into `self`, honouring its `cleanup` flags.
+    /// Returns 0 on success or 1 if the string is not a URI reference, in
+    /// which case `self` is cleaned.
+    ///
+    /// Like C, this leaves `port` alone when cleaning, so a port from a
+    /// previous parse survives one that does not set it.
+    pub fn parse_reference(&mut self, s: &[u8]) -> c_int {
+        self.clean();
+        // Absolute URIs first, then relative references.
+        if (Parser { uri: self, s }).absolute() {
+            return 0;
+        }
+        self.clean();
+        if (Parser { uri: self, s }).relative_ref() {
+            return 0;
+        }
+        self.clean();
+        1
+    }
+
+    /// Drop every component, as `xmlCleanURI` does.
+    pub fn clean(&mut self) {
+        self.scheme = None;
+        self.server = None;
+        self.user = None;
+        self.path = None;
+        self.fragment = None;
+        self.opaque = None;
+        self.authority = None;
+        self.query = None;
+        self.query_raw = None;
+    }
+
+    /// Serialize the URI, escaping each component as `xmlSaveUri` does.
+    /// Returns `None` if the result would exceed `MAX_URI_LENGTH`.
+    pub fn save(&self) -> Option<Vec<u8>> {
+        fn escaped(out: &mut Vec<u8>, s: &[u8], keep: impl Fn(u8) -> bool) {
+            for &c in s {
+                if keep(c) {
+                    out.push(c);
+                } else {
+                    out.push(b'%');
+                    out.push(b"0123456789ABCDEF"[(c >> 4) as usize]);
+                    out.push(b"0123456789ABCDEF"[(c & 0xF) as usize]);
+                }
+            }
+        }
+
+        let mut out = Vec::with_capacity(80);
+        if let Some(scheme) = &self.scheme {
+            out.extend_from_slice(scheme);
+            out.push(b':');
+        }
+        if let Some(opaque) = &self.opaque {
+            escaped(&mut out, opaque, |c| is_reserved(c) || is_unreserved(c));
+        } else {
+            if self.server.is_some() || self.port != PORT_EMPTY {
+                out.extend_from_slice(b"//");
+                if let Some(user) = &self.user {
+                    escaped(&mut out, user, |c| {
+                        is_unreserved(c)
+                            || matches!(c, b';' | b':' | b'&' | b'=' | b'+' | b'$' | b',')
+                    });
+                    out.push(b'@');
+                }
+                if let Some(server) = &self.server {
+                    out.extend_from_slice(server);
+                }
+                if self.port > 0 {
+                    out.extend_from_slice(format!(":{}", self.port).as_bytes());
+                }
+            } else if let Some(authority) = &self.authority {
+                out.extend_from_slice(b"//");
+                escaped(&mut out, authority, |c| {
+                    is_unreserved(c)
+                        || matches!(c, b'$' | b',' | b';' | b':' | b'@' | b'&' | b'=' | b'+')
+                });
+            }
+            if let Some(mut path) = self.path.as_deref() {
+                // The colon in file:///d: must not be escaped.
+                if self.scheme.as_deref() == Some(b"file")
+                    && path.len() >= 3
+                    && path[0] == b'/'
+                    && is_alpha(path[1])
+                    && path[2] == b':'
+                {
+                    out.extend_from_slice(&path[..3]);
+                    path = &path[3..];
+                }
+                escaped(&mut out, path, |c| {
+                    is_unreserved(c)
+                        || matches!(c, b'/' | b';' | b'@' | b'&' | b'=' | b'+' | b'$' | b',')
+                });
+            }
+            if let Some(query_raw) = &self.query_raw {
+                out.push(b'?');
+                out.extend_from_slice(query_raw);
+            } else if let Some(query) = &self.query {
+                out.push(b'?');
+                escaped(&mut out, query, |c| is_unreserved(c) || is_reserved(c));
+            }
+        }
+        if let Some(fragment) = &self.fragment {
+            out.push(b'#');
+            escaped(&mut out, fragment, |c| is_unreserved(c) || is_reserved(c));
+        }
+        if out.len() >= MAX_URI_LENGTH {
+            return None;
+        }
+        Some(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn s(v: &[u8]) -> Option<Vec<u8>> {
+        Some(v.to_vec())
+    }
+
+    #[test]
+    fn test_parse_absolute() {
+        let uri = Uri::parse(b"http://user@example.com:8080/a/b%20c?x=1#frag").unwrap();
+        assert_eq!(uri.scheme, s(b"http"));
+        assert_eq!(uri.user, s(b"user"));
+        assert_eq!(uri.server, s(b"example.com"));
+        assert_eq!(uri.port, 8080);
+        assert_eq!(uri.path, s(b"/a/b c"));
+        assert_eq!(uri.query, s(b"x=1"));
+        assert_eq!(uri.query_raw, s(b"x=1"));
+        assert_eq!(uri.fragment, s(b"frag"));
+        assert_eq!(
+            uri.save().unwrap(),
+            b"http://user@example.com:8080/a/b%20c?x=1#frag"
+        );
+    }
+
+    #[test]
+    fn test_parse_relative() {
+        let uri = Uri::parse(b"../a/b").unwrap();
+        assert_eq!(uri.scheme, None);
+        assert_eq!(uri.path, s(b"../a/b"));
+
+        let uri = Uri::parse(b"//host").unwrap();
+        assert_eq!(uri.server, s(b"host"));
+        assert_eq!(uri.path, None);
+
+        assert_eq!(Uri::parse(b"a:b:c").unwrap().path, s(b"b:c"));
+        assert!(Uri::parse(b"a b").is_none());
+        assert!(Uri::parse(b"http://h:/p").is_none());
+        assert!(Uri::parse(b"http://h:99999999999/").is_none());
+    }
+
+    #[test]
+    fn test_empty_server() {
+        let uri = Uri::parse(b"file:///etc/hosts").unwrap();
+        assert_eq!(uri.server, None);
+        assert_eq!(uri.port, PORT_EMPTY_SERVER);
+        assert_eq!(uri.save().unwrap(), b"file:///etc/hosts");
+    }
+
+    #[test]
+    fn test_empty_component_quirk() {
+        // An empty component is unescaped up to the end of input.
+        let uri = Uri::parse(b"http://a/b?#f").unwrap();
+        assert_eq!(uri.query, s(b"#f"));
+        assert_eq!(uri.query_raw, s(b""));
+        assert_eq!(uri.fragment, s(b"f"));
+
+        let uri = Uri::parse(b"//@host/x").unwrap();
+        assert_eq!(uri.user, s(b"@host/x"));
+    }
+
+    #[test]
+    fn test_raw() {
+        let uri = Uri::parse_raw(b"a/%41?%42#%43", true).unwrap();
+        assert_eq!(uri.path, s(b"a/%41"));
+        assert_eq!(uri.query, s(b"%42"));
+        assert_eq!(uri.fragment, s(b"%43"));
+    }
+
+    #[test]
+    fn test_unescape() {
+        assert_eq!(unescape(b"%41%4a%"), b"AJ%");
+        assert_eq!(unescape(b"a%00b"), b"a\0b");
+        assert_eq!(Uri::parse(b"a%00b").unwrap().path, s(b"a"));
+    }
+
+    #[test]
+    fn test_save_escapes() {
+        let uri = Uri {
+            scheme: s(b"file"),
+            path: s(b"/c:/a b"),
+            port: PORT_EMPTY_SERVER,
+            fragment: s(b"x y"),
+            ..Uri::new()
+        };
+        assert_eq!(uri.save().unwrap(), b"file:///c:/a%20b#x%20y");
+    }
+
+    #[test]
+    fn test_canonic_path() {
+        assert_eq!(canonic_path(b"/tmp/a b.xml"), b"/tmp/a b.xml");
+        assert_eq!(canonic_path(b"C:\\dir\\f.xml"), b"C:\\dir\\f.xml");
+        assert_eq!(
+            canonic_path(b"http://h/a b/%41?q=<\xc3\xa9>#f"),
+            b"http://h/a%20b/%41?q=%3C%C3%A9%3E#f"
+        );
+        assert_eq!(
+            canonic_path(b"file://C:\\dir\\f.xml"),
+            b"file://C:%5Cdir%5Cf.xml"
+        );
+        assert_eq!(escape_str(b"a b@c/", b""), b"a%20b@c%2F");
+    }
+
+    #[test]
+    fn test_query_pairs() {
+        let pairs = |s: &[u8]| query_pairs(&Uri::parse(s).unwrap());
+        let owned = |v: &[(&str, &str)]| -> Vec<(String, String)> {
+            v.iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect()
+        };
+
+        assert_eq!(
+            pairs(b"/s?q=a%20b%26c&k%3D=%C3%A9;x=1+1"),
+            owned(&[("q", "a b&c"), ("k=", "é"), ("x", "1+1")])
+        );
+        assert_eq!(
+            pairs(b"?a=&b=2&&flag&=v"),
+            owned(&[("a", ""), ("b", "2"), ("flag", ""), ("", "v")])
+        );
+        assert_eq!(
+            pairs(b"?k=1&k=2;k=3"),
+            owned(&[("k", "1"), ("k", "2"), ("k", "3")])
+        );
+        assert_eq!(pairs(b"?a=%FF"), owned(&[("a", "\u{FFFD}")]));
+        assert_eq!(pairs(b"http://h/p?#f"), owned(&[]));
+        assert_eq!(pairs(b"http://h/p"), owned(&[]));
+
+        // Raw parsing keeps the query escaped, and a built URI may only
+        // have the unescaped one.
+        let uri = Uri::parse_raw(b"?a=%41", true).unwrap();
+        assert_eq!(query_pairs(&uri), owned(&[("a", "A")]));
+        let uri = Uri {
+            query: s(b"x=1;y"),
+            ..Uri::new()
+        };
+        assert_eq!(query_pairs(&uri), owned(&[("x", "1"), ("y", "")]));
+
+        // An unescaped query is not unescaped again: `%25` stays a literal
+        // `%41` and `%26` a literal `&`, which then separates pairs.
+        let mut uri = Uri::parse(b"?a=%2541&b=1%26c=2").unwrap();
+        assert_eq!(query_pairs(&uri), owned(&[("a", "%41"), ("b", "1&c=2")]));
+        uri.query_raw = None;
+        assert_eq!(uri.query, s(b"a=%41&b=1&c=2"));
+        assert_eq!(
+            query_pairs(&uri),
+            owned(&[("a", "%41"), ("b", "1"), ("c", "2")])
+        );
+    }
+}
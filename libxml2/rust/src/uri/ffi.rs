@@ -0,0 +1,268 @@
+#![allow(clippy::missing_safety_doc)]
+
+use std::ffi::CStr;
+use std::os::raw::{c_char, c_int, c_void};
+use std::ptr;
+
+use super::core::{self, Uri};
+use crate::static_bindings::{xmlChar, xmlFree, xmlMalloc, xmlURI};
+
+// Ensure symbols are exported for linking
+#[cfg(feature = "rust-uri")]
+#[used]
+static URI_FFI_LINKAGE: () = ();
+
+unsafe fn bytes(s: *const c_char) -> Option<Vec<u8>> {
+    if s.is_null() {
+        None
+    } else {
+        Some(CStr::from_ptr(s).to_bytes().to_vec())
+    }
+}
+
+/// Copy `value` into a NUL-terminated xmlMalloc'ed string.
+unsafe fn dup(value: &[u8]) -> *mut c_char {
+    let Some(malloc) = xmlMalloc else {
+        return ptr::null_mut();
+    };
+    let mem = malloc(value.len() + 1) as *mut u8;
+    if mem.is_null() {
+        return ptr::null_mut();
+    }
+    ptr::copy_nonoverlapping(value.as_ptr(), mem, value.len());
+    *mem.add(value.len()) = 0;
+    mem as *mut c_char
+}
+
+unsafe fn free(p: *mut c_char) {
+    if !p.is_null() {
+        xmlFree.unwrap()(p as *mut c_void);
+    }
+}
+
+unsafe fn from_c(uri: &xmlURI) -> Uri {
+    Uri {
+        scheme: bytes(uri.scheme),
+        opaque: bytes(uri.opaque),
+        authority: bytes(uri.authority),
+        server: bytes(uri.server),
+        user: bytes(uri.user),
+        port: uri.port,
+        path: bytes(uri.path),
+        query: bytes(uri.query),
+        fragment: bytes(uri.fragment),
+        cleanup: uri.cleanup,
+        query_raw: bytes(uri.query_raw),
+    }
+}
+
+unsafe fn free_fields(uri: &mut xmlURI) {
+    for field in [
+        &mut uri.scheme,
+        &mut uri.opaque,
+        &mut uri.authority,
+        &mut uri.server,
+        &mut uri.user,
+        &mut uri.path,
+        &mut uri.query,
+        &mut uri.fragment,
+        &mut uri.query_raw,
+    ] {
+        free(*field);
+        *field = ptr::null_mut();
+    }
+}
+
+/// Replace the contents of `uri` with `value`. Returns -1 if a copy could
+/// not be allocated, leaving `uri` cleaned.
+unsafe fn store(uri: &mut xmlURI, value: &Uri) -> c_int {
+    free_fields(uri);
+    uri.port = value.port;
+    uri.cleanup = value.cleanup;
+    let mut failed = false;
+    let mut copy = |v: &Option<Vec<u8>>| match v {
+        Some(v) => {
+            let p = dup(v);
+            failed |= p.is_null();
+            p
+        }
+        None => ptr::null_mut(),
+    };
+    uri.scheme = copy(&value.scheme);
+    uri.opaque = copy(&value.opaque);
+    uri.authority = copy(&value.authority);
+    uri.server = copy(&value.server);
+    uri.user = copy(&value.user);
+    uri.path = copy(&value.path);
+    uri.query = copy(&value.query);
+    uri.fragment = copy(&value.fragment);
+    uri.query_raw = copy(&value.query_raw);
+    if failed {
+        free_fields(uri);
+        return -1;
+    }
+    0
+}
+
+/// Simply creates an empty xmlURI.
+#[no_mangle]
+pub unsafe extern "C" fn xmlCreateURI() -> *mut xmlURI {
+    let Some(malloc) = xmlMalloc else {
+        return ptr::null_mut();
+    };
+    let uri = malloc(std::mem::size_of::<xmlURI>()) as *mut xmlURI;
+    if !uri.is_null() {
+        ptr::write_bytes(uri, 0, 1);
+        (*uri).port = core::PORT_EMPTY;
+    }
+    uri
+}
+
+/// Parse an URI reference string based on RFC 3986 and fills in the
+/// appropriate fields of the `uri` structure. Returns 0, 1 if the string
+/// is not a URI reference or -1 on error.
+#[no_mangle]
+pub unsafe extern "C" fn xmlParseURIReference(uri: *mut xmlURI, str: *const c_char) -> c_int {
+    if str.is_null() || uri.is_null() {
+        return -1;
+    }
+    let uri = &mut *uri;
+    let mut parsed = from_c(uri);
+    let ret = parsed.parse_reference(CStr::from_ptr(str).to_bytes());
+    if store(uri, &parsed) < 0 {
+        return -1;
+    }
+    ret
+}
+
+/// Parse an URI based on RFC 3986. Returns 0 on success, 1 if the URI
+/// is invalid or -1 if a memory allocation failed.
+#[no_mangle]
+pub unsafe extern "C" fn xmlParseURISafe(str: *const c_char, uri_out: *mut *mut xmlURI) -> c_int {
+    if uri_out.is_null() {
+        return 1;
+    }
+    *uri_out = ptr::null_mut();
+    if str.is_null() {
+        return 1;
+    }
+    let uri = xmlCreateURI();
+    if uri.is_null() {
+        return -1;
+    }
+    let ret = xmlParseURIReference(uri, str);
+    if ret != 0 {
+        xmlFreeURI(uri);
+        return ret;
+    }
+    *uri_out = uri;
+    0
+}
+
+/// Parse an URI based on RFC 3986. Returns a newly built xmlURI or NULL
+/// in case of error.
+#[no_mangle]
+pub unsafe extern "C" fn xmlParseURI(str: *const c_char) -> *mut xmlURI {
+    let mut uri = ptr::null_mut();
+    xmlParseURISafe(str, &mut uri);
+    uri
+}
+
+/// Parse an URI but allows to keep intact the original fragments. If
+/// `raw` is 1 unescaping of URI pieces are disabled.
+#[no_mangle]
+pub unsafe extern "C" fn xmlParseURIRaw(str: *const c_char, raw: c_int) -> *mut xmlURI {
+    if str.is_null() {
+        return ptr::null_mut();
+    }
+    let uri = xmlCreateURI();
+    if uri.is_null() {
+        return ptr::null_mut();
+    }
+    if raw != 0 {
+        (*uri).cleanup |= core::URI_NO_UNESCAPE;
+    }
+    if xmlParseURIReference(uri, str) != 0 {
+        xmlFreeURI(uri);
+        return ptr::null_mut();
+    }
+    uri
+}
+
+/// Save the URI as an escaped string. The caller must free the result.
+#[no_mangle]
+pub unsafe extern "C" fn xmlSaveUri(uri: *mut xmlURI) -> *mut xmlChar {
+    let Some(uri) = uri.as_ref() else {
+        return ptr::null_mut();
+    };
+    match from_c(uri).save() {
+        Some(out) => dup(&out) as *mut xmlChar,
+        None => ptr::null_mut(),
+    }
+}
+
+/// Free up the xmlURI struct.
+#[no_mangle]
+pub unsafe extern "C" fn xmlFreeURI(uri: *mut xmlURI) {
+    if let Some(u) = uri.as_mut() {
+        free_fields(u);
+        xmlFree.unwrap()(uri as *mut c_void);
+    }
+}
+
+/// Unescaping routine, but does not check that the string is an URI.
+/// `len <= 0` means the whole string. The result goes to `target` if
+/// given, which must be large enough, or to a new allocation.
+#[no_mangle]
+pub unsafe extern "C" fn xmlURIUnescapeString(
+    str: *const c_char,
+    len: c_int,
+    target: *mut c_char,
+) -> *mut c_char {
+    if str.is_null() {
+        return ptr::null_mut();
+    }
+    let input = if len <= 0 {
+        CStr::from_ptr(str).to_bytes()
+    } else {
+        std::slice::from_raw_parts(str as *const u8, len as usize)
+    };
+    let out = core::unescape(input);
+    if target.is_null() {
+        return dup(&out);
+    }
+    ptr::copy_nonoverlapping(out.as_ptr(), target as *mut u8, out.len());
+    *target.add(out.len()) = 0;
+    target
+}
+
+/// Escape a string to hex, ignoring unreserved characters, `@` and the
+/// characters in `list`, which may be NULL.
+#[no_mangle]
+pub unsafe extern "C" fn xmlURIEscapeStr(
+    str: *const xmlChar,
+    list: *const xmlChar,
+) -> *mut xmlChar {
+    let Some(s) = bytes(str as *const c_char) else {
+        return ptr::null_mut();
+    };
+    let keep = bytes(list as *const c_char).unwrap_or_default();
+    dup(&core::escape_str(&s, &keep)) as *mut xmlChar
+}
+
+/// Prepare a path: escape a legacy extended IRI containing `://`, or copy
+/// a filesystem path unchanged. The caller must free the result.
+#[no_mangle]
+pub unsafe extern "C" fn xmlCanonicPath(path: *const xmlChar) -> *mut xmlChar {
+    match bytes(path as *const c_char) {
+        Some(path) => dup(&core::canonic_path(&path)) as *mut xmlChar,
+        None => ptr::null_mut(),
+    }
+}
+
+/// Construct a URI expressing the existing path, the same as
+/// `xmlCanonicPath`.
+#[no_mangle]
+pub unsafe extern "C" fn xmlPathToURI(path: *const xmlChar) -> *mut xmlChar {
+    xmlCanonicPath(path)
+}
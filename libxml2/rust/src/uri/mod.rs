@@ -1 +1,8 @@
-// Empty stub module for uri
+//! Rust implementation of uri module
+//!
+//! RFC 3986 URI reference parsing and serialization
+
+pub mod core;
+pub mod ffi;
+
+pub use core::*;
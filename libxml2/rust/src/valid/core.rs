@@ -0,0 +1,473 @@
+#![allow(clippy::missing_safety_doc)]
+
+use std::collections::HashMap;
+use std::ffi::CStr;
+use std::os::raw::{c_char, c_void};
+use std::ptr;
+
+use crate::static_bindings::{
+    xmlAttrPtr, xmlAttributeDefault_XML_ATTRIBUTE_IMPLIED,
+    xmlAttributeDefault_XML_ATTRIBUTE_REQUIRED, xmlAttributePtr,
+    xmlAttributeType_XML_ATTRIBUTE_ENTITIES, xmlAttributeType_XML_ATTRIBUTE_ENTITY,
+    xmlAttributeType_XML_ATTRIBUTE_NOTATION, xmlChar, xmlDocPtr, xmlElementType_XML_ATTRIBUTE_DECL,
+    xmlElementType_XML_ELEMENT_NODE, xmlElementType_XML_ENTITY_DECL, xmlEntityPtr,
+    xmlEntityType_XML_EXTERNAL_GENERAL_UNPARSED_ENTITY, xmlFree, xmlGetDocEntity,
+    xmlGetDtdNotationDesc, xmlGetDtdQAttrDesc, xmlNewNsProp, xmlNodeListGetString, xmlNodePtr,
+    xmlNsPtr, xmlParserErrors, xmlParserErrors_XML_DTD_ENTITY_TYPE,
+    xmlParserErrors_XML_DTD_NOTATION_VALUE, xmlParserErrors_XML_DTD_UNKNOWN_ENTITY,
+    xmlParserErrors_XML_DTD_UNKNOWN_NOTATION, xmlSearchNs,
+};
+
+/// Bytes of a possibly null string.
+pub(crate) unsafe fn bytes<'a>(s: *const xmlChar) -> Option<&'a [u8]> {
+    if s.is_null() {
+        None
+    } else {
+        Some(CStr::from_ptr(s as *const c_char).to_bytes())
+    }
+}
+
+/// The qualified name of an element as the DTD spells it.
+pub(crate) unsafe fn element_qname(node: xmlNodePtr) -> Vec<u8> {
+    let mut qname = Vec::new();
+    let ns = (*node).ns;
+    if let Some(prefix) = (!ns.is_null()).then(|| bytes((*ns).prefix)).flatten() {
+        qname.extend_from_slice(prefix);
+        qname.push(b':');
+    }
+    qname.extend_from_slice(bytes((*node).name).unwrap_or_default());
+    qname
+}
+
+/// Attribute declarations with a default or `#FIXED` value, by element
+/// name, in the order the parser sees them: internal subset first.
+/// Redeclarations never make it into the DTD, so the first one wins.
+unsafe fn default_decls(doc: xmlDocPtr) -> HashMap<Vec<u8>, Vec<xmlAttributePtr>> {
+    let mut decls: HashMap<Vec<u8>, Vec<xmlAttributePtr>> = HashMap::new();
+    for dtd in [(*doc).intSubset, (*doc).extSubset] {
+        if dtd.is_null() {
+            continue;
+        }
+        let mut cur = (*dtd).children;
+        while !cur.is_null() {
+            if (*cur).type_ == xmlElementType_XML_ATTRIBUTE_DECL {
+                let decl = cur as xmlAttributePtr;
+                let has_default = !(*decl).defaultValue.is_null()
+                    && (*decl).def != xmlAttributeDefault_XML_ATTRIBUTE_IMPLIED
+                    && (*decl).def != xmlAttributeDefault_XML_ATTRIBUTE_REQUIRED;
+                if let (true, Some(elem)) = (has_default, bytes((*decl).elem)) {
+                    decls.entry(elem.to_vec()).or_default().push(decl);
+                }
+            }
+            cur = (*cur).next;
+        }
+    }
+    decls
+}
+
+/// Whether `node` has an attribute `name` in the namespace of `ns`.
+unsafe fn has_attribute(node: xmlNodePtr, name: &[u8], ns: xmlNsPtr) -> bool {
+    let href = if ns.is_null() {
+        None
+    } else {
+        bytes((*ns).href)
+    };
+    let mut attr = (*node).properties;
+    while !attr.is_null() {
+        let attr_href = if (*attr).ns.is_null() {
+            None
+        } else {
+            bytes((*(*attr).ns).href)
+        };
+        if bytes((*attr).name) == Some(name) && attr_href == href {
+            return true;
+        }
+        attr = (*attr).next;
+    }
+    false
+}
+
+/// Add the defaulted attributes `decls` declare for `node`. Returns the
+/// number added.
+unsafe fn default_element(node: xmlNodePtr, decls: &[xmlAttributePtr]) -> usize {
+    let mut added = 0;
+    for &decl in decls {
+        let name = bytes((*decl).name).unwrap_or_default();
+        let prefix = bytes((*decl).prefix);
+        // Namespace declarations are not attributes of the tree.
+        if prefix == Some(b"xmlns") || (prefix.is_none() && name == b"xmlns") {
+            continue;
+        }
+        let mut ns = ptr::null_mut();
+        if let Some(prefix) = prefix {
+            ns = xmlSearchNs((*node).doc, node, (*decl).prefix);
+            if ns.is_null() {
+                // The parser reports the unbound prefix and keeps it as part
+                // of the name, without a namespace.
+                let mut qname = prefix.to_vec();
+                qname.push(b':');
+                qname.extend_from_slice(name);
+                if has_attribute(node, &qname, ns) {
+                    continue;
+                }
+                qname.push(0);
+                if !xmlNewNsProp(node, ns, qname.as_ptr(), (*decl).defaultValue).is_null() {
+                    added += 1;
+                }
+                continue;
+            }
+        }
+        if has_attribute(node, name, ns) {
+            continue;
+        }
+        if !xmlNewNsProp(node, ns, (*decl).name, (*decl).defaultValue).is_null() {
+            added += 1;
+        }
+    }
+    added
+}
+
+/// Add the default and `#FIXED` attribute values the DTD of `doc`
+/// declares to every element which omits them, as the parser does with
+/// `XML_PARSE_DTDATTR`. Defaults go after the attributes present, in
+/// declaration order. Returns the number of attributes added.
+pub unsafe fn add_default_attributes(doc: xmlDocPtr) -> usize {
+    if doc.is_null() {
+        return 0;
+    }
+    let decls = default_decls(doc);
+    if decls.is_empty() {
+        return 0;
+    }
+
+    let mut added = 0;
+    for_each_element(doc, |node| {
+        if let Some(decls) = decls.get(&element_qname(node)) {
+            added += default_element(node, decls);
+        }
+    });
+    added
+}
+
+/// Call `f` on the elements of `doc` in document order, without entering
+/// entity references.
+pub(crate) unsafe fn for_each_element(doc: xmlDocPtr, mut f: impl FnMut(xmlNodePtr)) {
+    let mut node = (*doc).children;
+    while !node.is_null() {
+        if (*node).type_ == xmlElementType_XML_ELEMENT_NODE {
+            f(node);
+            if !(*node).children.is_null() {
+                node = (*node).children;
+                continue;
+            }
+        }
+        while (*node).next.is_null() {
+            node = (*node).parent;
+            if node.is_null() || node == doc as xmlNodePtr {
+                return;
+            }
+        }
+        node = (*node).next;
+    }
+}
+
+/// A validity error, with the code and message libxml2 reports for it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidityError {
+    pub code: xmlParserErrors,
+    pub message: String,
+}
+
+impl ValidityError {
+    pub(crate) fn new(code: xmlParserErrors, message: String) -> Self {
+        ValidityError { code, message }
+    }
+}
+
+/// Lossy text of a name or value for an error message.
+pub(crate) fn text(s: &[u8]) -> std::borrow::Cow<'_, str> {
+    String::from_utf8_lossy(s)
+}
+
+/// Whether a subset of `doc` declares the notation `name`.
+unsafe fn notation_declared(doc: xmlDocPtr, name: *const xmlChar) -> bool {
+    !xmlGetDtdNotationDesc((*doc).intSubset, name).is_null()
+        || !xmlGetDtdNotationDesc((*doc).extSubset, name).is_null()
+}
+
+/// The declaration of `attr` of `node`, looked up by the qualified name of
+/// the element first and then by its local name, like
+/// `xmlValidateOneAttribute`.
+unsafe fn attribute_decl(doc: xmlDocPtr, node: xmlNodePtr, attr: xmlAttrPtr) -> xmlAttributePtr {
+    let prefix = if (*attr).ns.is_null() {
+        ptr::null()
+    } else {
+        (*(*attr).ns).prefix
+    };
+    let lookup = |elem: *const xmlChar| {
+        let decl = xmlGetDtdQAttrDesc((*doc).intSubset, elem, (*attr).name, prefix);
+        if decl.is_null() {
+            xmlGetDtdQAttrDesc((*doc).extSubset, elem, (*attr).name, prefix)
+        } else {
+            decl
+        }
+    };
+    let mut decl = ptr::null_mut();
+    if !(*node).ns.is_null() && !(*(*node).ns).prefix.is_null() {
+        let mut qname = element_qname(node);
+        qname.push(0);
+        decl = lookup(qname.as_ptr());
+    }
+    if decl.is_null() {
+        decl = lookup((*node).name);
+    }
+    decl
+}
+
+/// Check that `name`, the value of the `ENTITY` or `ENTITIES` attribute
+/// `attr`, names an unparsed entity.
+unsafe fn check_entity(
+    doc: xmlDocPtr,
+    kind: &str,
+    attr: &[u8],
+    name: &[u8],
+    errors: &mut Vec<ValidityError>,
+) {
+    let mut cname = name.to_vec();
+    cname.push(0);
+    let mut ent: xmlEntityPtr = xmlGetDocEntity(doc, cname.as_ptr());
+    // A standalone document hides the external subset from the lookup;
+    // C drops the flag for good and retries.
+    if ent.is_null() && (*doc).standalone == 1 {
+        (*doc).standalone = 0;
+        ent = xmlGetDocEntity(doc, cname.as_ptr());
+    }
+    if ent.is_null() {
+        errors.push(ValidityError::new(
+            xmlParserErrors_XML_DTD_UNKNOWN_ENTITY,
+            format!(
+                "{kind} attribute {} reference an unknown entity \"{}\"\n",
+                text(attr),
+                text(name)
+            ),
+        ));
+    } else if (*ent).etype != xmlEntityType_XML_EXTERNAL_GENERAL_UNPARSED_ENTITY {
+        errors.push(ValidityError::new(
+            xmlParserErrors_XML_DTD_ENTITY_TYPE,
+            format!(
+                "{kind} attribute {} reference an entity \"{}\" of wrong type\n",
+                text(attr),
+                text(name)
+            ),
+        ));
+    }
+}
+
+/// Notation and entity checks for one attribute of `node`, in the order
+/// `xmlValidateOneAttribute` reports them.
+unsafe fn validate_attribute(
+    doc: xmlDocPtr,
+    node: xmlNodePtr,
+    attr: xmlAttrPtr,
+    errors: &mut Vec<ValidityError>,
+) {
+    let decl = attribute_decl(doc, node, attr);
+    if decl.is_null() {
+        return;
+    }
+    let atype = (*decl).atype;
+    if atype != xmlAttributeType_XML_ATTRIBUTE_NOTATION
+        && atype != xmlAttributeType_XML_ATTRIBUTE_ENTITY
+        && atype != xmlAttributeType_XML_ATTRIBUTE_ENTITIES
+    {
+        return;
+    }
+
+    let raw = xmlNodeListGetString(doc, (*attr).children, 1);
+    let value = if raw.is_null() {
+        Vec::new()
+    } else {
+        let value = CStr::from_ptr(raw as *const c_char).to_bytes().to_vec();
+        xmlFree.unwrap()(raw as *mut c_void);
+        value
+    };
+    let name = bytes((*attr).name).unwrap_or_default();
+    let elem = bytes((*node).name).unwrap_or_default();
+
+    if atype == xmlAttributeType_XML_ATTRIBUTE_NOTATION {
+        let mut cvalue = value.clone();
+        cvalue.push(0);
+        let declared = notation_declared(doc, cvalue.as_ptr());
+        if !declared {
+            errors.push(ValidityError::new(
+                xmlParserErrors_XML_DTD_UNKNOWN_NOTATION,
+                format!(
+                    "Value \"{}\" for attribute {} of {} is not a declared Notation\n",
+                    text(&value),
+                    text(name),
+                    text(elem)
+                ),
+            ));
+        }
+        let mut tree = (*decl).tree;
+        while !tree.is_null() && bytes((*tree).name) != Some(&value[..]) {
+            tree = (*tree).next;
+        }
+        if tree.is_null() {
+            errors.push(ValidityError::new(
+                xmlParserErrors_XML_DTD_NOTATION_VALUE,
+                format!(
+                    "Value \"{}\" for attribute {} of {} is not among the enumerated notations\n",
+                    text(&value),
+                    text(name),
+                    text(elem)
+                ),
+            ));
+        }
+        if !declared {
+            errors.push(ValidityError::new(
+                xmlParserErrors_XML_DTD_UNKNOWN_NOTATION,
+                format!(
+                    "NOTATION attribute {} reference an unknown notation \"{}\"\n",
+                    text(name),
+                    text(&value)
+                ),
+            ));
+        }
+    } else if atype == xmlAttributeType_XML_ATTRIBUTE_ENTITY {
+        check_entity(doc, "ENTITY", name, &value, errors);
+    } else {
+        for ent in value.split(|&c| is_blank(c)).filter(|n| !n.is_empty()) {
+            check_entity(doc, "ENTITIES", name, ent, errors);
+        }
+    }
+}
+
+pub(crate) fn is_blank(c: u8) -> bool {
+    matches!(c, b' ' | b'\t' | b'\n' | b'\r')
+}
+
+/// Check that the unparsed entities of the internal subset, then the
+/// external one, name declared notations, like `xmlValidateDtdFinal`.
+unsafe fn unparsed_entity_errors(doc: xmlDocPtr, errors: &mut Vec<ValidityError>) {
+    for dtd in [(*doc).intSubset, (*doc).extSubset] {
+        if dtd.is_null() {
+            continue;
+        }
+        let mut cur = (*dtd).children;
+        while !cur.is_null() {
+            if (*cur).type_ == xmlElementType_XML_ENTITY_DECL {
+                let ent = cur as xmlEntityPtr;
+                if (*ent).etype == xmlEntityType_XML_EXTERNAL_GENERAL_UNPARSED_ENTITY
+                    && !(*ent).content.is_null()
+                    && !notation_declared(doc, (*ent).content)
+                {
+                    errors.push(ValidityError::new(
+                        xmlParserErrors_XML_DTD_UNKNOWN_NOTATION,
+                        format!(
+                            "NOTATION {} is not declared\n",
+                            text(bytes((*ent).content).unwrap_or_default())
+                        ),
+                    ));
+                }
+            }
+            cur = (*cur).next;
+        }
+    }
+}
+
+/// Check the notations `doc` uses against the ones its DTD declares, with
+/// the validity errors libxml2 reports, in its order:
+///
+/// - every unparsed entity names a declared notation, checked after the
+///   attributes of the document element as the parser does;
+/// - the value of every `NOTATION` attribute is a declared notation and one
+///   of those its declaration lists;
+/// - the values of `ENTITY` and `ENTITIES` attributes name unparsed
+///   entities.
+///
+/// Attributes without a declaration are left alone. Returns no errors for a
+/// document without an internal subset.
+pub unsafe fn validate_notations(doc: xmlDocPtr) -> Vec<ValidityError> {
+    let mut errors = Vec::new();
+    if doc.is_null() || (*doc).intSubset.is_null() {
+        return errors;
+    }
+
+    let mut root = true;
+    for_each_element(doc, |node| {
+        let mut attr = (*node).properties;
+        while !attr.is_null() {
+            validate_attribute(doc, node, attr, &mut errors);
+            attr = (*attr).next;
+        }
+        // The parser finishes the DTD once it has the attributes of the
+        // document element.
+        if root {
+            unparsed_entity_errors(doc, &mut errors);
+            root = false;
+        }
+    });
+    errors
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::static_bindings::{xmlFreeDoc, xmlReadMemory};
+    use std::os::raw::c_int;
+
+    unsafe fn parse(xml: &[u8]) -> xmlDocPtr {
+        xmlReadMemory(
+            xml.as_ptr() as *const c_char,
+            xml.len() as c_int,
+            ptr::null(),
+            ptr::null(),
+            0,
+        )
+    }
+
+    #[test]
+    fn test_add_defaults() {
+        unsafe {
+            let doc = parse(
+                b"<!DOCTYPE r [<!ATTLIST e a CDATA 'd' b CDATA #IMPLIED c CDATA #FIXED 'f'>]>\
+                  <r><e/><e a='x'/></r>",
+            );
+            assert_eq!(add_default_attributes(doc), 3);
+            let root = (*(*doc).children).next;
+            let mut names = Vec::new();
+            let mut attr = (*(*root).children).properties;
+            while !attr.is_null() {
+                names.push(bytes((*attr).name).unwrap().to_vec());
+                attr = (*attr).next;
+            }
+            assert_eq!(names, [b"a".to_vec(), b"c".to_vec()]);
+            // Running again finds nothing to add.
+            assert_eq!(add_default_attributes(doc), 0);
+            xmlFreeDoc(doc);
+        }
+    }
+
+    #[test]
+    fn test_validate_notations() {
+        unsafe {
+            let doc = parse(
+                b"<!DOCTYPE r [<!NOTATION gif SYSTEM 'gif'>\
+                  <!ENTITY pic SYSTEM 'a.gif' NDATA gif><!ENTITY txt 'text'>\
+                  <!ATTLIST r n NOTATION (gif|png) #IMPLIED e ENTITY #IMPLIED>]>\
+                  <r n='gif' e='pic'><r n='png' e='txt'/></r>",
+            );
+            let codes: Vec<_> = validate_notations(doc).iter().map(|e| e.code).collect();
+            assert_eq!(
+                codes,
+                [
+                    xmlParserErrors_XML_DTD_UNKNOWN_NOTATION,
+                    xmlParserErrors_XML_DTD_UNKNOWN_NOTATION,
+                    xmlParserErrors_XML_DTD_ENTITY_TYPE,
+                ]
+            );
+            xmlFreeDoc(doc);
+        }
+    }
+}
@@ -1 +1,10 @@
-// Empty stub module for valid
+//! Rust implementation of valid module
+//!
+//! DTD attribute defaulting, notation checks and attribute value
+//! normalization over a parsed tree
+
+pub mod core;
+pub mod values;
+
+pub use core::*;
+pub use values::*;
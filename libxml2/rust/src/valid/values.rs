@@ -0,0 +1,379 @@
+#![allow(clippy::missing_safety_doc)]
+
+use std::collections::HashSet;
+use std::ffi::CStr;
+use std::os::raw::{c_char, c_int, c_void};
+use std::ptr;
+
+use super::core::{bytes, element_qname, for_each_element, is_blank, text, ValidityError};
+use crate::static_bindings::{
+    xmlAttrPtr, xmlAttributePtr, xmlAttributeType, xmlAttributeType_XML_ATTRIBUTE_CDATA,
+    xmlAttributeType_XML_ATTRIBUTE_ENUMERATION, xmlAttributeType_XML_ATTRIBUTE_ID,
+    xmlAttributeType_XML_ATTRIBUTE_IDREF, xmlAttributeType_XML_ATTRIBUTE_IDREFS,
+    xmlAttributeType_XML_ATTRIBUTE_NMTOKEN, xmlAttributeType_XML_ATTRIBUTE_NMTOKENS, xmlChar,
+    xmlDocProperties_XML_DOC_OLD10, xmlDocPtr, xmlFree, xmlGetDtdQAttrDesc, xmlNodeListGetString,
+    xmlNodePtr, xmlParserErrors_XML_DTD_ATTRIBUTE_VALUE, xmlParserErrors_XML_DTD_ID_REDEFINED,
+    xmlParserErrors_XML_DTD_NOT_STANDALONE, xmlParserErrors_XML_DTD_UNKNOWN_ID,
+};
+
+// From private/parser.h
+const XML_SCAN_NMTOKEN: c_int = 2;
+const XML_SCAN_OLD10: c_int = 4;
+
+extern "C" {
+    // Private name scanner of the parser, from private/parser.h, so names
+    // are checked with the same character classes.
+    fn xmlScanName(buf: *const xmlChar, maxSize: usize, flags: c_int) -> *const xmlChar;
+}
+
+/// Drop leading and trailing spaces and collapse the runs of spaces inside
+/// `value` to one, like `xmlValidNormalizeString`. Only #x20 counts: the
+/// parser has already turned other white space into spaces.
+pub fn normalize_value(value: &[u8]) -> Vec<u8> {
+    value
+        .split(|&c| c == b' ')
+        .filter(|token| !token.is_empty())
+        .collect::<Vec<_>>()
+        .join(&b' ')
+}
+
+/// The declaration of attribute `name`, with namespace prefix `prefix`, of
+/// `elem`: by the qualified name of the element first, then by its local
+/// name, each in the internal subset and then the external one. Also
+/// returns whether it came from the external subset.
+unsafe fn find_decl(
+    doc: xmlDocPtr,
+    elem: xmlNodePtr,
+    name: *const xmlChar,
+    prefix: *const xmlChar,
+) -> (xmlAttributePtr, bool) {
+    let lookup = |elem_name: *const xmlChar| {
+        let decl = xmlGetDtdQAttrDesc((*doc).intSubset, elem_name, name, prefix);
+        if !decl.is_null() || (*doc).extSubset.is_null() {
+            return (decl, false);
+        }
+        let decl = xmlGetDtdQAttrDesc((*doc).extSubset, elem_name, name, prefix);
+        (decl, !decl.is_null())
+    };
+    if !(*elem).ns.is_null() && !(*(*elem).ns).prefix.is_null() {
+        let mut qname = element_qname(elem);
+        qname.push(0);
+        let found = lookup(qname.as_ptr());
+        if !found.0.is_null() {
+            return found;
+        }
+    }
+    lookup((*elem).name)
+}
+
+/// The value `value` of the attribute `name` of `elem` normalized for its
+/// declared type, like `xmlValidCtxtNormalizeAttributeValue`: `None` when
+/// the attribute is undeclared or `CDATA`, whose values are left alone.
+/// `name` is the qualified name, split at its first colon.
+pub unsafe fn normalize_attribute_value(
+    doc: xmlDocPtr,
+    elem: xmlNodePtr,
+    name: &[u8],
+    value: &[u8],
+) -> Option<Vec<u8>> {
+    if doc.is_null() || elem.is_null() {
+        return None;
+    }
+    let (decl, _) = qname_decl(doc, elem, name);
+    if decl.is_null() || (*decl).atype == xmlAttributeType_XML_ATTRIBUTE_CDATA {
+        return None;
+    }
+    Some(normalize_value(value))
+}
+
+/// `find_decl` for a qualified attribute name.
+unsafe fn qname_decl(doc: xmlDocPtr, elem: xmlNodePtr, name: &[u8]) -> (xmlAttributePtr, bool) {
+    let (prefix, local) = match name.iter().position(|&c| c == b':') {
+        Some(colon) if colon > 0 => (Some(&name[..colon]), &name[colon + 1..]),
+        _ => (None, name),
+    };
+    let prefix = prefix.map(|p| [p, b"\0"].concat());
+    let local = [local, b"\0"].concat();
+    find_decl(
+        doc,
+        elem,
+        local.as_ptr(),
+        prefix.as_ref().map_or(ptr::null(), |p| p.as_ptr()),
+    )
+}
+
+/// Whether `value` is a single name, or a name token with `nmtoken`,
+/// `xmlValidateNameValue` and `xmlValidateNmtokenValue`.
+unsafe fn is_name(value: &[u8], flags: c_int) -> bool {
+    if value.is_empty() {
+        return false;
+    }
+    let value = [value, b"\0"].concat();
+    let end = xmlScanName(value.as_ptr(), usize::MAX, flags);
+    !end.is_null() && *end == 0
+}
+
+/// Whether `value` is a list of names, or name tokens, separated by
+/// spaces, `xmlValidateNamesValue` and `xmlValidateNmtokensValue`. Like C,
+/// name tokens may be surrounded by spaces and other blanks may lead;
+/// names may not.
+unsafe fn is_name_list(value: &[u8], flags: c_int) -> bool {
+    let nmtoken = flags & XML_SCAN_NMTOKEN != 0;
+    let value = [value, b"\0"].concat();
+    let mut cur = value.as_ptr();
+    if nmtoken {
+        while is_blank(*cur) {
+            cur = cur.add(1);
+        }
+    }
+    loop {
+        let end = xmlScanName(cur, usize::MAX, flags);
+        if end.is_null() || end == cur {
+            return false;
+        }
+        cur = end;
+        if *cur != b' ' {
+            return *cur == 0;
+        }
+        while *cur == b' ' {
+            cur = cur.add(1);
+        }
+        if nmtoken && *cur == 0 {
+            return true;
+        }
+    }
+}
+
+/// Whether `value` matches the production of the attribute type `atype`,
+/// like `xmlValidateAttributeValueInternal` for the types checked here.
+unsafe fn valid_syntax(atype: xmlAttributeType, value: &[u8], old10: bool) -> bool {
+    let flags = if old10 { XML_SCAN_OLD10 } else { 0 };
+    match atype {
+        xmlAttributeType_XML_ATTRIBUTE_IDREFS => is_name_list(value, flags),
+        xmlAttributeType_XML_ATTRIBUTE_ID | xmlAttributeType_XML_ATTRIBUTE_IDREF => {
+            is_name(value, flags)
+        }
+        xmlAttributeType_XML_ATTRIBUTE_NMTOKENS | xmlAttributeType_XML_ATTRIBUTE_ENUMERATION => {
+            is_name_list(value, flags | XML_SCAN_NMTOKEN)
+        }
+        xmlAttributeType_XML_ATTRIBUTE_NMTOKEN => is_name(value, flags | XML_SCAN_NMTOKEN),
+        _ => true,
+    }
+}
+
+/// An `IDREF` or `IDREFS` value to resolve once all IDs are known.
+struct Reference {
+    atype: xmlAttributeType,
+    name: Vec<u8>,
+    value: Vec<u8>,
+}
+
+/// The state of one `validate_attribute_values` walk.
+struct Values {
+    doc: xmlDocPtr,
+    old10: bool,
+    ids: HashSet<Vec<u8>>,
+    refs: Vec<Reference>,
+    errors: Vec<ValidityError>,
+}
+
+impl Values {
+    /// Normalize and check one attribute of `node`, in the order
+    /// `xmlValidateOneAttribute` reports.
+    unsafe fn attribute(&mut self, node: xmlNodePtr, attr: xmlAttrPtr) {
+        let prefix = if (*attr).ns.is_null() {
+            ptr::null()
+        } else {
+            (*(*attr).ns).prefix
+        };
+        let (decl, external) = find_decl(self.doc, node, (*attr).name, prefix);
+        if decl.is_null() {
+            return;
+        }
+        let atype = (*decl).atype;
+        if !matches!(
+            atype,
+            xmlAttributeType_XML_ATTRIBUTE_ID
+                | xmlAttributeType_XML_ATTRIBUTE_IDREF
+                | xmlAttributeType_XML_ATTRIBUTE_IDREFS
+                | xmlAttributeType_XML_ATTRIBUTE_NMTOKEN
+                | xmlAttributeType_XML_ATTRIBUTE_NMTOKENS
+                | xmlAttributeType_XML_ATTRIBUTE_ENUMERATION
+        ) {
+            return;
+        }
+
+        let raw = xmlNodeListGetString(self.doc, (*attr).children, 1);
+        let raw_value = if raw.is_null() {
+            Vec::new()
+        } else {
+            let value = CStr::from_ptr(raw as *const c_char).to_bytes().to_vec();
+            xmlFree.unwrap()(raw as *mut c_void);
+            value
+        };
+        let name = bytes((*attr).name).unwrap_or_default();
+        let elem = bytes((*node).name).unwrap_or_default();
+
+        let value = normalize_value(&raw_value);
+        if (*self.doc).standalone != 0 && external && value != raw_value {
+            let mut qname = Vec::new();
+            if let Some(prefix) = bytes(prefix) {
+                qname.extend_from_slice(prefix);
+                qname.push(b':');
+            }
+            qname.extend_from_slice(name);
+            self.errors.push(ValidityError::new(
+                xmlParserErrors_XML_DTD_NOT_STANDALONE,
+                format!(
+                    "standalone: {} on {} value had to be normalized based on external subset declaration\n",
+                    text(&qname),
+                    text(elem)
+                ),
+            ));
+        }
+
+        if !valid_syntax(atype, &value, self.old10) {
+            self.errors.push(ValidityError::new(
+                xmlParserErrors_XML_DTD_ATTRIBUTE_VALUE,
+                format!(
+                    "Syntax of value for attribute {} of {} is not valid\n",
+                    text(name),
+                    text(elem)
+                ),
+            ));
+        }
+
+        match atype {
+            // An empty ID is never added, which C reports as a redefinition.
+            xmlAttributeType_XML_ATTRIBUTE_ID
+                if value.is_empty() || !self.ids.insert(value.clone()) =>
+            {
+                self.errors.push(ValidityError::new(
+                    xmlParserErrors_XML_DTD_ID_REDEFINED,
+                    format!("ID {} already defined\n", text(&value)),
+                ));
+            }
+            xmlAttributeType_XML_ATTRIBUTE_IDREF | xmlAttributeType_XML_ATTRIBUTE_IDREFS => {
+                self.refs.push(Reference {
+                    atype,
+                    name: name.to_vec(),
+                    value,
+                });
+            }
+            xmlAttributeType_XML_ATTRIBUTE_ENUMERATION => {
+                let mut tree = (*decl).tree;
+                while !tree.is_null() && bytes((*tree).name) != Some(&value[..]) {
+                    tree = (*tree).next;
+                }
+                if tree.is_null() {
+                    self.errors.push(ValidityError::new(
+                        xmlParserErrors_XML_DTD_ATTRIBUTE_VALUE,
+                        format!(
+                            "Value \"{}\" for attribute {} of {} is not among the enumerated set\n",
+                            text(&value),
+                            text(name),
+                            text(elem)
+                        ),
+                    ));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Resolve the references against the IDs, like the end of
+    /// `xmlValidateDocumentFinal`.
+    fn references(&mut self) {
+        for r in &self.refs {
+            if r.atype == xmlAttributeType_XML_ATTRIBUTE_IDREF {
+                if !self.ids.contains(&r.value) {
+                    self.errors.push(ValidityError::new(
+                        xmlParserErrors_XML_DTD_UNKNOWN_ID,
+                        format!(
+                            "IDREF attribute {} references an unknown ID \"{}\"\n",
+                            text(&r.name),
+                            text(&r.value)
+                        ),
+                    ));
+                }
+                continue;
+            }
+            for id in r.value.split(|&c| is_blank(c)).filter(|id| !id.is_empty()) {
+                if !self.ids.contains(id) {
+                    self.errors.push(ValidityError::new(
+                        xmlParserErrors_XML_DTD_UNKNOWN_ID,
+                        format!(
+                            "IDREFS attribute {} references an unknown ID \"{}\"\n",
+                            text(&r.name),
+                            text(id)
+                        ),
+                    ));
+                }
+            }
+        }
+    }
+}
+
+/// Check the values of the attributes of `doc` declared with an enumerated
+/// or tokenized type (`ID`, `IDREF`, `IDREFS`, `NMTOKEN`, `NMTOKENS`) with
+/// the validity errors libxml2 reports:
+///
+/// - each value is normalized as `normalize_attribute_value` does, which is
+///   an error in a standalone document when the declaration comes from the
+///   external subset;
+/// - the normalized value must match the production of its type, and an
+///   enumerated one be among the values declared;
+/// - IDs must be unique, and every `IDREF` and `IDREFS` name an ID, which is
+///   checked once the whole document has been seen.
+///
+/// Errors come in document order, except that C reports the unknown IDs in
+/// the order of its reference table, a hash. Attributes without a
+/// declaration are left alone. Returns no errors for a document without an
+/// internal subset.
+pub unsafe fn validate_attribute_values(doc: xmlDocPtr) -> Vec<ValidityError> {
+    if doc.is_null() || (*doc).intSubset.is_null() {
+        return Vec::new();
+    }
+    let mut values = Values {
+        doc,
+        old10: (*doc).properties & xmlDocProperties_XML_DOC_OLD10 as c_int != 0,
+        ids: HashSet::new(),
+        refs: Vec::new(),
+        errors: Vec::new(),
+    };
+    for_each_element(doc, |node| {
+        let mut attr = (*node).properties;
+        while !attr.is_null() {
+            values.attribute(node, attr);
+            attr = (*attr).next;
+        }
+    });
+    values.references();
+    values.errors
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_value() {
+        assert_eq!(normalize_value(b"  a   b c  "), b"a b c");
+        assert_eq!(normalize_value(b"   "), b"");
+        assert_eq!(normalize_value(b"a\tb"), b"a\tb");
+    }
+
+    #[test]
+    fn test_name_lists() {
+        unsafe {
+            assert!(is_name_list(b"a b  c", 0));
+            assert!(!is_name_list(b" a", 0));
+            assert!(!is_name_list(b"1a", 0));
+            assert!(is_name_list(b"\t1a 2b ", XML_SCAN_NMTOKEN));
+            assert!(!is_name_list(b"", XML_SCAN_NMTOKEN));
+            assert!(!is_name_list(b"a\tb", XML_SCAN_NMTOKEN));
+            assert!(is_name(b"x:y", 0));
+            assert!(!is_name(b"a b", XML_SCAN_NMTOKEN));
+        }
+    }
+}
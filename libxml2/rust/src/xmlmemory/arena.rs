@@ -0,0 +1,303 @@
+use std::alloc::{self, Layout};
+use std::cell::{Cell, RefCell};
+use std::marker::PhantomData;
+use std::mem;
+use std::os::raw::{c_char, c_void};
+use std::ptr;
+use std::sync::OnceLock;
+
+use super::core::{mem_functions, set_mem_functions, MemFunctions};
+
+/// Alignment of arena blocks, that of `max_align_t`.
+const ALIGN: usize = 16;
+/// Each block starts with its size, padded to keep the block aligned.
+const HEADER: usize = ALIGN;
+/// The size of the first chunk; each one after is twice the one before.
+const FIRST_CHUNK: usize = 16 * 1024;
+
+/// A chunk of an arena, blocks bumped off its start.
+struct Chunk {
+    start: *mut u8,
+    size: usize,
+    used: usize,
+}
+
+thread_local! {
+    /// The arena blocks allocated on this thread come from, if any.
+    static ACTIVE: Cell<*const Arena> = const { Cell::new(ptr::null()) };
+    /// The chunks of every arena alive on this thread, which `free`
+    /// leaves alone.
+    static LIVE: RefCell<Vec<(usize, usize)>> = const { RefCell::new(Vec::new()) };
+}
+
+/// The functions set up before `Arena::install`, which allocate
+/// everything outside arenas.
+static HEAP: OnceLock<MemFunctions> = OnceLock::new();
+
+fn heap() -> &'static MemFunctions {
+    HEAP.get()
+        .expect("arena functions used before being set up")
+}
+
+/// The arena active on this thread.
+unsafe fn active<'a>() -> Option<&'a Arena> {
+    ACTIVE
+        .try_with(|active| active.get().as_ref())
+        .ok()
+        .flatten()
+}
+
+/// Whether `ptr` is in a chunk of an arena alive on this thread.
+fn in_arena(ptr: *const c_void) -> bool {
+    let ptr = ptr as usize;
+    LIVE.try_with(|live| {
+        live.borrow()
+            .iter()
+            .any(|&(start, end)| (start..end).contains(&ptr))
+    })
+    .unwrap_or(false)
+}
+
+unsafe extern "C" fn arena_malloc(size: usize) -> *mut c_void {
+    match active() {
+        Some(arena) => arena.alloc(size),
+        None => heap().malloc.unwrap()(size),
+    }
+}
+
+unsafe extern "C" fn arena_realloc(ptr: *mut c_void, size: usize) -> *mut c_void {
+    if ptr.is_null() {
+        return arena_malloc(size);
+    }
+    if !in_arena(ptr) {
+        return heap().realloc.unwrap()(ptr, size);
+    }
+    if active().is_some_and(|arena| arena.resize_top(ptr, size)) {
+        return ptr;
+    }
+    let old_size = *((ptr as *mut u8).sub(HEADER) as *const usize);
+    let new = arena_malloc(size);
+    if !new.is_null() {
+        ptr::copy_nonoverlapping(ptr as *const u8, new as *mut u8, old_size.min(size));
+    }
+    new
+}
+
+unsafe extern "C" fn arena_free(ptr: *mut c_void) {
+    if ptr.is_null() {
+        return;
+    }
+    if !in_arena(ptr) {
+        heap().free.unwrap()(ptr);
+    } else if let Some(arena) = active() {
+        arena.release_top(ptr);
+    }
+}
+
+unsafe extern "C" fn arena_strdup(s: *const c_char) -> *mut c_char {
+    let Some(arena) = active() else {
+        return heap().strdup.unwrap()(s);
+    };
+    let size = libc::strlen(s) + 1;
+    let copy = arena.alloc(size) as *mut c_char;
+    if !copy.is_null() {
+        ptr::copy_nonoverlapping(s, copy, size);
+    }
+    copy
+}
+
+/// A bump arena that libxml2 allocates from while it is active on the
+/// thread, with `scope`. Its memory is only released when it is
+/// dropped: freeing a block of it is a no-op, whenever C code does it,
+/// and reallocating one copies it. Only the last block handed out, while
+/// the arena is active, is resized in place and given back when freed,
+/// which covers the buffers a parser grows and drops.
+///
+/// Arenas need the allocation functions of `Arena::install`, which hand
+/// out arena blocks on threads with an active arena and take everything
+/// else to the functions set up before, `malloc` by default.
+pub struct Arena {
+    chunks: RefCell<Vec<Chunk>>,
+    /// Blocks are only recognized on the thread the arena lives on.
+    _thread: PhantomData<*const ()>,
+}
+
+impl Arena {
+    /// Set up the allocation functions arenas allocate with, once; later
+    /// calls do nothing.
+    ///
+    /// # Safety
+    ///
+    /// Like `xmlMemSetup`, this must happen before other threads use
+    /// libxml2, and the functions must not be replaced after.
+    pub unsafe fn install() {
+        HEAP.get_or_init(|| {
+            let heap = mem_functions();
+            set_mem_functions(MemFunctions {
+                free: Some(arena_free),
+                malloc: Some(arena_malloc),
+                malloc_atomic: Some(arena_malloc),
+                realloc: Some(arena_realloc),
+                strdup: Some(arena_strdup),
+            });
+            heap
+        });
+    }
+
+    /// A new, empty arena.
+    ///
+    /// Panics if `Arena::install` was not called before.
+    pub fn new() -> Arena {
+        assert!(HEAP.get().is_some(), "Arena::install was not called");
+        Arena {
+            chunks: RefCell::new(Vec::new()),
+            _thread: PhantomData,
+        }
+    }
+
+    /// Run `f` with this arena active on the thread: everything libxml2
+    /// allocates on it meanwhile comes from the arena. The arena active
+    /// before is again after.
+    ///
+    /// # Safety
+    ///
+    /// Nothing allocated in `f` may be used after the arena is dropped,
+    /// including what libxml2 keeps in globals, like the last error of
+    /// the thread, which `xmlResetLastError` clears.
+    pub unsafe fn scope<R>(&self, f: impl FnOnce() -> R) -> R {
+        struct Restore(*const Arena);
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                ACTIVE.with(|active| active.set(self.0));
+            }
+        }
+
+        let _restore = Restore(ACTIVE.with(|active| active.replace(self)));
+        f()
+    }
+
+    /// Free everything allocated from the arena, but keep its last and
+    /// largest chunk to allocate from again, so that parsing batch after
+    /// batch into one arena settles on a single chunk.
+    pub fn reset(&mut self) {
+        let mut chunks = mem::take(self.chunks.get_mut());
+        let last = chunks.pop();
+        release(chunks);
+        if let Some(mut last) = last {
+            last.used = 0;
+            self.chunks.get_mut().push(last);
+        }
+    }
+
+    /// The bytes handed out, headers included.
+    pub fn allocated(&self) -> usize {
+        self.chunks.borrow().iter().map(|chunk| chunk.used).sum()
+    }
+
+    /// A block of `size` bytes, or null if there is no memory.
+    fn alloc(&self, size: usize) -> *mut c_void {
+        let Some(need) = footprint(size) else {
+            return ptr::null_mut();
+        };
+        let mut chunks = self.chunks.borrow_mut();
+        let fits = chunks
+            .last()
+            .is_some_and(|chunk| chunk.size - chunk.used >= need);
+        if !fits {
+            let next = chunks.last().map_or(FIRST_CHUNK, |chunk| chunk.size * 2);
+            let size = next.max(need);
+            let Ok(layout) = Layout::from_size_align(size, ALIGN) else {
+                return ptr::null_mut();
+            };
+            let start = unsafe { alloc::alloc(layout) };
+            if start.is_null() {
+                return ptr::null_mut();
+            }
+            LIVE.with(|live| {
+                live.borrow_mut()
+                    .push((start as usize, start as usize + size))
+            });
+            chunks.push(Chunk {
+                start,
+                size,
+                used: 0,
+            });
+        }
+        let chunk = chunks.last_mut().unwrap();
+        unsafe {
+            let block = chunk.start.add(chunk.used);
+            chunk.used += need;
+            *(block as *mut usize) = size;
+            block.add(HEADER) as *mut c_void
+        }
+    }
+
+    /// The offset in the last chunk of the block at `ptr`, if it is the
+    /// last block bumped off it.
+    unsafe fn top(&self, ptr: *mut c_void) -> Option<usize> {
+        let chunks = self.chunks.borrow();
+        let chunk = chunks.last()?;
+        let block = (ptr as *mut u8).sub(HEADER);
+        let used = footprint(*(block as *const usize))?;
+        let offset = (block as usize).checked_sub(chunk.start as usize)?;
+        (offset.checked_add(used) == Some(chunk.used)).then_some(offset)
+    }
+
+    /// Grow or shrink the block at `ptr` in place, which only the last one
+    /// can, if it still fits. Returns whether it did.
+    unsafe fn resize_top(&self, ptr: *mut c_void, size: usize) -> bool {
+        let (Some(offset), Some(need)) = (self.top(ptr), footprint(size)) else {
+            return false;
+        };
+        let mut chunks = self.chunks.borrow_mut();
+        let chunk = chunks.last_mut().unwrap();
+        if offset + need > chunk.size {
+            return false;
+        }
+        chunk.used = offset + need;
+        *(chunk.start.add(offset) as *mut usize) = size;
+        true
+    }
+
+    /// Hand the memory of the block at `ptr` out again if it is the last
+    /// one, as a parser's buffers are when freed in the reverse order.
+    unsafe fn release_top(&self, ptr: *mut c_void) {
+        if let Some(offset) = self.top(ptr) {
+            self.chunks.borrow_mut().last_mut().unwrap().used = offset;
+        }
+    }
+}
+
+/// The bytes a block of `size` takes in a chunk, header included.
+fn footprint(size: usize) -> Option<usize> {
+    size.checked_add(HEADER + ALIGN - 1)
+        .map(|need| need & !(ALIGN - 1))
+}
+
+impl Default for Arena {
+    fn default() -> Self {
+        Arena::new()
+    }
+}
+
+impl Drop for Arena {
+    fn drop(&mut self) {
+        release(mem::take(self.chunks.get_mut()));
+    }
+}
+
+/// Forget `chunks` on this thread and give their memory back.
+fn release(chunks: Vec<Chunk>) {
+    let _ = LIVE.try_with(|live| {
+        live.borrow_mut()
+            .retain(|&(start, _)| !chunks.iter().any(|chunk| chunk.start as usize == start))
+    });
+    for chunk in chunks {
+        unsafe {
+            alloc::dealloc(
+                chunk.start,
+                Layout::from_size_align_unchecked(chunk.size, ALIGN),
+            )
+        };
+    }
+}
@@ -0,0 +1,271 @@
+#![allow(clippy::missing_safety_doc)]
+
+use std::mem;
+use std::os::raw::{c_char, c_uint, c_void};
+use std::ptr;
+use std::sync::atomic::{AtomicPtr, AtomicUsize, Ordering};
+
+use crate::static_bindings::{
+    xmlFree, xmlFreeFunc, xmlInitParser, xmlMalloc, xmlMallocAtomic, xmlMallocFunc, xmlMemStrdup,
+    xmlRealloc, xmlReallocFunc, xmlStrdupFunc,
+};
+
+extern "C" {
+    // Private in error.c
+    fn xmlPrintErrorMessage(fmt: *const c_char, ...);
+}
+
+/// The tag at the start of the header of every live debug block.
+const MEMTAG: c_uint = 0x5aa5;
+
+/// `MEMHDR`, the header in front of each debug block.
+#[repr(C)]
+struct MemHdr {
+    tag: c_uint,
+    size: usize,
+}
+
+const ALIGN_SIZE: usize = mem::size_of::<f64>();
+/// The header size, rounded up so the block after it stays aligned.
+const RESERVE_SIZE: usize = mem::size_of::<MemHdr>().div_ceil(ALIGN_SIZE) * ALIGN_SIZE;
+
+static DEBUG_MEM_SIZE: AtomicUsize = AtomicUsize::new(0);
+static DEBUG_MEM_BLOCKS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe fn header(ptr: *mut c_void) -> *mut MemHdr {
+    (ptr as *mut u8).sub(RESERVE_SIZE) as *mut MemHdr
+}
+
+unsafe fn client(hdr: *mut MemHdr) -> *mut c_void {
+    (hdr as *mut u8).add(RESERVE_SIZE) as *mut c_void
+}
+
+/// A new debug block of `size` bytes, `xmlMemMalloc`. Its size is counted
+/// in `memory_used` until it is freed with `debug_free`.
+pub unsafe fn debug_malloc(size: usize) -> *mut c_void {
+    xmlInitParser();
+    if size > usize::MAX - RESERVE_SIZE {
+        return ptr::null_mut();
+    }
+    let hdr = libc::malloc(RESERVE_SIZE + size) as *mut MemHdr;
+    if hdr.is_null() {
+        return ptr::null_mut();
+    }
+    (*hdr).tag = MEMTAG;
+    (*hdr).size = size;
+    DEBUG_MEM_SIZE.fetch_add(size, Ordering::Relaxed);
+    DEBUG_MEM_BLOCKS.fetch_add(1, Ordering::Relaxed);
+    client(hdr)
+}
+
+/// `ptr`, a debug block or null, resized to `size` bytes, `xmlMemRealloc`.
+/// On failure, `ptr` is left as it was.
+pub unsafe fn debug_realloc(ptr: *mut c_void, size: usize) -> *mut c_void {
+    if ptr.is_null() {
+        return debug_malloc(size);
+    }
+    xmlInitParser();
+    if size > usize::MAX - RESERVE_SIZE {
+        return ptr::null_mut();
+    }
+    let hdr = header(ptr);
+    if (*hdr).tag != MEMTAG {
+        xmlPrintErrorMessage(c"xmlMemRealloc: Tag error\n".as_ptr());
+        return ptr::null_mut();
+    }
+    let old_size = (*hdr).size;
+    (*hdr).tag = !MEMTAG;
+    let new = libc::realloc(hdr as *mut c_void, RESERVE_SIZE + size) as *mut MemHdr;
+    if new.is_null() {
+        (*hdr).tag = MEMTAG;
+        return ptr::null_mut();
+    }
+    (*new).tag = MEMTAG;
+    (*new).size = size;
+    DEBUG_MEM_SIZE.fetch_sub(old_size, Ordering::Relaxed);
+    DEBUG_MEM_SIZE.fetch_add(size, Ordering::Relaxed);
+    client(new)
+}
+
+/// Free a debug block, `xmlMemFree`. Its content is overwritten with
+/// 0xff first, so that use after free shows. A pointer without the tag is
+/// reported and left alone.
+pub unsafe fn debug_free(ptr: *mut c_void) {
+    if ptr.is_null() {
+        return;
+    }
+    if ptr as isize == -1 {
+        xmlPrintErrorMessage(c"xmlMemFree: Pointer from freed area\n".as_ptr());
+        return;
+    }
+    let hdr = header(ptr);
+    if (*hdr).tag != MEMTAG {
+        xmlPrintErrorMessage(c"xmlMemFree: Tag error\n".as_ptr());
+        return;
+    }
+    (*hdr).tag = !MEMTAG;
+    ptr::write_bytes(ptr as *mut u8, 0xff, (*hdr).size);
+    DEBUG_MEM_SIZE.fetch_sub((*hdr).size, Ordering::Relaxed);
+    DEBUG_MEM_BLOCKS.fetch_sub(1, Ordering::Relaxed);
+    libc::free(hdr as *mut c_void);
+}
+
+/// A copy of the NUL-terminated `s` in a new debug block, `xmlMemoryStrdup`.
+pub unsafe fn debug_strdup(s: *const c_char) -> *mut c_char {
+    let size = libc::strlen(s) + 1;
+    let copy = debug_malloc(size) as *mut c_char;
+    if !copy.is_null() {
+        ptr::copy_nonoverlapping(s, copy, size);
+    }
+    copy
+}
+
+/// The size of a debug block, or 0 for null or a block without the tag.
+pub unsafe fn debug_size(ptr: *mut c_void) -> usize {
+    if ptr.is_null() {
+        return 0;
+    }
+    let hdr = header(ptr);
+    if (*hdr).tag != MEMTAG {
+        return 0;
+    }
+    (*hdr).size
+}
+
+/// The bytes in debug blocks not yet freed.
+pub fn memory_used() -> usize {
+    DEBUG_MEM_SIZE.load(Ordering::Relaxed)
+}
+
+/// The number of debug blocks not yet freed.
+pub fn memory_blocks() -> usize {
+    DEBUG_MEM_BLOCKS.load(Ordering::Relaxed)
+}
+
+/// The allocation functions libxml2 calls for all its memory, the
+/// `xmlMalloc` family of globals.
+#[derive(Clone, Copy, Debug)]
+pub struct MemFunctions {
+    pub free: xmlFreeFunc,
+    pub malloc: xmlMallocFunc,
+    /// Used for blocks holding no pointers, which a garbage collector
+    /// needn't scan.
+    pub malloc_atomic: xmlMallocFunc,
+    pub realloc: xmlReallocFunc,
+    pub strdup: xmlStrdupFunc,
+}
+
+/// The allocation functions in use.
+pub fn mem_functions() -> MemFunctions {
+    unsafe {
+        MemFunctions {
+            free: xmlFree,
+            malloc: xmlMalloc,
+            malloc_atomic: xmlMallocAtomic,
+            realloc: xmlRealloc,
+            strdup: xmlMemStrdup,
+        }
+    }
+}
+
+/// Replace the allocation functions, unless one of them is missing.
+/// Returns whether they were replaced.
+///
+/// # Safety
+///
+/// Memory allocated before must not be freed or reallocated through the
+/// new functions unless they are compatible with the old ones. libxml2
+/// reads the globals without synchronization, so nothing may be running
+/// in it on another thread.
+pub unsafe fn set_mem_functions(functions: MemFunctions) -> bool {
+    if functions.free.is_none()
+        || functions.malloc.is_none()
+        || functions.malloc_atomic.is_none()
+        || functions.realloc.is_none()
+        || functions.strdup.is_none()
+    {
+        return false;
+    }
+    xmlFree = functions.free;
+    xmlMalloc = functions.malloc;
+    xmlMallocAtomic = functions.malloc_atomic;
+    xmlRealloc = functions.realloc;
+    xmlMemStrdup = functions.strdup;
+    true
+}
+
+/// An allocator libxml2 can be set up to take all its memory from with
+/// `set_allocator`. Blocks must be aligned for any type, like those of
+/// `malloc`. The methods must not panic: they are called from C, and a
+/// panic aborts the process.
+pub trait Allocator: Send + Sync {
+    /// A new block of `size` bytes, or null if there is no memory.
+    fn malloc(&self, size: usize) -> *mut c_void;
+
+    /// `ptr`, resized to `size` bytes, or a block of `size` bytes if it is
+    /// null. On failure, returns null and leaves `ptr` as it was.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` is null or a block of this allocator not yet freed.
+    unsafe fn realloc(&self, ptr: *mut c_void, size: usize) -> *mut c_void;
+
+    /// Free `ptr`.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` is null or a block of this allocator not yet freed.
+    unsafe fn free(&self, ptr: *mut c_void);
+}
+
+/// The allocator behind `allocator_malloc` and the rest, boxed twice to
+/// fit a thin pointer. Replaced ones are leaked, since another thread may
+/// still be in one of their methods.
+static ALLOCATOR: AtomicPtr<Box<dyn Allocator>> = AtomicPtr::new(ptr::null_mut());
+
+unsafe fn allocator<'a>() -> &'a dyn Allocator {
+    &**ALLOCATOR.load(Ordering::Acquire)
+}
+
+unsafe extern "C" fn allocator_malloc(size: usize) -> *mut c_void {
+    allocator().malloc(size)
+}
+
+unsafe extern "C" fn allocator_realloc(ptr: *mut c_void, size: usize) -> *mut c_void {
+    allocator().realloc(ptr, size)
+}
+
+unsafe extern "C" fn allocator_free(ptr: *mut c_void) {
+    allocator().free(ptr)
+}
+
+unsafe extern "C" fn allocator_strdup(s: *const c_char) -> *mut c_char {
+    let size = libc::strlen(s) + 1;
+    let copy = allocator().malloc(size) as *mut c_char;
+    if !copy.is_null() {
+        ptr::copy_nonoverlapping(s, copy, size);
+    }
+    copy
+}
+
+/// Route every allocation of libxml2, C or Rust, through `allocator`, as
+/// `xmlMemSetup` with functions calling its methods. Strings are copied
+/// into blocks from its `malloc`.
+///
+/// # Safety
+///
+/// As for `set_mem_functions`: memory allocated before, with `malloc`
+/// unless the functions were set up already, must not be freed or
+/// reallocated afterwards unless `allocator` can take blocks of the
+/// previous functions, which is best done before libxml2 allocates
+/// anything.
+pub unsafe fn set_allocator(allocator: Box<dyn Allocator>) {
+    ALLOCATOR.store(Box::into_raw(Box::new(allocator)), Ordering::Release);
+    set_mem_functions(MemFunctions {
+        free: Some(allocator_free),
+        malloc: Some(allocator_malloc),
+        malloc_atomic: Some(allocator_malloc),
+        realloc: Some(allocator_realloc),
+        strdup: Some(allocator_strdup),
+    });
+}
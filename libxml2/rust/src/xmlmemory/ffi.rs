@@ -0,0 +1,220 @@
+#![allow(clippy::missing_safety_doc)]
+
+use std::os::raw::{c_char, c_int, c_long, c_void};
+
+use super::core::{self, MemFunctions};
+use crate::static_bindings::{
+    xmlFreeFunc, xmlInitParser, xmlMallocFunc, xmlReallocFunc, xmlStrdupFunc, FILE,
+};
+
+// Ensure symbols are exported for linking
+#[cfg(feature = "rust-xmlmemory")]
+#[used]
+static XMLMEMORY_FFI_LINKAGE: () = ();
+
+/// `malloc` with the size of each block recorded, so that
+/// `xmlMemUsed` and `xmlMemBlocks` can count them.
+#[no_mangle]
+pub unsafe extern "C" fn xmlMemMalloc(size: usize) -> *mut c_void {
+    core::debug_malloc(size)
+}
+
+/// `realloc` for blocks of `xmlMemMalloc`.
+#[no_mangle]
+pub unsafe extern "C" fn xmlMemRealloc(ptr: *mut c_void, size: usize) -> *mut c_void {
+    core::debug_realloc(ptr, size)
+}
+
+/// `free` for blocks of `xmlMemMalloc`, checking their tag.
+#[no_mangle]
+pub unsafe extern "C" fn xmlMemFree(ptr: *mut c_void) {
+    core::debug_free(ptr)
+}
+
+/// `strdup` into a block of `xmlMemMalloc`.
+#[no_mangle]
+pub unsafe extern "C" fn xmlMemoryStrdup(s: *const c_char) -> *mut c_char {
+    core::debug_strdup(s)
+}
+
+/// Deprecated alias for `xmlMemMalloc`.
+#[no_mangle]
+pub unsafe extern "C" fn xmlMallocLoc(
+    size: usize,
+    _file: *const c_char,
+    _line: c_int,
+) -> *mut c_void {
+    core::debug_malloc(size)
+}
+
+/// Deprecated alias for `xmlMemMalloc`.
+#[no_mangle]
+pub unsafe extern "C" fn xmlMallocAtomicLoc(
+    size: usize,
+    _file: *const c_char,
+    _line: c_int,
+) -> *mut c_void {
+    core::debug_malloc(size)
+}
+
+/// Deprecated alias for `xmlMemRealloc`.
+#[no_mangle]
+pub unsafe extern "C" fn xmlReallocLoc(
+    ptr: *mut c_void,
+    size: usize,
+    _file: *const c_char,
+    _line: c_int,
+) -> *mut c_void {
+    core::debug_realloc(ptr, size)
+}
+
+/// Deprecated alias for `xmlMemoryStrdup`.
+#[no_mangle]
+pub unsafe extern "C" fn xmlMemStrdupLoc(
+    s: *const c_char,
+    _file: *const c_char,
+    _line: c_int,
+) -> *mut c_char {
+    core::debug_strdup(s)
+}
+
+/// The size of a block of `xmlMemMalloc`, or 0.
+#[no_mangle]
+pub unsafe extern "C" fn xmlMemSize(ptr: *mut c_void) -> usize {
+    core::debug_size(ptr)
+}
+
+/// The bytes in blocks of `xmlMemMalloc` not yet freed.
+#[no_mangle]
+pub extern "C" fn xmlMemUsed() -> c_int {
+    core::memory_used() as c_int
+}
+
+/// The number of blocks of `xmlMemMalloc` not yet freed.
+#[no_mangle]
+pub extern "C" fn xmlMemBlocks() -> c_int {
+    core::memory_blocks() as c_int
+}
+
+/// Removed feature, a no-op.
+#[no_mangle]
+pub extern "C" fn xmlMemDisplayLast(_fp: *mut FILE, _nb_bytes: c_long) {}
+
+/// Removed feature, a no-op.
+#[no_mangle]
+pub extern "C" fn xmlMemDisplay(_fp: *mut FILE) {}
+
+/// Removed feature, a no-op.
+#[no_mangle]
+pub extern "C" fn xmlMemShow(_fp: *mut FILE, _nr: c_int) {}
+
+/// Removed feature, a no-op.
+#[no_mangle]
+pub extern "C" fn xmlMemoryDump() {}
+
+/// Deprecated alias for `xmlInitParser`. Returns 0.
+#[no_mangle]
+pub unsafe extern "C" fn xmlInitMemory() -> c_int {
+    xmlInitParser();
+    0
+}
+
+/// Called by `xmlInitParser`. The counters are atomics, so there is no
+/// mutex to set up.
+#[no_mangle]
+pub extern "C" fn xmlInitMemoryInternal() {}
+
+/// A no-op.
+#[no_mangle]
+pub extern "C" fn xmlCleanupMemory() {}
+
+/// Called by `xmlCleanupParser`, with nothing to clean up.
+#[no_mangle]
+pub extern "C" fn xmlCleanupMemoryInternal() {}
+
+/// Replace the allocation functions, all but `strdup` also used for
+/// atomic blocks. Returns 0, or -1 if one is NULL.
+#[no_mangle]
+pub unsafe extern "C" fn xmlMemSetup(
+    free_func: xmlFreeFunc,
+    malloc_func: xmlMallocFunc,
+    realloc_func: xmlReallocFunc,
+    strdup_func: xmlStrdupFunc,
+) -> c_int {
+    xmlGcMemSetup(
+        free_func,
+        malloc_func,
+        malloc_func,
+        realloc_func,
+        strdup_func,
+    )
+}
+
+/// Store the allocation functions in use where the pointers given point.
+/// Returns 0.
+#[no_mangle]
+pub unsafe extern "C" fn xmlMemGet(
+    free_func: *mut xmlFreeFunc,
+    malloc_func: *mut xmlMallocFunc,
+    realloc_func: *mut xmlReallocFunc,
+    strdup_func: *mut xmlStrdupFunc,
+) -> c_int {
+    xmlGcMemGet(
+        free_func,
+        malloc_func,
+        std::ptr::null_mut(),
+        realloc_func,
+        strdup_func,
+    )
+}
+
+/// `xmlMemSetup` with a separate function for atomic blocks.
+#[no_mangle]
+pub unsafe extern "C" fn xmlGcMemSetup(
+    free_func: xmlFreeFunc,
+    malloc_func: xmlMallocFunc,
+    malloc_atomic_func: xmlMallocFunc,
+    realloc_func: xmlReallocFunc,
+    strdup_func: xmlStrdupFunc,
+) -> c_int {
+    let functions = MemFunctions {
+        free: free_func,
+        malloc: malloc_func,
+        malloc_atomic: malloc_atomic_func,
+        realloc: realloc_func,
+        strdup: strdup_func,
+    };
+    if core::set_mem_functions(functions) {
+        0
+    } else {
+        -1
+    }
+}
+
+/// `xmlMemGet` with the function for atomic blocks.
+#[no_mangle]
+pub unsafe extern "C" fn xmlGcMemGet(
+    free_func: *mut xmlFreeFunc,
+    malloc_func: *mut xmlMallocFunc,
+    malloc_atomic_func: *mut xmlMallocFunc,
+    realloc_func: *mut xmlReallocFunc,
+    strdup_func: *mut xmlStrdupFunc,
+) -> c_int {
+    let functions = core::mem_functions();
+    if let Some(out) = free_func.as_mut() {
+        *out = functions.free;
+    }
+    if let Some(out) = malloc_func.as_mut() {
+        *out = functions.malloc;
+    }
+    if let Some(out) = malloc_atomic_func.as_mut() {
+        *out = functions.malloc_atomic;
+    }
+    if let Some(out) = realloc_func.as_mut() {
+        *out = functions.realloc;
+    }
+    if let Some(out) = strdup_func.as_mut() {
+        *out = functions.strdup;
+    }
+    0
+}
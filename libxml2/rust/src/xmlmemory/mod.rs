@@ -1 +1,11 @@
-// Empty stub module for xmlmemory
+//! Rust implementation of xmlmemory module
+//!
+//! The allocation functions libxml2 takes its memory from, and the
+//! counting debug allocator, and bump arenas to parse into
+
+pub mod arena;
+pub mod core;
+pub mod ffi;
+
+pub use arena::*;
+pub use core::*;
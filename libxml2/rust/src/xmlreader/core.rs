@@ -0,0 +1,1141 @@
+#![allow(clippy::missing_safety_doc)]
+
+use std::ffi::{CStr, CString};
+use std::os::raw::{c_char, c_int, c_void};
+use std::ptr;
+
+use crate::static_bindings::{
+    startElementNsSAX2Func, startElementSAXFunc, xmlAttrPtr, xmlChar, xmlCreatePushParserCtxt,
+    xmlCtxtUseOptions, xmlDictLookup, xmlDictPtr, xmlDictQLookup,
+    xmlElementType_XML_ATTRIBUTE_NODE, xmlElementType_XML_CDATA_SECTION_NODE,
+    xmlElementType_XML_COMMENT_NODE, xmlElementType_XML_DOCUMENT_FRAG_NODE,
+    xmlElementType_XML_DOCUMENT_NODE, xmlElementType_XML_DOCUMENT_TYPE_NODE,
+    xmlElementType_XML_DTD_NODE, xmlElementType_XML_ELEMENT_NODE, xmlElementType_XML_ENTITY_DECL,
+    xmlElementType_XML_ENTITY_NODE, xmlElementType_XML_ENTITY_REF_NODE,
+    xmlElementType_XML_HTML_DOCUMENT_NODE, xmlElementType_XML_NAMESPACE_DECL,
+    xmlElementType_XML_NOTATION_NODE, xmlElementType_XML_PI_NODE, xmlElementType_XML_TEXT_NODE,
+    xmlElementType_XML_XINCLUDE_START, xmlFree, xmlFreeDoc, xmlFreeNode, xmlFreeParserCtxt,
+    xmlIsBlankNode, xmlNodeGetAttrValue, xmlNodeGetSpacePreserve, xmlNodeListGetString, xmlNodePtr,
+    xmlNsPtr, xmlParseChunk, xmlParserCtxtPtr, xmlParserInputState_XML_PARSER_EOF,
+    xmlParserMode_XML_PARSE_READER, xmlParserOption_XML_PARSE_COMPACT, xmlSAXHandler,
+    xmlSAXVersion, xmlSearchNs, xmlStopParser, xmlStrdup, xmlSwitchEncodingName, xmlUnlinkNode,
+};
+
+/// Input is fed to the push parser in blocks of this size.
+pub const CHUNK_SIZE: usize = 512;
+
+const NODE_IS_EMPTY: u16 = 0x1;
+const NODE_IS_PRESERVED: u16 = 0x2;
+
+/// `xmlTextReaderMode`, as returned by `xmlTextReaderReadState`.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReaderMode {
+    Initial = 0,
+    Interactive = 1,
+    Error = 2,
+    Eof = 3,
+    Closed = 4,
+    Reading = 5,
+}
+
+/// Position of the reader relative to the current node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ReaderState {
+    None,
+    Start,
+    Element,
+    End,
+    Backtrack,
+    Done,
+    Error,
+}
+
+/// `xmlReaderTypes`, the node types reported by `xmlTextReaderNodeType`.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReaderType {
+    None = 0,
+    Element = 1,
+    Attribute = 2,
+    Text = 3,
+    Cdata = 4,
+    EntityReference = 5,
+    Entity = 6,
+    ProcessingInstruction = 7,
+    Comment = 8,
+    Document = 9,
+    DocumentType = 10,
+    DocumentFragment = 11,
+    Notation = 12,
+    Whitespace = 13,
+    SignificantWhitespace = 14,
+    EndElement = 15,
+    EndEntity = 16,
+    XmlDeclaration = 17,
+}
+
+/// A pull parser over an in-memory document.
+///
+/// The push parser builds the tree incrementally and the reader walks it,
+/// freeing nodes once they have been left behind.
+pub struct TextReader {
+    mode: ReaderMode,
+    state: ReaderState,
+    ctxt: xmlParserCtxtPtr,
+    sax: Box<xmlSAXHandler>,
+    start_element: startElementSAXFunc,
+    start_element_ns: startElementNsSAX2Func,
+    input: Vec<u8>,
+    cur: usize,
+    node: xmlNodePtr,
+    /// Attribute or namespace declaration of `node` the reader is on.
+    curnode: xmlNodePtr,
+    depth: c_int,
+    dict: xmlDictPtr,
+    /// Holds attribute values made of several nodes for `const_value`.
+    value_buf: Vec<u8>,
+    ent_tab: Vec<xmlNodePtr>,
+    parser_flags: c_int,
+}
+
+unsafe fn reader_from_ctxt(ctx: *mut c_void) -> *mut TextReader {
+    let ctxt = ctx as xmlParserCtxtPtr;
+    if ctxt.is_null() {
+        return ptr::null_mut();
+    }
+    (*ctxt)._private as *mut TextReader
+}
+
+/// Flag elements written as `<a/>` so they don't report an end tag.
+unsafe fn mark_empty(ctxt: xmlParserCtxtPtr) {
+    let node = (*ctxt).node;
+    let input = (*ctxt).input;
+    if !node.is_null() && !input.is_null() && !(*input).cur.is_null() {
+        let cur = (*input).cur;
+        if *cur == b'/' && *cur.add(1) == b'>' {
+            (*node).extra = NODE_IS_EMPTY;
+        }
+    }
+}
+
+unsafe extern "C" fn start_element_hook(
+    ctx: *mut c_void,
+    fullname: *const xmlChar,
+    atts: *mut *const xmlChar,
+) {
+    let reader = reader_from_ctxt(ctx);
+    if reader.is_null() {
+        return;
+    }
+    if let Some(start) = (*reader).start_element {
+        start(ctx, fullname, atts);
+        mark_empty(ctx as xmlParserCtxtPtr);
+    }
+    (*reader).state = ReaderState::Element;
+}
+
+#[allow(clippy::too_many_arguments)]
+unsafe extern "C" fn start_element_ns_hook(
+    ctx: *mut c_void,
+    localname: *const xmlChar,
+    prefix: *const xmlChar,
+    uri: *const xmlChar,
+    nb_namespaces: c_int,
+    namespaces: *mut *const xmlChar,
+    nb_attributes: c_int,
+    nb_defaulted: c_int,
+    attributes: *mut *const xmlChar,
+) {
+    let reader = reader_from_ctxt(ctx);
+    if reader.is_null() {
+        return;
+    }
+    if let Some(start) = (*reader).start_element_ns {
+        start(
+            ctx,
+            localname,
+            prefix,
+            uri,
+            nb_namespaces,
+            namespaces,
+            nb_attributes,
+            nb_defaulted,
+            attributes,
+        );
+        mark_empty(ctx as xmlParserCtxtPtr);
+    }
+    (*reader).state = ReaderState::Element;
+}
+
+impl TextReader {
+    /// Create a reader over a copy of `buffer`. `options` is a combination
+    /// of `xmlParserOption`; `XML_PARSE_COMPACT` is always added since the
+    /// tree is never modified by users.
+    pub unsafe fn for_memory(
+        buffer: &[u8],
+        url: *const c_char,
+        encoding: *const c_char,
+        options: c_int,
+    ) -> Option<Box<TextReader>> {
+        let mut sax: Box<xmlSAXHandler> = Box::new(std::mem::zeroed());
+        xmlSAXVersion(&mut *sax, 2);
+
+        let mut reader = Box::new(TextReader {
+            mode: ReaderMode::Initial,
+            state: ReaderState::Start,
+            ctxt: ptr::null_mut(),
+            start_element: sax.startElement,
+            start_element_ns: sax.startElementNs,
+            sax,
+            input: buffer.to_vec(),
+            cur: 0,
+            node: ptr::null_mut(),
+            curnode: ptr::null_mut(),
+            depth: 0,
+            dict: ptr::null_mut(),
+            value_buf: Vec::new(),
+            ent_tab: Vec::new(),
+            parser_flags: options | xmlParserOption_XML_PARSE_COMPACT as c_int,
+        });
+
+        reader.sax.startElement = Some(start_element_hook);
+        reader.sax.startElementNs = Some(start_element_ns_hook);
+        reader.sax.ignorableWhitespace = reader.sax.characters;
+
+        // Prime the parser with the first bytes for encoding detection.
+        let initial = if reader.input.len() >= 4 { 4 } else { 0 };
+        reader.ctxt = xmlCreatePushParserCtxt(
+            &mut *reader.sax,
+            ptr::null_mut(),
+            reader.input.as_ptr() as *const c_char,
+            initial as c_int,
+            url,
+        );
+        if reader.ctxt.is_null() {
+            return None;
+        }
+        reader.cur = initial;
+
+        let ctxt = reader.ctxt;
+        reader.dict = (*ctxt).dict;
+        (*ctxt)._private = &mut *reader as *mut TextReader as *mut c_void;
+        (*ctxt).dictNames = 1;
+        (*ctxt).parseMode = xmlParserMode_XML_PARSE_READER;
+
+        xmlCtxtUseOptions(ctxt, reader.parser_flags);
+        if !encoding.is_null() {
+            xmlSwitchEncodingName(ctxt, encoding);
+        }
+        let input = (*ctxt).input;
+        if !url.is_null() && !input.is_null() && (*input).filename.is_null() {
+            (*input).filename = xmlStrdup(url as *const xmlChar) as *const c_char;
+            if (*input).filename.is_null() {
+                return None;
+            }
+        }
+
+        Some(reader)
+    }
+
+    /// Stop the parser and release the document and parser context. The
+    /// reader stays usable but every read afterwards fails.
+    pub fn close(&mut self) {
+        unsafe {
+            self.node = ptr::null_mut();
+            self.curnode = ptr::null_mut();
+            self.mode = ReaderMode::Closed;
+            if !self.ctxt.is_null() {
+                xmlStopParser(self.ctxt);
+                if !(*self.ctxt).myDoc.is_null() {
+                    xmlFreeDoc((*self.ctxt).myDoc);
+                    (*self.ctxt).myDoc = ptr::null_mut();
+                }
+                xmlFreeParserCtxt(self.ctxt);
+                self.ctxt = ptr::null_mut();
+            }
+        }
+    }
+
+    pub fn mode(&self) -> ReaderMode {
+        self.mode
+    }
+
+    pub fn current_node(&self) -> xmlNodePtr {
+        self.node
+    }
+
+    fn fail(&mut self) -> c_int {
+        self.mode = ReaderMode::Error;
+        self.state = ReaderState::Error;
+        -1
+    }
+
+    /// Push data down the progressive parser until a significant callback
+    /// got raised. Returns -1 in case of failure, 0 otherwise.
+    unsafe fn push_data(&mut self) -> c_int {
+        let ctxt = self.ctxt;
+        let len = self.input.len();
+        let oldstate = self.state;
+        self.state = ReaderState::None;
+
+        while self.state == ReaderState::None {
+            if len < self.cur + CHUNK_SIZE {
+                // The whole document is in memory, so there is nothing to
+                // refill.
+                if self.mode != ReaderMode::Eof {
+                    if len == self.cur {
+                        self.mode = ReaderMode::Eof;
+                        break;
+                    }
+                } else {
+                    break;
+                }
+            }
+
+            if len >= self.cur + CHUNK_SIZE {
+                let val = xmlParseChunk(
+                    ctxt,
+                    self.input.as_ptr().add(self.cur) as *const c_char,
+                    CHUNK_SIZE as c_int,
+                    0,
+                );
+                self.cur += CHUNK_SIZE;
+                if val != 0 {
+                    (*ctxt).wellFormed = 0;
+                }
+                if (*ctxt).wellFormed == 0 {
+                    break;
+                }
+            } else {
+                let s = len - self.cur;
+                let val = xmlParseChunk(
+                    ctxt,
+                    self.input.as_ptr().add(self.cur) as *const c_char,
+                    s as c_int,
+                    0,
+                );
+                self.cur += s;
+                if val != 0 {
+                    (*ctxt).wellFormed = 0;
+                }
+                break;
+            }
+        }
+        self.state = oldstate;
+
+        // At the end of the stream signal that the work is done to the
+        // push parser.
+        if self.mode == ReaderMode::Eof && self.state != ReaderState::Done {
+            let s = len - self.cur;
+            let val = xmlParseChunk(
+                ctxt,
+                self.input.as_ptr().add(self.cur) as *const c_char,
+                s as c_int,
+                1,
+            );
+            self.cur = len;
+            self.state = ReaderState::Done;
+            if val != 0 {
+                if (*ctxt).wellFormed != 0 {
+                    (*ctxt).wellFormed = 0;
+                } else {
+                    return -1;
+                }
+            }
+        }
+        if (*ctxt).wellFormed == 0 {
+            self.mode = ReaderMode::Eof;
+            return -1;
+        }
+
+        0
+    }
+
+    unsafe fn parser_stopped(&self) -> bool {
+        (*self.ctxt).disableSAX > 1
+    }
+
+    unsafe fn free_node(&mut self, node: xmlNodePtr) {
+        xmlUnlinkNode(node);
+        xmlFreeNode(node);
+    }
+
+    /// Make sure the current node and all its descendants are parsed.
+    /// Returns 1 on success and -1 in case of error.
+    unsafe fn do_expand(&mut self) -> c_int {
+        if self.node.is_null() || self.ctxt.is_null() {
+            return -1;
+        }
+        loop {
+            if self.parser_stopped() {
+                return 1;
+            }
+            if !successor(self.node).is_null() {
+                return 1;
+            }
+            if (*self.ctxt).nodeNr < self.depth {
+                return 1;
+            }
+            if self.mode == ReaderMode::Eof {
+                return 1;
+            }
+            if self.push_data() < 0 {
+                return self.fail();
+            }
+            if self.mode == ReaderMode::Eof {
+                return 1;
+            }
+        }
+    }
+
+    /// Parse the whole subtree of the current node and return it. The
+    /// subtree stays owned by the reader and is only valid until the next
+    /// read.
+    pub unsafe fn expand(&mut self) -> xmlNodePtr {
+        if self.node.is_null() || self.ctxt.is_null() {
+            return ptr::null_mut();
+        }
+        if self.do_expand() < 0 {
+            return ptr::null_mut();
+        }
+        self.node
+    }
+
+    /// Move to the next node in the stream. Returns 1 if a node was read,
+    /// 0 at the end of the document and -1 in case of error.
+    pub unsafe fn read(&mut self) -> c_int {
+        if self.state == ReaderState::Error {
+            return -1;
+        }
+        self.curnode = ptr::null_mut();
+        if self.ctxt.is_null() {
+            return -1;
+        }
+        let ctxt = self.ctxt;
+
+        let mut oldstate = ReaderState::Start;
+        let mut olddepth = 0;
+        let mut oldnode: xmlNodePtr = ptr::null_mut();
+        let mut initial = false;
+
+        if self.mode == ReaderMode::Initial {
+            self.mode = ReaderMode::Interactive;
+            loop {
+                if self.push_data() < 0 {
+                    return self.fail();
+                }
+                if !(*ctxt).node.is_null()
+                    || self.mode == ReaderMode::Eof
+                    || self.state == ReaderState::Done
+                {
+                    break;
+                }
+            }
+            if !(*ctxt).myDoc.is_null() {
+                self.node = (*(*ctxt).myDoc).children;
+            }
+            if self.node.is_null() {
+                if (*ctxt).node.is_null() {
+                    return self.fail();
+                }
+                self.node = *(*ctxt).nodeTab;
+            }
+            self.state = ReaderState::Element;
+            self.depth = 0;
+            (*ctxt).parseMode = xmlParserMode_XML_PARSE_READER;
+            initial = true;
+        } else {
+            oldstate = self.state;
+            olddepth = (*ctxt).nodeNr;
+            oldnode = self.node;
+        }
+
+        loop {
+            if !initial {
+                match self.next_node(oldstate, olddepth, &mut oldnode) {
+                    Step::Found => {}
+                    Step::End => {
+                        self.state = ReaderState::Done;
+                        return 0;
+                    }
+                    Step::Ret(ret) => return ret,
+                }
+            }
+            initial = false;
+
+            // If we are in the middle of a piece of text make sure it's
+            // finished.
+            let node = self.node;
+            if !node.is_null()
+                && (*node).next.is_null()
+                && ((*node).type_ == xmlElementType_XML_TEXT_NODE
+                    || (*node).type_ == xmlElementType_XML_CDATA_SECTION_NODE)
+                && self.do_expand() < 0
+            {
+                return -1;
+            }
+
+            // Handle entities enter and exit when in entity replacement mode
+            let node = self.node;
+            if !node.is_null()
+                && (*node).type_ == xmlElementType_XML_ENTITY_REF_NODE
+                && (*ctxt).replaceEntities == 1
+            {
+                let decl = (*node).children;
+                if !decl.is_null()
+                    && (*decl).type_ == xmlElementType_XML_ENTITY_DECL
+                    && !(*decl).children.is_null()
+                {
+                    self.ent_tab.push(node);
+                    self.node = (*decl).children;
+                }
+            }
+            let node = self.node;
+            if !node.is_null() && (*node).type_ == xmlElementType_XML_ENTITY_DECL {
+                if let Some(&ent) = self.ent_tab.last() {
+                    if (*ent).children == node {
+                        self.node = self.ent_tab.pop().unwrap();
+                        self.depth += 1;
+                        continue;
+                    }
+                }
+            }
+
+            return 1;
+        }
+    }
+
+    /// The `get_next_node` part of `xmlTextReaderRead`.
+    unsafe fn next_node(
+        &mut self,
+        oldstate: ReaderState,
+        olddepth: c_int,
+        oldnode: &mut xmlNodePtr,
+    ) -> Step {
+        let ctxt = self.ctxt;
+
+        if self.node.is_null() {
+            if self.mode == ReaderMode::Eof {
+                return Step::Ret(0);
+            }
+            return Step::Ret(self.fail());
+        }
+
+        // If we are not backtracking on ancestors or examined nodes, that
+        // the parser didn't finish or that we aren't at the end of stream,
+        // continue processing.
+        while !self.node.is_null()
+            && (*self.node).next.is_null()
+            && (*ctxt).nodeNr == olddepth
+            && self.needs_more_input(oldstate)
+            && ((*ctxt).node.is_null()
+                || (*ctxt).node == self.node
+                || (*ctxt).node == (*self.node).parent)
+            && (*ctxt).instate != xmlParserInputState_XML_PARSER_EOF
+            && !self.parser_stopped()
+        {
+            if self.push_data() < 0 {
+                return Step::Ret(self.fail());
+            }
+            if self.node.is_null() {
+                return Step::End;
+            }
+        }
+
+        let node = self.node;
+        if oldstate != ReaderState::Backtrack
+            && !(*node).children.is_null()
+            && (*node).type_ != xmlElementType_XML_ENTITY_REF_NODE
+            && (*node).type_ != xmlElementType_XML_XINCLUDE_START
+            && (*node).type_ != xmlElementType_XML_DTD_NODE
+        {
+            self.node = (*node).children;
+            self.depth += 1;
+            self.state = ReaderState::Element;
+            return Step::Found;
+        }
+
+        let unclosed_empty = oldstate == ReaderState::Element
+            && (*node).type_ == xmlElementType_XML_ELEMENT_NODE
+            && (*node).children.is_null()
+            && ((*node).extra & NODE_IS_EMPTY) == 0;
+
+        if !(*node).next.is_null() {
+            if unclosed_empty {
+                self.state = ReaderState::End;
+                return Step::Found;
+            }
+            self.node = (*node).next;
+            self.state = ReaderState::Element;
+
+            // Cleanup of the old node
+            let prev = (*self.node).prev;
+            if self.ent_tab.is_empty()
+                && !prev.is_null()
+                && (*prev).type_ != xmlElementType_XML_DTD_NODE
+                && ((*prev).extra & NODE_IS_PRESERVED) == 0
+            {
+                if *oldnode == prev {
+                    *oldnode = ptr::null_mut();
+                }
+                self.free_node(prev);
+            }
+
+            return Step::Found;
+        }
+
+        if unclosed_empty {
+            self.state = ReaderState::End;
+            return Step::Found;
+        }
+
+        self.node = (*node).parent;
+        if self.node.is_null()
+            || (*self.node).type_ == xmlElementType_XML_DOCUMENT_NODE
+            || (*self.node).type_ == xmlElementType_XML_HTML_DOCUMENT_NODE
+        {
+            if self.mode != ReaderMode::Eof {
+                let val = xmlParseChunk(ctxt, c"".as_ptr(), 0, 1);
+                self.state = ReaderState::Done;
+                if val != 0 {
+                    return Step::Ret(self.fail());
+                }
+            }
+            self.node = ptr::null_mut();
+            self.depth = -1;
+
+            // Cleanup of the old node
+            let old = *oldnode;
+            if !old.is_null()
+                && self.ent_tab.is_empty()
+                && (*old).type_ != xmlElementType_XML_DTD_NODE
+                && ((*old).extra & NODE_IS_PRESERVED) == 0
+            {
+                self.free_node(old);
+            }
+
+            return Step::End;
+        }
+
+        let last = (*self.node).last;
+        if self.ent_tab.is_empty() && !last.is_null() && ((*last).extra & NODE_IS_PRESERVED) == 0 {
+            self.free_node(last);
+        }
+        self.depth -= 1;
+        self.state = ReaderState::Backtrack;
+
+        Step::Found
+    }
+
+    unsafe fn needs_more_input(&self, oldstate: ReaderState) -> bool {
+        let node = self.node;
+        let children = (*node).children;
+
+        oldstate == ReaderState::Backtrack
+            || children.is_null()
+            || (*node).type_ == xmlElementType_XML_ENTITY_REF_NODE
+            || ((*children).type_ == xmlElementType_XML_TEXT_NODE && (*children).next.is_null())
+            || (*node).type_ == xmlElementType_XML_DTD_NODE
+            || (*node).type_ == xmlElementType_XML_DOCUMENT_NODE
+            || (*node).type_ == xmlElementType_XML_HTML_DOCUMENT_NODE
+    }
+
+    unsafe fn const_string(&self, string: &[u8]) -> *const xmlChar {
+        xmlDictLookup(self.dict, string.as_ptr(), string.len() as c_int)
+    }
+
+    /// The attribute or namespace declaration the reader was moved to, or
+    /// the current node. Namespace declarations are `xmlNs` structures,
+    /// which only share `type_` with nodes.
+    fn cur_node(&self) -> xmlNodePtr {
+        if self.curnode.is_null() {
+            self.node
+        } else {
+            self.curnode
+        }
+    }
+
+    /// The node type of the current node, or `None` if not positioned.
+    pub unsafe fn node_type(&self) -> ReaderType {
+        if self.node.is_null() {
+            return ReaderType::None;
+        }
+        let node = self.cur_node();
+        match (*node).type_ {
+            xmlElementType_XML_ELEMENT_NODE => {
+                if matches!(self.state, ReaderState::End | ReaderState::Backtrack) {
+                    ReaderType::EndElement
+                } else {
+                    ReaderType::Element
+                }
+            }
+            xmlElementType_XML_ATTRIBUTE_NODE | xmlElementType_XML_NAMESPACE_DECL => {
+                ReaderType::Attribute
+            }
+            xmlElementType_XML_TEXT_NODE => {
+                if xmlIsBlankNode(node) != 0 {
+                    if xmlNodeGetSpacePreserve(node) != 0 {
+                        ReaderType::SignificantWhitespace
+                    } else {
+                        ReaderType::Whitespace
+                    }
+                } else {
+                    ReaderType::Text
+                }
+            }
+            xmlElementType_XML_CDATA_SECTION_NODE => ReaderType::Cdata,
+            xmlElementType_XML_ENTITY_REF_NODE => ReaderType::EntityReference,
+            xmlElementType_XML_ENTITY_NODE => ReaderType::Entity,
+            xmlElementType_XML_PI_NODE => ReaderType::ProcessingInstruction,
+            xmlElementType_XML_COMMENT_NODE => ReaderType::Comment,
+            xmlElementType_XML_DOCUMENT_NODE | xmlElementType_XML_HTML_DOCUMENT_NODE => {
+                ReaderType::Document
+            }
+            xmlElementType_XML_DOCUMENT_FRAG_NODE => ReaderType::DocumentFragment,
+            xmlElementType_XML_NOTATION_NODE => ReaderType::Notation,
+            xmlElementType_XML_DOCUMENT_TYPE_NODE | xmlElementType_XML_DTD_NODE => {
+                ReaderType::DocumentType
+            }
+            _ => ReaderType::None,
+        }
+    }
+
+    /// Whether the current node is an element written as `<a/>`.
+    pub unsafe fn is_empty_element(&self) -> c_int {
+        let node = self.node;
+        if node.is_null() {
+            return -1;
+        }
+        if (*node).type_ != xmlElementType_XML_ELEMENT_NODE
+            || !self.curnode.is_null()
+            || !(*node).children.is_null()
+            || self.state == ReaderState::End
+        {
+            return 0;
+        }
+        ((*node).extra & NODE_IS_EMPTY != 0) as c_int
+    }
+
+    /// The depth of the current node; attributes are one level below
+    /// their element.
+    pub fn depth(&self) -> c_int {
+        if self.node.is_null() {
+            return 0;
+        }
+        if !self.curnode.is_null() {
+            return self.depth + 1;
+        }
+        self.depth
+    }
+
+    /// The qualified name of the current node, interned in the parser
+    /// dictionary. Namespace declarations are named `xmlns` or
+    /// `xmlns:prefix`.
+    pub unsafe fn const_name(&self) -> *const xmlChar {
+        if self.node.is_null() {
+            return ptr::null();
+        }
+        let node = self.cur_node();
+        match (*node).type_ {
+            xmlElementType_XML_ELEMENT_NODE | xmlElementType_XML_ATTRIBUTE_NODE => {
+                let ns = (*node).ns;
+                if ns.is_null() || (*ns).prefix.is_null() {
+                    (*node).name
+                } else {
+                    xmlDictQLookup(self.dict, (*ns).prefix, (*node).name)
+                }
+            }
+            xmlElementType_XML_NAMESPACE_DECL => {
+                let ns = node as xmlNsPtr;
+                if (*ns).prefix.is_null() {
+                    self.const_string(b"xmlns")
+                } else {
+                    xmlDictQLookup(self.dict, c"xmlns".as_ptr() as *const xmlChar, (*ns).prefix)
+                }
+            }
+            xmlElementType_XML_TEXT_NODE => self.const_string(b"#text"),
+            xmlElementType_XML_CDATA_SECTION_NODE => self.const_string(b"#cdata-section"),
+            xmlElementType_XML_COMMENT_NODE => self.const_string(b"#comment"),
+            xmlElementType_XML_DOCUMENT_NODE | xmlElementType_XML_HTML_DOCUMENT_NODE => {
+                self.const_string(b"#document")
+            }
+            xmlElementType_XML_DOCUMENT_FRAG_NODE => self.const_string(b"#document-fragment"),
+            xmlElementType_XML_ENTITY_NODE
+            | xmlElementType_XML_ENTITY_REF_NODE
+            | xmlElementType_XML_PI_NODE
+            | xmlElementType_XML_NOTATION_NODE
+            | xmlElementType_XML_DOCUMENT_TYPE_NODE
+            | xmlElementType_XML_DTD_NODE => {
+                if (*node).name.is_null() {
+                    ptr::null()
+                } else {
+                    xmlDictLookup(self.dict, (*node).name, -1)
+                }
+            }
+            _ => ptr::null(),
+        }
+    }
+
+    /// Whether the current node can have a text value.
+    pub unsafe fn has_value(&self) -> bool {
+        !self.node.is_null()
+            && matches!(
+                (*self.cur_node()).type_,
+                xmlElementType_XML_ATTRIBUTE_NODE
+                    | xmlElementType_XML_NAMESPACE_DECL
+                    | xmlElementType_XML_TEXT_NODE
+                    | xmlElementType_XML_CDATA_SECTION_NODE
+                    | xmlElementType_XML_PI_NODE
+                    | xmlElementType_XML_COMMENT_NODE
+            )
+    }
+
+    /// The text value of the current node, owned by the tree or, for an
+    /// attribute value made of several nodes, by the reader until the next
+    /// call.
+    pub unsafe fn const_value(&mut self) -> *const xmlChar {
+        if self.node.is_null() {
+            return ptr::null();
+        }
+        let node = self.cur_node();
+        match (*node).type_ {
+            xmlElementType_XML_NAMESPACE_DECL => (*(node as xmlNsPtr)).href,
+            xmlElementType_XML_ATTRIBUTE_NODE => {
+                let children = (*node).children;
+                if !children.is_null()
+                    && (*children).type_ == xmlElementType_XML_TEXT_NODE
+                    && (*children).next.is_null()
+                {
+                    return (*children).content;
+                }
+                self.value_buf.clear();
+                let value = xmlNodeListGetString((*node).doc, children, 1);
+                if !value.is_null() {
+                    self.value_buf
+                        .extend_from_slice(CStr::from_ptr(value as *const c_char).to_bytes());
+                    xmlFree.unwrap()(value as *mut c_void);
+                }
+                self.value_buf.push(0);
+                self.value_buf.as_ptr()
+            }
+            xmlElementType_XML_TEXT_NODE
+            | xmlElementType_XML_CDATA_SECTION_NODE
+            | xmlElementType_XML_PI_NODE
+            | xmlElementType_XML_COMMENT_NODE => (*node).content,
+            _ => ptr::null(),
+        }
+    }
+
+    /// The element the reader is on, if it can have attributes.
+    unsafe fn element(&self) -> Option<xmlNodePtr> {
+        let node = self.node;
+        (!node.is_null() && (*node).type_ == xmlElementType_XML_ELEMENT_NODE).then_some(node)
+    }
+
+    /// Move to the first attribute of the current element, namespace
+    /// declarations first. Returns 1 on success, 0 if the node is not an
+    /// element or has no attributes, and -1 if the reader is not on a
+    /// node.
+    pub unsafe fn move_to_first_attribute(&mut self) -> c_int {
+        if self.node.is_null() {
+            return -1;
+        }
+        let Some(node) = self.element() else {
+            return 0;
+        };
+        if !(*node).nsDef.is_null() {
+            self.curnode = (*node).nsDef as xmlNodePtr;
+        } else if !(*node).properties.is_null() {
+            self.curnode = (*node).properties as xmlNodePtr;
+        } else {
+            return 0;
+        }
+        1
+    }
+
+    /// Move to the attribute after the current one, from the namespace
+    /// declarations on to the attributes. Returns 1 on success, 0 after the
+    /// last attribute and -1 if the reader is not on a node.
+    pub unsafe fn move_to_next_attribute(&mut self) -> c_int {
+        if self.node.is_null() {
+            return -1;
+        }
+        let Some(node) = self.element() else {
+            return 0;
+        };
+        let cur = self.curnode;
+        if cur.is_null() {
+            return self.move_to_first_attribute();
+        }
+        let next = if (*cur).type_ == xmlElementType_XML_NAMESPACE_DECL {
+            let ns = cur as xmlNsPtr;
+            if (*ns).next.is_null() {
+                (*node).properties as xmlNodePtr
+            } else {
+                (*ns).next as xmlNodePtr
+            }
+        } else if (*cur).type_ == xmlElementType_XML_ATTRIBUTE_NODE {
+            (*cur).next
+        } else {
+            ptr::null_mut()
+        };
+        if next.is_null() {
+            return 0;
+        }
+        self.curnode = next;
+        1
+    }
+
+    /// Move back from an attribute to its element. Returns 1 if the reader
+    /// was on an attribute, 0 if not and -1 if it is not on a node.
+    pub unsafe fn move_to_element(&mut self) -> c_int {
+        if self.node.is_null() {
+            return -1;
+        }
+        if self.element().is_none() || self.curnode.is_null() {
+            return 0;
+        }
+        self.curnode = ptr::null_mut();
+        1
+    }
+
+    /// The value of attribute `no` of the current element, counting the
+    /// namespace declarations first. The caller must free the result. NULL
+    /// if out of range, or if the reader is on an attribute or not on an
+    /// element.
+    pub unsafe fn get_attribute_no(&self, no: c_int) -> *mut xmlChar {
+        if !self.curnode.is_null() {
+            return ptr::null_mut();
+        }
+        let Some(node) = self.element() else {
+            return ptr::null_mut();
+        };
+        let mut i = 0;
+        let mut ns = (*node).nsDef;
+        while i < no && !ns.is_null() {
+            ns = (*ns).next;
+            i += 1;
+        }
+        if !ns.is_null() {
+            return xmlStrdup((*ns).href);
+        }
+        let mut cur: xmlAttrPtr = (*node).properties;
+        if cur.is_null() {
+            return ptr::null_mut();
+        }
+        while i < no {
+            cur = (*cur).next;
+            if cur.is_null() {
+                return ptr::null_mut();
+            }
+            i += 1;
+        }
+        if (*cur).children.is_null() {
+            return ptr::null_mut();
+        }
+        xmlNodeListGetString((*node).doc, (*cur).children, 1)
+    }
+
+    /// The value of the attribute of the current element with qualified
+    /// name `name`, which may be a namespace declaration such as `xmlns` or
+    /// `xmlns:p`. Prefixes are resolved in the scope of the element. The
+    /// caller must free the result.
+    pub unsafe fn get_attribute(&self, name: &CStr) -> *mut xmlChar {
+        if !self.curnode.is_null() {
+            return ptr::null_mut();
+        }
+        let Some(node) = self.element() else {
+            return ptr::null_mut();
+        };
+        let (prefix, local) = split_qname(name);
+        let mut ret = ptr::null_mut();
+        match prefix {
+            None if name == c"xmlns" => {
+                let mut ns = (*node).nsDef;
+                while !ns.is_null() {
+                    if (*ns).prefix.is_null() {
+                        return xmlStrdup((*ns).href);
+                    }
+                    ns = (*ns).next;
+                }
+            }
+            None => {
+                xmlNodeGetAttrValue(
+                    node,
+                    local.as_ptr() as *const xmlChar,
+                    ptr::null(),
+                    &mut ret,
+                );
+            }
+            Some(prefix) if prefix.as_bytes() == b"xmlns" => {
+                let mut ns = (*node).nsDef;
+                while !ns.is_null() {
+                    if !(*ns).prefix.is_null()
+                        && CStr::from_ptr((*ns).prefix as *const c_char) == local
+                    {
+                        return xmlStrdup((*ns).href);
+                    }
+                    ns = (*ns).next;
+                }
+            }
+            Some(prefix) => {
+                let ns = xmlSearchNs((*node).doc, node, prefix.as_ptr() as *const xmlChar);
+                if !ns.is_null() {
+                    xmlNodeGetAttrValue(
+                        node,
+                        local.as_ptr() as *const xmlChar,
+                        (*ns).href,
+                        &mut ret,
+                    );
+                }
+            }
+        }
+        ret
+    }
+}
+
+/// Split a qualified name at its first colon like `xmlSplitQName4`. A name
+/// with a leading or trailing colon has no prefix.
+fn split_qname(name: &CStr) -> (Option<CString>, &CStr) {
+    let bytes = name.to_bytes_with_nul();
+    match bytes.iter().position(|&b| b == b':') {
+        Some(colon) if colon > 0 && bytes[colon + 1] != 0 => {
+            let prefix = CString::new(&bytes[..colon]).unwrap();
+            let local = CStr::from_bytes_with_nul(&bytes[colon + 1..]).unwrap();
+            (Some(prefix), local)
+        }
+        _ => (None, name),
+    }
+}
+
+impl Drop for TextReader {
+    fn drop(&mut self) {
+        self.close();
+    }
+}
+
+/// Outcome of one step of the tree walk in `xmlTextReaderRead`.
+enum Step {
+    Found,
+    End,
+    Ret(c_int),
+}
+
+/// The node following `cur` in document order, skipping its subtree.
+unsafe fn successor(mut cur: xmlNodePtr) -> xmlNodePtr {
+    if cur.is_null() {
+        return ptr::null_mut();
+    }
+    if !(*cur).next.is_null() {
+        return (*cur).next;
+    }
+    loop {
+        cur = (*cur).parent;
+        if cur.is_null() {
+            return ptr::null_mut();
+        }
+        if !(*cur).next.is_null() {
+            return (*cur).next;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::CStr;
+
+    fn events(xml: &[u8]) -> Vec<(ReaderType, String, c_int, c_int)> {
+        let mut out = Vec::new();
+        unsafe {
+            let mut reader = TextReader::for_memory(xml, ptr::null(), ptr::null(), 0).unwrap();
+            while reader.read() == 1 {
+                let name = CStr::from_ptr(reader.const_name() as *const c_char);
+                out.push((
+                    reader.node_type(),
+                    name.to_string_lossy().into_owned(),
+                    reader.depth(),
+                    reader.is_empty_element(),
+                ));
+            }
+        }
+        out
+    }
+
+    #[test]
+    fn test_read_sequence() {
+        let got = events(b"<a><b/><c>t</c><!--x--></a>");
+        let expected = vec![
+            (ReaderType::Element, "a".to_string(), 0, 0),
+            (ReaderType::Element, "b".to_string(), 1, 1),
+            (ReaderType::Element, "c".to_string(), 1, 0),
+            (ReaderType::Text, "#text".to_string(), 2, 0),
+            (ReaderType::EndElement, "c".to_string(), 1, 0),
+            (ReaderType::Comment, "#comment".to_string(), 1, 0),
+            (ReaderType::EndElement, "a".to_string(), 0, 0),
+        ];
+        assert_eq!(got, expected);
+    }
+
+    #[test]
+    fn test_read_empty_pair() {
+        let got = events(b"<r><e></e></r>");
+        let types: Vec<_> = got.iter().map(|e| e.0).collect();
+        assert_eq!(
+            types,
+            vec![
+                ReaderType::Element,
+                ReaderType::Element,
+                ReaderType::EndElement,
+                ReaderType::EndElement
+            ]
+        );
+        assert_eq!(got[1].3, 0);
+    }
+
+    #[test]
+    fn test_read_malformed() {
+        let mut ret = 1;
+        unsafe {
+            let mut reader =
+                TextReader::for_memory(b"<a><b></a>", ptr::null(), ptr::null(), 0).unwrap();
+            while ret == 1 {
+                ret = reader.read();
+            }
+        }
+        assert_eq!(ret, -1);
+    }
+
+    #[test]
+    fn test_split_qname() {
+        assert_eq!(split_qname(c"p:a"), (Some(c"p".to_owned()), c"a"));
+        assert_eq!(split_qname(c"a:b:c"), (Some(c"a".to_owned()), c"b:c"));
+        for name in [c"a", c":a", c"a:"] {
+            assert_eq!(split_qname(name), (None, name));
+        }
+    }
+
+    #[test]
+    fn test_attributes() {
+        unsafe {
+            let mut reader = TextReader::for_memory(
+                b"<a xmlns:p='urn:p' p:x='1' y='2'/>",
+                ptr::null(),
+                ptr::null(),
+                0,
+            )
+            .unwrap();
+            assert_eq!(reader.read(), 1);
+            let mut names = Vec::new();
+            while reader.move_to_next_attribute() == 1 {
+                assert_eq!(reader.node_type(), ReaderType::Attribute);
+                let name = CStr::from_ptr(reader.const_name() as *const c_char);
+                names.push(name.to_string_lossy().into_owned());
+            }
+            assert_eq!(names, ["xmlns:p", "p:x", "y"]);
+            assert_eq!(reader.move_to_element(), 1);
+            assert_eq!(reader.node_type(), ReaderType::Element);
+        }
+    }
+}
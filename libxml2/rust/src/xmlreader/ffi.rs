@@ -0,0 +1,193 @@
+#![allow(clippy::missing_safety_doc)]
+
+use std::ffi::CStr;
+use std::os::raw::{c_char, c_int};
+use std::ptr;
+
+use super::core::TextReader;
+use crate::static_bindings::{xmlChar, xmlNodePtr, xmlStrdup, xmlTextReader};
+
+// Rust-only, never `#[no_mangle]`: the C functions not ported here, such as
+// the validation hooks, would take the boxed `TextReader` for their own
+// struct, so xmlreader.c stays linked whole for C callers.
+
+unsafe fn as_reader<'a>(reader: *mut xmlTextReader) -> Option<&'a mut TextReader> {
+    (reader as *mut TextReader).as_mut()
+}
+
+/// Create an xmltextReader for an XML in-memory document.
+pub unsafe extern "C" fn xmlReaderForMemory(
+    buffer: *const c_char,
+    size: c_int,
+    url: *const c_char,
+    encoding: *const c_char,
+    options: c_int,
+) -> *mut xmlTextReader {
+    if buffer.is_null() || size < 0 {
+        return ptr::null_mut();
+    }
+    let input = std::slice::from_raw_parts(buffer as *const u8, size as usize);
+    match TextReader::for_memory(input, url, encoding, options) {
+        Some(reader) => Box::into_raw(reader) as *mut xmlTextReader,
+        None => ptr::null_mut(),
+    }
+}
+
+/// Deallocate all the resources associated to the reader.
+pub unsafe extern "C" fn xmlFreeTextReader(reader: *mut xmlTextReader) {
+    if !reader.is_null() {
+        drop(Box::from_raw(reader as *mut TextReader));
+    }
+}
+
+/// Moves the position of the current instance to the next node in the
+/// stream. Returns 1 if the node was read successfully, 0 if there are no
+/// more nodes to read, or -1 in case of error.
+pub unsafe extern "C" fn xmlTextReaderRead(reader: *mut xmlTextReader) -> c_int {
+    match as_reader(reader) {
+        Some(reader) => reader.read(),
+        None => -1,
+    }
+}
+
+/// Moves the reader to the closed state and releases the parser.
+pub unsafe extern "C" fn xmlTextReaderClose(reader: *mut xmlTextReader) -> c_int {
+    match as_reader(reader) {
+        Some(reader) => {
+            reader.close();
+            0
+        }
+        None => -1,
+    }
+}
+
+/// Reads the contents of the current node and the full subtree. The
+/// subtree is owned by the reader and freed on the next read.
+pub unsafe extern "C" fn xmlTextReaderExpand(reader: *mut xmlTextReader) -> xmlNodePtr {
+    match as_reader(reader) {
+        Some(reader) => reader.expand(),
+        None => ptr::null_mut(),
+    }
+}
+
+/// Get the node type of the current node, or -1 in case of error.
+pub unsafe extern "C" fn xmlTextReaderNodeType(reader: *mut xmlTextReader) -> c_int {
+    match as_reader(reader) {
+        Some(reader) => reader.node_type() as c_int,
+        None => -1,
+    }
+}
+
+/// Check if the current node is empty (`<a/>`).
+pub unsafe extern "C" fn xmlTextReaderIsEmptyElement(reader: *mut xmlTextReader) -> c_int {
+    match as_reader(reader) {
+        Some(reader) => reader.is_empty_element(),
+        None => -1,
+    }
+}
+
+/// The depth of the node in the tree.
+pub unsafe extern "C" fn xmlTextReaderDepth(reader: *mut xmlTextReader) -> c_int {
+    match as_reader(reader) {
+        Some(reader) => reader.depth(),
+        None => -1,
+    }
+}
+
+/// The qualified name of the node. The string is owned by the reader.
+pub unsafe extern "C" fn xmlTextReaderConstName(reader: *mut xmlTextReader) -> *const xmlChar {
+    match as_reader(reader) {
+        Some(reader) => reader.const_name(),
+        None => ptr::null(),
+    }
+}
+
+/// The qualified name of the node. The caller must free the result.
+pub unsafe extern "C" fn xmlTextReaderName(reader: *mut xmlTextReader) -> *mut xmlChar {
+    let name = xmlTextReaderConstName(reader);
+    if name.is_null() {
+        return ptr::null_mut();
+    }
+    xmlStrdup(name)
+}
+
+/// Whether the node can have a text value.
+pub unsafe extern "C" fn xmlTextReaderHasValue(reader: *mut xmlTextReader) -> c_int {
+    match as_reader(reader) {
+        Some(reader) => reader.has_value() as c_int,
+        None => -1,
+    }
+}
+
+/// The text value of the node. The string is owned by the document.
+pub unsafe extern "C" fn xmlTextReaderConstValue(reader: *mut xmlTextReader) -> *const xmlChar {
+    match as_reader(reader) {
+        Some(reader) => reader.const_value(),
+        None => ptr::null(),
+    }
+}
+
+/// The text value of the node. The caller must free the result.
+pub unsafe extern "C" fn xmlTextReaderValue(reader: *mut xmlTextReader) -> *mut xmlChar {
+    let value = xmlTextReaderConstValue(reader);
+    if value.is_null() {
+        return ptr::null_mut();
+    }
+    xmlStrdup(value)
+}
+
+/// Moves the position of the current instance to the first attribute
+/// associated with the current node. Returns 1 in case of success, -1 in
+/// case of error, 0 if not found.
+pub unsafe extern "C" fn xmlTextReaderMoveToFirstAttribute(reader: *mut xmlTextReader) -> c_int {
+    match as_reader(reader) {
+        Some(reader) => reader.move_to_first_attribute(),
+        None => -1,
+    }
+}
+
+/// Moves the position of the current instance to the next attribute
+/// associated with the current node. Returns 1 in case of success, -1 in
+/// case of error, 0 if not found.
+pub unsafe extern "C" fn xmlTextReaderMoveToNextAttribute(reader: *mut xmlTextReader) -> c_int {
+    match as_reader(reader) {
+        Some(reader) => reader.move_to_next_attribute(),
+        None => -1,
+    }
+}
+
+/// Moves the position of the current instance to the node that contains
+/// the current attribute node. Returns 1 in case of success, -1 in case of
+/// error, 0 if not moved.
+pub unsafe extern "C" fn xmlTextReaderMoveToElement(reader: *mut xmlTextReader) -> c_int {
+    match as_reader(reader) {
+        Some(reader) => reader.move_to_element(),
+        None => -1,
+    }
+}
+
+/// Provides the value of the attribute with the specified index relative
+/// to the containing element. The caller must free the result.
+pub unsafe extern "C" fn xmlTextReaderGetAttributeNo(
+    reader: *mut xmlTextReader,
+    no: c_int,
+) -> *mut xmlChar {
+    match as_reader(reader) {
+        Some(reader) => reader.get_attribute_no(no),
+        None => ptr::null_mut(),
+    }
+}
+
+/// Provides the value of the attribute with the specified qualified name.
+/// The caller must free the result.
+pub unsafe extern "C" fn xmlTextReaderGetAttribute(
+    reader: *mut xmlTextReader,
+    name: *const xmlChar,
+) -> *mut xmlChar {
+    match as_reader(reader) {
+        Some(reader) if !name.is_null() => {
+            reader.get_attribute(CStr::from_ptr(name as *const c_char))
+        }
+        _ => ptr::null_mut(),
+    }
+}
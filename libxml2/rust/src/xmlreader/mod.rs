@@ -1 +1,9 @@
-// Empty stub module for xmlreader
+//! Rust implementation of xmlreader module
+//!
+//! Streaming pull parser walking the tree built by the push parser, for Rust
+//! callers. The library keeps exporting the C reader of xmlreader.c.
+
+pub mod core;
+pub mod ffi;
+
+pub use core::*;
@@ -0,0 +1,309 @@
+use std::os::raw::c_int;
+
+use crate::encoding::{Converter, ENC_ERR_INPUT, ENC_ERR_SPACE};
+
+/// `XML_ESCAPE_ATTR` from `private/io.h`: escape for an attribute value.
+pub const ESCAPE_ATTR: u32 = 1 << 0;
+/// `XML_ESCAPE_NON_ASCII`: write non-ASCII characters as references.
+pub const ESCAPE_NON_ASCII: u32 = 1 << 1;
+
+/// The `xmlSaveOption` bits.
+pub const SAVE_FORMAT: c_int = 1 << 0;
+pub const SAVE_NO_DECL: c_int = 1 << 1;
+pub const SAVE_NO_EMPTY: c_int = 1 << 2;
+pub const SAVE_NO_XHTML: c_int = 1 << 3;
+pub const SAVE_XHTML: c_int = 1 << 4;
+pub const SAVE_AS_XML: c_int = 1 << 5;
+pub const SAVE_AS_HTML: c_int = 1 << 6;
+pub const SAVE_WSNONSIG: c_int = 1 << 7;
+pub const SAVE_EMPTY: c_int = 1 << 8;
+pub const SAVE_NO_INDENT: c_int = 1 << 9;
+pub const SAVE_INDENT: c_int = 1 << 10;
+
+/// Options that only change HTML, XHTML or whitespace-insignificant
+/// output, none of which `save_doc` writes yet.
+pub const SAVE_UNSUPPORTED: c_int = SAVE_XHTML | SAVE_AS_HTML | SAVE_WSNONSIG;
+
+/// Why `save_doc` or `dump_doc` could not serialize a document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SaveError {
+    /// The node is not an XML or HTML document.
+    NotADocument,
+    /// Options from `SAVE_UNSUPPORTED`, as given.
+    Options(c_int),
+    /// An HTML document without `SAVE_AS_XML`.
+    Html,
+    /// A document with an XHTML 1.0 DTD, without `SAVE_NO_XHTML`.
+    Xhtml,
+    /// An output encoding other than UTF-8 for `save_doc`, or one without
+    /// a converter for `dump_doc`.
+    Encoding(Vec<u8>),
+    /// Text the output encoding cannot convert because it is not UTF-8,
+    /// at this offset of the document serialized as UTF-8.
+    InvalidUtf8(usize),
+}
+
+/// Whether `name` is an encoding the output is written in unchanged.
+pub fn is_utf8_name(name: &[u8]) -> bool {
+    name.eq_ignore_ascii_case(b"UTF-8") || name.eq_ignore_ascii_case(b"UTF8")
+}
+
+/// Decode the character at the start of `s` like `xmlGetUTF8Char`,
+/// returning it and its length. The end of `s` reads as a NUL.
+///
+/// As in C, a 4-byte lead above 0xF4 is only checked through the value
+/// its low bits give.
+pub fn get_utf8_char(s: &[u8]) -> Option<(u32, usize)> {
+    let at = |i: usize| s.get(i).copied().unwrap_or(0) as u32;
+    let c = at(0);
+    if c < 0x80 {
+        return Some((c, 1));
+    }
+    if at(1) & 0xC0 != 0x80 {
+        return None;
+    }
+    if c < 0xE0 {
+        if c < 0xC2 {
+            return None;
+        }
+        return Some((((c & 0x1F) << 6) | (at(1) & 0x3F), 2));
+    }
+    if at(2) & 0xC0 != 0x80 {
+        return None;
+    }
+    if c < 0xF0 {
+        let val = ((c & 0x0F) << 12) | ((at(1) & 0x3F) << 6) | (at(2) & 0x3F);
+        if val < 0x800 || (0xD800..0xE000).contains(&val) {
+            return None;
+        }
+        return Some((val, 3));
+    }
+    if at(3) & 0xC0 != 0x80 {
+        return None;
+    }
+    let val = ((c & 0x07) << 18) | ((at(1) & 0x3F) << 12) | ((at(2) & 0x3F) << 6) | (at(3) & 0x3F);
+    if !(0x10000..0x110000).contains(&val) {
+        return None;
+    }
+    Some((val, 4))
+}
+
+/// The reference `xmlEscapeTab` and its attribute variant give an ASCII
+/// character, if it needs one.
+fn escape_ascii(c: u8, attr: bool) -> Option<&'static [u8]> {
+    match c {
+        b'\t' if attr => Some(b"&#9;"),
+        b'\n' if attr => Some(b"&#10;"),
+        b'\t' | b'\n' => None,
+        b'\r' => Some(b"&#13;"),
+        0x01..=0x1F => Some(b"&#xFFFD;"),
+        b'"' if attr => Some(b"&quot;"),
+        b'&' => Some(b"&amp;"),
+        b'<' => Some(b"&lt;"),
+        b'>' => Some(b"&gt;"),
+        _ => None,
+    }
+}
+
+/// Escape `text` like `xmlSerializeText` with the `ESCAPE_*` `flags`,
+/// appending to `out`. A NUL ends the text.
+///
+/// Control characters other than tab, newline and carriage return are
+/// written as `&#xFFFD;`. With `ESCAPE_NON_ASCII`, other characters
+/// become hex references, and each byte of invalid UTF-8, U+FFFE and
+/// U+FFFF become `&#xFFFD;`.
+pub fn serialize_text(text: &[u8], flags: u32, out: &mut Vec<u8>) {
+    let attr = flags & ESCAPE_ATTR != 0;
+    let non_ascii = flags & ESCAPE_NON_ASCII != 0;
+    let mut i = 0;
+    while i < text.len() {
+        let c = text[i];
+        if c == 0 {
+            break;
+        }
+        if c < 0x80 {
+            match escape_ascii(c, attr) {
+                Some(repl) => out.extend_from_slice(repl),
+                None => out.push(c),
+            }
+            i += 1;
+        } else if non_ascii {
+            let val = match get_utf8_char(&text[i..]) {
+                Some((val, len)) => {
+                    i += len;
+                    if val == 0xFFFE || val == 0xFFFF {
+                        0xFFFD
+                    } else {
+                        val
+                    }
+                }
+                None => {
+                    i += 1;
+                    0xFFFD
+                }
+            };
+            out.extend_from_slice(format!("&#x{val:X};").as_bytes());
+        } else {
+            out.push(c);
+            i += 1;
+        }
+    }
+}
+
+/// Quote `s` like `xmlOutputBufferWriteQuotedString`: in double quotes,
+/// or single quotes if it has a `"` but no `'`. With both, `"` is
+/// written as `&quot;`.
+pub fn write_quoted(s: &[u8], out: &mut Vec<u8>) {
+    if !s.contains(&b'"') {
+        out.push(b'"');
+        out.extend_from_slice(s);
+        out.push(b'"');
+    } else if !s.contains(&b'\'') {
+        out.push(b'\'');
+        out.extend_from_slice(s);
+        out.push(b'\'');
+    } else {
+        out.push(b'"');
+        for &c in s {
+            if c == b'"' {
+                out.extend_from_slice(b"&quot;");
+            } else {
+                out.push(c);
+            }
+        }
+        out.push(b'"');
+    }
+}
+
+/// Quote the value of an internal entity without its original text,
+/// as `xmlBufDumpEntityContent` does: `"` and `%` become references.
+pub fn write_entity_content(content: &[u8], out: &mut Vec<u8>) {
+    out.push(b'"');
+    for &c in content {
+        match c {
+            b'"' => out.extend_from_slice(b"&quot;"),
+            b'%' => out.extend_from_slice(b"&#x25;"),
+            _ => out.push(c),
+        }
+    }
+    out.push(b'"');
+}
+
+/// Convert `utf8`, a serialized document, with the output converter
+/// `convert` as `xmlCharEncOutput` does, appending to `out`. A character
+/// the encoding cannot represent is written as a decimal reference, which
+/// goes through the converter too. Invalid UTF-8, or a sequence cut off
+/// at the end, fails with its offset in `utf8`.
+pub fn encode_output(utf8: &[u8], convert: Converter, out: &mut Vec<u8>) -> Result<(), usize> {
+    let mut buf = [0; 4096];
+    let mut i = 0;
+    while i < utf8.len() {
+        let res = convert(&mut buf, &utf8[i..]);
+        out.extend_from_slice(&buf[..res.written]);
+        i += res.read;
+        match res.ret {
+            ENC_ERR_SPACE => {}
+            ENC_ERR_INPUT => {
+                let (c, len) = get_utf8_char(&utf8[i..]).ok_or(i)?;
+                let charref = format!("&#{c};");
+                let res = convert(&mut buf, charref.as_bytes());
+                if res.ret < 0 || res.read != charref.len() {
+                    return Err(i);
+                }
+                out.extend_from_slice(&buf[..res.written]);
+                i += len;
+            }
+            ret if ret < 0 || res.read == 0 => return Err(i),
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(s: &[u8], flags: u32) -> String {
+        let mut out = Vec::new();
+        serialize_text(s, flags, &mut out);
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_serialize_text() {
+        assert_eq!(text(b"a<b>&c\r\n\t\"", 0), "a&lt;b&gt;&amp;c&#13;\n\t\"");
+        assert_eq!(
+            text(b"a<b>&c\r\n\t\"", ESCAPE_ATTR),
+            "a&lt;b&gt;&amp;c&#13;&#10;&#9;&quot;"
+        );
+        assert_eq!(text(b"\x01\x1F\x7F", 0), "&#xFFFD;&#xFFFD;\x7F");
+        assert_eq!(text(b"ab\0cd", 0), "ab");
+        assert_eq!(text("é€😀".as_bytes(), 0), "é€😀");
+        assert_eq!(
+            text("é€😀".as_bytes(), ESCAPE_NON_ASCII),
+            "&#xE9;&#x20AC;&#x1F600;"
+        );
+        assert_eq!(
+            text(b"\xC3\xA9\xC3x\xEF\xBF\xBF", ESCAPE_NON_ASCII),
+            "&#xE9;&#xFFFD;x&#xFFFD;"
+        );
+        assert_eq!(text(b"\xE2\x82", ESCAPE_NON_ASCII), "&#xFFFD;&#xFFFD;");
+    }
+
+    #[test]
+    fn test_get_utf8_char() {
+        assert_eq!(get_utf8_char(b"a"), Some((0x61, 1)));
+        assert_eq!(get_utf8_char(b"\xC2\xA0"), Some((0xA0, 2)));
+        assert_eq!(get_utf8_char(b"\xC1\xBF"), None);
+        assert_eq!(get_utf8_char(b"\xE0\x80\x80"), None);
+        assert_eq!(get_utf8_char(b"\xED\xA0\x80"), None);
+        assert_eq!(get_utf8_char(b"\xF4\x90\x80\x80"), None);
+        assert_eq!(get_utf8_char(b"\xF9\x80\x80\x80"), Some((0x40000, 4)));
+    }
+
+    #[test]
+    fn test_encode_output() {
+        use crate::encoding::{ascii_to_ascii, utf8_to_latin1, utf8_to_utf16le};
+
+        let encode = |s: &[u8], convert| {
+            let mut out = Vec::new();
+            encode_output(s, convert, &mut out).map(|()| out)
+        };
+        assert_eq!(
+            encode("<r>é€😀</r>".as_bytes(), utf8_to_latin1 as Converter),
+            Ok(b"<r>\xE9&#8364;&#128512;</r>".to_vec())
+        );
+        assert_eq!(
+            encode("é".as_bytes(), ascii_to_ascii as Converter),
+            Ok(b"&#233;".to_vec())
+        );
+        assert_eq!(
+            encode("a€".as_bytes(), utf8_to_utf16le as Converter),
+            Ok(b"a\0\xAC\x20".to_vec())
+        );
+        assert_eq!(encode(b"ab\xE2\x82", utf8_to_latin1 as Converter), Err(2));
+        assert_eq!(encode(b"a\xFFb", utf8_to_latin1 as Converter), Err(1));
+        assert_eq!(encode(b"a\xC0\xAF", utf8_to_utf16le as Converter), Err(1));
+        let long = "é€".repeat(3000);
+        let latin1 = encode(long.as_bytes(), utf8_to_latin1 as Converter).unwrap();
+        assert_eq!(latin1, b"\xE9&#8364;".repeat(3000));
+    }
+
+    #[test]
+    fn test_write_quoted() {
+        let quoted = |s: &[u8]| {
+            let mut out = Vec::new();
+            write_quoted(s, &mut out);
+            String::from_utf8(out).unwrap()
+        };
+        assert_eq!(quoted(b"a'b"), "\"a'b\"");
+        assert_eq!(quoted(b"a\"b"), "'a\"b'");
+        assert_eq!(quoted(b"'a\"b"), "\"'a&quot;b\"");
+        assert_eq!(quoted(b""), "\"\"");
+
+        let mut out = Vec::new();
+        write_entity_content(b"50% \"off\"", &mut out);
+        assert_eq!(out, b"\"50&#x25; &quot;off&quot;\"");
+    }
+}
@@ -0,0 +1,97 @@
+#![allow(clippy::missing_safety_doc)]
+
+use std::ffi::CStr;
+use std::os::raw::{c_char, c_int};
+use std::ptr;
+
+use super::serialize;
+use crate::static_bindings::{xmlChar, xmlDocPtr, xmlMalloc};
+
+// Ensure symbols are exported for linking
+#[cfg(feature = "rust-xmlsave")]
+#[used]
+static XMLSAVE_FFI_LINKAGE: () = ();
+
+/// Copy `value` into a NUL-terminated xmlMalloc'ed string.
+unsafe fn dup(value: &[u8]) -> *mut xmlChar {
+    let Some(malloc) = xmlMalloc else {
+        return ptr::null_mut();
+    };
+    let mem = malloc(value.len() + 1) as *mut xmlChar;
+    if mem.is_null() {
+        return ptr::null_mut();
+    }
+    ptr::copy_nonoverlapping(value.as_ptr(), mem, value.len());
+    *mem.add(value.len()) = 0;
+    mem
+}
+
+/// Dump the document to memory in `txt_encoding`, or its own encoding
+/// if NULL, indented if `format` is set. The buffer is allocated with
+/// xmlMalloc and stays NULL if the document cannot be serialized.
+#[no_mangle]
+pub unsafe extern "C" fn xmlDocDumpFormatMemoryEnc(
+    out_doc: xmlDocPtr,
+    doc_txt_ptr: *mut *mut xmlChar,
+    doc_txt_len: *mut c_int,
+    txt_encoding: *const c_char,
+    format: c_int,
+) {
+    if !doc_txt_len.is_null() {
+        *doc_txt_len = 0;
+    }
+    if doc_txt_ptr.is_null() {
+        return;
+    }
+    *doc_txt_ptr = ptr::null_mut();
+    if out_doc.is_null() {
+        return;
+    }
+    let encoding = (!txt_encoding.is_null()).then(|| CStr::from_ptr(txt_encoding).to_bytes());
+    let Ok(out) = serialize::dump_doc(out_doc, encoding, format != 0) else {
+        return;
+    };
+    let Ok(len) = c_int::try_from(out.len()) else {
+        return;
+    };
+    let mem = dup(&out);
+    if mem.is_null() {
+        return;
+    }
+    if !doc_txt_len.is_null() {
+        *doc_txt_len = len;
+    }
+    *doc_txt_ptr = mem;
+}
+
+/// Same as `xmlDocDumpFormatMemoryEnc` without an encoding or formatting.
+#[no_mangle]
+pub unsafe extern "C" fn xmlDocDumpMemory(
+    cur: xmlDocPtr,
+    mem: *mut *mut xmlChar,
+    size: *mut c_int,
+) {
+    xmlDocDumpFormatMemoryEnc(cur, mem, size, ptr::null(), 0);
+}
+
+/// Same as `xmlDocDumpFormatMemoryEnc` without an encoding.
+#[no_mangle]
+pub unsafe extern "C" fn xmlDocDumpFormatMemory(
+    cur: xmlDocPtr,
+    mem: *mut *mut xmlChar,
+    size: *mut c_int,
+    format: c_int,
+) {
+    xmlDocDumpFormatMemoryEnc(cur, mem, size, ptr::null(), format);
+}
+
+/// Same as `xmlDocDumpFormatMemoryEnc` without formatting.
+#[no_mangle]
+pub unsafe extern "C" fn xmlDocDumpMemoryEnc(
+    out_doc: xmlDocPtr,
+    doc_txt_ptr: *mut *mut xmlChar,
+    doc_txt_len: *mut c_int,
+    txt_encoding: *const c_char,
+) {
+    xmlDocDumpFormatMemoryEnc(out_doc, doc_txt_ptr, doc_txt_len, txt_encoding, 0);
+}
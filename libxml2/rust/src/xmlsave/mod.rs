@@ -1 +1,10 @@
-// Empty stub module for xmlsave
+//! Rust implementation of xmlsave module
+//!
+//! XML document serialization
+
+pub mod core;
+pub mod ffi;
+mod serialize;
+
+pub use core::*;
+pub use serialize::{dump_doc, save_doc};
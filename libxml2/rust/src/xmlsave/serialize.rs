@@ -0,0 +1,741 @@
+#![allow(clippy::missing_safety_doc)]
+
+use std::ffi::CStr;
+use std::os::raw::{c_char, c_int, c_void};
+use std::ptr;
+
+use super::core::*;
+use crate::encoding::{
+    char_encoding_by_name, converters, converters_by_name, utf16_bom, CHAR_ENCODING_UTF16,
+    CHAR_ENCODING_UTF8,
+};
+use crate::static_bindings::{
+    xmlAttrPtr, xmlAttributeDefault_XML_ATTRIBUTE_FIXED, xmlAttributeDefault_XML_ATTRIBUTE_IMPLIED,
+    xmlAttributeDefault_XML_ATTRIBUTE_REQUIRED, xmlAttributePtr,
+    xmlAttributeType_XML_ATTRIBUTE_CDATA, xmlAttributeType_XML_ATTRIBUTE_ENTITIES,
+    xmlAttributeType_XML_ATTRIBUTE_ENTITY, xmlAttributeType_XML_ATTRIBUTE_ENUMERATION,
+    xmlAttributeType_XML_ATTRIBUTE_ID, xmlAttributeType_XML_ATTRIBUTE_IDREF,
+    xmlAttributeType_XML_ATTRIBUTE_IDREFS, xmlAttributeType_XML_ATTRIBUTE_NMTOKEN,
+    xmlAttributeType_XML_ATTRIBUTE_NMTOKENS, xmlAttributeType_XML_ATTRIBUTE_NOTATION, xmlChar,
+    xmlDocPtr, xmlDtdPtr, xmlElementContentOccur_XML_ELEMENT_CONTENT_MULT,
+    xmlElementContentOccur_XML_ELEMENT_CONTENT_ONCE,
+    xmlElementContentOccur_XML_ELEMENT_CONTENT_OPT,
+    xmlElementContentOccur_XML_ELEMENT_CONTENT_PLUS, xmlElementContentPtr,
+    xmlElementContentType_XML_ELEMENT_CONTENT_ELEMENT,
+    xmlElementContentType_XML_ELEMENT_CONTENT_OR, xmlElementContentType_XML_ELEMENT_CONTENT_PCDATA,
+    xmlElementContentType_XML_ELEMENT_CONTENT_SEQ, xmlElementPtr,
+    xmlElementTypeVal_XML_ELEMENT_TYPE_ANY, xmlElementTypeVal_XML_ELEMENT_TYPE_ELEMENT,
+    xmlElementTypeVal_XML_ELEMENT_TYPE_EMPTY, xmlElementTypeVal_XML_ELEMENT_TYPE_MIXED,
+    xmlElementType_XML_ATTRIBUTE_DECL, xmlElementType_XML_ATTRIBUTE_NODE,
+    xmlElementType_XML_CDATA_SECTION_NODE, xmlElementType_XML_COMMENT_NODE,
+    xmlElementType_XML_DOCUMENT_FRAG_NODE, xmlElementType_XML_DOCUMENT_NODE,
+    xmlElementType_XML_DTD_NODE, xmlElementType_XML_ELEMENT_DECL, xmlElementType_XML_ELEMENT_NODE,
+    xmlElementType_XML_ENTITY_DECL, xmlElementType_XML_ENTITY_REF_NODE,
+    xmlElementType_XML_HTML_DOCUMENT_NODE, xmlElementType_XML_NAMESPACE_DECL,
+    xmlElementType_XML_PI_NODE, xmlElementType_XML_TEXT_NODE, xmlElementType_XML_XINCLUDE_END,
+    xmlElementType_XML_XINCLUDE_START, xmlEntityPtr,
+    xmlEntityType_XML_EXTERNAL_GENERAL_PARSED_ENTITY,
+    xmlEntityType_XML_EXTERNAL_GENERAL_UNPARSED_ENTITY,
+    xmlEntityType_XML_EXTERNAL_PARAMETER_ENTITY, xmlEntityType_XML_INTERNAL_GENERAL_ENTITY,
+    xmlEntityType_XML_INTERNAL_PARAMETER_ENTITY, xmlEnumerationPtr, xmlHashScan, xmlIsXHTML,
+    xmlNodePtr, xmlNotationPtr, xmlNsPtr,
+};
+
+/// `XML_LOCAL_NAMESPACE`, the type of a namespace declaration.
+const LOCAL_NAMESPACE: u32 = xmlElementType_XML_NAMESPACE_DECL;
+
+/// The default `xmlTreeIndentString`, written once per level.
+const INDENT: &[u8] = b"  ";
+/// The deepest level indented, `MAX_INDENT` bytes of `INDENT`.
+const MAX_INDENT_LEVEL: i32 = 60 / INDENT.len() as i32;
+
+unsafe fn bytes<'a>(p: *const xmlChar) -> &'a [u8] {
+    if p.is_null() {
+        &[]
+    } else {
+        CStr::from_ptr(p as *const c_char).to_bytes()
+    }
+}
+
+unsafe extern "C" fn collect_notation(
+    payload: *mut c_void,
+    data: *mut c_void,
+    _name: *const xmlChar,
+) {
+    (*(data as *mut Vec<xmlNotationPtr>)).push(payload as xmlNotationPtr);
+}
+
+/// The document's DTD, as `xmlGetIntSubset` finds it.
+unsafe fn int_subset(doc: xmlDocPtr) -> xmlDtdPtr {
+    let mut cur = (*doc).children;
+    while !cur.is_null() {
+        if (*cur).type_ == xmlElementType_XML_DTD_NODE {
+            return cur as xmlDtdPtr;
+        }
+        cur = (*cur).next;
+    }
+    (*doc).intSubset
+}
+
+/// The state of `xmlSaveCtxt` that XML output reads.
+struct Saver {
+    out: Vec<u8>,
+    options: c_int,
+    /// No output encoding, so text is kept ASCII.
+    non_ascii: u32,
+    /// `SAVE_FORMAT`, cleared inside elements with text children.
+    format: bool,
+    /// The indentation level, -1 inside the DTD.
+    level: i32,
+}
+
+impl Saver {
+    fn write(&mut self, data: &[u8]) {
+        self.out.extend_from_slice(data);
+    }
+
+    unsafe fn write_str(&mut self, s: *const xmlChar) {
+        self.out.extend_from_slice(bytes(s));
+    }
+
+    unsafe fn write_text(&mut self, text: *const xmlChar, flags: u32) {
+        serialize_text(bytes(text), flags | self.non_ascii, &mut self.out);
+    }
+
+    unsafe fn write_quoted(&mut self, s: *const xmlChar) {
+        write_quoted(bytes(s), &mut self.out);
+    }
+
+    /// `xmlSaveWriteIndent`, unless `SAVE_NO_INDENT`.
+    fn indent(&mut self) {
+        if self.options & SAVE_NO_INDENT != 0 {
+            return;
+        }
+        for _ in 0..self.level.clamp(0, MAX_INDENT_LEVEL) {
+            self.write(INDENT);
+        }
+    }
+
+    unsafe fn write_qname(&mut self, prefix: *const xmlChar, name: *const xmlChar) {
+        if !prefix.is_null() {
+            self.write_str(prefix);
+            self.write(b":");
+        }
+        self.write_str(name);
+    }
+
+    unsafe fn write_node_name(&mut self, cur: xmlNodePtr) {
+        let ns = (*cur).ns;
+        let prefix = if ns.is_null() {
+            ptr::null()
+        } else {
+            (*ns).prefix
+        };
+        self.write_qname(prefix, (*cur).name);
+    }
+
+    /// `xmlNsDumpOutput`: a namespace declaration, less `xmlns:xml`.
+    unsafe fn namespace(&mut self, cur: xmlNsPtr) {
+        if (*cur).type_ != LOCAL_NAMESPACE || (*cur).href.is_null() {
+            return;
+        }
+        if bytes((*cur).prefix) == b"xml" {
+            return;
+        }
+        if (*cur).prefix.is_null() {
+            self.write(b" xmlns");
+        } else {
+            self.write(b" xmlns:");
+            self.write_str((*cur).prefix);
+        }
+        self.write(b"=\"");
+        self.write_text((*cur).href, ESCAPE_ATTR);
+        self.write(b"\"");
+    }
+
+    /// `xmlAttrDumpOutput`: text children are escaped, entity references
+    /// kept, and anything else dropped.
+    unsafe fn attribute(&mut self, cur: xmlAttrPtr) {
+        self.write(b" ");
+        let ns = (*cur).ns;
+        let prefix = if ns.is_null() {
+            ptr::null()
+        } else {
+            (*ns).prefix
+        };
+        self.write_qname(prefix, (*cur).name);
+        self.write(b"=\"");
+        let mut child = (*cur).children;
+        while !child.is_null() {
+            if (*child).type_ == xmlElementType_XML_TEXT_NODE {
+                self.write_text((*child).content, ESCAPE_ATTR);
+            } else if (*child).type_ == xmlElementType_XML_ENTITY_REF_NODE {
+                self.write(b"&");
+                self.write_str((*child).name);
+                self.write(b";");
+            }
+            child = (*child).next;
+        }
+        self.write(b"\"");
+    }
+
+    /// `xmlDtdDumpOutput`. Notations are not in the children, so they come
+    /// first, in the order of their hash table.
+    unsafe fn dtd(&mut self, dtd: xmlDtdPtr) {
+        self.write(b"<!DOCTYPE ");
+        self.write_str((*dtd).name);
+        if !(*dtd).ExternalID.is_null() {
+            self.write(b" PUBLIC ");
+            self.write_quoted((*dtd).ExternalID);
+            self.write(b" ");
+            self.write_quoted((*dtd).SystemID);
+        } else if !(*dtd).SystemID.is_null() {
+            self.write(b" SYSTEM ");
+            self.write_quoted((*dtd).SystemID);
+        }
+        if (*dtd).entities.is_null()
+            && (*dtd).elements.is_null()
+            && (*dtd).attributes.is_null()
+            && (*dtd).notations.is_null()
+            && (*dtd).pentities.is_null()
+        {
+            self.write(b">");
+            return;
+        }
+        self.write(b" [\n");
+        let doc = (*dtd).doc;
+        if !(*dtd).notations.is_null() && (doc.is_null() || (*doc).intSubset == dtd) {
+            let mut notations: Vec<xmlNotationPtr> = Vec::new();
+            xmlHashScan(
+                (*dtd).notations as _,
+                Some(collect_notation),
+                &mut notations as *mut _ as *mut c_void,
+            );
+            for nota in notations {
+                self.notation_decl(nota);
+            }
+        }
+        let (format, level) = (self.format, self.level);
+        self.format = false;
+        self.level = -1;
+        let mut cur = (*dtd).children;
+        while !cur.is_null() {
+            self.node(cur);
+            cur = (*cur).next;
+        }
+        self.format = format;
+        self.level = level;
+        self.write(b"]>");
+    }
+
+    /// `xmlBufDumpNotationDecl`.
+    unsafe fn notation_decl(&mut self, nota: xmlNotationPtr) {
+        self.write(b"<!NOTATION ");
+        self.write_str((*nota).name);
+        if !(*nota).PublicID.is_null() {
+            self.write(b" PUBLIC ");
+            self.write_quoted((*nota).PublicID);
+            if !(*nota).SystemID.is_null() {
+                self.write(b" ");
+                self.write_quoted((*nota).SystemID);
+            }
+        } else {
+            self.write(b" SYSTEM ");
+            self.write_quoted((*nota).SystemID);
+        }
+        self.write(b" >\n");
+    }
+
+    /// `xmlBufDumpElementDecl`.
+    unsafe fn element_decl(&mut self, elem: xmlElementPtr) {
+        self.write(b"<!ELEMENT ");
+        self.write_qname((*elem).prefix, (*elem).name);
+        self.write(b" ");
+        match (*elem).etype {
+            xmlElementTypeVal_XML_ELEMENT_TYPE_EMPTY => self.write(b"EMPTY"),
+            xmlElementTypeVal_XML_ELEMENT_TYPE_ANY => self.write(b"ANY"),
+            xmlElementTypeVal_XML_ELEMENT_TYPE_MIXED
+            | xmlElementTypeVal_XML_ELEMENT_TYPE_ELEMENT => {
+                let content = (*elem).content;
+                if !content.is_null() {
+                    self.write(b"(");
+                    self.element_content(content, content);
+                    self.write(b")");
+                    self.element_occur(content);
+                }
+            }
+            _ => {}
+        }
+        self.write(b">\n");
+    }
+
+    /// The inside of the outermost parentheses of a content model, as
+    /// `xmlBufDumpElementContent` writes it. A nested group gets its own
+    /// parentheses when its type differs from its parent's or it has an
+    /// occurrence operator.
+    unsafe fn element_content(&mut self, cur: xmlElementContentPtr, top: xmlElementContentPtr) {
+        match (*cur).type_ {
+            xmlElementContentType_XML_ELEMENT_CONTENT_PCDATA => self.write(b"#PCDATA"),
+            xmlElementContentType_XML_ELEMENT_CONTENT_ELEMENT => {
+                self.write_qname((*cur).prefix, (*cur).name)
+            }
+            xmlElementContentType_XML_ELEMENT_CONTENT_SEQ
+            | xmlElementContentType_XML_ELEMENT_CONTENT_OR => {
+                let parent = (*cur).parent;
+                let group = cur != top
+                    && !parent.is_null()
+                    && ((*cur).type_ != (*parent).type_
+                        || (*cur).ocur != xmlElementContentOccur_XML_ELEMENT_CONTENT_ONCE);
+                if group {
+                    self.write(b"(");
+                }
+                let sep: &[u8] = if (*cur).type_ == xmlElementContentType_XML_ELEMENT_CONTENT_SEQ {
+                    b" , "
+                } else {
+                    b" | "
+                };
+                if !(*cur).c1.is_null() {
+                    self.element_content((*cur).c1, top);
+                }
+                self.write(sep);
+                if !(*cur).c2.is_null() {
+                    self.element_content((*cur).c2, top);
+                }
+                if group {
+                    self.write(b")");
+                }
+            }
+            _ => {}
+        }
+        if cur != top {
+            self.element_occur(cur);
+        }
+    }
+
+    /// `xmlBufDumpElementOccur`.
+    unsafe fn element_occur(&mut self, cur: xmlElementContentPtr) {
+        match (*cur).ocur {
+            xmlElementContentOccur_XML_ELEMENT_CONTENT_OPT => self.write(b"?"),
+            xmlElementContentOccur_XML_ELEMENT_CONTENT_MULT => self.write(b"*"),
+            xmlElementContentOccur_XML_ELEMENT_CONTENT_PLUS => self.write(b"+"),
+            _ => {}
+        }
+    }
+
+    /// `xmlSaveWriteAttributeDecl`.
+    unsafe fn attribute_decl(&mut self, attr: xmlAttributePtr) {
+        self.write(b"<!ATTLIST ");
+        self.write_str((*attr).elem);
+        self.write(b" ");
+        self.write_qname((*attr).prefix, (*attr).name);
+        match (*attr).atype {
+            xmlAttributeType_XML_ATTRIBUTE_CDATA => self.write(b" CDATA"),
+            xmlAttributeType_XML_ATTRIBUTE_ID => self.write(b" ID"),
+            xmlAttributeType_XML_ATTRIBUTE_IDREF => self.write(b" IDREF"),
+            xmlAttributeType_XML_ATTRIBUTE_IDREFS => self.write(b" IDREFS"),
+            xmlAttributeType_XML_ATTRIBUTE_ENTITY => self.write(b" ENTITY"),
+            xmlAttributeType_XML_ATTRIBUTE_ENTITIES => self.write(b" ENTITIES"),
+            xmlAttributeType_XML_ATTRIBUTE_NMTOKEN => self.write(b" NMTOKEN"),
+            xmlAttributeType_XML_ATTRIBUTE_NMTOKENS => self.write(b" NMTOKENS"),
+            xmlAttributeType_XML_ATTRIBUTE_ENUMERATION => {
+                self.write(b" (");
+                self.enumeration((*attr).tree);
+            }
+            xmlAttributeType_XML_ATTRIBUTE_NOTATION => {
+                self.write(b" NOTATION (");
+                self.enumeration((*attr).tree);
+            }
+            _ => {}
+        }
+        match (*attr).def {
+            xmlAttributeDefault_XML_ATTRIBUTE_REQUIRED => self.write(b" #REQUIRED"),
+            xmlAttributeDefault_XML_ATTRIBUTE_IMPLIED => self.write(b" #IMPLIED"),
+            xmlAttributeDefault_XML_ATTRIBUTE_FIXED => self.write(b" #FIXED"),
+            _ => {}
+        }
+        if !(*attr).defaultValue.is_null() {
+            self.write(b" \"");
+            self.write_text((*attr).defaultValue, ESCAPE_ATTR);
+            self.write(b"\"");
+        }
+        self.write(b">\n");
+    }
+
+    /// `xmlBufDumpEnumeration`, closing the parenthesis.
+    unsafe fn enumeration(&mut self, mut cur: xmlEnumerationPtr) {
+        while !cur.is_null() {
+            self.write_str((*cur).name);
+            if !(*cur).next.is_null() {
+                self.write(b" | ");
+            }
+            cur = (*cur).next;
+        }
+        self.write(b")");
+    }
+
+    /// `xmlBufDumpEntityDecl`. The value of an internal entity is its
+    /// original text if the parser kept it.
+    unsafe fn entity_decl(&mut self, ent: xmlEntityPtr) {
+        let etype = (*ent).etype;
+        if etype == xmlEntityType_XML_INTERNAL_PARAMETER_ENTITY
+            || etype == xmlEntityType_XML_EXTERNAL_PARAMETER_ENTITY
+        {
+            self.write(b"<!ENTITY % ");
+        } else {
+            self.write(b"<!ENTITY ");
+        }
+        self.write_str((*ent).name);
+        self.write(b" ");
+        if etype == xmlEntityType_XML_EXTERNAL_GENERAL_PARSED_ENTITY
+            || etype == xmlEntityType_XML_EXTERNAL_GENERAL_UNPARSED_ENTITY
+            || etype == xmlEntityType_XML_EXTERNAL_PARAMETER_ENTITY
+        {
+            if !(*ent).ExternalID.is_null() {
+                self.write(b"PUBLIC ");
+                self.write_quoted((*ent).ExternalID);
+                self.write(b" ");
+            } else {
+                self.write(b"SYSTEM ");
+            }
+            self.write_quoted((*ent).SystemID);
+        }
+        if etype == xmlEntityType_XML_EXTERNAL_GENERAL_UNPARSED_ENTITY && !(*ent).content.is_null()
+        {
+            self.write(b" NDATA ");
+            if !(*ent).orig.is_null() {
+                self.write_str((*ent).orig);
+            } else {
+                self.write_str((*ent).content);
+            }
+        }
+        if etype == xmlEntityType_XML_INTERNAL_GENERAL_ENTITY
+            || etype == xmlEntityType_XML_INTERNAL_PARAMETER_ENTITY
+        {
+            if !(*ent).orig.is_null() {
+                self.write_quoted((*ent).orig);
+            } else if !(*ent).content.is_null() {
+                write_entity_content(bytes((*ent).content), &mut self.out);
+            }
+        }
+        self.write(b">\n");
+    }
+
+    /// `xmlNodeDumpOutputInternal` without formatting: `cur` and its
+    /// children.
+    unsafe fn node(&mut self, cur: xmlNodePtr) {
+        match (*cur).type_ {
+            xmlElementType_XML_DTD_NODE => self.dtd(cur as xmlDtdPtr),
+            xmlElementType_XML_DOCUMENT_FRAG_NODE => self.children(cur),
+            xmlElementType_XML_ELEMENT_DECL => self.element_decl(cur as xmlElementPtr),
+            xmlElementType_XML_ATTRIBUTE_DECL => self.attribute_decl(cur as xmlAttributePtr),
+            xmlElementType_XML_ENTITY_DECL => self.entity_decl(cur as xmlEntityPtr),
+            xmlElementType_XML_ELEMENT_NODE => {
+                self.write(b"<");
+                self.write_node_name(cur);
+                let mut ns = (*cur).nsDef;
+                while !ns.is_null() {
+                    self.namespace(ns);
+                    ns = (*ns).next;
+                }
+                let mut attr = (*cur).properties;
+                while !attr.is_null() {
+                    self.attribute(attr);
+                    attr = (*attr).next;
+                }
+                if (*cur).children.is_null() {
+                    if self.options & SAVE_NO_EMPTY == 0 {
+                        self.write(b"/>");
+                        return;
+                    }
+                    self.write(b"></");
+                    self.write_node_name(cur);
+                    self.write(b">");
+                } else {
+                    let format = self.format;
+                    if self.format && has_text_child(cur) {
+                        self.format = false;
+                    }
+                    self.write(b">");
+                    if self.format {
+                        self.write(b"\n");
+                    }
+                    if self.level >= 0 {
+                        self.level += 1;
+                    }
+                    self.children(cur);
+                    if self.level > 0 {
+                        self.level -= 1;
+                    }
+                    if self.format {
+                        self.indent();
+                    }
+                    self.write(b"</");
+                    self.write_node_name(cur);
+                    self.write(b">");
+                    self.format = format;
+                }
+            }
+            xmlElementType_XML_TEXT_NODE => {
+                if (*cur).content.is_null() {
+                    return;
+                }
+                // C compares the name with its own `xmlStringTextNoenc`,
+                // which is not ours when the tree comes from another copy
+                // of the library.
+                if bytes((*cur).name) == b"textnoenc" {
+                    self.write_str((*cur).content);
+                } else {
+                    self.write_text((*cur).content, 0);
+                }
+            }
+            xmlElementType_XML_PI_NODE => {
+                self.write(b"<?");
+                self.write_str((*cur).name);
+                if !(*cur).content.is_null() {
+                    self.write(b" ");
+                    self.write_str((*cur).content);
+                }
+                self.write(b"?>");
+            }
+            xmlElementType_XML_COMMENT_NODE if !(*cur).content.is_null() => {
+                self.write(b"<!--");
+                self.write_str((*cur).content);
+                self.write(b"-->");
+            }
+            xmlElementType_XML_ENTITY_REF_NODE => {
+                self.write(b"&");
+                self.write_str((*cur).name);
+                self.write(b";");
+            }
+            xmlElementType_XML_CDATA_SECTION_NODE => self.cdata(bytes((*cur).content)),
+            xmlElementType_XML_ATTRIBUTE_NODE => self.attribute(cur as xmlAttrPtr),
+            xmlElementType_XML_NAMESPACE_DECL => self.namespace(cur as xmlNsPtr),
+            _ => {}
+        }
+    }
+
+    /// The children of `parent`, formatted each on its own line and
+    /// indented if they are markup.
+    unsafe fn children(&mut self, parent: xmlNodePtr) {
+        let mut child = (*parent).children;
+        while !child.is_null() {
+            let child_type = (*child).type_;
+            if self.format
+                && (child_type == xmlElementType_XML_ELEMENT_NODE
+                    || child_type == xmlElementType_XML_PI_NODE
+                    || child_type == xmlElementType_XML_COMMENT_NODE)
+            {
+                self.indent();
+            }
+            self.node(child);
+            if self.format
+                && child_type != xmlElementType_XML_XINCLUDE_START
+                && child_type != xmlElementType_XML_XINCLUDE_END
+            {
+                self.write(b"\n");
+            }
+            child = (*child).next;
+        }
+    }
+
+    /// A CDATA section, split after each `]]` followed by `>` so that
+    /// no section holds its own end marker.
+    fn cdata(&mut self, content: &[u8]) {
+        if content.is_empty() {
+            self.write(b"<![CDATA[]]>");
+            return;
+        }
+        let mut start = 0;
+        let mut end = 0;
+        while end < content.len() {
+            if content[end..].starts_with(b"]]>") {
+                end += 2;
+                self.write(b"<![CDATA[");
+                self.write(&content[start..end]);
+                self.write(b"]]>");
+                start = end;
+            }
+            end += 1;
+        }
+        if start != end {
+            self.write(b"<![CDATA[");
+            self.write(&content[start..]);
+            self.write(b"]]>");
+        }
+    }
+}
+
+/// Whether `cur` has text, CDATA or entity reference children, whose
+/// whitespace formatting would change.
+unsafe fn has_text_child(cur: xmlNodePtr) -> bool {
+    let mut child = (*cur).children;
+    while !child.is_null() {
+        let child_type = (*child).type_;
+        if child_type == xmlElementType_XML_TEXT_NODE
+            || child_type == xmlElementType_XML_CDATA_SECTION_NODE
+            || child_type == xmlElementType_XML_ENTITY_REF_NODE
+        {
+            return true;
+        }
+        child = (*child).next;
+    }
+    false
+}
+
+/// The checks `save_doc` and `dump_doc` share: `doc` is a document, the
+/// options are supported, and an HTML document has `SAVE_AS_XML`.
+unsafe fn check_doc(doc: xmlDocPtr, options: c_int) -> Result<(), SaveError> {
+    if doc.is_null() {
+        return Err(SaveError::NotADocument);
+    }
+    let doc_type = (*doc).type_;
+    if doc_type != xmlElementType_XML_DOCUMENT_NODE
+        && doc_type != xmlElementType_XML_HTML_DOCUMENT_NODE
+    {
+        return Err(SaveError::NotADocument);
+    }
+    if options & SAVE_UNSUPPORTED != 0 {
+        return Err(SaveError::Options(options & SAVE_UNSUPPORTED));
+    }
+    if doc_type == xmlElementType_XML_HTML_DOCUMENT_NODE && options & SAVE_AS_XML == 0 {
+        return Err(SaveError::Html);
+    }
+    Ok(())
+}
+
+/// Fail on a document with an XHTML 1.0 DTD, unless `SAVE_NO_XHTML`.
+unsafe fn check_xhtml(doc: xmlDocPtr, options: c_int) -> Result<(), SaveError> {
+    if options & SAVE_NO_XHTML == 0 {
+        let dtd = int_subset(doc);
+        if !dtd.is_null() && xmlIsXHTML((*dtd).SystemID, (*dtd).ExternalID) > 0 {
+            return Err(SaveError::Xhtml);
+        }
+    }
+    Ok(())
+}
+
+/// `xmlSaveDocInternal` for an XML document, in UTF-8. The declaration
+/// names `encoding`; without one, non-ASCII characters are escaped.
+unsafe fn write_doc(doc: xmlDocPtr, options: c_int, encoding: Option<&[u8]>) -> Vec<u8> {
+    let mut saver = Saver {
+        out: Vec::new(),
+        options,
+        non_ascii: if encoding.is_none() {
+            ESCAPE_NON_ASCII
+        } else {
+            0
+        },
+        format: options & SAVE_FORMAT != 0,
+        level: 0,
+    };
+    if options & SAVE_NO_DECL == 0 {
+        saver.write(b"<?xml version=\"");
+        if (*doc).version.is_null() {
+            saver.write(b"1.0");
+        } else {
+            saver.write_str((*doc).version);
+        }
+        saver.write(b"\"");
+        if let Some(encoding) = encoding {
+            saver.write(b" encoding=\"");
+            saver.write(encoding);
+            saver.write(b"\"");
+        }
+        match (*doc).standalone {
+            0 => saver.write(b" standalone=\"no\""),
+            1 => saver.write(b" standalone=\"yes\""),
+            _ => {}
+        }
+        saver.write(b"?>\n");
+    }
+    let mut child = (*doc).children;
+    while !child.is_null() {
+        saver.level = 0;
+        saver.node(child);
+        let child_type = (*child).type_;
+        if child_type != xmlElementType_XML_XINCLUDE_START
+            && child_type != xmlElementType_XML_XINCLUDE_END
+        {
+            saver.write(b"\n");
+        }
+        child = (*child).next;
+    }
+    saver.out
+}
+
+/// Serialize `doc` like `xmlSaveDoc` on a context from
+/// `xmlSaveToBuffer(buf, NULL, options)`.
+///
+/// The output is in the document's encoding, which must be UTF-8 or
+/// unset. HTML and XHTML output are not supported. `SAVE_FORMAT`
+/// indents with two spaces, as if `xmlIndentTreeOutput` were set, and
+/// `xmlSaveNoEmptyTags` is not consulted: only `SAVE_NO_EMPTY` gives
+/// end tags to empty elements.
+pub unsafe fn save_doc(doc: xmlDocPtr, options: c_int) -> Result<Vec<u8>, SaveError> {
+    check_doc(doc, options)?;
+    let encoding = (*doc).encoding;
+    if !encoding.is_null() && !is_utf8_name(bytes(encoding)) {
+        return Err(SaveError::Encoding(bytes(encoding).to_vec()));
+    }
+    check_xhtml(doc, options)?;
+    let encoding = (!encoding.is_null()).then(|| bytes(encoding));
+    Ok(write_doc(doc, options, encoding))
+}
+
+/// Serialize `doc` in `encoding`, or the document's encoding if `None`,
+/// like `xmlDocDumpFormatMemoryEnc`; `xmlDocDumpMemory` passes `None`
+/// and no formatting. The declaration names the encoding as given.
+///
+/// The document is written in UTF-8 and then converted by the output
+/// converter of the `encoding` module for that name. Characters the
+/// encoding cannot represent become decimal references, and "UTF-16"
+/// starts with a byte order mark. Without any encoding, the output is
+/// ASCII with non-ASCII characters escaped. `format` indents as
+/// `save_doc` does with `SAVE_FORMAT`. Documents with an XHTML 1.0 DTD
+/// are not supported.
+pub unsafe fn dump_doc(
+    doc: xmlDocPtr,
+    encoding: Option<&[u8]>,
+    format: bool,
+) -> Result<Vec<u8>, SaveError> {
+    let options = if format {
+        SAVE_AS_XML | SAVE_FORMAT
+    } else {
+        SAVE_AS_XML
+    };
+    check_doc(doc, options)?;
+    let encoding = encoding.or_else(|| {
+        let encoding = (*doc).encoding;
+        (!encoding.is_null()).then(|| bytes(encoding))
+    });
+    // The output converter, and whether it starts with a byte order mark.
+    let output = match encoding {
+        None => None,
+        Some(name) => {
+            let label = String::from_utf8_lossy(name);
+            match char_encoding_by_name(&label) {
+                Some(CHAR_ENCODING_UTF8) => None,
+                enc => {
+                    let found = enc
+                        .and_then(converters)
+                        .or_else(|| converters_by_name(&label));
+                    let (_, convert) = found.ok_or_else(|| SaveError::Encoding(name.to_vec()))?;
+                    Some((convert, enc == Some(CHAR_ENCODING_UTF16)))
+                }
+            }
+        }
+    };
+    check_xhtml(doc, options)?;
+    let utf8 = write_doc(doc, options, encoding);
+    let Some((convert, bom)) = output else {
+        return Ok(utf8);
+    };
+    let mut out = Vec::with_capacity(utf8.len());
+    if bom {
+        let mut mark = [0; 2];
+        let res = utf16_bom(&mut mark);
+        out.extend_from_slice(&mark[..res.written]);
+    }
+    encode_output(&utf8, convert, &mut out).map_err(SaveError::InvalidUtf8)?;
+    Ok(out)
+}
@@ -0,0 +1,623 @@
+#![allow(clippy::missing_safety_doc)]
+
+use std::ffi::{CStr, CString};
+use std::os::raw::{c_char, c_int, c_void};
+use std::ptr;
+
+use super::core::is_blank;
+use super::facets::SimpleType;
+use crate::static_bindings::{
+    xmlAutomataCompile, xmlAutomataGetInitState, xmlAutomataNewAllTrans, xmlAutomataNewCountTrans2,
+    xmlAutomataNewCountedTrans, xmlAutomataNewCounter, xmlAutomataNewCounterTrans,
+    xmlAutomataNewEpsilon, xmlAutomataNewOnceTrans2, xmlAutomataNewState,
+    xmlAutomataNewTransition2, xmlAutomataPtr, xmlAutomataSetFinalState, xmlAutomataStatePtr,
+    xmlChar, xmlElementType_XML_CDATA_SECTION_NODE, xmlElementType_XML_ELEMENT_NODE,
+    xmlElementType_XML_TEXT_NODE, xmlFreeAutomata, xmlNewAutomata, xmlNodePtr,
+    xmlParserErrors_XML_SCHEMAP_COS_ALL_LIMITED, xmlParserErrors_XML_SCHEMAP_INTERNAL,
+    xmlParserErrors_XML_SCHEMAP_NOT_DETERMINISTIC, xmlParserErrors_XML_SCHEMAP_P_PROPS_CORRECT_2_1,
+    xmlParserErrors_XML_SCHEMAP_P_PROPS_CORRECT_2_2,
+    xmlParserErrors_XML_SCHEMAP_S4S_ATTR_INVALID_VALUE,
+    xmlParserErrors_XML_SCHEMAP_S4S_ELEM_NOT_ALLOWED,
+    xmlParserErrors_XML_SCHEMAV_CVC_COMPLEX_TYPE_2_1,
+    xmlParserErrors_XML_SCHEMAV_CVC_COMPLEX_TYPE_2_3, xmlParserErrors_XML_SCHEMAV_CVC_ELT_1,
+    xmlParserErrors_XML_SCHEMAV_CVC_TYPE_3_1_2, xmlParserErrors_XML_SCHEMAV_ELEMENT_CONTENT,
+    xmlRegExecCtxtPtr, xmlRegExecPushString2, xmlRegFreeExecCtxt, xmlRegFreeRegexp,
+    xmlRegNewExecCtxt, xmlRegexpIsDeterminist, xmlRegexpPtr,
+};
+
+/// `UNBOUNDED` in xmlschemas.c, the counter limit of `maxOccurs="unbounded"`.
+const UNBOUNDED: c_int = 1 << 30;
+
+/// A model group or an element declaration, the term of a particle.
+pub enum Term {
+    /// The declaration is boxed so the automaton can point at it.
+    Element(Box<ElementDecl>),
+    Sequence(Vec<Particle>),
+    Choice(Vec<Particle>),
+    All(Vec<Particle>),
+}
+
+/// A term with the number of times it may occur.
+pub struct Particle {
+    pub min_occurs: u32,
+    /// `None` for `maxOccurs="unbounded"`.
+    pub max_occurs: Option<u32>,
+    pub term: Term,
+}
+
+impl Particle {
+    /// A particle occurring exactly once, as without `minOccurs` and
+    /// `maxOccurs`.
+    pub fn new(term: Term) -> Self {
+        Particle {
+            min_occurs: 1,
+            max_occurs: Some(1),
+            term,
+        }
+    }
+
+    pub fn element(decl: ElementDecl) -> Self {
+        Particle::new(Term::Element(Box::new(decl)))
+    }
+
+    pub fn sequence(particles: Vec<Particle>) -> Self {
+        Particle::new(Term::Sequence(particles))
+    }
+
+    pub fn choice(particles: Vec<Particle>) -> Self {
+        Particle::new(Term::Choice(particles))
+    }
+
+    pub fn all(particles: Vec<Particle>) -> Self {
+        Particle::new(Term::All(particles))
+    }
+
+    /// The same particle with `minOccurs` and `maxOccurs` set.
+    pub fn occurs(self, min_occurs: u32, max_occurs: Option<u32>) -> Self {
+        Particle {
+            min_occurs,
+            max_occurs,
+            ..self
+        }
+    }
+
+    fn min(&self) -> c_int {
+        self.min_occurs.min(UNBOUNDED as u32) as c_int
+    }
+
+    fn max(&self) -> c_int {
+        self.max_occurs
+            .map_or(UNBOUNDED, |max| max.min(UNBOUNDED as u32) as c_int)
+    }
+}
+
+/// The type of an element: the built-in types restricted by facets, or
+/// a complex type with element-only or empty content.
+pub enum ElementType {
+    Simple(SimpleType),
+    Complex(ComplexType),
+}
+
+/// A local element declaration, matched by name and namespace.
+pub struct ElementDecl {
+    pub name: Vec<u8>,
+    /// `None` for an unqualified element.
+    pub namespace: Option<Vec<u8>>,
+    pub ty: ElementType,
+}
+
+impl ElementDecl {
+    pub fn new(name: &[u8], ty: ElementType) -> Self {
+        ElementDecl {
+            name: name.to_vec(),
+            namespace: None,
+            ty,
+        }
+    }
+
+    /// Validate the element `node` and its content against this
+    /// declaration, returning the `xmlParserErrors` codes
+    /// `xmlSchemaValidateDoc` reports, in its order.
+    ///
+    /// An element with another name fails only
+    /// `XML_SCHEMAV_CVC_ELT_1`.
+    pub unsafe fn validate(&self, node: xmlNodePtr) -> Result<(), Vec<c_int>> {
+        let mut errors = Vec::new();
+        if self.matches(node) {
+            self.validate_content(node, &mut errors);
+        } else {
+            errors.push(xmlParserErrors_XML_SCHEMAV_CVC_ELT_1 as c_int);
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    unsafe fn matches(&self, node: xmlNodePtr) -> bool {
+        let ns = if (*node).ns.is_null() {
+            None
+        } else {
+            Some(bytes((*(*node).ns).href))
+        };
+        bytes((*node).name) == self.name.as_slice() && ns == self.namespace.as_deref()
+    }
+
+    unsafe fn validate_content(&self, node: xmlNodePtr, errors: &mut Vec<c_int>) {
+        match &self.ty {
+            ElementType::Simple(ty) => {
+                // The value is the text up to the first child element;
+                // the rest of the content is skipped.
+                let mut value = Vec::new();
+                let mut child = (*node).children;
+                while !child.is_null() {
+                    match (*child).type_ {
+                        xmlElementType_XML_TEXT_NODE | xmlElementType_XML_CDATA_SECTION_NODE => {
+                            value.extend_from_slice(bytes((*child).content));
+                        }
+                        xmlElementType_XML_ELEMENT_NODE => {
+                            errors.push(xmlParserErrors_XML_SCHEMAV_CVC_TYPE_3_1_2 as c_int);
+                            break;
+                        }
+                        _ => {}
+                    }
+                    child = (*child).next;
+                }
+                if let Err(codes) = ty.validate(&value) {
+                    errors.extend(codes);
+                }
+            }
+            ElementType::Complex(ty) => ty.validate_children(node, errors),
+        }
+    }
+}
+
+/// Why a `ComplexType` could not be built from its content model.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContentError {
+    /// `minOccurs` above `maxOccurs`.
+    MinAboveMax,
+    /// `maxOccurs` of 0.
+    MaxZero,
+    /// An `<all>` occurring other than once or optionally.
+    AllOccurs,
+    /// An `<all>` holding a model group, or inside one.
+    AllNesting,
+    /// An element of an `<all>` occurring more than once.
+    AllLimited,
+    /// A content model `xmlRegexpIsDeterminist` rejects.
+    NotDeterministic,
+    /// A content model `xmlAutomataCompile` failed on.
+    Internal,
+}
+
+impl ContentError {
+    /// The `xmlParserErrors` code the C schema parser reports.
+    pub fn code(self) -> c_int {
+        (match self {
+            ContentError::MinAboveMax => xmlParserErrors_XML_SCHEMAP_P_PROPS_CORRECT_2_1,
+            ContentError::MaxZero => xmlParserErrors_XML_SCHEMAP_P_PROPS_CORRECT_2_2,
+            ContentError::AllOccurs => xmlParserErrors_XML_SCHEMAP_S4S_ATTR_INVALID_VALUE,
+            ContentError::AllNesting => xmlParserErrors_XML_SCHEMAP_S4S_ELEM_NOT_ALLOWED,
+            ContentError::AllLimited => xmlParserErrors_XML_SCHEMAP_COS_ALL_LIMITED,
+            ContentError::NotDeterministic => xmlParserErrors_XML_SCHEMAP_NOT_DETERMINISTIC,
+            ContentError::Internal => xmlParserErrors_XML_SCHEMAP_INTERNAL,
+        }) as c_int
+    }
+}
+
+/// A complex type without attributes or mixed content: its content is
+/// empty or element-only, matched by a compiled `xmlregexp` automaton.
+pub struct ComplexType {
+    /// The automaton's transitions point at the element declarations
+    /// this particle owns.
+    particle: Particle,
+    /// Null for empty content.
+    model: xmlRegexpPtr,
+}
+
+impl ComplexType {
+    /// A type whose content is empty, as `<xs:complexType/>`.
+    pub fn empty() -> Self {
+        ComplexType {
+            particle: Particle::sequence(Vec::new()),
+            model: ptr::null_mut(),
+        }
+    }
+
+    /// A type whose content is `particle`, compiled to an automaton
+    /// like `xmlSchemaBuildContentModel`.
+    ///
+    /// As in C, a top-level `<sequence>` or `<all>` without particles,
+    /// or such a `<choice>` with `minOccurs` 0, makes the content empty.
+    /// A particle with `maxOccurs` 0 is rejected.
+    pub fn new(particle: Particle) -> Result<Self, ContentError> {
+        check_particle(&particle, false)?;
+        let empty = match &particle.term {
+            Term::Sequence(particles) | Term::All(particles) => particles.is_empty(),
+            Term::Choice(particles) => particles.is_empty() && particle.min_occurs == 0,
+            Term::Element(_) => false,
+        };
+        if empty {
+            return Ok(ComplexType {
+                particle,
+                model: ptr::null_mut(),
+            });
+        }
+        let model = unsafe {
+            let am = xmlNewAutomata();
+            let mut builder = Builder {
+                am,
+                state: xmlAutomataGetInitState(am),
+            };
+            builder.particle(&particle);
+            xmlAutomataSetFinalState(am, builder.state);
+            let model = xmlAutomataCompile(am);
+            xmlFreeAutomata(am);
+            model
+        };
+        if model.is_null() {
+            return Err(ContentError::Internal);
+        }
+        let ty = ComplexType { particle, model };
+        if unsafe { xmlRegexpIsDeterminist(ty.model) } != 1 {
+            return Err(ContentError::NotDeterministic);
+        }
+        Ok(ty)
+    }
+
+    pub fn particle(&self) -> &Particle {
+        &self.particle
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.model.is_null()
+    }
+
+    /// Check the children of `node` as `xmlSchemaVDocWalk` does.
+    ///
+    /// In empty content, text and elements are errors. In element-only
+    /// content, each text node that is not blank is an error; children
+    /// are pushed through the automaton and validated against the
+    /// declaration they matched. An unexpected child ends the check of
+    /// the content; otherwise the automaton must accept at the end.
+    unsafe fn validate_children(&self, node: xmlNodePtr, errors: &mut Vec<c_int>) {
+        if self.model.is_null() {
+            let mut child = (*node).children;
+            while !child.is_null() {
+                match (*child).type_ {
+                    xmlElementType_XML_TEXT_NODE | xmlElementType_XML_CDATA_SECTION_NODE => {
+                        errors.push(xmlParserErrors_XML_SCHEMAV_CVC_COMPLEX_TYPE_2_1 as c_int);
+                    }
+                    xmlElementType_XML_ELEMENT_NODE => {
+                        errors.push(xmlParserErrors_XML_SCHEMAV_CVC_COMPLEX_TYPE_2_1 as c_int);
+                        return;
+                    }
+                    _ => {}
+                }
+                child = (*child).next;
+            }
+            return;
+        }
+
+        let exec = xmlRegNewExecCtxt(self.model, Some(record_decl), ptr::null_mut());
+        let mut child = (*node).children;
+        let mut complete = true;
+        while !child.is_null() {
+            match (*child).type_ {
+                xmlElementType_XML_TEXT_NODE
+                    if bytes((*child).content).iter().all(|&c| is_blank(c)) => {}
+                xmlElementType_XML_TEXT_NODE | xmlElementType_XML_CDATA_SECTION_NODE => {
+                    errors.push(xmlParserErrors_XML_SCHEMAV_CVC_COMPLEX_TYPE_2_3 as c_int);
+                }
+                xmlElementType_XML_ELEMENT_NODE => {
+                    let ns = if (*child).ns.is_null() {
+                        ptr::null()
+                    } else {
+                        (*(*child).ns).href
+                    };
+                    let mut decl: *const ElementDecl = ptr::null();
+                    let ret = xmlRegExecPushString2(
+                        exec,
+                        (*child).name,
+                        ns,
+                        &mut decl as *mut _ as *mut c_void,
+                    );
+                    if ret < 0 {
+                        errors.push(xmlParserErrors_XML_SCHEMAV_ELEMENT_CONTENT as c_int);
+                        complete = false;
+                        break;
+                    }
+                    if !decl.is_null() {
+                        (*decl).validate_content(child, errors);
+                    }
+                }
+                _ => {}
+            }
+            child = (*child).next;
+        }
+        if complete && xmlRegExecPushString2(exec, ptr::null(), ptr::null(), ptr::null_mut()) != 1 {
+            errors.push(xmlParserErrors_XML_SCHEMAV_ELEMENT_CONTENT as c_int);
+        }
+        xmlRegFreeExecCtxt(exec);
+    }
+}
+
+impl Drop for ComplexType {
+    fn drop(&mut self) {
+        if !self.model.is_null() {
+            unsafe { xmlRegFreeRegexp(self.model) };
+        }
+    }
+}
+
+/// Record the declaration of the transition a child took, like
+/// `xmlSchemaVContentModelCallback`.
+unsafe extern "C" fn record_decl(
+    _exec: xmlRegExecCtxtPtr,
+    _name: *const xmlChar,
+    transdata: *mut c_void,
+    inputdata: *mut c_void,
+) {
+    if !inputdata.is_null() {
+        *(inputdata as *mut *const ElementDecl) = transdata as *const ElementDecl;
+    }
+}
+
+unsafe fn bytes<'a>(p: *const xmlChar) -> &'a [u8] {
+    if p.is_null() {
+        &[]
+    } else {
+        CStr::from_ptr(p as *const c_char).to_bytes()
+    }
+}
+
+/// Check the occurrences of `particle` and the terms it holds, as the
+/// schema parser does; `in_group` is set below a model group.
+fn check_particle(particle: &Particle, in_group: bool) -> Result<(), ContentError> {
+    if particle.max_occurs == Some(0) {
+        return Err(ContentError::MaxZero);
+    }
+    if particle
+        .max_occurs
+        .is_some_and(|max| particle.min_occurs > max)
+    {
+        return Err(ContentError::MinAboveMax);
+    }
+    match &particle.term {
+        Term::Element(_) => Ok(()),
+        Term::Sequence(particles) | Term::Choice(particles) => {
+            particles.iter().try_for_each(|p| check_particle(p, true))
+        }
+        Term::All(particles) => {
+            if in_group {
+                return Err(ContentError::AllNesting);
+            }
+            if particle.min_occurs > 1 || particle.max_occurs != Some(1) {
+                return Err(ContentError::AllOccurs);
+            }
+            for p in particles {
+                check_particle(p, true)?;
+                if !matches!(p.term, Term::Element(_)) {
+                    return Err(ContentError::AllNesting);
+                }
+                if p.min_occurs > 1 || p.max_occurs != Some(1) {
+                    return Err(ContentError::AllLimited);
+                }
+            }
+            Ok(())
+        }
+    }
+}
+
+/// The automaton under construction and its current state, as
+/// `xmlSchemaParserCtxt` holds them.
+struct Builder {
+    am: xmlAutomataPtr,
+    state: xmlAutomataStatePtr,
+}
+
+impl Builder {
+    /// Add the transitions of `particle` from the current state, like
+    /// `xmlSchemaBuildAContentModel`. Returns whether the particle may
+    /// match nothing.
+    unsafe fn particle(&mut self, particle: &Particle) -> bool {
+        let am = self.am;
+        let (min, max) = (particle.min(), particle.max());
+        match &particle.term {
+            Term::Element(decl) => self.element(particle, decl),
+            Term::Sequence(particles) => {
+                if min == 1 && max == 1 {
+                    return self.items(particles);
+                }
+                let mut ret;
+                let mut oldstate = self.state;
+                if max >= UNBOUNDED {
+                    if min > 1 {
+                        self.state = xmlAutomataNewEpsilon(am, oldstate, ptr::null_mut());
+                        oldstate = self.state;
+                        let counter = xmlAutomataNewCounter(am, min - 1, UNBOUNDED);
+                        ret = self.items(particles);
+                        let tmp = self.state;
+                        xmlAutomataNewCountedTrans(am, tmp, oldstate, counter);
+                        self.state = xmlAutomataNewCounterTrans(am, tmp, ptr::null_mut(), counter);
+                        if ret {
+                            xmlAutomataNewEpsilon(am, oldstate, self.state);
+                        }
+                    } else {
+                        self.state = xmlAutomataNewEpsilon(am, oldstate, ptr::null_mut());
+                        oldstate = self.state;
+                        ret = self.items(particles);
+                        xmlAutomataNewEpsilon(am, self.state, oldstate);
+                        // Block the way back in from another construct.
+                        self.state = xmlAutomataNewEpsilon(am, self.state, ptr::null_mut());
+                        if min == 0 {
+                            xmlAutomataNewEpsilon(am, oldstate, self.state);
+                            ret = true;
+                        }
+                    }
+                } else if max > 1 || min > 1 {
+                    self.state = xmlAutomataNewEpsilon(am, oldstate, ptr::null_mut());
+                    oldstate = self.state;
+                    let counter = xmlAutomataNewCounter(am, min - 1, max - 1);
+                    ret = self.items(particles);
+                    let tmp = self.state;
+                    xmlAutomataNewCountedTrans(am, tmp, oldstate, counter);
+                    self.state = xmlAutomataNewCounterTrans(am, tmp, ptr::null_mut(), counter);
+                    if min == 0 || ret {
+                        xmlAutomataNewEpsilon(am, oldstate, self.state);
+                        ret = true;
+                    }
+                } else {
+                    ret = self.items(particles);
+                    self.state = xmlAutomataNewEpsilon(am, self.state, ptr::null_mut());
+                    if min == 0 {
+                        xmlAutomataNewEpsilon(am, oldstate, self.state);
+                        ret = true;
+                    }
+                }
+                ret
+            }
+            Term::Choice(particles) => {
+                let mut ret = false;
+                let start = self.state;
+                let end = xmlAutomataNewState(am);
+                if max == 1 {
+                    for p in particles {
+                        self.state = start;
+                        if self.particle(p) {
+                            ret = true;
+                        }
+                        xmlAutomataNewEpsilon(am, self.state, end);
+                    }
+                } else {
+                    let counter = xmlAutomataNewCounter(
+                        am,
+                        (min - 1).max(0),
+                        if max == UNBOUNDED { UNBOUNDED } else { max - 1 },
+                    );
+                    let hop = xmlAutomataNewState(am);
+                    let base = xmlAutomataNewState(am);
+                    for p in particles {
+                        self.state = base;
+                        if self.particle(p) {
+                            ret = true;
+                        }
+                        xmlAutomataNewEpsilon(am, self.state, hop);
+                    }
+                    xmlAutomataNewEpsilon(am, start, base);
+                    xmlAutomataNewCountedTrans(am, hop, base, counter);
+                    xmlAutomataNewCounterTrans(am, hop, end, counter);
+                    if ret {
+                        xmlAutomataNewEpsilon(am, base, end);
+                    }
+                }
+                if min == 0 {
+                    xmlAutomataNewEpsilon(am, start, end);
+                    ret = true;
+                }
+                self.state = end;
+                ret
+            }
+            Term::All(particles) => {
+                if particles.is_empty() {
+                    return true;
+                }
+                let start = self.state;
+                let tmp = xmlAutomataNewState(am);
+                xmlAutomataNewEpsilon(am, self.state, tmp);
+                for p in particles {
+                    let Term::Element(decl) = &p.term else {
+                        continue;
+                    };
+                    let name = CString::new(decl.name.as_slice()).unwrap_or_default();
+                    let ns = decl
+                        .namespace
+                        .as_ref()
+                        .map(|ns| CString::new(ns.as_slice()).unwrap_or_default());
+                    let ns = ns.as_ref().map_or(ptr::null(), |ns| ns.as_ptr());
+                    let data = &**decl as *const ElementDecl as *mut c_void;
+                    if p.min_occurs == 1 {
+                        xmlAutomataNewOnceTrans2(
+                            am,
+                            tmp,
+                            tmp,
+                            name.as_ptr() as *const xmlChar,
+                            ns as *const xmlChar,
+                            1,
+                            1,
+                            data,
+                        );
+                    } else {
+                        xmlAutomataNewCountTrans2(
+                            am,
+                            tmp,
+                            tmp,
+                            name.as_ptr() as *const xmlChar,
+                            ns as *const xmlChar,
+                            0,
+                            1,
+                            data,
+                        );
+                    }
+                }
+                self.state = xmlAutomataNewAllTrans(am, tmp, ptr::null_mut(), 0);
+                if min == 0 {
+                    xmlAutomataNewEpsilon(am, start, self.state);
+                    return true;
+                }
+                false
+            }
+        }
+    }
+
+    /// Add the particles of a sequence one after the other. Returns
+    /// whether all of them may match nothing.
+    unsafe fn items(&mut self, particles: &[Particle]) -> bool {
+        let mut ret = true;
+        for p in particles {
+            if !self.particle(p) {
+                ret = false;
+            }
+        }
+        ret
+    }
+
+    /// Add the transitions of an element particle, like
+    /// `xmlSchemaBuildContentModelForElement`.
+    unsafe fn element(&mut self, particle: &Particle, decl: &ElementDecl) -> bool {
+        let am = self.am;
+        let (min, max) = (particle.min(), particle.max());
+        let name = CString::new(decl.name.as_slice()).unwrap_or_default();
+        let ns = decl
+            .namespace
+            .as_ref()
+            .map(|ns| CString::new(ns.as_slice()).unwrap_or_default());
+        let name = name.as_ptr() as *const xmlChar;
+        let ns = ns.as_ref().map_or(ptr::null(), |ns| ns.as_ptr()) as *const xmlChar;
+        let data = decl as *const ElementDecl as *mut c_void;
+
+        let start;
+        if max == 1 {
+            start = self.state;
+            self.state = xmlAutomataNewTransition2(am, start, ptr::null_mut(), name, ns, data);
+        } else if max >= UNBOUNDED && min < 2 {
+            start = self.state;
+            self.state = xmlAutomataNewTransition2(am, start, ptr::null_mut(), name, ns, data);
+            self.state = xmlAutomataNewTransition2(am, self.state, self.state, name, ns, data);
+        } else {
+            let max = if max == UNBOUNDED { UNBOUNDED } else { max - 1 };
+            start = xmlAutomataNewEpsilon(am, self.state, ptr::null_mut());
+            let counter = xmlAutomataNewCounter(am, (min - 1).max(0), max);
+            self.state = xmlAutomataNewTransition2(am, start, ptr::null_mut(), name, ns, data);
+            xmlAutomataNewCountedTrans(am, self.state, start, counter);
+            self.state = xmlAutomataNewCounterTrans(am, self.state, ptr::null_mut(), counter);
+        }
+        if min == 0 {
+            xmlAutomataNewEpsilon(am, start, self.state);
+            return true;
+        }
+        false
+    }
+}
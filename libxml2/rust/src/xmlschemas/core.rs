@@ -0,0 +1,430 @@
+use std::cmp::Ordering;
+
+/// The built-in simple types `SimpleType` restricts so far: the string
+/// types up to `token`, `decimal` and the integer types, and `boolean`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BuiltinType {
+    String,
+    NormalizedString,
+    Token,
+    Decimal,
+    Integer,
+    NonPositiveInteger,
+    NegativeInteger,
+    Long,
+    Int,
+    Short,
+    Byte,
+    NonNegativeInteger,
+    UnsignedLong,
+    UnsignedInt,
+    UnsignedShort,
+    UnsignedByte,
+    PositiveInteger,
+    Boolean,
+}
+
+/// The `whiteSpace` facet values, weakest first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum WhiteSpace {
+    Preserve,
+    Replace,
+    Collapse,
+}
+
+impl WhiteSpace {
+    /// The facet value `value`, if it is one.
+    pub fn from_name(value: &[u8]) -> Option<Self> {
+        match value {
+            b"preserve" => Some(WhiteSpace::Preserve),
+            b"replace" => Some(WhiteSpace::Replace),
+            b"collapse" => Some(WhiteSpace::Collapse),
+            _ => None,
+        }
+    }
+}
+
+/// A parsed value of a `BuiltinType`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Value {
+    /// A value of the string types, as validated.
+    String(Vec<u8>),
+    /// A value of `decimal` or one of the integer types.
+    Decimal(Decimal),
+    Boolean(bool),
+}
+
+/// A decimal number as libxml2 stores it: a sign and the digits before
+/// and after the point, without leading or trailing zeros but never
+/// empty.
+///
+/// Zero may carry either sign; both compare equal.
+#[derive(Debug, Clone)]
+pub struct Decimal {
+    negative: bool,
+    integral: Vec<u8>,
+    fraction: Vec<u8>,
+}
+
+/// The characters `xmlSchemaCollapseString` and the parsers skip.
+pub(super) fn is_blank(c: u8) -> bool {
+    matches!(c, b' ' | b'\t' | b'\n' | b'\r')
+}
+
+/// `value` without the blanks at either end.
+pub(super) fn trim(value: &[u8]) -> &[u8] {
+    let start = value
+        .iter()
+        .position(|&c| !is_blank(c))
+        .unwrap_or(value.len());
+    let end = value
+        .iter()
+        .rposition(|&c| !is_blank(c))
+        .map_or(start, |i| i + 1);
+    &value[start..end]
+}
+
+/// Apply the whitespace normalization `ws` to `value`, like
+/// `xmlSchemaWhiteSpaceReplace` and `xmlSchemaCollapseString`.
+pub fn normalize(value: &[u8], ws: WhiteSpace) -> Vec<u8> {
+    match ws {
+        WhiteSpace::Preserve => value.to_vec(),
+        WhiteSpace::Replace => value
+            .iter()
+            .map(|&c| {
+                if matches!(c, b'\t' | b'\n' | b'\r') {
+                    b' '
+                } else {
+                    c
+                }
+            })
+            .collect(),
+        WhiteSpace::Collapse => {
+            let mut out = Vec::with_capacity(value.len());
+            for word in value.split(|&c| is_blank(c)).filter(|w| !w.is_empty()) {
+                if !out.is_empty() {
+                    out.push(b' ');
+                }
+                out.extend_from_slice(word);
+            }
+            out
+        }
+    }
+}
+
+/// The number of characters in `value`, like `xmlUTF8Strlen`.
+pub fn char_count(value: &[u8]) -> usize {
+    value.iter().filter(|&&c| c & 0xC0 != 0x80).count()
+}
+
+/// The number of characters `value` has once collapsed, like
+/// `xmlSchemaNormLen`.
+pub fn collapsed_len(value: &[u8]) -> usize {
+    char_count(&normalize(value, WhiteSpace::Collapse))
+}
+
+impl Decimal {
+    fn new(negative: bool, integral: &[u8], fraction: &[u8]) -> Self {
+        let integral = match integral.iter().position(|&c| c != b'0') {
+            Some(i) => integral[i..].to_vec(),
+            None => b"0".to_vec(),
+        };
+        let fraction = match fraction.iter().rposition(|&c| c != b'0') {
+            Some(i) => fraction[..=i].to_vec(),
+            None => b"0".to_vec(),
+        };
+        Decimal {
+            negative,
+            integral,
+            fraction,
+        }
+    }
+
+    fn from_i64(value: i64) -> Self {
+        Decimal::new(value < 0, value.unsigned_abs().to_string().as_bytes(), b"")
+    }
+
+    /// Parse an `xs:decimal`: a sign, digits and a point, with at least
+    /// one digit. Blanks around it are skipped.
+    pub fn parse(value: &[u8]) -> Option<Self> {
+        let (negative, digits) = split_sign(trim(value));
+        let (integral, fraction) = match digits.iter().position(|&c| c == b'.') {
+            Some(dot) => (&digits[..dot], &digits[dot + 1..]),
+            None => (digits, &b""[..]),
+        };
+        let all_digits = |s: &[u8]| s.iter().all(u8::is_ascii_digit);
+        if integral.len() + fraction.len() == 0 || !all_digits(integral) || !all_digits(fraction) {
+            return None;
+        }
+        Some(Decimal::new(negative, integral, fraction))
+    }
+
+    /// Parse an `xs:integer`: a sign and at least one digit.
+    pub fn parse_integer(value: &[u8]) -> Option<Self> {
+        let (negative, digits) = split_sign(trim(value));
+        if digits.is_empty() || !digits.iter().all(u8::is_ascii_digit) {
+            return None;
+        }
+        Some(Decimal::new(negative, digits, b""))
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.integral == b"0" && self.fraction == b"0"
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.fraction == b"0"
+    }
+
+    /// The digits `totalDigits` counts: all of them but a lone zero
+    /// before the point, and at least one.
+    pub fn total_digits(&self) -> u64 {
+        let integral = if self.integral == b"0" {
+            0
+        } else {
+            self.integral.len()
+        };
+        let fraction = if self.is_integer() {
+            0
+        } else {
+            self.fraction.len()
+        };
+        (integral + fraction).max(1) as u64
+    }
+
+    /// The digits after the point `fractionDigits` counts.
+    pub fn fraction_digits(&self) -> u64 {
+        if self.is_integer() {
+            0
+        } else {
+            self.fraction.len() as u64
+        }
+    }
+
+    /// The integer part, like `xmlSchemaGetFacetValueAsULong`: without
+    /// sign, saturating.
+    pub fn as_u64(&self) -> u64 {
+        std::str::from_utf8(&self.integral)
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(u64::MAX)
+    }
+}
+
+fn split_sign(value: &[u8]) -> (bool, &[u8]) {
+    match value.first() {
+        Some(b'-') => (true, &value[1..]),
+        Some(b'+') => (false, &value[1..]),
+        _ => (false, value),
+    }
+}
+
+impl Ord for Decimal {
+    /// Numeric order, as `xmlSchemaValDecimalCompare` computes it.
+    fn cmp(&self, other: &Self) -> Ordering {
+        if self.is_zero() && other.is_zero() {
+            return Ordering::Equal;
+        }
+        if self.negative != other.negative {
+            return if self.negative {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            };
+        }
+        let ord = self
+            .integral
+            .len()
+            .cmp(&other.integral.len())
+            .then_with(|| self.integral.cmp(&other.integral))
+            .then_with(|| self.fraction.cmp(&other.fraction));
+        if self.negative {
+            ord.reverse()
+        } else {
+            ord
+        }
+    }
+}
+
+impl PartialEq for Decimal {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Decimal {}
+
+impl PartialOrd for Decimal {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl BuiltinType {
+    /// The type named `name` in the XML Schema namespace.
+    pub fn from_name(name: &[u8]) -> Option<Self> {
+        use BuiltinType::*;
+        Some(match name {
+            b"string" => String,
+            b"normalizedString" => NormalizedString,
+            b"token" => Token,
+            b"decimal" => Decimal,
+            b"integer" => Integer,
+            b"nonPositiveInteger" => NonPositiveInteger,
+            b"negativeInteger" => NegativeInteger,
+            b"long" => Long,
+            b"int" => Int,
+            b"short" => Short,
+            b"byte" => Byte,
+            b"nonNegativeInteger" => NonNegativeInteger,
+            b"unsignedLong" => UnsignedLong,
+            b"unsignedInt" => UnsignedInt,
+            b"unsignedShort" => UnsignedShort,
+            b"unsignedByte" => UnsignedByte,
+            b"positiveInteger" => PositiveInteger,
+            b"boolean" => Boolean,
+            _ => return None,
+        })
+    }
+
+    /// Whether the type derives from `xs:string`.
+    pub fn is_string(self) -> bool {
+        matches!(
+            self,
+            BuiltinType::String | BuiltinType::NormalizedString | BuiltinType::Token
+        )
+    }
+
+    /// Whether the type derives from `xs:decimal`.
+    pub fn is_decimal(self) -> bool {
+        !self.is_string() && self != BuiltinType::Boolean
+    }
+
+    /// The normalization the type applies by itself.
+    pub fn whitespace(self) -> WhiteSpace {
+        match self {
+            BuiltinType::String => WhiteSpace::Preserve,
+            BuiltinType::NormalizedString => WhiteSpace::Replace,
+            _ => WhiteSpace::Collapse,
+        }
+    }
+
+    /// The bounds of the integer types, inclusive.
+    fn bounds(self) -> (Option<Decimal>, Option<Decimal>) {
+        use BuiltinType::*;
+        let int = |v: i64| Some(self::Decimal::from_i64(v));
+        match self {
+            NonPositiveInteger => (None, int(0)),
+            NegativeInteger => (None, int(-1)),
+            Long => (int(i64::MIN), int(i64::MAX)),
+            Int => (int(i32::MIN.into()), int(i32::MAX.into())),
+            Short => (int(i16::MIN.into()), int(i16::MAX.into())),
+            Byte => (int(i8::MIN.into()), int(i8::MAX.into())),
+            NonNegativeInteger => (int(0), None),
+            UnsignedLong => (
+                int(0),
+                Some(self::Decimal::new(false, b"18446744073709551615", b"")),
+            ),
+            UnsignedInt => (int(0), int(u32::MAX.into())),
+            UnsignedShort => (int(0), int(u16::MAX.into())),
+            UnsignedByte => (int(0), int(u8::MAX.into())),
+            PositiveInteger => (int(1), None),
+            _ => (None, None),
+        }
+    }
+
+    /// Parse `value` in the lexical space of the type, as
+    /// `xmlSchemaValPredefTypeNodeNoNorm` does: blanks around numbers
+    /// and booleans are skipped, and strings are taken as they are.
+    pub fn parse(self, value: &[u8]) -> Option<Value> {
+        match self {
+            BuiltinType::String | BuiltinType::NormalizedString | BuiltinType::Token => {
+                Some(Value::String(value.to_vec()))
+            }
+            BuiltinType::Boolean => match trim(value) {
+                b"true" | b"1" => Some(Value::Boolean(true)),
+                b"false" | b"0" => Some(Value::Boolean(false)),
+                _ => None,
+            },
+            BuiltinType::Decimal => Decimal::parse(value).map(Value::Decimal),
+            _ => {
+                let decimal = Decimal::parse_integer(value)?;
+                // A positive integer may not have a minus sign, even on 0.
+                if self == BuiltinType::PositiveInteger && decimal.negative {
+                    return None;
+                }
+                let (min, max) = self.bounds();
+                if min.is_some_and(|min| decimal < min) || max.is_some_and(|max| decimal > max) {
+                    return None;
+                }
+                Some(Value::Decimal(decimal))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dec(s: &str) -> Decimal {
+        Decimal::parse(s.as_bytes()).unwrap()
+    }
+
+    #[test]
+    fn test_normalize() {
+        let s = b" a\t\tb \r\n c ";
+        assert_eq!(normalize(s, WhiteSpace::Preserve), s);
+        assert_eq!(normalize(s, WhiteSpace::Replace), b" a  b    c ");
+        assert_eq!(normalize(s, WhiteSpace::Collapse), b"a b c");
+        assert_eq!(normalize(b" \t ", WhiteSpace::Collapse), b"");
+        assert_eq!(collapsed_len(" é  b ".as_bytes()), 3);
+    }
+
+    #[test]
+    fn test_parse_decimal() {
+        for bad in ["", " ", ".", "-", "+.", "1.2.3", "1 2", "1e3", "--1", "0x1"] {
+            assert_eq!(Decimal::parse(bad.as_bytes()), None, "{bad:?}");
+        }
+        assert_eq!(dec(" 007.500 "), dec("7.5"));
+        assert_eq!(dec("0."), dec(".0"));
+        assert_eq!(dec("-0"), dec("+0.000"));
+        assert_eq!(dec("0").total_digits(), 1);
+        assert_eq!(dec("0.0120").total_digits(), 3);
+        assert_eq!(dec("120.0").total_digits(), 3);
+        assert_eq!(dec("1.230").fraction_digits(), 2);
+        assert_eq!(dec("99999999999999999999999").as_u64(), u64::MAX);
+    }
+
+    #[test]
+    fn test_decimal_order() {
+        let sorted = [
+            "-100", "-9.5", "-9.25", "-0.5", "0", "0.05", "0.5", "1", "9.99", "10",
+        ];
+        for (i, a) in sorted.iter().enumerate() {
+            for (j, b) in sorted.iter().enumerate() {
+                assert_eq!(dec(a).cmp(&dec(b)), i.cmp(&j), "{a} {b}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_integers() {
+        use BuiltinType::*;
+        assert!(Byte.parse(b"127").is_some());
+        assert!(Byte.parse(b"128").is_none());
+        assert!(Byte.parse(b"-128").is_some());
+        assert!(Int.parse(b"1.0").is_none());
+        assert!(UnsignedLong.parse(b"18446744073709551615").is_some());
+        assert!(UnsignedLong.parse(b"18446744073709551616").is_none());
+        assert!(UnsignedInt.parse(b"-0").is_some());
+        assert!(PositiveInteger.parse(b"-0").is_none());
+        assert!(PositiveInteger.parse(b"0").is_none());
+        assert!(NegativeInteger.parse(b"-0").is_none());
+        assert!(NonPositiveInteger.parse(b"+0").is_some());
+        assert!(Long.parse(b" -9223372036854775808 ").is_some());
+        assert!(Long.parse(b"-9223372036854775809").is_none());
+        assert_eq!(Boolean.parse(b" 1\n"), Some(Value::Boolean(true)));
+        assert_eq!(Boolean.parse(b"True"), None);
+    }
+}
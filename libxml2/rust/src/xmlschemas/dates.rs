@@ -0,0 +1,963 @@
+use std::cmp::Ordering;
+
+use super::core::trim;
+
+/// The date and time types of XML Schema.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateType {
+    DateTime,
+    Time,
+    Date,
+    GYearMonth,
+    GYear,
+    GMonthDay,
+    GDay,
+    GMonth,
+}
+
+/// A value of a `DateType`, with the fields of `xmlSchemaValDate`.
+///
+/// Fields the type does not have are 0, but for the day of a `gMonth`
+/// written like a `gMonthDay` with a timezone: `--12-05:00` is December
+/// at -05:00 with day 5, as C leaves it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DateValue {
+    pub kind: DateType,
+    pub year: i64,
+    pub month: u32,
+    pub day: u32,
+    pub hour: u32,
+    pub minute: u32,
+    pub second: f64,
+    /// The offset from UTC in minutes, if a timezone was given.
+    pub timezone: Option<i32>,
+}
+
+/// An `xs:duration` as `xmlSchemaValDuration` holds it: years count as
+/// twelve months, and the hours, minutes and seconds of whole days as
+/// days. All three fields have the sign of the duration.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Duration {
+    pub months: i64,
+    pub days: i64,
+    pub seconds: f64,
+}
+
+const SECS_PER_MIN: i64 = 60;
+const SECS_PER_HOUR: i64 = 60 * SECS_PER_MIN;
+const SECS_PER_DAY: i64 = 24 * SECS_PER_HOUR;
+const MINS_PER_DAY: i64 = 24 * 60;
+
+/// The widest timezone offset, in minutes.
+const MAX_TZO: i32 = 14 * 60;
+
+const DAYS_IN_MONTH: [u32; 12] = [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
+const DAYS_IN_MONTH_LEAP: [u32; 12] = [31, 29, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
+const DAY_IN_YEAR_BY_MONTH: [i64; 12] = [0, 31, 59, 90, 120, 151, 181, 212, 243, 273, 304, 334];
+const DAY_IN_LEAP_YEAR_BY_MONTH: [i64; 12] =
+    [0, 31, 60, 91, 121, 152, 182, 213, 244, 274, 305, 335];
+
+/// Year 0 counts as leap, as in C, though it is not a valid year.
+fn is_leap(year: i64) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+/// MAX_DAYINMONTH, for a month from 1 to 12.
+fn days_in_month(year: i64, month: u32) -> u32 {
+    if is_leap(year) {
+        DAYS_IN_MONTH_LEAP[month as usize - 1]
+    } else {
+        DAYS_IN_MONTH[month as usize - 1]
+    }
+}
+
+fn valid_month(month: u32) -> bool {
+    (1..=12).contains(&month)
+}
+
+/// The FQUOTIENT and MODULO macros, which compute in doubles.
+fn fquotient(a: f64, b: f64) -> f64 {
+    (a / b).floor()
+}
+
+fn modulo(a: f64, b: f64) -> f64 {
+    a - fquotient(a, b) * b
+}
+
+fn fquotient_range(a: f64, low: f64, high: f64) -> f64 {
+    fquotient(a - low, high - low)
+}
+
+fn modulo_range(a: f64, low: f64, high: f64) -> f64 {
+    modulo(a - low, high - low) + low
+}
+
+/// `printf("%.14g")`, zero-padded to `width`.
+fn format_g14(value: f64, width: usize) -> String {
+    fn trim_zeros(digits: &str) -> &str {
+        if digits.contains('.') {
+            digits.trim_end_matches('0').trim_end_matches('.')
+        } else {
+            digits
+        }
+    }
+    let s = if value == 0.0 {
+        "0".to_string()
+    } else {
+        let sci = format!("{value:.13e}");
+        let (mantissa, exp) = sci.split_once('e').unwrap();
+        let exp: i32 = exp.parse().unwrap();
+        if !(-4..14).contains(&exp) {
+            let sign = if exp < 0 { '-' } else { '+' };
+            format!("{}e{sign}{:02}", trim_zeros(mantissa), exp.abs())
+        } else {
+            trim_zeros(&format!("{value:.*}", (13 - exp) as usize)).to_string()
+        }
+    };
+    format!("{s:0>width$}")
+}
+
+/// A NUL-terminated view of a lexical value, as the C parsers walk it.
+struct Cursor<'a> {
+    s: &'a [u8],
+    pos: usize,
+}
+
+/// How the value goes on after a field, in RETURN_TYPE_IF_VALID.
+enum End {
+    /// A timezone, or none, ends the value.
+    Zone(Option<i32>),
+    /// A timezone is followed by more.
+    Trailing,
+    /// Something other than a timezone follows.
+    More,
+}
+
+impl Cursor<'_> {
+    fn at(&self, offset: usize) -> u8 {
+        self.s.get(self.pos + offset).copied().unwrap_or(0)
+    }
+
+    fn peek(&self) -> u8 {
+        self.at(0)
+    }
+
+    /// PARSE_2_DIGITS.
+    fn two_digits(&mut self) -> Option<u32> {
+        let (a, b) = (self.at(0), self.at(1));
+        if !a.is_ascii_digit() || !b.is_ascii_digit() {
+            return None;
+        }
+        self.pos += 2;
+        Some(u32::from(a - b'0') * 10 + u32::from(b - b'0'))
+    }
+
+    /// PARSE_FLOAT: two digits and an optional, non-empty fraction.
+    fn seconds(&mut self) -> Option<f64> {
+        let mut num = f64::from(self.two_digits()?);
+        if self.peek() == b'.' {
+            self.pos += 1;
+            if !self.peek().is_ascii_digit() {
+                return None;
+            }
+            let mut mult = 1.0;
+            while self.peek().is_ascii_digit() {
+                mult /= 10.0;
+                num += f64::from(self.peek() - b'0') * mult;
+                self.pos += 1;
+            }
+        }
+        Some(num)
+    }
+
+    /// _xmlSchemaParseGYear: at least four digits, without a leading
+    /// zero past four, and not 0.
+    fn year(&mut self) -> Option<i64> {
+        let negative = self.peek() == b'-';
+        if negative {
+            self.pos += 1;
+        }
+        let first = self.peek();
+        let mut year: i64 = 0;
+        let mut digits = 0;
+        while self.peek().is_ascii_digit() {
+            year = year
+                .checked_mul(10)?
+                .checked_add(i64::from(self.peek() - b'0'))?;
+            self.pos += 1;
+            digits += 1;
+        }
+        if digits < 4 || (digits > 4 && first == b'0') || year == 0 {
+            return None;
+        }
+        Some(if negative { -year } else { year })
+    }
+
+    fn month(&mut self) -> Option<u32> {
+        self.two_digits().filter(|&month| valid_month(month))
+    }
+
+    fn day(&mut self) -> Option<u32> {
+        self.two_digits().filter(|day| (1..=31).contains(day))
+    }
+
+    /// _xmlSchemaParseTime, into `dt`: up to 24:00:00.
+    fn time(&mut self, dt: &mut DateValue) -> Option<()> {
+        let hour = self.two_digits()?;
+        if self.peek() != b':' || hour > 24 {
+            return None;
+        }
+        self.pos += 1;
+        dt.hour = hour;
+        let minute = self.two_digits().filter(|&minute| minute < 60)?;
+        dt.minute = minute;
+        if self.peek() != b':' {
+            return None;
+        }
+        self.pos += 1;
+        dt.second = self.seconds()?;
+        let in_day = dt.hour < 24 && (0.0..60.0).contains(&dt.second);
+        let end_of_day = dt.hour == 24 && dt.minute == 0 && dt.second == 0.0;
+        (in_day || end_of_day).then_some(())
+    }
+
+    /// _xmlSchemaParseTimeZone: nothing, `Z`, or an offset of at most
+    /// 14 hours. The cursor only moves on success.
+    fn timezone(&mut self) -> Option<Option<i32>> {
+        match self.peek() {
+            0 => Some(None),
+            b'Z' => {
+                self.pos += 1;
+                Some(Some(0))
+            }
+            sign @ (b'+' | b'-') => {
+                let start = self.pos;
+                self.pos += 1;
+                let zone = (|| {
+                    let hours = self.two_digits().filter(|&hours| hours < 24)?;
+                    if self.peek() != b':' {
+                        return None;
+                    }
+                    self.pos += 1;
+                    let minutes = self.two_digits().filter(|&minutes| minutes < 60)?;
+                    let tzo = (hours * 60 + minutes) as i32;
+                    let tzo = if sign == b'-' { -tzo } else { tzo };
+                    (-MAX_TZO..=MAX_TZO).contains(&tzo).then_some(tzo)
+                })();
+                if zone.is_none() {
+                    self.pos = start;
+                }
+                zone.map(Some)
+            }
+            _ => None,
+        }
+    }
+
+    /// RETURN_TYPE_IF_VALID.
+    fn end(&mut self) -> End {
+        if !matches!(self.peek(), 0 | b'Z' | b'+' | b'-') {
+            return End::More;
+        }
+        match self.timezone() {
+            Some(_) if self.peek() != 0 => End::Trailing,
+            Some(zone) => End::Zone(zone),
+            None => End::More,
+        }
+    }
+}
+
+/// Finish `dt` as a `kind` if a timezone ends the value, fail if one is
+/// followed by more, or go on.
+macro_rules! return_type_if_valid {
+    ($cur:expr, $dt:expr, $kind:expr) => {
+        match $cur.end() {
+            End::Zone(zone) => {
+                $dt.kind = $kind;
+                $dt.timezone = zone;
+                return Some($dt);
+            }
+            End::Trailing => return None,
+            End::More => {}
+        }
+    };
+}
+
+/// xmlSchemaValidateDates for XML_SCHEMAS_UNKNOWN: the type is the one
+/// the value has.
+fn parse_any_date(s: &[u8]) -> Option<DateValue> {
+    let mut cur = Cursor { s, pos: 0 };
+    let mut dt = DateValue {
+        kind: DateType::DateTime,
+        year: 0,
+        month: 0,
+        day: 0,
+        hour: 0,
+        minute: 0,
+        second: 0.0,
+        timezone: None,
+    };
+    if cur.peek() == b'-' && cur.at(1) == b'-' {
+        cur.pos += 2;
+        if cur.peek() == b'-' {
+            cur.pos += 1;
+            dt.day = cur.day()?;
+            return_type_if_valid!(cur, dt, DateType::GDay);
+            return None;
+        }
+        dt.month = cur.month()?;
+        // A '-' starts either the day of a gMonthDay or a negative
+        // timezone, which also begins with two digits.
+        if cur.peek() == b'-' {
+            let rewind = cur.pos;
+            cur.pos += 1;
+            if let Some(day) = cur.day() {
+                dt.day = day;
+                if cur.peek() != b':' && dt.day <= days_in_month(dt.year, dt.month) {
+                    return_type_if_valid!(cur, dt, DateType::GMonthDay);
+                    return None;
+                }
+            }
+            cur.pos = rewind;
+        }
+        return_type_if_valid!(cur, dt, DateType::GMonth);
+        return None;
+    }
+
+    if cur.peek().is_ascii_digit() && cur.time(&mut dt).is_some() {
+        return_type_if_valid!(cur, dt, DateType::Time);
+    }
+
+    // Not a time: a date, possibly right-truncated.
+    cur.pos = 0;
+    dt.year = cur.year()?;
+    return_type_if_valid!(cur, dt, DateType::GYear);
+    if cur.peek() != b'-' {
+        return None;
+    }
+    cur.pos += 1;
+    dt.month = cur.month()?;
+    return_type_if_valid!(cur, dt, DateType::GYearMonth);
+    if cur.peek() != b'-' {
+        return None;
+    }
+    cur.pos += 1;
+    dt.day = cur.day()?;
+    if dt.day > days_in_month(dt.year, dt.month) {
+        return None;
+    }
+    return_type_if_valid!(cur, dt, DateType::Date);
+    if cur.peek() != b'T' {
+        return None;
+    }
+    cur.pos += 1;
+    cur.time(&mut dt)?;
+    dt.timezone = cur.timezone()?;
+    if cur.peek() != 0 {
+        return None;
+    }
+    dt.kind = DateType::DateTime;
+    Some(dt)
+}
+
+impl DateType {
+    /// The type named `name` in the XML Schema namespace.
+    pub fn from_name(name: &[u8]) -> Option<Self> {
+        use DateType::*;
+        Some(match name {
+            b"dateTime" => DateTime,
+            b"time" => Time,
+            b"date" => Date,
+            b"gYearMonth" => GYearMonth,
+            b"gYear" => GYear,
+            b"gMonthDay" => GMonthDay,
+            b"gDay" => GDay,
+            b"gMonth" => GMonth,
+            _ => return None,
+        })
+    }
+
+    /// The fields `xmlSchemaCompareDates` compares across types, as bits:
+    /// year, month, day and time. `gDay` claims year and month.
+    fn fields(self) -> u8 {
+        match self {
+            DateType::DateTime => 0xf,
+            DateType::Date => 0x7,
+            DateType::GYear => 0x1,
+            DateType::GMonth => 0x2,
+            DateType::GDay => 0x3,
+            DateType::GYearMonth => 0x3,
+            DateType::GMonthDay => 0x6,
+            DateType::Time => 0x8,
+        }
+    }
+}
+
+impl DateValue {
+    /// Parse a value of `kind`, as `xmlSchemaValidatePredefinedType`
+    /// does. Blanks around it are skipped.
+    pub fn parse(kind: DateType, value: &[u8]) -> Option<Self> {
+        parse_any_date(trim(value)).filter(|dt| dt.kind == kind)
+    }
+
+    fn tzo(&self) -> i32 {
+        self.timezone.unwrap_or(0)
+    }
+
+    /// _xmlSchemaDateAdd: the date `dur` later, with the timezone
+    /// folded in. The type grows to hold the fields the sum sets.
+    fn add(&self, dur: Duration) -> DateValue {
+        let mut d = *self;
+        let mut u = dur;
+        if d.month == 0 {
+            d.month = 1;
+        }
+        u.seconds -= f64::from(d.tzo() * 60);
+        if d.day == 0 {
+            d.day = 1;
+        }
+        let mut r = DateValue {
+            kind: self.kind,
+            year: 0,
+            month: 0,
+            day: 0,
+            hour: 0,
+            minute: 0,
+            second: 0.0,
+            timezone: d.timezone.map(|_| 0),
+        };
+
+        let carry = i64::from(d.month) + u.months;
+        r.month = modulo_range(carry as f64, 1.0, 13.0) as u32;
+        let carry = fquotient_range(carry as f64, 1.0, 13.0) as i64;
+        r.year = d.year + carry;
+        if r.year == 0 {
+            r.year += if d.year > 0 { -1 } else { 1 };
+        }
+
+        r.second = d.second + u.seconds;
+        let mut carry = fquotient((r.second as i64) as f64, 60.0) as i64;
+        if r.second != 0.0 {
+            r.second = modulo(r.second, 60.0);
+        }
+        carry += i64::from(d.minute);
+        r.minute = modulo(carry as f64, 60.0) as u32;
+        carry = fquotient(carry as f64, 60.0) as i64;
+        carry += i64::from(d.hour);
+        r.hour = modulo(carry as f64, 24.0) as u32;
+        carry = fquotient(carry as f64, 24.0) as i64;
+
+        let max_day = |r: &DateValue| {
+            (r.year != 0 && valid_month(r.month)).then(|| days_in_month(r.year, r.month))
+        };
+        let mut days = match max_day(&r) {
+            Some(max) if d.day > max => i64::from(max),
+            _ => i64::from(d.day.max(1)),
+        };
+        days += u.days + carry;
+        loop {
+            let carry = if days < 1 {
+                let month = i64::from(r.month) - 1;
+                let tmon = modulo_range(month as f64, 1.0, 13.0) as i64;
+                let mut tyr = r.year + fquotient_range(month as f64, 1.0, 13.0) as i64;
+                if tyr == 0 {
+                    tyr -= 1;
+                }
+                days += i64::from(days_in_month(tyr, tmon.clamp(1, 12) as u32));
+                -1
+            } else {
+                match max_day(&r) {
+                    Some(max) if days > i64::from(max) => {
+                        days -= i64::from(max);
+                        1
+                    }
+                    _ => break,
+                }
+            };
+            let temp = i64::from(r.month) + carry;
+            r.month = modulo_range(temp as f64, 1.0, 13.0) as u32;
+            r.year += fquotient_range(temp as f64, 1.0, 13.0) as i64;
+            if r.year == 0 {
+                r.year += if temp < 1 { -1 } else { 1 };
+            }
+        }
+        r.day = days as u32;
+
+        if r.kind != DateType::DateTime {
+            if r.hour != 0 || r.minute != 0 || r.second != 0.0 {
+                r.kind = DateType::DateTime;
+            } else if r.kind != DateType::Date {
+                if r.month != 1 && r.day != 1 {
+                    r.kind = DateType::Date;
+                } else if r.kind != DateType::GYearMonth && r.month != 1 {
+                    r.kind = DateType::GYearMonth;
+                }
+            }
+        }
+        r
+    }
+
+    /// xmlSchemaDateNormalize: the value in UTC, `offset` seconds
+    /// earlier. Only times and dates with a non-zero timezone move, so
+    /// `offset` is ignored without one.
+    fn normalize(&self, offset: f64) -> DateValue {
+        let timed = matches!(
+            self.kind,
+            DateType::Time | DateType::DateTime | DateType::Date
+        );
+        if !timed || self.tzo() == 0 {
+            return *self;
+        }
+        self.add(Duration {
+            months: 0,
+            days: 0,
+            seconds: -offset,
+        })
+    }
+
+    /// _xmlSchemaDateCastYMToDays plus the day: days since the epoch
+    /// of the proleptic calendar.
+    fn days(&self) -> i64 {
+        let month = self.month.max(1) as usize;
+        let year = self.year;
+        let before = if is_leap(year) {
+            DAY_IN_LEAP_YEAR_BY_MONTH[month - 1]
+        } else {
+            DAY_IN_YEAR_BY_MONTH[month - 1]
+        };
+        let years = if year <= 0 {
+            year * 365 + ((year + 1) / 4 - (year + 1) / 100 + (year + 1) / 400)
+        } else {
+            (year - 1) * 365 + ((year - 1) / 4 - (year - 1) / 100 + (year - 1) / 400)
+        };
+        years + before + i64::from(self.day)
+    }
+
+    /// TIME_TO_NUMBER: seconds into the day, counting the timezone.
+    fn seconds_of_day(&self) -> f64 {
+        let whole = i64::from(self.hour) * SECS_PER_HOUR
+            + i64::from(self.minute) * SECS_PER_MIN
+            + i64::from(self.tzo()) * SECS_PER_MIN;
+        whole as f64 + self.second
+    }
+
+    /// Order two dates as `xmlSchemaCompareValues` does: `None` when the
+    /// order is indeterminate, as between a date with a timezone and one
+    /// without within 14 hours of it, or between types missing fields
+    /// the other has, and when a year is too large to count its days.
+    ///
+    /// A value without timezone is not actually moved by 14 hours when
+    /// compared with one that has a timezone, as in C.
+    pub fn compare(&self, other: &DateValue) -> Option<Ordering> {
+        let (x, y) = (self, other);
+        let limit = i64::MAX / 366;
+        if [x.year, y.year]
+            .iter()
+            .any(|year| !(-limit..=limit).contains(year))
+        {
+            return None;
+        }
+        let fourteen_hours = (14 * SECS_PER_HOUR) as f64;
+
+        if x.timezone.is_some() && y.timezone.is_none() {
+            let p1 = x.normalize(0.0);
+            let q1 = y.normalize(fourteen_hours);
+            let (p1d, q1d) = (p1.days(), q1.days());
+            if p1d < q1d {
+                return Some(Ordering::Less);
+            }
+            if p1d == q1d {
+                if p1.seconds_of_day() - q1.seconds_of_day() < 0.0 {
+                    return Some(Ordering::Less);
+                }
+                let q2 = y.normalize(-fourteen_hours);
+                let q2d = q2.days();
+                if p1d > q2d {
+                    return Some(Ordering::Greater);
+                }
+                if p1d == q2d {
+                    return (p1.seconds_of_day() - q2.seconds_of_day() > 0.0)
+                        .then_some(Ordering::Greater);
+                }
+            }
+        } else if x.timezone.is_none() && y.timezone.is_some() {
+            let q1 = y.normalize(0.0);
+            let p1 = x.normalize(-fourteen_hours);
+            let (p1d, q1d) = (p1.days(), q1.days());
+            if p1d < q1d {
+                return Some(Ordering::Less);
+            }
+            if p1d == q1d {
+                if p1.seconds_of_day() - q1.seconds_of_day() < 0.0 {
+                    return Some(Ordering::Less);
+                }
+                let p2 = x.normalize(fourteen_hours);
+                let p2d = p2.days();
+                if p2d > q1d {
+                    return Some(Ordering::Greater);
+                }
+                if p2d == q1d {
+                    return (p2.seconds_of_day() - q1.seconds_of_day() > 0.0)
+                        .then_some(Ordering::Greater);
+                }
+            }
+        }
+
+        if x.kind == y.kind {
+            let q1 = y.normalize(0.0);
+            let p1 = x.normalize(0.0);
+            let sec = p1.seconds_of_day() - q1.seconds_of_day();
+            return Some(p1.days().cmp(&q1.days()).then(if sec < 0.0 {
+                Ordering::Less
+            } else if sec > 0.0 {
+                Ordering::Greater
+            } else {
+                Ordering::Equal
+            }));
+        }
+
+        let (xmask, ymask) = (x.kind.fields(), y.kind.fields());
+        let (differ, shared) = (xmask ^ ymask, xmask & ymask);
+        let fields: [(u8, Ordering); 3] = [
+            (1, x.year.cmp(&y.year)),
+            (2, x.month.cmp(&y.month)),
+            (4, x.day.cmp(&y.day)),
+        ];
+        for (bit, ord) in fields {
+            if differ & bit != 0 {
+                return None;
+            }
+            if shared & bit != 0 && ord != Ordering::Equal {
+                return Some(ord);
+            }
+        }
+        if differ & 8 != 0 {
+            return None;
+        }
+        if shared & 8 != 0 {
+            let ord = x.hour.cmp(&y.hour).then(x.minute.cmp(&y.minute));
+            if ord != Ordering::Equal {
+                return Some(ord);
+            }
+            if x.second < y.second {
+                return Some(Ordering::Less);
+            }
+            if x.second > y.second {
+                return Some(Ordering::Greater);
+            }
+        }
+        Some(Ordering::Equal)
+    }
+
+    /// The canonical form `xmlSchemaGetCanonValue` gives: times and
+    /// dates with a timezone in UTC marked `Z`, the g* types without
+    /// their timezone, and seconds as `%02.14g`.
+    pub fn canonical(&self) -> String {
+        let norm = if self.timezone.is_some() {
+            self.normalize(0.0)
+        } else {
+            *self
+        };
+        let z = if self.timezone.is_some() { "Z" } else { "" };
+        match self.kind {
+            DateType::GYear => format!("{:04}", self.year),
+            DateType::GMonth => format!("--{:02}", self.month),
+            DateType::GDay => format!("---{:02}", self.day),
+            DateType::GMonthDay => format!("--{:02}-{:02}", self.month, self.day),
+            DateType::GYearMonth if self.year < 0 => {
+                format!("-{:04}-{:02}", self.year.unsigned_abs(), self.month)
+            }
+            DateType::GYearMonth => format!("{:04}-{:02}", self.year, self.month),
+            DateType::Time => format!(
+                "{:02}:{:02}:{}{z}",
+                norm.hour,
+                norm.minute,
+                format_g14(norm.second, 2)
+            ),
+            DateType::Date => format!("{:04}-{:02}-{:02}{z}", norm.year, norm.month, norm.day),
+            DateType::DateTime => {
+                let mut s = format!(
+                    "{:04}-{:02}-{:02}T{:02}:{:02}:{}{z}",
+                    norm.year,
+                    norm.month,
+                    norm.day,
+                    norm.hour,
+                    norm.minute,
+                    format_g14(norm.second, 2)
+                );
+                // C prints into 50 bytes.
+                s.truncate(49);
+                s
+            }
+        }
+    }
+}
+
+impl Duration {
+    /// Parse an `xs:duration`, as `xmlSchemaValidatePredefinedType`
+    /// does: `-P`, then the years, months and days, then `T` and the
+    /// hours, minutes and seconds, each optional but in order, with at
+    /// least one in all. Only seconds may have a fraction. Blanks
+    /// around it are skipped.
+    pub fn parse(value: &[u8]) -> Option<Self> {
+        const DESIGNATORS: [u8; 6] = *b"YMDHMS";
+        let mut cur = Cursor {
+            s: trim(value),
+            pos: 0,
+        };
+        let negative = cur.peek() == b'-';
+        if negative {
+            cur.pos += 1;
+        }
+        if cur.peek() != b'P' {
+            return None;
+        }
+        cur.pos += 1;
+        if cur.peek() == 0 {
+            return None;
+        }
+        let mut dur = Duration {
+            months: 0,
+            days: 0,
+            seconds: 0.0,
+        };
+        let mut seq = 0;
+        let mut secs: i64 = 0;
+        let mut fraction = 0.0;
+        while cur.peek() != 0 {
+            if seq >= DESIGNATORS.len() {
+                return None;
+            }
+            if cur.peek() == b'T' {
+                if seq > 3 {
+                    return None;
+                }
+                cur.pos += 1;
+                seq = 3;
+            } else if seq == 3 {
+                return None;
+            }
+            let mut num: i64 = 0;
+            let mut has_digits = false;
+            while cur.peek().is_ascii_digit() {
+                num = num
+                    .checked_mul(10)?
+                    .checked_add(i64::from(cur.peek() - b'0'))?;
+                has_digits = true;
+                cur.pos += 1;
+            }
+            let has_fraction = cur.peek() == b'.';
+            if has_fraction {
+                cur.pos += 1;
+                let mut mult = 1.0;
+                while cur.peek().is_ascii_digit() {
+                    mult /= 10.0;
+                    fraction += f64::from(cur.peek() - b'0') * mult;
+                    has_digits = true;
+                    cur.pos += 1;
+                }
+            }
+            while cur.peek() != DESIGNATORS[seq] {
+                seq += 1;
+                // No T before a time item, or no designator at all.
+                if seq == 3 || seq == DESIGNATORS.len() {
+                    return None;
+                }
+            }
+            cur.pos += 1;
+            if !has_digits || (has_fraction && seq != 5) {
+                return None;
+            }
+            match seq {
+                0 => dur.months = num.checked_mul(12)?,
+                1 => dur.months = dur.months.checked_add(num)?,
+                2 => dur.days = num,
+                3 => {
+                    dur.days = dur.days.checked_add(num / 24)?;
+                    secs = (num % 24) * SECS_PER_HOUR;
+                }
+                4 => {
+                    dur.days = dur.days.checked_add(num / MINS_PER_DAY)?;
+                    secs += (num % MINS_PER_DAY) * SECS_PER_MIN;
+                }
+                _ => {
+                    dur.days = dur.days.checked_add(num / SECS_PER_DAY)?;
+                    secs += num % SECS_PER_DAY;
+                }
+            }
+            seq += 1;
+        }
+        dur.days = dur.days.checked_add(secs / SECS_PER_DAY)?;
+        dur.seconds = (secs % SECS_PER_DAY) as f64 + fraction;
+        if negative {
+            dur.months = -dur.months;
+            dur.days = -dur.days;
+            dur.seconds = -dur.seconds;
+        }
+        Some(dur)
+    }
+
+    /// Order two durations as `xmlSchemaCompareDurations` does: months
+    /// only compare with days within the range of days they may hold,
+    /// and `None` is returned outside it or for years too large to
+    /// count.
+    pub fn compare(&self, other: &Duration) -> Option<Ordering> {
+        const DAY_RANGE: [[i64; 12]; 2] = [
+            [0, 28, 59, 89, 120, 150, 181, 212, 242, 273, 303, 334],
+            [0, 31, 62, 92, 123, 153, 184, 215, 245, 276, 306, 337],
+        ];
+        let mon = self.months.wrapping_sub(other.months);
+        let mut sec = self.seconds - other.seconds;
+        let carry = (sec / SECS_PER_DAY as f64) as i64;
+        sec -= carry as f64 * SECS_PER_DAY as f64;
+        let day = self.days.wrapping_sub(other.days).wrapping_add(carry);
+
+        let sign = |sec: f64| {
+            if sec == 0.0 {
+                Ordering::Equal
+            } else if sec < 0.0 {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            }
+        };
+        if mon == 0 {
+            return Some(day.cmp(&0).then(sign(sec)));
+        }
+        let (invert, xmon, xday) = if mon > 0 {
+            if day >= 0 && sec >= 0.0 {
+                return Some(Ordering::Greater);
+            }
+            (false, mon, day.wrapping_neg())
+        } else if day <= 0 && sec <= 0.0 {
+            return Some(Ordering::Less);
+        } else {
+            (true, mon.wrapping_neg(), day)
+        };
+
+        let years = xmon / 12;
+        let (mut minday, mut maxday) = if years == 0 {
+            (0, 0)
+        } else {
+            if years > i64::MAX / 366 {
+                return None;
+            }
+            // Leap years are every fourth year, exceptions aside.
+            let maxday = 365 * years + (years + 3) / 4;
+            (maxday - 1, maxday)
+        };
+        minday += DAY_RANGE[0][(xmon % 12) as usize];
+        maxday += DAY_RANGE[1][(xmon % 12) as usize];
+
+        let ord = if maxday == minday && maxday == xday {
+            Ordering::Equal
+        } else if maxday < xday {
+            Ordering::Less
+        } else if minday > xday {
+            Ordering::Greater
+        } else {
+            return None;
+        };
+        Some(if invert { ord.reverse() } else { ord })
+    }
+
+    /// The canonical form `xmlSchemaGetCanonValue` gives, with all its
+    /// flaws: every field is written, days are not, and the sign is
+    /// `-` for positive durations and none for negative ones.
+    pub fn canonical(&self) -> String {
+        let months = self.months.unsigned_abs();
+        let year = fquotient(months as f64, 12.0) as u64;
+        let month = months.wrapping_sub(12 * year);
+        let seconds = self.seconds.abs();
+        let day = fquotient(seconds, 86400.0) as u64;
+        let mut left = seconds - (day * 86400) as f64;
+        let (mut hour, mut minute, mut second) = (0, 0, 0.0);
+        if left > 0.0 {
+            hour = fquotient(left, 3600.0) as u64;
+            left -= (hour * 3600) as f64;
+            if left > 0.0 {
+                minute = fquotient(left, 60.0) as u64;
+                second = left - (minute * 60) as f64;
+            }
+        }
+        let sign = if self.months < 0 || self.seconds < 0.0 {
+            ""
+        } else {
+            "-"
+        };
+        format!(
+            "{sign}P{year}Y{month}M{day}DT{hour}H{minute}M{}S",
+            format_g14(second, 0)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(kind: DateType, value: &str) -> DateValue {
+        DateValue::parse(kind, value.as_bytes()).unwrap()
+    }
+
+    #[test]
+    fn test_format_g14() {
+        assert_eq!(format_g14(0.0, 2), "00");
+        assert_eq!(format_g14(5.0, 2), "05");
+        assert_eq!(format_g14(5.5, 2), "5.5");
+        assert_eq!(format_g14(0.00001, 2), "1e-05");
+        assert_eq!(format_g14(59.123456789012345, 2), "59.123456789012");
+        assert_eq!(format_g14(0.1 + 0.2, 0), "0.3");
+    }
+
+    #[test]
+    fn test_parse_dates() {
+        use DateType::*;
+        let dt = date(DateTime, " 2004-02-29T23:59:59.5-14:00\n");
+        assert_eq!(dt.second, 59.5);
+        assert!(DateValue::parse(DateTime, b"2004-02-29T23:59:60-14:00").is_none());
+        assert!(DateValue::parse(Date, b"2003-02-29").is_none());
+        assert!(DateValue::parse(Date, b"0000-01-01").is_none());
+        assert!(DateValue::parse(GYear, b"02004").is_none());
+        assert_eq!(date(GYear, "-12345").year, -12345);
+        assert_eq!(date(Time, "24:00:00").hour, 24);
+        assert!(DateValue::parse(Time, b"24:00:01").is_none());
+        let month = date(GMonth, "--12-05:00");
+        assert_eq!((month.day, month.timezone), (5, Some(-300)));
+        assert_eq!(date(GMonthDay, "--02-29").day, 29);
+        assert!(DateValue::parse(GMonthDay, b"--02-30").is_none());
+    }
+
+    #[test]
+    fn test_end_of_day() {
+        // Only a value with a non-zero timezone is normalized, so 24:00
+        // stays on the day it ends.
+        let x = date(DateType::DateTime, "1999-12-31T24:00:00Z");
+        let y = date(DateType::DateTime, "2000-01-01T00:00:00Z");
+        assert_eq!(x.compare(&y), Some(Ordering::Less));
+        assert_eq!(x.canonical(), "1999-12-31T24:00:00Z");
+        let x = date(DateType::DateTime, "1999-12-31T24:00:00+01:00");
+        assert_eq!(x.canonical(), "1999-12-31T23:00:00Z");
+    }
+
+    #[test]
+    fn test_durations() {
+        let dur = |s: &str| Duration::parse(s.as_bytes()).unwrap();
+        assert_eq!(
+            dur("-P1Y2M3DT25H61M61.5S"),
+            Duration {
+                months: -14,
+                days: -4,
+                seconds: -(3600.0 + 3660.0 + 61.5),
+            }
+        );
+        for bad in [
+            "P", "-P", "PT", "P1D2Y", "P1H", "PT1D", "P1.5D", "P1YT", "1Y", "P-1Y",
+        ] {
+            assert_eq!(Duration::parse(bad.as_bytes()), None, "{bad:?}");
+        }
+        assert_eq!(dur("P1M").compare(&dur("P30D")), None);
+        assert_eq!(dur("P1M").compare(&dur("P32D")), Some(Ordering::Less));
+        assert_eq!(dur("PT24H").compare(&dur("P1D")), Some(Ordering::Equal));
+        assert_eq!(dur("P1Y2M").canonical(), "-P1Y2M0DT0H0M0S");
+    }
+}
@@ -0,0 +1,305 @@
+use std::os::raw::c_int;
+
+use super::core::{char_count, collapsed_len, normalize, BuiltinType, Decimal, Value, WhiteSpace};
+use crate::static_bindings::{
+    xmlChar, xmlParserErrors_XML_SCHEMAP_COS_ST_RESTRICTS_1_3_1,
+    xmlParserErrors_XML_SCHEMAP_INVALID_FACET_VALUE, xmlParserErrors_XML_SCHEMAP_REGEXP_INVALID,
+    xmlParserErrors_XML_SCHEMAV_CVC_DATATYPE_VALID_1_2_1,
+    xmlParserErrors_XML_SCHEMAV_CVC_ENUMERATION_VALID,
+    xmlParserErrors_XML_SCHEMAV_CVC_FRACTIONDIGITS_VALID,
+    xmlParserErrors_XML_SCHEMAV_CVC_LENGTH_VALID,
+    xmlParserErrors_XML_SCHEMAV_CVC_MAXEXCLUSIVE_VALID,
+    xmlParserErrors_XML_SCHEMAV_CVC_MAXINCLUSIVE_VALID,
+    xmlParserErrors_XML_SCHEMAV_CVC_MAXLENGTH_VALID,
+    xmlParserErrors_XML_SCHEMAV_CVC_MINEXCLUSIVE_VALID,
+    xmlParserErrors_XML_SCHEMAV_CVC_MININCLUSIVE_VALID,
+    xmlParserErrors_XML_SCHEMAV_CVC_MINLENGTH_VALID, xmlParserErrors_XML_SCHEMAV_CVC_PATTERN_VALID,
+    xmlParserErrors_XML_SCHEMAV_CVC_TOTALDIGITS_VALID, xmlRegFreeRegexp, xmlRegexpCompile,
+    xmlRegexpExec, xmlRegexpPtr,
+};
+
+/// The constraining facets of XML Schema Part 2.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FacetKind {
+    Length,
+    MinLength,
+    MaxLength,
+    Pattern,
+    Enumeration,
+    WhiteSpace,
+    MaxInclusive,
+    MaxExclusive,
+    MinInclusive,
+    MinExclusive,
+    TotalDigits,
+    FractionDigits,
+}
+
+impl FacetKind {
+    /// The facet whose element in the XML Schema namespace is `name`.
+    pub fn from_name(name: &[u8]) -> Option<Self> {
+        use FacetKind::*;
+        Some(match name {
+            b"length" => Length,
+            b"minLength" => MinLength,
+            b"maxLength" => MaxLength,
+            b"pattern" => Pattern,
+            b"enumeration" => Enumeration,
+            b"whiteSpace" => WhiteSpace,
+            b"maxInclusive" => MaxInclusive,
+            b"maxExclusive" => MaxExclusive,
+            b"minInclusive" => MinInclusive,
+            b"minExclusive" => MinExclusive,
+            b"totalDigits" => TotalDigits,
+            b"fractionDigits" => FractionDigits,
+            _ => return None,
+        })
+    }
+
+    /// Whether a type derived from `base` may restrict this facet.
+    pub fn applies_to(self, base: BuiltinType) -> bool {
+        use FacetKind::*;
+        match self {
+            Pattern | WhiteSpace => true,
+            Enumeration => base != BuiltinType::Boolean,
+            Length | MinLength | MaxLength => base.is_string(),
+            _ => base.is_decimal(),
+        }
+    }
+}
+
+/// A facet as a schema writes it: its kind and the text of its `value`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Facet {
+    pub kind: FacetKind,
+    pub value: Vec<u8>,
+}
+
+impl Facet {
+    pub fn new(kind: FacetKind, value: &[u8]) -> Self {
+        Facet {
+            kind,
+            value: value.to_vec(),
+        }
+    }
+}
+
+/// Why a `SimpleType` could not be built from its facets.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FacetError {
+    /// A facet the base type does not take.
+    NotApplicable(FacetKind),
+    /// A value outside the value space the facet takes.
+    InvalidValue(FacetKind, Vec<u8>),
+    /// A pattern `xmlRegexpCompile` rejects.
+    Regexp(Vec<u8>),
+}
+
+impl FacetError {
+    /// The `xmlParserErrors` code the C schema parser reports.
+    pub fn code(&self) -> c_int {
+        (match self {
+            FacetError::NotApplicable(_) => xmlParserErrors_XML_SCHEMAP_COS_ST_RESTRICTS_1_3_1,
+            FacetError::InvalidValue(..) => xmlParserErrors_XML_SCHEMAP_INVALID_FACET_VALUE,
+            FacetError::Regexp(_) => xmlParserErrors_XML_SCHEMAP_REGEXP_INVALID,
+        }) as c_int
+    }
+}
+
+/// A compiled `pattern`, freed with the type.
+struct Regexp(xmlRegexpPtr);
+
+impl Regexp {
+    fn compile(pattern: &[u8]) -> Option<Self> {
+        let mut source = pattern.to_vec();
+        source.push(0);
+        let regexp = unsafe { xmlRegexpCompile(source.as_ptr() as *const xmlChar) };
+        (!regexp.is_null()).then_some(Regexp(regexp))
+    }
+
+    fn matches(&self, value: &[u8]) -> bool {
+        let mut input = value.to_vec();
+        input.push(0);
+        unsafe { xmlRegexpExec(self.0, input.as_ptr() as *const xmlChar) == 1 }
+    }
+}
+
+impl Drop for Regexp {
+    fn drop(&mut self) {
+        unsafe { xmlRegFreeRegexp(self.0) };
+    }
+}
+
+/// The facets checked one by one, in the order they were given.
+enum Check {
+    Length(u64),
+    MinLength(u64),
+    MaxLength(u64),
+    MaxInclusive(Decimal),
+    MaxExclusive(Decimal),
+    MinInclusive(Decimal),
+    MinExclusive(Decimal),
+    TotalDigits(u64),
+    FractionDigits(u64),
+}
+
+/// An atomic simple type restricting a built-in type by facets, as a
+/// `<xs:simpleType><xs:restriction base="...">` compiles in C.
+pub struct SimpleType {
+    base: BuiltinType,
+    whitespace: WhiteSpace,
+    checks: Vec<Check>,
+    enumeration: Vec<Value>,
+    patterns: Vec<Regexp>,
+}
+
+impl SimpleType {
+    /// Restrict `base` by `facets`, checking each facet value like
+    /// `xmlSchemaCheckFacet`.
+    ///
+    /// Enumeration values and range bounds must be values of `base`,
+    /// lengths non-negative integers, `totalDigits` a positive integer.
+    /// Consistency between facets, such as `minLength` above
+    /// `maxLength`, is not checked.
+    pub fn new(base: BuiltinType, facets: &[Facet]) -> Result<Self, FacetError> {
+        if let Some(facet) = facets.iter().find(|f| !f.kind.applies_to(base)) {
+            return Err(FacetError::NotApplicable(facet.kind));
+        }
+        let mut ty = SimpleType {
+            base,
+            whitespace: base.whitespace(),
+            checks: Vec::new(),
+            enumeration: Vec::new(),
+            patterns: Vec::new(),
+        };
+        for facet in facets {
+            let invalid = || FacetError::InvalidValue(facet.kind, facet.value.clone());
+            let bound = || match base.parse(&facet.value) {
+                Some(Value::Decimal(d)) => Ok(d),
+                _ => Err(invalid()),
+            };
+            let count = |ty: BuiltinType| match ty.parse(&facet.value) {
+                Some(Value::Decimal(d)) => Ok(d.as_u64()),
+                _ => Err(invalid()),
+            };
+            let nn_count = || count(BuiltinType::NonNegativeInteger);
+            match facet.kind {
+                FacetKind::Length => ty.checks.push(Check::Length(nn_count()?)),
+                FacetKind::MinLength => ty.checks.push(Check::MinLength(nn_count()?)),
+                FacetKind::MaxLength => ty.checks.push(Check::MaxLength(nn_count()?)),
+                FacetKind::MaxInclusive => ty.checks.push(Check::MaxInclusive(bound()?)),
+                FacetKind::MaxExclusive => ty.checks.push(Check::MaxExclusive(bound()?)),
+                FacetKind::MinInclusive => ty.checks.push(Check::MinInclusive(bound()?)),
+                FacetKind::MinExclusive => ty.checks.push(Check::MinExclusive(bound()?)),
+                FacetKind::TotalDigits => ty
+                    .checks
+                    .push(Check::TotalDigits(count(BuiltinType::PositiveInteger)?)),
+                FacetKind::FractionDigits => ty.checks.push(Check::FractionDigits(nn_count()?)),
+                FacetKind::WhiteSpace => {
+                    ty.whitespace = WhiteSpace::from_name(&facet.value).ok_or_else(invalid)?;
+                }
+                FacetKind::Enumeration => {
+                    // Enumeration values are normalized as the base type
+                    // does, whatever the whiteSpace facet says.
+                    let value = normalize(&facet.value, base.whitespace());
+                    ty.enumeration.push(base.parse(&value).ok_or_else(invalid)?);
+                }
+                FacetKind::Pattern => {
+                    let regexp = Regexp::compile(&facet.value)
+                        .ok_or_else(|| FacetError::Regexp(facet.value.clone()))?;
+                    ty.patterns.push(regexp);
+                }
+            }
+        }
+        Ok(ty)
+    }
+
+    pub fn base(&self) -> BuiltinType {
+        self.base
+    }
+
+    /// The normalization of the type: its `whiteSpace` facet, or that of
+    /// the base type.
+    pub fn whitespace(&self) -> WhiteSpace {
+        self.whitespace
+    }
+
+    /// Validate `value` like `xmlSchemaVCheckCVCSimpleType` on element
+    /// content, returning the `xmlParserErrors` codes C reports, in its
+    /// order.
+    ///
+    /// A value outside the lexical space of the base type only fails
+    /// `XML_SCHEMAV_CVC_DATATYPE_VALID_1_2_1`. Otherwise each failing
+    /// length, range and digits facet is reported in turn, then the
+    /// enumeration if no value matches, then the patterns if none
+    /// matches.
+    pub fn validate(&self, value: &[u8]) -> Result<(), Vec<c_int>> {
+        // Only patterns and enumerations make C normalize the value up
+        // front; the rest normalize as they read it.
+        let value = if self.enumeration.is_empty() && self.patterns.is_empty() {
+            value.to_vec()
+        } else {
+            normalize(value, self.whitespace)
+        };
+        let Some(parsed) = self.base.parse(&value) else {
+            return Err(vec![
+                xmlParserErrors_XML_SCHEMAV_CVC_DATATYPE_VALID_1_2_1 as c_int,
+            ]);
+        };
+
+        let mut errors = Vec::new();
+        for check in &self.checks {
+            if let Some(code) = self.check(check, &value, &parsed) {
+                errors.push(code as c_int);
+            }
+        }
+        if !self.enumeration.is_empty() && !self.enumeration.contains(&parsed) {
+            errors.push(xmlParserErrors_XML_SCHEMAV_CVC_ENUMERATION_VALID as c_int);
+        }
+        if !self.patterns.is_empty() && !self.patterns.iter().any(|p| p.matches(&value)) {
+            errors.push(xmlParserErrors_XML_SCHEMAV_CVC_PATTERN_VALID as c_int);
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// The error `check` reports for `value`, parsed as `parsed`.
+    fn check(&self, check: &Check, value: &[u8], parsed: &Value) -> Option<u32> {
+        let len = || {
+            if self.base == BuiltinType::Token || self.whitespace == WhiteSpace::Collapse {
+                collapsed_len(value) as u64
+            } else {
+                char_count(value) as u64
+            }
+        };
+        let decimal = match parsed {
+            Value::Decimal(d) => Some(d),
+            _ => None,
+        };
+        let fails = match check {
+            Check::Length(n) => len() != *n,
+            Check::MinLength(n) => len() < *n,
+            Check::MaxLength(n) => len() > *n,
+            Check::MaxInclusive(b) => decimal.is_some_and(|d| d > b),
+            Check::MaxExclusive(b) => decimal.is_some_and(|d| d >= b),
+            Check::MinInclusive(b) => decimal.is_some_and(|d| d < b),
+            Check::MinExclusive(b) => decimal.is_some_and(|d| d <= b),
+            Check::TotalDigits(n) => decimal.is_some_and(|d| d.total_digits() > *n),
+            Check::FractionDigits(n) => decimal.is_some_and(|d| d.fraction_digits() > *n),
+        };
+        fails.then_some(match check {
+            Check::Length(_) => xmlParserErrors_XML_SCHEMAV_CVC_LENGTH_VALID,
+            Check::MinLength(_) => xmlParserErrors_XML_SCHEMAV_CVC_MINLENGTH_VALID,
+            Check::MaxLength(_) => xmlParserErrors_XML_SCHEMAV_CVC_MAXLENGTH_VALID,
+            Check::MaxInclusive(_) => xmlParserErrors_XML_SCHEMAV_CVC_MAXINCLUSIVE_VALID,
+            Check::MaxExclusive(_) => xmlParserErrors_XML_SCHEMAV_CVC_MAXEXCLUSIVE_VALID,
+            Check::MinInclusive(_) => xmlParserErrors_XML_SCHEMAV_CVC_MININCLUSIVE_VALID,
+            Check::MinExclusive(_) => xmlParserErrors_XML_SCHEMAV_CVC_MINEXCLUSIVE_VALID,
+            Check::TotalDigits(_) => xmlParserErrors_XML_SCHEMAV_CVC_TOTALDIGITS_VALID,
+            Check::FractionDigits(_) => xmlParserErrors_XML_SCHEMAV_CVC_FRACTIONDIGITS_VALID,
+        })
+    }
+}
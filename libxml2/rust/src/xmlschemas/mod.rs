@@ -1 +1,15 @@
-// Empty stub module for xmlschemas
+//! Rust implementation of xmlschemas module
+//!
+//! Validation of built-in simple types restricted by facets, and of
+//! element-only content models; parsing and ordering of date, time and
+//! duration values
+
+mod content;
+pub mod core;
+mod dates;
+mod facets;
+
+pub use content::*;
+pub use core::*;
+pub use dates::*;
+pub use facets::*;
@@ -0,0 +1,111 @@
+//! Conversions between `xmlChar*` strings and Rust strings
+//!
+//! These don't replace anything in `xmlstring.c`, so they are built
+//! whichever implementation is linked.
+
+use std::borrow::Cow;
+use std::ffi::{CStr, CString};
+use std::fmt;
+use std::os::raw::c_char;
+use std::ptr;
+
+use crate::static_bindings::xmlChar;
+
+/// Borrow a NUL-terminated `xmlChar*` as a Rust string, or `None` for
+/// NULL. Valid UTF-8 is borrowed; anything else is copied with the bad
+/// sequences replaced by U+FFFD.
+///
+/// # Safety
+///
+/// `s` must be null or a NUL-terminated string that stays valid and
+/// unmodified for `'a`.
+pub unsafe fn xmlchar_to_str<'a>(s: *const xmlChar) -> Option<Cow<'a, str>> {
+    if s.is_null() {
+        return None;
+    }
+    Some(CStr::from_ptr(s as *const c_char).to_string_lossy())
+}
+
+/// An owned NUL-terminated copy of a Rust string, to pass where libxml2
+/// takes a `const xmlChar*`.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct XmlCharBuf(CString);
+
+impl XmlCharBuf {
+    /// Pointer to the string, valid while `self` is.
+    pub fn as_ptr(&self) -> *const xmlChar {
+        self.0.as_ptr() as *const xmlChar
+    }
+
+    /// The string without its terminator.
+    pub fn as_bytes(&self) -> &[u8] {
+        self.0.as_bytes()
+    }
+
+    pub fn as_str(&self) -> &str {
+        // Only ever built from a `&str`
+        unsafe { std::str::from_utf8_unchecked(self.as_bytes()) }
+    }
+}
+
+impl fmt::Debug for XmlCharBuf {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+/// Copy `s` into a buffer libxml2 can read, or `None` if it contains a NUL
+/// byte, which an `xmlChar*` can't represent.
+pub fn str_to_xmlchar(s: &str) -> Option<XmlCharBuf> {
+    CString::new(s).ok().map(XmlCharBuf)
+}
+
+/// The buffer's pointer, or NULL for `None`, for the optional `xmlChar*`
+/// arguments of the C API.
+pub fn xmlchar_ptr(buf: Option<&XmlCharBuf>) -> *const xmlChar {
+    buf.map_or(ptr::null(), XmlCharBuf::as_ptr)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ptr_of(s: &CStr) -> *const xmlChar {
+        s.as_ptr() as *const xmlChar
+    }
+
+    #[test]
+    fn test_null_is_none() {
+        assert_eq!(unsafe { xmlchar_to_str(ptr::null()) }, None);
+        assert!(xmlchar_ptr(None).is_null());
+    }
+
+    #[test]
+    fn test_valid_utf8_is_borrowed() {
+        let ret = unsafe { xmlchar_to_str(ptr_of(c"caf\xc3\xa9")) }.unwrap();
+        assert!(matches!(ret, Cow::Borrowed("café")));
+
+        let ret = unsafe { xmlchar_to_str(ptr_of(c"")) }.unwrap();
+        assert!(matches!(ret, Cow::Borrowed("")));
+    }
+
+    #[test]
+    fn test_invalid_utf8_is_replaced() {
+        let ret = unsafe { xmlchar_to_str(ptr_of(c"a\xffb\xc3")) }.unwrap();
+        assert!(matches!(ret, Cow::Owned(_)));
+        assert_eq!(ret, "a\u{fffd}b\u{fffd}");
+    }
+
+    #[test]
+    fn test_str_to_xmlchar() {
+        let buf = str_to_xmlchar("café").unwrap();
+        assert_eq!(buf.as_bytes(), "café".as_bytes());
+        assert_eq!(buf.as_str(), "café");
+        let back = unsafe { xmlchar_to_str(buf.as_ptr()) }.unwrap();
+        assert_eq!(back, "café");
+        assert_eq!(xmlchar_ptr(Some(&buf)), buf.as_ptr());
+
+        assert_eq!(str_to_xmlchar("").unwrap().as_bytes(), b"");
+        assert_eq!(str_to_xmlchar("a\0b"), None);
+    }
+}
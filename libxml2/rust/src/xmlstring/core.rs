@@ -0,0 +1,271 @@
+#![allow(clippy::missing_safety_doc)]
+
+use std::os::raw::c_int;
+
+/// Byte of a NUL-terminated string at `i`, reading the terminator past
+/// the end of the slice.
+fn at(s: &[u8], i: usize) -> u8 {
+    s.get(i).copied().unwrap_or(0)
+}
+
+/// Case folding used by `xmlStrcasecmp`. This is ASCII lowercasing plus
+/// the `[` to `{` mapping the C `casemap` table has always had.
+pub fn casemap(c: u8) -> u8 {
+    match c {
+        b'[' => b'{',
+        _ => c.to_ascii_lowercase(),
+    }
+}
+
+/// Length of a string as `xmlStrlen` reports it: 0 if it does not fit in
+/// an int.
+pub fn str_len(s: &[u8]) -> c_int {
+    c_int::try_from(s.len()).unwrap_or(0)
+}
+
+/// Compare two strings byte by byte. Returns the difference of the first
+/// differing bytes, the end of a string counting as 0.
+pub fn str_cmp(a: &[u8], b: &[u8]) -> c_int {
+    str_cmp_by(a, b, |c| c)
+}
+
+/// Check whether two strings are equal.
+pub fn str_equal(a: &[u8], b: &[u8]) -> bool {
+    a == b
+}
+
+/// Compare two strings ignoring ASCII case, like `str_cmp`.
+pub fn str_casecmp(a: &[u8], b: &[u8]) -> c_int {
+    str_cmp_by(a, b, casemap)
+}
+
+fn str_cmp_by(a: &[u8], b: &[u8], map: impl Fn(u8) -> u8) -> c_int {
+    for i in 0..=a.len().max(b.len()) {
+        let diff = map(at(a, i)) as c_int - map(at(b, i)) as c_int;
+        if diff != 0 {
+            return diff;
+        }
+    }
+    0
+}
+
+/// Offset of the first occurrence of `needle` in `haystack`. An empty
+/// needle, or one too long for `xmlStrlen`, matches at 0 like in C.
+pub fn str_str(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    str_str_by(haystack, needle, |c| c)
+}
+
+/// Like `str_str`, ignoring ASCII case.
+pub fn str_casestr(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    str_str_by(haystack, needle, casemap)
+}
+
+fn str_str_by(haystack: &[u8], needle: &[u8], map: impl Fn(u8) -> u8) -> Option<usize> {
+    if str_len(needle) == 0 {
+        return Some(0);
+    }
+    haystack
+        .windows(needle.len())
+        .position(|window| window.iter().zip(needle).all(|(&a, &b)| map(a) == map(b)))
+}
+
+/// Up to `len` bytes of `s` from `start`, or `None` if `start` is not
+/// inside the string.
+pub fn str_sub(s: &[u8], start: usize, len: usize) -> Option<&[u8]> {
+    if start >= s.len() {
+        return None;
+    }
+    Some(&s[start..s.len().min(start.saturating_add(len))])
+}
+
+/// Bytes to allocate for a string of `size` bytes followed by `len` more
+/// and the terminator. `None` if the result would be longer than
+/// `INT_MAX`, which is where C gives up as well.
+pub fn concat_alloc_size(size: usize, len: usize) -> Option<usize> {
+    match size.checked_add(len) {
+        Some(total) if total <= c_int::MAX as usize => Some(total + 1),
+        _ => None,
+    }
+}
+
+/// Length of the sequence a UTF-8 lead byte starts, as `xmlCheckUTF8`
+/// sees it, or `None` for a continuation byte or 0xf8 and above.
+fn utf8_len(lead: u8) -> Option<usize> {
+    match lead {
+        0x00..=0x7f => Some(1),
+        0xc0..=0xdf => Some(2),
+        0xe0..=0xef => Some(3),
+        0xf0..=0xf7 => Some(4),
+        _ => None,
+    }
+}
+
+/// Whether `tail` holds all the continuation bytes of a sequence.
+fn is_continuation(tail: Option<&[u8]>) -> bool {
+    tail.is_some_and(|tail| tail.iter().all(|&b| b & 0xc0 == 0x80))
+}
+
+/// Offset of the first sequence `xmlCheckUTF8` rejects, checking one
+/// sequence at a time like the C loop. `None` if `buf` is valid.
+pub fn check_utf8(buf: &[u8]) -> Option<usize> {
+    let mut i = 0;
+    while i < buf.len() {
+        let Some(len) = utf8_len(buf[i]) else {
+            return Some(i);
+        };
+        if len > 1 && !is_continuation(buf.get(i + 1..i + len)) {
+            return Some(i);
+        }
+        i += len;
+    }
+    None
+}
+
+const HIGH_BITS: u64 = 0x8080_8080_8080_8080;
+
+/// Offset of the first sequence `xmlCheckUTF8` rejects, or `None` if
+/// `buf` is valid. Runs of ASCII are skipped 16 bytes at a time with
+/// word-sized loads.
+///
+/// Like `xmlCheckUTF8`, this only checks the byte patterns: overlong
+/// forms, surrogates and values above U+10FFFF pass. NUL is an ordinary
+/// character here, while C stops at the first one. The parser doesn't use
+/// it; it checks characters one at a time as it decodes them.
+pub fn xml_validate_utf8(buf: &[u8]) -> Option<usize> {
+    let mut i = 0;
+    while i < buf.len() {
+        while let Some(block) = buf.get(i..i + 16) {
+            let lo = u64::from_ne_bytes(block[..8].try_into().unwrap());
+            let hi = u64::from_ne_bytes(block[8..].try_into().unwrap());
+            if (lo | hi) & HIGH_BITS != 0 {
+                break;
+            }
+            i += 16;
+        }
+        let Some(&lead) = buf.get(i) else {
+            break;
+        };
+        if lead < 0x80 {
+            i += 1;
+            continue;
+        }
+        let Some(len) = utf8_len(lead) else {
+            return Some(i);
+        };
+        if !is_continuation(buf.get(i + 1..i + len)) {
+            return Some(i);
+        }
+        i += len;
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_str_len() {
+        assert_eq!(str_len(b""), 0);
+        assert_eq!(str_len(b"abc"), 3);
+    }
+
+    #[test]
+    fn test_str_cmp() {
+        assert_eq!(str_cmp(b"abc", b"abc"), 0);
+        assert_eq!(str_cmp(b"abd", b"abc"), 1);
+        assert_eq!(str_cmp(b"ab", b"abc"), -(b'c' as c_int));
+        assert_eq!(str_cmp(b"abc", b"ab"), b'c' as c_int);
+        assert_eq!(str_cmp(b"\xff", b"a"), 0xff - b'a' as c_int);
+        assert_eq!(str_cmp(b"", b""), 0);
+    }
+
+    #[test]
+    fn test_str_str() {
+        assert_eq!(str_str(b"abcabc", b"ca"), Some(2));
+        assert_eq!(str_str(b"aaab", b"aab"), Some(1));
+        assert_eq!(str_str(b"abc", b""), Some(0));
+        assert_eq!(str_str(b"", b""), Some(0));
+        assert_eq!(str_str(b"", b"a"), None);
+        assert_eq!(str_str(b"ab", b"abc"), None);
+        assert_eq!(str_str(b"abc", b"ABC"), None);
+        assert_eq!(str_casestr(b"xxABC", b"abc"), Some(2));
+        assert_eq!(str_casestr(b"a[b", b"A{B"), Some(0));
+    }
+
+    #[test]
+    fn test_str_sub() {
+        assert_eq!(str_sub(b"abcdef", 1, 3), Some(&b"bcd"[..]));
+        assert_eq!(str_sub(b"abcdef", 4, 10), Some(&b"ef"[..]));
+        assert_eq!(str_sub(b"abcdef", 0, 0), Some(&b""[..]));
+        assert_eq!(str_sub(b"abcdef", 6, 1), None);
+        assert_eq!(str_sub(b"", 0, 1), None);
+    }
+
+    #[test]
+    fn test_concat_alloc_size() {
+        let max = c_int::MAX as usize;
+        assert_eq!(concat_alloc_size(0, 0), Some(1));
+        assert_eq!(concat_alloc_size(3, 2), Some(6));
+        assert_eq!(concat_alloc_size(max - 1, 1), Some(max + 1));
+        assert_eq!(concat_alloc_size(1, max - 1), Some(max + 1));
+        assert_eq!(concat_alloc_size(max, 1), None);
+        assert_eq!(concat_alloc_size(1, max), None);
+        assert_eq!(concat_alloc_size(usize::MAX, 1), None);
+        assert_eq!(concat_alloc_size(usize::MAX, usize::MAX), None);
+    }
+
+    #[test]
+    fn test_str_equal() {
+        assert!(str_equal(b"abc", b"abc"));
+        assert!(!str_equal(b"abc", b"abC"));
+        assert!(!str_equal(b"abc", b"ab"));
+    }
+
+    #[test]
+    fn test_str_casecmp() {
+        assert_eq!(str_casecmp(b"ABC", b"abc"), 0);
+        assert_eq!(str_casecmp(b"abc", b"ABD"), -1);
+        assert_eq!(str_casecmp(b"[", b"{"), 0);
+        assert_eq!(str_casecmp(b"{", b"["), 0);
+        assert_eq!(str_casecmp(b"]", b"}"), b']' as c_int - b'}' as c_int);
+        assert_eq!(str_casecmp(b"\xc4", b"\xe4"), 0xc4 - 0xe4);
+    }
+
+    #[test]
+    fn test_validate_utf8() {
+        let cases: &[(&[u8], Option<usize>)] = &[
+            (b"", None),
+            (b"plain ascii text that is longer than one block", None),
+            ("caf\u{e9} \u{4e2d}\u{6587} \u{1f600}".as_bytes(), None),
+            (b"\xc0\x80 \xed\xa0\x80 \xf4\x90\x80\x80", None),
+            (b"a\0b", None),
+            (b"\x80", Some(0)),
+            (b"0123456789abcdefghij\xff", Some(20)),
+            (b"0123456789abcdef\xc3", Some(16)),
+            (b"ab\xe2\x82", Some(2)),
+            (b"ab\xe2\x82x", Some(2)),
+            (b"\xf0\x9f\x98\x80\xf8\x80\x80\x80", Some(4)),
+        ];
+        for &(buf, expected) in cases {
+            assert_eq!(xml_validate_utf8(buf), expected, "{buf:?}");
+            assert_eq!(check_utf8(buf), expected, "{buf:?}");
+        }
+    }
+
+    #[test]
+    fn test_validate_utf8_every_offset() {
+        // An error after each possible amount of ASCII, to hit every
+        // position within and after the 16-byte blocks.
+        for prefix in 0..40 {
+            for tail in [&b"\xe2\x82\xac"[..], b"\xc3", b"\xbf"] {
+                let mut buf = vec![b'a'; prefix];
+                buf.extend_from_slice(tail);
+                buf.extend_from_slice(&[b'z'; 20]);
+                let expected = check_utf8(&buf);
+                assert_eq!(xml_validate_utf8(&buf), expected);
+                assert_eq!(expected.is_none(), tail.len() == 3);
+            }
+        }
+    }
+}
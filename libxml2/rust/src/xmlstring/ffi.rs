@@ -0,0 +1,223 @@
+#![allow(clippy::missing_safety_doc)]
+
+use std::ffi::CStr;
+use std::os::raw::{c_char, c_int};
+use std::ptr;
+
+use super::core;
+use crate::static_bindings::{xmlChar, xmlFree, xmlMalloc, xmlRealloc};
+
+// Ensure symbols are exported for linking
+#[cfg(feature = "rust-xmlstring")]
+#[used]
+static XMLSTRING_FFI_LINKAGE: () = ();
+
+unsafe fn bytes<'a>(s: *const xmlChar) -> &'a [u8] {
+    CStr::from_ptr(s as *const c_char).to_bytes()
+}
+
+/// Copy the concatenation of `parts` into a NUL-terminated xmlMalloc'ed
+/// string.
+unsafe fn dup(parts: &[&[u8]]) -> *mut xmlChar {
+    let Some(malloc) = xmlMalloc else {
+        return ptr::null_mut();
+    };
+    let len: usize = parts.iter().map(|part| part.len()).sum();
+    let mem = malloc(len + 1) as *mut xmlChar;
+    if mem.is_null() {
+        return ptr::null_mut();
+    }
+    let mut offset = 0;
+    for part in parts {
+        ptr::copy_nonoverlapping(part.as_ptr(), mem.add(offset), part.len());
+        offset += part.len();
+    }
+    *mem.add(len) = 0;
+    mem
+}
+
+/// `len` bytes at `s`, which may run past a NUL like the C memcpy.
+unsafe fn raw<'a>(s: *const xmlChar, len: c_int) -> &'a [u8] {
+    std::slice::from_raw_parts(s, len as usize)
+}
+
+/// Length of a xmlChar's string. Returns 0 for NULL.
+#[no_mangle]
+pub unsafe extern "C" fn xmlStrlen(str: *const xmlChar) -> c_int {
+    if str.is_null() {
+        return 0;
+    }
+    core::str_len(bytes(str))
+}
+
+/// a strcmp for xmlChar's. NULL sorts before any string.
+#[no_mangle]
+pub unsafe extern "C" fn xmlStrcmp(str1: *const xmlChar, str2: *const xmlChar) -> c_int {
+    if str1 == str2 {
+        return 0;
+    }
+    if str1.is_null() {
+        return -1;
+    }
+    if str2.is_null() {
+        return 1;
+    }
+    core::str_cmp(bytes(str1), bytes(str2))
+}
+
+/// Check if both strings are equal or have the same content. Returns 1
+/// if they are equal, 0 otherwise.
+#[no_mangle]
+pub unsafe extern "C" fn xmlStrEqual(str1: *const xmlChar, str2: *const xmlChar) -> c_int {
+    if str1 == str2 {
+        return 1;
+    }
+    if str1.is_null() || str2.is_null() {
+        return 0;
+    }
+    core::str_equal(bytes(str1), bytes(str2)) as c_int
+}
+
+/// a strcasecmp for xmlChar's. NULL sorts before any string.
+#[no_mangle]
+pub unsafe extern "C" fn xmlStrcasecmp(str1: *const xmlChar, str2: *const xmlChar) -> c_int {
+    if str1 == str2 {
+        return 0;
+    }
+    if str1.is_null() {
+        return -1;
+    }
+    if str2.is_null() {
+        return 1;
+    }
+    core::str_casecmp(bytes(str1), bytes(str2))
+}
+
+/// Check whether a string is made of well-formed UTF-8 byte sequences.
+/// Returns 1 if it is, 0 otherwise or for NULL.
+#[no_mangle]
+pub unsafe extern "C" fn xmlCheckUTF8(utf: *const xmlChar) -> c_int {
+    if utf.is_null() {
+        return 0;
+    }
+    core::xml_validate_utf8(bytes(utf)).is_none() as c_int
+}
+
+/// a strstr for xmlChar's. Returns a pointer to the first occurrence of
+/// `val` in `str`, `str` itself for an empty `val`, or NULL.
+#[no_mangle]
+pub unsafe extern "C" fn xmlStrstr(str: *const xmlChar, val: *const xmlChar) -> *const xmlChar {
+    if str.is_null() || val.is_null() {
+        return ptr::null();
+    }
+    match core::str_str(bytes(str), bytes(val)) {
+        Some(offset) => str.add(offset),
+        None => ptr::null(),
+    }
+}
+
+/// a case-ignoring strstr for xmlChar's, folding case like xmlStrcasecmp.
+#[no_mangle]
+pub unsafe extern "C" fn xmlStrcasestr(str: *const xmlChar, val: *const xmlChar) -> *const xmlChar {
+    if str.is_null() || val.is_null() {
+        return ptr::null();
+    }
+    match core::str_casestr(bytes(str), bytes(val)) {
+        Some(offset) => str.add(offset),
+        None => ptr::null(),
+    }
+}
+
+/// Extract up to `len` bytes of `str` starting at byte `start` into a new
+/// string. Returns NULL for NULL, negative arguments or a `start` at or
+/// past the end of the string.
+#[no_mangle]
+pub unsafe extern "C" fn xmlStrsub(str: *const xmlChar, start: c_int, len: c_int) -> *mut xmlChar {
+    if str.is_null() || start < 0 || len < 0 {
+        return ptr::null_mut();
+    }
+    match core::str_sub(bytes(str), start as usize, len as usize) {
+        Some(sub) => dup(&[sub]),
+        None => ptr::null_mut(),
+    }
+}
+
+/// Append the first `len` bytes of `add` to `cur`, reallocating it.
+/// Returns the new string, `cur` if there is nothing to add, or NULL for a
+/// negative `len` or a result longer than INT_MAX. `cur` is freed if the
+/// reallocation fails, but not on overflow.
+#[no_mangle]
+pub unsafe extern "C" fn xmlStrncat(cur: *mut xmlChar, add: *const xmlChar, len: c_int) -> *mut xmlChar {
+    if add.is_null() || len == 0 {
+        return cur;
+    }
+    if len < 0 {
+        return ptr::null_mut();
+    }
+    if cur.is_null() {
+        return dup(&[raw(add, len)]);
+    }
+
+    let size = bytes(cur).len();
+    let Some(alloc) = core::concat_alloc_size(size, len as usize) else {
+        return ptr::null_mut();
+    };
+    let Some(realloc) = xmlRealloc else {
+        return ptr::null_mut();
+    };
+    let ret = realloc(cur as *mut _, alloc) as *mut xmlChar;
+    if ret.is_null() {
+        if let Some(free) = xmlFree {
+            free(cur as *mut _);
+        }
+        return ptr::null_mut();
+    }
+    ptr::copy_nonoverlapping(add, ret.add(size), len as usize);
+    *ret.add(size + len as usize) = 0;
+    ret
+}
+
+/// Concatenate `str1` and the first `len` bytes of `str2`, or all of it
+/// if `len` is negative, into a new string. Neither input is freed.
+#[no_mangle]
+pub unsafe extern "C" fn xmlStrncatNew(str1: *const xmlChar, str2: *const xmlChar, len: c_int) -> *mut xmlChar {
+    let len = if len >= 0 {
+        len
+    } else if str2.is_null() {
+        0
+    } else {
+        core::str_len(bytes(str2))
+    };
+    if str1.is_null() {
+        if str2.is_null() {
+            return ptr::null_mut();
+        }
+        return dup(&[raw(str2, len)]);
+    }
+    if str2.is_null() || len == 0 {
+        return dup(&[bytes(str1)]);
+    }
+
+    let first = bytes(str1);
+    if core::concat_alloc_size(first.len(), len as usize).is_none() {
+        return ptr::null_mut();
+    }
+    dup(&[first, raw(str2, len)])
+}
+
+/// a strcat for xmlChar's: append `add` to `cur`, reallocating it. See
+/// xmlStrncat; an `add` longer than INT_MAX gives NULL.
+#[no_mangle]
+pub unsafe extern "C" fn xmlStrcat(cur: *mut xmlChar, add: *const xmlChar) -> *mut xmlChar {
+    if add.is_null() {
+        return cur;
+    }
+    let add_bytes = bytes(add);
+    if cur.is_null() {
+        return dup(&[add_bytes]);
+    }
+    match c_int::try_from(add_bytes.len()) {
+        Ok(len) => xmlStrncat(cur, add, len),
+        Err(_) => ptr::null_mut(),
+    }
+}
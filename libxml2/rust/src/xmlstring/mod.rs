@@ -1,5 +1,15 @@
 //! Rust implementation of xmlstring module
-//! 
-//! String manipulation utilities for xmlChar* and UTF-8 handling
+//!
+//! String manipulation utilities for xmlChar* and UTF-8 handling. The
+//! `xmlChar*` to Rust string conversions in `convert` are always built;
+//! the rest replaces `xmlstring.c` under `rust-xmlstring`.
 
-// Stub implementation - will be filled in during actual porting
\ No newline at end of file
+pub mod convert;
+#[cfg(feature = "rust-xmlstring")]
+pub mod core;
+#[cfg(feature = "rust-xmlstring")]
+pub mod ffi;
+
+pub use convert::*;
+#[cfg(feature = "rust-xmlstring")]
+pub use core::*;
@@ -0,0 +1,1307 @@
+#![allow(clippy::missing_safety_doc)]
+
+use std::os::raw::{c_char, c_int};
+
+use crate::static_bindings::{
+    xmlBuf, xmlCharEncodingHandler, xmlFindCharEncodingHandler, xmlGetUTF8Char, xmlOutputBuffer,
+    xmlOutputBufferClose, xmlOutputBufferFlush, xmlOutputBufferWrite,
+};
+
+extern "C" {
+    // Private helpers from buf.c and encoding.c.
+    fn xmlBufCreate(size: usize) -> *mut xmlBuf;
+    fn xmlCharEncOutput(output: *mut xmlOutputBuffer, init: c_int) -> c_int;
+}
+
+/// Escape attribute content: `\t`, `\n` and `"` in addition to text.
+pub const ESCAPE_ATTR: u32 = 1 << 0;
+/// Replace non-ASCII characters with hex character references.
+pub const ESCAPE_NON_ASCII: u32 = 1 << 1;
+/// Escape `"` in text content.
+pub const ESCAPE_QUOT: u32 = 1 << 3;
+
+/// Escape `text` like `xmlEscapeText`. Control characters which are not
+/// allowed in XML become `&#xFFFD;`. Stops at the first NUL byte.
+pub fn escape_xml(text: &[u8], flags: u32, out: &mut Vec<u8>) {
+    let attr = flags & ESCAPE_ATTR != 0;
+    let quot = flags & (ESCAPE_ATTR | ESCAPE_QUOT) != 0;
+    let mut i = 0;
+
+    while i < text.len() {
+        let c = text[i];
+        match c {
+            0 => break,
+            b'&' => out.extend_from_slice(b"&amp;"),
+            b'<' => out.extend_from_slice(b"&lt;"),
+            b'>' => out.extend_from_slice(b"&gt;"),
+            b'\r' => out.extend_from_slice(b"&#13;"),
+            b'"' if quot => out.extend_from_slice(b"&quot;"),
+            b'\t' if attr => out.extend_from_slice(b"&#9;"),
+            b'\n' if attr => out.extend_from_slice(b"&#10;"),
+            b'\t' | b'\n' => out.push(c),
+            0x01..=0x1f => out.extend_from_slice(b"&#xFFFD;"),
+            0x80.. if flags & ESCAPE_NON_ASCII != 0 => {
+                let rest = &text[i..];
+                let mut len = rest.len().min(4) as c_int;
+                let mut val = unsafe { xmlGetUTF8Char(rest.as_ptr(), &mut len) };
+                if val < 0 {
+                    val = 0xFFFD;
+                    len = 1;
+                } else if val == 0xFFFE || val == 0xFFFF {
+                    val = 0xFFFD;
+                }
+                out.extend_from_slice(format!("&#x{:X};", val).as_bytes());
+                i += len as usize;
+                continue;
+            }
+            _ => out.push(c),
+        }
+        i += 1;
+    }
+}
+
+/// What the writer is in the middle of, kept per open construct.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WriterState {
+    None,
+    Name,
+    Attribute,
+    Text,
+    Comment,
+    Pi,
+    PiText,
+    Cdata,
+    /// After `<!DOCTYPE name`, before any internal subset.
+    Dtd,
+    /// In the internal subset.
+    DtdText,
+    DtdElem,
+    DtdElemText,
+    DtdAttl,
+    DtdAttlText,
+    DtdEnty,
+    DtdEntyText,
+    /// A parameter entity, before its value.
+    DtdPent,
+}
+
+struct StackEntry {
+    name: Vec<u8>,
+    state: WriterState,
+}
+
+/// A streaming XML writer on top of an `xmlOutputBuffer`.
+///
+/// Every method returns the number of bytes written (which may be 0
+/// because of buffering) or -1 in case of error, like the C API.
+pub struct TextWriter {
+    out: *mut xmlOutputBuffer,
+    nodes: Vec<StackEntry>,
+    indent: bool,
+    doindent: bool,
+    ichar: Vec<u8>,
+    qchar: u8,
+    doc_encoding: bool,
+}
+
+impl TextWriter {
+    /// Create a writer taking ownership of `out`.
+    pub fn new(out: *mut xmlOutputBuffer) -> TextWriter {
+        TextWriter {
+            out,
+            nodes: Vec::new(),
+            indent: false,
+            doindent: false,
+            ichar: b" ".to_vec(),
+            qchar: b'"',
+            doc_encoding: false,
+        }
+    }
+
+    fn write(&mut self, data: &[u8]) -> c_int {
+        if self.out.is_null() {
+            return -1;
+        }
+        unsafe {
+            xmlOutputBufferWrite(
+                self.out,
+                data.len() as c_int,
+                data.as_ptr() as *const c_char,
+            )
+        }
+    }
+
+    /// Write one indent string per open ancestor of the current element.
+    fn write_indent(&mut self) -> c_int {
+        let depth = self.nodes.len();
+        if depth < 1 {
+            return -1;
+        }
+        for _ in 1..depth {
+            let ichar = std::mem::take(&mut self.ichar);
+            let ret = self.write(&ichar);
+            self.ichar = ichar;
+            if ret == -1 {
+                return -1;
+            }
+        }
+        (depth - 1) as c_int
+    }
+
+    fn write_quote(&mut self) -> c_int {
+        let q = [self.qchar];
+        self.write(&q)
+    }
+
+    fn top_state(&self) -> Option<WriterState> {
+        self.nodes.last().map(|p| p.state)
+    }
+
+    fn set_top_state(&mut self, state: WriterState) {
+        if let Some(p) = self.nodes.last_mut() {
+            p.state = state;
+        }
+    }
+
+    /// Start a new document with an XML declaration. `encoding` installs an
+    /// output encoder and is written into the declaration.
+    pub unsafe fn start_document(
+        &mut self,
+        version: Option<&[u8]>,
+        encoding: *const c_char,
+        standalone: Option<&[u8]>,
+    ) -> c_int {
+        if self.out.is_null() || !self.nodes.is_empty() {
+            return -1;
+        }
+
+        let mut encoder: *mut xmlCharEncodingHandler = std::ptr::null_mut();
+        if !encoding.is_null() {
+            encoder = xmlFindCharEncodingHandler(encoding);
+            if encoder.is_null() {
+                return -1;
+            }
+        }
+
+        let out = self.out;
+        (*out).encoder = encoder;
+        if !encoder.is_null() {
+            if (*out).conv.is_null() {
+                (*out).conv = xmlBufCreate(4000);
+            }
+            xmlCharEncOutput(out, 1);
+            self.doc_encoding = true;
+        } else {
+            (*out).conv = std::ptr::null_mut();
+        }
+
+        let mut decl = b"<?xml version=".to_vec();
+        decl.push(self.qchar);
+        decl.extend_from_slice(version.unwrap_or(b"1.0"));
+        decl.push(self.qchar);
+        if !encoder.is_null() {
+            decl.extend_from_slice(b" encoding=");
+            decl.push(self.qchar);
+            decl.extend_from_slice(std::ffi::CStr::from_ptr((*encoder).name).to_bytes());
+            decl.push(self.qchar);
+        }
+        if let Some(standalone) = standalone {
+            decl.extend_from_slice(b" standalone=");
+            decl.push(self.qchar);
+            decl.extend_from_slice(standalone);
+            decl.push(self.qchar);
+        }
+        decl.extend_from_slice(b"?>\n");
+
+        self.write(&decl)
+    }
+
+    /// Close all open elements and flush the output.
+    pub fn end_document(&mut self) -> c_int {
+        let mut sum = 0;
+        while let Some(state) = self.top_state() {
+            let count = match state {
+                WriterState::Name | WriterState::Attribute | WriterState::Text => {
+                    self.end_element()
+                }
+                WriterState::Pi | WriterState::PiText => self.end_pi(),
+                WriterState::Cdata => self.end_cdata(),
+                WriterState::Comment => self.end_comment(),
+                WriterState::Dtd
+                | WriterState::DtdText
+                | WriterState::DtdElem
+                | WriterState::DtdElemText
+                | WriterState::DtdAttl
+                | WriterState::DtdAttlText
+                | WriterState::DtdEnty
+                | WriterState::DtdEntyText
+                | WriterState::DtdPent => self.end_dtd(),
+                WriterState::None => break,
+            };
+            if count < 0 {
+                return -1;
+            }
+            sum += count;
+        }
+
+        if !self.indent {
+            let count = self.write(b"\n");
+            if count < 0 {
+                return -1;
+            }
+            sum += count;
+        }
+
+        let count = self.flush();
+        if count < 0 {
+            return -1;
+        }
+        sum + count
+    }
+
+    /// Close the start tag of the current element if it is still open.
+    fn close_start_tag(&mut self) -> c_int {
+        let mut sum = 0;
+        match self.top_state() {
+            Some(WriterState::Attribute) => {
+                let count = self.end_attribute();
+                if count < 0 {
+                    return -1;
+                }
+                sum += count;
+            }
+            Some(WriterState::Name) => {}
+            _ => return 0,
+        }
+        let count = self.write(b">");
+        if count < 0 {
+            return -1;
+        }
+        self.set_top_state(WriterState::Text);
+        sum + count
+    }
+
+    /// Start an element, closing the start tag of the parent if needed.
+    pub fn start_element(&mut self, name: &[u8]) -> c_int {
+        if name.is_empty() {
+            return -1;
+        }
+        if matches!(
+            self.top_state(),
+            Some(WriterState::Pi) | Some(WriterState::PiText)
+        ) {
+            return -1;
+        }
+
+        let parent_open = matches!(
+            self.top_state(),
+            Some(WriterState::Name) | Some(WriterState::Attribute)
+        );
+        let mut sum = self.close_start_tag();
+        if sum < 0 {
+            return -1;
+        }
+        if parent_open && self.indent {
+            self.write(b"\n");
+        }
+
+        self.nodes.push(StackEntry {
+            name: name.to_vec(),
+            state: WriterState::Name,
+        });
+
+        if self.indent {
+            sum += self.write_indent();
+        }
+
+        let mut tag = Vec::with_capacity(name.len() + 1);
+        tag.push(b'<');
+        tag.extend_from_slice(name);
+        let count = self.write(&tag);
+        if count < 0 {
+            return -1;
+        }
+        sum += count;
+        sum
+    }
+
+    /// End the current element, as `<a/>` if nothing was written inside.
+    pub fn end_element(&mut self) -> c_int {
+        let mut sum = 0;
+        let Some(state) = self.top_state() else {
+            return -1;
+        };
+
+        match state {
+            WriterState::Attribute | WriterState::Name => {
+                if state == WriterState::Attribute {
+                    let count = self.end_attribute();
+                    if count < 0 {
+                        return -1;
+                    }
+                    sum += count;
+                }
+                if self.indent {
+                    // The next element needs an indent.
+                    self.doindent = true;
+                }
+                let count = self.write(b"/>");
+                if count < 0 {
+                    return -1;
+                }
+                sum += count;
+            }
+            WriterState::Text => {
+                let count = self.write_end_tag();
+                if count < 0 {
+                    return -1;
+                }
+                sum += count;
+            }
+            _ => return -1,
+        }
+
+        if self.indent {
+            sum += self.write(b"\n");
+        }
+
+        self.nodes.pop();
+        sum
+    }
+
+    /// End the current element, always writing an end tag.
+    pub fn full_end_element(&mut self) -> c_int {
+        let mut sum = 0;
+        let Some(state) = self.top_state() else {
+            return -1;
+        };
+
+        match state {
+            WriterState::Attribute | WriterState::Name => {
+                let count = self.close_start_tag();
+                if count < 0 {
+                    return -1;
+                }
+                sum += count;
+                if self.indent {
+                    self.doindent = false;
+                }
+            }
+            WriterState::Text => {}
+            _ => return -1,
+        }
+        let count = self.write_end_tag();
+        if count < 0 {
+            return -1;
+        }
+        sum += count;
+
+        if self.indent {
+            sum += self.write(b"\n");
+        }
+
+        self.nodes.pop();
+        sum
+    }
+
+    /// Write the end tag of the current element, indented unless text
+    /// was written since the last tag.
+    fn write_end_tag(&mut self) -> c_int {
+        let mut sum = 0;
+        if self.indent && self.doindent {
+            sum += self.write_indent();
+        }
+        self.doindent = true;
+
+        let Some(p) = self.nodes.last() else {
+            return -1;
+        };
+        let mut tag = Vec::with_capacity(p.name.len() + 3);
+        tag.extend_from_slice(b"</");
+        tag.extend_from_slice(&p.name);
+        tag.push(b'>');
+        let count = self.write(&tag);
+        if count < 0 {
+            return -1;
+        }
+        sum + count
+    }
+
+    /// Start an attribute on the current element.
+    pub fn start_attribute(&mut self, name: &[u8]) -> c_int {
+        if name.is_empty() {
+            return -1;
+        }
+
+        let mut sum = 0;
+        match self.top_state() {
+            Some(WriterState::Attribute) => {
+                let count = self.end_attribute();
+                if count < 0 {
+                    return -1;
+                }
+                sum += count;
+            }
+            Some(WriterState::Name) => {}
+            _ => return -1,
+        }
+
+        let mut start = Vec::with_capacity(name.len() + 3);
+        start.push(b' ');
+        start.extend_from_slice(name);
+        start.push(b'=');
+        start.push(self.qchar);
+        let count = self.write(&start);
+        if count < 0 {
+            return -1;
+        }
+        self.set_top_state(WriterState::Attribute);
+        sum + count
+    }
+
+    /// End the current attribute.
+    pub fn end_attribute(&mut self) -> c_int {
+        if self.top_state() != Some(WriterState::Attribute) {
+            return -1;
+        }
+        self.set_top_state(WriterState::Name);
+        self.write_quote()
+    }
+
+    /// Write a complete attribute.
+    pub fn write_attribute(&mut self, name: &[u8], content: &[u8]) -> c_int {
+        let start = self.start_attribute(name);
+        if start < 0 {
+            return -1;
+        }
+        let text = self.write_string(content);
+        if text < 0 {
+            return -1;
+        }
+        let end = self.end_attribute();
+        if end < 0 {
+            return -1;
+        }
+        start + text + end
+    }
+
+    /// Write escaped text. Inside an attribute the attribute escaping
+    /// rules apply. Inside a CDATA section `]]>` is split across two
+    /// sections, and text that would end a comment or processing
+    /// instruction early is an error.
+    pub fn write_string(&mut self, content: &[u8]) -> c_int {
+        let mut buf = Vec::with_capacity(content.len());
+        match self.top_state() {
+            Some(WriterState::Cdata) => {
+                let mut rest = content;
+                while let Some(k) = find(rest, b"]]>") {
+                    buf.extend_from_slice(&rest[..k + 2]);
+                    buf.extend_from_slice(b"]]><![CDATA[");
+                    rest = &rest[k + 2..];
+                }
+                buf.extend_from_slice(rest);
+            }
+            Some(WriterState::Comment) if !is_comment_text(content) => return -1,
+            Some(WriterState::Pi) | Some(WriterState::PiText) if find(content, b"?>").is_some() => {
+                return -1;
+            }
+            Some(WriterState::Name) | Some(WriterState::Text) => {
+                escape_xml(content, ESCAPE_QUOT, &mut buf);
+            }
+            Some(WriterState::Attribute) => {
+                let mut flags = ESCAPE_ATTR;
+                if !self.doc_encoding {
+                    flags |= ESCAPE_NON_ASCII;
+                }
+                escape_xml(content, flags, &mut buf);
+                // Attribute content is written without being counted.
+                return if self.write(&buf) < 0 { -1 } else { 0 };
+            }
+            _ => buf.extend_from_slice(content),
+        }
+        self.write_raw(&buf)
+    }
+
+    /// Write text without escaping.
+    pub fn write_raw(&mut self, content: &[u8]) -> c_int {
+        let mut sum = 0;
+        // An entity value starts with its quote.
+        let value_start = [b' ', self.qchar];
+        let pending: Option<(&[u8], _)> = match self.top_state() {
+            Some(WriterState::Name) => Some((b">", WriterState::Text)),
+            // The space between the target and the content.
+            Some(WriterState::Pi) => Some((b" ", WriterState::PiText)),
+            Some(WriterState::Dtd) => Some((b" [", WriterState::DtdText)),
+            Some(WriterState::DtdElem) => Some((b" ", WriterState::DtdElemText)),
+            Some(WriterState::DtdAttl) => Some((b" ", WriterState::DtdAttlText)),
+            Some(WriterState::DtdEnty) | Some(WriterState::DtdPent) => {
+                Some((&value_start, WriterState::DtdEntyText))
+            }
+            _ => None,
+        };
+        if let Some((extra, state)) = pending {
+            let count = self.write(extra);
+            if count < 0 {
+                return -1;
+            }
+            self.set_top_state(state);
+            sum += count;
+        }
+
+        if self.indent {
+            self.doindent = false;
+        }
+
+        let count = self.write(content);
+        if count < 0 {
+            return -1;
+        }
+        sum + count
+    }
+
+    /// Start a comment, closing the start tag of the current element if
+    /// needed.
+    pub fn start_comment(&mut self) -> c_int {
+        let mut sum = 0;
+        match self.top_state() {
+            None | Some(WriterState::None) | Some(WriterState::Text) => {}
+            Some(WriterState::Name) => {
+                let count = self.write(b">");
+                if count < 0 {
+                    return -1;
+                }
+                sum += count;
+                if self.indent {
+                    let count = self.write(b"\n");
+                    if count < 0 {
+                        return -1;
+                    }
+                    sum += count;
+                }
+                self.set_top_state(WriterState::Text);
+            }
+            _ => return -1,
+        }
+
+        self.nodes.push(StackEntry {
+            name: Vec::new(),
+            state: WriterState::Comment,
+        });
+
+        if self.indent {
+            let count = self.write_indent();
+            if count < 0 {
+                return -1;
+            }
+            sum += count;
+        }
+
+        let count = self.write(b"<!--");
+        if count < 0 {
+            return -1;
+        }
+        sum + count
+    }
+
+    /// End the current comment.
+    pub fn end_comment(&mut self) -> c_int {
+        if self.top_state() != Some(WriterState::Comment) {
+            return -1;
+        }
+        let mut sum = self.write(b"-->");
+        if sum < 0 {
+            return -1;
+        }
+        if self.indent {
+            let count = self.write(b"\n");
+            if count < 0 {
+                return -1;
+            }
+            sum += count;
+        }
+        self.nodes.pop();
+        sum
+    }
+
+    /// Write a complete comment. Content containing `--` or ending with
+    /// `-` is rejected before anything is written.
+    pub fn write_comment(&mut self, content: &[u8]) -> c_int {
+        if !is_comment_text(content) {
+            return -1;
+        }
+        let start = self.start_comment();
+        if start < 0 {
+            return -1;
+        }
+        let text = self.write_string(content);
+        if text < 0 {
+            return -1;
+        }
+        let end = self.end_comment();
+        if end < 0 {
+            return -1;
+        }
+        start + text + end
+    }
+
+    /// Start a processing instruction. Targets named `xml` in any case are
+    /// reserved.
+    pub fn start_pi(&mut self, target: &[u8]) -> c_int {
+        if target.is_empty() || target.eq_ignore_ascii_case(b"xml") {
+            return -1;
+        }
+        match self.top_state() {
+            None
+            | Some(WriterState::None)
+            | Some(WriterState::Text)
+            | Some(WriterState::Dtd)
+            | Some(WriterState::Name)
+            | Some(WriterState::Attribute) => {}
+            _ => return -1,
+        }
+        let mut sum = self.close_start_tag();
+        if sum < 0 {
+            return -1;
+        }
+
+        self.nodes.push(StackEntry {
+            name: target.to_vec(),
+            state: WriterState::Pi,
+        });
+
+        let mut start = Vec::with_capacity(target.len() + 2);
+        start.extend_from_slice(b"<?");
+        start.extend_from_slice(target);
+        let count = self.write(&start);
+        if count < 0 {
+            return -1;
+        }
+        sum += count;
+        sum
+    }
+
+    /// End the current processing instruction. Like C, returns 0 without
+    /// writing anything if nothing is open.
+    pub fn end_pi(&mut self) -> c_int {
+        match self.top_state() {
+            None => return 0,
+            Some(WriterState::Pi) | Some(WriterState::PiText) => {}
+            _ => return -1,
+        }
+        let mut sum = self.write(b"?>");
+        if sum < 0 {
+            return -1;
+        }
+        if self.indent {
+            let count = self.write(b"\n");
+            if count < 0 {
+                return -1;
+            }
+            sum += count;
+        }
+        self.nodes.pop();
+        sum
+    }
+
+    /// Write a complete processing instruction. Content containing `?>` is
+    /// rejected before anything is written.
+    pub fn write_pi(&mut self, target: &[u8], content: Option<&[u8]>) -> c_int {
+        if content.is_some_and(|content| find(content, b"?>").is_some()) {
+            return -1;
+        }
+        let start = self.start_pi(target);
+        if start < 0 {
+            return -1;
+        }
+        let text = match content {
+            Some(content) => self.write_string(content),
+            None => 0,
+        };
+        if text < 0 {
+            return -1;
+        }
+        let end = self.end_pi();
+        if end < 0 {
+            return -1;
+        }
+        start + text + end
+    }
+
+    /// Start a CDATA section. Like C, this is allowed in content, where
+    /// nothing is open and in processing instructions, but not in another
+    /// section, a comment or a DTD.
+    pub fn start_cdata(&mut self) -> c_int {
+        match self.top_state() {
+            None
+            | Some(WriterState::None)
+            | Some(WriterState::Text)
+            | Some(WriterState::Pi)
+            | Some(WriterState::PiText)
+            | Some(WriterState::Name)
+            | Some(WriterState::Attribute) => {}
+            _ => return -1,
+        }
+        let mut sum = self.close_start_tag();
+        if sum < 0 {
+            return -1;
+        }
+
+        self.nodes.push(StackEntry {
+            name: Vec::new(),
+            state: WriterState::Cdata,
+        });
+
+        let count = self.write(b"<![CDATA[");
+        if count < 0 {
+            return -1;
+        }
+        sum += count;
+        sum
+    }
+
+    /// End the current CDATA section.
+    pub fn end_cdata(&mut self) -> c_int {
+        if self.top_state() != Some(WriterState::Cdata) {
+            return -1;
+        }
+        let count = self.write(b"]]>");
+        if count < 0 {
+            return -1;
+        }
+        self.nodes.pop();
+        count
+    }
+
+    /// Write a complete CDATA section, split in several where `content`
+    /// contains `]]>`.
+    pub fn write_cdata(&mut self, content: Option<&[u8]>) -> c_int {
+        let start = self.start_cdata();
+        if start < 0 {
+            return -1;
+        }
+        let text = match content {
+            Some(content) => self.write_string(content),
+            None => 0,
+        };
+        if text < 0 {
+            return -1;
+        }
+        let end = self.end_cdata();
+        if end < 0 {
+            return -1;
+        }
+        start + text + end
+    }
+
+    /// Start a document type declaration. Only allowed before anything
+    /// else is open. A public identifier needs a system identifier.
+    pub fn start_dtd(&mut self, name: &[u8], pubid: Option<&[u8]>, sysid: Option<&[u8]>) -> c_int {
+        if name.is_empty() || !self.nodes.is_empty() {
+            return -1;
+        }
+        self.nodes.push(StackEntry {
+            name: name.to_vec(),
+            state: WriterState::Dtd,
+        });
+
+        let mut start = b"<!DOCTYPE ".to_vec();
+        start.extend_from_slice(name);
+        let mut sum = self.write(&start);
+        if sum < 0 {
+            return -1;
+        }
+
+        let sep: &[u8] = if self.indent { b"\n" } else { b" " };
+        let mut ids = Vec::new();
+        if let Some(pubid) = pubid {
+            if sysid.is_none() {
+                return -1;
+            }
+            ids.extend_from_slice(sep);
+            ids.extend_from_slice(b"PUBLIC ");
+            self.push_quoted(&mut ids, pubid);
+        }
+        if let Some(sysid) = sysid {
+            if pubid.is_none() {
+                ids.extend_from_slice(sep);
+                ids.extend_from_slice(b"SYSTEM ");
+            } else if self.indent {
+                ids.extend_from_slice(b"\n       ");
+            } else {
+                ids.push(b' ');
+            }
+            self.push_quoted(&mut ids, sysid);
+        }
+        let count = self.write(&ids);
+        if count < 0 {
+            return -1;
+        }
+        sum += count;
+        sum
+    }
+
+    /// End the document type declaration, closing any declaration or
+    /// comment still open in it.
+    pub fn end_dtd(&mut self) -> c_int {
+        let mut sum = 0;
+        while let Some(state) = self.top_state() {
+            let count = match state {
+                WriterState::Dtd | WriterState::DtdText => {
+                    let end: &[u8] = match (state == WriterState::DtdText, self.indent) {
+                        (true, true) => b"]>\n",
+                        (true, false) => b"]>",
+                        (false, true) => b">\n",
+                        (false, false) => b">",
+                    };
+                    let count = self.write(end);
+                    self.nodes.pop();
+                    count
+                }
+                WriterState::DtdElem | WriterState::DtdElemText => self.end_dtd_element(),
+                WriterState::DtdAttl | WriterState::DtdAttlText => self.end_dtd_attlist(),
+                WriterState::DtdEnty | WriterState::DtdEntyText | WriterState::DtdPent => {
+                    self.end_dtd_entity()
+                }
+                WriterState::Comment => self.end_comment(),
+                _ => break,
+            };
+            if count < 0 {
+                return -1;
+            }
+            sum += count;
+        }
+        sum
+    }
+
+    /// Write a complete document type declaration, with `subset` as its
+    /// internal subset.
+    pub fn write_dtd(
+        &mut self,
+        name: &[u8],
+        pubid: Option<&[u8]>,
+        sysid: Option<&[u8]>,
+        subset: Option<&[u8]>,
+    ) -> c_int {
+        let start = self.start_dtd(name, pubid, sysid);
+        if start < 0 {
+            return -1;
+        }
+        let text = match subset {
+            Some(subset) => self.write_string(subset),
+            None => 0,
+        };
+        if text < 0 {
+            return -1;
+        }
+        let end = self.end_dtd();
+        if end < 0 {
+            return -1;
+        }
+        start + text + end
+    }
+
+    /// Write `" ["` to start the internal subset if the DTD has none yet.
+    fn enter_internal_subset(&mut self) -> c_int {
+        if self.top_state() != Some(WriterState::Dtd) {
+            return 0;
+        }
+        let start: &[u8] = if self.indent { b" [\n" } else { b" [" };
+        let count = self.write(start);
+        if count < 0 {
+            return -1;
+        }
+        self.set_top_state(WriterState::DtdText);
+        count
+    }
+
+    /// Push a markup declaration and write its keyword and name, indented.
+    fn start_declaration(&mut self, keyword: &[u8], name: &[u8], state: WriterState) -> c_int {
+        let mut sum = self.enter_internal_subset();
+        if sum < 0 {
+            return -1;
+        }
+        self.nodes.push(StackEntry {
+            name: name.to_vec(),
+            state,
+        });
+        if self.indent {
+            let count = self.write_indent();
+            if count < 0 {
+                return -1;
+            }
+            sum += count;
+        }
+
+        let mut start = keyword.to_vec();
+        start.extend_from_slice(name);
+        let count = self.write(&start);
+        if count < 0 {
+            return -1;
+        }
+        sum + count
+    }
+
+    /// Close the declaration on top if it is in `states`, with `end`.
+    fn end_declaration(&mut self, states: &[WriterState], end: &[u8]) -> c_int {
+        if !self
+            .top_state()
+            .is_some_and(|state| states.contains(&state))
+        {
+            return -1;
+        }
+        let mut sum = self.write(end);
+        if sum < 0 {
+            return -1;
+        }
+        if self.indent {
+            let count = self.write(b"\n");
+            if count < 0 {
+                return -1;
+            }
+            sum += count;
+        }
+        self.nodes.pop();
+        sum
+    }
+
+    /// Start an element type declaration, in the DTD.
+    pub fn start_dtd_element(&mut self, name: &[u8]) -> c_int {
+        if name.is_empty() {
+            return -1;
+        }
+        match self.top_state() {
+            Some(WriterState::Dtd) | Some(WriterState::DtdText) | Some(WriterState::None) => {}
+            _ => return -1,
+        }
+        self.start_declaration(b"<!ELEMENT ", name, WriterState::DtdElem)
+    }
+
+    /// End the current element type declaration.
+    pub fn end_dtd_element(&mut self) -> c_int {
+        self.end_declaration(&[WriterState::DtdElem, WriterState::DtdElemText], b">")
+    }
+
+    /// Write a complete element type declaration with `content` as its
+    /// content model.
+    pub fn write_dtd_element(&mut self, name: &[u8], content: &[u8]) -> c_int {
+        let start = self.start_dtd_element(name);
+        if start < 0 {
+            return -1;
+        }
+        let text = self.write_string(content);
+        if text < 0 {
+            return -1;
+        }
+        let end = self.end_dtd_element();
+        if end < 0 {
+            return -1;
+        }
+        start + text + end
+    }
+
+    /// Start an attribute list declaration, in the DTD.
+    pub fn start_dtd_attlist(&mut self, name: &[u8]) -> c_int {
+        if name.is_empty() {
+            return -1;
+        }
+        match self.top_state() {
+            Some(WriterState::Dtd) | Some(WriterState::DtdText) | Some(WriterState::None) => {}
+            _ => return -1,
+        }
+        self.start_declaration(b"<!ATTLIST ", name, WriterState::DtdAttl)
+    }
+
+    /// End the current attribute list declaration.
+    pub fn end_dtd_attlist(&mut self) -> c_int {
+        self.end_declaration(&[WriterState::DtdAttl, WriterState::DtdAttlText], b">")
+    }
+
+    /// Write a complete attribute list declaration with `content` as its
+    /// attribute definitions.
+    pub fn write_dtd_attlist(&mut self, name: &[u8], content: &[u8]) -> c_int {
+        let start = self.start_dtd_attlist(name);
+        if start < 0 {
+            return -1;
+        }
+        let text = self.write_string(content);
+        if text < 0 {
+            return -1;
+        }
+        let end = self.end_dtd_attlist();
+        if end < 0 {
+            return -1;
+        }
+        start + text + end
+    }
+
+    /// Start an entity declaration, a parameter entity if `pe`. Like C,
+    /// this is also allowed where nothing is open.
+    pub fn start_dtd_entity(&mut self, pe: bool, name: &[u8]) -> c_int {
+        if name.is_empty() {
+            return -1;
+        }
+        match self.top_state() {
+            None
+            | Some(WriterState::Dtd)
+            | Some(WriterState::DtdText)
+            | Some(WriterState::None) => {}
+            _ => return -1,
+        }
+        if pe {
+            self.start_declaration(b"<!ENTITY % ", name, WriterState::DtdPent)
+        } else {
+            self.start_declaration(b"<!ENTITY ", name, WriterState::DtdEnty)
+        }
+    }
+
+    /// End the current entity declaration, closing the quoted value if
+    /// one was written.
+    pub fn end_dtd_entity(&mut self) -> c_int {
+        if self.top_state() == Some(WriterState::DtdEntyText) {
+            let end = [self.qchar, b'>'];
+            return self.end_declaration(&[WriterState::DtdEntyText], &end);
+        }
+        self.end_declaration(&[WriterState::DtdEnty, WriterState::DtdPent], b">")
+    }
+
+    /// Write a complete entity declaration: an internal entity with
+    /// `content` as its value if there is no `pubid` or `sysid`, an
+    /// external one otherwise. Parameter entities can't have an `ndataid`.
+    pub fn write_dtd_entity(
+        &mut self,
+        pe: bool,
+        name: &[u8],
+        pubid: Option<&[u8]>,
+        sysid: Option<&[u8]>,
+        ndataid: Option<&[u8]>,
+        content: Option<&[u8]>,
+    ) -> c_int {
+        if pe && ndataid.is_some() {
+            return -1;
+        }
+        match (pubid, sysid, content) {
+            (None, None, Some(content)) => self.write_dtd_internal_entity(pe, name, content),
+            (None, None, None) => -1,
+            _ => self.write_dtd_external_entity(pe, name, pubid, sysid, ndataid),
+        }
+    }
+
+    /// Write a complete internal entity declaration.
+    pub fn write_dtd_internal_entity(&mut self, pe: bool, name: &[u8], content: &[u8]) -> c_int {
+        let start = self.start_dtd_entity(pe, name);
+        if start < 0 {
+            return -1;
+        }
+        let text = self.write_string(content);
+        if text < 0 {
+            return -1;
+        }
+        let end = self.end_dtd_entity();
+        if end < 0 {
+            return -1;
+        }
+        start + text + end
+    }
+
+    /// Write a complete external entity declaration.
+    pub fn write_dtd_external_entity(
+        &mut self,
+        pe: bool,
+        name: &[u8],
+        pubid: Option<&[u8]>,
+        sysid: Option<&[u8]>,
+        ndataid: Option<&[u8]>,
+    ) -> c_int {
+        if (pubid.is_none() && sysid.is_none()) || (pe && ndataid.is_some()) {
+            return -1;
+        }
+        let start = self.start_dtd_entity(pe, name);
+        if start < 0 {
+            return -1;
+        }
+        let ids = self.write_dtd_external_entity_contents(pubid, sysid, ndataid);
+        if ids < 0 {
+            return -1;
+        }
+        let end = self.end_dtd_entity();
+        if end < 0 {
+            return -1;
+        }
+        start + ids + end
+    }
+
+    /// Write the external identifiers and notation of the entity
+    /// declaration just started. A public identifier needs a system
+    /// identifier.
+    pub fn write_dtd_external_entity_contents(
+        &mut self,
+        pubid: Option<&[u8]>,
+        sysid: Option<&[u8]>,
+        ndataid: Option<&[u8]>,
+    ) -> c_int {
+        match self.top_state() {
+            Some(WriterState::DtdEnty) => {}
+            Some(WriterState::DtdPent) if ndataid.is_none() => {}
+            _ => return -1,
+        }
+        if pubid.is_some() && sysid.is_none() {
+            return -1;
+        }
+        let mut ids = self.external_id(pubid, sysid);
+        if let Some(ndataid) = ndataid {
+            ids.extend_from_slice(b" NDATA ");
+            ids.extend_from_slice(ndataid);
+        }
+        self.write(&ids)
+    }
+
+    /// Write a notation declaration, in the internal subset.
+    pub fn write_dtd_notation(
+        &mut self,
+        name: &[u8],
+        pubid: Option<&[u8]>,
+        sysid: Option<&[u8]>,
+    ) -> c_int {
+        if name.is_empty() {
+            return -1;
+        }
+        match self.top_state() {
+            Some(WriterState::Dtd) | Some(WriterState::DtdText) => {}
+            _ => return -1,
+        }
+        let mut sum = self.enter_internal_subset();
+        if sum < 0 {
+            return -1;
+        }
+        if self.indent {
+            let count = self.write_indent();
+            if count < 0 {
+                return -1;
+            }
+            sum += count;
+        }
+
+        let mut decl = b"<!NOTATION ".to_vec();
+        decl.extend_from_slice(name);
+        decl.extend_from_slice(&self.external_id(pubid, sysid));
+        decl.push(b'>');
+        let count = self.write(&decl);
+        if count < 0 {
+            return -1;
+        }
+        sum + count
+    }
+
+    /// ` PUBLIC "pubid"` and ` "sysid"`, or ` SYSTEM "sysid"` without
+    /// `pubid`.
+    fn external_id(&self, pubid: Option<&[u8]>, sysid: Option<&[u8]>) -> Vec<u8> {
+        let mut ids = Vec::new();
+        if let Some(pubid) = pubid {
+            ids.extend_from_slice(b" PUBLIC ");
+            self.push_quoted(&mut ids, pubid);
+        }
+        if let Some(sysid) = sysid {
+            if pubid.is_none() {
+                ids.extend_from_slice(b" SYSTEM");
+            }
+            ids.push(b' ');
+            self.push_quoted(&mut ids, sysid);
+        }
+        ids
+    }
+
+    fn push_quoted(&self, out: &mut Vec<u8>, value: &[u8]) {
+        out.push(self.qchar);
+        out.extend_from_slice(value);
+        out.push(self.qchar);
+    }
+
+    /// Enable or disable indentation of nested elements.
+    pub fn set_indent(&mut self, indent: c_int) -> c_int {
+        if indent < 0 {
+            return -1;
+        }
+        self.indent = indent != 0;
+        self.doindent = true;
+        0
+    }
+
+    /// Set the string written once per nesting level when indenting.
+    pub fn set_indent_string(&mut self, ichar: &[u8]) {
+        self.ichar = ichar.to_vec();
+    }
+
+    /// Flush the output buffer.
+    pub fn flush(&mut self) -> c_int {
+        if self.out.is_null() {
+            return 0;
+        }
+        unsafe { xmlOutputBufferFlush(self.out) }
+    }
+}
+
+/// Position of the first occurrence of `needle` in `haystack`.
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack.windows(needle.len()).position(|w| w == needle)
+}
+
+/// Whether `content` can be the text of a comment: no `--` and no `-` at
+/// the end, which would run into the closing `-->`.
+fn is_comment_text(content: &[u8]) -> bool {
+    find(content, b"--").is_none() && content.last() != Some(&b'-')
+}
+
+impl Drop for TextWriter {
+    fn drop(&mut self) {
+        if !self.out.is_null() {
+            unsafe { xmlOutputBufferClose(self.out) };
+            self.out = std::ptr::null_mut();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn escaped(text: &[u8], flags: u32) -> String {
+        let mut out = Vec::new();
+        escape_xml(text, flags, &mut out);
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_escape_text() {
+        assert_eq!(escaped(b"a<b>&c", 0), "a&lt;b&gt;&amp;c");
+        assert_eq!(escaped(b"\"q\"\r\n\t", 0), "\"q\"&#13;\n\t");
+        assert_eq!(escaped(b"\"q\"", ESCAPE_QUOT), "&quot;q&quot;");
+        assert_eq!(escaped(b"a\x01b", 0), "a&#xFFFD;b");
+        assert_eq!(escaped(b"ab\0cd", 0), "ab");
+    }
+
+    #[test]
+    fn test_escape_attr() {
+        assert_eq!(
+            escaped(b"\"\t\n\r<", ESCAPE_ATTR),
+            "&quot;&#9;&#10;&#13;&lt;"
+        );
+        assert_eq!(escaped("é€".as_bytes(), ESCAPE_ATTR), "é€");
+        assert_eq!(
+            escaped("é€".as_bytes(), ESCAPE_ATTR | ESCAPE_NON_ASCII),
+            "&#xE9;&#x20AC;"
+        );
+        assert_eq!(
+            escaped(b"a\xffb", ESCAPE_ATTR | ESCAPE_NON_ASCII),
+            "a&#xFFFD;b"
+        );
+    }
+
+    #[test]
+    fn test_comment_text() {
+        assert!(is_comment_text(b""));
+        assert!(is_comment_text(b" a - b "));
+        assert!(is_comment_text(b"-a"));
+        assert!(!is_comment_text(b"a--b"));
+        assert!(!is_comment_text(b"a-"));
+    }
+}
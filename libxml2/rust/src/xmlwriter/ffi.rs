@@ -0,0 +1,517 @@
+#![allow(clippy::missing_safety_doc)]
+
+use std::ffi::CStr;
+use std::os::raw::{c_char, c_int};
+use std::ptr;
+
+use super::core::TextWriter;
+use crate::static_bindings::{
+    xmlBuffer, xmlChar, xmlOutputBuffer, xmlOutputBufferClose, xmlOutputBufferCreateBuffer,
+    xmlTextWriter,
+};
+
+// Rust-only, never `#[no_mangle]`: the C functions not ported here, such as
+// the variadic format writers, would take the boxed `TextWriter` for their
+// own struct, so xmlwriter.c stays linked whole for C callers.
+
+unsafe fn as_writer<'a>(writer: *mut xmlTextWriter) -> Option<&'a mut TextWriter> {
+    (writer as *mut TextWriter).as_mut()
+}
+
+unsafe fn bytes<'a, T>(s: *const T) -> Option<&'a [u8]> {
+    if s.is_null() {
+        None
+    } else {
+        Some(CStr::from_ptr(s as *const c_char).to_bytes())
+    }
+}
+
+/// Create a new xmlTextWriter structure using an xmlOutputBuffer. The
+/// buffer is closed when the writer is freed.
+pub unsafe extern "C" fn xmlNewTextWriter(out: *mut xmlOutputBuffer) -> *mut xmlTextWriter {
+    Box::into_raw(Box::new(TextWriter::new(out))) as *mut xmlTextWriter
+}
+
+/// Create a new xmlTextWriter structure with `buf` as output.
+pub unsafe extern "C" fn xmlNewTextWriterMemory(
+    buf: *mut xmlBuffer,
+    _compression: c_int,
+) -> *mut xmlTextWriter {
+    let out = xmlOutputBufferCreateBuffer(buf, ptr::null_mut());
+    if out.is_null() {
+        return ptr::null_mut();
+    }
+    let ret = xmlNewTextWriter(out);
+    if ret.is_null() {
+        xmlOutputBufferClose(out);
+    }
+    ret
+}
+
+/// Deallocate all the resources associated to the writer.
+pub unsafe extern "C" fn xmlFreeTextWriter(writer: *mut xmlTextWriter) {
+    if !writer.is_null() {
+        drop(Box::from_raw(writer as *mut TextWriter));
+    }
+}
+
+/// Start a new xml document.
+pub unsafe extern "C" fn xmlTextWriterStartDocument(
+    writer: *mut xmlTextWriter,
+    version: *const c_char,
+    encoding: *const c_char,
+    standalone: *const c_char,
+) -> c_int {
+    match as_writer(writer) {
+        Some(writer) => writer.start_document(bytes(version), encoding, bytes(standalone)),
+        None => -1,
+    }
+}
+
+/// End an xml document. All open elements are closed, and the content is
+/// flushed to the output.
+pub unsafe extern "C" fn xmlTextWriterEndDocument(writer: *mut xmlTextWriter) -> c_int {
+    match as_writer(writer) {
+        Some(writer) => writer.end_document(),
+        None => -1,
+    }
+}
+
+/// Start an xml element.
+pub unsafe extern "C" fn xmlTextWriterStartElement(
+    writer: *mut xmlTextWriter,
+    name: *const xmlChar,
+) -> c_int {
+    match (as_writer(writer), bytes(name)) {
+        (Some(writer), Some(name)) => writer.start_element(name),
+        _ => -1,
+    }
+}
+
+/// End the current xml element.
+pub unsafe extern "C" fn xmlTextWriterEndElement(writer: *mut xmlTextWriter) -> c_int {
+    match as_writer(writer) {
+        Some(writer) => writer.end_element(),
+        None => -1,
+    }
+}
+
+/// End the current xml element. Writes an end tag even if the element is
+/// empty.
+pub unsafe extern "C" fn xmlTextWriterFullEndElement(writer: *mut xmlTextWriter) -> c_int {
+    match as_writer(writer) {
+        Some(writer) => writer.full_end_element(),
+        None => -1,
+    }
+}
+
+/// Start an xml attribute.
+pub unsafe extern "C" fn xmlTextWriterStartAttribute(
+    writer: *mut xmlTextWriter,
+    name: *const xmlChar,
+) -> c_int {
+    match (as_writer(writer), bytes(name)) {
+        (Some(writer), Some(name)) => writer.start_attribute(name),
+        _ => -1,
+    }
+}
+
+/// End the current xml attribute.
+pub unsafe extern "C" fn xmlTextWriterEndAttribute(writer: *mut xmlTextWriter) -> c_int {
+    match as_writer(writer) {
+        Some(writer) => writer.end_attribute(),
+        None => -1,
+    }
+}
+
+/// Write an xml attribute.
+pub unsafe extern "C" fn xmlTextWriterWriteAttribute(
+    writer: *mut xmlTextWriter,
+    name: *const xmlChar,
+    content: *const xmlChar,
+) -> c_int {
+    let Some(writer) = as_writer(writer) else {
+        return -1;
+    };
+    match (bytes(name), bytes(content)) {
+        (Some(name), Some(content)) => writer.write_attribute(name, content),
+        (Some(name), None) => {
+            // The attribute is started before the missing content is noticed.
+            writer.start_attribute(name);
+            -1
+        }
+        _ => -1,
+    }
+}
+
+/// Write an xml text.
+pub unsafe extern "C" fn xmlTextWriterWriteString(
+    writer: *mut xmlTextWriter,
+    content: *const xmlChar,
+) -> c_int {
+    match (as_writer(writer), bytes(content)) {
+        (Some(writer), Some(content)) => writer.write_string(content),
+        _ => -1,
+    }
+}
+
+/// Write an xml text without escaping.
+pub unsafe extern "C" fn xmlTextWriterWriteRawLen(
+    writer: *mut xmlTextWriter,
+    content: *const xmlChar,
+    len: c_int,
+) -> c_int {
+    let Some(writer) = as_writer(writer) else {
+        return -1;
+    };
+    if content.is_null() || len < 0 {
+        return -1;
+    }
+    writer.write_raw(std::slice::from_raw_parts(content, len as usize))
+}
+
+/// Write a raw xml text.
+pub unsafe extern "C" fn xmlTextWriterWriteRaw(
+    writer: *mut xmlTextWriter,
+    content: *const xmlChar,
+) -> c_int {
+    match (as_writer(writer), bytes(content)) {
+        (Some(writer), Some(content)) => writer.write_raw(content),
+        _ => -1,
+    }
+}
+
+/// Start an xml comment.
+pub unsafe extern "C" fn xmlTextWriterStartComment(writer: *mut xmlTextWriter) -> c_int {
+    match as_writer(writer) {
+        Some(writer) => writer.start_comment(),
+        None => -1,
+    }
+}
+
+/// End the current xml comment.
+pub unsafe extern "C" fn xmlTextWriterEndComment(writer: *mut xmlTextWriter) -> c_int {
+    match as_writer(writer) {
+        Some(writer) => writer.end_comment(),
+        None => -1,
+    }
+}
+
+/// Write an xml comment. Content containing `--` or ending with `-` is an
+/// error.
+pub unsafe extern "C" fn xmlTextWriterWriteComment(
+    writer: *mut xmlTextWriter,
+    content: *const xmlChar,
+) -> c_int {
+    let Some(writer) = as_writer(writer) else {
+        return -1;
+    };
+    match bytes(content) {
+        Some(content) => writer.write_comment(content),
+        None => {
+            // The comment is started before the missing content is noticed.
+            writer.start_comment();
+            -1
+        }
+    }
+}
+
+/// Start an xml PI.
+pub unsafe extern "C" fn xmlTextWriterStartPI(
+    writer: *mut xmlTextWriter,
+    target: *const xmlChar,
+) -> c_int {
+    match (as_writer(writer), bytes(target)) {
+        (Some(writer), Some(target)) => writer.start_pi(target),
+        _ => -1,
+    }
+}
+
+/// End the current xml PI.
+pub unsafe extern "C" fn xmlTextWriterEndPI(writer: *mut xmlTextWriter) -> c_int {
+    match as_writer(writer) {
+        Some(writer) => writer.end_pi(),
+        None => -1,
+    }
+}
+
+/// Write an xml PI. Content containing `?>` is an error.
+pub unsafe extern "C" fn xmlTextWriterWritePI(
+    writer: *mut xmlTextWriter,
+    target: *const xmlChar,
+    content: *const xmlChar,
+) -> c_int {
+    match (as_writer(writer), bytes(target)) {
+        (Some(writer), Some(target)) => writer.write_pi(target, bytes(content)),
+        _ => -1,
+    }
+}
+
+/// Start an xml CDATA section.
+pub unsafe extern "C" fn xmlTextWriterStartCDATA(writer: *mut xmlTextWriter) -> c_int {
+    match as_writer(writer) {
+        Some(writer) => writer.start_cdata(),
+        None => -1,
+    }
+}
+
+/// End the current xml CDATA section.
+pub unsafe extern "C" fn xmlTextWriterEndCDATA(writer: *mut xmlTextWriter) -> c_int {
+    match as_writer(writer) {
+        Some(writer) => writer.end_cdata(),
+        None => -1,
+    }
+}
+
+/// Write an xml CDATA section. `]]>` in the content ends one section and
+/// starts another.
+pub unsafe extern "C" fn xmlTextWriterWriteCDATA(
+    writer: *mut xmlTextWriter,
+    content: *const xmlChar,
+) -> c_int {
+    match as_writer(writer) {
+        Some(writer) => writer.write_cdata(bytes(content)),
+        None => -1,
+    }
+}
+
+/// Start an xml DTD.
+pub unsafe extern "C" fn xmlTextWriterStartDTD(
+    writer: *mut xmlTextWriter,
+    name: *const xmlChar,
+    pubid: *const xmlChar,
+    sysid: *const xmlChar,
+) -> c_int {
+    match (as_writer(writer), bytes(name)) {
+        (Some(writer), Some(name)) => writer.start_dtd(name, bytes(pubid), bytes(sysid)),
+        _ => -1,
+    }
+}
+
+/// End an xml DTD.
+pub unsafe extern "C" fn xmlTextWriterEndDTD(writer: *mut xmlTextWriter) -> c_int {
+    match as_writer(writer) {
+        Some(writer) => writer.end_dtd(),
+        None => -1,
+    }
+}
+
+/// Write a DTD.
+pub unsafe extern "C" fn xmlTextWriterWriteDTD(
+    writer: *mut xmlTextWriter,
+    name: *const xmlChar,
+    pubid: *const xmlChar,
+    sysid: *const xmlChar,
+    subset: *const xmlChar,
+) -> c_int {
+    match (as_writer(writer), bytes(name)) {
+        (Some(writer), Some(name)) => {
+            writer.write_dtd(name, bytes(pubid), bytes(sysid), bytes(subset))
+        }
+        _ => -1,
+    }
+}
+
+/// Start an xml DTD element.
+pub unsafe extern "C" fn xmlTextWriterStartDTDElement(
+    writer: *mut xmlTextWriter,
+    name: *const xmlChar,
+) -> c_int {
+    match (as_writer(writer), bytes(name)) {
+        (Some(writer), Some(name)) => writer.start_dtd_element(name),
+        _ => -1,
+    }
+}
+
+/// End an xml DTD element.
+pub unsafe extern "C" fn xmlTextWriterEndDTDElement(writer: *mut xmlTextWriter) -> c_int {
+    match as_writer(writer) {
+        Some(writer) => writer.end_dtd_element(),
+        None => -1,
+    }
+}
+
+/// Write a DTD element.
+pub unsafe extern "C" fn xmlTextWriterWriteDTDElement(
+    writer: *mut xmlTextWriter,
+    name: *const xmlChar,
+    content: *const xmlChar,
+) -> c_int {
+    match (as_writer(writer), bytes(name), bytes(content)) {
+        (Some(writer), Some(name), Some(content)) => writer.write_dtd_element(name, content),
+        _ => -1,
+    }
+}
+
+/// Start an xml DTD ATTLIST.
+pub unsafe extern "C" fn xmlTextWriterStartDTDAttlist(
+    writer: *mut xmlTextWriter,
+    name: *const xmlChar,
+) -> c_int {
+    match (as_writer(writer), bytes(name)) {
+        (Some(writer), Some(name)) => writer.start_dtd_attlist(name),
+        _ => -1,
+    }
+}
+
+/// End an xml DTD attribute list.
+pub unsafe extern "C" fn xmlTextWriterEndDTDAttlist(writer: *mut xmlTextWriter) -> c_int {
+    match as_writer(writer) {
+        Some(writer) => writer.end_dtd_attlist(),
+        None => -1,
+    }
+}
+
+/// Write a DTD ATTLIST.
+pub unsafe extern "C" fn xmlTextWriterWriteDTDAttlist(
+    writer: *mut xmlTextWriter,
+    name: *const xmlChar,
+    content: *const xmlChar,
+) -> c_int {
+    match (as_writer(writer), bytes(name), bytes(content)) {
+        (Some(writer), Some(name), Some(content)) => writer.write_dtd_attlist(name, content),
+        _ => -1,
+    }
+}
+
+/// Start an xml DTD entity, a parameter entity if `pe` is not 0.
+pub unsafe extern "C" fn xmlTextWriterStartDTDEntity(
+    writer: *mut xmlTextWriter,
+    pe: c_int,
+    name: *const xmlChar,
+) -> c_int {
+    match (as_writer(writer), bytes(name)) {
+        (Some(writer), Some(name)) => writer.start_dtd_entity(pe != 0, name),
+        _ => -1,
+    }
+}
+
+/// End an xml DTD entity.
+pub unsafe extern "C" fn xmlTextWriterEndDTDEntity(writer: *mut xmlTextWriter) -> c_int {
+    match as_writer(writer) {
+        Some(writer) => writer.end_dtd_entity(),
+        None => -1,
+    }
+}
+
+/// Write a DTD entity, internal if there is neither `pubid` nor `sysid`.
+pub unsafe extern "C" fn xmlTextWriterWriteDTDEntity(
+    writer: *mut xmlTextWriter,
+    pe: c_int,
+    name: *const xmlChar,
+    pubid: *const xmlChar,
+    sysid: *const xmlChar,
+    ndataid: *const xmlChar,
+    content: *const xmlChar,
+) -> c_int {
+    match (as_writer(writer), bytes(name)) {
+        (Some(writer), Some(name)) => writer.write_dtd_entity(
+            pe != 0,
+            name,
+            bytes(pubid),
+            bytes(sysid),
+            bytes(ndataid),
+            bytes(content),
+        ),
+        _ => -1,
+    }
+}
+
+/// Write a DTD internal entity.
+pub unsafe extern "C" fn xmlTextWriterWriteDTDInternalEntity(
+    writer: *mut xmlTextWriter,
+    pe: c_int,
+    name: *const xmlChar,
+    content: *const xmlChar,
+) -> c_int {
+    match (as_writer(writer), bytes(name), bytes(content)) {
+        (Some(writer), Some(name), Some(content)) => {
+            writer.write_dtd_internal_entity(pe != 0, name, content)
+        }
+        _ => -1,
+    }
+}
+
+/// Write a DTD external entity.
+pub unsafe extern "C" fn xmlTextWriterWriteDTDExternalEntity(
+    writer: *mut xmlTextWriter,
+    pe: c_int,
+    name: *const xmlChar,
+    pubid: *const xmlChar,
+    sysid: *const xmlChar,
+    ndataid: *const xmlChar,
+) -> c_int {
+    match (as_writer(writer), bytes(name)) {
+        (Some(writer), Some(name)) => writer.write_dtd_external_entity(
+            pe != 0,
+            name,
+            bytes(pubid),
+            bytes(sysid),
+            bytes(ndataid),
+        ),
+        _ => -1,
+    }
+}
+
+/// Write the contents of a DTD external entity, after
+/// `xmlTextWriterStartDTDEntity`.
+pub unsafe extern "C" fn xmlTextWriterWriteDTDExternalEntityContents(
+    writer: *mut xmlTextWriter,
+    pubid: *const xmlChar,
+    sysid: *const xmlChar,
+    ndataid: *const xmlChar,
+) -> c_int {
+    match as_writer(writer) {
+        Some(writer) => {
+            writer.write_dtd_external_entity_contents(bytes(pubid), bytes(sysid), bytes(ndataid))
+        }
+        None => -1,
+    }
+}
+
+/// Write a DTD notation.
+pub unsafe extern "C" fn xmlTextWriterWriteDTDNotation(
+    writer: *mut xmlTextWriter,
+    name: *const xmlChar,
+    pubid: *const xmlChar,
+    sysid: *const xmlChar,
+) -> c_int {
+    match (as_writer(writer), bytes(name)) {
+        (Some(writer), Some(name)) => writer.write_dtd_notation(name, bytes(pubid), bytes(sysid)),
+        _ => -1,
+    }
+}
+
+/// Set indentation output. indent = 0 do not indentation. indent > 0 do
+/// indentation.
+pub unsafe extern "C" fn xmlTextWriterSetIndent(
+    writer: *mut xmlTextWriter,
+    indent: c_int,
+) -> c_int {
+    match as_writer(writer) {
+        Some(writer) => writer.set_indent(indent),
+        None => -1,
+    }
+}
+
+/// Set string indentation.
+pub unsafe extern "C" fn xmlTextWriterSetIndentString(
+    writer: *mut xmlTextWriter,
+    str: *const xmlChar,
+) -> c_int {
+    match (as_writer(writer), bytes(str)) {
+        (Some(writer), Some(ichar)) => {
+            writer.set_indent_string(ichar);
+            0
+        }
+        _ => -1,
+    }
+}
+
+/// Flush the output buffer.
+pub unsafe extern "C" fn xmlTextWriterFlush(writer: *mut xmlTextWriter) -> c_int {
+    match as_writer(writer) {
+        Some(writer) => writer.flush(),
+        None => -1,
+    }
+}
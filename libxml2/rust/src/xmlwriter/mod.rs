@@ -1 +1,9 @@
-// Empty stub module for xmlwriter
+//! Rust implementation of xmlwriter module
+//!
+//! Streaming XML writer on top of an output buffer, for Rust callers. The
+//! library keeps exporting the C writer of xmlwriter.c.
+
+pub mod core;
+pub mod ffi;
+
+pub use core::*;
@@ -0,0 +1,215 @@
+#![allow(clippy::missing_safety_doc)]
+
+use std::mem;
+use std::os::raw::{c_int, c_void};
+use std::ptr;
+
+use crate::static_bindings::{
+    xmlChar, xmlError, xmlErrorDomain_XML_FROM_XPATH, xmlGenericErrorFunc, xmlHashAddEntry2,
+    xmlHashCreate, xmlHashFree, xmlHashLookup2, xmlHashRemoveEntry2, xmlRealloc,
+    xmlStructuredErrorFunc, xmlXPathContext, xmlXPathError_XPATH_MEMORY_ERROR, xmlXPathFreeObject,
+    xmlXPathFuncLookupFunc, xmlXPathFunction, xmlXPathObjectPtr, xmlXPathParserContext,
+};
+
+extern "C" {
+    // Private in error.c
+    fn xmlRaiseMemoryError(
+        schannel: xmlStructuredErrorFunc,
+        channel: xmlGenericErrorFunc,
+        data: *mut c_void,
+        domain: c_int,
+        error: *mut xmlError,
+    );
+}
+
+/// The deepest the value stack may grow, `XPATH_MAX_STACK_DEPTH`.
+pub const MAX_STACK_DEPTH: c_int = 1_000_000;
+/// The size of a value stack when it is first allocated.
+const MIN_STACK_SIZE: c_int = 10;
+
+/// Report an allocation failure on `ctxt`, `xmlXPathErrMemory`: to its
+/// structured error handler if it has one, and into its `lastError`.
+pub unsafe fn err_memory(ctxt: *mut xmlXPathContext) {
+    if ctxt.is_null() {
+        return;
+    }
+    xmlRaiseMemoryError(
+        (*ctxt).error,
+        None,
+        (*ctxt).userData,
+        xmlErrorDomain_XML_FROM_XPATH as c_int,
+        &mut (*ctxt).lastError,
+    );
+}
+
+/// Report an allocation failure during an evaluation,
+/// `xmlXPathPErrMemory`, which also stops it.
+pub unsafe fn perr_memory(ctxt: *mut xmlXPathParserContext) {
+    if ctxt.is_null() {
+        return;
+    }
+    (*ctxt).error = xmlXPathError_XPATH_MEMORY_ERROR as c_int;
+    err_memory((*ctxt).context);
+}
+
+/// Add `f` as the function `name` in the namespace `ns_uri`, NULL for
+/// none, to the functions of `ctxt`, or remove the function if `f` is
+/// `None`. Returns 0, or -1 if a pointer is NULL, the function to remove
+/// does not exist, one of that name exists already or memory ran out.
+pub unsafe fn register_func_ns(
+    ctxt: *mut xmlXPathContext,
+    name: *const xmlChar,
+    ns_uri: *const xmlChar,
+    f: xmlXPathFunction,
+) -> c_int {
+    if ctxt.is_null() || name.is_null() {
+        return -1;
+    }
+    if (*ctxt).funcHash.is_null() {
+        (*ctxt).funcHash = xmlHashCreate(0);
+        if (*ctxt).funcHash.is_null() {
+            err_memory(ctxt);
+            return -1;
+        }
+    }
+    let Some(f) = f else {
+        return xmlHashRemoveEntry2((*ctxt).funcHash, name, ns_uri, None);
+    };
+    // The table holds data pointers, so the function goes in as one, as
+    // the memcpy does in C.
+    if xmlHashAddEntry2((*ctxt).funcHash, name, ns_uri, f as *mut c_void) < 0 {
+        err_memory(ctxt);
+        return -1;
+    }
+    0
+}
+
+/// The function `name` in `ns_uri` that `register_func_ns` added to
+/// `ctxt`, if any. Unlike `xmlXPathFunctionLookupNS`, the core library
+/// functions and `ctxt.funcLookupFunc` are not consulted.
+pub unsafe fn registered_func_ns(
+    ctxt: *mut xmlXPathContext,
+    name: *const xmlChar,
+    ns_uri: *const xmlChar,
+) -> xmlXPathFunction {
+    if ctxt.is_null() || name.is_null() || (*ctxt).funcHash.is_null() {
+        return None;
+    }
+    let payload = xmlHashLookup2((*ctxt).funcHash, name, ns_uri);
+    if payload.is_null() {
+        return None;
+    }
+    Some(mem::transmute::<
+        *mut c_void,
+        unsafe extern "C" fn(*mut xmlXPathParserContext, c_int),
+    >(payload))
+}
+
+/// Make `f` find the functions of `ctxt` that are not registered,
+/// `xmlXPathRegisterFuncLookup`. It is called with `data`.
+pub unsafe fn register_func_lookup(
+    ctxt: *mut xmlXPathContext,
+    f: xmlXPathFuncLookupFunc,
+    data: *mut c_void,
+) {
+    if ctxt.is_null() {
+        return;
+    }
+    (*ctxt).funcLookupFunc = f;
+    (*ctxt).funcLookupData = data;
+}
+
+/// Forget every function registered on `ctxt`.
+pub unsafe fn registered_funcs_cleanup(ctxt: *mut xmlXPathContext) {
+    if ctxt.is_null() {
+        return;
+    }
+    xmlHashFree((*ctxt).funcHash, None);
+    (*ctxt).funcHash = ptr::null_mut();
+}
+
+/// Take the top object off the value stack of `ctxt`, which the caller
+/// then owns, or NULL if the stack is empty. `ctxt.value` becomes the
+/// object below it.
+pub unsafe fn value_pop(ctxt: *mut xmlXPathParserContext) -> xmlXPathObjectPtr {
+    if ctxt.is_null() || (*ctxt).valueNr <= 0 {
+        return ptr::null_mut();
+    }
+    let ctxt = &mut *ctxt;
+    ctxt.valueNr -= 1;
+    let top = ctxt.valueTab.add(ctxt.valueNr as usize);
+    ctxt.value = if ctxt.valueNr > 0 {
+        *top.sub(1)
+    } else {
+        ptr::null_mut()
+    };
+    mem::replace(&mut *top, ptr::null_mut())
+}
+
+/// The capacity the value stack grows to from `capacity`, by half up to
+/// `MAX_STACK_DEPTH`, or `None` if it is there already.
+fn grow_capacity(capacity: c_int) -> Option<c_int> {
+    if capacity <= 0 {
+        return Some(MIN_STACK_SIZE);
+    }
+    if capacity >= MAX_STACK_DEPTH {
+        return None;
+    }
+    let extra = (capacity + 1) / 2;
+    Some(if capacity > MAX_STACK_DEPTH - extra {
+        MAX_STACK_DEPTH
+    } else {
+        capacity + extra
+    })
+}
+
+/// Push `value` onto the value stack of `ctxt`, which takes it over.
+/// Returns its index, or -1 if `ctxt` is NULL, or if `value` is NULL or
+/// the stack cannot grow, which are memory errors of `ctxt`; `value` is
+/// freed then.
+pub unsafe fn value_push(ctxt: *mut xmlXPathParserContext, value: xmlXPathObjectPtr) -> c_int {
+    if ctxt.is_null() {
+        return -1;
+    }
+    if value.is_null() {
+        // A NULL value typically means an allocation failed.
+        perr_memory(ctxt);
+        return -1;
+    }
+    let c = &mut *ctxt;
+    if c.valueNr >= c.valueMax {
+        let tab = grow_capacity(c.valueMax).and_then(|size| {
+            let realloc = xmlRealloc?;
+            let tab = realloc(
+                c.valueTab as *mut c_void,
+                size as usize * mem::size_of::<xmlXPathObjectPtr>(),
+            );
+            (!tab.is_null()).then_some((tab as *mut xmlXPathObjectPtr, size))
+        });
+        let Some((tab, size)) = tab else {
+            perr_memory(ctxt);
+            xmlXPathFreeObject(value);
+            return -1;
+        };
+        c.valueTab = tab;
+        c.valueMax = size;
+    }
+    *c.valueTab.add(c.valueNr as usize) = value;
+    c.value = value;
+    c.valueNr += 1;
+    c.valueNr - 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stack_grows_by_half_up_to_the_limit() {
+        assert_eq!(grow_capacity(0), Some(10));
+        assert_eq!(grow_capacity(10), Some(15));
+        assert_eq!(grow_capacity(15), Some(23));
+        assert_eq!(grow_capacity(MAX_STACK_DEPTH - 10), Some(MAX_STACK_DEPTH));
+        assert_eq!(grow_capacity(MAX_STACK_DEPTH), None);
+    }
+}
@@ -0,0 +1,81 @@
+#![allow(clippy::missing_safety_doc)]
+
+use std::os::raw::{c_int, c_void};
+
+use super::core;
+use crate::static_bindings::{
+    xmlChar, xmlXPathContext, xmlXPathFuncLookupFunc, xmlXPathFunction, xmlXPathObjectPtr,
+    xmlXPathParserContext,
+};
+
+// Ensure symbols are exported for linking
+#[cfg(feature = "rust-xpath")]
+#[used]
+static XPATH_FFI_LINKAGE: () = ();
+
+/// Report an allocation failure on an XPath context.
+#[no_mangle]
+pub unsafe extern "C" fn xmlXPathErrMemory(ctxt: *mut xmlXPathContext) {
+    core::err_memory(ctxt);
+}
+
+/// Report an allocation failure during an evaluation and stop it.
+#[no_mangle]
+pub unsafe extern "C" fn xmlXPathPErrMemory(ctxt: *mut xmlXPathParserContext) {
+    core::perr_memory(ctxt);
+}
+
+/// Register a function without a namespace, or unregister it if `f` is
+/// NULL. Returns 0 or -1.
+#[no_mangle]
+pub unsafe extern "C" fn xmlXPathRegisterFunc(
+    ctxt: *mut xmlXPathContext,
+    name: *const xmlChar,
+    f: xmlXPathFunction,
+) -> c_int {
+    core::register_func_ns(ctxt, name, std::ptr::null(), f)
+}
+
+/// Register a function in a namespace, or unregister it if `f` is NULL.
+/// Returns 0 or -1.
+#[no_mangle]
+pub unsafe extern "C" fn xmlXPathRegisterFuncNS(
+    ctxt: *mut xmlXPathContext,
+    name: *const xmlChar,
+    ns_uri: *const xmlChar,
+    f: xmlXPathFunction,
+) -> c_int {
+    core::register_func_ns(ctxt, name, ns_uri, f)
+}
+
+/// Set a callback finding functions that are not registered.
+#[no_mangle]
+pub unsafe extern "C" fn xmlXPathRegisterFuncLookup(
+    ctxt: *mut xmlXPathContext,
+    f: xmlXPathFuncLookupFunc,
+    func_ctxt: *mut c_void,
+) {
+    core::register_func_lookup(ctxt, f, func_ctxt);
+}
+
+/// Unregister every function of the context.
+#[no_mangle]
+pub unsafe extern "C" fn xmlXPathRegisteredFuncsCleanup(ctxt: *mut xmlXPathContext) {
+    core::registered_funcs_cleanup(ctxt);
+}
+
+/// Pop the top object of the value stack, or NULL if it is empty.
+#[no_mangle]
+pub unsafe extern "C" fn xmlXPathValuePop(ctxt: *mut xmlXPathParserContext) -> xmlXPathObjectPtr {
+    core::value_pop(ctxt)
+}
+
+/// Push an object onto the value stack, freeing it on failure. Returns
+/// its index or -1.
+#[no_mangle]
+pub unsafe extern "C" fn xmlXPathValuePush(
+    ctxt: *mut xmlXPathParserContext,
+    value: xmlXPathObjectPtr,
+) -> c_int {
+    core::value_push(ctxt, value)
+}
@@ -1 +1,9 @@
-// Empty stub module for xpath
+//! Rust implementation of xpath module
+//!
+//! Extension function registration and the value stack that functions
+//! take their arguments from and push their results onto
+
+pub mod core;
+pub mod ffi;
+
+pub use core::*;
@@ -0,0 +1,324 @@
+//! Tests for buf module
+
+#[cfg(feature = "rust-buf")]
+mod rust_tests {
+    use libxml2::buf::ffi::*;
+    use libxml2::buf::{Buf, GrowthPolicy};
+    use libxml2::libxml2_dynload;
+    use libxml2::xmlChar;
+    use std::ffi::CStr;
+    use std::os::raw::{c_char, c_int, c_uint, c_void};
+    use std::ptr;
+    use std::slice;
+
+    /// The `xmlBuffer` functions of one implementation. The static and
+    /// dynamic bindings each have their own `xmlBuffer` type.
+    struct Api<B> {
+        create: unsafe extern "C" fn() -> *mut B,
+        create_size: unsafe extern "C" fn(usize) -> *mut B,
+        create_static: unsafe extern "C" fn(*mut c_void, usize) -> *mut B,
+        free: unsafe extern "C" fn(*mut B),
+        add: unsafe extern "C" fn(*mut B, *const xmlChar, c_int) -> c_int,
+        add_head: unsafe extern "C" fn(*mut B, *const xmlChar, c_int) -> c_int,
+        ccat: unsafe extern "C" fn(*mut B, *const c_char) -> c_int,
+        shrink: unsafe extern "C" fn(*mut B, c_uint) -> c_int,
+        grow: unsafe extern "C" fn(*mut B, c_uint) -> c_int,
+        resize: unsafe extern "C" fn(*mut B, c_uint) -> c_int,
+        empty: unsafe extern "C" fn(*mut B),
+        detach: unsafe extern "C" fn(*mut B) -> *mut xmlChar,
+        content: unsafe extern "C" fn(*const B) -> *const xmlChar,
+        length: unsafe extern "C" fn(*const B) -> c_int,
+        quoted: unsafe extern "C" fn(*mut B, *const xmlChar),
+        xml_free: unsafe extern "C" fn(*mut c_void),
+    }
+
+    fn rust_api() -> Api<libxml2::xmlBuffer> {
+        Api {
+            create: xmlBufferCreate,
+            create_size: xmlBufferCreateSize,
+            create_static: xmlBufferCreateStatic,
+            free: xmlBufferFree,
+            add: xmlBufferAdd,
+            add_head: xmlBufferAddHead,
+            ccat: xmlBufferCCat,
+            shrink: xmlBufferShrink,
+            grow: xmlBufferGrow,
+            resize: xmlBufferResize,
+            empty: xmlBufferEmpty,
+            detach: xmlBufferDetach,
+            content: xmlBufferContent,
+            length: xmlBufferLength,
+            quoted: xmlBufferWriteQuotedString,
+            xml_free: unsafe { libxml2::xmlFree.unwrap() },
+        }
+    }
+
+    fn c_api() -> Api<impl Sized> {
+        let c = libxml2_dynload::get_c_baseline();
+        Api {
+            create: *c.xmlBufferCreate.as_ref().unwrap(),
+            create_size: *c.xmlBufferCreateSize.as_ref().unwrap(),
+            create_static: *c.xmlBufferCreateStatic.as_ref().unwrap(),
+            free: *c.xmlBufferFree.as_ref().unwrap(),
+            add: *c.xmlBufferAdd.as_ref().unwrap(),
+            add_head: *c.xmlBufferAddHead.as_ref().unwrap(),
+            ccat: *c.xmlBufferCCat.as_ref().unwrap(),
+            shrink: *c.xmlBufferShrink.as_ref().unwrap(),
+            grow: *c.xmlBufferGrow.as_ref().unwrap(),
+            resize: *c.xmlBufferResize.as_ref().unwrap(),
+            empty: *c.xmlBufferEmpty.as_ref().unwrap(),
+            detach: *c.xmlBufferDetach.as_ref().unwrap(),
+            content: *c.xmlBufferContent.as_ref().unwrap(),
+            length: *c.xmlBufferLength.as_ref().unwrap(),
+            quoted: *c.xmlBufferWriteQuotedString.as_ref().unwrap(),
+            xml_free: unsafe { libxml2::xmlFree.unwrap() },
+        }
+    }
+
+    fn x(s: &CStr) -> *const xmlChar {
+        s.as_ptr() as *const xmlChar
+    }
+
+    /// Content and length of the buffer, which is checked to be
+    /// NUL-terminated.
+    unsafe fn state<B>(api: &Api<B>, buf: *mut B) -> String {
+        let content = (api.content)(buf);
+        let len = (api.length)(buf);
+        if content.is_null() {
+            return format!("null/{len}");
+        }
+        assert_eq!(*content.add(len as usize), 0);
+        let bytes = slice::from_raw_parts(content, len as usize);
+        format!("{:?}/{len}", String::from_utf8_lossy(bytes))
+    }
+
+    /// Run the same calls through one implementation, recording return
+    /// values and content. `xmlBufferGrow` returns the room left after
+    /// growing, which depends on the growth strategy, so only its sign
+    /// is recorded.
+    unsafe fn script<B>(api: &Api<B>) -> Vec<String> {
+        let mut log = Vec::new();
+        let long = "0123456789".repeat(40);
+        let long = std::ffi::CString::new(long).unwrap();
+
+        let buf = (api.create)();
+        log.push(state(api, buf));
+        log.push(format!("add {}", (api.add)(buf, x(c"hello"), -1)));
+        log.push(format!("add {}", (api.add)(buf, x(c", world"), 3)));
+        log.push(format!("add {}", (api.add)(buf, x(c"ignored"), 0)));
+        log.push(format!("add {}", (api.add)(buf, ptr::null(), 1)));
+        log.push(state(api, buf));
+        log.push(format!("shrink {}", (api.shrink)(buf, 2)));
+        log.push(format!("shrink {}", (api.shrink)(buf, 100)));
+        log.push(format!("shrink {}", (api.shrink)(buf, 0)));
+        log.push(state(api, buf));
+        log.push(format!("head {}", (api.add_head)(buf, x(c"ab"), -1)));
+        log.push(format!("head {}", (api.add_head)(buf, x(c"<<<"), -1)));
+        log.push(state(api, buf));
+        log.push(format!("add {}", (api.add)(buf, long.as_ptr() as _, -1)));
+        log.push(format!(
+            "head {}",
+            (api.add_head)(buf, long.as_ptr() as _, 25)
+        ));
+        log.push(state(api, buf));
+        log.push(format!("grow {}", (api.grow)(buf, 1000) >= 0));
+        log.push(format!("resize {}", (api.resize)(buf, 5000)));
+        log.push(format!("shrink {}", (api.shrink)(buf, 400)));
+        log.push(state(api, buf));
+        (api.empty)(buf);
+        log.push(state(api, buf));
+        (api.ccat)(buf, c"after empty".as_ptr());
+        log.push(state(api, buf));
+        (api.shrink)(buf, 6);
+        let detached = (api.detach)(buf);
+        log.push(format!("{:?}", CStr::from_ptr(detached as *const c_char)));
+        (api.xml_free)(detached as *mut c_void);
+        log.push(state(api, buf));
+        log.push(format!("add {}", (api.add)(buf, x(c"again"), -1)));
+        log.push(state(api, buf));
+        (api.free)(buf);
+
+        let buf = (api.create_size)(0);
+        log.push(state(api, buf));
+        log.push(format!("add {}", (api.add)(buf, x(c"x"), -1)));
+        log.push(state(api, buf));
+        (api.free)(buf);
+
+        let buf = (api.create_size)(3);
+        for quoted in [c"plain", c"say \"hi\"", c"it's \"x\" 'y'\"", c"\"", c""] {
+            (api.quoted)(buf, x(quoted));
+            (api.ccat)(buf, c" ".as_ptr());
+        }
+        log.push(state(api, buf));
+        (api.free)(buf);
+
+        let buf = (api.create_static)(c"static".as_ptr() as *mut c_void, 6);
+        log.push(state(api, buf));
+        (api.free)(buf);
+        log
+    }
+
+    #[test]
+    fn test_buffer_matches_baseline() {
+        unsafe {
+            let rust = script(&rust_api());
+            let c = script(&c_api());
+            assert_eq!(rust, c);
+        }
+    }
+
+    /// After every growth the content pointer must hold all bytes added so
+    /// far and the terminating NUL. Between growths it must not move.
+    #[test]
+    fn test_buffer_content_valid_across_growth() {
+        unsafe {
+            let buf = xmlBufferCreateSize(0);
+            let mut expected = Vec::new();
+            let mut content = ptr::null();
+            let mut moves = 0;
+            for i in 0..20_000u32 {
+                let chunk = format!("<{i}>");
+                let room = (*buf).size.wrapping_sub((*buf).use_);
+                assert_eq!(xmlBufferAdd(buf, chunk.as_ptr(), chunk.len() as c_int), 0);
+                expected.extend_from_slice(chunk.as_bytes());
+
+                let now = xmlBufferContent(buf);
+                if now != content {
+                    assert!(chunk.len() as c_uint >= room);
+                    moves += 1;
+                    content = now;
+                    assert_eq!(slice::from_raw_parts(now, expected.len()), &expected[..]);
+                }
+                assert_eq!(xmlBufferLength(buf) as usize, expected.len());
+                assert_eq!(*now.add(expected.len()), 0);
+            }
+            assert_eq!(
+                slice::from_raw_parts(content, expected.len()),
+                &expected[..]
+            );
+            assert!(moves < 20, "{moves} reallocations");
+            xmlBufferFree(buf);
+        }
+    }
+
+    #[test]
+    fn test_buf_content_valid_across_growth() {
+        unsafe {
+            let buf = xmlBufCreate(0);
+            let mut expected = Vec::new();
+            let mut content = xmlBufContent(buf);
+            for i in 0..20_000u32 {
+                let chunk = format!("[{i}]");
+                assert_eq!(xmlBufAdd(buf, chunk.as_ptr(), chunk.len()), 0);
+                expected.extend_from_slice(chunk.as_bytes());
+                if i % 7 == 0 {
+                    // Shrunk space at the front is reused before growing.
+                    assert_eq!(xmlBufShrink(buf, 3), 3);
+                    expected.drain(..3);
+                }
+
+                let now = xmlBufContent(buf);
+                if now != content {
+                    content = now;
+                    assert_eq!(slice::from_raw_parts(now, expected.len()), &expected[..]);
+                }
+                assert_eq!(xmlBufUse(buf), expected.len());
+                assert_eq!(xmlBufEnd(buf), now.add(expected.len()));
+                assert_eq!(*xmlBufEnd(buf), 0);
+            }
+            assert_eq!(
+                slice::from_raw_parts(content, expected.len()),
+                &expected[..]
+            );
+
+            let detached = xmlBufDetach(buf);
+            assert_eq!(
+                CStr::from_ptr(detached as *const c_char).to_bytes(),
+                &expected[..]
+            );
+            libxml2::xmlFree.unwrap()(detached as *mut c_void);
+            assert_eq!(xmlBufUse(buf), 0);
+            xmlBufFree(buf);
+        }
+    }
+
+    #[test]
+    fn test_buf_policy_threshold() {
+        let mut buf = Buf::new(0).unwrap();
+        buf.policy = GrowthPolicy {
+            min_capacity: 8,
+            threshold: 1024,
+        };
+        let mut capacities = vec![buf.capacity()];
+        for _ in 0..4096 {
+            buf.add(b"x").unwrap();
+            if buf.capacity() != *capacities.last().unwrap() {
+                capacities.push(buf.capacity());
+            }
+        }
+        assert_eq!(
+            capacities,
+            [0, 8, 16, 32, 64, 128, 256, 512, 1024, 1536, 2304, 3456, 5184]
+        );
+        assert_eq!(buf.as_bytes(), &[b'x'; 4096][..]);
+    }
+
+    #[test]
+    fn test_buf_into_vec() {
+        let input = b"<doc><a x=\"1\">text &amp; more</a><b/></doc>";
+        unsafe {
+            let doc = libxml2::xmlReadMemory(
+                input.as_ptr() as *const c_char,
+                input.len() as c_int,
+                ptr::null(),
+                ptr::null(),
+                0,
+            );
+            assert!(!doc.is_null());
+            let (mut mem, mut size) = (ptr::null_mut(), 0);
+            libxml2::xmlDocDumpMemory(doc, &mut mem, &mut size);
+            let serialized = slice::from_raw_parts(mem, size as usize).to_vec();
+            libxml2::xmlFree.unwrap()(mem as *mut c_void);
+            libxml2::xmlFreeDoc(doc);
+
+            // Written in chunks, as an output buffer does.
+            let buf = xmlBufCreate(0);
+            for chunk in serialized.chunks(7) {
+                assert_eq!(xmlBufAdd(buf, chunk.as_ptr(), chunk.len()), 0);
+            }
+            // Taking the content leaves a valid, empty buffer behind.
+            assert_eq!((*(buf as *mut Buf)).take_vec(), serialized);
+            assert_eq!(xmlBufUse(buf), 0);
+            assert_eq!(*xmlBufContent(buf), 0);
+
+            for chunk in serialized.chunks(5) {
+                assert_eq!(xmlBufAdd(buf, chunk.as_ptr(), chunk.len()), 0);
+            }
+            assert_eq!(xmlBufShrink(buf, 21), 21);
+            let owned = Box::from_raw(buf as *mut Buf).into_vec();
+            assert_eq!(owned, &serialized[21..]);
+            assert!(owned.starts_with(b"\n<doc>"));
+        }
+    }
+
+    #[test]
+    fn test_buf_round_trip_through_buffer() {
+        unsafe {
+            let buffer = xmlBufferCreate();
+            xmlBufferCCat(buffer, c"0123456789".as_ptr());
+            xmlBufferShrink(buffer, 4);
+
+            let buf = xmlBufFromBuffer(buffer);
+            assert_eq!(xmlBufUse(buf), 6);
+            assert_eq!(xmlBufAdd(buf, x(c"abc"), 3), 0);
+            assert_eq!(xmlBufBackToBuffer(buf, buffer), 0);
+
+            assert_eq!(
+                CStr::from_ptr(xmlBufferContent(buffer) as *const c_char),
+                c"456789abc"
+            );
+            assert_eq!(xmlBufferLength(buffer), 9);
+            xmlBufferFree(buffer);
+        }
+    }
+}
@@ -0,0 +1,70 @@
+//! Tests for the Rust exports `build.rs` renames in the hybrid build
+
+#[path = "../build/exports.rs"]
+mod exports;
+
+use exports::*;
+use std::fs;
+use std::path::Path;
+
+/// The `#[no_mangle]` functions and statics of an ffi.rs, in order
+fn no_mangle_items(source: &str) -> Vec<String> {
+    let mut items = Vec::new();
+    let mut lines = source.lines().map(str::trim);
+    while let Some(line) = lines.next() {
+        if line != "#[no_mangle]" {
+            continue;
+        }
+        let item = lines.by_ref().find(|line| !line.starts_with("#[")).unwrap();
+        let mut words = item
+            .split(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+            .filter(|word| !word.is_empty());
+        assert!(words.by_ref().any(|word| word == "fn" || word == "static"), "{}", item);
+        items.push(words.find(|word| *word != "mut").unwrap().to_string());
+    }
+    items
+}
+
+#[test]
+fn test_exports_match_ffi() {
+    let src = Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+    for entry in fs::read_dir(&src).unwrap() {
+        let dir = entry.unwrap().path();
+        let Ok(source) = fs::read_to_string(dir.join("ffi.rs")) else {
+            continue;
+        };
+        let module = dir.file_name().unwrap().to_str().unwrap().replace('_', "-");
+        assert_eq!(rust_exports(&module), no_mangle_items(&source), "{}", module);
+    }
+    for (module, symbols) in RUST_EXPORTS {
+        assert!(!symbols.is_empty(), "{}", module);
+        assert!(src.join(module.replace('-', "_")).join("ffi.rs").is_file(), "{}", module);
+    }
+}
+
+#[test]
+fn test_rename_defines() {
+    assert_eq!(rename_defines("encoding"), [
+        "-DxmlIsolat1ToUTF8=xmlIsolat1ToUTF8__c",
+        "-DxmlUTF8ToIsolat1=xmlUTF8ToIsolat1__c",
+    ]);
+    assert!(rename_defines("hash").is_empty());
+}
+
+#[test]
+fn test_missing_c_definitions() {
+    let nm_output = "\
+_GLOBAL_OFFSET_TABLE_ U
+xmlIsolat1ToUTF8 U
+xmlIsolat1ToUTF8__c T 0 1c8
+xmlUTF8ToIsolat1__c w
+xmlCharEncOutFunc T 1d0 96
+";
+    let defined = defined_symbols(nm_output);
+    assert_eq!(defined, ["xmlIsolat1ToUTF8__c", "xmlCharEncOutFunc"]);
+    assert_eq!(missing_c_definitions("encoding", &defined), ["xmlUTF8ToIsolat1"]);
+    // Mach-O symbols, and tables
+    let defined = ["_xmlIsolat1ToUTF8__c", "_xmlUTF8ToIsolat1__c"];
+    assert!(missing_c_definitions("encoding", &defined).is_empty());
+    assert!(defined_symbols("xmlIsPubidChar_tab__c R 0 100\n").contains(&"xmlIsPubidChar_tab__c"));
+}
@@ -0,0 +1,137 @@
+//! Tests for the configure options `build.rs` derives from Cargo features
+
+#[path = "../build/options.rs"]
+mod options;
+
+use options::*;
+use std::collections::{BTreeSet, HashMap};
+
+/// The `[features]` table of Cargo.toml, as each feature's implied features.
+fn cargo_features() -> HashMap<String, Vec<String>> {
+    let manifest = include_str!("../Cargo.toml");
+    let mut features = HashMap::new();
+    let mut in_features = false;
+    let mut lines = manifest.lines();
+    while let Some(line) = lines.next() {
+        let line = line.split('#').next().unwrap().trim();
+        if line.starts_with('[') {
+            in_features = line == "[features]";
+            continue;
+        }
+        let Some((name, value)) = line.split_once('=').filter(|_| in_features) else {
+            continue;
+        };
+        let mut value = value.trim().to_string();
+        while !value.ends_with(']') {
+            value.push_str(lines.next().unwrap().split('#').next().unwrap().trim());
+        }
+        let implied = value
+            .trim_matches(|c| c == '[' || c == ']')
+            .split(',')
+            .map(|f| f.trim().trim_matches('"').to_string())
+            .filter(|f| !f.is_empty())
+            .collect();
+        features.insert(name.trim().to_string(), implied);
+    }
+    features
+}
+
+/// `selected` with every feature they imply, as Cargo resolves them.
+fn resolve(selected: &[&str]) -> BTreeSet<String> {
+    let table = cargo_features();
+    let mut resolved = BTreeSet::new();
+    let mut pending: Vec<String> = selected.iter().map(|f| f.to_string()).collect();
+    while let Some(feature) = pending.pop() {
+        if resolved.insert(feature.clone()) {
+            pending.extend(table.get(&feature).cloned().unwrap_or_default());
+        }
+    }
+    resolved
+}
+
+fn enabled(features: &BTreeSet<String>) -> impl Fn(&str) -> bool + '_ {
+    move |feature| features.contains(feature)
+}
+
+#[test]
+fn test_default_matches_configure() {
+    let none = BTreeSet::new();
+    let args = configure_args(&enabled(&none));
+    for (option, default, _) in CONFIG_OPTIONS {
+        let switch = if *default { "with" } else { "without" };
+        assert!(args.contains(&format!("--{}-{}", switch, option)), "{:?}", args);
+    }
+    assert_eq!(check_options(&[], &enabled(&none)), Ok(()));
+    assert!(c_file_enabled("xmlschemas.c", &enabled(&none)));
+    assert!(c_file_enabled("parser.c", &enabled(&none)));
+    assert!(!c_file_enabled("schematron.c", &enabled(&none)));
+}
+
+#[test]
+fn test_every_option_has_a_feature() {
+    let table = cargo_features();
+    assert_eq!(table.get("default"), Some(&Vec::new()));
+    for (option, default, _) in CONFIG_OPTIONS {
+        let feature = format!("{}-{}", if *default { "without" } else { "with" }, option);
+        assert!(table.contains_key(&feature), "no {} feature", feature);
+    }
+}
+
+#[test]
+fn test_without_features_turn_off_dependents() {
+    // Every without-X feature, with what Cargo.toml makes it imply, leaves
+    // no option on without its dependencies.
+    for feature in cargo_features().keys().filter(|f| f.starts_with("without-")) {
+        let features = resolve(&[feature]);
+        assert_eq!(check_options(&[], &enabled(&features)), Ok(()), "{}", feature);
+        let option = &feature["without-".len()..];
+        assert!(!option_enabled(option, &enabled(&features)));
+        assert!(configure_args(&enabled(&features)).contains(&format!("--without-{}", option)));
+    }
+    // The implications are what keeps them consistent.
+    let bare: BTreeSet<String> = ["without-xpath".to_string()].into();
+    assert!(check_options(&[], &enabled(&bare)).is_err());
+}
+
+#[test]
+fn test_disabled_option_drops_c_files() {
+    let features = resolve(&["without-schemas", "without-html"]);
+    for file in ["xmlschemas.c", "xmlschemastypes.c", "relaxng.c", "HTMLparser.c", "HTMLtree.c"] {
+        assert!(!c_file_enabled(file, &enabled(&features)), "{}", file);
+    }
+    for file in ["parser.c", "xmlregexp.c", "xpath.c"] {
+        assert!(c_file_enabled(file, &enabled(&features)), "{}", file);
+    }
+}
+
+#[test]
+fn test_rejected_combinations() {
+    let schematron = resolve(&["with-schematron"]);
+    assert_eq!(check_options(&[], &enabled(&schematron)), Ok(()));
+    assert!(c_file_enabled("schematron.c", &enabled(&schematron)));
+    let without_xpath = resolve(&["with-schematron", "without-xpath"]);
+    assert!(check_options(&[], &enabled(&without_xpath)).is_err());
+
+    // Rust modules of a part turned off, but not of one left on.
+    let features = resolve(&["without-regexps"]);
+    for (module, option) in MODULE_OPTIONS {
+        let result = check_options(&[module.to_string()], &enabled(&features));
+        assert_eq!(result.is_err(), !option_enabled(option, &enabled(&features)), "{}", module);
+    }
+    assert!(check_options(&["xmlschemas".to_string()], &enabled(&features)).is_err());
+    assert_eq!(check_options(&["xpath".to_string()], &enabled(&features)), Ok(()));
+    // rust-schematron turns Schematron on.
+    assert!(resolve(&["rust-schematron"]).contains("with-schematron"));
+}
+
+#[test]
+fn test_dependencies_follow_configure() {
+    // Turning off a dependency turns off its dependents, like the
+    // "Disable dependent modules" block of configure.ac.
+    for (option, dependencies) in OPTION_DEPENDENCIES {
+        for dependency in *dependencies {
+            let features = resolve(&[&format!("without-{}", dependency)]);
+            assert!(!option_enabled(option, &enabled(&features)), "{} without {}", option, dependency);
+        }
+    }
+}
@@ -0,0 +1,413 @@
+//! Tests for c14n module
+
+use libxml2::*;
+use std::ffi::{CStr, CString};
+use std::os::raw::{c_char, c_int};
+use std::path::{Path, PathBuf};
+
+const PARSE_OPTIONS: c_int = (xmlParserOption_XML_PARSE_DTDATTR
+    | xmlParserOption_XML_PARSE_NOENT
+    | xmlParserOption_XML_PARSE_NOWARNING) as c_int;
+
+const C14N_1_0: c_int = xmlC14NMode_XML_C14N_1_0 as c_int;
+const C14N_EXCLUSIVE_1_0: c_int = xmlC14NMode_XML_C14N_EXCLUSIVE_1_0 as c_int;
+
+/// Every supported mode, with and without comments.
+const MODES: [(c_int, c_int); 4] = [
+    (C14N_1_0, 0),
+    (C14N_1_0, 1),
+    (C14N_EXCLUSIVE_1_0, 0),
+    (C14N_EXCLUSIVE_1_0, 1),
+];
+
+/// Comments before, inside and after the document element.
+const COMMENTED: &[u8] = b"<?xml version=\"1.0\"?>
+<!-- before --><?pi?>
+<r><!-- inside --><a>x<!---->y</a></r>
+<!-- after --><!-- last -->
+";
+
+fn source_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .unwrap()
+        .to_path_buf()
+}
+
+/// One W3C test vector from `test/c14n`, as run by `runtest`.
+struct Vector {
+    name: String,
+    xml: CString,
+    /// XPath selecting the node-set, with the namespaces it uses.
+    xpath: Option<(CString, Vec<(CString, CString)>)>,
+    inclusive_prefixes: Vec<CString>,
+}
+
+/// Read the `<XPath>` element of an `.xpath` file with the C baseline.
+fn load_xpath(path: &Path) -> (CString, Vec<(CString, CString)>) {
+    let c_lib = libxml2_dynload::get_c_baseline();
+    let path = CString::new(path.to_str().unwrap()).unwrap();
+    unsafe {
+        let doc = c_lib.xmlReadFile(path.as_ptr(), std::ptr::null(), PARSE_OPTIONS);
+        assert!(!doc.is_null());
+        let node = c_lib.xmlDocGetRootElement(doc);
+        let content = c_lib.xmlNodeGetContent(node);
+        let expr = CStr::from_ptr(content as *const c_char).to_owned();
+        xmlFree.unwrap()(content as *mut std::os::raw::c_void);
+
+        let mut namespaces = Vec::new();
+        let mut ns = (*node).nsDef;
+        while !ns.is_null() {
+            namespaces.push((
+                CStr::from_ptr((*ns).prefix as *const c_char).to_owned(),
+                CStr::from_ptr((*ns).href as *const c_char).to_owned(),
+            ));
+            ns = (*ns).next;
+        }
+        c_lib.xmlFreeDoc(doc);
+        (expr, namespaces)
+    }
+}
+
+/// All vectors in `test/c14n/<subdir>`, in name order.
+fn vectors(subdir: &str) -> Vec<Vector> {
+    let dir = source_dir().join("test/c14n").join(subdir);
+    let mut names: Vec<String> = std::fs::read_dir(&dir)
+        .unwrap()
+        .filter_map(|entry| {
+            let name = entry.unwrap().file_name().into_string().unwrap();
+            name.strip_suffix(".xml").map(str::to_string)
+        })
+        .collect();
+    names.sort();
+
+    names
+        .into_iter()
+        .map(|name| {
+            let xpath = dir.join(format!("{}.xpath", name));
+            let xpath = xpath.exists().then(|| load_xpath(&xpath));
+            let inclusive_prefixes = match std::fs::read_to_string(dir.join(format!("{}.ns", name)))
+            {
+                Ok(list) => {
+                    let list = list.trim_start_matches('\'').trim_end_matches('\'');
+                    list.split(',')
+                        .map(|prefix| CString::new(prefix).unwrap())
+                        .collect()
+                }
+                Err(_) => Vec::new(),
+            };
+            let xml = dir.join(format!("{}.xml", name));
+            Vector {
+                xml: CString::new(xml.to_str().unwrap()).unwrap(),
+                name: format!("{}/{}", subdir, name),
+                xpath,
+                inclusive_prefixes,
+            }
+        })
+        .collect()
+}
+
+fn prefix_ptrs(vector: &Vector) -> Vec<*mut xmlChar> {
+    let mut ptrs: Vec<*mut xmlChar> = vector
+        .inclusive_prefixes
+        .iter()
+        .map(|prefix| prefix.as_ptr() as *mut xmlChar)
+        .collect();
+    ptrs.push(std::ptr::null_mut());
+    ptrs
+}
+
+unsafe fn take_result(ret: c_int, result: *mut xmlChar) -> Option<Vec<u8>> {
+    if ret < 0 {
+        return None;
+    }
+    let out = std::slice::from_raw_parts(result, ret as usize).to_vec();
+    xmlFree.unwrap()(result as *mut std::os::raw::c_void);
+    Some(out)
+}
+
+/// Canonicalize a vector with the C baseline.
+fn c_canonicalize(vector: &Vector, mode: c_int, with_comments: c_int) -> Option<Vec<u8>> {
+    let c_lib = libxml2_dynload::get_c_baseline();
+    unsafe {
+        let doc = c_lib.xmlReadFile(vector.xml.as_ptr(), std::ptr::null(), PARSE_OPTIONS);
+        assert!(!doc.is_null(), "{}", vector.name);
+
+        let mut nodes = std::ptr::null_mut();
+        let mut obj = std::ptr::null_mut();
+        if let Some((expr, namespaces)) = &vector.xpath {
+            let ctxt = c_lib.xmlXPathNewContext(doc);
+            for (prefix, href) in namespaces {
+                c_lib.xmlXPathRegisterNs(
+                    ctxt,
+                    prefix.as_ptr() as *const xmlChar,
+                    href.as_ptr() as *const xmlChar,
+                );
+            }
+            obj = c_lib.xmlXPathEvalExpression(expr.as_ptr() as *const xmlChar, ctxt);
+            assert!(!obj.is_null(), "{}", vector.name);
+            nodes = (*obj).nodesetval;
+            c_lib.xmlXPathFreeContext(ctxt);
+        }
+
+        let mut prefixes = prefix_ptrs(vector);
+        let mut result = std::ptr::null_mut();
+        let ret = c_lib.xmlC14NDocDumpMemory(
+            doc,
+            nodes,
+            mode,
+            prefixes.as_mut_ptr(),
+            with_comments,
+            &mut result,
+        );
+        let out = take_result(ret, result);
+
+        if !obj.is_null() {
+            c_lib.xmlXPathFreeObject(obj);
+        }
+        c_lib.xmlFreeDoc(doc);
+        out
+    }
+}
+
+/// Canonicalize an in-memory document with the C baseline.
+fn c_dump_memory(input: &[u8], mode: c_int, with_comments: c_int) -> Option<Vec<u8>> {
+    let c_lib = libxml2_dynload::get_c_baseline();
+    unsafe {
+        let doc = c_lib.xmlReadMemory(
+            input.as_ptr() as *const c_char,
+            input.len() as c_int,
+            std::ptr::null(),
+            std::ptr::null(),
+            PARSE_OPTIONS,
+        );
+        let mut result = std::ptr::null_mut();
+        let ret = c_lib.xmlC14NDocDumpMemory(
+            doc,
+            std::ptr::null_mut(),
+            mode,
+            std::ptr::null_mut(),
+            with_comments,
+            &mut result,
+        );
+        c_lib.xmlFreeDoc(doc);
+        take_result(ret, result)
+    }
+}
+
+#[test]
+fn test_c14n_baseline_comments() {
+    let with = c_dump_memory(COMMENTED, C14N_1_0, 1).unwrap();
+    let without = c_dump_memory(COMMENTED, C14N_1_0, 0).unwrap();
+    assert!(String::from_utf8(with).unwrap().contains("<!-- inside -->"));
+    assert!(!String::from_utf8(without).unwrap().contains("<!--"));
+}
+
+#[test]
+fn test_c14n_baseline_matches_reference() {
+    for (subdir, mode, with_comments) in [
+        ("without-comments", C14N_1_0, 0),
+        ("with-comments", C14N_1_0, 1),
+        ("exc-without-comments", C14N_EXCLUSIVE_1_0, 0),
+    ] {
+        for vector in vectors(subdir) {
+            let name = vector.name.rsplit('/').next().unwrap();
+            let reference = source_dir().join("result/c14n").join(subdir).join(name);
+            let Ok(expected) = std::fs::read(reference) else {
+                continue;
+            };
+            assert_eq!(
+                c_canonicalize(&vector, mode, with_comments),
+                Some(expected),
+                "{}",
+                vector.name
+            );
+        }
+    }
+}
+
+#[cfg(feature = "rust-c14n")]
+mod rust_tests {
+    use super::*;
+    use libxml2::c14n::ffi;
+
+    /// Canonicalize a vector with the Rust implementation.
+    fn rust_canonicalize(vector: &Vector, mode: c_int, with_comments: c_int) -> Option<Vec<u8>> {
+        unsafe {
+            let doc = xmlReadFile(vector.xml.as_ptr(), std::ptr::null(), PARSE_OPTIONS);
+            assert!(!doc.is_null(), "{}", vector.name);
+
+            let mut nodes = std::ptr::null_mut();
+            let mut obj = std::ptr::null_mut();
+            if let Some((expr, namespaces)) = &vector.xpath {
+                let ctxt = xmlXPathNewContext(doc);
+                for (prefix, href) in namespaces {
+                    xmlXPathRegisterNs(
+                        ctxt,
+                        prefix.as_ptr() as *const xmlChar,
+                        href.as_ptr() as *const xmlChar,
+                    );
+                }
+                obj = xmlXPathEvalExpression(expr.as_ptr() as *const xmlChar, ctxt);
+                assert!(!obj.is_null(), "{}", vector.name);
+                nodes = (*obj).nodesetval;
+                xmlXPathFreeContext(ctxt);
+            }
+
+            let mut prefixes = prefix_ptrs(vector);
+            let mut result = std::ptr::null_mut();
+            let ret = ffi::xmlC14NDocDumpMemory(
+                doc,
+                nodes,
+                mode,
+                prefixes.as_mut_ptr(),
+                with_comments,
+                &mut result,
+            );
+            let out = take_result(ret, result);
+
+            if !obj.is_null() {
+                xmlXPathFreeObject(obj);
+            }
+            xmlFreeDoc(doc);
+            out
+        }
+    }
+
+    fn assert_vectors_match(subdir: &str) {
+        for vector in vectors(subdir) {
+            for (mode, with_comments) in MODES {
+                let c_out = c_canonicalize(&vector, mode, with_comments);
+                let rust_out = rust_canonicalize(&vector, mode, with_comments);
+                assert!(c_out.is_some(), "{} mode={}", vector.name, mode);
+                assert_eq!(
+                    rust_out.as_deref().map(String::from_utf8_lossy),
+                    c_out.as_deref().map(String::from_utf8_lossy),
+                    "{} mode={} with_comments={}",
+                    vector.name,
+                    mode,
+                    with_comments
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_c14n_inclusive_vectors_match_baseline() {
+        assert_vectors_match("without-comments");
+    }
+
+    #[test]
+    fn test_c14n_with_comments_vectors_match_baseline() {
+        assert_vectors_match("with-comments");
+    }
+
+    #[test]
+    fn test_c14n_exclusive_vectors_match_baseline() {
+        assert_vectors_match("exc-without-comments");
+    }
+
+    #[test]
+    fn test_c14n_comments_match_baseline() {
+        for (mode, with_comments) in MODES {
+            let c_out = c_dump_memory(COMMENTED, mode, with_comments);
+            let rust_out = unsafe {
+                let doc = parse(COMMENTED);
+                let out = dump_memory(doc, mode, with_comments);
+                xmlFreeDoc(doc);
+                out
+            };
+            assert_eq!(
+                rust_out.as_deref().map(String::from_utf8_lossy),
+                c_out.as_deref().map(String::from_utf8_lossy),
+                "mode={} with_comments={}",
+                mode,
+                with_comments
+            );
+        }
+    }
+
+    #[test]
+    fn test_c14n_comment_placement() {
+        unsafe {
+            let doc = parse(COMMENTED);
+            let out = |with_comments| {
+                String::from_utf8(dump_memory(doc, C14N_1_0, with_comments).unwrap()).unwrap()
+            };
+            assert_eq!(
+                out(1),
+                "<!-- before -->\n<?pi?>\n<r><!-- inside --><a>x<!---->y</a></r>\n\
+                 <!-- after -->\n<!-- last -->"
+            );
+            assert_eq!(out(0), "<?pi?>\n<r><a>xy</a></r>");
+            xmlFreeDoc(doc);
+        }
+    }
+
+    #[test]
+    fn test_c14n_memory_document() {
+        let input = b"<?pi before?><r xmlns=\"urn:d\" xmlns:a=\"urn:a\" xmlns:u=\"urn:u\" \
+                      z=\"1\" a:y=\"&lt;&quot;\"><a:e b='x&#9;y'>t&amp;&#13;</a:e><e xmlns=\"\"/></r><?pi after?>";
+        unsafe {
+            let doc = parse(input);
+            let canonical = |mode| String::from_utf8(dump_memory(doc, mode, 0).unwrap()).unwrap();
+
+            assert_eq!(
+                canonical(C14N_1_0),
+                "<?pi before?>\n<r xmlns=\"urn:d\" xmlns:a=\"urn:a\" xmlns:u=\"urn:u\" \
+                 z=\"1\" a:y=\"&lt;&quot;\"><a:e b=\"x&#x9;y\">t&amp;&#xD;</a:e>\
+                 <e xmlns=\"\"></e></r>\n<?pi after?>"
+            );
+            // The unused `u` prefix is dropped and `a` moves to its first use.
+            assert_eq!(
+                canonical(C14N_EXCLUSIVE_1_0),
+                "<?pi before?>\n<r xmlns=\"urn:d\" xmlns:a=\"urn:a\" \
+                 z=\"1\" a:y=\"&lt;&quot;\"><a:e b=\"x&#x9;y\">t&amp;&#xD;</a:e>\
+                 <e xmlns=\"\"></e></r>\n<?pi after?>"
+            );
+            xmlFreeDoc(doc);
+        }
+    }
+
+    unsafe fn parse(input: &[u8]) -> xmlDocPtr {
+        xmlReadMemory(
+            input.as_ptr() as *const c_char,
+            input.len() as c_int,
+            std::ptr::null(),
+            std::ptr::null(),
+            PARSE_OPTIONS,
+        )
+    }
+
+    unsafe fn dump_memory(doc: xmlDocPtr, mode: c_int, with_comments: c_int) -> Option<Vec<u8>> {
+        let mut result = std::ptr::null_mut();
+        let ret = ffi::xmlC14NDocDumpMemory(
+            doc,
+            std::ptr::null_mut(),
+            mode,
+            std::ptr::null_mut(),
+            with_comments,
+            &mut result,
+        );
+        take_result(ret, result)
+    }
+
+    #[test]
+    fn test_c14n_rejects_unsupported() {
+        unsafe {
+            // Relative namespace URIs cannot be canonicalized.
+            let doc = parse(b"<r xmlns=\"relative\"/>");
+            assert_eq!(dump_memory(doc, C14N_1_0, 0), None);
+            assert_eq!(dump_memory(doc, C14N_EXCLUSIVE_1_0, 0), None);
+            xmlFreeDoc(doc);
+
+            // Canonical XML 1.1 is not ported.
+            let doc = parse(b"<r/>");
+            assert_eq!(dump_memory(doc, C14N_1_0, 0), Some(b"<r></r>".to_vec()));
+            assert_eq!(dump_memory(doc, xmlC14NMode_XML_C14N_1_1 as c_int, 0), None);
+            xmlFreeDoc(doc);
+
+            assert_eq!(dump_memory(std::ptr::null_mut(), C14N_1_0, 0), None);
+        }
+    }
+}
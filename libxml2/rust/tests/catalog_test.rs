@@ -0,0 +1,495 @@
+//! Tests for catalog module
+
+use libxml2::*;
+use std::ffi::{CStr, CString};
+use std::os::raw::{c_char, c_void};
+use std::path::{Path, PathBuf};
+use std::ptr;
+use std::sync::Mutex;
+
+/// The default catalogs are global, so tests loading them take turns.
+static GLOBAL_CATALOGS: Mutex<()> = Mutex::new(());
+
+const MAIN: &str = r#"<?xml version="1.0"?>
+<catalog xmlns="urn:oasis:names:tc:entity:xmlns:xml:catalog" prefer="public">
+  <public publicId="-//Example//DTD Doc V1//EN" uri="dtd/doc1.dtd"/>
+  <public publicId="  -//Example//DTD   Spaced//EN " uri="dtd/spaced.dtd"/>
+  <system systemId="http://example.com/doc.dtd" uri="dtd/doc.dtd"/>
+  <rewriteSystem systemIdStartString="http://example.com/dtds/" rewritePrefix="local/dtds/"/>
+  <rewriteSystem systemIdStartString="http://example.com/dtds/v2/" rewritePrefix="local/v2/"/>
+  <delegatePublic publicIdStartString="-//Delegated//" catalog="delegate.xml"/>
+  <delegateSystem systemIdStartString="http://delegated.example.com/" catalog="delegate.xml"/>
+  <uri name="http://example.com/schema.xsd" uri="schemas/schema.xsd"/>
+  <rewriteURI uriStartString="http://example.com/schemas/" rewritePrefix="schemas/"/>
+  <delegateURI uriStartString="http://delegated.example.com/uris/" catalog="delegate.xml"/>
+  <group prefer="system" xml:base="http://mirror.example.com/">
+    <public publicId="-//Example//DTD Grouped//EN" uri="grouped.dtd"/>
+    <delegatePublic publicIdStartString="-//Ignored//" catalog="delegate.xml"/>
+  </group>
+  <system uri="lacks-a-system-id.dtd"/>
+  <nextCatalog catalog="does-not-exist.xml"/>
+  <nextCatalog catalog="next.xml"/>
+</catalog>
+"#;
+
+const DELEGATE: &str = r#"<?xml version="1.0"?>
+<catalog xmlns="urn:oasis:names:tc:entity:xmlns:xml:catalog">
+  <public publicId="-//Delegated//DTD A//EN" uri="delegated-a.dtd"/>
+  <system systemId="http://delegated.example.com/a.dtd" uri="delegated-a.dtd"/>
+  <uri name="http://delegated.example.com/uris/a" uri="delegated-uri-a"/>
+  <public publicId="-//Ignored//DTD X//EN" uri="ignored-x.dtd"/>
+</catalog>
+"#;
+
+/// Also has what the delegates of the main catalog lack, which the cut
+/// after them must hide.
+const NEXT: &str = r#"<?xml version="1.0"?>
+<catalog xmlns="urn:oasis:names:tc:entity:xmlns:xml:catalog">
+  <public publicId="-//Next//DTD N//EN" uri="next-n.dtd"/>
+  <system systemId="http://next.example.com/n.dtd" uri="next-n.dtd"/>
+  <uri name="urn:next" uri="next-uri"/>
+  <public publicId="-//Delegated//DTD B//EN" uri="hidden-b.dtd"/>
+  <system systemId="http://delegated.example.com/b.dtd" uri="hidden-b.dtd"/>
+</catalog>
+"#;
+
+/// A catalog that is its own next catalog.
+const LOOP: &str = r#"<?xml version="1.0"?>
+<catalog xmlns="urn:oasis:names:tc:entity:xmlns:xml:catalog">
+  <public publicId="-//Loop//DTD L//EN" uri="loop-l.dtd"/>
+  <nextCatalog catalog="loop.xml"/>
+</catalog>
+"#;
+
+const NOT_A_CATALOG: &str = "<?xml version=\"1.0\"?>\n<catalog/>\n";
+
+const IDS: &[(Option<&str>, Option<&str>)] = &[
+    (Some("-//Example//DTD Doc V1//EN"), None),
+    (Some("-//Example//DTD Spaced//EN"), None),
+    (Some(" -//Example//DTD  Doc V1//EN\n"), None),
+    (None, Some("http://example.com/doc.dtd")),
+    (None, Some("http://example.com/dtds/a/b.dtd")),
+    (None, Some("http://example.com/dtds/v2/c.dtd")),
+    (Some("-//Delegated//DTD A//EN"), None),
+    (Some("-//Delegated//DTD B//EN"), None),
+    (None, Some("http://delegated.example.com/a.dtd")),
+    (None, Some("http://delegated.example.com/b.dtd")),
+    (Some("-//Example//DTD Grouped//EN"), None),
+    (Some("-//Ignored//DTD X//EN"), None),
+    (Some("-//Next//DTD N//EN"), None),
+    (None, Some("http://next.example.com/n.dtd")),
+    (
+        Some("-//Example//DTD Doc V1//EN"),
+        Some("http://example.com/doc.dtd"),
+    ),
+    (
+        Some("-//Example//DTD Doc V1//EN"),
+        Some("http://nowhere.example.com/x.dtd"),
+    ),
+    (Some("urn:publicid:-:Example:DTD+Doc+V1:EN"), None),
+    (None, Some("urn:publicid:-:Next:DTD+N:EN")),
+    (
+        Some("-//Next//DTD N//EN"),
+        Some("urn:publicid:-:Next:DTD+N:EN"),
+    ),
+    (Some("   "), Some("http://next.example.com/n.dtd")),
+    (None, Some("http://nowhere.example.com/x.dtd")),
+];
+
+const URIS: &[&str] = &[
+    "http://example.com/schema.xsd",
+    "http://example.com/schemas/x/y.xsd",
+    "http://delegated.example.com/uris/a",
+    "http://delegated.example.com/uris/b",
+    "http://delegated.example.com/a.dtd",
+    "urn:next",
+    "urn:publicid:-:Next:DTD+N:EN",
+    "http://nowhere.example.com/x",
+];
+
+/// Fresh directory with the catalogs above
+fn catalog_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("libxml2-{}-{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    for (file, content) in [
+        ("main.xml", MAIN),
+        ("delegate.xml", DELEGATE),
+        ("next.xml", NEXT),
+        ("loop.xml", LOOP),
+        ("not-a-catalog.xml", NOT_A_CATALOG),
+        ("sgml.cat", "-- SGML --\nPUBLIC \"-//A//EN\" \"a.dtd\"\n"),
+    ] {
+        std::fs::write(dir.join(file), content).unwrap();
+    }
+    dir
+}
+
+fn c_path(path: &Path) -> CString {
+    CString::new(path.to_str().unwrap()).unwrap()
+}
+
+fn c_opt(s: Option<&str>) -> Option<CString> {
+    s.map(|s| CString::new(s).unwrap())
+}
+
+fn ptr_of(s: &Option<CString>) -> *const xmlChar {
+    s.as_ref()
+        .map_or(ptr::null(), |s| s.as_ptr() as *const xmlChar)
+}
+
+/// Take a result of the baseline, which allocates with malloc.
+unsafe fn c_take(p: *mut xmlChar) -> Option<String> {
+    if p.is_null() {
+        return None;
+    }
+    let s = CStr::from_ptr(p as *const c_char)
+        .to_string_lossy()
+        .into_owned();
+    libc::free(p as *mut libc::c_void);
+    Some(s)
+}
+
+/// What the functions of `lib` resolve `IDS` and `URIS` to, with the
+/// default catalogs loaded from `catalogs` and with them as the catalogs
+/// of a document.
+struct Resolved {
+    ids: Vec<Option<String>>,
+    uris: Vec<Option<String>>,
+    local_ids: Vec<Option<String>>,
+    local_uris: Vec<Option<String>>,
+}
+
+/// Resolve everything with the C baseline.
+unsafe fn c_resolve_all(catalogs: &[&Path]) -> Resolved {
+    let c_lib = libxml2::libxml2_dynload::get_c_baseline();
+    c_lib.xmlCatalogCleanup();
+    let mut local = ptr::null_mut();
+    for path in catalogs {
+        assert_eq!(c_lib.xmlLoadCatalog(c_path(path).as_ptr()), 0);
+        local = c_lib.xmlCatalogAddLocal(local, c_path(path).as_ptr() as *const xmlChar);
+    }
+    let ids = |resolve: &dyn Fn(*const xmlChar, *const xmlChar) -> *mut xmlChar| {
+        IDS.iter()
+            .map(|(p, s)| {
+                let (p, s) = (c_opt(*p), c_opt(*s));
+                c_take(resolve(ptr_of(&p), ptr_of(&s)))
+            })
+            .collect()
+    };
+    let uris = |resolve: &dyn Fn(*const xmlChar) -> *mut xmlChar| {
+        URIS.iter()
+            .map(|u| c_take(resolve(CString::new(*u).unwrap().as_ptr() as *const xmlChar)))
+            .collect()
+    };
+    let resolved = Resolved {
+        ids: ids(&|p, s| c_lib.xmlCatalogResolve(p, s)),
+        uris: uris(&|u| c_lib.xmlCatalogResolveURI(u)),
+        local_ids: ids(&|p, s| c_lib.xmlCatalogLocalResolve(local, p, s)),
+        local_uris: uris(&|u| c_lib.xmlCatalogLocalResolveURI(local, u)),
+    };
+    c_lib.xmlCatalogFreeLocal(local);
+    c_lib.xmlCatalogCleanup();
+    resolved
+}
+
+#[test]
+fn test_resolve_baseline() {
+    let _lock = GLOBAL_CATALOGS.lock().unwrap_or_else(|e| e.into_inner());
+    let dir = catalog_dir("catalog-baseline");
+    let resolved = unsafe { c_resolve_all(&[&dir.join("main.xml")]) };
+    let at = |file: &str| Some(dir.join(file).to_str().unwrap().to_string());
+    assert_eq!(
+        resolved.ids,
+        [
+            at("dtd/doc1.dtd"),
+            at("dtd/spaced.dtd"),
+            at("dtd/doc1.dtd"),
+            at("dtd/doc.dtd"),
+            at("local/dtds/a/b.dtd"),
+            at("local/v2/c.dtd"),
+            at("delegated-a.dtd"),
+            // Cut after the delegates, so next.xml is not searched
+            None,
+            at("delegated-a.dtd"),
+            None,
+            Some("http://mirror.example.com/grouped.dtd".to_string()),
+            // The delegate is in a group preferring system IDs
+            None,
+            at("next-n.dtd"),
+            at("next-n.dtd"),
+            at("dtd/doc.dtd"),
+            at("dtd/doc1.dtd"),
+            at("dtd/doc1.dtd"),
+            at("next-n.dtd"),
+            at("next-n.dtd"),
+            at("next-n.dtd"),
+            None,
+        ]
+    );
+    assert_eq!(
+        resolved.uris,
+        [
+            at("schemas/schema.xsd"),
+            at("schemas/x/y.xsd"),
+            at("delegated-uri-a"),
+            None,
+            None,
+            at("next-uri"),
+            at("next-n.dtd"),
+            None,
+        ]
+    );
+    assert_eq!(resolved.local_ids, resolved.ids);
+    assert_eq!(resolved.local_uris, resolved.uris);
+}
+
+#[cfg(feature = "rust-catalog")]
+mod rust_tests {
+    use super::*;
+
+    unsafe fn take(p: *mut xmlChar) -> Option<String> {
+        if p.is_null() {
+            return None;
+        }
+        let s = CStr::from_ptr(p as *const c_char)
+            .to_string_lossy()
+            .into_owned();
+        xmlFree.unwrap()(p as *mut c_void);
+        Some(s)
+    }
+
+    /// Resolve everything with the Rust port.
+    unsafe fn rust_resolve_all(catalogs: &[&Path]) -> Resolved {
+        xmlCatalogCleanup();
+        let mut local = ptr::null_mut();
+        for path in catalogs {
+            assert_eq!(xmlLoadCatalog(c_path(path).as_ptr()), 0);
+            local = xmlCatalogAddLocal(local, c_path(path).as_ptr() as *const xmlChar);
+        }
+        let ids = |resolve: &dyn Fn(*const xmlChar, *const xmlChar) -> *mut xmlChar| {
+            IDS.iter()
+                .map(|(p, s)| {
+                    let (p, s) = (c_opt(*p), c_opt(*s));
+                    take(resolve(ptr_of(&p), ptr_of(&s)))
+                })
+                .collect()
+        };
+        let uris = |resolve: &dyn Fn(*const xmlChar) -> *mut xmlChar| {
+            URIS.iter()
+                .map(|u| take(resolve(CString::new(*u).unwrap().as_ptr() as *const xmlChar)))
+                .collect()
+        };
+        let resolved = Resolved {
+            ids: ids(&|p, s| xmlCatalogResolve(p, s)),
+            uris: uris(&|u| xmlCatalogResolveURI(u)),
+            local_ids: ids(&|p, s| xmlCatalogLocalResolve(local, p, s)),
+            local_uris: uris(&|u| xmlCatalogLocalResolveURI(local, u)),
+        };
+        xmlCatalogFreeLocal(local);
+        xmlCatalogCleanup();
+        resolved
+    }
+
+    fn assert_same(catalogs: &[&Path]) {
+        let expected = unsafe { c_resolve_all(catalogs) };
+        let actual = unsafe { rust_resolve_all(catalogs) };
+        assert_eq!(actual.ids, expected.ids);
+        assert_eq!(actual.uris, expected.uris);
+        assert_eq!(actual.local_ids, expected.local_ids);
+        assert_eq!(actual.local_uris, expected.local_uris);
+    }
+
+    #[test]
+    fn test_resolve_matches_baseline() {
+        let _lock = GLOBAL_CATALOGS.lock().unwrap_or_else(|e| e.into_inner());
+        let dir = catalog_dir("catalog-rust");
+        assert_same(&[&dir.join("main.xml")]);
+        // Catalogs that fail to load are skipped.
+        assert_same(&[
+            &dir.join("not-a-catalog.xml"),
+            &dir.join("does-not-exist.xml"),
+            &dir.join("next.xml"),
+            &dir.join("main.xml"),
+        ]);
+    }
+
+    #[test]
+    fn test_resolve_system_and_public() {
+        let _lock = GLOBAL_CATALOGS.lock().unwrap_or_else(|e| e.into_inner());
+        let c_lib = libxml2::libxml2_dynload::get_c_baseline();
+        let dir = catalog_dir("catalog-single");
+        let main = c_path(&dir.join("main.xml"));
+        unsafe {
+            c_lib.xmlCatalogCleanup();
+            xmlCatalogCleanup();
+            assert_eq!(c_lib.xmlLoadCatalog(main.as_ptr()), 0);
+            assert_eq!(xmlLoadCatalog(main.as_ptr()), 0);
+            for (p, s) in IDS {
+                if let Some(p) = c_opt(*p) {
+                    let p = p.as_ptr() as *const xmlChar;
+                    assert_eq!(
+                        take(xmlCatalogResolvePublic(p)),
+                        c_take(c_lib.xmlCatalogResolvePublic(p))
+                    );
+                }
+                if let Some(s) = c_opt(*s) {
+                    let s = s.as_ptr() as *const xmlChar;
+                    assert_eq!(
+                        take(xmlCatalogResolveSystem(s)),
+                        c_take(c_lib.xmlCatalogResolveSystem(s))
+                    );
+                }
+            }
+            assert!(xmlCatalogResolve(ptr::null(), ptr::null()).is_null());
+            assert!(xmlCatalogResolveURI(ptr::null()).is_null());
+            c_lib.xmlCatalogCleanup();
+            xmlCatalogCleanup();
+        }
+    }
+
+    #[test]
+    fn test_load_catalog() {
+        let _lock = GLOBAL_CATALOGS.lock().unwrap_or_else(|e| e.into_inner());
+        let dir = catalog_dir("catalog-load");
+        unsafe {
+            xmlCatalogCleanup();
+            let missing = c_path(&dir.join("missing.xml"));
+            assert_eq!(xmlLoadCatalog(missing.as_ptr()), -1);
+            // SGML catalogs are not supported.
+            assert_eq!(xmlLoadCatalog(c_path(&dir.join("sgml.cat")).as_ptr()), -1);
+
+            // Once there are default catalogs, files are added unread.
+            let paths = format!(
+                "{}:{} {}",
+                dir.join("next.xml").display(),
+                dir.join("missing.xml").display(),
+                dir.join("main.xml").display()
+            );
+            xmlLoadCatalogs(CString::new(paths).unwrap().as_ptr());
+            let id = c"-//Example//DTD Doc V1//EN".as_ptr() as *const xmlChar;
+            assert_eq!(
+                take(xmlCatalogResolvePublic(id)),
+                Some(dir.join("dtd/doc1.dtd").to_str().unwrap().to_string())
+            );
+            xmlCatalogCleanup();
+        }
+    }
+
+    #[test]
+    fn test_recursion_is_cut() {
+        let dir = catalog_dir("catalog-loop");
+        let catalog = c_path(&dir.join("loop.xml"));
+        let c_lib = libxml2::libxml2_dynload::get_c_baseline();
+        unsafe {
+            let c_local =
+                c_lib.xmlCatalogAddLocal(ptr::null_mut(), catalog.as_ptr() as *const xmlChar);
+            let local = xmlCatalogAddLocal(ptr::null_mut(), catalog.as_ptr() as *const xmlChar);
+            for id in [c"-//Loop//DTD L//EN", c"-//Missing//EN"] {
+                let id = id.as_ptr() as *const xmlChar;
+                assert_eq!(
+                    take(xmlCatalogLocalResolve(local, id, ptr::null())),
+                    c_take(c_lib.xmlCatalogLocalResolve(c_local, id, ptr::null()))
+                );
+            }
+            // C recurses without end looking URIs up in such catalogs.
+            let uri = c"http://example.com/x".as_ptr() as *const xmlChar;
+            assert!(xmlCatalogLocalResolveURI(local, uri).is_null());
+            xmlCatalogFreeLocal(local);
+            c_lib.xmlCatalogFreeLocal(c_local);
+        }
+    }
+
+    #[test]
+    fn test_settings() {
+        let _lock = GLOBAL_CATALOGS.lock().unwrap_or_else(|e| e.into_inner());
+        unsafe {
+            assert_eq!(xmlCatalogGetDefaults(), xmlCatalogAllow_XML_CATA_ALLOW_ALL);
+            xmlCatalogSetDefaults(xmlCatalogAllow_XML_CATA_ALLOW_GLOBAL);
+            assert_eq!(
+                xmlCatalogGetDefaults(),
+                xmlCatalogAllow_XML_CATA_ALLOW_GLOBAL
+            );
+            xmlCatalogSetDefaults(xmlCatalogAllow_XML_CATA_ALLOW_ALL);
+
+            let public = xmlCatalogPrefer_XML_CATA_PREFER_PUBLIC;
+            let system = xmlCatalogPrefer_XML_CATA_PREFER_SYSTEM;
+            assert_eq!(xmlCatalogSetDefaultPrefer(system), public);
+            assert_eq!(
+                xmlCatalogSetDefaultPrefer(xmlCatalogPrefer_XML_CATA_PREFER_NONE),
+                system
+            );
+            assert_eq!(xmlCatalogSetDefaultPrefer(public), system);
+
+            let old = xmlCatalogSetDebug(-3);
+            assert_eq!(xmlCatalogSetDebug(old), 0);
+        }
+    }
+
+    #[test]
+    fn test_prefer_system_ignores_public_delegates() {
+        let _lock = GLOBAL_CATALOGS.lock().unwrap_or_else(|e| e.into_inner());
+        let dir = catalog_dir("catalog-prefer");
+        let c_lib = libxml2::libxml2_dynload::get_c_baseline();
+        let system = xmlCatalogPrefer_XML_CATA_PREFER_SYSTEM;
+        unsafe {
+            c_lib.xmlCatalogSetDefaultPrefer(system);
+            xmlCatalogSetDefaultPrefer(system);
+            assert_same(&[&dir.join("delegate.xml"), &dir.join("main.xml")]);
+            c_lib.xmlCatalogSetDefaultPrefer(xmlCatalogPrefer_XML_CATA_PREFER_PUBLIC);
+            xmlCatalogSetDefaultPrefer(xmlCatalogPrefer_XML_CATA_PREFER_PUBLIC);
+        }
+    }
+
+    #[test]
+    fn test_parser_resolves_with_catalogs() {
+        let _lock = GLOBAL_CATALOGS.lock().unwrap_or_else(|e| e.into_inner());
+        let dir = catalog_dir("catalog-parser");
+        std::fs::write(
+            dir.join("catalog.xml"),
+            r#"<catalog xmlns="urn:oasis:names:tc:entity:xmlns:xml:catalog">
+  <public publicId="-//Example//DTD Greeting//EN" uri="greeting.dtd"/>
+</catalog>
+"#,
+        )
+        .unwrap();
+        std::fs::write(dir.join("greeting.dtd"), "<!ENTITY who 'catalog'>\n").unwrap();
+        let doc = b"<!DOCTYPE greeting PUBLIC \"-//Example//DTD Greeting//EN\" \
+            \"http://nowhere.invalid/greeting.dtd\"><greeting>hello &who;</greeting>";
+        unsafe {
+            xmlCatalogCleanup();
+            assert_eq!(xmlLoadCatalog(c_path(&dir.join("catalog.xml")).as_ptr()), 0);
+            let options = xmlParserOption_XML_PARSE_DTDLOAD
+                | xmlParserOption_XML_PARSE_NOENT
+                | xmlParserOption_XML_PARSE_NONET;
+            let parsed = xmlReadMemory(
+                doc.as_ptr() as *const c_char,
+                doc.len() as i32,
+                ptr::null(),
+                ptr::null(),
+                options as i32,
+            );
+            assert!(!parsed.is_null());
+            let content = xmlNodeGetContent(xmlDocGetRootElement(parsed));
+            assert_eq!(take(content).as_deref(), Some("hello catalog"));
+            xmlFreeDoc(parsed);
+
+            // Documents can opt out of the system catalogs.
+            let parsed = xmlReadMemory(
+                doc.as_ptr() as *const c_char,
+                doc.len() as i32,
+                ptr::null(),
+                ptr::null(),
+                (options
+                    | xmlParserOption_XML_PARSE_NO_SYS_CATALOG
+                    | xmlParserOption_XML_PARSE_RECOVER) as i32,
+            );
+            if !parsed.is_null() {
+                let content = xmlNodeGetContent(xmlDocGetRootElement(parsed));
+                assert_ne!(take(content).as_deref(), Some("hello catalog"));
+                xmlFreeDoc(parsed);
+            }
+            xmlCatalogCleanup();
+        }
+    }
+}
@@ -0,0 +1,96 @@
+//! Tests for chvalid module
+
+use libxml2::*;
+
+#[test]
+fn test_chvalid_baseline() {
+    let c_lib = libxml2_dynload::get_c_baseline();
+    unsafe {
+        assert_eq!(c_lib.xmlIsBaseChar(b'A' as u32), 1);
+        assert_eq!(c_lib.xmlIsBaseChar(0x132), 0);
+        assert_eq!(c_lib.xmlIsDigit(0x660), 1);
+        assert_eq!(c_lib.xmlIsChar(0xfffe), 0);
+        assert_eq!(c_lib.xmlIsChar(0x10000), 1);
+    }
+}
+
+#[cfg(feature = "rust-chvalid")]
+mod rust_tests {
+    use super::*;
+    use libxml2::chvalid::ffi::{self, xmlChRangeGroup};
+    use libxml2::libxml2_dynload::BaselineLib;
+    use std::slice;
+
+    /// Range groups exported by both builds
+    fn groups() -> [(&'static str, &'static xmlChRangeGroup); 6] {
+        [
+            ("xmlIsBaseCharGroup", &ffi::xmlIsBaseCharGroup),
+            ("xmlIsCharGroup", &ffi::xmlIsCharGroup),
+            ("xmlIsCombiningGroup", &ffi::xmlIsCombiningGroup),
+            ("xmlIsDigitGroup", &ffi::xmlIsDigitGroup),
+            ("xmlIsExtenderGroup", &ffi::xmlIsExtenderGroup),
+            ("xmlIsIdeographicGroup", &ffi::xmlIsIdeographicGroup),
+        ]
+    }
+
+    /// (low, high) pairs of a group, short ranges first
+    unsafe fn ranges(group: &xmlChRangeGroup) -> Vec<(u32, u32)> {
+        let mut out = Vec::new();
+        if group.nbShortRange > 0 {
+            let short = slice::from_raw_parts(group.shortRange, group.nbShortRange as usize);
+            out.extend(short.iter().map(|r| (r.low as u32, r.high as u32)));
+        }
+        if group.nbLongRange > 0 {
+            let long = slice::from_raw_parts(group.longRange, group.nbLongRange as usize);
+            out.extend(long.iter().map(|r| (r.low, r.high)));
+        }
+        out
+    }
+
+    unsafe fn c_group(name: &str) -> &'static xmlChRangeGroup {
+        let baseline = BaselineLib::load().unwrap();
+        &*(baseline.symbol(name).unwrap() as *const xmlChRangeGroup)
+    }
+
+    #[test]
+    fn test_generated_tables_match_c() {
+        for (name, group) in groups() {
+            unsafe {
+                let c = c_group(name);
+                assert_eq!(group.nbShortRange, c.nbShortRange, "{}", name);
+                assert_eq!(group.nbLongRange, c.nbLongRange, "{}", name);
+                assert_eq!(ranges(group), ranges(c), "{}", name);
+            }
+        }
+
+        let baseline = BaselineLib::load().unwrap();
+        let c_tab = unsafe { &*(baseline.symbol("xmlIsPubidChar_tab").unwrap() as *const [u8; 256]) };
+        assert_eq!(&ffi::xmlIsPubidChar_tab, c_tab);
+    }
+
+    #[test]
+    fn test_predicates_match_c() {
+        // Everything up to the CJK blocks, then the edges of every range
+        let mut samples: Vec<u32> = (0..0x3100).collect();
+        for (name, _) in groups() {
+            for (low, high) in unsafe { ranges(c_group(name)) } {
+                samples.extend([low.saturating_sub(1), low, high, high + 1]);
+            }
+        }
+        samples.extend([0xfffe, 0xffff, 0x10ffff, 0x110000, u32::MAX]);
+
+        let c_lib = libxml2_dynload::get_c_baseline();
+        for ch in samples {
+            unsafe {
+                assert_eq!(chvalid::is_base_char(ch), c_lib.xmlIsBaseChar(ch) != 0, "{:#x}", ch);
+                assert_eq!(chvalid::is_blank(ch), c_lib.xmlIsBlank(ch) != 0, "{:#x}", ch);
+                assert_eq!(chvalid::is_char(ch), c_lib.xmlIsChar(ch) != 0, "{:#x}", ch);
+                assert_eq!(chvalid::is_combining(ch), c_lib.xmlIsCombining(ch) != 0, "{:#x}", ch);
+                assert_eq!(chvalid::is_digit(ch), c_lib.xmlIsDigit(ch) != 0, "{:#x}", ch);
+                assert_eq!(chvalid::is_extender(ch), c_lib.xmlIsExtender(ch) != 0, "{:#x}", ch);
+                assert_eq!(chvalid::is_ideographic(ch), c_lib.xmlIsIdeographic(ch) != 0, "{:#x}", ch);
+                assert_eq!(chvalid::is_pubid_char(ch), c_lib.xmlIsPubidChar(ch) != 0, "{:#x}", ch);
+            }
+        }
+    }
+}
@@ -95,4 +95,180 @@ fn test_dict_qname_lookup() {
         
         xmlDictFree(dict);
     }
-}
\ No newline at end of file
+}
+
+#[cfg(feature = "rust-dict")]
+mod differential_tests {
+    use libxml2::*;
+    use std::collections::HashMap;
+    use std::ffi::CStr;
+    use std::os::raw::c_int;
+    use std::ptr;
+
+    /// The dict functions of one implementation. The static and dynamic
+    /// bindings each have their own `xmlDict` type.
+    struct Api<D> {
+        create: unsafe extern "C" fn() -> *mut D,
+        create_sub: unsafe extern "C" fn(*mut D) -> *mut D,
+        free: unsafe extern "C" fn(*mut D),
+        lookup: unsafe extern "C" fn(*mut D, *const xmlChar, c_int) -> *const xmlChar,
+        qlookup: unsafe extern "C" fn(*mut D, *const xmlChar, *const xmlChar) -> *const xmlChar,
+        exists: unsafe extern "C" fn(*mut D, *const xmlChar, c_int) -> *const xmlChar,
+        owns: unsafe extern "C" fn(*mut D, *const xmlChar) -> c_int,
+        size: unsafe extern "C" fn(*mut D) -> c_int,
+        set_limit: unsafe extern "C" fn(*mut D, usize) -> usize,
+        usage: unsafe extern "C" fn(*mut D) -> usize,
+    }
+
+    fn rust_api() -> Api<xmlDict> {
+        Api {
+            create: xmlDictCreate,
+            create_sub: xmlDictCreateSub,
+            free: xmlDictFree,
+            lookup: xmlDictLookup,
+            qlookup: xmlDictQLookup,
+            exists: xmlDictExists,
+            owns: xmlDictOwns,
+            size: xmlDictSize,
+            set_limit: xmlDictSetLimit,
+            usage: xmlDictGetUsage,
+        }
+    }
+
+    fn c_api() -> Api<impl Sized> {
+        let c = libxml2_dynload::get_c_baseline();
+        Api {
+            create: *c.xmlDictCreate.as_ref().unwrap(),
+            create_sub: *c.xmlDictCreateSub.as_ref().unwrap(),
+            free: *c.xmlDictFree.as_ref().unwrap(),
+            lookup: *c.xmlDictLookup.as_ref().unwrap(),
+            qlookup: *c.xmlDictQLookup.as_ref().unwrap(),
+            exists: *c.xmlDictExists.as_ref().unwrap(),
+            owns: *c.xmlDictOwns.as_ref().unwrap(),
+            size: *c.xmlDictSize.as_ref().unwrap(),
+            set_limit: *c.xmlDictSetLimit.as_ref().unwrap(),
+            usage: *c.xmlDictGetUsage.as_ref().unwrap(),
+        }
+    }
+
+    /// Records results in a form comparable across implementations:
+    /// strings by content, and pointers by the order they first showed up.
+    #[derive(Default)]
+    struct Trace {
+        log: Vec<String>,
+        seen: HashMap<*const xmlChar, usize>,
+    }
+
+    impl Trace {
+        fn string(&mut self, what: &str, s: *const xmlChar) {
+            if s.is_null() {
+                self.log.push(format!("{}: NULL", what));
+                return;
+            }
+            let next = self.seen.len();
+            let id = *self.seen.entry(s).or_insert(next);
+            let text = unsafe { CStr::from_ptr(s as *const _) }.to_string_lossy();
+            self.log.push(format!("{}: #{} {:?}", what, id, text));
+        }
+
+        fn value(&mut self, what: &str, v: impl std::fmt::Debug) {
+            self.log.push(format!("{}: {:?}", what, v));
+        }
+    }
+
+    fn x(s: &CStr) -> *const xmlChar {
+        s.as_ptr() as *const xmlChar
+    }
+
+    fn names() -> Vec<Vec<u8>> {
+        let mut names: Vec<Vec<u8>> = ["html", "body", "div", "a", "href", "xmlns", "", "x"]
+            .iter()
+            .map(|s| s.as_bytes().to_vec())
+            .collect();
+        names.extend((0..3000).map(|i| format!("name{}", i % 1200).into_bytes()));
+        names.push(vec![b'L'; 2500]);
+        names.push(vec![b'M'; 70000]);
+        names
+    }
+
+    unsafe fn script<D>(api: &Api<D>) -> Vec<String> {
+        let mut t = Trace::default();
+        let dict = (api.create)();
+        for (i, name) in names().iter().enumerate() {
+            let mut s = name.clone();
+            s.push(0);
+            t.string("lookup", (api.lookup)(dict, s.as_ptr(), -1));
+            if i % 7 == 0 {
+                // Lengths past the NUL stop at the NUL.
+                t.string("len", (api.lookup)(dict, s.as_ptr(), s.len() as c_int + 3));
+                t.string("prefix", (api.lookup)(dict, s.as_ptr(), name.len() as c_int / 2));
+                t.string("q", (api.qlookup)(dict, x(c"ns"), s.as_ptr()));
+                t.string("qnull", (api.qlookup)(dict, ptr::null(), s.as_ptr()));
+                t.string("qexists", (api.exists)(dict, x(c"ns:name1"), -1));
+            }
+            t.value("size", (api.size)(dict));
+            t.value("usage", (api.usage)(dict));
+        }
+        let first = (api.lookup)(dict, x(c"html"), -1);
+        t.value("owns", (api.owns)(dict, first));
+        t.value("owns+4", (api.owns)(dict, first.add(4)));
+        t.value("owns other", (api.owns)(dict, x(c"html")));
+        t.value("owns null", (api.owns)(dict, ptr::null()));
+        t.string("missing", (api.exists)(dict, x(c"missing"), -1));
+
+        let sub = (api.create_sub)(dict);
+        t.string("sub inherited", (api.lookup)(sub, x(c"body"), -1));
+        t.string("sub exists", (api.exists)(sub, x(c"name5"), -1));
+        t.string("sub new", (api.lookup)(sub, x(c"fresh"), -1));
+        t.string("sub q", (api.qlookup)(sub, x(c"ns"), x(c"name7")));
+        t.string("parent lacks", (api.exists)(dict, x(c"fresh"), -1));
+        t.value("sub size", (api.size)(sub));
+        t.value("sub owns inherited", (api.owns)(sub, first));
+        t.value("sub usage", (api.usage)(sub));
+        // The sub dict keeps its parent alive.
+        (api.free)(dict);
+        t.string("after parent free", (api.lookup)(sub, x(c"div"), -1));
+        (api.free)(sub);
+        // Freed addresses may come back.
+        t.seen.clear();
+
+        let limited = (api.create)();
+        t.value("old limit", (api.set_limit)(limited, 3000));
+        for i in 0..200 {
+            let s = format!("{}{}\0", "y".repeat(i * 7 % 300), i);
+            t.string("limited", (api.lookup)(limited, s.as_ptr(), -1));
+            t.value("limited usage", (api.usage)(limited));
+        }
+        t.value("new limit", (api.set_limit)(limited, 0));
+        (api.free)(limited);
+
+        t.value("null size", (api.size)(ptr::null_mut()));
+        t.string("null dict", (api.lookup)(ptr::null_mut(), x(c"a"), -1));
+        t.string("null name", (api.lookup)(ptr::null_mut(), ptr::null(), -1));
+        t.log
+    }
+
+    #[test]
+    fn test_dict_matches_baseline() {
+        let (c, rust) = unsafe { (script(&c_api()), script(&rust_api())) };
+        assert_eq!(c.len(), rust.len());
+        for (c, rust) in c.iter().zip(&rust) {
+            assert_eq!(c, rust);
+        }
+    }
+
+    #[test]
+    fn test_dict_reference_counting() {
+        unsafe {
+            let dict = xmlDictCreate();
+            assert_eq!(xmlDictReference(dict), 0);
+            let name = xmlDictLookup(dict, x(c"kept"), -1);
+            xmlDictFree(dict);
+            // Still referenced once.
+            assert_eq!(xmlDictLookup(dict, x(c"kept"), -1), name);
+            xmlDictFree(dict);
+            assert_eq!(xmlDictReference(ptr::null_mut()), -1);
+            xmlDictFree(ptr::null_mut());
+        }
+    }
+}
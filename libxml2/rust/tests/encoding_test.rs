@@ -0,0 +1,1032 @@
+//! Tests for encoding module
+
+use libxml2::*;
+use std::ffi::CStr;
+use std::os::raw::c_int;
+use std::ptr;
+
+/// Encodings with a built-in converter in both directions.
+const ENCODINGS: &[xmlCharEncoding] = &[
+    xmlCharEncoding_XML_CHAR_ENCODING_UTF8,
+    xmlCharEncoding_XML_CHAR_ENCODING_UTF16LE,
+    xmlCharEncoding_XML_CHAR_ENCODING_UTF16BE,
+    xmlCharEncoding_XML_CHAR_ENCODING_8859_1,
+    xmlCharEncoding_XML_CHAR_ENCODING_ASCII,
+    xmlCharEncoding_XML_CHAR_ENCODING_UTF16,
+];
+
+/// Inputs with truncated, overlong and surrogate sequences in both UTF-8
+/// and UTF-16.
+const INPUTS: &[&[u8]] = &[
+    b"",
+    b"plain ascii",
+    b"caf\xc3\xa9 \xe2\x82\xac \xf0\x9f\x98\x80",
+    b"a\xe2\x82",
+    b"\xc0\xaf",
+    b"\xed\xa0\x80",
+    b"\xf4\x90\x80\x80",
+    b"\xc3",
+    b"\xc3(",
+    b"\x80abc",
+    b"a\x00\xac\x20\x3d\xd8\x00\xde",
+    b"\x3d\xd8",
+    b"\x00\xdc\x41\x00",
+    b"\x41\x00\x42",
+];
+
+/// What a converter reports: produced bytes, `*inlen`, `*outlen` and the
+/// return value.
+type Outcome = (Vec<u8>, c_int, c_int, c_int);
+
+/// The C baseline converter for `enc`, from its built-in handler.
+unsafe fn c_converter(enc: xmlCharEncoding, output: bool) -> xmlCharEncConvFunc {
+    let c_lib = libxml2_dynload::get_c_baseline();
+    let mut handler = ptr::null_mut();
+    c_lib.xmlLookupCharEncodingHandler(enc, &mut handler);
+    if handler.is_null() {
+        // UTF-8 input needs no handler.
+        return None;
+    }
+    if output {
+        (*handler).output.func
+    } else {
+        (*handler).input.func
+    }
+}
+
+unsafe fn c_convert(func: xmlCharEncConvFunc, input: &[u8], space: usize) -> Outcome {
+    let mut out = vec![0u8; space];
+    let mut outlen = space as c_int;
+    let mut inlen = input.len() as c_int;
+    let ret = func.unwrap()(
+        ptr::null_mut(),
+        out.as_mut_ptr(),
+        &mut outlen,
+        input.as_ptr(),
+        &mut inlen,
+        0,
+    );
+    out.truncate(outlen.max(0) as usize);
+    (out, inlen, outlen, ret)
+}
+
+#[test]
+fn test_encoding_baseline() {
+    unsafe {
+        let latin1 = c_converter(xmlCharEncoding_XML_CHAR_ENCODING_8859_1, false);
+        assert_eq!(
+            c_convert(latin1, b"caf\xe9", 16),
+            (b"caf\xc3\xa9".to_vec(), 4, 5, 5)
+        );
+        assert_eq!(
+            c_convert(latin1, b"caf\xe9", 4),
+            (b"caf".to_vec(), 3, 3, xmlCharEncError_XML_ENC_ERR_SPACE)
+        );
+        assert!(c_converter(xmlCharEncoding_XML_CHAR_ENCODING_UTF8, false).is_none());
+    }
+}
+
+#[cfg(feature = "rust-encoding")]
+mod rust_tests {
+    use super::*;
+    use libxml2::encoding::{self, ffi, Converter};
+
+    fn rust_convert(convert: Converter, input: &[u8], space: usize) -> Outcome {
+        let mut out = vec![0u8; space];
+        let res = convert(&mut out, input);
+        out.truncate(res.written);
+        (out, res.read as c_int, res.written as c_int, res.ret)
+    }
+
+    /// Every converter on every input, with every output size from 0 up to
+    /// a little more than the input needs.
+    #[test]
+    fn test_converters_match_baseline() {
+        for &enc in ENCODINGS {
+            let (input_fn, output_fn) = encoding::converters(enc).unwrap();
+            for (output, convert) in [(false, input_fn), (true, output_fn)] {
+                let c_func = unsafe { c_converter(enc, output) };
+                let Some(c_func) = c_func else { continue };
+                for &input in INPUTS {
+                    for space in 0..=input.len() * 2 + 4 {
+                        let c = unsafe { c_convert(Some(c_func), input, space) };
+                        let rust = rust_convert(convert, input, space);
+                        assert_eq!(
+                            c, rust,
+                            "encoding {} output {} input {:?} space {}",
+                            enc, output, input, space
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_utf8_passthrough() {
+        let convert = encoding::converters(xmlCharEncoding_XML_CHAR_ENCODING_UTF8)
+            .unwrap()
+            .0;
+        assert_eq!(
+            rust_convert(convert, b"ab\xffc", 3),
+            (b"ab\xff".to_vec(), 3, 3, encoding::ENC_ERR_SPACE)
+        );
+        assert_eq!(rust_convert(convert, b"ab", 3), (b"ab".to_vec(), 2, 2, 2));
+    }
+
+    #[test]
+    fn test_latin1_exports_match_baseline() {
+        let c_lib = libxml2_dynload::get_c_baseline();
+        type Export = unsafe extern "C" fn(*mut u8, *mut c_int, *const u8, *mut c_int) -> c_int;
+        let pairs: [(Export, Export); 2] = [
+            (
+                *c_lib.xmlIsolat1ToUTF8.as_ref().unwrap(),
+                ffi::xmlIsolat1ToUTF8,
+            ),
+            (
+                *c_lib.xmlUTF8ToIsolat1.as_ref().unwrap(),
+                ffi::xmlUTF8ToIsolat1,
+            ),
+        ];
+        for (c_fn, rust_fn) in pairs {
+            for &input in INPUTS {
+                for space in 0..=input.len() * 2 + 1 {
+                    let run = |f: Export| unsafe {
+                        let mut out = vec![0u8; space];
+                        let mut outlen = space as c_int;
+                        let mut inlen = input.len() as c_int;
+                        let ret = f(out.as_mut_ptr(), &mut outlen, input.as_ptr(), &mut inlen);
+                        out.truncate(outlen.max(0) as usize);
+                        (out, inlen, outlen, ret)
+                    };
+                    assert_eq!(run(c_fn), run(rust_fn), "input {:?} space {}", input, space);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_latin1_exports_null() {
+        let mut len = 0;
+        let mut out = [0u8; 4];
+        unsafe {
+            assert_eq!(
+                ffi::xmlIsolat1ToUTF8(out.as_mut_ptr(), &mut len, ptr::null(), &mut len),
+                encoding::ENC_ERR_INTERNAL
+            );
+            assert_eq!(
+                ffi::xmlUTF8ToIsolat1(ptr::null_mut(), &mut len, b"a".as_ptr(), &mut len),
+                encoding::ENC_ERR_INTERNAL
+            );
+        }
+    }
+
+    /// A converter of the C baseline's iconv handler for `name`, which is
+    /// how C handles UCS-4.
+    struct Iconv {
+        handler: *mut dynamic_bindings::xmlCharEncodingHandler,
+        output: bool,
+    }
+
+    impl Iconv {
+        /// The decoder or encoder for `name`. The differential tests need
+        /// it, so a baseline built without iconv fails them.
+        fn open(name: &CStr, output: bool) -> Iconv {
+            let c_lib = libxml2_dynload::get_c_baseline();
+            let mut handler = ptr::null_mut();
+            unsafe {
+                c_lib.xmlOpenCharEncodingHandler(name.as_ptr(), output as c_int, &mut handler);
+            }
+            assert!(
+                !handler.is_null(),
+                "the C baseline has no iconv converter for {name:?}; build it with iconv"
+            );
+            Iconv { handler, output }
+        }
+
+        /// The decoder and encoder for `name`.
+        fn open_pair(name: &CStr) -> (Iconv, Iconv) {
+            (Iconv::open(name, false), Iconv::open(name, true))
+        }
+
+        /// Like `c_convert`, with every success reported as 0, which is
+        /// what iconv returns.
+        fn convert(&self, input: &[u8], space: usize) -> Outcome {
+            unsafe {
+                let h = &*self.handler;
+                let (func, ctxt) = if self.output {
+                    (h.output.func, h.outputCtxt)
+                } else {
+                    (h.input.func, h.inputCtxt)
+                };
+                let mut out = vec![0u8; space];
+                let mut outlen = space as c_int;
+                let mut inlen = input.len() as c_int;
+                let ret = func.unwrap()(
+                    ctxt,
+                    out.as_mut_ptr(),
+                    &mut outlen,
+                    input.as_ptr(),
+                    &mut inlen,
+                    0,
+                );
+                out.truncate(outlen.max(0) as usize);
+                (out, inlen, outlen, ret)
+            }
+        }
+    }
+
+    impl Drop for Iconv {
+        fn drop(&mut self) {
+            let c_lib = libxml2_dynload::get_c_baseline();
+            unsafe { c_lib.xmlCharEncCloseFunc(self.handler) };
+        }
+    }
+
+    fn utf32(units: &[u32], big_endian: bool) -> Vec<u8> {
+        units
+            .iter()
+            .flat_map(|&c| {
+                if big_endian {
+                    c.to_be_bytes()
+                } else {
+                    c.to_le_bytes()
+                }
+            })
+            .collect()
+    }
+
+    /// Decode UTF-32 with the standard library: the text up to the first
+    /// invalid unit, and the offset of that unit.
+    fn reference_utf32(input: &[u8], big_endian: bool) -> (String, Option<usize>) {
+        let mut text = String::new();
+        for (i, unit) in input.chunks_exact(4).enumerate() {
+            let unit = unit.try_into().unwrap();
+            let c = if big_endian {
+                u32::from_be_bytes(unit)
+            } else {
+                u32::from_le_bytes(unit)
+            };
+            match char::from_u32(c) {
+                Some(c) => text.push(c),
+                None => return (text, Some(i * 4)),
+            }
+        }
+        (text, None)
+    }
+
+    /// UTF-32 units, valid and not: U+10FFFF and the value just above it,
+    /// surrogates, and a unit cut off by the end of the input.
+    fn utf32_inputs(big_endian: bool) -> Vec<Vec<u8>> {
+        let text: Vec<u32> = "x\u{e9}\u{20ac}\u{1f600}"
+            .chars()
+            .map(|c| c as u32)
+            .collect();
+        let mut inputs = vec![
+            utf32(&text, big_endian),
+            utf32(&[0x41, 0x10FFFF, 0x42], big_endian),
+            utf32(&[0x41, 0x110000, 0x42], big_endian),
+            utf32(&[0xFFFFFFFF], big_endian),
+            utf32(&[0x41, 0xD800, 0xDC00], big_endian),
+            utf32(&[0xDFFF], big_endian),
+        ];
+        let mut cut = utf32(&[0x41, 0x20AC], big_endian);
+        cut.truncate(6);
+        inputs.push(cut);
+        inputs
+    }
+
+    #[test]
+    fn test_utf32_matches_reference() {
+        for big_endian in [false, true] {
+            let name = if big_endian { "UTF-32BE" } else { "UTF-32LE" };
+            let (to_utf8, from_utf8) = encoding::converters_by_name(name).unwrap();
+            for input in utf32_inputs(big_endian) {
+                let (text, bad) = reference_utf32(&input, big_endian);
+                let (out, read, written, ret) = rust_convert(to_utf8, &input, input.len() * 2);
+                assert_eq!(out, text.as_bytes(), "{name} {input:?}");
+                assert_eq!(written as usize, text.len());
+                match bad {
+                    Some(at) => assert_eq!((read, ret), (at as c_int, encoding::ENC_ERR_INPUT)),
+                    None => assert_eq!((read, ret), ((input.len() & !3) as c_int, written)),
+                }
+
+                let back = rust_convert(from_utf8, text.as_bytes(), input.len() + 4);
+                assert_eq!(back.0, &input[..bad.unwrap_or(input.len() & !3)]);
+            }
+        }
+    }
+
+    /// Both directions of both byte orders against iconv in the C
+    /// baseline, with every output size.
+    #[test]
+    fn test_utf32_matches_iconv() {
+        for (name, big_endian) in [(c"UTF-32LE", false), (c"UTF-32BE", true)] {
+            let (c_in, c_out) = Iconv::open_pair(name);
+            let (to_utf8, from_utf8) =
+                encoding::converters_by_name(name.to_str().unwrap()).unwrap();
+            let cases = utf32_inputs(big_endian)
+                .into_iter()
+                .map(|input| (input, &c_in, to_utf8))
+                .chain(
+                    INPUTS
+                        .iter()
+                        .map(|input| (input.to_vec(), &c_out, from_utf8)),
+                );
+            for (input, c, convert) in cases {
+                for space in 0..=input.len() * 4 + 4 {
+                    let mut rust = rust_convert(convert, &input, space);
+                    rust.3 = rust.3.min(0);
+                    let mut c_res = c.convert(&input, space);
+                    // When an invalid sequence meets a full output, glibc
+                    // reports either error depending on the sequence.
+                    let errors = [encoding::ENC_ERR_INPUT, encoding::ENC_ERR_SPACE];
+                    if errors.contains(&rust.3) && errors.contains(&c_res.3) && rust.2 == c_res.2 {
+                        c_res.3 = rust.3;
+                    }
+                    assert_eq!(
+                        c_res, rust,
+                        "{name:?} output {} input {input:?} space {space}",
+                        c.output
+                    );
+                }
+            }
+        }
+    }
+
+    /// Input with and without a byte order mark. With one, the mark picks
+    /// the converter and is skipped; without, "UTF-32" means little-endian
+    /// as "UCS-4" does in the C alias table.
+    #[test]
+    fn test_utf32_bom() {
+        let text = "<doc>\u{e9}\u{1f600}</doc>";
+        let units: Vec<u32> = text.chars().map(|c| c as u32).collect();
+        let decode = |input: &[u8]| {
+            let (enc, skip) = match encoding::detect_utf32_bom(input) {
+                Some(enc) => (enc, 4),
+                None => (xmlCharEncoding_XML_CHAR_ENCODING_UCS4LE, 0),
+            };
+            let to_utf8 = encoding::converters(enc).unwrap().0;
+            rust_convert(to_utf8, &input[skip..], 64).0
+        };
+
+        for big_endian in [false, true] {
+            let mut marked = utf32(&[0xFEFF], big_endian);
+            marked.extend(utf32(&units, big_endian));
+            assert_eq!(decode(&marked), text.as_bytes());
+
+            let (out, read, _, ret) = Iconv::open(c"UTF-32", false).convert(&marked, 64);
+            assert_eq!(
+                (out, read, ret),
+                (text.as_bytes().to_vec(), marked.len() as c_int, 0)
+            );
+        }
+        assert_eq!(decode(&utf32(&units, false)), text.as_bytes());
+        for name in ["UTF-32", "ucs-4", "UCS4"] {
+            let to_utf8 = encoding::converters_by_name(name).unwrap().0;
+            let input = utf32(&units, false);
+            assert_eq!(rust_convert(to_utf8, &input, 64).0, text.as_bytes());
+        }
+
+        // A big-endian mark read as little-endian is above U+10FFFF.
+        let (_, _, _, ret) = rust_convert(encoding::utf32le_to_utf8, &utf32(&[0xFEFF], true), 8);
+        assert_eq!(ret, encoding::ENC_ERR_INPUT);
+    }
+
+    /// Whether `out` does not end in a cut-off UTF-8 sequence. Invalid
+    /// sequences before are skipped, as a strict UTF-8 decoder passes them
+    /// on.
+    fn ends_on_boundary(mut out: &[u8]) -> bool {
+        loop {
+            match std::str::from_utf8(out) {
+                Ok(_) => return true,
+                Err(e) => match e.error_len() {
+                    Some(len) => out = &out[e.valid_up_to() + len..],
+                    None => return false,
+                },
+            }
+        }
+    }
+
+    /// Feed `input` to a decoder in the pieces starting at `cuts`.
+    fn decode_pieces(
+        decoder: &mut encoding::Decoder,
+        input: &[u8],
+        cuts: &[usize],
+    ) -> Result<Vec<u8>, encoding::DecodeError> {
+        let mut out = Vec::new();
+        let mut start = 0;
+        for &end in cuts.iter().chain([&input.len()]) {
+            let appended = decoder.decode(&input[start..end], &mut out)?;
+            assert!(ends_on_boundary(&out), "{out:?}");
+            assert!(appended <= out.len());
+            start = end;
+        }
+        decoder.finish(&mut out)?;
+        Ok(out)
+    }
+
+    const DOCUMENT: &str =
+        "<?xml version=\"1.0\"?>\n<doc a=\"\u{e9}\">caf\u{e9} \u{20ac}\u{1f600}\u{10ffff}</doc>\n";
+
+    /// A UTF-8 document split in two at every offset, and fed one byte at
+    /// a time, decodes as it does whole.
+    #[test]
+    fn test_decoder_split_utf8() {
+        let input = DOCUMENT.as_bytes();
+        let new =
+            || encoding::Decoder::for_encoding(xmlCharEncoding_XML_CHAR_ENCODING_UTF8).unwrap();
+        let whole = decode_pieces(&mut new(), input, &[]).unwrap();
+        assert_eq!(whole, input);
+        for cut in 0..=input.len() {
+            assert_eq!(
+                decode_pieces(&mut new(), input, &[cut]),
+                Ok(whole.clone()),
+                "cut {cut}"
+            );
+        }
+        let bytes: Vec<usize> = (1..input.len()).collect();
+        assert_eq!(decode_pieces(&mut new(), input, &bytes), Ok(whole));
+    }
+
+    /// The same with the other multi-byte encodings, at every pair of cuts.
+    #[test]
+    fn test_decoder_split_encodings() {
+        let encodings = [
+            xmlCharEncoding_XML_CHAR_ENCODING_UTF16LE,
+            xmlCharEncoding_XML_CHAR_ENCODING_UTF16BE,
+            xmlCharEncoding_XML_CHAR_ENCODING_UCS4LE,
+            xmlCharEncoding_XML_CHAR_ENCODING_UCS4BE,
+        ];
+        for enc in encodings {
+            let (_, from_utf8) = encoding::converters(enc).unwrap();
+            let (input, _, _, _) = rust_convert(from_utf8, DOCUMENT.as_bytes(), 1024);
+            for first in 0..=input.len() {
+                for second in first..=input.len() {
+                    let mut decoder = encoding::Decoder::for_encoding(enc).unwrap();
+                    assert_eq!(
+                        decode_pieces(&mut decoder, &input, &[first, second]),
+                        Ok(DOCUMENT.as_bytes().to_vec()),
+                        "encoding {enc} cuts {first} {second}"
+                    );
+                }
+            }
+        }
+    }
+
+    fn input_error(offset: usize) -> encoding::DecodeError {
+        encoding::DecodeError {
+            code: encoding::ENC_ERR_INPUT,
+            offset,
+        }
+    }
+
+    #[test]
+    fn test_decoder_errors() {
+        let utf8 =
+            || encoding::Decoder::for_encoding(xmlCharEncoding_XML_CHAR_ENCODING_UTF8).unwrap();
+        assert_eq!(utf8().policy(), encoding::ErrorPolicy::Strict);
+
+        // A character cut off by the end of the document
+        let mut decoder = utf8();
+        let mut out = Vec::new();
+        assert_eq!(decoder.decode(b"ab\xe2\x82", &mut out), Ok(2));
+        assert_eq!(decoder.pending(), b"\xe2\x82");
+        assert_eq!(decoder.finish(&mut out), Err(input_error(2)));
+
+        // Invalid input after a cut, with the text before it kept
+        let mut decoder = encoding::Decoder::new(encoding::utf16le_to_utf8, 2);
+        let mut out = Vec::new();
+        assert_eq!(decoder.decode(b"a\x00b\x00\x3d", &mut out), Ok(2));
+        assert_eq!(
+            decoder.decode(b"\xd8\x41\x00\x00\xdc", &mut out),
+            Err(input_error(4))
+        );
+        assert_eq!(out, b"ab");
+
+        let mut decoder = encoding::Decoder::new(encoding::ascii_to_ascii, 1);
+        let mut out = Vec::new();
+        assert_eq!(decoder.decode(b"ok", &mut out), Ok(2));
+        assert_eq!(decoder.decode(b"!\xff", &mut out), Err(input_error(3)));
+        assert_eq!(out, b"ok!");
+    }
+
+    /// Input with invalid sequences, as the encoding, the offset a strict
+    /// decoder stops at and the text a replacing one produces.
+    const INVALID: &[(xmlCharEncoding, &[u8], usize, &str)] = &[
+        (
+            xmlCharEncoding_XML_CHAR_ENCODING_ASCII,
+            b"<a>ok\xff\xfe!</a>",
+            5,
+            "<a>ok\u{fffd}\u{fffd}!</a>",
+        ),
+        (
+            xmlCharEncoding_XML_CHAR_ENCODING_UTF16LE,
+            b"a\x00\x00\xdcb\x00\x3d\xd8c\x00\x3d\xd8\x00\xde",
+            2,
+            "a\u{fffd}b\u{fffd}c\u{1f600}",
+        ),
+        (
+            xmlCharEncoding_XML_CHAR_ENCODING_UTF16BE,
+            b"\x00a\xdc\x00\xdc\x00\x00b",
+            2,
+            "a\u{fffd}\u{fffd}b",
+        ),
+        (
+            xmlCharEncoding_XML_CHAR_ENCODING_UCS4LE,
+            b"a\x00\x00\x00\x00\x00\x11\x00\x00\xd8\x00\x00b\x00\x00\x00",
+            4,
+            "a\u{fffd}\u{fffd}b",
+        ),
+    ];
+
+    /// A strict decoder reports where the first invalid sequence starts. A
+    /// replacing one writes one U+FFFD per invalid unit and goes on, with
+    /// the same result wherever the input is split.
+    #[test]
+    fn test_decoder_replace() {
+        for &(enc, input, offset, text) in INVALID {
+            let mut decoder = encoding::Decoder::for_encoding(enc).unwrap();
+            assert_eq!(
+                decode_pieces(&mut decoder, input, &[]),
+                Err(input_error(offset))
+            );
+            for first in 0..=input.len() {
+                for second in first..=input.len() {
+                    let mut decoder = encoding::Decoder::for_encoding(enc).unwrap();
+                    decoder.set_policy(encoding::ErrorPolicy::Replace);
+                    assert_eq!(
+                        decode_pieces(&mut decoder, input, &[first, second]),
+                        Ok(text.as_bytes().to_vec()),
+                        "encoding {enc} cuts {first} {second}"
+                    );
+                }
+                // Cut right before the error, the strict decoder fails in
+                // the second piece and still reports the offset in the
+                // whole input.
+                let mut decoder = encoding::Decoder::for_encoding(enc).unwrap();
+                assert_eq!(
+                    decode_pieces(&mut decoder, input, &[first]),
+                    Err(input_error(offset)),
+                    "encoding {enc} cut {first}"
+                );
+            }
+        }
+    }
+
+    /// The UTF-8 converter copies its input like C, so a strict decoder
+    /// only fails on a character cut off at the end. A replacing one
+    /// checks the UTF-8 and replaces each maximal invalid subpart, as
+    /// `String::from_utf8_lossy` does.
+    #[test]
+    fn test_decoder_replace_utf8() {
+        let input = b"a\xc0\xafb\xe2\x82(c\xed\xa0\x80d\xf0\x9f";
+        let text = String::from_utf8_lossy(input);
+        assert_eq!(text.matches('\u{fffd}').count(), 7);
+        for cut in 0..=input.len() {
+            let mut decoder =
+                encoding::Decoder::for_encoding(xmlCharEncoding_XML_CHAR_ENCODING_UTF8).unwrap();
+            assert_eq!(
+                decode_pieces(&mut decoder, input, &[cut]),
+                Err(input_error(input.len() - 2))
+            );
+            decoder.set_policy(encoding::ErrorPolicy::Replace);
+            let mut out = Vec::new();
+            assert_eq!(decoder.finish(&mut out), Ok(3));
+            assert_eq!(out, "\u{fffd}".as_bytes());
+
+            let mut decoder =
+                encoding::Decoder::for_encoding(xmlCharEncoding_XML_CHAR_ENCODING_UTF8).unwrap();
+            decoder.set_policy(encoding::ErrorPolicy::Replace);
+            assert_eq!(
+                decode_pieces(&mut decoder, input, &[cut]),
+                Ok(text.as_bytes().to_vec()),
+                "cut {cut}"
+            );
+        }
+    }
+
+    #[cfg(feature = "shift-jis")]
+    mod shift_jis {
+        use super::*;
+        use libxml2::encoding::{sjis_to_utf8, utf8_to_sjis, Converted};
+
+        fn convert(convert: Converter, input: &[u8], space: usize) -> (Vec<u8>, Converted) {
+            let mut out = vec![0; space];
+            let res = convert(&mut out, input);
+            out.truncate(res.written);
+            (out, res)
+        }
+
+        fn round_trip(sjis: &[u8], text: &str) {
+            let (utf8, res) = convert(sjis_to_utf8, sjis, sjis.len() * 3);
+            assert_eq!(utf8, text.as_bytes());
+            assert_eq!((res.read, res.ret), (sjis.len(), text.len() as c_int));
+            let (back, res) = convert(utf8_to_sjis, text.as_bytes(), sjis.len());
+            assert_eq!(back, sjis);
+            assert_eq!((res.read, res.ret), (text.len(), sjis.len() as c_int));
+        }
+
+        #[test]
+        fn test_ascii() {
+            let ascii: Vec<u8> = (0..0x80).collect();
+            round_trip(&ascii, std::str::from_utf8(&ascii).unwrap());
+        }
+
+        #[test]
+        fn test_kanji() {
+            round_trip(
+                b"\x93\xfa\x96\x7b\x8c\xea\x8a\xbf\x8e\x9a",
+                "\u{65e5}\u{672c}\u{8a9e}\u{6f22}\u{5b57}",
+            );
+            round_trip(b"<\x93\x8c\x8b\x9e/>", "<\u{6771}\u{4eac}/>");
+            // Full-width punctuation and the last rows of the grid.
+            round_trip(b"\x81\x40\x81\x5f\xea\xa4", "\u{3000}\u{ff3c}\u{7199}");
+        }
+
+        #[test]
+        fn test_half_width_katakana() {
+            let sjis: Vec<u8> = (0xA1..=0xDF).collect();
+            let text: String = (0xFF61..=0xFF9F)
+                .map(|c| char::from_u32(c).unwrap())
+                .collect();
+            round_trip(&sjis, &text);
+        }
+
+        #[test]
+        fn test_malformed() {
+            let stop = |read, written| Converted {
+                read,
+                written,
+                ret: encoding::ENC_ERR_INPUT,
+            };
+            // Trail bytes out of range.
+            assert_eq!(convert(sjis_to_utf8, b"\x81\x20", 8).1, stop(0, 0));
+            assert_eq!(convert(sjis_to_utf8, b"a\x93\x7f", 8).1, stop(1, 1));
+            assert_eq!(convert(sjis_to_utf8, b"\x93\xfd", 8).1, stop(0, 0));
+            // Bytes that start no character, even at the end.
+            for b in [0x80, 0xA0, 0xF0, 0xFD, 0xFF] {
+                assert_eq!(convert(sjis_to_utf8, &[b'a', b, b'b'], 8).1, stop(1, 1));
+                assert_eq!(convert(sjis_to_utf8, &[b'a', b], 8).1, stop(1, 1));
+            }
+            // An unassigned row.
+            assert_eq!(convert(sjis_to_utf8, b"\x85\x40", 8).1, stop(0, 0));
+
+            // A lead byte at the end is left for the next call.
+            let (out, res) = convert(sjis_to_utf8, b"a\x93", 8);
+            assert_eq!((out, res.read, res.ret), (b"a".to_vec(), 1, 1));
+
+            // Characters Shift-JIS can't represent, and bad UTF-8.
+            assert_eq!(convert(utf8_to_sjis, "a\u{e9}".as_bytes(), 8).1, stop(1, 1));
+            assert_eq!(
+                convert(utf8_to_sjis, "\u{1f600}".as_bytes(), 8).1,
+                stop(0, 0)
+            );
+            assert_eq!(
+                convert(utf8_to_sjis, b"\xe6\x97", 8).1,
+                Converted {
+                    read: 0,
+                    written: 0,
+                    ret: 0
+                }
+            );
+            assert_eq!(convert(utf8_to_sjis, b"\xc0\xaf", 8).1, stop(0, 0));
+        }
+
+        #[test]
+        fn test_partial_output() {
+            let (out, res) = convert(sjis_to_utf8, b"a\x93\xfa", 3);
+            assert_eq!(out, b"a");
+            assert_eq!((res.read, res.ret), (1, encoding::ENC_ERR_SPACE));
+            let (out, res) = convert(utf8_to_sjis, "a\u{65e5}".as_bytes(), 2);
+            assert_eq!(out, b"a");
+            assert_eq!((res.read, res.ret), (1, encoding::ENC_ERR_SPACE));
+        }
+
+        #[test]
+        fn test_registry() {
+            for name in ["Shift_JIS", "SJIS", "shift-jis", "MS_Kanji", "x-sjis"] {
+                let (to_utf8, _) = encoding::converters_by_name(name).unwrap();
+                assert_eq!(convert(to_utf8, b"\x93\xfa", 8).0, "\u{65e5}".as_bytes());
+            }
+            assert!(encoding::converters(xmlCharEncoding_XML_CHAR_ENCODING_SHIFT_JIS).is_some());
+        }
+
+        /// Every lead and trail byte pair, and every character back,
+        /// against glibc's Shift_JIS through the C baseline. glibc maps
+        /// 0x5C and 0x7E to the yen sign and overline, so those are left
+        /// out.
+        #[test]
+        fn test_matches_iconv() {
+            let (c_in, c_out) = Iconv::open_pair(c"Shift_JIS");
+            let mut inputs: Vec<Vec<u8>> = (0x80..=0xFF).map(|b| vec![b]).collect();
+            for lead in 0x80..=0xFF {
+                inputs.extend((0..=0xFF).map(|trail| vec![lead, trail]));
+            }
+            let mut texts = Vec::new();
+            for input in inputs {
+                let mut rust = rust_convert(sjis_to_utf8, &input, 8);
+                if rust.0.iter().any(|b| b"\\~".contains(b)) {
+                    continue;
+                }
+                rust.3 = rust.3.min(0);
+                assert_eq!(c_in.convert(&input, 8), rust, "{input:x?}");
+                let decoded = std::str::from_utf8(&rust.0).unwrap();
+                if rust.1 as usize == input.len() && decoded.chars().count() == 1 {
+                    texts.push(rust.0);
+                }
+            }
+            assert_eq!(texts.len(), 6879 + 63);
+
+            for text in texts {
+                let mut rust = rust_convert(utf8_to_sjis, &text, 8);
+                rust.3 = rust.3.min(0);
+                assert_eq!(c_out.convert(&text, 8), rust, "{text:x?}");
+            }
+        }
+    }
+
+    #[cfg(feature = "big5")]
+    mod big5 {
+        use super::*;
+        use libxml2::encoding::{big5_to_utf8, utf8_to_big5, Converted};
+
+        fn convert(convert: Converter, input: &[u8], space: usize) -> (Vec<u8>, Converted) {
+            let mut out = vec![0; space];
+            let res = convert(&mut out, input);
+            out.truncate(res.written);
+            (out, res)
+        }
+
+        fn round_trip(big5: &[u8], text: &str) {
+            let (utf8, res) = convert(big5_to_utf8, big5, big5.len() * 3);
+            assert_eq!(utf8, text.as_bytes());
+            assert_eq!((res.read, res.ret), (big5.len(), text.len() as c_int));
+            let (back, res) = convert(utf8_to_big5, text.as_bytes(), big5.len());
+            assert_eq!(back, big5);
+            assert_eq!((res.read, res.ret), (text.len(), big5.len() as c_int));
+        }
+
+        #[test]
+        fn test_ascii() {
+            let ascii: Vec<u8> = (0..0x80).collect();
+            round_trip(&ascii, std::str::from_utf8(&ascii).unwrap());
+        }
+
+        #[test]
+        fn test_traditional_chinese() {
+            round_trip(
+                b"\xa4\xa4\xa4\xe5 \xa5\x78\xc6\x57 \xc1\x63\xc5\xe9\xa6\x72",
+                "\u{4e2d}\u{6587} \u{53f0}\u{7063} \u{7e41}\u{9ad4}\u{5b57}",
+            );
+            round_trip(b"<\xa7\x41\xa6\x6e/>", "<\u{4f60}\u{597d}/>");
+            // Full-width punctuation and both ends of the less frequent
+            // characters.
+            round_trip(
+                b"\xa1\x40\xa1\x41\xc9\x40\xf9\xd5",
+                "\u{3000}\u{ff0c}\u{4e42}\u{9f98}",
+            );
+        }
+
+        #[test]
+        fn test_truncated() {
+            // A lead byte at the end is left for the next call.
+            let (out, res) = convert(big5_to_utf8, b"a\xa4\xa4\xa4", 8);
+            assert_eq!(out, "a\u{4e2d}".as_bytes());
+            assert_eq!((res.read, res.ret), (3, 4));
+            let (out, res) = convert(big5_to_utf8, b"\xa4", 8);
+            assert_eq!((out.len(), res.read, res.ret), (0, 0, 0));
+
+            // The caller resumes with the lead byte and the rest.
+            let (out, res) = convert(big5_to_utf8, b"\xa4\xe5", 8);
+            assert_eq!((out, res.read), ("\u{6587}".as_bytes().to_vec(), 2));
+        }
+
+        #[test]
+        fn test_malformed() {
+            let stop = |read, written| Converted {
+                read,
+                written,
+                ret: encoding::ENC_ERR_INPUT,
+            };
+            // Trail bytes out of range.
+            assert_eq!(convert(big5_to_utf8, b"\xa4\x30", 8).1, stop(0, 0));
+            assert_eq!(convert(big5_to_utf8, b"a\xa4\x7f", 8).1, stop(1, 1));
+            assert_eq!(convert(big5_to_utf8, b"\xa4\xa0", 8).1, stop(0, 0));
+            assert_eq!(convert(big5_to_utf8, b"\xa4\xff", 8).1, stop(0, 0));
+            // Bytes that start no character, even at the end.
+            for b in [0x80, 0xA0, 0xFA, 0xFF] {
+                assert_eq!(convert(big5_to_utf8, &[b'a', b, b'b'], 8).1, stop(1, 1));
+                assert_eq!(convert(big5_to_utf8, &[b'a', b], 8).1, stop(1, 1));
+            }
+            // Unassigned pairs and the vendor extensions.
+            assert_eq!(convert(big5_to_utf8, b"\xc8\x7e", 8).1, stop(0, 0));
+            assert_eq!(convert(big5_to_utf8, b"\xf9\xd6", 8).1, stop(0, 0));
+
+            // Characters Big5 can't represent, and bad UTF-8.
+            assert_eq!(convert(utf8_to_big5, "a\u{e9}".as_bytes(), 8).1, stop(1, 1));
+            assert_eq!(
+                convert(utf8_to_big5, "\u{20000}".as_bytes(), 8).1,
+                stop(0, 0)
+            );
+            assert_eq!(convert(utf8_to_big5, b"\xc0\xaf", 8).1, stop(0, 0));
+        }
+
+        #[test]
+        fn test_partial_output() {
+            let (out, res) = convert(big5_to_utf8, b"a\xa4\xa4", 3);
+            assert_eq!(out, b"a");
+            assert_eq!((res.read, res.ret), (1, encoding::ENC_ERR_SPACE));
+            let (out, res) = convert(utf8_to_big5, "a\u{4e2d}".as_bytes(), 2);
+            assert_eq!(out, b"a");
+            assert_eq!((res.read, res.ret), (1, encoding::ENC_ERR_SPACE));
+        }
+
+        #[test]
+        fn test_registry() {
+            for name in ["Big5", "BIG-FIVE", "big-5", "CN-Big5", "csBig5"] {
+                let (to_utf8, _) = encoding::converters_by_name(name).unwrap();
+                assert_eq!(convert(to_utf8, b"\xa4\xa4", 8).0, "\u{4e2d}".as_bytes());
+            }
+        }
+
+        /// Every pair in the two blocks of Chinese characters, A440-C67E
+        /// and C940-F9D5, and every character back, against glibc's BIG5
+        /// through the C baseline. The symbols and vendor extensions
+        /// around them differ between Big5 tables.
+        #[test]
+        fn test_matches_iconv() {
+            let (c_in, c_out) = Iconv::open_pair(c"BIG5");
+            let trails: Vec<u8> = (0x40..=0x7E).chain(0xA1..=0xFE).collect();
+            let mut inputs = Vec::new();
+            for lead in 0xA4..=0xF9u8 {
+                for &trail in &trails {
+                    let pair = u16::from_be_bytes([lead, trail]);
+                    if pair <= 0xC67E || (0xC940..=0xF9D5).contains(&pair) {
+                        inputs.push([lead, trail]);
+                    }
+                }
+            }
+            let mut texts = Vec::new();
+            for input in inputs {
+                let mut rust = rust_convert(big5_to_utf8, &input, 8);
+                rust.3 = rust.3.min(0);
+                assert_eq!(c_in.convert(&input, 8), rust, "{input:x?}");
+                if rust.3 == 0 {
+                    texts.push(rust.0);
+                }
+            }
+            assert_eq!(texts.len(), 5401 + 7652);
+
+            for text in texts {
+                let mut rust = rust_convert(utf8_to_big5, &text, 8);
+                rust.3 = rust.3.min(0);
+                assert_eq!(c_out.convert(&text, 8), rust, "{text:x?}");
+            }
+        }
+    }
+
+    #[cfg(feature = "euc-jp")]
+    mod euc_jp {
+        use super::*;
+        use libxml2::encoding::{euc_jp_to_utf8, utf8_to_euc_jp, Converted};
+
+        fn convert(convert: Converter, input: &[u8], space: usize) -> (Vec<u8>, Converted) {
+            let mut out = vec![0; space];
+            let res = convert(&mut out, input);
+            out.truncate(res.written);
+            (out, res)
+        }
+
+        fn round_trip(euc: &[u8], text: &str) {
+            let (utf8, res) = convert(euc_jp_to_utf8, euc, euc.len() * 3);
+            assert_eq!(utf8, text.as_bytes());
+            assert_eq!((res.read, res.ret), (euc.len(), text.len() as c_int));
+            let (back, res) = convert(utf8_to_euc_jp, text.as_bytes(), euc.len());
+            assert_eq!(back, euc);
+            assert_eq!((res.read, res.ret), (text.len(), euc.len() as c_int));
+        }
+
+        #[test]
+        fn test_jis0208() {
+            let ascii: Vec<u8> = (0..0x80).collect();
+            round_trip(&ascii, std::str::from_utf8(&ascii).unwrap());
+            round_trip(b"\xc6\xfc\xcb\xdc\xb8\xec", "\u{65e5}\u{672c}\u{8a9e}");
+            round_trip(
+                b"<\xc5\xec\xb5\xfe/>\xa1\xa1",
+                "<\u{6771}\u{4eac}/>\u{3000}",
+            );
+        }
+
+        #[test]
+        fn test_single_shifts() {
+            // SS2: half-width katakana.
+            let euc: Vec<u8> = (0xA1..=0xDF).flat_map(|b| [0x8E, b]).collect();
+            let text: String = (0xFF61..=0xFF9F)
+                .map(|c| char::from_u32(c).unwrap())
+                .collect();
+            round_trip(&euc, &text);
+            // SS3: JIS X 0212, next to JIS X 0208 and ASCII.
+            round_trip(
+                b"\x8f\xb0\xa1a\xc6\xfc\x8f\xa2\xaf\x8f\xed\xe3",
+                "\u{4e02}a\u{65e5}\u{2d8}\u{9fa5}",
+            );
+        }
+
+        #[test]
+        fn test_malformed() {
+            let stop = |read, written| Converted {
+                read,
+                written,
+                ret: encoding::ENC_ERR_INPUT,
+            };
+            // Second bytes out of range.
+            assert_eq!(convert(euc_jp_to_utf8, b"\xc6\x41", 8).1, stop(0, 0));
+            assert_eq!(convert(euc_jp_to_utf8, b"a\xc6\xff", 8).1, stop(1, 1));
+            assert_eq!(convert(euc_jp_to_utf8, b"a\x8e\xe0", 8).1, stop(1, 1));
+            assert_eq!(convert(euc_jp_to_utf8, b"\x8e\x41", 8).1, stop(0, 0));
+            assert_eq!(convert(euc_jp_to_utf8, b"\x8f\x41\xa1", 8).1, stop(0, 0));
+            assert_eq!(convert(euc_jp_to_utf8, b"\x8f\xb0\x41", 8).1, stop(0, 0));
+            // The single shifts don't nest.
+            assert_eq!(convert(euc_jp_to_utf8, b"\x8e\x8e\xb1", 8).1, stop(0, 0));
+            assert_eq!(convert(euc_jp_to_utf8, b"\x8f\x8e\xb1", 8).1, stop(0, 0));
+            // Bytes that start no character.
+            for b in [0xA0, 0xFF] {
+                assert_eq!(convert(euc_jp_to_utf8, &[b'a', b, 0xA1], 8).1, stop(1, 1));
+            }
+            // Unassigned characters in both sets, and the JIS X 0212 tilde,
+            // which would stand for ASCII.
+            assert_eq!(convert(euc_jp_to_utf8, b"\xa9\xa1", 8).1, stop(0, 0));
+            assert_eq!(convert(euc_jp_to_utf8, b"\x8f\xa1\xa1", 8).1, stop(0, 0));
+            assert_eq!(convert(euc_jp_to_utf8, b"\x8f\xa2\xb7", 8).1, stop(0, 0));
+
+            // Characters EUC-JP can't represent, the shift bytes as C1
+            // controls, and bad UTF-8.
+            for text in ["a\u{20ac}", "a\u{8e}", "a\u{8f}", "a\u{1f600}"] {
+                assert_eq!(convert(utf8_to_euc_jp, text.as_bytes(), 8).1, stop(1, 1));
+            }
+            assert_eq!(convert(utf8_to_euc_jp, b"\xc0\xaf", 8).1, stop(0, 0));
+        }
+
+        /// Cut-off sequences are left unread; the caller passes them again
+        /// with the rest of the input.
+        #[test]
+        fn test_truncated() {
+            let input = b"a\xc6\xfc\x8e\xb1\x8f\xb0\xa1";
+            for end in 1..input.len() {
+                let (out, res) = convert(euc_jp_to_utf8, &input[..end], 16);
+                let (rest, res2) = convert(euc_jp_to_utf8, &input[res.read..], 16);
+                assert_eq!(res.ret, res.written as c_int, "end {end}");
+                assert_eq!(res2.read, input.len() - res.read);
+                assert_eq!([out, rest].concat(), "a\u{65e5}\u{ff71}\u{4e02}".as_bytes());
+            }
+            let (_, res) = convert(euc_jp_to_utf8, b"a\x8f\xb0", 16);
+            assert_eq!((res.read, res.written, res.ret), (1, 1, 1));
+        }
+
+        #[test]
+        fn test_registry() {
+            for name in ["EUC-JP", "eucJP", "euc_jp", "x-euc-jp"] {
+                let (to_utf8, _) = encoding::converters_by_name(name).unwrap();
+                assert_eq!(convert(to_utf8, b"\x8e\xb1", 8).0, "\u{ff71}".as_bytes());
+            }
+            assert!(encoding::converters(xmlCharEncoding_XML_CHAR_ENCODING_EUC_JP).is_some());
+        }
+
+        /// Every sequence with one or two bytes after the first, and every
+        /// character back, against glibc's EUC-JP through the C baseline.
+        /// glibc reads 0x8FA2B7 as a full-width tilde and takes 0xA0 at
+        /// the end for a cut-off sequence, which are left out.
+        #[test]
+        fn test_matches_iconv() {
+            let (c_in, c_out) = Iconv::open_pair(c"EUC-JP");
+            let mut inputs: Vec<Vec<u8>> = (0x80..=0xFF).map(|b| vec![b]).collect();
+            for first in 0x80..=0xFF {
+                inputs.extend((0..=0xFF).map(|b| vec![first, b]));
+            }
+            for b in 0xA1..=0xFE {
+                inputs.extend((0..=0xFF).map(|c| vec![0x8F, b, c]));
+            }
+            inputs.retain(|input| input.last() != Some(&0xA0) && input[..] != [0x8F, 0xA2, 0xB7]);
+            let mut texts = Vec::new();
+            for input in inputs {
+                let mut rust = rust_convert(euc_jp_to_utf8, &input, 8);
+                rust.3 = rust.3.min(0);
+                assert_eq!(c_in.convert(&input, 8), rust, "{input:x?}");
+                let decoded = std::str::from_utf8(&rust.0).unwrap();
+                if rust.1 as usize == input.len() && decoded.chars().count() == 1 {
+                    texts.push(rust.0);
+                }
+            }
+            assert_eq!(texts.len(), 30 + 63 + 6879 + 6066);
+
+            for text in texts {
+                for space in 0..=text.len() {
+                    let mut rust = rust_convert(utf8_to_euc_jp, &text, space);
+                    rust.3 = rust.3.min(0);
+                    assert_eq!(c_out.convert(&text, space), rust, "{text:x?}");
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,365 @@
+//! Tests for htmlparser module
+
+#[cfg(feature = "rust-htmlparser")]
+mod rust_tests {
+    use libxml2::htmlparser::entities::ENTITIES;
+    use libxml2::htmlparser::{data_mode, decode_refs, parse_raw_text, Event, TreeBuilder};
+    use libxml2::*;
+    use std::ffi::CStr;
+    use std::os::raw::{c_char, c_int};
+
+    /// Named references from HTML 4 and HTML5, with and without the
+    /// semicolon, as prefixes of longer names and next to `=`.
+    const SLICE: &[&str] = &[
+        "&nbsp;&copy;&eacute;&lt;&gt;&amp;&quot;&apos;",
+        "&nbsp &copy &eacute &lt &gt &amp &quot &apos",
+        "&AMP;&AMP &LT &Eacute &frac12 &sup2 &yuml",
+        "&notin; &notit; &not; &not &noti",
+        "&ampx &amp= &amp;x &copy2 &copyright",
+        "&CounterClockwiseContourIntegral; &NotNestedGreaterGreater;",
+        "&fjlig; &ThickSpace; &NewLine; &bsol; &Tab;",
+        "&bogus; &; &1 & && &#",
+        "&#65;&#x42;&#X43&#0;&#128;&#x9f;&#xD800;&#1114112;&#99999999999;",
+        "&#x; &#xg; &#a;",
+    ];
+
+    /// Text and the value of an attribute holding it, both parsed by the
+    /// C baseline.
+    fn c_decode(input: &str) -> (String, String) {
+        assert!(!input.contains(['<', '"', '\r']));
+        let html = format!("<p title=\"{input}\">{input}</p>");
+        let c_lib = libxml2_dynload::get_c_baseline();
+        let options =
+            (htmlParserOption_HTML_PARSE_NOERROR | htmlParserOption_HTML_PARSE_NOWARNING) as c_int;
+        unsafe {
+            let doc = c_lib.htmlReadMemory(
+                html.as_ptr() as *const c_char,
+                html.len() as c_int,
+                std::ptr::null(),
+                std::ptr::null(),
+                options,
+            );
+            assert!(!doc.is_null());
+            // html > body > p
+            let mut p = c_lib.xmlDocGetRootElement(doc);
+            while !p.is_null() && CStr::from_ptr((*p).name as *const c_char) != c"p" {
+                p = (*p).last;
+            }
+            assert!(!p.is_null(), "no p in {html:?}");
+
+            let take = |s: *mut xmlChar| {
+                assert!(!s.is_null());
+                let ret = CStr::from_ptr(s as *const c_char)
+                    .to_string_lossy()
+                    .into_owned();
+                libc::free(s as *mut libc::c_void);
+                ret
+            };
+            let text = take(c_lib.xmlNodeGetContent(p));
+            let attr = take(c_lib.xmlGetProp(p, c"title".as_ptr() as *const xmlChar));
+            c_lib.xmlFreeDoc(doc);
+            (text, attr)
+        }
+    }
+
+    fn rust_decode(input: &str, is_attr: bool) -> String {
+        String::from_utf8(decode_refs(input.as_bytes(), is_attr)).unwrap()
+    }
+
+    #[test]
+    fn test_named_entities_match_c() {
+        for input in SLICE {
+            let (text, attr) = c_decode(input);
+            assert_eq!(rust_decode(input, false), text, "text {input:?}");
+            assert_eq!(rust_decode(input, true), attr, "attribute {input:?}");
+        }
+    }
+
+    #[test]
+    fn test_lenient_semicolon() {
+        assert_eq!(rust_decode("&nbsp &eacute", false), "\u{a0} \u{e9}");
+        // Only the HTML 4 names work without the semicolon.
+        assert_eq!(rust_decode("&bsol &Tab", false), "&bsol &Tab");
+        // In attributes, not before letters, digits or `=`.
+        assert_eq!(rust_decode("?a=1&copy=2&copyx", true), "?a=1&copy=2&copyx");
+        assert_eq!(rust_decode("&copy-&copy", true), "\u{a9}-\u{a9}");
+    }
+
+    /// Every entity in the table, in each of the ways the semicolon rules
+    /// treat differently.
+    #[test]
+    fn test_all_entities_match_c() {
+        let names: Vec<&str> = ENTITIES.iter().map(|entity| entity.0).collect();
+        assert_eq!(names.len(), 2125);
+        for chunk in names.chunks(50) {
+            let input: String = chunk
+                .iter()
+                .map(|name| format!("&{name};&{name} &{name}x &{name}= |"))
+                .collect();
+            let (text, attr) = c_decode(&input);
+            assert_eq!(rust_decode(&input, false), text, "text {input:?}");
+            assert_eq!(rust_decode(&input, true), attr, "attribute {input:?}");
+        }
+    }
+
+    /// The text content of the first `name` element in `html`, parsed as
+    /// UTF-8 by the C baseline.
+    fn c_element_text(html: &str, name: &str) -> String {
+        let c_lib = libxml2_dynload::get_c_baseline();
+        let options =
+            (htmlParserOption_HTML_PARSE_NOERROR | htmlParserOption_HTML_PARSE_NOWARNING) as c_int;
+        unsafe {
+            let doc = c_lib.htmlReadMemory(
+                html.as_ptr() as *const c_char,
+                html.len() as c_int,
+                std::ptr::null(),
+                c"UTF-8".as_ptr(),
+                options,
+            );
+            assert!(!doc.is_null());
+            let mut stack = vec![c_lib.xmlDocGetRootElement(doc)];
+            let mut found = std::ptr::null_mut();
+            while let Some(node) = stack.pop() {
+                if node.is_null() {
+                    continue;
+                }
+                if (*node).type_ == xmlElementType_XML_ELEMENT_NODE
+                    && CStr::from_ptr((*node).name as *const c_char).to_bytes() == name.as_bytes()
+                {
+                    found = node;
+                    break;
+                }
+                stack.push((*node).next);
+                stack.push((*node).children);
+            }
+            assert!(!found.is_null(), "no {name} in {html:?}");
+
+            let s = c_lib.xmlNodeGetContent(found);
+            assert!(!s.is_null());
+            let text = CStr::from_ptr(s as *const c_char)
+                .to_string_lossy()
+                .into_owned();
+            libc::free(s as *mut libc::c_void);
+            c_lib.xmlFreeDoc(doc);
+            text
+        }
+    }
+
+    fn rust_element_text(content: &str, name: &str) -> String {
+        let mode = data_mode(name.as_bytes()).unwrap();
+        let (text, _) = parse_raw_text(content.as_bytes(), name.as_bytes(), mode);
+        String::from_utf8(text).unwrap()
+    }
+
+    /// Markup, references and comments inside raw text elements, and end
+    /// tags that do and do not close them.
+    const RAW_TEXT: &[&str] = &[
+        "if (a < b && c > d) { x = \"<b>&amp;</b>\"; }",
+        "<!-- hidden --> document.write('<p>&lt;</p>');",
+        "a &copy; &#65; &nbsp b<i>c</i>",
+        "x</scriptx>y</styles>z</SCRIPT",
+        "<!--<script>var s = '</script>';</script>-->tail",
+        "<!--<script></script>-->",
+        "<!-- x --><!-",
+        "a\r\nb\rc\0d \u{e9}",
+    ];
+
+    #[test]
+    fn test_raw_text_matches_c() {
+        for name in ["script", "style", "textarea", "title", "xmp"] {
+            for content in RAW_TEXT {
+                for end in [
+                    format!("</{name}>"),
+                    format!("</{}\t>", name.to_uppercase()),
+                ] {
+                    let html =
+                        format!("<html><body><{name}>{content}{end}<p>after</p></body></html>");
+                    let expected = c_element_text(&html, name);
+                    let (_, body) = html.split_once(&format!("<{name}>")).unwrap();
+                    assert_eq!(rust_element_text(body, name), expected, "{html:?}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_script_comment() {
+        let content = "var a = 1 < 2 && 3 > 2; <!-- if (a) { b(); } --> c('&amp;');";
+        let html = format!("<script>{content}</script>");
+        assert_eq!(c_element_text(&html, "script"), content);
+        assert_eq!(
+            rust_element_text(&format!("{content}</script>"), "script"),
+            content
+        );
+    }
+
+    /// The tree C builds from `html`, as `name(children)` for elements,
+    /// quoted text and `<!--comments-->`, without the DTD.
+    fn c_tree(html: &str) -> String {
+        unsafe fn walk(mut node: *mut dynamic_bindings::xmlNode, out: &mut String) {
+            let mut text = Vec::new();
+            while !node.is_null() {
+                let type_ = (*node).type_;
+                if type_ == xmlElementType_XML_TEXT_NODE
+                    || type_ == xmlElementType_XML_CDATA_SECTION_NODE
+                {
+                    text.extend_from_slice(
+                        CStr::from_ptr((*node).content as *const c_char).to_bytes(),
+                    );
+                } else {
+                    flush_text(&mut text, out);
+                    let name = CStr::from_ptr((*node).name as *const c_char).to_string_lossy();
+                    if type_ == xmlElementType_XML_ELEMENT_NODE {
+                        out.push_str(&format!("{name}("));
+                        walk((*node).children, out);
+                        out.push(')');
+                    } else if type_ == xmlElementType_XML_COMMENT_NODE {
+                        let content = CStr::from_ptr((*node).content as *const c_char);
+                        out.push_str(&format!("<!--{}-->", content.to_string_lossy()));
+                    }
+                }
+                node = (*node).next;
+            }
+            flush_text(&mut text, out);
+        }
+
+        let c_lib = libxml2_dynload::get_c_baseline();
+        let options =
+            (htmlParserOption_HTML_PARSE_NOERROR | htmlParserOption_HTML_PARSE_NOWARNING) as c_int;
+        unsafe {
+            let doc = c_lib.htmlReadMemory(
+                html.as_ptr() as *const c_char,
+                html.len() as c_int,
+                std::ptr::null(),
+                c"UTF-8".as_ptr(),
+                options,
+            );
+            assert!(!doc.is_null());
+            let mut out = String::new();
+            walk((*doc).children, &mut out);
+            c_lib.xmlFreeDoc(doc);
+            out
+        }
+    }
+
+    fn flush_text(text: &mut Vec<u8>, out: &mut String) {
+        if !text.is_empty() {
+            out.push_str(&format!("{:?}", String::from_utf8_lossy(text)));
+            text.clear();
+        }
+    }
+
+    /// Feed the tags, comments and text of a simple `html` to the tree
+    /// builder and print the tree as `c_tree` does. Text outside any
+    /// element has no node to go to and is dropped, as in C.
+    fn rust_tree(html: &str) -> String {
+        let position = |s: &[u8], pat: &[u8]| s.windows(pat.len()).position(|w| w == pat);
+        let mut builder = TreeBuilder::new();
+        // htmlParseDocument skips leading whitespace.
+        let mut rest = html.trim_start().as_bytes();
+        while !rest.is_empty() {
+            if let Some(after) = rest.strip_prefix(b"<!--") {
+                let end = position(after, b"-->").unwrap();
+                builder.comment(&after[..end]);
+                rest = &after[end + 3..];
+            } else if let Some(after) = rest.strip_prefix(b"</") {
+                let end = position(after, b">").unwrap();
+                builder.end_tag(&after[..end].to_ascii_lowercase());
+                rest = &after[end + 1..];
+            } else if let Some(after) = rest.strip_prefix(b"<") {
+                let end = position(after, b">").unwrap();
+                let tag = &after[..end];
+                let self_closing = tag.ends_with(b"/");
+                let name = tag[..tag.len() - self_closing as usize].to_ascii_lowercase();
+                rest = &after[end + 1..];
+                if let Some(mode) = builder.start_tag(&name, self_closing) {
+                    let current = builder.current().unwrap().to_vec();
+                    let (text, end) = parse_raw_text(rest, &current, mode);
+                    builder.text(&text);
+                    rest = &rest[end..];
+                }
+            } else {
+                let end = position(rest, b"<").unwrap_or(rest.len());
+                builder.text(&decode_refs(&rest[..end], false));
+                rest = &rest[end..];
+            }
+        }
+
+        let mut stack = vec![String::new()];
+        let mut text = Vec::new();
+        for event in builder.finish() {
+            if let Event::Text(data) = event {
+                if stack.len() > 1 {
+                    text.extend_from_slice(&data);
+                }
+                continue;
+            }
+            flush_text(&mut text, stack.last_mut().unwrap());
+            match event {
+                Event::Start(name) => stack.push(format!("{}(", String::from_utf8_lossy(&name))),
+                Event::End(_) => {
+                    let element = stack.pop().unwrap() + ")";
+                    stack.last_mut().unwrap().push_str(&element);
+                }
+                Event::Comment(data) => stack
+                    .last_mut()
+                    .unwrap()
+                    .push_str(&format!("<!--{}-->", String::from_utf8_lossy(&data))),
+                Event::Text(_) => unreachable!(),
+            }
+        }
+        stack.pop().unwrap()
+    }
+
+    /// Fragments without `html`, with content before or instead of
+    /// `head`, with text at the top level, and with misplaced and
+    /// mismatched tags.
+    const FRAGMENTS: &[&str] = &[
+        "<title>x</title><p>y",
+        "<p>y",
+        "text at the top",
+        "  leading blanks &amp; text",
+        "<!-- c --><title>t</title>body text",
+        "<meta><link><script>s < t</script><div>d</div>",
+        "<head><title>t</title></head> x",
+        "<head> <title>t</title> </head> <body> x </body> ",
+        "x<head><title>t</title></head><title>u</title>",
+        "<title>a</title> b <title>c</title>",
+        "<style>a<b</style><b>bold</b>",
+        "<body><p>a<p>b</body>",
+        "<html><p>x</html>",
+        "<html><html><body><body>x</body></body></html>y",
+        "<p>a<body>b</body>c",
+        "<div>a<head>b</head>c</div>",
+        "<table><tr><td>a<td>b<tr><td>c</table>",
+        "<ul><li>a<li>b</ul><ol><li>c</ol>",
+        "<frameset><frame></frameset><noframes>n</noframes>",
+        "<br>text<hr/>more<img>",
+        "<div><span>a</div>b",
+        "<table><tr><td><div>x</table>y</div>z",
+        "<td>x</div>y</td>",
+        "</p>x</html>",
+        "<p>a</td>b<h1>c<p>d</h1>",
+        "<a>1<a>2<table><tr><td><a>3</a></table>",
+        "<dl><dt>a<dd>b<dt>c</dl>",
+        "<select><option>a<option>b</select>",
+        "<span/>x<p/>y",
+        "<script>a</script><p>b<script>c</script>",
+    ];
+
+    #[test]
+    fn test_implied_elements_match_c() {
+        for html in FRAGMENTS {
+            assert_eq!(rust_tree(html), c_tree(html), "{html:?}");
+        }
+    }
+
+    #[test]
+    fn test_implied_head_and_body() {
+        assert_eq!(
+            rust_tree("<title>x</title><p>y"),
+            "html(head(title(\"x\"))body(p(\"y\")))"
+        );
+        assert_eq!(rust_tree("text"), "html(body(\"text\"))");
+    }
+}
@@ -0,0 +1,192 @@
+//! Tests for htmltree module
+
+use libxml2::*;
+use std::os::raw::{c_char, c_int};
+
+const SAMPLE: &[u8] = b"<!DOCTYPE html PUBLIC \"-//W3C//DTD HTML 4.01//EN\" \"http://www.w3.org/TR/html4/strict.dtd\">
+<html><head><title>a &amp; b</title>
+<style>p > a { color: red }</style>
+<script>if (a < b && c > d) { document.write(\"<p>\"); }</script>
+</head><body>
+<p>Line<br>break <img src=\"x y.png\" alt='\"q\"'></p>
+<form action=\"/s?a=1&b=2\"><input type=checkbox checked><select><option selected>1</option></select></form>
+<a href=\"a b.html\" name=\"n m\">link</a><hr><!-- comment --><?pi data>
+<div><div>nested</div><pre>pre
+text</pre></div>
+</body></html>
+";
+
+fn parse_options() -> c_int {
+    (htmlParserOption_HTML_PARSE_NOERROR | htmlParserOption_HTML_PARSE_NOWARNING) as c_int
+}
+
+/// Serialize SAMPLE with the C baseline library.
+fn c_dump(format: c_int) -> Vec<u8> {
+    let c_lib = libxml2_dynload::get_c_baseline();
+    unsafe {
+        let doc = c_lib.htmlReadMemory(
+            SAMPLE.as_ptr() as *const c_char,
+            SAMPLE.len() as c_int,
+            std::ptr::null(),
+            std::ptr::null(),
+            parse_options(),
+        );
+        assert!(!doc.is_null());
+
+        let mut mem = std::ptr::null_mut();
+        let mut size = 0;
+        c_lib.htmlDocDumpMemoryFormat(doc, &mut mem, &mut size, format);
+        assert!(!mem.is_null());
+        let out = std::slice::from_raw_parts(mem, size as usize).to_vec();
+
+        libc::free(mem as *mut libc::c_void);
+        c_lib.xmlFreeDoc(doc);
+        out
+    }
+}
+
+/// Serialize SAMPLE with the hybrid library.
+fn hybrid_dump(format: c_int) -> Vec<u8> {
+    unsafe {
+        let doc = htmlReadMemory(
+            SAMPLE.as_ptr() as *const c_char,
+            SAMPLE.len() as c_int,
+            std::ptr::null(),
+            std::ptr::null(),
+            parse_options(),
+        );
+        assert!(!doc.is_null());
+
+        let mut mem = std::ptr::null_mut();
+        let mut size = 0;
+        htmlDocDumpMemoryFormat(doc, &mut mem, &mut size, format);
+        assert!(!mem.is_null());
+        let out = std::slice::from_raw_parts(mem, size as usize).to_vec();
+
+        xmlFree.unwrap()(mem as *mut std::os::raw::c_void);
+        xmlFreeDoc(doc);
+        out
+    }
+}
+
+#[test]
+fn test_html_doc_dump_matches_baseline() {
+    for format in [0, 1] {
+        let c_out = c_dump(format);
+        let hybrid_out = hybrid_dump(format);
+        assert_eq!(
+            String::from_utf8_lossy(&hybrid_out),
+            String::from_utf8_lossy(&c_out),
+            "format={}",
+            format
+        );
+    }
+}
+
+#[test]
+fn test_html_doc_dump_output() {
+    let out = String::from_utf8(hybrid_dump(0)).unwrap();
+
+    assert!(out.starts_with("<!DOCTYPE html PUBLIC \"-//W3C//DTD HTML 4.01//EN\""));
+    // Void elements have no end tag
+    assert!(out.contains("Line<br>break"));
+    assert!(!out.contains("</br>"));
+    assert!(!out.contains("</img>"));
+    assert!(!out.contains("</input>"));
+    // Raw text elements are not escaped
+    assert!(out.contains("if (a < b && c > d) { document.write(\"<p>\"); }"));
+    assert!(out.contains("<style>p > a { color: red }</style>"));
+    // Boolean attributes are minimized
+    assert!(out.contains("<input type=\"checkbox\" checked>"));
+    assert!(out.contains("<option selected>"));
+    // Text and attribute escaping
+    assert!(out.contains("<title>a &amp; b</title>"));
+    assert!(out.contains("alt=\"&quot;q&quot;\""));
+    // URI attributes
+    assert!(out.contains("action=\"/s?a=1&amp;b=2\""));
+    assert!(out.contains("href=\"a%20b.html\" name=\"n%20m\""));
+    assert!(out.contains("<!-- comment -->"));
+}
+
+#[cfg(feature = "rust-htmltree")]
+mod rust_tests {
+    use super::*;
+    use libxml2::htmltree;
+
+    #[test]
+    fn test_html_node_dump_matches_baseline() {
+        let c_lib = libxml2_dynload::get_c_baseline();
+        unsafe {
+            let c_doc = c_lib.htmlReadMemory(
+                SAMPLE.as_ptr() as *const c_char,
+                SAMPLE.len() as c_int,
+                std::ptr::null(),
+                std::ptr::null(),
+                parse_options(),
+            );
+            let c_buf = c_lib.xmlBufferCreate();
+            let c_ret = c_lib.htmlNodeDump(c_buf, c_doc, c_lib.xmlDocGetRootElement(c_doc));
+            let c_out = std::slice::from_raw_parts(
+                c_lib.xmlBufferContent(c_buf),
+                c_lib.xmlBufferLength(c_buf) as usize,
+            )
+            .to_vec();
+
+            let doc = htmlReadMemory(
+                SAMPLE.as_ptr() as *const c_char,
+                SAMPLE.len() as c_int,
+                std::ptr::null(),
+                std::ptr::null(),
+                parse_options(),
+            );
+            let buf = xmlBufferCreate();
+            let ret = htmltree::ffi::htmlNodeDump(buf, doc, xmlDocGetRootElement(doc));
+            let out = std::slice::from_raw_parts(xmlBufferContent(buf), xmlBufferLength(buf) as usize)
+                .to_vec();
+
+            assert_eq!(ret, c_ret);
+            assert_eq!(String::from_utf8_lossy(&out), String::from_utf8_lossy(&c_out));
+
+            xmlBufferFree(buf);
+            xmlFreeDoc(doc);
+            c_lib.xmlBufferFree(c_buf);
+            c_lib.xmlFreeDoc(c_doc);
+        }
+    }
+
+    #[test]
+    fn test_html_meta_encoding() {
+        let input = b"<html><head><meta http-equiv=\"Content-Type\" content=\"text/html; charset=ISO-8859-1\"></head><body></body></html>";
+        unsafe {
+            let doc = htmlReadMemory(
+                input.as_ptr() as *const c_char,
+                input.len() as c_int,
+                std::ptr::null(),
+                std::ptr::null(),
+                parse_options(),
+            );
+            assert!(!doc.is_null());
+
+            let enc = htmltree::ffi::htmlGetMetaEncoding(doc);
+            assert!(!enc.is_null());
+            let enc = std::ffi::CStr::from_ptr(enc as *const c_char);
+            assert_eq!(enc.to_bytes(), b"ISO-8859-1");
+
+            assert_eq!(htmltree::ffi::htmlSetMetaEncoding(doc, c"UTF-8".as_ptr() as *const xmlChar), 0);
+            let enc = htmltree::ffi::htmlGetMetaEncoding(doc);
+            let enc = std::ffi::CStr::from_ptr(enc as *const c_char);
+            assert_eq!(enc.to_bytes(), b"UTF-8");
+
+            xmlFreeDoc(doc);
+        }
+    }
+
+    #[test]
+    fn test_html_is_boolean_attr() {
+        unsafe {
+            assert_eq!(htmltree::ffi::htmlIsBooleanAttr(c"Checked".as_ptr() as *const xmlChar), 1);
+            assert_eq!(htmltree::ffi::htmlIsBooleanAttr(c"href".as_ptr() as *const xmlChar), 0);
+            assert_eq!(htmltree::ffi::htmlIsBooleanAttr(std::ptr::null()), 0);
+        }
+    }
+}
@@ -0,0 +1,53 @@
+//! Tests for init_chimera and cleanup_chimera
+
+use libxml2::*;
+use std::os::raw::{c_char, c_int};
+use std::ptr;
+
+fn parse_ok(doc: &[u8]) -> bool {
+    unsafe {
+        let parsed = xmlReadMemory(
+            doc.as_ptr() as *const c_char,
+            doc.len() as c_int,
+            ptr::null(),
+            ptr::null(),
+            0,
+        );
+        if parsed.is_null() {
+            return false;
+        }
+        xmlFreeDoc(parsed);
+        true
+    }
+}
+
+/// One test, so the steps run in order within this process.
+#[test]
+fn test_init_chimera() {
+    // Parsing initializes lazily without an explicit call.
+    assert!(parse_ok(b"<lazy/>"));
+
+    // Repeated and concurrent calls are harmless.
+    init_chimera();
+    init_chimera();
+    let threads: Vec<_> = (0..4)
+        .map(|_| {
+            std::thread::spawn(|| {
+                init_chimera();
+                parse_ok(b"<threaded a='1'/>")
+            })
+        })
+        .collect();
+    for thread in threads {
+        assert!(thread.join().unwrap());
+    }
+    assert!(parse_ok(b"<r><a/></r>"));
+
+    // After cleanup the next parse initializes again. init_chimera has
+    // run its `Once` and does nothing, which parsing does not mind.
+    unsafe { cleanup_chimera() };
+    assert!(parse_ok(b"<again/>"));
+    unsafe { cleanup_chimera() };
+    init_chimera();
+    assert!(parse_ok(b"<after-init/>"));
+}
@@ -0,0 +1,178 @@
+//! Tests for the differential parse harness in libxml2_dynload
+
+use libxml2::libxml2_dynload::{diff_parse, diff_parse_outcome, diff_trees, TreeDiff};
+use libxml2::*;
+use std::os::raw::c_int;
+use std::path::{Path, PathBuf};
+
+const PARSE_OPTIONS: c_int = (xmlParserOption_XML_PARSE_NOERROR
+    | xmlParserOption_XML_PARSE_NOWARNING
+    | xmlParserOption_XML_PARSE_NONET) as c_int;
+
+/// Every `*.xml` file at the top of `test/`.
+fn corpus() -> Vec<(PathBuf, Vec<u8>)> {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .unwrap()
+        .join("test");
+    let mut files: Vec<PathBuf> = std::fs::read_dir(&dir)
+        .expect("test corpus directory should exist")
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.is_file() && path.extension().is_some_and(|ext| ext == "xml"))
+        .collect();
+    files.sort();
+    assert!(!files.is_empty(), "no XML files in {}", dir.display());
+    files
+        .into_iter()
+        .map(|path| {
+            let data = std::fs::read(&path).unwrap();
+            (path, data)
+        })
+        .collect()
+}
+
+#[test]
+fn test_diff_parse_identical() {
+    let doc = b"<?xml version=\"1.0\"?>
+<r xmlns=\"urn:a\" xmlns:b=\"urn:b\" b:x=\"1\" y=\"a&amp;b\"><!-- c --><b:e>text<![CDATA[<raw>]]></b:e><?pi data?></r>";
+    assert_eq!(diff_parse(doc, PARSE_OPTIONS), Ok(()));
+}
+
+#[test]
+fn test_diff_parse_malformed() {
+    // Both builds reject the document, which is not a divergence.
+    assert_eq!(diff_parse(b"<r><unclosed></r>", PARSE_OPTIONS), Ok(()));
+}
+
+#[test]
+fn test_diff_trees_reports_first_difference() {
+    let parse = |doc: &[u8]| unsafe {
+        xmlReadMemory(
+            doc.as_ptr() as *const std::os::raw::c_char,
+            doc.len() as c_int,
+            std::ptr::null(),
+            std::ptr::null(),
+            PARSE_OPTIONS,
+        )
+    };
+    let a = parse(b"<r><a x=\"1\"/>text</r>");
+    let b = parse(b"<r><a x=\"2\"/>text</r>");
+    let c = parse(b"<r><a x=\"1\"/>other</r>");
+    unsafe {
+        assert_eq!(diff_trees(a, a), Ok(()));
+
+        let diff = diff_trees(a, b).unwrap_err();
+        assert_eq!(diff.path, "/r[0]/a[0]");
+        assert_eq!(diff.field, "attributes");
+
+        let diff = diff_trees(a, c).unwrap_err();
+        assert_eq!(diff.path, "/r[0]/#text[1]");
+        assert_eq!(diff.field, "content");
+
+        let diff = diff_trees(a, std::ptr::null_mut()).unwrap_err();
+        assert_eq!((diff.path.as_str(), diff.field), ("/", "parsed"));
+
+        xmlFreeDoc(a);
+        xmlFreeDoc(b);
+        xmlFreeDoc(c);
+    }
+}
+
+#[test]
+fn test_tree_diff_display() {
+    let diff = TreeDiff {
+        path: "/r[0]/#text[1]".to_string(),
+        field: "content",
+        c: "Some(\"a\")".to_string(),
+        rust: "Some(\"b\")".to_string(),
+    };
+    assert_eq!(
+        diff.to_string(),
+        "content differs at /r[0]/#text[1]: C baseline \"Some(\\\"a\\\")\", hybrid \"Some(\\\"b\\\")\""
+    );
+}
+
+/// Every corpus file, and a spread of truncated prefixes of it, must parse
+/// to the same tree (or fail) in both builds.
+#[test]
+fn test_corpus_has_no_structural_divergence() {
+    let mut failures = Vec::new();
+    for (path, data) in corpus() {
+        for cut in 0..=8 {
+            let input = &data[..data.len() * cut / 8];
+            if let Err(diff) = diff_parse(input, PARSE_OPTIONS) {
+                failures.push(format!("{} ({}/8): {}", path.display(), cut, diff));
+            }
+        }
+    }
+    assert!(failures.is_empty(), "divergences:\n{}", failures.join("\n"));
+}
+
+/// Every `*.xml` file below `dir`, at any depth.
+fn walk_corpus(dir: &Path, files: &mut Vec<PathBuf>) {
+    for entry in std::fs::read_dir(dir).unwrap().flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            walk_corpus(&path, files);
+        } else if path.extension().is_some_and(|ext| ext == "xml") {
+            files.push(path);
+        }
+    }
+}
+
+/// Every XML sample below `LIBXML2_CORPUS`, or `test/` by default, must
+/// parse to the same tree with the same last error in the C baseline and
+/// in the hybrid, whichever modules it has in Rust. Samples the baseline
+/// rejects are expected errors, which the hybrid must reject alike.
+#[test]
+fn test_corpus_parse_outcomes_match() {
+    let dir = std::env::var_os("LIBXML2_CORPUS").map_or_else(
+        || {
+            Path::new(env!("CARGO_MANIFEST_DIR"))
+                .parent()
+                .unwrap()
+                .join("test")
+        },
+        PathBuf::from,
+    );
+    let mut files = Vec::new();
+    walk_corpus(&dir, &mut files);
+    files.sort();
+    assert!(!files.is_empty(), "no XML files below {}", dir.display());
+
+    let mut failures = Vec::new();
+    let mut rejected = Vec::new();
+    for path in &files {
+        let data = std::fs::read(path).unwrap();
+        match diff_parse_outcome(&data, PARSE_OPTIONS) {
+            Ok(outcome) if !outcome.parsed => {
+                rejected.push(format!("{} (error {})", path.display(), outcome.code));
+            }
+            Ok(_) => {}
+            Err(diff) => failures.push(format!("{}: {}", path.display(), diff)),
+        }
+    }
+    eprintln!(
+        "{} samples, {} rejected by the C baseline:\n{}",
+        files.len(),
+        rejected.len(),
+        rejected.join("\n")
+    );
+    assert!(rejected.len() < files.len());
+    assert!(failures.is_empty(), "divergences:\n{}", failures.join("\n"));
+}
+
+#[test]
+fn test_diff_parse_outcome() {
+    let outcome = diff_parse_outcome(b"<r/>", PARSE_OPTIONS).unwrap();
+    assert!(outcome.parsed);
+    assert_eq!((outcome.domain, outcome.code), (0, 0));
+
+    let outcome = diff_parse_outcome(b"<r><unclosed></r>", PARSE_OPTIONS).unwrap();
+    assert!(!outcome.parsed);
+    assert_eq!(
+        outcome.code,
+        xmlParserErrors_XML_ERR_TAG_NAME_MISMATCH as c_int
+    );
+}
@@ -14,11 +14,8 @@ fn run_test_binary(test_name: &str, args: &[&str]) -> Result<(), Box<dyn std::er
     let mut cmd = Command::new(&binary_path);
     cmd.args(args);
     
-    // Set working directory to the project root (libxml2 source directory)
-    if let Ok(manifest_dir) = env::var("CARGO_MANIFEST_DIR") {
-        let project_root = Path::new(&manifest_dir).parent().unwrap();
-        cmd.current_dir(project_root);
-    }
+    // Run from the libxml2 source tree the binaries were built from
+    cmd.current_dir(env!("LIBXML2_SRC_DIR"));
     
     let output = cmd.output()?;
     
@@ -0,0 +1,399 @@
+//! Tests for list module
+
+use libxml2::*;
+use std::os::raw::{c_int, c_void};
+
+/// Data of the lists: a sort key and an id telling equal keys apart.
+type Item = (i32, i32);
+
+unsafe extern "C" fn compare_key(data0: *const c_void, data1: *const c_void) -> c_int {
+    (*(data0 as *const Item)).0 - (*(data1 as *const Item)).0
+}
+
+unsafe extern "C" fn collect(data: *const c_void, user: *mut c_void) -> c_int {
+    let out = &mut *(user as *mut Vec<Item>);
+    out.push(*(data as *const Item));
+    // Stop after an item with a negative id.
+    ((*(data as *const Item)).1 >= 0) as c_int
+}
+
+type Compare = Option<unsafe extern "C" fn(*const c_void, *const c_void) -> c_int>;
+type Walker = Option<unsafe extern "C" fn(*const c_void, *mut c_void) -> c_int>;
+
+/// The `xmlList` functions of one implementation. The static and dynamic
+/// bindings each have their own `xmlList` and `xmlLink` types.
+struct Api<L, K> {
+    create: unsafe extern "C" fn(Option<unsafe extern "C" fn(*mut K)>, Compare) -> *mut L,
+    delete: unsafe extern "C" fn(*mut L),
+    search: unsafe extern "C" fn(*mut L, *mut c_void) -> *mut c_void,
+    reverse_search: unsafe extern "C" fn(*mut L, *mut c_void) -> *mut c_void,
+    insert: unsafe extern "C" fn(*mut L, *mut c_void) -> c_int,
+    append: unsafe extern "C" fn(*mut L, *mut c_void) -> c_int,
+    remove_first: unsafe extern "C" fn(*mut L, *mut c_void) -> c_int,
+    remove_last: unsafe extern "C" fn(*mut L, *mut c_void) -> c_int,
+    remove_all: unsafe extern "C" fn(*mut L, *mut c_void) -> c_int,
+    empty: unsafe extern "C" fn(*mut L) -> c_int,
+    front: unsafe extern "C" fn(*mut L) -> *mut K,
+    end: unsafe extern "C" fn(*mut L) -> *mut K,
+    size: unsafe extern "C" fn(*mut L) -> c_int,
+    pop_front: unsafe extern "C" fn(*mut L),
+    pop_back: unsafe extern "C" fn(*mut L),
+    push_front: unsafe extern "C" fn(*mut L, *mut c_void) -> c_int,
+    push_back: unsafe extern "C" fn(*mut L, *mut c_void) -> c_int,
+    reverse: unsafe extern "C" fn(*mut L),
+    sort: unsafe extern "C" fn(*mut L),
+    walk: unsafe extern "C" fn(*mut L, Walker, *mut c_void),
+    reverse_walk: unsafe extern "C" fn(*mut L, Walker, *mut c_void),
+    merge: unsafe extern "C" fn(*mut L, *mut L),
+    dup: unsafe extern "C" fn(*mut L) -> *mut L,
+    copy: unsafe extern "C" fn(*mut L, *mut L) -> c_int,
+    link_data: unsafe extern "C" fn(*mut K) -> *mut c_void,
+}
+
+fn c_api() -> Api<dynamic_bindings::xmlList, dynamic_bindings::xmlLink> {
+    let c = libxml2_dynload::get_c_baseline();
+    Api {
+        create: *c.xmlListCreate.as_ref().unwrap(),
+        delete: *c.xmlListDelete.as_ref().unwrap(),
+        search: *c.xmlListSearch.as_ref().unwrap(),
+        reverse_search: *c.xmlListReverseSearch.as_ref().unwrap(),
+        insert: *c.xmlListInsert.as_ref().unwrap(),
+        append: *c.xmlListAppend.as_ref().unwrap(),
+        remove_first: *c.xmlListRemoveFirst.as_ref().unwrap(),
+        remove_last: *c.xmlListRemoveLast.as_ref().unwrap(),
+        remove_all: *c.xmlListRemoveAll.as_ref().unwrap(),
+        empty: *c.xmlListEmpty.as_ref().unwrap(),
+        front: *c.xmlListFront.as_ref().unwrap(),
+        end: *c.xmlListEnd.as_ref().unwrap(),
+        size: *c.xmlListSize.as_ref().unwrap(),
+        pop_front: *c.xmlListPopFront.as_ref().unwrap(),
+        pop_back: *c.xmlListPopBack.as_ref().unwrap(),
+        push_front: *c.xmlListPushFront.as_ref().unwrap(),
+        push_back: *c.xmlListPushBack.as_ref().unwrap(),
+        reverse: *c.xmlListReverse.as_ref().unwrap(),
+        sort: *c.xmlListSort.as_ref().unwrap(),
+        walk: *c.xmlListWalk.as_ref().unwrap(),
+        reverse_walk: *c.xmlListReverseWalk.as_ref().unwrap(),
+        merge: *c.xmlListMerge.as_ref().unwrap(),
+        dup: *c.xmlListDup.as_ref().unwrap(),
+        copy: *c.xmlListCopy.as_ref().unwrap(),
+        link_data: *c.xmlLinkGetData.as_ref().unwrap(),
+    }
+}
+
+impl<L, K> Api<L, K> {
+    unsafe fn contents(&self, l: *mut L) -> Vec<Item> {
+        let mut out = Vec::new();
+        (self.walk)(l, Some(collect), &mut out as *mut _ as *mut c_void);
+        out
+    }
+
+    /// A list built from `items` in order with the push functions.
+    unsafe fn pushed(&self, items: &mut [Item]) -> *mut L {
+        let l = (self.create)(None, Some(compare_key));
+        for item in items {
+            (self.push_back)(l, item as *mut Item as *mut c_void);
+        }
+        l
+    }
+
+    /// Run every operation on lists of `items` and record what is seen.
+    unsafe fn script(&self, items: &mut [Item]) -> Vec<String> {
+        let mut log = Vec::new();
+        let l = (self.create)(None, Some(compare_key));
+        for (i, item) in items.iter_mut().enumerate() {
+            let data = item as *mut Item as *mut c_void;
+            let r = match i % 4 {
+                0 => (self.insert)(l, data),
+                1 => (self.append)(l, data),
+                2 => (self.push_front)(l, data),
+                _ => (self.push_back)(l, data),
+            };
+            log.push(format!("add {r} {:?}", self.contents(l)));
+        }
+        for key in [0, 2, 5, 9] {
+            let mut probe: Item = (key, 0);
+            let probe = &mut probe as *mut Item as *mut c_void;
+            let found = |data: *mut c_void| (!data.is_null()).then(|| *(data as *const Item));
+            log.push(format!(
+                "search {key} {:?} {:?}",
+                found((self.search)(l, probe)),
+                found((self.reverse_search)(l, probe))
+            ));
+        }
+        let mut reversed: Vec<Item> = Vec::new();
+        (self.reverse_walk)(l, Some(collect), &mut reversed as *mut _ as *mut c_void);
+        log.push(format!("reverse walk {reversed:?}"));
+
+        (self.sort)(l);
+        log.push(format!("sort {:?}", self.contents(l)));
+        (self.reverse)(l);
+        log.push(format!("reverse {:?}", self.contents(l)));
+        let ends = |lk: *mut K| *((self.link_data)(lk) as *const Item);
+        log.push(format!(
+            "ends {:?} {:?}",
+            ends((self.front)(l)),
+            ends((self.end)(l))
+        ));
+        (self.sort)(l);
+
+        let mut two: Item = (2, 0);
+        let two = &mut two as *mut Item as *mut c_void;
+        log.push(format!("remove first {}", (self.remove_first)(l, two)));
+        log.push(format!("remove last {}", (self.remove_last)(l, two)));
+        log.push(format!("remove all {}", (self.remove_all)(l, two)));
+        log.push(format!("remove again {}", (self.remove_first)(l, two)));
+        log.push(format!("size {} {:?}", (self.size)(l), self.contents(l)));
+        (self.pop_front)(l);
+        (self.pop_back)(l);
+        log.push(format!("pop {:?}", self.contents(l)));
+        while (self.empty)(l) == 0 {
+            (self.pop_back)(l);
+        }
+        (self.pop_front)(l);
+        log.push(format!(
+            "empty {} {} {:?}",
+            (self.size)(l),
+            (self.link_data)((self.front)(l)).is_null(),
+            self.contents(l)
+        ));
+        (self.delete)(l);
+
+        let null = std::ptr::null_mut();
+        log.push(format!(
+            "null {} {} {} {} {} {}",
+            (self.insert)(null, two),
+            (self.push_back)(null, two),
+            (self.remove_all)(null, two),
+            (self.empty)(null),
+            (self.size)(null),
+            (self.front)(null).is_null()
+        ));
+        log
+    }
+}
+
+impl<L, K> Api<L, K> {
+    /// Duplicate an unsorted list, change the original and record both.
+    unsafe fn dup_script(&self, items: &mut [Item]) -> Vec<String> {
+        let mut log = Vec::new();
+        let l = self.pushed(items);
+        let copy = (self.dup)(l);
+        log.push(format!("dup {:?}", self.contents(copy)));
+        (self.pop_front)(l);
+        (self.reverse)(l);
+        let mut extra: Item = (-1, 0);
+        (self.insert)(l, &mut extra as *mut Item as *mut c_void);
+        log.push(format!("original {:?}", self.contents(l)));
+        log.push(format!("copy {:?}", self.contents(copy)));
+
+        let into = self.pushed(&mut items[..2]);
+        log.push(format!("copy into {}", (self.copy)(into, copy)));
+        log.push(format!("copied {:?}", self.contents(into)));
+        let null = std::ptr::null_mut();
+        log.push(format!(
+            "null {} {} {}",
+            (self.dup)(null).is_null(),
+            (self.copy)(into, null),
+            (self.copy)(null, copy)
+        ));
+        (self.delete)(into);
+        (self.delete)(copy);
+        (self.delete)(l);
+        log
+    }
+}
+
+/// Keys with duplicates, an item with a negative id stopping walks, and
+/// keys out of order.
+fn items() -> Vec<Item> {
+    vec![
+        (3, 0),
+        (1, 0),
+        (2, 0),
+        (3, 1),
+        (2, 1),
+        (0, 0),
+        (2, 2),
+        (5, 0),
+        (1, 1),
+        (4, -1),
+        (2, 3),
+        (3, 2),
+    ]
+}
+
+/// Two sorted lists sharing keys.
+fn sorted_pair() -> (Vec<Item>, Vec<Item>) {
+    (
+        vec![(1, 0), (2, 0), (2, 1), (4, 0), (6, 0)],
+        vec![(0, 1), (2, 2), (2, 3), (4, 1), (5, 1), (6, 1), (7, 1)],
+    )
+}
+
+#[test]
+fn test_list_baseline() {
+    let api = c_api();
+    let log = unsafe { api.script(&mut items()) };
+    assert!(log.contains(&"remove all 2".to_string()));
+
+    // C moves the data of the second list with xmlListInsert, so its
+    // equal data lands first and in reverse order.
+    let (mut a, mut b) = sorted_pair();
+    unsafe {
+        let (l1, l2) = (api.pushed(&mut a), api.pushed(&mut b));
+        (api.merge)(l1, l2);
+        assert_eq!((api.empty)(l2), 1);
+        assert_eq!(api.contents(l1)[2..6], [(2, 3), (2, 2), (2, 0), (2, 1)]);
+        (api.delete)(l1);
+        (api.delete)(l2);
+    }
+}
+
+#[test]
+fn test_dup_baseline() {
+    let log = unsafe { c_api().dup_script(&mut items()) };
+    // xmlListDup inserts each data in order, equal data reversed.
+    assert!(log[0].starts_with("dup [(0, 0), (1, 1), (1, 0), (2, 3), (2, 2)"));
+}
+
+#[cfg(feature = "rust-list")]
+mod rust_tests {
+    use super::*;
+    use libxml2::list::ffi::{
+        xmlLinkGetData, xmlListAppend, xmlListCopy, xmlListCreate, xmlListDelete, xmlListDup,
+        xmlListEmpty, xmlListEnd, xmlListFront, xmlListInsert, xmlListMerge, xmlListPopBack,
+        xmlListPopFront, xmlListPushBack, xmlListPushFront, xmlListRemoveAll, xmlListRemoveFirst,
+        xmlListRemoveLast, xmlListReverse, xmlListReverseSearch, xmlListReverseWalk, xmlListSearch,
+        xmlListSize, xmlListSort, xmlListWalk,
+    };
+    use std::cell::RefCell;
+
+    fn rust_api() -> Api<xmlList, xmlLink> {
+        Api {
+            create: xmlListCreate,
+            delete: xmlListDelete,
+            search: xmlListSearch,
+            reverse_search: xmlListReverseSearch,
+            insert: xmlListInsert,
+            append: xmlListAppend,
+            remove_first: xmlListRemoveFirst,
+            remove_last: xmlListRemoveLast,
+            remove_all: xmlListRemoveAll,
+            empty: xmlListEmpty,
+            front: xmlListFront,
+            end: xmlListEnd,
+            size: xmlListSize,
+            pop_front: xmlListPopFront,
+            pop_back: xmlListPopBack,
+            push_front: xmlListPushFront,
+            push_back: xmlListPushBack,
+            reverse: xmlListReverse,
+            sort: xmlListSort,
+            walk: xmlListWalk,
+            reverse_walk: xmlListReverseWalk,
+            merge: xmlListMerge,
+            dup: xmlListDup,
+            copy: xmlListCopy,
+            link_data: xmlLinkGetData,
+        }
+    }
+
+    #[test]
+    fn test_list_matches_baseline() {
+        let log = unsafe { rust_api().script(&mut items()) };
+        assert_eq!(log, unsafe { c_api().script(&mut items()) });
+    }
+
+    #[test]
+    fn test_merge_sorted_stable() {
+        let api = rust_api();
+        let (mut a, mut b) = sorted_pair();
+        let merged = unsafe {
+            let (l1, l2) = (api.pushed(&mut a), api.pushed(&mut b));
+            (api.merge)(l1, l2);
+            assert_eq!((api.empty)(l2), 1);
+            let merged = api.contents(l1);
+            (api.delete)(l1);
+            (api.delete)(l2);
+            merged
+        };
+        assert!(merged.windows(2).all(|pair| pair[0].0 <= pair[1].0));
+        assert_eq!(merged.len(), a.len() + b.len());
+        assert_eq!(merged[2..6], [(2, 0), (2, 1), (2, 2), (2, 3)]);
+        assert_eq!(merged[6..8], [(4, 0), (4, 1)]);
+
+        // The same keys as C, only equal data is ordered differently.
+        let c_api = c_api();
+        let c_merged = unsafe {
+            let (l1, l2) = (c_api.pushed(&mut a), c_api.pushed(&mut b));
+            (c_api.merge)(l1, l2);
+            let merged = c_api.contents(l1);
+            (c_api.delete)(l1);
+            (c_api.delete)(l2);
+            merged
+        };
+        let keys = |items: &[Item]| items.iter().map(|item| item.0).collect::<Vec<_>>();
+        assert_eq!(keys(&merged), keys(&c_merged));
+    }
+
+    #[test]
+    fn test_dup_matches_baseline() {
+        let log = unsafe { rust_api().dup_script(&mut items()) };
+        assert_eq!(log, unsafe { c_api().dup_script(&mut items()) });
+    }
+
+    thread_local! {
+        static FREED: RefCell<Vec<Item>> = const { RefCell::new(Vec::new()) };
+    }
+
+    unsafe extern "C" fn record_free(lk: *mut xmlLink) {
+        let item = *(xmlLinkGetData(lk) as *const Item);
+        FREED.with(|freed| freed.borrow_mut().push(item));
+    }
+
+    #[test]
+    fn test_merge_moves_ownership() {
+        let (mut a, mut b) = sorted_pair();
+        unsafe {
+            let l1 = xmlListCreate(Some(record_free), Some(compare_key));
+            let l2 = xmlListCreate(Some(record_free), Some(compare_key));
+            for item in &mut a {
+                xmlListAppend(l1, item as *mut Item as *mut c_void);
+            }
+            for item in &mut b {
+                xmlListAppend(l2, item as *mut Item as *mut c_void);
+            }
+            xmlListMerge(l1, l2);
+            xmlListMerge(l1, l1);
+            xmlListMerge(l1, std::ptr::null_mut());
+            xmlListDelete(l2);
+            assert!(FREED.with(|freed| freed.borrow().is_empty()));
+            xmlListDelete(l1);
+        }
+        let freed = FREED.with(|freed| freed.take());
+        assert_eq!(freed.len(), a.len() + b.len());
+    }
+
+    #[test]
+    fn test_dup_shares_data() {
+        let mut items = items();
+        unsafe {
+            let l = xmlListCreate(Some(record_free), Some(compare_key));
+            for item in &mut items {
+                xmlListPushBack(l, item as *mut Item as *mut c_void);
+            }
+            let copy = xmlListDup(l);
+            assert_eq!(
+                xmlLinkGetData(xmlListFront(copy)),
+                &mut items[5] as *mut Item as *mut c_void
+            );
+            // The copy has no deallocator: freeing it leaves the data to
+            // the original.
+            xmlListDelete(copy);
+            assert!(FREED.with(|freed| freed.borrow().is_empty()));
+            xmlListDelete(l);
+        }
+        assert_eq!(FREED.with(|freed| freed.take()), items);
+    }
+}
@@ -0,0 +1,21 @@
+//! Tests for RUST_MODULES and implementation_of
+
+use libxml2::{implementation_of, Impl, RUST_MODULES};
+
+#[test]
+fn test_implementation_of() {
+    #[cfg(feature = "rust-chvalid")]
+    assert_eq!(implementation_of("chvalid"), Impl::Rust);
+    #[cfg(not(feature = "rust-chvalid"))]
+    assert_eq!(implementation_of("chvalid"), Impl::C);
+
+    #[cfg(feature = "rust-list")]
+    assert_eq!(implementation_of("list"), Impl::Rust);
+    #[cfg(not(feature = "rust-list"))]
+    assert_eq!(implementation_of("list"), Impl::C);
+
+    assert_eq!(implementation_of("no-such-module"), Impl::C);
+    for module in RUST_MODULES {
+        assert_eq!(implementation_of(module), Impl::Rust);
+    }
+}
@@ -0,0 +1,253 @@
+//! Tests for parser module
+
+use libxml2::*;
+use std::ffi::CStr;
+use std::os::raw::{c_char, c_int, c_void};
+
+/// Documents that break one namespace constraint each, or several in one
+/// tag.
+const DOCUMENTS: &[&str] = &[
+    // Undeclared prefixes, on elements and attributes, and a declaration
+    // later in the tag or out of scope
+    "<a:b/>",
+    "<r><e p:x='1' q:y='2'/></r>",
+    "<r p:x='1' xmlns:p='urn:p'/>",
+    "<r><e xmlns:p='urn:p'/><p:e/></r>",
+    "<p:r xmlns:p='urn:p'><p:e><q:f/></p:e></p:r>",
+    "<xmlns:r/>",
+    "<r xml:lang='en' xml:space='preserve'/>",
+    // Duplicate attributes after expansion, not caught by a default
+    // namespace, and three of a kind
+    "<r xmlns:a='urn:x' xmlns:b='urn:x'><e a:n='1' b:n='2'/></r>",
+    "<r xmlns='urn:x' xmlns:a='urn:x' n='1' a:n='2'/>",
+    "<r xmlns:a='urn:x' xmlns:b='urn:x' xmlns:c='urn:x' a:n='' b:n='' c:n='' n=''/>",
+    "<r xmlns:a='urn:x'><e xmlns:b='urn:y' a:n='' b:n=''><f xmlns:b='urn:x' a:n='' b:n=''/></e></r>",
+    "<r xmlns:x='http://www.w3.org/XML/1998/namespace'/>",
+    "<r xmlns:a='urn:x' xml:n='1' a:n='2' u:n='3' v:n='4'/>",
+    // Namespace names only the parser expands
+    "<r xmlns:a='urn:x' xmlns:b='urn&#58;x' a:n='' b:n=''/>",
+    "<!DOCTYPE r [<!ENTITY u 'urn:x'>]><r xmlns:a='&u;' xmlns:b='urn:x' a:n='' b:n=''/>",
+    // Misuse of xml and xmlns
+    "<r xmlns:xml='urn:wrong'/>",
+    "<r xmlns:xml='http://www.w3.org/XML/1998/namespace'/>",
+    "<r xmlns='http://www.w3.org/XML/1998/namespace'/>",
+    "<r xmlns:xmlns='urn:x'/>",
+    "<r xmlns:p='http://www.w3.org/2000/xmlns/'/>",
+    "<r xmlns='http://www.w3.org/2000/xmlns/'/>",
+    "<r xmlns:p=''><p:e/></r>",
+    "<r xmlns='urn:x'><e xmlns=''/></r>",
+    // Bad and relative URIs
+    "<r xmlns='rel/path'/>",
+    "<r xmlns:p='rel/path'/>",
+    "<r xmlns='http://a b/' xmlns:p='%zz'/>",
+    "<r xmlns:p='urn:p' xmlns:q='::'/>",
+    // Names that are no QNames
+    "<a:b:c/>",
+    "<r a:='1' :b='2' x:y:z='3' xmlns:='urn:x'/>",
+    "<r xmlns:p='urn:p'><p:1a/></r>",
+    "<r xmlns:p='urn:p'><p:\u{b7}a/></r>",
+];
+
+unsafe extern "C" fn collect_error(data: *mut c_void, error: *const dynamic_bindings::xmlError) {
+    let errors = &mut *(data as *mut Vec<(xmlParserErrors, xmlErrorLevel, String)>);
+    if (*error).domain == xmlErrorDomain_XML_FROM_NAMESPACE as c_int {
+        let message = CStr::from_ptr((*error).message).to_string_lossy();
+        errors.push((
+            (*error).code as xmlParserErrors,
+            (*error).level as xmlErrorLevel,
+            message.into_owned(),
+        ));
+    }
+}
+
+/// The namespace errors and warnings the C parser reports for `input`.
+fn c_namespace_errors(
+    input: &str,
+    options: c_int,
+) -> Vec<(xmlParserErrors, xmlErrorLevel, String)> {
+    let c_lib = libxml2_dynload::get_c_baseline();
+    let mut errors = Vec::new();
+    unsafe {
+        let ctxt = c_lib.xmlNewParserCtxt();
+        assert!(!ctxt.is_null());
+        c_lib.xmlCtxtSetErrorHandler(
+            ctxt,
+            Some(collect_error),
+            &mut errors as *mut _ as *mut c_void,
+        );
+        let doc = c_lib.xmlCtxtReadMemory(
+            ctxt,
+            input.as_ptr() as *const c_char,
+            input.len() as c_int,
+            std::ptr::null(),
+            std::ptr::null(),
+            options,
+        );
+        c_lib.xmlFreeDoc(doc);
+        c_lib.xmlFreeParserCtxt(ctxt);
+    }
+    errors
+}
+
+#[test]
+fn test_namespace_errors_baseline() {
+    let codes = |input| -> Vec<_> {
+        c_namespace_errors(input, 0)
+            .into_iter()
+            .map(|(code, _, _)| code)
+            .collect()
+    };
+    assert_eq!(
+        codes("<a:b/>"),
+        [xmlParserErrors_XML_NS_ERR_UNDEFINED_NAMESPACE]
+    );
+    assert_eq!(
+        codes("<r xmlns:a='urn:x' xmlns:b='urn:x' a:n='1' b:n='2'/>"),
+        [xmlParserErrors_XML_NS_ERR_ATTRIBUTE_REDEFINED]
+    );
+    assert_eq!(
+        c_namespace_errors("<r xmlns='rel'/>", 0),
+        [(
+            xmlParserErrors_XML_WAR_NS_URI_RELATIVE,
+            xmlErrorLevel_XML_ERR_WARNING,
+            "xmlns: URI rel is not absolute\n".to_string()
+        )]
+    );
+    assert!(codes("<r xmlns:p='urn:p'><p:e xml:lang='en'/></r>").is_empty());
+}
+
+#[cfg(feature = "rust-parser")]
+mod rust_tests {
+    use super::*;
+    use libxml2::parser::{NsChecker, NsError};
+
+    unsafe extern "C" fn collect_rust_error(data: *mut c_void, error: *const xmlError) {
+        collect_error(data, error as *const dynamic_bindings::xmlError);
+    }
+
+    /// The namespace errors and warnings of `input` parsed with the
+    /// checker in place of `xmlParseStartTag2`'s, and what it returned.
+    fn rust_check(
+        input: &str,
+        options: c_int,
+    ) -> (c_int, Vec<(xmlParserErrors, xmlErrorLevel, String)>) {
+        let mut errors = Vec::new();
+        unsafe {
+            let ctxt = xmlNewParserCtxt();
+            assert!(!ctxt.is_null());
+            xmlCtxtSetErrorHandler(
+                ctxt,
+                Some(collect_rust_error),
+                &mut errors as *mut _ as *mut c_void,
+            );
+            let ret = parser::check_namespaces(ctxt, input.as_bytes(), options);
+            xmlFreeParserCtxt(ctxt);
+            (ret, errors)
+        }
+    }
+
+    fn rust_namespace_errors(
+        input: &str,
+        options: c_int,
+    ) -> Vec<(xmlParserErrors, xmlErrorLevel, String)> {
+        rust_check(input, options).1
+    }
+
+    #[test]
+    fn test_namespace_errors_match_c() {
+        let pedantic = xmlParserOption_XML_PARSE_PEDANTIC as c_int;
+        for doc in DOCUMENTS {
+            assert_eq!(
+                rust_namespace_errors(doc, 0),
+                c_namespace_errors(doc, 0),
+                "{doc}"
+            );
+            assert_eq!(
+                rust_namespace_errors(doc, pedantic),
+                c_namespace_errors(doc, pedantic),
+                "{doc} (pedantic)"
+            );
+        }
+    }
+
+    #[test]
+    fn test_every_constraint_covered() {
+        let mut codes: Vec<_> = DOCUMENTS
+            .iter()
+            .flat_map(|doc| rust_namespace_errors(doc, xmlParserOption_XML_PARSE_PEDANTIC as c_int))
+            .map(|(code, _, _)| code)
+            .collect();
+        codes.sort();
+        codes.dedup();
+        assert_eq!(
+            codes,
+            [
+                xmlParserErrors_XML_WAR_NS_URI,
+                xmlParserErrors_XML_WAR_NS_URI_RELATIVE,
+                xmlParserErrors_XML_NS_ERR_XML_NAMESPACE,
+                xmlParserErrors_XML_NS_ERR_UNDEFINED_NAMESPACE,
+                xmlParserErrors_XML_NS_ERR_QNAME,
+                xmlParserErrors_XML_NS_ERR_ATTRIBUTE_REDEFINED,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_check_namespaces_result() {
+        assert_eq!(
+            rust_check("<p:r xmlns:p='urn:p'><e/></p:r>", 0),
+            (0, vec![])
+        );
+        // Warnings leave the namespaces well-formed
+        assert_eq!(rust_check("<r xmlns='rel'/>", 0).0, 0);
+        assert_eq!(rust_check("<a:b/>", 0).0, 1);
+        let (ret, errors) = rust_check("<r><p:e></r>", 0);
+        assert_eq!(ret, -1);
+        assert_eq!(errors.len(), 1);
+        // The context parses as before afterwards, in namespace mode
+        unsafe {
+            let ctxt = xmlNewParserCtxt();
+            assert_eq!(parser::check_namespaces(ctxt, b"<a:b/>", 0), 1);
+            let doc = xmlCtxtReadMemory(
+                ctxt,
+                c"<p:r xmlns:p='urn:p'/>".as_ptr(),
+                22,
+                std::ptr::null(),
+                std::ptr::null(),
+                0,
+            );
+            assert!(!doc.is_null());
+            assert!(!(*xmlDocGetRootElement(doc)).ns.is_null());
+            xmlFreeDoc(doc);
+            xmlFreeParserCtxt(ctxt);
+        }
+    }
+
+    #[test]
+    fn test_scopes() {
+        let mut checker = NsChecker::new(false);
+        assert!(checker
+            .start_element(b"p:r", &[(b"xmlns:p", b"urn:p"), (b"xmlns", b"urn:d")])
+            .is_empty());
+        assert_eq!(checker.lookup(Some(b"p")), Some(&b"urn:p"[..]));
+        assert_eq!(checker.lookup(None), Some(&b"urn:d"[..]));
+        assert!(checker
+            .start_element(b"e", &[(b"xmlns:p", b"urn:q"), (b"xmlns", b"")])
+            .is_empty());
+        assert_eq!(checker.lookup(Some(b"p")), Some(&b"urn:q"[..]));
+        assert_eq!(checker.lookup(None), None);
+        checker.end_element();
+        assert_eq!(checker.lookup(Some(b"p")), Some(&b"urn:p"[..]));
+        assert_eq!(checker.lookup(Some(b"xml")), Some(parser::XML_NAMESPACE));
+        checker.end_element();
+        assert_eq!(checker.lookup(Some(b"p")), None);
+        assert_eq!(
+            checker.start_element(b"p:e", &[]),
+            [NsError {
+                code: xmlParserErrors_XML_NS_ERR_UNDEFINED_NAMESPACE,
+                level: xmlErrorLevel_XML_ERR_ERROR,
+                message: "Namespace prefix p on e is not defined\n".to_string(),
+            }]
+        );
+    }
+}
@@ -0,0 +1,341 @@
+//! Tests for relaxng module
+
+use libxml2::*;
+use std::ffi::CStr;
+use std::os::raw::{c_char, c_int, c_void};
+
+const RNG: &str = "http://relaxng.org/ns/structure/1.0";
+
+/// The code, node and message of a structured error.
+type CError = (c_int, *mut c_void, String);
+
+/// Schemas, each with instances to validate: valid ones, and ones with
+/// missing, misplaced or extra elements, attributes and text.
+fn cases() -> Vec<(String, &'static [&'static str])> {
+    vec![
+        (
+            format!(
+                "<element name='addressBook' xmlns='{RNG}'>\
+                 <zeroOrMore><element name='card'>\
+                 <optional><attribute name='id'/></optional>\
+                 <element name='name'><text/></element>\
+                 <element name='email'><text/></element>\
+                 <optional><element name='note'><text/></element></optional>\
+                 </element></zeroOrMore></element>"
+            ),
+            &[
+                "<addressBook/>",
+                "<addressBook><card><name>a</name><email>b</email></card></addressBook>",
+                "<addressBook>\n  <card id='1'>\n    <name>a</name>\n    <!-- c -->\n    \
+                 <email>b</email>\n    <note/>\n  </card>\n  <card><name/><email/></card>\n\
+                 </addressBook>",
+                "<addressBook><card><email>b</email><name>a</name></card></addressBook>",
+                "<addressBook><card x='1'><name>a</name><email>b</email></card></addressBook>",
+                "<addressBook><card><name>a</name></card></addressBook>",
+                "<addressBook><card><name>a</name><email>b</email><x/></card></addressBook>",
+                "<book/>",
+                "<addressBook>text</addressBook>",
+                "<addressBook><card><name><b/></name><email/></card></addressBook>",
+                "<addressBook xmlns='urn:a'/>",
+            ],
+        ),
+        (
+            format!(
+                "<grammar xmlns='{RNG}' ns='urn:t'>\
+                 <start><ref name='doc'/></start>\
+                 <define name='doc'><element name='doc'>\
+                 <attribute name='version'><text/></attribute>\
+                 <oneOrMore><ref name='item'/></oneOrMore></element></define>\
+                 <define name='item'><choice>\
+                 <element name='a'><empty/></element>\
+                 <element name='b'><ref name='item'/></element>\
+                 <element name='c'><text/></element>\
+                 </choice></define></grammar>"
+            ),
+            &[
+                "<doc xmlns='urn:t' version='1'><a/><c>x</c><b><a/></b></doc>",
+                "<doc xmlns='urn:t' version='1'><b><b><c/></b></b></doc>",
+                "<doc xmlns='urn:t' version='1'/>",
+                "<doc xmlns='urn:t'><a/></doc>",
+                "<doc xmlns='urn:t' version='1'><a>x</a></doc>",
+                "<doc xmlns='urn:t' version='1'><d/></doc>",
+                "<doc xmlns='urn:t' version='1'><b/></doc>",
+                "<doc xmlns='urn:t' version='1'><a xmlns=''/></doc>",
+                "<doc version='1'><a/></doc>",
+                "<doc xmlns='urn:u' version='1'><a/></doc>",
+                "<doc xmlns='urn:t' version='1'>x<a/></doc>",
+            ],
+        ),
+        (
+            format!(
+                "<element name='r' xmlns='{RNG}'><zeroOrMore><choice>\
+                 <group><element name='a'><empty/></element><element name='b'><empty/></element></group>\
+                 <group><element name='a'><empty/></element><element name='c'><empty/></element></group>\
+                 </choice></zeroOrMore>\
+                 <optional><attribute name='x'><empty/></attribute></optional></element>"
+            ),
+            &[
+                "<r/>",
+                "<r><a/><b/><a/><c/></r>",
+                "<r x=' '><a/><c/></r>",
+                "<r x='1'/>",
+                "<r><a/></r>",
+                "<r><a/><d/></r>",
+                "<r><b/></r>",
+                "<r><a/><b/><a/><b/><c/></r>",
+            ],
+        ),
+        (
+            format!(
+                "<element name='r' xmlns='{RNG}'><choice>\
+                 <attribute name='x'/><element name='x'><text/></element>\
+                 </choice><zeroOrMore><element name='y'><empty/></element></zeroOrMore></element>"
+            ),
+            &[
+                "<r x='1'/>",
+                "<r><x/><y/></r>",
+                "<r/>",
+                "<r x='1'><x/></r>",
+                "<r><y/></r>",
+                "<r><x/>t</r>",
+            ],
+        ),
+        (
+            format!(
+                "<element name='r' xmlns='{RNG}'><oneOrMore><element name='i'>\
+                 <optional><attribute name='k'/></optional><choice>\
+                 <element name='a'><text/></element>\
+                 <group><element name='b'><empty/></element>\
+                 <optional><element name='c'><empty/></element></optional></group>\
+                 </choice></element></oneOrMore></element>"
+            ),
+            &[
+                "<r><i><a>x</a></i><i k='1'><b/><c/></i><i><b/></i></r>",
+                "<r/>",
+                "<r><i/></r>",
+                "<r><i><c/></i></r>",
+                "<r><i><b/><b/></i></r>",
+                "<r><i k='1' j='2'><a/></i></r>",
+                "<r><i><a><b/></a></i><i><b/></i></r>",
+                "<r><i><b/></i><j/></r>",
+                "<r><i><b/></i>text</r>",
+            ],
+        ),
+    ]
+}
+
+/// Schemas the parser rejects.
+fn bad_schemas() -> Vec<String> {
+    vec![
+        format!("<element xmlns='{RNG}'><empty/></element>"),
+        format!("<element name='a' xmlns='{RNG}'/>"),
+        format!("<grammar xmlns='{RNG}'><define name='a'><empty/></define></grammar>"),
+        format!("<grammar xmlns='{RNG}'><start><ref name='a'/></start></grammar>"),
+        format!(
+            "<grammar xmlns='{RNG}'><start><ref name='a'/></start>\
+             <define name='a'><ref name='a'/></define></grammar>"
+        ),
+        format!("<element name='a' xmlns='{RNG}'><attribute name='b'><element name='c'><empty/></element></attribute></element>"),
+        format!("<element name='a' xmlns='{RNG}'><group><attribute name='b'/><attribute name='b'/></group></element>"),
+        format!("<element name='a' xmlns='{RNG}'><oneOrMore><group><attribute name='b'/><element name='c'><empty/></element></group></oneOrMore></element>"),
+        format!("<element name='a' xmlns='{RNG}'><foo/></element>"),
+        "<element name='a'/>".to_string(),
+    ]
+}
+
+/// Collect the structured errors in `domain`, passed as the first
+/// element of the `(c_int, Vec<_>)` in `data`, with their node and
+/// message.
+unsafe extern "C" fn collect_error(data: *mut c_void, error: *const dynamic_bindings::xmlError) {
+    let (domain, errors) = &mut *(data as *mut (c_int, Vec<CError>));
+    if (*error).domain == *domain {
+        let message = if (*error).message.is_null() {
+            String::new()
+        } else {
+            CStr::from_ptr((*error).message)
+                .to_string_lossy()
+                .into_owned()
+        };
+        errors.push(((*error).code, (*error).node, message));
+    }
+}
+
+/// Parse `input` with the C baseline.
+unsafe fn c_parse(input: &str) -> dynamic_bindings::xmlDocPtr {
+    let c_lib = libxml2_dynload::get_c_baseline();
+    let doc = c_lib.xmlReadMemory(
+        input.as_ptr() as *const c_char,
+        input.len() as c_int,
+        std::ptr::null(),
+        std::ptr::null(),
+        0,
+    );
+    assert!(!doc.is_null(), "{input}");
+    doc
+}
+
+/// Parse `rng` with the C baseline, returning the schema or the codes
+/// of the errors the parser reports.
+unsafe fn c_schema(rng: &str) -> Result<dynamic_bindings::xmlRelaxNGPtr, Vec<c_int>> {
+    let c_lib = libxml2_dynload::get_c_baseline();
+    let ctxt = c_lib.xmlRelaxNGNewMemParserCtxt(rng.as_ptr() as *const c_char, rng.len() as c_int);
+    assert!(!ctxt.is_null());
+    let mut errors: (c_int, Vec<CError>) = (xmlErrorDomain_XML_FROM_RELAXNGP as c_int, Vec::new());
+    c_lib.xmlRelaxNGSetParserStructuredErrors(
+        ctxt,
+        Some(collect_error),
+        &mut errors as *mut _ as *mut c_void,
+    );
+    let schema = c_lib.xmlRelaxNGParse(ctxt);
+    c_lib.xmlRelaxNGFreeParserCtxt(ctxt);
+    if schema.is_null() {
+        Err(errors.1.into_iter().map(|e| e.0).collect())
+    } else {
+        assert!(errors.1.is_empty(), "{rng}: {:?}", errors.1);
+        Ok(schema)
+    }
+}
+
+/// Validate `doc` with the C baseline: whether it is valid, and the
+/// code, node and message of each error reported.
+unsafe fn c_validate(
+    schema: dynamic_bindings::xmlRelaxNGPtr,
+    doc: dynamic_bindings::xmlDocPtr,
+) -> (bool, Vec<CError>) {
+    let c_lib = libxml2_dynload::get_c_baseline();
+    let ctxt = c_lib.xmlRelaxNGNewValidCtxt(schema);
+    let mut errors: (c_int, Vec<CError>) = (xmlErrorDomain_XML_FROM_RELAXNGV as c_int, Vec::new());
+    c_lib.xmlRelaxNGSetValidStructuredErrors(
+        ctxt,
+        Some(collect_error),
+        &mut errors as *mut _ as *mut c_void,
+    );
+    let ret = c_lib.xmlRelaxNGValidateDoc(ctxt, doc);
+    c_lib.xmlRelaxNGFreeValidCtxt(ctxt);
+    (ret == 0, errors.1)
+}
+
+#[test]
+fn test_relaxng_baseline() {
+    let c_lib = libxml2_dynload::get_c_baseline();
+    let (rng, _) = &cases()[0];
+    unsafe {
+        let schema = c_schema(rng).unwrap();
+        let codes = |input: &str| {
+            let doc = c_parse(input);
+            let (valid, errors) = c_validate(schema, doc);
+            c_lib.xmlFreeDoc(doc);
+            (valid, errors.into_iter().map(|e| e.0).collect::<Vec<_>>())
+        };
+        assert_eq!(
+            codes("<addressBook><card><name/><email/></card></addressBook>"),
+            (true, vec![])
+        );
+        assert_eq!(
+            codes("<addressBook><card><email/><name/></card></addressBook>"),
+            (
+                false,
+                vec![xmlRelaxNGValidErr_XML_RELAXNG_ERR_ELEMWRONG as c_int]
+            )
+        );
+        assert_eq!(
+            codes("<addressBook><card x='1'><name/><email/></card></addressBook>"),
+            (
+                false,
+                vec![xmlRelaxNGValidErr_XML_RELAXNG_ERR_INVALIDATTR as c_int]
+            )
+        );
+        assert_eq!(
+            codes("<book/>"),
+            (
+                false,
+                vec![xmlRelaxNGValidErr_XML_RELAXNG_ERR_ELEMNAME as c_int]
+            )
+        );
+        c_lib.xmlRelaxNGFree(schema);
+
+        assert_eq!(
+            c_schema(&format!(
+                "<grammar xmlns='{RNG}'><start><ref name='a'/></start></grammar>"
+            ))
+            .err()
+            .unwrap()
+            .first(),
+            Some(&(xmlParserErrors_XML_RNGP_REF_NO_DEF as c_int))
+        );
+    }
+}
+
+#[cfg(feature = "rust-relaxng")]
+mod rust_tests {
+    use super::*;
+
+    /// Parse `rng` into a Rust grammar.
+    unsafe fn rust_schema(rng: &str) -> Result<Grammar, GrammarError> {
+        let c_lib = libxml2_dynload::get_c_baseline();
+        let doc = c_parse(rng);
+        let grammar = Grammar::parse(doc as xmlDocPtr);
+        c_lib.xmlFreeDoc(doc);
+        grammar
+    }
+
+    #[test]
+    fn test_validate_matches_baseline() {
+        let c_lib = libxml2_dynload::get_c_baseline();
+        for (rng, instances) in cases() {
+            unsafe {
+                let schema = c_schema(&rng).unwrap();
+                let grammar = rust_schema(&rng).unwrap_or_else(|e| panic!("{rng}: {e:?}"));
+                for input in instances {
+                    let doc = c_parse(input);
+                    let expected = c_validate(schema, doc);
+                    let actual = match grammar.validate(doc as xmlDocPtr) {
+                        Ok(()) => (true, Vec::new()),
+                        Err(errors) => (
+                            false,
+                            errors
+                                .into_iter()
+                                .map(|e| (e.code, e.node as *mut c_void, e.message))
+                                .collect(),
+                        ),
+                    };
+                    c_lib.xmlFreeDoc(doc);
+                    assert_eq!(actual, expected, "{rng}: {input}");
+                }
+                c_lib.xmlRelaxNGFree(schema);
+            }
+        }
+    }
+
+    #[test]
+    fn test_bad_schemas_match_baseline() {
+        for rng in bad_schemas() {
+            unsafe {
+                let expected = c_schema(&rng).err().unwrap();
+                let actual = rust_schema(&rng).err().unwrap();
+                assert_eq!(expected.first(), Some(&actual.code()), "{rng}: {actual:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_unsupported() {
+        for pattern in [
+            "<data type='int'/>",
+            "<value>a</value>",
+            "<interleave><empty/></interleave>",
+            "<element><anyName/><empty/></element>",
+        ] {
+            let rng = format!("<element name='a' xmlns='{RNG}'>{pattern}</element>");
+            let err = unsafe { rust_schema(&rng) }.err().unwrap();
+            assert!(
+                matches!(err, GrammarError::Unsupported(_)),
+                "{rng}: {err:?}"
+            );
+            assert_eq!(
+                err.code(),
+                xmlParserErrors_XML_RNGP_UNKNOWN_CONSTRUCT as c_int
+            );
+        }
+    }
+}
@@ -0,0 +1,1230 @@
+//! Tests for the safe document wrappers
+
+use libxml2::{
+    debug_dump, process_xincludes, xmlErrorDomain_XML_FROM_IO, xmlErrorDomain_XML_FROM_PARSER,
+    xmlErrorDomain_XML_FROM_XINCLUDE, xmlErrorLevel_XML_ERR_FATAL,
+    xmlParserErrors_XML_ERR_DOCUMENT_EMPTY, xmlParserErrors_XML_ERR_RESOURCE_LIMIT,
+    xmlParserErrors_XML_ERR_TAG_NAME_MISMATCH, xmlParserErrors_XML_ERR_UNDECLARED_ENTITY,
+    xmlParserErrors_XML_IO_ENOENT, xmlParserErrors_XML_IO_NETWORK_ATTEMPT,
+    xmlParserErrors_XML_XINCLUDE_NO_FALLBACK, xmlParserErrors_XML_XINCLUDE_PARSE_VALUE,
+    xmlParserErrors_XML_XINCLUDE_RECURSION, xmlParserOption_XML_PARSE_DTDLOAD,
+    xmlParserOption_XML_PARSE_HUGE, xmlParserOption_XML_PARSE_NOBASEFIX,
+    xmlParserOption_XML_PARSE_NOENT, xmlParserOption_XML_PARSE_NOERROR,
+    xmlParserOption_XML_PARSE_NONET, xmlParserOption_XML_PARSE_NOXINCNODE,
+    xmlParserOption_XML_PARSE_NO_XXE, xmlParserOption_XML_PARSE_RECOVER, xmlResourceType,
+    xmlResourceType_XML_RESOURCE_DTD, xmlResourceType_XML_RESOURCE_GENERAL_ENTITY, ParserOptions,
+    XmlDoc, XmlError, XmlNode, PARSER_MAX_DEPTH, PARSER_MAX_DEPTH_HUGE,
+};
+use std::io::{self, Cursor, Read};
+use std::os::raw::{c_char, c_int};
+
+fn names<'a>(nodes: impl Iterator<Item = XmlNode<'a>>) -> Vec<&'a str> {
+    nodes.map(|node| node.name().unwrap()).collect()
+}
+
+#[test]
+fn test_children_in_document_order() {
+    let doc =
+        XmlDoc::parse_memory(b"<root><a/><b x='1'/>text<c><d/></c><!--x--></root>", 0).unwrap();
+    let root = doc.root().unwrap();
+    assert_eq!(root.name(), Some("root"));
+    assert_eq!(names(root.children()), ["a", "b", "text", "c", "comment"]);
+    assert_eq!(
+        names(root.children().filter(|n| n.is_element())),
+        ["a", "b", "c"]
+    );
+
+    let mut children = root.children();
+    assert_eq!(children.by_ref().count(), 5);
+    assert!(children.next().is_none());
+    assert!(children.next().is_none());
+
+    for child in root.children() {
+        assert_eq!(child.parent(), Some(root));
+    }
+    assert_eq!(root.parent(), None);
+
+    let c = root.children().nth(3).unwrap();
+    assert_eq!(names(c.children()), ["d"]);
+    assert_eq!(c.children().next().unwrap().children().count(), 0);
+    assert_eq!(
+        c.prev_sibling().and_then(|n| n.content()).as_deref(),
+        Some("text")
+    );
+}
+
+#[test]
+fn test_doc_children() {
+    let doc = XmlDoc::parse_memory(b"<?pi data?><root/><!--end-->", 0).unwrap();
+    assert_eq!(names(doc.children()), ["pi", "root", "comment"]);
+}
+
+#[test]
+fn test_attributes() {
+    let doc = XmlDoc::parse_memory(
+        b"<!DOCTYPE r [<!ENTITY e 'ent'>]><r x='1' y='a&amp;&e;' z=''>t</r>",
+        0,
+    )
+    .unwrap();
+    let root = doc.root().unwrap();
+    let attrs: Vec<_> = root
+        .attributes()
+        .map(|attr| (attr.name().unwrap(), attr.value()))
+        .collect();
+    assert_eq!(
+        attrs,
+        [
+            ("x", "1".to_owned()),
+            ("y", "a&ent".to_owned()),
+            ("z", String::new())
+        ]
+    );
+    assert!(root.attributes().all(|attr| attr.parent() == Some(root)));
+
+    // Only elements have attributes.
+    let text = root.children().next().unwrap();
+    assert_eq!(text.attributes().count(), 0);
+}
+
+#[test]
+fn test_attribute_lookup() {
+    let doc = XmlDoc::parse_memory(
+        b"<!DOCTYPE r [<!ATTLIST r d CDATA 'def'>]><r x='1' caf\xc3\xa9='\xc3\xa9' z=''>t</r>",
+        0,
+    )
+    .unwrap();
+    let root = doc.root().unwrap();
+    assert_eq!(root.attribute("x").as_deref(), Some("1"));
+    assert_eq!(root.attribute("caf\u{e9}").as_deref(), Some("\u{e9}"));
+    assert_eq!(root.attribute("z").as_deref(), Some(""));
+    assert_eq!(root.attribute("d").as_deref(), Some("def"));
+    assert_eq!(root.attribute("missing"), None);
+    assert_eq!(root.attribute("x\0y"), None);
+
+    let text = root.children().next().unwrap();
+    assert_eq!(text.attribute("x"), None);
+}
+
+#[test]
+fn test_parse_failure() {
+    let options = xmlParserOption_XML_PARSE_NOERROR as c_int;
+    assert!(XmlDoc::parse_memory(b"<unclosed>", options).is_err());
+    let err = XmlDoc::parse_memory(b"", options).unwrap_err();
+    assert_eq!(err.code, xmlParserErrors_XML_ERR_DOCUMENT_EMPTY as c_int);
+}
+
+fn parse_root_name(input: &[u8]) -> Result<String, Box<dyn std::error::Error>> {
+    let doc = XmlDoc::parse_memory(input, xmlParserOption_XML_PARSE_NOERROR as c_int)?;
+    Ok(doc.root().ok_or("no root")?.name().unwrap().to_owned())
+}
+
+#[test]
+fn test_error_display() {
+    let err = XmlDoc::parse_memory(
+        b"<doc>\n  <a>\n</b>\n</doc>",
+        xmlParserOption_XML_PARSE_NOERROR as c_int,
+    )
+    .unwrap_err();
+    assert_eq!(err.domain, xmlErrorDomain_XML_FROM_PARSER as c_int);
+    assert_eq!(err.code, xmlParserErrors_XML_ERR_TAG_NAME_MISMATCH as c_int);
+    assert_eq!(err.level, xmlErrorLevel_XML_ERR_FATAL);
+    assert_eq!(err.line, 3);
+
+    let shown = err.to_string();
+    assert!(shown.contains("line 3"), "{shown}");
+    assert!(shown.contains("76"), "{shown}");
+    assert!(shown.contains(&err.message));
+    assert!(!shown.ends_with('\n'));
+
+    // Errors flow through `?` into boxed errors.
+    assert_eq!(parse_root_name(b"<ok/>").unwrap(), "ok");
+    let boxed = parse_root_name(b"<doc>\n  <a>\n</b>\n</doc>").unwrap_err();
+    assert_eq!(boxed.downcast_ref::<XmlError>(), Some(&err));
+}
+
+#[test]
+fn test_error_from_raw() {
+    unsafe {
+        assert_eq!(XmlError::from_raw(std::ptr::null()), None);
+    }
+}
+
+/// The tree below `node` as one line per node, for comparing parses.
+fn dump(node: XmlNode, depth: usize, out: &mut Vec<String>) {
+    let attrs: Vec<_> = node
+        .attributes()
+        .map(|attr| format!("{}={:?}", attr.name().unwrap(), attr.value()))
+        .collect();
+    let content = if node.is_element() {
+        None
+    } else {
+        node.content()
+    };
+    out.push(format!(
+        "{}{} {:?} {attrs:?} {content:?}",
+        " ".repeat(depth),
+        node.node_type(),
+        node.name()
+    ));
+    for child in node.children() {
+        dump(child, depth + 1, out);
+    }
+}
+
+fn dump_doc(doc: &XmlDoc) -> Vec<String> {
+    let mut out = Vec::new();
+    for node in doc.children() {
+        dump(node, 0, &mut out);
+    }
+    out
+}
+
+/// Hands out at most one byte per read.
+struct ByteReader<'a>(&'a [u8]);
+
+impl Read for ByteReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.0.len().min(buf.len()).min(1);
+        buf[..n].copy_from_slice(&self.0[..n]);
+        self.0 = &self.0[n..];
+        Ok(n)
+    }
+}
+
+/// Yields `input`, then fails.
+struct FailingReader<'a>(&'a [u8]);
+
+impl Read for FailingReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.0.is_empty() {
+            return Err(io::Error::other("disk on fire"));
+        }
+        let n = self.0.len().min(buf.len());
+        buf[..n].copy_from_slice(&self.0[..n]);
+        self.0 = &self.0[n..];
+        Ok(n)
+    }
+}
+
+fn reader_input() -> Vec<u8> {
+    let mut input = String::from(
+        "<?xml version='1.0' encoding='UTF-8'?>\n\
+         <!DOCTYPE r [<!ENTITY e 'entity text'>]>\n\
+         <r xmlns:p='urn:p' a='&e;'><!-- comment --><?pi x?>\n",
+    );
+    for i in 0..2000 {
+        input.push_str(&format!(
+            "<p:item n='{i}'>caf\u{e9} \u{65e5}\u{672c} \u{1f600} &amp; &e;<![CDATA[<raw>]]></p:item>\n"
+        ));
+    }
+    input.push_str("</r>\n");
+    input.into_bytes()
+}
+
+#[test]
+fn test_parse_reader_matches_memory() {
+    let input = reader_input();
+    let expected = dump_doc(&XmlDoc::parse_memory(&input, 0).unwrap());
+    assert!(expected.len() > 6000);
+
+    let doc = XmlDoc::parse_reader(Cursor::new(input.clone()), 0).unwrap();
+    assert_eq!(dump_doc(&doc), expected);
+
+    let doc = XmlDoc::parse_reader(ByteReader(&input), 0).unwrap();
+    assert_eq!(dump_doc(&doc), expected);
+}
+
+#[test]
+fn test_parse_reader_errors() {
+    let options = xmlParserOption_XML_PARSE_NOERROR as c_int;
+    let err = XmlDoc::parse_reader(ByteReader(b"<a><b></a>"), options).unwrap_err();
+    assert_eq!(err.code, xmlParserErrors_XML_ERR_TAG_NAME_MISMATCH as c_int);
+
+    let input = reader_input();
+    let err = XmlDoc::parse_reader(FailingReader(&input[..5000]), options).unwrap_err();
+    assert_eq!(err.domain, xmlErrorDomain_XML_FROM_IO as c_int);
+    assert!(err.to_string().contains("disk on fire"), "{err}");
+
+    // Recovery doesn't hide a read error either.
+    let options = options | xmlParserOption_XML_PARSE_RECOVER as c_int;
+    let err = XmlDoc::parse_reader(FailingReader(&input[..5000]), options).unwrap_err();
+    assert_eq!(err.domain, xmlErrorDomain_XML_FROM_IO as c_int);
+}
+
+#[test]
+fn test_parse_reader_panic() {
+    struct Panicking;
+
+    impl Read for Panicking {
+        fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+            panic!("reader panicked");
+        }
+    }
+
+    let options = xmlParserOption_XML_PARSE_NOERROR as c_int;
+    let payload =
+        std::panic::catch_unwind(|| XmlDoc::parse_reader(Panicking, options)).unwrap_err();
+    assert_eq!(payload.downcast_ref::<&str>(), Some(&"reader panicked"));
+}
+
+/// `depth` nested elements.
+fn nested(depth: usize) -> Vec<u8> {
+    let mut input = "<a>".repeat(depth);
+    input.push_str(&"</a>".repeat(depth));
+    input.into_bytes()
+}
+
+/// The code and message of the error the C baseline fails `input` with.
+fn c_parse_error(input: &[u8], options: c_int) -> (c_int, String) {
+    let c_lib = libxml2::libxml2_dynload::get_c_baseline();
+    unsafe {
+        let ctxt = c_lib.xmlNewParserCtxt();
+        let doc = c_lib.xmlCtxtReadMemory(
+            ctxt,
+            input.as_ptr() as *const c_char,
+            input.len() as c_int,
+            std::ptr::null(),
+            std::ptr::null(),
+            options,
+        );
+        assert!(doc.is_null());
+        let err = &*c_lib.xmlCtxtGetLastError(ctxt as *mut _);
+        let message = std::ffi::CStr::from_ptr(err.message).to_string_lossy();
+        let ret = (err.code, message.trim_end().to_owned());
+        c_lib.xmlFreeParserCtxt(ctxt);
+        ret
+    }
+}
+
+#[test]
+fn test_depth_limit_matches_baseline() {
+    let options = xmlParserOption_XML_PARSE_NOERROR as c_int;
+    assert!(XmlDoc::parse_memory(&nested(PARSER_MAX_DEPTH), options).is_ok());
+
+    let deep = nested(PARSER_MAX_DEPTH + 1);
+    let err = XmlDoc::parse_memory(&deep, options).unwrap_err();
+    assert_eq!(err.code, xmlParserErrors_XML_ERR_RESOURCE_LIMIT as c_int);
+    assert!(err.message.starts_with("Excessive depth"), "{err}");
+    assert_eq!((err.code, err.message), c_parse_error(&deep, options));
+
+    let huge = options | xmlParserOption_XML_PARSE_HUGE as c_int;
+    assert!(XmlDoc::parse_memory(&deep, huge).is_ok());
+    let deep = nested(PARSER_MAX_DEPTH_HUGE + 1);
+    let err = XmlDoc::parse_memory(&deep, huge).unwrap_err();
+    assert_eq!((err.code, err.message), c_parse_error(&deep, huge));
+}
+
+#[test]
+fn test_max_depth_option() {
+    let options = ParserOptions {
+        flags: xmlParserOption_XML_PARSE_NOERROR as c_int,
+        max_depth: Some(10),
+        ..Default::default()
+    };
+    assert_eq!(options.effective_max_depth(), 10);
+    assert!(XmlDoc::parse_memory(&nested(10), options).is_ok());
+
+    let err = XmlDoc::parse_memory(&nested(11), options).unwrap_err();
+    assert_eq!(err.domain, xmlErrorDomain_XML_FROM_PARSER as c_int);
+    assert_eq!(err.code, xmlParserErrors_XML_ERR_RESOURCE_LIMIT as c_int);
+    assert_eq!(err.message, "Excessive depth in document: 10, limit is 10");
+    let err = XmlDoc::parse_reader(ByteReader(&nested(11)), options).unwrap_err();
+    assert_eq!(err.code, xmlParserErrors_XML_ERR_RESOURCE_LIMIT as c_int);
+
+    // Recovery keeps no partial document.
+    let recover = ParserOptions {
+        flags: options.flags | xmlParserOption_XML_PARSE_RECOVER as c_int,
+        ..options
+    };
+    assert!(XmlDoc::parse_memory(&nested(11), recover).is_err());
+
+    // A limit can't raise the parser's own.
+    let options = ParserOptions {
+        max_depth: Some(100_000),
+        ..options
+    };
+    assert_eq!(options.effective_max_depth(), PARSER_MAX_DEPTH);
+    let err = XmlDoc::parse_memory(&nested(PARSER_MAX_DEPTH + 1), options).unwrap_err();
+    assert!(err.message.ends_with("use XML_PARSE_HUGE option"), "{err}");
+}
+
+#[test]
+fn test_pathological_depth() {
+    let deep = nested(1_000_000);
+    let options = xmlParserOption_XML_PARSE_NOERROR as c_int;
+    for flags in [options, options | xmlParserOption_XML_PARSE_HUGE as c_int] {
+        let err = XmlDoc::parse_memory(&deep, flags).unwrap_err();
+        assert_eq!(err.code, xmlParserErrors_XML_ERR_RESOURCE_LIMIT as c_int);
+        let limited = ParserOptions {
+            flags,
+            max_depth: Some(64),
+            ..Default::default()
+        };
+        let err = XmlDoc::parse_memory(&deep, limited).unwrap_err();
+        assert_eq!(err.code, xmlParserErrors_XML_ERR_RESOURCE_LIMIT as c_int);
+    }
+}
+
+#[test]
+fn test_parse_str() {
+    let doc = XmlDoc::parse_str("<greeting lang='fr'>caf\u{e9}</greeting>", 0).unwrap();
+    let root = doc.root().unwrap();
+    assert_eq!(root.name(), Some("greeting"));
+    assert_eq!(
+        root.children().next().unwrap().content().as_deref(),
+        Some("caf\u{e9}")
+    );
+
+    // A `str` is UTF-8 whatever the declaration claims.
+    let doc = XmlDoc::parse_str(
+        "<?xml version='1.0' encoding='ISO-8859-1'?><r>\u{e9}</r>",
+        0,
+    )
+    .unwrap();
+    assert_eq!(doc.root().unwrap().content().as_deref(), Some("\u{e9}"));
+}
+
+#[test]
+fn test_parse_str_errors() {
+    // A namespace error, then the one that stops the parser.
+    let input = "<p:doc>\n  <a>\n</b>\n</p:doc>";
+    let errors = XmlDoc::parse_str(input, 0).unwrap_err();
+    assert_eq!(errors.len(), 2);
+    assert_ne!(errors[0].level, xmlErrorLevel_XML_ERR_FATAL);
+    let err = XmlDoc::parse_memory(input.as_bytes(), xmlParserOption_XML_PARSE_NOERROR as c_int)
+        .unwrap_err();
+    assert_eq!(err.code, xmlParserErrors_XML_ERR_TAG_NAME_MISMATCH as c_int);
+    assert_eq!(errors[1], err);
+
+    // Errors the parser never reported are returned too.
+    let options = ParserOptions {
+        flags: 0,
+        max_depth: Some(2),
+        ..Default::default()
+    };
+    let errors = XmlDoc::parse_str("<a><b><c/></b></a>", options).unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0].code,
+        xmlParserErrors_XML_ERR_RESOURCE_LIMIT as c_int
+    );
+}
+
+/// The document element name and last error code of the C baseline
+/// reading the NUL-terminated `input`.
+fn c_read_doc(input: &[u8]) -> (Option<String>, c_int) {
+    let c_lib = libxml2::libxml2_dynload::get_c_baseline();
+    unsafe {
+        let ctxt = c_lib.xmlNewParserCtxt();
+        let doc = c_lib.xmlCtxtReadDoc(
+            ctxt,
+            input.as_ptr(),
+            std::ptr::null(),
+            c"UTF-8".as_ptr(),
+            xmlParserOption_XML_PARSE_NOERROR as c_int,
+        );
+        let name = if doc.is_null() {
+            None
+        } else {
+            let root = c_lib.xmlDocGetRootElement(doc);
+            let name = std::ffi::CStr::from_ptr((*root).name as *const c_char);
+            c_lib.xmlFreeDoc(doc);
+            Some(name.to_string_lossy().into_owned())
+        };
+        let err = c_lib.xmlCtxtGetLastError(ctxt as *mut _);
+        let code = if err.is_null() { 0 } else { (*err).code };
+        c_lib.xmlFreeParserCtxt(ctxt);
+        (name, code)
+    }
+}
+
+#[test]
+fn test_parse_str_embedded_nul() {
+    for input in [
+        "<root/>\0<trailing>",
+        "<a>text\0</a>",
+        "\0<a/>",
+        "<a x='\0'/>",
+    ] {
+        let mut c_input = input.as_bytes().to_vec();
+        c_input.push(0);
+        let (c_name, c_code) = c_read_doc(&c_input);
+        match XmlDoc::parse_str(input, 0) {
+            Ok(doc) => {
+                assert_eq!(doc.root().unwrap().name().map(str::to_owned), c_name);
+            }
+            Err(errors) => {
+                assert_eq!(c_name, None, "{input:?}");
+                assert_eq!(errors.last().unwrap().code, c_code, "{input:?}");
+            }
+        }
+    }
+    let doc = XmlDoc::parse_str("<root/>\0<trailing>", 0).unwrap();
+    assert_eq!(doc.root().unwrap().name(), Some("root"));
+}
+
+/// A mismatched end tag and a reference to an undeclared entity, both
+/// fatal without recovery.
+const MALFORMED: &str = "<doc>\n  <a>one</b>\n  <c>two &undefined; three</c>\n  <d/>\n</doc>\n";
+
+unsafe extern "C" fn collect_c_error(
+    data: *mut std::ffi::c_void,
+    error: *const libxml2::dynamic_bindings::xmlError,
+) {
+    let errors = &mut *(data as *mut Vec<XmlError>);
+    errors.extend(XmlError::from_raw(error as *const _));
+}
+
+/// The debug dump of the document the C baseline recovers from `input`,
+/// and every error it reports on the way.
+fn c_recover(input: &str) -> (String, Vec<XmlError>) {
+    let c_lib = libxml2::libxml2_dynload::get_c_baseline();
+    let mut errors = Vec::new();
+    unsafe {
+        let ctxt = c_lib.xmlNewParserCtxt();
+        c_lib.xmlCtxtSetErrorHandler(
+            ctxt,
+            Some(collect_c_error),
+            &mut errors as *mut Vec<XmlError> as *mut _,
+        );
+        let raw = c_lib.xmlCtxtReadMemory(
+            ctxt,
+            input.as_ptr() as *const c_char,
+            input.len() as c_int,
+            std::ptr::null(),
+            std::ptr::null(),
+            xmlParserOption_XML_PARSE_RECOVER as c_int,
+        );
+        c_lib.xmlFreeParserCtxt(ctxt);
+        assert!(!raw.is_null());
+        let doc = XmlDoc::from_raw(raw as *mut _).unwrap();
+        let mut out = Vec::new();
+        debug_dump(&doc, &mut out).unwrap();
+        c_lib.xmlFreeDoc(doc.into_raw() as *mut _);
+        (String::from_utf8(out).unwrap(), errors)
+    }
+}
+
+#[test]
+fn test_parse_recover_matches_baseline() {
+    let (c_dump, c_errors) = c_recover(MALFORMED);
+    let (doc, errors) = XmlDoc::parse_recover(MALFORMED.as_bytes(), 0).unwrap();
+    let mut dump = Vec::new();
+    debug_dump(&doc, &mut dump).unwrap();
+    assert_eq!(String::from_utf8(dump).unwrap(), c_dump);
+    assert_eq!(errors, c_errors);
+
+    let codes: Vec<_> = errors.iter().map(|err| err.code).collect();
+    assert!(codes.contains(&(xmlParserErrors_XML_ERR_TAG_NAME_MISMATCH as c_int)));
+    assert!(codes.contains(&(xmlParserErrors_XML_ERR_UNDECLARED_ENTITY as c_int)));
+    assert_eq!(
+        names(
+            doc.root()
+                .unwrap()
+                .children()
+                .filter(|node| node.name() != Some("text"))
+        ),
+        ["a", "c", "d"]
+    );
+
+    // Without recovery there is no document, and the same first error.
+    let failed = XmlDoc::parse_str(MALFORMED, 0).unwrap_err();
+    assert_eq!(failed[0], errors[0]);
+
+    // Nothing to recover.
+    let errors = XmlDoc::parse_recover(b"", 0).unwrap_err();
+    assert_eq!(
+        errors.last().unwrap().code,
+        xmlParserErrors_XML_ERR_DOCUMENT_EMPTY as c_int
+    );
+}
+
+/// An external DTD declaring a default attribute, and an external entity
+/// referenced from content.
+const EXTERNAL: &str = "<!DOCTYPE doc SYSTEM 'ext.dtd' [\n\
+<!ENTITY ext SYSTEM 'ext.xml'>\n\
+]>\n\
+<doc>&ext;</doc>\n";
+
+/// The content of each resource `EXTERNAL` references.
+fn external_resource(url: &str) -> Option<&'static str> {
+    match url {
+        "ext.dtd" => Some("<!ATTLIST doc from CDATA 'dtd'>"),
+        "ext.xml" => Some("<in/>"),
+        _ => None,
+    }
+}
+
+thread_local! {
+    static LOADED: std::cell::RefCell<Vec<(String, xmlResourceType)>> = const { std::cell::RefCell::new(Vec::new()) };
+}
+
+fn rust_loader(url: &str, _public_id: Option<&str>, kind: xmlResourceType) -> Option<Vec<u8>> {
+    LOADED.with(|loaded| loaded.borrow_mut().push((url.to_owned(), kind)));
+    external_resource(url).map(|content| content.as_bytes().to_vec())
+}
+
+unsafe extern "C" fn c_loader(
+    vctxt: *mut std::ffi::c_void,
+    url: *const c_char,
+    _public_id: *const c_char,
+    kind: libxml2::dynamic_bindings::xmlResourceType,
+    _flags: libxml2::dynamic_bindings::xmlParserInputFlags,
+    out: *mut *mut libxml2::dynamic_bindings::xmlParserInput,
+) -> libxml2::dynamic_bindings::xmlParserErrors {
+    let loaded = &mut *(vctxt as *mut Vec<(String, xmlResourceType)>);
+    let name = std::ffi::CStr::from_ptr(url).to_string_lossy().into_owned();
+    loaded.push((name.clone(), kind));
+    let Some(content) = external_resource(&name) else {
+        return xmlParserErrors_XML_IO_ENOENT as _;
+    };
+    let c_lib = libxml2::libxml2_dynload::get_c_baseline();
+    *out = c_lib.xmlNewInputFromMemory(url, content.as_ptr() as *const _, content.len(), 0);
+    0
+}
+
+/// The debug dump of what the C baseline parses `input` into with
+/// `flags`, the resources its loader was asked for, and every error.
+fn c_parse_external(
+    input: &str,
+    flags: c_int,
+    loader: bool,
+) -> (String, Vec<(String, xmlResourceType)>, Vec<XmlError>) {
+    let c_lib = libxml2::libxml2_dynload::get_c_baseline();
+    let mut loaded = Vec::new();
+    let mut errors = Vec::new();
+    unsafe {
+        let ctxt = c_lib.xmlNewParserCtxt();
+        c_lib.xmlCtxtSetErrorHandler(
+            ctxt,
+            Some(collect_c_error),
+            &mut errors as *mut Vec<XmlError> as *mut _,
+        );
+        if loader {
+            c_lib.xmlCtxtSetResourceLoader(
+                ctxt,
+                Some(c_loader),
+                &mut loaded as *mut Vec<(String, xmlResourceType)> as *mut _,
+            );
+        }
+        let raw = c_lib.xmlCtxtReadMemory(
+            ctxt,
+            input.as_ptr() as *const c_char,
+            input.len() as c_int,
+            std::ptr::null(),
+            std::ptr::null(),
+            flags,
+        );
+        c_lib.xmlFreeParserCtxt(ctxt);
+        let doc = XmlDoc::from_raw(raw as *mut _).unwrap();
+        let mut out = Vec::new();
+        debug_dump(&doc, &mut out).unwrap();
+        c_lib.xmlFreeDoc(doc.into_raw() as *mut _);
+        (String::from_utf8(out).unwrap(), loaded, errors)
+    }
+}
+
+#[test]
+fn test_external_loads_match_baseline() {
+    let dtd = (String::from("ext.dtd"), xmlResourceType_XML_RESOURCE_DTD);
+    let entity = (
+        String::from("ext.xml"),
+        xmlResourceType_XML_RESOURCE_GENERAL_ENTITY,
+    );
+    for (flags, expected) in [
+        (0, vec![]),
+        (xmlParserOption_XML_PARSE_DTDLOAD, vec![dtd.clone()]),
+        (xmlParserOption_XML_PARSE_NOENT, vec![entity.clone()]),
+        (
+            xmlParserOption_XML_PARSE_DTDLOAD | xmlParserOption_XML_PARSE_NOENT,
+            vec![dtd.clone(), entity.clone()],
+        ),
+        (
+            xmlParserOption_XML_PARSE_DTDLOAD
+                | xmlParserOption_XML_PARSE_NOENT
+                | xmlParserOption_XML_PARSE_NO_XXE,
+            vec![],
+        ),
+    ] {
+        let flags = flags as c_int;
+        let (c_dump, c_loaded, _) = c_parse_external(EXTERNAL, flags, true);
+        assert_eq!(c_loaded, expected, "flags={flags}");
+
+        LOADED.with(|loaded| loaded.borrow_mut().clear());
+        let options = ParserOptions {
+            flags,
+            loader: Some(rust_loader),
+            ..Default::default()
+        };
+        let doc = XmlDoc::parse_memory(EXTERNAL.as_bytes(), options).unwrap();
+        let mut dump = Vec::new();
+        debug_dump(&doc, &mut dump).unwrap();
+        assert_eq!(String::from_utf8(dump).unwrap(), c_dump, "flags={flags}");
+        assert_eq!(
+            LOADED.with(|loaded| loaded.take()),
+            c_loaded,
+            "flags={flags}"
+        );
+    }
+}
+
+#[test]
+fn test_network_blocked_by_default() {
+    let input = "<!DOCTYPE doc [\n\
+<!ENTITY remote SYSTEM 'http://127.0.0.1:9/remote.xml'>\n\
+]>\n\
+<doc>&remote;</doc>\n";
+    let flags = xmlParserOption_XML_PARSE_NOENT as c_int;
+    let (c_dump, _, c_errors) = c_parse_external(
+        input,
+        flags | (xmlParserOption_XML_PARSE_NONET | xmlParserOption_XML_PARSE_RECOVER) as c_int,
+        false,
+    );
+    assert!(c_errors
+        .iter()
+        .any(|err| err.code == xmlParserErrors_XML_IO_NETWORK_ATTEMPT as c_int));
+
+    let (doc, errors) = XmlDoc::parse_recover(input.as_bytes(), flags).unwrap();
+    let mut dump = Vec::new();
+    debug_dump(&doc, &mut dump).unwrap();
+    assert_eq!(String::from_utf8(dump).unwrap(), c_dump);
+    assert_eq!(errors, c_errors);
+
+    // Allowing the network doesn't override an explicit NONET.
+    let options = ParserOptions {
+        flags: flags | xmlParserOption_XML_PARSE_NONET as c_int,
+        allow_network: true,
+        ..Default::default()
+    };
+    let (_, nonet_errors) = XmlDoc::parse_recover(input.as_bytes(), options).unwrap();
+    assert_eq!(nonet_errors, errors);
+}
+
+#[test]
+#[should_panic(expected = "loader failed")]
+fn test_loader_panic() {
+    let options = ParserOptions {
+        flags: xmlParserOption_XML_PARSE_DTDLOAD as c_int,
+        loader: Some(|_, _, _| panic!("loader failed")),
+        ..Default::default()
+    };
+    let _ = XmlDoc::parse_memory(EXTERNAL.as_bytes(), options);
+}
+
+#[cfg(feature = "net")]
+mod net_tests {
+    use super::*;
+    use libxml2::{
+        fetch, install_net_loader, xmlParserErrors_XML_IO_EIO,
+        xmlParserErrors_XML_IO_UNSUPPORTED_PROTOCOL, NetAllowlist,
+    };
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{Arc, Once};
+    use std::thread;
+
+    const DTD: &str = "<!ENTITY greeting 'hello'>\n<!ATTLIST doc from CDATA 'remote'>";
+
+    /// The response to a request for `path` to the server on `port`.
+    fn respond(path: &str, port: u16) -> String {
+        let moved = |location: &str| format!("HTTP/1.0 301 Moved\r\nLocation: {location}\r\n\r\n");
+        match path {
+            "/doc.dtd" | "/dir/doc.dtd" => format!(
+                "HTTP/1.0 200 OK\r\nContent-Length: {}\r\n\r\n{DTD}",
+                DTD.len()
+            ),
+            "/moved.dtd" => moved("/doc.dtd"),
+            "/dir/relative.dtd" => moved("doc.dtd"),
+            "/same-scheme.dtd" => moved(&format!("//127.0.0.1:{port}/dir/doc.dtd")),
+            "/other-host.dtd" => moved(&format!("//localhost:{port}/doc.dtd")),
+            "/huge.dtd" => format!("HTTP/1.0 200 OK\r\n\r\n{}", " ".repeat(64 << 20)),
+            _ => "HTTP/1.0 404 Not Found\r\n\r\n".to_owned(),
+        }
+    }
+
+    /// A local HTTP server, its port and the count of requests it answered.
+    fn serve() -> (u16, Arc<AtomicUsize>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let requests = Arc::new(AtomicUsize::new(0));
+        let count = requests.clone();
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                let path = line.split(' ').nth(1).unwrap_or_default().to_owned();
+                while line != "\r\n" && !line.is_empty() {
+                    line.clear();
+                    reader.read_line(&mut line).unwrap();
+                }
+                count.fetch_add(1, Ordering::SeqCst);
+                // The client hangs up on a response too large.
+                let _ = stream.write_all(respond(&path, port).as_bytes());
+            }
+        });
+        (port, requests)
+    }
+
+    fn allowlist() -> NetAllowlist {
+        NetAllowlist {
+            schemes: vec!["http".to_owned()],
+            hosts: vec!["127.0.0.1".to_owned()],
+        }
+    }
+
+    fn install() {
+        static INSTALL: Once = Once::new();
+        INSTALL.call_once(|| unsafe { install_net_loader(allowlist()) });
+    }
+
+    fn remote_doc(url: &str) -> String {
+        format!("<!DOCTYPE doc SYSTEM '{url}'>\n<doc>&greeting;</doc>")
+    }
+
+    const FLAGS: c_int =
+        (xmlParserOption_XML_PARSE_DTDLOAD | xmlParserOption_XML_PARSE_NOENT) as c_int;
+
+    #[test]
+    fn test_remote_dtd_fetched() {
+        install();
+        let (port, requests) = serve();
+        let input = remote_doc(&format!("http://127.0.0.1:{port}/moved.dtd"));
+        let options = ParserOptions {
+            flags: FLAGS,
+            allow_network: true,
+            ..Default::default()
+        };
+        let doc = XmlDoc::parse_memory(input.as_bytes(), options).unwrap();
+        let root = doc.root().unwrap();
+        assert_eq!(root.content().as_deref(), Some("hello"));
+        assert_eq!(root.attribute("from").as_deref(), Some("remote"));
+        // The redirect and the DTD.
+        assert_eq!(requests.load(Ordering::SeqCst), 2);
+
+        // Local resources still go to libxml2's loader.
+        let (_, errors) =
+            XmlDoc::parse_recover(remote_doc("missing.dtd").as_bytes(), options).unwrap();
+        assert_eq!(errors[0].code, xmlParserErrors_XML_IO_ENOENT as c_int);
+        assert!(errors[0].message.contains("missing.dtd"));
+    }
+
+    #[test]
+    fn test_remote_dtd_blocked() {
+        install();
+        let (port, requests) = serve();
+        let input = remote_doc(&format!("http://127.0.0.1:{port}/doc.dtd"));
+        let network_attempt = |options: ParserOptions| {
+            let (_, errors) = XmlDoc::parse_recover(input.as_bytes(), options).unwrap();
+            errors
+                .iter()
+                .any(|err| err.code == xmlParserErrors_XML_IO_NETWORK_ATTEMPT as c_int)
+        };
+
+        // NONET, added by default or given, blocks the fetch.
+        assert!(network_attempt(FLAGS.into()));
+        assert!(network_attempt(ParserOptions {
+            flags: FLAGS | xmlParserOption_XML_PARSE_NONET as c_int,
+            allow_network: true,
+            ..Default::default()
+        }));
+        assert_eq!(requests.load(Ordering::SeqCst), 0);
+
+        // So does a host not in the allowlist.
+        let input = remote_doc(&format!("http://localhost:{port}/doc.dtd"));
+        let (_, errors) = XmlDoc::parse_recover(
+            input.as_bytes(),
+            ParserOptions {
+                flags: FLAGS,
+                allow_network: true,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        assert!(errors
+            .iter()
+            .any(|err| err.code == xmlParserErrors_XML_IO_NETWORK_ATTEMPT as c_int));
+        assert_eq!(requests.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn test_fetch() {
+        let (port, requests) = serve();
+        let allowlist = allowlist();
+        let url = format!("http://127.0.0.1:{port}/doc.dtd");
+        assert!(allowlist.allows(&url));
+        assert_eq!(fetch(&url, &allowlist).unwrap(), DTD.as_bytes());
+
+        let missing = fetch(&format!("http://127.0.0.1:{port}/none"), &allowlist).unwrap_err();
+        assert_eq!(missing.domain, xmlErrorDomain_XML_FROM_IO as c_int);
+        assert_eq!(missing.code, xmlParserErrors_XML_IO_ENOENT as c_int);
+        assert_eq!(requests.load(Ordering::SeqCst), 2);
+
+        let https = NetAllowlist {
+            schemes: vec!["HTTP".to_owned(), "https".to_owned()],
+            ..allowlist.clone()
+        };
+        let err = fetch(&format!("https://127.0.0.1:{port}/doc.dtd"), &https).unwrap_err();
+        assert_eq!(
+            err.code,
+            xmlParserErrors_XML_IO_UNSUPPORTED_PROTOCOL as c_int
+        );
+        assert!(!allowlist.allows("file:///etc/passwd"));
+        assert!(!allowlist.allows("http://user@127.0.0.1/doc.dtd"));
+        assert!(!NetAllowlist::default().allows(&url));
+        assert_eq!(requests.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_fetch_redirects() {
+        let (port, requests) = serve();
+        let allowlist = allowlist();
+        let base = format!("http://127.0.0.1:{port}");
+        // A relative Location resolves against the URL redirected, a
+        // scheme-relative one keeps its scheme.
+        for path in ["/moved.dtd", "/dir/relative.dtd", "/same-scheme.dtd"] {
+            assert_eq!(
+                fetch(&format!("{base}{path}"), &allowlist).unwrap(),
+                DTD.as_bytes()
+            );
+        }
+        assert_eq!(requests.load(Ordering::SeqCst), 6);
+
+        // The target of a redirect is checked against the allowlist.
+        let err = fetch(&format!("{base}/other-host.dtd"), &allowlist).unwrap_err();
+        assert_eq!(err.code, xmlParserErrors_XML_IO_NETWORK_ATTEMPT as c_int);
+        assert!(err
+            .message
+            .contains(&format!("http://localhost:{port}/doc.dtd")));
+        assert_eq!(requests.load(Ordering::SeqCst), 7);
+    }
+
+    #[test]
+    fn test_fetch_too_large() {
+        let (port, _) = serve();
+        let err = fetch(&format!("http://127.0.0.1:{port}/huge.dtd"), &allowlist()).unwrap_err();
+        assert_eq!(err.domain, xmlErrorDomain_XML_FROM_IO as c_int);
+        assert_eq!(err.code, xmlParserErrors_XML_IO_EIO as c_int);
+    }
+}
+
+#[cfg(feature = "rayon")]
+mod parse_many_tests {
+    use super::*;
+    use libxml2::parse_many;
+
+    /// Well-formed, malformed, warning and too deep documents.
+    fn batch() -> Vec<Vec<u8>> {
+        let mut inputs = vec![
+            b"<root><a/><b x='1'/>text</root>".to_vec(),
+            b"<p:doc>\n  <a>\n</b>\n</p:doc>".to_vec(),
+            b"".to_vec(),
+            b"<r xmlns:p='rel'/>".to_vec(),
+            reader_input(),
+            nested(5),
+            nested(PARSER_MAX_DEPTH + 1),
+        ];
+        for i in 0..50 {
+            inputs.push(format!("<doc n='{i}'><item>{i}</item></doc>").into_bytes());
+        }
+        inputs
+    }
+
+    #[test]
+    fn test_parse_many_matches_sequential() {
+        let inputs = batch();
+        let slices: Vec<&[u8]> = inputs.iter().map(Vec::as_slice).collect();
+        for options in [
+            ParserOptions::from(0),
+            ParserOptions {
+                flags: 0,
+                max_depth: Some(3),
+                ..Default::default()
+            },
+        ] {
+            let results = parse_many(&slices, options);
+            assert_eq!(results.len(), inputs.len());
+            for (input, result) in slices.iter().zip(&results) {
+                match (result, XmlDoc::parse_memory(input, options)) {
+                    (Ok(doc), Ok(expected)) => assert_eq!(dump_doc(doc), dump_doc(&expected)),
+                    (Err(errors), Err(err)) => assert_eq!(errors.last(), Some(&err)),
+                    (result, expected) => panic!("{result:?} but {expected:?}"),
+                }
+            }
+            // The errors of a document are its own.
+            let errors = results[1].as_ref().unwrap_err();
+            assert_eq!(errors.len(), 2);
+            assert_eq!(
+                errors[1].code,
+                xmlParserErrors_XML_ERR_TAG_NAME_MISMATCH as c_int
+            );
+            assert_eq!(
+                results[2].as_ref().unwrap_err()[0].code,
+                xmlParserErrors_XML_ERR_DOCUMENT_EMPTY as c_int
+            );
+        }
+        assert!(parse_many(&[], 0).is_empty());
+    }
+
+    #[test]
+    fn test_parse_many_stress() {
+        let inputs = batch();
+        let slices: Vec<&[u8]> = inputs
+            .iter()
+            .cycle()
+            .take(2000)
+            .map(Vec::as_slice)
+            .collect();
+        let expected: Vec<_> = parse_many(&slices[..inputs.len()], 0)
+            .iter()
+            .map(|result| result.as_ref().map(dump_doc).map_err(|e| e.len()))
+            .collect();
+        for _ in 0..5 {
+            let results = parse_many(&slices, 0);
+            // Documents are freed on another thread than the one that
+            // parsed them.
+            let dumps: Vec<_> = std::thread::scope(|scope| {
+                scope
+                    .spawn(|| {
+                        results
+                            .into_iter()
+                            .map(|result| result.as_ref().map(dump_doc).map_err(|e| e.len()))
+                            .collect()
+                    })
+                    .join()
+                    .unwrap()
+            });
+            for (i, dump) in dumps.iter().enumerate() {
+                assert_eq!(dump, &expected[i % inputs.len()]);
+            }
+        }
+    }
+}
+
+/// A document with a DTD and one node of each kind a parse produces.
+const DEBUG_INPUT: &str = "<?xml version='1.0' encoding='UTF-8' standalone='yes'?>\n\
+<!DOCTYPE root SYSTEM 'root.dtd' [\n\
+<!ELEMENT root (item+, note?)>\n\
+<!ELEMENT item (#PCDATA|b)*>\n\
+<!ELEMENT b EMPTY>\n\
+<!ELEMENT note ANY>\n\
+<!ATTLIST item kind (a|b|c|d|e|f) 'a' id ID #IMPLIED>\n\
+<!ATTLIST root version CDATA #FIXED '1'>\n\
+<!ENTITY who 'the world, \u{e9}t\u{e9} and winter alike, from dusk to dawn'>\n\
+<!ENTITY ext SYSTEM 'ext.xml'>\n\
+]>\n\
+<root xmlns='urn:d' xmlns:p='urn:p' version='1'>\n\
+  <!-- a comment -->\n\
+  <item kind='c' p:x='y' xml:lang='fr'>Hello &who;<b/></item>\n\
+  <item>caf\u{e9}\t<![CDATA[x < y]]></item>\n\
+  <?target some data?>\n\
+  <p:note>An unusually long line of text that runs past forty bytes</p:note>\n\
+</root>\n";
+
+#[test]
+fn test_debug_dump() {
+    let doc = XmlDoc::parse_memory(DEBUG_INPUT.as_bytes(), 0).unwrap();
+    let mut out = Vec::new();
+    debug_dump(&doc, &mut out).unwrap();
+    let out = String::from_utf8(out).unwrap();
+    let lines: Vec<_> = out.lines().collect();
+    assert_eq!(
+        lines[..4],
+        [
+            "DOCUMENT",
+            "version=1.0",
+            "encoding=UTF-8",
+            "standalone=true"
+        ]
+    );
+    for line in [
+        "namespace xml href=http://www.w3.org/XML/1998/namespace",
+        "  DTD(root), SYSTEM root.dtd",
+        "    ELEMDECL(item), MIXED (#PCDATA | b)*",
+        "    ATTRDECL(kind) for item ENUMERATION (a|b|c|d|e...)\"a\"",
+        "     content=the world, #C3#A9t#C3#A9 and winter alike, from ...",
+        "  ELEMENT root",
+        "    default namespace href=urn:d",
+        "    ATTRIBUTE version",
+        "      ATTRIBUTE kind",
+        "        TEXT interned",
+        "          content=c",
+        "      ENTITY_REF(who)",
+        "        INTERNAL_GENERAL_ENTITY who",
+        "        content=caf#C3#A9 ",
+        "    PI target",
+        "    ELEMENT p:note",
+        "        content=An unusually long line of text that runs...",
+    ] {
+        assert!(lines.contains(&line), "{line:?} not in\n{out}");
+    }
+    assert!(!out.contains("ERROR"), "{out}");
+}
+
+#[test]
+fn test_debug_dump_matches_baseline() {
+    let c_lib = libxml2::libxml2_dynload::get_c_baseline();
+    for (input, options) in [
+        (DEBUG_INPUT, 0),
+        (DEBUG_INPUT, xmlParserOption_XML_PARSE_NOENT as c_int),
+        ("<a><b>x</b><!--y--><c z='1'/></a>", 0),
+    ] {
+        unsafe {
+            let raw = c_lib.xmlReadMemory(
+                input.as_ptr() as *const c_char,
+                input.len() as c_int,
+                c"doc.xml".as_ptr(),
+                std::ptr::null(),
+                options,
+            );
+            let mut buf = std::ptr::null_mut();
+            let mut len = 0;
+            let file = libc::open_memstream(&mut buf, &mut len);
+            c_lib.xmlDebugDumpDocument(file as *mut _, raw);
+            libc::fclose(file);
+            let expected = std::slice::from_raw_parts(buf as *const u8, len).to_vec();
+            libc::free(buf as *mut libc::c_void);
+
+            let doc = XmlDoc::from_raw(raw as *mut _).unwrap();
+            let mut actual = Vec::new();
+            debug_dump(&doc, &mut actual).unwrap();
+            c_lib.xmlFreeDoc(doc.into_raw() as *mut _);
+            assert_eq!(
+                String::from_utf8_lossy(&actual),
+                String::from_utf8_lossy(&expected),
+                "{input}"
+            );
+        }
+    }
+}
+
+/// Fresh, empty directory under the system temp dir
+fn scratch_dir(name: &str) -> std::path::PathBuf {
+    let dir = std::env::temp_dir().join(format!("libxml2-{}-{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(dir.join("sub")).unwrap();
+    dir
+}
+
+const XINCLUDE_MAIN: &str = r#"<?xml version="1.0"?>
+<doc xmlns:xi="http://www.w3.org/2001/XInclude">
+  <p>before</p>
+  <xi:include href="sub/inc.xml"/>
+  <list><xi:include href="items.xml" xpointer="xpointer(//item[2])"/></list>
+  <text><xi:include href="sub/note.txt" parse="text"/></text>
+  <xi:include href="missing.xml"><xi:fallback><p>fallback</p></xi:fallback></xi:include>
+</doc>
+"#;
+
+/// Expand the includes of `path` with the baseline `xmlXIncludeProcessFlags`
+/// and return its result and a dump of the tree.
+fn c_xinclude(path: &std::path::Path, flags: c_int) -> (c_int, String) {
+    let c_lib = libxml2::libxml2_dynload::get_c_baseline();
+    let path = std::ffi::CString::new(path.to_str().unwrap()).unwrap();
+    unsafe {
+        let doc = c_lib.xmlReadFile(path.as_ptr(), std::ptr::null(), 0);
+        assert!(!doc.is_null());
+        let ret = c_lib.xmlXIncludeProcessFlags(doc, flags);
+        let mut buf = std::ptr::null_mut();
+        let mut len = 0;
+        let file = libc::open_memstream(&mut buf, &mut len);
+        c_lib.xmlDebugDumpDocument(file as *mut _, doc);
+        libc::fclose(file);
+        let dump = String::from_utf8_lossy(std::slice::from_raw_parts(buf as *const u8, len));
+        let dump = dump.into_owned();
+        libc::free(buf as *mut libc::c_void);
+        c_lib.xmlFreeDoc(doc);
+        (ret, dump)
+    }
+}
+
+fn rust_xinclude(path: &std::path::Path, flags: c_int) -> (Result<usize, Vec<XmlError>>, String) {
+    let path = std::ffi::CString::new(path.to_str().unwrap()).unwrap();
+    let mut doc = unsafe {
+        XmlDoc::from_raw(libxml2::xmlReadFile(path.as_ptr(), std::ptr::null(), 0)).unwrap()
+    };
+    let ret = process_xincludes(&mut doc, flags);
+    let mut dump = Vec::new();
+    debug_dump(&doc, &mut dump).unwrap();
+    (ret, String::from_utf8(dump).unwrap())
+}
+
+#[test]
+fn test_xinclude_matches_baseline() {
+    let dir = scratch_dir("xinclude");
+    std::fs::write(dir.join("main.xml"), XINCLUDE_MAIN).unwrap();
+    std::fs::write(
+        dir.join("sub/inc.xml"),
+        "<section><a href='rel.xml'>included</a><!--c--></section>",
+    )
+    .unwrap();
+    std::fs::write(
+        dir.join("items.xml"),
+        "<items><item>one</item><item>two<b/></item><item>three</item></items>",
+    )
+    .unwrap();
+    std::fs::write(dir.join("sub/note.txt"), "plain <text> & more\n").unwrap();
+
+    let main = dir.join("main.xml");
+    for flags in [
+        0,
+        xmlParserOption_XML_PARSE_NOXINCNODE as c_int,
+        xmlParserOption_XML_PARSE_NOBASEFIX as c_int,
+        xmlParserOption_XML_PARSE_NONET as c_int,
+    ] {
+        let (c_ret, c_dump) = c_xinclude(&main, flags);
+        let (ret, dump) = rust_xinclude(&main, flags);
+        assert_eq!(ret.unwrap(), c_ret as usize, "flags {flags}");
+        assert_eq!(dump, c_dump, "flags {flags}");
+    }
+    assert!(c_xinclude(&main, 0).1.contains("included"));
+    assert!(c_xinclude(&main, 0).1.contains("two"));
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_xinclude_errors() {
+    let dir = scratch_dir("xinclude-errors");
+    for (name, input, code) in [
+        (
+            "missing.xml",
+            r#"<doc xmlns:xi="http://www.w3.org/2001/XInclude"><xi:include href="nope.xml"/></doc>"#,
+            xmlParserErrors_XML_XINCLUDE_NO_FALLBACK,
+        ),
+        (
+            "net.xml",
+            r#"<doc xmlns:xi="http://www.w3.org/2001/XInclude"><xi:include href="http://example.invalid/a.xml"/></doc>"#,
+            xmlParserErrors_XML_XINCLUDE_NO_FALLBACK,
+        ),
+        (
+            // Loaded as a text resource with NONET, unlike in C
+            "net-text.xml",
+            r#"<doc xmlns:xi="http://www.w3.org/2001/XInclude"><xi:include href="http://example.invalid/a.txt" parse="text"/></doc>"#,
+            xmlParserErrors_XML_IO_NETWORK_ATTEMPT,
+        ),
+        (
+            "parse.xml",
+            r#"<doc xmlns:xi="http://www.w3.org/2001/XInclude"><xi:include href="a.xml" parse="html"/></doc>"#,
+            xmlParserErrors_XML_XINCLUDE_PARSE_VALUE,
+        ),
+        (
+            "self.xml",
+            r#"<doc xmlns:xi="http://www.w3.org/2001/XInclude"><xi:include href=""/></doc>"#,
+            xmlParserErrors_XML_XINCLUDE_RECURSION,
+        ),
+    ] {
+        let path = dir.join(name);
+        std::fs::write(&path, input).unwrap();
+        let flags = xmlParserOption_XML_PARSE_NONET as c_int;
+        let (c_ret, c_dump) = c_xinclude(&path, flags);
+        let (ret, dump) = rust_xinclude(&path, flags);
+        assert_eq!(c_ret, -1, "{name}");
+        let errors = ret.unwrap_err();
+        assert_eq!(errors[0].domain, xmlErrorDomain_XML_FROM_XINCLUDE as c_int);
+        assert_eq!(errors[0].code, code as c_int, "{name}: {errors:?}");
+        assert_eq!(dump, c_dump, "{name}");
+    }
+    let _ = std::fs::remove_dir_all(&dir);
+}
@@ -0,0 +1,651 @@
+//! Tests for SAX2 module
+
+use libxml2::*;
+use std::ffi::CStr;
+use std::os::raw::{c_char, c_int, c_void};
+use std::slice;
+
+/// Comments, PIs and CDATA sections in the prolog, the internal subset, the
+/// content and the epilog, on several lines.
+const SAMPLE: &[u8] = b"<?xml version=\"1.0\"?>
+<!-- prolog -->
+<?style href=\"a.css\"?>
+<!DOCTYPE doc [
+<!-- in the subset -->
+<?subset pi?>
+<!ELEMENT doc ANY>
+]>
+<doc><![CDATA[first <child>]]>text<![CDATA[]]><![CDATA[a]]]]><![CDATA[>b]]>
+  <!---->
+  <?empty?>
+  <e><!-- nested --><?pi  spaced   data ?><![CDATA[
+multi
+line]]></e>
+  <![CDATA[after text]]>
+</doc>
+<!-- epilog --><?end?>
+";
+
+/// Declarations of every kind in the internal subset: content models,
+/// attribute types and defaults, prefixed names, internal, external and
+/// parameter entities, notations, and redeclarations.
+const DECLS: &[u8] = b"<?xml version=\"1.0\"?>
+<!DOCTYPE doc PUBLIC \"-//Test//DTD Doc//EN\" \"doc.dtd\" [
+<!ELEMENT doc (head, (p | list | p:note)*, foot?)>
+<!ELEMENT head (#PCDATA)>
+<!ELEMENT p (#PCDATA | em | strong)*>
+<!ELEMENT list (item+, (sep, item)*)>
+<!ELEMENT p:note EMPTY>
+<!ELEMENT foot ANY>
+<!ATTLIST doc
+  id ID #REQUIRED
+  ref IDREF #IMPLIED
+  refs IDREFS #IMPLIED
+  lang CDATA \"en\"
+  ver CDATA #FIXED \"1.0\"
+  kind (a | b | c) \"b\"
+  fmt NOTATION (gif | png) #IMPLIED
+  xml:id CDATA #IMPLIED
+  p:a NMTOKEN #IMPLIED
+  xmlns:p CDATA #FIXED \"urn:p\">
+<!ATTLIST doc lang CDATA \"fr\" tokens NMTOKENS \"x y\">
+<!ATTLIST p src ENTITY #IMPLIED srcs ENTITIES #IMPLIED>
+<!ENTITY e \"internal &amp; text\">
+<!ENTITY e \"redeclared\">
+<!ENTITY ext SYSTEM \"chapter.xml\">
+<!ENTITY pub PUBLIC \"-//Test//Ent//EN\" \"sub/pub.xml\">
+<!ENTITY abs SYSTEM \"http://example.com/abs.xml\">
+<!ENTITY pic SYSTEM \"pic.gif\" NDATA gif>
+<!ENTITY % pe \"<!ELEMENT em (#PCDATA)>\">
+%pe;
+<!ENTITY % ext-pe SYSTEM \"missing.ent\">
+<!ENTITY lt \"&#38;#60;\">
+<!ENTITY amp \"and\">
+<!NOTATION gif SYSTEM \"image/gif\">
+<!NOTATION png PUBLIC \"-//PNG//\" \"image/png\">
+<!NOTATION jpeg PUBLIC \"-//JPEG//\">
+<!ELEMENT strong (#PCDATA)>
+]>
+<doc id=\"d\"><head>h</head><p>&e;</p><foot/></doc>
+";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Event {
+    Comment(Vec<u8>),
+    Pi(Vec<u8>, Option<Vec<u8>>),
+    Cdata(Vec<u8>),
+    InternalSubset(Option<Vec<u8>>, Option<Vec<u8>>, Option<Vec<u8>>),
+    /// Name, type and content model.
+    ElementDecl(Vec<u8>, c_int, String),
+    /// Element, attribute, type, default kind, default value and values.
+    AttributeDecl(
+        Vec<u8>,
+        Vec<u8>,
+        c_int,
+        c_int,
+        Option<Vec<u8>>,
+        Vec<Vec<u8>>,
+    ),
+    /// Name, type, public and system IDs, and content.
+    EntityDecl(
+        Vec<u8>,
+        c_int,
+        Option<Vec<u8>>,
+        Option<Vec<u8>>,
+        Option<Vec<u8>>,
+    ),
+    NotationDecl(Vec<u8>, Option<Vec<u8>>, Option<Vec<u8>>),
+    /// Code, level and message of an error or warning.
+    Error(c_int, xmlErrorLevel, String),
+}
+
+type PiFn = unsafe extern "C" fn(*mut c_void, *const xmlChar, *const xmlChar);
+type CommentFn = unsafe extern "C" fn(*mut c_void, *const xmlChar);
+type CdataFn = unsafe extern "C" fn(*mut c_void, *const xmlChar, c_int);
+type SubsetFn = unsafe extern "C" fn(*mut c_void, *const xmlChar, *const xmlChar, *const xmlChar);
+type ElementDeclFn = unsafe extern "C" fn(*mut c_void, *const xmlChar, c_int, xmlElementContentPtr);
+type AttributeDeclFn = unsafe extern "C" fn(
+    *mut c_void,
+    *const xmlChar,
+    *const xmlChar,
+    c_int,
+    c_int,
+    *const xmlChar,
+    xmlEnumerationPtr,
+);
+type EntityDeclFn = unsafe extern "C" fn(
+    *mut c_void,
+    *const xmlChar,
+    c_int,
+    *const xmlChar,
+    *const xmlChar,
+    *mut xmlChar,
+);
+/// `ElementDeclFn` and `AttributeDeclFn` as the C baseline declares them.
+type CElementDeclFn = unsafe extern "C" fn(
+    *mut c_void,
+    *const xmlChar,
+    c_int,
+    *mut dynamic_bindings::xmlElementContent,
+);
+type CAttributeDeclFn = unsafe extern "C" fn(
+    *mut c_void,
+    *const xmlChar,
+    *const xmlChar,
+    c_int,
+    c_int,
+    *const xmlChar,
+    *mut dynamic_bindings::xmlEnumeration,
+);
+type NotationDeclFn =
+    unsafe extern "C" fn(*mut c_void, *const xmlChar, *const xmlChar, *const xmlChar);
+
+/// The SAX2 handlers a recording handler hands its events on to.
+#[derive(Clone, Copy)]
+struct Sax2 {
+    pi: PiFn,
+    comment: CommentFn,
+    cdata: CdataFn,
+    internal_subset: SubsetFn,
+    element_decl: ElementDeclFn,
+    attribute_decl: AttributeDeclFn,
+    entity_decl: EntityDeclFn,
+    notation_decl: NotationDeclFn,
+}
+
+/// The state behind `_private` of a recording parser context.
+struct Recorder {
+    sax2: Sax2,
+    events: Vec<Event>,
+}
+
+unsafe fn bytes(s: *const xmlChar) -> Option<Vec<u8>> {
+    if s.is_null() {
+        return None;
+    }
+    Some(CStr::from_ptr(s as *const c_char).to_bytes().to_vec())
+}
+
+unsafe fn string(s: *const xmlChar) -> Option<String> {
+    bytes(s).map(|b| String::from_utf8_lossy(&b).into_owned())
+}
+
+unsafe fn recorder<'a>(ctx: *mut c_void) -> &'a mut Recorder {
+    &mut *((*(ctx as xmlParserCtxtPtr))._private as *mut Recorder)
+}
+
+unsafe extern "C" fn record_pi(ctx: *mut c_void, target: *const xmlChar, data: *const xmlChar) {
+    let rec = recorder(ctx);
+    rec.events
+        .push(Event::Pi(bytes(target).unwrap_or_default(), bytes(data)));
+    (rec.sax2.pi)(ctx, target, data);
+}
+
+unsafe extern "C" fn record_comment(ctx: *mut c_void, value: *const xmlChar) {
+    let rec = recorder(ctx);
+    rec.events
+        .push(Event::Comment(bytes(value).unwrap_or_default()));
+    (rec.sax2.comment)(ctx, value);
+}
+
+unsafe extern "C" fn record_cdata(ctx: *mut c_void, value: *const xmlChar, len: c_int) {
+    let rec = recorder(ctx);
+    let block = slice::from_raw_parts(value, len as usize).to_vec();
+    rec.events.push(Event::Cdata(block));
+    (rec.sax2.cdata)(ctx, value, len);
+}
+
+unsafe extern "C" fn record_internal_subset(
+    ctx: *mut c_void,
+    name: *const xmlChar,
+    external_id: *const xmlChar,
+    system_id: *const xmlChar,
+) {
+    let rec = recorder(ctx);
+    rec.events.push(Event::InternalSubset(
+        bytes(name),
+        bytes(external_id),
+        bytes(system_id),
+    ));
+    (rec.sax2.internal_subset)(ctx, name, external_id, system_id);
+}
+
+/// A content model in DTD syntax, with the prefix of each name.
+unsafe fn content_model(content: xmlElementContentPtr) -> String {
+    if content.is_null() {
+        return String::new();
+    }
+    let c = &*content;
+    let model = if c.type_ == xmlElementContentType_XML_ELEMENT_CONTENT_PCDATA {
+        "#PCDATA".to_string()
+    } else if c.type_ == xmlElementContentType_XML_ELEMENT_CONTENT_ELEMENT {
+        let prefix = string(c.prefix).unwrap_or_default();
+        format!("{prefix}|{}", string(c.name).unwrap_or_default())
+    } else {
+        let sep = if c.type_ == xmlElementContentType_XML_ELEMENT_CONTENT_SEQ {
+            ","
+        } else {
+            "|"
+        };
+        format!("({} {sep} {})", content_model(c.c1), content_model(c.c2))
+    };
+    format!("{model}{}", ["", "", "?", "*", "+"][c.ocur as usize])
+}
+
+unsafe extern "C" fn record_element_decl(
+    ctx: *mut c_void,
+    name: *const xmlChar,
+    type_: c_int,
+    content: xmlElementContentPtr,
+) {
+    let rec = recorder(ctx);
+    rec.events.push(Event::ElementDecl(
+        bytes(name).unwrap_or_default(),
+        type_,
+        content_model(content),
+    ));
+    (rec.sax2.element_decl)(ctx, name, type_, content);
+}
+
+unsafe extern "C" fn record_attribute_decl(
+    ctx: *mut c_void,
+    elem: *const xmlChar,
+    fullname: *const xmlChar,
+    type_: c_int,
+    def: c_int,
+    default_value: *const xmlChar,
+    tree: xmlEnumerationPtr,
+) {
+    let rec = recorder(ctx);
+    let mut values = Vec::new();
+    let mut cur = tree;
+    while !cur.is_null() {
+        values.push(bytes((*cur).name).unwrap_or_default());
+        cur = (*cur).next;
+    }
+    rec.events.push(Event::AttributeDecl(
+        bytes(elem).unwrap_or_default(),
+        bytes(fullname).unwrap_or_default(),
+        type_,
+        def,
+        bytes(default_value),
+        values,
+    ));
+    (rec.sax2.attribute_decl)(ctx, elem, fullname, type_, def, default_value, tree);
+}
+
+unsafe extern "C" fn record_entity_decl(
+    ctx: *mut c_void,
+    name: *const xmlChar,
+    type_: c_int,
+    public_id: *const xmlChar,
+    system_id: *const xmlChar,
+    content: *mut xmlChar,
+) {
+    let rec = recorder(ctx);
+    rec.events.push(Event::EntityDecl(
+        bytes(name).unwrap_or_default(),
+        type_,
+        bytes(public_id),
+        bytes(system_id),
+        bytes(content),
+    ));
+    (rec.sax2.entity_decl)(ctx, name, type_, public_id, system_id, content);
+}
+
+unsafe extern "C" fn record_notation_decl(
+    ctx: *mut c_void,
+    name: *const xmlChar,
+    public_id: *const xmlChar,
+    system_id: *const xmlChar,
+) {
+    let rec = recorder(ctx);
+    rec.events.push(Event::NotationDecl(
+        bytes(name).unwrap_or_default(),
+        bytes(public_id),
+        bytes(system_id),
+    ));
+    (rec.sax2.notation_decl)(ctx, name, public_id, system_id);
+}
+
+unsafe extern "C" fn record_error(ctx: *mut c_void, error: *const xmlError) {
+    let rec = &mut *(ctx as *mut Recorder);
+    let message = CStr::from_ptr((*error).message).to_string_lossy();
+    rec.events.push(Event::Error(
+        (*error).code,
+        (*error).level,
+        message.into_owned(),
+    ));
+}
+
+/// `record_error` for the C baseline, whose errors are the same struct.
+unsafe extern "C" fn record_c_error(ctx: *mut c_void, error: *const dynamic_bindings::xmlError) {
+    record_error(ctx, error as *const xmlError);
+}
+
+/// Point the handlers of `ctxt` at the recording ones.
+unsafe fn install(ctxt: xmlParserCtxtPtr, rec: &mut Recorder) {
+    (*ctxt)._private = rec as *mut Recorder as *mut c_void;
+    let sax = &mut *(*ctxt).sax;
+    sax.processingInstruction = Some(record_pi);
+    sax.comment = Some(record_comment);
+    sax.cdataBlock = Some(record_cdata);
+    sax.internalSubset = Some(record_internal_subset);
+    sax.elementDecl = Some(record_element_decl);
+    sax.attributeDecl = Some(record_attribute_decl);
+    sax.entityDecl = Some(record_entity_decl);
+    sax.notationDecl = Some(record_notation_decl);
+}
+
+/// Type, name, content and line of every node below `node`, and of the
+/// nodes in DTDs. Declarations, which are other structs, show their type
+/// and name, and attribute and entity declarations what SAX2 filled in.
+unsafe fn dump(node: xmlNodePtr, depth: usize, out: &mut Vec<String>) {
+    let mut cur = node;
+    while !cur.is_null() {
+        let name = string((*cur).name);
+        let indent = "  ".repeat(depth);
+        let type_ = (*cur).type_;
+        if type_ == xmlElementType_XML_ATTRIBUTE_DECL {
+            let attr = &*(cur as xmlAttributePtr);
+            out.push(format!(
+                "{indent}{type_} {name:?} elem {:?} prefix {:?} default {:?}",
+                string(attr.elem),
+                string(attr.prefix),
+                string(attr.defaultValue),
+            ));
+        } else if type_ == xmlElementType_XML_ENTITY_DECL {
+            let ent = &*(cur as xmlEntityPtr);
+            out.push(format!(
+                "{indent}{type_} {name:?} etype {} URI {:?} content {:?}",
+                ent.etype,
+                string(ent.URI),
+                string(ent.content),
+            ));
+        } else if (xmlElementType_XML_DTD_NODE..=xmlElementType_XML_ENTITY_DECL).contains(&type_) {
+            out.push(format!("{indent}{type_} {name:?}"));
+        } else {
+            let content = if type_ == xmlElementType_XML_ELEMENT_NODE {
+                None
+            } else {
+                string((*cur).content)
+            };
+            out.push(format!(
+                "{indent}{type_} {name:?} {content:?} line {}",
+                (*cur).line
+            ));
+        }
+        dump((*cur).children, depth + 1, out);
+        cur = (*cur).next;
+    }
+}
+
+/// The events recorded and tree built parsing `input`, chaining to `sax2`.
+/// `new_ctxt` makes a context that reports errors to `record_error` with
+/// the data it is given, and `read` parses with it.
+unsafe fn record(
+    sax2: Sax2,
+    new_ctxt: impl FnOnce(*mut c_void) -> xmlParserCtxtPtr,
+    read: impl FnOnce(xmlParserCtxtPtr) -> xmlDocPtr,
+    free: impl FnOnce(xmlParserCtxtPtr, xmlDocPtr),
+) -> (Vec<Event>, Vec<String>) {
+    let mut rec = Recorder {
+        sax2,
+        events: Vec::new(),
+    };
+    let ctxt = new_ctxt(&mut rec as *mut Recorder as *mut c_void);
+    assert!(!ctxt.is_null());
+    install(ctxt, &mut rec);
+    let doc = read(ctxt);
+    assert!(!doc.is_null());
+    let mut tree = Vec::new();
+    dump((*doc).children, 0, &mut tree);
+    free(ctxt, doc);
+    (rec.events, tree)
+}
+
+/// Parse `input` as XML, or HTML, with `options`, using the C baseline and
+/// its SAX2 handlers.
+fn c_record(input: &[u8], html: bool, options: c_int) -> (Vec<Event>, Vec<String>) {
+    let c_lib = libxml2_dynload::get_c_baseline();
+    unsafe {
+        let sax2 = Sax2 {
+            pi: *c_lib.xmlSAX2ProcessingInstruction.as_ref().unwrap(),
+            comment: *c_lib.xmlSAX2Comment.as_ref().unwrap(),
+            cdata: *c_lib.xmlSAX2CDataBlock.as_ref().unwrap(),
+            internal_subset: *c_lib.xmlSAX2InternalSubset.as_ref().unwrap(),
+            // The content and enumeration structs are the same on both
+            // sides, only declared twice
+            element_decl: std::mem::transmute::<CElementDeclFn, ElementDeclFn>(
+                *c_lib.xmlSAX2ElementDecl.as_ref().unwrap(),
+            ),
+            attribute_decl: std::mem::transmute::<CAttributeDeclFn, AttributeDeclFn>(
+                *c_lib.xmlSAX2AttributeDecl.as_ref().unwrap(),
+            ),
+            entity_decl: *c_lib.xmlSAX2EntityDecl.as_ref().unwrap(),
+            notation_decl: *c_lib.xmlSAX2NotationDecl.as_ref().unwrap(),
+        };
+        record(
+            sax2,
+            |data| {
+                let ctxt = if html {
+                    c_lib.htmlNewParserCtxt()
+                } else {
+                    c_lib.xmlNewParserCtxt()
+                };
+                c_lib.xmlCtxtSetErrorHandler(ctxt, Some(record_c_error), data);
+                ctxt as xmlParserCtxtPtr
+            },
+            |ctxt| {
+                let ctxt = ctxt as *mut dynamic_bindings::xmlParserCtxt;
+                let (buf, len) = (input.as_ptr() as *const c_char, input.len() as c_int);
+                let doc = if html {
+                    c_lib.htmlCtxtReadMemory(
+                        ctxt,
+                        buf,
+                        len,
+                        std::ptr::null(),
+                        std::ptr::null(),
+                        options,
+                    )
+                } else {
+                    c_lib.xmlCtxtReadMemory(
+                        ctxt,
+                        buf,
+                        len,
+                        std::ptr::null(),
+                        std::ptr::null(),
+                        options,
+                    )
+                };
+                doc as xmlDocPtr
+            },
+            |ctxt, doc| {
+                c_lib.xmlFreeDoc(doc as *mut dynamic_bindings::xmlDoc);
+                c_lib.xmlFreeParserCtxt(ctxt as *mut dynamic_bindings::xmlParserCtxt);
+            },
+        )
+    }
+}
+
+/// The HTML parser hands the raw text of scripts and styles over as CDATA
+/// blocks.
+fn html_sample() -> Vec<u8> {
+    let mut input = b"<html><head><script>".to_vec();
+    for i in 0..2000 {
+        input.extend_from_slice(format!("var v{i} = '<b>{i}</b>';\n").as_bytes());
+    }
+    input.extend_from_slice(b"</script><style>p { color: red }</style></head>");
+    input.extend_from_slice(b"<body><!-- c --><p>x</p><script></script></body></html>");
+    input
+}
+
+#[test]
+fn test_sax2_events_baseline() {
+    let (events, tree) = c_record(SAMPLE, false, 0);
+    assert_eq!(events[0], Event::Comment(b" prolog ".to_vec()));
+    assert!(events.contains(&Event::Pi(b"empty".to_vec(), None)));
+    assert!(events.contains(&Event::Pi(b"pi".to_vec(), Some(b"spaced   data ".to_vec()))));
+    assert!(events.contains(&Event::Cdata(b"a]]".to_vec())));
+    assert!(events.contains(&Event::ElementDecl(
+        b"doc".to_vec(),
+        xmlElementTypeVal_XML_ELEMENT_TYPE_ANY as c_int,
+        String::new()
+    )));
+    assert_eq!(events.len(), 18);
+    assert!(tree.iter().any(|line| line.contains("\"first <child>\"")));
+
+    let (events, _) = c_record(&html_sample(), true, 0);
+    assert_eq!(events.len(), 3);
+    assert_eq!(events[2], Event::Comment(b" c ".to_vec()));
+}
+
+#[test]
+fn test_sax2_decls_baseline() {
+    let (events, tree) = c_record(DECLS, false, 0);
+    assert_eq!(
+        events[0],
+        Event::InternalSubset(
+            Some(b"doc".to_vec()),
+            Some(b"-//Test//DTD Doc//EN".to_vec()),
+            Some(b"doc.dtd".to_vec())
+        )
+    );
+    assert!(events.contains(&Event::ElementDecl(
+        b"doc".to_vec(),
+        xmlElementTypeVal_XML_ELEMENT_TYPE_ELEMENT as c_int,
+        "(|head , ((|p | (|list | p|note))* , |foot?))".to_string()
+    )));
+    assert!(events.contains(&Event::AttributeDecl(
+        b"doc".to_vec(),
+        b"fmt".to_vec(),
+        xmlAttributeType_XML_ATTRIBUTE_NOTATION as c_int,
+        xmlAttributeDefault_XML_ATTRIBUTE_IMPLIED as c_int,
+        None,
+        vec![b"gif".to_vec(), b"png".to_vec()]
+    )));
+    let codes: Vec<_> = events
+        .iter()
+        .filter_map(|event| match event {
+            Event::Error(code, _, _) => Some(*code as xmlParserErrors),
+            _ => None,
+        })
+        .collect();
+    assert!(codes.contains(&xmlParserErrors_XML_DTD_XMLID_TYPE));
+    assert!(codes.contains(&xmlParserErrors_XML_ERR_REDECL_PREDEF_ENTITY));
+    assert!(tree.iter().any(|line| line.contains("\"chapter.xml\"")));
+}
+
+#[cfg(feature = "rust-sax2")]
+mod rust_tests {
+    use super::*;
+    use libxml2::sax2::ffi::{
+        xmlSAX2AttributeDecl, xmlSAX2CDataBlock, xmlSAX2Comment, xmlSAX2ElementDecl,
+        xmlSAX2EntityDecl, xmlSAX2InternalSubset, xmlSAX2NotationDecl,
+        xmlSAX2ProcessingInstruction,
+    };
+
+    const RUST_SAX2: Sax2 = Sax2 {
+        pi: xmlSAX2ProcessingInstruction,
+        comment: xmlSAX2Comment,
+        cdata: xmlSAX2CDataBlock,
+        internal_subset: xmlSAX2InternalSubset,
+        element_decl: xmlSAX2ElementDecl,
+        attribute_decl: xmlSAX2AttributeDecl,
+        entity_decl: xmlSAX2EntityDecl,
+        notation_decl: xmlSAX2NotationDecl,
+    };
+
+    /// Parse `input` as XML, or HTML, with `options`, handing the events to
+    /// the Rust SAX2 handlers.
+    fn rust_record(input: &[u8], html: bool, options: c_int) -> (Vec<Event>, Vec<String>) {
+        unsafe {
+            record(
+                RUST_SAX2,
+                |data| {
+                    let ctxt = if html {
+                        htmlNewParserCtxt()
+                    } else {
+                        xmlNewParserCtxt()
+                    };
+                    xmlCtxtSetErrorHandler(ctxt, Some(record_error), data);
+                    ctxt
+                },
+                |ctxt| {
+                    let (buf, len) = (input.as_ptr() as *const c_char, input.len() as c_int);
+                    if html {
+                        htmlCtxtReadMemory(
+                            ctxt,
+                            buf,
+                            len,
+                            std::ptr::null(),
+                            std::ptr::null(),
+                            options,
+                        )
+                    } else {
+                        xmlCtxtReadMemory(
+                            ctxt,
+                            buf,
+                            len,
+                            std::ptr::null(),
+                            std::ptr::null(),
+                            options,
+                        )
+                    }
+                },
+                |ctxt, doc| {
+                    xmlFreeDoc(doc);
+                    xmlFreeParserCtxt(ctxt);
+                },
+            )
+        }
+    }
+
+    #[test]
+    fn test_sax2_match_baseline() {
+        let (events, tree) = rust_record(SAMPLE, false, 0);
+        let (c_events, c_tree) = c_record(SAMPLE, false, 0);
+        assert_eq!(events, c_events);
+        assert_eq!(tree, c_tree);
+    }
+
+    #[test]
+    fn test_sax2_decls_match_baseline() {
+        let pedantic = xmlParserOption_XML_PARSE_PEDANTIC as c_int;
+        for options in [0, pedantic] {
+            let (events, tree) = rust_record(DECLS, false, options);
+            let (c_events, c_tree) = c_record(DECLS, false, options);
+            assert_eq!(events, c_events);
+            assert_eq!(tree, c_tree);
+        }
+    }
+
+    #[test]
+    fn test_sax2_html_raw_text() {
+        let input = html_sample();
+        let (events, tree) = rust_record(&input, true, 0);
+        let (c_events, c_tree) = c_record(&input, true, 0);
+        assert_eq!(events, c_events);
+        assert_eq!(tree, c_tree);
+        // The blocks of the script are merged into one node.
+        assert_eq!(
+            tree.iter()
+                .filter(|line| line.starts_with("      4 "))
+                .count(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_sax2_null_context() {
+        unsafe {
+            xmlSAX2Comment(std::ptr::null_mut(), c"x".as_ptr() as *const xmlChar);
+            xmlSAX2ProcessingInstruction(
+                std::ptr::null_mut(),
+                c"x".as_ptr() as *const xmlChar,
+                std::ptr::null(),
+            );
+            xmlSAX2CDataBlock(std::ptr::null_mut(), std::ptr::null(), 0);
+        }
+    }
+}
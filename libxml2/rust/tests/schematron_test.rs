@@ -0,0 +1,271 @@
+//! Tests for schematron module
+
+use libxml2::*;
+use std::ffi::CStr;
+use std::os::raw::{c_char, c_int, c_void};
+
+const SCT: &str = "http://purl.oclc.org/dsdl/schematron";
+
+/// The code, node, line, message, pattern, path and report of a
+/// structured error.
+type CError = (c_int, *mut c_void, c_int, String, String, String, String);
+
+/// A schema with asserts and reports on several contexts, messages
+/// using `name` and `value-of`, a `let` and a namespaced rule.
+fn schema() -> String {
+    format!(
+        "<schema xmlns='{SCT}'>\
+         <title>Orders</title>\
+         <ns prefix='x' uri='urn:x'/>\
+         <pattern id='totals'>\
+         <rule context='order'>\
+         <let name='count' value='count(item)'/>\
+         <assert test='$count &gt; 0'>Order <value-of select='@id'/> has no item</assert>\
+         <assert test='@id'>The <name/> element needs an id</assert>\
+         <report test='$count &gt; 2'>Large order: <value-of select='$count'/> items,\n   \
+         total <value-of select='sum(item/@price)'/></report>\
+         </rule>\
+         <rule context='item'>\
+         <assert test='@price &gt;= 0'><name path='..'/> holds a negative price</assert>\
+         <report test='@gift'/>\
+         </rule>\
+         </pattern>\
+         <pattern name='notes'>\
+         <rule context='x:note'>\
+         <report test='string-length(.) &gt; 5'>Long note under \
+         <value-of select='name(..)'/>: <value-of select='.'/></report>\
+         <assert test='false()'><value-of select='../item'/></assert>\
+         </rule>\
+         </pattern>\
+         <pattern>\
+         <rule context='order'><assert test='false()'>unnamed</assert></rule>\
+         </pattern>\
+         </schema>"
+    )
+}
+
+/// Documents triggering none, some or all of the rules of `schema()`.
+const INSTANCES: &[&str] = &[
+    "<orders><order id='1'><item price='3'/></order></orders>",
+    "<orders>\n  <order id='1'/>\n  <order><item price='1'/></order>\n</orders>",
+    "<orders><order id='2'><item price='1.5'/><item price='-2'/>\
+     <item price='1e7' gift='yes'/></order></orders>",
+    "<orders xmlns:x='urn:x'><order id='3'><item price='1'/>\
+     <x:note>Deliver  \n </x:note><x:note>ok</x:note></order></orders>",
+    "<order><item price='0.0001'/><item price='0.00001'/><item price='x'/></order>",
+];
+
+/// Schemas the parser rejects.
+fn bad_schemas() -> Vec<String> {
+    vec![
+        "<schema/>".to_string(),
+        format!("<schema xmlns='{SCT}'/>"),
+        format!("<schema xmlns='{SCT}'><ns prefix='a'/><pattern id='p'><rule context='a'><assert test='1'/></rule></pattern></schema>"),
+        format!("<schema xmlns='{SCT}'><pattern id='p'/><title/></schema>"),
+        format!("<schema xmlns='{SCT}'><pattern id='p'><rule/><rule context=''/></pattern></schema>"),
+        format!("<schema xmlns='{SCT}'><pattern id='p'><rule context='a['><assert test='1'/></rule></pattern></schema>"),
+        format!("<schema xmlns='{SCT}'><pattern id='p'><rule context='a'><let name='v'/><assert test='1'/></rule></pattern></schema>"),
+        format!("<schema xmlns='{SCT}'><pattern id='p'><rule context='a'><foo/></rule></pattern></schema>"),
+        format!("<schema xmlns='{SCT}'><pattern id='p'><rule context='a'><assert test='1'><value-of/></assert>\
+                 <report test='1'><value-of select='('/></report><assert test=')'/><report/></rule></pattern></schema>"),
+    ]
+}
+
+fn text(p: *const c_char) -> String {
+    if p.is_null() {
+        String::new()
+    } else {
+        unsafe { CStr::from_ptr(p) }.to_string_lossy().into_owned()
+    }
+}
+
+/// Collect the structured errors in `domain`, passed as the first
+/// element of the `(c_int, Vec<_>)` in `data`.
+unsafe extern "C" fn collect_error(data: *mut c_void, error: *const dynamic_bindings::xmlError) {
+    let (domain, errors) = &mut *(data as *mut (c_int, Vec<CError>));
+    let e = &*error;
+    if e.domain == *domain {
+        errors.push((
+            e.code,
+            e.node,
+            e.line,
+            text(e.message),
+            text(e.str1),
+            text(e.str2),
+            text(e.str3),
+        ));
+    }
+}
+
+/// Parse `input` with the C baseline.
+unsafe fn c_parse(input: &str) -> dynamic_bindings::xmlDocPtr {
+    let c_lib = libxml2_dynload::get_c_baseline();
+    let doc = c_lib.xmlReadMemory(
+        input.as_ptr() as *const c_char,
+        input.len() as c_int,
+        std::ptr::null(),
+        std::ptr::null(),
+        0,
+    );
+    assert!(!doc.is_null(), "{input}");
+    doc
+}
+
+/// Compile the schema in `doc` with the C baseline, returning the
+/// schema or the code, node and message of each error. The parser has
+/// no structured error setter, so errors are caught by the global
+/// handler.
+unsafe fn c_schema(
+    doc: dynamic_bindings::xmlDocPtr,
+) -> Result<dynamic_bindings::xmlSchematronPtr, Vec<(c_int, *mut c_void, String)>> {
+    let c_lib = libxml2_dynload::get_c_baseline();
+    let ctxt = c_lib.xmlSchematronNewDocParserCtxt(doc);
+    assert!(!ctxt.is_null());
+    let mut errors: (c_int, Vec<CError>) = (xmlErrorDomain_XML_FROM_SCHEMASP as c_int, Vec::new());
+    c_lib.xmlSetStructuredErrorFunc(&mut errors as *mut _ as *mut c_void, Some(collect_error));
+    let schema = c_lib.xmlSchematronParse(ctxt);
+    c_lib.xmlSetStructuredErrorFunc(std::ptr::null_mut(), None);
+    c_lib.xmlSchematronFreeParserCtxt(ctxt);
+    if schema.is_null() {
+        Err(errors.1.into_iter().map(|e| (e.0, e.1, e.3)).collect())
+    } else {
+        assert!(errors.1.is_empty(), "{:?}", errors.1);
+        Ok(schema)
+    }
+}
+
+/// Validate `doc` with the C baseline, reporting through structured
+/// errors: the error count and the errors.
+unsafe fn c_validate(
+    schema: dynamic_bindings::xmlSchematronPtr,
+    doc: dynamic_bindings::xmlDocPtr,
+) -> (c_int, Vec<CError>) {
+    let c_lib = libxml2_dynload::get_c_baseline();
+    let ctxt = c_lib.xmlSchematronNewValidCtxt(
+        schema,
+        xmlSchematronValidOptions_XML_SCHEMATRON_OUT_ERROR as c_int,
+    );
+    let mut errors: (c_int, Vec<CError>) =
+        (xmlErrorDomain_XML_FROM_SCHEMATRONV as c_int, Vec::new());
+    c_lib.xmlSchematronSetValidStructuredErrors(
+        ctxt,
+        Some(collect_error),
+        &mut errors as *mut _ as *mut c_void,
+    );
+    let ret = c_lib.xmlSchematronValidateDoc(ctxt, doc);
+    c_lib.xmlSchematronFreeValidCtxt(ctxt);
+    (ret, errors.1)
+}
+
+#[test]
+fn test_schematron_baseline() {
+    let c_lib = libxml2_dynload::get_c_baseline();
+    unsafe {
+        let sdoc = c_parse(&schema());
+        let schema = c_schema(sdoc).unwrap();
+        let doc = c_parse(INSTANCES[1]);
+        let (count, errors) = c_validate(schema, doc);
+        assert_eq!(count, 2);
+        let errors: Vec<_> = errors.into_iter().map(|e| (e.0, e.3, e.4)).collect();
+        // value-of gives the names of the nodes it selects, not their values.
+        assert_eq!(
+            errors,
+            vec![
+                (
+                    xmlParserErrors_XML_SCHEMATRONV_ASSERT as c_int,
+                    "/orders/order[1] line 2: Order id has no item\n".to_string(),
+                    "totals".to_string()
+                ),
+                (
+                    xmlParserErrors_XML_SCHEMATRONV_ASSERT as c_int,
+                    "/orders/order[2] line 3: The order element needs an id\n".to_string(),
+                    "totals".to_string()
+                ),
+            ]
+        );
+        c_lib.xmlFreeDoc(doc);
+        c_lib.xmlSchematronFree(schema);
+        c_lib.xmlFreeDoc(sdoc);
+
+        let sdoc = c_parse(&format!("<schema xmlns='{SCT}'/>"));
+        let errors = c_schema(sdoc).err().unwrap();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].0, xmlParserErrors_XML_SCHEMAP_NOROOT as c_int);
+        c_lib.xmlFreeDoc(sdoc);
+    }
+}
+
+#[cfg(feature = "rust-schematron")]
+mod rust_tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_matches_baseline() {
+        let c_lib = libxml2_dynload::get_c_baseline();
+        unsafe {
+            let sdoc = c_parse(&schema());
+            let c_schema = c_schema(sdoc).unwrap();
+            let schema = Schematron::parse(sdoc as xmlDocPtr).unwrap_or_else(|e| panic!("{e:?}"));
+            for input in INSTANCES {
+                let doc = c_parse(input);
+                let (count, expected) = c_validate(c_schema, doc);
+                let actual: Vec<CError> = match schema.validate(doc as xmlDocPtr) {
+                    Ok(()) => Vec::new(),
+                    Err(failures) => failures
+                        .into_iter()
+                        .map(|f| {
+                            (
+                                f.code,
+                                f.node as *mut c_void,
+                                f.line as c_int,
+                                f.message,
+                                f.pattern,
+                                f.path,
+                                f.report,
+                            )
+                        })
+                        .collect(),
+                };
+                c_lib.xmlFreeDoc(doc);
+                assert_eq!(actual, expected, "{input}");
+                assert_eq!(actual.len(), count as usize, "{input}");
+            }
+            c_lib.xmlSchematronFree(c_schema);
+            drop(schema);
+            c_lib.xmlFreeDoc(sdoc);
+        }
+    }
+
+    #[test]
+    fn test_bad_schemas_match_baseline() {
+        let c_lib = libxml2_dynload::get_c_baseline();
+        for input in bad_schemas() {
+            unsafe {
+                let sdoc = c_parse(&input);
+                let expected = c_schema(sdoc).err().unwrap();
+                let actual: Vec<_> = Schematron::parse(sdoc as xmlDocPtr)
+                    .err()
+                    .unwrap()
+                    .into_iter()
+                    .map(|e| (e.code, e.node as *mut c_void, e.message))
+                    .collect();
+                c_lib.xmlFreeDoc(sdoc);
+                assert_eq!(actual, expected, "{input}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_no_root() {
+        let c_lib = libxml2_dynload::get_c_baseline();
+        unsafe {
+            let sdoc = c_parse(&schema());
+            let schema = Schematron::parse(sdoc as xmlDocPtr).unwrap();
+            let doc = c_lib.xmlNewDoc(c"1.0".as_ptr() as *const xmlChar);
+            assert_eq!(schema.validate(doc as xmlDocPtr), Err(Vec::new()));
+            c_lib.xmlFreeDoc(doc);
+            drop(schema);
+            c_lib.xmlFreeDoc(sdoc);
+        }
+    }
+}
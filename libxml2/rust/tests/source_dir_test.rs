@@ -0,0 +1,214 @@
+//! Builds against a libxml2 source tree chosen through `LIBXML2_SRC`,
+//! including with some of its configure options turned off
+//!
+//! The tests running a nested cargo build, each with its own target
+//! directory, are ignored by default: `cargo test --test source_dir_test -- --ignored`.
+//! The others check the tree this build used.
+
+#[path = "../build/options.rs"]
+#[allow(dead_code)]
+mod options;
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+fn crate_dir() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+}
+
+/// Fresh, empty directory under the system temp dir
+fn scratch_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("libxml2-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+/// What a copy of the C sources leaves out: this crate, git, and the files
+/// configure generates, so that the nested build has to configure the copy
+const SKIP: &[&str] = &[
+    "rust",
+    ".git",
+    "config.h",
+    "config.status",
+    "include/libxml/xmlversion.h",
+];
+
+/// Copy `from` to `to`, except the paths in `skip`, relative to `from`
+fn copy_tree(from: &Path, to: &Path, skip: &[&str]) -> io::Result<()> {
+    fs::create_dir_all(to)?;
+    for entry in fs::read_dir(from)? {
+        let entry = entry?;
+        let name = entry.file_name();
+        if skip.iter().any(|s| name == *s) {
+            continue;
+        }
+        let target = to.join(&name);
+        if entry.file_type()?.is_dir() {
+            let prefix = format!("{}/", name.to_string_lossy());
+            let nested: Vec<&str> = skip
+                .iter()
+                .filter_map(|s| s.strip_prefix(prefix.as_str()))
+                .collect();
+            copy_tree(&entry.path(), &target, &nested)?;
+        } else {
+            fs::copy(entry.path(), &target)?;
+        }
+    }
+    Ok(())
+}
+
+/// `cargo build` of this crate against `source`, outside the outer build's target dir
+fn build_against(source: &Path, target_dir: &Path, extra_args: &[&str]) -> Output {
+    Command::new(env!("CARGO"))
+        .args(["build", "--offline", "--lib"])
+        .args(extra_args)
+        .current_dir(crate_dir())
+        .env("LIBXML2_SRC", source)
+        .env("CARGO_TARGET_DIR", target_dir)
+        .env_remove("RUST_MODULES")
+        .output()
+        .expect("failed to run cargo")
+}
+
+/// The configure option features of this build
+fn option_features() -> Vec<&'static str> {
+    [
+        ("without-c14n", cfg!(feature = "without-c14n")),
+        ("without-catalog", cfg!(feature = "without-catalog")),
+        ("without-debug", cfg!(feature = "without-debug")),
+        ("without-html", cfg!(feature = "without-html")),
+        ("without-output", cfg!(feature = "without-output")),
+        ("without-pattern", cfg!(feature = "without-pattern")),
+        ("without-reader", cfg!(feature = "without-reader")),
+        ("without-regexps", cfg!(feature = "without-regexps")),
+        ("without-relaxng", cfg!(feature = "without-relaxng")),
+        ("without-schemas", cfg!(feature = "without-schemas")),
+        ("without-valid", cfg!(feature = "without-valid")),
+        ("without-writer", cfg!(feature = "without-writer")),
+        ("without-xinclude", cfg!(feature = "without-xinclude")),
+        ("without-xpath", cfg!(feature = "without-xpath")),
+        ("without-xptr", cfg!(feature = "without-xptr")),
+        ("with-schematron", cfg!(feature = "with-schematron")),
+    ]
+    .into_iter()
+    .filter(|(_, on)| *on)
+    .map(|(feature, _)| feature)
+    .collect()
+}
+
+#[test]
+fn test_source_tree_configured_for_features() {
+    // The tree this crate was built from, as build.rs resolved it
+    let source = Path::new(env!("LIBXML2_SRC_DIR"));
+    for marker in ["configure.ac", "libxml.h", "config.h", "include/libxml/xmlversion.h"] {
+        assert!(source.join(marker).is_file(), "{} missing in {}", marker, source.display());
+    }
+
+    let output = Command::new("sh")
+        .args(["config.status", "--config"])
+        .current_dir(source)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let configured: Vec<String> = String::from_utf8_lossy(&output.stdout)
+        .split_whitespace()
+        .map(|arg| arg.trim_matches('\'').to_string())
+        .collect();
+    let features = option_features();
+    assert_eq!(configured, options::configure_args(&|f| features.contains(&f)));
+}
+
+#[test]
+#[ignore = "runs a full nested cargo build"]
+fn test_build_against_copied_source_tree() {
+    let scratch = scratch_dir("src-copy");
+    let source = scratch.join("libxml2");
+    // The crate stays where it is; only the C sources move
+    copy_tree(crate_dir().parent().unwrap(), &source, SKIP).unwrap();
+
+    let output = build_against(&source, &scratch.join("target"), &[]);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "build failed:\n{}", stderr);
+
+    // Configure ran in the copy, not in the checkout this crate lives in
+    assert!(
+        stderr.contains("Running configure"),
+        "configure did not run:\n{}",
+        stderr
+    );
+    assert!(source.join("config.h").exists());
+    assert!(stderr.contains(&format!("Using libxml2 sources from {}", source.display())));
+
+    let _ = fs::remove_dir_all(&scratch);
+}
+
+#[test]
+#[ignore = "runs a nested cargo build"]
+fn test_rejects_directory_without_sources() {
+    let scratch = scratch_dir("src-empty");
+    let source = scratch.join("empty");
+    fs::create_dir_all(&source).unwrap();
+
+    let output = build_against(&source, &scratch.join("target"), &[]);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!output.status.success());
+    assert!(
+        stderr.contains("does not look like a libxml2 source tree (missing configure.ac"),
+        "unexpected error:\n{}",
+        stderr
+    );
+
+    let _ = fs::remove_dir_all(&scratch);
+}
+
+/// Object files in the C baseline archive of a nested build
+fn baseline_members(target_dir: &Path) -> Vec<String> {
+    let pattern = target_dir.join("debug/build/libxml2-*/out/liblibxml2_c.a");
+    let archive = glob::glob(pattern.to_str().unwrap())
+        .unwrap()
+        .filter_map(Result::ok)
+        .next()
+        .expect("no C baseline archive");
+    let output = Command::new("ar").arg("t").arg(&archive).output().unwrap();
+    assert!(output.status.success());
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .map(str::to_string)
+        .collect()
+}
+
+#[test]
+#[ignore = "runs a full nested cargo build"]
+fn test_disabled_option_omits_c_objects() {
+    // Configure rewrites config.h, so run it on a copy instead of this checkout
+    let scratch = scratch_dir("no-schemas");
+    let source = scratch.join("libxml2");
+    copy_tree(crate_dir().parent().unwrap(), &source, SKIP).unwrap();
+
+    let target = scratch.join("target");
+    let output = build_against(
+        &source,
+        &target,
+        &["--features", "without-schemas,without-html"],
+    );
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "build failed:\n{}", stderr);
+
+    let members = baseline_members(&target);
+    for object in [
+        "xmlschemas.o",
+        "xmlschemastypes.o",
+        "relaxng.o",
+        "HTMLparser.o",
+    ] {
+        assert!(!members.iter().any(|m| m == object), "{} was built", object);
+    }
+    for object in ["parser.o", "tree.o", "xmlregexp.o", "xpath.o"] {
+        assert!(members.iter().any(|m| m == object), "{} is missing", object);
+    }
+
+    let _ = fs::remove_dir_all(&scratch);
+}
@@ -0,0 +1,212 @@
+//! Tests for threads module
+
+use libxml2::*;
+use std::cell::UnsafeCell;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+type Call = unsafe extern "C" fn();
+
+/// The order in which this thread, holding the library lock twice, and
+/// another thread waiting for it get to run.
+fn library_lock_order(lock: Call, unlock: Call) -> Vec<&'static str> {
+    let order = Arc::new(Mutex::new(Vec::new()));
+    unsafe {
+        lock();
+        lock();
+    }
+    let other = {
+        let order = order.clone();
+        thread::spawn(move || unsafe {
+            lock();
+            order.lock().unwrap().push("other");
+            unlock();
+        })
+    };
+    thread::sleep(Duration::from_millis(50));
+    order.lock().unwrap().push("held twice");
+    unsafe { unlock() };
+    thread::sleep(Duration::from_millis(50));
+    order.lock().unwrap().push("held once");
+    unsafe { unlock() };
+    other.join().unwrap();
+    Arc::try_unwrap(order).unwrap().into_inner().unwrap()
+}
+
+/// A counter only ever touched under a mutex.
+struct Counter(UnsafeCell<usize>);
+
+unsafe impl Sync for Counter {}
+
+/// The count after 8 threads each add 1000 to a counter under a mutex
+/// of `new`.
+fn count_under_mutex<M: 'static>(
+    new: unsafe extern "C" fn() -> *mut M,
+    lock: unsafe extern "C" fn(*mut M),
+    unlock: unsafe extern "C" fn(*mut M),
+    free: unsafe extern "C" fn(*mut M),
+) -> usize {
+    let mutex = unsafe { new() } as usize;
+    let counter = Arc::new(Counter(UnsafeCell::new(0)));
+    let threads: Vec<_> = (0..8)
+        .map(|_| {
+            let counter = counter.clone();
+            thread::spawn(move || {
+                for _ in 0..1000 {
+                    unsafe {
+                        lock(mutex as *mut M);
+                        *counter.0.get() += 1;
+                        unlock(mutex as *mut M);
+                    }
+                }
+            })
+        })
+        .collect();
+    for thread in threads {
+        thread.join().unwrap();
+    }
+    unsafe { free(mutex as *mut M) };
+    unsafe { *counter.0.get() }
+}
+
+#[test]
+fn test_library_lock_baseline() {
+    let c_lib = libxml2::libxml2_dynload::get_c_baseline();
+    let c_order = library_lock_order(
+        *c_lib.xmlLockLibrary.as_ref().unwrap(),
+        *c_lib.xmlUnlockLibrary.as_ref().unwrap(),
+    );
+    assert_eq!(c_order, ["held twice", "held once", "other"]);
+}
+
+#[test]
+fn test_mutex_baseline() {
+    let c_lib = libxml2::libxml2_dynload::get_c_baseline();
+    let count = count_under_mutex(
+        *c_lib.xmlNewMutex.as_ref().unwrap(),
+        *c_lib.xmlMutexLock.as_ref().unwrap(),
+        *c_lib.xmlMutexUnlock.as_ref().unwrap(),
+        *c_lib.xmlFreeMutex.as_ref().unwrap(),
+    );
+    assert_eq!(count, 8000);
+}
+
+#[cfg(feature = "rust-threads")]
+mod rust_tests {
+    use super::*;
+    use std::ptr;
+    use std::sync::Barrier;
+
+    #[test]
+    fn test_library_lock_matches_baseline() {
+        let c_lib = libxml2::libxml2_dynload::get_c_baseline();
+        let c_order = library_lock_order(
+            *c_lib.xmlLockLibrary.as_ref().unwrap(),
+            *c_lib.xmlUnlockLibrary.as_ref().unwrap(),
+        );
+        assert_eq!(
+            library_lock_order(xmlLockLibrary, xmlUnlockLibrary),
+            c_order
+        );
+    }
+
+    #[test]
+    fn test_mutex_matches_baseline() {
+        assert_eq!(
+            count_under_mutex(xmlNewMutex, xmlMutexLock, xmlMutexUnlock, xmlFreeMutex),
+            8000
+        );
+    }
+
+    #[test]
+    fn test_reentrant_mutex() {
+        unsafe {
+            let mutex = xmlNewRMutex() as usize;
+            xmlRMutexLock(mutex as xmlRMutexPtr);
+            xmlRMutexLock(mutex as xmlRMutexPtr);
+            let other = thread::spawn(move || {
+                xmlRMutexLock(mutex as xmlRMutexPtr);
+                xmlRMutexUnlock(mutex as xmlRMutexPtr);
+            });
+            xmlRMutexUnlock(mutex as xmlRMutexPtr);
+            xmlRMutexUnlock(mutex as xmlRMutexPtr);
+            other.join().unwrap();
+            xmlFreeRMutex(mutex as xmlRMutexPtr);
+
+            // NULL is ignored throughout.
+            xmlRMutexLock(ptr::null_mut());
+            xmlRMutexUnlock(ptr::null_mut());
+            xmlFreeRMutex(ptr::null_mut());
+            xmlMutexLock(ptr::null_mut());
+            xmlFreeMutex(ptr::null_mut());
+        }
+    }
+
+    /// The ways a first use can set the library up.
+    const FIRST_USES: &[fn()] = &[
+        init_chimera,
+        || unsafe { xmlInitParser() },
+        || unsafe { xmlInitThreads() },
+    ];
+
+    /// Start `threads` threads at once, each setting the library up in
+    /// one of `ways`, and return whether each found it set up as soon as
+    /// its call returned.
+    fn race_to_initialize(threads: usize, ways: &'static [fn()]) -> Vec<bool> {
+        let barrier = Arc::new(Barrier::new(threads));
+        let handles: Vec<_> = (0..threads)
+            .map(|i| {
+                let barrier = barrier.clone();
+                thread::spawn(move || {
+                    barrier.wait();
+                    ways[i % ways.len()]();
+                    is_initialized()
+                })
+            })
+            .collect();
+        handles.into_iter().map(|h| h.join().unwrap()).collect()
+    }
+
+    fn parse_ok(doc: &str) -> bool {
+        unsafe {
+            let parsed = xmlReadMemory(
+                doc.as_ptr() as *const _,
+                doc.len() as _,
+                ptr::null(),
+                ptr::null(),
+                0,
+            );
+            if parsed.is_null() {
+                return false;
+            }
+            xmlFreeDoc(parsed);
+            true
+        }
+    }
+
+    /// One test, so that nothing else in this process sets the library up
+    /// or cleans it up meanwhile.
+    #[test]
+    fn test_concurrent_initialization() {
+        let seen = race_to_initialize(64, FIRST_USES);
+        assert!(seen.iter().all(|&set_up| set_up));
+        assert_eq!(initializations(), 1);
+        assert!(parse_ok("<doc><a b='&lt;'/></doc>"));
+
+        // More callers, initialized or not, do nothing.
+        assert!(race_to_initialize(16, FIRST_USES).iter().all(|&set_up| set_up));
+        assert_eq!(initializations(), 1);
+
+        // After cleanup, the next race sets the library up once more.
+        // init_chimera has used up its Once and is left out.
+        unsafe { xmlCleanupParser() };
+        assert!(!is_initialized());
+        unsafe { xmlCleanupParser() };
+        init_chimera();
+        assert!(!is_initialized());
+        assert!(race_to_initialize(64, &FIRST_USES[1..]).iter().all(|&set_up| set_up));
+        assert_eq!(initializations(), 2);
+        assert!(parse_ok("<again/>"));
+    }
+}
@@ -0,0 +1,670 @@
+//! Tests for tree module
+
+use libxml2::*;
+use std::ffi::CStr;
+use std::os::raw::{c_char, c_int};
+use std::ptr;
+
+/// A document declaring an internal entity and one that refers to another
+const DOC: &[u8] = b"<!DOCTYPE r [
+<!ENTITY e 'ent'>
+<!ENTITY nested 'x&e;y'>
+]>
+<r a='1'><old/>text</r>";
+
+/// Type, name, content and entity link of the children of `node`, one
+/// level deep.
+unsafe fn children(node: xmlNodePtr) -> Vec<(u32, Option<String>, Option<String>, bool)> {
+    let string = |s: *const xmlChar| {
+        (!s.is_null()).then(|| {
+            CStr::from_ptr(s as *const c_char)
+                .to_string_lossy()
+                .into_owned()
+        })
+    };
+    let mut out = Vec::new();
+    let mut child = (*node).children;
+    while !child.is_null() {
+        let c = &*child;
+        assert_eq!(c.parent, node);
+        let linked = c.type_ == xmlElementType_XML_ENTITY_REF_NODE && !c.children.is_null();
+        out.push((c.type_, string(c.name), string(c.content), linked));
+        child = c.next;
+    }
+    out
+}
+
+#[test]
+fn test_set_content_baseline() {
+    let c_lib = libxml2_dynload::get_c_baseline();
+    unsafe {
+        let doc = c_lib.xmlReadMemory(
+            DOC.as_ptr() as *const c_char,
+            DOC.len() as c_int,
+            ptr::null(),
+            ptr::null(),
+            0,
+        );
+        assert!(!doc.is_null());
+        let root = c_lib.xmlDocGetRootElement(doc);
+        assert_eq!(
+            c_lib.xmlNodeSetContent(root, c"a&amp;b&e;".as_ptr() as *const xmlChar),
+            0
+        );
+        let kids = children(root as xmlNodePtr);
+        assert_eq!(
+            kids,
+            [
+                (
+                    xmlElementType_XML_TEXT_NODE,
+                    Some("text".into()),
+                    Some("a&b".into()),
+                    false
+                ),
+                (
+                    xmlElementType_XML_ENTITY_REF_NODE,
+                    Some("e".into()),
+                    Some("ent".into()),
+                    true
+                ),
+            ]
+        );
+        c_lib.xmlFreeDoc(doc);
+    }
+}
+
+/// A document running past line 65535, with elements, text, comments, PIs
+/// and attributes on both sides of it
+fn long_document() -> Vec<u8> {
+    let mut doc = b"<doc>\n".to_vec();
+    for i in 0..70_000 {
+        let line: &[u8] = match i % 1000 {
+            0 => b"<a x='1'>text\n<b/></a>",
+            1 => b"<!-- c -->",
+            2 => b"<?pi data?>",
+            3 => b"<empty/>",
+            4 => b"<nested><inner y='2'/></nested>",
+            _ => b"",
+        };
+        doc.extend_from_slice(line);
+        doc.push(b'\n');
+    }
+    doc.extend_from_slice(b"<last>end</last></doc>\n");
+    doc
+}
+
+/// Every node below `node` and the attributes of elements, in document
+/// order
+unsafe fn all_nodes(node: xmlNodePtr, out: &mut Vec<xmlNodePtr>) {
+    let mut cur = node;
+    while !cur.is_null() {
+        out.push(cur);
+        if (*cur).type_ == xmlElementType_XML_ELEMENT_NODE {
+            let mut attr = (*cur).properties;
+            while !attr.is_null() {
+                out.push(attr as xmlNodePtr);
+                attr = (*attr).next;
+            }
+        }
+        all_nodes((*cur).children, out);
+        cur = (*cur).next;
+    }
+}
+
+#[test]
+fn test_line_numbers_baseline() {
+    let c_lib = libxml2_dynload::get_c_baseline();
+    let input = long_document();
+    for (options, last_line) in [(0, 65535), (xmlParserOption_XML_PARSE_BIG_LINES, 70_072)] {
+        unsafe {
+            let doc = c_lib.xmlReadMemory(
+                input.as_ptr() as *const c_char,
+                input.len() as c_int,
+                ptr::null(),
+                ptr::null(),
+                options as c_int,
+            );
+            assert!(!doc.is_null());
+            let root = c_lib.xmlDocGetRootElement(doc);
+            assert_eq!(c_lib.xmlGetLineNo(root), 1);
+            let last = (*(root as xmlNodePtr)).last;
+            // The text of `<last>`, which only keeps its line with BIG_LINES
+            let text = (*last).children;
+            assert_eq!((*text).line, u16::MAX);
+            assert_eq!(c_lib.xmlGetLineNo(text as _), last_line);
+            c_lib.xmlFreeDoc(doc);
+        }
+    }
+}
+
+/// Nested `xml:base` attributes: relative, absolute-path, parent-relative
+/// and query-only ones, and bases that stop the walk
+const BASE_DOC: &[u8] = b"<!DOCTYPE doc [<!ENTITY e 'x'>]>
+<doc xml:base='sub/'>
+  <a xml:base='a/b.xml'><leaf>t</leaf></a>
+  <b xml:base='/abs/'><c xml:base='../up/'><d/></c></b>
+  <h xml:base='http://other.example/h/'><i xml:base='rel/'/></h>
+  <u xml:base='urn:x:y'><v xml:base='z'/></u>
+  <f xml:base='ftp://ftp.example/f/'><g/></f>
+  <q xml:base='?q#frag'/>
+  <empty xml:base=''/>
+  <e>&e;</e>
+  <plain base='not-xml-base'/>
+</doc>";
+
+/// Copy and free a string returned by either build; both allocate with
+/// `malloc` unless told otherwise.
+unsafe fn take(s: *mut xmlChar) -> Option<String> {
+    if s.is_null() {
+        return None;
+    }
+    let ret = CStr::from_ptr(s as *const c_char)
+        .to_string_lossy()
+        .into_owned();
+    xmlFree.unwrap()(s as *mut std::os::raw::c_void);
+    Some(ret)
+}
+
+#[test]
+fn test_node_get_base_baseline() {
+    let c_lib = libxml2_dynload::get_c_baseline();
+    unsafe {
+        let doc = c_lib.xmlReadMemory(
+            BASE_DOC.as_ptr() as *const c_char,
+            BASE_DOC.len() as c_int,
+            c"http://example.org/dir/doc.xml".as_ptr(),
+            ptr::null(),
+            0,
+        );
+        assert!(!doc.is_null());
+        let mut nodes = Vec::new();
+        all_nodes((*(doc as xmlDocPtr)).children, &mut nodes);
+        let base_of = |name: &str| {
+            let node = *nodes
+                .iter()
+                .find(|&&n| {
+                    (*n).type_ == xmlElementType_XML_ELEMENT_NODE
+                        && CStr::from_ptr((*n).name as *const c_char).to_bytes() == name.as_bytes()
+                })
+                .unwrap();
+            take(c_lib.xmlNodeGetBase(doc, node as _))
+        };
+        assert_eq!(
+            base_of("leaf").as_deref(),
+            Some("http://example.org/dir/sub/a/b.xml")
+        );
+        assert_eq!(base_of("d").as_deref(), Some("http://example.org/up/"));
+        assert_eq!(base_of("i").as_deref(), Some("http://other.example/h/rel/"));
+        assert_eq!(
+            base_of("plain").as_deref(),
+            Some("http://example.org/dir/sub/")
+        );
+        c_lib.xmlFreeDoc(doc);
+    }
+}
+
+/// Documents with a subtree, the first element in `<src>`, to move under
+/// `<dst>`, where its namespaces are not all in scope.
+const MOVE_DOCS: &[&str] = &[
+    // A prefix declared on the old parent only
+    "<r><src xmlns:a='urn:a'><a:m a:x='1' y='2'><a:c/>t</a:m></src><dst/></r>",
+    // The default namespace, with the `default` prefix taken
+    "<r xmlns:default='urn:o'><src xmlns='urn:d'><m><c xmlns:default='urn:p'/></m></src><dst/></r>",
+    // A prefix bound to another URI at the new parent
+    "<r><src xmlns:p='urn:a'><p:m><p:c p:x=''/></p:m></src><dst xmlns:p='urn:b' xmlns:p1='urn:c'/></r>",
+    // The URI in scope under another prefix, or only as the default,
+    // which attributes cannot use
+    "<r><src xmlns:a='urn:a' xmlns='urn:a'><m a:x='1'/></src><dst xmlns:q='urn:a'/></r>",
+    "<r><src xmlns:a='urn:a'><a:m a:x='1'/></src><dst xmlns='urn:a'/></r>",
+    // A declaration in scope but masked on the way down
+    "<r xmlns:p='urn:a'><src><p:m/></src><dst xmlns:p='urn:b'/></r>",
+    // Prefixes cut to 20 bytes before numbering
+    "<r><src xmlns:abcdefghijklmnopqrstuvwxyz='urn:l'><abcdefghijklmnopqrstuvwxyz:m/></src>\
+     <dst xmlns:abcdefghijklmnopqrst='urn:z'/></r>",
+    // Declarations inside the subtree, and the xml namespace
+    "<r><src xmlns:a='urn:a'><a:m xml:lang='en'><a:c xmlns:a='urn:c'><a:d/></a:c>\
+     <b:e xmlns:b='urn:a'/></a:m></src><dst/></r>",
+    // Entity references are not entered
+    "<!DOCTYPE r [<!ENTITY e '<a:i xmlns:a=\"urn:a\"/>'>]>\
+     <r><src xmlns:a='urn:a'><a:m>&e;</a:m></src><dst/></r>",
+];
+
+/// The element children of `node`.
+unsafe fn elements(node: xmlNodePtr) -> Vec<xmlNodePtr> {
+    let mut out = Vec::new();
+    let mut child = (*node).children;
+    while !child.is_null() {
+        if (*child).type_ == xmlElementType_XML_ELEMENT_NODE {
+            out.push(child);
+        }
+        child = (*child).next;
+    }
+    out
+}
+
+#[test]
+fn test_reconciliate_ns_baseline() {
+    let c_lib = libxml2_dynload::get_c_baseline();
+    unsafe {
+        let input = MOVE_DOCS[2];
+        let doc = c_lib.xmlReadMemory(
+            input.as_ptr() as *const c_char,
+            input.len() as c_int,
+            ptr::null(),
+            ptr::null(),
+            0,
+        );
+        let root = c_lib.xmlDocGetRootElement(doc);
+        let parts = elements(root as xmlNodePtr);
+        let moved = (*parts[0]).children as *mut dynamic_bindings::xmlNode;
+        c_lib.xmlUnlinkNode(moved);
+        c_lib.xmlAddChild(parts[1] as _, moved);
+        assert_eq!(c_lib.xmlReconciliateNs(doc, moved), 0);
+        let mut mem = ptr::null_mut();
+        let mut size = 0;
+        c_lib.xmlDocDumpMemory(doc, &mut mem, &mut size);
+        assert_eq!(
+            take(mem).unwrap(),
+            "<?xml version=\"1.0\"?>\n<r><src xmlns:p=\"urn:a\"/>\
+             <dst xmlns:p=\"urn:b\" xmlns:p1=\"urn:c\">\
+             <p2:m xmlns:p2=\"urn:a\"><p2:c p2:x=\"\"/></p2:m></dst></r>\n"
+        );
+        c_lib.xmlFreeDoc(doc);
+    }
+}
+
+#[cfg(feature = "rust-tree")]
+mod rust_tests {
+    use super::*;
+    use libxml2::libxml2_dynload::{diff_trees, BaselineLib};
+
+    /// Content strings for elements: predefined, declared and undeclared
+    /// entities, character references, and malformed references.
+    const CONTENT: &[&[u8]] = &[
+        b"",
+        b"plain",
+        b"a &amp; b",
+        b"&amp;&lt;&gt;&quot;&apos;",
+        b"&unknown;",
+        b"x&unknown;y&e;z",
+        b"&nested;&nested;",
+        b"&#65;&#x42;&#X43;&#0;&#;&#x110000;&#99999999;",
+        b"&#1z;&#xg;",
+        b"a&lt",
+        b"&;&&amp;",
+        b"&#0;",
+        b"caf\xc3\xa9 &#xe9;",
+    ];
+
+    const HTML_BASE_DOCS: &[&[u8]] = &[
+        b"<html><head><base href='http://h.example/x/'></head><body><p>t</p></body></html>",
+        b"<html><head><title>t</title><base target='_top'></head><body/></html>",
+        b"<html><body><base href='ignored/'><p>t</p></body></html>",
+    ];
+
+    type SetContentFn = unsafe fn(xmlNodePtr, &[u8], Option<c_int>) -> c_int;
+
+    /// Parse `DOC` in both builds, set the content of the node `pick` finds
+    /// in each, with `xmlNodeSetContentLen` if there is a `len`, and compare
+    /// the return values, the resulting trees and the children of the node.
+    unsafe fn compare(
+        pick: unsafe fn(xmlDocPtr) -> xmlNodePtr,
+        content: &[u8],
+        len: Option<c_int>,
+    ) {
+        let baseline = BaselineLib::load().unwrap();
+        let (buf, buf_len) = (DOC.as_ptr() as *const c_char, DOC.len() as c_int);
+        let c_doc = baseline.xmlReadMemory(buf, buf_len, ptr::null(), ptr::null(), 0) as xmlDocPtr;
+        let rust_doc = xmlReadMemory(buf, buf_len, ptr::null(), ptr::null(), 0);
+        assert!(!c_doc.is_null() && !rust_doc.is_null());
+
+        let c_set: SetContentFn = |node, content, len| {
+            let c_lib = libxml2_dynload::get_c_baseline();
+            let node = node as *mut dynamic_bindings::xmlNode;
+            match len {
+                None => c_lib.xmlNodeSetContent(node, content.as_ptr()),
+                Some(len) => c_lib.xmlNodeSetContentLen(node, content.as_ptr(), len),
+            }
+        };
+        let rust_set: SetContentFn = |node, content, len| match len {
+            None => tree::ffi::xmlNodeSetContent(node, content.as_ptr()),
+            Some(len) => tree::ffi::xmlNodeSetContentLen(node, content.as_ptr(), len),
+        };
+
+        let (c_node, rust_node) = (pick(c_doc), pick(rust_doc));
+        let label = String::from_utf8_lossy(content);
+        assert_eq!(
+            c_set(c_node, content, len),
+            rust_set(rust_node, content, len),
+            "{label:?} {len:?}"
+        );
+        assert_eq!(children(c_node), children(rust_node), "{label:?} {len:?}");
+        if let Err(diff) = diff_trees(c_doc, rust_doc) {
+            panic!("{label:?} {len:?}: {diff}");
+        }
+
+        baseline.xmlFreeDoc(c_doc as *mut dynamic_bindings::xmlDoc);
+        xmlFreeDoc(rust_doc);
+    }
+
+    unsafe fn root(doc: xmlDocPtr) -> xmlNodePtr {
+        let mut node = (*doc).children;
+        while (*node).type_ != xmlElementType_XML_ELEMENT_NODE {
+            node = (*node).next;
+        }
+        node
+    }
+
+    unsafe fn attribute(doc: xmlDocPtr) -> xmlNodePtr {
+        (*root(doc)).properties as xmlNodePtr
+    }
+
+    /// The `text` child of the root.
+    unsafe fn text(doc: xmlDocPtr) -> xmlNodePtr {
+        (*root(doc)).last
+    }
+
+    fn terminated(s: &[u8]) -> Vec<u8> {
+        let mut v = s.to_vec();
+        v.push(0);
+        v
+    }
+
+    #[test]
+    fn test_set_element_content_matches_c() {
+        for content in CONTENT {
+            let content = terminated(content);
+            unsafe {
+                compare(root, &content, None);
+                compare(attribute, &content, None);
+            }
+        }
+    }
+
+    #[test]
+    fn test_set_content_len_matches_c() {
+        let content = b"a&amp;b&#65;&e;&#x42;c\0";
+        for len in 0..content.len() as c_int {
+            unsafe {
+                compare(root, content, Some(len));
+                compare(text, content, Some(len));
+            }
+        }
+    }
+
+    #[test]
+    fn test_set_text_content_is_raw() {
+        for content in CONTENT {
+            let content = terminated(content);
+            unsafe { compare(text, &content, None) };
+        }
+    }
+
+    #[test]
+    fn test_null_arguments() {
+        unsafe {
+            assert_eq!(
+                tree::ffi::xmlNodeSetContent(ptr::null_mut(), ptr::null()),
+                1
+            );
+            compare(root, b"\0", None);
+
+            // A NULL content clears the node
+            let doc = xmlReadMemory(
+                DOC.as_ptr() as *const c_char,
+                DOC.len() as c_int,
+                ptr::null(),
+                ptr::null(),
+                0,
+            );
+            let node = text(doc);
+            assert_eq!(tree::ffi::xmlNodeSetContent(node, ptr::null()), 0);
+            assert!((*node).content.is_null());
+            let node = root(doc);
+            assert_eq!(tree::ffi::xmlNodeSetContent(node, ptr::null()), 0);
+            assert!((*node).children.is_null() && (*node).last.is_null());
+            xmlFreeDoc(doc);
+        }
+    }
+
+    #[test]
+    fn test_line_numbers_match_c() {
+        let baseline = BaselineLib::load().unwrap();
+        let input = long_document();
+        let (buf, len) = (input.as_ptr() as *const c_char, input.len() as c_int);
+        for options in [0, xmlParserOption_XML_PARSE_BIG_LINES as c_int] {
+            unsafe {
+                let c_doc = baseline.xmlReadMemory(buf, len, ptr::null(), ptr::null(), options);
+                let rust_doc = xmlReadMemory(buf, len, ptr::null(), ptr::null(), options);
+                assert!(!c_doc.is_null() && !rust_doc.is_null());
+
+                let (mut c_nodes, mut rust_nodes) = (Vec::new(), Vec::new());
+                all_nodes((*(c_doc as xmlDocPtr)).children, &mut c_nodes);
+                all_nodes((*rust_doc).children, &mut rust_nodes);
+                assert_eq!(c_nodes.len(), rust_nodes.len());
+                assert!(c_nodes.len() > 1000);
+
+                for (i, (&c, &rust)) in c_nodes.iter().zip(&rust_nodes).enumerate() {
+                    assert_eq!((*c).type_, (*rust).type_);
+                    // Attributes have no line field of their own
+                    if (*c).type_ != xmlElementType_XML_ATTRIBUTE_NODE {
+                        assert_eq!((*c).line, (*rust).line);
+                    }
+                    assert_eq!(
+                        baseline.xmlGetLineNo(c as *const dynamic_bindings::xmlNode),
+                        tree::ffi::xmlGetLineNo(rust),
+                        "node {} of type {}, options {}",
+                        i,
+                        (*c).type_,
+                        options
+                    );
+                }
+                assert_eq!(tree::ffi::xmlGetLineNo(ptr::null()), -1);
+
+                baseline.xmlFreeDoc(c_doc);
+                xmlFreeDoc(rust_doc);
+            }
+        }
+    }
+
+    /// `xmlNodeGetBaseSafe` on `node` of a baseline document and the
+    /// matching node of a hybrid one: return value and base.
+    unsafe fn bases(
+        c_doc: xmlDocPtr,
+        c_node: xmlNodePtr,
+        rust_doc: xmlDocPtr,
+        rust_node: xmlNodePtr,
+    ) -> ((c_int, Option<String>), (c_int, Option<String>)) {
+        let c_lib = libxml2_dynload::get_c_baseline();
+        let mut c_base = ptr::null_mut();
+        let c_ret = c_lib.xmlNodeGetBaseSafe(c_doc as _, c_node as _, &mut c_base);
+        let c_base = take(c_base);
+        let mut rust_base = ptr::null_mut();
+        let rust_ret = tree::ffi::xmlNodeGetBaseSafe(rust_doc, rust_node, &mut rust_base);
+        let rust_base = take(rust_base);
+        ((c_ret, c_base), (rust_ret, rust_base))
+    }
+
+    /// Parse `input` in both builds and compare the base of every node,
+    /// with and without `doc` passed, of the document and of the entity
+    /// declarations entity references point to.
+    unsafe fn compare_bases(input: &[u8], url: Option<&CStr>, html: bool) {
+        let baseline = BaselineLib::load().unwrap();
+        let (buf, len) = (input.as_ptr() as *const c_char, input.len() as c_int);
+        let url = url.map_or(ptr::null(), CStr::as_ptr);
+        let (c_doc, rust_doc) = if html {
+            (
+                baseline.htmlReadMemory(buf, len, url, ptr::null(), 0) as xmlDocPtr,
+                htmlReadMemory(buf, len, url, ptr::null(), 0),
+            )
+        } else {
+            (
+                baseline.xmlReadMemory(buf, len, url, ptr::null(), 0) as xmlDocPtr,
+                xmlReadMemory(buf, len, url, ptr::null(), 0),
+            )
+        };
+        assert!(!c_doc.is_null() && !rust_doc.is_null());
+
+        let (mut c_nodes, mut rust_nodes) = (Vec::new(), Vec::new());
+        all_nodes((*c_doc).children, &mut c_nodes);
+        all_nodes((*rust_doc).children, &mut rust_nodes);
+        assert_eq!(c_nodes.len(), rust_nodes.len());
+        for (&c, &rust) in c_nodes.iter().zip(&rust_nodes) {
+            let (c_base, rust_base) = bases(c_doc, c, rust_doc, rust);
+            assert_eq!(c_base, rust_base, "node type {}", (*c).type_);
+            let (c_base, rust_base) = bases(ptr::null_mut(), c, ptr::null_mut(), rust);
+            assert_eq!(c_base, rust_base, "node type {} without doc", (*c).type_);
+            if (*c).type_ == xmlElementType_XML_ENTITY_REF_NODE && !(*c).children.is_null() {
+                let (c_base, rust_base) = bases(c_doc, (*c).children, rust_doc, (*rust).children);
+                assert_eq!(c_base, rust_base, "entity declaration");
+            }
+        }
+        let (c_base, rust_base) = bases(c_doc, ptr::null_mut(), rust_doc, ptr::null_mut());
+        assert_eq!(c_base, rust_base, "document");
+
+        baseline.xmlFreeDoc(c_doc as *mut dynamic_bindings::xmlDoc);
+        xmlFreeDoc(rust_doc);
+    }
+
+    #[test]
+    fn test_node_get_base_matches_c() {
+        for url in [
+            Some(c"http://example.org/dir/doc.xml"),
+            Some(c"file:///tmp/doc.xml"),
+            Some(c"relative/doc.xml"),
+            None,
+        ] {
+            unsafe { compare_bases(BASE_DOC, url, false) };
+        }
+    }
+
+    #[test]
+    fn test_html_base_matches_c() {
+        for input in HTML_BASE_DOCS {
+            for url in [Some(c"http://example.org/page.html"), None] {
+                unsafe { compare_bases(input, url, true) };
+            }
+        }
+    }
+
+    #[test]
+    fn test_node_get_base_arguments() {
+        unsafe {
+            let mut base = ptr::null_mut();
+            assert_eq!(
+                tree::ffi::xmlNodeGetBaseSafe(ptr::null(), ptr::null(), &mut base),
+                1
+            );
+            assert!(base.is_null());
+            assert_eq!(
+                tree::ffi::xmlNodeGetBaseSafe(ptr::null(), ptr::null(), ptr::null_mut()),
+                1
+            );
+            assert!(tree::ffi::xmlNodeGetBase(ptr::null(), ptr::null()).is_null());
+        }
+    }
+
+    type ReconcileFn = unsafe fn(xmlDocPtr, xmlNodePtr) -> c_int;
+
+    /// Move the first element of `<src>` in `input` under `<dst>`, or the
+    /// one of `from` if given, and reconcile its namespaces. Returns the
+    /// result and the serialized document.
+    unsafe fn move_and_reconcile(
+        c: bool,
+        input: &str,
+        from: Option<&str>,
+        reconcile: ReconcileFn,
+    ) -> (c_int, String) {
+        let baseline = BaselineLib::load().unwrap();
+        let read = |s: &str| {
+            let (buf, len) = (s.as_ptr() as *const c_char, s.len() as c_int);
+            let doc = if c {
+                baseline.xmlReadMemory(buf, len, ptr::null(), ptr::null(), 0) as xmlDocPtr
+            } else {
+                xmlReadMemory(buf, len, ptr::null(), ptr::null(), 0)
+            };
+            assert!(!doc.is_null(), "{s}");
+            doc
+        };
+        let doc = read(input);
+        let source = from.map_or(doc, read);
+        let moved = elements(elements(root(source))[0])[0];
+        let dst = elements(root(doc))[1];
+        if c {
+            baseline.xmlUnlinkNode(moved as _);
+            baseline.xmlAddChild(dst as _, moved as _);
+        } else {
+            xmlUnlinkNode(moved);
+            xmlAddChild(dst, moved);
+        }
+        let ret = reconcile(doc, moved);
+
+        let (mut mem, mut size) = (ptr::null_mut(), 0);
+        if c {
+            if source != doc {
+                baseline.xmlFreeDoc(source as _);
+            }
+            baseline.xmlDocDumpMemory(doc as _, &mut mem, &mut size);
+            baseline.xmlFreeDoc(doc as _);
+        } else {
+            if source != doc {
+                xmlFreeDoc(source);
+            }
+            xmlDocDumpMemory(doc, &mut mem, &mut size);
+            xmlFreeDoc(doc);
+        }
+        (ret, take(mem).unwrap())
+    }
+
+    unsafe fn compare_reconciled(input: &str, from: Option<&str>) {
+        let c_reconcile: ReconcileFn = |doc, tree| {
+            let c_lib = libxml2_dynload::get_c_baseline();
+            c_lib.xmlReconciliateNs(doc as _, tree as _)
+        };
+        let rust_reconcile: ReconcileFn = |doc, tree| tree::ffi::xmlReconciliateNs(doc, tree);
+        let expected = move_and_reconcile(true, input, from, c_reconcile);
+        let actual = move_and_reconcile(false, input, from, rust_reconcile);
+        assert_eq!(expected, actual, "{input}");
+    }
+
+    #[test]
+    fn test_reconciliate_ns_matches_c() {
+        for input in MOVE_DOCS {
+            unsafe { compare_reconciled(input, None) };
+        }
+    }
+
+    /// A subtree from another document keeps pointing at declarations
+    /// freed with it unless they are all replaced.
+    #[test]
+    fn test_reconciliate_ns_across_documents() {
+        let from = "<r><src xmlns:a='urn:a' xmlns='urn:d'>\
+                    <a:m a:x='1' xml:space='preserve'><c/><a:c xmlns:b='urn:b' b:y=''/></a:m>\
+                    </src></r>";
+        for input in [
+            "<r><src/><dst/></r>",
+            "<r xmlns:a='urn:b'><src/><dst xmlns='urn:a'/></r>",
+        ] {
+            unsafe { compare_reconciled(input, Some(from)) };
+        }
+    }
+
+    #[test]
+    fn test_reconciliate_ns_arguments() {
+        unsafe {
+            let input = MOVE_DOCS[0];
+            let (buf, len) = (input.as_ptr() as *const c_char, input.len() as c_int);
+            let doc = xmlReadMemory(buf, len, ptr::null(), ptr::null(), 0);
+            let other = xmlReadMemory(buf, len, ptr::null(), ptr::null(), 0);
+            let root = root(doc);
+            assert_eq!(tree::ffi::xmlReconciliateNs(doc, ptr::null_mut()), -1);
+            assert_eq!(tree::ffi::xmlReconciliateNs(other, root), -1);
+            let text = (*elements(elements(root)[0])[0]).last;
+            assert_eq!((*text).type_, xmlElementType_XML_TEXT_NODE);
+            assert_eq!(tree::ffi::xmlReconciliateNs(doc, text), -1);
+            assert_eq!(tree::ffi::xmlReconciliateNs(doc, root), 0);
+            xmlFreeDoc(doc);
+            xmlFreeDoc(other);
+        }
+    }
+}
@@ -0,0 +1,295 @@
+//! Tests for uri module
+
+use libxml2::*;
+use std::ffi::{CStr, CString};
+use std::os::raw::{c_char, c_int, c_void};
+
+/// References covering every branch of the grammar, plus the inputs where
+/// the C parser has quirks.
+const REFERENCES: &[&str] = &[
+    "http://example.com/",
+    "http://user:pw@example.com:8080/a/b;p?q=1&r=2#frag",
+    "https://[::1]:443/x",
+    "ftp://1.2.3.4/file",
+    "ftp://256.1.1.1/file",
+    "file:///etc/hosts",
+    "file:///c:/dir/file",
+    "mailto:someone@example.com",
+    "urn:isbn:0451450523",
+    "foo:",
+    "//host",
+    "//host:12",
+    "//@host/x",
+    "/abs/path",
+    "rel/path",
+    "../up",
+    "a:b:c",
+    "?query",
+    "#frag",
+    "",
+    "http://a/b?#f",
+    "http://a/b%20c%2Fd?x=%41#%42",
+    "a%00b",
+    "http://h:/p",
+    "http://h:99999999999/",
+    "http://[::1/",
+    "a b",
+    "x:y z",
+    "%zz",
+    "http://h/%41b c",
+];
+
+/// Filesystem paths and legacy extended IRIs for `xmlCanonicPath`:
+/// absolute and relative, with spaces, reserved and non-ASCII characters,
+/// existing escapes and Windows drive letters and backslashes.
+const PATHS: &[&[u8]] = &[
+    b"",
+    b"/etc/hosts",
+    b"rel/dir/../file.xml",
+    b"./a b/c d.xml",
+    b"/tmp/100%/x#y?z.xml",
+    b"/t\xc3\xa9st/\xe2\x82\xac.xml",
+    b"C:\\Program Files\\doc.xml",
+    b"c:/dir/file name.xml",
+    b"\\\\server\\share\\f.xml",
+    b"file:///tmp/a b.xml",
+    b"file:///C:/Program Files/doc.xml",
+    b"file://C:\\dir\\f.xml",
+    b"http://h/a b/%41?q=<v>&r={x}|y^`#f\\g",
+    b"http://h/\xc3\xa9\x7f\x01\"'",
+    b"urn:x:y z",
+    b"://",
+];
+
+/// Every field of an `xmlURI`, or None if parsing failed.
+type Fields = Option<(Vec<Option<Vec<u8>>>, c_int, c_int)>;
+
+unsafe fn opt(s: *const c_char) -> Option<Vec<u8>> {
+    if s.is_null() {
+        None
+    } else {
+        Some(CStr::from_ptr(s).to_bytes().to_vec())
+    }
+}
+
+unsafe fn fields(uri: *const xmlURI) -> Fields {
+    let uri = uri.as_ref()?;
+    let strings = [
+        uri.scheme,
+        uri.opaque,
+        uri.authority,
+        uri.server,
+        uri.user,
+        uri.path,
+        uri.query,
+        uri.fragment,
+        uri.query_raw,
+    ];
+    Some((
+        strings.iter().map(|&s| opt(s)).collect(),
+        uri.port,
+        uri.cleanup,
+    ))
+}
+
+/// Parse and save with the C baseline.
+fn c_parse(s: &CStr, raw: c_int) -> (Fields, Option<Vec<u8>>) {
+    let c_lib = libxml2_dynload::get_c_baseline();
+    unsafe {
+        let uri = c_lib.xmlParseURIRaw(s.as_ptr(), raw);
+        // The baseline struct has the same layout as the static one.
+        let result = fields(uri as *const xmlURI);
+        let mut saved = None;
+        if !uri.is_null() {
+            let out = c_lib.xmlSaveUri(uri);
+            saved = opt(out as *const c_char);
+            libc::free(out as *mut c_void);
+            c_lib.xmlFreeURI(uri);
+        }
+        (result, saved)
+    }
+}
+
+/// `xmlCanonicPath` or `xmlPathToURI` of the C baseline.
+fn c_canonic_path(path: &[u8], to_uri: bool) -> Option<Vec<u8>> {
+    let c_lib = libxml2_dynload::get_c_baseline();
+    let path = CString::new(path).unwrap();
+    unsafe {
+        let path = path.as_ptr() as *const xmlChar;
+        let out = if to_uri {
+            c_lib.xmlPathToURI(path)
+        } else {
+            c_lib.xmlCanonicPath(path)
+        };
+        let result = opt(out as *const c_char);
+        libc::free(out as *mut c_void);
+        result
+    }
+}
+
+#[test]
+fn test_canonic_path_baseline() {
+    assert_eq!(
+        c_canonic_path(b"/tmp/a b.xml", false).as_deref(),
+        Some(&b"/tmp/a b.xml"[..])
+    );
+    assert_eq!(
+        c_canonic_path(b"file:///tmp/a b.xml", true).as_deref(),
+        Some(&b"file:///tmp/a%20b.xml"[..])
+    );
+}
+
+#[test]
+fn test_uri_baseline() {
+    let (fields, saved) = c_parse(c"http://user@h:8/p?q#f", 0);
+    let (strings, port, _) = fields.unwrap();
+    assert_eq!(strings[0].as_deref(), Some(&b"http"[..]));
+    assert_eq!(strings[3].as_deref(), Some(&b"h"[..]));
+    assert_eq!(port, 8);
+    assert_eq!(saved.as_deref(), Some(&b"http://user@h:8/p?q#f"[..]));
+    assert_eq!(c_parse(c"a b", 0), (None, None));
+}
+
+#[cfg(feature = "rust-uri")]
+mod rust_tests {
+    use super::*;
+    use libxml2::uri::ffi;
+
+    fn rust_parse(s: &CStr, raw: c_int) -> (Fields, Option<Vec<u8>>) {
+        unsafe {
+            let uri = ffi::xmlParseURIRaw(s.as_ptr(), raw);
+            let result = fields(uri);
+            let mut saved = None;
+            if !uri.is_null() {
+                let out = ffi::xmlSaveUri(uri);
+                saved = opt(out as *const c_char);
+                xmlFree.unwrap()(out as *mut c_void);
+                ffi::xmlFreeURI(uri);
+            }
+            (result, saved)
+        }
+    }
+
+    #[test]
+    fn test_uri_matches_baseline() {
+        for reference in REFERENCES {
+            let s = CString::new(*reference).unwrap();
+            for raw in [0, 1] {
+                assert_eq!(
+                    rust_parse(&s, raw),
+                    c_parse(&s, raw),
+                    "{:?} (raw {})",
+                    reference,
+                    raw
+                );
+            }
+        }
+    }
+
+    fn rust_canonic_path(path: &[u8], to_uri: bool) -> Option<Vec<u8>> {
+        let path = CString::new(path).unwrap();
+        unsafe {
+            let path = path.as_ptr() as *const xmlChar;
+            let out = if to_uri {
+                ffi::xmlPathToURI(path)
+            } else {
+                ffi::xmlCanonicPath(path)
+            };
+            let result = opt(out as *const c_char);
+            if !out.is_null() {
+                xmlFree.unwrap()(out as *mut c_void);
+            }
+            result
+        }
+    }
+
+    #[test]
+    fn test_canonic_path_matches_baseline() {
+        for path in PATHS {
+            for to_uri in [false, true] {
+                assert_eq!(
+                    rust_canonic_path(path, to_uri),
+                    c_canonic_path(path, to_uri),
+                    "{:?} (to URI {})",
+                    String::from_utf8_lossy(path),
+                    to_uri
+                );
+            }
+        }
+        unsafe {
+            assert!(ffi::xmlCanonicPath(std::ptr::null()).is_null());
+            assert!(ffi::xmlPathToURI(std::ptr::null()).is_null());
+        }
+    }
+
+    /// Paths in the form of the platform the tests run on, drive letters
+    /// and backslashes on Windows, and as `file:` URIs.
+    #[test]
+    fn test_canonic_path_platform() {
+        let dir = std::env::temp_dir()
+            .join("canonic path")
+            .join("d\u{e9}j\u{e0}.xml");
+        let path = dir.to_str().unwrap();
+        for path in [path.to_string(), format!("file://{path}")] {
+            let rust = rust_canonic_path(path.as_bytes(), false);
+            assert_eq!(rust, c_canonic_path(path.as_bytes(), false), "{path}");
+            if !path.contains("://") {
+                assert_eq!(rust.as_deref(), Some(path.as_bytes()));
+            }
+        }
+    }
+
+    #[test]
+    fn test_uri_escape_str() {
+        let escape = |s: &CStr, list: *const xmlChar| unsafe {
+            let c_lib = libxml2_dynload::get_c_baseline();
+            let c_out = c_lib.xmlURIEscapeStr(s.as_ptr() as *const xmlChar, list);
+            let c = opt(c_out as *const c_char);
+            libc::free(c_out as *mut c_void);
+            let out = ffi::xmlURIEscapeStr(s.as_ptr() as *const xmlChar, list);
+            let rust = opt(out as *const c_char);
+            xmlFree.unwrap()(out as *mut c_void);
+            assert_eq!(rust, c, "{s:?}");
+            rust
+        };
+        assert_eq!(
+            escape(c"a b/@~", std::ptr::null()).as_deref(),
+            Some(&b"a%20b%2F@~"[..])
+        );
+        assert_eq!(
+            escape(c"a b/?", c"/ ".as_ptr() as *const xmlChar).as_deref(),
+            Some(&b"a b/%3F"[..])
+        );
+        assert_eq!(escape(c"", std::ptr::null()).as_deref(), Some(&b""[..]));
+        unsafe {
+            assert!(ffi::xmlURIEscapeStr(std::ptr::null(), std::ptr::null()).is_null());
+        }
+    }
+
+    #[test]
+    fn test_uri_parse_reference_keeps_port() {
+        unsafe {
+            let uri = ffi::xmlCreateURI();
+            assert_eq!(ffi::xmlParseURIReference(uri, c"http://h:80/".as_ptr()), 0);
+            assert_eq!(ffi::xmlParseURIReference(uri, c"rel".as_ptr()), 0);
+            assert_eq!((*uri).port, 80);
+            assert!((*uri).server.is_null());
+            assert_eq!(ffi::xmlParseURIReference(uri, std::ptr::null()), -1);
+            ffi::xmlFreeURI(uri);
+        }
+    }
+
+    #[test]
+    fn test_uri_unescape_string() {
+        unsafe {
+            let out = ffi::xmlURIUnescapeString(c"a%41%4".as_ptr(), 0, std::ptr::null_mut());
+            assert_eq!(CStr::from_ptr(out).to_bytes(), b"aA%4");
+            xmlFree.unwrap()(out as *mut c_void);
+
+            let mut target = [0 as c_char; 8];
+            let out = ffi::xmlURIUnescapeString(c"%41bc".as_ptr(), 4, target.as_mut_ptr());
+            assert_eq!(out, target.as_mut_ptr());
+            assert_eq!(CStr::from_ptr(out).to_bytes(), b"Ab");
+        }
+    }
+}
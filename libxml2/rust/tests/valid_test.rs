@@ -0,0 +1,377 @@
+//! Tests for valid module
+
+use libxml2::*;
+use std::ffi::CStr;
+use std::os::raw::{c_char, c_int, c_void};
+
+/// Defaults for elements that omit, override or already carry the
+/// attribute, `#FIXED` values, prefixed attributes and declarations for
+/// namespaces, which are not attributes of the tree.
+const DEFAULTS: &[u8] = b"<!DOCTYPE r [
+<!ELEMENT r (e|p:e|f)*>
+<!ELEMENT e EMPTY>
+<!ATTLIST e a CDATA \"default\">
+<!ATTLIST e a CDATA \"redeclared\" b CDATA #IMPLIED c NMTOKEN #FIXED \"fixed\">
+<!ATTLIST e xml:lang CDATA \"en\" p:q CDATA \"pq\" z:u CDATA \"unbound\">
+<!ATTLIST p:e a CDATA \"prefixed\" xmlns:x CDATA \"urn:x\">
+<!ATTLIST f d CDATA #REQUIRED xmlns CDATA \"urn:d\">
+]>
+<r xmlns:p=\"urn:p\">
+  <e/>
+  <e a=\"given\" xml:lang=\"fr\"/>
+  <e p:q=\"given\" c=\"fixed\"/>
+  <p:e/>
+  <f d=\"1\"/>
+</r>";
+
+/// Parse `input` with the C baseline and serialize the document element.
+fn c_parse(input: &[u8], options: c_int) -> String {
+    let c_lib = libxml2_dynload::get_c_baseline();
+    unsafe {
+        let doc = c_lib.xmlReadMemory(
+            input.as_ptr() as *const c_char,
+            input.len() as c_int,
+            std::ptr::null(),
+            std::ptr::null(),
+            options,
+        );
+        assert!(!doc.is_null());
+        let buf = c_lib.xmlBufferCreate();
+        c_lib.xmlNodeDump(buf, doc, c_lib.xmlDocGetRootElement(doc), 0, 0);
+        let out = CStr::from_ptr(c_lib.xmlBufferContent(buf) as *const c_char)
+            .to_string_lossy()
+            .into_owned();
+        c_lib.xmlBufferFree(buf);
+        c_lib.xmlFreeDoc(doc);
+        out
+    }
+}
+
+/// Notations declared and used by unparsed entities and by `NOTATION`,
+/// `ENTITY` and `ENTITIES` attributes, rightly and wrongly. The error on
+/// the document element comes before those about the DTD.
+const NOTATIONS: &[u8] = b"<!DOCTYPE r [
+<!ELEMENT r (e)*>
+<!ELEMENT e (#PCDATA)>
+<!NOTATION gif SYSTEM \"image/gif\">
+<!NOTATION png PUBLIC \"-//PNG//\" \"image/png\">
+<!ENTITY logo SYSTEM \"logo.gif\" NDATA gif>
+<!ENTITY icon SYSTEM \"icon.svg\" NDATA svg>
+<!ENTITY text \"parsed\">
+<!ATTLIST r src ENTITY #IMPLIED>
+<!ATTLIST e
+  n NOTATION (gif|png|jpeg) #IMPLIED
+  m NOTATION (gif) #IMPLIED
+  e ENTITY #IMPLIED
+  es ENTITIES #IMPLIED>
+]>
+<r src=\"text\">
+  <e n=\"gif\" e=\"logo\" es=\"logo icon\"/>
+  <e n=\"jpeg\"/>
+  <e m=\"png\" n=\"tiff\"/>
+  <e e=\"text\" es=\"  logo   missing amp \"/>
+  <e e=\"missing\"/>
+</r>";
+
+/// The validity codes about notations and unparsed entities.
+const NOTATION_CODES: &[xmlParserErrors] = &[
+    xmlParserErrors_XML_DTD_UNKNOWN_NOTATION,
+    xmlParserErrors_XML_DTD_NOTATION_VALUE,
+    xmlParserErrors_XML_DTD_UNKNOWN_ENTITY,
+    xmlParserErrors_XML_DTD_ENTITY_TYPE,
+];
+
+/// The validity errors with one of `codes`, as the C parser reports them.
+struct Collected {
+    codes: &'static [xmlParserErrors],
+    errors: Vec<(xmlParserErrors, String)>,
+}
+
+unsafe extern "C" fn collect_error(data: *mut c_void, error: *const dynamic_bindings::xmlError) {
+    let collected = &mut *(data as *mut Collected);
+    let code = (*error).code as xmlParserErrors;
+    if (*error).domain == xmlErrorDomain_XML_FROM_VALID as c_int && collected.codes.contains(&code)
+    {
+        let message = CStr::from_ptr((*error).message).to_string_lossy();
+        collected.errors.push((code, message.into_owned()));
+    }
+}
+
+/// The errors with one of `codes` the C parser reports validating `input`.
+fn c_validity_errors(
+    input: &[u8],
+    codes: &'static [xmlParserErrors],
+) -> Vec<(xmlParserErrors, String)> {
+    let c_lib = libxml2_dynload::get_c_baseline();
+    let mut collected = Collected {
+        codes,
+        errors: Vec::new(),
+    };
+    unsafe {
+        let ctxt = c_lib.xmlNewParserCtxt();
+        assert!(!ctxt.is_null());
+        c_lib.xmlCtxtSetErrorHandler(
+            ctxt,
+            Some(collect_error),
+            &mut collected as *mut _ as *mut c_void,
+        );
+        let doc = c_lib.xmlCtxtReadMemory(
+            ctxt,
+            input.as_ptr() as *const c_char,
+            input.len() as c_int,
+            std::ptr::null(),
+            std::ptr::null(),
+            xmlParserOption_XML_PARSE_DTDVALID as c_int,
+        );
+        assert!(!doc.is_null());
+        c_lib.xmlFreeDoc(doc);
+        c_lib.xmlFreeParserCtxt(ctxt);
+    }
+    collected.errors
+}
+
+#[test]
+fn test_notation_errors_baseline() {
+    let errors = c_validity_errors(NOTATIONS, NOTATION_CODES);
+    assert!(errors.contains(&(
+        xmlParserErrors_XML_DTD_UNKNOWN_NOTATION,
+        "NOTATION svg is not declared\n".to_string()
+    )));
+    assert_eq!(errors.len(), 12);
+}
+
+#[test]
+fn test_dtdattr_baseline() {
+    let quiet = (xmlParserOption_XML_PARSE_NOWARNING | xmlParserOption_XML_PARSE_NOERROR) as c_int;
+    let out = c_parse(DEFAULTS, quiet | xmlParserOption_XML_PARSE_DTDATTR as c_int);
+    assert!(out.contains("<e a=\"default\" c=\"fixed\" xml:lang=\"en\" p:q=\"pq\" z:u="));
+    assert!(!c_parse(DEFAULTS, quiet).contains("default"));
+}
+
+/// Enumerated and tokenized attributes with extra white space, values
+/// outside the enumeration, bad name tokens, a repeated ID and an `IDREF`
+/// to no ID. The tab from a character reference is not white space to
+/// normalize, so it makes its `NMTOKENS` value invalid.
+const VALUES: &[u8] = b"<!DOCTYPE r [
+<!ELEMENT r (e)*>
+<!ELEMENT e EMPTY>
+<!ATTLIST e
+  color (red|green|blue) #IMPLIED
+  tokens NMTOKENS #IMPLIED
+  tok NMTOKEN #IMPLIED
+  id ID #IMPLIED
+  ref IDREF #IMPLIED
+  refs IDREFS #IMPLIED
+  note CDATA #IMPLIED>
+]>
+<r>
+  <e id=\"a\" color=\"  red  \" tokens=\"  one   two\n three  \" note=\"  kept  \"/>
+  <e id=\" b \" color=\"purple\" tokens=\"x y! z\"/>
+  <e id=\"a\" tok=\" 1.5 \" ref=\"b\" refs=\" a   b \"/>
+  <e color=\"Red\" tok=\"two words\" ref=\"nope\" tokens=\"one&#9;two\"/>
+</r>";
+
+/// The validity codes about attribute values.
+const VALUE_CODES: &[xmlParserErrors] = &[
+    xmlParserErrors_XML_DTD_ATTRIBUTE_VALUE,
+    xmlParserErrors_XML_DTD_ID_REDEFINED,
+    xmlParserErrors_XML_DTD_UNKNOWN_ID,
+    xmlParserErrors_XML_DTD_NOT_STANDALONE,
+];
+
+#[test]
+fn test_value_errors_baseline() {
+    let errors = c_validity_errors(VALUES, VALUE_CODES);
+    assert!(errors.contains(&(
+        xmlParserErrors_XML_DTD_ATTRIBUTE_VALUE,
+        "Value \"purple\" for attribute color of e is not among the enumerated set\n".to_string()
+    )));
+    assert!(errors.contains(&(
+        xmlParserErrors_XML_DTD_ID_REDEFINED,
+        "ID a already defined\n".to_string()
+    )));
+    let quiet = (xmlParserOption_XML_PARSE_NOWARNING | xmlParserOption_XML_PARSE_NOERROR) as c_int;
+    assert!(c_parse(VALUES, quiet).contains("color=\"red\" tokens=\"one two three\""));
+}
+
+#[cfg(feature = "rust-valid")]
+mod rust_tests {
+    use super::*;
+    use libxml2::valid::{
+        add_default_attributes, normalize_attribute_value, validate_attribute_values,
+        validate_notations,
+    };
+
+    const QUIET: c_int =
+        (xmlParserOption_XML_PARSE_NOWARNING | xmlParserOption_XML_PARSE_NOERROR) as c_int;
+
+    /// Parse `input` without defaults, add them with the Rust module and
+    /// serialize the document element.
+    fn rust_parse(input: &[u8]) -> (String, usize) {
+        unsafe {
+            let doc = xmlReadMemory(
+                input.as_ptr() as *const c_char,
+                input.len() as c_int,
+                std::ptr::null(),
+                std::ptr::null(),
+                QUIET,
+            );
+            assert!(!doc.is_null());
+            let added = add_default_attributes(doc);
+            let buf = xmlBufferCreate();
+            xmlNodeDump(buf, doc, xmlDocGetRootElement(doc), 0, 0);
+            let out = CStr::from_ptr(xmlBufferContent(buf) as *const c_char)
+                .to_string_lossy()
+                .into_owned();
+            xmlBufferFree(buf);
+            xmlFreeDoc(doc);
+            (out, added)
+        }
+    }
+
+    #[test]
+    fn test_defaults_match_dtdattr() {
+        let options = QUIET | xmlParserOption_XML_PARSE_DTDATTR as c_int;
+        let (out, added) = rust_parse(DEFAULTS);
+        assert_eq!(out, c_parse(DEFAULTS, options));
+        assert_eq!(added, 12);
+    }
+
+    #[test]
+    fn test_default_filled() {
+        let input = b"<!DOCTYPE doc [
+<!ELEMENT doc (e)>
+<!ELEMENT e EMPTY>
+<!ATTLIST e a CDATA \"default\">
+]>
+<doc><e/></doc>";
+        let options = QUIET | xmlParserOption_XML_PARSE_DTDATTR as c_int;
+        let (out, _) = rust_parse(input);
+        assert_eq!(out, "<doc><e a=\"default\"/></doc>");
+        assert_eq!(out, c_parse(input, options));
+    }
+
+    #[test]
+    fn test_no_dtd() {
+        let (out, added) = rust_parse(b"<r><e a=\"1\"/></r>");
+        assert_eq!(out, "<r><e a=\"1\"/></r>");
+        assert_eq!(added, 0);
+    }
+
+    #[test]
+    fn test_notations_match_baseline() {
+        unsafe {
+            let doc = xmlReadMemory(
+                NOTATIONS.as_ptr() as *const c_char,
+                NOTATIONS.len() as c_int,
+                std::ptr::null(),
+                std::ptr::null(),
+                QUIET,
+            );
+            assert!(!doc.is_null());
+            let errors: Vec<_> = validate_notations(doc)
+                .into_iter()
+                .map(|e| (e.code, e.message))
+                .collect();
+            xmlFreeDoc(doc);
+            assert_eq!(errors, c_validity_errors(NOTATIONS, NOTATION_CODES));
+        }
+    }
+
+    #[test]
+    fn test_undeclared_notation() {
+        let input = b"<!DOCTYPE doc [
+<!NOTATION gif SYSTEM \"gif\">
+<!ENTITY pic SYSTEM \"pic.png\" NDATA png>
+<!ATTLIST doc fmt NOTATION (gif|png) #IMPLIED src ENTITY #IMPLIED>
+]>
+<doc fmt=\"png\" src=\"pic\"/>";
+        unsafe {
+            let doc = xmlReadMemory(
+                input.as_ptr() as *const c_char,
+                input.len() as c_int,
+                std::ptr::null(),
+                std::ptr::null(),
+                QUIET,
+            );
+            let errors: Vec<_> = validate_notations(doc)
+                .into_iter()
+                .map(|e| (e.code, e.message))
+                .collect();
+            xmlFreeDoc(doc);
+            assert_eq!(errors[2].1, "NOTATION png is not declared\n");
+            assert_eq!(errors, c_validity_errors(input, NOTATION_CODES));
+        }
+    }
+
+    #[test]
+    fn test_values_match_baseline() {
+        unsafe {
+            let doc = xmlReadMemory(
+                VALUES.as_ptr() as *const c_char,
+                VALUES.len() as c_int,
+                std::ptr::null(),
+                std::ptr::null(),
+                QUIET,
+            );
+            assert!(!doc.is_null());
+            let errors: Vec<_> = validate_attribute_values(doc)
+                .into_iter()
+                .map(|e| (e.code, e.message))
+                .collect();
+            xmlFreeDoc(doc);
+            assert_eq!(errors, c_validity_errors(VALUES, VALUE_CODES));
+        }
+        // The parser normalizes declared values itself, validating or not.
+        let options = QUIET | xmlParserOption_XML_PARSE_DTDVALID as c_int;
+        assert_eq!(c_parse(VALUES, QUIET), c_parse(VALUES, options));
+    }
+
+    #[test]
+    fn test_normalize_matches_baseline() {
+        let c_lib = libxml2_dynload::get_c_baseline();
+        unsafe {
+            let doc = xmlReadMemory(
+                VALUES.as_ptr() as *const c_char,
+                VALUES.len() as c_int,
+                std::ptr::null(),
+                std::ptr::null(),
+                QUIET,
+            );
+            let elem = (*xmlDocGetRootElement(doc)).children;
+            let vctxt = c_lib.xmlNewValidCtxt();
+            for (name, value) in [
+                ("color", "  red  "),
+                ("tokens", " one   two  three "),
+                ("tokens", "one\ttwo"),
+                ("tok", "   "),
+                ("id", "a"),
+                ("refs", "  a  b"),
+                ("note", "  kept  "),
+                ("undeclared", " x "),
+            ] {
+                let cname = std::ffi::CString::new(name).unwrap();
+                let cvalue = std::ffi::CString::new(value).unwrap();
+                let normalized = c_lib.xmlValidCtxtNormalizeAttributeValue(
+                    vctxt,
+                    doc as *mut _,
+                    elem as *mut _,
+                    cname.as_ptr() as *const xmlChar,
+                    cvalue.as_ptr() as *const xmlChar,
+                );
+                let expected = (!normalized.is_null()).then(|| {
+                    let value = CStr::from_ptr(normalized as *const c_char)
+                        .to_bytes()
+                        .to_vec();
+                    libc::free(normalized as *mut c_void);
+                    value
+                });
+                let actual =
+                    normalize_attribute_value(doc, elem, name.as_bytes(), value.as_bytes());
+                assert_eq!(actual, expected, "{name}={value:?}");
+            }
+            c_lib.xmlFreeValidCtxt(vctxt);
+            xmlFreeDoc(doc);
+        }
+    }
+}
@@ -0,0 +1,382 @@
+//! Tests for xmlmemory module
+
+use libxml2::libxml2_dynload;
+use std::cell::Cell;
+use std::os::raw::{c_char, c_int, c_void};
+use std::sync::Mutex;
+
+/// The allocation functions are global; tests replacing them take turns.
+static SETUP: Mutex<()> = Mutex::new(());
+
+const DOC: &str = "<?xml version='1.0'?>\n\
+<doc a='1' xmlns:p='urn:p'><p:item>some text</p:item><!-- c --><item b='&amp;'/></doc>";
+
+thread_local! {
+    /// Blocks allocated and freed on this thread by the counting functions.
+    static COUNTS: Cell<(usize, usize)> = const { Cell::new((0, 0)) };
+}
+
+fn count(allocated: usize, freed: usize) {
+    COUNTS.with(|counts| {
+        let (a, f) = counts.get();
+        counts.set((a + allocated, f + freed));
+    });
+}
+
+unsafe extern "C" fn counting_malloc(size: usize) -> *mut c_void {
+    count(1, 0);
+    libc::malloc(size)
+}
+
+unsafe extern "C" fn counting_realloc(ptr: *mut c_void, size: usize) -> *mut c_void {
+    count(ptr.is_null() as usize, 0);
+    libc::realloc(ptr, size)
+}
+
+unsafe extern "C" fn counting_free(ptr: *mut c_void) {
+    count(0, !ptr.is_null() as usize);
+    libc::free(ptr)
+}
+
+unsafe extern "C" fn counting_strdup(s: *const c_char) -> *mut c_char {
+    count(1, 0);
+    libc::strdup(s)
+}
+
+/// The blocks allocated and freed on this thread while running `f`.
+fn counted(f: impl FnOnce()) -> (usize, usize) {
+    COUNTS.with(|counts| counts.set((0, 0)));
+    f();
+    COUNTS.with(|counts| counts.get())
+}
+
+/// The blocks the C baseline allocates and frees parsing `DOC` and
+/// freeing the document with the counting functions set up.
+fn c_parse_counted() -> (usize, usize) {
+    let c_lib = libxml2_dynload::get_c_baseline();
+    unsafe {
+        c_lib.xmlInitParser();
+        let (mut free, mut malloc, mut realloc, mut strdup) = (None, None, None, None);
+        c_lib.xmlMemGet(&mut free, &mut malloc, &mut realloc, &mut strdup);
+        let ret = c_lib.xmlMemSetup(
+            Some(counting_free),
+            Some(counting_malloc),
+            Some(counting_realloc),
+            Some(counting_strdup),
+        );
+        assert_eq!(ret, 0);
+        let counts = counted(|| {
+            let doc = c_lib.xmlReadMemory(
+                DOC.as_ptr() as *const c_char,
+                DOC.len() as c_int,
+                std::ptr::null(),
+                std::ptr::null(),
+                0,
+            );
+            assert!(!doc.is_null());
+            c_lib.xmlFreeDoc(doc);
+        });
+        c_lib.xmlMemSetup(free, malloc, realloc, strdup);
+        counts
+    }
+}
+
+#[test]
+fn test_counting_functions_baseline() {
+    let _setup = SETUP.lock().unwrap();
+    let (allocated, freed) = c_parse_counted();
+    assert!(allocated > 0);
+    assert_eq!(allocated, freed);
+}
+
+#[cfg(feature = "rust-xmlmemory")]
+mod rust_tests {
+    use super::*;
+    use libxml2::*;
+
+    type Get = unsafe extern "C" fn(
+        *mut xmlFreeFunc,
+        *mut xmlMallocFunc,
+        *mut xmlReallocFunc,
+        *mut xmlStrdupFunc,
+    ) -> c_int;
+    type Setup =
+        unsafe extern "C" fn(xmlFreeFunc, xmlMallocFunc, xmlReallocFunc, xmlStrdupFunc) -> c_int;
+
+    /// The functions `get` gives, as addresses.
+    unsafe fn addresses(get: Get) -> [usize; 4] {
+        let (mut free, mut malloc, mut realloc, mut strdup) = (None, None, None, None);
+        assert_eq!(get(&mut free, &mut malloc, &mut realloc, &mut strdup), 0);
+        [
+            free.map_or(0, |f| f as usize),
+            malloc.map_or(0, |f| f as usize),
+            realloc.map_or(0, |f| f as usize),
+            strdup.map_or(0, |f| f as usize),
+        ]
+    }
+
+    /// The results of setting up counting functions, then the same with
+    /// `free` missing, and what `get` gives back after each.
+    unsafe fn setup_script(setup: Setup, get: Get) -> Vec<(c_int, [usize; 4])> {
+        let mut out = Vec::new();
+        let ret = setup(
+            Some(counting_free),
+            Some(counting_malloc),
+            Some(counting_realloc),
+            Some(counting_strdup),
+        );
+        out.push((ret, addresses(get)));
+        let ret = setup(
+            None,
+            Some(libc::malloc),
+            Some(libc::realloc),
+            Some(libc::strdup),
+        );
+        out.push((ret, addresses(get)));
+        out
+    }
+
+    #[test]
+    fn test_mem_setup_matches_baseline() {
+        let _setup = SETUP.lock().unwrap();
+        let c_lib = libxml2_dynload::get_c_baseline();
+        unsafe {
+            let before = mem_functions();
+            let c_before = {
+                let (mut free, mut malloc, mut realloc, mut strdup) = (None, None, None, None);
+                c_lib.xmlMemGet(&mut free, &mut malloc, &mut realloc, &mut strdup);
+                (free, malloc, realloc, strdup)
+            };
+
+            let c = setup_script(
+                *c_lib.xmlMemSetup.as_ref().unwrap(),
+                *c_lib.xmlMemGet.as_ref().unwrap(),
+            );
+            let rust = setup_script(xmlMemSetup, xmlMemGet);
+            assert_eq!(rust, c);
+            assert_eq!(rust[0].0, 0);
+            assert_eq!(rust[1].0, -1);
+            assert_eq!(rust[1].1, rust[0].1);
+
+            // `xmlMemSetup` uses `malloc` for atomic blocks too.
+            let counting = counting_malloc as unsafe extern "C" fn(usize) -> *mut c_void;
+            let functions = mem_functions();
+            assert_eq!(
+                functions.malloc_atomic.map(|f| f as usize),
+                Some(counting as usize)
+            );
+            let mut atomic = None;
+            xmlGcMemGet(
+                std::ptr::null_mut(),
+                std::ptr::null_mut(),
+                &mut atomic,
+                std::ptr::null_mut(),
+                std::ptr::null_mut(),
+            );
+            assert_eq!(atomic.map(|f| f as usize), Some(counting as usize));
+
+            assert!(set_mem_functions(before));
+            c_lib.xmlMemSetup(c_before.0, c_before.1, c_before.2, c_before.3);
+        }
+    }
+
+    /// `xmlMemUsed` and `xmlMemBlocks` after each step of a script of
+    /// debug allocations, relative to before it.
+    unsafe fn debug_script(
+        malloc: unsafe extern "C" fn(usize) -> *mut c_void,
+        realloc: unsafe extern "C" fn(*mut c_void, usize) -> *mut c_void,
+        free: unsafe extern "C" fn(*mut c_void),
+        strdup: unsafe extern "C" fn(*const c_char) -> *mut c_char,
+        size: unsafe extern "C" fn(*mut c_void) -> usize,
+        used: unsafe extern "C" fn() -> c_int,
+        blocks: unsafe extern "C" fn() -> c_int,
+    ) -> Vec<(c_int, c_int, usize)> {
+        let (used0, blocks0) = (used(), blocks());
+        let state = |n: usize| (used() - used0, blocks() - blocks0, n);
+        let mut out = Vec::new();
+        let a = malloc(10);
+        out.push(state(size(a)));
+        let a = realloc(a, 100);
+        out.push(state(size(a)));
+        let b = strdup(c"a copied string".as_ptr());
+        out.push(state(size(b as *mut c_void)));
+        assert_eq!(libc::strcmp(b, c"a copied string".as_ptr()), 0);
+        let c = realloc(std::ptr::null_mut(), 7);
+        out.push(state(size(c)));
+        free(a);
+        out.push(state(size(std::ptr::null_mut())));
+        free(std::ptr::null_mut());
+        free(b as *mut c_void);
+        free(c);
+        out.push(state(0));
+        out
+    }
+
+    #[test]
+    fn test_debug_blocks_match_baseline() {
+        let c_lib = libxml2_dynload::get_c_baseline();
+        unsafe {
+            let c = debug_script(
+                *c_lib.xmlMemMalloc.as_ref().unwrap(),
+                *c_lib.xmlMemRealloc.as_ref().unwrap(),
+                *c_lib.xmlMemFree.as_ref().unwrap(),
+                *c_lib.xmlMemoryStrdup.as_ref().unwrap(),
+                *c_lib.xmlMemSize.as_ref().unwrap(),
+                *c_lib.xmlMemUsed.as_ref().unwrap(),
+                *c_lib.xmlMemBlocks.as_ref().unwrap(),
+            );
+            let rust = debug_script(
+                xmlMemMalloc,
+                xmlMemRealloc,
+                xmlMemFree,
+                xmlMemoryStrdup,
+                xmlMemSize,
+                xmlMemUsed,
+                xmlMemBlocks,
+            );
+            assert_eq!(rust, c);
+            assert_eq!(rust[2], (116, 2, 16));
+            assert_eq!(rust.last(), Some(&(0, 0, 0)));
+        }
+    }
+
+    /// Counts into `COUNTS` like the counting functions.
+    struct CountingAllocator;
+
+    impl Allocator for CountingAllocator {
+        fn malloc(&self, size: usize) -> *mut c_void {
+            unsafe { counting_malloc(size) }
+        }
+
+        unsafe fn realloc(&self, ptr: *mut c_void, size: usize) -> *mut c_void {
+            counting_realloc(ptr, size)
+        }
+
+        unsafe fn free(&self, ptr: *mut c_void) {
+            counting_free(ptr)
+        }
+    }
+
+    #[test]
+    fn test_allocator_matches_baseline() {
+        let _setup = SETUP.lock().unwrap();
+        let c_counts = c_parse_counted();
+        unsafe {
+            xmlInitParser();
+            let before = mem_functions();
+            // The counting allocator takes blocks from `malloc`, like
+            // the default functions.
+            set_allocator(Box::new(CountingAllocator));
+            let counts = counted(|| {
+                let doc = xmlReadMemory(
+                    DOC.as_ptr() as *const c_char,
+                    DOC.len() as c_int,
+                    std::ptr::null(),
+                    std::ptr::null(),
+                    0,
+                );
+                assert!(!doc.is_null());
+                xmlFreeDoc(doc);
+            });
+            assert!(set_mem_functions(before));
+
+            assert!(counts.0 > 0);
+            assert_eq!(counts.0, counts.1);
+            assert!(c_counts.0 > 0);
+            assert_eq!(c_counts.0, c_counts.1);
+        }
+    }
+
+    fn dump(doc: &XmlDoc) -> String {
+        let mut out = Vec::new();
+        debug_dump(doc, &mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_arena_parse() {
+        let _setup = SETUP.lock().unwrap();
+        let expected = dump(&XmlDoc::parse_memory(DOC.as_bytes(), 0).unwrap());
+
+        unsafe { Arena::install() };
+        let arena = Arena::new();
+        let docs: Vec<_> = (0..100)
+            .map(|_| XmlDoc::parse_memory_in(&arena, DOC.as_bytes(), 0).unwrap())
+            .collect();
+        let allocated = arena.allocated();
+        assert!(allocated > 0);
+        for doc in &docs {
+            assert_eq!(dump(doc), expected);
+        }
+
+        unsafe {
+            // Freeing a node of the arena leaves its memory alone.
+            let root = docs[0].root().unwrap().as_ptr();
+            let first = (*root).children;
+            xmlUnlinkNode(first);
+            xmlFreeNode(first);
+            assert_eq!(docs[0].root().unwrap().children().count(), 2);
+            assert_eq!(dump(&docs[1]), expected);
+
+            // A node added out of the arena's scope is on the heap, and
+            // goes with the document.
+            let added = xmlNewChild(
+                root,
+                std::ptr::null_mut(),
+                c"added".as_ptr() as *const _,
+                c"text".as_ptr() as *const _,
+            );
+            assert!(!added.is_null());
+            assert_eq!(arena.allocated(), allocated);
+            assert_eq!(
+                docs[0].root().unwrap().children().last().unwrap().name(),
+                Some("added")
+            );
+        }
+        drop(docs);
+
+        // A failed parse leaves no error pointing into the arena.
+        let err =
+            XmlDoc::parse_memory_in(&arena, b"<doc>", xmlParserOption_XML_PARSE_NOERROR as c_int)
+                .unwrap_err();
+        assert_eq!(err.code, xmlParserErrors_XML_ERR_TAG_NOT_FINISHED as c_int);
+
+        // A reset arena parses the same documents into one chunk.
+        let mut arena = arena;
+        arena.reset();
+        assert_eq!(arena.allocated(), 0);
+        let doc = XmlDoc::parse_memory_in(&arena, DOC.as_bytes(), 0).unwrap();
+        assert_eq!(dump(&doc), expected);
+        drop(doc);
+        drop(arena);
+        unsafe {
+            assert!(xmlGetLastError().is_null());
+        }
+
+        // Outside of any arena, parsing allocates from the heap again.
+        assert_eq!(
+            dump(&XmlDoc::parse_memory(DOC.as_bytes(), 0).unwrap()),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_nested_arenas() {
+        let _setup = SETUP.lock().unwrap();
+        unsafe { Arena::install() };
+        let outer = Arena::new();
+        let inner = Arena::new();
+        unsafe {
+            outer.scope(|| {
+                let doc = XmlDoc::parse_memory_in(&inner, DOC.as_bytes(), 0).unwrap();
+                assert_eq!(outer.allocated(), 0);
+                assert!(inner.allocated() > 0);
+                // Back in the outer arena.
+                let copy = xmlStrdup(c"copy".as_ptr() as *const _);
+                assert!(outer.allocated() > 0);
+                xmlFree.unwrap()(copy as *mut c_void);
+                drop(doc);
+            });
+        }
+    }
+}
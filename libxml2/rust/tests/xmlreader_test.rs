@@ -0,0 +1,460 @@
+//! Tests for xmlreader module
+
+use libxml2::*;
+use std::ffi::CStr;
+use std::os::raw::{c_char, c_int};
+
+const SAMPLE: &[u8] = b"<?xml version=\"1.0\"?>
+<!DOCTYPE doc [<!ENTITY e \"entity text\">]>
+<doc xmlns:p=\"urn:p\" a=\"1\">
+  <p:item id=\"1\"/>
+  <item>text &amp; more</item>
+  <empty></empty>
+  <!-- a comment -->
+  <?pi data?>
+  <mixed>one<b>two</b>three<![CDATA[<raw>]]></mixed>
+  <e>&e;</e>
+</doc>
+";
+
+unsafe fn name_of(name: *const xmlChar) -> String {
+    if name.is_null() {
+        return String::new();
+    }
+    CStr::from_ptr(name as *const c_char)
+        .to_string_lossy()
+        .into_owned()
+}
+
+/// Walk SAMPLE with the C baseline reader.
+fn c_events(input: &[u8], options: c_int) -> (Vec<(c_int, String)>, c_int) {
+    let c_lib = libxml2_dynload::get_c_baseline();
+    let mut events = Vec::new();
+    unsafe {
+        let reader = c_lib.xmlReaderForMemory(
+            input.as_ptr() as *const c_char,
+            input.len() as c_int,
+            std::ptr::null(),
+            std::ptr::null(),
+            options,
+        );
+        assert!(!reader.is_null());
+        let mut ret = c_lib.xmlTextReaderRead(reader);
+        while ret == 1 {
+            events.push((
+                c_lib.xmlTextReaderNodeType(reader),
+                name_of(c_lib.xmlTextReaderConstName(reader)),
+            ));
+            ret = c_lib.xmlTextReaderRead(reader);
+        }
+        c_lib.xmlFreeTextReader(reader);
+        (events, ret)
+    }
+}
+
+#[test]
+fn test_reader_walks_document() {
+    let (events, ret) = c_events(SAMPLE, 0);
+    assert_eq!(ret, 0);
+    assert_eq!(events.first(), Some(&(10, "doc".to_string())));
+    assert_eq!(events.last(), Some(&(15, "doc".to_string())));
+}
+
+const EXPAND_SAMPLE: &[u8] = b"<list>
+  <item n=\"1\">first</item>
+  <target id=\"t\"><a>one</a><!-- c --><b x=\"&lt;\">two &amp; three</b><c/></target>
+  <item n=\"2\">last</item>
+</list>";
+
+/// Expand the `target` element with the C baseline reader and serialize it.
+fn c_expand_target() -> String {
+    let c_lib = libxml2_dynload::get_c_baseline();
+    unsafe {
+        let reader = c_lib.xmlReaderForMemory(
+            EXPAND_SAMPLE.as_ptr() as *const c_char,
+            EXPAND_SAMPLE.len() as c_int,
+            std::ptr::null(),
+            std::ptr::null(),
+            0,
+        );
+        let mut out = String::new();
+        while c_lib.xmlTextReaderRead(reader) == 1 {
+            if name_of(c_lib.xmlTextReaderConstName(reader)) != "target" {
+                continue;
+            }
+            let node = c_lib.xmlTextReaderExpand(reader);
+            assert!(!node.is_null());
+            let buf = c_lib.xmlBufferCreate();
+            c_lib.xmlNodeDump(buf, (*node).doc, node, 0, 0);
+            out = name_of(c_lib.xmlBufferContent(buf));
+            c_lib.xmlBufferFree(buf);
+            break;
+        }
+        c_lib.xmlFreeTextReader(reader);
+        out
+    }
+}
+
+#[test]
+fn test_reader_expand_baseline() {
+    assert!(c_expand_target().starts_with("<target id=\"t\"><a>one</a>"));
+}
+
+const ATTR_SAMPLE: &[u8] = b"<!DOCTYPE r [<!ENTITY e \"ent\">]>
+<r xmlns=\"urn:d\" xmlns:p=\"urn:p\" a=\"1\" p:b=\"2\">
+  <c p:x=\"y\" x=\"&amp;&e;z\" xmlns:q=\"urn:q\" q:x=\"w\"/>
+  <d xmlns=\"\" e=\"\"/>
+  <f>text</f>
+</r>";
+
+/// Names looked up with `xmlTextReaderGetAttribute` on every element.
+const ATTR_NAMES: &[&CStr] = &[
+    c"xmlns", c"xmlns:p", c"xmlns:q", c"xmlns:z", c"a", c"p:b", c"b", c"x", c"p:x", c"q:x", c"z:x",
+    c"e", c":x", c"x:",
+];
+
+/// An element and what the reader reports about its attributes: node
+/// type, name, value and depth when moving through them, the values by
+/// index and by name, and what moving back to the element returns.
+type AttrEvent = (
+    String,
+    Vec<(c_int, String, String, c_int)>,
+    Vec<Option<String>>,
+    Vec<Option<String>>,
+    c_int,
+);
+
+/// Take a string returned by the C baseline.
+unsafe fn c_owned(s: *mut xmlChar) -> Option<String> {
+    if s.is_null() {
+        return None;
+    }
+    let ret = name_of(s);
+    libc::free(s as *mut libc::c_void);
+    Some(ret)
+}
+
+/// Walk the attributes of every element with the C baseline reader.
+fn c_attributes(input: &[u8], options: c_int) -> Vec<AttrEvent> {
+    let c_lib = libxml2_dynload::get_c_baseline();
+    let mut out = Vec::new();
+    unsafe {
+        let reader = c_lib.xmlReaderForMemory(
+            input.as_ptr() as *const c_char,
+            input.len() as c_int,
+            std::ptr::null(),
+            std::ptr::null(),
+            options,
+        );
+        assert!(!reader.is_null());
+        while c_lib.xmlTextReaderRead(reader) == 1 {
+            if c_lib.xmlTextReaderNodeType(reader) != 1 {
+                continue;
+            }
+            let name = name_of(c_lib.xmlTextReaderConstName(reader));
+            let mut attrs = Vec::new();
+            while c_lib.xmlTextReaderMoveToNextAttribute(reader) == 1 {
+                attrs.push((
+                    c_lib.xmlTextReaderNodeType(reader),
+                    name_of(c_lib.xmlTextReaderConstName(reader)),
+                    name_of(c_lib.xmlTextReaderConstValue(reader)),
+                    c_lib.xmlTextReaderDepth(reader),
+                ));
+            }
+            let back = c_lib.xmlTextReaderMoveToElement(reader);
+            let by_index = (0..=attrs.len() as c_int)
+                .map(|no| c_owned(c_lib.xmlTextReaderGetAttributeNo(reader, no)))
+                .collect();
+            let by_name = ATTR_NAMES
+                .iter()
+                .map(|n| {
+                    c_owned(c_lib.xmlTextReaderGetAttribute(reader, n.as_ptr() as *const xmlChar))
+                })
+                .collect();
+            out.push((name, attrs, by_index, by_name, back));
+        }
+        c_lib.xmlFreeTextReader(reader);
+    }
+    out
+}
+
+#[test]
+fn test_reader_attributes_baseline() {
+    let events = c_attributes(ATTR_SAMPLE, 0);
+    let (name, attrs, _, _, back) = &events[0];
+    assert_eq!(name, "r");
+    assert_eq!(attrs[0], (2, "xmlns".to_string(), "urn:d".to_string(), 1));
+    assert_eq!(attrs[1].1, "xmlns:p");
+    assert_eq!(*back, 1);
+}
+
+#[cfg(feature = "rust-xmlreader")]
+mod rust_tests {
+    use super::*;
+    use libxml2::xmlreader::ffi;
+
+    /// Walk `input` with the Rust reader.
+    fn rust_events(input: &[u8], options: c_int) -> (Vec<(c_int, String)>, c_int) {
+        let mut events = Vec::new();
+        unsafe {
+            let reader = ffi::xmlReaderForMemory(
+                input.as_ptr() as *const c_char,
+                input.len() as c_int,
+                std::ptr::null(),
+                std::ptr::null(),
+                options,
+            );
+            assert!(!reader.is_null());
+            let mut ret = ffi::xmlTextReaderRead(reader);
+            while ret == 1 {
+                events.push((
+                    ffi::xmlTextReaderNodeType(reader),
+                    name_of(ffi::xmlTextReaderConstName(reader)),
+                ));
+                ret = ffi::xmlTextReaderRead(reader);
+            }
+            ffi::xmlFreeTextReader(reader);
+            (events, ret)
+        }
+    }
+
+    #[test]
+    fn test_reader_sequence_matches_baseline() {
+        let noent = xmlParserOption_XML_PARSE_NOENT as c_int;
+        for options in [0, noent] {
+            let c_out = c_events(SAMPLE, options);
+            let rust_out = rust_events(SAMPLE, options);
+            assert_eq!(rust_out, c_out, "options={}", options);
+        }
+    }
+
+    #[test]
+    fn test_reader_large_document_matches_baseline() {
+        // Spans many parser chunks so nodes are freed while reading.
+        let mut input = b"<root>".to_vec();
+        for i in 0..500 {
+            input.extend_from_slice(format!("<n i=\"{}\">v{}<c/></n>\n", i, i).as_bytes());
+        }
+        input.extend_from_slice(b"</root>");
+
+        assert_eq!(rust_events(&input, 0), c_events(&input, 0));
+    }
+
+    #[test]
+    fn test_reader_malformed_matches_baseline() {
+        let input = b"<a><b>text</a>";
+        let (c_seq, c_ret) = c_events(input, 0);
+        let (rust_seq, rust_ret) = rust_events(input, 0);
+        assert_eq!(rust_ret, -1);
+        assert_eq!(rust_ret, c_ret);
+        assert_eq!(rust_seq, c_seq);
+    }
+
+    #[test]
+    fn test_reader_accessors() {
+        let input = b"<r><a/><b>hi</b></r>";
+        unsafe {
+            let reader = ffi::xmlReaderForMemory(
+                input.as_ptr() as *const c_char,
+                input.len() as c_int,
+                std::ptr::null(),
+                std::ptr::null(),
+                0,
+            );
+
+            assert_eq!(ffi::xmlTextReaderRead(reader), 1);
+            assert_eq!(ffi::xmlTextReaderDepth(reader), 0);
+            assert_eq!(ffi::xmlTextReaderHasValue(reader), 0);
+
+            assert_eq!(ffi::xmlTextReaderRead(reader), 1);
+            assert_eq!(name_of(ffi::xmlTextReaderConstName(reader)), "a");
+            assert_eq!(ffi::xmlTextReaderIsEmptyElement(reader), 1);
+            assert_eq!(ffi::xmlTextReaderDepth(reader), 1);
+
+            assert_eq!(ffi::xmlTextReaderRead(reader), 1);
+            assert_eq!(ffi::xmlTextReaderIsEmptyElement(reader), 0);
+
+            assert_eq!(ffi::xmlTextReaderRead(reader), 1);
+            assert_eq!(ffi::xmlTextReaderNodeType(reader), 3);
+            assert_eq!(ffi::xmlTextReaderDepth(reader), 2);
+            assert_eq!(ffi::xmlTextReaderHasValue(reader), 1);
+            let value = ffi::xmlTextReaderValue(reader);
+            assert_eq!(name_of(value), "hi");
+            xmlFree.unwrap()(value as *mut std::os::raw::c_void);
+
+            assert_eq!(ffi::xmlTextReaderClose(reader), 0);
+            assert_eq!(ffi::xmlTextReaderRead(reader), -1);
+            ffi::xmlFreeTextReader(reader);
+        }
+    }
+
+    #[test]
+    fn test_reader_expand_matches_baseline() {
+        unsafe {
+            let reader = ffi::xmlReaderForMemory(
+                EXPAND_SAMPLE.as_ptr() as *const c_char,
+                EXPAND_SAMPLE.len() as c_int,
+                std::ptr::null(),
+                std::ptr::null(),
+                0,
+            );
+            let mut out = String::new();
+            while ffi::xmlTextReaderRead(reader) == 1 {
+                if name_of(ffi::xmlTextReaderConstName(reader)) != "target" {
+                    continue;
+                }
+                let node = ffi::xmlTextReaderExpand(reader);
+                assert!(!node.is_null());
+                let buf = xmlBufferCreate();
+                xmlNodeDump(buf, (*node).doc, node, 0, 0);
+                out = name_of(xmlBufferContent(buf));
+                xmlBufferFree(buf);
+
+                // The reader keeps streaming past the expanded subtree.
+                assert_eq!(ffi::xmlTextReaderRead(reader), 1);
+                assert_eq!(name_of(ffi::xmlTextReaderConstName(reader)), "a");
+                assert_eq!(ffi::xmlTextReaderRead(reader), 1);
+                assert_eq!(name_of(ffi::xmlTextReaderConstName(reader)), "#text");
+                break;
+            }
+            ffi::xmlFreeTextReader(reader);
+
+            assert_eq!(out, c_expand_target());
+        }
+    }
+
+    #[test]
+    fn test_reader_expand_unpositioned() {
+        let input = b"<r/>";
+        unsafe {
+            let reader = ffi::xmlReaderForMemory(
+                input.as_ptr() as *const c_char,
+                input.len() as c_int,
+                std::ptr::null(),
+                std::ptr::null(),
+                0,
+            );
+            assert!(ffi::xmlTextReaderExpand(reader).is_null());
+            ffi::xmlFreeTextReader(reader);
+            assert!(ffi::xmlTextReaderExpand(std::ptr::null_mut()).is_null());
+        }
+    }
+
+    /// Take a string returned by the Rust reader.
+    unsafe fn rust_owned(s: *mut xmlChar) -> Option<String> {
+        if s.is_null() {
+            return None;
+        }
+        let ret = name_of(s);
+        xmlFree.unwrap()(s as *mut std::os::raw::c_void);
+        Some(ret)
+    }
+
+    /// Walk the attributes of every element with the Rust reader.
+    fn rust_attributes(input: &[u8], options: c_int) -> Vec<AttrEvent> {
+        let mut out = Vec::new();
+        unsafe {
+            let reader = ffi::xmlReaderForMemory(
+                input.as_ptr() as *const c_char,
+                input.len() as c_int,
+                std::ptr::null(),
+                std::ptr::null(),
+                options,
+            );
+            assert!(!reader.is_null());
+            while ffi::xmlTextReaderRead(reader) == 1 {
+                if ffi::xmlTextReaderNodeType(reader) != 1 {
+                    continue;
+                }
+                let name = name_of(ffi::xmlTextReaderConstName(reader));
+                let mut attrs = Vec::new();
+                while ffi::xmlTextReaderMoveToNextAttribute(reader) == 1 {
+                    attrs.push((
+                        ffi::xmlTextReaderNodeType(reader),
+                        name_of(ffi::xmlTextReaderConstName(reader)),
+                        name_of(ffi::xmlTextReaderConstValue(reader)),
+                        ffi::xmlTextReaderDepth(reader),
+                    ));
+                }
+                let back = ffi::xmlTextReaderMoveToElement(reader);
+                let by_index = (0..=attrs.len() as c_int)
+                    .map(|no| rust_owned(ffi::xmlTextReaderGetAttributeNo(reader, no)))
+                    .collect();
+                let by_name = ATTR_NAMES
+                    .iter()
+                    .map(|n| {
+                        rust_owned(ffi::xmlTextReaderGetAttribute(
+                            reader,
+                            n.as_ptr() as *const xmlChar,
+                        ))
+                    })
+                    .collect();
+                out.push((name, attrs, by_index, by_name, back));
+            }
+            ffi::xmlFreeTextReader(reader);
+        }
+        out
+    }
+
+    #[test]
+    fn test_reader_attributes_match_baseline() {
+        let noent = xmlParserOption_XML_PARSE_NOENT as c_int;
+        for input in [ATTR_SAMPLE, SAMPLE] {
+            for options in [0, noent] {
+                let rust_out = rust_attributes(input, options);
+                assert_eq!(
+                    rust_out,
+                    c_attributes(input, options),
+                    "options={}",
+                    options
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_reader_attributes_large_document() {
+        let mut input = b"<root xmlns:n=\"urn:n\">".to_vec();
+        for i in 0..300 {
+            input.extend_from_slice(
+                format!(
+                    "<n:e n:i=\"{}\" xmlns:m=\"urn:m{}\" v=\"&lt;{}\"/>\n",
+                    i, i, i
+                )
+                .as_bytes(),
+            );
+        }
+        input.extend_from_slice(b"</root>");
+
+        assert_eq!(rust_attributes(&input, 0), c_attributes(&input, 0));
+    }
+
+    #[test]
+    fn test_reader_attribute_position() {
+        let input = b"<r a=\"1\"><s/></r>";
+        unsafe {
+            let reader = ffi::xmlReaderForMemory(
+                input.as_ptr() as *const c_char,
+                input.len() as c_int,
+                std::ptr::null(),
+                std::ptr::null(),
+                0,
+            );
+            assert_eq!(ffi::xmlTextReaderMoveToFirstAttribute(reader), -1);
+            assert_eq!(ffi::xmlTextReaderRead(reader), 1);
+            assert_eq!(ffi::xmlTextReaderMoveToFirstAttribute(reader), 1);
+            assert_eq!(ffi::xmlTextReaderHasValue(reader), 1);
+            // Lookups only work from the element.
+            assert!(ffi::xmlTextReaderGetAttributeNo(reader, 0).is_null());
+            assert_eq!(ffi::xmlTextReaderMoveToNextAttribute(reader), 0);
+
+            // Reading on leaves the attribute.
+            assert_eq!(ffi::xmlTextReaderRead(reader), 1);
+            assert_eq!(name_of(ffi::xmlTextReaderConstName(reader)), "s");
+            assert_eq!(ffi::xmlTextReaderDepth(reader), 1);
+            assert_eq!(ffi::xmlTextReaderMoveToFirstAttribute(reader), 0);
+            assert_eq!(ffi::xmlTextReaderMoveToElement(reader), 0);
+            ffi::xmlFreeTextReader(reader);
+        }
+    }
+}
@@ -0,0 +1,467 @@
+//! Tests for xmlsave module
+
+use libxml2::*;
+use std::os::raw::{c_char, c_int};
+
+/// Documents the serializers must write byte for byte alike, covering
+/// namespaces, CDATA, comments, PIs, non-ASCII text with and without a
+/// declared encoding, escaping and the internal subset.
+const DOCUMENTS: &[&str] = &[
+    "<r/>",
+    "<?xml version=\"1.0\" standalone=\"yes\"?><r><a></a><b/><c> </c></r>",
+    "<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"no\"?><r/>",
+    // Namespaces: default, prefixed, redeclared, undeclared and xml:
+    "<r xmlns='urn:d' xmlns:p='urn:p'><p:e p:a='1' b='2' xml:lang='en'/>\
+     <e xmlns='' xmlns:p='urn:q'><p:f/></e><p:g xmlns:p='urn:&amp;&#233;'/></r>",
+    // CDATA, with markup, empty, next to text and with a lone ]]
+    "<r><![CDATA[a < b && c > d]]><![CDATA[]]>x<![CDATA[]]]]>y<![CDATA[]>]]></r>",
+    // Comments and PIs, in and around the root
+    "<?top data?><!-- before --><r><?empty?><?pi  a b ?><!----><!-- - --></r><!-- after --><?end?>",
+    // Non-ASCII without an encoding is written as references
+    "<r a='é€😀'>café € 😀 \u{FFFD} \u{10FFFF}<é/></r>",
+    "<?xml version='1.0' encoding='UTF-8'?><r a='é€😀'>café € 😀 \u{10FFFF}<é/></r>",
+    "<?xml version='1.0' encoding='utf-8'?><r>\u{FFFD}&#xA0;&#x2028;</r>",
+    // Escaping in text and attributes
+    "<r a='&quot;&apos;&lt;&amp;&gt;&#9;&#10;&#13;' b=\"'\">&lt;&amp;&gt;&#13;\"'\t\r\n</r>",
+    "<r a='  x  y  '>]]&gt;</r>",
+    // The internal subset, with notations, entities and references
+    "<!DOCTYPE r SYSTEM \"r.dtd\" [\n\
+     <!ELEMENT r (a | b | (c, d?)+ | e)*>\n\
+     <!ELEMENT a (#PCDATA | b | c)*>\n\
+     <!ELEMENT b EMPTY>\n\
+     <!ELEMENT c ANY>\n\
+     <!ELEMENT d (a, (b | c)+, (a, b), (p:e | (c, d))?)>\n\
+     <!ELEMENT e (#PCDATA)>\n\
+     <!ATTLIST r id ID #REQUIRED x (u | v) \"u\" n NOTATION (gif | png) #IMPLIED>\n\
+     <!ATTLIST r f CDATA #FIXED \"a&lt;&quot;é&#9;\" p:q IDREFS #IMPLIED t ENTITY #IMPLIED>\n\
+     <!ATTLIST a r IDREF #IMPLIED s ENTITIES #IMPLIED u NMTOKEN #IMPLIED w NMTOKENS 'x y'>\n\
+     <!ENTITY e \"x &amp; 'y' &#37; é\">\n\
+     <!ENTITY q 'say \"hi\"'>\n\
+     <!ENTITY both \"&#34;'\">\n\
+     <!ENTITY % pe \"<!-- pe -->\">\n\
+     <!ENTITY % ext SYSTEM \"ext.ent\">\n\
+     <!ENTITY ext PUBLIC \"-//E//x\" 'ext.xml'>\n\
+     <!ENTITY pic PUBLIC \"-//P\" \"pic.gif\" NDATA gif>\n\
+     <!ENTITY pic2 SYSTEM \"pic.png\" NDATA png>\n\
+     <!NOTATION gif PUBLIC \"image/gif\">\n\
+     <!NOTATION png SYSTEM 'png'>\n\
+     <!NOTATION jpg PUBLIC \"image/jpeg\" \"jpeg\">\n\
+     <!-- in the subset --><?subset pi?>\n\
+     ]><r id='i' x='v'>&e;-&q;-&both;<a>&amp;&e;</a></r>",
+    "<!DOCTYPE r PUBLIC \"-//R//EN\" \"r.dtd\"><r/>",
+    "<!DOCTYPE r SYSTEM 'say \"r\".dtd'><r/>",
+    "<!DOCTYPE r><r/>",
+    // Element content to indent, mixed content that is not, and a
+    // nesting deeper than the indentation goes
+    "<!--c--><r><a><b/><!--x--><?p d?><c t='1'></c></a><m>t<e><f/></e></m>\
+     <n><![CDATA[d]]></n><o>&amp;</o></r>",
+    "<r>\n  <a><b/></a>\n</r>",
+    "<r><a><a><a><a><a><a><a><a><a><a><a><a><a><a><a><a><a><a><a><a><a><a><a><a><a><a>\
+     <a><a><a><a><a><a><b/></a></a></a></a></a></a></a></a></a></a></a></a></a></a></a>\
+     </a></a></a></a></a></a></a></a></a></a></a></a></a></a></a></a></a></r>",
+];
+
+/// Save options, each with and without the others.
+const OPTIONS: &[c_int] = &[
+    0,
+    xmlSaveOption_XML_SAVE_NO_DECL as c_int,
+    xmlSaveOption_XML_SAVE_NO_EMPTY as c_int,
+    (xmlSaveOption_XML_SAVE_NO_DECL | xmlSaveOption_XML_SAVE_NO_EMPTY) as c_int,
+    xmlSaveOption_XML_SAVE_FORMAT as c_int,
+    (xmlSaveOption_XML_SAVE_FORMAT | xmlSaveOption_XML_SAVE_NO_EMPTY) as c_int,
+    (xmlSaveOption_XML_SAVE_FORMAT | xmlSaveOption_XML_SAVE_NO_INDENT) as c_int,
+];
+
+/// Parse `input` with the C baseline, keeping entity references.
+unsafe fn c_parse(input: &str) -> dynamic_bindings::xmlDocPtr {
+    let c_lib = libxml2_dynload::get_c_baseline();
+    let doc = c_lib.xmlReadMemory(
+        input.as_ptr() as *const c_char,
+        input.len() as c_int,
+        std::ptr::null(),
+        std::ptr::null(),
+        (xmlParserOption_XML_PARSE_NOERROR | xmlParserOption_XML_PARSE_NOWARNING) as c_int,
+    );
+    assert!(!doc.is_null(), "{input}");
+    doc
+}
+
+/// Serialize `doc` with the C baseline `xmlSaveDoc`.
+unsafe fn c_save(doc: dynamic_bindings::xmlDocPtr, options: c_int) -> Vec<u8> {
+    let c_lib = libxml2_dynload::get_c_baseline();
+    let buf = c_lib.xmlBufferCreate();
+    let ctxt = c_lib.xmlSaveToBuffer(buf, std::ptr::null(), options);
+    assert!(!ctxt.is_null());
+    assert!(c_lib.xmlSaveDoc(ctxt, doc) >= 0);
+    c_lib.xmlSaveClose(ctxt);
+    let out = std::slice::from_raw_parts(
+        c_lib.xmlBufferContent(buf),
+        c_lib.xmlBufferLength(buf) as usize,
+    )
+    .to_vec();
+    c_lib.xmlBufferFree(buf);
+    out
+}
+
+/// Dump `doc` with the C baseline `xmlDocDumpFormatMemoryEnc`, in its
+/// own encoding if `encoding` is `None`.
+unsafe fn c_dump(
+    doc: dynamic_bindings::xmlDocPtr,
+    encoding: Option<&str>,
+    format: c_int,
+) -> Vec<u8> {
+    let c_lib = libxml2_dynload::get_c_baseline();
+    let encoding = encoding.map(|e| std::ffi::CString::new(e).unwrap());
+    let mut mem = std::ptr::null_mut();
+    let mut size = 0;
+    c_lib.xmlDocDumpFormatMemoryEnc(
+        doc,
+        &mut mem,
+        &mut size,
+        encoding.as_ref().map_or(std::ptr::null(), |e| e.as_ptr()),
+        format,
+    );
+    assert!(!mem.is_null());
+    let out = std::slice::from_raw_parts(mem, size as usize).to_vec();
+    libc::free(mem as *mut libc::c_void);
+    out
+}
+
+/// The offset of the first byte where `a` and `b` differ, or of the end
+/// of the shorter one.
+fn first_difference(a: &[u8], b: &[u8]) -> Option<usize> {
+    if a == b {
+        return None;
+    }
+    Some(a.iter().zip(b).take_while(|(x, y)| x == y).count())
+}
+
+#[test]
+fn test_first_difference() {
+    assert_eq!(first_difference(b"abc", b"abc"), None);
+    assert_eq!(first_difference(b"abc", b"abd"), Some(2));
+    assert_eq!(first_difference(b"ab", b"abc"), Some(2));
+    assert_eq!(first_difference(b"", b"a"), Some(0));
+}
+
+#[test]
+fn test_save_doc_baseline() {
+    let c_lib = libxml2_dynload::get_c_baseline();
+    let save = |input, options| unsafe {
+        let doc = c_parse(input);
+        let out = c_save(doc, options);
+        c_lib.xmlFreeDoc(doc);
+        String::from_utf8(out).unwrap()
+    };
+    assert_eq!(
+        save("<r a='é&#10;'>é<![CDATA[é]]></r>", 0),
+        "<?xml version=\"1.0\"?>\n<r a=\"&#xE9;&#10;\">&#xE9;<![CDATA[é]]></r>\n"
+    );
+    assert_eq!(
+        save("<?xml version='1.0' encoding='UTF-8'?><r>é</r>", 0),
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<r>é</r>\n"
+    );
+    let no_decl = xmlSaveOption_XML_SAVE_NO_DECL as c_int;
+    let no_empty = xmlSaveOption_XML_SAVE_NO_EMPTY as c_int;
+    assert_eq!(
+        save("<!--c--><r><e/></r>", no_decl),
+        "<!--c-->\n<r><e/></r>\n"
+    );
+    assert_eq!(save("<r><e/></r>", no_decl | no_empty), "<r><e></e></r>\n");
+    let format = xmlSaveOption_XML_SAVE_FORMAT as c_int;
+    assert_eq!(
+        save("<r><a><b/></a><c>t<d/></c></r>", no_decl | format),
+        "<r>\n  <a>\n    <b/>\n  </a>\n  <c>t<d/></c>\n</r>\n"
+    );
+}
+
+#[test]
+fn test_dump_memory_baseline() {
+    let c_lib = libxml2_dynload::get_c_baseline();
+    unsafe {
+        let doc = c_parse("<r a='é'>é€</r>");
+        assert_eq!(
+            c_dump(doc, Some("ISO-8859-1"), 0),
+            b"<?xml version=\"1.0\" encoding=\"ISO-8859-1\"?>\n<r a=\"\xE9\">\xE9&#8364;</r>\n"
+        );
+        assert_eq!(
+            c_dump(doc, None, 0),
+            b"<?xml version=\"1.0\"?>\n<r a=\"&#xE9;\">&#xE9;&#x20AC;</r>\n"
+        );
+        let mut mem = std::ptr::null_mut();
+        let mut size = 0;
+        c_lib.xmlDocDumpMemory(doc, &mut mem, &mut size);
+        assert_eq!(
+            std::slice::from_raw_parts(mem, size as usize),
+            c_dump(doc, None, 0)
+        );
+        libc::free(mem as *mut libc::c_void);
+        c_lib.xmlFreeDoc(doc);
+    }
+}
+
+#[cfg(feature = "rust-xmlsave")]
+mod rust_tests {
+    use super::*;
+    use libxml2::xmlsave::{self, SaveError};
+
+    /// Documents with non-ASCII text, attributes and names to dump in an
+    /// output encoding, one of them declaring Latin-1 itself.
+    const DUMP_DOCUMENTS: &[&str] = &[
+        "<r a='é€😀'>café € 😀 \u{FFFD}<é/></r>",
+        "<?xml version='1.0' encoding='UTF-8' standalone='yes'?><r>ü&lt;&#xA0;</r>",
+        "<?xml version='1.0' encoding='ISO-8859-1'?><r a='\u{e9}'>d\u{e9}j\u{e0} vu</r>",
+        "<!DOCTYPE r [<!ENTITY e 'é€'>]><r>&e;<![CDATA[ñ]]><!--ö--></r>",
+    ];
+
+    /// Output encodings for `xmlDocDumpFormatMemoryEnc`, as C spells them and
+    /// in other case or under an alias.
+    const DUMP_ENCODINGS: &[&str] = &[
+        "ISO-8859-1",
+        "UTF-8",
+        "latin1",
+        "utf8",
+        "US-ASCII",
+        "UTF-16",
+        "UTF-16BE",
+    ];
+
+    /// Serialize the C baseline's tree with the Rust module.
+    unsafe fn rust_save(
+        doc: dynamic_bindings::xmlDocPtr,
+        options: c_int,
+    ) -> Result<Vec<u8>, SaveError> {
+        xmlsave::save_doc(doc as xmlDocPtr, options)
+    }
+
+    /// Compare both serializations of `doc`, showing where they part.
+    unsafe fn assert_same_output(doc: dynamic_bindings::xmlDocPtr, options: c_int, what: &str) {
+        let expected = c_save(doc, options);
+        let actual = rust_save(doc, options).unwrap_or_else(|e| panic!("{what}: {e:?}"));
+        if let Some(offset) = first_difference(&actual, &expected) {
+            let context = |out: &[u8]| {
+                let start = offset.saturating_sub(20);
+                String::from_utf8_lossy(&out[start.min(out.len())..(offset + 20).min(out.len())])
+                    .into_owned()
+            };
+            panic!(
+                "{what} (options {options:#x}): first difference at byte {offset}\n  \
+                 C:    {:?}\n  Rust: {:?}",
+                context(&expected),
+                context(&actual)
+            );
+        }
+    }
+
+    #[test]
+    fn test_save_doc_matches_baseline() {
+        let c_lib = libxml2_dynload::get_c_baseline();
+        for input in DOCUMENTS {
+            unsafe {
+                let doc = c_parse(input);
+                for &options in OPTIONS {
+                    assert_same_output(doc, options, input);
+                }
+                c_lib.xmlFreeDoc(doc);
+            }
+        }
+    }
+
+    /// Nodes the parser never builds: CDATA holding its own end marker,
+    /// raw control characters, bad UTF-8 and a PI without content.
+    #[test]
+    fn test_save_built_doc_matches_baseline() {
+        let c_lib = libxml2_dynload::get_c_baseline();
+        unsafe {
+            let doc = c_lib.xmlNewDoc(c"1.0".as_ptr() as *const xmlChar);
+            let root = c_lib.xmlNewDocNode(
+                doc,
+                std::ptr::null_mut(),
+                c"r".as_ptr() as *const xmlChar,
+                std::ptr::null(),
+            );
+            c_lib.xmlAddChild(doc as _, root);
+            let cdata = b"a]]>b]]]]>>c]]>";
+            c_lib.xmlAddChild(
+                root,
+                c_lib.xmlNewCDataBlock(doc, cdata.as_ptr(), cdata.len() as c_int),
+            );
+            c_lib.xmlAddChild(
+                root,
+                c_lib.xmlNewDocText(
+                    doc,
+                    c"\x01\x08\x0b\x1f\x7f \xc3 \xed\xa0\x80 \xef\xbf\xbe".as_ptr()
+                        as *const xmlChar,
+                ),
+            );
+            c_lib.xmlAddChild(
+                root,
+                c_lib.xmlNewDocPI(doc, c"bare".as_ptr() as *const xmlChar, std::ptr::null()),
+            );
+            c_lib.xmlAddChild(
+                root,
+                c_lib.xmlNewDocComment(doc, c"".as_ptr() as *const xmlChar),
+            );
+            c_lib.xmlNewProp(
+                root,
+                c"a".as_ptr() as *const xmlChar,
+                c"\x02\xc3\xa9\xf4\x90\x80\x80".as_ptr() as *const xmlChar,
+            );
+            for &options in OPTIONS {
+                assert_same_output(doc, options, "built document");
+            }
+            c_lib.xmlFreeDoc(doc);
+        }
+    }
+
+    #[test]
+    fn test_save_doc_unsupported() {
+        let c_lib = libxml2_dynload::get_c_baseline();
+        unsafe {
+            let doc = c_parse("<r/>");
+            let wsnonsig = xmlSaveOption_XML_SAVE_WSNONSIG as c_int;
+            assert_eq!(rust_save(doc, wsnonsig), Err(SaveError::Options(wsnonsig)));
+            c_lib.xmlFreeDoc(doc);
+
+            let doc = c_parse("<?xml version='1.0' encoding='ISO-8859-1'?><r/>");
+            assert_eq!(
+                rust_save(doc, 0),
+                Err(SaveError::Encoding(b"ISO-8859-1".to_vec()))
+            );
+            c_lib.xmlFreeDoc(doc);
+
+            let doc = c_parse(
+                "<!DOCTYPE html PUBLIC \"-//W3C//DTD XHTML 1.0 Strict//EN\" \
+                 \"http://www.w3.org/TR/xhtml1/DTD/xhtml1-strict.dtd\">\
+                 <html xmlns='http://www.w3.org/1999/xhtml'><body><br/></body></html>",
+            );
+            assert_eq!(rust_save(doc, 0), Err(SaveError::Xhtml));
+            let no_xhtml = xmlSaveOption_XML_SAVE_NO_XHTML as c_int;
+            assert_same_output(doc, no_xhtml, "XHTML document as XML");
+            c_lib.xmlFreeDoc(doc);
+
+            let html = "<p>x";
+            let doc = c_lib.htmlReadMemory(
+                html.as_ptr() as *const c_char,
+                html.len() as c_int,
+                std::ptr::null(),
+                std::ptr::null(),
+                (htmlParserOption_HTML_PARSE_NOERROR | htmlParserOption_HTML_PARSE_NOWARNING)
+                    as c_int,
+            );
+            assert_eq!(rust_save(doc, 0), Err(SaveError::Html));
+            let as_xml = xmlSaveOption_XML_SAVE_AS_XML as c_int;
+            assert_same_output(doc, as_xml, "HTML document as XML");
+            c_lib.xmlFreeDoc(doc);
+
+            assert_eq!(
+                rust_save(std::ptr::null_mut(), 0),
+                Err(SaveError::NotADocument)
+            );
+        }
+    }
+
+    #[test]
+    fn test_dump_doc_matches_baseline() {
+        let c_lib = libxml2_dynload::get_c_baseline();
+        for input in DUMP_DOCUMENTS {
+            unsafe {
+                let doc = c_parse(input);
+                let encodings = DUMP_ENCODINGS.iter().map(Some).chain([None]);
+                for (encoding, format) in encodings.flat_map(|e| [(e, 0), (e, 1)]) {
+                    let what = format!("{input} in {encoding:?}, format {format}");
+                    let expected = c_dump(doc, encoding.copied(), format);
+                    let actual = xmlsave::dump_doc(
+                        doc as xmlDocPtr,
+                        encoding.map(|e| e.as_bytes()),
+                        format != 0,
+                    )
+                    .unwrap_or_else(|e| panic!("{what}: {e:?}"));
+                    assert_eq!(
+                        first_difference(&actual, &expected),
+                        None,
+                        "{what}\n  C:    {:?}\n  Rust: {:?}",
+                        String::from_utf8_lossy(&expected),
+                        String::from_utf8_lossy(&actual)
+                    );
+                }
+                c_lib.xmlFreeDoc(doc);
+            }
+        }
+    }
+
+    #[test]
+    fn test_dump_doc_unsupported() {
+        let c_lib = libxml2_dynload::get_c_baseline();
+        unsafe {
+            let doc = c_parse("<r>é</r>");
+            let dump = |encoding: &[u8], format| {
+                xmlsave::dump_doc(doc as xmlDocPtr, Some(encoding), format)
+            };
+            assert_eq!(
+                dump(b"ISO-8859-2", false),
+                Err(SaveError::Encoding(b"ISO-8859-2".to_vec()))
+            );
+            c_lib.xmlFreeDoc(doc);
+
+            let doc = c_lib.xmlNewDoc(c"1.0".as_ptr() as *const xmlChar);
+            let root = c_lib.xmlNewDocNode(
+                doc,
+                std::ptr::null_mut(),
+                c"r".as_ptr() as *const xmlChar,
+                c"a\xFFb".as_ptr() as *const xmlChar,
+            );
+            c_lib.xmlAddChild(doc as _, root);
+            assert_eq!(
+                xmlsave::dump_doc(doc as xmlDocPtr, Some(b"ISO-8859-1"), false),
+                Err(SaveError::InvalidUtf8(48))
+            );
+            c_lib.xmlFreeDoc(doc);
+        }
+    }
+
+    /// The exported entry points, called on the baseline's tree, return
+    /// an xmlMalloc'ed copy of what the baseline dumps.
+    #[test]
+    fn test_dump_memory_exports() {
+        let c_lib = libxml2_dynload::get_c_baseline();
+        let take = |mem: *mut xmlChar, size: c_int| unsafe {
+            assert!(!mem.is_null());
+            let out = std::slice::from_raw_parts(mem, size as usize).to_vec();
+            assert_eq!(*mem.add(out.len()), 0);
+            xmlFree.unwrap()(mem as *mut std::os::raw::c_void);
+            out
+        };
+        unsafe {
+            let doc = c_parse("<r a='é'><e>€</e></r>");
+            let rust_doc = doc as xmlDocPtr;
+            let mut mem = std::ptr::null_mut();
+            let mut size = 0;
+            xmlsave::ffi::xmlDocDumpMemory(rust_doc, &mut mem, &mut size);
+            assert_eq!(take(mem, size), c_dump(doc, None, 0));
+            xmlsave::ffi::xmlDocDumpFormatMemory(rust_doc, &mut mem, &mut size, 1);
+            assert_eq!(take(mem, size), c_dump(doc, None, 1));
+            xmlsave::ffi::xmlDocDumpMemoryEnc(rust_doc, &mut mem, &mut size, c"latin1".as_ptr());
+            assert_eq!(take(mem, size), c_dump(doc, Some("latin1"), 0));
+            xmlsave::ffi::xmlDocDumpFormatMemoryEnc(
+                rust_doc,
+                &mut mem,
+                &mut size,
+                c"UTF-16".as_ptr(),
+                1,
+            );
+            assert_eq!(take(mem, size), c_dump(doc, Some("UTF-16"), 1));
+
+            // Failures leave the buffer NULL and the length 0.
+            xmlsave::ffi::xmlDocDumpMemoryEnc(rust_doc, &mut mem, &mut size, c"EBCDIC".as_ptr());
+            assert!(mem.is_null());
+            assert_eq!(size, 0);
+            c_lib.xmlFreeDoc(doc);
+
+            size = 1;
+            xmlsave::ffi::xmlDocDumpMemory(std::ptr::null_mut(), &mut mem, &mut size);
+            assert!(mem.is_null());
+            assert_eq!(size, 0);
+        }
+    }
+}
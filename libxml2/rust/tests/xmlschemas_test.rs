@@ -0,0 +1,1061 @@
+//! Tests for xmlschemas module
+
+use libxml2::*;
+use std::os::raw::{c_char, c_int, c_void};
+
+/// Facets as a schema writes them: element name and value.
+type Facets = &'static [(&'static str, &'static str)];
+
+/// Restrictions of built-in types by their facets, each with values to
+/// validate: lexically invalid ones, values failing one facet or
+/// several, and whitespace the type normalizes or keeps.
+const CASES: &[(&str, Facets, &[&str])] = &[
+    (
+        "int",
+        &[
+            ("minInclusive", "5"),
+            ("maxExclusive", "100"),
+            ("pattern", "[0-9]+"),
+            ("totalDigits", "2"),
+        ],
+        &[
+            "5", "99", "100", "4", "-1", " 7 ", "007", "099", "abc", "", "1.0", "+50",
+        ],
+    ),
+    (
+        "token",
+        &[
+            ("enumeration", " a  b"),
+            ("enumeration", "c"),
+            ("pattern", "[a-z]"),
+            ("pattern", "a b"),
+            ("maxLength", "2"),
+            ("minLength", "2"),
+        ],
+        &["a b", " a   b ", "c", "xyz", "ab", "a\tb", ""],
+    ),
+    (
+        "string",
+        &[
+            ("whiteSpace", "collapse"),
+            ("enumeration", " a"),
+            ("enumeration", "b"),
+            ("length", "1"),
+        ],
+        &[" a", "a", "  ", " b ", "b", "c"],
+    ),
+    (
+        "string",
+        &[("maxLength", "3"), ("minLength", "1")],
+        &["", "a", "abc", "abcd", "é€😀", "é€😀x", "  ", " a b "],
+    ),
+    (
+        "normalizedString",
+        &[("length", "3"), ("pattern", "a.b")],
+        &["a b", "a\tb", "a\nb", "axb", "ab", " a b "],
+    ),
+    (
+        "decimal",
+        &[
+            ("minExclusive", "-1.5"),
+            ("maxInclusive", "10"),
+            ("totalDigits", "4"),
+            ("fractionDigits", "2"),
+        ],
+        &[
+            "0",
+            "-1.5",
+            "-1.49",
+            "10",
+            "10.00",
+            "10.01",
+            "1.234",
+            "123.4",
+            "12.34",
+            "-0",
+            ".5",
+            "5.",
+            "1e3",
+            "00012.3400",
+            "",
+        ],
+    ),
+    (
+        "decimal",
+        &[
+            ("enumeration", "1.0"),
+            ("enumeration", "-2"),
+            ("enumeration", "0"),
+        ],
+        &["1", "01.000", "-2.0", "2", "-0.0", "+0", "0.1"],
+    ),
+    (
+        "byte",
+        &[("minInclusive", "-10"), ("maxExclusive", "10")],
+        &["-10", "9", "10", "-11", "127", "128", "-129", "x"],
+    ),
+    (
+        "unsignedShort",
+        &[
+            ("maxInclusive", "1000"),
+            ("enumeration", "7"),
+            ("enumeration", "1001"),
+        ],
+        &["7", "1001", "8", "-1", "65535", "65536"],
+    ),
+    (
+        "positiveInteger",
+        &[("minExclusive", "3")],
+        &["4", "3", "0", "-5", "+7", "99999999999999999999999"],
+    ),
+    (
+        "nonPositiveInteger",
+        &[("maxExclusive", "-2")],
+        &["-3", "-2", "0", "-0", "1"],
+    ),
+    (
+        "long",
+        &[("fractionDigits", "0")],
+        &[
+            "9223372036854775807",
+            "9223372036854775808",
+            "-9223372036854775808",
+            "1.0",
+        ],
+    ),
+    (
+        "boolean",
+        &[("pattern", "true|false")],
+        &["true", "false", "1", "0", " true ", "yes"],
+    ),
+    (
+        "string",
+        &[("pattern", "\\d{2}-\\p{Lu}+"), ("pattern", "x*")],
+        &["12-AB", "1-AB", "12-ab", "", "xxx", "12-É"],
+    ),
+    (
+        "token",
+        &[("whiteSpace", "preserve"), ("length", "3")],
+        &["a b", " ab", "a  b"],
+    ),
+    (
+        "int",
+        &[("whiteSpace", "replace"), ("pattern", "1")],
+        &["1", " 1", "1\t"],
+    ),
+];
+
+/// Escape `value` for an attribute or text, keeping a carriage return
+/// from being normalized away.
+fn escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('"', "&quot;")
+        .replace('\r', "&#13;")
+        .replace('\t', "&#9;")
+        .replace('\n', "&#10;")
+}
+
+/// A schema whose only element `v` has `base` restricted by `facets`.
+fn xsd(base: &str, facets: &[(&str, &str)]) -> String {
+    let facets: String = facets
+        .iter()
+        .map(|(name, value)| format!("<xs:{name} value=\"{}\"/>", escape(value)))
+        .collect();
+    format!(
+        "<xs:schema xmlns:xs=\"http://www.w3.org/2001/XMLSchema\">\
+         <xs:element name=\"v\"><xs:simpleType><xs:restriction base=\"xs:{base}\">\
+         {facets}</xs:restriction></xs:simpleType></xs:element></xs:schema>"
+    )
+}
+
+/// Collect the codes of the structured errors in `domain`, passed as the
+/// first element of the `(c_int, Vec<c_int>)` in `data`.
+unsafe extern "C" fn collect_error(data: *mut c_void, error: *const dynamic_bindings::xmlError) {
+    let (domain, codes) = &mut *(data as *mut (c_int, Vec<c_int>));
+    if (*error).domain == *domain {
+        codes.push((*error).code);
+    }
+}
+
+/// Parse `xsd` with the C baseline, returning the schema or the codes of
+/// the errors the parser reports.
+unsafe fn c_schema(xsd: &str) -> Result<dynamic_bindings::xmlSchemaPtr, Vec<c_int>> {
+    let c_lib = libxml2_dynload::get_c_baseline();
+    let ctxt = c_lib.xmlSchemaNewMemParserCtxt(xsd.as_ptr() as *const c_char, xsd.len() as c_int);
+    assert!(!ctxt.is_null());
+    let mut errors = (xmlErrorDomain_XML_FROM_SCHEMASP as c_int, Vec::new());
+    c_lib.xmlSchemaSetParserStructuredErrors(
+        ctxt,
+        Some(collect_error),
+        &mut errors as *mut _ as *mut c_void,
+    );
+    let schema = c_lib.xmlSchemaParse(ctxt);
+    c_lib.xmlSchemaFreeParserCtxt(ctxt);
+    if schema.is_null() {
+        Err(errors.1)
+    } else {
+        assert!(errors.1.is_empty(), "{xsd}: {:?}", errors.1);
+        Ok(schema)
+    }
+}
+
+/// Parse `input` with the C baseline.
+unsafe fn c_parse(input: &str) -> dynamic_bindings::xmlDocPtr {
+    let c_lib = libxml2_dynload::get_c_baseline();
+    let doc = c_lib.xmlReadMemory(
+        input.as_ptr() as *const c_char,
+        input.len() as c_int,
+        std::ptr::null(),
+        std::ptr::null(),
+        0,
+    );
+    assert!(!doc.is_null(), "{input}");
+    doc
+}
+
+/// The codes of the errors the C baseline reports validating
+/// `<v>value</v>` against `schema`.
+unsafe fn c_validate(schema: dynamic_bindings::xmlSchemaPtr, value: &str) -> Vec<c_int> {
+    c_validate_doc(schema, &format!("<v>{}</v>", escape(value)))
+}
+
+/// The codes of the errors the C baseline reports validating the
+/// document `input` against `schema`.
+unsafe fn c_validate_doc(schema: dynamic_bindings::xmlSchemaPtr, input: &str) -> Vec<c_int> {
+    let c_lib = libxml2_dynload::get_c_baseline();
+    let doc = c_parse(input);
+    let ctxt = c_lib.xmlSchemaNewValidCtxt(schema);
+    let mut errors = (xmlErrorDomain_XML_FROM_SCHEMASV as c_int, Vec::new());
+    c_lib.xmlSchemaSetValidStructuredErrors(
+        ctxt,
+        Some(collect_error),
+        &mut errors as *mut _ as *mut c_void,
+    );
+    let ret = c_lib.xmlSchemaValidateDoc(ctxt, doc);
+    c_lib.xmlSchemaFreeValidCtxt(ctxt);
+    c_lib.xmlFreeDoc(doc);
+    assert_eq!(ret, errors.1.last().copied().unwrap_or(0), "{input}");
+    errors.1
+}
+
+#[test]
+fn test_schemas_baseline() {
+    let c_lib = libxml2_dynload::get_c_baseline();
+    unsafe {
+        let schema = c_schema(&xsd("int", CASES[0].1)).unwrap();
+        assert_eq!(c_validate(schema, "50"), []);
+        assert_eq!(
+            c_validate(schema, "100"),
+            [
+                xmlParserErrors_XML_SCHEMAV_CVC_MAXEXCLUSIVE_VALID as c_int,
+                xmlParserErrors_XML_SCHEMAV_CVC_TOTALDIGITS_VALID as c_int,
+            ]
+        );
+        assert_eq!(
+            c_validate(schema, "abc"),
+            [xmlParserErrors_XML_SCHEMAV_CVC_DATATYPE_VALID_1_2_1 as c_int]
+        );
+        c_lib.xmlSchemaFree(schema);
+
+        let schema = c_schema(
+            "<xs:schema xmlns:xs=\"http://www.w3.org/2001/XMLSchema\">\
+             <xs:element name=\"v\"><xs:complexType><xs:sequence>\
+             <xs:element name=\"a\"/><xs:element name=\"b\" maxOccurs=\"unbounded\"/>\
+             </xs:sequence></xs:complexType></xs:element></xs:schema>",
+        )
+        .unwrap();
+        assert_eq!(c_validate_doc(schema, "<v><a/><b/><b/></v>"), []);
+        assert_eq!(
+            c_validate_doc(schema, "<v><b/></v>"),
+            [xmlParserErrors_XML_SCHEMAV_ELEMENT_CONTENT as c_int]
+        );
+        assert_eq!(
+            c_validate_doc(schema, "<w/>"),
+            [xmlParserErrors_XML_SCHEMAV_CVC_ELT_1 as c_int]
+        );
+        c_lib.xmlSchemaFree(schema);
+
+        assert_eq!(
+            c_schema(&xsd("string", &[("pattern", "[a-")]))
+                .err()
+                .unwrap(),
+            [xmlParserErrors_XML_SCHEMAP_REGEXP_INVALID as c_int]
+        );
+    }
+}
+
+/// Date, time and duration literals by type: valid values, the bounds of
+/// each field and of timezones, values of other types and invalid ones.
+const DATE_LITERALS: &[(&str, &[&str])] = &[
+    (
+        "dateTime",
+        &[
+            "2004-04-12T13:20:00",
+            "2004-04-12T13:20:00Z",
+            "2004-04-12T13:20:00-05:00",
+            "2004-04-12T13:20:15.5+14:00",
+            "2004-04-12T13:20:00-14:00",
+            "2004-04-12T13:20:00+14:01",
+            "2004-04-12T13:20:00+24:00",
+            "2004-04-12T23:20:00-01:30",
+            "2004-04-13T00:30:00+01:00",
+            "2004-04-12T24:00:00",
+            "2004-04-12T24:00:01",
+            "1999-12-31T24:00:00+01:00",
+            "2000-02-29T00:00:00",
+            "1900-02-29T00:00:00",
+            "2000-03-01T00:30:00+01:00",
+            "-0001-12-31T23:59:59.999",
+            "-0001-01-01T00:00:00+01:00",
+            "0001-01-01T00:00:00+01:00",
+            "12004-01-01T00:00:00Z",
+            "02004-01-01T00:00:00",
+            "0000-01-01T00:00:00",
+            "9223372036854775807-12-31T23:59:59Z",
+            "2004-04-12T13:20",
+            "2004-04-12 13:20:00",
+            " 2004-04-12T13:20:00Z\n",
+            "2004-04-12T13:20:00.Z",
+            "2004-04-12T13:20:60",
+            "2004-13-12T13:20:00",
+            "2004-04-12T13:20:00.000001",
+            "2004-04-12T13:20:05.1234567890123456789",
+            "2004-04-12T13:20:00Z ",
+            "2004-04-12T13:20:00ZZ",
+        ],
+    ),
+    (
+        "date",
+        &[
+            "2004-04-12",
+            "2004-04-12Z",
+            "2004-04-12+13:00",
+            "2004-04-12-13:00",
+            "2004-04-11-11:00",
+            "2004-02-30",
+            "2004-4-12",
+            "-2004-04-12",
+            "2004-04-12T00:00:00",
+            "2004-04-12+14:30",
+        ],
+    ),
+    (
+        "time",
+        &[
+            "13:20:00",
+            "13:20:00Z",
+            "13:20:00.25-05:00",
+            "00:00:00+14:00",
+            "23:00:00-01:00",
+            "24:00:00",
+            "23:59:59.9999999",
+            "13:20:05.5",
+            "00:00:00.00001Z",
+            "25:00:00",
+            "13:60:00",
+            "13:20",
+            "1:20:00",
+            "13:20:00+1:00",
+        ],
+    ),
+    (
+        "gYear",
+        &[
+            "2004",
+            "-2004",
+            "2004Z",
+            "2004+05:00",
+            "20040",
+            "0000",
+            "204",
+            "+2004",
+            "02004",
+        ],
+    ),
+    (
+        "gYearMonth",
+        &["2004-04", "-0045-12Z", "2004-13", "2004-4", "2004-04-05:00"],
+    ),
+    (
+        "gMonth",
+        &[
+            "--04",
+            "--04Z",
+            "--04-05:00",
+            "--12-14:00",
+            "--13",
+            "--04--",
+            "--4",
+        ],
+    ),
+    (
+        "gMonthDay",
+        &[
+            "--04-12",
+            "--02-29",
+            "--02-30",
+            "--04-31",
+            "--12-31-14:00",
+            "--04-12Z",
+            "--04-12:00",
+        ],
+    ),
+    (
+        "gDay",
+        &[
+            "---12",
+            "---31",
+            "---32",
+            "---00",
+            "---12Z",
+            "---12-05:00",
+            "--12",
+        ],
+    ),
+    (
+        "duration",
+        &[
+            "P1Y",
+            "-P1Y2M3DT4H5M6.7S",
+            "P0D",
+            "PT0S",
+            "P1M",
+            "-P1M",
+            "P28D",
+            "P29D",
+            "P30D",
+            "P31D",
+            "-P31D",
+            "P12M",
+            "P365D",
+            "P366D",
+            "P400Y",
+            "P146097D",
+            "PT24H",
+            "P1D",
+            "PT1440M",
+            "PT86400S",
+            "PT86399.5S",
+            "PT1.S",
+            "PT.5S",
+            " P1D ",
+            "P768614336404564650Y",
+            "P768614336404564651Y",
+            "P9223372036854775807D",
+            "P9223372036854775808D",
+            "PT9223372036854775807S",
+            "P",
+            "PT",
+            "P1D2Y",
+            "P1.5D",
+            "P1H",
+            "P-1D",
+            "1Y",
+        ],
+    ),
+];
+
+/// Parse `value` as the built-in type `name` with the C baseline.
+unsafe fn c_value(name: &str, value: &str) -> Option<dynamic_bindings::xmlSchemaValPtr> {
+    let c_lib = libxml2_dynload::get_c_baseline();
+    let name = std::ffi::CString::new(name).unwrap();
+    let ty = c_lib.xmlSchemaGetPredefinedType(
+        name.as_ptr() as *const xmlChar,
+        c"http://www.w3.org/2001/XMLSchema".as_ptr() as *const xmlChar,
+    );
+    assert!(!ty.is_null());
+    let value = std::ffi::CString::new(value).unwrap();
+    let mut val = std::ptr::null_mut();
+    let ret = c_lib.xmlSchemaValidatePredefinedType(ty, value.as_ptr() as *const xmlChar, &mut val);
+    assert!(ret >= 0);
+    (ret == 0).then_some(val)
+}
+
+/// The canonical form of `val` in the C baseline.
+unsafe fn c_canonical(val: dynamic_bindings::xmlSchemaValPtr) -> String {
+    let c_lib = libxml2_dynload::get_c_baseline();
+    let mut out = std::ptr::null();
+    assert_eq!(c_lib.xmlSchemaGetCanonValue(val, &mut out), 0);
+    let s = std::ffi::CStr::from_ptr(out as *const c_char)
+        .to_string_lossy()
+        .into_owned();
+    libc::free(out as *mut c_void);
+    s
+}
+
+#[test]
+fn test_date_values_baseline() {
+    let c_lib = libxml2_dynload::get_c_baseline();
+    unsafe {
+        let month = c_value("duration", "P1M").unwrap();
+        let days = c_value("duration", "P30D").unwrap();
+        assert_eq!(c_lib.xmlSchemaCompareValues(month, days), 2);
+        assert_eq!(c_canonical(month), "-P0Y1M0DT0H0M0S");
+        c_lib.xmlSchemaFreeValue(month);
+        c_lib.xmlSchemaFreeValue(days);
+
+        let x = c_value("dateTime", "2004-04-12T13:20:00-05:00").unwrap();
+        let y = c_value("dateTime", "2004-04-12T18:20:00Z").unwrap();
+        assert_eq!(c_lib.xmlSchemaCompareValues(x, y), 0);
+        assert_eq!(c_canonical(x), "2004-04-12T18:20:00Z");
+        c_lib.xmlSchemaFreeValue(x);
+        c_lib.xmlSchemaFreeValue(y);
+        assert!(c_value("date", "2004-02-30").is_none());
+    }
+}
+
+#[cfg(feature = "rust-xmlschemas")]
+mod rust_tests {
+    use super::*;
+    use libxml2::xmlschemas::{
+        BuiltinType, ComplexType, ContentError, DateType, DateValue, Duration, ElementDecl,
+        ElementType, Facet, FacetKind, Particle, SimpleType,
+    };
+    use std::cmp::Ordering;
+
+    /// Facets the schema parser rejects.
+    const BAD_FACETS: &[(&str, Facets)] = &[
+        ("int", &[("length", "3")]),
+        ("string", &[("minInclusive", "3")]),
+        ("boolean", &[("enumeration", "true")]),
+        ("byte", &[("maxInclusive", "300")]),
+        ("int", &[("enumeration", "x")]),
+        ("string", &[("maxLength", "-1")]),
+        ("decimal", &[("totalDigits", "0")]),
+        ("decimal", &[("fractionDigits", "1.5")]),
+        ("string", &[("whiteSpace", "trim")]),
+        ("string", &[("pattern", "[a-")]),
+        ("string", &[("length", "x"), ("pattern", "(")]),
+    ];
+
+    /// How often a particle occurs: `minOccurs`, and `maxOccurs` with
+    /// `None` for unbounded.
+    type Occurs = (u32, Option<u32>);
+
+    /// A content model as the tests write it, turned into both a schema and
+    /// the Rust particles.
+    struct Model {
+        term: ModelTerm,
+        occurs: Occurs,
+    }
+
+    enum ModelTerm {
+        /// An element of a built-in type.
+        Simple(&'static str, &'static str),
+        /// An element whose complex type has empty content.
+        Empty(&'static str),
+        /// An element whose complex type has the content model given.
+        Complex(&'static str, Box<Model>),
+        Sequence(Vec<Model>),
+        Choice(Vec<Model>),
+        All(Vec<Model>),
+    }
+
+    impl Model {
+        fn new(term: ModelTerm) -> Self {
+            Model {
+                term,
+                occurs: (1, Some(1)),
+            }
+        }
+
+        fn occurs(self, min: u32, max: Option<u32>) -> Self {
+            Model {
+                occurs: (min, max),
+                ..self
+            }
+        }
+
+        /// The model as the content of an `<xs:complexType>`.
+        fn xsd(&self) -> String {
+            let (min, max) = self.occurs;
+            let mut occurs = String::new();
+            if min != 1 {
+                occurs += &format!(" minOccurs=\"{min}\"");
+            }
+            match max {
+                Some(1) => {}
+                Some(max) => occurs += &format!(" maxOccurs=\"{max}\""),
+                None => occurs += " maxOccurs=\"unbounded\"",
+            }
+            let group = |tag: &str, models: &[Model]| {
+                let content: String = models.iter().map(Model::xsd).collect();
+                format!("<xs:{tag}{occurs}>{content}</xs:{tag}>")
+            };
+            match &self.term {
+                ModelTerm::Simple(name, ty) => {
+                    format!("<xs:element name=\"{name}\" type=\"xs:{ty}\"{occurs}/>")
+                }
+                ModelTerm::Empty(name) => {
+                    format!("<xs:element name=\"{name}\"{occurs}><xs:complexType/></xs:element>")
+                }
+                ModelTerm::Complex(name, model) => format!(
+                    "<xs:element name=\"{name}\"{occurs}><xs:complexType>{}</xs:complexType>\
+                     </xs:element>",
+                    model.xsd()
+                ),
+                ModelTerm::Sequence(models) => group("sequence", models),
+                ModelTerm::Choice(models) => group("choice", models),
+                ModelTerm::All(models) => group("all", models),
+            }
+        }
+    }
+
+    fn simple(name: &'static str, ty: &'static str) -> Model {
+        Model::new(ModelTerm::Simple(name, ty))
+    }
+
+    fn empty(name: &'static str) -> Model {
+        Model::new(ModelTerm::Empty(name))
+    }
+
+    fn complex(name: &'static str, model: Model) -> Model {
+        Model::new(ModelTerm::Complex(name, Box::new(model)))
+    }
+
+    fn sequence(models: Vec<Model>) -> Model {
+        Model::new(ModelTerm::Sequence(models))
+    }
+
+    fn choice(models: Vec<Model>) -> Model {
+        Model::new(ModelTerm::Choice(models))
+    }
+
+    fn all(models: Vec<Model>) -> Model {
+        Model::new(ModelTerm::All(models))
+    }
+
+    /// A schema whose only element `v` has a complex type with `model` as
+    /// content, its local elements qualified by `namespace` if given.
+    fn content_xsd(model: &Model, namespace: Option<&str>) -> String {
+        let target = namespace.map_or(String::new(), |ns| {
+            format!(" targetNamespace=\"{ns}\" elementFormDefault=\"qualified\"")
+        });
+        format!(
+            "<xs:schema xmlns:xs=\"http://www.w3.org/2001/XMLSchema\"{target}>\
+             <xs:element name=\"v\"><xs:complexType>{}</xs:complexType></xs:element></xs:schema>",
+            model.xsd()
+        )
+    }
+
+    /// Content models with the children of `v` to validate: sequences,
+    /// choices and `<all>`, nested and repeated, holding elements of simple,
+    /// empty and complex types.
+    fn content_cases() -> Vec<(Model, Option<&'static str>, &'static [&'static str])> {
+        vec![
+            (
+                sequence(vec![
+                    simple("a", "int").occurs(1, Some(2)),
+                    empty("b").occurs(0, Some(1)),
+                    simple("c", "string").occurs(2, None),
+                ]),
+                None,
+                &[
+                    "<a>1</a><c/><c/>",
+                    "<a>1</a><a>2</a><a>3</a><c/><c/>",
+                    "<a>x</a><c/>",
+                    "<a>x</a><b> </b><c/><c/>",
+                    "<a>1</a><b>t<q/></b><c/><c/>",
+                    " <a>1<q/>2</a> x <c/><c/>",
+                    "<a> 1</a><!--x--><a>2 </a> <![CDATA[ ]]> <c/><c/>",
+                    "<c/>",
+                    "<a>9999999999</a><d/><a>x</a>",
+                    "<a>1</a><b/><b/>",
+                    "<a>1</a>",
+                    "",
+                    "x",
+                    "<a>1</a><c/><c><q/>t</c>",
+                    "<a>1</a><c/><c>  </c><c/><c/>",
+                    "<a>1</a><b><!--c--><?pi?></b><c/><c/>",
+                    "<a>1<![CDATA[2]]></a><c/><c/>",
+                ],
+            ),
+            (
+                choice(vec![
+                    simple("a", "byte"),
+                    sequence(vec![empty("b"), simple("c", "boolean").occurs(0, Some(1))]),
+                ])
+                .occurs(1, Some(3)),
+                None,
+                &[
+                    "<a>1</a>",
+                    "<b/>",
+                    "<b/><c>true</c><a>2</a><b/>",
+                    "<a>1</a><a>2</a><a>3</a><a>4</a>",
+                    "<c>true</c>",
+                    "",
+                    "<b/><b/><b/>",
+                    "<a>300</a><b/><c>x</c>",
+                ],
+            ),
+            (
+                all(vec![
+                    simple("a", "int"),
+                    empty("b").occurs(0, Some(1)),
+                    simple("c", "string"),
+                ]),
+                None,
+                &[
+                    "<a>1</a><c/>",
+                    "<c/><a>1</a>",
+                    "<c/><b/><a>1</a>",
+                    "<a>1</a>",
+                    "<a>1</a><a>2</a><c/>",
+                    "<b/>",
+                    "",
+                    "<a>x</a><c/><d/>",
+                ],
+            ),
+            (
+                all(vec![empty("a"), empty("b")]).occurs(0, Some(1)),
+                None,
+                &["", "<a/><b/>", "<b/>", "<b/><a/>"],
+            ),
+            (
+                sequence(vec![empty("a"), empty("b").occurs(0, Some(1))]).occurs(2, None),
+                None,
+                &[
+                    "<a/><a/>",
+                    "<a/><b/><a/>",
+                    "<a/>",
+                    "<a/><b/><a/><a/><b/>",
+                    "<a/><b/><b/><a/>",
+                    "",
+                ],
+            ),
+            (
+                sequence(vec![empty("a"), empty("b")]).occurs(0, Some(3)),
+                None,
+                &[
+                    "",
+                    "<a/><b/>",
+                    "<a/><b/><a/><b/><a/><b/>",
+                    "<a/><b/><a/><b/><a/><b/><a/><b/>",
+                    "<a/>",
+                    "<b/>",
+                ],
+            ),
+            (
+                sequence(vec![empty("e").occurs(3, Some(5))]),
+                None,
+                &[
+                    "<e/><e/>",
+                    "<e/><e/><e/>",
+                    "<e/><e/><e/><e/><e/>",
+                    "<e/><e/><e/><e/><e/><e/>",
+                ],
+            ),
+            (
+                sequence(vec![
+                    complex(
+                        "p",
+                        sequence(vec![
+                            simple("x", "int").occurs(0, None),
+                            complex("y", choice(vec![empty("z").occurs(1, None)])),
+                        ]),
+                    ),
+                    simple("q", "string").occurs(0, Some(1)),
+                ]),
+                None,
+                &[
+                    "<p/>",
+                    "<p><x>1</x><x>2</x><y><z/><z/></y></p><q>s</q>",
+                    "<p><y/></p>",
+                    "<p><x>a</x><y><z/><w/></y><x>1</x></p><q/>",
+                    "<p>t</p>",
+                    "<p><y><z/></y></p><q><q/></q>",
+                ],
+            ),
+            (sequence(vec![]), None, &["", " ", "<a/>", "x<a/>y"]),
+            (choice(vec![]), None, &["", "<a/>"]),
+            (
+                choice(vec![empty("a"), empty("b")]).occurs(0, None),
+                None,
+                &["", "<a/><b/><a/>", "<c/>"],
+            ),
+            (
+                choice(vec![empty("a"), empty("b")]).occurs(2, Some(2)),
+                None,
+                &["<a/>", "<a/><b/>", "<b/><b/><a/>"],
+            ),
+            (
+                sequence(vec![simple("a", "int"), empty("b").occurs(0, None)]),
+                Some("urn:t"),
+                &["<a>1</a>", "<a xmlns=''>1</a>", "<a>1</a><b/><b/>", "<b/>"],
+            ),
+        ]
+    }
+
+    /// Content models the schema parser rejects.
+    fn bad_content() -> Vec<Model> {
+        vec![
+            sequence(vec![empty("a").occurs(2, Some(1))]),
+            sequence(vec![empty("a").occurs(1, Some(0))]),
+            all(vec![empty("a").occurs(0, Some(2))]),
+            all(vec![empty("a")]).occurs(1, Some(2)),
+            sequence(vec![all(vec![empty("a")])]),
+            all(vec![sequence(vec![empty("a")])]),
+            sequence(vec![empty("a").occurs(0, Some(1)), empty("a")]),
+            choice(vec![empty("a"), sequence(vec![empty("a"), empty("b")])]),
+            sequence(vec![complex(
+                "p",
+                sequence(vec![empty("x").occurs(0, Some(1)), empty("x")]),
+            )]),
+        ]
+    }
+
+    /// The instance whose root `v` holds `children`.
+    fn content_doc(children: &str, namespace: Option<&str>) -> String {
+        match namespace {
+            Some(ns) => format!("<v xmlns=\"{ns}\">{children}</v>"),
+            None => format!("<v>{children}</v>"),
+        }
+    }
+
+    /// The Rust type restricting `base` by `facets`.
+    fn rust_type(base: &str, facets: &[(&str, &str)]) -> Result<SimpleType, c_int> {
+        let base = BuiltinType::from_name(base.as_bytes()).unwrap();
+        let facets: Vec<Facet> = facets
+            .iter()
+            .map(|(name, value)| {
+                Facet::new(
+                    FacetKind::from_name(name.as_bytes()).unwrap(),
+                    value.as_bytes(),
+                )
+            })
+            .collect();
+        SimpleType::new(base, &facets).map_err(|e| e.code())
+    }
+
+    #[test]
+    fn test_validate_matches_baseline() {
+        let c_lib = libxml2_dynload::get_c_baseline();
+        for (base, facets, values) in CASES {
+            let xsd = xsd(base, facets);
+            let ty = rust_type(base, facets).unwrap_or_else(|e| panic!("{xsd}: {e}"));
+            unsafe {
+                let schema = c_schema(&xsd).unwrap();
+                for value in *values {
+                    let expected = c_validate(schema, value);
+                    let actual = ty.validate(value.as_bytes()).err().unwrap_or_default();
+                    assert_eq!(actual, expected, "{xsd}: {value:?}");
+                }
+                c_lib.xmlSchemaFree(schema);
+            }
+        }
+    }
+
+    #[test]
+    fn test_bad_facets_match_baseline() {
+        for (base, facets) in BAD_FACETS {
+            let xsd = xsd(base, facets);
+            let expected = unsafe { c_schema(&xsd) }.err().unwrap();
+            let actual = rust_type(base, facets).err().unwrap();
+            assert!(
+                expected.contains(&actual),
+                "{xsd}: {actual} not in {expected:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_whitespace() {
+        let ty = rust_type("token", &[]).unwrap();
+        assert_eq!(ty.whitespace(), libxml2::xmlschemas::WhiteSpace::Collapse);
+        let ty = rust_type("string", &[("whiteSpace", "replace")]).unwrap();
+        assert_eq!(ty.whitespace(), libxml2::xmlschemas::WhiteSpace::Replace);
+        assert_eq!(ty.base(), BuiltinType::String);
+    }
+
+    /// The Rust particle for `model`, its elements in `namespace`.
+    fn particle(model: &Model, namespace: Option<&str>) -> Result<Particle, ContentError> {
+        let decl = |name: &str, ty| {
+            let mut decl = ElementDecl::new(name.as_bytes(), ty);
+            decl.namespace = namespace.map(|ns| ns.as_bytes().to_vec());
+            Particle::element(decl)
+        };
+        let group = |models: &[Model]| {
+            models
+                .iter()
+                .map(|m| particle(m, namespace))
+                .collect::<Result<Vec<_>, _>>()
+        };
+        let particle = match &model.term {
+            ModelTerm::Simple(name, ty) => {
+                let base = BuiltinType::from_name(ty.as_bytes()).unwrap();
+                decl(
+                    name,
+                    ElementType::Simple(SimpleType::new(base, &[]).unwrap()),
+                )
+            }
+            ModelTerm::Empty(name) => decl(name, ElementType::Complex(ComplexType::empty())),
+            ModelTerm::Complex(name, model) => decl(
+                name,
+                ElementType::Complex(ComplexType::new(particle(model, namespace)?)?),
+            ),
+            ModelTerm::Sequence(models) => Particle::sequence(group(models)?),
+            ModelTerm::Choice(models) => Particle::choice(group(models)?),
+            ModelTerm::All(models) => Particle::all(group(models)?),
+        };
+        let (min, max) = model.occurs;
+        Ok(particle.occurs(min, max))
+    }
+
+    /// The Rust declaration of `v` with `model` as content.
+    fn root_decl(model: &Model, namespace: Option<&str>) -> Result<ElementDecl, c_int> {
+        let ty = particle(model, namespace)
+            .and_then(ComplexType::new)
+            .map_err(ContentError::code)?;
+        let mut decl = ElementDecl::new(b"v", ElementType::Complex(ty));
+        decl.namespace = namespace.map(|ns| ns.as_bytes().to_vec());
+        Ok(decl)
+    }
+
+    #[test]
+    fn test_content_matches_baseline() {
+        let c_lib = libxml2_dynload::get_c_baseline();
+        for (model, namespace, instances) in content_cases() {
+            let xsd = content_xsd(&model, namespace);
+            let decl = root_decl(&model, namespace).unwrap_or_else(|e| panic!("{xsd}: {e}"));
+            unsafe {
+                let schema = c_schema(&xsd).unwrap();
+                for children in instances {
+                    let input = content_doc(children, namespace);
+                    let expected = c_validate_doc(schema, &input);
+                    let doc = c_parse(&input);
+                    let root = c_lib.xmlDocGetRootElement(doc);
+                    let actual = decl.validate(root as xmlNodePtr).err().unwrap_or_default();
+                    c_lib.xmlFreeDoc(doc);
+                    assert_eq!(actual, expected, "{xsd}: {input}");
+                }
+                c_lib.xmlSchemaFree(schema);
+            }
+        }
+    }
+
+    #[test]
+    fn test_bad_content_matches_baseline() {
+        for model in bad_content() {
+            let xsd = content_xsd(&model, None);
+            let expected = unsafe { c_schema(&xsd) }.err().unwrap();
+            let actual = root_decl(&model, None).err().unwrap();
+            assert!(
+                expected.contains(&actual),
+                "{xsd}: {actual} not in {expected:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_root_name() {
+        let c_lib = libxml2_dynload::get_c_baseline();
+        let decl = root_decl(&sequence(vec![]), None).unwrap();
+        unsafe {
+            for input in ["<w/>", "<v xmlns='urn:t'/>"] {
+                let doc = c_parse(input);
+                let root = c_lib.xmlDocGetRootElement(doc) as xmlNodePtr;
+                assert_eq!(
+                    decl.validate(root),
+                    Err(vec![xmlParserErrors_XML_SCHEMAV_CVC_ELT_1 as c_int])
+                );
+                c_lib.xmlFreeDoc(doc);
+            }
+        }
+        let ElementType::Complex(ty) = &decl.ty else {
+            unreachable!()
+        };
+        assert!(ty.is_empty());
+    }
+
+    /// The order `xmlSchemaCompareValues` gives `x` and `y`: none when
+    /// indeterminate or in error.
+    unsafe fn c_compare(
+        x: dynamic_bindings::xmlSchemaValPtr,
+        y: dynamic_bindings::xmlSchemaValPtr,
+    ) -> Option<Ordering> {
+        let c_lib = libxml2_dynload::get_c_baseline();
+        match c_lib.xmlSchemaCompareValues(x, y) {
+            -1 => Some(Ordering::Less),
+            0 => Some(Ordering::Equal),
+            1 => Some(Ordering::Greater),
+            2 | -2 => None,
+            ret => panic!("{ret}"),
+        }
+    }
+
+    #[test]
+    fn test_dates_match_baseline() {
+        let c_lib = libxml2_dynload::get_c_baseline();
+        let mut values = Vec::new();
+        for (name, literals) in DATE_LITERALS.iter().filter(|(name, _)| *name != "duration") {
+            let kind = DateType::from_name(name.as_bytes()).unwrap();
+            // Each literal is tried as every date type, so values of other
+            // types must fail too.
+            for (other, _) in DATE_LITERALS.iter().filter(|(name, _)| *name != "duration") {
+                for value in *literals {
+                    let other_kind = DateType::from_name(other.as_bytes()).unwrap();
+                    let actual = DateValue::parse(other_kind, value.as_bytes());
+                    let expected = unsafe { c_value(other, value) };
+                    assert_eq!(actual.is_some(), expected.is_some(), "{other} {value:?}");
+                    if let (Some(actual), Some(expected)) = (actual, expected) {
+                        assert_eq!(actual.kind, other_kind);
+                        assert_eq!(
+                            actual.canonical(),
+                            unsafe { c_canonical(expected) },
+                            "{other} {value:?}"
+                        );
+                        if *other == *name {
+                            values.push((*value, actual, expected));
+                        } else {
+                            unsafe { c_lib.xmlSchemaFreeValue(expected) };
+                        }
+                    }
+                }
+            }
+            assert!(values.iter().any(|(_, v, _)| v.kind == kind), "{name}");
+        }
+        for (x, rx, cx) in &values {
+            for (y, ry, cy) in &values {
+                let expected = unsafe { c_compare(*cx, *cy) };
+                assert_eq!(rx.compare(ry), expected, "{x:?} {y:?}");
+            }
+        }
+        for (_, _, val) in values {
+            unsafe { c_lib.xmlSchemaFreeValue(val) };
+        }
+    }
+
+    #[test]
+    fn test_durations_match_baseline() {
+        let c_lib = libxml2_dynload::get_c_baseline();
+        let (_, literals) = DATE_LITERALS
+            .iter()
+            .find(|(name, _)| *name == "duration")
+            .unwrap();
+        let mut values = Vec::new();
+        for value in *literals {
+            let actual = Duration::parse(value.as_bytes());
+            let expected = unsafe { c_value("duration", value) };
+            assert_eq!(actual.is_some(), expected.is_some(), "{value:?}");
+            if let (Some(actual), Some(expected)) = (actual, expected) {
+                assert_eq!(
+                    actual.canonical(),
+                    unsafe { c_canonical(expected) },
+                    "{value:?}"
+                );
+                values.push((*value, actual, expected));
+            }
+        }
+        for (x, rx, cx) in &values {
+            for (y, ry, cy) in &values {
+                let expected = unsafe { c_compare(*cx, *cy) };
+                assert_eq!(rx.compare(ry), expected, "{x:?} {y:?}");
+            }
+        }
+        for (_, _, val) in values {
+            unsafe { c_lib.xmlSchemaFreeValue(val) };
+        }
+    }
+}
@@ -0,0 +1,400 @@
+//! Tests for xmlstring module
+
+use libxml2::*;
+use std::os::raw::c_int;
+use std::ptr;
+
+/// Pairs of NUL-terminated strings that share prefixes, differ in case or
+/// contain bytes above 0x7f.
+const PAIRS: &[(&[u8], &[u8])] = &[
+    (b"\0", b"\0"),
+    (b"abc\0", b"abc\0"),
+    (b"abc\0", b"abd\0"),
+    (b"ab\0", b"abc\0"),
+    (b"abc\0", b"ab\0"),
+    (b"ABC\0", b"abc\0"),
+    (b"[\0", b"{\0"),
+    (b"caf\xc3\xa9\0", b"CAF\xc3\x89\0"),
+    (b"\xff\0", b"a\0"),
+];
+
+/// NUL-terminated strings with valid and broken UTF-8 at various offsets.
+const UTF8: &[&[u8]] = &[
+    b"\0",
+    b"ascii only, longer than sixteen bytes\0",
+    b"caf\xc3\xa9 \xe4\xb8\xad \xf0\x9f\x98\x80\0",
+    b"\xc0\x80\xed\xa0\x80\xf7\xbf\xbf\xbf\0",
+    b"\x80\0",
+    b"abc\xc3\0",
+    b"abc\xe2\x82\0",
+    b"0123456789abcdefghij\xf0\x9f\x98x\0",
+    b"\xf8\x88\x80\x80\x80\0",
+    b"ok\0\xff\0",
+];
+
+#[test]
+fn test_xmlstring_baseline() {
+    let c_lib = libxml2_dynload::get_c_baseline();
+    unsafe {
+        assert_eq!(c_lib.xmlStrlen(ptr::null()), 0);
+        assert_eq!(c_lib.xmlStrlen(b"abc\0".as_ptr()), 3);
+        assert_eq!(c_lib.xmlStrcmp(b"abd\0".as_ptr(), b"abc\0".as_ptr()), 1);
+        assert_eq!(c_lib.xmlStrcmp(ptr::null(), b"\0".as_ptr()), -1);
+        assert_eq!(c_lib.xmlStrEqual(ptr::null(), ptr::null()), 1);
+        assert_eq!(c_lib.xmlStrcasecmp(b"ABC\0".as_ptr(), b"abc\0".as_ptr()), 0);
+    }
+}
+
+/// Haystack and needle pairs: empty needles, no match, overlapping and
+/// repeated candidates, case differences.
+const SEARCHES: &[(&[u8], &[u8])] = &[
+    (b"abc\0", b"\0"),
+    (b"\0", b"\0"),
+    (b"\0", b"a\0"),
+    (b"abc\0", b"abcd\0"),
+    (b"abc\0", b"x\0"),
+    (b"aaab\0", b"aab\0"),
+    (b"abababc\0", b"ababc\0"),
+    (b"xxABCxx\0", b"abc\0"),
+    (b"x[y\0", b"X{Y\0"),
+    (b"caf\xc3\xa9\0", b"\xc3\xa9\0"),
+];
+
+#[test]
+fn test_xmlstring_substring_baseline() {
+    let c_lib = libxml2_dynload::get_c_baseline();
+    let s = b"abcabc\0".as_ptr();
+    unsafe {
+        assert_eq!(c_lib.xmlStrstr(s, b"ca\0".as_ptr()), s.add(2));
+        assert_eq!(c_lib.xmlStrstr(s, b"\0".as_ptr()), s);
+        assert!(c_lib.xmlStrstr(s, ptr::null()).is_null());
+        assert_eq!(c_lib.xmlStrcasestr(s, b"BCA\0".as_ptr()), s.add(1));
+        assert!(c_lib.xmlStrsub(s, 6, 1).is_null());
+    }
+}
+
+#[test]
+fn test_xmlchar_to_str() {
+    use std::borrow::Cow;
+    use xmlstring::{str_to_xmlchar, xmlchar_ptr, xmlchar_to_str};
+
+    assert_eq!(unsafe { xmlchar_to_str(ptr::null()) }, None);
+    assert!(xmlchar_ptr(None).is_null());
+
+    for s in UTF8 {
+        let bytes = &s[..s.iter().position(|&b| b == 0).unwrap()];
+        let ret = unsafe { xmlchar_to_str(s.as_ptr()) }.unwrap();
+        assert_eq!(ret, String::from_utf8_lossy(bytes));
+        // Borrowed exactly when the input is valid
+        assert_eq!(
+            matches!(ret, Cow::Borrowed(_)),
+            std::str::from_utf8(bytes).is_ok(),
+            "{:?}",
+            bytes
+        );
+    }
+
+    for s in ["", "abc", "caf\u{e9} \u{4e2d} \u{1f600}"] {
+        let buf = str_to_xmlchar(s).unwrap();
+        let c_lib = libxml2_dynload::get_c_baseline();
+        assert_eq!(unsafe { c_lib.xmlStrlen(buf.as_ptr()) }, s.len() as c_int);
+        assert_eq!(unsafe { xmlchar_to_str(xmlchar_ptr(Some(&buf))) }.unwrap(), s);
+    }
+    assert_eq!(str_to_xmlchar("nul\0inside"), None);
+}
+
+#[cfg(feature = "rust-xmlstring")]
+mod rust_tests {
+    use super::*;
+    use libxml2::differential_test;
+    use libxml2::xmlstring::ffi;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+    use std::ffi::CStr;
+    use std::os::raw::c_void;
+
+    #[test]
+    fn test_xmlstring_matches_baseline() {
+        for &(a, b) in PAIRS {
+            let (a, b) = (a.as_ptr(), b.as_ptr());
+            differential_test!(xmlStrlen, (a));
+            differential_test!(xmlStrcmp, (a, b));
+            differential_test!(xmlStrcmp, (b, a));
+            differential_test!(xmlStrEqual, (a, b));
+            differential_test!(xmlStrcasecmp, (a, b));
+            differential_test!(xmlStrcasecmp, (b, a));
+        }
+        for &s in UTF8 {
+            differential_test!(xmlCheckUTF8, (s.as_ptr()));
+        }
+    }
+
+    #[test]
+    fn test_xmlstring_null() {
+        let s = b"abc\0".as_ptr();
+        unsafe {
+            assert_eq!(ffi::xmlStrlen(ptr::null()), 0);
+            assert_eq!(ffi::xmlStrcmp(ptr::null(), ptr::null()), 0);
+            assert_eq!(ffi::xmlStrcmp(ptr::null(), s), -1);
+            assert_eq!(ffi::xmlStrcmp(s, ptr::null()), 1);
+            assert_eq!(ffi::xmlStrEqual(ptr::null(), ptr::null()), 1);
+            assert_eq!(ffi::xmlStrEqual(s, ptr::null()), 0);
+            assert_eq!(ffi::xmlStrcasecmp(ptr::null(), s), -1);
+            assert_eq!(ffi::xmlStrcasecmp(s, ptr::null()), 1);
+            assert_eq!(ffi::xmlCheckUTF8(ptr::null()), 0);
+        }
+    }
+
+    /// Random NUL-terminated string over a small alphabet, so matches and
+    /// near-matches are common.
+    fn random_string(rng: &mut StdRng, max_len: usize) -> Vec<u8> {
+        const ALPHABET: &[u8] = b"aabAB[{\xc3";
+        let len = rng.gen_range(0..=max_len);
+        let mut s: Vec<u8> = (0..len)
+            .map(|_| ALPHABET[rng.gen_range(0..ALPHABET.len())])
+            .collect();
+        s.push(0);
+        s
+    }
+
+    fn searches() -> Vec<(Vec<u8>, Vec<u8>)> {
+        let mut rng = StdRng::seed_from_u64(0x5eed);
+        let mut searches: Vec<_> = SEARCHES
+            .iter()
+            .map(|&(h, n)| (h.to_vec(), n.to_vec()))
+            .collect();
+        for _ in 0..2000 {
+            searches.push((random_string(&mut rng, 24), random_string(&mut rng, 4)));
+        }
+        searches
+    }
+
+    #[test]
+    fn test_xmlstrstr_matches_baseline() {
+        // Both return pointers into the same haystack, so they compare directly
+        for (haystack, needle) in searches() {
+            let (h, n) = (haystack.as_ptr(), needle.as_ptr());
+            differential_test!(xmlStrstr, (h, n));
+            differential_test!(xmlStrcasestr, (h, n));
+        }
+        let s = b"abc\0".as_ptr();
+        differential_test!(xmlStrstr, (s, ptr::null()));
+        differential_test!(xmlStrstr, (ptr::null(), s));
+        differential_test!(xmlStrcasestr, (s, ptr::null()));
+        differential_test!(xmlStrcasestr, (ptr::null(), s));
+    }
+
+    #[test]
+    fn test_xmlstrsub_matches_baseline() {
+        let same = |c: &*mut xmlChar, rust: &*mut xmlChar| unsafe {
+            let equal = match (c.is_null(), rust.is_null()) {
+                (false, false) => {
+                    CStr::from_ptr(*c as *const _) == CStr::from_ptr(*rust as *const _)
+                }
+                (c_null, rust_null) => c_null == rust_null,
+            };
+            for p in [*c, *rust] {
+                if !p.is_null() {
+                    xmlFree.unwrap()(p as *mut _);
+                }
+            }
+            equal
+        };
+        let mut rng = StdRng::seed_from_u64(0x5ab);
+        for _ in 0..2000 {
+            let s = random_string(&mut rng, 12);
+            let start = rng.gen_range(-1..s.len() as c_int + 1);
+            let len = rng.gen_range(-1..8);
+            // C copies `len` bytes whatever the string length, so keep
+            // its reads inside the buffer
+            if start >= 0 && start as usize + len.max(0) as usize > s.len() {
+                continue;
+            }
+            differential_test!(xmlStrsub, (s.as_ptr(), start, len) -> same);
+        }
+        differential_test!(xmlStrsub, (ptr::null(), 0, 1) -> same);
+    }
+
+    /// Copy of `s` in xmlMalloc'ed memory, as xmlStrcat expects
+    fn malloced(s: &[u8]) -> *mut xmlChar {
+        unsafe {
+            let mem = xmlMalloc.unwrap()(s.len() + 1) as *mut xmlChar;
+            ptr::copy_nonoverlapping(s.as_ptr(), mem, s.len());
+            *mem.add(s.len()) = 0;
+            mem
+        }
+    }
+
+    /// Content of a returned string, freeing it
+    fn take(s: *mut xmlChar) -> Option<Vec<u8>> {
+        if s.is_null() {
+            return None;
+        }
+        unsafe {
+            let content = CStr::from_ptr(s as *const _).to_bytes().to_vec();
+            xmlFree.unwrap()(s as *mut _);
+            Some(content)
+        }
+    }
+
+    thread_local! {
+        static ALLOCATIONS: std::cell::RefCell<Vec<usize>> = const { std::cell::RefCell::new(Vec::new()) };
+    }
+
+    unsafe extern "C" fn counting_malloc(size: usize) -> *mut c_void {
+        ALLOCATIONS.with(|a| a.borrow_mut().push(size));
+        libc::malloc(size)
+    }
+
+    unsafe extern "C" fn counting_realloc(mem: *mut c_void, size: usize) -> *mut c_void {
+        ALLOCATIONS.with(|a| a.borrow_mut().push(size));
+        libc::realloc(mem, size)
+    }
+
+    /// Run `f` with malloc-backed allocators in both libraries that record
+    /// the sizes requested on this thread
+    fn allocations(f: impl FnOnce()) -> Vec<usize> {
+        let c_lib = libxml2_dynload::get_c_baseline();
+        let setup = |malloc: xmlMallocFunc, realloc: xmlReallocFunc| unsafe {
+            let strdup: xmlStrdupFunc = Some(libc::strdup);
+            c_lib.xmlMemSetup(Some(libc::free), malloc, realloc, strdup);
+            xmlMemSetup(Some(libc::free), malloc, realloc, strdup);
+        };
+        setup(Some(counting_malloc), Some(counting_realloc));
+        ALLOCATIONS.with(|a| a.borrow_mut().clear());
+        f();
+        setup(Some(libc::malloc), Some(libc::realloc));
+        ALLOCATIONS.with(|a| a.take())
+    }
+
+    #[test]
+    fn test_xmlstrcat_matches_baseline() {
+        let c_lib = libxml2_dynload::get_c_baseline();
+        let mut rng = StdRng::seed_from_u64(0xca7);
+        for _ in 0..1000 {
+            let cur = random_string(&mut rng, 12);
+            let add = random_string(&mut rng, 12);
+            let cur = &cur[..cur.len() - 1];
+            let len = rng.gen_range(-1..add.len() as c_int);
+            unsafe {
+                let c = take(c_lib.xmlStrcat(malloced(cur), add.as_ptr()));
+                let rust = take(xmlStrcat(malloced(cur), add.as_ptr()));
+                assert_eq!(c, rust, "xmlStrcat({:?}, {:?})", cur, add);
+
+                let c = c_lib.xmlStrncat(malloced(cur), add.as_ptr(), len);
+                let rust = xmlStrncat(malloced(cur), add.as_ptr(), len);
+                // A negative length gives NULL and leaves `cur` alone
+                assert_eq!(
+                    take(c),
+                    take(rust),
+                    "xmlStrncat({:?}, {:?}, {})",
+                    cur,
+                    add,
+                    len
+                );
+
+                let c = take(c_lib.xmlStrncatNew(cur.as_ptr(), add.as_ptr(), len));
+                let rust = take(xmlStrncatNew(cur.as_ptr(), add.as_ptr(), len));
+                assert_eq!(c, rust, "xmlStrncatNew({:?}, {:?}, {})", cur, add, len);
+            }
+        }
+
+        let s = b"abc\0".as_ptr();
+        unsafe {
+            assert_eq!(
+                take(c_lib.xmlStrcat(ptr::null_mut(), s)),
+                take(xmlStrcat(ptr::null_mut(), s))
+            );
+            assert_eq!(
+                take(c_lib.xmlStrncat(ptr::null_mut(), s, 2)),
+                take(xmlStrncat(ptr::null_mut(), s, 2))
+            );
+            for (a, b) in [
+                (s, ptr::null()),
+                (ptr::null(), s),
+                (ptr::null(), ptr::null()),
+            ] {
+                assert_eq!(
+                    take(c_lib.xmlStrncatNew(a, b, -1)),
+                    take(xmlStrncatNew(a, b, -1))
+                );
+                assert_eq!(
+                    take(c_lib.xmlStrncatNew(a, b, 0)),
+                    take(xmlStrncatNew(a, b, 0))
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_xmlstrcat_allocation_sizes() {
+        let c_lib = libxml2_dynload::get_c_baseline();
+        let add = b"defgh\0".as_ptr();
+        type Concat<'a> = &'a dyn Fn() -> Option<Vec<u8>>;
+        let cases: [(&str, Concat); 6] = [
+            ("C xmlStrncat", &|| {
+                take(unsafe { c_lib.xmlStrncat(malloced(b"abc"), add, 2) })
+            }),
+            ("xmlStrncat", &|| {
+                take(unsafe { xmlStrncat(malloced(b"abc"), add, 2) })
+            }),
+            ("C xmlStrcat", &|| {
+                take(unsafe { c_lib.xmlStrcat(malloced(b"abc"), add) })
+            }),
+            ("xmlStrcat", &|| {
+                take(unsafe { xmlStrcat(malloced(b"abc"), add) })
+            }),
+            ("C xmlStrncatNew", &|| {
+                take(unsafe { c_lib.xmlStrncatNew(b"abc\0".as_ptr(), add, 2) })
+            }),
+            ("xmlStrncatNew", &|| {
+                take(unsafe { xmlStrncatNew(b"abc\0".as_ptr(), add, 2) })
+            }),
+        ];
+        for (name, f) in cases {
+            let mut result = None;
+            let sizes = allocations(|| result = f());
+            let result = result.unwrap();
+            // The copy of `cur` made by `malloced`, then exactly the result
+            assert_eq!(
+                sizes.last(),
+                Some(&(result.len() + 1)),
+                "{}: {:?}",
+                name,
+                sizes
+            );
+        }
+    }
+
+    #[test]
+    fn test_xmlstrcat_overflow() {
+        // Lengths whose sum passes INT_MAX are refused before anything is
+        // allocated or read from `add`, so a short `add` is safe here
+        let c_lib = libxml2_dynload::get_c_baseline();
+        let add = b"x\0".as_ptr();
+        let sizes = allocations(|| unsafe {
+            for len in [c_int::MAX, c_int::MAX - 1] {
+                let (c_cur, rust_cur) = (malloced(b"ab"), malloced(b"ab"));
+                assert!(c_lib.xmlStrncat(c_cur, add, len).is_null());
+                assert!(xmlStrncat(rust_cur, add, len).is_null());
+                // Neither frees `cur` on overflow
+                assert_eq!(take(c_cur), take(rust_cur));
+
+                let s = b"ab\0".as_ptr();
+                assert!(c_lib.xmlStrncatNew(s, add, len).is_null());
+                assert!(xmlStrncatNew(s, add, len).is_null());
+            }
+        });
+        // Only the four copies of "ab"
+        assert_eq!(sizes, vec![3; 4]);
+    }
+
+    #[test]
+    fn test_xmlstring_core() {
+        assert_eq!(xmlstring::str_cmp(b"ab", b"abc"), -(b'c' as c_int));
+        assert!(xmlstring::str_equal(b"abc", b"abc"));
+        assert_eq!(xmlstring::str_casecmp(b"ABC", b"abc"), 0);
+        assert_eq!(xmlstring::xml_validate_utf8(b"abc\xe2\x82"), Some(3));
+        assert_eq!(xmlstring::check_utf8(b"abc\xe2\x82"), Some(3));
+    }
+}
@@ -0,0 +1,775 @@
+//! Tests for xmlwriter module
+
+use libxml2::*;
+
+/// One step of building a document, replayed against each writer.
+#[derive(Clone, Copy)]
+enum Op {
+    StartDocument,
+    Start(&'static [u8]),
+    End,
+    FullEnd,
+    Attr(&'static [u8], &'static [u8]),
+    Text(&'static [u8]),
+    Raw(&'static [u8]),
+    Indent(&'static [u8]),
+    Comment(&'static [u8]),
+    Pi(&'static [u8], Option<&'static [u8]>),
+    Cdata(Option<&'static [u8]>),
+    Dtd(&'static [u8], Option<&'static [u8]>, Option<&'static [u8]>),
+    EndDtd,
+    DtdElement(&'static [u8], &'static [u8]),
+    DtdAttlist(&'static [u8], &'static [u8]),
+    /// Parameter entity flag, name, public and system ID, notation, value.
+    DtdEntity(
+        bool,
+        &'static [u8],
+        Option<&'static [u8]>,
+        Option<&'static [u8]>,
+        Option<&'static [u8]>,
+        Option<&'static [u8]>,
+    ),
+    DtdNotation(&'static [u8], Option<&'static [u8]>, Option<&'static [u8]>),
+    // Constructs left open, to check what may follow them.
+    StartAttr(&'static [u8]),
+    StartComment,
+    StartPi(&'static [u8]),
+    StartCdata,
+    StartDtdElement(&'static [u8]),
+    StartDtdAttlist(&'static [u8]),
+    StartDtdEntity(bool, &'static [u8]),
+    EndDocument,
+}
+
+const DOCUMENT: &[Op] = &[
+    Op::StartDocument,
+    Op::Start(b"catalog\0"),
+    Op::Attr(b"version\0", b"1 & \"2\"\0"),
+    Op::Attr(b"note\0", b"tab\there\nnew <line> caf\xc3\xa9\0"),
+    Op::Start(b"book\0"),
+    Op::Attr(b"id\0", b"b1\0"),
+    Op::Start(b"title\0"),
+    Op::Text(b"Fish & Chips <\"cooking\">\r\n\0"),
+    Op::End,
+    Op::Start(b"empty\0"),
+    Op::End,
+    Op::Start(b"full\0"),
+    Op::FullEnd,
+    Op::Raw(b"<raw>&amp;</raw>\0"),
+    Op::End,
+    Op::Start(b"book\0"),
+    Op::Text(b"caf\xc3\xa9\0"),
+    Op::EndDocument,
+];
+
+const NESTED: &[Op] = &[
+    Op::Indent(b"  \0"),
+    Op::StartDocument,
+    Op::Start(b"root\0"),
+    Op::Attr(b"a\0", b"1\0"),
+    Op::Start(b"child\0"),
+    Op::Start(b"leaf\0"),
+    Op::End,
+    Op::Start(b"full\0"),
+    Op::FullEnd,
+    Op::End,
+    Op::Start(b"child\0"),
+    Op::Start(b"inner\0"),
+    Op::Start(b"deep\0"),
+    Op::Attr(b"b\0", b"2\0"),
+    Op::EndDocument,
+];
+
+const MIXED: &[Op] = &[
+    Op::Indent(b"\t\0"),
+    Op::StartDocument,
+    Op::Start(b"p\0"),
+    Op::Text(b"Some \0"),
+    Op::Start(b"b\0"),
+    Op::Text(b"bold\0"),
+    Op::End,
+    Op::Text(b" and \0"),
+    Op::Start(b"i\0"),
+    Op::Start(b"br\0"),
+    Op::End,
+    Op::End,
+    Op::Raw(b"<raw/>\0"),
+    Op::Start(b"q\0"),
+    Op::End,
+    Op::End,
+    Op::EndDocument,
+];
+
+const NODES: &[Op] = &[
+    Op::StartDocument,
+    Op::Comment(b" before <root> & \"after\" \0"),
+    Op::Pi(b"style\0", Some(b"href=\"a.css\" <&>\0")),
+    Op::Start(b"root\0"),
+    Op::Attr(b"a\0", b"1\0"),
+    Op::Cdata(Some(b"<b>bold</b> & more\0")),
+    Op::Start(b"p\0"),
+    Op::Comment(b"\0"),
+    Op::Pi(b"empty\0", None),
+    Op::Pi(b"blank\0", Some(b"\0")),
+    Op::Cdata(None),
+    Op::End,
+    Op::Start(b"q\0"),
+    Op::Pi(b"in-start-tag\0", Some(b"x\0")),
+    Op::End,
+    Op::Start(b"c\0"),
+    Op::Comment(b"in start tag\0"),
+    Op::Text(b"text\0"),
+    Op::Cdata(Some(b"]]\0")),
+    Op::EndDocument,
+];
+
+const NODES_INDENT: &[Op] = &[
+    Op::Indent(b"  \0"),
+    Op::StartDocument,
+    Op::Comment(b"top\0"),
+    Op::Start(b"root\0"),
+    Op::Comment(b"first child\0"),
+    Op::Start(b"a\0"),
+    Op::Attr(b"x\0", b"1\0"),
+    Op::Pi(b"pi\0", Some(b"data\0")),
+    Op::Cdata(Some(b"cdata\0")),
+    Op::End,
+    Op::Start(b"b\0"),
+    Op::Start(b"c\0"),
+    Op::Comment(b"deep\0"),
+    Op::End,
+    Op::End,
+    Op::Pi(b"last\0", None),
+    Op::EndDocument,
+];
+
+const DTD: &[Op] = &[
+    Op::StartDocument,
+    Op::Dtd(
+        b"doc\0",
+        Some(b"-//Test//DTD Doc//EN\0"),
+        Some(b"doc.dtd\0"),
+    ),
+    Op::DtdElement(b"doc\0", b"(item*)\0"),
+    Op::DtdAttlist(b"doc\0", b"id ID #IMPLIED\0"),
+    Op::DtdEntity(false, b"greeting\0", None, None, None, Some(b"hello\0")),
+    Op::DtdEntity(true, b"common\0", None, Some(b"common.ent\0"), None, None),
+    Op::DtdEntity(
+        false,
+        b"logo\0",
+        Some(b"-//Test//Logo//EN\0"),
+        Some(b"logo.gif\0"),
+        Some(b"gif\0"),
+        None,
+    ),
+    Op::DtdNotation(b"gif\0", None, Some(b"image/gif\0")),
+    Op::DtdNotation(b"png\0", Some(b"-//Test//PNG//EN\0"), None),
+    Op::EndDtd,
+    Op::Start(b"doc\0"),
+    Op::End,
+    Op::EndDocument,
+];
+
+const DTD_INDENT: &[Op] = &[
+    Op::Indent(b"  \0"),
+    Op::Dtd(b"a\0", None, Some(b"a.dtd\0")),
+    Op::DtdNotation(b"n\0", Some(b"-//N//EN\0"), Some(b"n.bin\0")),
+    Op::DtdElement(b"a\0", b"EMPTY\0"),
+    Op::StartDtdEntity(false, b"open\0"),
+    Op::Text(b"left open\0"),
+    Op::EndDocument,
+];
+
+const DTD_EXTERNAL_ONLY: &[Op] = &[
+    Op::Dtd(
+        b"html\0",
+        Some(b"-//W3C//DTD XHTML 1.0 Strict//EN\0"),
+        Some(b"strict.dtd\0"),
+    ),
+    Op::EndDtd,
+    Op::Indent(b" \0"),
+    Op::Dtd(b"again\0", Some(b"p\0"), Some(b"s\0")),
+    Op::EndDocument,
+];
+
+/// `xmlTextWriterStartCDATA` after each kind of construct left open,
+/// `true` where C allows a CDATA section.
+const CDATA_CONTEXTS: &[(&[Op], bool)] = &[
+    (&[], true),
+    (&[Op::Start(b"e\0")], true),
+    (&[Op::Start(b"e\0"), Op::StartAttr(b"a\0")], true),
+    (&[Op::Start(b"e\0"), Op::Text(b"t\0")], true),
+    (&[Op::StartPi(b"pi\0")], true),
+    (&[Op::StartPi(b"pi\0"), Op::Text(b"t\0")], true),
+    (&[Op::StartCdata], false),
+    (&[Op::StartComment], false),
+    (&[Op::Dtd(b"d\0", None, None)], false),
+    (&[Op::Dtd(b"d\0", None, None), Op::Text(b"\0")], false),
+    (
+        &[Op::Dtd(b"d\0", None, None), Op::StartDtdElement(b"e\0")],
+        false,
+    ),
+    (
+        &[
+            Op::Dtd(b"d\0", None, None),
+            Op::StartDtdElement(b"e\0"),
+            Op::Text(b"ANY\0"),
+        ],
+        false,
+    ),
+    (
+        &[Op::Dtd(b"d\0", None, None), Op::StartDtdAttlist(b"e\0")],
+        false,
+    ),
+    (
+        &[
+            Op::Dtd(b"d\0", None, None),
+            Op::StartDtdAttlist(b"e\0"),
+            Op::Text(b"a CDATA #IMPLIED\0"),
+        ],
+        false,
+    ),
+    (&[Op::StartDtdEntity(false, b"e\0")], false),
+    (
+        &[Op::StartDtdEntity(false, b"e\0"), Op::Text(b"v\0")],
+        false,
+    ),
+    (&[Op::StartDtdEntity(true, b"e\0")], false),
+];
+
+fn opt(s: Option<&[u8]>) -> *const xmlChar {
+    s.map_or(std::ptr::null(), |s| s.as_ptr())
+}
+
+/// Run `ops` on the C baseline writer, returning what each call returned
+/// and the output.
+fn c_run(ops: &[Op]) -> (Vec<i32>, Vec<u8>) {
+    let c_lib = libxml2_dynload::get_c_baseline();
+    unsafe {
+        let buf = c_lib.xmlBufferCreate();
+        let writer = c_lib.xmlNewTextWriterMemory(buf, 0);
+        assert!(!writer.is_null());
+        let mut rets = Vec::new();
+        for op in ops {
+            let ret = match op {
+                Op::StartDocument => c_lib.xmlTextWriterStartDocument(
+                    writer,
+                    std::ptr::null(),
+                    std::ptr::null(),
+                    std::ptr::null(),
+                ),
+                Op::Start(name) => c_lib.xmlTextWriterStartElement(writer, name.as_ptr()),
+                Op::End => c_lib.xmlTextWriterEndElement(writer),
+                Op::FullEnd => c_lib.xmlTextWriterFullEndElement(writer),
+                Op::Attr(name, value) => {
+                    c_lib.xmlTextWriterWriteAttribute(writer, name.as_ptr(), value.as_ptr())
+                }
+                Op::Text(text) => c_lib.xmlTextWriterWriteString(writer, text.as_ptr()),
+                Op::Raw(text) => c_lib.xmlTextWriterWriteRaw(writer, text.as_ptr()),
+                Op::Indent(ichar) => {
+                    c_lib.xmlTextWriterSetIndent(writer, 1);
+                    c_lib.xmlTextWriterSetIndentString(writer, ichar.as_ptr())
+                }
+                Op::Comment(text) => c_lib.xmlTextWriterWriteComment(writer, text.as_ptr()),
+                Op::Pi(target, content) => c_lib.xmlTextWriterWritePI(
+                    writer,
+                    target.as_ptr(),
+                    content.map_or(std::ptr::null(), |c| c.as_ptr()),
+                ),
+                Op::Cdata(content) => c_lib.xmlTextWriterWriteCDATA(
+                    writer,
+                    content.map_or(std::ptr::null(), |c| c.as_ptr()),
+                ),
+                Op::Dtd(name, pubid, sysid) => {
+                    c_lib.xmlTextWriterStartDTD(writer, name.as_ptr(), opt(*pubid), opt(*sysid))
+                }
+                Op::EndDtd => c_lib.xmlTextWriterEndDTD(writer),
+                Op::DtdElement(name, content) => {
+                    c_lib.xmlTextWriterWriteDTDElement(writer, name.as_ptr(), content.as_ptr())
+                }
+                Op::DtdAttlist(name, content) => {
+                    c_lib.xmlTextWriterWriteDTDAttlist(writer, name.as_ptr(), content.as_ptr())
+                }
+                Op::DtdEntity(pe, name, pubid, sysid, ndataid, content) => c_lib
+                    .xmlTextWriterWriteDTDEntity(
+                        writer,
+                        *pe as i32,
+                        name.as_ptr(),
+                        opt(*pubid),
+                        opt(*sysid),
+                        opt(*ndataid),
+                        opt(*content),
+                    ),
+                Op::DtdNotation(name, pubid, sysid) => c_lib.xmlTextWriterWriteDTDNotation(
+                    writer,
+                    name.as_ptr(),
+                    opt(*pubid),
+                    opt(*sysid),
+                ),
+                Op::StartAttr(name) => c_lib.xmlTextWriterStartAttribute(writer, name.as_ptr()),
+                Op::StartComment => c_lib.xmlTextWriterStartComment(writer),
+                Op::StartPi(target) => c_lib.xmlTextWriterStartPI(writer, target.as_ptr()),
+                Op::StartCdata => c_lib.xmlTextWriterStartCDATA(writer),
+                Op::StartDtdElement(name) => {
+                    c_lib.xmlTextWriterStartDTDElement(writer, name.as_ptr())
+                }
+                Op::StartDtdAttlist(name) => {
+                    c_lib.xmlTextWriterStartDTDAttlist(writer, name.as_ptr())
+                }
+                Op::StartDtdEntity(pe, name) => {
+                    c_lib.xmlTextWriterStartDTDEntity(writer, *pe as i32, name.as_ptr())
+                }
+                Op::EndDocument => c_lib.xmlTextWriterEndDocument(writer),
+            };
+            rets.push(ret);
+        }
+        c_lib.xmlFreeTextWriter(writer);
+        let out = std::slice::from_raw_parts(
+            c_lib.xmlBufferContent(buf),
+            c_lib.xmlBufferLength(buf) as usize,
+        )
+        .to_vec();
+        c_lib.xmlBufferFree(buf);
+        (rets, out)
+    }
+}
+
+fn c_write(ops: &[Op]) -> Vec<u8> {
+    let (rets, out) = c_run(ops);
+    assert!(rets.iter().all(|&ret| ret >= 0), "{rets:?}");
+    out
+}
+
+#[test]
+fn test_writer_baseline_output() {
+    let out = String::from_utf8(c_write(DOCUMENT)).unwrap();
+    assert!(out.starts_with("<?xml version=\"1.0\"?>\n<catalog version=\"1 &amp; &quot;2&quot;\""));
+    assert!(out.contains("<empty/><full></full>"));
+}
+
+#[test]
+fn test_writer_baseline_indent() {
+    let out = String::from_utf8(c_write(NESTED)).unwrap();
+    assert!(out.contains("\n  <child>\n    <leaf/>\n"));
+    let out = String::from_utf8(c_write(MIXED)).unwrap();
+    // End tags after text stay inline; start tags are still indented.
+    assert!(out.contains("<p>Some \t<b>bold</b>\n and "));
+    assert!(out.ends_with("<raw/>\t<q/>\n</p>\n"));
+}
+
+#[test]
+fn test_writer_baseline_nodes() {
+    let out = String::from_utf8(c_write(NODES)).unwrap();
+    assert!(out.contains("<!-- before <root> & \"after\" -->"));
+    assert!(out.contains("<?empty?><?blank ?><![CDATA[]]>"));
+    let out = String::from_utf8(c_write(NODES_INDENT)).unwrap();
+    assert!(out.contains("\n  <!--first child-->\n"));
+}
+
+#[test]
+fn test_writer_baseline_dtd() {
+    let out = String::from_utf8(c_write(DTD)).unwrap();
+    assert_eq!(
+        out,
+        "<?xml version=\"1.0\"?>\n\
+         <!DOCTYPE doc PUBLIC \"-//Test//DTD Doc//EN\" \"doc.dtd\" [\
+         <!ELEMENT doc (item*)><!ATTLIST doc id ID #IMPLIED>\
+         <!ENTITY greeting \"hello\"><!ENTITY % common SYSTEM \"common.ent\">\
+         <!ENTITY logo PUBLIC \"-//Test//Logo//EN\" \"logo.gif\" NDATA gif>\
+         <!NOTATION gif SYSTEM \"image/gif\"><!NOTATION png PUBLIC \"-//Test//PNG//EN\">]>\
+         <doc/>\n"
+    );
+}
+
+/// A writer replaying ops, as `c_run`.
+type Run = fn(&[Op]) -> (Vec<i32>, Vec<u8>);
+
+/// `setup` followed by `xmlTextWriterStartCDATA`, with what each call
+/// returned.
+fn cdata_after(setup: &[Op], run: Run) -> Vec<i32> {
+    run(&[setup, &[Op::StartCdata]].concat()).0
+}
+
+#[test]
+fn test_writer_baseline_cdata_contexts() {
+    for (setup, allowed) in CDATA_CONTEXTS {
+        let rets = cdata_after(setup, c_run);
+        let (cdata, setup_rets) = rets.split_last().unwrap();
+        assert!(setup_rets.iter().all(|&ret| ret >= 0), "{rets:?}");
+        assert_eq!(*cdata >= 0, *allowed, "{rets:?}");
+    }
+}
+
+#[cfg(feature = "rust-xmlwriter")]
+mod rust_tests {
+    use super::*;
+    use libxml2::xmlwriter::ffi;
+
+    fn rust_run(ops: &[Op]) -> (Vec<i32>, Vec<u8>) {
+        unsafe {
+            let buf = xmlBufferCreate();
+            let writer = ffi::xmlNewTextWriterMemory(buf, 0);
+            assert!(!writer.is_null());
+            let mut rets = Vec::new();
+            for op in ops {
+                let ret = match op {
+                    Op::StartDocument => ffi::xmlTextWriterStartDocument(
+                        writer,
+                        std::ptr::null(),
+                        std::ptr::null(),
+                        std::ptr::null(),
+                    ),
+                    Op::Start(name) => ffi::xmlTextWriterStartElement(writer, name.as_ptr()),
+                    Op::End => ffi::xmlTextWriterEndElement(writer),
+                    Op::FullEnd => ffi::xmlTextWriterFullEndElement(writer),
+                    Op::Attr(name, value) => {
+                        ffi::xmlTextWriterWriteAttribute(writer, name.as_ptr(), value.as_ptr())
+                    }
+                    Op::Text(text) => ffi::xmlTextWriterWriteString(writer, text.as_ptr()),
+                    Op::Raw(text) => ffi::xmlTextWriterWriteRaw(writer, text.as_ptr()),
+                    Op::Indent(ichar) => {
+                        ffi::xmlTextWriterSetIndent(writer, 1);
+                        ffi::xmlTextWriterSetIndentString(writer, ichar.as_ptr())
+                    }
+                    Op::Comment(text) => ffi::xmlTextWriterWriteComment(writer, text.as_ptr()),
+                    Op::Pi(target, content) => ffi::xmlTextWriterWritePI(
+                        writer,
+                        target.as_ptr(),
+                        content.map_or(std::ptr::null(), |c| c.as_ptr()),
+                    ),
+                    Op::Cdata(content) => ffi::xmlTextWriterWriteCDATA(
+                        writer,
+                        content.map_or(std::ptr::null(), |c| c.as_ptr()),
+                    ),
+                    Op::Dtd(name, pubid, sysid) => {
+                        ffi::xmlTextWriterStartDTD(writer, name.as_ptr(), opt(*pubid), opt(*sysid))
+                    }
+                    Op::EndDtd => ffi::xmlTextWriterEndDTD(writer),
+                    Op::DtdElement(name, content) => {
+                        ffi::xmlTextWriterWriteDTDElement(writer, name.as_ptr(), content.as_ptr())
+                    }
+                    Op::DtdAttlist(name, content) => {
+                        ffi::xmlTextWriterWriteDTDAttlist(writer, name.as_ptr(), content.as_ptr())
+                    }
+                    Op::DtdEntity(pe, name, pubid, sysid, ndataid, content) => {
+                        ffi::xmlTextWriterWriteDTDEntity(
+                            writer,
+                            *pe as i32,
+                            name.as_ptr(),
+                            opt(*pubid),
+                            opt(*sysid),
+                            opt(*ndataid),
+                            opt(*content),
+                        )
+                    }
+                    Op::DtdNotation(name, pubid, sysid) => ffi::xmlTextWriterWriteDTDNotation(
+                        writer,
+                        name.as_ptr(),
+                        opt(*pubid),
+                        opt(*sysid),
+                    ),
+                    Op::StartAttr(name) => ffi::xmlTextWriterStartAttribute(writer, name.as_ptr()),
+                    Op::StartComment => ffi::xmlTextWriterStartComment(writer),
+                    Op::StartPi(target) => ffi::xmlTextWriterStartPI(writer, target.as_ptr()),
+                    Op::StartCdata => ffi::xmlTextWriterStartCDATA(writer),
+                    Op::StartDtdElement(name) => {
+                        ffi::xmlTextWriterStartDTDElement(writer, name.as_ptr())
+                    }
+                    Op::StartDtdAttlist(name) => {
+                        ffi::xmlTextWriterStartDTDAttlist(writer, name.as_ptr())
+                    }
+                    Op::StartDtdEntity(pe, name) => {
+                        ffi::xmlTextWriterStartDTDEntity(writer, *pe as i32, name.as_ptr())
+                    }
+                    Op::EndDocument => ffi::xmlTextWriterEndDocument(writer),
+                };
+                rets.push(ret);
+            }
+            ffi::xmlFreeTextWriter(writer);
+            let out =
+                std::slice::from_raw_parts(xmlBufferContent(buf), xmlBufferLength(buf) as usize)
+                    .to_vec();
+            xmlBufferFree(buf);
+            (rets, out)
+        }
+    }
+
+    fn rust_write(ops: &[Op]) -> Vec<u8> {
+        let (rets, out) = rust_run(ops);
+        assert!(rets.iter().all(|&ret| ret >= 0), "{rets:?}");
+        out
+    }
+
+    #[test]
+    fn test_writer_matches_baseline() {
+        let c_out = c_write(DOCUMENT);
+        let rust_out = rust_write(DOCUMENT);
+        assert_eq!(
+            String::from_utf8_lossy(&rust_out),
+            String::from_utf8_lossy(&c_out)
+        );
+    }
+
+    #[test]
+    fn test_writer_indent_matches_baseline() {
+        for ops in [NESTED, MIXED] {
+            let c_out = c_write(ops);
+            let rust_out = rust_write(ops);
+            assert_eq!(
+                String::from_utf8_lossy(&rust_out),
+                String::from_utf8_lossy(&c_out)
+            );
+        }
+    }
+
+    #[test]
+    fn test_writer_indent_output() {
+        let out = String::from_utf8(rust_write(NESTED)).unwrap();
+        assert_eq!(
+            out,
+            "<?xml version=\"1.0\"?>\n\
+             <root a=\"1\">\n\
+             \x20 <child>\n\
+             \x20   <leaf/>\n\
+             \x20   <full></full>\n\
+             \x20 </child>\n\
+             \x20 <child>\n\
+             \x20   <inner>\n\
+             \x20     <deep b=\"2\"/>\n\
+             \x20   </inner>\n\
+             \x20 </child>\n\
+             </root>\n"
+        );
+    }
+
+    #[test]
+    fn test_writer_set_indent_invalid() {
+        unsafe {
+            let buf = xmlBufferCreate();
+            let writer = ffi::xmlNewTextWriterMemory(buf, 0);
+            assert_eq!(ffi::xmlTextWriterSetIndent(writer, -1), -1);
+            assert_eq!(
+                ffi::xmlTextWriterSetIndentString(writer, std::ptr::null()),
+                -1
+            );
+            assert_eq!(ffi::xmlTextWriterSetIndent(std::ptr::null_mut(), 1), -1);
+            ffi::xmlFreeTextWriter(writer);
+            xmlBufferFree(buf);
+        }
+    }
+
+    #[test]
+    fn test_writer_output() {
+        let out = String::from_utf8(rust_write(DOCUMENT)).unwrap();
+        assert_eq!(
+            out,
+            "<?xml version=\"1.0\"?>\n\
+             <catalog version=\"1 &amp; &quot;2&quot;\" \
+             note=\"tab&#9;here&#10;new &lt;line&gt; caf&#xE9;\">\
+             <book id=\"b1\"><title>Fish &amp; Chips &lt;&quot;cooking&quot;&gt;&#13;\n</title>\
+             <empty/><full></full><raw>&amp;</raw></book>\
+             <book>café</book></catalog>\n"
+        );
+    }
+
+    #[test]
+    fn test_writer_invalid_calls() {
+        unsafe {
+            let buf = xmlBufferCreate();
+            let writer = ffi::xmlNewTextWriterMemory(buf, 0);
+
+            // Nothing to end and no element to put an attribute on.
+            assert_eq!(ffi::xmlTextWriterEndElement(writer), -1);
+            assert_eq!(
+                ffi::xmlTextWriterWriteAttribute(
+                    writer,
+                    c"a".as_ptr() as *const xmlChar,
+                    c"b".as_ptr() as *const xmlChar
+                ),
+                -1
+            );
+            assert_eq!(
+                ffi::xmlTextWriterStartElement(writer, c"".as_ptr() as *const xmlChar),
+                -1
+            );
+
+            assert!(ffi::xmlTextWriterStartElement(writer, c"a".as_ptr() as *const xmlChar) >= 0);
+            assert_eq!(
+                ffi::xmlTextWriterStartDocument(
+                    writer,
+                    std::ptr::null(),
+                    std::ptr::null(),
+                    std::ptr::null()
+                ),
+                -1
+            );
+            assert_eq!(ffi::xmlTextWriterEndAttribute(writer), -1);
+
+            ffi::xmlFreeTextWriter(writer);
+            xmlBufferFree(buf);
+        }
+    }
+
+    #[test]
+    fn test_writer_nodes_match_baseline() {
+        for ops in [NODES, NODES_INDENT] {
+            let c_out = c_write(ops);
+            let rust_out = rust_write(ops);
+            assert_eq!(
+                String::from_utf8_lossy(&rust_out),
+                String::from_utf8_lossy(&c_out)
+            );
+        }
+    }
+
+    #[test]
+    fn test_writer_cdata_split() {
+        // C writes `]]>` as is, ending the section early. The Rust writer
+        // must produce what C does for the content cut into sections.
+        let rust_out = rust_write(&[
+            Op::Start(b"r\0"),
+            Op::Cdata(Some(b"a]]>b]]>]]>\0")),
+            Op::End,
+        ]);
+        let c_out = c_write(&[
+            Op::Start(b"r\0"),
+            Op::Cdata(Some(b"a]]\0")),
+            Op::Cdata(Some(b">b]]\0")),
+            Op::Cdata(Some(b">]]\0")),
+            Op::Cdata(Some(b">\0")),
+            Op::End,
+        ]);
+        assert_eq!(
+            String::from_utf8_lossy(&rust_out),
+            String::from_utf8_lossy(&c_out)
+        );
+        assert_eq!(
+            String::from_utf8(rust_out).unwrap(),
+            "<r><![CDATA[a]]]]><![CDATA[>b]]]]><![CDATA[>]]]]><![CDATA[>]]></r>"
+        );
+    }
+
+    #[test]
+    fn test_writer_rejects_unterminable_nodes() {
+        unsafe {
+            let buf = xmlBufferCreate();
+            let writer = ffi::xmlNewTextWriterMemory(buf, 0);
+            assert!(ffi::xmlTextWriterStartElement(writer, c"r".as_ptr() as *const xmlChar) >= 0);
+            for comment in [c"a--b", c"--", c"ends with -"] {
+                assert_eq!(
+                    ffi::xmlTextWriterWriteComment(writer, comment.as_ptr() as *const xmlChar),
+                    -1
+                );
+            }
+            assert_eq!(
+                ffi::xmlTextWriterWritePI(
+                    writer,
+                    c"pi".as_ptr() as *const xmlChar,
+                    c"a ?> b".as_ptr() as *const xmlChar
+                ),
+                -1
+            );
+            for target in [c"xml", c"XmL", c""] {
+                assert_eq!(
+                    ffi::xmlTextWriterWritePI(
+                        writer,
+                        target.as_ptr() as *const xmlChar,
+                        std::ptr::null()
+                    ),
+                    -1
+                );
+            }
+
+            // Nothing was written, so the start tag is still open.
+            assert!(
+                ffi::xmlTextWriterWriteComment(writer, c"a - b".as_ptr() as *const xmlChar) >= 0
+            );
+            assert!(ffi::xmlTextWriterEndElement(writer) >= 0);
+            ffi::xmlFreeTextWriter(writer);
+            assert_eq!(
+                String::from_utf8_lossy(std::slice::from_raw_parts(
+                    xmlBufferContent(buf),
+                    xmlBufferLength(buf) as usize
+                )),
+                "<r><!--a - b--></r>"
+            );
+            xmlBufferFree(buf);
+        }
+    }
+
+    #[test]
+    fn test_writer_nested_node_errors() {
+        unsafe {
+            let buf = xmlBufferCreate();
+            let writer = ffi::xmlNewTextWriterMemory(buf, 0);
+            assert!(ffi::xmlTextWriterStartPI(writer, c"pi".as_ptr() as *const xmlChar) >= 0);
+            assert_eq!(
+                ffi::xmlTextWriterStartPI(writer, c"pi".as_ptr() as *const xmlChar),
+                -1
+            );
+            assert_eq!(
+                ffi::xmlTextWriterStartElement(writer, c"e".as_ptr() as *const xmlChar),
+                -1
+            );
+            assert!(ffi::xmlTextWriterEndPI(writer) >= 0);
+            assert_eq!(ffi::xmlTextWriterEndPI(writer), 0);
+
+            assert!(ffi::xmlTextWriterStartCDATA(writer) >= 0);
+            assert_eq!(ffi::xmlTextWriterStartCDATA(writer), -1);
+            assert_eq!(ffi::xmlTextWriterEndComment(writer), -1);
+            assert!(ffi::xmlTextWriterEndCDATA(writer) >= 0);
+            assert_eq!(ffi::xmlTextWriterEndCDATA(writer), -1);
+            ffi::xmlFreeTextWriter(writer);
+            xmlBufferFree(buf);
+        }
+    }
+
+    #[test]
+    fn test_writer_dtd_matches_baseline() {
+        for ops in [DTD, DTD_INDENT, DTD_EXTERNAL_ONLY] {
+            let (c_rets, c_out) = c_run(ops);
+            let (rets, out) = rust_run(ops);
+            assert_eq!(rets, c_rets);
+            assert_eq!(
+                String::from_utf8_lossy(&out),
+                String::from_utf8_lossy(&c_out)
+            );
+        }
+    }
+
+    #[test]
+    fn test_writer_cdata_contexts() {
+        // Sections are refused in other sections, comments and anywhere in
+        // a DTD, as in C.
+        for (setup, allowed) in CDATA_CONTEXTS {
+            let rets = cdata_after(setup, rust_run);
+            assert_eq!(rets, cdata_after(setup, c_run));
+            assert_eq!(*rets.last().unwrap() >= 0, *allowed, "{rets:?}");
+        }
+    }
+
+    #[test]
+    fn test_writer_invalid_dtd_calls() {
+        // Identifiers a declaration can't have, and DTDs not in the prolog.
+        for ops in [
+            &[Op::Dtd(b"d\0", Some(b"p\0"), None)][..],
+            &[Op::Start(b"e\0"), Op::Dtd(b"d\0", None, None)],
+            &[Op::Dtd(b"d\0", None, None), Op::Dtd(b"d\0", None, None)],
+            &[Op::DtdEntity(
+                true,
+                b"e\0",
+                None,
+                Some(b"s\0"),
+                Some(b"n\0"),
+                None,
+            )],
+            &[Op::DtdEntity(false, b"e\0", None, None, None, None)],
+            &[Op::DtdNotation(b"n\0", None, Some(b"s\0"))],
+            &[Op::Start(b"e\0"), Op::StartDtdElement(b"e\0")],
+        ] {
+            let (rets, out) = rust_run(ops);
+            assert_eq!(*rets.last().unwrap(), -1);
+            assert_eq!((rets, out), c_run(ops));
+        }
+    }
+}
@@ -0,0 +1,245 @@
+//! Tests for xpath module
+
+use libxml2::*;
+use std::ffi::CStr;
+use std::os::raw::c_int;
+use std::ptr;
+
+type CContext = dynamic_bindings::xmlXPathContext;
+type CParserContext = dynamic_bindings::xmlXPathParserContext;
+
+/// The namespace `my` is bound to.
+const NS: &CStr = c"urn:example:my";
+
+/// Expressions calling `my:double`, with arguments of each type, nested
+/// calls and the wrong number of arguments.
+const EXPRESSIONS: &[&str] = &[
+    "my:double(21)",
+    "my:double('1.5') + my:double(my:double(1))",
+    "my:double(true())",
+    "my:double('x') = my:double('x')",
+    "sum(my:double(2) | 3)",
+    "my:double()",
+    "my:double(1, 2)",
+    "double(2)",
+];
+
+/// `my:double(number)` as C would write it, with the value stack of the
+/// C baseline.
+unsafe extern "C" fn c_double(ctxt: *mut CParserContext, nargs: c_int) {
+    let c_lib = libxml2::libxml2_dynload::get_c_baseline();
+    if nargs != 1 {
+        c_lib.xmlXPathErr(ctxt, xmlXPathError_XPATH_INVALID_ARITY as c_int);
+        return;
+    }
+    let n = c_lib.xmlXPathPopNumber(ctxt);
+    c_lib.xmlXPathValuePush(ctxt, c_lib.xmlXPathNewFloat(n * 2.0));
+}
+
+/// Register `my:double` and bind `my` in a C baseline context.
+unsafe fn c_register(ctxt: *mut CContext) -> c_int {
+    let c_lib = libxml2::libxml2_dynload::get_c_baseline();
+    c_lib.xmlXPathRegisterFuncNS(
+        ctxt,
+        c"double".as_ptr() as *const xmlChar,
+        NS.as_ptr() as *const xmlChar,
+        Some(c_double),
+    )
+}
+
+/// Evaluate each expression with the C baseline in a context `register`
+/// set up, returning the numbers or booleans, as numbers, they yield, or
+/// the error code if the evaluation failed. `cleanup` runs before the
+/// context is freed.
+unsafe fn eval_all(
+    register: unsafe fn(*mut CContext) -> c_int,
+    cleanup: unsafe fn(*mut CContext),
+) -> Vec<Result<f64, c_int>> {
+    let c_lib = libxml2::libxml2_dynload::get_c_baseline();
+    let ctxt = c_lib.xmlXPathNewContext(ptr::null_mut());
+    assert_eq!(
+        c_lib.xmlXPathRegisterNs(
+            ctxt,
+            c"my".as_ptr() as *const xmlChar,
+            NS.as_ptr() as *const xmlChar
+        ),
+        0
+    );
+    assert_eq!(register(ctxt), 0);
+    let results = EXPRESSIONS
+        .iter()
+        .map(|expr| {
+            let expr = format!("{expr}\0");
+            let obj = c_lib.xmlXPathEvalExpression(expr.as_ptr(), ctxt);
+            if obj.is_null() {
+                return Err((*ctxt).lastError.code);
+            }
+            let value = match (*obj).type_ {
+                dynamic_bindings::xmlXPathObjectType_XPATH_NUMBER => (*obj).floatval,
+                dynamic_bindings::xmlXPathObjectType_XPATH_BOOLEAN => (*obj).boolval as f64,
+                other => panic!("{expr} yields an object of type {other}"),
+            };
+            c_lib.xmlXPathFreeObject(obj);
+            Ok(value)
+        })
+        .collect();
+    cleanup(ctxt);
+    c_lib.xmlXPathFreeContext(ctxt);
+    results
+}
+
+unsafe fn no_cleanup(_: *mut CContext) {}
+
+#[test]
+fn test_register_func_baseline() {
+    let results = unsafe { eval_all(c_register, no_cleanup) };
+    let unknown = 1200 + xmlXPathError_XPATH_UNKNOWN_FUNC_ERROR as c_int;
+    let arity = 1200 + xmlXPathError_XPATH_INVALID_ARITY as c_int;
+    assert_eq!(
+        results,
+        [
+            Ok(42.0),
+            Ok(7.0),
+            Ok(2.0),
+            Ok(0.0),
+            // A number is not a node-set.
+            Err(1200 + xmlXPathError_XPATH_INVALID_TYPE as c_int),
+            Err(arity),
+            Err(arity),
+            Err(unknown),
+        ]
+    );
+}
+
+#[cfg(feature = "rust-xpath")]
+mod rust_tests {
+    use super::*;
+
+    // Nothing in Rust evaluates expressions yet, so the functions below
+    // are registered by the Rust port into contexts of the C baseline and
+    // called by its evaluator. Only the value stack is the port's; objects
+    // are still made and freed by the baseline.
+
+    /// `c_double` with the value stack of the Rust port.
+    unsafe extern "C" fn rust_double(ctxt: *mut xmlXPathParserContext, nargs: c_int) {
+        let c_lib = libxml2::libxml2_dynload::get_c_baseline();
+        let c_ctxt = ctxt as *mut CParserContext;
+        if nargs != 1 {
+            c_lib.xmlXPathErr(c_ctxt, xmlXPathError_XPATH_INVALID_ARITY as c_int);
+            return;
+        }
+        let arg = xmlXPathValuePop(ctxt) as *mut dynamic_bindings::xmlXPathObject;
+        if arg.is_null() {
+            c_lib.xmlXPathErr(c_ctxt, xmlXPathError_XPATH_STACK_ERROR as c_int);
+            return;
+        }
+        let n = c_lib.xmlXPathCastToNumber(arg);
+        c_lib.xmlXPathFreeObject(arg);
+        xmlXPathValuePush(ctxt, c_lib.xmlXPathNewFloat(n * 2.0) as xmlXPathObjectPtr);
+    }
+
+    unsafe fn rust_register(ctxt: *mut CContext) -> c_int {
+        xmlXPathRegisterFuncNS(
+            ctxt as *mut xmlXPathContext,
+            c"double".as_ptr() as *const xmlChar,
+            NS.as_ptr() as *const xmlChar,
+            Some(rust_double),
+        )
+    }
+
+    /// The table `rust_register` made is freed by this library.
+    unsafe fn rust_cleanup(ctxt: *mut CContext) {
+        xmlXPathRegisteredFuncsCleanup(ctxt as *mut xmlXPathContext);
+    }
+
+    #[test]
+    fn test_register_func_matches_baseline() {
+        let expected = unsafe { eval_all(c_register, no_cleanup) };
+        assert_eq!(unsafe { eval_all(rust_register, rust_cleanup) }, expected);
+    }
+
+    #[test]
+    fn test_register_and_unregister() {
+        let c_lib = libxml2::libxml2_dynload::get_c_baseline();
+        unsafe {
+            let name = c"double".as_ptr() as *const xmlChar;
+            assert_eq!(
+                xmlXPathRegisterFunc(ptr::null_mut(), name, Some(rust_double)),
+                -1
+            );
+
+            let c_ctxt = c_lib.xmlXPathNewContext(ptr::null_mut());
+            let ctxt = c_ctxt as *mut xmlXPathContext;
+            assert_eq!(
+                xmlXPathRegisterFunc(ctxt, ptr::null(), Some(rust_double)),
+                -1
+            );
+            assert_eq!(xmlXPathRegisterFunc(ctxt, name, Some(rust_double)), 0);
+            let found = registered_func_ns(ctxt, name, ptr::null());
+            assert_eq!(
+                found.map(|f| f as usize),
+                Some(rust_double as unsafe extern "C" fn(_, _) as usize)
+            );
+            // Without a namespace and in one are different functions.
+            assert!(registered_func_ns(ctxt, name, NS.as_ptr() as *const xmlChar).is_none());
+            let obj = c_lib.xmlXPathEvalExpression(c"double(4)".as_ptr() as *const xmlChar, c_ctxt);
+            assert_eq!((*obj).floatval, 8.0);
+            c_lib.xmlXPathFreeObject(obj);
+
+            // Registering a name again fails, as in C, and keeps the first.
+            assert_eq!(xmlXPathRegisterFunc(ctxt, name, Some(rust_double)), -1);
+
+            // NULL removes a function, once.
+            assert_eq!(xmlXPathRegisterFunc(ctxt, name, None), 0);
+            assert_eq!(xmlXPathRegisterFunc(ctxt, name, None), -1);
+            assert!(registered_func_ns(ctxt, name, ptr::null()).is_none());
+            assert!(c_lib
+                .xmlXPathEvalExpression(c"double(4)".as_ptr() as *const xmlChar, c_ctxt)
+                .is_null());
+
+            xmlXPathRegisteredFuncsCleanup(ctxt);
+            assert!((*ctxt).funcHash.is_null());
+            c_lib.xmlXPathFreeContext(c_ctxt);
+        }
+    }
+
+    #[test]
+    fn test_value_stack() {
+        let c_lib = libxml2::libxml2_dynload::get_c_baseline();
+        unsafe {
+            let mut parser: xmlXPathParserContext = std::mem::zeroed();
+            assert!(xmlXPathValuePop(&mut parser).is_null());
+
+            // Past the initial 10 slots, the stack grows.
+            let pushed: Vec<_> = (0..25)
+                .map(|i| c_lib.xmlXPathNewFloat(i as f64) as xmlXPathObjectPtr)
+                .collect();
+            for (i, &obj) in pushed.iter().enumerate() {
+                assert_eq!(xmlXPathValuePush(&mut parser, obj), i as c_int);
+                assert_eq!(parser.value, obj);
+            }
+            assert_eq!(parser.valueNr, 25);
+            assert_eq!(parser.valueMax, 35);
+
+            for (i, &obj) in pushed.iter().enumerate().rev() {
+                assert_eq!(xmlXPathValuePop(&mut parser), obj);
+                let below = if i > 0 {
+                    pushed[i - 1]
+                } else {
+                    ptr::null_mut()
+                };
+                assert_eq!(parser.value, below);
+                assert!((*parser.valueTab.add(i)).is_null());
+                c_lib.xmlXPathFreeObject(obj as *mut dynamic_bindings::xmlXPathObject);
+            }
+            assert!(xmlXPathValuePop(&mut parser).is_null());
+
+            // Pushing NULL is a memory error, which stops the evaluation.
+            assert_eq!(xmlXPathValuePush(&mut parser, ptr::null_mut()), -1);
+            assert_eq!(parser.error, xmlXPathError_XPATH_MEMORY_ERROR as c_int);
+            assert_eq!(xmlXPathValuePush(ptr::null_mut(), ptr::null_mut()), -1);
+
+            xmlFree.unwrap()(parser.valueTab as *mut std::os::raw::c_void);
+        }
+    }
+}
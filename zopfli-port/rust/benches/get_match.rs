@@ -0,0 +1,58 @@
+//! Compares GetMatch with GetMatchSimd on input where every match runs to
+//! the maximum length, and times whole compressions of such input.
+//!
+//! cargo +nightly bench --features simd --bench get_match
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use zopfli::ffi::ZopfliOptions;
+use zopfli::lz77::{GetMatch, GetMatchSimd};
+use zopfli::zopfli::ZopfliFormat;
+use zopfli::zopfli_lib::ZopfliCompress;
+
+/// Input whose matches are all ZOPFLI_MAX_MATCH long.
+fn matchable() -> Vec<u8> {
+    b"abcdefgh".repeat(4096)
+}
+
+/// Runs f on the longest match at each position, as ZopfliFindLongestMatch
+/// does with distance 8.
+fn scan_all(
+    data: &[u8],
+    f: unsafe fn(*const u8, *const u8, *const u8, *const u8) -> *const u8,
+) -> usize {
+    let mut total = 0;
+    for pos in 8..data.len() - 258 {
+        unsafe {
+            let scan = data.as_ptr().add(pos);
+            let end = scan.add(258);
+            total += f(scan, scan.sub(8), end, end.sub(8)) as usize - scan as usize;
+        }
+    }
+    total
+}
+
+fn bench_get_match(c: &mut Criterion) {
+    let data = matchable();
+    let mut group = c.benchmark_group("get_match");
+    group.bench_function("scalar", |b| b.iter(|| scan_all(black_box(&data), GetMatch)));
+    group.bench_function("simd", |b| b.iter(|| scan_all(black_box(&data), GetMatchSimd)));
+    group.finish();
+}
+
+fn bench_compress(c: &mut Criterion) {
+    let data = matchable();
+    let options = ZopfliOptions {
+        numiterations: 1,
+        ..ZopfliOptions::default()
+    };
+    c.bench_function("compress matchable", |b| {
+        b.iter(|| {
+            let mut out = Vec::new();
+            ZopfliCompress(&options, ZopfliFormat::ZOPFLI_FORMAT_DEFLATE, black_box(&data), &mut out);
+            out
+        })
+    });
+}
+
+criterion_group!(benches, bench_get_match, bench_compress);
+criterion_main!(benches);
@@ -0,0 +1,90 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+use std::os::raw::{c_uchar, c_uint};
+use zopfli::util::{ZOPFLI_NUM_D, ZOPFLI_NUM_LL};
+use zopfli::{deflate, ffi};
+
+/// Counts to build valid code lengths from, and how many bits are already
+/// in the output so the tree starts anywhere within a byte.
+#[derive(Debug, arbitrary::Arbitrary)]
+struct FuzzInput {
+    ll_counts: Vec<u16>,
+    d_counts: Vec<u16>,
+    offset: u8,
+}
+
+/// The number of bits written so far.
+fn bit_position(bp: c_uchar, outsize: usize) -> usize {
+    outsize * 8 - ((8 - bp as usize) & 7)
+}
+
+fuzz_target!(|input: FuzzInput| {
+    let mut ll_counts: Vec<usize> = input.ll_counts.iter().map(|&c| c as usize).collect();
+    ll_counts.resize(ZOPFLI_NUM_LL, 0);
+    let mut d_counts: Vec<usize> = input.d_counts.iter().map(|&c| c as usize).collect();
+    d_counts.resize(ZOPFLI_NUM_D, 0);
+
+    let mut ll_lengths: [c_uint; ZOPFLI_NUM_LL] = [0; ZOPFLI_NUM_LL];
+    let mut d_lengths: [c_uint; ZOPFLI_NUM_D] = [0; ZOPFLI_NUM_D];
+    unsafe {
+        ffi::ZopfliCalculateBitLengths(
+            ll_counts.as_ptr(),
+            ZOPFLI_NUM_LL,
+            15,
+            ll_lengths.as_mut_ptr(),
+        );
+        ffi::ZopfliCalculateBitLengths(d_counts.as_ptr(), ZOPFLI_NUM_D, 15, d_lengths.as_mut_ptr());
+    }
+    // The C code does not handle a tree without lit/len codes, see
+    // fuzz_CalculateTreeSize.
+    if ll_lengths.iter().all(|&l| l == 0) {
+        return;
+    }
+    let offset = (input.offset % 8) as c_uint;
+
+    unsafe {
+        let c_size = ffi::CalculateTreeSize(ll_lengths.as_ptr(), d_lengths.as_ptr());
+        let rust_size = deflate::CalculateTreeSize(ll_lengths.as_ptr(), d_lengths.as_ptr());
+        assert_eq!(c_size, rust_size);
+
+        let mut c_bp = 0;
+        let mut c_out: *mut c_uchar = std::ptr::null_mut();
+        let mut c_outsize = 0;
+        ffi::AddBits(0, offset, &mut c_bp, &mut c_out, &mut c_outsize);
+        let c_start = bit_position(c_bp, c_outsize);
+        ffi::AddDynamicTree(
+            ll_lengths.as_ptr(),
+            d_lengths.as_ptr(),
+            &mut c_bp,
+            &mut c_out,
+            &mut c_outsize,
+        );
+
+        let mut rust_bp = 0;
+        let mut rust_out: *mut c_uchar = std::ptr::null_mut();
+        let mut rust_outsize = 0;
+        deflate::AddBits(0, offset, &mut rust_bp, &mut rust_out, &mut rust_outsize);
+        let rust_start = bit_position(rust_bp, rust_outsize);
+        deflate::AddDynamicTree(
+            ll_lengths.as_ptr(),
+            d_lengths.as_ptr(),
+            &mut rust_bp,
+            &mut rust_out,
+            &mut rust_outsize,
+        );
+
+        // The estimate used for block type decisions is what is written.
+        assert_eq!(bit_position(c_bp, c_outsize) - c_start, c_size);
+        assert_eq!(bit_position(rust_bp, rust_outsize) - rust_start, rust_size);
+
+        assert_eq!(c_bp, rust_bp);
+        assert_eq!(c_outsize, rust_outsize);
+        assert_eq!(
+            std::slice::from_raw_parts(c_out, c_outsize),
+            std::slice::from_raw_parts(rust_out, rust_outsize)
+        );
+
+        libc::free(c_out as *mut _);
+        libc::free(rust_out as *mut _);
+    }
+});
@@ -0,0 +1,99 @@
+#![no_main]
+use libfuzzer_sys::{fuzz_target, arbitrary::{self, Arbitrary, Unstructured}};
+use libc::{c_void, size_t};
+use std::mem::MaybeUninit;
+use zopfli::{ffi, util};
+
+/// Input made of long runs of one byte, or of a short pattern repeated,
+/// which is where the split point search sees the flattest costs.
+#[derive(Debug)]
+struct FuzzInput {
+    data: Vec<u8>,
+    maxblocks: usize,
+}
+
+impl<'a> Arbitrary<'a> for FuzzInput {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        let nruns = u.int_in_range(1..=32)?;
+        let mut data = Vec::new();
+        for _ in 0..nruns {
+            let period = u.int_in_range(1..=4)?;
+            let pattern: Vec<u8> = (0..period)
+                .map(|_| u.arbitrary())
+                .collect::<arbitrary::Result<_>>()?;
+            let len = u.int_in_range(1..=4096)?;
+            data.extend(pattern.iter().cycle().take(len));
+            if data.len() >= 65536 {
+                data.truncate(65536);
+                break;
+            }
+        }
+        let maxblocks = u.int_in_range(0..=20)?;
+
+        Ok(FuzzInput { data, maxblocks })
+    }
+}
+
+fuzz_target!(|input: FuzzInput| {
+    let options = ffi::ZopfliOptions {
+        verbose: 0,
+        verbose_more: 0,
+        numiterations: 15,
+        blocksplitting: 1,
+        blocksplittinglast: 0,
+        blocksplittingmax: 15,
+    };
+
+    // Both splitters run on the same store, made by the C greedy LZ77.
+    let mut s = MaybeUninit::<ffi::ZopfliBlockState>::uninit();
+    let mut store = MaybeUninit::<ffi::ZopfliLZ77Store>::uninit();
+    let mut hash = MaybeUninit::<ffi::ZopfliHash>::uninit();
+    let (s, store, hash) = (s.as_mut_ptr(), store.as_mut_ptr(), hash.as_mut_ptr());
+    unsafe {
+        ffi::ZopfliInitLZ77Store(input.data.as_ptr(), store);
+        ffi::ZopfliInitBlockState(&options, 0, input.data.len(), 0, s);
+        ffi::ZopfliAllocHash(util::ZOPFLI_WINDOW_SIZE, hash);
+        ffi::ZopfliLZ77Greedy(s, input.data.as_ptr(), 0, input.data.len(), store, hash);
+    }
+
+    let mut c_splitpoints: *mut size_t = std::ptr::null_mut();
+    let mut c_npoints: size_t = 0;
+    let mut rust_splitpoints: *mut size_t = std::ptr::null_mut();
+    let mut rust_npoints: size_t = 0;
+
+    unsafe {
+        ffi::ZopfliBlockSplitLZ77(
+            &options,
+            store,
+            input.maxblocks,
+            &mut c_splitpoints,
+            &mut c_npoints,
+        );
+        zopfli::blocksplitter::ZopfliBlockSplitLZ77(
+            &options,
+            store,
+            input.maxblocks,
+            &mut rust_splitpoints,
+            &mut rust_npoints,
+        );
+    }
+
+    assert_eq!(c_npoints, rust_npoints, "Number of splitpoints differs");
+
+    if c_npoints > 0 {
+        let c_splits = unsafe { std::slice::from_raw_parts(c_splitpoints, c_npoints) };
+        let rust_splits = unsafe { std::slice::from_raw_parts(rust_splitpoints, rust_npoints) };
+        assert_eq!(c_splits, rust_splits, "Splitpoints differ");
+        assert!(rust_splits.windows(2).all(|w| w[0] < w[1]), "Splitpoints not strictly increasing");
+    } else {
+        assert!(rust_splitpoints.is_null());
+    }
+
+    unsafe {
+        libc::free(c_splitpoints as *mut c_void);
+        libc::free(rust_splitpoints as *mut c_void);
+        ffi::ZopfliCleanHash(hash);
+        ffi::ZopfliCleanBlockState(s);
+        ffi::ZopfliCleanLZ77Store(store);
+    }
+});
@@ -109,14 +109,20 @@ unsafe extern "C" fn SplitCost(i: size_t, context: *mut c_void) -> f64 {
     EstimateCost(c.lz77, c.start, i) + EstimateCost(c.lz77, i, c.end)
 }
 
-fn AddSorted(value: size_t, out: &mut Vec<size_t>) {
-    out.push(value);
-    out.sort_unstable();
+/// Appends value to the C array and moves it to its sorted place. Equal
+/// values are not merged, like in C: the splitter only adds points strictly
+/// inside a block, so no point can come up twice.
+unsafe fn AddSorted(value: size_t, out: &mut *mut size_t, outsize: &mut size_t) {
+    util::ZOPFLI_APPEND_DATA(value, out, outsize);
+    let points = std::slice::from_raw_parts_mut(*out, *outsize);
+    if let Some(i) = points.iter().position(|&p| p > value) {
+        points[i..].rotate_right(1);
+    }
 }
 
 unsafe fn PrintBlockSplitPoints(
     lz77: &ZopfliLZ77Store,
-    lz77splitpoints: &Vec<size_t>,
+    lz77splitpoints: &[size_t],
 ) {
     let mut splitpoints: Vec<size_t> = Vec::new();
     let mut pos: size_t = 0;
@@ -156,7 +162,7 @@ unsafe fn PrintBlockSplitPoints(
 fn FindLargestSplittableBlock(
     lz77size: size_t,
     done: &[u8],
-    splitpoints: &Vec<size_t>,
+    splitpoints: &[size_t],
     lstart: &mut size_t,
     lend: &mut size_t,
 ) -> bool {
@@ -193,16 +199,12 @@ pub unsafe fn ZopfliBlockSplitLZ77(
     let lz77 = &*lz77;
     let options = &*options;
 
+    // This code fails on tiny files. The caller's points are left alone.
     if lz77.size < 10 {
-        *splitpoints = std::ptr::null_mut();
-        *npoints = 0;
         return;
     }
 
     let mut done: Vec<u8> = vec![0; lz77.size];
-    let mut c_splitpoints: *mut size_t = std::ptr::null_mut();
-    let mut c_splitpoints_size: size_t = 0;
-    let mut c_splitpoints_capacity: size_t = 0;
 
     let mut lstart = 0;
     let mut lend = lz77.size;
@@ -238,55 +240,16 @@ pub unsafe fn ZopfliBlockSplitLZ77(
         if splitcost > origcost || llpos == lstart + 1 || llpos == lend {
             done[lstart] = 1;
         } else {
-            // Add to C-style array using libc allocation
-            if c_splitpoints_size >= c_splitpoints_capacity {
-                let new_capacity = if c_splitpoints_capacity == 0 { 1 } else { c_splitpoints_capacity * 2 };
-                let new_ptr = if c_splitpoints.is_null() {
-                    libc::malloc(new_capacity * std::mem::size_of::<size_t>())
-                } else {
-                    libc::realloc(c_splitpoints as *mut c_void, new_capacity * std::mem::size_of::<size_t>())
-                };
-                if new_ptr.is_null() {
-                    panic!("Out of memory");
-                }
-                c_splitpoints = new_ptr as *mut size_t;
-                c_splitpoints_capacity = new_capacity;
-            }
-            
-            // Insert in sorted order
-            let mut insert_pos = c_splitpoints_size;
-            for i in 0..c_splitpoints_size {
-                if *c_splitpoints.add(i) > llpos {
-                    insert_pos = i;
-                    break;
-                }
-            }
-            
-            // Shift elements to make room
-            if insert_pos < c_splitpoints_size {
-                libc::memmove(
-                    c_splitpoints.add(insert_pos + 1) as *mut c_void,
-                    c_splitpoints.add(insert_pos) as *const c_void,
-                    (c_splitpoints_size - insert_pos) * std::mem::size_of::<size_t>(),
-                );
-            }
-            
-            *c_splitpoints.add(insert_pos) = llpos;
-            c_splitpoints_size += 1;
+            AddSorted(llpos, &mut *splitpoints, &mut *npoints);
             numblocks += 1;
         }
 
-        if !FindLargestSplittableBlock(
-            lz77.size,
-            &done,
-            &if c_splitpoints_size > 0 { 
-                std::slice::from_raw_parts(c_splitpoints, c_splitpoints_size).to_vec()
-            } else { 
-                Vec::new() 
-            },
-            &mut lstart,
-            &mut lend,
-        ) {
+        let points = if *npoints > 0 {
+            std::slice::from_raw_parts(*splitpoints, *npoints)
+        } else {
+            &[]
+        };
+        if !FindLargestSplittableBlock(lz77.size, &done, points, &mut lstart, &mut lend) {
             break;
         }
 
@@ -295,13 +258,14 @@ pub unsafe fn ZopfliBlockSplitLZ77(
         }
     }
 
-    if options.verbose > 0 && c_splitpoints_size > 0 {
-        let splitpoints_vec = std::slice::from_raw_parts(c_splitpoints, c_splitpoints_size).to_vec();
-        PrintBlockSplitPoints(lz77, &splitpoints_vec);
+    if options.verbose > 0 {
+        let points = if *npoints > 0 {
+            std::slice::from_raw_parts(*splitpoints, *npoints)
+        } else {
+            &[]
+        };
+        PrintBlockSplitPoints(lz77, points);
     }
-
-    *npoints = c_splitpoints_size;
-    *splitpoints = c_splitpoints;
 }
 
 pub unsafe fn ZopfliBlockSplit(
@@ -374,23 +338,11 @@ pub fn ZopfliBlockSplitSimple(
 ) {
     // The C function is "simple" and doesn't actually use the input data,
     // so we don't need to construct a slice for it.
-    // We just need to honor the instart, inend, and blocksize parameters.
-
-    // A more Rusty way to do this without unsafe code in a loop.
-    let mut points = Vec::new();
+    // The points are appended with malloc/realloc like in C, so the caller
+    // frees them with free() and an empty range leaves *splitpoints alone.
     let mut i = instart;
     while i < inend {
-        points.push(i);
+        unsafe { util::ZOPFLI_APPEND_DATA(i, &mut *splitpoints, &mut *npoints) };
         i += blocksize;
     }
-
-    // Now, give ownership of the data to the C code.
-    unsafe {
-        // Leaking the memory, so C code is responsible for freeing it.
-        points.shrink_to_fit();
-        let (ptr, len) = (points.as_mut_ptr(), points.len());
-        std::mem::forget(points);
-        *splitpoints = ptr;
-        *npoints = len;
-    }
 }
@@ -1007,6 +1007,19 @@ pub unsafe fn EncodeTree(
     result_size
 }
 
+/// The signature of ZopfliDeflate, shared by the functions that can output
+/// the DEFLATE stream of a container in its place.
+pub type DeflateFn = unsafe fn(
+    *const ZopfliOptions,
+    c_int,
+    c_int,
+    *const c_uchar,
+    size_t,
+    *mut c_uchar,
+    *mut *mut c_uchar,
+    *mut size_t,
+);
+
 pub unsafe fn ZopfliDeflate(
     options: *const ZopfliOptions,
     btype: c_int,
@@ -1016,17 +1029,41 @@ pub unsafe fn ZopfliDeflate(
     bp: *mut c_uchar,
     out: *mut *mut c_uchar,
     outsize: *mut size_t,
+) {
+    ZopfliDeflateFrom(options, btype, final_block, r#in, 0, insize, bp, out, outsize);
+}
+
+/// Like ZopfliDeflate, but compresses only the bytes from instart to inend.
+/// The bytes before instart are not output, but fill the window matches can
+/// refer back to, as with a zlib preset dictionary.
+///
+/// # Safety
+///
+/// As for ZopfliDeflate, with r#in readable up to inend, which must not be
+/// before instart.
+#[allow(clippy::too_many_arguments)]
+pub unsafe fn ZopfliDeflateFrom(
+    options: *const ZopfliOptions,
+    btype: c_int,
+    final_block: c_int,
+    r#in: *const c_uchar,
+    instart: size_t,
+    inend: size_t,
+    bp: *mut c_uchar,
+    out: *mut *mut c_uchar,
+    outsize: *mut size_t,
 ) {
     let offset = *outsize;
+    let insize = inend - instart;
     
     if crate::util::ZOPFLI_MASTER_BLOCK_SIZE == 0 {
-        ZopfliDeflatePart(options, btype, final_block, r#in, 0, insize, bp, out, outsize);
+        ZopfliDeflatePart(options, btype, final_block, r#in, instart, inend, bp, out, outsize);
     } else {
-        let mut i = 0;
+        let mut i = instart;
         loop {
-            let masterfinal = i + crate::util::ZOPFLI_MASTER_BLOCK_SIZE >= insize;
+            let masterfinal = i + crate::util::ZOPFLI_MASTER_BLOCK_SIZE >= inend;
             let final2 = (final_block != 0) && masterfinal;
-            let size = if masterfinal { insize - i } else { crate::util::ZOPFLI_MASTER_BLOCK_SIZE };
+            let size = if masterfinal { inend - i } else { crate::util::ZOPFLI_MASTER_BLOCK_SIZE };
             
             ZopfliDeflatePart(
                 options,
@@ -1041,7 +1078,7 @@ pub unsafe fn ZopfliDeflate(
             );
             i += size;
             
-            if i >= insize {
+            if i >= inend {
                 break;
             }
         }
@@ -1058,6 +1095,71 @@ pub unsafe fn ZopfliDeflate(
     }
 }
 
+/// Like ZopfliDeflate, but quick: skips the optimal LZ77 iterations and the
+/// block splitting, and outputs each master block as a single block of the
+/// greedy LZ77 parse. btype 2 gives it the dynamic Huffman tree of the
+/// parse, btype 1 the fixed tree, btype 0 stores the input.
+///
+/// # Safety
+///
+/// As for ZopfliDeflate: options must point to valid options, r#in to
+/// insize readable bytes, bp to the bit position in the last byte of out,
+/// and out and outsize to a buffer allocated with malloc, or NULL, and its
+/// size, which the output is appended to.
+#[allow(clippy::too_many_arguments)]
+pub unsafe fn ZopfliDeflateFast(
+    options: *const ZopfliOptions,
+    btype: c_int,
+    final_block: c_int,
+    r#in: *const c_uchar,
+    insize: size_t,
+    bp: *mut c_uchar,
+    out: *mut *mut c_uchar,
+    outsize: *mut size_t,
+) {
+    let offset = *outsize;
+    let mut i = 0;
+    loop {
+        let masterfinal = i + crate::util::ZOPFLI_MASTER_BLOCK_SIZE >= insize
+            || crate::util::ZOPFLI_MASTER_BLOCK_SIZE == 0;
+        let final2 = (final_block != 0) && masterfinal;
+        let size = if masterfinal { insize - i } else { crate::util::ZOPFLI_MASTER_BLOCK_SIZE };
+
+        if btype == 0 {
+            AddNonCompressedBlock(options, final2 as c_int, r#in, i, i + size, bp, out, outsize);
+        } else {
+            let mut s: ZopfliBlockState = std::mem::zeroed();
+            let mut store: ZopfliLZ77Store = std::mem::zeroed();
+            let mut h: ffi::ZopfliHash = std::mem::zeroed();
+            ffi::ZopfliInitLZ77Store(r#in, &mut store);
+            ffi::ZopfliInitBlockState(options, i, i + size, 0, &mut s);
+            ffi::ZopfliAllocHash(crate::util::ZOPFLI_WINDOW_SIZE, &mut h);
+
+            ffi::ZopfliLZ77Greedy(&mut s, r#in, i, i + size, &mut store, &mut h);
+            AddLZ77Block(options, btype, final2 as c_int, &store, 0, store.size, 0, bp, out, outsize);
+
+            ffi::ZopfliCleanHash(&mut h);
+            ffi::ZopfliCleanBlockState(&mut s);
+            ffi::ZopfliCleanLZ77Store(&mut store);
+        }
+        i += size;
+
+        if i >= insize {
+            break;
+        }
+    }
+
+    if (*options).verbose != 0 {
+        let removed_percent = 100.0 * (insize as f64 - (*outsize - offset) as f64) / insize as f64;
+        eprintln!(
+            "Original Size: {}, Deflate: {}, Compression: {:.6}% Removed",
+            insize,
+            *outsize - offset,
+            removed_percent
+        );
+    }
+}
+
 pub unsafe fn ZopfliDeflatePart(
     options: *const ZopfliOptions,
     btype: c_int,
@@ -1114,7 +1216,7 @@ pub unsafe fn ZopfliDeflatePart(
         let mut store: ZopfliLZ77Store = std::mem::zeroed();
         ffi::ZopfliInitLZ77Store(r#in, &mut store);
         ffi::ZopfliInitBlockState(options, start, end, 1, &mut s);
-        ffi::ZopfliLZ77Optimal(&mut s, r#in, start, end, (*options).numiterations, &mut store);
+        crate::squeeze::ZopfliLZ77Optimal(&mut s, r#in, start, end, (*options).numiterations, &mut store);
         totalcost += ZopfliCalculateBlockSizeAutoType(&store, 0, store.size);
 
         ffi::ZopfliAppendLZ77Store(&store, &mut lz77);
@@ -1174,3 +1276,101 @@ pub unsafe fn ZopfliDeflatePart(
     libc::free(splitpoints as *mut libc::c_void);
     libc::free(splitpoints_uncompressed as *mut libc::c_void);
 }
+
+/// A raw DEFLATE stream compressed a buffer at a time, for input that is
+/// not all at hand at once. Each push outputs its buffer as one or more
+/// non-final blocks, whose matches can refer back into the last
+/// ZOPFLI_WINDOW_SIZE bytes pushed before it, and finish ends the stream
+/// with an empty final block.
+pub struct DeflateStream {
+    options: ZopfliOptions,
+    window: Vec<u8>,
+    bp: c_uchar,
+    out: *mut c_uchar,
+    outsize: size_t,
+}
+
+impl DeflateStream {
+    pub fn new(options: &ZopfliOptions) -> Self {
+        DeflateStream {
+            options: *options,
+            window: Vec::new(),
+            bp: 0,
+            out: ptr::null_mut(),
+            outsize: 0,
+        }
+    }
+
+    /// Compresses input into non-final blocks. An empty input outputs
+    /// nothing.
+    pub fn push(&mut self, input: &[u8]) {
+        if input.is_empty() {
+            return;
+        }
+        let instart = self.window.len();
+        self.window.extend_from_slice(input);
+        unsafe {
+            ZopfliDeflateFrom(
+                &self.options,
+                2,
+                0,
+                self.window.as_ptr(),
+                instart,
+                self.window.len(),
+                &mut self.bp,
+                &mut self.out,
+                &mut self.outsize,
+            );
+        }
+        let excess = self.window.len().saturating_sub(crate::util::ZOPFLI_WINDOW_SIZE);
+        self.window.drain(..excess);
+    }
+
+    /// Takes the whole bytes output so far. A byte that later blocks still
+    /// have bits to add to is kept until they do.
+    pub fn take_output(&mut self) -> Vec<u8> {
+        let whole = if self.bp == 0 { self.outsize } else { self.outsize - 1 };
+        let mut result = Vec::with_capacity(whole);
+        unsafe {
+            if whole > 0 {
+                result.extend_from_slice(std::slice::from_raw_parts(self.out, whole));
+            }
+            let partial = (self.bp != 0).then(|| *self.out.add(whole));
+            free(self.out as *mut libc::c_void);
+            self.out = ptr::null_mut();
+            self.outsize = 0;
+            if let Some(partial) = partial {
+                ZOPFLI_APPEND_DATA(partial, &mut self.out, &mut self.outsize);
+            }
+        }
+        result
+    }
+
+    /// Outputs the final block and returns the rest of the stream, what
+    /// take_output did not return before.
+    pub fn finish(mut self) -> Vec<u8> {
+        let end = self.window.len();
+        unsafe {
+            ZopfliDeflateFrom(
+                &self.options,
+                2,
+                1,
+                self.window.as_ptr(),
+                end,
+                end,
+                &mut self.bp,
+                &mut self.out,
+                &mut self.outsize,
+            );
+        }
+        // Nothing follows, so the last byte is whole, padded with zeros.
+        self.bp = 0;
+        self.take_output()
+    }
+}
+
+impl Drop for DeflateStream {
+    fn drop(&mut self) {
+        unsafe { free(self.out as *mut libc::c_void) };
+    }
+}
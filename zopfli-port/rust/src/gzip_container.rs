@@ -1,7 +1,7 @@
-use crate::deflate::ZopfliDeflate;
+use crate::deflate::{DeflateFn, ZopfliDeflate};
 use crate::ffi::ZopfliOptions;
 use crate::util::ZOPFLI_APPEND_DATA;
-use libc::{c_uchar, size_t};
+use libc::{c_int, c_uchar, size_t};
 use std::io::{self, Write};
 
 const CRC32_TABLE: [u32; 256] = [
@@ -64,6 +64,27 @@ pub unsafe fn ZopfliGzipCompress(
     insize: size_t,
     out: *mut *mut c_uchar,
     outsize: *mut size_t,
+) {
+    ZopfliGzipCompressWith(options, ZopfliDeflate, 2 /* Dynamic block */,
+                           r#in, insize, out, outsize);
+}
+
+/// Like ZopfliGzipCompress, but with the DEFLATE stream deflate outputs in
+/// blocks of type btype, such as the greedy parse of ZopfliDeflateFast.
+///
+/// # Safety
+///
+/// options must point to valid options, r#in to insize readable bytes, and
+/// out and outsize to a buffer allocated with malloc, or NULL, and its size,
+/// which the output is appended to.
+pub unsafe fn ZopfliGzipCompressWith(
+    options: *const ZopfliOptions,
+    deflate: DeflateFn,
+    btype: c_int,
+    r#in: *const c_uchar,
+    insize: size_t,
+    out: *mut *mut c_uchar,
+    outsize: *mut size_t,
 ) {
     let input_slice = std::slice::from_raw_parts(r#in, insize);
     let crcvalue = crc(input_slice);
@@ -82,8 +103,7 @@ pub unsafe fn ZopfliGzipCompress(
     ZOPFLI_APPEND_DATA(2u8, &mut *out, &mut *outsize);   // XFL, 2 indicates best compression
     ZOPFLI_APPEND_DATA(3u8, &mut *out, &mut *outsize);   // OS follows Unix conventions
 
-    ZopfliDeflate(options, 2 /* Dynamic block */, 1,
-                  r#in, insize, &mut bp, out, outsize);
+    deflate(options, btype, 1, r#in, insize, &mut bp, out, outsize);
 
     // CRC
     ZOPFLI_APPEND_DATA((crcvalue % 256) as u8, &mut *out, &mut *outsize);
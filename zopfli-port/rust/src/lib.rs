@@ -3,6 +3,7 @@
 #![allow(unused_variables)]
 #![allow(unused_imports)]
 #![allow(non_camel_case_types)]
+#![cfg_attr(feature = "simd", feature(portable_simd))]
 
 pub mod lz77;
 pub mod ffi;
@@ -30,3 +31,5 @@ pub mod zlib_container;
 pub mod gzip_container;
 
 pub mod zopfli_lib;
+
+pub mod options;
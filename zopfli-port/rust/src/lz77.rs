@@ -339,6 +339,7 @@ use crate::util::{
 };
 use std::os::raw::{c_uchar, c_ushort};
 use libc::c_uint;
+use crate::zopfli::BlockType;
 
 unsafe fn TryGetFromLongestMatchCache(
     s: *mut ffi::ZopfliBlockState,
@@ -421,7 +422,15 @@ unsafe fn StoreInLongestMatchCache(
     }
 }
 
-unsafe fn GetMatch(
+/// Finds how far scan and match_ are equal, up to end: the first differing
+/// byte in scan, or end. Compares a word at a time while before safe_end,
+/// which must be at most end - 8, then a byte at a time.
+///
+/// # Safety
+///
+/// scan and match_ must be readable up to end, which must not be before
+/// scan.
+pub unsafe fn GetMatch(
     scan: *const c_uchar,
     match_: *const c_uchar,
     end: *const c_uchar,
@@ -470,6 +479,44 @@ unsafe fn GetMatch(
     scan_ptr
 }
 
+/// Like GetMatch, but skipping equal 32 and then 16 byte chunks with
+/// portable SIMD first. GetMatch finds the differing byte in the chunk that
+/// has one, and compares the rest, so no byte at or after end is counted.
+///
+/// # Safety
+///
+/// scan and match_ must be readable up to end, which must not be before
+/// scan.
+#[cfg(feature = "simd")]
+pub unsafe fn GetMatchSimd(
+    scan: *const c_uchar,
+    match_: *const c_uchar,
+    end: *const c_uchar,
+    safe_end: *const c_uchar,
+) -> *const c_uchar {
+    use std::simd::{u8x16, u8x32};
+
+    let mut scan_ptr = scan;
+    let mut match_ptr = match_;
+
+    while end as usize - scan_ptr as usize >= 32
+        && u8x32::from_array(std::ptr::read_unaligned(scan_ptr as *const [u8; 32]))
+            == u8x32::from_array(std::ptr::read_unaligned(match_ptr as *const [u8; 32]))
+    {
+        scan_ptr = scan_ptr.add(32);
+        match_ptr = match_ptr.add(32);
+    }
+    if end as usize - scan_ptr as usize >= 16
+        && u8x16::from_array(std::ptr::read_unaligned(scan_ptr as *const [u8; 16]))
+            == u8x16::from_array(std::ptr::read_unaligned(match_ptr as *const [u8; 16]))
+    {
+        scan_ptr = scan_ptr.add(16);
+        match_ptr = match_ptr.add(16);
+    }
+
+    GetMatch(scan_ptr, match_ptr, end, safe_end)
+}
+
 pub unsafe fn ZopfliFindLongestMatch(
     s: *mut ffi::ZopfliBlockState,
     h: *const ffi::ZopfliHash,
@@ -552,6 +599,9 @@ pub unsafe fn ZopfliFindLongestMatch(
                     match_ = match_.add(same as usize);
                 }
 
+                #[cfg(feature = "simd")]
+                let scan_end = GetMatchSimd(scan, match_, arrayend, arrayend_safe);
+                #[cfg(not(feature = "simd"))]
                 let scan_end = GetMatch(scan, match_, arrayend, arrayend_safe);
                 currentlength = (scan_end as usize - (array.add(pos) as usize)) as c_ushort;
             }
@@ -718,3 +768,94 @@ pub unsafe fn ZopfliLZ77Greedy(
         i += 1;
     }
 }
+
+/// An owned ZopfliLZ77Store: the literals, lengths and distances of an LZ77
+/// parse of an input it borrows.
+pub struct Lz77Store<'a> {
+    store: ZopfliLZ77Store,
+    _input: std::marker::PhantomData<&'a [u8]>,
+}
+
+impl<'a> Lz77Store<'a> {
+    /// The greedy parse of input, as used for block splitting.
+    pub fn greedy(options: &ffi::ZopfliOptions, input: &'a [u8]) -> Self {
+        let mut result = Self::empty(input);
+        unsafe {
+            let mut s: ZopfliBlockState = std::mem::zeroed();
+            let mut h: ffi::ZopfliHash = std::mem::zeroed();
+            ZopfliInitBlockState(options, 0, input.len(), 0, &mut s);
+            crate::hash::ZopfliAllocHash(crate::util::ZOPFLI_WINDOW_SIZE, &mut h);
+            ZopfliLZ77Greedy(&mut s, input.as_ptr(), 0, input.len(), &mut result.store, &mut h);
+            crate::hash::ZopfliCleanHash(&mut h);
+            ZopfliCleanBlockState(&mut s);
+        }
+        result
+    }
+
+    /// The optimal parse of input, after options.numiterations iterations,
+    /// as compressed in a single dynamic block. With no iterations, the
+    /// greedy parse.
+    pub fn optimal(options: &ffi::ZopfliOptions, input: &'a [u8]) -> Self {
+        let mut result = Self::empty(input);
+        unsafe {
+            let mut s: ZopfliBlockState = std::mem::zeroed();
+            ZopfliInitBlockState(options, 0, input.len(), 1, &mut s);
+            crate::squeeze::ZopfliLZ77Optimal(
+                &mut s,
+                input.as_ptr(),
+                0,
+                input.len(),
+                options.numiterations,
+                &mut result.store,
+            );
+            ZopfliCleanBlockState(&mut s);
+        }
+        result
+    }
+
+    fn empty(input: &'a [u8]) -> Self {
+        let mut store: ZopfliLZ77Store = unsafe { std::mem::zeroed() };
+        ZopfliInitLZ77Store(input.as_ptr(), &mut store);
+        Lz77Store {
+            store,
+            _input: std::marker::PhantomData,
+        }
+    }
+
+    /// The number of literals and length/distance pairs.
+    pub fn len(&self) -> usize {
+        self.store.size
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.store.size == 0
+    }
+
+    /// The store, for the raw functions.
+    pub fn as_raw(&self) -> *const ZopfliLZ77Store {
+        &self.store
+    }
+
+    /// The size in bits of the symbols in range, among 0..len(), output as
+    /// a block of type btype, header included, or as the smallest of the
+    /// three types if btype is None.
+    ///
+    /// Panics if range is out of bounds.
+    pub fn block_size_bits(&self, range: std::ops::Range<usize>, btype: Option<BlockType>) -> f64 {
+        assert!(range.start <= range.end && range.end <= self.len(), "range out of bounds");
+        unsafe {
+            match btype {
+                None => crate::deflate::ZopfliCalculateBlockSizeAutoType(&self.store, range.start, range.end),
+                Some(btype) => {
+                    crate::deflate::ZopfliCalculateBlockSize(&self.store, range.start, range.end, c_int::from(btype))
+                }
+            }
+        }
+    }
+}
+
+impl Drop for Lz77Store<'_> {
+    fn drop(&mut self) {
+        unsafe { ZopfliCleanLZ77Store(&mut self.store) };
+    }
+}
@@ -1,11 +1,12 @@
 use std::env;
-use std::fs;
+use std::fs::{self, OpenOptions};
 use std::io::{self, Read, Write};
+use std::path::Path;
 use std::process;
 
 use zopfli::ffi::ZopfliOptions;
 use zopfli::zopfli::ZopfliFormat;
-use zopfli::zopfli_lib::ZopfliCompress;
+use zopfli::zopfli_lib::{ZopfliCompress, ZopfliCompressFast, ZopfliCompressFixed};
 
 fn print_usage(program_name: &str) {
     eprintln!("Usage: {} [OPTIONS] [INPUT_FILE] [OUTPUT_FILE]", program_name);
@@ -16,10 +17,22 @@ fn print_usage(program_name: &str) {
     eprintln!("  --i-gzip NUM           Iterations for gzip format");
     eprintln!("  --i-deflate NUM        Iterations for deflate format");
     eprintln!("  --i-zlib NUM           Iterations for zlib format");
+    eprintln!("  --fast                 Greedy parse in one block: much faster, larger output");
+    eprintln!("  --fixed                Only fixed Huffman blocks");
+    eprintln!("  -c, --stdout           Write to stdout and keep the input file");
+    eprintln!("  -k, --keep             Keep the input file");
+    eprintln!("  --force                Overwrite existing output files");
     eprintln!("  -h, --help             Show this help message");
     eprintln!();
-    eprintln!("If no input file is specified, reads from stdin.");
-    eprintln!("If no output file is specified, writes to stdout.");
+    eprintln!("If no input file is specified, reads from stdin and writes to stdout.");
+    eprintln!("If an input file but no output file is specified, gzip output goes to");
+    eprintln!("the input file name with .gz appended and, like gzip, the input file");
+    eprintln!("is deleted unless --keep is given. zlib and deflate output goes to stdout.");
+}
+
+/// The file name gzip-style compression of input writes to.
+fn derived_output(input: &str) -> String {
+    format!("{}.gz", input)
 }
 
 fn parse_format(format_str: &str) -> Result<ZopfliFormat, String> {
@@ -46,6 +59,11 @@ fn main() {
     };
     let mut input_file: Option<String> = None;
     let mut output_file: Option<String> = None;
+    let mut to_stdout = false;
+    let mut keep = false;
+    let mut force = false;
+    let mut fast = false;
+    let mut fixed = false;
     
     let mut i = 1;
     while i < args.len() {
@@ -54,6 +72,11 @@ fn main() {
                 print_usage(program_name);
                 return;
             }
+            "-c" | "--stdout" => to_stdout = true,
+            "-k" | "--keep" => keep = true,
+            "--force" => force = true,
+            "--fast" => fast = true,
+            "--fixed" => fixed = true,
             "-f" | "--format" => {
                 if i + 1 >= args.len() {
                     eprintln!("Error: {} requires an argument", args[i]);
@@ -156,8 +179,37 @@ fn main() {
         i += 1;
     }
     
+    if fast && fixed {
+        eprintln!("Error: --fast and --fixed are mutually exclusive");
+        process::exit(1);
+    }
+    if to_stdout && output_file.is_some() {
+        eprintln!("Error: --stdout and an output file are mutually exclusive");
+        process::exit(1);
+    }
+
+    // Like gzip, compress FILE to FILE.gz and delete FILE, unless told
+    // otherwise. Other formats go to stdout.
+    let mut derived_name = false;
+    let mut remove_input = false;
+    let gzip = format == ZopfliFormat::ZOPFLI_FORMAT_GZIP;
+    if let (Some(filename), None, false, true) = (&input_file, &output_file, to_stdout, gzip) {
+        let derived = derived_output(filename);
+        if filename.ends_with(".gz") && !force {
+            eprintln!("Error: '{}' already has .gz suffix -- unchanged", filename);
+            process::exit(1);
+        }
+        if !force && Path::new(&derived).exists() {
+            eprintln!("Error: '{}' already exists; use --force to overwrite", derived);
+            process::exit(1);
+        }
+        output_file = Some(derived);
+        derived_name = true;
+        remove_input = !keep;
+    }
+
     // Read input data
-    let input_data = match input_file {
+    let input_data = match &input_file {
         Some(filename) => {
             match fs::read(&filename) {
                 Ok(data) => data,
@@ -185,12 +237,29 @@ fn main() {
     
     // Compress the data
     let mut output_data = Vec::new();
-    ZopfliCompress(&options, format, &input_data, &mut output_data);
+    if fast {
+        ZopfliCompressFast(&options, format, &input_data, &mut output_data);
+    } else if fixed {
+        ZopfliCompressFixed(&options, format, &input_data, &mut output_data);
+    } else {
+        ZopfliCompress(&options, format, &input_data, &mut output_data);
+    }
     
     // Write output data
-    match output_file {
+    match &output_file {
         Some(filename) => {
-            if let Err(e) = fs::write(&filename, &output_data) {
+            // A derived name is never overwritten without --force, even if
+            // the file appeared while compressing.
+            let mut open = OpenOptions::new();
+            if derived_name && !force {
+                open.write(true).create_new(true);
+            } else {
+                open.write(true).create(true).truncate(true);
+            }
+            if let Err(e) = open
+                .open(filename)
+                .and_then(|mut file| file.write_all(&output_data))
+            {
                 eprintln!("Error writing file '{}': {}", filename, e);
                 process::exit(1);
             }
@@ -203,6 +272,15 @@ fn main() {
         }
     }
     
+    if remove_input {
+        if let Some(filename) = &input_file {
+            if let Err(e) = fs::remove_file(filename) {
+                eprintln!("Error removing file '{}': {}", filename, e);
+                process::exit(1);
+            }
+        }
+    }
+    
     // Print compression statistics to stderr
     let compression_ratio = if input_data.is_empty() {
         0.0
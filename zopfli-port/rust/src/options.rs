@@ -0,0 +1,96 @@
+//! Plain Rust mirrors of ZopfliOptions and ZopfliFormat, which can be
+//! loaded from configuration files with the serde feature. Convert them to
+//! the C structs with From.
+
+use std::os::raw::c_int;
+
+use crate::ffi::ZopfliOptions;
+use crate::zopfli::ZopfliFormat;
+
+/// The settings of ZopfliOptions. Missing fields take the defaults of
+/// ZopfliInitOptions when deserialized.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, deny_unknown_fields))]
+pub struct Options {
+    /// Whether to print output.
+    pub verbose: bool,
+    /// Whether to print more detailed output.
+    pub verbose_more: bool,
+    /// Maximum amount of times to rerun forward and backward pass to optimize
+    /// LZ77 compression cost. Good values: 10, 15 for small files, 5 for
+    /// files over several MB in size or it will be too slow.
+    pub numiterations: i32,
+    /// Whether to split the data in multiple deflate blocks with optimal
+    /// choice for the block boundaries. Block splitting gives better
+    /// compression.
+    pub blocksplitting: bool,
+    /// No longer used, left for compatibility.
+    pub blocksplittinglast: bool,
+    /// Maximum amount of blocks to split into (0 for unlimited).
+    pub blocksplittingmax: i32,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        ZopfliOptions::default().into()
+    }
+}
+
+impl From<Options> for ZopfliOptions {
+    fn from(options: Options) -> Self {
+        ZopfliOptions {
+            verbose: options.verbose as c_int,
+            verbose_more: options.verbose_more as c_int,
+            numiterations: options.numiterations,
+            blocksplitting: options.blocksplitting as c_int,
+            blocksplittinglast: options.blocksplittinglast as c_int,
+            blocksplittingmax: options.blocksplittingmax,
+        }
+    }
+}
+
+impl From<ZopfliOptions> for Options {
+    fn from(options: ZopfliOptions) -> Self {
+        Options {
+            verbose: options.verbose != 0,
+            verbose_more: options.verbose_more != 0,
+            numiterations: options.numiterations,
+            blocksplitting: options.blocksplitting != 0,
+            blocksplittinglast: options.blocksplittinglast != 0,
+            blocksplittingmax: options.blocksplittingmax,
+        }
+    }
+}
+
+/// The output formats of ZopfliFormat, named "gzip", "zlib" and "deflate"
+/// when serialized.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum Format {
+    #[default]
+    Gzip,
+    Zlib,
+    Deflate,
+}
+
+impl From<Format> for ZopfliFormat {
+    fn from(format: Format) -> Self {
+        match format {
+            Format::Gzip => ZopfliFormat::ZOPFLI_FORMAT_GZIP,
+            Format::Zlib => ZopfliFormat::ZOPFLI_FORMAT_ZLIB,
+            Format::Deflate => ZopfliFormat::ZOPFLI_FORMAT_DEFLATE,
+        }
+    }
+}
+
+impl From<ZopfliFormat> for Format {
+    fn from(format: ZopfliFormat) -> Self {
+        match format {
+            ZopfliFormat::ZOPFLI_FORMAT_GZIP => Format::Gzip,
+            ZopfliFormat::ZOPFLI_FORMAT_ZLIB => Format::Zlib,
+            ZopfliFormat::ZOPFLI_FORMAT_DEFLATE => Format::Deflate,
+        }
+    }
+}
@@ -466,6 +466,11 @@ pub unsafe fn ZopfliLZ77Optimal(
         }
         lastcost = cost;
     }
+    // Without iterations C leaves store empty, which loses the input;
+    // the greedy parse the iterations start from is the best there is.
+    if numiterations <= 0 {
+        ZopfliCopyLZ77Store(&currentstore, store);
+    }
     ZopfliCleanLZ77Store(&mut currentstore);
     ZopfliCleanHash(&mut *h);
 }
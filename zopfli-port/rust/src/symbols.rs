@@ -1,123 +1,133 @@
 use std::os::raw::c_int;
 
-pub fn ZopfliGetDistExtraBits(dist: c_int) -> c_int {
-    if dist < 5 {
-        0
-    } else {
-        // This is equivalent to `floor(log2(dist - 1)) - 1`.
-        // The C code uses `(31 ^ __builtin_clz(dist - 1)) - 1`.
-        // `31 - leading_zeros` is a more stable way to get `floor(log2)`.
-        (31 - (dist as u32 - 1).leading_zeros() as c_int) - 1
+// The lookup tables below are generated at compile time from the DEFLATE
+// length and distance codes of RFC 1951 section 3.2.5.
+
+/// The first length of each length symbol, 257 to 285.
+const LENGTH_SYMBOL_BASE: [c_int; 29] = [
+    3, 4, 5, 6, 7, 8, 9, 10, 11, 13, 15, 17, 19, 23, 27, 31, 35, 43, 51, 59, 67, 83, 99, 115,
+    131, 163, 195, 227, 258,
+];
+
+/// The extra bits of each length symbol, 257 to 285.
+const LENGTH_SYMBOL_EXTRA_BITS: [c_int; 29] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4, 5, 5, 5, 5, 0,
+];
+
+/// The first distance of each distance symbol.
+const DIST_SYMBOL_BASE: [c_int; 30] = [
+    1, 2, 3, 4, 5, 7, 9, 13, 17, 25, 33, 49, 65, 97, 129, 193, 257, 385, 513, 769, 1025, 1537,
+    2049, 3073, 4097, 6145, 8193, 12289, 16385, 24577,
+];
+
+/// The extra bits of each distance symbol.
+const DIST_SYMBOL_EXTRA_BITS: [c_int; 30] = [
+    0, 0, 0, 0, 1, 1, 2, 2, 3, 3, 4, 4, 5, 5, 6, 6, 7, 7, 8, 8, 9, 9, 10, 10, 11, 11, 12, 12, 13,
+    13,
+];
+
+/// The largest distance DEFLATE can encode, and the last one in the
+/// distance tables.
+const MAX_DIST: c_int = 32768;
+
+/// The length symbol of each length up to 258, 0 for the lengths below 3,
+/// which have none.
+const LENGTH_SYMBOL_TABLE: [c_int; 259] = length_symbols();
+
+/// The extra bits of each length up to 258.
+const LENGTH_EXTRA_BITS_TABLE: [c_int; 259] = length_extra(false);
+
+/// The value of the extra bits of each length up to 258.
+const LENGTH_EXTRA_BITS_VALUE_TABLE: [c_int; 259] = length_extra(true);
+
+/// The distance symbol of each distance up to MAX_DIST, 0 for distance 0,
+/// which has none.
+static DIST_SYMBOL_TABLE: [u8; MAX_DIST as usize + 1] = dist_symbols();
+
+/// The symbol whose range of values starting at base holds value.
+const fn symbol_index(base: &[c_int], value: c_int) -> usize {
+    let mut s = 0;
+    while s + 1 < base.len() && base[s + 1] <= value {
+        s += 1;
     }
+    s
 }
 
-pub fn ZopfliGetDistExtraBitsValue(dist: c_int) -> c_int {
-    if dist < 5 {
-        return 0;
+const fn length_symbols() -> [c_int; 259] {
+    let mut table = [0; 259];
+    let mut l = 3;
+    while l < table.len() {
+        table[l] = 257 + symbol_index(&LENGTH_SYMBOL_BASE, l as c_int) as c_int;
+        l += 1;
     }
-    // In C: l = 31 ^ __builtin_clz(dist - 1), which is log2(dist - 1)
-    // In Rust, this is more idiomatically calculated as:
-    let l = 31 - (dist as u32 - 1).leading_zeros() as c_int;
-    (dist - (1 + (1 << l))) & ((1 << (l - 1)) - 1)
+    table
 }
 
-pub fn ZopfliGetDistSymbol(dist: c_int) -> c_int {
-    if dist < 5 {
-        dist - 1
-    } else {
-        // In C: l = 31 ^ __builtin_clz(dist - 1), which is floor(log2(dist - 1))
-        // In Rust, this is more idiomatically calculated as:
-        let l = 31 - (dist as u32 - 1).leading_zeros() as c_int;
-        let r = ((dist - 1) >> (l - 1)) & 1;
-        l * 2 + r
+const fn length_extra(value: bool) -> [c_int; 259] {
+    let mut table = [0; 259];
+    let mut l = 3;
+    while l < table.len() {
+        let s = (LENGTH_SYMBOL_TABLE[l] - 257) as usize;
+        table[l] = if value {
+            l as c_int - LENGTH_SYMBOL_BASE[s]
+        } else {
+            LENGTH_SYMBOL_EXTRA_BITS[s]
+        };
+        l += 1;
     }
+    table
 }
 
-pub fn ZopfliGetLengthExtraBits(l: c_int) -> c_int {
-    static ZOPFLI_LENGTH_EXTRA_BITS_TABLE: [c_int; 259] = [
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-        2, 2, 2, 2, 2, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
-        3, 3, 3, 3, 3, 3, 3, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
-        4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
-        4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5,
-        5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5,
-        5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5,
-        5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5,
-        5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 0,
-    ];
-    if l < 0 || l as usize >= ZOPFLI_LENGTH_EXTRA_BITS_TABLE.len() {
-        0  // Return 0 for invalid lengths (defensive programming)
-    } else {
-        ZOPFLI_LENGTH_EXTRA_BITS_TABLE[l as usize]
+const fn dist_symbols() -> [u8; MAX_DIST as usize + 1] {
+    // One pass over the symbols, each filling its range of distances.
+    let mut table = [0; MAX_DIST as usize + 1];
+    let mut s = 0;
+    while s < DIST_SYMBOL_BASE.len() {
+        let mut dist = DIST_SYMBOL_BASE[s];
+        let end = dist + (1 << DIST_SYMBOL_EXTRA_BITS[s]);
+        while dist < end {
+            table[dist as usize] = s as u8;
+            dist += 1;
+        }
+        s += 1;
     }
+    table
+}
+
+// The distance functions are only defined for the distances DEFLATE can
+// encode, 1 to MAX_DIST, which is all zopfli ever passes them.
+
+pub fn ZopfliGetDistExtraBits(dist: c_int) -> c_int {
+    debug_assert!((1..=MAX_DIST).contains(&dist), "distance {dist}");
+    DIST_SYMBOL_EXTRA_BITS[DIST_SYMBOL_TABLE[dist as usize] as usize]
+}
+
+pub fn ZopfliGetDistExtraBitsValue(dist: c_int) -> c_int {
+    debug_assert!((1..=MAX_DIST).contains(&dist), "distance {dist}");
+    dist - DIST_SYMBOL_BASE[DIST_SYMBOL_TABLE[dist as usize] as usize]
+}
+
+pub fn ZopfliGetDistSymbol(dist: c_int) -> c_int {
+    debug_assert!((1..=MAX_DIST).contains(&dist), "distance {dist}");
+    DIST_SYMBOL_TABLE[dist as usize] as c_int
+}
+
+pub fn ZopfliGetLengthExtraBits(l: c_int) -> c_int {
+    LENGTH_EXTRA_BITS_TABLE[l as usize]
 }
 
 pub fn ZopfliGetLengthExtraBitsValue(l: c_int) -> c_int {
-    static ZOPFLI_LENGTH_EXTRA_BITS_VALUE_TABLE: [c_int; 259] = [
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 1, 0, 1, 0, 1, 0, 1, 2, 3, 0, 1, 2, 3, 0,
-        1, 2, 3, 0, 1, 2, 3, 0, 1, 2, 3, 4, 5, 6, 7, 0, 1, 2, 3, 4, 5, 6, 7, 0, 1, 2, 3, 4,
-        5, 6, 7, 0, 1, 2, 3, 4, 5, 6, 7, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14,
-        15, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 0, 1, 2, 3, 4, 5, 6, 7, 8,
-        9, 10, 11, 12, 13, 14, 15, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 0,
-        1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23,
-        24, 25, 26, 27, 28, 29, 30, 31, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14,
-        15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 0, 1, 2, 3, 4,
-        5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26,
-        27, 28, 29, 30, 31, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17,
-        18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 0,
-    ];
-    ZOPFLI_LENGTH_EXTRA_BITS_VALUE_TABLE[l as usize]
+    LENGTH_EXTRA_BITS_VALUE_TABLE[l as usize]
 }
 
 pub fn ZopfliGetLengthSymbol(l: c_int) -> c_int {
-    static ZOPFLI_LENGTH_SYMBOL_TABLE: [c_int; 259] = [
-        0, 0, 0, 257, 258, 259, 260, 261, 262, 263, 264,
-        265, 265, 266, 266, 267, 267, 268, 268,
-        269, 269, 269, 269, 270, 270, 270, 270,
-        271, 271, 271, 271, 272, 272, 272, 272,
-        273, 273, 273, 273, 273, 273, 273, 273,
-        274, 274, 274, 274, 274, 274, 274, 274,
-        275, 275, 275, 275, 275, 275, 275, 275,
-        276, 276, 276, 276, 276, 276, 276, 276,
-        277, 277, 277, 277, 277, 277, 277, 277,
-        277, 277, 277, 277, 277, 277, 277, 277,
-        278, 278, 278, 278, 278, 278, 278, 278,
-        278, 278, 278, 278, 278, 278, 278, 278,
-        279, 279, 279, 279, 279, 279, 279, 279,
-        279, 279, 279, 279, 279, 279, 279, 279,
-        280, 280, 280, 280, 280, 280, 280, 280,
-        280, 280, 280, 280, 280, 280, 280, 280,
-        281, 281, 281, 281, 281, 281, 281, 281,
-        281, 281, 281, 281, 281, 281, 281, 281,
-        281, 281, 281, 281, 281, 281, 281, 281,
-        281, 281, 281, 281, 281, 281, 281, 281,
-        282, 282, 282, 282, 282, 282, 282, 282,
-        282, 282, 282, 282, 282, 282, 282, 282,
-        282, 282, 282, 282, 282, 282, 282, 282,
-        282, 282, 282, 282, 282, 282, 282, 282,
-        283, 283, 283, 283, 283, 283, 283, 283,
-        283, 283, 283, 283, 283, 283, 283, 283,
-        283, 283, 283, 283, 283, 283, 283, 283,
-        283, 283, 283, 283, 283, 283, 283, 283,
-        284, 284, 284, 284, 284, 284, 284, 284,
-        284, 284, 284, 284, 284, 284, 284, 284,
-        284, 284, 284, 284, 284, 284, 284, 284,
-        284, 284, 284, 284, 284, 284, 284, 285
-    ];
-    ZOPFLI_LENGTH_SYMBOL_TABLE[l as usize]
+    LENGTH_SYMBOL_TABLE[l as usize]
 }
 
 pub fn ZopfliGetLengthSymbolExtraBits(s: c_int) -> c_int {
-    static TABLE: [c_int; 29] = [
-        0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4, 5, 5, 5, 5, 0,
-    ];
-    TABLE[(s - 257) as usize]
+    LENGTH_SYMBOL_EXTRA_BITS[(s - 257) as usize]
 }
 
 pub fn ZopfliGetDistSymbolExtraBits(s: c_int) -> c_int {
-    static TABLE: [c_int; 30] = [
-        0, 0, 0, 0, 1, 1, 2, 2, 3, 3, 4, 4, 5, 5, 6, 6, 7, 7, 8, 8, 9, 9, 10, 10, 11, 11, 12,
-        12, 13, 13,
-    ];
-    TABLE[s as usize]
+    DIST_SYMBOL_EXTRA_BITS[s as usize]
 }
@@ -1,7 +1,7 @@
 use crate::ffi::ZopfliOptions;
-use crate::util::ZOPFLI_APPEND_DATA;
-use crate::deflate::ZopfliDeflate;
-use libc::{c_uchar, size_t};
+use crate::util::{ZOPFLI_APPEND_DATA, ZOPFLI_WINDOW_SIZE};
+use crate::deflate::{DeflateFn, ZopfliDeflate, ZopfliDeflateFrom};
+use libc::{c_int, c_uchar, size_t};
 use std::io::{self, Write};
 
 fn adler32(data: &[u8]) -> u32 {
@@ -35,13 +35,85 @@ pub unsafe fn ZopfliZlibCompress(
     insize: size_t,
     out: *mut *mut c_uchar,
     outsize: *mut size_t,
+) {
+    ZopfliZlibCompressWith(options, ZopfliDeflate, 2 /* dynamic block */,
+                           r#in, insize, out, outsize);
+}
+
+/// Like ZopfliZlibCompress, but with a preset dictionary: the decompressor
+/// must be given the same dictionary, which the stream identifies by its
+/// Adler-32 after setting the FDICT flag. The dictionary fills the window
+/// before the input, so matches can refer back into its last 32K bytes.
+/// An empty dictionary is no dictionary.
+///
+/// # Safety
+///
+/// options must point to valid options, r#in to insize readable bytes, and
+/// out and outsize to a buffer allocated with malloc, or NULL, and its size,
+/// which the output is appended to.
+pub unsafe fn ZopfliZlibCompressDict(
+    options: *const ZopfliOptions,
+    dict: &[u8],
+    r#in: *const c_uchar,
+    insize: size_t,
+    out: *mut *mut c_uchar,
+    outsize: *mut size_t,
+) {
+    if dict.is_empty() {
+        ZopfliZlibCompress(options, r#in, insize, out, outsize);
+        return;
+    }
+    zlib_compress(options, dict, r#in, insize, out, outsize, |bp, out, outsize| {
+        // Only the window before the input can be referred back to.
+        let window = &dict[dict.len().saturating_sub(ZOPFLI_WINDOW_SIZE)..];
+        let mut primed = Vec::with_capacity(window.len() + insize);
+        primed.extend_from_slice(window);
+        primed.extend_from_slice(std::slice::from_raw_parts(r#in, insize));
+        ZopfliDeflateFrom(options, 2 /* dynamic block */, 1 /* final */,
+                          primed.as_ptr(), window.len(), primed.len(),
+                          bp, out, outsize);
+    });
+}
+
+/// Like ZopfliZlibCompress, but with the DEFLATE stream deflate outputs in
+/// blocks of type btype, such as the greedy parse of ZopfliDeflateFast.
+///
+/// # Safety
+///
+/// options must point to valid options, r#in to insize readable bytes, and
+/// out and outsize to a buffer allocated with malloc, or NULL, and its size,
+/// which the output is appended to.
+pub unsafe fn ZopfliZlibCompressWith(
+    options: *const ZopfliOptions,
+    deflate: DeflateFn,
+    btype: c_int,
+    r#in: *const c_uchar,
+    insize: size_t,
+    out: *mut *mut c_uchar,
+    outsize: *mut size_t,
+) {
+    zlib_compress(options, &[], r#in, insize, out, outsize, |bp, out, outsize| {
+        deflate(options, btype, 1 /* final */, r#in, insize, bp, out, outsize);
+    });
+}
+
+/// Wraps the DEFLATE stream deflate appends to out in the zlib header and
+/// trailer.
+unsafe fn zlib_compress(
+    options: *const ZopfliOptions,
+    dict: &[u8],
+    r#in: *const c_uchar,
+    insize: size_t,
+    out: *mut *mut c_uchar,
+    outsize: *mut size_t,
+    deflate: impl FnOnce(*mut c_uchar, *mut *mut c_uchar, *mut size_t),
 ) {
     let mut bitpointer = 0u8;
     let input_slice = std::slice::from_raw_parts(r#in, insize);
     let checksum = adler32(input_slice);
     let cmf = 120u32;  // CM 8, CINFO 7. See zlib spec.
     let flevel = 3u32;
-    let fdict = 0u32;
+    let fdict = !dict.is_empty() as u32;
     let mut cmfflg = 256 * cmf + fdict * 32 + flevel * 64;
     let fcheck = 31 - cmfflg % 31;
     cmfflg += fcheck;
@@ -49,8 +121,13 @@ pub unsafe fn ZopfliZlibCompress(
     ZOPFLI_APPEND_DATA((cmfflg / 256) as u8, &mut *out, &mut *outsize);
     ZOPFLI_APPEND_DATA((cmfflg % 256) as u8, &mut *out, &mut *outsize);
 
-    ZopfliDeflate(options, 2 /* dynamic block */, 1 /* final */,
-                  r#in, insize, &mut bitpointer, out, outsize);
+    if !dict.is_empty() {
+        for byte in adler32(dict).to_be_bytes() {
+            ZOPFLI_APPEND_DATA(byte, &mut *out, &mut *outsize);
+        }
+    }
+
+    deflate(&mut bitpointer, out, outsize);
 
     ZOPFLI_APPEND_DATA(((checksum >> 24) % 256) as u8, &mut *out, &mut *outsize);
     ZOPFLI_APPEND_DATA(((checksum >> 16) % 256) as u8, &mut *out, &mut *outsize);
@@ -64,4 +141,4 @@ pub unsafe fn ZopfliZlibCompress(
             insize, *outsize, removed_percent
         );
     }
-}
\ No newline at end of file
+}
@@ -7,4 +7,41 @@ pub enum ZopfliFormat {
   ZOPFLI_FORMAT_GZIP,
   ZOPFLI_FORMAT_ZLIB,
   ZOPFLI_FORMAT_DEFLATE
-}
\ No newline at end of file
+}
+
+/// The type of DEFLATE blocks to output, the btype of ZopfliDeflate.
+/// Converts to the C integer, 0 to 2.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlockType {
+  /// Uncompressed blocks.
+  Stored,
+  /// Fixed Huffman blocks.
+  Fixed,
+  /// Dynamic Huffman blocks, except that each block is output as whichever
+  /// of the three types is smallest.
+  Dynamic
+}
+
+impl From<BlockType> for c_int {
+  fn from(btype: BlockType) -> c_int {
+    match btype {
+      BlockType::Stored => 0,
+      BlockType::Fixed => 1,
+      BlockType::Dynamic => 2,
+    }
+  }
+}
+
+/// Fails with the value itself if it is not 0, 1 or 2.
+impl TryFrom<c_int> for BlockType {
+  type Error = c_int;
+
+  fn try_from(btype: c_int) -> Result<Self, c_int> {
+    match btype {
+      0 => Ok(BlockType::Stored),
+      1 => Ok(BlockType::Fixed),
+      2 => Ok(BlockType::Dynamic),
+      _ => Err(btype),
+    }
+  }
+}
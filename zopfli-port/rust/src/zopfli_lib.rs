@@ -1,8 +1,8 @@
 use crate::ffi::{ZopfliOptions};
-use crate::zopfli::ZopfliFormat;
-use crate::gzip_container::ZopfliGzipCompress;
-use crate::zlib_container::ZopfliZlibCompress;
-use crate::deflate::ZopfliDeflate;
+use crate::zopfli::{BlockType, ZopfliFormat};
+use crate::gzip_container::ZopfliGzipCompressWith;
+use crate::zlib_container::{ZopfliZlibCompressDict, ZopfliZlibCompressWith};
+use crate::deflate::{DeflateFn, ZopfliDeflate, ZopfliDeflateFast};
 use std::os::raw::{c_uchar, c_int};
 use libc::size_t;
 use std::ptr;
@@ -12,15 +12,56 @@ pub fn ZopfliCompress(
     output_type: ZopfliFormat,
     input: &[u8],
     out: &mut Vec<u8>,
+) {
+    compress_with(options, output_type, ZopfliDeflate, BlockType::Dynamic, input, out);
+}
+
+/// Compresses like ZopfliCompress, but from a greedy LZ77 parse in a single
+/// dynamic Huffman block, skipping the iterations and block splitting that
+/// make Zopfli slow. The output is larger, but still valid. The iteration
+/// and block splitting options are ignored.
+pub fn ZopfliCompressFast(
+    options: &ZopfliOptions,
+    output_type: ZopfliFormat,
+    input: &[u8],
+    out: &mut Vec<u8>,
+) {
+    compress_with(options, output_type, ZopfliDeflateFast, BlockType::Dynamic, input, out);
+}
+
+/// Compresses like ZopfliCompress, but in fixed Huffman blocks only,
+/// skipping the comparison with dynamic and stored blocks and the block
+/// splitting. Fixed blocks have no tree to output, which suits tiny inputs
+/// and testing decoders.
+pub fn ZopfliCompressFixed(
+    options: &ZopfliOptions,
+    output_type: ZopfliFormat,
+    input: &[u8],
+    out: &mut Vec<u8>,
+) {
+    compress_with(options, output_type, ZopfliDeflate, BlockType::Fixed, input, out);
+}
+
+/// Appends input compressed to output_type to out, with the DEFLATE stream
+/// deflate outputs in blocks of type btype.
+fn compress_with(
+    options: &ZopfliOptions,
+    output_type: ZopfliFormat,
+    deflate: DeflateFn,
+    btype: BlockType,
+    input: &[u8],
+    out: &mut Vec<u8>,
 ) {
     let mut c_out: *mut c_uchar = ptr::null_mut();
     let mut c_outsize: size_t = 0;
-    
+
     unsafe {
         match output_type {
             ZopfliFormat::ZOPFLI_FORMAT_GZIP => {
-                ZopfliGzipCompress(
+                ZopfliGzipCompressWith(
                     options,
+                    deflate,
+                    btype.into(),
                     input.as_ptr(),
                     input.len(),
                     &mut c_out,
@@ -28,8 +69,10 @@ pub fn ZopfliCompress(
                 );
             }
             ZopfliFormat::ZOPFLI_FORMAT_ZLIB => {
-                ZopfliZlibCompress(
+                ZopfliZlibCompressWith(
                     options,
+                    deflate,
+                    btype.into(),
                     input.as_ptr(),
                     input.len(),
                     &mut c_out,
@@ -38,9 +81,9 @@ pub fn ZopfliCompress(
             }
             ZopfliFormat::ZOPFLI_FORMAT_DEFLATE => {
                 let mut bp = 0u8;
-                ZopfliDeflate(
+                deflate(
                     options,
-                    2, // Dynamic block
+                    btype.into(),
                     1, // Final
                     input.as_ptr(),
                     input.len(),
@@ -50,11 +93,73 @@ pub fn ZopfliCompress(
                 );
             }
         }
-        
-        if !c_out.is_null() && c_outsize > 0 {
-            let result_slice = std::slice::from_raw_parts(c_out, c_outsize);
-            out.extend_from_slice(result_slice);
-            libc::free(c_out as *mut libc::c_void);
-        }
+
+        take_output(c_out, c_outsize, out);
+    }
+}
+
+/// Appends the c_outsize bytes at c_out to out and frees c_out, which the
+/// raw functions allocated with malloc.
+unsafe fn take_output(c_out: *mut c_uchar, c_outsize: size_t, out: &mut Vec<u8>) {
+    if !c_out.is_null() && c_outsize > 0 {
+        out.extend_from_slice(std::slice::from_raw_parts(c_out, c_outsize));
+    }
+    libc::free(c_out as *mut libc::c_void);
+}
+
+/// Compresses input to the zlib format with the preset dictionary dict,
+/// appending to out. See ZopfliZlibCompressDict.
+pub fn ZopfliCompressZlibDict(
+    options: &ZopfliOptions,
+    dict: &[u8],
+    input: &[u8],
+    out: &mut Vec<u8>,
+) {
+    let mut c_out: *mut c_uchar = ptr::null_mut();
+    let mut c_outsize: size_t = 0;
+
+    unsafe {
+        ZopfliZlibCompressDict(
+            options,
+            dict,
+            input.as_ptr(),
+            input.len(),
+            &mut c_out,
+            &mut c_outsize,
+        );
+
+        take_output(c_out, c_outsize, out);
+    }
+}
+
+/// Compresses input to raw DEFLATE blocks of type btype, the last of them
+/// final if final_block is set. See ZopfliDeflate. Also returns the number
+/// of bits used in the last byte, 0 if all 8 are, for output continuing
+/// the stream at that bit.
+pub fn deflate(
+    options: &ZopfliOptions,
+    btype: BlockType,
+    final_block: bool,
+    input: &[u8],
+) -> (Vec<u8>, u8) {
+    let mut c_out: *mut c_uchar = ptr::null_mut();
+    let mut c_outsize: size_t = 0;
+    let mut bp = 0u8;
+    let mut out = Vec::new();
+
+    unsafe {
+        ZopfliDeflate(
+            options,
+            btype.into(),
+            final_block as c_int,
+            input.as_ptr(),
+            input.len(),
+            &mut bp,
+            &mut c_out,
+            &mut c_outsize,
+        );
+
+        take_output(c_out, c_outsize, &mut out);
     }
-}
\ No newline at end of file
+    (out, bp)
+}
@@ -0,0 +1,51 @@
+use zopfli::deflate::{ZopfliCalculateBlockSize, ZopfliCalculateBlockSizeAutoType};
+use zopfli::ffi::ZopfliOptions;
+use zopfli::lz77::Lz77Store;
+use zopfli::zopfli::BlockType;
+
+fn input() -> Vec<u8> {
+    let mut input = b"Known input: ".to_vec();
+    for i in 0..400 {
+        input.extend_from_slice(format!("record {} has value {};", i % 23, i * 7).as_bytes());
+    }
+    input
+}
+
+#[test]
+fn test_block_size_bits_matches_raw() {
+    let options = ZopfliOptions::default();
+    let input = input();
+    for store in [
+        Lz77Store::greedy(&options, &input),
+        Lz77Store::optimal(&options, &input),
+    ] {
+        assert!(!store.is_empty());
+        let n = store.len();
+        for range in [0..n, 0..n / 2, n / 3..n, 10..11, 5..5] {
+            for btype in [BlockType::Stored, BlockType::Fixed, BlockType::Dynamic] {
+                let raw = unsafe {
+                    ZopfliCalculateBlockSize(store.as_raw(), range.start, range.end, btype.into())
+                };
+                assert_eq!(store.block_size_bits(range.clone(), Some(btype)), raw);
+            }
+            let raw =
+                unsafe { ZopfliCalculateBlockSizeAutoType(store.as_raw(), range.start, range.end) };
+            assert_eq!(store.block_size_bits(range.clone(), None), raw);
+        }
+
+        // A stored block has a 5 byte header.
+        assert_eq!(
+            store.block_size_bits(0..n, Some(BlockType::Stored)),
+            (input.len() * 8 + 40) as f64
+        );
+        assert!(store.block_size_bits(0..n, None) < store.block_size_bits(0..n, Some(BlockType::Fixed)));
+    }
+}
+
+#[test]
+#[should_panic(expected = "out of bounds")]
+fn test_block_size_bits_out_of_bounds() {
+    let input = input();
+    let store = Lz77Store::greedy(&ZopfliOptions::default(), &input);
+    store.block_size_bits(0..store.len() + 1, None);
+}
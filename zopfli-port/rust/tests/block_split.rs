@@ -0,0 +1,58 @@
+use std::io::Read;
+
+use flate2::read::DeflateDecoder;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use zopfli::ffi::ZopfliOptions;
+use zopfli::zopfli::ZopfliFormat;
+use zopfli::zopfli_lib::ZopfliCompress;
+
+fn options(blocksplitting: i32) -> ZopfliOptions {
+    ZopfliOptions {
+        verbose: 0,
+        verbose_more: 0,
+        numiterations: 5,
+        blocksplitting,
+        blocksplittinglast: 0,
+        blocksplittingmax: 15,
+    }
+}
+
+/// English text followed by random bytes: one Huffman tree cannot suit both
+/// halves, so a split between them pays for its second tree.
+fn two_halves() -> Vec<u8> {
+    const WORDS: &[&str] = &[
+        "the", "quick", "brown", "fox", "jumps", "over", "lazy", "dog", "and", "then", "runs",
+        "back", "home", "to", "its", "den", "where", "it", "sleeps", "until", "morning",
+    ];
+    let mut rng = StdRng::seed_from_u64(196);
+    let mut data = Vec::new();
+    while data.len() < 20000 {
+        data.extend_from_slice(WORDS[rng.gen_range(0..WORDS.len())].as_bytes());
+        data.push(if rng.gen_range(0..12) == 0 { b'\n' } else { b' ' });
+    }
+    data.extend((0..20000).map(|_| rng.gen::<u8>()));
+    data
+}
+
+fn deflate(options: &ZopfliOptions, input: &[u8]) -> Vec<u8> {
+    let mut out = Vec::new();
+    ZopfliCompress(options, ZopfliFormat::ZOPFLI_FORMAT_DEFLATE, input, &mut out);
+    let mut inflated = Vec::new();
+    DeflateDecoder::new(&out[..]).read_to_end(&mut inflated).unwrap();
+    assert_eq!(inflated, input);
+    out
+}
+
+#[test]
+fn test_block_splitting_reduces_size() {
+    let input = two_halves();
+    let split = deflate(&options(1), &input);
+    let unsplit = deflate(&options(0), &input);
+    assert!(
+        split.len() < unsplit.len(),
+        "split {} bytes, unsplit {} bytes",
+        split.len(),
+        unsplit.len()
+    );
+}
@@ -0,0 +1,91 @@
+use std::ptr;
+
+use libc::{c_void, size_t};
+use zopfli::blocksplitter::ZopfliBlockSplitSimple;
+use zopfli::ffi;
+
+/// `(instart, inend, blocksize)` ranges: ones dividing evenly, ones
+/// leaving a short final block, blocks larger than the range, empty and
+/// reversed ranges, and a range not starting at 0.
+const CASES: &[(size_t, size_t, size_t)] = &[
+    (0, 100, 10),
+    (0, 100, 7),
+    (0, 100, 99),
+    (0, 100, 100),
+    (0, 100, 101),
+    (0, 100, 1000),
+    (0, 1, 1),
+    (0, 1, 2),
+    (0, 2, 1),
+    (5, 6, 1),
+    (5, 105, 25),
+    (5, 104, 25),
+    (5, 106, 25),
+    (1000, 65536, 4096),
+    (0, 0, 1),
+    (7, 7, 3),
+    (10, 5, 1),
+];
+
+/// The split points `split` gives, freed with `free` as a C caller would.
+fn split_points(
+    split: unsafe fn(*const u8, size_t, size_t, size_t, *mut *mut size_t, *mut size_t),
+    instart: size_t,
+    inend: size_t,
+    blocksize: size_t,
+) -> Vec<size_t> {
+    let mut points: *mut size_t = ptr::null_mut();
+    let mut npoints: size_t = 0;
+    unsafe {
+        split(ptr::null(), instart, inend, blocksize, &mut points, &mut npoints);
+        if npoints == 0 {
+            // Nothing was allocated, so there is nothing to free.
+            assert!(points.is_null());
+            return Vec::new();
+        }
+        let copy = std::slice::from_raw_parts(points, npoints).to_vec();
+        libc::free(points as *mut c_void);
+        copy
+    }
+}
+
+unsafe fn c_split(
+    input: *const u8,
+    instart: size_t,
+    inend: size_t,
+    blocksize: size_t,
+    points: *mut *mut size_t,
+    npoints: *mut size_t,
+) {
+    ffi::ZopfliBlockSplitSimple(input, instart, inend, blocksize, points, npoints);
+}
+
+unsafe fn rust_split(
+    input: *const u8,
+    instart: size_t,
+    inend: size_t,
+    blocksize: size_t,
+    points: *mut *mut size_t,
+    npoints: *mut size_t,
+) {
+    ZopfliBlockSplitSimple(input, instart, inend, blocksize, points, npoints);
+}
+
+#[test]
+fn test_block_split_simple_matches_c() {
+    for &(instart, inend, blocksize) in CASES {
+        let expected = split_points(c_split, instart, inend, blocksize);
+        let actual = split_points(rust_split, instart, inend, blocksize);
+        assert_eq!(actual, expected, "instart {instart}, inend {inend}, blocksize {blocksize}");
+    }
+}
+
+#[test]
+fn test_block_split_simple_final_block() {
+    // Points start each block, so the last one is the start of the final,
+    // possibly short, block and never inend itself.
+    assert_eq!(split_points(rust_split, 0, 100, 25), [0, 25, 50, 75]);
+    assert_eq!(split_points(rust_split, 0, 101, 25), [0, 25, 50, 75, 100]);
+    assert_eq!(split_points(rust_split, 3, 10, 100), [3]);
+    assert!(split_points(rust_split, 10, 10, 4).is_empty());
+}
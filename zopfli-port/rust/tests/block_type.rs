@@ -0,0 +1,51 @@
+use std::io::Read;
+use std::os::raw::c_int;
+
+use flate2::read::DeflateDecoder;
+use zopfli::ffi::ZopfliOptions;
+use zopfli::zopfli::BlockType;
+use zopfli::zopfli_lib::deflate;
+
+const TEXT: &[u8] = b"It was the best of times, it was the worst of times, it was the age \
+of wisdom, it was the age of foolishness, it was the epoch of belief, it was the epoch of \
+incredulity, it was the season of Light, it was the season of Darkness.";
+
+#[test]
+fn test_block_types_decompress() {
+    let options = ZopfliOptions {
+        numiterations: 1,
+        ..ZopfliOptions::default()
+    };
+    for btype in [BlockType::Stored, BlockType::Fixed, BlockType::Dynamic] {
+        for input in [&b""[..], b"a", TEXT, &TEXT.repeat(50)] {
+            let (data, _) = deflate(&options, btype, true, input);
+            // A single final block: BFINAL, then BTYPE in the next two
+            // bits. Dynamic splits blocks and picks the smallest type.
+            if btype != BlockType::Dynamic {
+                assert_eq!(data[0] & 1, 1);
+                assert_eq!(c_int::from(data[0] >> 1 & 3), c_int::from(btype), "{btype:?}");
+            }
+
+            let mut inflated = Vec::new();
+            DeflateDecoder::new(&data[..]).read_to_end(&mut inflated).unwrap();
+            assert_eq!(inflated, input, "{btype:?}");
+        }
+    }
+
+    let (data, _) = deflate(&options, BlockType::Dynamic, true, &TEXT.repeat(50));
+    assert_eq!(data[0] >> 1 & 3, 2);
+}
+
+#[test]
+fn test_block_type_integers() {
+    for (btype, value) in [
+        (BlockType::Stored, 0),
+        (BlockType::Fixed, 1),
+        (BlockType::Dynamic, 2),
+    ] {
+        assert_eq!(c_int::from(btype), value);
+        assert_eq!(BlockType::try_from(value), Ok(btype));
+    }
+    assert_eq!(BlockType::try_from(3), Err(3));
+    assert_eq!(BlockType::try_from(-1), Err(-1));
+}
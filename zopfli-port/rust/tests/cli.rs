@@ -0,0 +1,137 @@
+use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::{Command, Output, Stdio};
+
+use flate2::{Decompress, FlushDecompress};
+use zopfli::ffi::ZopfliOptions;
+use zopfli::zopfli::BlockType;
+use zopfli::zopfli_lib::deflate;
+
+/// A fresh directory for one test.
+fn scratch(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("zopfli-cli-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn zopfli(args: &[&str], stdin: &[u8]) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_zopfli"))
+        .args(["-i", "1"])
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child.stdin.take().unwrap().write_all(stdin).unwrap();
+    child.wait_with_output().unwrap()
+}
+
+/// The data of a single member gzip file.
+fn gunzip(data: &[u8]) -> Vec<u8> {
+    assert_eq!(&data[..3], &[0x1f, 0x8b, 8]);
+    let mut d = Decompress::new(false);
+    let mut out = Vec::with_capacity(1 << 16);
+    d.decompress_vec(&data[10..data.len() - 8], &mut out, FlushDecompress::Finish)
+        .unwrap();
+    out
+}
+
+const TEXT: &[u8] = b"hello hello hello zopfli zopfli zopfli\n";
+
+#[test]
+fn test_derived_name() {
+    let dir = scratch("derived");
+    let input = dir.join("a.txt");
+    fs::write(&input, TEXT).unwrap();
+
+    let out = zopfli(&[input.to_str().unwrap()], b"");
+    assert!(out.status.success());
+    assert!(out.stdout.is_empty());
+    assert_eq!(gunzip(&fs::read(dir.join("a.txt.gz")).unwrap()), TEXT);
+    assert!(!input.exists());
+
+    // Other formats still go to stdout and keep the input.
+    fs::write(&input, TEXT).unwrap();
+    for format in ["zlib", "deflate"] {
+        let out = zopfli(&["-f", format, input.to_str().unwrap()], b"");
+        assert!(out.status.success());
+        assert!(!out.stdout.is_empty());
+        assert!(input.exists());
+        assert!(!dir.join(format!("a.txt.{}", format)).exists());
+    }
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_stdout() {
+    let dir = scratch("stdout");
+    let input = dir.join("a.txt");
+    fs::write(&input, TEXT).unwrap();
+
+    let out = zopfli(&["--stdout", input.to_str().unwrap()], b"");
+    assert!(out.status.success());
+    assert_eq!(gunzip(&out.stdout), TEXT);
+    assert!(input.exists());
+    assert!(!dir.join("a.txt.gz").exists());
+
+    // Without an input file, stdin goes to stdout.
+    let out = zopfli(&[], TEXT);
+    assert!(out.status.success());
+    assert_eq!(gunzip(&out.stdout), TEXT);
+
+    let out = zopfli(&["-c", input.to_str().unwrap(), "out.gz"], b"");
+    assert!(!out.status.success());
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_refuses_overwrite() {
+    let dir = scratch("overwrite");
+    let input = dir.join("a.txt");
+    let output = dir.join("a.txt.gz");
+    fs::write(&input, TEXT).unwrap();
+    fs::write(&output, b"precious").unwrap();
+
+    let out = zopfli(&[input.to_str().unwrap()], b"");
+    assert!(!out.status.success());
+    assert!(String::from_utf8_lossy(&out.stderr).contains("--force"));
+    assert_eq!(fs::read(&output).unwrap(), b"precious");
+    assert!(input.exists());
+
+    let out = zopfli(&["--force", input.to_str().unwrap()], b"");
+    assert!(out.status.success());
+    assert_eq!(gunzip(&fs::read(&output).unwrap()), TEXT);
+    assert!(!input.exists());
+
+    // Compressed files are not compressed again.
+    let out = zopfli(&[output.to_str().unwrap()], b"");
+    assert!(!out.status.success());
+    assert!(!dir.join("a.txt.gz.gz").exists());
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_deflate_matches_cli() {
+    let options = ZopfliOptions {
+        numiterations: 1,
+        ..ZopfliOptions::default()
+    };
+    for input in [&b""[..], b"a", TEXT, &TEXT.repeat(100)] {
+        let out = zopfli(&["-c", "-f", "deflate"], input);
+        assert!(out.status.success());
+        let (data, bp) = deflate(&options, BlockType::Dynamic, true, input);
+        assert_eq!(data, out.stdout);
+        assert!(bp < 8);
+
+        let mut d = Decompress::new(false);
+        let mut inflated = Vec::with_capacity(input.len());
+        d.decompress_vec(&data, &mut inflated, FlushDecompress::Finish).unwrap();
+        assert_eq!(inflated, input);
+    }
+}
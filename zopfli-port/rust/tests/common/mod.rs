@@ -0,0 +1,50 @@
+//! Helpers shared by the integration tests.
+#![allow(dead_code)]
+
+use std::io::Read;
+
+use flate2::read::{DeflateDecoder, GzDecoder, ZlibDecoder};
+use zopfli::ffi::ZopfliOptions;
+use zopfli::zopfli::ZopfliFormat;
+
+pub const FORMATS: [ZopfliFormat; 3] = [
+    ZopfliFormat::ZOPFLI_FORMAT_GZIP,
+    ZopfliFormat::ZOPFLI_FORMAT_ZLIB,
+    ZopfliFormat::ZOPFLI_FORMAT_DEFLATE,
+];
+
+/// The default options, but with numiterations iterations.
+pub fn options(numiterations: i32) -> ZopfliOptions {
+    ZopfliOptions {
+        verbose: 0,
+        verbose_more: 0,
+        numiterations,
+        blocksplitting: 1,
+        blocksplittinglast: 0,
+        blocksplittingmax: 15,
+    }
+}
+
+/// Small inputs: empty, a single byte, short text, a short period and
+/// every byte value.
+pub fn inputs() -> Vec<Vec<u8>> {
+    vec![
+        Vec::new(),
+        b"a".to_vec(),
+        b"tiny payload".to_vec(),
+        b"abcabcabcabd".repeat(500),
+        (0..=255u8).cycle().take(5000).collect(),
+    ]
+}
+
+/// Decompresses data in format with flate2.
+pub fn decompress(format: ZopfliFormat, data: &[u8]) -> Vec<u8> {
+    let mut out = Vec::new();
+    match format {
+        ZopfliFormat::ZOPFLI_FORMAT_GZIP => GzDecoder::new(data).read_to_end(&mut out),
+        ZopfliFormat::ZOPFLI_FORMAT_ZLIB => ZlibDecoder::new(data).read_to_end(&mut out),
+        ZopfliFormat::ZOPFLI_FORMAT_DEFLATE => DeflateDecoder::new(data).read_to_end(&mut out),
+    }
+    .unwrap();
+    out
+}
@@ -0,0 +1,93 @@
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use zopfli::ffi::{self, ZopfliBlockState, ZopfliLZ77Store, ZopfliOptions};
+use zopfli::squeeze::ZopfliLZ77Optimal;
+use zopfli::zopfli::ZopfliFormat;
+use zopfli::zopfli_lib::ZopfliCompress;
+
+mod common;
+use common::options;
+
+/// Inputs whose cost stops improving after a few iterations, which makes
+/// ZopfliLZ77Optimal randomize its statistics, and some where it does not.
+fn inputs() -> Vec<(&'static str, Vec<u8>)> {
+    let mut rng = StdRng::seed_from_u64(147);
+    let random: Vec<u8> = (0..10000).map(|_| rng.gen()).collect();
+    let skewed: Vec<u8> = (0..20000)
+        .map(|_| b"aaaaaaaabbbbccd \n"[rng.gen_range(0..17)])
+        .collect();
+    let mut text = Vec::new();
+    for i in 0..600 {
+        text.extend_from_slice(
+            format!("line {} of {}: value={}\n", i, i % 17, i * 31 % 1000).as_bytes(),
+        );
+    }
+    vec![
+        ("repetitive", b"zopfli ".repeat(5000)),
+        ("random", random),
+        ("skewed", skewed),
+        ("structured text", text),
+    ]
+}
+
+/// Fills freed heap memory with garbage, so reads of uninitialized memory
+/// are likely to see different values in the next run.
+fn scribble() {
+    for size in [1 << 10, 1 << 16, 1 << 20] {
+        std::hint::black_box(vec![0xa5u8; size]);
+    }
+}
+
+/// The literals, lengths and distances ZopfliLZ77Optimal picks for input.
+fn optimal(options: &ZopfliOptions, input: &[u8]) -> (Vec<u16>, Vec<u16>) {
+    unsafe {
+        let mut s: ZopfliBlockState = std::mem::zeroed();
+        let mut store: ZopfliLZ77Store = std::mem::zeroed();
+        ffi::ZopfliInitBlockState(options, 0, input.len(), 1, &mut s);
+        ffi::ZopfliInitLZ77Store(input.as_ptr(), &mut store);
+        ZopfliLZ77Optimal(
+            &mut s,
+            input.as_ptr(),
+            0,
+            input.len(),
+            options.numiterations,
+            &mut store,
+        );
+        let litlens = std::slice::from_raw_parts(store.litlens, store.size).to_vec();
+        let dists = std::slice::from_raw_parts(store.dists, store.size).to_vec();
+        ffi::ZopfliCleanLZ77Store(&mut store);
+        ffi::ZopfliCleanBlockState(&mut s);
+        (litlens, dists)
+    }
+}
+
+#[test]
+fn test_lz77_optimal_deterministic() {
+    // Well past the 5 iterations after which equal costs randomize.
+    let options = options(25);
+    for (name, input) in inputs() {
+        let first = optimal(&options, &input);
+        scribble();
+        let second = optimal(&options, &input);
+        assert!(!first.0.is_empty());
+        assert!(first == second, "{}: the runs differ", name);
+    }
+}
+
+#[test]
+fn test_compress_deterministic() {
+    let options = options(15);
+    for (name, input) in inputs() {
+        for format in [
+            ZopfliFormat::ZOPFLI_FORMAT_GZIP,
+            ZopfliFormat::ZOPFLI_FORMAT_DEFLATE,
+        ] {
+            let mut first = Vec::new();
+            ZopfliCompress(&options, format, &input, &mut first);
+            scribble();
+            let mut second = Vec::new();
+            ZopfliCompress(&options, format, &input, &mut second);
+            assert!(first == second, "{}, {:?}: the runs differ", name, format);
+        }
+    }
+}
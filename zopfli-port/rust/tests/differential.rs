@@ -0,0 +1,81 @@
+use std::ptr;
+
+use libc::{c_uchar, size_t};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use zopfli::ffi::{self, ZopfliOptions};
+use zopfli::zopfli::ZopfliFormat;
+use zopfli::zopfli_lib::ZopfliCompress;
+
+mod common;
+use common::{options, FORMATS};
+
+/// Inputs exercising stored, fixed and dynamic blocks, long matches and
+/// block splitting.
+fn inputs() -> Vec<(&'static str, Vec<u8>)> {
+    let mut rng = StdRng::seed_from_u64(0x5eed);
+    let random: Vec<u8> = (0..20000).map(|_| rng.gen()).collect();
+    let mut text = Vec::new();
+    for i in 0..600 {
+        text.extend_from_slice(
+            format!(
+                "<item id=\"{}\"><name>entry {}</name><value>{}</value></item>\n",
+                i,
+                i % 13,
+                i * i
+            )
+            .as_bytes(),
+        );
+    }
+    // Random data followed by text gives the block splitter something to do.
+    let mut mixed = random[..5000].to_vec();
+    mixed.extend_from_slice(&text[..10000]);
+    vec![
+        ("empty", Vec::new()),
+        ("single byte", vec![b'a']),
+        ("repetitive", vec![b'z'; 100000]),
+        ("short period", b"abc".repeat(10000)),
+        ("random", random),
+        ("structured text", text),
+        ("mixed", mixed),
+    ]
+}
+
+fn c_compress(options: &ZopfliOptions, format: ZopfliFormat, input: &[u8]) -> Vec<u8> {
+    let mut out: *mut c_uchar = ptr::null_mut();
+    let mut outsize: size_t = 0;
+    unsafe {
+        ffi::ZopfliCompress(
+            options,
+            format as ffi::ZopfliFormat,
+            input.as_ptr(),
+            input.len(),
+            &mut out,
+            &mut outsize,
+        );
+        let result = std::slice::from_raw_parts(out, outsize).to_vec();
+        libc::free(out as *mut libc::c_void);
+        result
+    }
+}
+
+#[test]
+fn test_compress_matches_c() {
+    let options = options(5);
+    for (name, input) in inputs() {
+        for format in FORMATS {
+            let mut rust = Vec::new();
+            ZopfliCompress(&options, format, &input, &mut rust);
+            let c = c_compress(&options, format, &input);
+            assert!(!c.is_empty(), "{}, {:?}: no output", name, format);
+            assert!(
+                rust == c,
+                "{}, {:?}: {} bytes, C {} bytes",
+                name,
+                format,
+                rust.len(),
+                c.len()
+            );
+        }
+    }
+}
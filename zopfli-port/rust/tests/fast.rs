@@ -0,0 +1,76 @@
+use std::time::{Duration, Instant};
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use zopfli::ffi::ZopfliOptions;
+use zopfli::zopfli::ZopfliFormat;
+use zopfli::zopfli_lib::{ZopfliCompress, ZopfliCompressFast};
+
+mod common;
+use common::{decompress, FORMATS};
+
+fn text(lines: usize) -> Vec<u8> {
+    let mut text = Vec::new();
+    for i in 0..lines {
+        text.extend_from_slice(
+            format!(
+                "{} the quick brown fox {} jumps over the lazy dog\n",
+                i,
+                i % 9
+            )
+            .as_bytes(),
+        );
+    }
+    text
+}
+
+#[test]
+fn test_fast_round_trip() {
+    let options = ZopfliOptions::default();
+    let mut rng = StdRng::seed_from_u64(148);
+    let random: Vec<u8> = (0..70000).map(|_| rng.gen()).collect();
+    let inputs = [Vec::new(), vec![b'x'], vec![0; 100000], random, text(2000)];
+    for input in &inputs {
+        for format in FORMATS {
+            let mut out = Vec::new();
+            ZopfliCompressFast(&options, format, input, &mut out);
+            assert!(
+                decompress(format, &out) == *input,
+                "{:?}, {} bytes",
+                format,
+                input.len()
+            );
+        }
+    }
+}
+
+#[test]
+fn test_fast_is_faster() {
+    let options = ZopfliOptions::default();
+    let input = text(1000);
+    let time = |compress: fn(&ZopfliOptions, ZopfliFormat, &[u8], &mut Vec<u8>)| {
+        let mut out = Vec::new();
+        let start = Instant::now();
+        compress(
+            &options,
+            ZopfliFormat::ZOPFLI_FORMAT_DEFLATE,
+            &input,
+            &mut out,
+        );
+        (start.elapsed(), out)
+    };
+    let (full_time, full) = time(ZopfliCompress);
+    let (fast_time, fast) = time(ZopfliCompressFast);
+    assert_eq!(
+        decompress(ZopfliFormat::ZOPFLI_FORMAT_DEFLATE, &fast),
+        input
+    );
+    assert!(full.len() <= fast.len());
+    // Far apart in practice; loose to stay clear of noisy machines.
+    assert!(
+        fast_time * 4 < full_time.max(Duration::from_millis(1)),
+        "fast {:?}, full {:?}",
+        fast_time,
+        full_time
+    );
+}
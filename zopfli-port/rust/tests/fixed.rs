@@ -0,0 +1,65 @@
+use std::ptr;
+
+use libc::{c_uchar, size_t};
+use zopfli::deflate::{ZopfliCalculateBlockSize, ZopfliDeflate};
+use zopfli::ffi::{self, ZopfliBlockState, ZopfliLZ77Store, ZopfliOptions};
+use zopfli::zopfli_lib::ZopfliCompressFixed;
+
+mod common;
+use common::{decompress, inputs, FORMATS};
+
+#[test]
+fn test_fixed_round_trip() {
+    let options = ZopfliOptions::default();
+    for input in inputs() {
+        for format in FORMATS {
+            let mut out = Vec::new();
+            ZopfliCompressFixed(&options, format, &input, &mut out);
+            assert_eq!(decompress(format, &out), input);
+        }
+    }
+}
+
+#[test]
+fn test_fixed_size_matches_estimate() {
+    let options = ZopfliOptions::default();
+    for input in inputs() {
+        unsafe {
+            let mut out: *mut c_uchar = ptr::null_mut();
+            let mut outsize: size_t = 0;
+            let mut bp = 0u8;
+            ZopfliDeflate(
+                &options,
+                1,
+                1,
+                input.as_ptr(),
+                input.len(),
+                &mut bp,
+                &mut out,
+                &mut outsize,
+            );
+            let stream = std::slice::from_raw_parts(out, outsize).to_vec();
+            libc::free(out as *mut libc::c_void);
+
+            // BFINAL set, BTYPE 01, and nothing else but fixed blocks.
+            assert_eq!(stream[0] & 7, 0b011);
+            let bits = if bp == 0 {
+                outsize * 8
+            } else {
+                (outsize - 1) * 8 + bp as usize
+            };
+
+            // The same parse ZopfliDeflate makes for a fixed block.
+            let mut s: ZopfliBlockState = std::mem::zeroed();
+            let mut store: ZopfliLZ77Store = std::mem::zeroed();
+            ffi::ZopfliInitLZ77Store(input.as_ptr(), &mut store);
+            ffi::ZopfliInitBlockState(&options, 0, input.len(), 1, &mut s);
+            ffi::ZopfliLZ77OptimalFixed(&mut s, input.as_ptr(), 0, input.len(), &mut store);
+            let estimate = ZopfliCalculateBlockSize(&store, 0, store.size, 1);
+            ffi::ZopfliCleanBlockState(&mut s);
+            ffi::ZopfliCleanLZ77Store(&mut store);
+
+            assert_eq!(estimate, bits as f64, "{} bytes of input", input.len());
+        }
+    }
+}
@@ -0,0 +1,54 @@
+#![cfg(feature = "simd")]
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use zopfli::lz77::{GetMatch, GetMatchSimd};
+
+/// A period 7 pattern with a byte changed here and there, so that matches
+/// end at every position within and across SIMD chunks.
+fn data() -> Vec<u8> {
+    let mut rng = StdRng::seed_from_u64(194);
+    let mut data: Vec<u8> = b"zopfli ".iter().cycle().take(20000).copied().collect();
+    for _ in 0..300 {
+        let i = rng.gen_range(0..data.len());
+        data[i] = rng.gen();
+    }
+    data
+}
+
+#[test]
+fn test_get_match_simd_matches_scalar() {
+    let data = data();
+    let mut rng = StdRng::seed_from_u64(0x51d);
+    for pos in (300..data.len() - 300).step_by(7) {
+        for _ in 0..4 {
+            let dist = rng.gen_range(1..=pos.min(32768));
+            let limit = rng.gen_range(0..=258);
+            unsafe {
+                let scan = data.as_ptr().add(pos);
+                let match_ = scan.sub(dist);
+                let end = scan.add(limit);
+                let safe_end = end.sub(8);
+                assert_eq!(
+                    GetMatchSimd(scan, match_, end, safe_end),
+                    GetMatch(scan, match_, end, safe_end),
+                    "pos {pos}, dist {dist}, limit {limit}"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_get_match_simd_stops_at_end() {
+    // Equal far beyond end: the match must end exactly at end.
+    let data = vec![b'a'; 1024];
+    for limit in 0..=258 {
+        unsafe {
+            let scan = data.as_ptr().add(512);
+            let end = scan.add(limit);
+            let result = GetMatchSimd(scan, scan.sub(1), end, end.sub(8));
+            assert_eq!(result, end, "limit {limit}");
+        }
+    }
+}
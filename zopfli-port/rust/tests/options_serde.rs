@@ -0,0 +1,62 @@
+#![cfg(feature = "serde")]
+
+use zopfli::ffi::ZopfliOptions;
+use zopfli::options::{Format, Options};
+use zopfli::zopfli::ZopfliFormat;
+
+#[test]
+fn test_options_json_round_trip() {
+    let options = Options {
+        verbose: true,
+        numiterations: 5,
+        blocksplittingmax: 0,
+        ..Options::default()
+    };
+    let json = serde_json::to_string(&options).unwrap();
+    let back: Options = serde_json::from_str(&json).unwrap();
+    assert_eq!(back, options);
+
+    let ffi: ZopfliOptions = back.into();
+    assert_eq!(
+        ffi,
+        ZopfliOptions {
+            verbose: 1,
+            verbose_more: 0,
+            numiterations: 5,
+            blocksplitting: 1,
+            blocksplittinglast: 0,
+            blocksplittingmax: 0,
+        }
+    );
+    assert_eq!(Options::from(ffi), options);
+}
+
+#[test]
+fn test_options_json_defaults() {
+    let options: Options = serde_json::from_str(r#"{"numiterations": 50}"#).unwrap();
+    let ffi: ZopfliOptions = options.into();
+    assert_eq!(
+        ffi,
+        ZopfliOptions {
+            numiterations: 50,
+            ..ZopfliOptions::default()
+        }
+    );
+
+    assert!(serde_json::from_str::<Options>(r#"{"iterations": 50}"#).is_err());
+}
+
+#[test]
+fn test_format_json() {
+    for (format, name, ffi) in [
+        (Format::Gzip, "\"gzip\"", ZopfliFormat::ZOPFLI_FORMAT_GZIP),
+        (Format::Zlib, "\"zlib\"", ZopfliFormat::ZOPFLI_FORMAT_ZLIB),
+        (Format::Deflate, "\"deflate\"", ZopfliFormat::ZOPFLI_FORMAT_DEFLATE),
+    ] {
+        assert_eq!(serde_json::to_string(&format).unwrap(), name);
+        assert_eq!(serde_json::from_str::<Format>(name).unwrap(), format);
+        assert_eq!(ZopfliFormat::from(format), ffi);
+        assert_eq!(Format::from(ffi), format);
+    }
+    assert!(serde_json::from_str::<Format>("\"bzip2\"").is_err());
+}
@@ -0,0 +1,78 @@
+use std::io::Read;
+
+use flate2::read::DeflateDecoder;
+use zopfli::deflate::DeflateStream;
+use zopfli::ffi::ZopfliOptions;
+
+fn inflate(data: &[u8]) -> Vec<u8> {
+    let mut out = Vec::new();
+    DeflateDecoder::new(data).read_to_end(&mut out).unwrap();
+    out
+}
+
+fn record(i: usize) -> Vec<u8> {
+    format!("2026-10-16T12:00:{:02}Z INFO request served path=/api/items/{i} status=200\n", i % 60)
+        .into_bytes()
+}
+
+#[test]
+fn test_stream_round_trip() {
+    let a = b"the first buffer, ".to_vec();
+    let b = (0..=255u8).cycle().take(5000).collect::<Vec<u8>>();
+    let c = b"abcabcabcabd".repeat(500);
+
+    let mut stream = DeflateStream::new(&ZopfliOptions::default());
+    stream.push(&a);
+    stream.push(&b);
+    stream.push(&[]);
+    stream.push(&c);
+    let out = stream.finish();
+
+    assert_eq!(inflate(&out), [a, b, c].concat());
+}
+
+#[test]
+fn test_stream_empty() {
+    let stream = DeflateStream::new(&ZopfliOptions::default());
+    assert!(inflate(&stream.finish()).is_empty());
+}
+
+#[test]
+fn test_stream_take_output() {
+    let options = ZopfliOptions::default();
+    let records: Vec<Vec<u8>> = (0..50).map(record).collect();
+
+    let mut whole = DeflateStream::new(&options);
+    let mut taken = DeflateStream::new(&options);
+    let mut out = Vec::new();
+    for r in &records {
+        whole.push(r);
+        taken.push(r);
+        out.extend(taken.take_output());
+    }
+    out.extend(taken.finish());
+
+    assert_eq!(out, whole.finish());
+    assert_eq!(inflate(&out), records.concat());
+}
+
+#[test]
+fn test_stream_matches_across_buffers() {
+    // Each record repeats most of the one before, which only matches
+    // reaching back into earlier buffers can make use of.
+    let options = ZopfliOptions::default();
+    let records: Vec<Vec<u8>> = (0..50).map(record).collect();
+
+    let mut stream = DeflateStream::new(&options);
+    let mut separate = 0;
+    for r in &records {
+        stream.push(r);
+        let mut alone = DeflateStream::new(&options);
+        alone.push(r);
+        separate += alone.finish().len();
+    }
+    let out = stream.finish();
+
+    assert_eq!(inflate(&out), records.concat());
+    assert!(out.len() * 2 < separate, "{} vs {}", out.len(), separate);
+}
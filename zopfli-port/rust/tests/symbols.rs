@@ -0,0 +1,43 @@
+use zopfli::ffi;
+use zopfli::symbols::*;
+
+#[test]
+fn test_length_tables_match_c() {
+    for l in 0..=258 {
+        unsafe {
+            assert_eq!(ZopfliGetLengthSymbol(l), ffi::ZopfliGetLengthSymbol(l), "length {l}");
+            assert_eq!(ZopfliGetLengthExtraBits(l), ffi::ZopfliGetLengthExtraBits(l), "length {l}");
+            assert_eq!(
+                ZopfliGetLengthExtraBitsValue(l),
+                ffi::ZopfliGetLengthExtraBitsValue(l),
+                "length {l}"
+            );
+        }
+    }
+    for s in 257..=285 {
+        unsafe {
+            assert_eq!(ZopfliGetLengthSymbolExtraBits(s), ffi::ZopfliGetLengthSymbolExtraBits(s));
+        }
+    }
+}
+
+#[test]
+fn test_dist_tables_match_c() {
+    // The tables cover every distance DEFLATE can encode.
+    for dist in 1..=32768 {
+        unsafe {
+            assert_eq!(ZopfliGetDistSymbol(dist), ffi::ZopfliGetDistSymbol(dist), "dist {dist}");
+            assert_eq!(ZopfliGetDistExtraBits(dist), ffi::ZopfliGetDistExtraBits(dist), "dist {dist}");
+            assert_eq!(
+                ZopfliGetDistExtraBitsValue(dist),
+                ffi::ZopfliGetDistExtraBitsValue(dist),
+                "dist {dist}"
+            );
+        }
+    }
+    for s in 0..=29 {
+        unsafe {
+            assert_eq!(ZopfliGetDistSymbolExtraBits(s), ffi::ZopfliGetDistSymbolExtraBits(s));
+        }
+    }
+}
@@ -0,0 +1,100 @@
+use zopfli::ffi::{self, ZopfliBlockState, ZopfliLZ77Store};
+use zopfli::lz77::Lz77Store;
+use zopfli::squeeze::ZopfliLZ77Optimal;
+use zopfli::zopfli_lib::ZopfliCompress;
+
+mod common;
+use common::{decompress, inputs, options, FORMATS};
+
+/// The literals, lengths and distances of store.
+unsafe fn symbols(store: &ZopfliLZ77Store) -> (Vec<u16>, Vec<u16>) {
+    if store.size == 0 {
+        return (Vec::new(), Vec::new());
+    }
+    (
+        std::slice::from_raw_parts(store.litlens, store.size).to_vec(),
+        std::slice::from_raw_parts(store.dists, store.size).to_vec(),
+    )
+}
+
+#[test]
+fn test_zero_iterations_round_trip() {
+    for input in inputs() {
+        for format in FORMATS {
+            let mut out = Vec::new();
+            ZopfliCompress(&options(0), format, &input, &mut out);
+            assert_eq!(decompress(format, &out), input, "{:?}", format);
+        }
+    }
+}
+
+/// The store of the C parse of input: ZopfliLZ77Optimal with numiterations,
+/// or ZopfliLZ77Greedy for None.
+unsafe fn c_parse(input: &[u8], numiterations: Option<i32>) -> (Vec<u16>, Vec<u16>) {
+    let options = options(numiterations.unwrap_or(0));
+    let mut s: ZopfliBlockState = std::mem::zeroed();
+    let mut store: ZopfliLZ77Store = std::mem::zeroed();
+    ffi::ZopfliInitBlockState(&options, 0, input.len(), 1, &mut s);
+    ffi::ZopfliInitLZ77Store(input.as_ptr(), &mut store);
+    match numiterations {
+        Some(n) => ffi::ZopfliLZ77Optimal(&mut s, input.as_ptr(), 0, input.len(), n, &mut store),
+        None => {
+            let mut h: ffi::ZopfliHash = std::mem::zeroed();
+            ffi::ZopfliAllocHash(32768, &mut h);
+            ffi::ZopfliLZ77Greedy(&mut s, input.as_ptr(), 0, input.len(), &mut store, &mut h);
+            ffi::ZopfliCleanHash(&mut h);
+        }
+    }
+    let result = symbols(&store);
+    ffi::ZopfliCleanLZ77Store(&mut store);
+    ffi::ZopfliCleanBlockState(&mut s);
+    result
+}
+
+#[test]
+fn test_lz77_optimal_matches_c() {
+    // With iterations the Rust parse is C's. Without, C leaves the store
+    // empty and Rust gives C's greedy parse instead.
+    for input in inputs() {
+        for numiterations in 0..=3 {
+            let options = options(numiterations);
+            unsafe {
+                let mut s: ZopfliBlockState = std::mem::zeroed();
+                let mut rust: ZopfliLZ77Store = std::mem::zeroed();
+                ffi::ZopfliInitBlockState(&options, 0, input.len(), 1, &mut s);
+                ffi::ZopfliInitLZ77Store(input.as_ptr(), &mut rust);
+                ZopfliLZ77Optimal(&mut s, input.as_ptr(), 0, input.len(), numiterations, &mut rust);
+
+                let expected = c_parse(&input, Some(numiterations).filter(|&n| n > 0));
+                assert_eq!(symbols(&rust), expected, "{} iterations", numiterations);
+                if numiterations == 0 {
+                    assert_eq!(c_parse(&input, Some(0)), (Vec::new(), Vec::new()));
+                }
+
+                ffi::ZopfliCleanLZ77Store(&mut rust);
+                ffi::ZopfliCleanBlockState(&mut s);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_zero_iterations_optimal_store_is_greedy() {
+    // Lz77Store::optimal gets the greedy parse from ZopfliLZ77Optimal, which
+    // covers the whole input.
+    let options = options(0);
+    for input in inputs() {
+        let optimal = Lz77Store::optimal(&options, &input);
+        let greedy = Lz77Store::greedy(&options, &input);
+        unsafe {
+            let (litlens, dists) = symbols(&*optimal.as_raw());
+            assert_eq!((litlens.clone(), dists.clone()), symbols(&*greedy.as_raw()));
+            let covered: usize = litlens
+                .iter()
+                .zip(&dists)
+                .map(|(&litlen, &dist)| if dist == 0 { 1 } else { litlen as usize })
+                .sum();
+            assert_eq!(covered, input.len());
+        }
+    }
+}
@@ -0,0 +1,83 @@
+use flate2::{Decompress, FlushDecompress, Status};
+use zopfli::ffi::ZopfliOptions;
+use zopfli::util::ZopfliInitOptions;
+use zopfli::zopfli_lib::ZopfliCompressZlibDict;
+
+fn options() -> ZopfliOptions {
+    let mut options: ZopfliOptions = unsafe { std::mem::zeroed() };
+    unsafe { ZopfliInitOptions(&mut options) };
+    options
+}
+
+/// Inflates a zlib stream, giving the decompressor dict when it asks for one.
+fn inflate(stream: &[u8], dict: &[u8]) -> Vec<u8> {
+    let mut d = Decompress::new(true);
+    let mut out = Vec::with_capacity(1 << 16);
+    let mut pos = 0;
+    loop {
+        let before = d.total_in();
+        match d.decompress_vec(&stream[pos..], &mut out, FlushDecompress::Finish) {
+            Ok(Status::StreamEnd) => break,
+            Ok(_) => out.reserve(1 << 16),
+            Err(err) => {
+                let adler = err.needs_dictionary().expect("corrupt stream");
+                assert_eq!(adler, u32::from_be_bytes(stream[2..6].try_into().unwrap()));
+                d.set_dictionary(dict).unwrap();
+            }
+        }
+        pos += (d.total_in() - before) as usize;
+    }
+    assert_eq!(d.total_in() as usize, stream.len());
+    out
+}
+
+fn record(i: usize) -> Vec<u8> {
+    format!(
+        "{{\"id\":{i},\"type\":\"event\",\"source\":\"sensor-{}\",\"status\":\"ok\",\"tags\":[\"alpha\",\"beta\"]}}",
+        i % 7
+    )
+    .into_bytes()
+}
+
+#[test]
+fn test_dict_round_trip() {
+    let options = options();
+    let dict = record(1000);
+    for i in 0..20 {
+        let input = record(i);
+        let mut plain = Vec::new();
+        ZopfliCompressZlibDict(&options, &[], &input, &mut plain);
+        let mut primed = Vec::new();
+        ZopfliCompressZlibDict(&options, &dict, &input, &mut primed);
+
+        // FDICT is set only with a dictionary, and FCHECK stays valid.
+        assert_eq!(plain[1] & 0x20, 0);
+        assert_eq!(primed[1] & 0x20, 0x20);
+        assert_eq!(u16::from_be_bytes([primed[0], primed[1]]) % 31, 0);
+
+        assert_eq!(inflate(&plain, &[]), input);
+        assert_eq!(inflate(&primed, &dict), input);
+        // The dictionary adds 4 bytes of Adler-32 and still saves more.
+        assert!(primed.len() < plain.len(), "{} >= {}", primed.len(), plain.len());
+    }
+}
+
+#[test]
+fn test_dict_edge_cases() {
+    let options = options();
+    let input = record(3);
+
+    // An empty input still needs the dictionary.
+    let dict = record(4);
+    let mut out = Vec::new();
+    ZopfliCompressZlibDict(&options, &dict, &[], &mut out);
+    assert_eq!(inflate(&out, &dict), b"");
+
+    // Only the last 32K of a long dictionary fill the window, as in zlib.
+    let mut dict = vec![b'x'; 40000];
+    dict.extend_from_slice(&input);
+    let mut out = Vec::new();
+    ZopfliCompressZlibDict(&options, &dict, &input, &mut out);
+    assert_eq!(inflate(&out, &dict), input);
+    assert!(out.len() < 20);
+}